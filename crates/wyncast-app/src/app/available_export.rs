@@ -0,0 +1,102 @@
+// Available view export: the Available tab's rows, filtered and ordered as
+// the user sees them, written as CSV. A snapshot of the board at a moment of
+// the draft (say, entering a run on starting pitchers) to look back on later.
+
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use wyncast_core::stamp::SessionStamp;
+
+use crate::protocol::AvailableViewRow;
+
+/// CSV header of the export.
+const HEADER: [&str; 10] = [
+    "rank", "player", "team", "positions", "value", "consensus", "vor", "cliff", "z_total", "pinned",
+];
+
+/// Export rows in the order given, numbered from 1 as on screen.
+pub fn rows(view: &[AvailableViewRow]) -> Vec<[String; 10]> {
+    view.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            [
+                (i + 1).to_string(),
+                row.name.clone(),
+                row.team.clone(),
+                row.positions.clone(),
+                format!("{:.1}", row.dollar_value),
+                format!("{:.1}", row.consensus_value),
+                format!("{:.2}", row.vor),
+                format!("{:.1}", row.cliff),
+                format!("{:.2}", row.total_zscore),
+                row.pinned.to_string(),
+            ]
+        })
+        .collect()
+}
+
+/// Write the view to a timestamped file in `dir`, headed by the session
+/// stamp as a `#` comment, and return its path.
+pub fn write(view: &[AvailableViewRow], session: &SessionStamp, dir: &Path) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create {}", dir.display()))?;
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let path = dir.join(format!("available-{stamp}.csv"));
+
+    let mut file = std::fs::File::create(&path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    writeln!(file, "# {}", session.summary())
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    let mut writer = csv::Writer::from_writer(file);
+    writer.write_record(HEADER)?;
+    for row in rows(view) {
+        writer.write_record(&row)?;
+    }
+    writer
+        .flush()
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(name: &str, dollar_value: f64, pinned: bool) -> AvailableViewRow {
+        AvailableViewRow {
+            name: name.into(),
+            team: "SEA".into(),
+            positions: "2B/SS".into(),
+            dollar_value,
+            consensus_value: 28.0,
+            vor: 3.456,
+            cliff: 4.0,
+            total_zscore: 1.5,
+            pinned,
+        }
+    }
+
+    #[test]
+    fn rows_keep_the_order_shown() {
+        let view = [row("Pinned", 5.0, true), row("Star", 32.04, false)];
+        let rows = rows(&view);
+        assert_eq!(rows[0][..2], ["1", "Pinned"]);
+        assert_eq!(rows[1], ["2", "Star", "SEA", "2B/SS", "32.0", "28.0", "3.46", "4.0", "1.50", "false"]);
+    }
+
+    #[test]
+    fn write_creates_a_timestamped_csv() {
+        let dir = std::env::temp_dir().join(format!("wyncast_available_export_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let session = SessionStamp::new("draft_1", &wyncast_core::config::Config::default());
+        let path = write(&[row("Star", 32.0, false)], &session, &dir).unwrap();
+        assert!(path.file_name().unwrap().to_string_lossy().starts_with("available-"));
+        let text = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], format!("# {}", session.summary()));
+        assert_eq!(lines[1], "rank,player,team,positions,value,consensus,vor,cliff,z_total,pinned");
+        assert_eq!(lines[2], "1,Star,SEA,2B/SS,32.0,28.0,3.46,4.0,1.50,false");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,151 @@
+// Bulk recalculation: rebuilding the valuations for a full state sync.
+//
+// A FULL_STATE_SYNC that resets the draft revalues every projected player
+// and then applies every pick in the snapshot; deep into a draft that is
+// noticeable. The valuations are computed on a blocking-pool thread so the
+// event loop's worker stays free for the WebSocket and the UI, and for
+// snapshots with at least `PROGRESS_MIN_PICKS` picks the TUI is sent
+// `UiUpdate::RecalcProgress` as each stage finishes: valuing the players,
+// applying the picks, and updating inflation and scarcity. The overlay
+// closes with a final `RecalcProgress(None)`.
+
+use std::collections::HashMap;
+
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use wyncast_baseball::valuation;
+use wyncast_baseball::valuation::zscore::PlayerValuation;
+
+use super::ui_health::UiHealth;
+use super::AppState;
+use crate::protocol::{RecalcProgress, UiUpdate};
+
+/// Snapshots with fewer picks rebuild too quickly to need the overlay.
+pub(super) const PROGRESS_MIN_PICKS: usize = 40;
+
+/// Recalculation steps after the picks: valuations, inflation, scarcity.
+const RECALC_STEPS: usize = 3;
+
+/// Reports a bulk recalculation's progress to the TUI. Silent for small
+/// snapshots.
+pub(super) struct ProgressReporter {
+    progress: Option<RecalcProgress>,
+}
+
+impl ProgressReporter {
+    /// A reporter for a rebuild applying `picks_total` picks.
+    pub(super) fn new(picks_total: usize) -> Self {
+        let progress = (picks_total >= PROGRESS_MIN_PICKS).then(|| RecalcProgress {
+            stage: String::new(),
+            picks_applied: 0,
+            picks_total,
+            recalcs_done: 0,
+            recalcs_total: RECALC_STEPS,
+        });
+        ProgressReporter { progress }
+    }
+
+    /// Start `stage`.
+    pub(super) async fn stage(&mut self, health: &UiHealth, ui_tx: &mpsc::Sender<UiUpdate>, stage: &str) {
+        self.report(health, ui_tx, |p| p.stage = stage.to_string()).await;
+    }
+
+    /// `picks` picks have been applied.
+    pub(super) async fn picks_applied(&mut self, health: &UiHealth, ui_tx: &mpsc::Sender<UiUpdate>, picks: usize) {
+        self.report(health, ui_tx, |p| p.picks_applied = picks.min(p.picks_total)).await;
+    }
+
+    /// A recalculation step has finished.
+    pub(super) async fn recalc_done(&mut self, health: &UiHealth, ui_tx: &mpsc::Sender<UiUpdate>) {
+        self.report(health, ui_tx, |p| p.recalcs_done = (p.recalcs_done + 1).min(p.recalcs_total))
+            .await;
+    }
+
+    /// Close the overlay.
+    pub(super) async fn finish(self, health: &UiHealth, ui_tx: &mpsc::Sender<UiUpdate>) {
+        if self.progress.is_some() {
+            health.send(ui_tx, UiUpdate::RecalcProgress(None)).await;
+        }
+    }
+
+    async fn report(
+        &mut self,
+        health: &UiHealth,
+        ui_tx: &mpsc::Sender<UiUpdate>,
+        change: impl FnOnce(&mut RecalcProgress),
+    ) {
+        if let Some(progress) = self.progress.as_mut() {
+            change(progress);
+            health.send(ui_tx, UiUpdate::RecalcProgress(Some(progress.clone()))).await;
+        }
+    }
+}
+
+impl AppState {
+    /// `initial_valuations`, with the valuations computed on a blocking-pool
+    /// thread.
+    pub(super) async fn initial_valuations_off_thread(&self, roster: &HashMap<String, usize>) -> Vec<PlayerValuation> {
+        let Some(projections) = self.all_projections.clone() else {
+            return Vec::new();
+        };
+        let config = self.config.clone();
+        let roster = roster.clone();
+        let registry = self.stat_registry.clone();
+        let cache = self.valuation_cache.clone();
+        let computed = tokio::task::spawn_blocking(move || match cache {
+            Some(path) => valuation::cache::compute_initial_cached(&projections, &config, &roster, &registry, &path),
+            None => valuation::compute_initial(&projections, &config, &roster, &registry),
+        })
+        .await;
+        let valuations = match computed {
+            Ok(valuations) => valuations,
+            Err(e) => {
+                warn!("Valuation task failed: {}", e);
+                Vec::new()
+            }
+        };
+        self.finish_valuations(valuations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(rx: &mut mpsc::Receiver<UiUpdate>) -> Vec<Option<RecalcProgress>> {
+        std::iter::from_fn(|| rx.try_recv().ok())
+            .map(|update| match update {
+                UiUpdate::RecalcProgress(progress) => progress,
+                other => panic!("unexpected update {other:?}"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn a_large_rebuild_reports_each_stage_then_closes() {
+        let health = UiHealth::default();
+        let (ui_tx, mut rx) = mpsc::channel(16);
+
+        let mut reporter = ProgressReporter::new(150);
+        reporter.stage(&health, &ui_tx, "Valuing players").await;
+        reporter.recalc_done(&health, &ui_tx).await;
+        reporter.picks_applied(&health, &ui_tx, 150).await;
+        reporter.finish(&health, &ui_tx).await;
+
+        let updates = drain(&mut rx);
+        assert_eq!(updates.len(), 4);
+        let first = updates[0].as_ref().unwrap();
+        assert_eq!(first.stage, "Valuing players");
+        assert_eq!((first.picks_total, first.recalcs_total), (150, RECALC_STEPS));
+        assert_eq!(first.ratio(), 0.0);
+        let applied = updates[2].as_ref().unwrap();
+        assert_eq!((applied.picks_applied, applied.recalcs_done), (150, 1));
+        assert_eq!(updates[3], None);
+
+        let mut small = ProgressReporter::new(PROGRESS_MIN_PICKS - 1);
+        small.stage(&health, &ui_tx, "Valuing players").await;
+        small.finish(&health, &ui_tx).await;
+        assert!(drain(&mut rx).is_empty());
+    }
+}
@@ -1,11 +1,17 @@
+use anyhow::Context;
 use tokio::sync::mpsc;
 use tracing::{info, warn};
 
+use wyncast_baseball::draft::pick::positions_str;
+use wyncast_baseball::valuation::identity;
+use wyncast_core::logging;
+
 use crate::protocol::{
     AppMode, OnboardingAction, OnboardingUpdate, UiUpdate, UserCommand,
 };
 
-use super::AppState;
+use super::undo::Edit;
+use super::{available_export, keeper_report, projection_audit, stats_link, support_bundle, time_machine, AppState};
 use super::onboarding_handler::{get_api_key_for_provider, handle_onboarding_action, handle_settings_action};
 
 /// Handle a user command from the TUI.
@@ -31,6 +37,9 @@ pub(super) async fn handle_user_command(
             } else {
                 warn!("Cannot request keyframe: no outbound WebSocket channel");
             }
+            state.ui_health.resynced();
+            let snapshot = state.build_snapshot();
+            state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
         }
         UserCommand::ManualPick {
             player_name,
@@ -41,27 +50,56 @@ pub(super) async fn handle_user_command(
                 "Manual pick: {} -> team {} for ${}",
                 player_name, team_idx, price
             );
-            if team_idx < state.draft_state.teams.len() {
+            let outcome = if team_idx < state.draft_state.teams.len() {
+                // Record the player's own positions so the pick is slotted
+                // like a live one. A name several players share gets all
+                // their positions, so the pick fits each of them and waits
+                // for me to say which. A player already drafted keeps their
+                // pick's position, so a repeat is seen as one; anyone else
+                // outside the pool is UTIL.
+                let positions = identity::name_positions(&state.available_players, &player_name);
+                let drafted = state.draft_state.picks.iter().find(|p| p.player_name == player_name);
+                let position = if !positions.is_empty() {
+                    positions_str(&positions)
+                } else if let Some(drafted) = drafted {
+                    drafted.position.clone()
+                } else {
+                    warn!("Manual pick of {} is not in the available pool; recording it as UTIL", player_name);
+                    "UTIL".to_string()
+                };
                 let team = &state.draft_state.teams[team_idx];
                 let pick = wyncast_baseball::draft::pick::DraftPick {
                     pick_number: 0, // overwritten by record_pick
                     team_id: team.team_id.clone(),
                     team_name: team.team_name.clone(),
-                    player_name,
-                    position: "UTIL".to_string(),
+                    player_name: player_name.clone(),
+                    position,
                     price,
                     espn_player_id: None,
                     eligible_slots: vec![],
-            assigned_slot: None,
+                    assigned_slot: None,
                 };
-                state.process_new_picks(vec![pick]);
+                let before = state.draft_state.picks.len();
+                state.apply_edit(Edit::ManualPick { pick, removed: Vec::new(), signed: None });
 
-                // Send updated state to TUI
-                let snapshot = state.build_snapshot();
-                let _ = ui_tx
-                    .send(UiUpdate::StateSnapshot(Box::new(snapshot)))
-                    .await;
-            }
+                if state.draft_state.picks.len() > before {
+                    // Send updated state to TUI
+                    let snapshot = state.build_snapshot();
+                    state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
+                    Ok(player_name)
+                } else {
+                    warn!("Manual pick of {} changed nothing: already drafted", player_name);
+                    Err(format!("{player_name} has already been drafted"))
+                }
+            } else {
+                warn!("Manual pick of {} rejected: no team #{}", player_name, team_idx);
+                Err(format!(
+                    "no team #{} (the league has {})",
+                    team_idx,
+                    state.draft_state.teams.len()
+                ))
+            };
+            state.ui_health.send(ui_tx, UiUpdate::ManualPicked(outcome)).await;
         }
         UserCommand::Scroll { .. } => {
             // Scroll is handled by the TUI directly, no app-level action needed
@@ -81,11 +119,10 @@ pub(super) async fn handle_user_command(
         UserCommand::OpenSettings => {
             info!("Opening settings screen");
             state.app_mode = AppMode::Settings(crate::protocol::SettingsSection::LlmConfig);
-            let _ = ui_tx
-                .send(UiUpdate::ModeChanged(AppMode::Settings(
-                    crate::protocol::SettingsSection::LlmConfig,
-                )))
-                .await;
+            let update = UiUpdate::ModeChanged(AppMode::Settings(
+                crate::protocol::SettingsSection::LlmConfig,
+            ));
+            state.ui_health.send(ui_tx, update).await;
             // Send a ProgressSync so the TUI can show a masked placeholder
             // for the saved API key (instead of showing a blank field).
             let provider = state
@@ -100,24 +137,21 @@ pub(super) async fn handle_user_command(
                 let m = crate::onboarding::strategy_config::mask_api_key(&raw_key);
                 if m.is_empty() { None } else { Some(m) }
             };
-            let _ = ui_tx
-                .send(UiUpdate::OnboardingUpdate(
-                    OnboardingUpdate::ProgressSync {
-                        provider: state.onboarding_progress.llm_provider.clone(),
-                        model: state.onboarding_progress.llm_model.clone(),
-                        api_key_mask: mask,
-                    },
-                ))
-                .await;
+            let update = UiUpdate::OnboardingUpdate(
+                OnboardingUpdate::ProgressSync {
+                    provider: state.onboarding_progress.llm_provider.clone(),
+                    model: state.onboarding_progress.llm_model.clone(),
+                    api_key_mask: mask,
+                },
+            );
+            state.ui_health.send(ui_tx, update).await;
         }
         UserCommand::ExitSettings => {
             info!("Exiting settings, returning to draft mode");
             state.app_mode = AppMode::Draft;
-            let _ = ui_tx.send(UiUpdate::ModeChanged(AppMode::Draft)).await;
+            state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Draft)).await;
             let snapshot = state.build_snapshot();
-            let _ = ui_tx
-                .send(UiUpdate::StateSnapshot(Box::new(snapshot)))
-                .await;
+            state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
         }
         UserCommand::SaveAndExitSettings { llm, strategy } => {
             info!("Saving settings and exiting to draft mode");
@@ -145,17 +179,13 @@ pub(super) async fn handle_user_command(
             }
             // Transition to draft mode
             state.app_mode = AppMode::Draft;
-            let _ = ui_tx.send(UiUpdate::ModeChanged(AppMode::Draft)).await;
+            state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Draft)).await;
             let snapshot = state.build_snapshot();
-            let _ = ui_tx
-                .send(UiUpdate::StateSnapshot(Box::new(snapshot)))
-                .await;
+            state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
         }
         UserCommand::SwitchSettingsTab(section) => {
             state.app_mode = AppMode::Settings(section);
-            let _ = ui_tx
-                .send(UiUpdate::ModeChanged(AppMode::Settings(section)))
-                .await;
+            state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Settings(section))).await;
             // When switching to the StrategyConfig tab, send current saved
             // config so the TUI initializes the strategy wizard at the
             // Review step with the correct values (including strategy_overview).
@@ -166,16 +196,172 @@ pub(super) async fn handle_user_command(
                     crate::onboarding::strategy_config::categories_from_league(&state.config.league),
                 );
                 let overview = state.config.strategy.strategy_overview.clone().unwrap_or_default();
-                let _ = ui_tx
-                    .send(UiUpdate::OnboardingUpdate(
-                        crate::protocol::OnboardingUpdate::StrategyLlmComplete {
-                            hitting_budget_pct: pct,
-                            category_weights: weights,
-                            strategy_overview: overview,
-                        },
-                    ))
-                    .await;
+                let update = UiUpdate::OnboardingUpdate(
+                    crate::protocol::OnboardingUpdate::StrategyLlmComplete {
+                        hitting_budget_pct: pct,
+                        category_weights: weights,
+                        strategy_overview: overview,
+                    },
+                );
+                state.ui_health.send(ui_tx, update).await;
+            }
+        }
+        UserCommand::RequestTimeMachine { pick_number } => {
+            let snapshot = time_machine::build_snapshot(state, pick_number);
+            if snapshot.is_none() {
+                warn!("Time machine: could not reconstruct state at pick {}", pick_number);
+            }
+            let update = UiUpdate::TimeMachine {
+                pick_number,
+                snapshot: snapshot.map(Box::new),
+            };
+            state.ui_health.send(ui_tx, update).await;
+        }
+        UserCommand::ExplainPlayer { player } => {
+            let explanation = state.explain_player(&player);
+            if let Err(e) = &explanation {
+                warn!("Could not explain {}: {}", player.name, e);
+            }
+            state.ui_health.send(ui_tx, UiUpdate::PlayerExplanation(explanation.map(Box::new))).await;
+        }
+        UserCommand::ComparePlayers { a, b } => {
+            let comparison = state.compare_players(&a, &b);
+            if let Err(e) = &comparison {
+                warn!("Could not compare {} and {}: {}", a.name, b.name, e);
+            }
+            state.ui_health.send(ui_tx, UiUpdate::PlayerComparison(comparison.map(Box::new))).await;
+        }
+        UserCommand::ResolvePick { pick_number, team } => {
+            let outcome = state.resolve_pick(pick_number, &team);
+            match &outcome {
+                Ok(_) => {
+                    let snapshot = state.build_snapshot();
+                    state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
+                }
+                Err(e) => warn!("Could not resolve pick #{}: {}", pick_number, e),
+            }
+            state.ui_health.send(ui_tx, UiUpdate::PickResolved(outcome)).await;
+        }
+        UserCommand::OpenStatsPage { name, team, site } => {
+            let url = stats_link::url(state.config.display.stats_url(site), &name, &team);
+            info!("Opening {} page for {}: {}", site.label(), name, url);
+            let outcome = stats_link::open(&url)
+                .map(|()| format!("{} page for {name}", site.label()))
+                .map_err(|e| e.to_string());
+            if let Err(e) = &outcome {
+                warn!("Could not open {}: {}", url, e);
             }
+            state.ui_health.send(ui_tx, UiUpdate::StatsPageOpened(outcome)).await;
+        }
+        UserCommand::CompareWithLlm { a, b } => match state.trigger_comparison(&a, &b) {
+            Ok(request_id) => {
+                state.ui_health.send(ui_tx, UiUpdate::ComparisonStarted { request_id }).await;
+            }
+            Err(e) => warn!("Could not compare {} and {}: {}", a.name, b.name, e),
+        },
+        UserCommand::QuickBid(key) => {
+            if let Some(intent) = state.log_quick_bid(key) {
+                state.ui_health.send(ui_tx, UiUpdate::BidIntent(intent)).await;
+            }
+        }
+        UserCommand::SnoozeRecalc => {
+            if state.resume_recalc() {
+                info!("Recalculation snooze ended by hand");
+            } else {
+                state.snooze_recalc();
+                info!("Automatic recalculation snoozed");
+            }
+            let snapshot = state.build_snapshot();
+            state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
+        }
+        UserCommand::SetPin { name, pinned } => {
+            state.apply_edit(Edit::Pin { name, pinned });
+        }
+        UserCommand::MoveToSlot { player_name, slot } => {
+            let from = state.draft_state.my_team().and_then(|t| t.roster.slot_of(&player_name));
+            match from {
+                Some(from) => state.apply_edit(Edit::MoveSlot { player: player_name, from, to: slot }),
+                None => warn!("Slot move of {} rejected: not on my roster", player_name),
+            }
+            let snapshot = state.build_snapshot();
+            state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
+        }
+        UserCommand::Undo | UserCommand::Redo => {
+            let undone = cmd == UserCommand::Undo;
+            let outcome = if undone { state.undo() } else { state.redo() };
+            if outcome.is_ok() {
+                if let Some((name, pinned)) = state.undo_stack.last(undone).and_then(|e| e.pin(undone)) {
+                    let update = UiUpdate::PinSet { name: name.to_string(), pinned };
+                    state.ui_health.send(ui_tx, update).await;
+                }
+                let snapshot = state.build_snapshot();
+                state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
+            }
+            state.ui_health.send(ui_tx, UiUpdate::UndoRedo(outcome)).await;
+        }
+        UserCommand::DumpSupportBundle => {
+            let result = logging::resolve_log_dir(&state.config.logging)
+                .context("failed to resolve the log directory")
+                .and_then(|dir| support_bundle::write(state, &dir));
+            let outcome = match result {
+                Ok(path) => {
+                    info!("Support bundle written to {}", path.display());
+                    Ok(path.display().to_string())
+                }
+                Err(e) => {
+                    warn!("Failed to write support bundle: {:#}", e);
+                    Err(format!("{e:#}"))
+                }
+            };
+            state.ui_health.send(ui_tx, UiUpdate::SupportBundle(outcome)).await;
+        }
+        UserCommand::ExportProjectionAudit => {
+            let result = logging::resolve_log_dir(&state.config.logging)
+                .context("failed to resolve the log directory")
+                .and_then(|dir| projection_audit::write(state, &dir));
+            let outcome = match result {
+                Ok(path) => {
+                    info!("Projection audit written to {}", path.display());
+                    Ok(path.display().to_string())
+                }
+                Err(e) => {
+                    warn!("Failed to write projection audit: {:#}", e);
+                    Err(format!("{e:#}"))
+                }
+            };
+            state.ui_health.send(ui_tx, UiUpdate::ProjectionAudit(outcome)).await;
+        }
+        UserCommand::ExportKeeperReport => {
+            let result = logging::resolve_log_dir(&state.config.logging)
+                .context("failed to resolve the log directory")
+                .and_then(|dir| keeper_report::write(state, &dir));
+            let outcome = match result {
+                Ok(path) => {
+                    info!("Keeper report written to {}", path.display());
+                    Ok(path.display().to_string())
+                }
+                Err(e) => {
+                    warn!("Failed to write keeper report: {:#}", e);
+                    Err(format!("{e:#}"))
+                }
+            };
+            state.ui_health.send(ui_tx, UiUpdate::KeeperReport(outcome)).await;
+        }
+        UserCommand::ExportAvailableView(view) => {
+            let result = logging::resolve_log_dir(&state.config.logging)
+                .context("failed to resolve the log directory")
+                .and_then(|dir| available_export::write(&view, &state.session_stamp, &dir));
+            let outcome = match result {
+                Ok(path) => {
+                    info!("Available view written to {}", path.display());
+                    Ok(path.display().to_string())
+                }
+                Err(e) => {
+                    warn!("Failed to write the Available view: {:#}", e);
+                    Err(format!("{e:#}"))
+                }
+            };
+            state.ui_health.send(ui_tx, UiUpdate::AvailableExport(outcome)).await;
         }
         UserCommand::Quit => {
             // Handled in the main loop
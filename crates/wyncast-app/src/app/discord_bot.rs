@@ -0,0 +1,221 @@
+// Discord bot for second-screen queries (built with the `discord-bot`
+// feature). Connects to the Discord gateway, listens for direct messages
+// from the users in `[discord_bot] allowed_users`, and answers `!value` and
+// `!plan` through the same query layer as every other front-end: each
+// message becomes a `QueryRequest` for the event loop, and the answer is
+// posted back to the channel it came from.
+
+use std::time::Duration;
+
+use anyhow::Context;
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, oneshot};
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, info, warn};
+
+use super::notify::{truncate, DISCORD_MESSAGE_LIMIT};
+use super::query::{Query, QueryRequest};
+
+const GATEWAY_URL: &str = "wss://gateway.discord.gg/?v=10&encoding=json";
+const API_URL: &str = "https://discord.com/api/v10";
+
+/// DIRECT_MESSAGES | MESSAGE_CONTENT. Message content is a privileged
+/// intent and must be enabled for the bot in the developer portal.
+const INTENTS: u64 = (1 << 12) | (1 << 15);
+
+// Gateway opcodes.
+const OP_DISPATCH: u64 = 0;
+const OP_HEARTBEAT: u64 = 1;
+const OP_IDENTIFY: u64 = 2;
+const OP_RECONNECT: u64 = 7;
+const OP_INVALID_SESSION: u64 = 9;
+const OP_HELLO: u64 = 10;
+
+/// Pause before reconnecting after the gateway drops us.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// How long to wait for the Discord API when replying.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A direct message from an allowed user that asks a query.
+#[derive(Debug, Clone, PartialEq)]
+struct DirectMessage {
+    channel_id: String,
+    query: Query,
+}
+
+impl DirectMessage {
+    /// Pick the query out of a MESSAGE_CREATE event. Guild messages, bot
+    /// messages, strangers and ordinary chatter are ignored.
+    fn from_event(event: &Value, allowed_users: &[String]) -> Option<Self> {
+        if !event["guild_id"].is_null() || event["author"]["bot"].as_bool() == Some(true) {
+            return None;
+        }
+        let author = event["author"]["id"].as_str()?;
+        if !allowed_users.iter().any(|u| u == author) {
+            debug!("Ignoring Discord message from user {}", author);
+            return None;
+        }
+        Some(DirectMessage {
+            channel_id: event["channel_id"].as_str()?.to_string(),
+            query: Query::parse(event["content"].as_str()?)?,
+        })
+    }
+}
+
+fn identify(token: &str) -> Value {
+    json!({
+        "op": OP_IDENTIFY,
+        "d": {
+            "token": token,
+            "intents": INTENTS,
+            "properties": { "os": std::env::consts::OS, "browser": "wyncast", "device": "wyncast" },
+        },
+    })
+}
+
+fn heartbeat(seq: Option<u64>) -> Message {
+    Message::Text(json!({ "op": OP_HEARTBEAT, "d": seq }).to_string().into())
+}
+
+/// Run the bot until the event loop goes away, reconnecting whenever the
+/// gateway drops the session.
+pub async fn run(token: String, allowed_users: Vec<String>, query_tx: mpsc::Sender<QueryRequest>) {
+    let http = reqwest::Client::new();
+    while !query_tx.is_closed() {
+        match session(&token, &allowed_users, &query_tx, &http).await {
+            Ok(()) => info!("Discord gateway closed the session, reconnecting"),
+            Err(e) => warn!("Discord bot disconnected: {:#}", e),
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+    info!("Discord bot stopped");
+}
+
+/// One gateway session: identify, heartbeat, and answer messages until the
+/// connection ends.
+async fn session(
+    token: &str,
+    allowed_users: &[String],
+    query_tx: &mpsc::Sender<QueryRequest>,
+    http: &reqwest::Client,
+) -> anyhow::Result<()> {
+    let (ws, _) = tokio_tungstenite::connect_async(GATEWAY_URL)
+        .await
+        .context("failed to connect to the Discord gateway")?;
+    let (mut sink, mut stream) = ws.split();
+
+    let hello = match stream.next().await {
+        Some(Ok(Message::Text(text))) => serde_json::from_str::<Value>(&text)?,
+        other => anyhow::bail!("expected the gateway hello, got {other:?}"),
+    };
+    if hello["op"].as_u64() != Some(OP_HELLO) {
+        anyhow::bail!("expected the gateway hello, got op {}", hello["op"]);
+    }
+    let interval = hello["d"]["heartbeat_interval"]
+        .as_u64()
+        .context("gateway hello has no heartbeat interval")?;
+    sink.send(Message::Text(identify(token).to_string().into())).await?;
+    info!("Discord bot connected");
+
+    let mut heartbeat_timer = tokio::time::interval(Duration::from_millis(interval));
+    // The first tick completes immediately; the identify stands in for it.
+    heartbeat_timer.tick().await;
+    let mut seq: Option<u64> = None;
+
+    loop {
+        tokio::select! {
+            _ = heartbeat_timer.tick() => sink.send(heartbeat(seq)).await?,
+            message = stream.next() => {
+                let payload: Value = match message {
+                    Some(Ok(Message::Text(text))) => serde_json::from_str(&text)?,
+                    Some(Ok(Message::Close(_))) | None => return Ok(()),
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => return Err(e.into()),
+                };
+                if let Some(s) = payload["s"].as_u64() {
+                    seq = Some(s);
+                }
+                match payload["op"].as_u64() {
+                    Some(OP_DISPATCH) if payload["t"] == "MESSAGE_CREATE" => {
+                        if let Some(dm) = DirectMessage::from_event(&payload["d"], allowed_users) {
+                            tokio::spawn(reply(dm, query_tx.clone(), http.clone(), token.to_string()));
+                        }
+                    }
+                    Some(OP_HEARTBEAT) => sink.send(heartbeat(seq)).await?,
+                    Some(OP_RECONNECT) | Some(OP_INVALID_SESSION) => return Ok(()),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Ask the event loop and post its answer back to the DM channel.
+async fn reply(dm: DirectMessage, query_tx: mpsc::Sender<QueryRequest>, http: reqwest::Client, token: String) {
+    let (reply_tx, reply_rx) = oneshot::channel();
+    let request = QueryRequest { query: dm.query, reply: reply_tx };
+    if query_tx.send(request).await.is_err() {
+        return;
+    }
+    let Ok(answer) = reply_rx.await else {
+        return;
+    };
+
+    let result = http
+        .post(format!("{API_URL}/channels/{}/messages", dm.channel_id))
+        .header("Authorization", format!("Bot {token}"))
+        .timeout(REPLY_TIMEOUT)
+        .json(&json!({ "content": truncate(&answer, DISCORD_MESSAGE_LIMIT) }))
+        .send()
+        .await
+        .and_then(|resp| resp.error_for_status());
+    if let Err(e) = result {
+        warn!("Failed to reply on Discord: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(author: &str, content: &str) -> Value {
+        json!({
+            "channel_id": "42",
+            "content": content,
+            "author": { "id": author },
+        })
+    }
+
+    #[test]
+    fn direct_messages_from_allowed_users_become_queries() {
+        let allowed = vec!["7".to_string()];
+        assert_eq!(
+            DirectMessage::from_event(&event("7", "!value Soto"), &allowed),
+            Some(DirectMessage { channel_id: "42".into(), query: Query::Value("Soto".into()) })
+        );
+        assert_eq!(DirectMessage::from_event(&event("7", "thanks!"), &allowed), None);
+        assert_eq!(DirectMessage::from_event(&event("8", "!plan"), &allowed), None);
+    }
+
+    #[test]
+    fn guild_and_bot_messages_are_ignored() {
+        let allowed = vec!["7".to_string()];
+        let mut guild = event("7", "!plan");
+        guild["guild_id"] = json!("1");
+        assert_eq!(DirectMessage::from_event(&guild, &allowed), None);
+
+        let mut bot = event("7", "!plan");
+        bot["author"]["bot"] = json!(true);
+        assert_eq!(DirectMessage::from_event(&bot, &allowed), None);
+    }
+
+    #[test]
+    fn identify_requests_direct_message_content() {
+        let payload = identify("token");
+        assert_eq!(payload["op"], OP_IDENTIFY);
+        assert_eq!(payload["d"]["intents"], 36864);
+        assert_eq!(payload["d"]["token"], "token");
+    }
+}
@@ -0,0 +1,101 @@
+// Doctor: the `--doctor` quick mode. Lists every place the app reads or
+// writes, resolved the way a normal launch resolves it, and whether each one
+// exists, so a launch from a shortcut or another directory can be checked
+// without starting a draft. The same list is logged at every startup.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use wyncast_baseball::valuation::projections::resolve_data_path;
+use wyncast_core::app_dirs;
+use wyncast_core::config::Config;
+use wyncast_core::logging;
+
+/// One resolved location.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedPath {
+    pub label: String,
+    pub path: PathBuf,
+}
+
+/// Every location `config` resolves to: the app directories, the database,
+/// cache, logs and backups, then each configured data file.
+pub fn resolved_paths(config: &Config) -> Vec<ResolvedPath> {
+    let mut paths = vec![
+        resolved("Data directory", app_dirs::app_data_dir()),
+        resolved("Config", app_dirs::config_dir()),
+        resolved("Database", config.data_paths.database_path()),
+        resolved("Valuations cache", config.data_paths.valuation_cache_path()),
+    ];
+    if let Ok(dir) = logging::resolve_log_dir(&config.logging) {
+        paths.push(resolved("Logs", dir));
+    }
+    if let Some(dir) = &config.backup.dir {
+        paths.push(resolved("Backups", dir.into()));
+    }
+
+    let data = &config.data_paths;
+    let files = [
+        ("Hitter projections", &data.hitters),
+        ("Pitcher projections", &data.pitchers),
+        ("Adjustments", &data.adjustments),
+        ("Prep sheet", &data.prep),
+        ("Nomination script", &data.nomination_queue),
+    ];
+    for (label, raw) in files {
+        if let Some(raw) = raw {
+            paths.push(resolved(label, resolve_data_path(raw)));
+        }
+    }
+    for raw in &data.scripts {
+        paths.push(resolved("Script", resolve_data_path(raw)));
+    }
+    paths
+}
+
+fn resolved(label: &str, path: PathBuf) -> ResolvedPath {
+    ResolvedPath { label: label.into(), path }
+}
+
+/// Render the paths for the terminal, one per line, flagging the ones that
+/// do not exist yet.
+pub fn render(paths: &[ResolvedPath]) -> String {
+    let width = paths.iter().map(|p| p.label.len()).max().unwrap_or(0);
+    let mut out = String::new();
+    for p in paths {
+        let missing = if p.path.exists() { "" } else { "  (missing)" };
+        let _ = writeln!(out, "{:<width$}  {}{}", p.label, p.path.display(), missing);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_follow_the_config_overrides() {
+        let mut config = crate::app::test_utils::test_config();
+        config.data_paths.database = Some("/srv/wyncast/draft.db".into());
+        config.data_paths.cache_dir = Some("/srv/wyncast/cache".into());
+        config.data_paths.prep = Some("/srv/wyncast/prep.csv".into());
+
+        let paths = resolved_paths(&config);
+        let find = |label: &str| paths.iter().find(|p| p.label == label).map(|p| p.path.clone());
+        assert_eq!(find("Database"), Some("/srv/wyncast/draft.db".into()));
+        assert_eq!(find("Valuations cache"), Some("/srv/wyncast/cache/valuations.cache".into()));
+        assert_eq!(find("Prep sheet"), Some("/srv/wyncast/prep.csv".into()));
+        assert_eq!(find("Config"), Some(app_dirs::config_dir()));
+    }
+
+    #[test]
+    fn render_flags_missing_paths() {
+        let text = render(&[
+            resolved("Data directory", app_dirs::app_data_dir()),
+            resolved("Database", "/nonexistent/draft.db".into()),
+        ]);
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(!lines[0].contains("(missing)"), "{text}");
+        assert_eq!(lines[1], "Database        /nonexistent/draft.db  (missing)");
+    }
+}
@@ -0,0 +1,68 @@
+// Keeper report: every keeper contract priced against the model, written as
+// CSV with this season's salary and surplus, the surplus over the rest of
+// the contract, and the salary schedule. Sorted by this season's surplus so
+// the best keepers come first.
+
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use wyncast_baseball::valuation::keeper::{self, KeeperValue};
+
+use super::AppState;
+
+/// CSV header of the report.
+const HEADER: [&str; 7] = ["player", "salary", "years", "value", "surplus", "future_surplus", "salaries"];
+
+/// Report rows, best surplus first. Players without a model value (already
+/// drafted or not projected) come last with those columns empty.
+pub fn rows(values: &[KeeperValue]) -> Vec<[String; 7]> {
+    let mut sorted: Vec<&KeeperValue> = values.iter().collect();
+    sorted.sort_by(|a, b| {
+        let key = |v: &KeeperValue| v.surplus().unwrap_or(f64::NEG_INFINITY);
+        key(b).total_cmp(&key(a))
+    });
+    let dollars = |v: Option<f64>| v.map(|v| format!("{v:.1}")).unwrap_or_default();
+    sorted
+        .into_iter()
+        .map(|v| {
+            [
+                v.player.clone(),
+                v.salary().to_string(),
+                v.years().to_string(),
+                dollars(v.value),
+                dollars(v.surplus()),
+                dollars(v.future_surplus()),
+                v.salaries.iter().map(u32::to_string).collect::<Vec<_>>().join("/"),
+            ]
+        })
+        .collect()
+}
+
+/// Write the report to a timestamped file in `dir`, headed by the session
+/// stamp as a `#` comment, and return its path.
+pub fn write(state: &AppState, dir: &Path) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create {}", dir.display()))?;
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let path = dir.join(format!("keeper-report-{stamp}.csv"));
+
+    let values = keeper::keeper_values(
+        &state.keeper_contracts,
+        &state.config.league.keepers,
+        &state.available_players,
+    );
+    let mut file = std::fs::File::create(&path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    writeln!(file, "# {}", state.session_stamp.summary())
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    let mut writer = csv::Writer::from_writer(file);
+    writer.write_record(HEADER)?;
+    for row in rows(&values) {
+        writer.write_record(&row)?;
+    }
+    writer
+        .flush()
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
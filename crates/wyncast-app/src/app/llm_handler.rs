@@ -1,15 +1,20 @@
 use tokio::sync::mpsc;
 use tracing::{debug, warn};
 
+use wyncast_baseball::llm::style;
+
 use crate::protocol::{LlmEvent, LlmStreamUpdate, UiUpdate};
 
+use super::llm_request_manager::DEADLINE_STOP_REASON;
 use super::AppState;
 
 /// Handle an LLM streaming event.
 ///
 /// Validates the event against the request manager, converts it to
 /// a generic `LlmStreamUpdate`, and sends a single `UiUpdate::LlmUpdate`.
-/// No mode matching, no text buffering on AppState.
+/// No mode matching, no text buffering on AppState. A completed nomination
+/// analysis is trimmed to the configured style first; one cut off by the
+/// auction clock is finalized with the engine's verdict before that.
 pub(super) async fn handle_llm_event(
     state: &mut AppState,
     event: LlmEvent,
@@ -30,6 +35,18 @@ pub(super) async fn handle_llm_event(
             (LlmStreamUpdate::Token(text), false)
         }
         LlmEvent::Complete { full_text, stop_reason, .. } => {
+            let at_deadline = stop_reason.as_deref() == Some(DEADLINE_STOP_REASON);
+            let full_text = if state.analysis_request_id == Some(request_id) {
+                let full_text = match &state.nomination_verdict {
+                    Some(verdict) if at_deadline => {
+                        style::finalize_at_deadline(&full_text, &verdict.verdict, verdict.max_bid)
+                    }
+                    _ => full_text,
+                };
+                style::trim_analysis(&full_text, &state.config.strategy.llm)
+            } else {
+                full_text
+            };
             let text = if stop_reason.as_deref() == Some("max_tokens") {
                 format!("{full_text}\n\n[Response truncated due to token limit]")
             } else {
@@ -42,11 +59,11 @@ pub(super) async fn handle_llm_event(
         }
     };
 
-    let send_result = ui_tx.send(UiUpdate::LlmUpdate { request_id, update }).await;
+    let delivered = state.ui_health.send(ui_tx, UiUpdate::LlmUpdate { request_id, update }).await;
 
     if is_terminal {
         state.llm_requests.complete(request_id);
-        if send_result.is_err() {
+        if !delivered {
             warn!("Failed to deliver terminal LLM event for request {}", request_id);
         }
     }
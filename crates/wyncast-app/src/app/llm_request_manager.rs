@@ -1,13 +1,20 @@
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tracing::{info, warn};
 
 use wyncast_llm::client::LlmClient;
+use wyncast_llm::error::LlmError;
 use crate::protocol::LlmEvent;
 
+/// `stop_reason` of a reply finalized at its deadline rather than by the
+/// provider.
+pub const DEADLINE_STOP_REASON: &str = "deadline";
+
 /// Manages active LLM streaming requests by ID.
 ///
 /// Each request gets a unique monotonic ID (which doubles as the `generation`
@@ -43,17 +50,40 @@ impl LlmRequestManager {
         user_content: String,
         max_tokens: u32,
         tx: mpsc::Sender<LlmEvent>,
+    ) -> u64 {
+        self.start_with_deadline(client, system, user_content, max_tokens, tx, None)
+    }
+
+    /// Like [`start`](Self::start), but a reply still streaming after
+    /// `deadline` is cut off there: the request is dropped and what has
+    /// streamed so far is sent as a `Complete` event with
+    /// [`DEADLINE_STOP_REASON`].
+    pub fn start_with_deadline(
+        &mut self,
+        client: Arc<LlmClient>,
+        system: String,
+        user_content: String,
+        max_tokens: u32,
+        tx: mpsc::Sender<LlmEvent>,
+        deadline: Option<Duration>,
     ) -> u64 {
         let id = self.next_id;
         self.next_id += 1;
 
         let handle = tokio::spawn(async move {
-            if let Err(e) = client
-                .stream_message(&system, &user_content, max_tokens, tx, id)
-                .await
-            {
-                warn!("LLM request {} failed: {}", id, e);
-            }
+            let Some(deadline) = deadline else {
+                if let Err(e) = client
+                    .stream_message(&system, &user_content, max_tokens, tx, id)
+                    .await
+                {
+                    warn!("LLM request {} failed: {}", id, e);
+                }
+                return;
+            };
+
+            let (inner_tx, inner_rx) = mpsc::channel(64);
+            let stream = client.stream_message(&system, &user_content, max_tokens, inner_tx, id);
+            relay_until(id, deadline, stream, inner_rx, tx).await;
         });
 
         self.active.insert(id, handle);
@@ -115,6 +145,58 @@ impl LlmRequestManager {
     }
 }
 
+/// Relay the events of request `id` from `inner_rx` to `tx`, keeping the
+/// text streamed so far, until the reply ends or `deadline` passes.
+async fn relay_until(
+    id: u64,
+    deadline: Duration,
+    stream: impl Future<Output = Result<(), LlmError>>,
+    mut inner_rx: mpsc::Receiver<LlmEvent>,
+    tx: mpsc::Sender<LlmEvent>,
+) {
+    tokio::pin!(stream);
+    let expiry = tokio::time::sleep(deadline);
+    tokio::pin!(expiry);
+    let mut text = String::new();
+    let mut streaming = true;
+    loop {
+        tokio::select! {
+            result = &mut stream, if streaming => {
+                streaming = false;
+                if let Err(e) = result {
+                    warn!("LLM request {} failed: {}", id, e);
+                }
+            }
+            event = inner_rx.recv() => {
+                let Some(event) = event else { return };
+                let terminal = match &event {
+                    LlmEvent::Token { text: token, .. } => {
+                        text.push_str(token);
+                        false
+                    }
+                    _ => true,
+                };
+                if tx.send(event).await.is_err() || terminal {
+                    return;
+                }
+            }
+            _ = &mut expiry => {
+                info!("LLM request {} reached its deadline after {} chars", id, text.len());
+                let _ = tx
+                    .send(LlmEvent::Complete {
+                        full_text: text,
+                        input_tokens: 0,
+                        output_tokens: 0,
+                        stop_reason: Some(DEADLINE_STOP_REASON.to_string()),
+                        generation: id,
+                    })
+                    .await;
+                return;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,6 +250,40 @@ mod tests {
         assert!(!mgr.is_active(id));
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn a_reply_still_streaming_at_the_deadline_is_completed_there() {
+        let (inner_tx, inner_rx) = mpsc::channel(8);
+        let (tx, mut rx) = mpsc::channel(8);
+        let token = |text: &str| LlmEvent::Token { text: text.into(), generation: 7 };
+        inner_tx.send(token("Strong bat. ")).await.unwrap();
+        inner_tx.send(token("Fills 1B")).await.unwrap();
+
+        // The provider never finishes.
+        let stream = std::future::pending::<Result<(), LlmError>>();
+        relay_until(7, Duration::from_secs(10), stream, inner_rx, tx).await;
+
+        assert_eq!(rx.recv().await, Some(token("Strong bat. ")));
+        assert_eq!(rx.recv().await, Some(token("Fills 1B")));
+        match rx.recv().await {
+            Some(LlmEvent::Complete { full_text, stop_reason, generation, .. }) => {
+                assert_eq!(full_text, "Strong bat. Fills 1B");
+                assert_eq!(stop_reason.as_deref(), Some(DEADLINE_STOP_REASON));
+                assert_eq!(generation, 7);
+            }
+            other => panic!("expected a deadline completion, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_reply_done_before_the_deadline_is_relayed_as_is() {
+        let mgr_client = Arc::new(LlmClient::Disabled);
+        let mut mgr = LlmRequestManager::new();
+        let (tx, mut rx) = mpsc::channel(8);
+        let id = mgr.start_with_deadline(mgr_client, "s".into(), "u".into(), 100, tx, Some(Duration::from_secs(30)));
+        assert!(matches!(rx.recv().await, Some(LlmEvent::Error { generation, .. }) if generation == id));
+        assert!(rx.recv().await.is_none(), "nothing after the terminal event");
+    }
+
     #[tokio::test]
     async fn cancel_all_clears_active() {
         let mut mgr = LlmRequestManager::new();
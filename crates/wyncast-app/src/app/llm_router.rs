@@ -0,0 +1,773 @@
+// LLM routing: which nominations and pauses start, skip or cancel the
+// streaming analysis and planning requests, and the prompts they are
+// built from.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{debug, info, warn};
+
+use wyncast_baseball::draft::nomination_order;
+use wyncast_baseball::draft::roster;
+use wyncast_baseball::draft::snake;
+use wyncast_baseball::draft::state::ActiveNomination;
+use wyncast_baseball::llm::prompt::{self, BudgetContext};
+use wyncast_baseball::llm::style;
+use wyncast_baseball::valuation::identity::{self, PlayerRef};
+use wyncast_baseball::valuation::standings::{self, StandingsShift, TeamProjection};
+use wyncast_baseball::valuation::zscore::PlayerValuation;
+
+use crate::protocol::NominationInfo;
+use wyncast_core::nomination::NominationVerdict;
+use wyncast_core::stats::ProjectionData;
+use wyncast_baseball::valuation::analysis::{
+    apply_eligible_slots, compute_instant_analysis, InstantAnalysis,
+};
+
+use super::prefire::{self, PlanBasis};
+use super::{AnalysisPlayer, AppState};
+
+/// How long before the auction clock runs out a streaming analysis is
+/// finalized, so the verdict is on screen while there is time to bid.
+const CLOCK_MARGIN: Duration = Duration::from_secs(2);
+
+impl AppState {
+    /// How buying `player` would move me in the projected category
+    /// standings; empty without projections or before my team is known.
+    fn standings_impact(&self, player: &PlayerValuation) -> Vec<StandingsShift> {
+        let (Some(projections), Some(me)) = (&self.all_projections, self.draft_state.my_team_idx) else {
+            return Vec::new();
+        };
+        let teams: Vec<TeamProjection> = self
+            .draft_state
+            .teams
+            .iter()
+            .map(|team| TeamProjection::from_roster(&team.roster, projections))
+            .collect();
+        standings::standings_impact(player, me, &teams, &self.available_players, &self.stat_registry)
+    }
+
+    /// Handle a new or changed nomination.
+    ///
+    /// Computes instant analysis and triggers LLM analysis (stub for now).
+    pub fn handle_nomination(
+        &mut self,
+        nomination: &ActiveNomination,
+    ) -> Option<InstantAnalysis> {
+        // A snake draft has no auction to bid in: no bid verdicts or advice.
+        if self.config.league.is_snake() {
+            debug!("Ignoring a nomination in a snake draft: {}", nomination.player_name);
+            return None;
+        }
+        let my_team = match self.draft_state.my_team() {
+            Some(t) => t,
+            None => {
+                warn!("handle_nomination called before teams registered, skipping");
+                return None;
+            }
+        };
+
+        // Find the nominated player in our available pool
+        let player = identity::find_player(&self.available_players, &nomination.player_name, &nomination.position, None)
+            .best()
+            .map(|i| &self.available_players[i]);
+
+        // Projections for my rostered players, for marginal category gains.
+        let my_projections: Vec<ProjectionData> = match self.all_projections {
+            Some(ref projections) => my_team
+                .roster
+                .slots
+                .iter()
+                .filter_map(|slot| slot.player.as_ref())
+                .filter_map(|rp| projections.projection_for(&rp.name, !rp.position.is_hitter()))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        // Live ESPN eligibility beats the projection file's positions.
+        let analysis = player.map(|p| {
+            let p = apply_eligible_slots(p, &nomination.eligible_slots);
+            let mut analysis = compute_instant_analysis(
+                &p,
+                &my_team.roster,
+                my_team.budget_remaining,
+                &my_projections,
+                &self.available_players,
+                &self.scarcity,
+                &self.inflation,
+                &self.category_needs,
+                &self.stat_registry,
+            );
+            analysis.standings = self.standings_impact(&p);
+            analysis
+        });
+
+        // Keep the latest verdict to grade once the player is sold.
+        self.nomination_verdict = analysis.as_ref().map(|a| NominationVerdict {
+            player_name: nomination.player_name.clone(),
+            verdict: a.verdict.label().to_string(),
+            max_bid: a.bid_ceiling,
+        });
+
+        // Update DraftState nomination
+        self.draft_state.current_nomination = Some(nomination.clone());
+        if self
+            .bid_intent
+            .as_ref()
+            .is_some_and(|i| i.player_name != nomination.player_name)
+        {
+            self.bid_intent = None;
+        }
+
+        // Trigger LLM nomination analysis (sets llm_mode, clears text, spawns task)
+        if self.llm_paused {
+            info!(
+                "LLM paused while disconnected, skipping analysis of {}",
+                nomination.player_name
+            );
+        } else {
+            self.trigger_nomination_analysis(nomination, analysis.as_ref());
+        }
+
+        analysis
+    }
+
+    /// Handle nomination cleared (pick completed for the nominated player).
+    ///
+    /// Returns `Some(plan_request_id)` if a nomination planning task was started,
+    /// so callers can send `UiUpdate::PlanStarted` to clear stale plan text in the TUI.
+    pub fn handle_nomination_cleared(&mut self) -> Option<u64> {
+        self.draft_state.current_nomination = None;
+        self.bid_intent = None;
+        if let Some(id) = self.analysis_request_id.take() {
+            self.llm_requests.cancel(id);
+        }
+        self.analysis_player = None;
+
+        // Auto-trigger nomination planning between picks so the plan panel
+        // is populated before the user needs to nominate. Only fire when the
+        // config flag is set, we already know which team is ours, the
+        // extension is connected, and the prefire policy finds the last plan
+        // stale.
+        if self.config.strategy.llm.prefire_planning
+            && !self.llm_paused
+            && self.draft_state.my_team().is_some()
+        {
+            match self.prefire_reason() {
+                Some(reason) => {
+                    info!("Auto-triggering nomination planning: {}", reason);
+                    return self.trigger_nomination_planning();
+                }
+                None => debug!("Skipping prefire planning: nothing material changed since the last plan"),
+            }
+        }
+        None
+    }
+
+    /// Plan my next pick as a snake draft's picks come in, which has no
+    /// nominations to plan between. Fires under the same conditions and
+    /// `[llm.prefire]` policy as planning between auction nominations.
+    ///
+    /// Returns `Some(plan_request_id)` if planning was started.
+    pub fn handle_snake_picks(&mut self) -> Option<u64> {
+        if !self.config.strategy.llm.prefire_planning || self.llm_paused || self.draft_state.my_team().is_none() {
+            return None;
+        }
+        match self.prefire_reason() {
+            Some(reason) => {
+                info!("Auto-triggering snake pick planning: {}", reason);
+                self.trigger_nomination_planning()
+            }
+            None => {
+                debug!("Skipping snake pick planning: nothing material changed since the last plan");
+                None
+            }
+        }
+    }
+
+    /// Why the `[llm.prefire]` policy would prefire a plan now, if it would.
+    fn prefire_reason(&self) -> Option<String> {
+        let config = &self.config.strategy.llm.prefire;
+        let now = PlanBasis::capture(&self.draft_state, &self.available_players, config.top_n)?;
+        let my_turn_in = if self.config.league.is_snake() {
+            snake::snake_turn(&self.draft_state).and_then(|t| t.my_turn_in())
+        } else {
+            nomination_order::nomination_turn(&self.draft_state).and_then(|t| t.my_turn_in)
+        };
+        prefire::reason(config, self.plan_basis.as_ref(), &now, my_turn_in)
+    }
+
+    /// Cancel all active LLM tasks.
+    pub fn cancel_llm_tasks(&mut self) {
+        if let Some(id) = self.analysis_request_id.take() {
+            self.llm_requests.cancel(id);
+        }
+        if let Some(id) = self.plan_request_id.take() {
+            self.llm_requests.cancel(id);
+        }
+        self.analysis_player = None;
+        info!("Cancelled LLM tasks");
+    }
+
+    /// Suspend automatic LLM requests after the extension disconnects.
+    ///
+    /// Returns `true` if this paused them, so the caller tells the TUI once.
+    pub fn pause_llm(&mut self) -> bool {
+        !std::mem::replace(&mut self.llm_paused, true)
+    }
+
+    /// Trigger LLM nomination analysis for a nominated player.
+    ///
+    /// Cancels any in-flight analysis task, builds the analysis prompt from
+    /// current state, and spawns a streaming task via the request manager.
+    pub fn trigger_nomination_analysis(&mut self, nomination: &ActiveNomination, analysis: Option<&InstantAnalysis>) {
+        // Secondary guard: if already analyzing this exact player, skip to avoid
+        // canceling and restarting the active LLM task. This is a backstop for
+        // cases where preserve_llm in handle_full_state_sync doesn't fully prevent
+        // nomination_changed from firing (e.g., when saved_nomination is None).
+        if let Some(ref ap) = self.analysis_player {
+            let same = if !ap.player_id.is_empty() && !nomination.player_id.is_empty() {
+                ap.player_id == nomination.player_id
+            } else {
+                ap.player_name == nomination.player_name
+            };
+            if same {
+                info!(
+                    "LLM already analyzing {} — preserving active task (FullStateSync guard)",
+                    nomination.player_name
+                );
+                return;
+            }
+        }
+
+        // Cancel only previous analysis
+        if let Some(id) = self.analysis_request_id.take() {
+            self.llm_requests.cancel(id);
+        }
+        self.analysis_player = None;
+
+        let my_team = match self.draft_state.my_team() {
+            Some(t) => t,
+            None => {
+                warn!("trigger_nomination_analysis called before teams registered, skipping");
+                return;
+            }
+        };
+
+        // Extract budget info from my_team before the borrow ends
+        let my_team_budget = my_team.budget_remaining;
+        let my_roster = my_team.roster.clone();
+
+        // Find the nominated player in our pool
+        let player = identity::find_player(&self.available_players, &nomination.player_name, &nomination.position, None)
+            .best()
+            .map(|i| &self.available_players[i]);
+
+        let player = match player {
+            Some(p) => p.clone(),
+            None => {
+                info!(
+                    "Player {} not found in available pool, skipping LLM analysis",
+                    nomination.player_name
+                );
+                return;
+            }
+        };
+
+        // Track which player is being analyzed
+        self.analysis_player = Some(AnalysisPlayer {
+            player_name: nomination.player_name.clone(),
+            player_id: nomination.player_id.clone(),
+        });
+
+        let nom_info = NominationInfo {
+            player_name: nomination.player_name.clone(),
+            position: nomination.position.clone(),
+            nominated_by: nomination.nominated_by.clone(),
+            current_bid: nomination.current_bid,
+            current_bidder: nomination.current_bidder.clone(),
+            time_remaining: nomination.time_remaining,
+            eligible_slots: nomination.eligible_slots.clone(),
+        };
+
+        // Build budget context for the LLM
+        let empty_slots = my_roster.empty_slots();
+        let max_safe_bid =
+            my_team_budget.saturating_sub(roster::reserve(empty_slots, self.config.league.min_bid));
+        let avg_per_slot = if empty_slots > 0 {
+            my_team_budget as f64 / empty_slots as f64
+        } else {
+            0.0
+        };
+
+        let (engine_bid_floor, engine_bid_ceiling, engine_verdict) = match analysis {
+            Some(a) => {
+                let verdict = match a.roster_block {
+                    Some(block) => format!("{} ({})", a.verdict.label(), block.label()),
+                    None => a.verdict.label().to_string(),
+                };
+                (a.bid_floor, a.bid_ceiling, verdict)
+            }
+            None => {
+                // Fallback: compute inline
+                let adjusted = self.inflation.adjust(player.dollar_value);
+                let floor = self.inflation.legal_bid(adjusted * 0.70);
+                let ceiling = self.inflation.legal_bid(adjusted);
+                (floor, ceiling, "UNKNOWN".to_string())
+            }
+        };
+
+        let budget = BudgetContext {
+            budget_remaining: my_team_budget,
+            empty_slots,
+            max_safe_bid,
+            avg_per_slot,
+            pick_number: self.draft_state.pick_count + 1,
+            total_picks: self.draft_state.total_picks,
+            engine_bid_floor,
+            engine_bid_ceiling,
+            engine_verdict,
+        };
+
+        let system = prompt::system_prompt(&self.config.league, self.roster_config.as_ref(), self.config.strategy.strategy_overview.as_deref());
+        let user_content = prompt::build_nomination_analysis_prompt(
+            &player,
+            &nom_info,
+            &my_roster,
+            &self.category_needs,
+            &self.scarcity,
+            &self.available_players,
+            &self.draft_state,
+            &self.inflation,
+            &budget,
+            &self.stat_registry,
+            &self.config.strategy.llm,
+        );
+
+        // Keep to the auction clock: a short answer when it is short, and
+        // whatever has streamed when it is about to run out.
+        let mut max_tokens = self.config.strategy.llm.analysis_max_tokens;
+        if style::wants_short_answer(nomination.time_remaining) {
+            max_tokens = max_tokens.min(style::SHORT_ANSWER_MAX_TOKENS);
+        }
+        let deadline = nomination
+            .time_remaining
+            .map(|secs| Duration::from_secs(secs.into()).saturating_sub(CLOCK_MARGIN));
+        let client = Arc::clone(&self.llm_client);
+        let tx = self.llm_tx.clone();
+
+        let id = self.llm_requests.start_with_deadline(client, system, user_content, max_tokens, tx, deadline);
+        self.analysis_request_id = Some(id);
+        info!(
+            "Triggered LLM nomination analysis for {} (bid: ${}, request_id: {})",
+            nomination.player_name, nomination.current_bid, id
+        );
+    }
+
+    /// Trigger LLM nomination planning (what to nominate next).
+    ///
+    /// Cancels any in-flight plan task, builds the planning prompt from
+    /// current state, and spawns a streaming task via the request manager.
+    ///
+    /// Returns `Some(request_id)` if a planning task was successfully started,
+    /// `None` if it was skipped (e.g. teams not yet registered). Callers that
+    /// receive `Some` should send `UiUpdate::PlanStarted` to clear stale plan
+    /// text in the TUI before the first token arrives.
+    pub fn trigger_nomination_planning(&mut self) -> Option<u64> {
+        // Cancel only previous plan
+        if let Some(id) = self.plan_request_id.take() {
+            self.llm_requests.cancel(id);
+        }
+
+        let my_team = match self.draft_state.my_team() {
+            Some(t) => t,
+            None => {
+                warn!("trigger_nomination_planning called before teams registered, skipping");
+                return None;
+            }
+        };
+
+        // Extract budget info from my_team before the borrow ends
+        let my_team_budget = my_team.budget_remaining;
+        let my_roster = my_team.roster.clone();
+
+        // Build budget context for the LLM
+        let empty_slots = my_roster.empty_slots();
+        let max_safe_bid =
+            my_team_budget.saturating_sub(roster::reserve(empty_slots, self.config.league.min_bid));
+        let avg_per_slot = if empty_slots > 0 {
+            my_team_budget as f64 / empty_slots as f64
+        } else {
+            0.0
+        };
+
+        let budget = BudgetContext {
+            budget_remaining: my_team_budget,
+            empty_slots,
+            max_safe_bid,
+            avg_per_slot,
+            pick_number: self.draft_state.pick_count + 1,
+            total_picks: self.draft_state.total_picks,
+            // Planning prompt doesn't have a specific player, so use zeros for engine fields
+            engine_bid_floor: 0,
+            engine_bid_ceiling: 0,
+            engine_verdict: String::new(),
+        };
+
+        let system = prompt::system_prompt(&self.config.league, self.roster_config.as_ref(), self.config.strategy.strategy_overview.as_deref());
+        // A snake draft plans my next pick from the board instead.
+        let user_content = if self.config.league.is_snake() {
+            prompt::build_snake_pick_prompt(
+                &my_roster,
+                &self.category_needs,
+                &self.scarcity,
+                &self.available_players,
+                &self.draft_state,
+                snake::snake_turn(&self.draft_state).as_ref(),
+                &self.stat_registry,
+            )
+        } else {
+            prompt::build_nomination_planning_prompt(
+                &my_roster,
+                &self.category_needs,
+                &self.scarcity,
+                &self.available_players,
+                &self.draft_state,
+                &self.inflation,
+                &budget,
+                &self.stat_registry,
+            )
+        };
+
+        let max_tokens = self.config.strategy.llm.planning_max_tokens;
+        let client = Arc::clone(&self.llm_client);
+        let tx = self.llm_tx.clone();
+
+        let id = self.llm_requests.start(client, system, user_content, max_tokens, tx);
+        self.plan_request_id = Some(id);
+        self.plan_basis = PlanBasis::capture(
+            &self.draft_state,
+            &self.available_players,
+            self.config.strategy.llm.prefire.top_n,
+        );
+        info!("Triggered LLM nomination planning (request_id: {})", id);
+        Some(id)
+    }
+
+    /// Ask the LLM, once, which of two available players to prefer.
+    ///
+    /// Cancels any earlier comparison still streaming. Returns the request
+    /// ID, or why the players could not be compared.
+    pub fn trigger_comparison(&mut self, a: &PlayerRef, b: &PlayerRef) -> Result<u64, String> {
+        if let Some(id) = self.compare_request_id.take() {
+            self.llm_requests.cancel(id);
+        }
+        let comparison = self.compare_players(a, b)?;
+
+        let system = prompt::system_prompt(&self.config.league, self.roster_config.as_ref(), self.config.strategy.strategy_overview.as_deref());
+        let user_content = prompt::build_comparison_prompt(
+            &comparison,
+            self.draft_state.my_team().map(|t| &t.roster),
+            &self.category_needs,
+            &self.stat_registry,
+        );
+
+        let max_tokens = self.config.strategy.llm.analysis_max_tokens;
+        let client = Arc::clone(&self.llm_client);
+        let tx = self.llm_tx.clone();
+
+        let id = self.llm_requests.start(client, system, user_content, max_tokens, tx);
+        self.compare_request_id = Some(id);
+        info!("Triggered LLM comparison of {} and {} (request_id: {})", a.name, b.name, id);
+        Ok(id)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::app::test_utils::*;
+    use wyncast_baseball::draft::state::ActiveNomination;
+
+    // -----------------------------------------------------------------------
+    // Tests: LLM trigger logic
+    // -----------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn nomination_triggers_llm_mode() {
+        let mut state = create_test_app_state();
+
+        let nomination = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 5,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+
+        let _analysis = state.handle_nomination(&nomination);
+
+        // analysis_player and analysis_request_id should be set
+        assert!(state.analysis_player.is_some());
+        assert!(state.analysis_request_id.is_some());
+        let ap = state.analysis_player.as_ref().unwrap();
+        assert_eq!(ap.player_name, "H_Star");
+        assert_eq!(ap.player_id, "espn_1");
+    }
+
+    #[tokio::test]
+    async fn nomination_returns_analysis_for_known_player() {
+        let mut state = create_test_app_state();
+
+        let nomination = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 5,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+
+        let analysis = state.handle_nomination(&nomination);
+
+        // Should return analysis since H_Star is in the available pool
+        assert!(analysis.is_some());
+        let analysis = analysis.unwrap();
+        assert_eq!(analysis.player_name, "H_Star");
+    }
+
+    #[tokio::test]
+    async fn nomination_returns_none_for_unknown_player() {
+        let mut state = create_test_app_state();
+
+        let nomination = ActiveNomination {
+            player_name: "Unknown Player".into(),
+            player_id: "espn_999".into(),
+            position: "OF".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 1,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+
+        let analysis = state.handle_nomination(&nomination);
+
+        // Should return None since the player is not in our pool
+        assert!(analysis.is_none());
+    }
+
+    #[tokio::test]
+    async fn verdict_is_recorded_with_the_pick_when_the_player_sells() {
+        let mut state = create_test_app_state();
+        let nomination = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 5,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+        let analysis = state.handle_nomination(&nomination).unwrap();
+        state.handle_nomination_cleared();
+
+        // A different player sold first keeps the verdict waiting.
+        state.process_new_picks(vec![test_pick("P_Ace", "2", 30)]);
+        assert!(state.nomination_verdict.is_some());
+        state.process_new_picks(vec![test_pick("H_Star", "2", 60)]);
+        assert!(state.nomination_verdict.is_none());
+
+        let saved = state.db.load_pick_verdicts(&state.draft_id).unwrap();
+        let verdict = &saved["H_Star"];
+        assert_eq!(verdict.verdict, analysis.verdict.label());
+        assert_eq!(verdict.max_bid, analysis.bid_ceiling);
+        assert!(!saved.contains_key("P_Ace"));
+        assert_eq!(state.pick_verdicts, saved);
+    }
+
+    // -----------------------------------------------------------------------
+    // Tests: LLM cancellation (new nomination cancels previous)
+    // -----------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn new_nomination_clears_previous_analysis() {
+        let mut state = create_test_app_state();
+
+        // First nomination
+        let nom1 = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 5,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+        state.handle_nomination(&nom1);
+
+        // Second nomination (should cancel first)
+        let nom2 = ActiveNomination {
+            player_name: "H_Good".into(),
+            player_id: "espn_2".into(),
+            position: "2B".into(),
+            nominated_by: "Team 1".into(),
+            current_bid: 3,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+        state.handle_nomination(&nom2);
+
+        // analysis_player should be updated to the new nomination
+        let ap = state.analysis_player.as_ref().expect("Expected analysis_player to be set");
+        assert_eq!(ap.player_name, "H_Good");
+    }
+
+    #[tokio::test]
+    async fn nomination_cleared_resets_state() {
+        let mut state = create_test_app_state();
+
+        // Set up a nomination
+        let nom = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 5,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+        state.handle_nomination(&nom);
+
+        // Clear the nomination
+        let plan_id = state.handle_nomination_cleared();
+
+        assert!(state.draft_state.current_nomination.is_none());
+        assert!(state.analysis_player.is_none());
+        assert!(state.analysis_request_id.is_none());
+        // With prefire_planning=true and teams registered, planning should
+        // auto-trigger so the plan panel is populated between nominations.
+        assert!(
+            state.plan_request_id.is_some(),
+            "expected plan_request_id to be set after clearing (prefire_planning=true)"
+        );
+        assert!(plan_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn paused_llm_skips_automatic_analysis_and_planning() {
+        let mut state = create_test_app_state();
+        assert!(state.pause_llm());
+
+        let nom = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 5,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+        let analysis = state.handle_nomination(&nom);
+
+        // The instant analysis and nomination still go through; only the
+        // LLM calls wait for the extension to come back.
+        assert!(analysis.is_some());
+        assert!(state.draft_state.current_nomination.is_some());
+        assert!(state.analysis_request_id.is_none());
+
+        assert!(state.handle_nomination_cleared().is_none());
+        assert!(state.plan_request_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn snake_picks_plan_my_next_pick() {
+        let mut state = create_test_app_state();
+        state.config.league.draft_type = wyncast_core::config::DraftType::Snake;
+
+        let nom = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 5,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+        assert!(state.handle_nomination(&nom).is_none(), "no bid advice in a snake draft");
+        assert!(state.analysis_request_id.is_none());
+
+        assert!(state.handle_snake_picks().is_some());
+        assert!(state.plan_request_id.is_some());
+
+        assert!(state.pause_llm());
+        assert!(state.handle_snake_picks().is_none());
+    }
+
+    #[tokio::test]
+    async fn nomination_cleared_skips_planning_when_prefire_disabled() {
+        let mut state = create_test_app_state();
+        state.config.strategy.llm.prefire_planning = false;
+
+        let nom = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 5,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+        state.handle_nomination(&nom);
+
+        state.handle_nomination_cleared();
+
+        assert!(state.draft_state.current_nomination.is_none());
+        assert!(state.plan_request_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn prefire_planning_waits_for_a_material_change() {
+        let mut state = create_test_app_state();
+        // With two teams my turn is never far off, so leave the order out
+        // until the end.
+        let order = std::mem::take(&mut state.draft_state.nomination_order);
+        assert!(state.handle_nomination_cleared().is_some(), "no plan yet");
+        assert!(state.handle_nomination_cleared().is_none(), "nothing changed");
+
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45)]);
+        assert!(state.handle_nomination_cleared().is_some(), "my roster changed");
+        state.process_new_picks(vec![test_pick("P_Ace", "2", 30)]);
+        assert!(state.handle_nomination_cleared().is_none(), "one player off the top");
+
+        state.draft_state.nomination_order = order;
+        assert!(state.handle_nomination_cleared().is_some(), "my turn is near");
+
+        state.config.strategy.llm.prefire.mode = wyncast_core::config::PrefireMode::Always;
+        assert!(state.handle_nomination_cleared().is_some());
+    }
+
+    #[tokio::test]
+    async fn nomination_cleared_skips_planning_when_no_teams() {
+        let mut state = create_test_app_state_no_teams();
+
+        state.handle_nomination_cleared();
+
+        assert!(state.plan_request_id.is_none());
+    }
+}
@@ -0,0 +1,206 @@
+// Missed picks: sales inferred when their pick never shows up.
+//
+// ESPN's pick list is virtualized, so a pick can scroll past before the
+// extension scrapes it: the nomination clears, but no pick follows, and the
+// budgets stay wrong while the player stays "available". When a nomination
+// with a bid on it ends and no pick of the player arrives within
+// `MISSED_PICK_UPDATES` state updates, the sale is recorded as a provisional
+// pick at the last bid, to the last bidder. If the real pick turns up later,
+// it takes the provisional one's place.
+
+use tracing::{info, warn};
+
+use wyncast_baseball::draft::pick::DraftPick;
+use wyncast_baseball::draft::state::ActiveNomination;
+
+use super::AppState;
+
+/// State updates to wait for an ended nomination's pick before inferring it.
+pub const MISSED_PICK_UPDATES: usize = 5;
+
+/// A nomination that ended without its pick, yet.
+#[derive(Debug, Clone)]
+pub struct EndedNomination {
+    /// The nomination as last seen, with its final bid and bidder.
+    pub nomination: ActiveNomination,
+    /// State updates seen since it ended.
+    pub updates: usize,
+}
+
+impl AppState {
+    /// Wait for the pick of `nomination`, which just ended (cleared, or
+    /// replaced by the next player). Nominations nobody bid on, and ones
+    /// whose pick is already in, are not waited for.
+    pub fn note_nomination_ended(&mut self, nomination: ActiveNomination) {
+        let has_bidder = nomination.current_bidder.as_ref().is_some_and(|b| !b.is_empty());
+        if nomination.current_bid == 0 || !has_bidder || self.is_picked(&nomination.player_name) {
+            return;
+        }
+        self.ended_nominations.retain(|e| e.nomination.player_name != nomination.player_name);
+        self.ended_nominations.push(EndedNomination { nomination, updates: 0 });
+    }
+
+    /// Count a state update against each ended nomination, and record a
+    /// provisional pick for each one that has waited `MISSED_PICK_UPDATES`
+    /// updates for its pick. Returns the provisional picks recorded.
+    pub fn infer_missed_picks(&mut self) -> Vec<DraftPick> {
+        let mut due = Vec::new();
+        let ended = std::mem::take(&mut self.ended_nominations);
+        for mut e in ended {
+            if self.is_picked(&e.nomination.player_name) {
+                continue;
+            }
+            e.updates += 1;
+            if e.updates >= MISSED_PICK_UPDATES {
+                due.push(e.nomination);
+            } else {
+                self.ended_nominations.push(e);
+            }
+        }
+
+        let mut inferred = Vec::new();
+        for nomination in due {
+            let bidder = nomination.current_bidder.clone().unwrap_or_default();
+            let Some(team) = self
+                .draft_state
+                .teams
+                .iter()
+                .find(|t| t.team_name == bidder || t.team_id == bidder)
+            else {
+                warn!(
+                    "{}'s pick never arrived, and the last bidder {} is not a known team; not inferring it",
+                    nomination.player_name, bidder
+                );
+                continue;
+            };
+            let pick = DraftPick {
+                pick_number: 0,
+                team_id: team.team_id.clone(),
+                team_name: team.team_name.clone(),
+                player_name: nomination.player_name.clone(),
+                position: nomination.position.clone(),
+                price: nomination.current_bid,
+                espn_player_id: Some(nomination.player_id.clone()).filter(|id| !id.is_empty()),
+                eligible_slots: nomination.eligible_slots.clone(),
+                assigned_slot: None,
+            };
+            warn!(
+                "PROVISIONAL pick: {} to {} for ${}, inferred after {} updates without the pick",
+                pick.player_name, pick.team_name, pick.price, MISSED_PICK_UPDATES
+            );
+            self.provisional_picks.insert(pick.player_name.clone());
+            self.process_new_picks(vec![pick.clone()]);
+            inferred.push(pick);
+        }
+        inferred
+    }
+
+    /// Put each real pick among `new_picks` whose sale was inferred in place
+    /// of its provisional pick, rebuilding the draft from the corrected
+    /// picks. Returns the other picks, still to be recorded.
+    pub fn reconcile_provisional_picks(&mut self, new_picks: Vec<DraftPick>) -> Vec<DraftPick> {
+        if self.provisional_picks.is_empty() {
+            return new_picks;
+        }
+        let (real, rest): (Vec<DraftPick>, Vec<DraftPick>) = new_picks
+            .into_iter()
+            .partition(|p| self.provisional_picks.contains(&p.player_name));
+        if real.is_empty() {
+            return rest;
+        }
+
+        let mut picks = self.draft_state.picks.clone();
+        for pick in real {
+            self.provisional_picks.remove(&pick.player_name);
+            match picks.iter_mut().find(|p| p.player_name == pick.player_name) {
+                Some(provisional) => {
+                    info!(
+                        "Pick of {} arrived: {} for ${} replaces the provisional {} for ${}",
+                        pick.player_name, pick.team_name, pick.price, provisional.team_name, provisional.price
+                    );
+                    *provisional = pick;
+                }
+                None => picks.push(pick),
+            }
+        }
+        self.rebuild_from_picks(picks, Vec::new());
+        rest
+    }
+
+    /// Whether a pick of `player_name` is recorded.
+    fn is_picked(&self, player_name: &str) -> bool {
+        self.draft_state.picks.iter().any(|p| p.player_name == player_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::test_utils::*;
+
+    fn sold(player_name: &str, bid: u32, bidder: &str) -> ActiveNomination {
+        ActiveNomination {
+            player_name: player_name.into(),
+            player_id: String::new(),
+            position: "1B".into(),
+            nominated_by: "Team 1".into(),
+            current_bid: bid,
+            current_bidder: Some(bidder.into()),
+            time_remaining: Some(0),
+            eligible_slots: vec![],
+        }
+    }
+
+    #[test]
+    fn a_missed_pick_is_inferred_after_enough_updates() {
+        let mut state = create_test_app_state();
+        state.note_nomination_ended(sold("H_Star", 40, "Team 2"));
+        state.note_nomination_ended(sold("Nobody Bid", 0, "Team 2"));
+        for _ in 1..MISSED_PICK_UPDATES {
+            assert!(state.infer_missed_picks().is_empty());
+        }
+
+        let inferred = state.infer_missed_picks();
+        assert_eq!(inferred.len(), 1);
+        assert_eq!((inferred[0].team_id.as_str(), inferred[0].price), ("2", 40));
+        assert!(state.provisional_picks.contains("H_Star"));
+        assert!(state.available_players.iter().all(|p| p.name != "H_Star"));
+        assert_eq!(state.draft_state.team("2").unwrap().budget_remaining, 220);
+        assert!(state.infer_missed_picks().is_empty(), "inferred once");
+    }
+
+    #[test]
+    fn a_pick_that_arrives_in_time_is_not_inferred() {
+        let mut state = create_test_app_state();
+        state.note_nomination_ended(sold("H_Star", 40, "Team 2"));
+        state.process_new_picks(vec![test_pick("H_Star", "2", 41)]);
+        for _ in 0..MISSED_PICK_UPDATES {
+            assert!(state.infer_missed_picks().is_empty());
+        }
+        assert!(state.ended_nominations.is_empty());
+    }
+
+    #[test]
+    fn the_real_pick_replaces_the_provisional_one() {
+        let mut state = create_test_app_state();
+        state.process_new_picks(vec![test_pick("H_Star", "1", 30)]);
+        state.note_nomination_ended(sold("H_Good", 20, "Team 2"));
+        for _ in 0..MISSED_PICK_UPDATES {
+            state.infer_missed_picks();
+        }
+        assert_eq!(state.draft_state.picks.len(), 2);
+
+        // The real sale went to Team 1 for a dollar more, alongside a new pick.
+        let rest = state.reconcile_provisional_picks(vec![
+            test_pick("H_Good", "1", 21),
+            test_pick("P_Ace", "2", 25),
+        ]);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].player_name, "P_Ace");
+        assert!(state.provisional_picks.is_empty());
+        let good = &state.draft_state.picks[1];
+        assert_eq!((good.player_name.as_str(), good.team_id.as_str(), good.price), ("H_Good", "1", 21));
+        assert_eq!(state.draft_state.team("1").unwrap().budget_spent, 51);
+        assert_eq!(state.draft_state.team("2").unwrap().budget_spent, 0);
+    }
+}
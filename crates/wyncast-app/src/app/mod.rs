@@ -1,59 +1,55 @@
-// Application state and orchestration logic.
+// Application orchestration.
 //
 // The central event loop that coordinates WebSocket events from the Firefox
-// extension, LLM streaming events, and user commands from the TUI. Maintains
-// the complete application state and pushes UI updates to the TUI render loop.
+// extension, LLM streaming events, and user commands from the TUI, and pushes
+// UI updates to the TUI render loop. The state it maintains lives in `state`;
+// the handlers for each event source, LLM request routing (`llm_router`) and
+// snapshot building (`snapshot`) are submodules that extend `AppState`.
 
+mod state;
+mod snapshot;
 mod ws_handler;
+mod llm_router;
 mod llm_handler;
 mod command_handler;
 mod onboarding_handler;
 mod llm_request_manager;
+mod time_machine;
+mod missed_picks;
+mod prefire;
+mod resume;
+mod bulk_recalc;
+mod ui_health;
+pub mod undo;
+pub mod available_export;
+#[cfg(feature = "discord-bot")]
+pub mod discord_bot;
+pub mod doctor;
+pub mod keeper_report;
+pub mod notify;
+pub mod projection_audit;
+pub mod protocol_schema;
+pub mod query;
+pub mod session_fixture;
+pub mod stats_link;
+pub mod support_bundle;
+pub mod values_report;
+#[cfg(test)]
+mod test_utils;
 
 pub use llm_request_manager::LlmRequestManager;
+pub use state::{AnalysisPlayer, AppState};
 
-use std::sync::atomic::{AtomicI8, AtomicU64};
-use std::sync::Arc;
 use std::time::Duration;
 
 use tokio::sync::mpsc;
 use tokio::time::Instant;
 use tracing::{info, warn};
 
-use wyncast_core::config::Config;
-use wyncast_core::db::Database;
-use wyncast_baseball::draft::pick::{playing_positions_from_slots, Position};
-use wyncast_baseball::draft::state::{
-    ActiveNomination, DraftState, NominationPayload, PickPayload,
-    StateUpdatePayload, TeamBudgetPayload,
-};
-use wyncast_llm::client::LlmClient;
-use wyncast_baseball::llm::prompt::{self, BudgetContext};
-
-use crate::onboarding::{OnboardingManager, OnboardingProgress, RealFileSystem};
-use crate::protocol::{
-    AppMode, AppSnapshot, ConnectionStatus, LlmEvent, NominationInfo,
-    TabId, TeamSnapshot, UiUpdate, UserCommand,
-};
-use wyncast_core::stats::{CategoryValues, StatRegistry};
-use wyncast_baseball::valuation::analysis::{compute_instant_analysis, InstantAnalysis};
-use wyncast_baseball::valuation::auction::InflationTracker;
-use wyncast_baseball::valuation::projections::AllProjections;
-use wyncast_baseball::valuation::scarcity::{compute_scarcity, ScarcityEntry};
-use wyncast_baseball::valuation::zscore::PlayerValuation;
+use wyncast_core::crash;
 use wyncast_core::ws_server::WsEvent;
 
-// ---------------------------------------------------------------------------
-// Supporting types
-// ---------------------------------------------------------------------------
-
-/// Tracks which player is currently being analyzed by the LLM.
-/// Used by the preserve_llm guard in ws_handler and duplicate-check in trigger_nomination_analysis.
-#[derive(Debug, Clone, PartialEq)]
-pub struct AnalysisPlayer {
-    pub player_name: String,
-    pub player_id: String,
-}
+use crate::protocol::{ConnectionStatus, LlmEvent, UiUpdate, UserCommand, WsServerStatus};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -74,775 +70,20 @@ const CONNECTION_TEST_FAILED: i8 = 0;
 /// Connection test was run and succeeded.
 const CONNECTION_TEST_PASSED: i8 = 1;
 
-// ---------------------------------------------------------------------------
-// AppState
-// ---------------------------------------------------------------------------
-
-/// The complete application state.
-pub struct AppState {
-    /// Current UI mode (Onboarding, Draft, or Settings).
-    pub app_mode: AppMode,
-    pub config: Config,
-    pub draft_state: DraftState,
-    pub available_players: Vec<PlayerValuation>,
-    pub all_projections: Option<AllProjections>,
-    pub inflation: InflationTracker,
-    pub scarcity: Vec<ScarcityEntry>,
-    pub db: Database,
-    /// Unique identifier for the current draft session. Picks are scoped to
-    /// this ID so restarts don't replay picks from a different draft.
-    pub draft_id: String,
-    /// Draft identifier scraped from the ESPN page by the extension (e.g.
-    /// league ID from URL or team-name fingerprint). Used to detect when
-    /// the extension is reporting state from a different draft than the one
-    /// stored in `draft_id`. `None` until the first STATE_UPDATE arrives
-    /// with a non-null `draftId`.
-    pub espn_draft_id: Option<String>,
-    pub previous_extension_state: Option<StateUpdatePayload>,
-    pub llm_requests: LlmRequestManager,
-    pub analysis_request_id: Option<u64>,
-    pub plan_request_id: Option<u64>,
-    pub analysis_player: Option<AnalysisPlayer>,
-    pub connection_status: ConnectionStatus,
-    /// Timestamp of the last WebSocket message (or connection event) received.
-    /// `None` when not connected. Used to detect stale connections when the
-    /// browser tab is closed without a clean WebSocket close frame.
-    pub last_ws_message_time: Option<Instant>,
-    pub active_tab: TabId,
-    pub category_needs: CategoryValues,
-    pub stat_registry: StatRegistry,
-    /// LLM client for streaming Claude API calls. Wrapped in Arc for
-    /// sharing with spawned tasks.
-    pub llm_client: Arc<LlmClient>,
-    /// Sender for LLM events; spawned tasks use a clone of this sender
-    /// to stream tokens back to the main event loop.
-    pub llm_tx: mpsc::Sender<LlmEvent>,
-    /// Sender for outbound WebSocket messages to the extension.
-    /// Used to send `REQUEST_KEYFRAME` messages.
-    pub ws_outbound_tx: Option<mpsc::Sender<String>>,
-    /// Onboarding manager for loading/saving onboarding progress.
-    pub onboarding_manager: OnboardingManager<RealFileSystem>,
-    /// Cached onboarding progress (updated in-memory on each Set* action,
-    /// persisted to disk on GoNext/GoBack transitions).
-    pub onboarding_progress: OnboardingProgress,
-    /// Tracks the result of the last API connection test during onboarding.
-    /// Uses `CONNECTION_NEVER_TESTED`, `CONNECTION_TEST_FAILED`, `CONNECTION_TEST_PASSED`.
-    /// Shared with the spawned TestConnection task via Arc so it can update
-    /// the result without routing through the event loop.
-    pub connection_test_result: Arc<AtomicI8>,
-    /// Generation counter for connection tests. Incremented when a new test
-    /// is triggered. Spawned test tasks
-    /// capture the current generation and only write to `connection_test_result`
-    /// if the generation hasn't changed, preventing stale writes.
-    pub connection_test_generation: Arc<AtomicU64>,
-    /// Whether grid-sourced picks have already been persisted to DB this session.
-    /// Set to true after the first grid-based rebuild to avoid redundant writes
-    /// on subsequent 10-second FULL_STATE_SYNC keyframes.
-    pub grid_picks_persisted: bool,
-    /// Roster configuration inferred from ESPN or set from defaults.
-    /// `None` until roster is inferred from the ESPN draft board.
-    pub roster_config: Option<std::collections::HashMap<String, usize>>,
-    /// Latest matchup snapshot received from the extension.
-    pub matchup_snapshot: Option<wyncast_baseball::matchup::MatchupSnapshot>,
-}
-
-impl AppState {
-    /// Create a new AppState with the given components.
-    ///
-    /// The `draft_id` identifies the current draft session. On startup, callers
-    /// should load the stored draft_id from the database (or generate a new one).
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
-        config: Config,
-        draft_state: DraftState,
-        available_players: Vec<PlayerValuation>,
-        all_projections: Option<AllProjections>,
-        db: Database,
-        draft_id: String,
-        llm_client: LlmClient,
-        llm_tx: mpsc::Sender<LlmEvent>,
-        ws_outbound_tx: Option<mpsc::Sender<String>>,
-        app_mode: AppMode,
-        onboarding_manager: OnboardingManager<RealFileSystem>,
-        roster_config: Option<std::collections::HashMap<String, usize>>,
-    ) -> Self {
-        let scarcity = match &roster_config {
-            Some(rc) => compute_scarcity(&available_players, rc),
-            None => Vec::new(),
-        };
-        let inflation = InflationTracker::new();
-        let onboarding_progress = onboarding_manager.load_progress();
-        let stat_registry = StatRegistry::from_league_config(&config.league)
-            .expect("league config must produce a valid stat registry");
-        let category_needs = CategoryValues::uniform(stat_registry.len(), 0.5);
-
-        AppState {
-            app_mode,
-            config,
-            draft_state,
-            available_players,
-            all_projections,
-            inflation,
-            scarcity,
-            db,
-            draft_id,
-            espn_draft_id: None,
-            previous_extension_state: None,
-            llm_requests: LlmRequestManager::new(),
-            analysis_request_id: None,
-            plan_request_id: None,
-            analysis_player: None,
-            connection_status: ConnectionStatus::Disconnected,
-            last_ws_message_time: None,
-            active_tab: TabId::Analysis,
-            category_needs,
-            stat_registry,
-            llm_client: Arc::new(llm_client),
-            llm_tx,
-            ws_outbound_tx,
-            onboarding_manager,
-            onboarding_progress,
-            connection_test_result: Arc::new(AtomicI8::new(CONNECTION_NEVER_TESTED)),
-            connection_test_generation: Arc::new(AtomicU64::new(0)),
-            grid_picks_persisted: false,
-            roster_config,
-            matchup_snapshot: None,
-        }
-    }
-
-    /// Default roster configuration (used as fallback until ESPN provides the actual roster layout).
-    pub fn default_roster_config() -> std::collections::HashMap<String, usize> {
-        let mut roster = std::collections::HashMap::new();
-        roster.insert("C".to_string(), 1);
-        roster.insert("1B".to_string(), 1);
-        roster.insert("2B".to_string(), 1);
-        roster.insert("3B".to_string(), 1);
-        roster.insert("SS".to_string(), 1);
-        roster.insert("LF".to_string(), 1);
-        roster.insert("CF".to_string(), 1);
-        roster.insert("RF".to_string(), 1);
-        roster.insert("UTIL".to_string(), 1);
-        roster.insert("SP".to_string(), 5);
-        roster.insert("RP".to_string(), 6);
-        roster.insert("BE".to_string(), 6);
-        roster.insert("IL".to_string(), 5);
-        roster
-    }
-
-    /// Apply a roster configuration inferred from the ESPN draft board.
-    ///
-    /// Sets the roster_config and attempts to compute valuations. If projections
-    /// are not yet available (waiting for ESPN), only stores the config and
-    /// defers valuation until `apply_projections()` is called.
-    pub fn apply_roster_config(&mut self, roster: std::collections::HashMap<String, usize>) {
-        info!("Applying roster config: {:?}", roster);
-        self.roster_config = Some(roster);
-        self.try_compute_valuations();
-    }
-
-    /// Store projections and compute valuations if roster config is available.
-    ///
-    /// Called when ESPN projections arrive from the extension. If the roster
-    /// config has already been inferred from the draft board, valuations are
-    /// computed immediately. Otherwise, projections are stored and valuations
-    /// are deferred until `apply_roster_config()` is called.
-    pub fn apply_projections(&mut self, projections: AllProjections) {
-        info!(
-            "Applying projections: {} hitters, {} pitchers",
-            projections.hitters.len(),
-            projections.pitchers.len()
-        );
-        self.all_projections = Some(projections);
-        self.try_compute_valuations();
-    }
-
-    /// Compute initial valuations if both projections and roster config are available.
-    ///
-    /// After computing the full player pool, removes any players that have
-    /// already been drafted (present in `draft_state.picks`). This handles
-    /// the case where projections arrive after picks have been recorded
-    /// (e.g. backend restart mid-draft).
-    fn try_compute_valuations(&mut self) {
-        let (Some(projections), Some(roster)) = (&self.all_projections, &self.roster_config) else {
-            return;
-        };
-        self.available_players = wyncast_baseball::valuation::compute_initial(
-            projections,
-            &self.config,
-            roster,
-            &self.stat_registry,
-        )
-        .unwrap_or_default();
-
-        // Remove already-drafted players from the available pool
-        if !self.draft_state.picks.is_empty() {
-            let drafted_names: std::collections::HashSet<&str> = self
-                .draft_state
-                .picks
-                .iter()
-                .map(|p| p.player_name.as_str())
-                .collect();
-            self.available_players
-                .retain(|p| !drafted_names.contains(p.name.as_str()));
-            info!(
-                "Filtered {} drafted players from available pool ({} remaining)",
-                drafted_names.len(),
-                self.available_players.len()
-            );
-        }
-
-        self.scarcity = compute_scarcity(&self.available_players, roster);
-    }
-
-    /// Reconstruct the LLM client from the current config.
-    ///
-    /// Called after settings changes (API key, provider, model) so that
-    /// subsequent LLM calls use the updated configuration.
-    pub fn reload_llm_client(&mut self) {
-        self.llm_client = Arc::new(LlmClient::from_config(&self.config));
-        info!("Reloaded LLM client (provider={:?}, model={})",
-            self.config.strategy.llm.provider,
-            self.config.strategy.llm.model,
-        );
-    }
-
-    /// Process new picks from the extension state diff.
-    ///
-    /// For each new pick:
-    /// 1. Record in DraftState
-    /// 2. Persist to DB
-    /// 3. Remove from available player pool
-    /// 4. Update inflation and scarcity
-    pub fn process_new_picks(
-        &mut self,
-        new_picks: Vec<wyncast_baseball::draft::pick::DraftPick>,
-    ) {
-        if new_picks.is_empty() {
-            return;
-        }
-
-        for pick in &new_picks {
-            info!(
-                "Recording pick #{}: {} -> {} for ${}",
-                pick.pick_number, pick.player_name, pick.team_name, pick.price
-            );
-
-            // Record in DraftState (assigns canonical sequential pick_number)
-            let prev_count = self.draft_state.picks.len();
-            self.draft_state.record_pick(pick.clone());
-
-            // Only persist if record_pick actually added a new pick (wasn't deduped).
-            // Use the pick from draft_state.picks which has the corrected pick_number;
-            // the original `pick` from ESPN may have an unreliable pick_number (e.g.
-            // always 1) due to ESPN's virtualized pick list.
-            if self.draft_state.picks.len() > prev_count {
-                let canonical_pick = self.draft_state.picks.last().unwrap();
-                if let Err(e) = self.db.record_pick(canonical_pick, &self.draft_id) {
-                    warn!("Failed to persist pick to DB: {}", e);
-                }
-            }
-
-            // Remove from available player pool.
-            // Primary match is by name; fall back to ESPN player ID when available
-            // to guard against minor name-format mismatches between extension and
-            // projection data (e.g. "J.D. Martinez" vs "JD Martinez").
-            let player_name = &pick.player_name;
-            let espn_id = pick.espn_player_id.as_deref();
-            self.available_players.retain(|p| {
-                if p.name == *player_name {
-                    return false;
-                }
-                // If the pick carries an ESPN ID, check for an ID-based match.
-                // Future player records might carry an ID field. For now this is
-                // a defensive no-op placeholder that keeps the structure ready
-                // for ID-based matching.
-                if let Some(_id) = espn_id {
-                    // TODO: match against player.espn_id once that field exists
-                }
-                true
-            });
-        }
-
-        // Update inflation
-        self.inflation.update(
-            &self.available_players,
-            &self.draft_state,
-            &self.config.league,
-        );
-
-        // Update scarcity
-        if let Some(ref roster) = self.roster_config {
-            self.scarcity = compute_scarcity(&self.available_players, roster);
-        }
-
-        // Update category needs (for now, uniform - real implementation in TUI tasks)
-        // Category needs would be recomputed based on the user's roster composition.
-    }
-
-    /// Build an `AppSnapshot` from the current application state.
-    ///
-    /// This captures all recalculated data (available players, scarcity,
-    /// budget, inflation, draft log, roster, team summaries) into a single
-    /// snapshot that the TUI can apply in one shot.
-    pub fn build_snapshot(&self) -> AppSnapshot {
-        let my_team = self.draft_state.my_team();
-
-        let (my_roster, budget_spent, budget_remaining, max_bid, avg_per_slot) =
-            if let Some(team) = my_team {
-                let roster = team.roster.slots.clone();
-                let empty_slots = roster.iter().filter(|s| s.player.is_none()).count();
-                let avg = if empty_slots > 0 {
-                    team.budget_remaining as f64 / empty_slots as f64
-                } else {
-                    0.0
-                };
-                let max = if empty_slots > 1 {
-                    team.budget_remaining.saturating_sub((empty_slots as u32) - 1)
-                } else {
-                    team.budget_remaining
-                };
-                (roster, team.budget_spent, team.budget_remaining, max, avg)
-            } else {
-                // Teams not yet registered; return defaults
-                (Vec::new(), 0, self.config.league.salary_cap, self.config.league.salary_cap, 0.0)
-            };
-
-        // Compute hitter/pitcher budget split
-        let salary_cap = self.config.league.salary_cap;
-        let hitting_frac = self.config.strategy.hitting_budget_fraction;
-        let hitting_target = (salary_cap as f64 * hitting_frac).round() as u32;
-        let pitching_target = salary_cap.saturating_sub(hitting_target);
-
-        let (hitting_spent, pitching_spent) = if let Some(team) = my_team {
-            let my_team_id = &team.team_id;
-            let mut h_spent: u32 = 0;
-            let mut p_spent: u32 = 0;
-            for pick in &self.draft_state.picks {
-                if pick.team_id != *my_team_id {
-                    continue;
-                }
-                let is_hitter = match Position::from_str_pos(&pick.position) {
-                    Some(pos) if !matches!(pos, Position::Bench | Position::InjuredList) => {
-                        pos.is_hitter()
-                    }
-                    Some(_) => {
-                        // Bench or IL: fall back to eligible_slots
-                        let playing = playing_positions_from_slots(&pick.eligible_slots);
-                        playing.iter().any(|p| p.is_hitter())
-                    }
-                    None => continue, // unparseable position, skip
-                };
-                if is_hitter {
-                    h_spent += pick.price;
-                } else {
-                    p_spent += pick.price;
-                }
-            }
-            (h_spent, p_spent)
-        } else {
-            (0, 0)
-        };
-
-        let team_snapshots = self
-            .draft_state
-            .teams
-            .iter()
-            .map(|t| {
-                let filled = t.roster.filled_count();
-                let total = t.roster.draftable_count();
-                TeamSnapshot {
-                    name: t.team_name.clone(),
-                    budget_remaining: t.budget_remaining,
-                    slots_filled: filled,
-                    total_slots: total,
-                }
-            })
-            .collect();
-
-        AppSnapshot {
-            app_mode: self.app_mode.clone(),
-            pick_count: self.draft_state.pick_count,
-            total_picks: self.draft_state.total_picks,
-            active_tab: None, // Don't override the user's active tab
-            available_players: self.available_players.clone(),
-            positional_scarcity: self.scarcity.clone(),
-            draft_log: self.draft_state.picks.clone(),
-            my_roster,
-            budget_spent,
-            budget_remaining,
-            salary_cap,
-            inflation_rate: self.inflation.inflation_rate,
-            max_bid,
-            avg_per_slot,
-            hitting_spent,
-            hitting_target,
-            pitching_spent,
-            pitching_target,
-            team_snapshots,
-            llm_configured: matches!(*self.llm_client, LlmClient::Active(_)),
-        }
-    }
-
-    /// Handle a new or changed nomination.
-    ///
-    /// Computes instant analysis and triggers LLM analysis (stub for now).
-    pub fn handle_nomination(
-        &mut self,
-        nomination: &ActiveNomination,
-    ) -> Option<InstantAnalysis> {
-        let my_team = match self.draft_state.my_team() {
-            Some(t) => t,
-            None => {
-                warn!("handle_nomination called before teams registered, skipping");
-                return None;
-            }
-        };
-
-        // Find the nominated player in our available pool
-        let player = self
-            .available_players
-            .iter()
-            .find(|p| p.name == nomination.player_name);
-
-        let analysis = player.map(|p| {
-            compute_instant_analysis(
-                p,
-                &my_team.roster,
-                &self.available_players,
-                &self.scarcity,
-                &self.inflation,
-                &self.category_needs,
-                &self.stat_registry,
-            )
-        });
-
-        // Update DraftState nomination
-        self.draft_state.current_nomination = Some(nomination.clone());
-
-        // Trigger LLM nomination analysis (sets llm_mode, clears text, spawns task)
-        self.trigger_nomination_analysis(nomination, analysis.as_ref());
-
-        analysis
-    }
-
-    /// Handle nomination cleared (pick completed for the nominated player).
-    ///
-    /// Returns `Some(plan_request_id)` if a nomination planning task was started,
-    /// so callers can send `UiUpdate::PlanStarted` to clear stale plan text in the TUI.
-    pub fn handle_nomination_cleared(&mut self) -> Option<u64> {
-        self.draft_state.current_nomination = None;
-        if let Some(id) = self.analysis_request_id.take() {
-            self.llm_requests.cancel(id);
-        }
-        self.analysis_player = None;
-
-        // Auto-trigger nomination planning between picks so the plan panel
-        // is populated before the user needs to nominate. Only fire when the
-        // config flag is set and we already know which team is ours.
-        if self.config.strategy.llm.prefire_planning && self.draft_state.my_team().is_some() {
-            info!("Auto-triggering nomination planning (prefire_planning=true)");
-            return self.trigger_nomination_planning();
-        }
-        None
-    }
-
-    /// Cancel all active LLM tasks.
-    pub fn cancel_llm_tasks(&mut self) {
-        if let Some(id) = self.analysis_request_id.take() {
-            self.llm_requests.cancel(id);
-        }
-        if let Some(id) = self.plan_request_id.take() {
-            self.llm_requests.cancel(id);
-        }
-        self.analysis_player = None;
-        info!("Cancelled LLM tasks");
-    }
-
-    /// Trigger LLM nomination analysis for a nominated player.
-    ///
-    /// Cancels any in-flight analysis task, builds the analysis prompt from
-    /// current state, and spawns a streaming task via the request manager.
-    pub fn trigger_nomination_analysis(&mut self, nomination: &ActiveNomination, analysis: Option<&InstantAnalysis>) {
-        // Secondary guard: if already analyzing this exact player, skip to avoid
-        // canceling and restarting the active LLM task. This is a backstop for
-        // cases where preserve_llm in handle_full_state_sync doesn't fully prevent
-        // nomination_changed from firing (e.g., when saved_nomination is None).
-        if let Some(ref ap) = self.analysis_player {
-            let same = if !ap.player_id.is_empty() && !nomination.player_id.is_empty() {
-                ap.player_id == nomination.player_id
-            } else {
-                ap.player_name == nomination.player_name
-            };
-            if same {
-                info!(
-                    "LLM already analyzing {} — preserving active task (FullStateSync guard)",
-                    nomination.player_name
-                );
-                return;
-            }
-        }
-
-        // Cancel only previous analysis
-        if let Some(id) = self.analysis_request_id.take() {
-            self.llm_requests.cancel(id);
-        }
-        self.analysis_player = None;
-
-        let my_team = match self.draft_state.my_team() {
-            Some(t) => t,
-            None => {
-                warn!("trigger_nomination_analysis called before teams registered, skipping");
-                return;
-            }
-        };
-
-        // Extract budget info from my_team before the borrow ends
-        let my_team_budget = my_team.budget_remaining;
-        let my_roster = my_team.roster.clone();
-
-        // Find the nominated player in our pool
-        let player = self
-            .available_players
-            .iter()
-            .find(|p| p.name == nomination.player_name);
-
-        let player = match player {
-            Some(p) => p.clone(),
-            None => {
-                info!(
-                    "Player {} not found in available pool, skipping LLM analysis",
-                    nomination.player_name
-                );
-                return;
-            }
-        };
-
-        // Track which player is being analyzed
-        self.analysis_player = Some(AnalysisPlayer {
-            player_name: nomination.player_name.clone(),
-            player_id: nomination.player_id.clone(),
-        });
-
-        let nom_info = NominationInfo {
-            player_name: nomination.player_name.clone(),
-            position: nomination.position.clone(),
-            nominated_by: nomination.nominated_by.clone(),
-            current_bid: nomination.current_bid,
-            current_bidder: nomination.current_bidder.clone(),
-            time_remaining: nomination.time_remaining,
-            eligible_slots: nomination.eligible_slots.clone(),
-        };
-
-        // Build budget context for the LLM
-        let empty_slots = my_roster.empty_slots();
-        let max_safe_bid = if empty_slots > 1 {
-            my_team_budget.saturating_sub((empty_slots as u32) - 1)
-        } else {
-            my_team_budget
-        };
-        let avg_per_slot = if empty_slots > 0 {
-            my_team_budget as f64 / empty_slots as f64
-        } else {
-            0.0
-        };
-
-        let (engine_bid_floor, engine_bid_ceiling, engine_verdict) = match analysis {
-            Some(a) => (a.bid_floor, a.bid_ceiling, a.verdict.label().to_string()),
-            None => {
-                // Fallback: compute inline
-                let adjusted = self.inflation.adjust(player.dollar_value);
-                let floor = (adjusted * 0.70).round().max(1.0) as u32;
-                let ceiling = adjusted.round().max(1.0) as u32;
-                (floor, ceiling, "UNKNOWN".to_string())
-            }
-        };
-
-        let budget = BudgetContext {
-            budget_remaining: my_team_budget,
-            empty_slots,
-            max_safe_bid,
-            avg_per_slot,
-            pick_number: self.draft_state.pick_count + 1,
-            total_picks: self.draft_state.total_picks,
-            engine_bid_floor,
-            engine_bid_ceiling,
-            engine_verdict,
-        };
-
-        let system = prompt::system_prompt(&self.config.league, self.roster_config.as_ref(), self.config.strategy.strategy_overview.as_deref());
-        let user_content = prompt::build_nomination_analysis_prompt(
-            &player,
-            &nom_info,
-            &my_roster,
-            &self.category_needs,
-            &self.scarcity,
-            &self.available_players,
-            &self.draft_state,
-            &self.inflation,
-            &budget,
-            &self.stat_registry,
-        );
-
-        let max_tokens = self.config.strategy.llm.analysis_max_tokens;
-        let client = Arc::clone(&self.llm_client);
-        let tx = self.llm_tx.clone();
-
-        let id = self.llm_requests.start(client, system, user_content, max_tokens, tx);
-        self.analysis_request_id = Some(id);
-        info!(
-            "Triggered LLM nomination analysis for {} (bid: ${}, request_id: {})",
-            nomination.player_name, nomination.current_bid, id
-        );
-    }
-
-    /// Trigger LLM nomination planning (what to nominate next).
-    ///
-    /// Cancels any in-flight plan task, builds the planning prompt from
-    /// current state, and spawns a streaming task via the request manager.
-    ///
-    /// Returns `Some(request_id)` if a planning task was successfully started,
-    /// `None` if it was skipped (e.g. teams not yet registered). Callers that
-    /// receive `Some` should send `UiUpdate::PlanStarted` to clear stale plan
-    /// text in the TUI before the first token arrives.
-    pub fn trigger_nomination_planning(&mut self) -> Option<u64> {
-        // Cancel only previous plan
-        if let Some(id) = self.plan_request_id.take() {
-            self.llm_requests.cancel(id);
-        }
-
-        let my_team = match self.draft_state.my_team() {
-            Some(t) => t,
-            None => {
-                warn!("trigger_nomination_planning called before teams registered, skipping");
-                return None;
-            }
-        };
-
-        // Extract budget info from my_team before the borrow ends
-        let my_team_budget = my_team.budget_remaining;
-        let my_roster = my_team.roster.clone();
-
-        // Build budget context for the LLM
-        let empty_slots = my_roster.empty_slots();
-        let max_safe_bid = if empty_slots > 1 {
-            my_team_budget.saturating_sub((empty_slots as u32) - 1)
-        } else {
-            my_team_budget
-        };
-        let avg_per_slot = if empty_slots > 0 {
-            my_team_budget as f64 / empty_slots as f64
-        } else {
-            0.0
-        };
-
-        let budget = BudgetContext {
-            budget_remaining: my_team_budget,
-            empty_slots,
-            max_safe_bid,
-            avg_per_slot,
-            pick_number: self.draft_state.pick_count + 1,
-            total_picks: self.draft_state.total_picks,
-            // Planning prompt doesn't have a specific player, so use zeros for engine fields
-            engine_bid_floor: 0,
-            engine_bid_ceiling: 0,
-            engine_verdict: String::new(),
-        };
-
-        let system = prompt::system_prompt(&self.config.league, self.roster_config.as_ref(), self.config.strategy.strategy_overview.as_deref());
-        let user_content = prompt::build_nomination_planning_prompt(
-            &my_roster,
-            &self.category_needs,
-            &self.scarcity,
-            &self.available_players,
-            &self.draft_state,
-            &self.inflation,
-            &budget,
-            &self.stat_registry,
-        );
-
-        let max_tokens = self.config.strategy.llm.planning_max_tokens;
-        let client = Arc::clone(&self.llm_client);
-        let tx = self.llm_tx.clone();
-
-        let id = self.llm_requests.start(client, system, user_content, max_tokens, tx);
-        self.plan_request_id = Some(id);
-        info!("Triggered LLM nomination planning (request_id: {})", id);
-        Some(id)
-    }
-
-    /// Convert extension PickData format to our internal StateUpdatePayload format.
-    pub fn convert_extension_state(
-        payload: &crate::protocol::StateUpdatePayload,
-    ) -> StateUpdatePayload {
-        StateUpdatePayload {
-            picks: payload
-                .picks
-                .iter()
-                .map(|p| PickPayload {
-                    pick_number: p.pick_number,
-                    team_id: p.team_id.clone(),
-                    team_name: p.team_name.clone(),
-                    player_id: p.player_id.clone(),
-                    player_name: p.player_name.clone(),
-                    position: p.position.clone(),
-                    price: p.price,
-                    eligible_slots: p.eligible_slots.clone(),
-                    assigned_slot: p.assigned_slot,
-                })
-                .collect(),
-            current_nomination: payload.current_nomination.as_ref().and_then(|n| {
-                // Filter out premature nominations: during the pre-nomination
-                // phase the nominator is browsing players and the extension may
-                // (despite the JS-side guard) send a nomination with no bid, no
-                // bidder, and no nominator. A real nomination in the "offer"
-                // stage always has at least a current_bid > 0 or a non-empty
-                // nominated_by field (populated from the bid history).
-                let has_bid = n.current_bid > 0;
-                let has_nominator = !n.nominated_by.is_empty();
-                let has_bidder = n.current_bidder.as_ref().is_some_and(|b| !b.is_empty());
-                if !has_bid && !has_nominator && !has_bidder {
-                    warn!(
-                        "Filtering premature nomination for '{}': no bid, no nominator, no bidder",
-                        n.player_name
-                    );
-                    return None;
-                }
-                Some(NominationPayload {
-                    player_id: n.player_id.clone(),
-                    player_name: n.player_name.clone(),
-                    position: n.position.clone(),
-                    nominated_by: n.nominated_by.clone(),
-                    current_bid: n.current_bid,
-                    current_bidder: n.current_bidder.clone(),
-                    time_remaining: n.time_remaining,
-                    eligible_slots: n.eligible_slots.clone(),
-                })
-            }),
-            teams: payload
-                .teams
-                .iter()
-                .map(|t| TeamBudgetPayload {
-                    team_id: t.team_id.clone().unwrap_or_default(),
-                    team_name: t.team_name.clone(),
-                    budget: t.budget,
-                })
-                .collect(),
-            pick_count: payload.pick_count,
-            total_picks: payload.total_picks,
-        }
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Main event loop
 // ---------------------------------------------------------------------------
 
 /// Run the main application event loop.
 ///
-/// Listens on three channels using `tokio::select!`:
+/// Listens on four channels using `tokio::select!`:
 /// 1. WebSocket events from the extension
 /// 2. LLM streaming events
 /// 3. User commands from the TUI
+/// 4. Second-screen queries, when `state.query_rx` is set
+///
+/// Timers drive the heartbeat check and, when `[backup]` is configured,
+/// periodic database backups.
 ///
 /// Pushes UI updates through `ui_tx` for the TUI render loop.
 pub async fn run(
@@ -857,11 +98,35 @@ pub async fn run(
     // Send initial snapshot so the TUI has available players immediately,
     // before any WebSocket events arrive from the extension.
     let initial_snapshot = state.build_snapshot();
-    let _ = ui_tx.send(UiUpdate::StateSnapshot(Box::new(initial_snapshot))).await;
+    state.ui_health.send(&ui_tx, UiUpdate::StateSnapshot(Box::new(initial_snapshot))).await;
+    crash::set_draft_context(&state.draft_id, state.draft_state.picks.len());
+
+    // Surface a crash from the previous session (recorded by the panic hook)
+    // once, then mark it as seen so it isn't shown again on the next launch.
+    match state.db.latest_unacknowledged_crash() {
+        Ok(Some(report)) => {
+            warn!("{}", report.summary());
+            state.ui_health.send(&ui_tx, UiUpdate::CrashNotice(Box::new(report))).await;
+            if let Err(e) = state.db.acknowledge_crashes() {
+                warn!("Failed to acknowledge crash reports: {}", e);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load previous crash report: {}", e),
+    }
 
     // Track whether the LLM channel is still open. When it closes we replace
     // the recv future with a pending future so tokio::select! never spins on it.
     let mut llm_open = true;
+    // Likewise for the WebSocket channel, which closes early when the server
+    // could not bind a port. The app keeps running (offline) in that case.
+    let mut ws_open = true;
+    // Second-screen queries are optional; without a receiver the branch
+    // stays disabled.
+    let mut query_rx = state.query_rx.take();
+    // With `WYNCAST_RECORD_SESSION` set, the extension's messages are also
+    // appended to a replay log for `--make-fixture`.
+    let mut recorder = session_fixture::SessionRecorder::from_env();
 
     // Interval timer for heartbeat timeout checks. Fires every
     // HEARTBEAT_CHECK_INTERVAL; the handler compares Instant::now()
@@ -871,22 +136,41 @@ pub async fn run(
     // real check happens after one full interval.
     heartbeat_interval.tick().await;
 
+    // Database backups, when a backup directory is configured. The first
+    // backup is taken one full interval after startup.
+    let mut backup_interval = match state.config.backup.dir {
+        Some(_) => {
+            let period = Duration::from_secs(state.config.backup.interval_minutes * 60);
+            let mut interval = tokio::time::interval(period);
+            interval.tick().await;
+            Some(interval)
+        }
+        None => None,
+    };
+
     loop {
+        // A snoozed recalculation ends once the picks stop coming.
+        let recalc_deadline = state.recalc_deadline();
         tokio::select! {
             // --- WebSocket events ---
-            ws_event = ws_rx.recv() => {
+            ws_event = ws_rx.recv(), if ws_open => {
                 match ws_event {
                     Some(WsEvent::Connected { addr }) => {
                         info!("Extension connected from {}", addr);
                         state.connection_status = ConnectionStatus::Connected;
                         state.last_ws_message_time = Some(Instant::now());
-                        let _ = ui_tx.send(UiUpdate::ConnectionStatus(ConnectionStatus::Connected)).await;
+                        state.ui_health.send(&ui_tx, UiUpdate::ConnectionStatus(ConnectionStatus::Connected)).await;
                     }
                     Some(WsEvent::Disconnected) => {
                         info!("Extension disconnected");
                         state.connection_status = ConnectionStatus::Disconnected;
                         state.last_ws_message_time = None;
-                        let _ = ui_tx.send(UiUpdate::ConnectionStatus(ConnectionStatus::Disconnected)).await;
+                        state.heartbeat_ack = None;
+                        state.extension_health = None;
+                        state.ui_health.send(&ui_tx, UiUpdate::ConnectionStatus(ConnectionStatus::Disconnected)).await;
+                        if state.pause_llm() {
+                            state.ui_health.send(&ui_tx, UiUpdate::LlmPaused(true)).await;
+                        }
                     }
                     Some(WsEvent::Message(json_str)) => {
                         // If we had marked the connection as stale-disconnected
@@ -902,7 +186,7 @@ pub async fn run(
                         {
                             info!("Extension connection restored (received message after stale timeout)");
                             state.connection_status = ConnectionStatus::Connected;
-                            let _ = ui_tx.send(UiUpdate::ConnectionStatus(ConnectionStatus::Connected)).await;
+                            state.ui_health.send(&ui_tx, UiUpdate::ConnectionStatus(ConnectionStatus::Connected)).await;
                         }
                         // Only track message timestamps when we have an active
                         // connection (last_ws_message_time is Some from a prior
@@ -912,11 +196,22 @@ pub async fn run(
                         if state.last_ws_message_time.is_some() {
                             state.last_ws_message_time = Some(Instant::now());
                         }
+                        if let Some(recorder) = recorder.as_mut() {
+                            recorder.record(&json_str);
+                        }
                         ws_handler::handle_ws_message(&mut state, &json_str, &ui_tx).await;
                     }
+                    Some(WsEvent::Listening { port, requested_port }) => {
+                        let status = WsServerStatus::Listening { port, requested_port };
+                        state.ui_health.send(&ui_tx, UiUpdate::WsServer(status)).await;
+                    }
+                    Some(WsEvent::BindFailed { requested_port, error }) => {
+                        let status = WsServerStatus::BindFailed { requested_port, error };
+                        state.ui_health.send(&ui_tx, UiUpdate::WsServer(status)).await;
+                    }
                     None => {
-                        info!("WebSocket channel closed, shutting down");
-                        break;
+                        info!("WebSocket channel closed");
+                        ws_open = false;
                     }
                 }
             }
@@ -952,6 +247,55 @@ pub async fn run(
                 }
             }
 
+            // --- Second-screen queries ---
+            request = async {
+                match query_rx.as_mut() {
+                    Some(rx) => rx.recv().await,
+                    None => std::future::pending().await,
+                }
+            }, if query_rx.is_some() => {
+                match request {
+                    Some(request) => {
+                        let answer = query::answer(&state, &request.query);
+                        let _ = request.reply.send(answer);
+                    }
+                    None => {
+                        info!("Query channel closed");
+                        query_rx = None;
+                    }
+                }
+            }
+
+            // --- Database backup ---
+            _ = async {
+                match backup_interval.as_mut() {
+                    Some(interval) => interval.tick().await,
+                    None => std::future::pending().await,
+                }
+            }, if backup_interval.is_some() => {
+                match state.backup_database() {
+                    Ok(Some(path)) => info!("Database backed up to {}", path.display()),
+                    Ok(None) => {}
+                    Err(e) => warn!("Database backup failed: {:#}", e),
+                }
+            }
+
+            // --- Snoozed recalculation ---
+            _ = async {
+                match recalc_deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline).await,
+                    None => std::future::pending().await,
+                }
+            }, if recalc_deadline.is_some() => {
+                info!(
+                    "No picks for {}s, ending the recalculation snooze",
+                    state.config.display.recalc_snooze_secs
+                );
+                state.resume_recalc();
+                let snapshot = state.build_snapshot();
+                state.ui_health.send(&ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
+            }
+
             // --- Heartbeat timeout check ---
             _ = heartbeat_interval.tick() => {
                 if state.connection_status == ConnectionStatus::Connected {
@@ -963,9 +307,10 @@ pub async fn run(
                                 elapsed
                             );
                             state.connection_status = ConnectionStatus::Disconnected;
-                            let _ = ui_tx
-                                .send(UiUpdate::ConnectionStatus(ConnectionStatus::Disconnected))
-                                .await;
+                            state.ui_health.send(&ui_tx, UiUpdate::ConnectionStatus(ConnectionStatus::Disconnected)).await;
+                            if state.pause_llm() {
+                                state.ui_health.send(&ui_tx, UiUpdate::LlmPaused(true)).await;
+                            }
                         }
                     }
                 }
@@ -979,14 +324,6 @@ pub async fn run(
     Ok(())
 }
 
-
-
-
-
-
-
-
-
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -994,616 +331,260 @@ pub async fn run(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
+    use super::test_utils::*;
     use std::sync::atomic::Ordering;
-    use wyncast_core::config::*;
-    use wyncast_core::db::Database;
-    use wyncast_baseball::draft::pick::{DraftPick, Position};
-    use wyncast_baseball::draft::state::{ActiveNomination, DraftState};
-    use crate::protocol::{LlmEvent, OnboardingAction, OnboardingUpdate, UserCommand};
-    use wyncast_baseball::test_utils::{
-        self, make_hitter, make_pitcher, test_espn_budgets, test_registry, test_roster_config,
-        test_strategy_config,
-    };
-    use wyncast_baseball::valuation::projections::{AllProjections, PitcherType};
-    use wyncast_baseball::valuation::zscore::PlayerValuation;
+    use wyncast_baseball::draft::pick::DraftPick;
+    use wyncast_baseball::draft::state::DraftState;
+    use crate::protocol::{ExtensionHealth, LlmEvent, OnboardingAction, OnboardingUpdate, UserCommand};
+    use wyncast_baseball::valuation::projections::{AllProjections, ProjectionSource};
+    use tokio::sync::mpsc;
+    #[cfg(feature = "http")]
+    use wyncast_llm::client::LlmClient;
+    use crate::protocol::{AppMode, UiUpdate};
 
-    // -----------------------------------------------------------------------
-    // Test helpers
-    // -----------------------------------------------------------------------
+    #[test]
+    fn time_machine_reconstructs_state_after_past_pick() {
+        let mut state = create_test_app_state();
+        state.all_projections = Some(AllProjections {
+            hitters: vec![
+                test_hitter_projection("Slugger One", 40),
+                test_hitter_projection("Slugger Two", 35),
+                test_hitter_projection("Slugger Three", 20),
+            ],
+            pitchers: vec![],
+            source: ProjectionSource::Espn,
+        });
+        state.process_new_picks(vec![
+            test_pick("Slugger One", "1", 40),
+            test_pick("Slugger Two", "2", 30),
+        ]);
 
-    /// 2-team league config for app integration tests.
-    fn test_league_config() -> LeagueConfig {
-        let mut lc = test_utils::test_league_config();
-        lc.num_teams = 2;
-        lc
+        let snapshot = time_machine::build_snapshot(&state, 1).expect("pick 1 exists");
+
+        assert_eq!(snapshot.pick.pick_number, 1);
+        assert_eq!(snapshot.pick.player_name, "Slugger One");
+        assert!(snapshot.pick_value.is_some());
+        assert_eq!(snapshot.total_recorded, 2);
+        // Only the first pick has been paid for.
+        let budgets: Vec<u32> = snapshot.teams.iter().map(|t| t.budget_remaining).collect();
+        assert_eq!(budgets, vec![220, 260]);
+        // The second pick's player is still on the board.
+        let names: Vec<&str> = snapshot.top_available.iter().map(|p| p.name.as_str()).collect();
+        assert!(names.contains(&"Slugger Two"));
+        assert!(!names.contains(&"Slugger One"));
+        // Live state is untouched.
+        assert_eq!(state.draft_state.picks.len(), 2);
+        assert_eq!(state.draft_state.team("2").unwrap().budget_remaining, 230);
     }
 
-    fn test_config() -> Config {
-        Config {
-            league: test_league_config(),
-            strategy: test_strategy_config(),
-            credentials: CredentialsConfig::default(),
-            ws_port: 9001,
-            data_paths: DataPaths::default(),
-        }
-    }
+    #[tokio::test]
+    async fn my_picks_sign_keeper_contracts_and_appear_in_the_report() {
+        let mut state = create_test_app_state();
+        let dir = std::env::temp_dir().join(format!("wyncast_keeper_report_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        state.config.logging.dir = Some(dir.to_string_lossy().into_owned());
+        state.config.league.keepers.contract_years = 3;
+        state.config.league.keepers.raise = 5;
+
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45), test_pick("P_Ace", "2", 30)]);
+        assert_eq!(state.keeper_contracts.len(), 1, "only my picks are signed");
+        assert_eq!(state.db.load_keeper_contracts().unwrap(), state.keeper_contracts);
+
+        // Priced against the model while the player is still available.
+        let mut contract = state.keeper_contracts[0].clone();
+        contract.player = "H_Good".into();
+        state.keeper_contracts.push(contract);
+        let snapshot = state.build_snapshot();
+        assert_eq!(snapshot.keeper_values[0].value, None, "H_Star was drafted");
+        assert_eq!(snapshot.keeper_values[1].salaries, vec![45, 50, 55]);
+        assert!(snapshot.keeper_values[1].value.is_some());
 
-    fn test_players() -> Vec<PlayerValuation> {
-        vec![
-            make_hitter(
-                "H_Star",
-                100,
-                40,
-                100,
-                70,
-                20,
-                550,
-                0.300,
-                vec![Position::FirstBase],
-            ),
-            make_hitter(
-                "H_Good",
-                80,
-                25,
-                75,
-                55,
-                15,
-                530,
-                0.280,
-                vec![Position::SecondBase],
-            ),
-            make_hitter(
-                "H_Mid",
-                60,
-                15,
-                55,
-                40,
-                10,
-                500,
-                0.265,
-                vec![Position::ShortStop],
-            ),
-            make_hitter(
-                "H_Low",
-                45,
-                8,
-                40,
-                30,
-                5,
-                480,
-                0.250,
-                vec![Position::Catcher],
-            ),
-            make_pitcher(
-                "P_Ace",
-                250,
-                18,
-                0,
-                0,
-                200.0,
-                2.80,
-                1.00,
-                PitcherType::SP,
-            ),
-            make_pitcher(
-                "P_Good",
-                200,
-                14,
-                0,
-                0,
-                180.0,
-                3.20,
-                1.10,
-                PitcherType::SP,
-            ),
-            make_pitcher(
-                "P_Mid",
-                150,
-                10,
-                0,
-                0,
-                160.0,
-                3.80,
-                1.20,
-                PitcherType::SP,
-            ),
-        ]
-    }
+        let (ui_tx, mut ui_rx) = mpsc::channel(16);
+        command_handler::handle_user_command(&mut state, UserCommand::ExportKeeperReport, &ui_tx).await;
+        let path = match ui_rx.recv().await {
+            Some(UiUpdate::KeeperReport(Ok(path))) => path,
+            other => panic!("Expected a written keeper report, got {:?}", other),
+        };
+        let text = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], format!("# {}", state.session_stamp.summary()));
+        assert_eq!(lines[1], "player,salary,years,value,surplus,future_surplus,salaries");
+        assert!(lines[2].starts_with("H_Good,45,3,"), "got: {}", lines[2]);
+        assert_eq!(lines[3], "H_Star,45,3,,,,45/50/55");
 
-    fn empty_projections() -> Option<AllProjections> {
-        Some(AllProjections {
-            hitters: vec![],
-            pitchers: vec![],
-        })
+        let _ = std::fs::remove_dir_all(&dir);
     }
 
-    fn test_onboarding_manager() -> OnboardingManager<RealFileSystem> {
-        let tmp = std::env::temp_dir().join(format!("wyncast_test_{}", std::process::id()));
-        let _ = std::fs::create_dir_all(&tmp);
-        OnboardingManager::new(tmp, RealFileSystem)
+    #[tokio::test(start_paused = true)]
+    async fn resync_resends_the_snapshot_after_lost_updates() {
+        let mut state = create_test_app_state();
+        let (ui_tx, mut ui_rx) = mpsc::channel(1);
+        state.ui_health.send(&ui_tx, UiUpdate::NominationCleared).await;
+        state.ui_health.send(&ui_tx, UiUpdate::NominationCleared).await;
+        assert_eq!(state.ui_health.lost(), 1);
+        ui_rx.recv().await.unwrap();
+
+        command_handler::handle_user_command(&mut state, UserCommand::RequestKeyframe, &ui_tx).await;
+        assert!(matches!(ui_rx.recv().await, Some(UiUpdate::StateSnapshot(_))));
+        assert_eq!(state.ui_health.lost(), 0);
+        assert!(ui_rx.try_recv().is_err(), "the resync is not reported as a loss");
     }
 
-    fn create_test_app_state() -> AppState {
-        let config = test_config();
-        let mut draft_state = DraftState::new(260, &test_roster_config());
-        // Register teams from ESPN data and set my team
-        draft_state.reconcile_budgets(&test_espn_budgets(2));
-        draft_state.set_my_team_by_id("1");
-
-        let mut available = test_players();
-
-        // Run initial valuation so dollar values are set
-        let test_roster = AppState::default_roster_config();
-        let registry = StatRegistry::from_league_config(&config.league)
-            .expect("test registry");
-        wyncast_baseball::valuation::recalculate_all(
-            &mut available,
-            &test_roster,
-            &config.league,
-            &config.strategy,
-            &draft_state,
-            &registry,
-        );
+    #[tokio::test]
+    async fn manual_pick_answers_whether_it_was_taken() {
+        let mut state = create_test_app_state();
+        let (ui_tx, mut ui_rx) = mpsc::channel(16);
+        let pick = |team_idx| UserCommand::ManualPick { player_name: "H_Star".into(), team_idx, price: 30 };
 
-        let db = Database::open(":memory:").expect("in-memory db");
-        let draft_id = Database::generate_draft_id();
-        let llm_client = LlmClient::Disabled;
-        let (llm_tx, _llm_rx) = mpsc::channel(16);
+        command_handler::handle_user_command(&mut state, pick(99), &ui_tx).await;
+        match ui_rx.recv().await {
+            Some(UiUpdate::ManualPicked(Err(e))) => assert!(e.starts_with("no team #99"), "{e}"),
+            other => panic!("Expected a rejection, got {:?}", other),
+        }
+        assert!(state.draft_state.picks.is_empty());
 
-        AppState::new(config, draft_state, available, empty_projections(), db, draft_id, llm_client, llm_tx, None, AppMode::Draft, test_onboarding_manager(), Some(test_roster_config()))
-    }
+        command_handler::handle_user_command(&mut state, pick(1), &ui_tx).await;
+        assert!(matches!(ui_rx.recv().await, Some(UiUpdate::StateSnapshot(_))));
+        assert!(matches!(ui_rx.recv().await, Some(UiUpdate::ManualPicked(Ok(name))) if name == "H_Star"));
+        assert_eq!(state.draft_state.picks.len(), 1);
+        assert_eq!(state.draft_state.picks[0].position, "1B", "recorded at the player's position, not UTIL");
 
-    /// Drain the initial `StateSnapshot` that `run()` sends before entering
-    /// the event loop. Tests that spawn `run()` and then assert on the first
-    /// UI update must call this first, otherwise they'll see the snapshot
-    /// instead of the event-driven update they expect.
-    async fn drain_initial_snapshot(ui_rx: &mut mpsc::Receiver<UiUpdate>) {
-        let update = ui_rx.recv().await.expect("should receive initial snapshot");
-        assert!(
-            matches!(update, UiUpdate::StateSnapshot(_)),
-            "Expected initial StateSnapshot, got {:?}", update
-        );
+        // The same pick again records nothing, and says so.
+        command_handler::handle_user_command(&mut state, pick(1), &ui_tx).await;
+        match ui_rx.recv().await {
+            Some(UiUpdate::ManualPicked(Err(e))) => assert_eq!(e, "H_Star has already been drafted"),
+            other => panic!("Expected a rejection, got {:?}", other),
+        }
+        assert_eq!(state.draft_state.picks.len(), 1);
     }
 
-    // -----------------------------------------------------------------------
-    // Tests: State diff detection -> pick recording -> recalculation
-    // -----------------------------------------------------------------------
-
-    #[test]
-    fn process_new_picks_updates_state() {
+    #[tokio::test]
+    async fn a_manual_pick_of_a_shared_name_waits_to_be_resolved() {
         let mut state = create_test_app_state();
-        let initial_count = state.available_players.len();
-        let initial_pick_count = state.draft_state.pick_count;
+        let (ui_tx, _ui_rx) = mpsc::channel(16);
+        let mut namesake = state.available_players.iter().find(|p| p.name == "H_Star").unwrap().clone();
+        namesake.team = "KC".into();
+        namesake.positions = vec![wyncast_baseball::draft::pick::Position::Catcher];
+        state.available_players.push(namesake);
+        let pool = state.available_players.len();
 
-        let pick = DraftPick {
-            pick_number: 1,
-            team_id: "1".into(),
-            team_name: "Team 1".into(),
-            player_name: "H_Star".into(),
-            position: "1B".into(),
-            price: 45,
-            espn_player_id: None,
-            eligible_slots: vec![],
-            assigned_slot: None,
-        };
+        let pick = UserCommand::ManualPick { player_name: "H_Star".into(), team_idx: 1, price: 30 };
+        command_handler::handle_user_command(&mut state, pick, &ui_tx).await;
+        assert_eq!(state.draft_state.picks[0].position, "1B/C", "fits either H_Star");
+        assert_eq!(state.available_players.len(), pool);
+        assert_eq!(state.ambiguous_picks.len(), 1);
+    }
 
-        state.process_new_picks(vec![pick]);
+    #[test]
+    fn value_query_reports_available_and_drafted_players() {
+        let mut state = create_test_app_state();
+        let answer = query::answer(&state, &query::Query::Value("h_star".into()));
+        assert!(answer.starts_with("H_Star (1B, "), "{answer}");
+        assert!(answer.contains("adjusted for inflation"));
 
-        // Pick count should increase
-        assert_eq!(state.draft_state.pick_count, initial_pick_count + 1);
+        let answer = query::answer(&state, &query::Query::Value("H_".into()));
+        assert!(answer.starts_with("\"H_\" matches "), "{answer}");
 
-        // Player should be removed from available pool
-        assert_eq!(state.available_players.len(), initial_count - 1);
-        assert!(!state
-            .available_players
-            .iter()
-            .any(|p| p.name == "H_Star"));
+        state.process_new_picks(vec![test_pick("H_Star", "2", 45)]);
+        let answer = query::answer(&state, &query::Query::Value("H_Star".into()));
+        assert_eq!(answer, "H_Star went to Team 2 for $45 (pick 1)");
+    }
 
-        // Team budget should be updated
-        let team = state.draft_state.team("1").unwrap();
-        assert_eq!(team.budget_spent, 45);
-        assert_eq!(team.budget_remaining, 215);
+    #[test]
+    fn plan_query_suggests_one_target_per_open_slot() {
+        let state = create_test_app_state();
+        let answer = query::answer(&state, &query::Query::Plan);
+        assert!(answer.starts_with("$260 left, max bid $"), "{answer}");
+        assert!(answer.contains("\n1B: H_Star $"), "{answer}");
+        assert!(answer.contains("\nSP x"), "{answer}");
+        assert_eq!(answer.matches("H_Star").count(), 1, "{answer}");
     }
 
     #[test]
-    fn process_new_picks_updates_inflation() {
+    fn time_machine_rejects_unknown_pick() {
         let mut state = create_test_app_state();
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45)]);
+
+        assert!(time_machine::build_snapshot(&state, 0).is_none());
+        assert!(time_machine::build_snapshot(&state, 2).is_none());
+        assert!(time_machine::build_snapshot(&state, 1).is_some());
+    }
+
+    // -----------------------------------------------------------------------
+    // Tests: support bundle
+    // -----------------------------------------------------------------------
 
-        let pick = DraftPick {
-            pick_number: 1,
-            team_id: "1".into(),
-            team_name: "Team 1".into(),
+    #[tokio::test]
+    async fn dump_support_bundle_writes_state_without_api_keys() {
+        let mut state = create_test_app_state();
+        let dir = std::env::temp_dir().join(format!("wyncast_support_bundle_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        state.config.logging.dir = Some(dir.to_string_lossy().into_owned());
+        state.config.credentials.anthropic_api_key = Some("sk-ant-secret".into());
+        state.process_new_picks(vec![DraftPick {
+            pick_number: 0,
+            team_id: "2".into(),
+            team_name: "Team 2".into(),
             player_name: "H_Star".into(),
             position: "1B".into(),
-            price: 45,
+            price: 40,
             espn_player_id: None,
             eligible_slots: vec![],
             assigned_slot: None,
+        }]);
+
+        let (ui_tx, mut ui_rx) = mpsc::channel(16);
+        command_handler::handle_user_command(&mut state, UserCommand::DumpSupportBundle, &ui_tx).await;
+        let path = match ui_rx.recv().await {
+            Some(UiUpdate::SupportBundle(Ok(path))) => path,
+            other => panic!("Expected a written support bundle, got {:?}", other),
         };
 
-        state.process_new_picks(vec![pick]);
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(!text.contains("sk-ant-secret"), "API keys must not be dumped");
+        let bundle: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(bundle["config"]["api_keys"]["anthropic"], true);
+        assert_eq!(bundle["draft_id"], state.draft_id.as_str());
+        assert_eq!(bundle["session_stamp"]["draft_id"], state.draft_id.as_str());
 
-        // Inflation tracker should be updated
-        assert!(state.inflation.total_dollars_spent > 0.0);
-        assert!(state.inflation.inflation_rate.is_finite());
+        // The draft state section loads back into a DraftState.
+        let restored = DraftState::from_json(&bundle["draft_state"].to_string()).unwrap();
+        assert_eq!(restored.picks.len(), 1);
+        assert_eq!(restored.picks[0].player_name, "H_Star");
+
+        let _ = std::fs::remove_dir_all(&dir);
     }
 
-    #[test]
-    fn process_new_picks_updates_scarcity() {
+    #[tokio::test]
+    async fn projection_audit_lists_only_overridden_players() {
         let mut state = create_test_app_state();
+        let dir = std::env::temp_dir().join(format!("wyncast_projection_audit_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        state.config.logging.dir = Some(dir.to_string_lossy().into_owned());
+        let overridden = state.available_players[0].name.clone();
+        for (i, player) in state.available_players.iter_mut().enumerate() {
+            let (source, is_override) = if i == 0 { ("hitters.csv", true) } else { ("ESPN", false) };
+            player.projection = player.projection.clone().with_source(source, is_override);
+        }
 
-        // Record the initial scarcity state for FirstBase
-        let initial_fb_count = state
-            .scarcity
-            .iter()
-            .find(|s| s.position == Position::FirstBase)
-            .map(|s| s.players_above_replacement);
-
-        let pick = DraftPick {
-            pick_number: 1,
-            team_id: "1".into(),
-            team_name: "Team 1".into(),
-            player_name: "H_Star".into(),
-            position: "1B".into(),
-            price: 45,
-            espn_player_id: None,
-            eligible_slots: vec![],
-            assigned_slot: None,
+        let (ui_tx, mut ui_rx) = mpsc::channel(16);
+        command_handler::handle_user_command(&mut state, UserCommand::ExportProjectionAudit, &ui_tx).await;
+        let path = match ui_rx.recv().await {
+            Some(UiUpdate::ProjectionAudit(Ok(path))) => path,
+            other => panic!("Expected a written projection audit, got {:?}", other),
         };
 
-        state.process_new_picks(vec![pick]);
-
-        // Scarcity should be recalculated
-        let new_fb_count = state
-            .scarcity
-            .iter()
-            .find(|s| s.position == Position::FirstBase)
-            .map(|s| s.players_above_replacement);
-
-        // After removing a 1B player, the count should change (or at least be recalculated)
-        // The exact change depends on whether H_Star had positive VOR
-        assert!(new_fb_count.is_some());
-        // Just verify scarcity was recomputed (if H_Star had positive VOR, count should decrease)
-        if let (Some(initial), Some(new)) = (initial_fb_count, new_fb_count) {
-            // If the star had positive VOR, count should decrease
-            if initial > 0 {
-                assert!(new <= initial);
-            }
-        }
-    }
+        let text = std::fs::read_to_string(&path).unwrap();
+        let mut lines = text.lines();
+        assert!(lines.next().unwrap().starts_with("# wyncast "));
+        assert_eq!(lines.next(), Some("player,team,field,value,sources,overridden"));
+        let rows: Vec<&str> = lines.collect();
+        assert_eq!(rows.len(), state.available_players[0].projection.values.len());
+        assert!(rows.iter().all(|r| r.starts_with(&format!("{overridden},"))));
+        assert!(rows.iter().all(|r| r.ends_with(",hitters.csv (override),true")));
 
-    #[test]
-    fn process_new_picks_persists_to_db() {
-        let mut state = create_test_app_state();
-
-        let pick = DraftPick {
-            pick_number: 1,
-            team_id: "1".into(),
-            team_name: "Team 1".into(),
-            player_name: "H_Star".into(),
-            position: "1B".into(),
-            price: 45,
-            espn_player_id: Some("espn_123".into()),
-            eligible_slots: vec![],
-            assigned_slot: None,
-        };
-
-        state.process_new_picks(vec![pick]);
-
-        // Verify the pick was persisted to DB
-        let db_picks = state.db.load_picks(&state.draft_id).unwrap();
-        assert_eq!(db_picks.len(), 1);
-        assert_eq!(db_picks[0].player_name, "H_Star");
-        assert_eq!(db_picks[0].price, 45);
-        assert_eq!(db_picks[0].espn_player_id, Some("espn_123".into()));
-    }
-
-    #[test]
-    fn process_new_picks_persists_canonical_pick_number_to_db() {
-        let mut state = create_test_app_state();
-
-        // Simulate ESPN sending all picks with pick_number=1 (the known bug
-        // in ESPN's virtualized pick list)
-        let picks = vec![
-            DraftPick {
-                pick_number: 1,
-                team_id: "1".into(),
-                team_name: "Team 1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                price: 45,
-                espn_player_id: Some("espn_1".into()),
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-            DraftPick {
-                pick_number: 1,
-                team_id: "2".into(),
-                team_name: "Team 2".into(),
-                player_name: "P_Ace".into(),
-                position: "SP".into(),
-                price: 50,
-                espn_player_id: Some("espn_2".into()),
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-            DraftPick {
-                pick_number: 1,
-                team_id: "3".into(),
-                team_name: "Team 3".into(),
-                player_name: "H_Good".into(),
-                position: "2B".into(),
-                price: 30,
-                espn_player_id: Some("espn_3".into()),
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-        ];
-
-        state.process_new_picks(picks);
-
-        // All 3 picks should be persisted with canonical sequential pick numbers
-        let db_picks = state.db.load_picks(&state.draft_id).unwrap();
-        assert_eq!(db_picks.len(), 3, "All picks must be persisted, not just the first");
-        assert_eq!(db_picks[0].pick_number, 1);
-        assert_eq!(db_picks[0].player_name, "H_Star");
-        assert_eq!(db_picks[1].pick_number, 2);
-        assert_eq!(db_picks[1].player_name, "P_Ace");
-        assert_eq!(db_picks[2].pick_number, 3);
-        assert_eq!(db_picks[2].player_name, "H_Good");
-    }
-
-    #[test]
-    fn process_multiple_picks_at_once() {
-        let mut state = create_test_app_state();
-        let initial_count = state.available_players.len();
-
-        let picks = vec![
-            DraftPick {
-                pick_number: 1,
-                team_id: "team_1".into(),
-                team_name: "Team 1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                price: 45,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-            DraftPick {
-                pick_number: 2,
-                team_id: "team_2".into(),
-                team_name: "Team 2".into(),
-                player_name: "P_Ace".into(),
-                position: "SP".into(),
-                price: 50,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-        ];
-
-        state.process_new_picks(picks);
-
-        assert_eq!(state.draft_state.pick_count, 2);
-        assert_eq!(state.available_players.len(), initial_count - 2);
-        assert!(!state.available_players.iter().any(|p| p.name == "H_Star"));
-        assert!(!state.available_players.iter().any(|p| p.name == "P_Ace"));
-    }
-
-    // -----------------------------------------------------------------------
-    // Tests: New picks update DraftState, available players, inflation
-    // -----------------------------------------------------------------------
-
-    #[test]
-    fn picks_update_draft_state_and_available() {
-        let mut state = create_test_app_state();
-
-        let pick = DraftPick {
-            pick_number: 1,
-            team_id: "2".into(),
-            team_name: "Team 2".into(),
-            player_name: "H_Good".into(),
-            position: "2B".into(),
-            price: 30,
-            espn_player_id: None,
-            eligible_slots: vec![],
-            assigned_slot: None,
-        };
-
-        state.process_new_picks(vec![pick]);
-
-        // DraftState should have the pick
-        assert_eq!(state.draft_state.picks.len(), 1);
-        assert_eq!(state.draft_state.picks[0].player_name, "H_Good");
-
-        // Team 2 budget should be updated
-        let team2 = state.draft_state.team("2").unwrap();
-        assert_eq!(team2.budget_spent, 30);
-
-        // H_Good should not be in available pool
-        assert!(!state
-            .available_players
-            .iter()
-            .any(|p| p.name == "H_Good"));
-    }
-
-    // -----------------------------------------------------------------------
-    // Tests: LLM trigger logic
-    // -----------------------------------------------------------------------
-
-    #[tokio::test]
-    async fn nomination_triggers_llm_mode() {
-        let mut state = create_test_app_state();
-
-        let nomination = ActiveNomination {
-            player_name: "H_Star".into(),
-            player_id: "espn_1".into(),
-            position: "1B".into(),
-            nominated_by: "Team 2".into(),
-            current_bid: 5,
-            current_bidder: None,
-            time_remaining: Some(30),
-            eligible_slots: vec![],
-        };
-
-        let _analysis = state.handle_nomination(&nomination);
-
-        // analysis_player and analysis_request_id should be set
-        assert!(state.analysis_player.is_some());
-        assert!(state.analysis_request_id.is_some());
-        let ap = state.analysis_player.as_ref().unwrap();
-        assert_eq!(ap.player_name, "H_Star");
-        assert_eq!(ap.player_id, "espn_1");
-    }
-
-    #[tokio::test]
-    async fn nomination_returns_analysis_for_known_player() {
-        let mut state = create_test_app_state();
-
-        let nomination = ActiveNomination {
-            player_name: "H_Star".into(),
-            player_id: "espn_1".into(),
-            position: "1B".into(),
-            nominated_by: "Team 2".into(),
-            current_bid: 5,
-            current_bidder: None,
-            time_remaining: Some(30),
-            eligible_slots: vec![],
-        };
-
-        let analysis = state.handle_nomination(&nomination);
-
-        // Should return analysis since H_Star is in the available pool
-        assert!(analysis.is_some());
-        let analysis = analysis.unwrap();
-        assert_eq!(analysis.player_name, "H_Star");
-    }
-
-    #[tokio::test]
-    async fn nomination_returns_none_for_unknown_player() {
-        let mut state = create_test_app_state();
-
-        let nomination = ActiveNomination {
-            player_name: "Unknown Player".into(),
-            player_id: "espn_999".into(),
-            position: "OF".into(),
-            nominated_by: "Team 2".into(),
-            current_bid: 1,
-            current_bidder: None,
-            time_remaining: Some(30),
-            eligible_slots: vec![],
-        };
-
-        let analysis = state.handle_nomination(&nomination);
-
-        // Should return None since the player is not in our pool
-        assert!(analysis.is_none());
-    }
-
-    // -----------------------------------------------------------------------
-    // Tests: LLM cancellation (new nomination cancels previous)
-    // -----------------------------------------------------------------------
-
-    #[tokio::test]
-    async fn new_nomination_clears_previous_analysis() {
-        let mut state = create_test_app_state();
-
-        // First nomination
-        let nom1 = ActiveNomination {
-            player_name: "H_Star".into(),
-            player_id: "espn_1".into(),
-            position: "1B".into(),
-            nominated_by: "Team 2".into(),
-            current_bid: 5,
-            current_bidder: None,
-            time_remaining: Some(30),
-            eligible_slots: vec![],
-        };
-        state.handle_nomination(&nom1);
-
-        // Second nomination (should cancel first)
-        let nom2 = ActiveNomination {
-            player_name: "H_Good".into(),
-            player_id: "espn_2".into(),
-            position: "2B".into(),
-            nominated_by: "Team 1".into(),
-            current_bid: 3,
-            current_bidder: None,
-            time_remaining: Some(30),
-            eligible_slots: vec![],
-        };
-        state.handle_nomination(&nom2);
-
-        // analysis_player should be updated to the new nomination
-        let ap = state.analysis_player.as_ref().expect("Expected analysis_player to be set");
-        assert_eq!(ap.player_name, "H_Good");
-    }
-
-    #[tokio::test]
-    async fn nomination_cleared_resets_state() {
-        let mut state = create_test_app_state();
-
-        // Set up a nomination
-        let nom = ActiveNomination {
-            player_name: "H_Star".into(),
-            player_id: "espn_1".into(),
-            position: "1B".into(),
-            nominated_by: "Team 2".into(),
-            current_bid: 5,
-            current_bidder: None,
-            time_remaining: Some(30),
-            eligible_slots: vec![],
-        };
-        state.handle_nomination(&nom);
-
-        // Clear the nomination
-        let plan_id = state.handle_nomination_cleared();
-
-        assert!(state.draft_state.current_nomination.is_none());
-        assert!(state.analysis_player.is_none());
-        assert!(state.analysis_request_id.is_none());
-        // With prefire_planning=true and teams registered, planning should
-        // auto-trigger so the plan panel is populated between nominations.
-        assert!(
-            state.plan_request_id.is_some(),
-            "expected plan_request_id to be set after clearing (prefire_planning=true)"
-        );
-        assert!(plan_id.is_some());
-    }
-
-    #[tokio::test]
-    async fn nomination_cleared_skips_planning_when_prefire_disabled() {
-        let mut state = create_test_app_state();
-        state.config.strategy.llm.prefire_planning = false;
-
-        let nom = ActiveNomination {
-            player_name: "H_Star".into(),
-            player_id: "espn_1".into(),
-            position: "1B".into(),
-            nominated_by: "Team 2".into(),
-            current_bid: 5,
-            current_bidder: None,
-            time_remaining: Some(30),
-            eligible_slots: vec![],
-        };
-        state.handle_nomination(&nom);
-
-        state.handle_nomination_cleared();
-
-        assert!(state.draft_state.current_nomination.is_none());
-        assert!(state.plan_request_id.is_none());
-    }
-
-    #[tokio::test]
-    async fn nomination_cleared_skips_planning_when_no_teams() {
-        let mut state = create_test_app_state_no_teams();
-
-        state.handle_nomination_cleared();
-
-        assert!(state.plan_request_id.is_none());
-    }
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 
     // -----------------------------------------------------------------------
     // Tests: Async event loop
@@ -1633,6 +614,28 @@ mod tests {
         drop(cmd_tx);
     }
 
+    #[tokio::test]
+    async fn event_loop_answers_queries() {
+        let mut state = create_test_app_state();
+        let (query_tx, query_rx) = mpsc::channel(4);
+        state.query_rx = Some(query_rx);
+        let (_ws_tx, ws_rx) = mpsc::channel(16);
+        let (_llm_tx, llm_rx) = mpsc::channel(16);
+        let (cmd_tx, cmd_rx) = mpsc::channel(16);
+        let (ui_tx, _ui_rx) = mpsc::channel(64);
+        let handle = tokio::spawn(run(ws_rx, llm_rx, cmd_rx, ui_tx, state));
+
+        let (reply, answer) = tokio::sync::oneshot::channel();
+        let request = query::QueryRequest { query: query::Query::Value("P_Ace".into()), reply };
+        query_tx.send(request).await.unwrap();
+        assert!(answer.await.unwrap().starts_with("P_Ace (SP, "));
+
+        // Losing the query front-end does not stop the loop.
+        drop(query_tx);
+        cmd_tx.send(UserCommand::Quit).await.unwrap();
+        assert!(handle.await.unwrap().is_ok());
+    }
+
     #[tokio::test]
     async fn event_loop_handles_connection_status() {
         let state = create_test_app_state();
@@ -1673,6 +676,45 @@ mod tests {
         let _ = handle.await;
     }
 
+    #[tokio::test]
+    async fn event_loop_reports_bind_failure_and_keeps_running() {
+        let state = create_test_app_state();
+        let (ws_tx, ws_rx) = mpsc::channel(16);
+        let (_llm_tx, llm_rx) = mpsc::channel(16);
+        let (cmd_tx, cmd_rx) = mpsc::channel(16);
+        let (ui_tx, mut ui_rx) = mpsc::channel(64);
+
+        let handle = tokio::spawn(run(ws_rx, llm_rx, cmd_rx, ui_tx, state));
+        drain_initial_snapshot(&mut ui_rx).await;
+
+        ws_tx
+            .send(WsEvent::BindFailed {
+                requested_port: 9001,
+                error: "no free port".into(),
+            })
+            .await
+            .unwrap();
+        // The server task ends after a failed bind, closing the channel.
+        drop(ws_tx);
+
+        let update = ui_rx.recv().await.unwrap();
+        assert!(
+            matches!(
+                update,
+                UiUpdate::WsServer(WsServerStatus::BindFailed { requested_port: 9001, .. })
+            ),
+            "Expected WsServer(BindFailed), got {:?}", update
+        );
+
+        // The loop has seen the closed channel and is still serving commands.
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!handle.is_finished());
+        cmd_tx.send(UserCommand::Quit).await.unwrap();
+        assert!(handle.await.unwrap().is_ok());
+    }
+
     #[tokio::test]
     async fn event_loop_handles_llm_tokens() {
         let state = create_test_app_state();
@@ -1721,6 +763,97 @@ mod tests {
         let _ = handle.await;
     }
 
+    #[tokio::test]
+    async fn event_loop_trims_a_completed_analysis_to_the_style() {
+        let mut state = create_test_app_state();
+        state.config.strategy.llm.analysis_verbosity = wyncast_core::config::AnalysisVerbosity::Terse;
+        state.config.strategy.llm.analysis_tldr = true;
+        let (_ws_tx, ws_rx) = mpsc::channel(16);
+        let (llm_tx, llm_rx) = mpsc::channel(16);
+        let (cmd_tx, cmd_rx) = mpsc::channel(16);
+        let (ui_tx, mut ui_rx) = mpsc::channel(64);
+
+        let gen = 7u64;
+        let handle = tokio::spawn(async move {
+            let mut state = state;
+            state.llm_requests.track_test_id(gen);
+            state.analysis_request_id = Some(gen);
+            run(ws_rx, llm_rx, cmd_rx, ui_tx, state).await
+        });
+        drain_initial_snapshot(&mut ui_rx).await;
+
+        llm_tx
+            .send(LlmEvent::Complete {
+                full_text: "Sure, here goes.\n- Verdict: PASS\n- Too rich\nTL;DR: pass.".into(),
+                input_tokens: 10,
+                output_tokens: 10,
+                stop_reason: Some("end_turn".into()),
+                generation: gen,
+            })
+            .await
+            .unwrap();
+
+        match ui_rx.recv().await.unwrap() {
+            UiUpdate::LlmUpdate { update, .. } => assert_eq!(
+                update,
+                crate::protocol::LlmStreamUpdate::Complete(
+                    "TL;DR: pass.\n- Verdict: PASS\n- Too rich".into()
+                )
+            ),
+            other => panic!("Expected LlmUpdate, got {:?}", other),
+        }
+
+        cmd_tx.send(UserCommand::Quit).await.unwrap();
+        let _ = handle.await;
+    }
+
+    #[tokio::test]
+    async fn event_loop_closes_an_analysis_cut_off_by_the_clock_with_the_verdict() {
+        let mut state = create_test_app_state();
+        state.nomination_verdict = Some(wyncast_core::nomination::NominationVerdict {
+            player_name: "H_Star".into(),
+            verdict: "STRONG TARGET".into(),
+            max_bid: 31,
+        });
+        let (_ws_tx, ws_rx) = mpsc::channel(16);
+        let (llm_tx, llm_rx) = mpsc::channel(16);
+        let (cmd_tx, cmd_rx) = mpsc::channel(16);
+        let (ui_tx, mut ui_rx) = mpsc::channel(64);
+
+        let gen = 9u64;
+        let handle = tokio::spawn(async move {
+            let mut state = state;
+            state.llm_requests.track_test_id(gen);
+            state.analysis_request_id = Some(gen);
+            run(ws_rx, llm_rx, cmd_rx, ui_tx, state).await
+        });
+        drain_initial_snapshot(&mut ui_rx).await;
+
+        llm_tx
+            .send(LlmEvent::Complete {
+                full_text: "Elite bat. Fills 1B and the power is re".into(),
+                input_tokens: 0,
+                output_tokens: 0,
+                stop_reason: Some(llm_request_manager::DEADLINE_STOP_REASON.into()),
+                generation: gen,
+            })
+            .await
+            .unwrap();
+
+        match ui_rx.recv().await.unwrap() {
+            UiUpdate::LlmUpdate { update, .. } => assert_eq!(
+                update,
+                crate::protocol::LlmStreamUpdate::Complete(
+                    "Elite bat.\n\nVERDICT (clock expiring): STRONG TARGET, max $31".into()
+                )
+            ),
+            other => panic!("Expected LlmUpdate, got {:?}", other),
+        }
+
+        cmd_tx.send(UserCommand::Quit).await.unwrap();
+        let _ = handle.await;
+    }
+
     #[tokio::test]
     async fn event_loop_handles_state_update_with_picks() {
         let state = create_test_app_state();
@@ -1762,553 +895,38 @@ mod tests {
             }
         });
 
-        ws_tx
-            .send(WsEvent::Message(state_update.to_string()))
-            .await
-            .unwrap();
-
-        // Should receive a StateSnapshot first (new picks trigger snapshot)
-        let update = ui_rx.recv().await.unwrap();
-        match update {
-            UiUpdate::StateSnapshot(snapshot) => {
-                assert_eq!(snapshot.pick_count, 1);
-                // H_Star should have been removed from available players
-                assert!(!snapshot.available_players.iter().any(|p| p.name == "H_Star"));
-                // Draft log should contain the pick
-                assert_eq!(snapshot.draft_log.len(), 1);
-                assert_eq!(snapshot.draft_log[0].player_name, "H_Star");
-            }
-            other => panic!("Expected StateSnapshot, got {:?}", other),
-        }
-
-        // Then receive the NominationUpdate
-        let update = ui_rx.recv().await.unwrap();
-        match update {
-            UiUpdate::NominationUpdate { info, .. } => {
-                assert_eq!(info.player_name, "H_Good");
-                assert_eq!(info.current_bid, 5);
-            }
-            other => panic!("Expected NominationUpdate, got {:?}", other),
-        }
-
-        // Clean up
-        cmd_tx.send(UserCommand::Quit).await.unwrap();
-        let _ = handle.await;
-    }
-
-    // -----------------------------------------------------------------------
-    // Tests: Extension state conversion
-    // -----------------------------------------------------------------------
-
-    // -----------------------------------------------------------------------
-    // Tests: First nomination with delayed team registration
-    // -----------------------------------------------------------------------
-
-    /// Create an AppState WITHOUT teams registered (simulates the state
-    /// before the first STATE_UPDATE containing team budget data).
-    fn create_test_app_state_no_teams() -> AppState {
-        let config = test_config();
-        let draft_state = DraftState::new(260, &test_roster_config());
-        // Do NOT call reconcile_budgets or set_my_team_by_id
-
-        let available = test_players();
-
-        let db = Database::open(":memory:").expect("in-memory db");
-        let llm_client = LlmClient::Disabled;
-        let (llm_tx, _llm_rx) = mpsc::channel(16);
-
-        let draft_id = Database::generate_draft_id();
-        AppState::new(config, draft_state, available, empty_projections(), db, draft_id, llm_client, llm_tx, None, AppMode::Draft, test_onboarding_manager(), Some(test_roster_config()))
-    }
-
-    #[tokio::test]
-    async fn first_nomination_with_teams_in_same_update_triggers_analysis() {
-        // Scenario: The very first STATE_UPDATE from the extension contains
-        // both team budget data AND the first nomination. Teams aren't
-        // registered yet (no prior state updates). The nomination analysis
-        // should still be triggered because reconcile_budgets runs before
-        // nomination handling within handle_state_update.
-        let mut state = create_test_app_state_no_teams();
-        let (ui_tx, mut ui_rx) = mpsc::channel(64);
-
-        assert!(state.draft_state.teams.is_empty(), "Teams should start empty");
-        assert!(state.analysis_request_id.is_none(), "analysis_request_id should start as None");
-
-        // Simulate the first STATE_UPDATE with teams + nomination
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "espn_1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                nominated_by: "Team 2".into(),
-                current_bid: 5,
-                current_bidder: None,
-                time_remaining: Some(30),
-                eligible_slots: vec![],
-            }),
-            my_team_id: Some("1".into()),
-            teams: vec![
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("1".into()),
-                    team_name: "Team 1".into(),
-                    budget: 260,
-                },
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("2".into()),
-                    team_name: "Team 2".into(),
-                    budget: 260,
-                },
-            ],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        ws_handler::handle_state_update(&mut state, ext_payload, &ui_tx).await;
-
-        // Teams should now be registered
-        assert_eq!(state.draft_state.teams.len(), 2);
-
-        // Analysis should have been triggered
-        assert!(
-            state.analysis_request_id.is_some(),
-            "analysis_request_id should be set after first nomination with teams in same update"
-        );
-        assert!(state.analysis_player.is_some());
-
-        // current_nomination should be set on the draft state
-        assert!(state.draft_state.current_nomination.is_some());
-        assert_eq!(
-            state.draft_state.current_nomination.as_ref().unwrap().player_name,
-            "H_Star"
-        );
-
-        // Drain UI updates and verify we got a NominationUpdate
-        let mut got_nomination_update = false;
-        while let Ok(update) = ui_rx.try_recv() {
-            if let UiUpdate::NominationUpdate { info, .. } = update {
-                assert_eq!(info.player_name, "H_Star");
-                got_nomination_update = true;
-            }
-        }
-        assert!(
-            got_nomination_update,
-            "Should have received a NominationUpdate"
-        );
-    }
-
-    #[tokio::test]
-    async fn first_nomination_before_teams_retries_after_registration() {
-        // Scenario: The first STATE_UPDATE has a nomination but NO team data.
-        // handle_nomination() fails because my_team() returns None.
-        // A second STATE_UPDATE arrives with team data (and the same nomination).
-        // The retry logic should detect the unanalyzed nomination and trigger analysis.
-        let mut state = create_test_app_state_no_teams();
-        let (ui_tx, mut ui_rx) = mpsc::channel(64);
-
-        assert!(state.draft_state.teams.is_empty());
-
-        // First STATE_UPDATE: nomination but no teams
-        let ext_payload_1 = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "espn_1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                nominated_by: "Team 2".into(),
-                current_bid: 5,
-                current_bidder: None,
-                time_remaining: Some(30),
-                eligible_slots: vec![],
-            }),
-            my_team_id: Some("1".into()),
-            teams: vec![],  // No teams!
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        ws_handler::handle_state_update(&mut state, ext_payload_1, &ui_tx).await;
-
-        // Teams should still be empty
-        assert!(state.draft_state.teams.is_empty());
-        // analysis should be None (nomination was skipped)
-        assert!(
-            state.analysis_request_id.is_none(),
-            "analysis_request_id should be None since teams aren't registered"
-        );
-        // current_nomination should NOT be set (handle_nomination returned early)
-        assert!(
-            state.draft_state.current_nomination.is_none(),
-            "current_nomination should be None since handle_nomination returned early"
-        );
-
-        // Drain any UI updates from first round
-        while ui_rx.try_recv().is_ok() {}
-
-        // Second STATE_UPDATE: teams arrive, same nomination still active
-        let ext_payload_2 = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "espn_1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                nominated_by: "Team 2".into(),
-                current_bid: 5,
-                current_bidder: None,
-                time_remaining: Some(25),  // Time ticked down
-                eligible_slots: vec![],
-            }),
-            my_team_id: Some("1".into()),
-            teams: vec![
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("1".into()),
-                    team_name: "Team 1".into(),
-                    budget: 260,
-                },
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("2".into()),
-                    team_name: "Team 2".into(),
-                    budget: 260,
-                },
-            ],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        ws_handler::handle_state_update(&mut state, ext_payload_2, &ui_tx).await;
-
-        // Teams should now be registered
-        assert_eq!(state.draft_state.teams.len(), 2);
-
-        // Analysis should now be set (retry triggered the analysis)
-        assert!(
-            state.analysis_request_id.is_some(),
-            "analysis_request_id should be set after teams registered with pending nomination"
-        );
-        assert!(state.analysis_player.is_some());
-
-        // current_nomination should now be set
-        assert!(state.draft_state.current_nomination.is_some());
-        assert_eq!(
-            state.draft_state.current_nomination.as_ref().unwrap().player_name,
-            "H_Star"
-        );
-
-        // Verify we got a NominationUpdate from the retry
-        let mut got_nomination_update = false;
-        while let Ok(update) = ui_rx.try_recv() {
-            if let UiUpdate::NominationUpdate { info, .. } = update {
-                assert_eq!(info.player_name, "H_Star");
-                got_nomination_update = true;
-            }
-        }
-        assert!(
-            got_nomination_update,
-            "Should have received a NominationUpdate from the retry"
-        );
-    }
-
-    #[tokio::test]
-    async fn retry_does_not_fire_when_nomination_already_analyzed() {
-        // Scenario: Teams are registered in a state update that also contains
-        // a nomination, and the normal flow handles it. The retry should NOT
-        // fire a second time (llm_mode is already set).
-        let mut state = create_test_app_state_no_teams();
-        let (ui_tx, mut ui_rx) = mpsc::channel(64);
-
-        // First STATE_UPDATE with teams + nomination
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "espn_1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                nominated_by: "Team 2".into(),
-                current_bid: 5,
-                current_bidder: None,
-                time_remaining: Some(30),
-                eligible_slots: vec![],
-            }),
-            my_team_id: Some("1".into()),
-            teams: vec![
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("1".into()),
-                    team_name: "Team 1".into(),
-                    budget: 260,
-                },
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("2".into()),
-                    team_name: "Team 2".into(),
-                    budget: 260,
-                },
-            ],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        ws_handler::handle_state_update(&mut state, ext_payload, &ui_tx).await;
-
-        // Count NominationUpdate messages -- should be exactly 1
-        // (from the normal flow, not doubled by the retry)
-        let mut nomination_update_count = 0;
-        while let Ok(update) = ui_rx.try_recv() {
-            if matches!(update, UiUpdate::NominationUpdate { .. }) {
-                nomination_update_count += 1;
-            }
-        }
-        assert_eq!(
-            nomination_update_count, 1,
-            "Should get exactly 1 NominationUpdate, not doubled by retry"
-        );
-    }
-
-    // -----------------------------------------------------------------------
-    // Tests: Extension state conversion
-    // -----------------------------------------------------------------------
-
-    #[test]
-    fn convert_extension_state_round_trip() {
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![crate::protocol::PickData {
-                pick_number: 1,
-                team_id: "team_1".into(),
-                team_name: "Team 1".into(),
-                player_id: "espn_1".into(),
-                player_name: "Player One".into(),
-                position: "SP".into(),
-                price: 30,
-                eligible_slots: vec![14, 13, 16, 17],
-            assigned_slot: None,
-            }],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "espn_2".into(),
-                player_name: "Player Two".into(),
-                position: "1B".into(),
-                nominated_by: "Team 2".into(),
-                current_bid: 10,
-                current_bidder: Some("Team 3".into()),
-                time_remaining: Some(25),
-                eligible_slots: vec![1, 7, 12, 16, 17],
-            }),
-            my_team_id: Some("team_1".into()),
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: Some("espn_42_2026".into()),
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let internal = AppState::convert_extension_state(&ext_payload);
-
-        assert_eq!(internal.picks.len(), 1);
-        assert_eq!(internal.picks[0].pick_number, 1);
-        assert_eq!(internal.picks[0].player_name, "Player One");
-
-        let nom = internal.current_nomination.as_ref().unwrap();
-        assert_eq!(nom.player_name, "Player Two");
-        assert_eq!(nom.current_bid, 10);
-        assert_eq!(nom.current_bidder, Some("Team 3".into()));
-    }
-
-    // -----------------------------------------------------------------------
-    // Tests: Premature nomination filtering
-    // -----------------------------------------------------------------------
-
-    #[test]
-    fn premature_nomination_filtered_no_bid_no_bidder_no_nominator() {
-        // A premature nomination has currentBid=0, no currentBidder, and
-        // empty nominatedBy (no bid history). This should be filtered out.
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "".into(),
-                player_name: "Michael King".into(),
-                position: "SP".into(),
-                nominated_by: "".into(),
-                current_bid: 0,
-                current_bidder: None,
-                time_remaining: Some(30),
-                eligible_slots: vec![],
-            }),
-            my_team_id: Some("team_1".into()),
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let internal = AppState::convert_extension_state(&ext_payload);
-        assert!(
-            internal.current_nomination.is_none(),
-            "Premature nomination (no bid, no bidder, no nominator) should be filtered out"
-        );
-    }
-
-    #[test]
-    fn confirmed_nomination_with_bid_passes_through() {
-        // A confirmed nomination has currentBid > 0. This should pass through.
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "".into(),
-                player_name: "Michael King".into(),
-                position: "SP".into(),
-                nominated_by: "Team 3".into(),
-                current_bid: 1,
-                current_bidder: None,
-                time_remaining: Some(30),
-                eligible_slots: vec![],
-            }),
-            my_team_id: Some("team_1".into()),
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let internal = AppState::convert_extension_state(&ext_payload);
-        assert!(
-            internal.current_nomination.is_some(),
-            "Confirmed nomination with bid > 0 should pass through"
-        );
-        assert_eq!(
-            internal.current_nomination.as_ref().unwrap().player_name,
-            "Michael King"
-        );
-    }
-
-    #[test]
-    fn nomination_with_nominator_but_zero_bid_passes_through() {
-        // Edge case: nominatedBy is set but currentBid is 0. This can happen
-        // if the initial nomination bid is $0 (unlikely but defensively valid).
-        // The presence of a nominator (from bid history) means it's confirmed.
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "".into(),
-                player_name: "Michael King".into(),
-                position: "SP".into(),
-                nominated_by: "Team 5".into(),
-                current_bid: 0,
-                current_bidder: None,
-                time_remaining: Some(30),
-                eligible_slots: vec![],
-            }),
-            my_team_id: Some("team_1".into()),
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let internal = AppState::convert_extension_state(&ext_payload);
-        assert!(
-            internal.current_nomination.is_some(),
-            "Nomination with a nominator should pass through even with bid=0"
-        );
-    }
-
-    #[test]
-    fn nomination_with_bidder_but_zero_bid_passes_through() {
-        // Edge case: currentBidder is set but currentBid is 0.
-        // The presence of a bidder means bidding has started.
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "".into(),
-                player_name: "Michael King".into(),
-                position: "SP".into(),
-                nominated_by: "".into(),
-                current_bid: 0,
-                current_bidder: Some("Team 7".into()),
-                time_remaining: Some(30),
-                eligible_slots: vec![],
-            }),
-            my_team_id: Some("team_1".into()),
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let internal = AppState::convert_extension_state(&ext_payload);
-        assert!(
-            internal.current_nomination.is_some(),
-            "Nomination with a current bidder should pass through even with bid=0"
-        );
-    }
-
-    #[test]
-    fn premature_nomination_with_empty_bidder_string_filtered() {
-        // currentBidder is Some("") — effectively empty. Should still be filtered.
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: Some(crate::protocol::NominationData {
-                player_id: "".into(),
-                player_name: "Michael King".into(),
-                position: "SP".into(),
-                nominated_by: "".into(),
-                current_bid: 0,
-                current_bidder: Some("".into()),
-                time_remaining: Some(30),
-                eligible_slots: vec![],
-            }),
-            my_team_id: Some("team_1".into()),
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
+        ws_tx
+            .send(WsEvent::Message(state_update.to_string()))
+            .await
+            .unwrap();
 
-        let internal = AppState::convert_extension_state(&ext_payload);
-        assert!(
-            internal.current_nomination.is_none(),
-            "Nomination with empty bidder string should be filtered like None"
-        );
-    }
+        // Should receive a StateSnapshot first (new picks trigger snapshot)
+        let update = ui_rx.recv().await.unwrap();
+        match update {
+            UiUpdate::StateSnapshot(snapshot) => {
+                assert_eq!(snapshot.pick_count, 1);
+                // H_Star should have been removed from available players
+                assert!(!snapshot.available_players.iter().any(|p| p.name == "H_Star"));
+                // Draft log should contain the pick
+                assert_eq!(snapshot.draft_log.len(), 1);
+                assert_eq!(snapshot.draft_log[0].player_name, "H_Star");
+            }
+            other => panic!("Expected StateSnapshot, got {:?}", other),
+        }
 
-    #[test]
-    fn null_nomination_remains_null() {
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: None,
-            my_team_id: Some("team_1".into()),
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
+        // Then receive the NominationUpdate
+        let update = ui_rx.recv().await.unwrap();
+        match update {
+            UiUpdate::NominationUpdate { info, .. } => {
+                assert_eq!(info.player_name, "H_Good");
+                assert_eq!(info.current_bid, 5);
+            }
+            other => panic!("Expected NominationUpdate, got {:?}", other),
+        }
 
-        let internal = AppState::convert_extension_state(&ext_payload);
-        assert!(
-            internal.current_nomination.is_none(),
-            "Null nomination should remain None"
-        );
+        // Clean up
+        cmd_tx.send(UserCommand::Quit).await.unwrap();
+        let _ = handle.await;
     }
 
     // -----------------------------------------------------------------------
@@ -2542,6 +1160,8 @@ mod tests {
             "Expected Disconnected from heartbeat timeout, got {:?}",
             update
         );
+        let update = ui_rx.recv().await.unwrap();
+        assert!(matches!(update, UiUpdate::LlmPaused(true)), "got {:?}", update);
 
         // Now send a new message (simulating extension coming back)
         let heartbeat = r#"{"type":"EXTENSION_HEARTBEAT","payload":{"timestamp":456}}"#;
@@ -2565,6 +1185,40 @@ mod tests {
         let _ = handle.await;
     }
 
+    #[tokio::test]
+    async fn snoozed_recalculation_resumes_after_quiet_period() {
+        tokio::time::pause();
+
+        let state = create_test_app_state();
+        let (_ws_tx, ws_rx) = mpsc::channel(16);
+        let (_llm_tx, llm_rx) = mpsc::channel(16);
+        let (cmd_tx, cmd_rx) = mpsc::channel(16);
+        let (ui_tx, mut ui_rx) = mpsc::channel(64);
+
+        let handle = tokio::spawn(run(ws_rx, llm_rx, cmd_rx, ui_tx, state));
+        drain_initial_snapshot(&mut ui_rx).await;
+
+        cmd_tx.send(UserCommand::SnoozeRecalc).await.unwrap();
+        match ui_rx.recv().await.unwrap() {
+            UiUpdate::StateSnapshot(snapshot) => assert_eq!(snapshot.recalc_snoozed, Some(0)),
+            other => panic!("expected StateSnapshot, got {:?}", other),
+        }
+
+        // Default recalc_snooze_secs is 10.
+        tokio::time::advance(Duration::from_secs(11)).await;
+        let update = tokio::time::timeout(Duration::from_secs(5), ui_rx.recv())
+            .await
+            .expect("Should receive UI update")
+            .expect("Channel should not be closed");
+        match update {
+            UiUpdate::StateSnapshot(snapshot) => assert_eq!(snapshot.recalc_snoozed, None),
+            other => panic!("expected StateSnapshot, got {:?}", other),
+        }
+
+        cmd_tx.send(UserCommand::Quit).await.unwrap();
+        let _ = handle.await;
+    }
+
     #[tokio::test]
     async fn clean_disconnect_does_not_reconnect_on_message() {
         // When the ws_server sends a proper Disconnected event (clean close),
@@ -2597,6 +1251,8 @@ mod tests {
             update,
             UiUpdate::ConnectionStatus(ConnectionStatus::Disconnected)
         ));
+        let update = ui_rx.recv().await.unwrap();
+        assert!(matches!(update, UiUpdate::LlmPaused(true)), "got {:?}", update);
 
         // Send a heartbeat (this should be processed without triggering
         // a reconnect, because last_ws_message_time was cleared to None
@@ -2627,792 +1283,62 @@ mod tests {
         let _ = handle.await;
     }
 
-    // -----------------------------------------------------------------------
-    // Tests: Roster snapshot correctness (issue: same player in every slot)
-    // -----------------------------------------------------------------------
-
-    #[test]
-    fn build_snapshot_roster_shows_only_my_team_players() {
-        let mut state = create_test_app_state();
-
-        // Record picks for different teams:
-        // Pick 1: H_Star -> Team 1 (my team)
-        // Pick 2: P_Ace -> Team 2 (other team)
-        // Pick 3: H_Good -> Team 1 (my team)
-        state.process_new_picks(vec![
-            DraftPick {
-                pick_number: 1,
-                team_id: "1".into(),
-                team_name: "Team 1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                price: 45,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-            DraftPick {
-                pick_number: 2,
-                team_id: "2".into(),
-                team_name: "Team 2".into(),
-                player_name: "P_Ace".into(),
-                position: "SP".into(),
-                price: 50,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-            DraftPick {
-                pick_number: 3,
-                team_id: "1".into(),
-                team_name: "Team 1".into(),
-                player_name: "H_Good".into(),
-                position: "2B".into(),
-                price: 30,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-        ]);
-
-        let snapshot = state.build_snapshot();
-
-        // My team (Team 1) should have exactly 2 filled roster slots
-        let filled_slots: Vec<_> = snapshot
-            .my_roster
-            .iter()
-            .filter(|s| s.player.is_some())
-            .collect();
-        assert_eq!(
-            filled_slots.len(),
-            2,
-            "Expected 2 filled slots for my team, got {}. Filled: {:?}",
-            filled_slots.len(),
-            filled_slots
-                .iter()
-                .map(|s| format!(
-                    "{}: {}",
-                    s.position.display_str(),
-                    s.player.as_ref().unwrap().name
-                ))
-                .collect::<Vec<_>>()
-        );
-
-        // Verify the correct players are in the correct slots
-        let player_names: Vec<&str> = filled_slots
-            .iter()
-            .map(|s| s.player.as_ref().unwrap().name.as_str())
-            .collect();
-        assert!(
-            player_names.contains(&"H_Star"),
-            "H_Star should be in my roster"
-        );
-        assert!(
-            player_names.contains(&"H_Good"),
-            "H_Good should be in my roster"
-        );
-        assert!(
-            !player_names.contains(&"P_Ace"),
-            "P_Ace should NOT be in my roster (Team 2 player)"
-        );
-
-        // Verify each filled slot has a DIFFERENT player
-        let unique_names: std::collections::HashSet<&str> =
-            player_names.iter().copied().collect();
-        assert_eq!(
-            unique_names.len(),
-            filled_slots.len(),
-            "Each filled slot should have a unique player name"
-        );
-    }
-
-    #[test]
-    fn build_snapshot_roster_correct_position_assignment() {
-        let mut state = create_test_app_state();
-
-        // Record picks for my team with different positions
-        state.process_new_picks(vec![
-            DraftPick {
-                pick_number: 1,
-                team_id: "1".into(),
-                team_name: "Team 1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                price: 45,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-            DraftPick {
-                pick_number: 2,
-                team_id: "1".into(),
-                team_name: "Team 1".into(),
-                player_name: "P_Ace".into(),
-                position: "SP".into(),
-                price: 50,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-        ]);
-
-        let snapshot = state.build_snapshot();
-
-        // Verify 1B slot has H_Star
-        let slot_1b = snapshot
-            .my_roster
-            .iter()
-            .find(|s| s.position == Position::FirstBase)
-            .expect("should have a 1B slot");
-        assert!(slot_1b.player.is_some(), "1B slot should be filled");
-        assert_eq!(slot_1b.player.as_ref().unwrap().name, "H_Star");
-
-        // Verify an SP slot has P_Ace
-        let sp_filled: Vec<_> = snapshot
-            .my_roster
-            .iter()
-            .filter(|s| s.position == Position::StartingPitcher && s.player.is_some())
-            .collect();
-        assert_eq!(sp_filled.len(), 1, "Should have exactly 1 filled SP slot");
-        assert_eq!(sp_filled[0].player.as_ref().unwrap().name, "P_Ace");
-
-        // Verify other slots are empty
-        let other_filled: Vec<_> = snapshot
-            .my_roster
-            .iter()
-            .filter(|s| {
-                s.player.is_some()
-                    && s.position != Position::FirstBase
-                    && s.position != Position::StartingPitcher
-            })
-            .collect();
-        assert!(
-            other_filled.is_empty(),
-            "No other slots should be filled, but found: {:?}",
-            other_filled
-                .iter()
-                .map(|s| format!(
-                    "{}: {}",
-                    s.position.display_str(),
-                    s.player.as_ref().unwrap().name
-                ))
-                .collect::<Vec<_>>()
-        );
-    }
-
-    /// Simulate the exact first-STATE_UPDATE scenario:
-    /// - Teams not registered yet
-    /// - Picks arrive before reconcile_budgets
-    /// - Then reconcile_budgets registers teams and replays pending picks
-    /// - Then set_my_team_by_id
-    /// - Then build_snapshot
-    #[test]
-    fn first_state_update_roster_correctness() {
-        let config = test_config();
-        let draft_state = DraftState::new(260, &test_roster_config());
-        // Note: NOT calling reconcile_budgets yet (simulates first update)
-        assert!(draft_state.teams.is_empty());
-
-        let mut available = test_players();
-        let test_roster = AppState::default_roster_config();
-        let registry = StatRegistry::from_league_config(&config.league)
-            .expect("test registry");
-        wyncast_baseball::valuation::recalculate_all(
-            &mut available,
-            &test_roster,
-            &config.league,
-            &config.strategy,
-            &draft_state,
-            &registry,
-        );
-        let db = Database::open(":memory:").expect("in-memory db");
-        let draft_id = Database::generate_draft_id();
-        let llm_client = LlmClient::Disabled;
-        let (llm_tx, _llm_rx) = mpsc::channel(16);
-
-        let mut state = AppState::new(
-            config,
-            draft_state,
-            available,
-            empty_projections(),
-            db,
-            draft_id,
-            llm_client,
-            llm_tx,
-            None,
-            AppMode::Draft,
-            test_onboarding_manager(),
-            Some(test_roster_config()),
-        );
-
-        // Step 1: process_new_picks while teams are empty
-        // (simulates what happens in handle_state_update before reconcile_budgets)
-        state.process_new_picks(vec![
-            DraftPick {
-                pick_number: 1,
-                team_id: "1".into(),
-                team_name: "Team Alpha".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                price: 45,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-            DraftPick {
-                pick_number: 2,
-                team_id: "2".into(),
-                team_name: "Team Beta".into(),
-                player_name: "P_Ace".into(),
-                position: "SP".into(),
-                price: 50,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-        ]);
-
-        // Teams are still empty after process_new_picks
-        assert!(state.draft_state.teams.is_empty());
-        // But picks are stored
-        assert_eq!(state.draft_state.picks.len(), 2);
-
-        // Step 2: reconcile_budgets registers teams (first call)
-        // This also calls replay_pending_picks
-        let budgets = vec![
-            wyncast_baseball::draft::state::TeamBudgetPayload {
-                team_id: "1".into(),
-                team_name: "Team Alpha".into(),
-                budget: 215, // 260 - 45
-            },
-            wyncast_baseball::draft::state::TeamBudgetPayload {
-                team_id: "2".into(),
-                team_name: "Team Beta".into(),
-                budget: 210, // 260 - 50
-            },
-        ];
-        let reconcile = state.draft_state.reconcile_budgets(&budgets);
-        assert!(reconcile.teams_registered);
-
-        // Step 3: set my team
-        state.draft_state.set_my_team_by_id("1");
-
-        // Step 4: build snapshot
-        let snapshot = state.build_snapshot();
-
-        // My team (Team Alpha) should have exactly 1 player (H_Star at 1B)
-        let filled: Vec<_> = snapshot
-            .my_roster
-            .iter()
-            .filter(|s| s.player.is_some())
-            .collect();
-        assert_eq!(
-            filled.len(),
-            1,
-            "Expected 1 filled slot for Team Alpha, got {}. Filled: {:?}",
-            filled.len(),
-            filled
-                .iter()
-                .map(|s| format!(
-                    "{}: {}",
-                    s.position.display_str(),
-                    s.player.as_ref().unwrap().name
-                ))
-                .collect::<Vec<_>>()
-        );
-        assert_eq!(filled[0].player.as_ref().unwrap().name, "H_Star");
-
-        // Team Beta should have P_Ace, not on my roster
-        let team_beta = state.draft_state.team("2").unwrap();
-        assert_eq!(team_beta.roster.filled_count(), 1);
-    }
-
-    /// Simulate multiple consecutive state updates (as happens in normal operation).
-    /// Each update sends the full pick list; only truly new picks should be processed.
-    #[test]
-    fn consecutive_state_updates_no_duplicate_roster_entries() {
-        let mut state = create_test_app_state();
-
-        // Build internal payloads as they would come from the extension
-        use wyncast_baseball::draft::state::{
-            compute_state_diff, PickPayload, StateUpdatePayload as InternalStatePayload,
-        };
-
-        // First update: 1 pick
-        let payload1 = InternalStatePayload {
-            picks: vec![PickPayload {
-                pick_number: 1,
-                team_id: "1".into(),
-                team_name: "Team 1".into(),
-                player_id: "p1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                price: 45,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            }],
-            current_nomination: None,
-            teams: vec![],
-            pick_count: Some(1),
-            total_picks: Some(260),
-        };
-
-        let diff1 = compute_state_diff(&None, &payload1);
-        assert_eq!(diff1.new_picks.len(), 1);
-        state.process_new_picks(diff1.new_picks);
-
-        let snapshot1 = state.build_snapshot();
-        let filled1: Vec<_> = snapshot1
-            .my_roster
-            .iter()
-            .filter(|s| s.player.is_some())
-            .collect();
-        assert_eq!(filled1.len(), 1, "After 1st update: 1 filled slot");
-        assert_eq!(filled1[0].player.as_ref().unwrap().name, "H_Star");
-
-        // Second update: same 1 pick (no changes)
-        let diff2 = compute_state_diff(&Some(payload1.clone()), &payload1);
-        assert!(diff2.new_picks.is_empty(), "No new picks on duplicate update");
-
-        // Third update: 2 picks (one new)
-        let payload3 = InternalStatePayload {
-            picks: vec![
-                PickPayload {
-                    pick_number: 1,
-                    team_id: "1".into(),
-                    team_name: "Team 1".into(),
-                    player_id: "p1".into(),
-                    player_name: "H_Star".into(),
-                    position: "1B".into(),
-                    price: 45,
-                    eligible_slots: vec![],
-            assigned_slot: None,
-                },
-                PickPayload {
-                    pick_number: 2,
-                    team_id: "2".into(),
-                    team_name: "Team 2".into(),
-                    player_id: "p2".into(),
-                    player_name: "P_Ace".into(),
-                    position: "SP".into(),
-                    price: 50,
-                    eligible_slots: vec![],
-            assigned_slot: None,
-                },
-            ],
-            current_nomination: None,
-            teams: vec![],
-            pick_count: Some(2),
-            total_picks: Some(260),
-        };
-
-        let diff3 = compute_state_diff(&Some(payload1.clone()), &payload3);
-        assert_eq!(diff3.new_picks.len(), 1, "Only 1 new pick on 3rd update");
-        assert_eq!(diff3.new_picks[0].player_name, "P_Ace");
-        state.process_new_picks(diff3.new_picks);
-
-        let snapshot3 = state.build_snapshot();
-        let filled3: Vec<_> = snapshot3
-            .my_roster
-            .iter()
-            .filter(|s| s.player.is_some())
-            .collect();
-        // Team 1 has H_Star; P_Ace went to Team 2
-        assert_eq!(filled3.len(), 1, "After 3rd update: still 1 filled slot for my team");
-        assert_eq!(filled3[0].player.as_ref().unwrap().name, "H_Star");
-
-        // Fourth update: 3 picks (one more for my team)
-        let payload4 = InternalStatePayload {
-            picks: vec![
-                PickPayload {
-                    pick_number: 1,
-                    team_id: "1".into(),
-                    team_name: "Team 1".into(),
-                    player_id: "p1".into(),
-                    player_name: "H_Star".into(),
-                    position: "1B".into(),
-                    price: 45,
-                    eligible_slots: vec![],
-            assigned_slot: None,
-                },
-                PickPayload {
-                    pick_number: 2,
-                    team_id: "2".into(),
-                    team_name: "Team 2".into(),
-                    player_id: "p2".into(),
-                    player_name: "P_Ace".into(),
-                    position: "SP".into(),
-                    price: 50,
-                    eligible_slots: vec![],
-            assigned_slot: None,
-                },
-                PickPayload {
-                    pick_number: 3,
-                    team_id: "1".into(),
-                    team_name: "Team 1".into(),
-                    player_id: "p3".into(),
-                    player_name: "H_Good".into(),
-                    position: "2B".into(),
-                    price: 30,
-                    eligible_slots: vec![],
-            assigned_slot: None,
-                },
-            ],
-            current_nomination: None,
-            teams: vec![],
-            pick_count: Some(3),
-            total_picks: Some(260),
-        };
-
-        let diff4 = compute_state_diff(&Some(payload3), &payload4);
-        assert_eq!(diff4.new_picks.len(), 1, "Only 1 new pick on 4th update");
-        assert_eq!(diff4.new_picks[0].player_name, "H_Good");
-        state.process_new_picks(diff4.new_picks);
-
-        let snapshot4 = state.build_snapshot();
-        let filled4: Vec<_> = snapshot4
-            .my_roster
-            .iter()
-            .filter(|s| s.player.is_some())
-            .collect();
-        assert_eq!(filled4.len(), 2, "After 4th update: 2 filled slots for my team");
-
-        // Each filled slot should have a DIFFERENT player
-        let names: Vec<&str> = filled4
-            .iter()
-            .map(|s| s.player.as_ref().unwrap().name.as_str())
-            .collect();
-        let unique: std::collections::HashSet<&str> = names.iter().copied().collect();
-        assert_eq!(
-            unique.len(),
-            2,
-            "Both slots should have different players, got: {:?}",
-            names
-        );
-        assert!(names.contains(&"H_Star"));
-        assert!(names.contains(&"H_Good"));
-    }
-
-    /// Test what happens when pick team_id doesn't match registered team_id
-    /// (as happens with DOM scraping where teamId = team name, not numeric ID).
-    /// The team_name fallback should correctly route picks.
-    #[test]
-    fn picks_with_espn_team_id_route_correctly() {
-        let mut state = create_test_app_state();
-
-        // Picks use ESPN numeric team IDs (as resolved by the extension)
-        state.process_new_picks(vec![
-            DraftPick {
-                pick_number: 1,
-                team_id: "1".into(),
-                team_name: "Team 1".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                price: 45,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-            DraftPick {
-                pick_number: 2,
-                team_id: "2".into(),
-                team_name: "Team 2".into(),
-                player_name: "P_Ace".into(),
-                position: "SP".into(),
-                price: 50,
-                espn_player_id: None,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            },
-        ]);
-
-        // Team 1 (my team) should have H_Star
-        let my_team = state.draft_state.my_team().unwrap();
-        assert_eq!(
-            my_team.roster.filled_count(),
-            1,
-            "Team 1 should have 1 player"
-        );
-        let filled: Vec<_> = my_team
-            .roster
-            .slots
-            .iter()
-            .filter(|s| s.player.is_some())
-            .collect();
-        assert_eq!(filled[0].player.as_ref().unwrap().name, "H_Star");
-
-        // Team 2 should have P_Ace
-        let team2 = state.draft_state.team("2").unwrap();
-        assert_eq!(
-            team2.roster.filled_count(),
-            1,
-            "Team 2 should have 1 player"
-        );
-    }
-
-    /// Test what happens when team_name in pick doesn't match any registered team.
-    /// The pick should be stored but NOT assigned to any team roster.
-    #[test]
-    fn unmatched_team_pick_not_assigned_to_any_roster() {
-        let mut state = create_test_app_state();
-
-        state.process_new_picks(vec![DraftPick {
-            pick_number: 1,
-            team_id: "Nonexistent Team".into(),
-            team_name: "Nonexistent Team".into(),
-            player_name: "H_Star".into(),
-            position: "1B".into(),
-            price: 45,
-            espn_player_id: None,
-            eligible_slots: vec![],
-            assigned_slot: None,
-        }]);
-
-        // Pick should be recorded
-        assert_eq!(state.draft_state.pick_count, 1);
-        assert_eq!(state.draft_state.picks.len(), 1);
-
-        // But no team should have any filled roster slots
-        for team in &state.draft_state.teams {
-            assert_eq!(
-                team.roster.filled_count(),
-                0,
-                "Team {} should have 0 players when pick team doesn't match",
-                team.team_name
-            );
-        }
-
-        // My roster should be empty
-        let snapshot = state.build_snapshot();
-        let filled: Vec<_> = snapshot
-            .my_roster
-            .iter()
-            .filter(|s| s.player.is_some())
-            .collect();
-        assert!(filled.is_empty(), "My roster should be empty");
-    }
-
-    // -----------------------------------------------------------------------
-    // Tests: ESPN draft ID detection
-    // -----------------------------------------------------------------------
-
-    #[tokio::test]
-    async fn first_espn_draft_id_is_stored() {
-        let mut state = create_test_app_state();
-        assert!(state.espn_draft_id.is_none());
-
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: None,
-            my_team_id: None,
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: Some("espn_12345_2026".into()),
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let (ui_tx, _ui_rx) = mpsc::channel(64);
-        ws_handler::handle_state_update(&mut state, ext_payload, &ui_tx).await;
-
-        // ESPN draft ID should now be stored in state
-        assert_eq!(state.espn_draft_id, Some("espn_12345_2026".into()));
-        // Internal draft_id should NOT change (same draft)
-        assert_eq!(state.draft_id, state.draft_id); // unchanged
-
-        // Should also be persisted in DB
-        let db_espn_id = state.db.get_espn_draft_id().unwrap();
-        assert_eq!(db_espn_id, Some("espn_12345_2026".into()));
-    }
-
-    #[tokio::test]
-    async fn same_espn_draft_id_does_not_start_new_session() {
-        let mut state = create_test_app_state();
-        let original_draft_id = state.draft_id.clone();
-
-        // Set up an existing ESPN draft ID
-        state.espn_draft_id = Some("espn_12345_2026".into());
-
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: None,
-            my_team_id: None,
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: Some("espn_12345_2026".into()),
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let (ui_tx, _ui_rx) = mpsc::channel(64);
-        ws_handler::handle_state_update(&mut state, ext_payload, &ui_tx).await;
-
-        // Draft ID should remain the same
-        assert_eq!(state.draft_id, original_draft_id);
-        assert_eq!(state.espn_draft_id, Some("espn_12345_2026".into()));
-    }
-
-    #[tokio::test]
-    async fn different_espn_draft_id_starts_new_session() {
-        let mut state = create_test_app_state();
-        // Use a known fixed draft_id so the generated one will differ
-        let original_draft_id = "test_original_draft_001".to_string();
-        state.draft_id = original_draft_id.clone();
-
-        // Simulate having a stored ESPN draft ID from a previous session
-        state.espn_draft_id = Some("espn_12345_2026".into());
-
-        // Extension now reports a different draft ID (different league/season)
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: None,
-            my_team_id: None,
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: Some("espn_67890_2026".into()),
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let (ui_tx, _ui_rx) = mpsc::channel(64);
-        ws_handler::handle_state_update(&mut state, ext_payload, &ui_tx).await;
-
-        // A new draft session should have been started
-        assert_ne!(state.draft_id, original_draft_id);
-        assert!(state.draft_id.starts_with("draft_"), "New draft ID should be generated: {}", state.draft_id);
-        assert_eq!(state.espn_draft_id, Some("espn_67890_2026".into()));
-
-        // New draft ID should be persisted
-        let db_draft_id = state.db.get_draft_id().unwrap();
-        assert_eq!(db_draft_id, Some(state.draft_id.clone()));
-
-        let db_espn_id = state.db.get_espn_draft_id().unwrap();
-        assert_eq!(db_espn_id, Some("espn_67890_2026".into()));
-
-        // In-memory draft state should be reset (no picks, no teams)
-        assert!(state.draft_state.picks.is_empty(), "Picks should be cleared on new draft");
-        assert!(state.draft_state.teams.is_empty(), "Teams should be cleared on new draft");
-    }
-
     #[tokio::test]
-    async fn null_espn_draft_id_does_not_trigger_new_session() {
+    async fn heartbeat_ack_echo_reports_round_trip_latency() {
+        tokio::time::pause();
         let mut state = create_test_app_state();
-        let original_draft_id = state.draft_id.clone();
-
-        // Simulate having a stored ESPN draft ID
-        state.espn_draft_id = Some("espn_12345_2026".into());
-
-        // Extension sends no draft ID (e.g., old extension version)
-        let ext_payload = crate::protocol::StateUpdatePayload {
-            picks: vec![],
-            current_nomination: None,
-            my_team_id: None,
-            teams: vec![],
-            pick_count: None,
-            total_picks: None,
-            draft_id: None,
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let (ui_tx, _ui_rx) = mpsc::channel(64);
-        ws_handler::handle_state_update(&mut state, ext_payload, &ui_tx).await;
+        let (out_tx, mut out_rx) = mpsc::channel(16);
+        state.ws_outbound_tx = Some(out_tx);
+        let (ui_tx, mut ui_rx) = mpsc::channel(16);
 
-        // Draft ID should remain unchanged
-        assert_eq!(state.draft_id, original_draft_id);
-        assert_eq!(state.espn_draft_id, Some("espn_12345_2026".into()));
+        // First heartbeat: nothing to measure yet, but it is acknowledged.
+        let first = r#"{"type":"EXTENSION_HEARTBEAT","payload":{"timestamp":1}}"#;
+        ws_handler::handle_ws_message(&mut state, first, &ui_tx).await;
+        assert!(ui_rx.try_recv().is_err());
+        let ack: serde_json::Value = serde_json::from_str(&out_rx.try_recv().unwrap()).unwrap();
+        assert_eq!(ack["type"], "HEARTBEAT_ACK");
+        let ack_ts = ack["timestamp"].as_u64().unwrap();
+
+        // The extension held the ack for 5s before echoing it with its next
+        // heartbeat; 120ms of the elapsed time was the round trip.
+        tokio::time::advance(std::time::Duration::from_millis(5120)).await;
+        let second = format!(
+            r#"{{"type":"EXTENSION_HEARTBEAT","payload":{{"timestamp":2,"ackTimestamp":{ack_ts},"ackHeldMs":5000}}}}"#
+        );
+        ws_handler::handle_ws_message(&mut state, &second, &ui_tx).await;
+        match ui_rx.try_recv().unwrap() {
+            UiUpdate::ExtensionLatency { rtt_ms } => assert_eq!(rtt_ms, 120),
+            other => panic!("expected ExtensionLatency, got {other:?}"),
+        }
+        assert!(out_rx.try_recv().is_ok(), "second heartbeat should be acknowledged too");
     }
 
     #[tokio::test]
-    async fn espn_draft_id_resilient_across_reconnects() {
+    async fn heartbeat_health_reaches_the_ui_when_it_changes() {
         let mut state = create_test_app_state();
+        let (ui_tx, mut ui_rx) = mpsc::channel(16);
 
-        // First connection: receive ESPN draft ID and process some picks
-        let ext_payload1 = crate::protocol::StateUpdatePayload {
-            picks: vec![crate::protocol::PickData {
-                pick_number: 1,
-                team_id: "1".into(),
-                team_name: "Team 1".into(),
-                player_id: "".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                price: 45,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            }],
-            current_nomination: None,
-            my_team_id: Some("1".into()),
-            teams: vec![
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("1".into()),
-                    team_name: "Team 1".into(),
-                    budget: 215,
-                },
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("2".into()),
-                    team_name: "Team 2".into(),
-                    budget: 260,
-                },
-            ],
-            pick_count: Some(1),
-            total_picks: Some(260),
-            draft_id: Some("espn_12345_2026".into()),
-            source: Some("test".into()),
-            ..Default::default()
-        };
-
-        let (ui_tx, _ui_rx) = mpsc::channel(64);
-        ws_handler::handle_state_update(&mut state, ext_payload1, &ui_tx).await;
-
-        let draft_id_after_first = state.draft_id.clone();
-        assert_eq!(state.espn_draft_id, Some("espn_12345_2026".into()));
-
-        // Simulate disconnect and reconnect -- previous_extension_state is
-        // cleared to None (simulating a fresh connection)
-        state.previous_extension_state = None;
-
-        // Second connection: same ESPN draft ID, same picks visible
-        let ext_payload2 = crate::protocol::StateUpdatePayload {
-            picks: vec![crate::protocol::PickData {
-                pick_number: 1,
-                team_id: "1".into(),
-                team_name: "Team 1".into(),
-                player_id: "".into(),
-                player_name: "H_Star".into(),
-                position: "1B".into(),
-                price: 45,
-                eligible_slots: vec![],
-            assigned_slot: None,
-            }],
-            current_nomination: None,
-            my_team_id: Some("1".into()),
-            teams: vec![
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("1".into()),
-                    team_name: "Team 1".into(),
-                    budget: 215,
-                },
-                crate::protocol::TeamBudgetData {
-                    team_id: Some("2".into()),
-                    team_name: "Team 2".into(),
-                    budget: 260,
-                },
-            ],
-            pick_count: Some(1),
-            total_picks: Some(260),
-            draft_id: Some("espn_12345_2026".into()),
-            source: Some("test".into()),
-            ..Default::default()
+        let heartbeat = r#"{"type":"EXTENSION_HEARTBEAT","payload":{"timestamp":1,"updatesPerMinute":12,"picksAgeMs":4000,"jsErrors":1}}"#;
+        ws_handler::handle_ws_message(&mut state, heartbeat, &ui_tx).await;
+        let expected = ExtensionHealth {
+            updates_per_minute: 12,
+            picks_age_ms: Some(4000),
+            nomination_age_ms: None,
+            js_errors: 1,
         };
+        match ui_rx.try_recv().unwrap() {
+            UiUpdate::ExtensionHealth(health) => assert_eq!(health, expected),
+            other => panic!("expected ExtensionHealth, got {other:?}"),
+        }
+        assert_eq!(state.extension_health, Some(expected));
 
-        ws_handler::handle_state_update(&mut state, ext_payload2, &ui_tx).await;
-
-        // Draft ID should NOT change across reconnect with same ESPN ID
-        assert_eq!(state.draft_id, draft_id_after_first);
-        assert_eq!(state.espn_draft_id, Some("espn_12345_2026".into()));
+        // The same health again is not re-sent, and older extensions that
+        // report none leave it alone.
+        ws_handler::handle_ws_message(&mut state, heartbeat, &ui_tx).await;
+        let bare = r#"{"type":"EXTENSION_HEARTBEAT","payload":{"timestamp":2}}"#;
+        ws_handler::handle_ws_message(&mut state, bare, &ui_tx).await;
+        assert!(ui_rx.try_recv().is_err());
+        assert!(state.extension_health.is_some());
     }
 
     // -----------------------------------------------------------------------
@@ -3420,7 +1346,7 @@ mod tests {
     // -----------------------------------------------------------------------
 
     #[tokio::test]
-    async fn onboarding_skip_from_llm_setup_shows_strategy_setup() {
+    async fn onboarding_skip_from_llm_setup_shows_league_setup() {
         use crate::onboarding::OnboardingStep;
 
         let mut state = create_test_app_state();
@@ -3436,23 +1362,29 @@ mod tests {
         )
         .await;
 
-        // AppState should now show StrategySetup (not Draft)
+        // AppState should now show LeagueSetup (not Draft)
         assert_eq!(
             state.app_mode,
-            AppMode::Onboarding(OnboardingStep::StrategySetup)
+            AppMode::Onboarding(OnboardingStep::LeagueSetup)
         );
 
         // Persisted step should remain at LlmSetup (not advanced)
         assert_eq!(state.onboarding_progress.current_step, OnboardingStep::LlmSetup);
 
-        // UI channel should have received ModeChanged(Onboarding(StrategySetup))
+        // The form is filled from the config, then the mode changes
+        let update = ui_rx.recv().await.expect("expected LeagueSync update");
+        assert!(
+            matches!(update, UiUpdate::OnboardingUpdate(OnboardingUpdate::LeagueSync(_))),
+            "first update should be LeagueSync, got {:?}",
+            update,
+        );
         let update = ui_rx.recv().await.expect("expected ModeChanged update");
         assert!(
             matches!(
                 update,
-                UiUpdate::ModeChanged(AppMode::Onboarding(OnboardingStep::StrategySetup))
+                UiUpdate::ModeChanged(AppMode::Onboarding(OnboardingStep::LeagueSetup))
             ),
-            "first update should be ModeChanged(Onboarding(StrategySetup)), got {:?}",
+            "second update should be ModeChanged(Onboarding(LeagueSetup)), got {:?}",
             update,
         );
     }
@@ -3560,10 +1492,10 @@ mod tests {
         )
         .await;
 
-        // Should advance to StrategySetup
+        // Should advance to LeagueSetup
         assert_eq!(
             state.app_mode,
-            AppMode::Onboarding(OnboardingStep::StrategySetup)
+            AppMode::Onboarding(OnboardingStep::LeagueSetup)
         );
     }
 
@@ -3586,25 +1518,13 @@ mod tests {
         )
         .await;
 
-        // Should advance to StrategySetup
+        // Should advance to LeagueSetup
         assert_eq!(
             state.app_mode,
-            AppMode::Onboarding(OnboardingStep::StrategySetup)
+            AppMode::Onboarding(OnboardingStep::LeagueSetup)
         );
     }
 
-    // -----------------------------------------------------------------------
-    // Tests: build_snapshot includes llm_configured (Task 7)
-    // -----------------------------------------------------------------------
-
-    #[test]
-    fn build_snapshot_llm_configured_false_when_disabled() {
-        let state = create_test_app_state();
-        // create_test_app_state uses LlmClient::Disabled
-        let snap = state.build_snapshot();
-        assert!(!snap.llm_configured);
-    }
-
     #[tokio::test]
     async fn exit_settings_transitions_to_draft_mode() {
         use crate::protocol::SettingsSection;
@@ -3727,7 +1647,7 @@ mod tests {
         let cats = crate::onboarding::strategy_config::default_categories();
         let json = r#"{"hitting_budget_pct": 200, "category_weights": {"SV": -1.0, "BB": 7.0}}"#;
         let (pct, weights, _overview) = onboarding_handler::parse_strategy_json(json, &cats).unwrap();
-        assert_eq!(pct, 100); // clamped to 100
+        assert_eq!(pct, 99); // clamped to 99
         assert!((weights.get_by_name("SV").unwrap() - 0.0).abs() < f32::EPSILON); // clamped to 0.0
         assert!((weights.get_by_name("BB").unwrap() - 5.0).abs() < f32::EPSILON); // clamped to 5.0
     }
@@ -3812,21 +1732,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn reload_llm_client_updates_client() {
-        let mut state = create_test_app_state();
-
-        // Initially disabled (no API key in test config)
-        assert!(matches!(&*state.llm_client, LlmClient::Disabled));
-
-        // Set an API key and reload
-        state.config.credentials.anthropic_api_key = Some("sk-ant-test-key".to_string());
-        state.reload_llm_client();
-
-        // Now the client should be Active
-        assert!(matches!(&*state.llm_client, LlmClient::Active(_)));
-    }
-
     #[tokio::test]
     async fn settings_save_strategy_updates_config_stays_in_settings() {
         use crate::protocol::SettingsSection;
@@ -3877,6 +1782,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "http")]
     #[tokio::test]
     async fn settings_set_api_key_reloads_llm_client() {
         use crate::protocol::SettingsSection;
@@ -0,0 +1,499 @@
+// Draft recap notification: once every roster slot is filled, the recap is
+// posted to a Discord or Slack webhook and/or written as an email-ready HTML
+// file, as configured in the `[notify]` section of strategy.toml. The webhook
+// URL is a credential and lives in credentials.toml.
+//
+// Players the engine gave a verdict on while they were on the block are
+// graded against it: how many sold at or under the model's max bid, and the
+// biggest overpays. The HTML page also lists the strategy settings that
+// changed since the previous draft, from the session stamps in the DB, so a
+// change can be read against how the draft went.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde_json::{json, Value};
+use wyncast_baseball::draft::state::DraftState;
+use wyncast_core::config::{Config, WebhookKind};
+use wyncast_core::nomination::NominationVerdict;
+use wyncast_core::stamp::{SessionStamp, SettingChange};
+
+/// Discord rejects messages longer than this.
+pub(super) const DISCORD_MESSAGE_LIMIT: usize = 2000;
+
+/// Most expensive picks listed in the recap.
+const TOP_BUYS: usize = 5;
+
+/// Biggest overpays against the model's max listed in the recap text.
+const MODEL_MISSES: usize = 3;
+
+/// How long to wait for the webhook before giving up.
+#[cfg(feature = "http")]
+const WEBHOOK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// One pick as it appears in the recap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecapPick {
+    pub player: String,
+    pub position: String,
+    pub team: String,
+    pub price: u32,
+}
+
+/// A sold player the model gave a verdict on, against what they went for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelCall {
+    pub player: String,
+    pub team: String,
+    pub price: u32,
+    pub verdict: String,
+    pub max_bid: u32,
+}
+
+impl ModelCall {
+    /// Dollars paid over the model's max; 0 at or under it.
+    pub fn over(&self) -> u32 {
+        self.price.saturating_sub(self.max_bid)
+    }
+}
+
+/// The strategy settings this draft ran with, against the previous draft's.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsDiff {
+    pub previous_draft: String,
+    /// When the previous draft's last session started (RFC 3339).
+    pub previous_recorded_at: String,
+    /// Empty when nothing changed.
+    pub changes: Vec<SettingChange>,
+}
+
+impl SettingsDiff {
+    /// `current`'s settings against `previous`'s. `None` when `previous`
+    /// predates settings being kept.
+    pub fn between(current: &SessionStamp, previous: &SessionStamp) -> Option<Self> {
+        if previous.settings.is_empty() {
+            return None;
+        }
+        Some(SettingsDiff {
+            previous_draft: previous.draft_id.clone(),
+            previous_recorded_at: previous.recorded_at.clone(),
+            changes: current.settings_changes(previous),
+        })
+    }
+}
+
+/// One team's draft: what it spent and who it bought, in draft order.
+#[derive(Debug, Clone)]
+pub struct TeamRecap {
+    pub name: String,
+    pub spent: u32,
+    pub left: u32,
+    pub is_mine: bool,
+    pub picks: Vec<RecapPick>,
+}
+
+/// The finished draft, summarized for the league.
+#[derive(Debug, Clone)]
+pub struct Recap {
+    pub league: String,
+    pub total_spent: u32,
+    pub pick_count: usize,
+    pub top_buys: Vec<RecapPick>,
+    pub teams: Vec<TeamRecap>,
+    /// The model's verdicts on the players sold, in draft order.
+    pub model_calls: Vec<ModelCall>,
+    /// The session stamp: the model version and inputs behind the advice.
+    /// Only in the HTML page; the league has no use for it.
+    pub stamp: Option<String>,
+    /// Strategy setting changes since the previous draft. Only in the HTML
+    /// page, like the stamp.
+    pub settings_diff: Option<SettingsDiff>,
+}
+
+impl Recap {
+    pub fn build(draft_state: &DraftState, league: &str, verdicts: &HashMap<String, NominationVerdict>) -> Self {
+        let my_team_id = draft_state.my_team().map(|t| t.team_id.as_str());
+        let pick = |p: &wyncast_baseball::draft::pick::DraftPick| RecapPick {
+            player: p.player_name.clone(),
+            position: p.position.clone(),
+            team: p.team_name.clone(),
+            price: p.price,
+        };
+
+        let teams = draft_state
+            .teams
+            .iter()
+            .map(|t| TeamRecap {
+                name: t.team_name.clone(),
+                spent: t.budget_spent,
+                left: t.budget_remaining,
+                is_mine: Some(t.team_id.as_str()) == my_team_id,
+                picks: draft_state
+                    .picks
+                    .iter()
+                    .filter(|p| p.team_id == t.team_id)
+                    .map(pick)
+                    .collect(),
+            })
+            .collect();
+
+        // Stable sort keeps draft order among equal prices.
+        let mut top_buys: Vec<RecapPick> = draft_state.picks.iter().map(pick).collect();
+        top_buys.sort_by_key(|p| std::cmp::Reverse(p.price));
+        top_buys.truncate(TOP_BUYS);
+
+        let model_calls = draft_state
+            .picks
+            .iter()
+            .filter_map(|p| {
+                let verdict = verdicts.get(&p.player_name)?;
+                Some(ModelCall {
+                    player: p.player_name.clone(),
+                    team: p.team_name.clone(),
+                    price: p.price,
+                    verdict: verdict.verdict.clone(),
+                    max_bid: verdict.max_bid,
+                })
+            })
+            .collect();
+
+        Recap {
+            league: league.to_string(),
+            total_spent: draft_state.picks.iter().map(|p| p.price).sum(),
+            pick_count: draft_state.picks.len(),
+            top_buys,
+            teams,
+            model_calls,
+            stamp: None,
+            settings_diff: None,
+        }
+    }
+
+    /// "n of m sold at or under the model's max", or `None` without verdicts.
+    fn model_summary(&self) -> Option<String> {
+        if self.model_calls.is_empty() {
+            return None;
+        }
+        let within = self.model_calls.iter().filter(|c| c.over() == 0).count();
+        Some(format!(
+            "{} of {} sold at or under the model's max",
+            within,
+            self.model_calls.len()
+        ))
+    }
+
+    /// Markdown-flavored text understood by both Discord and Slack.
+    pub fn text(&self) -> String {
+        let mut out = format!(
+            "*{} draft recap*\n{} picks, ${} spent\n\n*Top buys*\n",
+            self.league, self.pick_count, self.total_spent
+        );
+        for (i, p) in self.top_buys.iter().enumerate() {
+            let _ = writeln!(out, "{}. {} ({}) to {}: ${}", i + 1, p.player, p.position, p.team, p.price);
+        }
+        if let Some(summary) = self.model_summary() {
+            let _ = writeln!(out, "\n*Model calls*\n{summary}");
+            let mut misses: Vec<&ModelCall> = self.model_calls.iter().filter(|c| c.over() > 0).collect();
+            misses.sort_by_key(|c| std::cmp::Reverse(c.over()));
+            for c in misses.into_iter().take(MODEL_MISSES) {
+                let _ = writeln!(
+                    out,
+                    "{}: model said max ${}, went for ${} to {}",
+                    c.player, c.max_bid, c.price, c.team
+                );
+            }
+        }
+        out.push_str("\n*Teams*\n");
+        for team in &self.teams {
+            let _ = write!(out, "{}: ${} spent, ${} left", team.name, team.spent, team.left);
+            if let Some(best) = team.picks.iter().max_by_key(|p| p.price) {
+                let _ = write!(out, ", top buy {} ${}", best.player, best.price);
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// A standalone HTML page with every team's full draft, ready to paste
+    /// into an email.
+    pub fn html(&self) -> String {
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>{0} draft recap</title></head>\n\
+             <body style=\"font-family: sans-serif\">\n<h1>{0} draft recap</h1>\n<p>{1} picks, ${2} spent</p>\n",
+            escape(&self.league),
+            self.pick_count,
+            self.total_spent
+        );
+        out.push_str("<h2>Top buys</h2>\n<ol>\n");
+        for p in &self.top_buys {
+            let _ = writeln!(
+                out,
+                "<li>{} ({}) to {}: ${}</li>",
+                escape(&p.player),
+                escape(&p.position),
+                escape(&p.team),
+                p.price
+            );
+        }
+        out.push_str("</ol>\n");
+        if let Some(summary) = self.model_summary() {
+            let _ = writeln!(
+                out,
+                "<h2>Model calls</h2>\n<p>{summary}</p>\n<table>\n<tr><th align=\"left\">Player</th><th align=\"left\">Verdict</th><th align=\"right\">Max</th><th align=\"right\">Price</th><th align=\"left\">Team</th></tr>"
+            );
+            for c in &self.model_calls {
+                let _ = writeln!(
+                    out,
+                    "<tr><td>{}</td><td>{}</td><td align=\"right\">${}</td><td align=\"right\">${}</td><td>{}</td></tr>",
+                    escape(&c.player),
+                    escape(&c.verdict),
+                    c.max_bid,
+                    c.price,
+                    escape(&c.team)
+                );
+            }
+            out.push_str("</table>\n");
+        }
+        for team in &self.teams {
+            let mine = if team.is_mine { " (my team)" } else { "" };
+            let _ = writeln!(
+                out,
+                "<h2>{}{}</h2>\n<p>${} spent, ${} left</p>\n<table>\n<tr><th align=\"left\">Player</th><th align=\"left\">Pos</th><th align=\"right\">Price</th></tr>",
+                escape(&team.name),
+                mine,
+                team.spent,
+                team.left
+            );
+            for p in &team.picks {
+                let _ = writeln!(
+                    out,
+                    "<tr><td>{}</td><td>{}</td><td align=\"right\">${}</td></tr>",
+                    escape(&p.player),
+                    escape(&p.position),
+                    p.price
+                );
+            }
+            out.push_str("</table>\n");
+        }
+        if let Some(diff) = &self.settings_diff {
+            let date = diff.previous_recorded_at.get(..10).unwrap_or(&diff.previous_recorded_at);
+            let _ = writeln!(
+                out,
+                "<h2>Settings vs. previous draft</h2>\n<p>Compared with {} ({})</p>",
+                escape(&diff.previous_draft),
+                escape(date)
+            );
+            if diff.changes.is_empty() {
+                out.push_str("<p>No strategy settings changed.</p>\n");
+            } else {
+                out.push_str(
+                    "<table>\n<tr><th align=\"left\">Setting</th><th align=\"left\">Before</th><th align=\"left\">Now</th></tr>\n",
+                );
+                for change in &diff.changes {
+                    let _ = writeln!(
+                        out,
+                        "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                        escape(&change.key),
+                        escape(change.previous.as_deref().unwrap_or("unset")),
+                        escape(change.current.as_deref().unwrap_or("unset"))
+                    );
+                }
+                out.push_str("</table>\n");
+            }
+        }
+        if let Some(stamp) = &self.stamp {
+            let _ = writeln!(out, "<p><small>Model: {}</small></p>", escape(stamp));
+        }
+        out.push_str("</body></html>\n");
+        out
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Cut `text` to at most `limit` characters, marking the cut.
+pub(super) fn truncate(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(limit - 1).collect();
+    cut.push('\u{2026}');
+    cut
+}
+
+/// The webhook body for `kind`.
+pub fn webhook_payload(kind: WebhookKind, recap: &Recap) -> Value {
+    let text = recap.text();
+    match kind {
+        WebhookKind::Discord => json!({ "content": truncate(&text, DISCORD_MESSAGE_LIMIT) }),
+        WebhookKind::Slack => json!({ "text": text }),
+    }
+}
+
+/// Write the HTML recap to a timestamped file in `dir` and return its path.
+pub fn write_html(recap: &Recap, dir: &Path) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create {}", dir.display()))?;
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let path = dir.join(format!("draft-recap-{stamp}.html"));
+    std::fs::write(&path, recap.html())
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(feature = "http")]
+async fn post(url: &str, payload: &Value) -> anyhow::Result<()> {
+    let resp = reqwest::Client::new()
+        .post(url)
+        .timeout(WEBHOOK_TIMEOUT)
+        .json(payload)
+        .send()
+        .await
+        .context("failed to reach the recap webhook")?;
+    let status = resp.status();
+    if !status.is_success() {
+        anyhow::bail!("recap webhook returned {status}");
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "http"))]
+async fn post(_url: &str, _payload: &Value) -> anyhow::Result<()> {
+    anyhow::bail!("this build cannot post to webhooks (build with the `http` feature)")
+}
+
+/// Deliver the recap everywhere `config` asks for. Returns a one-line
+/// description of what was done, or of the first failure.
+pub async fn send(recap: &Recap, config: &Config, html_dir: anyhow::Result<PathBuf>) -> anyhow::Result<String> {
+    let mut done = Vec::new();
+    if let (Some(kind), Some(url)) = (config.notify.webhook, &config.credentials.recap_webhook_url) {
+        post(url, &webhook_payload(kind, recap)).await?;
+        done.push(format!("posted to {kind:?}"));
+    }
+    if config.notify.html {
+        let path = write_html(recap, &html_dir?)?;
+        done.push(format!("written to {}", path.display()));
+    }
+    Ok(done.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wyncast_baseball::draft::state::TeamBudgetPayload;
+    use wyncast_baseball::test_utils::test_pick;
+
+    fn drafted() -> DraftState {
+        let mut roster = HashMap::new();
+        roster.insert("1B".to_string(), 2);
+        let mut state = DraftState::new(260, &roster);
+        state.reconcile_budgets(&[
+            TeamBudgetPayload { team_id: "1".into(), team_name: "Team 1".into(), budget: 260 },
+            TeamBudgetPayload { team_id: "2".into(), team_name: "Team 2".into(), budget: 260 },
+        ]);
+        state.set_my_team_by_id("2");
+        for (team, player, price) in [(1, "Cheap", 3), (2, "Star", 60), (1, "A&B <Jr>", 25), (2, "Mid", 25)] {
+            state.record_pick(test_pick(player).team(team).price(price).build());
+        }
+        state
+    }
+
+    #[test]
+    fn build_summarizes_teams_and_top_buys() {
+        let recap = Recap::build(&drafted(), "Test League", &HashMap::new());
+        assert_eq!(recap.pick_count, 4);
+        assert_eq!(recap.total_spent, 113);
+        let top: Vec<&str> = recap.top_buys.iter().map(|p| p.player.as_str()).collect();
+        assert_eq!(top, vec!["Star", "A&B <Jr>", "Mid", "Cheap"]);
+        assert_eq!(recap.teams[0].spent, 28);
+        assert_eq!(recap.teams[0].picks.len(), 2);
+        assert!(recap.teams[1].is_mine);
+        assert!(recap.text().contains("Team 2: $85 spent, $175 left, top buy Star $60"));
+    }
+
+    #[test]
+    fn model_calls_grade_the_verdicts_of_sold_players() {
+        let verdicts: HashMap<String, NominationVerdict> = [("Star", 50), ("Mid", 18), ("Cheap", 5), ("Unsold", 9)]
+            .into_iter()
+            .map(|(player, max_bid)| {
+                let verdict = NominationVerdict {
+                    player_name: player.into(),
+                    verdict: "CONDITIONAL".into(),
+                    max_bid,
+                };
+                (player.to_string(), verdict)
+            })
+            .collect();
+        let recap = Recap::build(&drafted(), "Test League", &verdicts);
+
+        let players: Vec<&str> = recap.model_calls.iter().map(|c| c.player.as_str()).collect();
+        assert_eq!(players, vec!["Cheap", "Star", "Mid"]);
+        let text = recap.text();
+        assert!(text.contains("1 of 3 sold at or under the model's max"));
+        assert!(text.contains("Star: model said max $50, went for $60 to Team 2\nMid: model said max $18, went for $25 to Team 2"));
+        assert!(recap.html().contains("<td>Mid</td><td>CONDITIONAL</td><td align=\"right\">$18</td>"));
+
+        let ungraded = Recap::build(&drafted(), "Test League", &HashMap::new());
+        assert!(!ungraded.text().contains("Model calls"));
+    }
+
+    #[test]
+    fn html_escapes_names() {
+        let html = Recap::build(&drafted(), "Test League", &HashMap::new()).html();
+        assert!(html.contains("A&amp;B &lt;Jr&gt;"));
+        assert!(html.contains("Team 2 (my team)"));
+    }
+
+    #[test]
+    fn the_stamp_is_in_the_page_but_not_the_post() {
+        let mut recap = Recap::build(&drafted(), "Test League", &HashMap::new());
+        assert!(!recap.html().contains("Model:"));
+        recap.stamp = Some("wyncast 0.1.0, config 00ff00ff00ff00ff".into());
+        assert!(recap.html().contains("<p><small>Model: wyncast 0.1.0, config 00ff00ff00ff00ff</small></p>"));
+        assert!(!recap.text().contains("wyncast 0.1.0"));
+    }
+
+    #[test]
+    fn the_page_lists_setting_changes_since_the_previous_draft() {
+        let mut config = Config::default();
+        let previous = SessionStamp { recorded_at: "2026-03-01T19:00:00.000Z".into(), ..SessionStamp::new("draft_1", &config) };
+        config.strategy.hitting_budget_fraction = 0.7;
+        let current = SessionStamp::new("draft_2", &config);
+
+        let mut recap = Recap::build(&drafted(), "Test League", &HashMap::new());
+        recap.settings_diff = SettingsDiff::between(&current, &previous);
+        let html = recap.html();
+        assert!(html.contains("<p>Compared with draft_1 (2026-03-01)</p>"), "{html}");
+        assert!(html.contains("<tr><td>hitting_budget_fraction</td><td>0.65</td><td>0.7</td></tr>"), "{html}");
+        assert!(!recap.text().contains("hitting_budget_fraction"));
+
+        recap.settings_diff = SettingsDiff::between(&previous, &previous);
+        assert!(recap.html().contains("No strategy settings changed."));
+        let unkept = SessionStamp { settings: Default::default(), ..previous.clone() };
+        assert_eq!(SettingsDiff::between(&current, &unkept), None);
+    }
+
+    #[test]
+    fn discord_payload_respects_message_limit() {
+        let mut recap = Recap::build(&drafted(), "Test League", &HashMap::new());
+        recap.league = "x".repeat(3000);
+        let content = webhook_payload(WebhookKind::Discord, &recap)["content"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        assert_eq!(content.chars().count(), DISCORD_MESSAGE_LIMIT);
+        assert!(content.ends_with('\u{2026}'));
+
+        let slack = webhook_payload(WebhookKind::Slack, &recap);
+        assert!(slack["text"].as_str().unwrap().len() > DISCORD_MESSAGE_LIMIT);
+    }
+}
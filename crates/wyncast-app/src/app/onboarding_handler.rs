@@ -5,8 +5,10 @@ use tokio::sync::mpsc;
 use tracing::{info, warn};
 
 use wyncast_core::config::Config;
+use wyncast_core::stats::{CategoryValues, StatRegistry};
 use wyncast_llm::client::LlmClient;
 use wyncast_baseball::llm::prompt;
+use crate::onboarding::league_setup::LeagueSetup;
 use crate::onboarding::OnboardingStep;
 use crate::protocol::{
     AppMode, OnboardingAction, OnboardingUpdate, UiUpdate,
@@ -66,14 +68,13 @@ pub(super) async fn handle_onboarding_action(
 
             if api_key.is_empty() {
                 state.connection_test_result.store(CONNECTION_TEST_FAILED, Ordering::Relaxed);
-                let _ = ui_tx
-                    .send(UiUpdate::OnboardingUpdate(
-                        OnboardingUpdate::ConnectionTestResult {
-                            success: false,
-                            message: "No API key entered".to_string(),
-                        },
-                    ))
-                    .await;
+                let update = UiUpdate::OnboardingUpdate(
+                    OnboardingUpdate::ConnectionTestResult {
+                        success: false,
+                        message: "No API key entered".to_string(),
+                    },
+                );
+                state.ui_health.send(ui_tx, update).await;
                 return;
             }
 
@@ -82,6 +83,7 @@ pub(super) async fn handle_onboarding_action(
             let generation = state.connection_test_generation.fetch_add(1, Ordering::Relaxed) + 1;
 
             let tx = ui_tx.clone();
+            let health = Arc::clone(&state.ui_health);
             let tracker = Arc::clone(&state.connection_test_result);
             let gen_tracker = Arc::clone(&state.connection_test_generation);
             tokio::spawn(async move {
@@ -95,17 +97,16 @@ pub(super) async fn handle_onboarding_action(
                         Ordering::Relaxed,
                     );
                 }
-                let _ = tx
-                    .send(UiUpdate::OnboardingUpdate(
-                        OnboardingUpdate::ConnectionTestResult {
-                            success,
-                            message: match &result {
-                                Ok(msg) => msg.clone(),
-                                Err(msg) => msg.clone(),
-                            },
+                let update = UiUpdate::OnboardingUpdate(
+                    OnboardingUpdate::ConnectionTestResult {
+                        success,
+                        message: match &result {
+                            Ok(msg) => msg.clone(),
+                            Err(msg) => msg.clone(),
                         },
-                    ))
-                    .await;
+                    },
+                );
+                health.send(&tx, update).await;
             });
         }
         OnboardingAction::GoNext => {
@@ -115,14 +116,13 @@ pub(super) async fn handle_onboarding_action(
                     let test_val = state.connection_test_result.load(Ordering::Relaxed);
                     if test_val == CONNECTION_TEST_FAILED {
                         // Test was run and failed — send error back to TUI
-                        let _ = ui_tx
-                            .send(UiUpdate::OnboardingUpdate(
-                                OnboardingUpdate::ConnectionTestResult {
-                                    success: false,
-                                    message: "Connection test failed — fix the API key or skip to proceed".to_string(),
-                                },
-                            ))
-                            .await;
+                        let update = UiUpdate::OnboardingUpdate(
+                            OnboardingUpdate::ConnectionTestResult {
+                                success: false,
+                                message: "Connection test failed — fix the API key or skip to proceed".to_string(),
+                            },
+                        );
+                        state.ui_health.send(ui_tx, update).await;
                         return;
                     }
 
@@ -138,21 +138,11 @@ pub(super) async fn handle_onboarding_action(
                     state.reload_llm_client();
 
                     // Advance to next step
-                    state.onboarding_progress.current_step = OnboardingStep::StrategySetup;
-                    if let Err(e) = state
-                        .onboarding_manager
-                        .save_progress(&state.onboarding_progress)
-                    {
-                        warn!("Failed to save onboarding progress: {}", e);
-                    }
-
-                    state.app_mode =
-                        AppMode::Onboarding(OnboardingStep::StrategySetup);
-                    let _ = ui_tx
-                        .send(UiUpdate::ModeChanged(AppMode::Onboarding(
-                            OnboardingStep::StrategySetup,
-                        )))
-                        .await;
+                    enter_league_setup(state, ui_tx).await;
+                }
+                AppMode::Onboarding(OnboardingStep::LeagueSetup) => {
+                    // Keep the league settings already on disk.
+                    enter_strategy_setup(state, ui_tx).await;
                 }
                 AppMode::Onboarding(OnboardingStep::StrategySetup) => {
                     // Mark onboarding as complete
@@ -169,20 +159,16 @@ pub(super) async fn handle_onboarding_action(
                     state.llm_client = Arc::new(LlmClient::from_config(&state.config));
 
                     state.app_mode = AppMode::Draft;
-                    let _ = ui_tx.send(UiUpdate::ModeChanged(AppMode::Draft)).await;
+                    state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Draft)).await;
                     let snapshot = state.build_snapshot();
-                    let _ = ui_tx
-                        .send(UiUpdate::StateSnapshot(Box::new(snapshot)))
-                        .await;
+                    state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
                 }
                 AppMode::Onboarding(OnboardingStep::Complete) => {
                     // Already complete, go to draft
                     state.app_mode = AppMode::Draft;
-                    let _ = ui_tx.send(UiUpdate::ModeChanged(AppMode::Draft)).await;
+                    state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Draft)).await;
                     let snapshot = state.build_snapshot();
-                    let _ = ui_tx
-                        .send(UiUpdate::StateSnapshot(Box::new(snapshot)))
-                        .await;
+                    state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
                 }
                 _ => {
                     // Not in an onboarding mode, ignore
@@ -195,6 +181,9 @@ pub(super) async fn handle_onboarding_action(
                     // Already at first step, no-op
                 }
                 AppMode::Onboarding(OnboardingStep::StrategySetup) => {
+                    enter_league_setup(state, ui_tx).await;
+                }
+                AppMode::Onboarding(OnboardingStep::LeagueSetup) => {
                     state.onboarding_progress.current_step = OnboardingStep::LlmSetup;
                     if let Err(e) = state
                         .onboarding_manager
@@ -204,20 +193,18 @@ pub(super) async fn handle_onboarding_action(
                     }
 
                     state.app_mode = AppMode::Onboarding(OnboardingStep::LlmSetup);
-                    let _ = ui_tx
-                        .send(UiUpdate::OnboardingUpdate(
-                            OnboardingUpdate::ProgressSync {
-                                provider: state.onboarding_progress.llm_provider.clone(),
-                                model: state.onboarding_progress.llm_model.clone(),
-                                api_key_mask: None,
-                            },
-                        ))
-                        .await;
-                    let _ = ui_tx
-                        .send(UiUpdate::ModeChanged(AppMode::Onboarding(
-                            OnboardingStep::LlmSetup,
-                        )))
-                        .await;
+                    let update = UiUpdate::OnboardingUpdate(
+                        OnboardingUpdate::ProgressSync {
+                            provider: state.onboarding_progress.llm_provider.clone(),
+                            model: state.onboarding_progress.llm_model.clone(),
+                            api_key_mask: None,
+                        },
+                    );
+                    state.ui_health.send(ui_tx, update).await;
+                    let update = UiUpdate::ModeChanged(AppMode::Onboarding(
+                        OnboardingStep::LlmSetup,
+                    ));
+                    state.ui_health.send(ui_tx, update).await;
                 }
                 AppMode::Onboarding(OnboardingStep::Complete) => {
                     // Go back to strategy setup
@@ -230,11 +217,10 @@ pub(super) async fn handle_onboarding_action(
                     }
                     state.app_mode =
                         AppMode::Onboarding(OnboardingStep::StrategySetup);
-                    let _ = ui_tx
-                        .send(UiUpdate::ModeChanged(AppMode::Onboarding(
-                            OnboardingStep::StrategySetup,
-                        )))
-                        .await;
+                    let update = UiUpdate::ModeChanged(AppMode::Onboarding(
+                        OnboardingStep::StrategySetup,
+                    ));
+                    state.ui_health.send(ui_tx, update).await;
                 }
                 _ => {
                     // Not in an onboarding mode, ignore
@@ -253,40 +239,73 @@ pub(super) async fn handle_onboarding_action(
 
             match state.app_mode {
                 AppMode::Onboarding(OnboardingStep::LlmSetup) => {
-                    // Skip LlmSetup -> show StrategySetup for this session
+                    // Skip LlmSetup -> show LeagueSetup for this session
                     // but don't advance current_step (stays at LlmSetup)
+                    state.app_mode = AppMode::Onboarding(OnboardingStep::LeagueSetup);
+                    let update = UiUpdate::OnboardingUpdate(OnboardingUpdate::LeagueSync(
+                        LeagueSetup::from_config(&state.config),
+                    ));
+                    state.ui_health.send(ui_tx, update).await;
+                    let update = UiUpdate::ModeChanged(AppMode::Onboarding(
+                        OnboardingStep::LeagueSetup,
+                    ));
+                    state.ui_health.send(ui_tx, update).await;
+                }
+                AppMode::Onboarding(OnboardingStep::LeagueSetup) => {
+                    // Skip LeagueSetup -> show StrategySetup for this session,
+                    // keeping the league settings on disk
                     state.app_mode = AppMode::Onboarding(OnboardingStep::StrategySetup);
-                    let _ = ui_tx
-                        .send(UiUpdate::ModeChanged(AppMode::Onboarding(
-                            OnboardingStep::StrategySetup,
-                        )))
-                        .await;
+                    let update = UiUpdate::ModeChanged(AppMode::Onboarding(
+                        OnboardingStep::StrategySetup,
+                    ));
+                    state.ui_health.send(ui_tx, update).await;
                 }
                 AppMode::Onboarding(OnboardingStep::StrategySetup) => {
                     // Skip StrategySetup -> transition to Draft for this session
                     // but don't advance current_step (stays at StrategySetup)
                     state.llm_client = Arc::new(LlmClient::from_config(&state.config));
                     state.app_mode = AppMode::Draft;
-                    let _ = ui_tx.send(UiUpdate::ModeChanged(AppMode::Draft)).await;
+                    state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Draft)).await;
                     let snapshot = state.build_snapshot();
-                    let _ = ui_tx
-                        .send(UiUpdate::StateSnapshot(Box::new(snapshot)))
-                        .await;
+                    state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
                 }
                 AppMode::Onboarding(OnboardingStep::Complete) => {
                     // Already complete, go to draft
                     state.app_mode = AppMode::Draft;
-                    let _ = ui_tx.send(UiUpdate::ModeChanged(AppMode::Draft)).await;
+                    state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Draft)).await;
                     let snapshot = state.build_snapshot();
-                    let _ = ui_tx
-                        .send(UiUpdate::StateSnapshot(Box::new(snapshot)))
-                        .await;
+                    state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
                 }
                 _ => {
                     // Not in an onboarding mode, ignore
                 }
             }
         }
+        OnboardingAction::SaveLeagueConfig(setup) => {
+            let mut config = state.config.clone();
+            setup.apply(&mut config);
+            let problems = setup.problems(&config);
+            if !problems.is_empty() {
+                state.ui_health.send(ui_tx, UiUpdate::OnboardingUpdate(OnboardingUpdate::LeagueRejected(problems))).await;
+                return;
+            }
+            if let Err(e) = state.onboarding_manager.save_league(&config) {
+                warn!("Failed to save the league setup: {}", e);
+                let update = UiUpdate::OnboardingUpdate(OnboardingUpdate::LeagueRejected(vec![format!(
+                    "could not write the config files: {e}"
+                )]));
+                state.ui_health.send(ui_tx, update).await;
+                return;
+            }
+            info!(
+                "Saved league setup: {} teams, ${} cap, {} categories",
+                setup.num_teams,
+                setup.salary_cap,
+                setup.categories().count()
+            );
+            apply_league_config(state, config);
+            enter_strategy_setup(state, ui_tx).await;
+        }
         OnboardingAction::SaveStrategyConfig { hitting_budget_pct, category_weights, strategy_overview } => {
             // Update in-memory config
             state.config.strategy.hitting_budget_fraction = hitting_budget_pct as f64 / 100.0;
@@ -321,130 +340,121 @@ pub(super) async fn handle_onboarding_action(
 
             // Transition to Draft mode
             state.app_mode = AppMode::Draft;
-            let _ = ui_tx.send(UiUpdate::ModeChanged(AppMode::Draft)).await;
+            state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Draft)).await;
             let snapshot = state.build_snapshot();
-            let _ = ui_tx
-                .send(UiUpdate::StateSnapshot(Box::new(snapshot)))
-                .await;
+            state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
         }
         OnboardingAction::ConfigureStrategyWithLlm(description) => {
             // Check if LLM is available
             let llm_client = state.llm_client.clone();
-            match &*llm_client {
-                LlmClient::Disabled => {
-                    let _ = ui_tx
-                        .send(UiUpdate::OnboardingUpdate(
-                            OnboardingUpdate::StrategyLlmError(
-                                "LLM is not configured. Please set up an API key first.".to_string(),
-                            ),
-                        ))
-                        .await;
-                }
-                LlmClient::Active(_) => {
-                    // Allocate a unique generation ID BEFORE spawning the task
-                    // to prevent race conditions with stale events.
-                    let generation = state.llm_requests.allocate_id();
-                    let tx = ui_tx.clone();
-
-                    // Build the prompt for strategy configuration
-                    let league_ctx = prompt::format_league_context(&state.config.league, state.roster_config.as_ref());
-                    let cat_keys: Vec<&str> = state.config.league.batting_categories.categories.iter()
-                        .chain(state.config.league.pitching_categories.categories.iter())
-                        .map(|s| s.as_str())
-                        .collect();
-                    let system = format!(
-                        "You are a fantasy baseball strategy advisor. Given the user's \
-                        strategy description, output ONLY a valid JSON object (no markdown, no \
-                        explanation) with exactly these fields:\n\
-                        - \"hitting_budget_pct\": integer 0-100 (percentage of budget for hitting)\n\
-                        - \"category_weights\": object with keys {cat_keys}, \
-                        each a float where 1.0 = normal importance, >1.0 = overweight, <1.0 = underweight (min 0.0, max 5.0)\n\
-                        - \"strategy_overview\": a 2-3 sentence prose summary of the strategy that captures the key \
-                        decisions (budget split, punt categories, target player profiles, market edges). This will be \
-                        fed to the draft-time AI advisor for context.\n\n\
-                        {league_ctx}",
-                        cat_keys = cat_keys.join(", "),
-                        league_ctx = league_ctx,
-                    );
+            if !llm_client.is_active() {
+                let update = UiUpdate::OnboardingUpdate(
+                    OnboardingUpdate::StrategyLlmError(
+                        "LLM is not configured. Please set up an API key first.".to_string(),
+                    ),
+                );
+                state.ui_health.send(ui_tx, update).await;
+            } else {
+                // Allocate a unique generation ID BEFORE spawning the task
+                // to prevent race conditions with stale events.
+                let generation = state.llm_requests.allocate_id();
+                let tx = ui_tx.clone();
+                let health = Arc::clone(&state.ui_health);
+
+                // Build the prompt for strategy configuration
+                let league_ctx = prompt::format_league_context(&state.config.league, state.roster_config.as_ref());
+                let cat_keys: Vec<&str> = state.config.league.batting_categories.categories.iter()
+                    .chain(state.config.league.pitching_categories.categories.iter())
+                    .map(|s| s.as_str())
+                    .collect();
+                let system = format!(
+                    "You are a fantasy baseball strategy advisor. Given the user's \
+                    strategy description, output ONLY a valid JSON object (no markdown, no \
+                    explanation) with exactly these fields:\n\
+                    - \"hitting_budget_pct\": integer 1-99 (percentage of budget for hitting)\n\
+                    - \"category_weights\": object with keys {cat_keys}, \
+                    each a float where 1.0 = normal importance, >1.0 = overweight, <1.0 = underweight (min 0.0, max 5.0)\n\
+                    - \"strategy_overview\": a 2-3 sentence prose summary of the strategy that captures the key \
+                    decisions (budget split, punt categories, target player profiles, market edges). This will be \
+                    fed to the draft-time AI advisor for context.\n\n\
+                    {league_ctx}",
+                    cat_keys = cat_keys.join(", "),
+                    league_ctx = league_ctx,
+                );
 
-                    let user_content = format!(
-                        "Configure my draft strategy based on this description:\n\n{}",
-                        description
-                    );
+                let user_content = format!(
+                    "Configure my draft strategy based on this description:\n\n{}",
+                    description
+                );
+
+                let categories = crate::onboarding::strategy_config::categories_from_league(&state.config.league);
+
+                // Spawn LLM streaming task
+                let handle = tokio::spawn(async move {
+                    let (stream_tx, mut stream_rx) = tokio::sync::mpsc::channel::<crate::protocol::LlmEvent>(100);
+
+                    // Start the LLM stream in a separate task
+                    let client = llm_client.clone();
+                    let sys = system.to_string();
+                    let usr = user_content.clone();
+                    tokio::spawn(async move {
+                        let _ = client.stream_message(&sys, &usr, 1024, stream_tx, generation).await;
+                    });
 
-                    let categories = crate::onboarding::strategy_config::categories_from_league(&state.config.league);
-
-                    // Spawn LLM streaming task
-                    let handle = tokio::spawn(async move {
-                        let (stream_tx, mut stream_rx) = tokio::sync::mpsc::channel::<crate::protocol::LlmEvent>(100);
-
-                        // Start the LLM stream in a separate task
-                        let client = llm_client.clone();
-                        let sys = system.to_string();
-                        let usr = user_content.clone();
-                        tokio::spawn(async move {
-                            let _ = client.stream_message(&sys, &usr, 1024, stream_tx, generation).await;
-                        });
-
-                        let mut full_text = String::new();
-
-                        while let Some(event) = stream_rx.recv().await {
-                            match event {
-                                crate::protocol::LlmEvent::Token { text, generation: g } => {
-                                    if g == generation {
-                                        full_text.push_str(&text);
-                                        let _ = tx
-                                            .send(UiUpdate::OnboardingUpdate(
-                                                OnboardingUpdate::StrategyLlmToken(text),
-                                            ))
-                                            .await;
-                                    }
+                    let mut full_text = String::new();
+
+                    while let Some(event) = stream_rx.recv().await {
+                        match event {
+                            crate::protocol::LlmEvent::Token { text, generation: g } => {
+                                if g == generation {
+                                    full_text.push_str(&text);
+                                    let update = UiUpdate::OnboardingUpdate(
+                                        OnboardingUpdate::StrategyLlmToken(text),
+                                    );
+                                    health.send(&tx, update).await;
                                 }
-                                crate::protocol::LlmEvent::Complete { full_text: ft, generation: g, .. } => {
-                                    if g == generation {
-                                        full_text = ft;
-                                        break;
-                                    }
+                            }
+                            crate::protocol::LlmEvent::Complete { full_text: ft, generation: g, .. } => {
+                                if g == generation {
+                                    full_text = ft;
+                                    break;
                                 }
-                                crate::protocol::LlmEvent::Error { message, generation: g } => {
-                                    if g == generation {
-                                        let _ = tx
-                                            .send(UiUpdate::OnboardingUpdate(
-                                                OnboardingUpdate::StrategyLlmError(message),
-                                            ))
-                                            .await;
-                                        return;
-                                    }
+                            }
+                            crate::protocol::LlmEvent::Error { message, generation: g } => {
+                                if g == generation {
+                                    let update = UiUpdate::OnboardingUpdate(
+                                        OnboardingUpdate::StrategyLlmError(message),
+                                    );
+                                    health.send(&tx, update).await;
+                                    return;
                                 }
                             }
                         }
+                    }
 
-                        // Parse JSON from the response
-                        match parse_strategy_json(&full_text, &categories) {
-                            Ok((pct, weights, overview)) => {
-                                let _ = tx
-                                    .send(UiUpdate::OnboardingUpdate(
-                                        OnboardingUpdate::StrategyLlmComplete {
-                                            hitting_budget_pct: pct,
-                                            category_weights: weights,
-                                            strategy_overview: overview,
-                                        },
-                                    ))
-                                    .await;
-                            }
-                            Err(e) => {
-                                let _ = tx
-                                    .send(UiUpdate::OnboardingUpdate(
-                                        OnboardingUpdate::StrategyLlmError(
-                                            format!("Failed to parse LLM response: {}", e),
-                                        ),
-                                    ))
-                                    .await;
-                            }
+                    // Parse JSON from the response
+                    match parse_strategy_json(&full_text, &categories) {
+                        Ok((pct, weights, overview)) => {
+                            let update = UiUpdate::OnboardingUpdate(
+                                OnboardingUpdate::StrategyLlmComplete {
+                                    hitting_budget_pct: pct,
+                                    category_weights: weights,
+                                    strategy_overview: overview,
+                                },
+                            );
+                            health.send(&tx, update).await;
                         }
-                    });
-                    state.llm_requests.track(generation, handle);
-                }
+                        Err(e) => {
+                            let update = UiUpdate::OnboardingUpdate(
+                                OnboardingUpdate::StrategyLlmError(
+                                    format!("Failed to parse LLM response: {}", e),
+                                ),
+                            );
+                            health.send(&tx, update).await;
+                        }
+                    }
+                });
+                state.llm_requests.track(generation, handle);
             }
         }
         OnboardingAction::TestConnectionWith { .. } => {
@@ -458,6 +468,49 @@ pub(super) async fn handle_onboarding_action(
     }
 }
 
+/// Move to the league step, persisting it as the step to resume from, with
+/// the league settings now in use to start the form from.
+async fn enter_league_setup(state: &mut AppState, ui_tx: &mpsc::Sender<UiUpdate>) {
+    state.onboarding_progress.current_step = OnboardingStep::LeagueSetup;
+    if let Err(e) = state.onboarding_manager.save_progress(&state.onboarding_progress) {
+        warn!("Failed to save onboarding progress: {}", e);
+    }
+    state.app_mode = AppMode::Onboarding(OnboardingStep::LeagueSetup);
+    let update = UiUpdate::OnboardingUpdate(OnboardingUpdate::LeagueSync(LeagueSetup::from_config(
+        &state.config,
+    )));
+    state.ui_health.send(ui_tx, update).await;
+    state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Onboarding(OnboardingStep::LeagueSetup))).await;
+}
+
+/// Move to the strategy step, persisting it as the step to resume from.
+async fn enter_strategy_setup(state: &mut AppState, ui_tx: &mpsc::Sender<UiUpdate>) {
+    state.onboarding_progress.current_step = OnboardingStep::StrategySetup;
+    if let Err(e) = state.onboarding_manager.save_progress(&state.onboarding_progress) {
+        warn!("Failed to save onboarding progress: {}", e);
+    }
+    state.app_mode = AppMode::Onboarding(OnboardingStep::StrategySetup);
+    state.ui_health.send(ui_tx, UiUpdate::ModeChanged(AppMode::Onboarding(OnboardingStep::StrategySetup))).await;
+}
+
+/// Switch to a saved league setup: the new categories, and the projection
+/// CSVs it names, valued for the roster in use.
+fn apply_league_config(state: &mut AppState, config: Config) {
+    match StatRegistry::from_league_config(&config.league) {
+        Ok(registry) => {
+            state.category_needs = CategoryValues::uniform(registry.len(), 0.5);
+            state.stat_registry = registry;
+        }
+        Err(e) => warn!("The league's categories are invalid: {}", e),
+    }
+    state.config = config;
+    match valuation::projections::load_all(&state.config) {
+        Ok(Some(projections)) => state.apply_projections(projections),
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load the projection CSVs: {}", e),
+    }
+}
+
 /// Handle an onboarding-style action dispatched from the settings screen.
 ///
 /// Settings mode reuses the same onboarding input handlers, so it receives
@@ -465,7 +518,7 @@ pub(super) async fn handle_onboarding_action(
 /// - `SetProvider`/`SetModel`/`SetApiKey` update config and reload the LLM client
 /// - `SaveStrategyConfig` persists strategy without transitioning to Draft
 /// - `TestConnection` works the same as during onboarding
-/// - `GoNext`/`GoBack`/`Skip` are filtered out by input.rs and should not arrive
+/// - `GoNext`/`GoBack`/`Skip`/`SaveLeagueConfig` are filtered out by input.rs and should not arrive
 /// - `ConfigureStrategyWithLlm` works the same as during onboarding
 pub(super) async fn handle_settings_action(
     state: &mut AppState,
@@ -521,15 +574,14 @@ pub(super) async fn handle_settings_action(
                 let m = crate::onboarding::strategy_config::mask_api_key(&raw_key);
                 if m.is_empty() { None } else { Some(m) }
             };
-            let _ = ui_tx
-                .send(UiUpdate::OnboardingUpdate(
-                    OnboardingUpdate::ProgressSync {
-                        provider: state.onboarding_progress.llm_provider.clone(),
-                        model: state.onboarding_progress.llm_model.clone(),
-                        api_key_mask: mask,
-                    },
-                ))
-                .await;
+            let update = UiUpdate::OnboardingUpdate(
+                OnboardingUpdate::ProgressSync {
+                    provider: state.onboarding_progress.llm_provider.clone(),
+                    model: state.onboarding_progress.llm_model.clone(),
+                    api_key_mask: mask,
+                },
+            );
+            state.ui_health.send(ui_tx, update).await;
 
             // Auto-trigger connection test
             Box::pin(handle_settings_action(state, OnboardingAction::TestConnection, ui_tx)).await;
@@ -552,15 +604,14 @@ pub(super) async fn handle_settings_action(
                 let m = crate::onboarding::strategy_config::mask_api_key(&raw_key);
                 if m.is_empty() { None } else { Some(m) }
             };
-            let _ = ui_tx
-                .send(UiUpdate::OnboardingUpdate(
-                    OnboardingUpdate::ProgressSync {
-                        provider: state.onboarding_progress.llm_provider.clone(),
-                        model: state.onboarding_progress.llm_model.clone(),
-                        api_key_mask: mask,
-                    },
-                ))
-                .await;
+            let update = UiUpdate::OnboardingUpdate(
+                OnboardingUpdate::ProgressSync {
+                    provider: state.onboarding_progress.llm_provider.clone(),
+                    model: state.onboarding_progress.llm_model.clone(),
+                    api_key_mask: mask,
+                },
+            );
+            state.ui_health.send(ui_tx, update).await;
         }
         OnboardingAction::SetModel(model_id) => {
             state.config.strategy.llm.model = model_id.clone();
@@ -586,30 +637,29 @@ pub(super) async fn handle_settings_action(
             // Test with explicit params — does NOT mutate app state.
             // Used by the settings cascade so Esc can cleanly revert.
             if api_key.is_empty() {
-                let _ = ui_tx
-                    .send(UiUpdate::OnboardingUpdate(
-                        OnboardingUpdate::ConnectionTestResult {
-                            success: false,
-                            message: "No API key entered".to_string(),
-                        },
-                    ))
-                    .await;
+                let update = UiUpdate::OnboardingUpdate(
+                    OnboardingUpdate::ConnectionTestResult {
+                        success: false,
+                        message: "No API key entered".to_string(),
+                    },
+                );
+                state.ui_health.send(ui_tx, update).await;
                 return;
             }
             let tx = ui_tx.clone();
+            let health = Arc::clone(&state.ui_health);
             tokio::spawn(async move {
                 let result = test_api_connection(&provider, &api_key, &model_id).await;
-                let _ = tx
-                    .send(UiUpdate::OnboardingUpdate(
-                        OnboardingUpdate::ConnectionTestResult {
-                            success: result.is_ok(),
-                            message: match &result {
-                                Ok(msg) => msg.clone(),
-                                Err(msg) => msg.clone(),
-                            },
+                let update = UiUpdate::OnboardingUpdate(
+                    OnboardingUpdate::ConnectionTestResult {
+                        success: result.is_ok(),
+                        message: match &result {
+                            Ok(msg) => msg.clone(),
+                            Err(msg) => msg.clone(),
                         },
-                    ))
-                    .await;
+                    },
+                );
+                health.send(&tx, update).await;
             });
         }
         OnboardingAction::TestConnection => {
@@ -628,31 +678,30 @@ pub(super) async fn handle_settings_action(
             let api_key = get_api_key_for_provider(&provider, &state.config);
 
             if api_key.is_empty() {
-                let _ = ui_tx
-                    .send(UiUpdate::OnboardingUpdate(
-                        OnboardingUpdate::ConnectionTestResult {
-                            success: false,
-                            message: "No API key entered".to_string(),
-                        },
-                    ))
-                    .await;
+                let update = UiUpdate::OnboardingUpdate(
+                    OnboardingUpdate::ConnectionTestResult {
+                        success: false,
+                        message: "No API key entered".to_string(),
+                    },
+                );
+                state.ui_health.send(ui_tx, update).await;
                 return;
             }
 
             let tx = ui_tx.clone();
+            let health = Arc::clone(&state.ui_health);
             tokio::spawn(async move {
                 let result = test_api_connection(&provider, &api_key, &model_id).await;
-                let _ = tx
-                    .send(UiUpdate::OnboardingUpdate(
-                        OnboardingUpdate::ConnectionTestResult {
-                            success: result.is_ok(),
-                            message: match &result {
-                                Ok(msg) => msg.clone(),
-                                Err(msg) => msg.clone(),
-                            },
+                let update = UiUpdate::OnboardingUpdate(
+                    OnboardingUpdate::ConnectionTestResult {
+                        success: result.is_ok(),
+                        message: match &result {
+                            Ok(msg) => msg.clone(),
+                            Err(msg) => msg.clone(),
                         },
-                    ))
-                    .await;
+                    },
+                );
+                health.send(&tx, update).await;
             });
         }
         OnboardingAction::SaveStrategyConfig { hitting_budget_pct, category_weights, strategy_overview } => {
@@ -679,7 +728,7 @@ pub(super) async fn handle_settings_action(
             state.reload_llm_client();
 
             // Recalculate valuations with new strategy weights
-            let roster = state.roster_config.clone().unwrap_or_else(super::AppState::default_roster_config);
+            let roster = state.roster_config.clone().unwrap_or_else(|| state.config.league.roster_slots());
             valuation::recalculate_all(
                 &mut state.available_players,
                 &roster,
@@ -688,13 +737,13 @@ pub(super) async fn handle_settings_action(
                 &state.draft_state,
                 &state.stat_registry,
             );
-            state.scarcity = compute_scarcity(&state.available_players, &roster);
+            state.plugins.apply(&mut state.available_players);
+            state.apply_budget_split();
+            state.scarcity = compute_scarcity(&state.available_players, &roster, state.config.league.num_teams);
 
             // Send updated snapshot to TUI (stay in Settings mode)
             let snapshot = state.build_snapshot();
-            let _ = ui_tx
-                .send(UiUpdate::StateSnapshot(Box::new(snapshot)))
-                .await;
+            state.ui_health.send(ui_tx, UiUpdate::StateSnapshot(Box::new(snapshot))).await;
         }
         OnboardingAction::ConfigureStrategyWithLlm(description) => {
             // Delegate to the same LLM generation logic as onboarding
@@ -707,7 +756,10 @@ pub(super) async fn handle_settings_action(
         }
         // GoNext/GoBack/Skip are filtered by the input handler and should
         // not reach here. If they do, ignore them silently.
-        OnboardingAction::GoNext | OnboardingAction::GoBack | OnboardingAction::Skip => {}
+        OnboardingAction::GoNext
+        | OnboardingAction::GoBack
+        | OnboardingAction::Skip
+        | OnboardingAction::SaveLeagueConfig(_) => {}
     }
 }
 
@@ -769,6 +821,7 @@ pub(super) fn get_api_key_for_provider(
 /// Test an API connection by making a minimal request to the provider.
 ///
 /// Returns `Ok(message)` on success or `Err(message)` on failure.
+#[cfg(feature = "http")]
 pub(super) async fn test_api_connection(
     provider: &wyncast_core::llm::provider::LlmProvider,
     api_key: &str,
@@ -857,10 +910,19 @@ pub(super) async fn test_api_connection(
     }
 }
 
+#[cfg(not(feature = "http"))]
+pub(super) async fn test_api_connection(
+    _provider: &wyncast_core::llm::provider::LlmProvider,
+    _api_key: &str,
+    _model_id: &str,
+) -> Result<String, String> {
+    Err("This build cannot reach LLM providers (build with the `http` feature)".to_string())
+}
+
 /// Parse the LLM's JSON response into a hitting budget percentage, category weights,
 /// and strategy overview.
 ///
-/// The LLM is prompted to return a JSON object with `hitting_budget_pct` (int 0-100),
+/// The LLM is prompted to return a JSON object with `hitting_budget_pct` (int 1-99),
 /// `category_weights` (map of category name to float), and `strategy_overview` (string).
 /// This function extracts the JSON from the response (stripping any surrounding
 /// text/markdown fences) and parses it.
@@ -904,7 +966,7 @@ pub(super) fn parse_strategy_json(
     let pct = parsed
         .get("hitting_budget_pct")
         .and_then(|v| v.as_u64())
-        .map(|v| v.min(100) as u8)
+        .map(|v| v.clamp(1, 99) as u8)
         .unwrap_or(65);
 
     let mut weights = CategoryWeights::new(categories.to_vec());
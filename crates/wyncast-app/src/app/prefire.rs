@@ -0,0 +1,102 @@
+// Prefire policy: whether a nomination clearing warrants a new plan.
+//
+// With `prefire_planning` on, a nomination plan can be started after every
+// pick, but early in the draft most picks leave my plan as it was. In the
+// default adaptive mode each plan records what it was made from (my picks,
+// my budget, the top of the available pool) and the next one is prefired
+// only when that has changed materially, or when my turn to nominate is
+// within `[llm.prefire] turn_window` nominations.
+
+use wyncast_baseball::draft::state::DraftState;
+use wyncast_baseball::valuation::zscore::PlayerValuation;
+use wyncast_core::config::{PrefireConfig, PrefireMode};
+
+/// What a plan was made from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanBasis {
+    /// Players on my roster.
+    my_picks: usize,
+    my_budget: u32,
+    /// The most valuable available players, best first.
+    top: Vec<String>,
+}
+
+impl PlanBasis {
+    /// The basis of a plan made now, or `None` before my team is known.
+    pub fn capture(draft_state: &DraftState, available: &[PlayerValuation], top_n: usize) -> Option<Self> {
+        let me = draft_state.my_team()?;
+        let mut top: Vec<&PlayerValuation> = available.iter().collect();
+        top.sort_by(|a, b| b.dollar_value.total_cmp(&a.dollar_value));
+        Some(PlanBasis {
+            my_picks: draft_state.picks.iter().filter(|p| p.team_id == me.team_id).count(),
+            my_budget: me.budget_remaining,
+            top: top.into_iter().take(top_n).map(|p| p.name.clone()).collect(),
+        })
+    }
+}
+
+/// Why a plan should be prefired with the draft at `now`, or `None` to keep
+/// the last one. `my_turn_in` is the number of nominations before mine.
+pub fn reason(
+    config: &PrefireConfig,
+    last: Option<&PlanBasis>,
+    now: &PlanBasis,
+    my_turn_in: Option<usize>,
+) -> Option<String> {
+    if config.mode == PrefireMode::Always {
+        return Some("prefire mode is always".into());
+    }
+    let Some(last) = last else {
+        return Some("no plan yet".into());
+    };
+    if let Some(turns) = my_turn_in.filter(|&t| t <= config.turn_window) {
+        return Some(format!("my nomination is {turns} away"));
+    }
+    if now.my_picks != last.my_picks {
+        return Some("my roster changed".into());
+    }
+    if now.my_budget != last.my_budget {
+        return Some("my budget changed".into());
+    }
+    let gone = last.top.iter().filter(|name| !now.top.contains(name)).count();
+    if gone >= config.top_changes {
+        return Some(format!("{gone} of the top {} available are gone", last.top.len()));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn basis(my_picks: usize, my_budget: u32, top: &[&str]) -> PlanBasis {
+        PlanBasis { my_picks, my_budget, top: top.iter().map(|s| s.to_string()).collect() }
+    }
+
+    #[test]
+    fn adaptive_mode_waits_for_a_material_change() {
+        let config = PrefireConfig { top_changes: 2, ..PrefireConfig::default() };
+        let last = basis(3, 200, &["A", "B", "C", "D"]);
+        let reason = |now: &PlanBasis, turn| reason(&config, Some(&last), now, turn);
+
+        assert_eq!(reason(&basis(3, 200, &["A", "C", "D", "E"]), Some(5)), None, "one of the top gone");
+        assert_eq!(reason(&basis(3, 200, &["A", "B", "C", "D"]), None), None);
+        assert_eq!(
+            reason(&basis(3, 200, &["A", "D", "E", "F"]), Some(5)).as_deref(),
+            Some("2 of the top 4 available are gone")
+        );
+        assert_eq!(reason(&basis(4, 180, &["A", "B", "C", "D"]), Some(5)).as_deref(), Some("my roster changed"));
+        assert_eq!(reason(&basis(3, 200, &["A", "B", "C", "D"]), Some(2)).as_deref(), Some("my nomination is 2 away"));
+        assert_eq!(
+            super::reason(&config, None, &last, Some(5)).as_deref(),
+            Some("no plan yet")
+        );
+    }
+
+    #[test]
+    fn always_mode_prefires_after_every_pick() {
+        let config = PrefireConfig { mode: PrefireMode::Always, ..PrefireConfig::default() };
+        let last = basis(3, 200, &["A"]);
+        assert!(reason(&config, Some(&last), &last, None).is_some());
+    }
+}
@@ -0,0 +1,64 @@
+// Projection audit: every available player whose projections were overridden
+// or blended, written as CSV with one row per projection value and the
+// sources it came from. Lets the user check which numbers a configured CSV
+// replaced before trusting the valuations built on them.
+
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use wyncast_baseball::valuation::zscore::{describe_provenance, PlayerValuation};
+
+use super::AppState;
+
+/// CSV header of the audit file.
+const HEADER: [&str; 6] = ["player", "team", "field", "value", "sources", "overridden"];
+
+/// Audit rows for the given players: only players with at least one
+/// overridden or blended value, fields in alphabetical order.
+pub fn rows(players: &[PlayerValuation]) -> Vec<[String; 6]> {
+    let mut rows = Vec::new();
+    for player in players
+        .iter()
+        .filter(|p| p.projection.is_overridden_or_blended())
+    {
+        let projection = &player.projection;
+        let mut fields: Vec<&String> = projection.values.keys().collect();
+        fields.sort();
+        for field in fields {
+            let sources = projection.provenance.get(field).map(Vec::as_slice).unwrap_or(&[]);
+            rows.push([
+                player.name.clone(),
+                player.team.clone(),
+                field.clone(),
+                projection.values[field].to_string(),
+                describe_provenance(sources),
+                sources.iter().any(|s| s.overridden).to_string(),
+            ]);
+        }
+    }
+    rows
+}
+
+/// Write the audit of the available players to a timestamped file in `dir`,
+/// headed by the session stamp as a `#` comment, and return its path.
+pub fn write(state: &AppState, dir: &Path) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create {}", dir.display()))?;
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let path = dir.join(format!("projection-audit-{stamp}.csv"));
+
+    let mut file = std::fs::File::create(&path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    writeln!(file, "# {}", state.session_stamp.summary())
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    let mut writer = csv::Writer::from_writer(file);
+    writer.write_record(HEADER)?;
+    for row in rows(&state.available_players) {
+        writer.write_record(&row)?;
+    }
+    writer
+        .flush()
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
@@ -0,0 +1,714 @@
+// JSON Schema of the extension protocol: the `--export-schema` quick mode.
+//
+// Describes every message the browser extension sends over the WebSocket
+// (`ExtensionMessage` and its payloads, camelCase as on the wire) and the
+// ones the backend sends back (`HEARTBEAT_ACK`, `REQUEST_KEYFRAME`,
+// `RESUME`), so the extension can validate its payloads against the shapes
+// serde expects.
+//
+// Written by hand (draft-07) rather than derived. A field is required when
+// serde rejects a message without it: anything not `Option` and not
+// `#[serde(default)]`. Unknown fields are allowed, as serde ignores them.
+// The tests check serialized messages against the schema, so a field added
+// to a payload without a schema entry fails them.
+
+use serde_json::{json, Map, Value};
+
+/// The schema of the extension protocol.
+pub fn extension_schema() -> Value {
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Wyncast extension protocol",
+        "description": "Messages from the browser extension to the backend (ExtensionMessage) and back (BackendMessage).",
+        "oneOf": [reference("ExtensionMessage"), reference("BackendMessage")],
+        "definitions": definitions(),
+    })
+}
+
+/// Render the schema for the terminal, pretty-printed.
+pub fn render() -> String {
+    let mut out = serde_json::to_string_pretty(&extension_schema()).expect("a JSON value always serializes");
+    out.push('\n');
+    out
+}
+
+fn definitions() -> Value {
+    let defs: Vec<(&str, Value)> = vec![
+        (
+            "ExtensionMessage",
+            json!({
+                "oneOf": [
+                    message("EXTENSION_CONNECTED", &[("payload", reference("ExtensionConnectedPayload"))]),
+                    message("STATE_UPDATE", &[("timestamp", uint()), ("payload", reference("StateUpdatePayload"))]),
+                    message("FULL_STATE_SYNC", &[("timestamp", uint()), ("payload", reference("StateUpdatePayload"))]),
+                    message("EXTENSION_HEARTBEAT", &[("payload", reference("HeartbeatPayload"))]),
+                    message("PLAYER_PROJECTIONS", &[("timestamp", uint()), ("payload", reference("EspnProjectionsPayload"))]),
+                    message("MATCHUP_STATE", &[("timestamp", uint()), ("payload", reference("MatchupStatePayload"))]),
+                    message("YAHOO_STATE_UPDATE", &[("timestamp", uint()), ("payload", reference("YahooDraftPayload"))]),
+                    message("YAHOO_FULL_STATE_SYNC", &[("timestamp", uint()), ("payload", reference("YahooDraftPayload"))]),
+                ]
+            }),
+        ),
+        (
+            "BackendMessage",
+            json!({
+                "oneOf": [
+                    message("HEARTBEAT_ACK", &[("timestamp", uint())]),
+                    message("REQUEST_KEYFRAME", &[]),
+                    message("RESUME", &[("payload", reference("ResumeToken"))]),
+                ]
+            }),
+        ),
+        (
+            "ResumeToken",
+            object(
+                &[
+                    ("draftId", nullable(string())),
+                    ("pickCount", uint()),
+                    ("lastPick", nullable(reference("PickIdentity"))),
+                ],
+                &[],
+            ),
+        ),
+        (
+            "PickIdentity",
+            object(
+                &[("playerId", string()), ("playerName", string()), ("teamId", string()), ("price", uint())],
+                &[],
+            ),
+        ),
+        (
+            "ExtensionConnectedPayload",
+            object(&[("platform", string()), ("extensionVersion", string())], &[]),
+        ),
+        (
+            "StateUpdatePayload",
+            object(
+                &[],
+                &[
+                    ("picks", array(reference("PickData"))),
+                    ("currentNomination", nullable(reference("NominationData"))),
+                    ("myTeamId", nullable(string())),
+                    ("teams", array(reference("TeamBudgetData"))),
+                    ("pickCount", nullable(uint())),
+                    ("totalPicks", nullable(uint())),
+                    ("draftId", nullable(string())),
+                    ("source", nullable(string())),
+                    ("draftBoard", nullable(reference("DraftBoardData"))),
+                    ("pickHistory", nullable(array(reference("PickHistoryEntry")))),
+                    ("teamIdMapping", nullable(array(reference("TeamIdMapping")))),
+                ],
+            ),
+        ),
+        (
+            "PickData",
+            object(
+                &[
+                    ("pickNumber", uint()),
+                    ("teamId", string()),
+                    ("teamName", string()),
+                    ("playerId", string()),
+                    ("playerName", string()),
+                    ("position", string()),
+                    ("price", uint()),
+                ],
+                &[("eligibleSlots", array(uint())), ("assignedSlot", nullable(uint()))],
+            ),
+        ),
+        (
+            "NominationData",
+            object(
+                &[
+                    ("playerId", string()),
+                    ("playerName", string()),
+                    ("position", string()),
+                    ("nominatedBy", string()),
+                    ("currentBid", uint()),
+                ],
+                &[
+                    ("currentBidder", nullable(string())),
+                    ("timeRemaining", nullable(uint())),
+                    ("eligibleSlots", array(uint())),
+                ],
+            ),
+        ),
+        (
+            "TeamBudgetData",
+            object(
+                &[("teamName", string()), ("budget", uint())],
+                &[("teamId", nullable(string()))],
+            ),
+        ),
+        (
+            "HeartbeatPayload",
+            object(
+                &[("timestamp", uint())],
+                &[
+                    ("ackTimestamp", nullable(uint())),
+                    ("ackHeldMs", nullable(uint())),
+                    ("updatesPerMinute", nullable(uint())),
+                    ("picksAgeMs", nullable(uint())),
+                    ("nominationAgeMs", nullable(uint())),
+                    ("jsErrors", nullable(uint())),
+                ],
+            ),
+        ),
+        (
+            "EspnProjectionsPayload",
+            object(&[("players", array(reference("EspnPlayerProjection")))], &[]),
+        ),
+        (
+            "EspnPlayerProjection",
+            object(
+                &[
+                    ("espnId", uint()),
+                    ("name", string()),
+                    ("team", string()),
+                    ("defaultPositionId", uint()),
+                ],
+                &[
+                    ("eligibleSlots", array(uint())),
+                    ("batting", nullable(reference("EspnBattingProjection"))),
+                    ("pitching", nullable(reference("EspnPitchingProjection"))),
+                ],
+            ),
+        ),
+        (
+            "EspnBattingProjection",
+            object(
+                &[
+                    ("pa", uint()),
+                    ("ab", uint()),
+                    ("h", uint()),
+                    ("hr", uint()),
+                    ("r", uint()),
+                    ("rbi", uint()),
+                    ("bb", uint()),
+                    ("sb", uint()),
+                    ("avg", number()),
+                ],
+                &[],
+            ),
+        ),
+        (
+            "EspnPitchingProjection",
+            object(
+                &[
+                    ("ip", number()),
+                    ("k", uint()),
+                    ("w", uint()),
+                    ("sv", uint()),
+                    ("hd", uint()),
+                    ("era", number()),
+                    ("whip", number()),
+                    ("g", uint()),
+                    ("gs", uint()),
+                ],
+                &[],
+            ),
+        ),
+        (
+            "MatchupStatePayload",
+            object(
+                &[
+                    ("matchupPeriod", uint()),
+                    ("startDate", string()),
+                    ("endDate", string()),
+                    ("selectedDay", string()),
+                    ("homeTeam", reference("MatchupTeamPayload")),
+                    ("awayTeam", reference("MatchupTeamPayload")),
+                    ("categories", array(reference("MatchupCategoryPayload"))),
+                    ("homeBatting", reference("MatchupSectionPayload")),
+                    ("homePitching", reference("MatchupSectionPayload")),
+                    ("awayBatting", reference("MatchupSectionPayload")),
+                    ("awayPitching", reference("MatchupSectionPayload")),
+                ],
+                &[],
+            ),
+        ),
+        (
+            "MatchupTeamPayload",
+            object(&[("name", string()), ("record", string()), ("matchupScore", string())], &[]),
+        ),
+        (
+            "MatchupCategoryPayload",
+            object(
+                &[("statId", uint()), ("abbrev", string()), ("lowerIsBetter", boolean())],
+                &[("homeValue", nullable(number())), ("awayValue", nullable(number()))],
+            ),
+        ),
+        (
+            "MatchupSectionPayload",
+            object(
+                &[("headers", array(string())), ("players", array(reference("MatchupPlayerPayload")))],
+                &[("totals", nullable(array(nullable(number()))))],
+            ),
+        ),
+        (
+            "MatchupPlayerPayload",
+            object(
+                &[
+                    ("slot", string()),
+                    ("name", string()),
+                    ("team", string()),
+                    ("positions", array(string())),
+                    ("stats", array(nullable(number()))),
+                ],
+                &[("opponent", nullable(string())), ("status", nullable(string()))],
+            ),
+        ),
+        (
+            "DraftBoardData",
+            object(
+                &[("teams", array(reference("DraftBoardTeam")))],
+                &[("onTheClockTeam", nullable(string()))],
+            ),
+        ),
+        (
+            "DraftBoardTeam",
+            object(
+                &[
+                    ("teamName", string()),
+                    ("column", uint()),
+                    ("isMyTeam", boolean()),
+                    ("isOnTheClock", boolean()),
+                    ("slots", array(reference("DraftBoardSlot"))),
+                ],
+                &[("teamId", string())],
+            ),
+        ),
+        (
+            "DraftBoardSlot",
+            object(
+                &[("row", uint()), ("rosterSlot", string()), ("filled", boolean())],
+                &[
+                    ("firstName", nullable(string())),
+                    ("lastName", nullable(string())),
+                    ("proTeam", nullable(string())),
+                    ("naturalPosition", nullable(string())),
+                    ("price", nullable(uint())),
+                ],
+            ),
+        ),
+        (
+            "PickHistoryEntry",
+            object(
+                &[
+                    ("pickNumber", uint()),
+                    ("round", uint()),
+                    ("playerName", string()),
+                    ("teamName", string()),
+                    ("price", uint()),
+                ],
+                &[
+                    ("espnPlayerId", string()),
+                    ("eligiblePositions", array(string())),
+                    ("teamId", string()),
+                    ("isMyPick", boolean()),
+                ],
+            ),
+        ),
+        (
+            "TeamIdMapping",
+            object(&[("teamName", string()), ("espnTeamId", string())], &[]),
+        ),
+        (
+            "YahooDraftPayload",
+            object(
+                &[],
+                &[
+                    ("leagueKey", nullable(string())),
+                    ("myTeamKey", nullable(string())),
+                    ("teams", array(reference("YahooTeam"))),
+                    ("picks", array(reference("YahooPick"))),
+                    ("nomination", nullable(reference("YahooNomination"))),
+                    ("totalPicks", nullable(uint())),
+                ],
+            ),
+        ),
+        (
+            "YahooTeam",
+            object(&[("teamKey", string()), ("name", string()), ("budget", uint())], &[]),
+        ),
+        (
+            "YahooPick",
+            object(
+                &[
+                    ("pick", uint()),
+                    ("teamKey", string()),
+                    ("playerKey", string()),
+                    ("playerName", string()),
+                    ("positions", string()),
+                    ("cost", uint()),
+                ],
+                &[],
+            ),
+        ),
+        (
+            "YahooNomination",
+            object(
+                &[
+                    ("playerKey", string()),
+                    ("playerName", string()),
+                    ("positions", string()),
+                    ("bid", uint()),
+                ],
+                &[
+                    ("nominatorTeamKey", nullable(string())),
+                    ("bidderTeamKey", nullable(string())),
+                    ("secondsLeft", nullable(uint())),
+                ],
+            ),
+        ),
+    ];
+    Value::Object(defs.into_iter().map(|(name, def)| (name.to_string(), def)).collect())
+}
+
+/// A message tagged by its `type` field, with the given required fields.
+fn message(tag: &str, fields: &[(&str, Value)]) -> Value {
+    let mut all = vec![("type", json!({ "const": tag }))];
+    all.extend(fields.iter().cloned());
+    object(&all, &[])
+}
+
+/// An object with `required` fields and fields that may be left out.
+fn object(required: &[(&str, Value)], optional: &[(&str, Value)]) -> Value {
+    let properties: Map<String, Value> = required
+        .iter()
+        .chain(optional)
+        .map(|(name, schema)| (name.to_string(), schema.clone()))
+        .collect();
+    let names: Vec<&str> = required.iter().map(|(name, _)| *name).collect();
+    json!({ "type": "object", "properties": properties, "required": names })
+}
+
+fn reference(name: &str) -> Value {
+    json!({ "$ref": format!("#/definitions/{name}") })
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn uint() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+fn number() -> Value {
+    json!({ "type": "number" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn array(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+/// `schema` or `null`.
+fn nullable(schema: Value) -> Value {
+    json!({ "anyOf": [schema, { "type": "null" }] })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::resume::{PickIdentity, ResumeToken};
+    use crate::protocol::*;
+    use crate::protocol::yahoo::{YahooDraftPayload, YahooNomination, YahooPick, YahooTeam};
+
+    /// Check `value` against `schema`, covering the keywords used above.
+    /// Unlike the schema, it also rejects fields the schema does not list,
+    /// so a payload field added without a schema entry is caught.
+    fn check(value: &Value, schema: &Value, root: &Value, path: &str) -> Result<(), String> {
+        if let Some(name) = schema["$ref"].as_str() {
+            let name = name.trim_start_matches("#/definitions/");
+            return check(value, &root["definitions"][name], root, path);
+        }
+        if let Some(choices) = schema["oneOf"].as_array().or(schema["anyOf"].as_array()) {
+            let errors: Vec<String> = choices
+                .iter()
+                .filter_map(|choice| check(value, choice, root, path).err())
+                .collect();
+            return if errors.len() < choices.len() {
+                Ok(())
+            } else {
+                Err(errors.join("; "))
+            };
+        }
+        if let Some(expected) = schema.get("const") {
+            return if value == expected { Ok(()) } else { Err(format!("{path}: expected {expected}")) };
+        }
+        let fits = match schema["type"].as_str() {
+            Some("object") => value.is_object(),
+            Some("array") => value.is_array(),
+            Some("string") => value.is_string(),
+            Some("integer") => value.is_u64(),
+            Some("number") => value.is_number(),
+            Some("boolean") => value.is_boolean(),
+            Some("null") => value.is_null(),
+            other => return Err(format!("{path}: unexpected schema type {other:?}")),
+        };
+        if !fits {
+            return Err(format!("{path}: {value} is not {}", schema["type"]));
+        }
+        if let Some(object) = value.as_object() {
+            for required in schema["required"].as_array().into_iter().flatten() {
+                let required = required.as_str().unwrap();
+                if !object.contains_key(required) {
+                    return Err(format!("{path}: missing {required}"));
+                }
+            }
+            for (key, field) in object {
+                let Some(field_schema) = schema["properties"].get(key) else {
+                    return Err(format!("{path}: {key} is not in the schema"));
+                };
+                check(field, field_schema, root, &format!("{path}.{key}"))?;
+            }
+        }
+        if let Some(items) = value.as_array() {
+            for (i, item) in items.iter().enumerate() {
+                check(item, &schema["items"], root, &format!("{path}[{i}]"))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn conforms(value: &Value) {
+        let schema = extension_schema();
+        if let Err(e) = check(value, &schema, &schema, "$") {
+            panic!("{value} does not match the schema: {e}");
+        }
+    }
+
+    /// One of each message, with every optional field filled in.
+    fn samples() -> Vec<ExtensionMessage> {
+        let full_state = StateUpdatePayload {
+            picks: vec![PickData {
+                pick_number: 1,
+                team_id: "3".into(),
+                team_name: "Vorticists".into(),
+                player_id: "12345".into(),
+                player_name: "Shohei Ohtani".into(),
+                position: "DH".into(),
+                price: 62,
+                eligible_slots: vec![11, 12],
+                assigned_slot: Some(11),
+            }],
+            current_nomination: Some(NominationData {
+                player_id: "67890".into(),
+                player_name: "Aaron Judge".into(),
+                position: "OF".into(),
+                nominated_by: "Team Alpha".into(),
+                current_bid: 55,
+                current_bidder: Some("Team Beta".into()),
+                time_remaining: Some(15),
+                eligible_slots: vec![5, 12],
+            }),
+            my_team_id: Some("7".into()),
+            teams: vec![TeamBudgetData { team_id: Some("3".into()), team_name: "Vorticists".into(), budget: 198 }],
+            pick_count: Some(2),
+            total_picks: Some(260),
+            draft_id: Some("espn_12345_2026".into()),
+            source: Some("dom_scraper".into()),
+            draft_board: Some(DraftBoardData {
+                teams: vec![DraftBoardTeam {
+                    team_id: "3".into(),
+                    team_name: "Vorticists".into(),
+                    column: 0,
+                    is_my_team: false,
+                    is_on_the_clock: true,
+                    slots: vec![DraftBoardSlot {
+                        row: 0,
+                        roster_slot: "UTIL".into(),
+                        filled: true,
+                        first_name: Some("Shohei".into()),
+                        last_name: Some("Ohtani".into()),
+                        pro_team: Some("LAD".into()),
+                        natural_position: Some("DH".into()),
+                        price: Some(62),
+                    }],
+                }],
+                on_the_clock_team: Some("Vorticists".into()),
+            }),
+            pick_history: Some(vec![PickHistoryEntry {
+                pick_number: 1,
+                round: 1,
+                player_name: "Shohei Ohtani".into(),
+                espn_player_id: "12345".into(),
+                eligible_positions: vec!["DH".into()],
+                team_id: "3".into(),
+                team_name: "Vorticists".into(),
+                price: 62,
+                is_my_pick: false,
+            }]),
+            team_id_mapping: Some(vec![TeamIdMapping { team_name: "Vorticists".into(), espn_team_id: "3".into() }]),
+        };
+        let section = MatchupSectionPayload {
+            headers: vec!["H".into(), "AVG".into()],
+            players: vec![MatchupPlayerPayload {
+                slot: "C".into(),
+                name: "Will Smith".into(),
+                team: "LAD".into(),
+                positions: vec!["C".into()],
+                opponent: Some("@SF".into()),
+                status: None,
+                stats: vec![Some(2.0), None],
+            }],
+            totals: Some(vec![Some(2.0), None]),
+        };
+        let team = MatchupTeamPayload { name: "Vorticists".into(), record: "1-0-0".into(), matchup_score: "6-4-2".into() };
+        vec![
+            ExtensionMessage::ExtensionConnected {
+                payload: ExtensionConnectedPayload { platform: "firefox".into(), extension_version: "0.2.1".into() },
+            },
+            ExtensionMessage::StateUpdate { timestamp: 1700000000, payload: StateUpdatePayload::default() },
+            ExtensionMessage::FullStateSync { timestamp: 1700000000, payload: full_state },
+            ExtensionMessage::ExtensionHeartbeat {
+                payload: HeartbeatPayload {
+                    timestamp: 1700000001,
+                    ack_timestamp: Some(1700000000),
+                    ack_held_ms: Some(4990),
+                    updates_per_minute: Some(18),
+                    picks_age_ms: Some(2500),
+                    nomination_age_ms: Some(900),
+                    js_errors: Some(0),
+                },
+            },
+            ExtensionMessage::PlayerProjections {
+                timestamp: 1700000002,
+                payload: EspnProjectionsPayload {
+                    players: vec![EspnPlayerProjection {
+                        espn_id: 39832,
+                        name: "Shohei Ohtani".into(),
+                        team: "LAD".into(),
+                        default_position_id: 10,
+                        eligible_slots: vec![11, 12],
+                        batting: Some(EspnBattingProjection {
+                            pa: 650, ab: 560, h: 160, hr: 44, r: 110, rbi: 100, bb: 80, sb: 20, avg: 0.286,
+                        }),
+                        pitching: Some(EspnPitchingProjection {
+                            ip: 120.5, k: 150, w: 9, sv: 0, hd: 0, era: 3.1, whip: 1.05, g: 22, gs: 22,
+                        }),
+                    }],
+                },
+            },
+            ExtensionMessage::MatchupState {
+                timestamp: 1700000003,
+                payload: MatchupStatePayload {
+                    matchup_period: 1,
+                    start_date: "2026-03-26".into(),
+                    end_date: "2026-04-05".into(),
+                    selected_day: "2026-03-27".into(),
+                    home_team: team.clone(),
+                    away_team: team,
+                    categories: vec![MatchupCategoryPayload {
+                        stat_id: 20,
+                        abbrev: "R".into(),
+                        home_value: Some(12.0),
+                        away_value: None,
+                        lower_is_better: false,
+                    }],
+                    home_batting: section.clone(),
+                    home_pitching: section.clone(),
+                    away_batting: section.clone(),
+                    away_pitching: section,
+                },
+            },
+            ExtensionMessage::YahooStateUpdate { timestamp: 1700000004, payload: YahooDraftPayload::default() },
+            ExtensionMessage::YahooFullStateSync {
+                timestamp: 1700000004,
+                payload: YahooDraftPayload {
+                    league_key: Some("458.l.12345".into()),
+                    my_team_key: Some("458.l.12345.t.3".into()),
+                    teams: vec![YahooTeam { team_key: "458.l.12345.t.3".into(), name: "Vorticists".into(), budget: 218 }],
+                    picks: vec![YahooPick {
+                        pick: 1,
+                        team_key: "458.l.12345.t.3".into(),
+                        player_key: "458.p.10835".into(),
+                        player_name: "Bobby Witt Jr.".into(),
+                        positions: "SS,3B".into(),
+                        cost: 42,
+                    }],
+                    nomination: Some(YahooNomination {
+                        player_key: "458.p.9124".into(),
+                        player_name: "Gerrit Cole".into(),
+                        positions: "SP".into(),
+                        nominator_team_key: Some("458.l.12345.t.3".into()),
+                        bid: 18,
+                        bidder_team_key: None,
+                        seconds_left: Some(12),
+                    }),
+                    total_picks: Some(276),
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn every_message_matches_the_schema_and_round_trips() {
+        let samples = samples();
+        let schema = extension_schema();
+        assert_eq!(
+            samples.len(),
+            schema["definitions"]["ExtensionMessage"]["oneOf"].as_array().unwrap().len()
+        );
+        for msg in samples {
+            let json = serde_json::to_value(&msg).unwrap();
+            conforms(&json);
+            let parsed: ExtensionMessage = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, msg);
+        }
+    }
+
+    #[test]
+    fn required_fields_are_what_serde_requires() {
+        let schema = extension_schema();
+        // The smallest message serde takes is valid, and dropping a field
+        // the schema requires makes serde refuse it too.
+        let bare = json!({ "type": "STATE_UPDATE", "timestamp": 1, "payload": {} });
+        conforms(&bare);
+        assert!(serde_json::from_value::<ExtensionMessage>(bare).is_ok());
+
+        for msg in samples() {
+            let json = serde_json::to_value(&msg).unwrap();
+            let tag = json["type"].as_str().unwrap();
+            let variant = schema["definitions"]["ExtensionMessage"]["oneOf"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|v| v["properties"]["type"]["const"] == tag)
+                .unwrap();
+            let payload_ref = variant["properties"]["payload"]["$ref"].as_str().unwrap();
+            let payload_schema = &schema["definitions"][payload_ref.trim_start_matches("#/definitions/")];
+            for field in payload_schema["required"].as_array().unwrap() {
+                let mut broken = json.clone();
+                broken["payload"].as_object_mut().unwrap().remove(field.as_str().unwrap());
+                assert!(
+                    serde_json::from_value::<ExtensionMessage>(broken).is_err(),
+                    "{tag}: serde accepts a payload without {field}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn backend_messages_match_the_schema() {
+        conforms(&json!({ "type": "HEARTBEAT_ACK", "timestamp": 1700000000u64 }));
+        conforms(&json!({ "type": "REQUEST_KEYFRAME" }));
+        let token = ResumeToken {
+            draft_id: Some("espn_1".into()),
+            pick_count: 12,
+            last_pick: Some(PickIdentity {
+                player_id: "4242".into(),
+                player_name: "Slugger".into(),
+                team_id: "3".into(),
+                price: 41,
+            }),
+        };
+        conforms(&serde_json::from_str(&token.message()).unwrap());
+        conforms(&serde_json::from_str(&ResumeToken { draft_id: None, pick_count: 0, last_pick: None }.message()).unwrap());
+        assert!(check(&json!({ "type": "HELLO" }), &extension_schema(), &extension_schema(), "$").is_err());
+    }
+}
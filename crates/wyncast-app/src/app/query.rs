@@ -0,0 +1,206 @@
+// Second-screen queries: short text commands (`!value Soto`, `!plan`)
+// answered from the live AppState. Front-ends outside the TUI (the Discord
+// bot) parse a message into a `Query`, send it to the event loop as a
+// `QueryRequest`, and relay the plain-text answer back to whoever asked.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use tokio::sync::oneshot;
+use wyncast_baseball::draft::pick::Position;
+use wyncast_baseball::valuation::zscore::PlayerValuation;
+
+use super::AppState;
+
+/// Most players listed when a name matches several.
+const MAX_MATCHES: usize = 5;
+
+/// A question about the draft in progress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    /// Value, inflation-adjusted price and VOR of a player.
+    Value(String),
+    /// My budget, open slots and the best available player for each.
+    Plan,
+    /// The list of commands.
+    Help,
+}
+
+impl Query {
+    /// Parse a chat message. Messages that do not start with `!` are not
+    /// queries and yield `None`; unknown commands yield `Help`.
+    pub fn parse(message: &str) -> Option<Self> {
+        let command = message.trim().strip_prefix('!')?;
+        let (name, arg) = command.split_once(char::is_whitespace).unwrap_or((command, ""));
+        let arg = arg.trim();
+        Some(match name.to_lowercase().as_str() {
+            "value" | "v" if !arg.is_empty() => Query::Value(arg.to_string()),
+            "plan" | "p" => Query::Plan,
+            _ => Query::Help,
+        })
+    }
+}
+
+/// A query on its way to the event loop, with the channel the answer goes
+/// back on.
+#[derive(Debug)]
+pub struct QueryRequest {
+    pub query: Query,
+    pub reply: oneshot::Sender<String>,
+}
+
+/// Answer `query` from the current state.
+pub fn answer(state: &AppState, query: &Query) -> String {
+    match query {
+        Query::Value(name) => value(state, name),
+        Query::Plan => plan(state),
+        Query::Help => "Commands: `!value <player>` (value, adjusted price and VOR), \
+                        `!plan` (budget, open slots and targets)"
+            .to_string(),
+    }
+}
+
+fn positions(player: &PlayerValuation) -> String {
+    player
+        .positions
+        .iter()
+        .map(Position::display_str)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn value(state: &AppState, name: &str) -> String {
+    let needle = name.to_lowercase();
+    let exact = state
+        .available_players
+        .iter()
+        .find(|p| p.name.to_lowercase() == needle);
+    let matches: Vec<&PlayerValuation> = match exact {
+        Some(p) => vec![p],
+        None => state
+            .available_players
+            .iter()
+            .filter(|p| p.name.to_lowercase().contains(&needle))
+            .collect(),
+    };
+
+    match matches.as_slice() {
+        [player] => format!(
+            "{} ({}, {}): ${:.0} value, ${:.0} adjusted for inflation, VOR {:.1}",
+            player.name,
+            positions(player),
+            player.team,
+            player.dollar_value,
+            state.inflation.adjust(player.dollar_value),
+            player.vor
+        ),
+        [] => {
+            if let Some(pick) = state
+                .draft_state
+                .picks
+                .iter()
+                .find(|p| p.player_name.to_lowercase().contains(&needle))
+            {
+                format!(
+                    "{} went to {} for ${} (pick {})",
+                    pick.player_name, pick.team_name, pick.price, pick.pick_number
+                )
+            } else if state.pool_excluded.iter().any(|p| p.to_lowercase().contains(&needle)) {
+                format!("\"{name}\" is outside the league's player pool")
+            } else {
+                format!("No available player matches \"{name}\"")
+            }
+        }
+        several => {
+            let names: Vec<&str> = several.iter().take(MAX_MATCHES).map(|p| p.name.as_str()).collect();
+            let more = several.len().saturating_sub(MAX_MATCHES);
+            let mut out = format!("\"{name}\" matches {}", names.join(", "));
+            if more > 0 {
+                let _ = write!(out, " and {more} more");
+            }
+            out
+        }
+    }
+}
+
+/// Whether `player` can start in an open `slot`.
+fn fits(player: &PlayerValuation, slot: Position) -> bool {
+    if slot == Position::Utility {
+        return !player.is_pitcher;
+    }
+    let accepted = slot.accepted_positions();
+    player.positions.iter().any(|p| accepted.contains(p))
+}
+
+fn plan(state: &AppState) -> String {
+    let Some(team) = state.draft_state.my_team() else {
+        return "My team is not known yet; open the draft room with the extension running".to_string();
+    };
+    let roster = &team.roster;
+    let open = roster.empty_slots();
+    let mut out = format!(
+        "${} left, max bid ${}, {} open slot{}",
+        team.budget_remaining,
+        roster.max_bid(team.budget_remaining, state.config.league.min_bid),
+        open,
+        if open == 1 { "" } else { "s" }
+    );
+    if open == 0 {
+        return out;
+    }
+
+    // Open starting slots in roster order; bench, IL and minors take anyone.
+    let mut slots: BTreeMap<(u8, &str), (Position, usize)> = BTreeMap::new();
+    for slot in roster.slots.iter().filter(|s| s.player.is_none()) {
+        if matches!(slot.position, Position::Bench) || slot.position.is_reserve_slot() {
+            continue;
+        }
+        slots
+            .entry((slot.position.sort_order(), slot.position.display_str()))
+            .or_insert((slot.position, 0))
+            .1 += 1;
+    }
+
+    // Each player is suggested for one slot only.
+    let mut suggested: Vec<&str> = Vec::new();
+    for ((_, label), (position, count)) in &slots {
+        let _ = write!(out, "\n{label}");
+        if *count > 1 {
+            let _ = write!(out, " x{count}");
+        }
+        let target = state
+            .available_players
+            .iter()
+            .filter(|p| fits(p, *position) && !suggested.contains(&p.name.as_str()))
+            .max_by(|a, b| a.dollar_value.total_cmp(&b.dollar_value));
+        match target {
+            Some(p) => {
+                suggested.push(&p.name);
+                let _ = write!(out, ": {} ${:.0}", p.name, state.inflation.adjust(p.dollar_value));
+            }
+            None => out.push_str(": nobody left"),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_recognizes_commands() {
+        assert_eq!(Query::parse("!value Juan Soto"), Some(Query::Value("Juan Soto".into())));
+        assert_eq!(Query::parse("  !V  soto "), Some(Query::Value("soto".into())));
+        assert_eq!(Query::parse("!plan"), Some(Query::Plan));
+        assert_eq!(Query::parse("!PLAN now"), Some(Query::Plan));
+    }
+
+    #[test]
+    fn parse_falls_back_to_help_and_ignores_chatter() {
+        assert_eq!(Query::parse("!value"), Some(Query::Help));
+        assert_eq!(Query::parse("!what"), Some(Query::Help));
+        assert_eq!(Query::parse("!"), Some(Query::Help));
+        assert_eq!(Query::parse("who should I bid on?"), None);
+    }
+}
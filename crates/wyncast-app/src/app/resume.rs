@@ -0,0 +1,263 @@
+// Resume handshake: apply a reconnect's snapshot as a delta when it can be.
+//
+// When the extension connects it is sent a `RESUME` message carrying a
+// resume token: the ESPN draft the app is following, how many picks it has
+// processed and the identity of the last one. The extension asks its content
+// script for a FULL_STATE_SYNC once the token arrives. If that snapshot
+// (or any later keyframe) is the same draft and still lists the last pick,
+// and the picks it has beyond ours account for every pick it lists, only the
+// new picks are applied: provisional picks, ended nominations and an
+// analysis in progress survive. Otherwise the draft state is rebuilt from
+// the snapshot as before.
+//
+// Provisional picks are ours alone, so the token and the comparison use the
+// confirmed picks; a provisional pick's real one arrives as a new pick and
+// takes its place as usual.
+
+use serde::Serialize;
+
+use wyncast_baseball::draft::pick::DraftPick;
+use wyncast_baseball::draft::state::{compute_state_diff, PickPayload, StateUpdatePayload};
+
+use super::AppState;
+use crate::protocol;
+
+/// What the app has processed, sent to the extension on connect.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeToken {
+    /// The ESPN draft identifier, once one has been seen.
+    pub draft_id: Option<String>,
+    /// Picks processed.
+    pub pick_count: usize,
+    pub last_pick: Option<PickIdentity>,
+}
+
+/// A pick as the snapshot would list it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PickIdentity {
+    /// ESPN player ID; empty when the pick was scraped without one.
+    pub player_id: String,
+    pub player_name: String,
+    pub team_id: String,
+    pub price: u32,
+}
+
+impl PickIdentity {
+    fn matches(&self, player_id: &str, player_name: &str) -> bool {
+        if !self.player_id.is_empty() && !player_id.is_empty() {
+            self.player_id == player_id
+        } else {
+            self.player_name == player_name
+        }
+    }
+}
+
+impl ResumeToken {
+    /// The token for the app's current state. Before any snapshot has been
+    /// processed there is nothing to resume, so the pick count is zero.
+    pub fn capture(state: &AppState) -> Self {
+        let picks = confirmed_picks(state);
+        ResumeToken {
+            draft_id: state.espn_draft_id.clone(),
+            pick_count: picks.len(),
+            last_pick: picks.last().map(|p| PickIdentity {
+                player_id: p.espn_player_id.clone().unwrap_or_default(),
+                player_name: p.player_name.clone(),
+                team_id: p.team_id.clone(),
+                price: p.price,
+            }),
+        }
+    }
+
+    /// The `RESUME` message for the extension.
+    pub fn message(&self) -> String {
+        serde_json::json!({ "type": "RESUME", "payload": self }).to_string()
+    }
+}
+
+/// The picks processed from the extension's snapshots, in order: all but the
+/// provisional ones, or none before the first snapshot.
+pub fn confirmed_picks(state: &AppState) -> Vec<DraftPick> {
+    if state.previous_extension_state.is_none() {
+        return vec![];
+    }
+    state
+        .draft_state
+        .picks
+        .iter()
+        .filter(|p| !state.provisional_picks.contains(&p.player_name))
+        .cloned()
+        .collect()
+}
+
+/// How to apply a full snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncPlan {
+    /// Apply only the picks this many picks past ours.
+    Delta { new_picks: usize },
+    /// Rebuild from the snapshot, and why.
+    Reset(String),
+}
+
+/// Whether `payload` can be applied on top of the state `token` describes,
+/// with `picks` its confirmed picks.
+pub fn plan(token: &ResumeToken, picks: &[DraftPick], payload: &protocol::StateUpdatePayload) -> SyncPlan {
+    if token.pick_count == 0 {
+        return SyncPlan::Reset("no picks to resume from".into());
+    }
+    if let (Some(ours), Some(theirs)) = (&token.draft_id, &payload.draft_id) {
+        if ours != theirs {
+            return SyncPlan::Reset(format!("the snapshot is of draft {theirs}, not {ours}"));
+        }
+    }
+
+    if let Some(last) = &token.last_pick {
+        let in_log = payload.picks.iter().any(|p| last.matches(&p.player_id, &p.player_name));
+        let in_history = payload
+            .pick_history
+            .iter()
+            .flatten()
+            .any(|p| last.matches(&p.espn_player_id, &p.player_name));
+        if !in_log && !in_history {
+            return SyncPlan::Reset(format!("the snapshot does not list {}", last.player_name));
+        }
+    }
+
+    // Diff against our picks the way a STATE_UPDATE would be.
+    let ours = StateUpdatePayload {
+        picks: picks.iter().map(PickPayload::from).collect(),
+        current_nomination: None,
+        teams: vec![],
+        pick_count: None,
+        total_picks: None,
+    };
+    let new_picks = compute_state_diff(&Some(ours), &AppState::convert_extension_state(payload))
+        .new_picks
+        .len();
+
+    // The grid lists every pick, as does the pick history; the pick log
+    // may not. Either must agree with ours plus the new ones.
+    let listed = match (&payload.draft_board, &payload.pick_history) {
+        (Some(board), _) if !board.teams.is_empty() => {
+            Some(board.teams.iter().flat_map(|t| &t.slots).filter(|s| s.filled).count())
+        }
+        (_, Some(history)) => Some(history.len()),
+        _ => None,
+    };
+    if let Some(listed) = listed {
+        let known = picks.len() + new_picks;
+        if listed != known {
+            return SyncPlan::Reset(format!("the snapshot lists {listed} picks, not {known}"));
+        }
+    }
+
+    SyncPlan::Delta { new_picks }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::test_utils::{create_test_app_state, test_pick};
+
+    fn snapshot(picks: &[(&str, &str, u32)], history: bool) -> protocol::StateUpdatePayload {
+        let picks: Vec<protocol::PickData> = picks
+            .iter()
+            .enumerate()
+            .map(|(i, &(name, team, price))| protocol::PickData {
+                pick_number: i as u32 + 1,
+                team_id: team.into(),
+                team_name: format!("Team {team}"),
+                player_id: String::new(),
+                player_name: name.into(),
+                position: "1B".into(),
+                price,
+                eligible_slots: vec![],
+                assigned_slot: None,
+            })
+            .collect();
+        let pick_history = history.then(|| {
+            picks
+                .iter()
+                .map(|p| protocol::PickHistoryEntry {
+                    pick_number: p.pick_number,
+                    round: 1,
+                    player_name: p.player_name.clone(),
+                    espn_player_id: String::new(),
+                    eligible_positions: vec![],
+                    team_id: p.team_id.clone(),
+                    team_name: p.team_name.clone(),
+                    price: p.price,
+                    is_my_pick: false,
+                })
+                .collect()
+        });
+        protocol::StateUpdatePayload {
+            picks,
+            current_nomination: None,
+            my_team_id: None,
+            teams: vec![],
+            pick_count: None,
+            total_picks: None,
+            draft_id: Some("espn_1".into()),
+            source: None,
+            draft_board: None,
+            pick_history,
+            team_id_mapping: None,
+        }
+    }
+
+    #[test]
+    fn a_snapshot_that_extends_our_picks_is_a_delta() {
+        let mut state = create_test_app_state();
+        state.espn_draft_id = Some("espn_1".into());
+        state.draft_state.picks = vec![test_pick("A", "1", 10), test_pick("B", "2", 12)];
+        state.previous_extension_state = Some(StateUpdatePayload {
+            picks: vec![],
+            current_nomination: None,
+            teams: vec![],
+            pick_count: None,
+            total_picks: None,
+        });
+        state.draft_state.picks.push(test_pick("P", "1", 4));
+        state.provisional_picks.insert("P".into());
+        let token = ResumeToken::capture(&state);
+        assert_eq!(token.pick_count, 2, "the provisional pick is left out");
+        assert_eq!(token.last_pick.as_ref().map(|p| p.player_name.as_str()), Some("B"));
+        let picks = &confirmed_picks(&state);
+
+        let more = snapshot(&[("A", "1", 10), ("B", "2", 12), ("C", "1", 5)], true);
+        assert_eq!(plan(&token, picks, &more), SyncPlan::Delta { new_picks: 1 });
+
+        // The log has scrolled A out of view; the history still lists it.
+        let mut scrolled = more.clone();
+        scrolled.picks.remove(0);
+        assert_eq!(plan(&token, picks, &scrolled), SyncPlan::Delta { new_picks: 1 });
+
+        // B was undone on ESPN.
+        let undone = snapshot(&[("A", "1", 10), ("C", "1", 5)], true);
+        assert_eq!(plan(&token, picks, &undone), SyncPlan::Reset("the snapshot does not list B".into()));
+
+        // A pick the log no longer shows, and that we never saw.
+        let mut missed = snapshot(&[("A", "1", 10), ("X", "2", 3), ("B", "2", 12), ("C", "1", 5)], true);
+        missed.picks.remove(1);
+        assert_eq!(plan(&token, picks, &missed), SyncPlan::Reset("the snapshot lists 4 picks, not 3".into()));
+
+        let mut other = more.clone();
+        other.draft_id = Some("espn_2".into());
+        assert!(matches!(plan(&token, picks, &other), SyncPlan::Reset(_)));
+    }
+
+    #[test]
+    fn nothing_to_resume_before_the_first_sync() {
+        let mut state = create_test_app_state();
+        state.draft_state.picks = vec![test_pick("A", "1", 10)];
+        let token = ResumeToken::capture(&state);
+        assert_eq!(token.pick_count, 0);
+        assert!(matches!(plan(&token, &[], &snapshot(&[("A", "1", 10)], true)), SyncPlan::Reset(_)));
+        let message: serde_json::Value = serde_json::from_str(&token.message()).unwrap();
+        assert_eq!(message["type"], "RESUME");
+        assert_eq!(message["payload"]["pickCount"], 0);
+    }
+}
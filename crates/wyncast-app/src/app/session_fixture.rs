@@ -0,0 +1,373 @@
+// Session recordings and the regression fixtures made from them.
+//
+// With `WYNCAST_RECORD_SESSION` set to a file, every message the extension
+// sends is appended to it as received, one per line: a replay log of the
+// session. The `--make-fixture LOG DIR` quick mode turns a log into a
+// fixture for the integration tests:
+//
+// - trimmed to the draft messages (heartbeats, projections and matchup
+//   pages dropped), without updates that repeat the one before, and with
+//   lines that are not JSON dropped;
+// - anonymized: team names become "Team 1", "Team 2", ... in order of
+//   appearance, the draft ID becomes "fixture", and timestamps become the
+//   message's place in the log;
+// - replayed through the backend (no projections, an in-memory database, the
+//   LLM off), with the draft state it ends in written as the expectation.
+//
+// A fixture is a directory holding `messages.jsonl` and `expected.json`.
+// The integration tests replay every fixture under `tests/fixtures/sessions`
+// and check the state still comes out the same.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::OpenOptions;
+use std::io::Write as _;
+use std::path::Path;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use wyncast_baseball::draft::state::DraftState;
+use wyncast_core::config::Config;
+use wyncast_core::db::Database;
+use wyncast_llm::client::LlmClient;
+
+use super::{ws_handler, AppState};
+use crate::onboarding::{OnboardingManager, RealFileSystem};
+use crate::protocol::AppMode;
+
+/// Environment variable naming the file to record the session's messages to.
+pub const RECORD_ENV: &str = "WYNCAST_RECORD_SESSION";
+
+/// Message types a fixture keeps.
+const DRAFT_MESSAGES: &[&str] = &["STATE_UPDATE", "FULL_STATE_SYNC", "YAHOO_STATE_UPDATE", "YAHOO_FULL_STATE_SYNC"];
+
+/// Fields holding a fantasy team's name, anywhere in a message.
+const TEAM_NAME_FIELDS: &[&str] = &["teamName", "nominatedBy", "currentBidder", "onTheClockTeam"];
+
+/// Fields holding the draft's or league's ID.
+const DRAFT_ID_FIELDS: &[&str] = &["draftId", "leagueKey"];
+
+/// Appends the extension's messages to the replay log.
+pub struct SessionRecorder {
+    file: std::fs::File,
+}
+
+impl SessionRecorder {
+    /// A recorder for the file in `WYNCAST_RECORD_SESSION`, if it is set
+    /// and the file opens.
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var_os(RECORD_ENV)?;
+        match OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(file) => {
+                tracing::info!("Recording the session to {}", Path::new(&path).display());
+                Some(SessionRecorder { file })
+            }
+            Err(e) => {
+                warn!("Cannot record the session to {}: {}", Path::new(&path).display(), e);
+                None
+            }
+        }
+    }
+
+    /// Append one message, as received.
+    pub fn record(&mut self, message: &str) {
+        let line = message.replace(['\n', '\r'], " ");
+        if let Err(e) = writeln!(self.file, "{line}") {
+            warn!("Failed to record a session message: {}", e);
+        }
+    }
+}
+
+/// The league settings a fixture replays under.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureLeague {
+    pub salary_cap: u32,
+    pub num_teams: usize,
+    pub roster: BTreeMap<String, usize>,
+}
+
+impl FixtureLeague {
+    pub fn from_config(config: &Config) -> Self {
+        FixtureLeague {
+            salary_cap: config.league.salary_cap,
+            num_teams: config.league.num_teams,
+            roster: config.league.roster_slots().into_iter().collect(),
+        }
+    }
+}
+
+/// A pick as the replay recorded it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedPick {
+    pub pick_number: u32,
+    pub team: String,
+    pub player: String,
+    pub position: String,
+    pub price: u32,
+}
+
+/// A team's budget and roster after the replay.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedTeam {
+    pub name: String,
+    pub spent: u32,
+    pub remaining: u32,
+    pub rostered: usize,
+}
+
+/// The draft state a fixture's messages end in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedState {
+    pub my_team: Option<String>,
+    pub nomination: Option<String>,
+    pub picks: Vec<ExpectedPick>,
+    pub teams: Vec<ExpectedTeam>,
+}
+
+impl ExpectedState {
+    fn of(draft_state: &DraftState) -> Self {
+        ExpectedState {
+            my_team: draft_state.my_team().map(|t| t.team_name.clone()),
+            nomination: draft_state.current_nomination.as_ref().map(|n| n.player_name.clone()),
+            picks: draft_state
+                .picks
+                .iter()
+                .map(|p| ExpectedPick {
+                    pick_number: p.pick_number,
+                    team: p.team_name.clone(),
+                    player: p.player_name.clone(),
+                    position: p.position.clone(),
+                    price: p.price,
+                })
+                .collect(),
+            teams: draft_state
+                .teams
+                .iter()
+                .map(|t| ExpectedTeam {
+                    name: t.team_name.clone(),
+                    spent: t.budget_spent,
+                    remaining: t.budget_remaining,
+                    rostered: t.roster.filled_count(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// `expected.json`: the league a fixture replays under and what it ends in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureExpectation {
+    pub league: FixtureLeague,
+    pub expected: ExpectedState,
+}
+
+/// A replay log cut down to a fixture's messages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trimmed {
+    /// The kept messages, one compact JSON line each.
+    pub messages: Vec<String>,
+    /// Lines dropped: not JSON, not a draft message, or a repeat.
+    pub dropped: usize,
+}
+
+/// Trim and anonymize a replay log.
+pub fn trim_and_anonymize(log: &str) -> Trimmed {
+    let mut teams: HashMap<String, String> = HashMap::new();
+    let mut messages = Vec::new();
+    let mut dropped = 0;
+    let mut previous: Option<Value> = None;
+
+    for line in log.lines().filter(|line| !line.trim().is_empty()) {
+        let Ok(mut message) = serde_json::from_str::<Value>(line) else {
+            dropped += 1;
+            continue;
+        };
+        let kind = message["type"].as_str().unwrap_or_default();
+        if !DRAFT_MESSAGES.contains(&kind) {
+            dropped += 1;
+            continue;
+        }
+        let yahoo = kind.starts_with("YAHOO_");
+        anonymize(&mut message["payload"], &mut teams, yahoo);
+        let repeat = previous
+            .as_ref()
+            .is_some_and(|p| p["type"] == message["type"] && p["payload"] == message["payload"]);
+        if repeat {
+            dropped += 1;
+            continue;
+        }
+        message["timestamp"] = Value::from(messages.len());
+        messages.push(message.to_string());
+        previous = Some(message);
+    }
+    Trimmed { messages, dropped }
+}
+
+fn anonymize(value: &mut Value, teams: &mut HashMap<String, String>, yahoo: bool) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                let is_team_name = TEAM_NAME_FIELDS.contains(&key.as_str()) || (yahoo && key == "name");
+                match field {
+                    Value::String(name) if is_team_name && !name.is_empty() => {
+                        let next = format!("Team {}", teams.len() + 1);
+                        *name = teams.entry(name.clone()).or_insert(next).clone();
+                    }
+                    Value::String(id) if DRAFT_ID_FIELDS.contains(&key.as_str()) => {
+                        *id = "fixture".to_string();
+                    }
+                    _ => anonymize(field, teams, yahoo),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                anonymize(item, teams, yahoo);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replay `messages` through a fresh backend under `league` and return the
+/// draft state they end in.
+pub async fn replay(league: &FixtureLeague, messages: &[String]) -> anyhow::Result<ExpectedState> {
+    let mut config = Config::default();
+    config.league.salary_cap = league.salary_cap;
+    config.league.num_teams = league.num_teams;
+    let roster: HashMap<String, usize> = league.roster.clone().into_iter().collect();
+    config.league.roster = roster.clone();
+
+    let db = Database::open(":memory:").context("failed to open an in-memory database")?;
+    let (llm_tx, _llm_rx) = mpsc::channel(16);
+    let onboarding = OnboardingManager::new(std::env::temp_dir().join("wyncast-fixture"), RealFileSystem);
+    let draft_state = DraftState::new(league.salary_cap, &roster);
+    let mut state = AppState::new(
+        config,
+        draft_state,
+        Vec::new(),
+        None,
+        db,
+        "fixture".to_string(),
+        LlmClient::Disabled,
+        llm_tx,
+        None,
+        AppMode::Draft,
+        onboarding,
+        Some(roster),
+    );
+
+    let (ui_tx, mut ui_rx) = mpsc::channel(1024);
+    for message in messages {
+        ws_handler::handle_ws_message(&mut state, message, &ui_tx).await;
+        while ui_rx.try_recv().is_ok() {}
+    }
+    Ok(ExpectedState::of(&state.draft_state))
+}
+
+/// Turn the replay log at `log` into a fixture in `dir`, replayed under
+/// `league`. Returns what it wrote, for the summary.
+pub async fn make_fixture(log: &Path, dir: &Path, league: FixtureLeague) -> anyhow::Result<(Trimmed, ExpectedState)> {
+    let text = std::fs::read_to_string(log).with_context(|| format!("failed to read {}", log.display()))?;
+    let trimmed = trim_and_anonymize(&text);
+    anyhow::ensure!(!trimmed.messages.is_empty(), "{} has no draft messages", log.display());
+    let expected = replay(&league, &trimmed.messages).await?;
+
+    std::fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let mut messages = trimmed.messages.join("\n");
+    messages.push('\n');
+    std::fs::write(dir.join("messages.jsonl"), messages).context("failed to write messages.jsonl")?;
+    let expectation = FixtureExpectation { league, expected: expected.clone() };
+    let mut json = serde_json::to_string_pretty(&expectation)?;
+    json.push('\n');
+    std::fs::write(dir.join("expected.json"), json).context("failed to write expected.json")?;
+    Ok((trimmed, expected))
+}
+
+/// Replay the fixture in `dir`: what it expects and what it got.
+pub async fn check_fixture(dir: &Path) -> anyhow::Result<(ExpectedState, ExpectedState)> {
+    let expectation: FixtureExpectation = serde_json::from_str(
+        &std::fs::read_to_string(dir.join("expected.json")).context("failed to read expected.json")?,
+    )
+    .context("failed to parse expected.json")?;
+    let messages: Vec<String> = std::fs::read_to_string(dir.join("messages.jsonl"))
+        .context("failed to read messages.jsonl")?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::to_string)
+        .collect();
+    let actual = replay(&expectation.league, &messages).await?;
+    Ok((expectation.expected, actual))
+}
+
+/// One line on what `--make-fixture` wrote.
+pub fn summary(dir: &Path, trimmed: &Trimmed, expected: &ExpectedState) -> String {
+    format!(
+        "Wrote {}: {} messages ({} dropped), {} picks, {} teams\n",
+        dir.display(),
+        trimmed.messages.len(),
+        trimmed.dropped,
+        expected.picks.len(),
+        expected.teams.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn a_log_becomes_an_anonymized_fixture_that_replays() {
+        let update = |picks: Value| {
+            json!({
+                "type": "STATE_UPDATE",
+                "timestamp": 1700000123,
+                "payload": {
+                    "draftId": "espn_98765_2026",
+                    "myTeamId": "2",
+                    "teams": [
+                        { "teamId": "1", "teamName": "Smith Family Sluggers", "budget": 230 },
+                        { "teamId": "2", "teamName": "Jane's Team", "budget": 260 }
+                    ],
+                    "picks": picks
+                }
+            })
+            .to_string()
+        };
+        let pick = json!({
+            "pickNumber": 1, "teamId": "1", "teamName": "Smith Family Sluggers",
+            "playerId": "1", "playerName": "Slugger", "position": "1B", "price": 30
+        });
+        let log = [
+            json!({ "type": "EXTENSION_CONNECTED", "payload": { "platform": "firefox", "extensionVersion": "1" } })
+                .to_string(),
+            update(json!([])),
+            "{not json".to_string(),
+            update(json!([pick])),
+            update(json!([pick])),
+            json!({ "type": "EXTENSION_HEARTBEAT", "payload": { "timestamp": 1 } }).to_string(),
+        ]
+        .join("\n");
+
+        let trimmed = trim_and_anonymize(&log);
+        assert_eq!((trimmed.messages.len(), trimmed.dropped), (2, 4));
+        let text = trimmed.messages.join("\n");
+        assert!(!text.contains("Smith") && !text.contains("Jane") && !text.contains("espn_98765"), "{text}");
+        let last: Value = serde_json::from_str(&trimmed.messages[1]).unwrap();
+        assert_eq!(last["timestamp"], 1);
+        assert_eq!(last["payload"]["picks"][0]["teamName"], "Team 1");
+        assert_eq!(last["payload"]["teams"][1]["teamName"], "Team 2");
+
+        let league = FixtureLeague::from_config(&Config::default());
+        let expected = replay(&league, &trimmed.messages).await.unwrap();
+        assert_eq!(expected.my_team.as_deref(), Some("Team 2"));
+        assert_eq!(expected.picks.len(), 1);
+        assert_eq!((expected.picks[0].player.as_str(), expected.picks[0].team.as_str()), ("Slugger", "Team 1"));
+        let team1 = expected.teams.iter().find(|t| t.name == "Team 1").unwrap();
+        assert_eq!((team1.spent, team1.remaining, team1.rostered), (30, 230, 1));
+    }
+}
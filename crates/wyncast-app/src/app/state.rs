@@ -0,0 +1,2045 @@
+// Application state: everything the event loop knows about the draft, and
+// the operations that change it outside the extension and LLM handlers --
+// loading projections and valuations, recording picks, keeper contracts,
+// nominators and bid intents.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicI8, AtomicU64};
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+use wyncast_core::backup;
+use wyncast_core::config::{self, Config, KeeperContract, KeeperRules};
+use wyncast_core::crash;
+use wyncast_core::db::Database;
+use wyncast_core::journal::{BidIntent, JournalEntry, QuickBid};
+use wyncast_core::nomination::NominationVerdict;
+use wyncast_core::stamp::SessionStamp;
+use wyncast_baseball::draft::invariants::{self, Violation};
+use wyncast_baseball::draft::nomination_order;
+use wyncast_baseball::draft::nomination_queue::{self, QueueEntry};
+use wyncast_baseball::draft::pick::positions_str;
+use wyncast_baseball::draft::plan_b::OutbidPlayer;
+use wyncast_baseball::draft::state::{ActiveNomination, DraftState, KeptPlayer, StateUpdatePayload};
+use wyncast_llm::client::LlmClient;
+
+use crate::onboarding::{OnboardingManager, OnboardingProgress, RealFileSystem};
+use crate::protocol::{AppMode, ConnectionStatus, DiffExplanation, DraftBoardData, ExtensionHealth, LlmEvent, TabId};
+use wyncast_core::stats::{CategoryValues, StatRegistry};
+use wyncast_baseball::valuation::adjustments::{self, Adjustment, AppliedAdjustment};
+use wyncast_baseball::valuation::auction::{self, BudgetSplit, InflationTracker};
+use wyncast_baseball::valuation::compare::{self, PlayerComparison};
+use wyncast_baseball::valuation::explain::{self, ValuationExplanation};
+use wyncast_baseball::valuation::identity::{self, AmbiguousPick, PlayerMatch, PlayerRef};
+use wyncast_baseball::valuation::custom_stats::CustomStats;
+use wyncast_baseball::valuation::plugins::Plugins;
+use wyncast_baseball::valuation;
+use wyncast_baseball::valuation::player_pool;
+use wyncast_baseball::valuation::prep::{self, PrepEntry};
+use wyncast_baseball::valuation::projections::AllProjections;
+use wyncast_baseball::valuation::scarcity::{compute_scarcity, ScarcityEntry};
+use wyncast_baseball::valuation::zscore::PlayerValuation;
+
+use super::missed_picks::EndedNomination;
+use super::prefire::PlanBasis;
+use super::ui_health::UiHealth;
+use super::undo::UndoStack;
+use super::{notify, query, LlmRequestManager, CONNECTION_NEVER_TESTED};
+
+// ---------------------------------------------------------------------------
+// Supporting types
+// ---------------------------------------------------------------------------
+
+/// Tracks which player is currently being analyzed by the LLM.
+/// Used by the preserve_llm guard in ws_handler and duplicate-check in trigger_nomination_analysis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalysisPlayer {
+    pub player_name: String,
+    pub player_id: String,
+}
+
+/// A snooze of automatic recalculation during a burst of picks. Picks and
+/// budgets are still recorded; re-pricing the pool, inflation and scarcity
+/// waits until the snooze ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecalcSnooze {
+    /// When the snooze started, or the last pick since then arrived.
+    pub last_activity: Instant,
+    /// Picks recorded since the last recalculation.
+    pub skipped_picks: usize,
+}
+
+// ---------------------------------------------------------------------------
+// AppState
+// ---------------------------------------------------------------------------
+
+/// Drop projections outside the league's player pool (AL-only, NL-only,
+/// team whitelist) and return the names that were dropped.
+pub(super) fn restrict_to_player_pool(projections: &mut AllProjections, config: &Config) -> HashSet<String> {
+    let excluded = player_pool::restrict(projections, &config.league.player_pool);
+    if !excluded.is_empty() {
+        info!(
+            "Player pool restriction removed {} players from the projections",
+            excluded.len()
+        );
+    }
+    excluded
+}
+
+/// The projection adjustments configured under `[data_paths]`. A file that
+/// cannot be read is logged and left out.
+pub(super) fn load_adjustments(config: &Config) -> Vec<Adjustment> {
+    match adjustments::load(&config.data_paths) {
+        Ok(rows) => {
+            if !rows.is_empty() {
+                info!("Loaded {} projection adjustments", rows.len());
+            }
+            rows
+        }
+        Err(e) => {
+            warn!("Failed to load projection adjustments: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// The prep sheet configured under `[data_paths]`. A file that cannot be
+/// read is logged and left out.
+fn load_prep(config: &Config) -> Vec<PrepEntry> {
+    match prep::load(&config.data_paths) {
+        Ok(entries) => {
+            if !entries.is_empty() {
+                info!("Loaded {} players from the prep sheet", entries.len());
+            }
+            entries
+        }
+        Err(e) => {
+            warn!("Failed to load the prep sheet: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Match the prep sheet's names to the freshly valued player pool and
+/// return the ones that match nobody.
+fn reconcile_prep(entries: &mut [PrepEntry], players: &[PlayerValuation]) -> Vec<String> {
+    if entries.is_empty() {
+        return Vec::new();
+    }
+    let unmatched = prep::reconcile(entries, players);
+    info!(
+        "Prep sheet: matched {} of {} players",
+        entries.len() - unmatched.len(),
+        entries.len()
+    );
+    if !unmatched.is_empty() {
+        warn!("Prep sheet names matching no projected player: {}", unmatched.join(", "));
+    }
+    unmatched
+}
+
+/// The nomination script configured under `[data_paths]`. A file that
+/// cannot be read is logged and left out.
+fn load_nomination_queue(config: &Config) -> Vec<QueueEntry> {
+    match nomination_queue::load(&config.data_paths) {
+        Ok(entries) => {
+            if !entries.is_empty() {
+                info!("Loaded {} scripted nominations", entries.len());
+            }
+            entries
+        }
+        Err(e) => {
+            warn!("Failed to load the nomination script: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Stamp a session of `draft_id` with the model version and inputs, and
+/// record it. A stamp that cannot be recorded is logged; the session still
+/// carries it.
+pub(super) fn stamp_session(db: &Database, draft_id: &str, config: &Config) -> SessionStamp {
+    let stamp = SessionStamp::new(draft_id, config);
+    info!("Session stamp: {}", stamp.summary());
+    if let Err(e) = db.record_session_stamp(&stamp) {
+        warn!("Failed to record the session stamp: {}", e);
+    }
+    stamp
+}
+
+/// Apply the projection adjustments and return what each player got.
+pub(super) fn adjust_projections(
+    projections: &mut AllProjections,
+    rows: &[Adjustment],
+) -> HashMap<String, Vec<AppliedAdjustment>> {
+    let applied = adjustments::apply(projections, rows);
+    if !applied.is_empty() {
+        info!("Projection adjustments changed {} players", applied.len());
+    }
+    applied
+}
+
+/// Store the contracts from `[league.keepers]`, replacing any stored ones
+/// for the same players, and return every stored contract.
+fn sync_keeper_contracts(db: &Database, rules: &KeeperRules) -> Vec<KeeperContract> {
+    for contract in &rules.contracts {
+        if let Err(e) = db.save_keeper_contract(contract) {
+            warn!("Failed to save keeper contract for {}: {}", contract.player, e);
+        }
+    }
+    db.load_keeper_contracts().unwrap_or_else(|e| {
+        warn!("Failed to load keeper contracts: {}", e);
+        rules.contracts.clone()
+    })
+}
+
+/// The complete application state.
+pub struct AppState {
+    /// Current UI mode (Onboarding, Draft, or Settings).
+    pub app_mode: AppMode,
+    pub config: Config,
+    pub draft_state: DraftState,
+    pub available_players: Vec<PlayerValuation>,
+    pub all_projections: Option<AllProjections>,
+    pub inflation: InflationTracker,
+    pub scarcity: Vec<ScarcityEntry>,
+    pub db: Database,
+    /// Unique identifier for the current draft session. Picks are scoped to
+    /// this ID so restarts don't replay picks from a different draft.
+    pub draft_id: String,
+    /// Draft identifier scraped from the ESPN page by the extension (e.g.
+    /// league ID from URL or team-name fingerprint). Used to detect when
+    /// the extension is reporting state from a different draft than the one
+    /// stored in `draft_id`. `None` until the first STATE_UPDATE arrives
+    /// with a non-null `draftId`.
+    pub espn_draft_id: Option<String>,
+    pub previous_extension_state: Option<StateUpdatePayload>,
+    pub llm_requests: LlmRequestManager,
+    pub analysis_request_id: Option<u64>,
+    pub plan_request_id: Option<u64>,
+    /// What the last nomination plan was made from, for deciding whether
+    /// the next pick warrants a new one.
+    pub plan_basis: Option<PlanBasis>,
+    /// The one-shot LLM comparison from the compare overlay, if running.
+    pub compare_request_id: Option<u64>,
+    pub analysis_player: Option<AnalysisPlayer>,
+    pub connection_status: ConnectionStatus,
+    /// Automatic LLM requests (nomination analysis and prefired plans) are
+    /// suspended: the extension dropped and our draft state may be stale.
+    /// Cleared by the next FULL_STATE_SYNC.
+    pub llm_paused: bool,
+    /// Automatic recalculation is snoozed (`z`); ends by hand or once no
+    /// pick has arrived for `display.recalc_snooze_secs`.
+    pub recalc_snooze: Option<RecalcSnooze>,
+    /// Nominations that ended with a bid, waiting for their pick to arrive.
+    pub ended_nominations: Vec<EndedNomination>,
+    /// Players whose pick was inferred from their nomination ending, until
+    /// the real pick arrives.
+    pub provisional_picks: HashSet<String>,
+    /// Timestamp of the last WebSocket message (or connection event) received.
+    /// `None` when not connected. Used to detect stale connections when the
+    /// browser tab is closed without a clean WebSocket close frame.
+    pub last_ws_message_time: Option<Instant>,
+    /// The last `HEARTBEAT_ACK` sent to the extension (its timestamp and
+    /// when it went out), waiting to be echoed by the next heartbeat.
+    pub heartbeat_ack: Option<(u64, Instant)>,
+    /// Scraping health from the extension's latest heartbeat. `None` until
+    /// one reports it, and again after a disconnect.
+    pub extension_health: Option<ExtensionHealth>,
+    /// The latest state diffs explained, oldest first, while
+    /// `logging.explain_diffs` is on. Holds the last 20.
+    pub diff_explanations: VecDeque<DiffExplanation>,
+    pub active_tab: TabId,
+    pub category_needs: CategoryValues,
+    pub stat_registry: StatRegistry,
+    /// LLM client for streaming Claude API calls. Wrapped in Arc for
+    /// sharing with spawned tasks.
+    pub llm_client: Arc<LlmClient>,
+    /// Sender for LLM events; spawned tasks use a clone of this sender
+    /// to stream tokens back to the main event loop.
+    pub llm_tx: mpsc::Sender<LlmEvent>,
+    /// Sender for outbound WebSocket messages to the extension.
+    /// Used to send `REQUEST_KEYFRAME` messages.
+    pub ws_outbound_tx: Option<mpsc::Sender<String>>,
+    /// Onboarding manager for loading/saving onboarding progress.
+    pub onboarding_manager: OnboardingManager<RealFileSystem>,
+    /// Cached onboarding progress (updated in-memory on each Set* action,
+    /// persisted to disk on GoNext/GoBack transitions).
+    pub onboarding_progress: OnboardingProgress,
+    /// Tracks the result of the last API connection test during onboarding.
+    /// Uses `CONNECTION_NEVER_TESTED`, `CONNECTION_TEST_FAILED`, `CONNECTION_TEST_PASSED`.
+    /// Shared with the spawned TestConnection task via Arc so it can update
+    /// the result without routing through the event loop.
+    pub connection_test_result: Arc<AtomicI8>,
+    /// Generation counter for connection tests. Incremented when a new test
+    /// is triggered. Spawned test tasks
+    /// capture the current generation and only write to `connection_test_result`
+    /// if the generation hasn't changed, preventing stale writes.
+    pub connection_test_generation: Arc<AtomicU64>,
+    /// Whether grid-sourced picks have already been persisted to DB this session.
+    /// Set to true after the first grid-based rebuild to avoid redundant writes
+    /// on subsequent 10-second FULL_STATE_SYNC keyframes.
+    pub grid_picks_persisted: bool,
+    /// Roster configuration inferred from ESPN or set from defaults.
+    /// `None` until roster is inferred from the ESPN draft board.
+    pub roster_config: Option<std::collections::HashMap<String, usize>>,
+    /// Latest matchup snapshot received from the extension.
+    pub matchup_snapshot: Option<wyncast_baseball::matchup::MatchupSnapshot>,
+    /// My logged bid intent for the player currently on the block. Reset
+    /// whenever a different player is nominated.
+    pub bid_intent: Option<BidIntent>,
+    /// Players I was in on who went to another team, in the order sold.
+    pub outbid_on: Vec<OutbidPlayer>,
+    /// The latest of `outbid_on`, whose substitutes are shown until I buy
+    /// a player.
+    pub plan_b: Option<OutbidPlayer>,
+    /// Hitter/pitcher split currently used to price the remaining pool.
+    pub budget_split: BudgetSplit,
+    /// Players dropped from the projections by the league's player pool
+    /// restriction, so a pick of one is flagged instead of treated as an
+    /// unknown player.
+    pub pool_excluded: HashSet<String>,
+    /// Picks whose name matches more than one player in the pool, waiting
+    /// for me to say which one went. Those players stay in the pool until
+    /// then.
+    pub ambiguous_picks: Vec<AmbiguousPick>,
+    /// Names more than one player in the projections goes by.
+    pub shared_names: HashSet<String>,
+    /// What the last draft-state invariants check found; empty when all
+    /// held.
+    pub invariant_violations: Vec<Violation>,
+    /// Rows of the projection adjustments CSV, applied to every set of
+    /// projections before valuation.
+    pub projection_adjustments: Vec<Adjustment>,
+    /// The adjustments each player's projections received, by name, for the
+    /// player detail.
+    pub applied_adjustments: HashMap<String, Vec<AppliedAdjustment>>,
+    /// Custom value adjustments, run over the pool after each valuation.
+    pub plugins: Plugins,
+    /// Names of the custom stat columns, in config order.
+    pub custom_stat_names: Vec<String>,
+    /// My prep sheet's values, tiers, targets, avoids and notes, with names
+    /// reconciled against the projections once valuations exist.
+    pub prep: Vec<PrepEntry>,
+    /// Prep sheet names that match no projected player.
+    pub prep_unmatched: Vec<String>,
+    /// The players I mean to nominate first, in order, with names
+    /// reconciled like the prep sheet's.
+    pub nomination_queue: Vec<QueueEntry>,
+    /// Whether the end-of-draft recap has gone out this session.
+    pub recap_sent: bool,
+    /// Send outcomes on the channel to the TUI.
+    pub ui_health: Arc<UiHealth>,
+    /// Pick count at the last database backup; `None` before the first.
+    pub backed_up_picks: Option<usize>,
+    /// Keeper contracts: those in `[league.keepers]` plus the ones signed
+    /// at earlier drafts, as stored in the database.
+    pub keeper_contracts: Vec<KeeperContract>,
+    /// Second-screen queries (the Discord bot) waiting for an answer.
+    /// Taken by `run()`; `None` when no front-end is attached.
+    pub query_rx: Option<mpsc::Receiver<query::QueryRequest>>,
+    /// Warm-start cache of the initial valuations; `None` computes them
+    /// every time.
+    pub valuation_cache: Option<PathBuf>,
+    /// Edits made by hand (manual picks) that can be undone and redone.
+    pub undo_stack: UndoStack,
+    /// The model's value of each drafted player when they were bought, by
+    /// pick number, for the session stats. Picks made before valuations
+    /// were ready have none.
+    pub pick_values: HashMap<u32, f64>,
+    /// The engine's verdict on the player on the block, recorded with the
+    /// pick once they are sold.
+    pub nomination_verdict: Option<NominationVerdict>,
+    /// The engine's verdicts on the players sold so far, by name, for the
+    /// draft recap.
+    pub pick_verdicts: HashMap<String, NominationVerdict>,
+    /// The model version and inputs this session started with, stamped
+    /// into every export and report.
+    pub session_stamp: SessionStamp,
+}
+
+impl AppState {
+    /// Create a new AppState with the given components.
+    ///
+    /// The `draft_id` identifies the current draft session. On startup, callers
+    /// should load the stored draft_id from the database (or generate a new one).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        config: Config,
+        draft_state: DraftState,
+        available_players: Vec<PlayerValuation>,
+        mut all_projections: Option<AllProjections>,
+        db: Database,
+        draft_id: String,
+        llm_client: LlmClient,
+        llm_tx: mpsc::Sender<LlmEvent>,
+        ws_outbound_tx: Option<mpsc::Sender<String>>,
+        app_mode: AppMode,
+        onboarding_manager: OnboardingManager<RealFileSystem>,
+        roster_config: Option<std::collections::HashMap<String, usize>>,
+    ) -> Self {
+        let scarcity = match &roster_config {
+            Some(rc) => compute_scarcity(&available_players, rc, config.league.num_teams),
+            None => Vec::new(),
+        };
+        let inflation = InflationTracker::new();
+        let onboarding_progress = onboarding_manager.load_progress();
+        let stat_registry = StatRegistry::from_league_config(&config.league)
+            .expect("league config must produce a valid stat registry");
+        let category_needs = CategoryValues::uniform(stat_registry.len(), 0.5);
+        let budget_split = BudgetSplit::fixed(&config.strategy);
+        let pool_excluded = all_projections
+            .as_mut()
+            .map(|p| restrict_to_player_pool(p, &config))
+            .unwrap_or_default();
+        let projection_adjustments = load_adjustments(&config);
+        let applied_adjustments = all_projections
+            .as_mut()
+            .map(|p| adjust_projections(p, &projection_adjustments))
+            .unwrap_or_default();
+        let custom_stats = CustomStats::parse(&config.display.custom_stats);
+        let custom_stat_names = custom_stats.names();
+        let mut plugins = Plugins::load(&config.data_paths);
+        if !custom_stats.is_empty() {
+            plugins.register(custom_stats);
+        }
+        let prep = load_prep(&config);
+        let nomination_queue = load_nomination_queue(&config);
+        let keeper_contracts = sync_keeper_contracts(&db, &config.league.keepers);
+        let mut draft_state = draft_state;
+        match db.load_nominations(&draft_id) {
+            Ok(nominators) => draft_state.nominators.extend(nominators),
+            Err(e) => warn!("Failed to load nominations: {}", e),
+        }
+        let pick_verdicts = db.load_pick_verdicts(&draft_id).unwrap_or_else(|e| {
+            warn!("Failed to load pick verdicts: {}", e);
+            HashMap::new()
+        });
+        let session_stamp = stamp_session(&db, &draft_id, &config);
+
+        AppState {
+            app_mode,
+            config,
+            draft_state,
+            available_players,
+            all_projections,
+            inflation,
+            scarcity,
+            db,
+            draft_id,
+            espn_draft_id: None,
+            previous_extension_state: None,
+            llm_requests: LlmRequestManager::new(),
+            analysis_request_id: None,
+            plan_request_id: None,
+            plan_basis: None,
+            compare_request_id: None,
+            analysis_player: None,
+            connection_status: ConnectionStatus::Disconnected,
+            llm_paused: false,
+            recalc_snooze: None,
+            ended_nominations: Vec::new(),
+            provisional_picks: HashSet::new(),
+            last_ws_message_time: None,
+            heartbeat_ack: None,
+            extension_health: None,
+            diff_explanations: VecDeque::new(),
+            active_tab: TabId::Analysis,
+            category_needs,
+            stat_registry,
+            llm_client: Arc::new(llm_client),
+            llm_tx,
+            ws_outbound_tx,
+            onboarding_manager,
+            onboarding_progress,
+            connection_test_result: Arc::new(AtomicI8::new(CONNECTION_NEVER_TESTED)),
+            connection_test_generation: Arc::new(AtomicU64::new(0)),
+            grid_picks_persisted: false,
+            roster_config,
+            matchup_snapshot: None,
+            bid_intent: None,
+            outbid_on: Vec::new(),
+            plan_b: None,
+            budget_split,
+            pool_excluded,
+            ambiguous_picks: Vec::new(),
+            shared_names: HashSet::new(),
+            invariant_violations: Vec::new(),
+            projection_adjustments,
+            applied_adjustments,
+            plugins,
+            custom_stat_names,
+            prep,
+            prep_unmatched: Vec::new(),
+            nomination_queue,
+            recap_sent: false,
+            ui_health: Arc::new(UiHealth::default()),
+            backed_up_picks: None,
+            keeper_contracts,
+            query_rx: None,
+            valuation_cache: None,
+            undo_stack: UndoStack::default(),
+            pick_values: HashMap::new(),
+            nomination_verdict: None,
+            pick_verdicts,
+            session_stamp,
+        }
+    }
+
+    /// Default roster configuration (used as fallback until ESPN provides the actual roster layout).
+    pub fn default_roster_config() -> std::collections::HashMap<String, usize> {
+        wyncast_core::config::default_roster()
+    }
+
+    /// Apply a roster configuration inferred from the ESPN draft board.
+    ///
+    /// Sets the roster_config and attempts to compute valuations. If projections
+    /// are not yet available (waiting for ESPN), only stores the config and
+    /// defers valuation until `apply_projections()` is called.
+    pub fn apply_roster_config(&mut self, roster: std::collections::HashMap<String, usize>) {
+        info!("Applying roster config: {:?}", roster);
+        for problem in config::pool_shortfalls(&roster, self.config.league.num_teams, &self.config.strategy.pool) {
+            warn!("The league's roster outgrows the player pool, growing it for valuations: {}", problem);
+        }
+        self.roster_config = Some(roster);
+        self.try_compute_valuations();
+    }
+
+    /// Store projections and compute valuations if roster config is available.
+    ///
+    /// Called when ESPN projections arrive from the extension. If the roster
+    /// config has already been inferred from the draft board, valuations are
+    /// computed immediately. Otherwise, projections are stored and valuations
+    /// are deferred until `apply_roster_config()` is called.
+    pub fn apply_projections(&mut self, mut projections: AllProjections) {
+        self.pool_excluded = restrict_to_player_pool(&mut projections, &self.config);
+        self.applied_adjustments = adjust_projections(&mut projections, &self.projection_adjustments);
+        info!(
+            "Applying projections: {} hitters, {} pitchers",
+            projections.hitters.len(),
+            projections.pitchers.len()
+        );
+        let shared = identity::duplicate_names(&projections);
+        for duplicate in &shared {
+            warn!(
+                "Projections list more than one {}; picks of the name are matched by position, \
+                 and asked about when that is not enough",
+                duplicate
+            );
+        }
+        self.shared_names = shared.into_iter().map(|s| s.name).collect();
+        self.all_projections = Some(projections);
+        self.try_compute_valuations();
+    }
+
+    /// Initial valuations of every projected player for `roster`, read from
+    /// the warm-start cache when the projections and settings are unchanged,
+    /// each carrying the projection adjustments it received, with the
+    /// valuation plugins applied. Empty without projections.
+    pub(super) fn initial_valuations(&self, roster: &std::collections::HashMap<String, usize>) -> Vec<PlayerValuation> {
+        let Some(projections) = &self.all_projections else {
+            return Vec::new();
+        };
+        let valuations = match &self.valuation_cache {
+            Some(path) => valuation::cache::compute_initial_cached(
+                projections,
+                &self.config,
+                roster,
+                &self.stat_registry,
+                path,
+            ),
+            None => valuation::compute_initial(projections, &self.config, roster, &self.stat_registry),
+        };
+        self.finish_valuations(valuations)
+    }
+
+    /// Give freshly computed valuations their projection adjustments and
+    /// apply the valuation plugins.
+    pub(super) fn finish_valuations(&self, mut valuations: Vec<PlayerValuation>) -> Vec<PlayerValuation> {
+        for player in &mut valuations {
+            if let Some(applied) = self.applied_adjustments.get(&player.name) {
+                player.projection.adjustments = applied.clone();
+            }
+        }
+        self.plugins.apply(&mut valuations);
+        valuations
+    }
+
+    /// The calculation chain behind an available player's value, for the
+    /// explain view.
+    pub fn explain_player(&self, player: &PlayerRef) -> Result<ValuationExplanation, String> {
+        let (Some(projections), Some(roster)) = (&self.all_projections, &self.roster_config) else {
+            return Err("Valuations have not been computed yet".into());
+        };
+        let player = self.available_player(player)?;
+        Ok(explain::explain(
+            player,
+            projections,
+            &self.config,
+            roster,
+            &self.stat_registry,
+            &self.inflation,
+        ))
+    }
+
+    /// Line two available players up side by side against my roster and
+    /// category needs for the compare overlay.
+    pub fn compare_players(&self, a: &PlayerRef, b: &PlayerRef) -> Result<PlayerComparison, String> {
+        let (a, b) = (self.available_player(a)?, self.available_player(b)?);
+        Ok(compare::compare(
+            a,
+            b,
+            self.draft_state.my_team().map(|t| &t.roster),
+            &self.category_needs,
+            &self.inflation,
+            &self.stat_registry,
+        ))
+    }
+
+    /// The available player a view named, told apart from namesakes by
+    /// team and position.
+    fn available_player(&self, player: &PlayerRef) -> Result<&PlayerValuation, String> {
+        player
+            .find(&self.available_players)
+            .best()
+            .map(|i| &self.available_players[i])
+            .ok_or_else(|| format!("{} is not in the available pool", player.name))
+    }
+
+    /// Say which player an ambiguous pick was: the one of that name who
+    /// plays for MLB `team`. Removes them from the pool, settles any other
+    /// pick of the name that is now clear, and reprices the rest. Returns
+    /// what was resolved, or why nothing was.
+    pub fn resolve_pick(&mut self, pick_number: u32, team: &str) -> Result<String, String> {
+        let idx = self
+            .ambiguous_picks
+            .iter()
+            .position(|p| p.pick_number == pick_number)
+            .ok_or_else(|| format!("Pick #{pick_number} is not waiting to be resolved"))?;
+        let pick = &self.ambiguous_picks[idx];
+        let player_idx = identity::find_player(&self.available_players, &pick.player_name, &pick.position, Some(team))
+            .unique()
+            .filter(|&i| self.available_players[i].team.eq_ignore_ascii_case(team))
+            .ok_or_else(|| format!("No {} of {team} is available", pick.player_name))?;
+
+        let pick = self.ambiguous_picks.remove(idx);
+        let player = self.available_players.remove(player_idx);
+        self.pick_values.insert(pick.pick_number, player.dollar_value);
+        info!("Resolved pick #{}: {} of {}", pick.pick_number, player.name, player.team);
+
+        // Another pick of the name may have only one player left to be.
+        let mut i = 0;
+        while i < self.ambiguous_picks.len() {
+            let other = &self.ambiguous_picks[i];
+            match identity::find_player(&self.available_players, &other.player_name, &other.position, None) {
+                PlayerMatch::Ambiguous(_) => i += 1,
+                PlayerMatch::Missing => {
+                    self.ambiguous_picks.remove(i);
+                }
+                PlayerMatch::Unique(p) => {
+                    let settled = self.available_players.remove(p);
+                    self.pick_values.insert(other.pick_number, settled.dollar_value);
+                    info!("Pick #{} can now only be {} of {}", other.pick_number, settled.name, settled.team);
+                    self.ambiguous_picks.remove(i);
+                }
+            }
+        }
+
+        self.apply_budget_split();
+        self.update_inflation();
+        if let Some(ref roster) = self.roster_config {
+            self.scarcity = compute_scarcity(&self.available_players, roster, self.config.league.num_teams);
+        }
+        Ok(format!("pick #{} is {} of {}", pick.pick_number, player.name, player.team))
+    }
+
+    /// Compute initial valuations if both projections and roster config are available.
+    ///
+    /// After computing the full player pool, removes any players that have
+    /// already been drafted (present in `draft_state.picks`). This handles
+    /// the case where projections arrive after picks have been recorded
+    /// (e.g. backend restart mid-draft).
+    fn try_compute_valuations(&mut self) {
+        let (Some(_), Some(roster)) = (&self.all_projections, self.roster_config.clone()) else {
+            return;
+        };
+        self.available_players = self.initial_valuations(&roster);
+        self.apply_keepers();
+        self.prep_unmatched = reconcile_prep(&mut self.prep, &self.available_players);
+        if !self.nomination_queue.is_empty() {
+            let unmatched = nomination_queue::reconcile(&mut self.nomination_queue, &self.available_players);
+            if !unmatched.is_empty() {
+                warn!("Nomination script names matching no projected player: {}", unmatched.join(", "));
+            }
+        }
+
+        // Remove already-drafted players from the available pool
+        if !self.draft_state.picks.is_empty() {
+            self.ambiguous_picks =
+                identity::remove_drafted(&mut self.available_players, &self.draft_state.picks);
+            info!(
+                "Filtered {} drafted players from available pool ({} remaining, {} ambiguous)",
+                self.draft_state.picks.len(),
+                self.available_players.len(),
+                self.ambiguous_picks.len()
+            );
+        }
+
+        self.scarcity = compute_scarcity(&self.available_players, &roster, self.config.league.num_teams);
+    }
+
+    /// Reconstruct the LLM client from the current config.
+    ///
+    /// Called after settings changes (API key, provider, model) so that
+    /// subsequent LLM calls use the updated configuration.
+    pub fn reload_llm_client(&mut self) {
+        self.llm_client = Arc::new(LlmClient::from_config(&self.config));
+        info!("Reloaded LLM client (provider={:?}, model={})",
+            self.config.strategy.llm.provider,
+            self.config.strategy.llm.model,
+        );
+    }
+
+    /// Process new picks from the extension state diff.
+    ///
+    /// For each new pick:
+    /// 1. Record in DraftState
+    /// 2. Persist to DB
+    /// 3. Remove from available player pool
+    /// 4. Update inflation and scarcity
+    pub fn process_new_picks(
+        &mut self,
+        new_picks: Vec<wyncast_baseball::draft::pick::DraftPick>,
+    ) {
+        if new_picks.is_empty() {
+            return;
+        }
+
+        let first_new = self.draft_state.picks.len();
+        let my_team_id = self.draft_state.my_team().map(|t| t.team_id.clone());
+        for pick in &new_picks {
+            info!(
+                "Recording pick #{}: {} -> {} for ${}",
+                pick.pick_number, pick.player_name, pick.team_name, pick.price
+            );
+
+            // Record in DraftState (assigns canonical sequential pick_number)
+            let before = self.draft_state.picks.len();
+            self.draft_state.record_pick(pick.clone());
+            let recorded = self.draft_state.picks[before..].first();
+
+            // Find the player in the available pool, by name and position.
+            // A repeat of a recorded pick matches nobody: its player is gone,
+            // and a namesake still in the pool must not go instead. A name
+            // that still fits several players waits for me to resolve it.
+            let player_name = &pick.player_name;
+            let matched = match recorded {
+                None => None,
+                Some(recorded) => {
+                    match identity::find_player(&self.available_players, player_name, &pick.position, None) {
+                        PlayerMatch::Missing => None,
+                        PlayerMatch::Unique(i) => Some(i),
+                        PlayerMatch::Ambiguous(candidates) => {
+                            warn!(
+                                "Pick #{}: {} matches {} players in the pool; asking which",
+                                recorded.pick_number,
+                                player_name,
+                                candidates.len()
+                            );
+                            self.ambiguous_picks
+                                .push(AmbiguousPick::new(recorded, &self.available_players, &candidates));
+                            None
+                        }
+                    }
+                }
+            };
+            if let Some(player) = matched.map(|i| &self.available_players[i]) {
+                if let Some(recorded) = recorded {
+                    self.pick_values.insert(recorded.pick_number, player.dollar_value);
+                }
+                let my_intent = self
+                    .bid_intent
+                    .as_ref()
+                    .filter(|i| i.player_name == *player_name && i.interested == Some(true));
+                if my_team_id.as_deref() == Some(pick.team_id.as_str()) {
+                    self.plan_b = None;
+                } else if let Some(intent) = my_intent {
+                    info!(
+                        "Outbid on {}: {} paid ${} (I was in at {:?})",
+                        player_name, pick.team_name, pick.price, intent.bid
+                    );
+                    let lost = OutbidPlayer::new(player, &pick.team_name, pick.price, intent.bid);
+                    self.outbid_on.push(lost.clone());
+                    self.plan_b = Some(lost);
+                }
+            }
+            if let Some(verdict) = self.nomination_verdict.take_if(|v| v.player_name == *player_name) {
+                if let Err(e) = self.db.record_pick_verdict(&self.draft_id, &verdict) {
+                    warn!("Failed to record verdict on {}: {}", player_name, e);
+                }
+                self.pick_verdicts.insert(player_name.clone(), verdict);
+            }
+            if self.pool_excluded.contains(player_name) {
+                warn!(
+                    "Pick #{}: {} is outside the league's player pool",
+                    pick.pick_number, player_name
+                );
+            }
+            if let Some(i) = matched {
+                self.available_players.remove(i);
+            }
+        }
+
+        // Persist everything record_pick actually added (deduped picks are
+        // skipped) as one batch, so a crash can't leave half of it on disk.
+        // Use the picks from draft_state.picks, which carry the corrected
+        // pick_number; the original picks from ESPN may have an unreliable
+        // pick_number (e.g. always 1) due to ESPN's virtualized pick list.
+        let added = &self.draft_state.picks[first_new..];
+        if !added.is_empty() {
+            if let Err(e) = self.db.record_picks(added, &self.draft_id) {
+                warn!("Failed to persist picks to DB: {}", e);
+            }
+            crash::set_draft_context(&self.draft_id, self.draft_state.picks.len());
+        }
+        self.sign_keeper_contracts(first_new);
+
+        // While snoozed, the rest waits for the burst to end.
+        if let Some(snooze) = self.recalc_snooze.as_mut() {
+            snooze.last_activity = Instant::now();
+            snooze.skipped_picks += self.draft_state.picks.len() - first_new;
+            return;
+        }
+        self.recalculate();
+
+        // Update category needs (for now, uniform - real implementation in TUI tasks)
+        // Category needs would be recomputed based on the user's roster composition.
+
+        if self.draft_state.picks.len() / invariants::CHECK_EVERY > first_new / invariants::CHECK_EVERY {
+            self.check_invariants();
+        }
+    }
+
+    /// Bring the smoothed inflation rate up to date with the pool and picks.
+    pub fn update_inflation(&mut self) {
+        // Nothing is priced in a snake draft.
+        if self.config.league.is_snake() {
+            return;
+        }
+        self.inflation.update(&self.available_players, &self.draft_state, &self.config.league);
+        self.inflation.smooth(&self.draft_state, &self.pick_values, &self.config.strategy.valuation);
+    }
+
+    /// Re-split the remaining pool (adaptive mode), then update inflation
+    /// and scarcity from the picks so far.
+    pub fn recalculate(&mut self) {
+        self.apply_budget_split();
+        self.update_inflation();
+        if let Some(ref roster) = self.roster_config {
+            self.scarcity = compute_scarcity(&self.available_players, roster, self.config.league.num_teams);
+        }
+    }
+
+    /// Snooze automatic recalculation until `resume_recalc`. Returns
+    /// `false` if it was already snoozed.
+    pub fn snooze_recalc(&mut self) -> bool {
+        if self.recalc_snooze.is_some() {
+            return false;
+        }
+        self.recalc_snooze = Some(RecalcSnooze { last_activity: Instant::now(), skipped_picks: 0 });
+        true
+    }
+
+    /// End the snooze, recalculating once if picks came in meanwhile.
+    /// Returns `false` if recalculation was not snoozed.
+    pub fn resume_recalc(&mut self) -> bool {
+        let Some(snooze) = self.recalc_snooze.take() else {
+            return false;
+        };
+        if snooze.skipped_picks > 0 {
+            info!("Recalculating after {} snoozed picks", snooze.skipped_picks);
+            self.recalculate();
+            self.check_invariants();
+        }
+        true
+    }
+
+    /// When a snooze ends by itself: `display.recalc_snooze_secs` after its
+    /// last activity. `None` when not snoozed, or when only a key ends it.
+    pub fn recalc_deadline(&self) -> Option<Instant> {
+        let secs = self.config.display.recalc_snooze_secs;
+        let snooze = self.recalc_snooze.as_ref().filter(|_| secs > 0)?;
+        Some(snooze.last_activity + std::time::Duration::from_secs(secs))
+    }
+
+    /// Cross-check the draft state, inflation tracker and available pool,
+    /// logging each violation when it first appears. The violations are kept
+    /// for the snapshot.
+    pub fn check_invariants(&mut self) {
+        let mut unchecked = self.shared_names.clone();
+        unchecked.extend(self.ambiguous_picks.iter().map(|p| p.player_name.clone()));
+        let violations = invariants::check(&self.draft_state, &self.available_players, &self.inflation, &unchecked);
+        for violation in violations.iter().filter(|v| !self.invariant_violations.contains(v)) {
+            warn!("Draft state invariant broken after pick {}: {}", self.draft_state.picks.len(), violation);
+        }
+        self.invariant_violations = violations;
+    }
+
+    /// Rebuild the draft as if only `picks` had been made, putting `returned`
+    /// back in the available pool: rosters, budgets and everything derived
+    /// from the pool. Rewrites the persisted pick log to match.
+    pub fn rebuild_from_picks(
+        &mut self,
+        picks: Vec<wyncast_baseball::draft::pick::DraftPick>,
+        returned: Vec<PlayerValuation>,
+    ) {
+        // The picks are numbered afresh; their values follow them.
+        let values: Vec<Option<f64>> = picks.iter().map(|p| self.pick_values.get(&p.pick_number).copied()).collect();
+        self.draft_state.restore_from_picks(picks);
+        self.pick_values = self
+            .draft_state
+            .picks
+            .iter()
+            .zip(values)
+            .filter_map(|(pick, value)| Some((pick.pick_number, value?)))
+            .collect();
+        self.available_players.extend(returned);
+        let draft_state = &self.draft_state;
+        self.ambiguous_picks.retain(|a| {
+            draft_state
+                .picks
+                .iter()
+                .any(|p| p.pick_number == a.pick_number && p.player_name == a.player_name)
+        });
+        if let Err(e) = self.db.replace_picks(&self.draft_state.picks, &self.draft_id) {
+            warn!("Failed to rewrite the pick log: {}", e);
+        }
+        crash::set_draft_context(&self.draft_id, self.draft_state.picks.len());
+
+        // A rebuild prices in everything, snoozed picks included.
+        self.recalculate();
+        if let Some(snooze) = self.recalc_snooze.as_mut() {
+            snooze.skipped_picks = 0;
+        }
+    }
+
+    /// Take the `[[league.keepers.kept]]` players out of the available pool
+    /// and put them on their teams' rosters at their salaries, so the pool,
+    /// the budgets and inflation all start from what is left. Call after the
+    /// pool is rebuilt. A keeper already out of the pool keeps the position
+    /// found for him before.
+    pub(super) fn apply_keepers(&mut self) {
+        self.draft_state.keepers_on_espn = self.config.league.keepers.kept_on_espn;
+        let kept = &self.config.league.keepers.kept;
+        if kept.is_empty() {
+            return;
+        }
+        let mut keepers = Vec::with_capacity(kept.len());
+        let mut taken = Vec::new();
+        for keeper in kept {
+            let found = identity::find_player(&self.available_players, &keeper.player, "", None).best();
+            let position = match found {
+                Some(i) => {
+                    taken.push(i);
+                    positions_str(&self.available_players[i].positions)
+                }
+                None => match self.draft_state.keepers.iter().find(|k| k.player_name == keeper.player) {
+                    Some(known) => known.position.clone(),
+                    None => {
+                        warn!("Keeper {} is not a projected player", keeper.player);
+                        String::new()
+                    }
+                },
+            };
+            keepers.push(KeptPlayer {
+                player_name: keeper.player.clone(),
+                team: keeper.team.clone(),
+                position,
+                price: keeper.price,
+            });
+        }
+        taken.sort_unstable();
+        taken.dedup();
+        for i in taken.into_iter().rev() {
+            self.available_players.remove(i);
+        }
+        info!("Applied {} keepers", keepers.len());
+        self.draft_state.keepers = keepers;
+        self.draft_state.apply_keepers();
+        self.update_inflation();
+    }
+
+    /// Sign a `[league.keepers] contract_years` contract for every player I
+    /// bought among the picks from `first_new` on, at the price paid.
+    /// Players already under contract keep the one they have.
+    fn sign_keeper_contracts(&mut self, first_new: usize) {
+        let years = self.config.league.keepers.contract_years;
+        if years == 0 {
+            return;
+        }
+        let Some(my_team_id) = self.draft_state.my_team().map(|t| t.team_id.clone()) else {
+            return;
+        };
+        for pick in &self.draft_state.picks[first_new..] {
+            if pick.team_id != my_team_id
+                || self.keeper_contracts.iter().any(|c| c.player == pick.player_name)
+            {
+                continue;
+            }
+            let contract = KeeperContract {
+                player: pick.player_name.clone(),
+                salary: pick.price,
+                years,
+                raise: None,
+                raise_pct: None,
+            };
+            if let Err(e) = self.db.save_keeper_contract(&contract) {
+                warn!("Failed to save keeper contract for {}: {}", contract.player, e);
+            }
+            self.keeper_contracts.push(contract);
+        }
+    }
+
+    /// The draft recap, the first time every roster slot is filled and a
+    /// recap destination is configured. `None` every other time.
+    pub fn take_draft_recap(&mut self) -> Option<notify::Recap> {
+        let total = self.draft_state.total_picks;
+        if self.recap_sent
+            || !self.config.notify.is_enabled()
+            || total == 0
+            || self.draft_state.picks.len() < total
+        {
+            return None;
+        }
+        self.recap_sent = true;
+        let mut recap = notify::Recap::build(&self.draft_state, &self.config.league.name, &self.pick_verdicts);
+        recap.stamp = Some(self.session_stamp.summary());
+        recap.settings_diff = match self.db.previous_session_stamp(&self.session_stamp.draft_id) {
+            Ok(previous) => previous.and_then(|previous| notify::SettingsDiff::between(&self.session_stamp, &previous)),
+            Err(e) => {
+                warn!("Failed to load the previous draft's session stamp: {}", e);
+                None
+            }
+        };
+        Some(recap)
+    }
+
+    /// Back up the database to `[backup] dir`, unless backups are off or no
+    /// pick has been made since the last one. Returns the new backup's path.
+    pub fn backup_database(&mut self) -> anyhow::Result<Option<PathBuf>> {
+        let Some(dir) = &self.config.backup.dir else {
+            return Ok(None);
+        };
+        let picks = self.draft_state.picks.len();
+        if self.backed_up_picks == Some(picks) {
+            return Ok(None);
+        }
+        let path = backup::write_backup(&self.db, Path::new(dir), self.config.backup.keep)?;
+        self.backed_up_picks = Some(picks);
+        Ok(Some(path))
+    }
+
+    /// Recompute the effective hitter/pitcher split from the room's spending
+    /// and, when it was learned, re-price the remaining pool at that split.
+    /// A no-op beyond resetting `budget_split` when adaptive mode is off.
+    pub fn apply_budget_split(&mut self) {
+        self.budget_split = auction::effective_budget_split(
+            &self.draft_state,
+            &self.config.league,
+            &self.config.strategy,
+        );
+        if self.budget_split.learned && !self.config.league.is_snake() {
+            let roster = self
+                .roster_config
+                .clone()
+                .unwrap_or_else(|| self.config.league.roster_slots());
+            auction::resplit_remaining_pool(
+                &mut self.available_players,
+                self.budget_split.hitting_fraction,
+                &roster,
+                self.config.league.salary_cap,
+                &self.config.strategy,
+            );
+            self.plugins.apply(&mut self.available_players);
+        }
+    }
+
+    /// Remember who nominated the player on the block, persisting it so the
+    /// nomination history survives a restart.
+    pub fn note_nominator(&mut self, nomination: &ActiveNomination) {
+        if !self.draft_state.note_nominator(nomination) {
+            return;
+        }
+        if let Err(e) = self.db.record_nomination(
+            &self.draft_id,
+            &nomination.player_name,
+            &nomination.nominated_by,
+        ) {
+            warn!("Failed to record nomination of {}: {}", nomination.player_name, e);
+        }
+    }
+
+    /// Whether `player_name` is in my nomination script.
+    pub fn is_scripted(&self, player_name: &str) -> bool {
+        self.nomination_queue.iter().any(|e| e.player == player_name)
+    }
+
+    /// Set the nomination order: `league.nomination_order` when configured,
+    /// otherwise the draft board's columns (left to right) when the update
+    /// carries the board. Teams neither lists go last.
+    pub fn refresh_nomination_order(&mut self, board: Option<&DraftBoardData>) {
+        if self.draft_state.teams.is_empty() {
+            return;
+        }
+        let configured = &self.config.league.nomination_order;
+        let names: Vec<&str> = if !configured.is_empty() {
+            configured.iter().map(String::as_str).collect()
+        } else if let Some(board) = board.filter(|b| !b.teams.is_empty()) {
+            let mut columns: Vec<_> = board.teams.iter().collect();
+            columns.sort_by_key(|t| t.column);
+            columns
+                .into_iter()
+                .map(|t| if t.team_id.is_empty() { t.team_name.as_str() } else { t.team_id.as_str() })
+                .collect()
+        } else {
+            return;
+        };
+        let order = nomination_order::order_from_names(&self.draft_state.teams, &names);
+        if order == self.draft_state.nomination_order {
+            return;
+        }
+        let unmatched: Vec<&str> = configured
+            .iter()
+            .map(String::as_str)
+            .filter(|name| {
+                !self.draft_state.teams.iter().any(|t| {
+                    t.team_name.eq_ignore_ascii_case(name) || t.team_id.eq_ignore_ascii_case(name)
+                })
+            })
+            .collect();
+        if !unmatched.is_empty() {
+            warn!("league.nomination_order names no team for: {}", unmatched.join(", "));
+        }
+        info!("Nomination order: {:?}", order);
+        self.draft_state.nomination_order = order;
+    }
+
+    /// Apply a quick-bid key to the current nomination and append it to the
+    /// decision journal.
+    ///
+    /// The cap key defaults to my prep sheet value for the player, or else
+    /// their inflation-adjusted value. Returns the updated intent, or `None` when nothing is on the block.
+    pub fn log_quick_bid(&mut self, key: QuickBid) -> Option<BidIntent> {
+        let nomination = self.draft_state.current_nomination.as_ref()?;
+        let my_value = self
+            .prep
+            .iter()
+            .find(|e| e.player == nomination.player_name)
+            .and_then(|e| e.my_value)
+            .map(|value| value.max(0.0).round() as u32);
+        let suggested_cap = my_value.or_else(|| {
+            identity::find_player(&self.available_players, &nomination.player_name, &nomination.position, None)
+                .best()
+                .map(|i| self.inflation.adjusted_bid(self.available_players[i].dollar_value))
+        });
+
+        let intent = self
+            .bid_intent
+            .get_or_insert_with(|| BidIntent::new(&nomination.player_name));
+        let (action, amount) = intent.resolve(key, nomination.current_bid, suggested_cap);
+        intent.apply(action, amount);
+
+        let entry = JournalEntry {
+            id: 0,
+            recorded_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            draft_id: self.draft_id.clone(),
+            player_name: nomination.player_name.clone(),
+            action,
+            amount,
+            current_bid: nomination.current_bid,
+        };
+        if let Err(e) = self.db.record_decision(&entry) {
+            warn!("Failed to record decision for {}: {}", entry.player_name, e);
+        }
+        self.bid_intent.clone()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::test_utils::*;
+    use wyncast_baseball::draft::pick::{DraftPick, Position};
+    use wyncast_baseball::draft::state::ActiveNomination;
+    use wyncast_baseball::valuation::projections::{AllProjections, ProjectionSource};
+    #[cfg(feature = "http")]
+    use wyncast_llm::client::LlmClient;
+    use crate::app::undo;
+
+    // -----------------------------------------------------------------------
+    // Tests: State diff detection -> pick recording -> recalculation
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn process_new_picks_updates_state() {
+        let mut state = create_test_app_state();
+        let initial_count = state.available_players.len();
+        let initial_pick_count = state.draft_state.pick_count;
+
+        let pick = DraftPick {
+            pick_number: 1,
+            team_id: "1".into(),
+            team_name: "Team 1".into(),
+            player_name: "H_Star".into(),
+            position: "1B".into(),
+            price: 45,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+
+        state.process_new_picks(vec![pick]);
+
+        // Pick count should increase
+        assert_eq!(state.draft_state.pick_count, initial_pick_count + 1);
+
+        // Player should be removed from available pool
+        assert_eq!(state.available_players.len(), initial_count - 1);
+        assert!(!state
+            .available_players
+            .iter()
+            .any(|p| p.name == "H_Star"));
+
+        // Team budget should be updated
+        let team = state.draft_state.team("1").unwrap();
+        assert_eq!(team.budget_spent, 45);
+        assert_eq!(team.budget_remaining, 215);
+    }
+
+    #[test]
+    fn a_pick_two_players_could_be_waits_until_resolved() {
+        let mut state = create_test_app_state();
+        let star = state.available_players.iter().position(|p| p.name == "H_Star").unwrap();
+        state.available_players[star].team = "LAD".into();
+        let star_value = state.available_players[star].dollar_value;
+        let mut namesake = state.available_players[star].clone();
+        namesake.team = "KC".into();
+        namesake.dollar_value = 3.0;
+        state.available_players.push(namesake);
+        let pool = state.available_players.len();
+
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45), test_pick("H_Star", "2", 2)]);
+        assert_eq!(state.draft_state.pick_count, 2);
+        assert_eq!(state.available_players.len(), pool, "neither H_Star is removed");
+        let pending: Vec<u32> = state.ambiguous_picks.iter().map(|p| p.pick_number).collect();
+        assert_eq!(pending, [1, 2]);
+        assert_eq!(state.build_snapshot().ambiguous_picks.len(), 2);
+
+        assert!(state.resolve_pick(1, "NYY").is_err());
+        assert_eq!(state.resolve_pick(1, "kc").unwrap(), "pick #1 is H_Star of KC");
+        // The other pick can only be the H_Star left.
+        assert!(state.ambiguous_picks.is_empty());
+        assert_eq!(state.available_players.len(), pool - 2);
+        assert!(state.available_players.iter().all(|p| p.name != "H_Star"));
+        // Each pick keeps the value of the H_Star it turned out to be.
+        assert_eq!(state.pick_values[&1], 3.0);
+        assert_eq!(state.pick_values[&2], star_value);
+        assert!(state.resolve_pick(2, "LAD").is_err(), "already settled");
+    }
+
+    #[test]
+    fn invariants_are_checked_every_few_picks() {
+        let mut state = create_test_app_state();
+        let names: Vec<String> = state.available_players.iter().take(5).map(|p| p.name.clone()).collect();
+        let first = state.available_players[0].clone();
+        state.process_new_picks(names.iter().take(4).map(|n| test_pick(n, "1", 5)).collect());
+        // A slip between checks goes unnoticed until the next one.
+        state.inflation.total_dollars_spent = 0.0;
+        state.available_players.push(first);
+        assert!(state.invariant_violations.is_empty());
+
+        state.process_new_picks(vec![test_pick(&names[4], "2", 5)]);
+        // The pick's inflation update put the spend right again.
+        let found: Vec<String> = state.invariant_violations.iter().map(ToString::to_string).collect();
+        assert_eq!(found, [format!("{} is on Team 1 and still available", names[0])]);
+        assert_eq!(state.build_snapshot().invariant_violations.len(), 1);
+    }
+
+    #[test]
+    fn losing_a_player_i_was_in_on_opens_plan_b_until_i_buy() {
+        let mut state = create_test_app_state();
+        state.bid_intent = Some(BidIntent {
+            player_name: "H_Star".into(),
+            interested: Some(true),
+            bid: Some(40),
+            cap: None,
+        });
+        state.process_new_picks(vec![test_pick("H_Star", "2", 45)]);
+
+        let lost = state.plan_b.clone().expect("outbid on H_Star");
+        assert_eq!(lost.player_name, "H_Star");
+        assert_eq!((lost.winner.as_str(), lost.price, lost.my_bid), ("Team 2", 45, Some(40)));
+        assert_eq!(state.outbid_on, [lost]);
+        let plan_b = state.build_snapshot().plan_b.unwrap();
+        assert!(plan_b.substitutes.iter().all(|s| s.name != "H_Star"));
+
+        // Players I was out on, or never flagged, aren't recorded.
+        state.bid_intent = None;
+        state.process_new_picks(vec![test_pick("H_Good", "2", 30)]);
+        assert_eq!(state.outbid_on.len(), 1);
+
+        state.process_new_picks(vec![test_pick("H_Mid", "1", 10)]);
+        assert!(state.plan_b.is_none());
+        assert!(state.build_snapshot().plan_b.is_none());
+        assert_eq!(state.outbid_on.len(), 1);
+    }
+
+    #[test]
+    fn player_pool_restriction_filters_projections_and_flags_picks() {
+        let mut state = create_test_app_state();
+        state.config.league.player_pool.leagues = vec!["NL".into()];
+        let mut yankee = test_hitter_projection("Yankee Slugger", 40);
+        yankee.team = "NYY".into();
+        let mut dodger = test_hitter_projection("Dodger Slugger", 35);
+        dodger.team = "LAD".into();
+        state.apply_projections(AllProjections {
+            hitters: vec![yankee, dodger],
+            pitchers: vec![],
+            source: ProjectionSource::Espn,
+        });
+
+        let names: Vec<&str> = state.available_players.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Dodger Slugger"]);
+        assert!(state.pool_excluded.contains("Yankee Slugger"));
+
+        state.process_new_picks(vec![
+            test_pick("Yankee Slugger", "1", 30),
+            test_pick("Dodger Slugger", "2", 25),
+        ]);
+        assert_eq!(state.build_snapshot().out_of_pool_picks, vec!["Yankee Slugger"]);
+    }
+
+    struct Hometown;
+
+    impl wyncast_baseball::valuation::plugins::ValuationPlugin for Hometown {
+        fn name(&self) -> &str {
+            "hometown"
+        }
+
+        fn adjust(&self, players: &mut [PlayerValuation]) {
+            for player in players.iter_mut().filter(|p| p.team == "SEA") {
+                player.dollar_value *= 3.0;
+            }
+        }
+    }
+
+    #[test]
+    fn plugins_adjust_the_initial_valuations() {
+        let mut state = create_test_app_state();
+        state.plugins.register(Hometown);
+        let mut mariner = test_hitter_projection("Mariner", 20);
+        mariner.team = "SEA".into();
+        state.apply_projections(AllProjections {
+            hitters: vec![test_hitter_projection("Slugger", 40), mariner],
+            pitchers: vec![],
+            source: ProjectionSource::Espn,
+        });
+
+        let names: Vec<&str> = state.available_players.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Mariner", "Slugger"]);
+    }
+
+    #[test]
+    fn custom_stats_reach_the_snapshot() {
+        let mut state = create_test_app_state();
+        let stats = CustomStats::parse(&["HR/PA = hr / pa".into(), "K-BB% = (k - bb) / ip".into()]);
+        state.custom_stat_names = stats.names();
+        state.plugins.register(stats);
+        state.apply_projections(AllProjections {
+            hitters: vec![test_hitter_projection("Slugger", 30)],
+            pitchers: vec![],
+            source: ProjectionSource::Espn,
+        });
+
+        let snapshot = state.build_snapshot();
+        assert_eq!(snapshot.custom_stats, ["HR/PA", "K-BB%"]);
+        assert_eq!(snapshot.available_players[0].custom_stats, vec![Some(0.05), None]);
+    }
+
+    #[test]
+    fn explain_player_follows_the_live_value() {
+        let mut state = create_test_app_state();
+        assert!(state.explain_player(&PlayerRef::named("Slugger One")).is_err(), "no projections yet");
+        state.apply_projections(AllProjections {
+            hitters: vec![
+                test_hitter_projection("Slugger One", 40),
+                test_hitter_projection("Slugger Two", 35),
+                test_hitter_projection("Slugger Three", 20),
+            ],
+            pitchers: vec![],
+            source: ProjectionSource::Espn,
+        });
+        state.process_new_picks(vec![test_pick("Slugger Three", "2", 30)]);
+
+        let player = state.available_players.iter().find(|p| p.name == "Slugger One").unwrap();
+        let explanation = state.explain_player(&PlayerRef::of(player)).unwrap();
+        assert_eq!(explanation.base_value, player.dollar_value);
+        assert_eq!(explanation.inflation_rate, state.inflation.inflation_rate);
+        assert_eq!(explanation.adjusted_value, state.inflation.adjust(player.dollar_value));
+        assert_eq!(
+            state.explain_player(&PlayerRef::named("Slugger Three")).unwrap_err(),
+            "Slugger Three is not in the available pool"
+        );
+    }
+
+    #[test]
+    fn compare_players_uses_the_live_values() {
+        let mut state = create_test_app_state();
+        state.apply_projections(AllProjections {
+            hitters: vec![
+                test_hitter_projection("Slugger One", 40),
+                test_hitter_projection("Slugger Two", 35),
+                test_hitter_projection("Slugger Three", 20),
+            ],
+            pitchers: vec![],
+            source: ProjectionSource::Espn,
+        });
+        state.process_new_picks(vec![test_pick("Slugger Three", "2", 30)]);
+
+        let comparison = state.compare_players(&PlayerRef::named("Slugger One"), &PlayerRef::named("Slugger Two")).unwrap();
+        let [one, two] = &comparison.players;
+        assert_eq!((one.name.as_str(), two.name.as_str()), ("Slugger One", "Slugger Two"));
+        let player = state.available_players.iter().find(|p| p.name == "Slugger One").unwrap();
+        assert_eq!(one.adjusted_value, state.inflation.adjust(player.dollar_value));
+        assert_eq!(
+            state.compare_players(&PlayerRef::named("Slugger One"), &PlayerRef::named("Slugger Three")).unwrap_err(),
+            "Slugger Three is not in the available pool"
+        );
+
+        // A namesake on another team is told apart by the team.
+        let mut namesake = player.clone();
+        namesake.team = "KC".into();
+        namesake.dollar_value = 1.0;
+        state.available_players.push(namesake.clone());
+        let comparison = state.compare_players(&PlayerRef::of(&namesake), &PlayerRef::named("Slugger Two")).unwrap();
+        assert_eq!(comparison.players[0].adjusted_value, state.inflation.adjust(1.0));
+    }
+
+    #[test]
+    fn draft_recap_is_taken_once_when_the_last_pick_lands() {
+        let mut state = create_test_app_state();
+        state.draft_state.total_picks = 2;
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45)]);
+        state.config.notify.html = true;
+        assert!(state.take_draft_recap().is_none(), "draft is not complete yet");
+
+        state.process_new_picks(vec![test_pick("P_Ace", "2", 30)]);
+        let recap = state.take_draft_recap().expect("draft is complete");
+        assert_eq!(recap.pick_count, 2);
+        assert_eq!(recap.total_spent, 75);
+        assert_eq!(recap.stamp, Some(state.session_stamp.summary()));
+        assert_eq!(recap.settings_diff, None, "no draft before this one");
+        assert!(state.take_draft_recap().is_none(), "recap goes out once");
+
+        // The next draft's recap compares its settings with this one's.
+        let previous = state.session_stamp.clone();
+        state.config.strategy.hitting_budget_fraction = 0.7;
+        state.session_stamp = stamp_session(&state.db, "draft_next", &state.config);
+        state.recap_sent = false;
+        let diff = state.take_draft_recap().unwrap().settings_diff.unwrap();
+        assert_eq!(diff.previous_draft, previous.draft_id);
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].key, "hitting_budget_fraction");
+    }
+
+    #[test]
+    fn the_session_is_stamped_at_start() {
+        let state = create_test_app_state();
+        let stamps = state.db.load_session_stamps(&state.draft_id).unwrap();
+        assert_eq!(stamps, vec![state.session_stamp.clone()]);
+        assert_eq!(stamps[0].app_version, env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn draft_recap_needs_a_destination() {
+        let mut state = create_test_app_state();
+        state.draft_state.total_picks = 1;
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45)]);
+        assert!(state.take_draft_recap().is_none());
+    }
+
+    #[test]
+    fn zero_dollar_league_reserves_nothing_for_open_slots() {
+        let mut state = create_test_app_state();
+        let snapshot = state.build_snapshot();
+        assert!(snapshot.max_bid < snapshot.budget_remaining, "$1 league reserves budget");
+
+        state.config.league.min_bid = 0;
+        state.process_new_picks(vec![test_pick("P_Ace", "2", 30)]);
+        let snapshot = state.build_snapshot();
+        assert_eq!(snapshot.min_bid, 0);
+        assert_eq!(snapshot.max_bid, snapshot.budget_remaining);
+        assert_eq!(state.inflation.min_bid, 0.0);
+    }
+
+    #[test]
+    fn nominators_are_persisted_and_feed_team_snapshots() {
+        let mut state = create_test_app_state();
+        let nomination = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 5,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        };
+        state.note_nominator(&nomination);
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45)]);
+
+        let saved = state.db.load_nominations(&state.draft_id).unwrap();
+        assert_eq!(saved.get("H_Star").map(String::as_str), Some("Team 2"));
+        let teams = state.build_snapshot().team_snapshots;
+        assert_eq!(teams[1].nominations.nominated, 1);
+        assert_eq!(teams[1].nominations.kept, 0);
+        assert_eq!(teams[0].nominations.nominated, 0);
+    }
+
+    #[test]
+    fn the_nomination_script_advances_with_the_draft() {
+        use wyncast_baseball::draft::nomination_queue::{Fallback, ScriptStatus};
+
+        let mut state = create_test_app_state();
+        let entry = |player: &str| QueueEntry { player: player.into(), fallback: Fallback::Skip, skip_if_filled: false };
+        state.nomination_queue = vec![entry("H_Star"), entry("H_Good")];
+        assert!(state.is_scripted("H_Good"));
+        let statuses = |state: &AppState| -> Vec<ScriptStatus> {
+            state.build_snapshot().nomination_script.into_iter().map(|s| s.status).collect()
+        };
+        assert_eq!(statuses(&state), [ScriptStatus::Next, ScriptStatus::Waiting]);
+
+        state.process_new_picks(vec![test_pick("H_Star", "2", 40)]);
+        assert_eq!(statuses(&state), [ScriptStatus::Drafted, ScriptStatus::Next]);
+    }
+
+    #[test]
+    fn nomination_order_comes_from_the_config_or_the_board() {
+        use crate::protocol::DraftBoardTeam;
+
+        let mut state = create_test_app_state();
+        let column = |team_id: &str, column| DraftBoardTeam {
+            team_id: team_id.into(),
+            team_name: format!("Team {team_id}"),
+            column,
+            is_my_team: false,
+            is_on_the_clock: false,
+            slots: vec![],
+        };
+        let board = DraftBoardData {
+            teams: vec![column("1", 1), column("2", 0)],
+            on_the_clock_team: None,
+        };
+
+        state.refresh_nomination_order(Some(&board));
+        assert_eq!(state.draft_state.nomination_order, vec![1, 0]);
+        let turn = state.build_snapshot().nomination_turn.unwrap();
+        assert_eq!(turn.next_team, "Team 2");
+        assert_eq!(turn.my_turn_in, Some(1));
+
+        // A configured order wins over the board.
+        state.config.league.nomination_order = vec!["team 1".into()];
+        state.refresh_nomination_order(Some(&board));
+        assert_eq!(state.draft_state.nomination_order, vec![0, 1]);
+    }
+
+    #[test]
+    fn keepers_leave_the_pool_and_charge_their_teams() {
+        let mut state = create_test_app_state();
+        state.config.league.keepers.kept = vec![config::Keeper { player: "H_Star".into(), team: "Team 2".into(), price: 40 }];
+        let available = state.available_players.len();
+
+        state.apply_keepers();
+        assert_eq!(state.available_players.len(), available - 1);
+        assert!(!state.available_players.iter().any(|p| p.name == "H_Star"));
+        let team = state.draft_state.team("2").unwrap();
+        assert!(team.roster.has_player("H_Star", None));
+        assert_eq!(team.budget_remaining, 220);
+        assert_eq!(state.inflation.total_dollars_spent, 40.0);
+
+        // Rebuilding the pool applies them again without charging twice.
+        state.apply_keepers();
+        assert_eq!(state.draft_state.keepers[0].position, "1B");
+        assert_eq!(state.draft_state.team("2").unwrap().budget_remaining, 220);
+    }
+
+    #[test]
+    fn manual_picks_can_be_undone_and_redone() {
+        let mut state = create_test_app_state();
+        state.config.league.keepers.contract_years = 2;
+        let available = state.available_players.len();
+        state.process_new_picks(vec![test_pick("P_Ace", "2", 30)]);
+        assert_eq!(state.undo(), Err("Nothing to undo".into()), "ESPN picks are not undoable");
+
+        state.apply_edit(undo::Edit::ManualPick {
+            pick: test_pick("H_Star", "1", 45),
+            removed: Vec::new(),
+            signed: None,
+        });
+        assert_eq!(state.draft_state.picks.len(), 2);
+        assert_eq!(state.keeper_contracts.len(), 1);
+
+        assert_eq!(state.undo(), Ok("Undid manual pick of H_Star".into()));
+        assert_eq!(state.draft_state.picks.len(), 1);
+        assert_eq!(state.draft_state.picks[0].player_name, "P_Ace");
+        assert_eq!(state.available_players.len(), available - 1);
+        assert!(state.available_players.iter().any(|p| p.name == "H_Star"));
+        assert!(state.keeper_contracts.is_empty());
+        assert!(state.db.load_keeper_contracts().unwrap().is_empty());
+        let saved = state.db.load_picks(&state.draft_id).unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].player_name, "P_Ace");
+        assert_eq!(state.undo(), Err("Nothing to undo".into()));
+
+        assert_eq!(state.redo(), Ok("Redid manual pick of H_Star".into()));
+        assert_eq!(state.draft_state.picks.len(), 2);
+        assert_eq!(state.keeper_contracts.len(), 1);
+        assert_eq!(state.db.load_picks(&state.draft_id).unwrap().len(), 2);
+        assert_eq!(state.redo(), Err("Nothing to redo".into()));
+    }
+
+    #[test]
+    fn pins_and_slot_moves_can_be_undone_and_redone() {
+        let mut state = create_test_app_state();
+        state.apply_edit(undo::Edit::Pin { name: "H_Star".into(), pinned: true });
+        assert_eq!(state.undo(), Ok("Undid pin of H_Star".into()));
+        assert_eq!(state.undo_stack.last(true).and_then(|e| e.pin(true)), Some(("H_Star", false)));
+        assert_eq!(state.redo(), Ok("Redid pin of H_Star".into()));
+        assert_eq!(state.undo_stack.last(false).and_then(|e| e.pin(false)), Some(("H_Star", true)));
+
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45)]);
+        let roster = |state: &AppState| state.draft_state.my_team().unwrap().roster.clone();
+        let first = roster(&state).slot_of("H_Star").unwrap();
+        let util = roster(&state).slots.iter().position(|s| s.position == Position::Utility).unwrap();
+        let catcher = roster(&state).slots.iter().position(|s| s.position == Position::Catcher).unwrap();
+
+        state.apply_edit(undo::Edit::MoveSlot { player: "H_Star".into(), from: first, to: catcher });
+        assert_eq!(roster(&state).slot_of("H_Star"), Some(first), "a first baseman can't catch");
+        assert_eq!(state.undo(), Ok("Undid pin of H_Star".into()), "nothing was moved to undo");
+
+        state.apply_edit(undo::Edit::MoveSlot { player: "H_Star".into(), from: first, to: util });
+        assert_eq!(roster(&state).slot_of("H_Star"), Some(util));
+        assert_eq!(state.undo(), Ok("Undid slot move of H_Star".into()));
+        assert_eq!(roster(&state).slot_of("H_Star"), Some(first));
+        assert_eq!(state.redo(), Ok("Redid slot move of H_Star".into()));
+        assert_eq!(roster(&state).slot_of("H_Star"), Some(util));
+        assert!(state.undo_stack.last(false).and_then(|e| e.pin(false)).is_none());
+    }
+
+    #[test]
+    fn database_backup_skips_when_no_pick_was_made() {
+        let dir = std::env::temp_dir().join("app_test_backup");
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut state = create_test_app_state();
+        assert!(state.backup_database().unwrap().is_none(), "backups are off");
+
+        state.config.backup.dir = Some(dir.to_string_lossy().into_owned());
+        let first = state.backup_database().unwrap().expect("first backup");
+        assert!(first.exists());
+        assert!(state.backup_database().unwrap().is_none(), "nothing changed");
+
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45)]);
+        assert!(state.backup_database().unwrap().is_some(), "a pick was made");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn process_new_picks_updates_inflation() {
+        let mut state = create_test_app_state();
+
+        let pick = DraftPick {
+            pick_number: 1,
+            team_id: "1".into(),
+            team_name: "Team 1".into(),
+            player_name: "H_Star".into(),
+            position: "1B".into(),
+            price: 45,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+
+        state.process_new_picks(vec![pick]);
+
+        // Inflation tracker should be updated
+        assert!(state.inflation.total_dollars_spent > 0.0);
+        assert!(state.inflation.inflation_rate.is_finite());
+    }
+
+    #[test]
+    fn snoozed_picks_wait_for_one_recalculation() {
+        let mut state = create_test_app_state();
+        assert!(state.recalc_deadline().is_none());
+        assert!(state.snooze_recalc());
+        assert!(!state.snooze_recalc(), "already snoozed");
+        let started = state.recalc_snooze.unwrap().last_activity;
+        assert_eq!(state.recalc_deadline(), Some(started + std::time::Duration::from_secs(10)));
+
+        state.process_new_picks(vec![test_pick("H_Star", "1", 45)]);
+        // The pick and budget are in, prices are not.
+        assert_eq!(state.draft_state.picks.len(), 1);
+        assert_eq!(state.draft_state.team("1").unwrap().budget_remaining, 215);
+        assert!(!state.available_players.iter().any(|p| p.name == "H_Star"));
+        assert_eq!(state.inflation.total_dollars_spent, 0.0);
+        assert_eq!(state.recalc_snooze.unwrap().skipped_picks, 1);
+
+        assert!(state.resume_recalc());
+        assert!(state.recalc_snooze.is_none());
+        assert_eq!(state.inflation.total_dollars_spent, 45.0);
+        assert!(!state.resume_recalc(), "not snoozed");
+
+        state.config.display.recalc_snooze_secs = 0;
+        state.snooze_recalc();
+        assert!(state.recalc_deadline().is_none(), "only a key ends it");
+    }
+
+    #[test]
+    fn process_new_picks_learns_budget_split_in_adaptive_mode() {
+        let mut state = create_test_app_state();
+        let pick = DraftPick {
+            pick_number: 1,
+            team_id: "1".into(),
+            team_name: "Team 1".into(),
+            player_name: "H_Star".into(),
+            position: "1B".into(),
+            price: 45,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+
+        // Fixed mode: the configured split, values untouched.
+        let mut fixed = create_test_app_state();
+        fixed.process_new_picks(vec![pick.clone()]);
+        assert!(!fixed.budget_split.learned);
+        assert!(fixed.build_snapshot().hitting_split > 0.0);
+
+        state.config.strategy.adaptive_budget_split = true;
+        state.process_new_picks(vec![pick]);
+
+        assert!(state.budget_split.learned);
+        let configured = state.config.strategy.hitting_budget_fraction;
+        assert!(
+            state.budget_split.hitting_fraction > configured,
+            "a room paying for hitters shifts the split toward hitters, got {}",
+            state.budget_split.hitting_fraction
+        );
+        let snapshot = state.build_snapshot();
+        assert!(snapshot.split_learned);
+        assert!((snapshot.hitting_split - state.budget_split.hitting_fraction).abs() < 1e-12);
+        assert!(state.inflation.inflation_rate.is_finite());
+    }
+
+    #[test]
+    fn process_new_picks_updates_scarcity() {
+        let mut state = create_test_app_state();
+
+        // Record the initial scarcity state for FirstBase
+        let initial_fb_count = state
+            .scarcity
+            .iter()
+            .find(|s| s.position == Position::FirstBase)
+            .map(|s| s.players_above_replacement);
+
+        let pick = DraftPick {
+            pick_number: 1,
+            team_id: "1".into(),
+            team_name: "Team 1".into(),
+            player_name: "H_Star".into(),
+            position: "1B".into(),
+            price: 45,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+
+        state.process_new_picks(vec![pick]);
+
+        // Scarcity should be recalculated
+        let new_fb_count = state
+            .scarcity
+            .iter()
+            .find(|s| s.position == Position::FirstBase)
+            .map(|s| s.players_above_replacement);
+
+        // After removing a 1B player, the count should change (or at least be recalculated)
+        // The exact change depends on whether H_Star had positive VOR
+        assert!(new_fb_count.is_some());
+        // Just verify scarcity was recomputed (if H_Star had positive VOR, count should decrease)
+        if let (Some(initial), Some(new)) = (initial_fb_count, new_fb_count) {
+            // If the star had positive VOR, count should decrease
+            if initial > 0 {
+                assert!(new <= initial);
+            }
+        }
+    }
+
+    #[test]
+    fn process_new_picks_persists_to_db() {
+        let mut state = create_test_app_state();
+
+        let pick = DraftPick {
+            pick_number: 1,
+            team_id: "1".into(),
+            team_name: "Team 1".into(),
+            player_name: "H_Star".into(),
+            position: "1B".into(),
+            price: 45,
+            espn_player_id: Some("espn_123".into()),
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+
+        state.process_new_picks(vec![pick]);
+
+        // Verify the pick was persisted to DB
+        let db_picks = state.db.load_picks(&state.draft_id).unwrap();
+        assert_eq!(db_picks.len(), 1);
+        assert_eq!(db_picks[0].player_name, "H_Star");
+        assert_eq!(db_picks[0].price, 45);
+        assert_eq!(db_picks[0].espn_player_id, Some("espn_123".into()));
+    }
+
+    #[test]
+    fn process_new_picks_persists_canonical_pick_number_to_db() {
+        let mut state = create_test_app_state();
+
+        // Simulate ESPN sending all picks with pick_number=1 (the known bug
+        // in ESPN's virtualized pick list)
+        let picks = vec![
+            DraftPick {
+                pick_number: 1,
+                team_id: "1".into(),
+                team_name: "Team 1".into(),
+                player_name: "H_Star".into(),
+                position: "1B".into(),
+                price: 45,
+                espn_player_id: Some("espn_1".into()),
+                eligible_slots: vec![],
+            assigned_slot: None,
+            },
+            DraftPick {
+                pick_number: 1,
+                team_id: "2".into(),
+                team_name: "Team 2".into(),
+                player_name: "P_Ace".into(),
+                position: "SP".into(),
+                price: 50,
+                espn_player_id: Some("espn_2".into()),
+                eligible_slots: vec![],
+            assigned_slot: None,
+            },
+            DraftPick {
+                pick_number: 1,
+                team_id: "3".into(),
+                team_name: "Team 3".into(),
+                player_name: "H_Good".into(),
+                position: "2B".into(),
+                price: 30,
+                espn_player_id: Some("espn_3".into()),
+                eligible_slots: vec![],
+            assigned_slot: None,
+            },
+        ];
+
+        state.process_new_picks(picks);
+
+        // All 3 picks should be persisted with canonical sequential pick numbers
+        let db_picks = state.db.load_picks(&state.draft_id).unwrap();
+        assert_eq!(db_picks.len(), 3, "All picks must be persisted, not just the first");
+        assert_eq!(db_picks[0].pick_number, 1);
+        assert_eq!(db_picks[0].player_name, "H_Star");
+        assert_eq!(db_picks[1].pick_number, 2);
+        assert_eq!(db_picks[1].player_name, "P_Ace");
+        assert_eq!(db_picks[2].pick_number, 3);
+        assert_eq!(db_picks[2].player_name, "H_Good");
+    }
+
+    #[test]
+    fn process_multiple_picks_at_once() {
+        let mut state = create_test_app_state();
+        let initial_count = state.available_players.len();
+
+        let picks = vec![
+            DraftPick {
+                pick_number: 1,
+                team_id: "team_1".into(),
+                team_name: "Team 1".into(),
+                player_name: "H_Star".into(),
+                position: "1B".into(),
+                price: 45,
+                espn_player_id: None,
+                eligible_slots: vec![],
+            assigned_slot: None,
+            },
+            DraftPick {
+                pick_number: 2,
+                team_id: "team_2".into(),
+                team_name: "Team 2".into(),
+                player_name: "P_Ace".into(),
+                position: "SP".into(),
+                price: 50,
+                espn_player_id: None,
+                eligible_slots: vec![],
+            assigned_slot: None,
+            },
+        ];
+
+        state.process_new_picks(picks);
+
+        assert_eq!(state.draft_state.pick_count, 2);
+        assert_eq!(state.available_players.len(), initial_count - 2);
+        assert!(!state.available_players.iter().any(|p| p.name == "H_Star"));
+        assert!(!state.available_players.iter().any(|p| p.name == "P_Ace"));
+    }
+
+    // -----------------------------------------------------------------------
+    // Tests: New picks update DraftState, available players, inflation
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn picks_update_draft_state_and_available() {
+        let mut state = create_test_app_state();
+
+        let pick = DraftPick {
+            pick_number: 1,
+            team_id: "2".into(),
+            team_name: "Team 2".into(),
+            player_name: "H_Good".into(),
+            position: "2B".into(),
+            price: 30,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+
+        state.process_new_picks(vec![pick]);
+
+        // DraftState should have the pick
+        assert_eq!(state.draft_state.picks.len(), 1);
+        assert_eq!(state.draft_state.picks[0].player_name, "H_Good");
+
+        // Team 2 budget should be updated
+        let team2 = state.draft_state.team("2").unwrap();
+        assert_eq!(team2.budget_spent, 30);
+
+        // H_Good should not be in available pool
+        assert!(!state
+            .available_players
+            .iter()
+            .any(|p| p.name == "H_Good"));
+    }
+
+    // -----------------------------------------------------------------------
+    // Tests: quick-bid decision journal
+    // -----------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn quick_bid_updates_intent_and_journals_it() {
+        use wyncast_core::journal::{JournalAction, QuickBid};
+
+        let mut state = create_test_app_state();
+        assert!(state.log_quick_bid(QuickBid::In).is_none(), "nothing on the block");
+
+        let nom = ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 17,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        };
+        state.handle_nomination(&nom);
+
+        state.log_quick_bid(QuickBid::In);
+        let intent = state.log_quick_bid(QuickBid::Cap).unwrap();
+        let expected_cap = state
+            .inflation
+            .adjust(state.available_players.iter().find(|p| p.name == "H_Star").unwrap().dollar_value)
+            .round() as u32;
+        assert_eq!(intent.bid, Some(18));
+        assert_eq!(intent.cap, Some(expected_cap));
+
+        let entries = state.db.load_decisions(&state.draft_id).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action, JournalAction::In);
+        assert_eq!(entries[0].amount, Some(18));
+        assert_eq!(entries[0].current_bid, 17);
+        assert_eq!(entries[1].action, JournalAction::Cap);
+
+        // A different player on the block starts a fresh intent.
+        let nom2 = ActiveNomination {
+            player_name: "H_Good".into(),
+            player_id: "espn_2".into(),
+            ..nom
+        };
+        state.handle_nomination(&nom2);
+        assert!(state.bid_intent.is_none());
+        let intent = state.log_quick_bid(QuickBid::Out).unwrap();
+        assert_eq!(intent.player_name, "H_Good");
+        assert_eq!(intent.cap, None);
+    }
+
+    #[tokio::test]
+    async fn my_prep_value_sets_the_cap() {
+        use wyncast_core::journal::QuickBid;
+
+        let mut state = create_test_app_state();
+        state.prep = vec![PrepEntry {
+            player: "H_Star".into(),
+            my_value: Some(31.4),
+            tier: Some(1),
+            target: true,
+            avoid: false,
+            note: String::new(),
+        }];
+        state.handle_nomination(&ActiveNomination {
+            player_name: "H_Star".into(),
+            player_id: "espn_1".into(),
+            position: "1B".into(),
+            nominated_by: "Team 2".into(),
+            current_bid: 17,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        });
+        let intent = state.log_quick_bid(QuickBid::Cap).unwrap();
+        assert_eq!(intent.cap, Some(31));
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn reload_llm_client_updates_client() {
+        let mut state = create_test_app_state();
+
+        // Initially disabled (no API key in test config)
+        assert!(matches!(&*state.llm_client, LlmClient::Disabled));
+
+        // Set an API key and reload
+        state.config.credentials.anthropic_api_key = Some("sk-ant-test-key".to_string());
+        state.reload_llm_client();
+
+        // Now the client should be Active
+        assert!(matches!(&*state.llm_client, LlmClient::Active(_)));
+    }
+}
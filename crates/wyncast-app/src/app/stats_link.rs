@@ -0,0 +1,70 @@
+// Player pages on external stats sites (FanGraphs, Baseball Savant), opened
+// in the system browser from the player detail for a quick look at a
+// surprising valuation.
+//
+// The URL comes from a template in the `[display]` section of
+// strategy.toml, with `{name}` and `{team}` filled in. The browser is
+// started with the platform's opener and left to run on its own.
+
+use std::io;
+use std::process::{Command, Stdio};
+
+/// Fill `template`'s `{name}` and `{team}` with the player's name and MLB
+/// team, URL-encoded.
+pub fn url(template: &str, name: &str, team: &str) -> String {
+    template
+        .replace("{name}", &encode(name))
+        .replace("{team}", &encode(team))
+}
+
+/// Percent-encode everything but unreserved characters, with spaces as `+`.
+fn encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Open `url` in the system browser.
+pub fn open(url: &str) -> io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        // Not `cmd /C start`: cmd would split the URL at each `&`.
+        let mut rundll = Command::new("rundll32");
+        rundll.arg("url.dll,FileProtocolHandler");
+        rundll
+    } else {
+        Command::new("xdg-open")
+    };
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    // Reap the opener when it exits; the browser outlives it.
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_the_template_url_encoded() {
+        assert_eq!(
+            url("https://example.com/?q={name}+{team}", "José Ramírez", "CLE"),
+            "https://example.com/?q=Jos%C3%A9+Ram%C3%ADrez+CLE"
+        );
+        assert_eq!(url("https://example.com/{name}", "A&B/C", ""), "https://example.com/A%26B%2FC");
+    }
+}
@@ -0,0 +1,104 @@
+// Support bundle: the complete app state dumped to one JSON file so a bug
+// report can carry the exact state that misbehaved instead of log excerpts.
+//
+// The draft state is written with `DraftState::to_json`, so it can be loaded
+// back with `DraftState::from_json`. The config is digested: the settings that
+// shape valuations are included, credentials are reduced to which providers
+// have a key.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde_json::{json, Value};
+
+use super::AppState;
+
+/// Bumped whenever the bundle layout changes.
+pub const BUNDLE_FORMAT: u32 = 1;
+
+/// Build the bundle for the current state.
+pub fn build(state: &AppState) -> anyhow::Result<Value> {
+    let draft_state: Value = serde_json::from_str(
+        &state
+            .draft_state
+            .to_json()
+            .context("failed to serialize draft state")?,
+    )?;
+
+    let available: Vec<Value> = state
+        .available_players
+        .iter()
+        .map(|p| {
+            json!({
+                "name": p.name,
+                "positions": p.positions.iter().map(|pos| pos.display_str()).collect::<Vec<_>>(),
+                "is_pitcher": p.is_pitcher,
+                "vor": p.vor,
+                "dollar_value": p.dollar_value,
+                "flex_bonus": p.flex_bonus,
+            })
+        })
+        .collect();
+
+    Ok(json!({
+        "format": BUNDLE_FORMAT,
+        "created_at": chrono::Utc::now().to_rfc3339(),
+        "app_version": env!("CARGO_PKG_VERSION"),
+        "app_mode": format!("{:?}", state.app_mode),
+        "connection_status": format!("{:?}", state.connection_status),
+        "draft_id": state.draft_id,
+        "session_stamp": state.session_stamp,
+        "espn_draft_id": state.espn_draft_id,
+        "config": config_digest(state),
+        "roster_config": state.roster_config,
+        "draft_state": draft_state,
+        "inflation": state.inflation,
+        "budget_split": state.budget_split,
+        "bid_intent": state.bid_intent,
+        "outbid_on": state.outbid_on,
+        "available_players": available,
+        "diff_explanations": state.diff_explanations,
+    }))
+}
+
+/// The parts of the config that shape valuations and connectivity. API keys
+/// are never included, only whether each provider has one.
+fn config_digest(state: &AppState) -> Value {
+    let config = &state.config;
+    let strategy = &config.strategy;
+    let credentials = &config.credentials;
+    json!({
+        "league": config.league,
+        "strategy": {
+            "hitting_budget_fraction": strategy.hitting_budget_fraction,
+            "adaptive_budget_split": strategy.adaptive_budget_split,
+            "weights": strategy.weights,
+            "pool": strategy.pool,
+            "flexibility": strategy.flexibility,
+            "valuation": strategy.valuation,
+            "llm_provider": strategy.llm.provider,
+            "llm_model": strategy.llm.model,
+            "has_strategy_overview": strategy.strategy_overview.is_some(),
+        },
+        "api_keys": {
+            "anthropic": credentials.anthropic_api_key.is_some(),
+            "google": credentials.google_api_key.is_some(),
+            "openai": credentials.openai_api_key.is_some(),
+        },
+        "ws_port": config.ws_port,
+        "data_paths": config.data_paths,
+    })
+}
+
+/// Write the bundle to a timestamped file in `dir` and return its path.
+pub fn write(state: &AppState, dir: &Path) -> anyhow::Result<PathBuf> {
+    let bundle = build(state)?;
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create {}", dir.display()))?;
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let path = dir.join(format!("support-{stamp}.json"));
+    let text = serde_json::to_string_pretty(&bundle)?;
+    std::fs::write(&path, text)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
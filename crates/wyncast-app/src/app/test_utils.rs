@@ -0,0 +1,229 @@
+// Shared fixtures for the app module tests.
+
+use super::AppState;
+use wyncast_core::config::*;
+use wyncast_core::db::Database;
+use wyncast_baseball::draft::pick::{DraftPick, Position};
+use wyncast_baseball::draft::state::DraftState;
+use wyncast_baseball::test_utils::{
+    self, make_hitter, make_pitcher, test_espn_budgets, test_roster_config,
+    test_strategy_config,
+};
+use wyncast_baseball::valuation::projections::{
+    AllProjections, HitterProjection, PitcherType, ProjectionSource,
+};
+use wyncast_baseball::valuation::zscore::PlayerValuation;
+use tokio::sync::mpsc;
+use wyncast_llm::client::LlmClient;
+use wyncast_core::stats::StatRegistry;
+use crate::onboarding::{OnboardingManager, RealFileSystem};
+use crate::protocol::{AppMode, UiUpdate};
+
+/// 2-team league config for app integration tests.
+pub(super) fn test_league_config() -> LeagueConfig {
+    let mut lc = test_utils::test_league_config();
+    lc.num_teams = 2;
+    lc
+}
+
+pub(super) fn test_config() -> Config {
+    Config {
+        league: test_league_config(),
+        strategy: test_strategy_config(),
+        credentials: CredentialsConfig::default(),
+        ws_port: 9001,
+        data_paths: DataPaths::default(),
+        logging: LoggingConfig::default(),
+        notify: Default::default(),
+        discord_bot: Default::default(),
+        accessibility: Default::default(),
+        display: Default::default(),
+        backup: Default::default(),
+    }
+}
+
+pub(super) fn test_players() -> Vec<PlayerValuation> {
+    vec![
+        make_hitter(
+            "H_Star",
+            100,
+            40,
+            100,
+            70,
+            20,
+            550,
+            0.300,
+            vec![Position::FirstBase],
+        ),
+        make_hitter(
+            "H_Good",
+            80,
+            25,
+            75,
+            55,
+            15,
+            530,
+            0.280,
+            vec![Position::SecondBase],
+        ),
+        make_hitter(
+            "H_Mid",
+            60,
+            15,
+            55,
+            40,
+            10,
+            500,
+            0.265,
+            vec![Position::ShortStop],
+        ),
+        make_hitter(
+            "H_Low",
+            45,
+            8,
+            40,
+            30,
+            5,
+            480,
+            0.250,
+            vec![Position::Catcher],
+        ),
+        make_pitcher(
+            "P_Ace",
+            250,
+            18,
+            0,
+            0,
+            200.0,
+            2.80,
+            1.00,
+            PitcherType::SP,
+        ),
+        make_pitcher(
+            "P_Good",
+            200,
+            14,
+            0,
+            0,
+            180.0,
+            3.20,
+            1.10,
+            PitcherType::SP,
+        ),
+        make_pitcher(
+            "P_Mid",
+            150,
+            10,
+            0,
+            0,
+            160.0,
+            3.80,
+            1.20,
+            PitcherType::SP,
+        ),
+    ]
+}
+
+pub(super) fn empty_projections() -> Option<AllProjections> {
+    Some(AllProjections {
+        hitters: vec![],
+        pitchers: vec![],
+        source: ProjectionSource::Espn,
+    })
+}
+
+pub(super) fn test_onboarding_manager() -> OnboardingManager<RealFileSystem> {
+    let tmp = std::env::temp_dir().join(format!("wyncast_test_{}", std::process::id()));
+    let _ = std::fs::create_dir_all(&tmp);
+    OnboardingManager::new(tmp, RealFileSystem)
+}
+
+pub(super) fn create_test_app_state() -> AppState {
+    let config = test_config();
+    let mut draft_state = DraftState::new(260, &test_roster_config());
+    // Register teams from ESPN data and set my team
+    draft_state.reconcile_budgets(&test_espn_budgets(2));
+    draft_state.set_my_team_by_id("1");
+
+    let mut available = test_players();
+
+    // Run initial valuation so dollar values are set
+    let test_roster = AppState::default_roster_config();
+    let registry = StatRegistry::from_league_config(&config.league)
+        .expect("test registry");
+    wyncast_baseball::valuation::recalculate_all(
+        &mut available,
+        &test_roster,
+        &config.league,
+        &config.strategy,
+        &draft_state,
+        &registry,
+    );
+
+    let db = Database::open(":memory:").expect("in-memory db");
+    let draft_id = Database::generate_draft_id();
+    let llm_client = LlmClient::Disabled;
+    let (llm_tx, _llm_rx) = mpsc::channel(16);
+
+    AppState::new(config, draft_state, available, empty_projections(), db, draft_id, llm_client, llm_tx, None, AppMode::Draft, test_onboarding_manager(), Some(test_roster_config()))
+}
+
+/// Drain the initial `StateSnapshot` that `run()` sends before entering
+/// the event loop. Tests that spawn `run()` and then assert on the first
+/// UI update must call this first, otherwise they'll see the snapshot
+/// instead of the event-driven update they expect.
+pub(super) async fn drain_initial_snapshot(ui_rx: &mut mpsc::Receiver<UiUpdate>) {
+    let update = ui_rx.recv().await.expect("should receive initial snapshot");
+    assert!(
+        matches!(update, UiUpdate::StateSnapshot(_)),
+        "Expected initial StateSnapshot, got {:?}", update
+    );
+}
+
+pub(super) fn test_pick(player_name: &str, team_id: &str, price: u32) -> DraftPick {
+    DraftPick {
+        pick_number: 0,
+        team_id: team_id.into(),
+        team_name: format!("Team {team_id}"),
+        player_name: player_name.into(),
+        position: "1B".into(),
+        price,
+        espn_player_id: None,
+        eligible_slots: vec![],
+        assigned_slot: None,
+    }
+}
+
+pub(super) fn test_hitter_projection(name: &str, hr: u32) -> HitterProjection {
+    HitterProjection {
+        name: name.into(),
+        team: "TST".into(),
+        pa: 600,
+        ab: 550,
+        h: 150,
+        hr,
+        r: 80,
+        rbi: 85,
+        bb: 50,
+        sb: 10,
+        avg: 0.273,
+        espn_position: "1B".into(),
+    }
+}
+
+/// Create an AppState WITHOUT teams registered (simulates the state
+/// before the first STATE_UPDATE containing team budget data).
+pub(super) fn create_test_app_state_no_teams() -> AppState {
+    let config = test_config();
+    let draft_state = DraftState::new(260, &test_roster_config());
+    // Do NOT call reconcile_budgets or set_my_team_by_id
+
+    let available = test_players();
+
+    let db = Database::open(":memory:").expect("in-memory db");
+    let llm_client = LlmClient::Disabled;
+    let (llm_tx, _llm_rx) = mpsc::channel(16);
+
+    let draft_id = Database::generate_draft_id();
+    AppState::new(config, draft_state, available, empty_projections(), db, draft_id, llm_client, llm_tx, None, AppMode::Draft, test_onboarding_manager(), Some(test_roster_config()))
+}
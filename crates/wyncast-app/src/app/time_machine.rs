@@ -0,0 +1,94 @@
+// Time machine: reconstruct the draft as it stood right after a past pick.
+//
+// The pick log in the database is replayed into a copy of the draft state
+// and the valuation pipeline is re-run, so budgets, inflation, scarcity, and
+// the available pool come out exactly as they were at that moment. Nothing
+// here touches live state.
+
+use tracing::warn;
+
+use wyncast_baseball::draft::pick::DraftPick;
+use wyncast_baseball::valuation::auction::{
+    effective_budget_split, resplit_remaining_pool, InflationTracker,
+};
+use wyncast_baseball::valuation::compute_initial;
+use wyncast_baseball::valuation::identity;
+use wyncast_baseball::valuation::scarcity::compute_scarcity;
+
+use crate::protocol::TimeMachineSnapshot;
+
+use super::snapshot::team_snapshots;
+use super::AppState;
+
+/// How many of the best remaining players a snapshot carries.
+const TOP_AVAILABLE: usize = 15;
+
+/// Rebuild the draft state as of right after pick `pick_number` (1-based).
+///
+/// Returns `None` if the pick doesn't exist or valuations can't be computed
+/// yet (no projections or roster config).
+pub(super) fn build_snapshot(state: &AppState, pick_number: usize) -> Option<TimeMachineSnapshot> {
+    let picks = pick_log(state);
+    if pick_number == 0 || pick_number > picks.len() {
+        return None;
+    }
+    let (Some(projections), Some(roster)) = (&state.all_projections, &state.roster_config) else {
+        return None;
+    };
+
+    let mut draft_state = state.draft_state.clone();
+    draft_state.restore_from_picks(picks[..pick_number].to_vec());
+    let pick = draft_state.picks.last()?.clone();
+
+    let mut pool = compute_initial(projections, &state.config, roster, &state.stat_registry);
+    state.plugins.apply(&mut pool);
+    let pick_value = identity::find_player(&pool, &pick.player_name, &pick.position, None)
+        .best()
+        .map(|i| pool[i].dollar_value);
+
+    // Players of an ambiguous name stay, as they did in the live pool.
+    let mut available = pool;
+    identity::remove_drafted(&mut available, &draft_state.picks);
+
+    let split = effective_budget_split(&draft_state, &state.config.league, &state.config.strategy);
+    if split.learned {
+        resplit_remaining_pool(
+            &mut available,
+            split.hitting_fraction,
+            roster,
+            state.config.league.salary_cap,
+            &state.config.strategy,
+        );
+        state.plugins.apply(&mut available);
+    }
+
+    let mut inflation = InflationTracker::new();
+    if !state.config.league.is_snake() {
+        inflation.update(&available, &draft_state, &state.config.league);
+        inflation.smooth(&draft_state, &state.pick_values, &state.config.strategy.valuation);
+    }
+    let scarcity = compute_scarcity(&available, roster, state.config.league.num_teams);
+
+    Some(TimeMachineSnapshot {
+        pick,
+        pick_value,
+        total_recorded: picks.len(),
+        inflation_rate: inflation.inflation_rate,
+        teams: team_snapshots(&draft_state, &state.config.league),
+        top_available: available.into_iter().take(TOP_AVAILABLE).collect(),
+        scarcity,
+    })
+}
+
+/// The persisted pick log for this draft, falling back to the in-memory
+/// picks if the database can't be read.
+fn pick_log(state: &AppState) -> Vec<DraftPick> {
+    match state.db.load_picks(&state.draft_id) {
+        Ok(picks) if !picks.is_empty() => picks,
+        Ok(_) => state.draft_state.picks.clone(),
+        Err(e) => {
+            warn!("Time machine: failed to load picks from DB: {}", e);
+            state.draft_state.picks.clone()
+        }
+    }
+}
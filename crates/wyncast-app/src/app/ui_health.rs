@@ -0,0 +1,146 @@
+// UI channel health: every update sent to the TUI goes through here.
+//
+// The channel to the TUI is bounded. When it is full the app waits for the
+// UI to catch up, but only for `UI_SEND_TIMEOUT`: a UI that is wedged must
+// not stall the draft. An update that times out is dropped, and one sent
+// after the TUI has gone is lost. Either way the UI no longer matches the
+// app's state, so the loss is counted and logged, and the TUI is told (with
+// the next update that gets through) to show a warning until a resync
+// re-sends the full snapshot.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::SendTimeoutError;
+use tracing::{info, warn};
+
+use crate::protocol::UiUpdate;
+
+/// How long a send waits for room in a full channel before dropping.
+pub const UI_SEND_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Send outcomes on the channel to the TUI. Shared with spawned tasks that
+/// report back to the UI.
+#[derive(Debug, Default)]
+pub struct UiHealth {
+    /// Updates lost since the last resync.
+    lost: AtomicUsize,
+    /// Sends that found the channel full, over the session.
+    full: AtomicUsize,
+    /// Whether the last send found the channel full, so each backlog is
+    /// logged once.
+    backed_up: AtomicBool,
+    /// Whether the TUI is yet to hear about the latest losses.
+    unreported: AtomicBool,
+}
+
+impl UiHealth {
+    /// Send `update` to the TUI, counting it if it is lost, and return
+    /// whether it was delivered. After a loss, the next update that gets
+    /// through is followed by a `UiUpdate::UiDesynced` with the count.
+    pub async fn send(&self, ui_tx: &mpsc::Sender<UiUpdate>, update: UiUpdate) -> bool {
+        if ui_tx.capacity() == 0 {
+            self.full.fetch_add(1, Ordering::Relaxed);
+            if !self.backed_up.swap(true, Ordering::Relaxed) {
+                warn!("UI channel full; waiting up to {:?} for the UI to catch up", UI_SEND_TIMEOUT);
+            }
+        } else {
+            self.backed_up.store(false, Ordering::Relaxed);
+        }
+
+        match ui_tx.send_timeout(update, UI_SEND_TIMEOUT).await {
+            Ok(()) => {
+                self.report(ui_tx);
+                true
+            }
+            Err(SendTimeoutError::Timeout(_)) => {
+                let lost = self.lost.fetch_add(1, Ordering::Relaxed) + 1;
+                self.unreported.store(true, Ordering::Relaxed);
+                warn!("Dropped a UI update: the UI did not catch up in {:?} ({} lost since the last resync)", UI_SEND_TIMEOUT, lost);
+                false
+            }
+            Err(SendTimeoutError::Closed(_)) => {
+                if self.lost.fetch_add(1, Ordering::Relaxed) == 0 {
+                    warn!("UI channel closed; updates are no longer reaching the UI");
+                }
+                false
+            }
+        }
+    }
+
+    /// Tell the TUI about unreported losses, if there is room for it.
+    fn report(&self, ui_tx: &mpsc::Sender<UiUpdate>) {
+        if !self.unreported.load(Ordering::Relaxed) {
+            return;
+        }
+        let lost = self.lost();
+        if ui_tx.try_send(UiUpdate::UiDesynced { lost }).is_ok() {
+            self.unreported.store(false, Ordering::Relaxed);
+        }
+    }
+
+    /// Updates lost since the last resync.
+    pub fn lost(&self) -> usize {
+        self.lost.load(Ordering::Relaxed)
+    }
+
+    /// Sends that found the channel full.
+    pub fn full(&self) -> usize {
+        self.full.load(Ordering::Relaxed)
+    }
+
+    /// Start counting afresh: the full snapshot about to go out brings the
+    /// UI back in line.
+    pub fn resynced(&self) {
+        let lost = self.lost.swap(0, Ordering::Relaxed);
+        self.unreported.store(false, Ordering::Relaxed);
+        if lost > 0 {
+            info!("Resyncing the UI after {} lost updates", lost);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::ConnectionStatus;
+
+    fn update() -> UiUpdate {
+        UiUpdate::ConnectionStatus(ConnectionStatus::Connected)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_dropped_update_is_reported_with_the_next_one() {
+        let health = UiHealth::default();
+        let (ui_tx, mut ui_rx) = mpsc::channel(2);
+        health.send(&ui_tx, update()).await;
+        health.send(&ui_tx, update()).await;
+
+        // The UI is not draining: the third update times out.
+        assert!(!health.send(&ui_tx, update()).await);
+        assert_eq!((health.lost(), health.full()), (1, 1));
+
+        ui_rx.recv().await.unwrap();
+        ui_rx.recv().await.unwrap();
+        health.send(&ui_tx, update()).await;
+        assert!(matches!(ui_rx.recv().await, Some(UiUpdate::ConnectionStatus(_))));
+        assert!(matches!(ui_rx.recv().await, Some(UiUpdate::UiDesynced { lost: 1 })));
+
+        health.resynced();
+        assert_eq!(health.lost(), 0);
+        health.send(&ui_tx, update()).await;
+        assert!(matches!(ui_rx.recv().await, Some(UiUpdate::ConnectionStatus(_))));
+        assert!(ui_rx.try_recv().is_err(), "nothing more to report");
+    }
+
+    #[tokio::test]
+    async fn sends_to_a_closed_channel_are_counted() {
+        let health = UiHealth::default();
+        let (ui_tx, ui_rx) = mpsc::channel(4);
+        drop(ui_rx);
+        health.send(&ui_tx, update()).await;
+        health.send(&ui_tx, update()).await;
+        assert_eq!(health.lost(), 2);
+    }
+}
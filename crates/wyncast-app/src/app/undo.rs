@@ -0,0 +1,174 @@
+// Undo/redo for edits made by hand in the app, as opposed to the picks and
+// state that arrive from ESPN. Each edit is a reversible command: it applies
+// itself to the AppState and knows how to take itself back. Undoing an edit
+// moves it to the redo stack; making a new edit clears that stack.
+//
+// Pins live in the TUI's Available panel: a pin edit only records the
+// change, and undoing or redoing it hands the pin back to the TUI.
+
+use tracing::{info, warn};
+use wyncast_baseball::draft::pick::DraftPick;
+use wyncast_baseball::valuation::identity;
+use wyncast_baseball::valuation::zscore::PlayerValuation;
+use wyncast_core::config::KeeperContract;
+
+use super::AppState;
+
+/// Most edits kept for undo; the oldest are dropped first.
+const MAX_UNDO: usize = 50;
+
+/// A reversible, user-initiated edit.
+#[derive(Debug, Clone)]
+pub enum Edit {
+    /// A pick entered by hand, as recorded, with the players it took out
+    /// of the available pool and the keeper contract it signed if it was
+    /// mine.
+    ManualPick {
+        pick: DraftPick,
+        removed: Vec<PlayerValuation>,
+        signed: Option<KeeperContract>,
+    },
+    /// A player pinned (or unpinned) on the Available tab.
+    Pin { name: String, pinned: bool },
+    /// One of my players moved from roster slot `from` to the empty slot
+    /// `to`.
+    MoveSlot { player: String, from: usize, to: usize },
+}
+
+impl Edit {
+    /// Short description for the status bar, e.g. "manual pick of Juan Soto".
+    pub fn describe(&self) -> String {
+        match self {
+            Edit::ManualPick { pick, .. } => format!("manual pick of {}", pick.player_name),
+            Edit::Pin { name, pinned: true } => format!("pin of {name}"),
+            Edit::Pin { name, pinned: false } => format!("unpin of {name}"),
+            Edit::MoveSlot { player, .. } => format!("slot move of {player}"),
+        }
+    }
+
+    /// Apply the edit. Returns `None` when it changed nothing (e.g. the
+    /// player had already been picked), so there is nothing to undo.
+    fn apply(self, state: &mut AppState) -> Option<Edit> {
+        match self {
+            Edit::ManualPick { pick, .. } => {
+                let (picks, contracts) = (state.draft_state.picks.len(), state.keeper_contracts.len());
+                let removed = identity::find_player(&state.available_players, &pick.player_name, &pick.position, None)
+                    .unique()
+                    .map(|i| state.available_players[i].clone())
+                    .into_iter()
+                    .collect();
+                state.process_new_picks(vec![pick]);
+                let recorded = state.draft_state.picks.get(picks)?.clone();
+                Some(Edit::ManualPick {
+                    pick: recorded,
+                    removed,
+                    signed: state.keeper_contracts.get(contracts).cloned(),
+                })
+            }
+            Edit::Pin { .. } => Some(self),
+            Edit::MoveSlot { ref player, from, to } => move_slot(state, player, from, to).then_some(self),
+        }
+    }
+
+    /// Take the edit back.
+    fn revert(&self, state: &mut AppState) {
+        match self {
+            Edit::ManualPick { pick, removed, signed } => {
+                if let Some(contract) = signed {
+                    state.keeper_contracts.retain(|c| c.player != contract.player);
+                    if let Err(e) = state.db.delete_keeper_contract(&contract.player) {
+                        warn!("Failed to delete keeper contract for {}: {}", contract.player, e);
+                    }
+                }
+                let picks = state
+                    .draft_state
+                    .picks
+                    .iter()
+                    .filter(|p| !(p.player_name == pick.player_name && p.team_id == pick.team_id))
+                    .cloned()
+                    .collect();
+                state.rebuild_from_picks(picks, removed.clone());
+            }
+            Edit::Pin { .. } => {}
+            Edit::MoveSlot { player, from, to } => {
+                if !move_slot(state, player, *to, *from) {
+                    warn!("Could not move {} back: my roster changed since", player);
+                }
+            }
+        }
+    }
+
+    /// The pin to hand back to the TUI once this edit is undone (`undone`)
+    /// or redone.
+    pub fn pin(&self, undone: bool) -> Option<(&str, bool)> {
+        match self {
+            Edit::Pin { name, pinned } => Some((name, *pinned != undone)),
+            _ => None,
+        }
+    }
+}
+
+/// Move `player` on my roster from slot `from` to the empty slot `to`, if
+/// they are still in `from`.
+fn move_slot(state: &mut AppState, player: &str, from: usize, to: usize) -> bool {
+    let Some(roster) = state.draft_state.my_team_mut().map(|t| &mut t.roster) else {
+        return false;
+    };
+    roster.slot_of(player) == Some(from) && roster.move_player(from, to)
+}
+
+/// Edits that can be undone, and the undone edits that can be redone.
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    done: Vec<Edit>,
+    undone: Vec<Edit>,
+}
+
+impl UndoStack {
+    fn push(&mut self, edit: Edit) {
+        if self.done.len() == MAX_UNDO {
+            self.done.remove(0);
+        }
+        self.done.push(edit);
+        self.undone.clear();
+    }
+
+    /// The edit just undone (`undone`) or redone.
+    pub fn last(&self, undone: bool) -> Option<&Edit> {
+        if undone { self.undone.last() } else { self.done.last() }
+    }
+}
+
+impl AppState {
+    /// Apply an edit made by hand and make it undoable.
+    pub fn apply_edit(&mut self, edit: Edit) {
+        let description = edit.describe();
+        match edit.apply(self) {
+            Some(applied) => self.undo_stack.push(applied),
+            None => info!("Edit changed nothing: {}", description),
+        }
+    }
+
+    /// Undo the last edit. Returns what was undone, for the status bar.
+    pub fn undo(&mut self) -> Result<String, String> {
+        let edit = self.undo_stack.done.pop().ok_or("Nothing to undo")?;
+        edit.revert(self);
+        let message = format!("Undid {}", edit.describe());
+        info!("{}", message);
+        self.undo_stack.undone.push(edit);
+        Ok(message)
+    }
+
+    /// Redo the last undone edit. Returns what was redone.
+    pub fn redo(&mut self) -> Result<String, String> {
+        let edit = self.undo_stack.undone.pop().ok_or("Nothing to redo")?;
+        let description = edit.describe();
+        let applied = edit
+            .apply(self)
+            .ok_or_else(|| format!("Could not redo the {description}"))?;
+        self.undo_stack.done.push(applied);
+        let message = format!("Redid {description}");
+        info!("{}", message);
+        Ok(message)
+    }
+}
@@ -0,0 +1,106 @@
+// Values report: the `--values` quick mode. Runs the valuation pipeline on
+// the configured projection CSVs, with the player pool, adjustments and
+// valuation scripts applied, and renders the top players at each position with their tiers as
+// plain text. No database, WebSocket server or TUI is involved, so it can be
+// rerun after every tweak to the weights. The roster is `[league.roster]`, or
+// the default layout, since the league's own arrives from ESPN during the draft.
+
+use std::fmt::Write;
+
+use anyhow::Context;
+use wyncast_baseball::valuation::{self, tiers::{self, PositionTiers}};
+use wyncast_baseball::valuation::plugins::Plugins;
+use wyncast_baseball::valuation::projections;
+use wyncast_core::config::Config;
+use wyncast_core::stamp::SessionStamp;
+use wyncast_core::stats::StatRegistry;
+
+use super::state::{adjust_projections, load_adjustments, restrict_to_player_pool};
+
+/// Players listed per position when `--values` is given no count.
+pub const DEFAULT_TOP_N: usize = 10;
+
+/// Value the configured projections and rank the top `top_n` players at
+/// each position.
+pub fn compute(config: &Config, top_n: usize) -> anyhow::Result<Vec<PositionTiers>> {
+    let mut projections = projections::load_all(config)
+        .context("failed to load projections")?
+        .context("--values needs hitter and pitcher CSVs under [data_paths]")?;
+    restrict_to_player_pool(&mut projections, config);
+    adjust_projections(&mut projections, &load_adjustments(config));
+    let registry = StatRegistry::from_league_config(&config.league)
+        .context("the league's categories are invalid")?;
+    let roster = config.league.roster_slots();
+    let mut players = valuation::compute_initial(&projections, config, &roster, &registry);
+    Plugins::load(&config.data_paths).apply(&mut players);
+    Ok(tiers::position_tiers(&players, &roster, top_n))
+}
+
+/// Render the rankings for the terminal: the league and the model version
+/// and inputs, then a heading per position and one line per player with
+/// their tier and dollar value.
+pub fn render(config: &Config, rankings: &[PositionTiers]) -> String {
+    let mut out = format!(
+        "{}: {} teams, ${} cap, default roster\nModel: {}\n",
+        config.league.name,
+        config.league.num_teams,
+        config.league.salary_cap,
+        SessionStamp::new("values", config).summary()
+    );
+    for ranking in rankings {
+        let _ = writeln!(out, "\n{}", ranking.position.display_str());
+        if ranking.players.is_empty() {
+            out.push_str("  (no players)\n");
+        }
+        for player in &ranking.players {
+            let _ = writeln!(
+                out,
+                "  T{:<2} ${:>3.0}  {} ({})  VOR {:.1}",
+                player.tier, player.dollar_value, player.name, player.team, player.vor
+            );
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wyncast_baseball::draft::pick::Position;
+    use wyncast_baseball::valuation::tiers::TieredPlayer;
+
+    #[test]
+    fn render_lists_each_position_with_tiers() {
+        let config = crate::app::test_utils::test_config();
+        let player = |name: &str, dollar_value, tier| TieredPlayer {
+            name: name.into(),
+            team: "NYY".into(),
+            dollar_value,
+            vor: 2.5,
+            tier,
+        };
+        let rankings = [
+            PositionTiers {
+                position: Position::ShortStop,
+                players: vec![player("Star", 41.2, 1), player("Glove", 12.0, 2)],
+            },
+            PositionTiers { position: Position::Catcher, players: vec![] },
+        ];
+        let text = render(&config, &rankings);
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[1].starts_with(&format!("Model: wyncast {}, config ", env!("CARGO_PKG_VERSION"))));
+        assert_eq!(lines[3], "SS");
+        assert_eq!(lines[4], "  T1  $ 41  Star (NYY)  VOR 2.5");
+        assert_eq!(lines[5], "  T2  $ 12  Glove (NYY)  VOR 2.5");
+        assert_eq!(lines[7..], ["C", "  (no players)"]);
+    }
+
+    #[test]
+    fn compute_needs_projection_files() {
+        let mut config = crate::app::test_utils::test_config();
+        config.data_paths.hitters = None;
+        config.data_paths.pitchers = None;
+        let err = compute(&config, DEFAULT_TOP_N).unwrap_err();
+        assert!(format!("{err:#}").contains("[data_paths]"), "{err:#}");
+    }
+}
@@ -0,0 +1,185 @@
+// League setup: the onboarding wizard's league questions (size, cap, roster
+// slots, scored categories and projection CSVs), shared by the TUI form and
+// the app, which checks the answers and writes them to league.toml and
+// strategy.toml.
+//
+// The form edits plain text, so the roster and category lists are parsed
+// and formatted here: a roster reads `C:1 1B:1 OF:3 SP:5`, categories
+// `R, HR, RBI`.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use wyncast_baseball::valuation::projections::resolve_data_path;
+use wyncast_core::config::{self, Config, ConfigError};
+use wyncast_core::stats::StatRegistry;
+
+/// Roster slots in the order they are listed; others follow alphabetically.
+const SLOT_ORDER: &[&str] = &[
+    "C", "1B", "2B", "3B", "SS", "MI", "CI", "LF", "CF", "RF", "OF", "UTIL", "DH", "SP", "RP", "P", "BE", "IL", "NA",
+];
+
+/// The league answers of the onboarding wizard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeagueSetup {
+    pub num_teams: usize,
+    pub salary_cap: u32,
+    pub roster: HashMap<String, usize>,
+    pub batting_categories: Vec<String>,
+    pub pitching_categories: Vec<String>,
+    /// Projection CSVs; both or neither, to wait for ESPN's projections.
+    pub hitters: Option<String>,
+    pub pitchers: Option<String>,
+}
+
+impl Default for LeagueSetup {
+    fn default() -> Self {
+        Self::from_config(&Config::default())
+    }
+}
+
+impl LeagueSetup {
+    /// The answers `config` already holds.
+    pub fn from_config(config: &Config) -> Self {
+        LeagueSetup {
+            num_teams: config.league.num_teams,
+            salary_cap: config.league.salary_cap,
+            roster: config.league.roster_slots(),
+            batting_categories: config.league.batting_categories.categories.clone(),
+            pitching_categories: config.league.pitching_categories.categories.clone(),
+            hitters: config.data_paths.hitters.clone(),
+            pitchers: config.data_paths.pitchers.clone(),
+        }
+    }
+
+    /// Write the answers into `config`. A newly scored category gets a
+    /// weight of 1.0, and the player pool grows to cover the roster.
+    pub fn apply(&self, config: &mut Config) {
+        config.league.num_teams = self.num_teams;
+        config.league.salary_cap = self.salary_cap;
+        config.league.roster = self.roster.clone();
+        config.league.batting_categories.categories = self.batting_categories.clone();
+        config.league.pitching_categories.categories = self.pitching_categories.clone();
+        config.data_paths.hitters = self.hitters.clone();
+        config.data_paths.pitchers = self.pitchers.clone();
+        for category in self.categories() {
+            if config.strategy.weights.get(category).is_none() {
+                config.strategy.weights.0.insert(category.clone(), 1.0);
+            }
+        }
+        config.strategy.pool = config.strategy.pool.covering(&config.league.roster_slots(), self.num_teams);
+    }
+
+    /// Batting then pitching categories.
+    pub fn categories(&self) -> impl Iterator<Item = &String> {
+        self.batting_categories.iter().chain(&self.pitching_categories)
+    }
+
+    /// What is wrong with `config` after `apply`: every settings problem,
+    /// an unknown or repeated category, and projection files that are
+    /// missing or given alone. Empty when it can be saved.
+    pub fn problems(&self, config: &Config) -> Vec<String> {
+        let mut problems = match config::validate(config) {
+            Err(ConfigError::Invalid(problems)) => problems.iter().map(ToString::to_string).collect(),
+            Err(e) => vec![e.to_string()],
+            Ok(()) => Vec::new(),
+        };
+        if self.batting_categories.is_empty() || self.pitching_categories.is_empty() {
+            problems.push("score at least one batting and one pitching category".into());
+        }
+        if let Err(e) = StatRegistry::from_league_config(&config.league) {
+            problems.push(e.to_string());
+        }
+        match (&self.hitters, &self.pitchers) {
+            (Some(_), None) => problems.push("give the pitcher projections too, or neither".into()),
+            (None, Some(_)) => problems.push("give the hitter projections too, or neither".into()),
+            _ => {}
+        }
+        for path in self.hitters.iter().chain(&self.pitchers) {
+            if !Path::new(&resolve_data_path(path)).is_file() {
+                problems.push(format!("no file at {path}"));
+            }
+        }
+        problems
+    }
+}
+
+/// Parse a roster like `C:1 1B:1 OF:3` (also `C=1`, comma-separated).
+pub fn parse_roster(text: &str) -> Result<HashMap<String, usize>, String> {
+    let mut roster = HashMap::new();
+    for entry in text.split([' ', ',']).filter(|e| !e.is_empty()) {
+        let (slot, count) = entry
+            .split_once([':', '='])
+            .ok_or_else(|| format!("'{entry}' is not SLOT:COUNT"))?;
+        let count: usize = count.parse().map_err(|_| format!("'{entry}': {count} is not a count"))?;
+        if roster.insert(slot.to_ascii_uppercase(), count).is_some() {
+            return Err(format!("{slot} is listed twice"));
+        }
+    }
+    if roster.is_empty() {
+        return Err("list the roster slots, e.g. C:1 1B:1 OF:3 SP:5".into());
+    }
+    Ok(roster)
+}
+
+/// Format a roster for `parse_roster`, in the usual slot order.
+pub fn format_roster(roster: &HashMap<String, usize>) -> String {
+    let mut slots: Vec<(&String, &usize)> = roster.iter().collect();
+    slots.sort_by_key(|(slot, _)| {
+        let rank = SLOT_ORDER.iter().position(|s| s == slot).unwrap_or(SLOT_ORDER.len());
+        (rank, slot.as_str())
+    });
+    slots
+        .iter()
+        .map(|(slot, count)| format!("{slot}:{count}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse a category list like `R, HR, RBI`, upper-cased.
+pub fn parse_categories(text: &str) -> Vec<String> {
+    text.split([' ', ','])
+        .filter(|c| !c.is_empty())
+        .map(str::to_ascii_uppercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rosters_parse_and_format_in_slot_order() {
+        let roster = parse_roster("sp:5 C:1, OF=3 1B:1").unwrap();
+        assert_eq!(roster["SP"], 5);
+        assert_eq!(format_roster(&roster), "C:1 1B:1 OF:3 SP:5");
+
+        assert_eq!(parse_roster("C 1").unwrap_err(), "'C' is not SLOT:COUNT");
+        assert!(parse_roster("C:1 C:2").is_err());
+        assert!(parse_roster("  ").is_err());
+        assert_eq!(parse_categories("r, hr,OBP"), ["R", "HR", "OBP"]);
+    }
+
+    #[test]
+    fn answers_are_checked_before_saving() {
+        let mut setup = LeagueSetup::default();
+        let mut config = Config::default();
+        setup.apply(&mut config);
+        assert!(setup.problems(&config).is_empty());
+
+        setup.num_teams = 0;
+        setup.batting_categories.push("OBP".into());
+        setup.pitching_categories.push("XYZ".into());
+        setup.hitters = Some("/nonexistent/hitters.csv".into());
+        let mut config = Config::default();
+        setup.apply(&mut config);
+        // New categories are weighted, so only the unknown one is a problem.
+        assert_eq!(config.strategy.weights.get("OBP"), Some(1.0));
+        let problems = setup.problems(&config);
+        assert!(problems.iter().any(|p| p.contains("league.num_teams")), "{problems:?}");
+        assert!(problems.iter().any(|p| p.contains("XYZ")), "{problems:?}");
+        assert!(problems.iter().any(|p| p.contains("pitcher projections")), "{problems:?}");
+        assert!(problems.iter().any(|p| p.contains("no file at /nonexistent/hitters.csv")), "{problems:?}");
+        assert!(!problems.iter().any(|p| p.contains("OBP")), "{problems:?}");
+    }
+}
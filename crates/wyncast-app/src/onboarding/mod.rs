@@ -7,6 +7,7 @@
 // inject a fake implementation and avoid writing to disk.
 
 pub mod fs;
+pub mod league_setup;
 pub mod strategy_config;
 
 use serde::{Deserialize, Serialize};
@@ -29,6 +30,8 @@ use wyncast_core::llm::provider::LlmProvider;
 pub enum OnboardingStep {
     #[default]
     LlmSetup,
+    /// League size, cap, roster, categories and projection CSVs.
+    LeagueSetup,
     StrategySetup,
     Complete,
 }
@@ -231,6 +234,61 @@ impl<F: FileSystem> OnboardingManager<F> {
         self.fs.rename(&tmp_path, &path)
     }
 
+    /// Save the league setup answers, as applied to `config`: the size, cap,
+    /// roster and categories to `league.toml`, the projection CSVs, category
+    /// weights and pool sizes to `strategy.toml`. Other settings in either
+    /// file are kept. Atomic write-to-temp-then-rename, league first.
+    pub fn save_league(&self, config: &wyncast_core::config::Config) -> std::io::Result<()> {
+        self.fs.create_dir_all(&self.config_dir)?;
+        let league = &config.league;
+
+        let path = self.config_dir.join("league.toml");
+        let mut doc: toml::Table = toml::from_str(&self.fs.read_to_string(&path).unwrap_or_default()).unwrap_or_default();
+        let table = sub_table(&mut doc, "league");
+        if !table.contains_key("name") {
+            // A fresh file needs the fields the loader requires.
+            let defaults = toml::Table::try_from(wyncast_core::config::LeagueConfig::default()).map_err(std::io::Error::other)?;
+            table.extend(defaults);
+        }
+        table.insert("num_teams".into(), toml::Value::Integer(league.num_teams as i64));
+        table.insert("salary_cap".into(), toml::Value::Integer(i64::from(league.salary_cap)));
+        table.insert("roster".into(), toml::Value::try_from(&league.roster).map_err(std::io::Error::other)?);
+        for (section, categories) in [
+            ("batting_categories", &league.batting_categories.categories),
+            ("pitching_categories", &league.pitching_categories.categories),
+        ] {
+            sub_table(table, section).insert(
+                "categories".into(),
+                toml::Value::try_from(categories).map_err(std::io::Error::other)?,
+            );
+        }
+        self.write_atomically(&path, &doc)?;
+
+        let path = self.config_dir.join("strategy.toml");
+        let mut doc: toml::Table = toml::from_str(&self.fs.read_to_string(&path).unwrap_or_default()).unwrap_or_default();
+        let paths = sub_table(&mut doc, "data_paths");
+        for (key, value) in [("hitters", &config.data_paths.hitters), ("pitchers", &config.data_paths.pitchers)] {
+            match value {
+                Some(value) => paths.insert(key.into(), toml::Value::String(value.clone())),
+                None => paths.remove(key),
+            };
+        }
+        let weights = sub_table(&mut doc, "category_weights");
+        for (category, weight) in config.strategy.weights.iter() {
+            weights.insert(category.to_string(), toml::Value::Float(weight));
+        }
+        let pool = toml::Table::try_from(&config.strategy.pool).map_err(std::io::Error::other)?;
+        sub_table(&mut doc, "pool").extend(pool);
+        self.write_atomically(&path, &doc)
+    }
+
+    fn write_atomically(&self, path: &std::path::Path, doc: &toml::Table) -> std::io::Result<()> {
+        let text = toml::to_string_pretty(doc).map_err(std::io::Error::other)?;
+        let tmp_path = path.with_extension("toml.tmp");
+        self.fs.write(&tmp_path, &text)?;
+        self.fs.rename(&tmp_path, path)
+    }
+
     /// Save credentials to `credentials.toml` using atomic write-to-temp-then-rename.
     pub fn save_credentials(&self, credentials: &CredentialsConfig) -> std::io::Result<()> {
         self.fs.create_dir_all(&self.config_dir)?;
@@ -263,6 +321,15 @@ impl<F: FileSystem> OnboardingManager<F> {
 // Private helpers
 // ---------------------------------------------------------------------------
 
+/// The table at `key` in `doc`, made (or replacing a non-table) if need be.
+fn sub_table<'a>(doc: &'a mut toml::Table, key: &str) -> &'a mut toml::Table {
+    let value = doc.entry(key).or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    if !value.is_table() {
+        *value = toml::Value::Table(toml::Table::new());
+    }
+    value.as_table_mut().expect("just made a table")
+}
+
 /// Pure logic check: returns `true` when progress + credentials indicate the
 /// app is fully configured. No I/O -- only inspects the provided data.
 fn check_configured(progress: &OnboardingProgress, credentials: &CredentialsConfig) -> bool {
@@ -407,6 +474,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -424,6 +493,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -441,6 +512,8 @@ mod tests {
             anthropic_api_key: None,
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -458,6 +531,8 @@ mod tests {
             anthropic_api_key: None,
             google_api_key: Some(String::new()),
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -475,6 +550,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -492,6 +569,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -509,6 +588,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(manager.is_configured(&creds));
@@ -526,6 +607,8 @@ mod tests {
             anthropic_api_key: None,
             google_api_key: Some("google-key-123".to_string()),
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(manager.is_configured(&creds));
@@ -543,6 +626,8 @@ mod tests {
             anthropic_api_key: None,
             google_api_key: None,
             openai_api_key: Some("sk-openai-test-key".to_string()),
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(manager.is_configured(&creds));
@@ -561,6 +646,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -578,6 +665,8 @@ mod tests {
             anthropic_api_key: Some("   \t\n".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -595,6 +684,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -612,6 +703,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -629,6 +722,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         let manager = manager_with_progress(&progress);
         assert!(!manager.is_configured(&creds));
@@ -642,6 +737,8 @@ mod tests {
             anthropic_api_key: Some("sk-ant-test-key".to_string()),
             google_api_key: None,
             openai_api_key: None,
+            recap_webhook_url: None,
+            discord_bot_token: None,
         };
         assert!(!manager.is_configured(&creds));
     }
@@ -834,4 +931,35 @@ mod tests {
         let tmp = manager.fs.get("/fake/config/strategy.toml.tmp");
         assert!(tmp.is_none(), "temp file should not remain after rename");
     }
+
+    #[test]
+    fn save_league_writes_a_loadable_league_and_keeps_strategy_sections() {
+        use crate::onboarding::league_setup::LeagueSetup;
+        use wyncast_core::config::{Config, LeagueConfig};
+
+        let existing = "[llm]\nmodel = \"claude-sonnet-4-6\"\n";
+        let fs = FakeFileSystem::new().with_file("/fake/config/strategy.toml", existing);
+        let manager = fake_manager(fs);
+
+        let setup = LeagueSetup {
+            num_teams: 12,
+            roster: [("C".to_string(), 2), ("OF".to_string(), 5), ("SP".to_string(), 7)].into(),
+            hitters: Some("h.csv".into()),
+            pitchers: Some("p.csv".into()),
+            ..Default::default()
+        };
+        let mut config = Config::default();
+        setup.apply(&mut config);
+        manager.save_league(&config).unwrap();
+
+        let league: toml::Table = toml::from_str(&manager.fs.get("/fake/config/league.toml").unwrap()).unwrap();
+        let league: LeagueConfig = league["league"].clone().try_into().unwrap();
+        assert_eq!(league.num_teams, 12);
+        assert_eq!(league.roster["SP"], 7);
+
+        let strategy = manager.fs.get("/fake/config/strategy.toml").unwrap();
+        assert!(strategy.contains("claude-sonnet-4-6"), "llm model should be preserved");
+        assert!(strategy.contains("hitters = \"h.csv\""), "{strategy}");
+        assert!(strategy.contains("hitter_pool_size"), "{strategy}");
+    }
 }
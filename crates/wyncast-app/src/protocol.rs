@@ -1,15 +1,40 @@
 // Message protocol types for WebSocket communication and internal async channels.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use wyncast_baseball::draft::nominations::NominationPattern;
 use wyncast_baseball::draft::pick::DraftPick;
 use wyncast_baseball::draft::roster::RosterSlot;
+use wyncast_core::config::StatsSite;
+use wyncast_core::crash::CrashReport;
+use wyncast_core::journal::{BidIntent, QuickBid};
 use wyncast_core::llm::provider::LlmProvider;
 use wyncast_baseball::matchup::MatchupSnapshot;
 use crate::onboarding::OnboardingStep;
+use wyncast_baseball::valuation::analysis::{self, CategoryGain};
+use wyncast_baseball::valuation::compare::PlayerComparison;
+use wyncast_baseball::valuation::standings::StandingsShift;
+use wyncast_baseball::valuation::explain::ValuationExplanation;
+use wyncast_baseball::valuation::identity::{AmbiguousPick, PlayerRef};
+use wyncast_baseball::draft::invariants::Violation;
+use wyncast_baseball::draft::session_stats::SessionStats;
+use wyncast_baseball::draft::nomination_advice::NominationAdvice;
+use wyncast_baseball::draft::nomination_queue::ScriptedNomination;
+use wyncast_baseball::draft::nomination_order::NominationTurn;
+use wyncast_baseball::draft::snake::SnakeStatus;
+use wyncast_baseball::draft::plan_b::PlanB;
+use wyncast_baseball::draft::roster_holes::RosterHole;
+use wyncast_baseball::draft::shopping_list::SlotNeed;
+use wyncast_baseball::draft::dart_board::DartGroup;
+use wyncast_baseball::valuation::keeper::KeeperValue;
+use wyncast_baseball::valuation::prep::PrepEntry;
 use wyncast_baseball::valuation::scarcity::ScarcityEntry;
 use wyncast_baseball::valuation::zscore::PlayerValuation;
 
+pub mod yahoo;
+
 // ---------------------------------------------------------------------------
 // Extension -> Backend messages (JSON over WebSocket)
 // ---------------------------------------------------------------------------
@@ -60,6 +85,22 @@ pub enum ExtensionMessage {
         timestamp: u64,
         payload: MatchupStatePayload,
     },
+
+    /// Draft-state snapshot from a Yahoo auction draft, converted into a
+    /// `STATE_UPDATE` on arrival.
+    #[serde(rename = "YAHOO_STATE_UPDATE")]
+    YahooStateUpdate {
+        timestamp: u64,
+        payload: yahoo::YahooDraftPayload,
+    },
+
+    /// Full Yahoo draft state on connect or reconnect, converted into a
+    /// `FULL_STATE_SYNC` on arrival.
+    #[serde(rename = "YAHOO_FULL_STATE_SYNC")]
+    YahooFullStateSync {
+        timestamp: u64,
+        payload: yahoo::YahooDraftPayload,
+    },
 }
 
 // ---------------------------------------------------------------------------
@@ -164,6 +205,69 @@ pub struct TeamBudgetData {
 #[serde(rename_all = "camelCase")]
 pub struct HeartbeatPayload {
     pub timestamp: u64,
+    /// Timestamp of the backend's last `HEARTBEAT_ACK`, echoed back so the
+    /// backend can measure round-trip latency.
+    #[serde(default)]
+    pub ack_timestamp: Option<u64>,
+    /// How long the extension held that ack before this heartbeat, which
+    /// the backend subtracts from the round trip.
+    #[serde(default)]
+    pub ack_held_ms: Option<u64>,
+    /// Draft state messages the extension relayed in the last minute.
+    #[serde(default)]
+    pub updates_per_minute: Option<u32>,
+    /// Time since the extension last scraped picks from the draft log, in
+    /// ms, or `None` when it has not yet.
+    #[serde(default)]
+    pub picks_age_ms: Option<u64>,
+    /// Time since the extension last scraped a player on the block, in ms,
+    /// or `None` when it has not yet.
+    #[serde(default)]
+    pub nomination_age_ms: Option<u64>,
+    /// JavaScript errors the content script hit while scraping, since the
+    /// extension started.
+    #[serde(default)]
+    pub js_errors: Option<u32>,
+}
+
+/// What one state update changed and why, recorded when
+/// `logging.explain_diffs` is on.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiffExplanation {
+    /// Local time of the update, `HH:MM:SS`.
+    pub at: String,
+    /// e.g. `1 pick new, nomination changed because bid went $5→$7`.
+    pub summary: String,
+    /// Each decision with its inputs.
+    pub decisions: Vec<String>,
+}
+
+/// Scraping health the extension reports with its heartbeats. Older
+/// extensions send none of it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExtensionHealth {
+    pub updates_per_minute: u32,
+    /// Time since picks were last scraped, in ms, as of the heartbeat.
+    pub picks_age_ms: Option<u64>,
+    /// Time since a nomination was last scraped, in ms, as of the heartbeat.
+    pub nomination_age_ms: Option<u64>,
+    pub js_errors: u32,
+}
+
+impl ExtensionHealth {
+    /// The health carried by a heartbeat, or `None` when it carries none.
+    pub fn from_heartbeat(payload: &HeartbeatPayload) -> Option<Self> {
+        let reported = payload.updates_per_minute.is_some()
+            || payload.picks_age_ms.is_some()
+            || payload.nomination_age_ms.is_some()
+            || payload.js_errors.is_some();
+        reported.then(|| ExtensionHealth {
+            updates_per_minute: payload.updates_per_minute.unwrap_or(0),
+            picks_age_ms: payload.picks_age_ms,
+            nomination_age_ms: payload.nomination_age_ms,
+            js_errors: payload.js_errors.unwrap_or(0),
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -413,6 +517,8 @@ pub enum OnboardingAction {
         category_weights: crate::onboarding::strategy_config::CategoryWeights,
         strategy_overview: Option<String>,
     },
+    /// Check and save the league setup answers, then move on to strategy.
+    SaveLeagueConfig(crate::onboarding::league_setup::LeagueSetup),
     /// Request LLM-assisted strategy configuration from a natural language description.
     ConfigureStrategyWithLlm(String),
     /// Navigate back to the previous onboarding step.
@@ -442,6 +548,11 @@ pub enum OnboardingUpdate {
         /// or the sync is from onboarding (where the user types the key fresh).
         api_key_mask: Option<String>,
     },
+    /// The league setup answers currently saved, sent on entering the
+    /// league step so the form starts from them.
+    LeagueSync(crate::onboarding::league_setup::LeagueSetup),
+    /// The league setup answers were not saved: what is wrong with them.
+    LeagueRejected(Vec<String>),
     /// A streamed token from the strategy LLM generation.
     StrategyLlmToken(String),
     /// Strategy LLM generation completed successfully with parsed config.
@@ -462,12 +573,30 @@ pub enum OnboardingUpdate {
 // depending on wyncast-tui.
 pub use wyncast_core::llm::events::LlmEvent;
 
+/// One row of the Available tab as the user sees it, for
+/// `UserCommand::ExportAvailableView`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvailableViewRow {
+    pub name: String,
+    pub team: String,
+    /// Eligible positions as shown, e.g. "2B/SS".
+    pub positions: String,
+    pub dollar_value: f64,
+    /// Value with every category weighted equally.
+    pub consensus_value: f64,
+    pub vor: f64,
+    pub cliff: f64,
+    pub total_zscore: f64,
+    pub pinned: bool,
+}
+
 /// Commands sent from the TUI to the app orchestrator.
 #[derive(Debug, Clone, PartialEq)]
 pub enum UserCommand {
     /// Request a full keyframe (FULL_STATE_SYNC) from the extension.
     /// Sends a `REQUEST_KEYFRAME` message over the WebSocket so the
-    /// extension responds with a complete state snapshot.
+    /// extension responds with a complete state snapshot, and re-sends the
+    /// app's full snapshot to the UI in case updates to it were lost.
     RequestKeyframe,
     ManualPick {
         player_name: String,
@@ -502,6 +631,49 @@ pub enum UserCommand {
     },
     /// Switch which settings tab is active.
     SwitchSettingsTab(SettingsSection),
+    /// Reconstruct the draft as it stood right after pick `pick_number`
+    /// (1-based) for the read-only time machine overlay.
+    RequestTimeMachine { pick_number: usize },
+    /// Work out the full calculation chain behind an available player's
+    /// value for the player detail popup.
+    ExplainPlayer { player: PlayerRef },
+    /// Line two available players up side by side for the compare overlay.
+    ComparePlayers { a: PlayerRef, b: PlayerRef },
+    /// Ask the LLM, once, which of two available players to prefer.
+    CompareWithLlm { a: PlayerRef, b: PlayerRef },
+    /// Say which player an ambiguous pick was: the one of the pick's name
+    /// on MLB `team`.
+    ResolvePick { pick_number: u32, team: String },
+    /// Open a player's page on an external stats site in the system
+    /// browser.
+    OpenStatsPage { name: String, team: String, site: StatsSite },
+    /// A single-key bid intent for the current nomination, logged to the
+    /// decision journal.
+    QuickBid(QuickBid),
+    /// Write a support bundle (the complete app state as JSON) to the log
+    /// directory.
+    DumpSupportBundle,
+    /// Write the overridden and blended projections of the available players
+    /// to a CSV in the log directory.
+    ExportProjectionAudit,
+    /// Write every keeper contract, priced against the model, to a CSV in
+    /// the log directory.
+    ExportKeeperReport,
+    /// Write the Available tab's rows, filtered and ordered as shown, to a
+    /// CSV in the log directory.
+    ExportAvailableView(Vec<AvailableViewRow>),
+    /// Snooze automatic recalculation for a burst of picks, or, when
+    /// snoozed, recalculate now.
+    SnoozeRecalc,
+    /// Record a player pinned or unpinned on the Available tab, so the
+    /// change can be undone.
+    SetPin { name: String, pinned: bool },
+    /// Move one of my players to the empty roster slot at index `slot`.
+    MoveToSlot { player_name: String, slot: usize },
+    /// Undo the last edit made by hand (e.g. a manual pick).
+    Undo,
+    /// Redo the last undone edit.
+    Redo,
     Quit,
 }
 
@@ -525,8 +697,13 @@ pub enum UiUpdate {
     LlmUpdate { request_id: u64, update: LlmStreamUpdate },
     /// Extension connection status changed.
     ConnectionStatus(ConnectionStatus),
-    /// A new nomination is active. Carries the analysis request ID if one was started.
-    NominationUpdate { info: Box<NominationInfo>, analysis_request_id: Option<u64> },
+    /// A new nomination is active. Carries the analysis request ID if one was
+    /// started, and the instant analysis if the player is in our pool.
+    NominationUpdate {
+        info: Box<NominationInfo>,
+        analysis_request_id: Option<u64>,
+        analysis: Option<Box<InstantAnalysis>>,
+    },
     /// Bid updated on the current nomination (same player, new bid amount).
     /// Unlike NominationUpdate, this does NOT clear accumulated LLM text.
     BidUpdate(Box<NominationInfo>),
@@ -534,12 +711,113 @@ pub enum UiUpdate {
     NominationCleared,
     /// A new nomination plan stream is starting. Carries the plan request ID.
     PlanStarted { request_id: u64 },
+    /// Automatic LLM requests were paused (the extension disconnected) or
+    /// resumed (a full state sync arrived).
+    LlmPaused(bool),
     /// An update for the onboarding wizard (e.g. connection test result).
     OnboardingUpdate(OnboardingUpdate),
     /// The app mode has changed (e.g. onboarding -> draft).
     ModeChanged(AppMode),
     /// Full matchup state snapshot for the matchup screen.
     MatchupSnapshot(Box<MatchupSnapshot>),
+    /// The previous session ended in a panic. Sent once at startup.
+    CrashNotice(Box<CrashReport>),
+    /// Past draft state requested via `UserCommand::RequestTimeMachine`.
+    /// `snapshot` is `None` when that pick could not be reconstructed.
+    TimeMachine {
+        pick_number: usize,
+        snapshot: Option<Box<TimeMachineSnapshot>>,
+    },
+    /// The calculation chain requested via `UserCommand::ExplainPlayer`, or
+    /// why it could not be worked out.
+    PlayerExplanation(Result<Box<ValuationExplanation>, String>),
+    /// The comparison requested via `UserCommand::ComparePlayers`, or why
+    /// it could not be made.
+    PlayerComparison(Result<Box<PlayerComparison>, String>),
+    /// An LLM comparison stream is starting. Carries its request ID.
+    ComparisonStarted { request_id: u64 },
+    /// Outcome of `UserCommand::ResolvePick`: the player the pick was, or
+    /// why it could not be resolved.
+    PickResolved(Result<String, String>),
+    /// Outcome of `UserCommand::ManualPick`: the player recorded, or why
+    /// the pick was rejected.
+    ManualPicked(Result<String, String>),
+    /// Outcome of `UserCommand::OpenStatsPage`: the page opened, or why
+    /// the browser could not be started.
+    StatsPageOpened(Result<String, String>),
+    /// My bid intent for the current nomination changed after a quick bid.
+    BidIntent(BidIntent),
+    /// The WebSocket server bound a port (or failed to). Sent once at startup.
+    WsServer(WsServerStatus),
+    /// Outcome of a support bundle dump: the file written, or the error.
+    SupportBundle(Result<String, String>),
+    /// Outcome of a projection audit export: the file written, or the error.
+    ProjectionAudit(Result<String, String>),
+    /// Outcome of a keeper report export: the file written, or the error.
+    KeeperReport(Result<String, String>),
+    /// Outcome of an Available view export: the file written, or the error.
+    AvailableExport(Result<String, String>),
+    /// Outcome of an undo or redo: what was undone or redone, or why
+    /// nothing was.
+    UndoRedo(Result<String, String>),
+    /// Pin (or unpin) a player on the Available tab: an undone or redone
+    /// pin edit.
+    PinSet { name: String, pinned: bool },
+    /// Outcome of the end-of-draft recap notification: where it went, or
+    /// the error.
+    DraftRecap(Result<String, String>),
+    /// Round-trip latency to the extension, measured when a heartbeat
+    /// echoes the backend's previous ack.
+    ExtensionLatency { rtt_ms: u64 },
+    /// Scraping health from the extension's latest heartbeat.
+    ExtensionHealth(ExtensionHealth),
+    /// What the latest state update changed and why (`logging.explain_diffs`).
+    DiffExplanation(DiffExplanation),
+    /// Updates to the UI were lost (`lost` since the last resync), so it
+    /// no longer matches the app until a resync.
+    UiDesynced { lost: usize },
+    /// How far a bulk recalculation (a full state sync's rebuild) has got,
+    /// or `None` once it is done.
+    RecalcProgress(Option<RecalcProgress>),
+}
+
+/// Progress through a bulk recalculation, for the progress overlay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecalcProgress {
+    /// What is running, e.g. "Valuing players".
+    pub stage: String,
+    pub picks_applied: usize,
+    pub picks_total: usize,
+    /// Recalculation steps finished, of `recalcs_total`.
+    pub recalcs_done: usize,
+    pub recalcs_total: usize,
+}
+
+impl RecalcProgress {
+    /// Share of the work done, from 0.0 to 1.0.
+    pub fn ratio(&self) -> f64 {
+        let total = self.picks_total + self.recalcs_total;
+        if total == 0 {
+            return 1.0;
+        }
+        ((self.picks_applied + self.recalcs_done) as f64 / total as f64).min(1.0)
+    }
+}
+
+/// Outcome of binding the WebSocket server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WsServerStatus {
+    /// Listening on `port`; differs from `requested_port` after a fallback.
+    Listening { port: u16, requested_port: u16 },
+    /// No port could be bound, so the extension cannot connect.
+    BindFailed { requested_port: u16, error: String },
+}
+
+impl WsServerStatus {
+    /// Whether the server is listening somewhere other than the configured port.
+    pub fn is_fallback(&self) -> bool {
+        matches!(self, WsServerStatus::Listening { port, requested_port } if port != requested_port)
+    }
 }
 
 /// WebSocket connection status.
@@ -578,6 +856,36 @@ pub enum TabFeature {
     Filter,
     /// Position-based filter cycling (the `p` key).
     PositionFilter,
+    /// Player detail popup with the dollar value breakdown (the `i` key).
+    PlayerDetail,
+    /// Time machine overlay for the selected past pick (the `t` key).
+    TimeMachine,
+    /// Pinning the top row to the top of the table (the `f` key).
+    Pin,
+    /// Switching the table between value and cliff order (the `o` key).
+    Sort,
+    /// Switching the table between all players, hitters and pitchers, each
+    /// with its own order and scroll position (the `h` key).
+    Pool,
+    /// Writing the table's rows, as shown, to a CSV (the `x` key).
+    Export,
+    /// Marking two players and comparing them side by side (the `m` key).
+    Compare,
+}
+
+impl TabFeature {
+    /// Every feature, in the order help lists them.
+    pub const ALL: [TabFeature; 9] = [
+        TabFeature::Filter,
+        TabFeature::PositionFilter,
+        TabFeature::PlayerDetail,
+        TabFeature::Pin,
+        TabFeature::Sort,
+        TabFeature::Pool,
+        TabFeature::Export,
+        TabFeature::Compare,
+        TabFeature::TimeMachine,
+    ];
 }
 
 impl TabId {
@@ -589,6 +897,13 @@ impl TabId {
             // support text filtering without position cycling (or vice versa).
             TabFeature::Filter => matches!(self, TabId::Available),
             TabFeature::PositionFilter => matches!(self, TabId::Available),
+            TabFeature::PlayerDetail => matches!(self, TabId::Available),
+            TabFeature::TimeMachine => matches!(self, TabId::DraftLog),
+            TabFeature::Pin => matches!(self, TabId::Available),
+            TabFeature::Sort => matches!(self, TabId::Available),
+            TabFeature::Pool => matches!(self, TabId::Available),
+            TabFeature::Export => matches!(self, TabId::Available),
+            TabFeature::Compare => matches!(self, TabId::Available),
         }
     }
 }
@@ -630,14 +945,73 @@ pub struct AppSnapshot {
     pub positional_scarcity: Vec<ScarcityEntry>,
     /// Chronological list of completed draft picks.
     pub draft_log: Vec<DraftPick>,
+    /// Names of drafted players outside the league's player pool.
+    pub out_of_pool_picks: Vec<String>,
+    /// Picks whose name fits more than one available player, waiting for
+    /// me to say which one went.
+    pub ambiguous_picks: Vec<AmbiguousPick>,
+    /// Draft-state invariants the last check found broken.
+    pub invariant_violations: Vec<Violation>,
+    /// My prep sheet, names reconciled against the projections.
+    pub prep: Vec<PrepEntry>,
+    /// Prep sheet names that match no projected player.
+    pub prep_unmatched: Vec<String>,
+    /// Keeper contracts priced against the model (keeper leagues only).
+    pub keeper_values: Vec<KeeperValue>,
+    /// Live analytics for the draft so far (the session stats overlay).
+    pub session_stats: SessionStats,
+    /// My open slots by position, with a suggested budget and the players
+    /// left for each (the shopping list panel).
+    pub shopping_list: Vec<SlotNeed>,
+    /// Cheap players worth over $1 for each of my open positions, once the
+    /// endgame is near (the dart board overlay).
+    pub dart_board: Vec<DartGroup>,
+    /// My open positions that are running short of players, counted against
+    /// every team still needing one, most alarming first.
+    pub roster_holes: Vec<RosterHole>,
+    /// Whose turn it is to nominate, and how many nominations until mine.
+    pub nomination_turn: Option<NominationTurn>,
+    /// A snake draft's round, pick and board, for the round/pick displays
+    /// that replace the budget and nomination ones. `None` in an auction.
+    pub snake: Option<SnakeStatus>,
+    /// The draft stage and whom to nominate in it, without the LLM (the
+    /// plan panel). `None` before my team is known.
+    pub nomination_advice: Option<NominationAdvice>,
+    /// My nomination script with each player's live status (the plan
+    /// panel). Empty when none is configured.
+    pub nomination_script: Vec<ScriptedNomination>,
+    /// Substitutes for the latest player I was outbid on, until I buy a
+    /// player (the Plan B strip).
+    pub plan_b: Option<PlanB>,
     /// User's roster slots (position + optional player).
     pub my_roster: Vec<RosterSlot>,
     /// Budget fields for the user's team.
     pub budget_spent: u32,
     pub budget_remaining: u32,
     pub salary_cap: u32,
-    /// Current league-wide inflation rate.
+    /// The league's minimum bid ($0 or $1).
+    pub min_bid: u32,
+    /// The league's bid increment: raises add multiples of it.
+    pub bid_increment: u32,
+    /// Show amounts as a percentage of the salary cap (the `[display]`
+    /// setting).
+    pub percent_of_cap: bool,
+    /// Toast each completed pick (the `[display]` setting).
+    pub pick_toasts: bool,
+    /// Names of the custom stat columns (the `[display]` setting), aligned
+    /// with each player's `custom_stats`.
+    pub custom_stats: Vec<String>,
+    /// Picks not yet priced in while automatic recalculation is snoozed;
+    /// `None` when it is not.
+    pub recalc_snoozed: Option<usize>,
+    /// The model's value of each drafted player when they were bought, by
+    /// pick number.
+    pub pick_values: HashMap<u32, f64>,
+    /// Current league-wide inflation rate, smoothed and damped per
+    /// `[valuation]`.
     pub inflation_rate: f64,
+    /// The inflation rate as the room stands, before smoothing and damping.
+    pub raw_inflation_rate: f64,
     /// Maximum bid the user can make right now.
     pub max_bid: u32,
     /// Average dollars remaining per empty roster slot.
@@ -650,6 +1024,10 @@ pub struct AppSnapshot {
     pub pitching_spent: u32,
     /// Pitching budget target (salary_cap * (1 - hitting_budget_fraction)).
     pub pitching_target: u32,
+    /// Hitters' share of the remaining pool's dollars (the effective split).
+    pub hitting_split: f64,
+    /// Whether `hitting_split` was learned from the room's spending.
+    pub split_learned: bool,
     /// Per-team summaries (name, budget, slots filled/total).
     pub team_snapshots: Vec<TeamSnapshot>,
     /// Whether the LLM client is configured (has a valid API key).
@@ -657,6 +1035,26 @@ pub struct AppSnapshot {
     pub llm_configured: bool,
 }
 
+/// The draft as it stood right after one past pick, reconstructed from the
+/// pick log. Read-only: nothing in here feeds back into live state.
+#[derive(Debug, Clone)]
+pub struct TimeMachineSnapshot {
+    /// The pick this snapshot was taken after (its `pick_number` is 1-based).
+    pub pick: DraftPick,
+    /// Pre-draft dollar value of the picked player, if it is in our pool.
+    pub pick_value: Option<f64>,
+    /// Number of picks recorded in the live draft (upper bound for stepping).
+    pub total_recorded: usize,
+    /// Inflation rate after this pick.
+    pub inflation_rate: f64,
+    /// Every team's budget and roster fill after this pick.
+    pub teams: Vec<TeamSnapshot>,
+    /// Best players still available after this pick, by dollar value.
+    pub top_available: Vec<PlayerValuation>,
+    /// Positional scarcity after this pick.
+    pub scarcity: Vec<ScarcityEntry>,
+}
+
 /// Lightweight summary of a team's draft state for the snapshot.
 #[derive(Debug, Clone)]
 pub struct TeamSnapshot {
@@ -664,6 +1062,14 @@ pub struct TeamSnapshot {
     pub budget_remaining: u32,
     pub slots_filled: usize,
     pub total_slots: usize,
+    /// Most the team can bid right now.
+    pub max_bid: u32,
+    /// How many $10+ players the team can still buy.
+    pub buys_10: usize,
+    /// How many $20+ players the team can still buy.
+    pub buys_20: usize,
+    /// The team's nominations among the players sold so far.
+    pub nominations: NominationPattern,
 }
 
 // Re-exported from wyncast-core so that wyncast-baseball (llm/prompt.rs) can
@@ -677,6 +1083,31 @@ pub struct InstantAnalysis {
     pub dollar_value: f64,
     pub adjusted_value: f64,
     pub verdict: InstantVerdict,
+    /// Why I can't roster the player (which makes the verdict a pass), e.g.
+    /// "no open slot".
+    pub roster_block: Option<String>,
+    /// What the player would add to my team, per category.
+    pub category_gains: Vec<CategoryGain>,
+    /// How buying the player would move me in the projected standings.
+    pub standings: Vec<StandingsShift>,
+}
+
+impl From<&analysis::InstantAnalysis> for InstantAnalysis {
+    fn from(a: &analysis::InstantAnalysis) -> Self {
+        InstantAnalysis {
+            player_name: a.player_name.clone(),
+            dollar_value: a.dollar_value,
+            adjusted_value: a.adjusted_value,
+            verdict: match a.verdict {
+                analysis::InstantVerdict::StrongTarget => InstantVerdict::StrongTarget,
+                analysis::InstantVerdict::ConditionalTarget => InstantVerdict::ConditionalTarget,
+                analysis::InstantVerdict::Pass => InstantVerdict::Pass,
+            },
+            roster_block: a.roster_block.map(|block| block.label()),
+            category_gains: a.category_gains.clone(),
+            standings: a.standings.clone(),
+        }
+    }
 }
 
 /// Quick verdict for a nomination.
@@ -708,6 +1139,53 @@ mod tests {
         assert!(TabId::Available.supports(TabFeature::PositionFilter));
     }
 
+    #[test]
+    fn available_supports_player_detail() {
+        assert!(TabId::Available.supports(TabFeature::PlayerDetail));
+    }
+
+    #[test]
+    fn only_available_supports_pin() {
+        assert!(TabId::Available.supports(TabFeature::Pin));
+        for tab in [TabId::Analysis, TabId::DraftLog, TabId::Teams] {
+            assert!(!tab.supports(TabFeature::Pin), "{:?}", tab);
+        }
+    }
+
+    #[test]
+    fn only_available_supports_sort() {
+        assert!(TabId::Available.supports(TabFeature::Sort));
+        assert!(TabId::Available.supports(TabFeature::Pool));
+        for tab in [TabId::Analysis, TabId::DraftLog, TabId::Teams] {
+            assert!(!tab.supports(TabFeature::Sort), "{:?}", tab);
+            assert!(!tab.supports(TabFeature::Pool), "{:?}", tab);
+        }
+    }
+
+    #[test]
+    fn only_available_supports_export() {
+        assert!(TabId::Available.supports(TabFeature::Export));
+        for tab in [TabId::Analysis, TabId::DraftLog, TabId::Teams] {
+            assert!(!tab.supports(TabFeature::Export), "{:?}", tab);
+        }
+    }
+
+    #[test]
+    fn only_available_supports_compare() {
+        assert!(TabId::Available.supports(TabFeature::Compare));
+        for tab in [TabId::Analysis, TabId::DraftLog, TabId::Teams] {
+            assert!(!tab.supports(TabFeature::Compare), "{:?}", tab);
+        }
+    }
+
+    #[test]
+    fn only_draft_log_supports_time_machine() {
+        assert!(TabId::DraftLog.supports(TabFeature::TimeMachine));
+        for tab in [TabId::Analysis, TabId::Available, TabId::Teams] {
+            assert!(!tab.supports(TabFeature::TimeMachine), "{:?}", tab);
+        }
+    }
+
     #[test]
     fn non_available_tabs_do_not_support_filter() {
         for tab in [TabId::Analysis, TabId::DraftLog, TabId::Teams] {
@@ -721,6 +1199,11 @@ mod tests {
                 "{:?} should not support PositionFilter",
                 tab
             );
+            assert!(
+                !tab.supports(TabFeature::PlayerDetail),
+                "{:?} should not support PlayerDetail",
+                tab
+            );
         }
     }
 
@@ -788,6 +1271,12 @@ mod tests {
         let msg = ExtensionMessage::ExtensionHeartbeat {
             payload: HeartbeatPayload {
                 timestamp: 1700000001,
+                ack_timestamp: Some(1700000000),
+                ack_held_ms: Some(4990),
+                updates_per_minute: Some(18),
+                picks_age_ms: Some(2500),
+                nomination_age_ms: None,
+                js_errors: Some(0),
             },
         };
         let json = serde_json::to_string(&msg).unwrap();
@@ -828,6 +1317,27 @@ mod tests {
         match msg {
             ExtensionMessage::ExtensionHeartbeat { payload } => {
                 assert_eq!(payload.timestamp, 9999);
+                assert_eq!(payload.ack_timestamp, None);
+            }
+            _ => panic!("expected ExtensionHeartbeat variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_heartbeat_with_ack_echo() {
+        let json = r#"{
+            "type": "EXTENSION_HEARTBEAT",
+            "payload": {
+                "timestamp": 9999,
+                "ackTimestamp": 5000,
+                "ackHeldMs": 4870
+            }
+        }"#;
+        let msg: ExtensionMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            ExtensionMessage::ExtensionHeartbeat { payload } => {
+                assert_eq!(payload.ack_timestamp, Some(5000));
+                assert_eq!(payload.ack_held_ms, Some(4870));
             }
             _ => panic!("expected ExtensionHeartbeat variant"),
         }
@@ -1029,17 +1539,42 @@ mod tests {
             available_players: vec![],
             positional_scarcity: vec![],
             draft_log: vec![],
+            out_of_pool_picks: vec![],
+            ambiguous_picks: vec![],
+            invariant_violations: vec![],
+            prep: vec![],
+            prep_unmatched: vec![],
+            keeper_values: vec![],
+            session_stats: Default::default(),
+            shopping_list: vec![],
+            dart_board: vec![],
+            roster_holes: vec![],
+            nomination_turn: None,
+            snake: None,
+            nomination_advice: None,
+            nomination_script: vec![],
+            plan_b: None,
             my_roster: vec![],
             budget_spent: 0,
             budget_remaining: 260,
             salary_cap: 260,
+            min_bid: 1,
+            bid_increment: 1,
+            percent_of_cap: false,
+            pick_toasts: true,
+            custom_stats: vec![],
+            recalc_snoozed: None,
+            pick_values: HashMap::new(),
             inflation_rate: 1.0,
+            raw_inflation_rate: 1.0,
             max_bid: 0,
             avg_per_slot: 0.0,
             hitting_spent: 0,
             hitting_target: 0,
             pitching_spent: 0,
             pitching_target: 0,
+            hitting_split: 0.0,
+            split_learned: false,
             team_snapshots: vec![],
             llm_configured: true,
         };
@@ -1393,17 +1928,42 @@ mod tests {
             available_players: vec![],
             positional_scarcity: vec![],
             draft_log: vec![],
+            out_of_pool_picks: vec![],
+            ambiguous_picks: vec![],
+            invariant_violations: vec![],
+            prep: vec![],
+            prep_unmatched: vec![],
+            keeper_values: vec![],
+            session_stats: Default::default(),
+            shopping_list: vec![],
+            dart_board: vec![],
+            roster_holes: vec![],
+            nomination_turn: None,
+            snake: None,
+            nomination_advice: None,
+            nomination_script: vec![],
+            plan_b: None,
             my_roster: vec![],
             budget_spent: 0,
             budget_remaining: 260,
             salary_cap: 260,
+            min_bid: 1,
+            bid_increment: 1,
+            percent_of_cap: false,
+            pick_toasts: true,
+            custom_stats: vec![],
+            recalc_snoozed: None,
+            pick_values: HashMap::new(),
             inflation_rate: 1.0,
+            raw_inflation_rate: 1.0,
             max_bid: 0,
             avg_per_slot: 0.0,
             hitting_spent: 0,
             hitting_target: 0,
             pitching_spent: 0,
             pitching_target: 0,
+            hitting_split: 0.0,
+            split_learned: false,
             team_snapshots: vec![],
             llm_configured: false,
         };
@@ -0,0 +1,286 @@
+// Yahoo auction drafts: the payload the extension scrapes from Yahoo's
+// draft client, and its conversion into the ESPN-shaped `StateUpdatePayload`
+// the rest of the pipeline diffs.
+//
+// Yahoo identifies everything by key: leagues ("458.l.12345"), teams
+// ("458.l.12345.t.3") and players ("458.p.10835"). Teams become the number
+// after ".t.", like ESPN's team IDs; player keys are kept whole as player
+// IDs, so they never collide with ESPN's numeric ones. Eligibility comes as
+// Yahoo position codes ("SS,2B", "Util", "BN", "IL10"), mapped onto ESPN
+// slot IDs so roster placement works unchanged. Yahoo's draft results list
+// every pick, so there is no draft board grid and no pick history.
+
+use serde::{Deserialize, Serialize};
+
+use wyncast_baseball::draft::pick::{
+    ESPN_SLOT_1B, ESPN_SLOT_2B, ESPN_SLOT_3B, ESPN_SLOT_BE, ESPN_SLOT_C, ESPN_SLOT_CF, ESPN_SLOT_CI,
+    ESPN_SLOT_DH, ESPN_SLOT_IF, ESPN_SLOT_IL, ESPN_SLOT_LF, ESPN_SLOT_MI, ESPN_SLOT_NA, ESPN_SLOT_OF,
+    ESPN_SLOT_P, ESPN_SLOT_RF, ESPN_SLOT_RP, ESPN_SLOT_SP, ESPN_SLOT_SS, ESPN_SLOT_UTIL,
+};
+
+use super::{NominationData, PickData, StateUpdatePayload, TeamBudgetData};
+
+/// `source` of a state update converted from Yahoo.
+pub const SOURCE: &str = "yahoo";
+
+/// Draft state scraped from a Yahoo auction draft.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YahooDraftPayload {
+    /// e.g. "458.l.12345".
+    #[serde(default)]
+    pub league_key: Option<String>,
+    /// Key of the team the user is drafting for.
+    #[serde(default)]
+    pub my_team_key: Option<String>,
+    #[serde(default)]
+    pub teams: Vec<YahooTeam>,
+    /// Every pick so far, as Yahoo's draft results list them.
+    #[serde(default)]
+    pub picks: Vec<YahooPick>,
+    /// The player up for bid.
+    #[serde(default)]
+    pub nomination: Option<YahooNomination>,
+    #[serde(default)]
+    pub total_picks: Option<u32>,
+}
+
+/// A team and its remaining budget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YahooTeam {
+    pub team_key: String,
+    pub name: String,
+    /// Dollars left to spend.
+    pub budget: u32,
+}
+
+/// A completed pick.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YahooPick {
+    /// Overall pick number, from 1.
+    pub pick: u32,
+    pub team_key: String,
+    pub player_key: String,
+    pub player_name: String,
+    /// Yahoo position codes, e.g. "SS,2B".
+    pub positions: String,
+    pub cost: u32,
+}
+
+/// The player up for bid.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YahooNomination {
+    pub player_key: String,
+    pub player_name: String,
+    /// Yahoo position codes, e.g. "SP,RP".
+    pub positions: String,
+    #[serde(default)]
+    pub nominator_team_key: Option<String>,
+    pub bid: u32,
+    #[serde(default)]
+    pub bidder_team_key: Option<String>,
+    #[serde(default)]
+    pub seconds_left: Option<u32>,
+}
+
+impl YahooDraftPayload {
+    /// The state update the ESPN pipeline takes for this draft state.
+    pub fn into_state_update(self) -> StateUpdatePayload {
+        let team_name = |key: &str| {
+            self.teams
+                .iter()
+                .find(|t| t.team_key == key)
+                .map(|t| t.name.clone())
+                .unwrap_or_else(|| team_id(key))
+        };
+
+        let picks = self
+            .picks
+            .iter()
+            .map(|p| PickData {
+                pick_number: p.pick,
+                team_id: team_id(&p.team_key),
+                team_name: team_name(&p.team_key),
+                player_id: p.player_key.clone(),
+                player_name: p.player_name.clone(),
+                position: position_str(&p.positions),
+                price: p.cost,
+                eligible_slots: eligible_slots(&p.positions),
+                assigned_slot: None,
+            })
+            .collect::<Vec<_>>();
+
+        let current_nomination = self.nomination.as_ref().map(|n| NominationData {
+            player_id: n.player_key.clone(),
+            player_name: n.player_name.clone(),
+            position: position_str(&n.positions),
+            nominated_by: n.nominator_team_key.as_deref().map(team_name).unwrap_or_default(),
+            current_bid: n.bid,
+            current_bidder: n.bidder_team_key.as_deref().map(team_name),
+            time_remaining: n.seconds_left,
+            eligible_slots: eligible_slots(&n.positions),
+        });
+
+        let teams = self
+            .teams
+            .iter()
+            .map(|t| TeamBudgetData { team_id: Some(team_id(&t.team_key)), team_name: t.name.clone(), budget: t.budget })
+            .collect();
+
+        StateUpdatePayload {
+            pick_count: Some(picks.len() as u32),
+            picks,
+            current_nomination,
+            my_team_id: self.my_team_key.as_deref().map(team_id),
+            teams,
+            total_picks: self.total_picks,
+            draft_id: self.league_key.as_ref().map(|key| format!("yahoo_{key}")),
+            source: Some(SOURCE.to_string()),
+            draft_board: None,
+            pick_history: None,
+            team_id_mapping: None,
+        }
+    }
+}
+
+/// The team ID for a Yahoo team key: the number after ".t.", or the whole
+/// key when it has none.
+pub fn team_id(team_key: &str) -> String {
+    match team_key.rsplit_once(".t.") {
+        Some((_, id)) if !id.is_empty() => id.to_string(),
+        _ => team_key.to_string(),
+    }
+}
+
+/// The ESPN slot ID for a Yahoo position code. `None` for codes with no
+/// slot.
+pub fn slot_for_code(code: &str) -> Option<u16> {
+    let slot = match code.trim().to_uppercase().as_str() {
+        "C" => ESPN_SLOT_C,
+        "1B" => ESPN_SLOT_1B,
+        "2B" => ESPN_SLOT_2B,
+        "3B" => ESPN_SLOT_3B,
+        "SS" => ESPN_SLOT_SS,
+        "LF" => ESPN_SLOT_LF,
+        "CF" => ESPN_SLOT_CF,
+        "RF" => ESPN_SLOT_RF,
+        "OF" => ESPN_SLOT_OF,
+        "MI" => ESPN_SLOT_MI,
+        "CI" => ESPN_SLOT_CI,
+        "IF" => ESPN_SLOT_IF,
+        "DH" => ESPN_SLOT_DH,
+        "UTIL" => ESPN_SLOT_UTIL,
+        "SP" => ESPN_SLOT_SP,
+        "RP" => ESPN_SLOT_RP,
+        "P" => ESPN_SLOT_P,
+        "BN" => ESPN_SLOT_BE,
+        "IL" | "IL10" | "IL15" | "IL60" | "IL+" | "DL" => ESPN_SLOT_IL,
+        "NA" => ESPN_SLOT_NA,
+        _ => return None,
+    };
+    Some(slot)
+}
+
+/// The Yahoo position codes in `positions`, which Yahoo separates with
+/// commas (and some views with slashes).
+fn codes(positions: &str) -> impl Iterator<Item = &str> {
+    positions.split([',', '/']).map(str::trim).filter(|code| !code.is_empty())
+}
+
+/// ESPN slot IDs for Yahoo's `positions`, in order and without repeats.
+pub fn eligible_slots(positions: &str) -> Vec<u16> {
+    let mut slots = Vec::new();
+    for slot in codes(positions).filter_map(slot_for_code) {
+        if !slots.contains(&slot) {
+            slots.push(slot);
+        }
+    }
+    slots
+}
+
+/// The position string the pipeline parses: the playing positions in
+/// `positions`, slash-separated, as ESPN writes them ("SS/2B").
+pub fn position_str(positions: &str) -> String {
+    codes(positions)
+        .filter(|code| !matches!(slot_for_code(code), None | Some(ESPN_SLOT_BE | ESPN_SLOT_IL | ESPN_SLOT_NA)))
+        .map(|code| if code.eq_ignore_ascii_case("Util") { "UTIL".to_string() } else { code.to_uppercase() })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> YahooDraftPayload {
+        serde_json::from_value(serde_json::json!({
+            "leagueKey": "458.l.12345",
+            "myTeamKey": "458.l.12345.t.3",
+            "teams": [
+                { "teamKey": "458.l.12345.t.1", "name": "Sluggers", "budget": 218 },
+                { "teamKey": "458.l.12345.t.3", "name": "Vorticists", "budget": 260 }
+            ],
+            "picks": [
+                {
+                    "pick": 1,
+                    "teamKey": "458.l.12345.t.1",
+                    "playerKey": "458.p.10835",
+                    "playerName": "Bobby Witt Jr.",
+                    "positions": "SS,3B",
+                    "cost": 42
+                }
+            ],
+            "nomination": {
+                "playerKey": "458.p.9124",
+                "playerName": "Gerrit Cole",
+                "positions": "SP,IL10",
+                "nominatorTeamKey": "458.l.12345.t.3",
+                "bid": 18,
+                "bidderTeamKey": "458.l.12345.t.1",
+                "secondsLeft": 12
+            },
+            "totalPicks": 276
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn yahoo_codes_map_to_espn_slots() {
+        assert_eq!(eligible_slots("SS,2B"), [ESPN_SLOT_SS, ESPN_SLOT_2B]);
+        assert_eq!(eligible_slots("1B/OF, Util"), [ESPN_SLOT_1B, ESPN_SLOT_OF, ESPN_SLOT_UTIL]);
+        assert_eq!(eligible_slots("SP,RP,IL60,NA,XX"), [ESPN_SLOT_SP, ESPN_SLOT_RP, ESPN_SLOT_IL, ESPN_SLOT_NA]);
+        assert_eq!(position_str("SP,IL10"), "SP");
+        assert_eq!(position_str("Util,BN"), "UTIL");
+        assert_eq!(team_id("458.l.12345.t.11"), "11");
+        assert_eq!(team_id("mine"), "mine");
+    }
+
+    #[test]
+    fn converts_into_an_espn_shaped_state_update() {
+        let update = payload().into_state_update();
+        assert_eq!(update.source.as_deref(), Some(SOURCE));
+        assert_eq!(update.draft_id.as_deref(), Some("yahoo_458.l.12345"));
+        assert_eq!(update.my_team_id.as_deref(), Some("3"));
+        assert_eq!((update.pick_count, update.total_picks), (Some(1), Some(276)));
+
+        let pick = &update.picks[0];
+        assert_eq!((pick.team_id.as_str(), pick.team_name.as_str()), ("1", "Sluggers"));
+        assert_eq!(pick.player_id, "458.p.10835");
+        assert_eq!(pick.position, "SS/3B");
+        assert_eq!(pick.eligible_slots, [ESPN_SLOT_SS, ESPN_SLOT_3B]);
+        assert_eq!(pick.price, 42);
+
+        let nomination = update.current_nomination.unwrap();
+        assert_eq!(nomination.nominated_by, "Vorticists");
+        assert_eq!(nomination.current_bidder.as_deref(), Some("Sluggers"));
+        assert_eq!((nomination.current_bid, nomination.time_remaining), (18, Some(12)));
+        assert_eq!(nomination.position, "SP");
+
+        assert_eq!(update.teams[1].team_id.as_deref(), Some("3"));
+        assert_eq!(update.teams[1].budget, 260);
+        assert!(update.draft_board.is_none() && update.pick_history.is_none());
+    }
+}
@@ -0,0 +1,213 @@
+// Dart board: the endgame's $1 targets, by my open slots.
+//
+// Once only a handful of my slots are open, most of them get filled at the
+// minimum bid. The dart board lists, for each open slot position, the
+// players left whose model value is over $1 but who should still go for
+// about that, best first, with flags for upside the value does not price:
+// a part-time projection that grows with playing time, a standout
+// category, or extra positions.
+
+use wyncast_core::stats::StatRegistry;
+
+use super::pick::Position;
+use super::roster::Roster;
+use super::shopping_list::fits;
+use crate::valuation::auction::InflationTracker;
+use crate::valuation::zscore::PlayerValuation;
+
+/// The board opens once this few of my slots are open.
+pub const ENDGAME_SLOTS: usize = 10;
+
+/// Darts listed per open position.
+const DARTS_PER_POSITION: usize = 5;
+
+/// Most a dart may cost over the minimum bid at today's prices.
+const DART_MARGIN: f64 = 4.0;
+
+/// Hitters projected under this many PA, and starters under this many IP,
+/// are part-time: their value grows if they win a full role.
+const PART_TIME_PA: f64 = 450.0;
+const PART_TIME_IP: f64 = 120.0;
+
+/// A category z-score at least this high is a standout.
+const STANDOUT_Z: f64 = 1.0;
+
+/// Upside a dart's value does not price in.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Upside {
+    /// Projected for a part-time role.
+    PlayingTime,
+    /// Stands out in one category (its abbreviation).
+    Category(String),
+    /// Eligible at more than one position.
+    MultiPosition,
+}
+
+impl Upside {
+    /// Short label for the board.
+    pub fn label(&self) -> String {
+        match self {
+            Upside::PlayingTime => "PT".into(),
+            Upside::Category(abbrev) => format!("{abbrev}+"),
+            Upside::MultiPosition => "multi".into(),
+        }
+    }
+}
+
+/// One $1 target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dart {
+    pub player_name: String,
+    pub team: String,
+    pub dollar_value: f64,
+    pub upside: Vec<Upside>,
+}
+
+/// The darts for one open position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DartGroup {
+    pub position: Position,
+    /// My open slots at this position.
+    pub open: usize,
+    /// Best first.
+    pub darts: Vec<Dart>,
+}
+
+/// Build the dart board for `roster`, in roster order. Empty until the
+/// endgame (at most `ENDGAME_SLOTS` open slots) and once the roster is
+/// full.
+pub fn dart_board(
+    roster: &Roster,
+    min_bid: u32,
+    available: &[PlayerValuation],
+    inflation: &InflationTracker,
+    registry: &StatRegistry,
+) -> Vec<DartGroup> {
+    let open: Vec<Position> = roster
+        .slots
+        .iter()
+        .filter(|s| s.player.is_none() && !s.position.is_reserve_slot())
+        .map(|s| s.position)
+        .collect();
+    if open.is_empty() || open.len() > ENDGAME_SLOTS {
+        return Vec::new();
+    }
+
+    // "$1" is the minimum bid, or $1 where $0 bids are allowed.
+    let floor = f64::from(min_bid.max(1));
+    let mut darts: Vec<&PlayerValuation> = available
+        .iter()
+        .filter(|p| p.dollar_value > floor && inflation.adjust(p.dollar_value) <= floor + DART_MARGIN)
+        .collect();
+    darts.sort_by(|a, b| b.dollar_value.total_cmp(&a.dollar_value));
+
+    let mut groups: Vec<DartGroup> = Vec::new();
+    for position in open {
+        if let Some(group) = groups.iter_mut().find(|g| g.position == position) {
+            group.open += 1;
+            continue;
+        }
+        groups.push(DartGroup {
+            position,
+            open: 1,
+            darts: darts
+                .iter()
+                .filter(|p| fits(position, p))
+                .take(DARTS_PER_POSITION)
+                .map(|p| Dart {
+                    player_name: p.name.clone(),
+                    team: p.team.clone(),
+                    dollar_value: p.dollar_value,
+                    upside: upside(p, registry),
+                })
+                .collect(),
+        });
+    }
+    groups
+}
+
+/// The upside flags for `player`.
+fn upside(player: &PlayerValuation, registry: &StatRegistry) -> Vec<Upside> {
+    let mut flags = Vec::new();
+    let part_time = if player.is_pitcher {
+        player.positions.contains(&Position::StartingPitcher)
+            && player.projection.values.get("ip").is_some_and(|&ip| ip < PART_TIME_IP)
+    } else {
+        player.projection.values.get("pa").is_some_and(|&pa| pa < PART_TIME_PA)
+    };
+    if part_time {
+        flags.push(Upside::PlayingTime);
+    }
+    let zscores = player.category_zscores.zscores();
+    let standout = registry
+        .all_stats()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, stat)| Some((zscores.get(i)?, stat)))
+        .filter(|(z, _)| *z >= STANDOUT_Z)
+        .max_by(|a, b| a.0.total_cmp(&b.0));
+    if let Some((_, stat)) = standout {
+        flags.push(Upside::Category(stat.abbrev.clone()));
+    }
+    if player.positions.len() > 1 {
+        flags.push(Upside::MultiPosition);
+    }
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::test_utils::{test_registry, TestPlayer};
+    use crate::valuation::projections::PitcherType;
+
+    fn roster(open: &[(&str, usize)]) -> Roster {
+        let config: HashMap<String, usize> = open.iter().map(|&(pos, n)| (pos.to_string(), n)).collect();
+        Roster::new(&config)
+    }
+
+    fn board(roster: &Roster, available: &[PlayerValuation]) -> Vec<DartGroup> {
+        dart_board(roster, 1, available, &InflationTracker::new(), &test_registry())
+    }
+
+    #[test]
+    fn darts_are_cheap_players_worth_over_a_dollar_by_open_slot() {
+        let mut part_timer = TestPlayer::hitter("Platoon C")
+            .positions(vec![Position::Catcher, Position::FirstBase])
+            .dollar(3.0)
+            .zscores(&[("SB", 1.4)])
+            .build();
+        part_timer.projection.values.insert("pa".into(), 320.0);
+        let available = vec![
+            TestPlayer::hitter("Star C").positions(vec![Position::Catcher]).dollar(25.0).build(),
+            TestPlayer::hitter("Backup C").positions(vec![Position::Catcher]).dollar(2.0).build(),
+            part_timer,
+            TestPlayer::hitter("Dollar C").positions(vec![Position::Catcher]).dollar(1.0).build(),
+            TestPlayer::pitcher("Long Man", PitcherType::SP).dollar(4.5).build(),
+        ];
+        let roster = roster(&[("C", 1), ("SP", 2), ("IL", 1)]);
+
+        let groups = board(&roster, &available);
+        assert_eq!(groups.len(), 2);
+        assert_eq!((groups[0].position, groups[0].open), (Position::Catcher, 1));
+        let names: Vec<&str> = groups[0].darts.iter().map(|d| d.player_name.as_str()).collect();
+        assert_eq!(names, ["Platoon C", "Backup C"]);
+        assert_eq!(
+            groups[0].darts[0].upside,
+            vec![Upside::PlayingTime, Upside::Category("SB".into()), Upside::MultiPosition]
+        );
+        assert!(groups[0].darts[1].upside.is_empty());
+        assert_eq!((groups[1].position, groups[1].open), (Position::StartingPitcher, 2));
+        assert_eq!(groups[1].darts[0].player_name, "Long Man");
+    }
+
+    #[test]
+    fn board_waits_for_the_endgame() {
+        let available = vec![TestPlayer::hitter("Backup").dollar(2.0).build()];
+        assert!(board(&roster(&[("1B", 1), ("BE", ENDGAME_SLOTS)]), &available).is_empty());
+        assert_eq!(board(&roster(&[("1B", 1), ("BE", ENDGAME_SLOTS - 1)]), &available).len(), 2);
+        assert!(board(&roster(&[]), &available).is_empty());
+    }
+}
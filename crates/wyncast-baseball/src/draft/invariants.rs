@@ -0,0 +1,168 @@
+// Draft-state invariants: cheap cross-checks run as the draft goes.
+//
+// Picks update the team budgets and rosters, the inflation tracker and the
+// available pool separately, and a slip in any of them skews every bit of
+// advice after it without a sound. Every `CHECK_EVERY` picks the app checks
+// that the three still agree:
+//
+// - the teams' spends add up to the inflation tracker's total;
+// - each team has a filled roster slot per pick it made and per player it
+//   kept from last season;
+// - nobody on a roster is still in the available pool.
+//
+// Violations are logged and shown rather than fixed: they point at a bug,
+// and guessing which side is right would hide it.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use super::state::DraftState;
+use crate::valuation::auction::InflationTracker;
+use crate::valuation::zscore::PlayerValuation;
+
+/// Picks between checks.
+pub const CHECK_EVERY: usize = 5;
+
+/// An invariant the draft state breaks.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// The teams' spends don't add up to the inflation tracker's total.
+    SpendMismatch { teams: u32, tracked: f64 },
+    /// A team's filled roster slots don't match the picks it made and the
+    /// players it kept.
+    RosterMismatch { team: String, filled: usize, picks: usize, kept: usize },
+    /// A rostered player is still in the available pool.
+    StillAvailable { player: String, team: String },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::SpendMismatch { teams, tracked } => {
+                write!(f, "teams spent ${teams} but inflation counts ${tracked:.0}")
+            }
+            Violation::RosterMismatch { team, filled, picks, kept: 0 } => {
+                write!(f, "{team} has {filled} rostered players for {picks} picks")
+            }
+            Violation::RosterMismatch { team, filled, picks, kept } => {
+                write!(f, "{team} has {filled} rostered players for {picks} picks and {kept} keepers")
+            }
+            Violation::StillAvailable { player, team } => {
+                write!(f, "{player} is on {team} and still available")
+            }
+        }
+    }
+}
+
+/// Check the invariants. Names in `unchecked` (shared by several players,
+/// or on a pick not yet resolved) can't say whether a rostered player is
+/// the one in the pool, so they are left out of that check.
+pub fn check(
+    state: &DraftState,
+    available: &[PlayerValuation],
+    inflation: &InflationTracker,
+    unchecked: &HashSet<String>,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    let teams = state.total_spent();
+    if (f64::from(teams) - inflation.total_dollars_spent).abs() > 0.5 {
+        violations.push(Violation::SpendMismatch {
+            teams,
+            tracked: inflation.total_dollars_spent,
+        });
+    }
+
+    let available_names: HashSet<&str> = available.iter().map(|p| p.name.as_str()).collect();
+    for team in &state.teams {
+        let filled = team.roster.filled_count();
+        let picks = state.picks.iter().filter(|p| p.team_id == team.team_id).count();
+        let kept = state.keepers.iter().filter(|k| k.kept_by(team)).count();
+        if filled != picks + kept {
+            violations.push(Violation::RosterMismatch {
+                team: team.team_name.clone(),
+                filled,
+                picks,
+                kept,
+            });
+        }
+
+        let rostered = team.roster.slots.iter().filter_map(|s| s.player.as_ref());
+        for player in rostered {
+            if available_names.contains(player.name.as_str()) && !unchecked.contains(&player.name) {
+                violations.push(Violation::StillAvailable {
+                    player: player.name.clone(),
+                    team: team.team_name.clone(),
+                });
+            }
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::state::KeptPlayer;
+    use crate::test_utils::{create_test_draft_state, test_pick, TestPlayer};
+
+    fn drafted() -> (DraftState, InflationTracker) {
+        let mut state = create_test_draft_state(2);
+        state.record_pick(test_pick("Alpha").team(1).price(30).build());
+        state.record_pick(test_pick("Bravo").team(2).price(12).build());
+        let mut inflation = InflationTracker::new();
+        inflation.total_dollars_spent = 42.0;
+        (state, inflation)
+    }
+
+    #[test]
+    fn a_consistent_draft_passes() {
+        let (state, inflation) = drafted();
+        let available = vec![TestPlayer::hitter("Charlie").build()];
+        assert!(check(&state, &available, &inflation, &HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn each_broken_invariant_is_reported() {
+        let (mut state, mut inflation) = drafted();
+        inflation.total_dollars_spent = 30.0;
+        state.teams[1].roster.slots.iter_mut().for_each(|s| s.player = None);
+        let available = vec![TestPlayer::hitter("Alpha").build()];
+
+        let violations = check(&state, &available, &inflation, &HashSet::new());
+        let text: Vec<String> = violations.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            text,
+            [
+                "teams spent $42 but inflation counts $30",
+                "Alpha is on Team 1 and still available",
+                "Team 2 has 0 rostered players for 1 picks",
+            ]
+        );
+
+        // A shared name can't tell who's who.
+        inflation.total_dollars_spent = 42.0;
+        let unchecked = HashSet::from(["Alpha".to_string()]);
+        assert_eq!(check(&state, &available, &inflation, &unchecked).len(), 1);
+    }
+
+    #[test]
+    fn keepers_count_toward_their_teams_rosters() {
+        let (mut state, mut inflation) = drafted();
+        state.keepers = vec![KeptPlayer {
+            player_name: "Delta".into(),
+            team: "Team 2".into(),
+            position: "C".into(),
+            price: 8,
+        }];
+        state.apply_keepers();
+        inflation.total_dollars_spent = 50.0;
+        let available = vec![TestPlayer::hitter("Charlie").build()];
+        assert!(check(&state, &available, &inflation, &HashSet::new()).is_empty());
+
+        state.teams[1].roster.slots.iter_mut().for_each(|s| s.player = None);
+        let text: Vec<String> =
+            check(&state, &available, &inflation, &HashSet::new()).iter().map(ToString::to_string).collect();
+        assert_eq!(text, ["Team 2 has 0 rostered players for 1 picks and 1 keepers"]);
+    }
+}
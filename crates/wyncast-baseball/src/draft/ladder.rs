@@ -0,0 +1,141 @@
+// Bid ladder: strategic bid amounts for the player on the block, worked out
+// from every team's max bid.
+//
+// Only teams whose max bid is above the current bid can still raise. The
+// ladder shows the next legal bid, a jump bid that prices out the teams that
+// cannot reach a threshold (the player's worth), and the prices at which only
+// a few teams can still afford to bid.
+//
+// Bids are whole dollars, and a raise adds a multiple of the platform's bid
+// increment, so every price on the ladder is the current bid plus whole
+// increments. `legal_bid` rounds a dollar value to such a bid the same way.
+
+/// A jump bid and the number of teams it prices out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JumpBid {
+    pub price: u32,
+    pub priced_out: usize,
+}
+
+/// Strategic bid amounts for one nomination.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidLadder {
+    /// The smallest legal raise: one increment over the current bid.
+    pub next_bid: u32,
+    /// The first legal bid over the highest max bid among the teams still
+    /// in that cannot reach the threshold; `None` when every team still in
+    /// can.
+    pub jump: Option<JumpBid>,
+    /// `(teams, price)`: from `price` on, only `teams` teams can still bid.
+    /// In the order the counts were asked for; counts that no price leaves
+    /// exactly (ties) or that are already reached are left out.
+    pub survivors: Vec<(usize, u32)>,
+}
+
+/// Build the ladder for a player at `current_bid`, raised in steps of
+/// `increment` dollars, given every team's max bid. The jump bid prices out
+/// the teams whose max bid is below `threshold`; `survivor_counts` lists
+/// the team counts to price.
+pub fn bid_ladder(
+    current_bid: u32,
+    increment: u32,
+    max_bids: &[u32],
+    threshold: u32,
+    survivor_counts: &[usize],
+) -> BidLadder {
+    let increment = increment.max(1);
+    // The first legal raise that reaches `price`.
+    let raise_to = |price: u32| current_bid + price.saturating_sub(current_bid).div_ceil(increment).max(1) * increment;
+
+    let mut still_in: Vec<u32> = max_bids.iter().copied().filter(|&m| m > current_bid).collect();
+    still_in.sort_unstable_by(|a, b| b.cmp(a));
+
+    let jump = still_in
+        .iter()
+        .copied()
+        .filter(|&m| m < threshold)
+        .max()
+        .map(|highest| JumpBid {
+            price: raise_to(highest + 1),
+            priced_out: still_in.iter().filter(|&&m| m <= highest).count(),
+        });
+
+    // At `still_in[n] + 1` the (n+1)-th highest team drops out, leaving n,
+    // unless the n-th highest can't afford the legal bid at that point
+    // either (it is tied, or the increment jumps past it).
+    let survivors = survivor_counts
+        .iter()
+        .filter(|&&n| n > 0 && n < still_in.len() && still_in[n - 1] >= raise_to(still_in[n] + 1))
+        .map(|&n| (n, raise_to(still_in[n] + 1)))
+        .collect();
+
+    BidLadder {
+        next_bid: current_bid + increment,
+        jump,
+        survivors,
+    }
+}
+
+/// Round a dollar value to the nearest legal bid: a whole multiple of
+/// `increment`, and never under `min_bid`.
+pub fn legal_bid(value: f64, min_bid: u32, increment: u32) -> u32 {
+    let increment = increment.max(1);
+    let steps = (value.max(0.0) / f64::from(increment)).round() as u32;
+    (steps * increment).max(min_bid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ladder_prices_out_teams_below_the_threshold() {
+        // Two teams are already out at $12.
+        let max_bids = [60, 45, 30, 25, 12, 5];
+        let ladder = bid_ladder(12, 1, &max_bids, 40, &[2, 1]);
+        assert_eq!(ladder.next_bid, 13);
+        assert_eq!(ladder.jump, Some(JumpBid { price: 31, priced_out: 2 }));
+        assert_eq!(ladder.survivors, vec![(2, 31), (1, 46)]);
+    }
+
+    #[test]
+    fn ladder_skips_unreachable_and_tied_points() {
+        // Everyone still in can reach the threshold: no jump.
+        let ladder = bid_ladder(10, 1, &[50, 50, 40], 30, &[3, 2, 1]);
+        assert_eq!(ladder.jump, None);
+        // Three teams are already all that is left, and the top two are tied.
+        assert_eq!(ladder.survivors, vec![(2, 41)]);
+
+        let ladder = bid_ladder(70, 1, &[50, 40], 100, &[1]);
+        assert_eq!(ladder.next_bid, 71);
+        assert_eq!(ladder.jump, None);
+        assert!(ladder.survivors.is_empty());
+    }
+
+    #[test]
+    fn ladder_prices_land_on_legal_raises() {
+        // $5 raises from $12: legal bids are 17, 22, 27, 32, ...
+        let max_bids = [60, 45, 30, 25, 12, 5];
+        let ladder = bid_ladder(12, 5, &max_bids, 40, &[2, 1]);
+        assert_eq!(ladder.next_bid, 17);
+        assert_eq!(ladder.jump, Some(JumpBid { price: 32, priced_out: 2 }));
+        assert_eq!(ladder.survivors, vec![(2, 32), (1, 47)]);
+
+        // When the first legal bid past the second team is past the first
+        // team too, no price leaves exactly one.
+        let ladder = bid_ladder(12, 5, &[60, 46, 30], 40, &[1]);
+        assert_eq!(ladder.survivors, vec![(1, 47)]);
+        let ladder = bid_ladder(12, 5, &[45, 44, 30], 40, &[1]);
+        assert!(ladder.survivors.is_empty());
+    }
+
+    #[test]
+    fn values_round_to_legal_bids() {
+        assert_eq!(legal_bid(23.5, 1, 1), 24);
+        assert_eq!(legal_bid(23.4, 1, 1), 23);
+        assert_eq!(legal_bid(23.4, 1, 5), 25);
+        assert_eq!(legal_bid(0.3, 1, 1), 1);
+        assert_eq!(legal_bid(0.3, 0, 1), 0);
+        assert_eq!(legal_bid(-4.0, 0, 0), 0);
+    }
+}
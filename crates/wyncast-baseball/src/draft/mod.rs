@@ -1,5 +1,17 @@
 // Draft state management: roster tracking, pick recording.
 
+pub mod dart_board;
+pub mod invariants;
+pub mod ladder;
+pub mod nomination_advice;
+pub mod nomination_order;
+pub mod nomination_queue;
+pub mod nominations;
 pub mod pick;
+pub mod plan_b;
 pub mod roster;
+pub mod roster_holes;
+pub mod session_stats;
+pub mod shopping_list;
+pub mod snake;
 pub mod state;
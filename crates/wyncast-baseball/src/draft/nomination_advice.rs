@@ -0,0 +1,311 @@
+// Nomination advice without the LLM: whom to put up next, by draft stage.
+//
+// What a nomination should do changes as the draft goes on. Early, with
+// every budget full, nominate elite players at positions I've already filled
+// so the other teams spend on them. In the middle, when a run on a position
+// starts, nominate more of it so the teams chasing it pay up. Late, with
+// budgets drained, nominate my own $1 targets before another team does. The
+// stage comes from how far the draft is and how full my roster is, whichever
+// is further along.
+
+use serde::{Deserialize, Serialize};
+
+use super::pick::{resolve_position, Position};
+use super::roster::Roster;
+use super::shopping_list::fits;
+use super::state::DraftState;
+use crate::valuation::cliff::cliff_position;
+use crate::valuation::scarcity::player_eligible_at;
+use crate::valuation::zscore::PlayerValuation;
+
+/// Share of the draft (or of my roster) done before the middle stage.
+const MID_STAGE_START: f64 = 0.3;
+
+/// Share of the draft (or of my roster) done before the late stage.
+const LATE_STAGE_START: f64 = 0.7;
+
+/// Recent picks looked at for a run on a position.
+const RUN_WINDOW: usize = 6;
+
+/// Picks at one position among the recent ones that make a run.
+const RUN_MIN_PICKS: usize = 3;
+
+/// Dollars over the minimum bid a late-draft target can be worth.
+const CHEAP_TARGET_MARGIN: f64 = 2.0;
+
+/// Players suggested at a time.
+pub const SUGGESTIONS: usize = 3;
+
+/// Where the draft is, for nomination purposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DraftStage {
+    /// Budgets are full: drain them.
+    Early,
+    /// Teams are filling needs: price-enforce the runs.
+    Mid,
+    /// Budgets are drained: grab the cheap targets.
+    Late,
+}
+
+impl DraftStage {
+    /// The stage for `pick_count` of `total_picks` made, with `filled` of my
+    /// `slots` roster slots filled.
+    pub fn detect(pick_count: usize, total_picks: usize, filled: usize, slots: usize) -> Self {
+        let share = |done: usize, of: usize| if of == 0 { 0.0 } else { done as f64 / of as f64 };
+        let progress = share(pick_count, total_picks).max(share(filled, slots));
+        if progress >= LATE_STAGE_START {
+            DraftStage::Late
+        } else if progress >= MID_STAGE_START {
+            DraftStage::Mid
+        } else {
+            DraftStage::Early
+        }
+    }
+
+    /// Return a human-readable label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DraftStage::Early => "early",
+            DraftStage::Mid => "mid",
+            DraftStage::Late => "late",
+        }
+    }
+
+    /// What nominations are for at this stage.
+    pub fn goal(&self) -> &'static str {
+        match self {
+            DraftStage::Early => "drain budgets",
+            DraftStage::Mid => "price-enforce runs",
+            DraftStage::Late => "grab $1 targets",
+        }
+    }
+}
+
+/// One player worth nominating, and why.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NominationSuggestion {
+    pub player_name: String,
+    pub dollar_value: f64,
+    pub reason: String,
+}
+
+/// The stage of the draft and the players to nominate in it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NominationAdvice {
+    pub stage: DraftStage,
+    /// Best first; empty when nobody fits the stage's goal.
+    pub suggestions: Vec<NominationSuggestion>,
+}
+
+/// Whether I still have an open positional slot `player` could start in.
+/// UTIL, DH and the bench don't count: anyone fills those.
+pub(crate) fn needed(roster: &Roster, player: &PlayerValuation) -> bool {
+    roster.slots.iter().any(|s| {
+        s.player.is_none()
+            && !matches!(
+                s.position,
+                Position::Utility | Position::DesignatedHitter | Position::Bench | Position::InjuredList | Position::Minors
+            )
+            && fits(s.position, player)
+    })
+}
+
+/// Outfield picks count toward one run, whichever field they play.
+fn run_position(position: Position) -> Position {
+    match position {
+        Position::LeftField | Position::CenterField | Position::RightField => Position::Outfield,
+        other => other,
+    }
+}
+
+/// The position of a run among the recent picks, with its pick count.
+fn current_run(draft_state: &DraftState) -> Option<(Position, usize)> {
+    let mut counts: Vec<(Position, usize)> = Vec::new();
+    let recent = draft_state.picks.iter().rev().take(RUN_WINDOW);
+    for position in recent.filter_map(|p| resolve_position(&p.position, &p.eligible_slots)) {
+        if position.is_meta_slot() {
+            continue;
+        }
+        let position = run_position(position);
+        match counts.iter_mut().find(|(p, _)| *p == position) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((position, 1)),
+        }
+    }
+    counts
+        .into_iter()
+        .filter(|&(_, n)| n >= RUN_MIN_PICKS)
+        .max_by_key(|&(_, n)| n)
+}
+
+fn label(player: &PlayerValuation) -> &'static str {
+    cliff_position(player).map_or("player", |p| p.display_str())
+}
+
+/// The most valuable players I don't need, as budget drains.
+fn drains<'a>(
+    roster: &Roster,
+    players: impl Iterator<Item = &'a PlayerValuation>,
+    reason: impl Fn(&PlayerValuation) -> String,
+) -> Vec<NominationSuggestion> {
+    let mut drains: Vec<&PlayerValuation> = players.filter(|p| !needed(roster, p)).collect();
+    drains.sort_by(|a, b| b.dollar_value.total_cmp(&a.dollar_value));
+    drains
+        .into_iter()
+        .take(SUGGESTIONS)
+        .map(|p| NominationSuggestion {
+            player_name: p.name.clone(),
+            dollar_value: p.dollar_value,
+            reason: reason(p),
+        })
+        .collect()
+}
+
+/// Advice for my next nomination. `None` before my team is known.
+pub fn nomination_advice(
+    draft_state: &DraftState,
+    available: &[PlayerValuation],
+    min_bid: u32,
+) -> Option<NominationAdvice> {
+    let roster = &draft_state.my_team()?.roster;
+    let stage = DraftStage::detect(
+        draft_state.pick_count,
+        draft_state.total_picks,
+        roster.filled_count(),
+        roster.draftable_count(),
+    );
+
+    let suggestions = match stage {
+        DraftStage::Early => drains(roster, available.iter(), |p| {
+            format!("elite {}, your slots there are filled", label(p))
+        }),
+        DraftStage::Mid => {
+            let run = current_run(draft_state).map(|(position, picks)| {
+                let in_run = available.iter().filter(|p| player_eligible_at(p, position));
+                drains(roster, in_run, |_| {
+                    format!("{position} run, {picks} of the last {RUN_WINDOW} picks: make them pay")
+                })
+            });
+            match run {
+                Some(run) if !run.is_empty() => run,
+                _ => drains(roster, available.iter(), |p| {
+                    format!("you don't need a {}: make them pay", label(p))
+                }),
+            }
+        }
+        DraftStage::Late => {
+            let ceiling = f64::from(min_bid.max(1)) + CHEAP_TARGET_MARGIN;
+            let mut targets: Vec<&PlayerValuation> = available
+                .iter()
+                .filter(|p| p.vor > 0.0 && p.dollar_value <= ceiling && needed(roster, p))
+                .collect();
+            targets.sort_by(|a, b| b.vor.total_cmp(&a.vor));
+            targets
+                .into_iter()
+                .take(SUGGESTIONS)
+                .map(|p| NominationSuggestion {
+                    player_name: p.name.clone(),
+                    dollar_value: p.dollar_value,
+                    reason: format!("cheap {} you need: get them before others can", label(p)),
+                })
+                .collect()
+        }
+    };
+    Some(NominationAdvice { stage, suggestions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::roster::RosteredPlayer;
+    use crate::test_utils::{create_test_draft_state, test_pick, TestPlayer};
+    use crate::valuation::projections::PitcherType;
+
+    fn fill(state: &mut DraftState, position: Position) {
+        let slot = state.teams[0]
+            .roster
+            .slots
+            .iter_mut()
+            .find(|s| s.position == position && s.player.is_none())
+            .unwrap();
+        slot.player = Some(RosteredPlayer {
+            name: "X".into(),
+            price: 1,
+            position,
+            eligible_slots: vec![],
+            espn_player_id: None,
+        });
+    }
+
+    fn names(advice: &NominationAdvice) -> Vec<&str> {
+        advice.suggestions.iter().map(|s| s.player_name.as_str()).collect()
+    }
+
+    #[test]
+    fn stage_follows_the_draft_or_my_roster_whichever_is_further() {
+        assert_eq!(DraftStage::detect(0, 0, 0, 0), DraftStage::Early);
+        assert_eq!(DraftStage::detect(20, 100, 2, 20), DraftStage::Early);
+        assert_eq!(DraftStage::detect(30, 100, 2, 20), DraftStage::Mid);
+        assert_eq!(DraftStage::detect(20, 100, 14, 20), DraftStage::Late);
+        assert_eq!(DraftStage::detect(70, 100, 0, 20), DraftStage::Late);
+    }
+
+    #[test]
+    fn early_nominations_are_elite_players_at_filled_positions() {
+        let mut state = create_test_draft_state(2);
+        fill(&mut state, Position::Catcher);
+        let available = vec![
+            TestPlayer::hitter("Star SS").positions(vec![Position::ShortStop]).dollar(40.0).build(),
+            TestPlayer::hitter("Star C").positions(vec![Position::Catcher]).dollar(30.0).build(),
+            TestPlayer::hitter("Okay C").positions(vec![Position::Catcher]).dollar(10.0).build(),
+        ];
+        let advice = nomination_advice(&state, &available, 1).unwrap();
+        assert_eq!(advice.stage, DraftStage::Early);
+        assert_eq!(names(&advice), ["Star C", "Okay C"]);
+        assert_eq!(advice.suggestions[0].reason, "elite C, your slots there are filled");
+    }
+
+    #[test]
+    fn mid_draft_nominations_feed_a_run() {
+        let mut state = create_test_draft_state(2);
+        state.total_picks = 20;
+        for (n, position) in ["SP", "C", "SP", "SS", "SP", "1B"].into_iter().enumerate() {
+            state.record_pick(test_pick(&format!("Gone {n}")).team(2).position(position).price(10).build());
+        }
+        state.pick_count = 8;
+        let available = vec![
+            TestPlayer::hitter("Star SS").positions(vec![Position::ShortStop]).dollar(40.0).build(),
+            TestPlayer::pitcher("Ace", PitcherType::SP).dollar(25.0).build(),
+        ];
+        // Both are open on my roster, so the run is fed only once my SP
+        // slots are full.
+        let advice = nomination_advice(&state, &available, 1).unwrap();
+        assert_eq!(advice.stage, DraftStage::Mid);
+        assert!(advice.suggestions.is_empty());
+
+        for _ in 0..5 {
+            fill(&mut state, Position::StartingPitcher);
+        }
+        let advice = nomination_advice(&state, &available, 1).unwrap();
+        assert_eq!(names(&advice), ["Ace"]);
+        assert_eq!(advice.suggestions[0].reason, "SP run, 3 of the last 6 picks: make them pay");
+    }
+
+    #[test]
+    fn late_nominations_are_my_cheap_targets() {
+        let mut state = create_test_draft_state(2);
+        state.total_picks = 20;
+        state.pick_count = 15;
+        fill(&mut state, Position::Catcher);
+        let available = vec![
+            TestPlayer::hitter("Cheap C").positions(vec![Position::Catcher]).dollar(1.0).vor(2.0).build(),
+            TestPlayer::hitter("Cheap SS").positions(vec![Position::ShortStop]).dollar(2.0).vor(1.0).build(),
+            TestPlayer::hitter("Better SS").positions(vec![Position::ShortStop]).dollar(3.0).vor(3.0).build(),
+            TestPlayer::hitter("Pricey SS").positions(vec![Position::ShortStop]).dollar(9.0).vor(8.0).build(),
+            TestPlayer::hitter("Scrub SS").positions(vec![Position::ShortStop]).dollar(1.0).vor(-1.0).build(),
+        ];
+        let advice = nomination_advice(&state, &available, 1).unwrap();
+        assert_eq!(advice.stage, DraftStage::Late);
+        assert_eq!(names(&advice), ["Better SS", "Cheap SS"]);
+    }
+}
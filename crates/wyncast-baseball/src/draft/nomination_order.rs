@@ -0,0 +1,191 @@
+// Nomination order: whose turn it is to nominate.
+//
+// Auction nominations go round the teams in a fixed order (ESPN's draft
+// board columns, or `league.nomination_order` in the config), skipping teams
+// whose rosters are full. The turn is counted from the latest nomination
+// whose nominator is known, one step per nomination since; with no known
+// nominator it is counted from the top of the order.
+
+use serde::{Deserialize, Serialize};
+
+use super::state::{DraftState, TeamState};
+
+/// Whose turn it is to nominate, seen from my team.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NominationTurn {
+    /// Name of the team that makes the next nomination.
+    pub next_team: String,
+    /// Nominations before my next one: 0 when I nominate next. `None` when
+    /// my team is unknown, outside the order, or has a full roster.
+    pub my_turn_in: Option<usize>,
+}
+
+/// Team indices in the order `names` lists them (matched by team name or ID,
+/// ignoring case), followed by the teams not listed, in `teams` order.
+pub fn order_from_names(teams: &[TeamState], names: &[&str]) -> Vec<usize> {
+    let mut order: Vec<usize> = Vec::with_capacity(teams.len());
+    for name in names {
+        let found = teams.iter().position(|t| {
+            t.team_name.eq_ignore_ascii_case(name) || t.team_id.eq_ignore_ascii_case(name)
+        });
+        if let Some(idx) = found.filter(|idx| !order.contains(idx)) {
+            order.push(idx);
+        }
+    }
+    let unlisted: Vec<usize> = (0..teams.len()).filter(|idx| !order.contains(idx)).collect();
+    order.extend(unlisted);
+    order
+}
+
+/// Whose turn it is to nominate next, and how many nominations until mine.
+/// `None` before the order is known or once every roster is full.
+pub fn nomination_turn(draft_state: &DraftState) -> Option<NominationTurn> {
+    let teams = &draft_state.teams;
+    let order: Vec<usize> = draft_state
+        .nomination_order
+        .iter()
+        .copied()
+        .filter(|&idx| idx < teams.len())
+        .collect();
+    let open = |pos: usize| teams[order[pos]].roster.empty_slots() > 0;
+    // The next position after `pos` whose team can still nominate.
+    let step = |pos: usize| {
+        (1..=order.len())
+            .map(|k| (pos + k) % order.len())
+            .find(|&p| open(p))
+    };
+
+    // Every nomination so far, oldest first: the players sold, then the one
+    // on the block. Each carries its nominator's place in the order, if known.
+    let nominator_pos = |nominator: Option<&String>| {
+        let nominator = nominator?;
+        order.iter().position(|&idx| {
+            &teams[idx].team_name == nominator || &teams[idx].team_id == nominator
+        })
+    };
+    let mut nominations: Vec<Option<usize>> = draft_state
+        .picks
+        .iter()
+        .map(|pick| nominator_pos(draft_state.nominators.get(&pick.player_name)))
+        .collect();
+    if let Some(nomination) = &draft_state.current_nomination {
+        let nominator = draft_state
+            .nominators
+            .get(&nomination.player_name)
+            .or(Some(&nomination.nominated_by).filter(|n| !n.is_empty()));
+        nominations.push(nominator_pos(nominator));
+    }
+
+    // Anchor on the latest known nominator (or just before the top of the
+    // order), then step once for each nomination after it.
+    let (mut pos, steps) = match nominations.iter().rposition(Option::is_some) {
+        Some(i) => (nominations[i]?, nominations.len() - 1 - i),
+        None => (order.len().checked_sub(1)?, nominations.len()),
+    };
+    for _ in 0..steps {
+        pos = step(pos)?;
+    }
+    let next = step(pos)?;
+
+    let my_turn_in = draft_state
+        .my_team_idx
+        .and_then(|me| order.iter().position(|&idx| idx == me))
+        .filter(|&mine| open(mine))
+        .map(|mine| {
+            let mut pos = next;
+            let mut turns = 0;
+            while pos != mine {
+                pos = step(pos).unwrap_or(mine);
+                turns += 1;
+            }
+            turns
+        });
+
+    Some(NominationTurn {
+        next_team: teams[order[next]].team_name.clone(),
+        my_turn_in,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::DraftPick;
+    use crate::draft::roster::RosteredPlayer;
+    use crate::test_utils::create_test_draft_state;
+
+    fn sell(state: &mut DraftState, player: &str, nominator: Option<&str>) {
+        if let Some(nominator) = nominator {
+            state.nominators.insert(player.to_string(), nominator.to_string());
+        }
+        state.record_pick(DraftPick {
+            pick_number: 0,
+            team_id: "2".to_string(),
+            team_name: "Team 2".to_string(),
+            player_name: player.to_string(),
+            position: "OF".to_string(),
+            price: 1,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        });
+    }
+
+    fn fill_roster(state: &mut DraftState, team: usize) {
+        for slot in &mut state.teams[team].roster.slots {
+            slot.player.get_or_insert_with(|| RosteredPlayer {
+                name: "X".into(),
+                price: 1,
+                position: slot.position,
+                eligible_slots: vec![],
+                espn_player_id: None,
+            });
+        }
+    }
+
+    #[test]
+    fn order_follows_names_then_the_rest() {
+        let state = create_test_draft_state(4);
+        let order = order_from_names(&state.teams, &["team 3", "1", "Nobody", "Team 3"]);
+        assert_eq!(order, vec![2, 0, 1, 3]);
+    }
+
+    #[test]
+    fn turn_counts_from_the_top_without_nominators() {
+        let mut state = create_test_draft_state(4);
+        let turn = nomination_turn(&state).unwrap();
+        assert_eq!(turn.next_team, "Team 1");
+        assert_eq!(turn.my_turn_in, Some(0));
+
+        sell(&mut state, "A", None);
+        sell(&mut state, "B", None);
+        let turn = nomination_turn(&state).unwrap();
+        assert_eq!(turn.next_team, "Team 3");
+        assert_eq!(turn.my_turn_in, Some(2));
+    }
+
+    #[test]
+    fn turn_follows_the_latest_known_nominator() {
+        let mut state = create_test_draft_state(4);
+        state.nomination_order = vec![3, 2, 1, 0];
+        sell(&mut state, "A", Some("Team 3"));
+        sell(&mut state, "B", None);
+        // Team 3 nominated, then Team 2 (unknown): Team 1 is next, me.
+        let turn = nomination_turn(&state).unwrap();
+        assert_eq!(turn.next_team, "Team 1");
+        assert_eq!(turn.my_turn_in, Some(0));
+
+        // Full rosters are skipped, mine included.
+        fill_roster(&mut state, 0);
+        let turn = nomination_turn(&state).unwrap();
+        assert_eq!(turn.next_team, "Team 4");
+        assert_eq!(turn.my_turn_in, None);
+    }
+
+    #[test]
+    fn no_turn_without_an_order() {
+        let mut state = create_test_draft_state(2);
+        state.nomination_order.clear();
+        assert_eq!(nomination_turn(&state), None);
+    }
+}
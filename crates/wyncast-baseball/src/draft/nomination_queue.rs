@@ -0,0 +1,383 @@
+// My nomination script: the players I mean to nominate first, in order.
+//
+// A CSV of players, read once at startup like the prep sheet. Each row may
+// say what happens when the player can no longer be nominated: `skip` (the
+// default) moves on to the next player, `stop` ends the script there, since
+// the players after it were planned around them. A row can also ask to be
+// skipped once my roster has no open slot the player would start in.
+//
+// The script advances on its own: a scripted player I nominate is done, and
+// one another team nominates first, or who is drafted, falls back by their
+// rule. The status of every row is recomputed from the draft on each
+// snapshot, so it is never stored.
+
+use std::io::Read;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use wyncast_core::config::DataPaths;
+
+use super::nomination_advice::needed;
+use super::state::DraftState;
+use crate::valuation::prep::{loose_name, ticked};
+use crate::valuation::projections::{resolve_data_path, ProjectionError};
+use crate::valuation::zscore::PlayerValuation;
+
+/// What happens to the script when a player in it can't be nominated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Fallback {
+    /// Move on to the next player.
+    #[default]
+    Skip,
+    /// End the script: the rest depended on this player.
+    Stop,
+}
+
+/// One row of the script.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueEntry {
+    pub player: String,
+    pub fallback: Fallback,
+    /// Skip the player once none of my open slots would start them.
+    pub skip_if_filled: bool,
+}
+
+/// Where a scripted player stands.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScriptStatus {
+    /// My next nomination.
+    Next,
+    /// Still to come, after the next one.
+    Waiting,
+    /// I nominated them, as scripted.
+    Nominated,
+    /// Another team nominated them first.
+    NominatedBy(String),
+    /// On the block, nominator not known yet.
+    OnTheBlock,
+    /// Drafted without a known nomination.
+    Drafted,
+    /// Skipped: my roster has no open slot they would start in.
+    Filled,
+    /// The name matches no player in the pool.
+    Unknown,
+    /// An earlier player with the `stop` rule fell through.
+    Stopped,
+}
+
+impl ScriptStatus {
+    /// Whether the row still counts toward my nominations.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, ScriptStatus::Next | ScriptStatus::Waiting)
+    }
+
+    /// A short description, empty for a player still waiting.
+    pub fn label(&self) -> String {
+        match self {
+            ScriptStatus::Next => "next".into(),
+            ScriptStatus::Waiting => String::new(),
+            ScriptStatus::Nominated => "nominated".into(),
+            ScriptStatus::NominatedBy(team) => format!("nominated by {team}"),
+            ScriptStatus::OnTheBlock => "on the block".into(),
+            ScriptStatus::Drafted => "drafted".into(),
+            ScriptStatus::Filled => "skipped, slots filled".into(),
+            ScriptStatus::Unknown => "not in the player pool".into(),
+            ScriptStatus::Stopped => "script stopped".into(),
+        }
+    }
+}
+
+/// A scripted player with their live status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptedNomination {
+    pub player: String,
+    pub status: ScriptStatus,
+}
+
+/// Column indices of the script's fields, from its header row.
+#[derive(Debug, Default)]
+struct Columns {
+    player: Option<usize>,
+    fallback: Option<usize>,
+    skip_if_filled: Option<usize>,
+}
+
+impl Columns {
+    fn from_headers(headers: &csv::StringRecord) -> Self {
+        let mut columns = Columns::default();
+        for (i, header) in headers.iter().enumerate() {
+            let key: String = header
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .map(|c| c.to_ascii_lowercase())
+                .collect();
+            let column = match key.as_str() {
+                "player" | "name" => &mut columns.player,
+                "fallback" | "ifgone" | "rule" => &mut columns.fallback,
+                "skipiffilled" | "needed" => &mut columns.skip_if_filled,
+                _ => continue,
+            };
+            column.get_or_insert(i);
+        }
+        columns
+    }
+}
+
+fn load_from_reader<R: Read>(rdr: R) -> Result<Vec<QueueEntry>, csv::Error> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(rdr);
+    let columns = Columns::from_headers(reader.headers()?);
+    let Some(player_column) = columns.player else {
+        warn!("nomination script has no player column; ignoring it");
+        return Ok(Vec::new());
+    };
+
+    let mut entries = Vec::new();
+    for result in reader.records() {
+        let record = match result {
+            Ok(record) => record,
+            Err(e) => {
+                warn!("skipping malformed nomination script row: {}", e);
+                continue;
+            }
+        };
+        let cell = |column: Option<usize>| column.and_then(|i| record.get(i)).map_or("", str::trim);
+        let player = cell(Some(player_column));
+        if player.is_empty() {
+            continue;
+        }
+        let fallback = match cell(columns.fallback).to_ascii_lowercase().as_str() {
+            "" | "skip" => Fallback::Skip,
+            "stop" => Fallback::Stop,
+            other => {
+                warn!("nomination script: unknown fallback '{}' for {}, skipping instead", other, player);
+                Fallback::Skip
+            }
+        };
+        entries.push(QueueEntry {
+            player: player.to_string(),
+            fallback,
+            skip_if_filled: ticked(cell(columns.skip_if_filled)),
+        });
+    }
+    Ok(entries)
+}
+
+/// Load the nomination script configured under `[data_paths]`, or nothing
+/// when none is configured.
+pub fn load(paths: &DataPaths) -> Result<Vec<QueueEntry>, ProjectionError> {
+    let Some(raw) = &paths.nomination_queue else {
+        return Ok(Vec::new());
+    };
+    let path = resolve_data_path(raw);
+    let file = std::fs::File::open(&path).map_err(|e| ProjectionError::Io {
+        path: path.display().to_string(),
+        source: e,
+    })?;
+    load_from_reader(file).map_err(|e| ProjectionError::Csv {
+        path: path.display().to_string(),
+        source: e,
+    })
+}
+
+/// Match each entry's name to `players` the way the prep sheet does, and
+/// return the names that match nobody, in script order.
+pub fn reconcile(entries: &mut [QueueEntry], players: &[PlayerValuation]) -> Vec<String> {
+    let mut unmatched = Vec::new();
+    for entry in entries {
+        if players.iter().any(|p| p.name == entry.player) {
+            continue;
+        }
+        let loose = loose_name(&entry.player);
+        match players.iter().find(|p| loose_name(&p.name) == loose) {
+            Some(player) => entry.player = player.name.clone(),
+            None => unmatched.push(entry.player.clone()),
+        }
+    }
+    unmatched
+}
+
+/// Each scripted player's status, in script order. The first one I can
+/// still nominate is `Next`.
+pub fn script_status(
+    entries: &[QueueEntry],
+    draft_state: &DraftState,
+    available: &[PlayerValuation],
+) -> Vec<ScriptedNomination> {
+    let my_team = draft_state.my_team();
+    let is_me = |team: &str| my_team.is_some_and(|t| t.team_name == team || t.team_id == team);
+    let mut stopped = false;
+    let mut next_found = false;
+
+    entries
+        .iter()
+        .map(|entry| {
+            let name = entry.player.as_str();
+            let on_block = draft_state.current_nomination.as_ref().is_some_and(|n| n.player_name == name);
+            let player = available.iter().find(|p| p.name == name);
+            let status = if let Some(team) = draft_state.nominators.get(name).filter(|t| !t.is_empty()) {
+                if is_me(team) {
+                    ScriptStatus::Nominated
+                } else {
+                    ScriptStatus::NominatedBy(team.clone())
+                }
+            } else if on_block {
+                ScriptStatus::OnTheBlock
+            } else if draft_state.picks.iter().any(|p| p.player_name == name) {
+                ScriptStatus::Drafted
+            } else if player.is_none() && !available.is_empty() {
+                ScriptStatus::Unknown
+            } else if stopped {
+                ScriptStatus::Stopped
+            } else if let (true, Some(player), Some(team)) = (entry.skip_if_filled, player, my_team) {
+                if needed(&team.roster, player) {
+                    ScriptStatus::Waiting
+                } else {
+                    ScriptStatus::Filled
+                }
+            } else {
+                ScriptStatus::Waiting
+            };
+
+            let fell_through = matches!(
+                status,
+                ScriptStatus::NominatedBy(_) | ScriptStatus::Drafted | ScriptStatus::Filled | ScriptStatus::Unknown
+            );
+            if fell_through && entry.fallback == Fallback::Stop {
+                stopped = true;
+            }
+            let status = if status == ScriptStatus::Waiting && !next_found {
+                next_found = true;
+                ScriptStatus::Next
+            } else {
+                status
+            };
+            ScriptedNomination { player: entry.player.clone(), status }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::{DraftPick, Position};
+    use crate::draft::state::ActiveNomination;
+    use crate::test_utils::{create_test_draft_state, TestPlayer};
+
+    fn entry(player: &str, fallback: Fallback, skip_if_filled: bool) -> QueueEntry {
+        QueueEntry { player: player.into(), fallback, skip_if_filled }
+    }
+
+    fn nomination(player: &str, by: &str) -> ActiveNomination {
+        ActiveNomination {
+            player_name: player.into(),
+            player_id: String::new(),
+            position: "SS".into(),
+            nominated_by: by.into(),
+            current_bid: 1,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        }
+    }
+
+    fn statuses(entries: &[QueueEntry], state: &DraftState, available: &[PlayerValuation]) -> Vec<ScriptStatus> {
+        script_status(entries, state, available).into_iter().map(|s| s.status).collect()
+    }
+
+    #[test]
+    fn reads_the_script_with_its_rules() {
+        let csv = "\
+Player,If Gone,Skip If Filled
+Juan Soto,,
+Bobby Witt Jr.,stop,x
+,skip,
+Luis Arraez,later,
+";
+        let entries = load_from_reader(csv.as_bytes()).unwrap();
+        assert_eq!(
+            entries,
+            [
+                entry("Juan Soto", Fallback::Skip, false),
+                entry("Bobby Witt Jr.", Fallback::Stop, true),
+                entry("Luis Arraez", Fallback::Skip, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn the_script_advances_past_players_who_are_gone() {
+        let available: Vec<PlayerValuation> = ["A", "B", "C", "D"]
+            .iter()
+            .map(|name| TestPlayer::hitter(name).positions(vec![Position::ShortStop]).build())
+            .collect();
+        let entries = [
+            entry("Gone", Fallback::Skip, false),
+            entry("A", Fallback::Skip, false),
+            entry("B", Fallback::Skip, false),
+            entry("C", Fallback::Skip, false),
+            entry("Nobody", Fallback::Skip, false),
+        ];
+        let mut state = create_test_draft_state(2);
+        state.picks.push(DraftPick {
+            pick_number: 1,
+            team_id: "2".into(),
+            team_name: "Team 2".into(),
+            player_name: "Gone".into(),
+            position: "SS".into(),
+            price: 10,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        });
+        use ScriptStatus::*;
+        assert_eq!(statuses(&entries, &state, &available), [Drafted, Next, Waiting, Waiting, Unknown]);
+
+        // I nominate A; another team beats me to B.
+        state.note_nominator(&nomination("A", "Team 1"));
+        state.note_nominator(&nomination("B", "Team 2"));
+        assert_eq!(
+            statuses(&entries, &state, &available),
+            [Drafted, Nominated, NominatedBy("Team 2".into()), Next, Unknown]
+        );
+    }
+
+    #[test]
+    fn stop_ends_the_script_and_filled_slots_skip() {
+        let available = vec![
+            TestPlayer::hitter("Shortstop").positions(vec![Position::ShortStop]).build(),
+            TestPlayer::hitter("Catcher").positions(vec![Position::Catcher]).build(),
+            TestPlayer::hitter("Later").positions(vec![Position::FirstBase]).build(),
+        ];
+        let mut state = create_test_draft_state(2);
+        let entries = [entry("Shortstop", Fallback::Stop, false), entry("Catcher", Fallback::Skip, true), entry("Later", Fallback::Skip, false)];
+        use ScriptStatus::*;
+        assert_eq!(statuses(&entries, &state, &available), [Next, Waiting, Waiting]);
+
+        // On the block, maybe by me: the script holds.
+        state.current_nomination = Some(nomination("Shortstop", ""));
+        assert_eq!(statuses(&entries, &state, &available), [OnTheBlock, Next, Waiting]);
+
+        state.note_nominator(&nomination("Shortstop", "Team 2"));
+        assert_eq!(statuses(&entries, &state, &available), [NominatedBy("Team 2".into()), Stopped, Stopped]);
+
+        state.current_nomination = None;
+        state.nominators.clear();
+        let catcher = state.teams[0].roster.slots.iter_mut().find(|s| s.position == Position::Catcher).unwrap();
+        catcher.player = Some(crate::draft::roster::RosteredPlayer {
+            name: "Mine".into(),
+            price: 5,
+            position: Position::Catcher,
+            eligible_slots: vec![],
+            espn_player_id: None,
+        });
+        assert_eq!(statuses(&entries, &state, &available), [Next, Filled, Waiting]);
+    }
+
+    #[test]
+    fn reconciling_takes_the_projections_spelling() {
+        let players = vec![TestPlayer::hitter("Bobby Witt Jr.").build()];
+        let mut entries = vec![entry("bobby witt", Fallback::Skip, false), entry("Jon Doe", Fallback::Skip, false)];
+        assert_eq!(reconcile(&mut entries, &players), ["Jon Doe"]);
+        assert_eq!(entries[0].player, "Bobby Witt Jr.");
+    }
+}
@@ -0,0 +1,155 @@
+// Nomination habits: who nominated each sold player, and whether they
+// bought it themselves.
+//
+// A team that mostly buys its own nominations is putting up players it
+// wants; one that mostly sells them is nominating to drain the other teams'
+// budgets (price enforcing). Knowing which is which tells you how hard to
+// push on their nominations.
+
+use serde::{Deserialize, Serialize};
+
+use super::state::DraftState;
+
+/// Fewest sold nominations before a team's habit is labelled.
+const MIN_NOMINATIONS: usize = 3;
+
+/// One team's nominations among the players sold so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct NominationPattern {
+    /// Players the team nominated that have been sold.
+    pub nominated: usize,
+    /// How many of those the team bought itself.
+    pub kept: usize,
+    /// Total price the players it nominated went for.
+    pub total_price: u32,
+}
+
+/// What a team's nominations say about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NominationStyle {
+    /// Buys most of what it nominates.
+    Targets,
+    /// Sells most of what it nominates to other teams.
+    PriceEnforcer,
+    /// Some of each.
+    Mixed,
+    /// Too few sold nominations to tell.
+    Unknown,
+}
+
+impl NominationStyle {
+    pub fn label(self) -> &'static str {
+        match self {
+            NominationStyle::Targets => "targets",
+            NominationStyle::PriceEnforcer => "enforcer",
+            NominationStyle::Mixed => "mixed",
+            NominationStyle::Unknown => "",
+        }
+    }
+}
+
+impl NominationPattern {
+    /// Average price of the players the team nominated.
+    pub fn avg_price(&self) -> Option<f64> {
+        (self.nominated > 0).then(|| f64::from(self.total_price) / self.nominated as f64)
+    }
+
+    /// Targets when the team bought at least half its nominations, price
+    /// enforcer when it bought a quarter or less.
+    pub fn style(&self) -> NominationStyle {
+        if self.nominated < MIN_NOMINATIONS {
+            return NominationStyle::Unknown;
+        }
+        let kept_share = self.kept as f64 / self.nominated as f64;
+        if kept_share >= 0.5 {
+            NominationStyle::Targets
+        } else if kept_share <= 0.25 {
+            NominationStyle::PriceEnforcer
+        } else {
+            NominationStyle::Mixed
+        }
+    }
+}
+
+/// Nomination patterns for every team, in `draft_state.teams` order.
+/// Nominators that match no team's name or ID are ignored.
+pub fn nomination_patterns(draft_state: &DraftState) -> Vec<NominationPattern> {
+    let teams = &draft_state.teams;
+    let mut patterns = vec![NominationPattern::default(); teams.len()];
+    for pick in &draft_state.picks {
+        let Some(nominator) = draft_state.nominators.get(&pick.player_name) else {
+            continue;
+        };
+        let Some(idx) = teams
+            .iter()
+            .position(|t| &t.team_name == nominator || &t.team_id == nominator)
+        else {
+            continue;
+        };
+        let pattern = &mut patterns[idx];
+        pattern.nominated += 1;
+        pattern.total_price += pick.price;
+        if pick.team_id == teams[idx].team_id {
+            pattern.kept += 1;
+        }
+    }
+    patterns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_draft_state, test_pick};
+
+    #[test]
+    fn patterns_count_kept_and_sold_nominations() {
+        let mut state = create_test_draft_state(3);
+        // Team 1 keeps two of three; Team 2 sells all three; nobody is
+        // credited with the un-nominated pick or the unknown nominator.
+        let sales = [
+            ("A", "Team 1", 1, 30),
+            ("B", "1", 1, 20),
+            ("C", "Team 1", 2, 10),
+            ("D", "Team 2", 1, 40),
+            ("E", "Team 2", 3, 25),
+            ("F", "Team 2", 3, 15),
+            ("G", "Team 9", 2, 5),
+        ];
+        for (player, nominator, winner, price) in sales {
+            state.nominators.insert(player.to_string(), nominator.to_string());
+            state.record_pick(test_pick(player).team(winner).position("OF").price(price).build());
+        }
+        state.record_pick(test_pick("H").team(2).position("OF").build());
+
+        let patterns = nomination_patterns(&state);
+        assert_eq!(patterns[0], NominationPattern { nominated: 3, kept: 2, total_price: 60 });
+        assert_eq!(patterns[0].style(), NominationStyle::Targets);
+        assert_eq!(patterns[1], NominationPattern { nominated: 3, kept: 0, total_price: 80 });
+        assert_eq!(patterns[1].style(), NominationStyle::PriceEnforcer);
+        assert_eq!(patterns[2], NominationPattern::default());
+        assert_eq!(patterns[2].style(), NominationStyle::Unknown);
+        assert_eq!(patterns[2].avg_price(), None);
+    }
+
+    #[test]
+    fn note_nominator_records_new_and_backfilled_nominators() {
+        use crate::draft::state::ActiveNomination;
+
+        let mut state = create_test_draft_state(2);
+        let mut nomination = ActiveNomination {
+            player_name: "Juan Soto".to_string(),
+            player_id: "1".to_string(),
+            position: "OF".to_string(),
+            nominated_by: String::new(),
+            current_bid: 1,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        };
+        assert!(!state.note_nominator(&nomination));
+        nomination.nominated_by = "Team 2".to_string();
+        assert!(state.note_nominator(&nomination));
+        assert!(!state.note_nominator(&nomination));
+        assert_eq!(state.nominators["Juan Soto"], "Team 2");
+    }
+}
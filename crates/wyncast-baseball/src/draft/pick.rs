@@ -25,6 +25,8 @@ pub const ESPN_SLOT_SP: u16 = 14;
 pub const ESPN_SLOT_RP: u16 = 15;
 pub const ESPN_SLOT_BE: u16 = 16;
 pub const ESPN_SLOT_IL: u16 = 17;
+pub const ESPN_SLOT_NA: u16 = 18; // minor league
+pub const ESPN_SLOT_IF: u16 = 19; // 1B/2B/3B/SS combo
 
 /// Baseball positions used for roster slot assignment.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -51,6 +53,11 @@ pub enum Position {
     CornerInfield,
     /// Generic P slot — accepts SP, RP.
     GenericPitcher,
+    /// IF combo slot — accepts 1B, 2B, 3B, SS.
+    Infield,
+    /// Minor league (NA) or taxi slot — drafted into, but outside the
+    /// salary cap and the active roster.
+    Minors,
 }
 
 impl Position {
@@ -59,9 +66,13 @@ impl Position {
     /// Handles ESPN-style abbreviations:
     /// - "1B" -> FirstBase, "2B" -> SecondBase, "3B" -> ThirdBase
     /// - "OF" -> CenterField (generic outfield maps to CenterField slot)
-    /// - "DH" -> DesignatedHitter, "UTIL" -> Utility, "BE"/"BN" -> Bench, "IL"/"DL" -> InjuredList
+    /// - "DH" -> DesignatedHitter, "UTIL"/"UT" -> Utility, "BE"/"BN" -> Bench
+    /// - "IL"/"DL"/"IR" -> InjuredList, "NA"/"MINORS"/"TAXI" -> Minors
+    ///
+    /// Only a single position; see [`Position::parse_list`] for strings
+    /// listing several, like "1B/3B" or "SP, RP".
     pub fn from_str_pos(s: &str) -> Option<Self> {
-        match s.to_uppercase().as_str() {
+        match s.trim().to_uppercase().as_str() {
             "C" => Some(Position::Catcher),
             "1B" => Some(Position::FirstBase),
             "2B" => Some(Position::SecondBase),
@@ -74,13 +85,47 @@ impl Position {
             "SP" => Some(Position::StartingPitcher),
             "RP" => Some(Position::ReliefPitcher),
             "DH" => Some(Position::DesignatedHitter),
-            "UTIL" => Some(Position::Utility),
+            "UTIL" | "UT" => Some(Position::Utility),
             "BE" | "BN" => Some(Position::Bench),
-            "IL" | "DL" => Some(Position::InjuredList),
+            "IL" | "DL" | "IR" => Some(Position::InjuredList),
+            "NA" | "MINORS" | "TAXI" => Some(Position::Minors),
             _ => None,
         }
     }
 
+    /// Parse a scraped position string into every position it lists, in
+    /// order and without repeats.
+    ///
+    /// ESPN writes multi-position eligibility as "1B/3B", "SP, RP" or
+    /// "2B SS"; any of `/`, `,`, `;` and whitespace separate positions. Each
+    /// token is read with [`Position::from_str_pos`], so "OF" is CenterField,
+    /// and the combo abbreviations "MI", "CI", "IF" and "P" are read as their
+    /// combo slots. Unknown tokens are skipped.
+    pub fn parse_list(s: &str) -> Vec<Self> {
+        let mut positions = Vec::new();
+        for token in position_tokens(s) {
+            let Some(pos) = Self::from_str_pos(token).or_else(|| Self::from_roster_slot_str(token)) else {
+                continue;
+            };
+            if !positions.contains(&pos) {
+                positions.push(pos);
+            }
+        }
+        positions
+    }
+
+    /// The primary position in a scraped position string: the first playing
+    /// position listed, or the first position of any kind when only
+    /// meta-slots are listed (a "BE" pick stays on the bench).
+    pub fn parse_primary(s: &str) -> Option<Self> {
+        let positions = Self::parse_list(s);
+        positions
+            .iter()
+            .find(|pos| !pos.is_meta_slot())
+            .or(positions.first())
+            .copied()
+    }
+
     /// Return the display string for this position.
     pub fn display_str(&self) -> &'static str {
         match self {
@@ -102,6 +147,8 @@ impl Position {
             Position::MiddleInfield => "MI",
             Position::CornerInfield => "CI",
             Position::GenericPitcher => "P",
+            Position::Infield => "IF",
+            Position::Minors => "NA",
         }
     }
 
@@ -122,6 +169,7 @@ impl Position {
                 | Position::Outfield
                 | Position::MiddleInfield
                 | Position::CornerInfield
+                | Position::Infield
         )
     }
 
@@ -129,17 +177,24 @@ impl Position {
     pub fn is_meta_slot(&self) -> bool {
         matches!(
             self,
-            Position::Utility | Position::Bench | Position::InjuredList
+            Position::Utility | Position::Bench | Position::InjuredList | Position::Minors
         )
     }
 
-    /// Whether this is a combo roster slot (OF, MI, CI, P).
+    /// Whether this is a reserve slot (IL or minors): outside the active
+    /// roster, so it reserves no budget and adds no demand for players.
+    pub fn is_reserve_slot(&self) -> bool {
+        matches!(self, Position::InjuredList | Position::Minors)
+    }
+
+    /// Whether this is a combo roster slot (OF, MI, CI, IF, P).
     pub fn is_combo_slot(&self) -> bool {
         matches!(
             self,
             Position::Outfield
                 | Position::MiddleInfield
                 | Position::CornerInfield
+                | Position::Infield
                 | Position::GenericPitcher
         )
     }
@@ -151,6 +206,7 @@ impl Position {
     /// - Outfield → [LF, CF, RF]
     /// - MiddleInfield → [2B, SS]
     /// - CornerInfield → [1B, 3B]
+    /// - Infield → [1B, 2B, 3B, SS]
     /// - GenericPitcher → [SP, RP]
     pub fn accepted_positions(&self) -> Vec<Position> {
         match self {
@@ -161,6 +217,12 @@ impl Position {
             ],
             Position::MiddleInfield => vec![Position::SecondBase, Position::ShortStop],
             Position::CornerInfield => vec![Position::FirstBase, Position::ThirdBase],
+            Position::Infield => vec![
+                Position::FirstBase,
+                Position::SecondBase,
+                Position::ThirdBase,
+                Position::ShortStop,
+            ],
             Position::GenericPitcher => vec![Position::StartingPitcher, Position::ReliefPitcher],
             other => vec![*other],
         }
@@ -172,6 +234,7 @@ impl Position {
     /// - "OF" → Outfield (not CenterField)
     /// - "MI" → MiddleInfield
     /// - "CI" → CornerInfield
+    /// - "IF" → Infield
     /// - "P"  → GenericPitcher
     ///
     /// All other strings delegate to `from_str_pos()`.
@@ -180,12 +243,15 @@ impl Position {
             "OF" => Some(Position::Outfield),
             "MI" => Some(Position::MiddleInfield),
             "CI" => Some(Position::CornerInfield),
+            "IF" => Some(Position::Infield),
             "P" => Some(Position::GenericPitcher),
             other => Self::from_str_pos(other),
         }
     }
 
     /// Deterministic ordering index for roster slot display.
+    ///
+    /// IF sorts after MI and CI so the narrower combo slots fill first.
     pub fn sort_order(&self) -> u8 {
         match self {
             Position::Catcher => 0,
@@ -195,17 +261,19 @@ impl Position {
             Position::CornerInfield => 4,
             Position::ShortStop => 5,
             Position::MiddleInfield => 6,
-            Position::LeftField => 7,
-            Position::CenterField => 8,
-            Position::RightField => 9,
-            Position::Outfield => 10,
-            Position::DesignatedHitter => 11,
-            Position::Utility => 12,
-            Position::StartingPitcher => 13,
-            Position::ReliefPitcher => 14,
-            Position::GenericPitcher => 15,
-            Position::Bench => 16,
-            Position::InjuredList => 17,
+            Position::Infield => 7,
+            Position::LeftField => 8,
+            Position::CenterField => 9,
+            Position::RightField => 10,
+            Position::Outfield => 11,
+            Position::DesignatedHitter => 12,
+            Position::Utility => 13,
+            Position::StartingPitcher => 14,
+            Position::ReliefPitcher => 15,
+            Position::GenericPitcher => 16,
+            Position::Bench => 17,
+            Position::InjuredList => 18,
+            Position::Minors => 19,
         }
     }
 }
@@ -221,7 +289,7 @@ impl fmt::Display for Position {
 // ---------------------------------------------------------------------------
 
 /// Map an ESPN slot ID to a Position enum value.
-/// Returns the combo variant for combo slots (OF, MI, CI, P).
+/// Returns the combo variant for combo slots (OF, MI, CI, IF, P).
 pub fn position_from_espn_slot(slot_id: u16) -> Option<Position> {
     match slot_id {
         ESPN_SLOT_C => Some(Position::Catcher),
@@ -242,6 +310,8 @@ pub fn position_from_espn_slot(slot_id: u16) -> Option<Position> {
         ESPN_SLOT_RP => Some(Position::ReliefPitcher),
         ESPN_SLOT_BE => Some(Position::Bench),
         ESPN_SLOT_IL => Some(Position::InjuredList),
+        ESPN_SLOT_NA => Some(Position::Minors),
+        ESPN_SLOT_IF => Some(Position::Infield),
         _ => None,
     }
 }
@@ -257,6 +327,7 @@ pub fn positions_from_espn_slot(slot_id: u16) -> Vec<Position> {
         ],
         ESPN_SLOT_MI => vec![Position::SecondBase, Position::ShortStop],
         ESPN_SLOT_CI => vec![Position::FirstBase, Position::ThirdBase],
+        ESPN_SLOT_IF => Position::Infield.accepted_positions(),
         ESPN_SLOT_P => vec![Position::StartingPitcher, Position::ReliefPitcher],
         other => position_from_espn_slot(other).into_iter().collect(),
     }
@@ -283,11 +354,29 @@ pub fn espn_slot_from_position(pos: Position) -> u16 {
         Position::MiddleInfield => ESPN_SLOT_MI,
         Position::CornerInfield => ESPN_SLOT_CI,
         Position::GenericPitcher => ESPN_SLOT_P,
+        Position::Infield => ESPN_SLOT_IF,
+        Position::Minors => ESPN_SLOT_NA,
     }
 }
 
+/// The tokens of a scraped position string: "SP, RP" yields "SP" and "RP".
+pub fn position_tokens(s: &str) -> impl Iterator<Item = &str> {
+    s.split(|c: char| c == '/' || c == ',' || c == ';' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+}
+
+/// Positions as a compact string for a pick or nomination, e.g. "1B/OF".
+/// The inverse of [`Position::parse_list`].
+pub fn positions_str(positions: &[Position]) -> String {
+    positions
+        .iter()
+        .map(|p| p.display_str())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 /// Extract all concrete playing positions from ESPN eligible slots,
-/// filtering out meta-slots (UTIL, BE, IL) and combo slots.
+/// filtering out meta-slots (UTIL, BE, IL, NA) and combo slots.
 pub fn playing_positions_from_slots(eligible_slots: &[u16]) -> Vec<Position> {
     eligible_slots
         .iter()
@@ -296,6 +385,67 @@ pub fn playing_positions_from_slots(eligible_slots: &[u16]) -> Vec<Position> {
         .collect()
 }
 
+/// Positions a player is actually eligible at, according to ESPN slots.
+///
+/// Only concrete slots count: ESPN lists a `P` slot alongside `SP` for a
+/// starter, and that does not make him RP-eligible. Combo slots are
+/// expanded only when no concrete slot is listed.
+pub fn eligible_positions_from_slots(eligible_slots: &[u16]) -> Vec<Position> {
+    let concrete: Vec<Position> = eligible_slots
+        .iter()
+        .filter_map(|&slot_id| position_from_espn_slot(slot_id))
+        .filter(|pos| !pos.is_meta_slot() && !pos.is_combo_slot())
+        .collect();
+    if concrete.is_empty() {
+        playing_positions_from_slots(eligible_slots)
+    } else {
+        concrete
+    }
+}
+
+/// The player's primary playing position according to ESPN eligible slots.
+///
+/// The first concrete (non-combo, non-meta) slot wins, so a reliever listed
+/// as `[P, RP]` is an RP. If only combo slots are present (e.g. `[MI]`),
+/// falls back to the first position they expand to.
+pub fn primary_position_from_slots(eligible_slots: &[u16]) -> Option<Position> {
+    eligible_positions_from_slots(eligible_slots).first().copied()
+}
+
+/// Resolve a player's position, preferring ESPN eligible slots over the
+/// scraped position string.
+///
+/// The scraped string is kept only when it agrees with the eligible slots
+/// (so "OF" on an LF/CF/RF player stays as-is); of a multi-position string
+/// like "1B/3B", the first position the slots agree with is kept. Otherwise
+/// the primary slot position wins. With no eligible slots, the string's
+/// primary position is used.
+pub fn resolve_position(position_str: &str, eligible_slots: &[u16]) -> Option<Position> {
+    let scraped = Position::parse_primary(position_str);
+    let eligible = eligible_positions_from_slots(eligible_slots);
+    if eligible.is_empty() {
+        return scraped;
+    }
+    Position::parse_list(position_str)
+        .into_iter()
+        .find(|pos| eligible.contains(pos))
+        .or_else(|| primary_position_from_slots(eligible_slots))
+        .or(scraped)
+}
+
+/// Position string for a pick or nomination after enrichment from ESPN
+/// eligible slots. Returns `position_str` unchanged when it is consistent
+/// with the slots (or no slots are known), otherwise the display string of
+/// the slot-derived position.
+pub fn enriched_position_str(position_str: &str, eligible_slots: &[u16]) -> String {
+    match resolve_position(position_str, eligible_slots) {
+        Some(pos) if !Position::parse_list(position_str).contains(&pos) => {
+            pos.display_str().to_string()
+        }
+        _ => position_str.to_string(),
+    }
+}
+
 /// Map a position string (e.g. "C", "1B", "SP", "BE", "OF", "MI") to the ESPN slot ID.
 ///
 /// This is the Rust equivalent of `espnSlotIdFromPositionStr()` in the
@@ -311,6 +461,21 @@ pub fn espn_slot_from_position_str(s: &str) -> Option<u16> {
 // DraftPick is defined in wyncast-core to allow db.rs (also in core) to use it.
 pub use wyncast_core::picks::DraftPick;
 
+/// Whether a recorded pick was a hitter (`Some(true)`) or a pitcher.
+///
+/// Bench, IL and minors picks fall back to the player's ESPN eligible slots.
+/// Returns `None` when the position string cannot be parsed.
+pub fn pick_is_hitter(pick: &DraftPick) -> Option<bool> {
+    match Position::parse_primary(&pick.position)? {
+        Position::Bench | Position::InjuredList | Position::Minors => Some(
+            playing_positions_from_slots(&pick.eligible_slots)
+                .iter()
+                .any(|p| p.is_hitter()),
+        ),
+        pos => Some(pos.is_hitter()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -352,6 +517,8 @@ mod tests {
         assert_eq!(Position::from_str_pos("BN"), Some(Position::Bench));
         assert_eq!(Position::from_str_pos("IL"), Some(Position::InjuredList));
         assert_eq!(Position::from_str_pos("DL"), Some(Position::InjuredList));
+        assert_eq!(Position::from_str_pos("NA"), Some(Position::Minors));
+        assert_eq!(Position::from_str_pos("taxi"), Some(Position::Minors));
     }
 
     #[test]
@@ -372,6 +539,63 @@ mod tests {
         assert_eq!(Position::from_str_pos("4B"), None);
     }
 
+    #[test]
+    fn parse_list_reads_scraped_position_strings() {
+        use Position::*;
+        // Strings as they come off the ESPN draft board and player cards.
+        let samples: [(&str, &[Position]); 12] = [
+            ("1B/3B", &[FirstBase, ThirdBase]),
+            ("SP, RP", &[StartingPitcher, ReliefPitcher]),
+            ("DH", &[DesignatedHitter]),
+            ("SS, 2B, OF", &[ShortStop, SecondBase, CenterField]),
+            ("C/1B/DH", &[Catcher, FirstBase, DesignatedHitter]),
+            ("2B,SS", &[SecondBase, ShortStop]),
+            ("LF/CF/RF", &[LeftField, CenterField, RightField]),
+            ("OF/CF", &[CenterField]),
+            ("RP/P", &[ReliefPitcher, GenericPitcher]),
+            ("UT", &[Utility]),
+            (" sp ", &[StartingPitcher]),
+            ("1B / DH ; IR", &[FirstBase, DesignatedHitter, InjuredList]),
+        ];
+        for (s, expected) in samples {
+            assert_eq!(Position::parse_list(s), expected, "{s:?}");
+        }
+        assert_eq!(Position::parse_list("MI, CI, IF"), [MiddleInfield, CornerInfield, Infield]);
+        assert!(Position::parse_list("").is_empty());
+        assert!(Position::parse_list("--").is_empty());
+        assert_eq!(Position::parse_list("XX/2B"), [SecondBase]);
+    }
+
+    #[test]
+    fn parse_primary_prefers_a_playing_position() {
+        assert_eq!(Position::parse_primary("1B/3B"), Some(Position::FirstBase));
+        assert_eq!(Position::parse_primary("UTIL/SS"), Some(Position::ShortStop));
+        assert_eq!(Position::parse_primary("BE"), Some(Position::Bench));
+        assert_eq!(Position::parse_primary("IL, NA"), Some(Position::InjuredList));
+        assert_eq!(Position::parse_primary("??"), None);
+        assert_eq!(positions_str(&Position::parse_list("SS, 2B")), "SS/2B");
+    }
+
+    #[test]
+    fn multi_position_strings_resolve_against_slots() {
+        assert_eq!(resolve_position("1B/3B", &[ESPN_SLOT_3B, ESPN_SLOT_CI]), Some(Position::ThirdBase));
+        assert_eq!(resolve_position("SP, RP", &[]), Some(Position::StartingPitcher));
+        assert_eq!(enriched_position_str("1B/3B", &[ESPN_SLOT_3B]), "1B/3B");
+        assert_eq!(enriched_position_str("SS/2B", &[ESPN_SLOT_C]), "C");
+        let pick = DraftPick {
+            pick_number: 1,
+            team_id: "1".into(),
+            team_name: "Team 1".into(),
+            player_name: "Swingman".into(),
+            position: "SP, RP".into(),
+            price: 5,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+        assert_eq!(pick_is_hitter(&pick), Some(false));
+    }
+
     #[test]
     fn display_str_roundtrip() {
         // Non-combo positions roundtrip through from_str_pos
@@ -405,6 +629,7 @@ mod tests {
             Position::Outfield,
             Position::MiddleInfield,
             Position::CornerInfield,
+            Position::Infield,
             Position::GenericPitcher,
         ];
         for pos in combo_positions {
@@ -434,6 +659,7 @@ mod tests {
         assert!(Position::Outfield.is_hitter());
         assert!(Position::MiddleInfield.is_hitter());
         assert!(Position::CornerInfield.is_hitter());
+        assert!(Position::Infield.is_hitter());
         assert!(!Position::StartingPitcher.is_hitter());
         assert!(!Position::ReliefPitcher.is_hitter());
         assert!(!Position::GenericPitcher.is_hitter());
@@ -469,6 +695,26 @@ mod tests {
         assert!(pick.eligible_slots.is_empty());
     }
 
+    #[test]
+    fn pick_is_hitter_uses_slots_for_bench_picks() {
+        let pick = |position: &str, eligible_slots: Vec<u16>| DraftPick {
+            pick_number: 1,
+            team_id: "team_1".to_string(),
+            team_name: "My Team".to_string(),
+            player_name: "Someone".to_string(),
+            position: position.to_string(),
+            price: 5,
+            espn_player_id: None,
+            eligible_slots,
+            assigned_slot: None,
+        };
+        assert_eq!(pick_is_hitter(&pick("SS", vec![])), Some(true));
+        assert_eq!(pick_is_hitter(&pick("SP", vec![])), Some(false));
+        assert_eq!(pick_is_hitter(&pick("BE", vec![ESPN_SLOT_SS])), Some(true));
+        assert_eq!(pick_is_hitter(&pick("BE", vec![ESPN_SLOT_SP])), Some(false));
+        assert_eq!(pick_is_hitter(&pick("??", vec![])), None);
+    }
+
     // -- ESPN slot ID mapping tests --
 
     #[test]
@@ -722,6 +968,17 @@ mod tests {
         assert!(Position::Utility.is_meta_slot());
         assert!(Position::Bench.is_meta_slot());
         assert!(Position::InjuredList.is_meta_slot());
+        assert!(Position::Minors.is_meta_slot());
+    }
+
+    #[test]
+    fn reserve_slots_are_il_and_minors() {
+        assert!(Position::InjuredList.is_reserve_slot());
+        assert!(Position::Minors.is_reserve_slot());
+        assert!(!Position::Bench.is_reserve_slot());
+        assert!(!Position::Minors.is_hitter());
+        assert_eq!(position_from_espn_slot(ESPN_SLOT_NA), Some(Position::Minors));
+        assert_eq!(espn_slot_from_position_str("NA"), Some(ESPN_SLOT_NA));
     }
 
     #[test]
@@ -883,6 +1140,126 @@ mod tests {
         assert_eq!(espn_slot_from_position_str("OF"), Some(ESPN_SLOT_OF));
         assert_eq!(espn_slot_from_position_str("MI"), Some(ESPN_SLOT_MI));
         assert_eq!(espn_slot_from_position_str("CI"), Some(ESPN_SLOT_CI));
+        assert_eq!(espn_slot_from_position_str("IF"), Some(ESPN_SLOT_IF));
         assert_eq!(espn_slot_from_position_str("P"), Some(ESPN_SLOT_P));
     }
+
+    #[test]
+    fn infield_slot_accepts_all_four_infield_positions() {
+        assert_eq!(
+            position_from_espn_slot(ESPN_SLOT_IF),
+            Some(Position::Infield)
+        );
+        assert_eq!(
+            positions_from_espn_slot(ESPN_SLOT_IF),
+            vec![
+                Position::FirstBase,
+                Position::SecondBase,
+                Position::ThirdBase,
+                Position::ShortStop,
+            ]
+        );
+        assert!(Position::Infield.is_combo_slot());
+        assert!(Position::Infield.sort_order() > Position::MiddleInfield.sort_order());
+        assert!(Position::Infield.sort_order() > Position::CornerInfield.sort_order());
+    }
+
+    #[test]
+    fn every_known_slot_maps_to_a_position() {
+        for slot_id in ESPN_SLOT_C..=ESPN_SLOT_IL {
+            let pos = position_from_espn_slot(slot_id)
+                .unwrap_or_else(|| panic!("slot {slot_id} has no position"));
+            assert_eq!(espn_slot_from_position(pos), slot_id);
+            assert!(!positions_from_espn_slot(slot_id).is_empty());
+        }
+    }
+
+    #[test]
+    fn eligible_positions_use_concrete_slots_only() {
+        assert_eq!(
+            eligible_positions_from_slots(&[ESPN_SLOT_P, ESPN_SLOT_SP, ESPN_SLOT_BE]),
+            vec![Position::StartingPitcher]
+        );
+        assert_eq!(
+            eligible_positions_from_slots(&[ESPN_SLOT_OF, ESPN_SLOT_UTIL]),
+            vec![Position::LeftField, Position::CenterField, Position::RightField]
+        );
+        assert!(eligible_positions_from_slots(&[ESPN_SLOT_UTIL, ESPN_SLOT_IL]).is_empty());
+    }
+
+    #[test]
+    fn primary_position_skips_combo_and_meta_slots() {
+        // RP listed as P/RP/BE/IL: the generic P slot must not win.
+        assert_eq!(
+            primary_position_from_slots(&[ESPN_SLOT_P, ESPN_SLOT_RP, ESPN_SLOT_BE, ESPN_SLOT_IL]),
+            Some(Position::ReliefPitcher)
+        );
+        // SS/2B/MI/UTIL/BE/IL
+        assert_eq!(
+            primary_position_from_slots(&[4, 2, 6, 12, 16, 17]),
+            Some(Position::ShortStop)
+        );
+        assert_eq!(
+            primary_position_from_slots(&[ESPN_SLOT_OF, ESPN_SLOT_LF, ESPN_SLOT_CF]),
+            Some(Position::LeftField)
+        );
+    }
+
+    #[test]
+    fn primary_position_falls_back_to_combo_expansion() {
+        assert_eq!(
+            primary_position_from_slots(&[ESPN_SLOT_MI, ESPN_SLOT_UTIL]),
+            Some(Position::SecondBase)
+        );
+        assert_eq!(primary_position_from_slots(&[ESPN_SLOT_UTIL, ESPN_SLOT_BE]), None);
+        assert_eq!(primary_position_from_slots(&[]), None);
+    }
+
+    #[test]
+    fn resolve_position_prefers_slots_over_bad_string() {
+        // Scraped "UTIL" for a first baseman
+        assert_eq!(
+            resolve_position("UTIL", &[ESPN_SLOT_1B, ESPN_SLOT_CI, ESPN_SLOT_UTIL]),
+            Some(Position::FirstBase)
+        );
+        // Scraped string that disagrees with eligibility
+        assert_eq!(
+            resolve_position("SP", &[ESPN_SLOT_P, ESPN_SLOT_RP]),
+            Some(Position::ReliefPitcher)
+        );
+        // Unparseable string
+        assert_eq!(
+            resolve_position("", &[ESPN_SLOT_C, ESPN_SLOT_UTIL]),
+            Some(Position::Catcher)
+        );
+    }
+
+    #[test]
+    fn resolve_position_keeps_consistent_string() {
+        // 2B/SS player scraped as "2B" keeps 2B even though SS is listed first.
+        assert_eq!(
+            resolve_position("2B", &[ESPN_SLOT_SS, ESPN_SLOT_2B, ESPN_SLOT_MI]),
+            Some(Position::SecondBase)
+        );
+        // "OF" parses to CF, which the OF slot covers.
+        assert_eq!(
+            resolve_position("OF", &[ESPN_SLOT_OF, ESPN_SLOT_LF, ESPN_SLOT_CF, ESPN_SLOT_RF]),
+            Some(Position::CenterField)
+        );
+    }
+
+    #[test]
+    fn resolve_position_without_slots_parses_string() {
+        assert_eq!(resolve_position("SS", &[]), Some(Position::ShortStop));
+        assert_eq!(resolve_position("??", &[]), None);
+    }
+
+    #[test]
+    fn enriched_position_str_rewrites_only_inconsistent_strings() {
+        assert_eq!(enriched_position_str("OF", &[ESPN_SLOT_OF, ESPN_SLOT_CF]), "OF");
+        assert_eq!(enriched_position_str("UTIL", &[ESPN_SLOT_1B, ESPN_SLOT_UTIL]), "1B");
+        assert_eq!(enriched_position_str("", &[ESPN_SLOT_P, ESPN_SLOT_SP]), "SP");
+        assert_eq!(enriched_position_str("DH", &[]), "DH");
+        assert_eq!(enriched_position_str("BE", &[ESPN_SLOT_UTIL, ESPN_SLOT_BE]), "BE");
+    }
 }
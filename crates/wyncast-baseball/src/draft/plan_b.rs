@@ -0,0 +1,134 @@
+// Plan B: the substitutes for a player I was outbid on.
+//
+// When a player I said I was in on goes to another team, the best players
+// still available at their position are the ones to turn to. Each is tiered
+// against the lost player (a new tier starts at a `TIER_BREAK` drop, as in
+// the position tiers), so a same-tier substitute is a like-for-like swap and
+// anything lower is a step down.
+
+use serde::{Deserialize, Serialize};
+
+use super::pick::Position;
+use crate::valuation::cliff::cliff_position;
+use crate::valuation::scarcity::player_eligible_at;
+use crate::valuation::tiers::tiers;
+use crate::valuation::zscore::PlayerValuation;
+
+/// Substitutes listed for a lost player.
+pub const SUBSTITUTES: usize = 3;
+
+/// A player I was in on who went to another team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutbidPlayer {
+    pub player_name: String,
+    pub position: Option<Position>,
+    /// The model's value of the player when they were sold.
+    pub dollar_value: f64,
+    /// The team that bought them.
+    pub winner: String,
+    pub price: u32,
+    /// My bid when I was last in, if I logged one.
+    pub my_bid: Option<u32>,
+}
+
+/// An available player who could stand in for the lost one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Substitute {
+    pub name: String,
+    pub dollar_value: f64,
+    /// Tiers below the lost player; 0 for the same tier.
+    pub tiers_down: usize,
+}
+
+/// The latest player I was outbid on and their best substitutes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanB {
+    pub lost: OutbidPlayer,
+    /// Most valuable first; empty when nobody is left at the position.
+    pub substitutes: Vec<Substitute>,
+}
+
+impl OutbidPlayer {
+    /// Record `player` going to `winner` for `price`.
+    pub fn new(player: &PlayerValuation, winner: &str, price: u32, my_bid: Option<u32>) -> Self {
+        Self {
+            player_name: player.name.clone(),
+            position: cliff_position(player),
+            dollar_value: player.dollar_value,
+            winner: winner.to_string(),
+            price,
+            my_bid,
+        }
+    }
+}
+
+/// The best available substitutes for `lost` at their position.
+pub fn plan_b(lost: &OutbidPlayer, available: &[PlayerValuation]) -> PlanB {
+    let mut candidates: Vec<&PlayerValuation> = match lost.position {
+        Some(position) => available
+            .iter()
+            .filter(|p| p.name != lost.player_name && player_eligible_at(p, position))
+            .collect(),
+        None => Vec::new(),
+    };
+    candidates.sort_by(|a, b| b.dollar_value.total_cmp(&a.dollar_value));
+    candidates.truncate(SUBSTITUTES);
+
+    // Tier the substitutes on a ladder headed by the lost player, clamped so
+    // a substitute worth more than them counts as the same tier.
+    let mut ladder = vec![lost.dollar_value];
+    ladder.extend(candidates.iter().map(|p| p.dollar_value.min(lost.dollar_value)));
+    let tiers = tiers(&ladder);
+
+    let substitutes = candidates
+        .into_iter()
+        .zip(&tiers[1..])
+        .map(|(p, tier)| Substitute {
+            name: p.name.clone(),
+            dollar_value: p.dollar_value,
+            tiers_down: tier - tiers[0],
+        })
+        .collect();
+    PlanB { lost: lost.clone(), substitutes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestPlayer;
+
+    fn shortstop(name: &str, dollar_value: f64) -> PlayerValuation {
+        TestPlayer::hitter(name)
+            .positions(vec![Position::ShortStop])
+            .dollar(dollar_value)
+            .build()
+    }
+
+    #[test]
+    fn substitutes_are_the_best_left_at_the_position_by_tier() {
+        let lost = OutbidPlayer::new(&shortstop("Star", 30.0), "Team 2", 34, Some(31));
+        assert_eq!(lost.position, Some(Position::ShortStop));
+        let available = vec![
+            shortstop("Close", 28.5),
+            shortstop("Drop", 24.0),
+            TestPlayer::hitter("Catcher").positions(vec![Position::Catcher]).dollar(29.0).build(),
+            shortstop("Next", 27.0),
+            shortstop("Fifth", 10.0),
+        ];
+        let plan = plan_b(&lost, &available);
+        let subs: Vec<(&str, usize)> =
+            plan.substitutes.iter().map(|s| (s.name.as_str(), s.tiers_down)).collect();
+        assert_eq!(subs, [("Close", 0), ("Next", 0), ("Drop", 1)]);
+    }
+
+    #[test]
+    fn better_substitutes_are_the_same_tier_and_none_without_a_position() {
+        let lost = OutbidPlayer::new(&shortstop("Star", 20.0), "Team 2", 22, None);
+        let plan = plan_b(&lost, &[shortstop("Better", 35.0)]);
+        assert_eq!(plan.substitutes[0].tiers_down, 0);
+
+        let mut nowhere = lost.clone();
+        nowhere.position = None;
+        assert!(plan_b(&nowhere, &[shortstop("Better", 35.0)]).substitutes.is_empty());
+    }
+}
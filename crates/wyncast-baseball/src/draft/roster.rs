@@ -4,7 +4,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use super::pick::{position_from_espn_slot, positions_from_espn_slot, Position};
+use super::pick::{position_from_espn_slot, positions_from_espn_slot, resolve_position, Position, ESPN_SLOT_NA};
 
 /// A player assigned to a roster slot.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,7 +39,7 @@ pub struct Roster {
 /// Returns true if:
 /// - The slot position matches the player position exactly, OR
 /// - The slot is a combo slot whose `accepted_positions()` contains the player position.
-fn slot_accepts(slot_pos: Position, player_pos: Position) -> bool {
+pub(crate) fn slot_accepts(slot_pos: Position, player_pos: Position) -> bool {
     if slot_pos == player_pos {
         return true;
     }
@@ -49,11 +49,31 @@ fn slot_accepts(slot_pos: Position, player_pos: Position) -> bool {
     false
 }
 
+/// The reserve slots a player may fall back to, in order: the bench, then
+/// a minors slot for a prospect only.
+fn reserve_slots(prospect: bool) -> &'static [Position] {
+    if prospect {
+        &[Position::Bench, Position::Minors]
+    } else {
+        &[Position::Bench]
+    }
+}
+
+/// The outfield positions, whose players fill each other's slots.
+const OUTFIELD: [Position; 3] = [Position::LeftField, Position::CenterField, Position::RightField];
+
+/// Budget held back for the other open slots while bidding on one of
+/// `empty_slots`: `min_bid` for each slot that still needs filling after
+/// this one.
+pub fn reserve(empty_slots: usize, min_bid: u32) -> u32 {
+    empty_slots.saturating_sub(1) as u32 * min_bid
+}
+
 impl Roster {
     /// Create a new roster from a config mapping position strings to slot counts.
     ///
-    /// The roster config may contain combo slot keys like "OF", "MI", "CI", "P"
-    /// which create combo-slot positions that accept multiple concrete positions.
+    /// The roster config may contain combo slot keys like "OF", "MI", "CI", "IF",
+    /// "P" which create combo-slot positions that accept multiple concrete positions.
     ///
     /// Slots are created in deterministic order based on `Position::sort_order()`.
     pub fn new(roster_config: &HashMap<String, usize>) -> Self {
@@ -89,6 +109,8 @@ impl Roster {
     /// 1. Dedicated position slot (exact match)
     /// 2. UTIL slot (for hitters only)
     /// 3. Bench (BE) slot
+    /// 4. Minors (NA) slot, only for a prospect: one whose position string
+    ///    lists NA
     ///
     /// Returns `true` if the player was successfully placed, `false` if no slot available.
     pub fn add_player(
@@ -98,10 +120,18 @@ impl Roster {
         price: u32,
         espn_player_id: Option<&str>,
     ) -> bool {
-        let pos = match Position::from_str_pos(position_str) {
+        // A multi-position string ("1B/3B") is placed by its primary
+        // position, but any position it lists can take a dedicated slot.
+        let listed = Position::parse_list(position_str);
+        let pos = match Position::parse_primary(position_str) {
             Some(p) => p,
             None => return false,
         };
+        let prospect = listed.contains(&Position::Minors);
+        let mut playing: Vec<Position> = listed.into_iter().filter(|p| !p.is_meta_slot()).collect();
+        if playing.is_empty() {
+            playing.push(pos);
+        }
 
         let player = RosteredPlayer {
             name: name.to_string(),
@@ -111,47 +141,50 @@ impl Roster {
             espn_player_id: espn_player_id.map(|s| s.to_string()),
         };
 
-        // 1. Try dedicated position slot
-        if let Some(slot) = self
-            .slots
-            .iter_mut()
-            .find(|s| s.position == pos && s.player.is_none())
-        {
-            slot.player = Some(player);
-            return true;
-        }
+        for &pos in &playing {
+            // 1. Try dedicated position slot
+            if let Some(slot) = self
+                .slots
+                .iter_mut()
+                .find(|s| s.position == pos && s.player.is_none())
+            {
+                slot.player = Some(player);
+                return true;
+            }
 
-        // For outfielders (LF/CF/RF), also try the other outfield slots
-        if matches!(
-            pos,
-            Position::LeftField | Position::CenterField | Position::RightField
-        ) {
-            let of_positions = [
-                Position::LeftField,
-                Position::CenterField,
-                Position::RightField,
-            ];
-            for &of_pos in &of_positions {
-                if of_pos == pos {
-                    continue; // Already tried exact match
-                }
-                if let Some(slot) = self
-                    .slots
-                    .iter_mut()
-                    .find(|s| s.position == of_pos && s.player.is_none())
-                {
-                    slot.player = Some(player);
-                    return true;
+            // For outfielders (LF/CF/RF), also try the other outfield slots
+            if matches!(
+                pos,
+                Position::LeftField | Position::CenterField | Position::RightField
+            ) {
+                let of_positions = [
+                    Position::LeftField,
+                    Position::CenterField,
+                    Position::RightField,
+                ];
+                for &of_pos in &of_positions {
+                    if of_pos == pos {
+                        continue; // Already tried exact match
+                    }
+                    if let Some(slot) = self
+                        .slots
+                        .iter_mut()
+                        .find(|s| s.position == of_pos && s.player.is_none())
+                    {
+                        slot.player = Some(player);
+                        return true;
+                    }
                 }
             }
         }
 
-        // 2b. Try combo slot that accepts this position (OF for outfielders, MI for 2B/SS, etc.)
-        if let Some(slot) = self
-            .slots
-            .iter_mut()
-            .find(|s| s.position.is_combo_slot() && slot_accepts(s.position, pos) && s.player.is_none())
-        {
+        // 2b. Try combo slot that accepts a listed position (OF for outfielders, MI for 2B/SS, etc.).
+        //     Slots are in sort order, so MI/CI fill before the wider IF slot.
+        if let Some(slot) = self.slots.iter_mut().find(|s| {
+            s.position.is_combo_slot()
+                && s.player.is_none()
+                && playing.iter().any(|&pos| slot_accepts(s.position, pos))
+        }) {
             slot.player = Some(player);
             return true;
         }
@@ -168,14 +201,16 @@ impl Roster {
             }
         }
 
-        // 3. Try bench slot
-        if let Some(slot) = self
-            .slots
-            .iter_mut()
-            .find(|s| s.position == Position::Bench && s.player.is_none())
-        {
-            slot.player = Some(player);
-            return true;
+        // 3. Try bench slot, then a minors slot
+        for &reserve in reserve_slots(prospect) {
+            if let Some(slot) = self
+                .slots
+                .iter_mut()
+                .find(|s| s.position == reserve && s.player.is_none())
+            {
+                slot.player = Some(player);
+                return true;
+            }
         }
 
         false
@@ -194,7 +229,8 @@ impl Roster {
     ///    Falls through to the ordered priority if the slot is already full.
     /// 1. Try each eligible position slot (mapped from ESPN slot IDs, in slot order)
     /// 2. Try UTIL slot (for hitters)
-    /// 3. Try bench slot
+    /// 3. Try bench slot, then a minors slot if the player is a prospect
+    ///    (ESPN lists or assigned NA)
     ///
     /// Falls back to single-position `add_player()` if both `eligible_slots`
     /// is empty and `assigned_slot` is None.
@@ -212,12 +248,14 @@ impl Roster {
             return self.add_player(name, position_str, price, espn_player_id);
         }
 
-        let parsed_pos = Position::from_str_pos(position_str);
-        let display_pos = parsed_pos.unwrap_or(Position::Bench);
-        let is_hitter = match parsed_pos {
-            Some(pos) => pos.is_hitter(),
-            None => {
-                // Unknown position string — derive from eligible_slots
+        // Eligible slots are authoritative; the scraped string only survives
+        // when it agrees with them.
+        let resolved_pos = resolve_position(position_str, eligible_slots);
+        let display_pos = resolved_pos.unwrap_or(Position::Bench);
+        let is_hitter = match resolved_pos {
+            Some(pos) if !matches!(pos, Position::Bench | Position::InjuredList | Position::Minors) => pos.is_hitter(),
+            _ => {
+                // Unknown position string or BE/IL/NA — derive from eligible_slots
                 eligible_slots.iter().any(|&slot_id| {
                     positions_from_espn_slot(slot_id)
                         .iter()
@@ -226,6 +264,7 @@ impl Roster {
             }
         };
 
+        let prospect = eligible_slots.contains(&ESPN_SLOT_NA) || assigned_slot == Some(ESPN_SLOT_NA);
         let player = RosteredPlayer {
             name: name.to_string(),
             price,
@@ -283,14 +322,16 @@ impl Roster {
             }
         }
 
-        // 3. Try bench slot
-        if let Some(slot) = self
-            .slots
-            .iter_mut()
-            .find(|s| s.position == Position::Bench && s.player.is_none())
-        {
-            slot.player = Some(player);
-            return true;
+        // 3. Try bench slot, then a minors slot
+        for &reserve in reserve_slots(prospect) {
+            if let Some(slot) = self
+                .slots
+                .iter_mut()
+                .find(|s| s.position == reserve && s.player.is_none())
+            {
+                slot.player = Some(player);
+                return true;
+            }
         }
 
         false
@@ -298,7 +339,8 @@ impl Roster {
 
     /// Whether there is an empty slot for any of the given ESPN eligible slots.
     ///
-    /// Checks eligible position slots (including combo slots), UTIL (if hitter), and bench.
+    /// Checks eligible position slots (including combo slots), UTIL (if
+    /// hitter), bench, and minors if NA is among the eligible slots.
     pub fn has_empty_slot_for_slots(&self, eligible_slots: &[u16], is_hitter: bool) -> bool {
         // Check each eligible position slot
         for &slot_id in eligible_slots {
@@ -322,29 +364,43 @@ impl Roster {
             return true;
         }
 
-        // Check bench
-        self.has_empty_slot(Position::Bench)
+        // Check bench, and minors for a prospect
+        let prospect = eligible_slots.contains(&ESPN_SLOT_NA);
+        reserve_slots(prospect).iter().any(|&reserve| self.has_empty_slot(reserve))
     }
 
-    /// Count of empty slots, excluding IL slots.
+    /// Count of empty active slots: IL and minors slots need no player and
+    /// reserve no budget.
     pub fn empty_slots(&self) -> usize {
         self.slots
             .iter()
-            .filter(|s| s.position != Position::InjuredList && s.player.is_none())
+            .filter(|s| !s.position.is_reserve_slot() && s.player.is_none())
             .count()
     }
 
     /// Maximum bid a team can make given their remaining budget.
     ///
-    /// Must reserve $1 per empty slot (excluding the slot about to be filled).
-    pub fn max_bid(&self, budget_remaining: u32) -> u32 {
+    /// Must reserve `min_bid` per empty slot (excluding the slot about to be
+    /// filled), so nothing is reserved in leagues with $0 bids.
+    pub fn max_bid(&self, budget_remaining: u32, min_bid: u32) -> u32 {
         let remaining_empty = self.empty_slots();
         if remaining_empty == 0 {
             return 0;
         }
-        // Reserve $1 for each remaining slot that still needs to be filled after this one
-        let reserved = (remaining_empty - 1) as u32;
-        budget_remaining.saturating_sub(reserved)
+        budget_remaining.saturating_sub(reserve(remaining_empty, min_bid))
+    }
+
+    /// How many players at `price` or more the team can still buy while
+    /// filling every other open slot at `min_bid`.
+    pub fn purchases_at(&self, budget_remaining: u32, price: u32, min_bid: u32) -> usize {
+        let empty = self.empty_slots();
+        let Some(spare) = budget_remaining.checked_sub(empty as u32 * min_bid) else {
+            return 0;
+        };
+        if price <= min_bid {
+            return empty;
+        }
+        (spare / (price - min_bid)).min(empty as u32) as usize
     }
 
     /// Whether a player is already on this roster.
@@ -376,13 +432,71 @@ impl Roster {
         self.slots.len()
     }
 
-    /// Total number of draftable slots (excluding IL).
+    /// Total number of draftable slots (excluding IL; minors slots are
+    /// drafted into).
     pub fn draftable_count(&self) -> usize {
         self.slots
             .iter()
             .filter(|s| s.position != Position::InjuredList)
             .count()
     }
+
+    /// Index of the slot holding the named player.
+    pub fn slot_of(&self, name: &str) -> Option<usize> {
+        self.slots
+            .iter()
+            .position(|s| s.player.as_ref().is_some_and(|p| p.name == name))
+    }
+
+    /// Move the player in slot `from` to the empty slot `to`.
+    ///
+    /// Returns `false`, changing nothing, if `from` is empty, `to` is taken,
+    /// or the player can't play the position of `to`.
+    pub fn move_player(&mut self, from: usize, to: usize) -> bool {
+        let fits = match (self.slots.get(from), self.slots.get(to)) {
+            (Some(source), Some(target)) if target.player.is_none() => {
+                source.player.as_ref().is_some_and(|p| target.fits(p))
+            }
+            _ => false,
+        };
+        if fits {
+            self.slots[to].player = self.slots[from].player.take();
+        }
+        fits
+    }
+}
+
+impl RosterSlot {
+    /// Whether this slot can hold `player`: a slot of one of their positions
+    /// (or a combo slot taking one), UTIL for hitters, or a reserve slot.
+    pub fn fits(&self, player: &RosteredPlayer) -> bool {
+        let mut positions: Vec<Position> = player
+            .eligible_slots
+            .iter()
+            .flat_map(|&id| positions_from_espn_slot(id))
+            .filter(|p| !p.is_meta_slot())
+            .collect();
+        if !player.position.is_meta_slot() {
+            positions.push(player.position);
+        }
+        match self.position {
+            Position::Bench | Position::Minors => true,
+            Position::InjuredList => false,
+            Position::Utility => positions.iter().any(|p| p.is_hitter()),
+            // Outfielders play any outfield slot, as in `add_player`.
+            slot if OUTFIELD.contains(&slot) => positions.iter().any(|p| OUTFIELD.contains(p)),
+            slot => positions.iter().any(|&p| slot_accepts(slot, p)),
+        }
+    }
+}
+
+/// The next empty slot after `from`, wrapping around, that can hold the
+/// player in slot `from`.
+pub fn next_open_slot(slots: &[RosterSlot], from: usize) -> Option<usize> {
+    let player = slots.get(from)?.player.as_ref()?;
+    (1..slots.len())
+        .map(|step| (from + step) % slots.len())
+        .find(|&i| slots[i].player.is_none() && slots[i].fits(player))
 }
 
 #[cfg(test)]
@@ -454,6 +568,22 @@ mod tests {
         assert_eq!(cf_slot.player.as_ref().unwrap().name, "Mike Trout");
     }
 
+    #[test]
+    fn add_player_multi_position_string() {
+        let mut roster = Roster::new(&test_roster_config());
+        assert!(roster.add_player("Incumbent 1B", "1B", 10, None));
+        // "1B/3B" takes the open 3B slot rather than UTIL.
+        assert!(roster.add_player("Corner Guy", "1B/3B", 20, None));
+        let slot = roster.slots.iter().find(|s| s.position == Position::ThirdBase).unwrap();
+        assert_eq!(slot.player.as_ref().unwrap().name, "Corner Guy");
+        assert_eq!(slot.player.as_ref().unwrap().position, Position::FirstBase);
+
+        assert!(roster.add_player("Swingman", "SP, RP", 5, None));
+        let slot = roster.slots.iter().find(|s| s.player.as_ref().is_some_and(|p| p.name == "Swingman")).unwrap();
+        assert_eq!(slot.position, Position::StartingPitcher);
+        assert!(!roster.add_player("Nobody", "??", 1, None));
+    }
+
     #[test]
     fn add_player_util_fallback() {
         let mut roster = Roster::new(&test_roster_config());
@@ -577,7 +707,16 @@ mod tests {
         let roster = Roster::new(&test_roster_config());
         // 26 draftable slots, all empty. Budget = 260.
         // Max bid = 260 - (26-1) = 235
-        assert_eq!(roster.max_bid(260), 235);
+        assert_eq!(roster.max_bid(260, 1), 235);
+    }
+
+    #[test]
+    fn max_bid_reserves_nothing_with_zero_dollar_bids() {
+        let roster = Roster::new(&test_roster_config());
+        assert_eq!(roster.max_bid(260, 0), 260);
+        assert_eq!(reserve(26, 0), 0);
+        assert_eq!(reserve(26, 1), 25);
+        assert_eq!(reserve(0, 1), 0);
     }
 
     #[test]
@@ -600,7 +739,19 @@ mod tests {
         // Now only 1 empty BE slot left
         assert_eq!(roster.empty_slots(), 1);
         // Max bid with 10 remaining = 10 (no reservation needed, last slot)
-        assert_eq!(roster.max_bid(10), 10);
+        assert_eq!(roster.max_bid(10, 1), 10);
+    }
+
+    #[test]
+    fn purchases_at_counts_big_buys_the_budget_allows() {
+        let roster = Roster::new(&test_roster_config());
+        // 26 open slots at $1 leave $234 to spread: $9 over the minimum buys
+        // 26 $10 players, $19 over buys 12 $20 players.
+        assert_eq!(roster.purchases_at(260, 10, 1), 26);
+        assert_eq!(roster.purchases_at(260, 20, 1), 12);
+        assert_eq!(roster.purchases_at(44, 20, 1), 0);
+        assert_eq!(roster.purchases_at(20, 10, 1), 0);
+        assert_eq!(roster.purchases_at(260, 20, 0), 13);
     }
 
     #[test]
@@ -735,7 +886,7 @@ mod tests {
         let mut roster = Roster::new(&config);
         roster.add_player("Player 1", "C", 5, None);
         assert_eq!(roster.empty_slots(), 0);
-        assert_eq!(roster.max_bid(250), 0);
+        assert_eq!(roster.max_bid(250, 1), 0);
     }
 
     // -- Combo/generic slot expansion tests --
@@ -908,6 +1059,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eligible_slots_override_inconsistent_position_string() {
+        let mut roster = Roster::new(&test_roster_config());
+        // Fill all SP and RP slots so placement falls through to UTIL/bench.
+        for i in 0..5 {
+            roster.add_player(&format!("SP {}", i), "SP", 10, None);
+        }
+        for i in 0..6 {
+            roster.add_player(&format!("RP {}", i), "RP", 5, None);
+        }
+        // Scraped "DH" is wrong: ESPN says this is a reliever.
+        assert!(roster.add_player_with_slots(
+            "Mislabeled Reliever",
+            "DH",
+            3,
+            &[13, 15, 16, 17], // P, RP, BE, IL
+            None,
+            None
+        ));
+        let util = roster
+            .slots
+            .iter()
+            .find(|s| s.position == Position::Utility)
+            .unwrap();
+        assert!(util.player.is_none(), "pitcher must not take the UTIL slot");
+        let placed = roster
+            .slots
+            .iter()
+            .filter_map(|s| s.player.as_ref())
+            .find(|p| p.name == "Mislabeled Reliever")
+            .unwrap();
+        assert_eq!(placed.position, Position::ReliefPitcher);
+    }
+
     // -- assigned_slot (ESPN source-of-truth placement) tests --
 
     #[test]
@@ -1011,6 +1196,36 @@ mod tests {
         assert_eq!(sp_slot.player.as_ref().unwrap().name, "SP Player");
     }
 
+    #[test]
+    fn minors_slots_take_prospects_but_reserve_nothing() {
+        use super::super::pick::{ESPN_SLOT_NA, ESPN_SLOT_SS};
+
+        let mut config = HashMap::new();
+        config.insert("SS".to_string(), 1);
+        config.insert("BE".to_string(), 1);
+        config.insert("NA".to_string(), 2);
+        let mut roster = Roster::new(&config);
+        assert_eq!(roster.draftable_count(), 4);
+        assert_eq!(roster.empty_slots(), 2);
+        assert_eq!(roster.max_bid(50, 1), 49);
+
+        // ESPN put the prospect in NA.
+        assert!(roster.add_player_with_slots("Prospect", "SS", 1, &[ESPN_SLOT_SS], Some(ESPN_SLOT_NA), None));
+        let minors = roster.slots.iter().find(|s| s.position == Position::Minors).unwrap();
+        assert_eq!(minors.player.as_ref().unwrap().name, "Prospect");
+        assert_eq!(roster.empty_slots(), 2);
+
+        // With the active slots full, only a prospect still lands in minors.
+        assert!(roster.add_player("Starter", "SS", 10, None));
+        assert!(roster.add_player("Backup", "SS", 1, None));
+        assert_eq!(roster.empty_slots(), 0);
+        assert!(!roster.has_empty_slot_for_slots(&[ESPN_SLOT_SS], true));
+        assert!(!roster.add_player("Veteran", "SS", 1, None));
+        assert!(roster.has_empty_slot_for_slots(&[ESPN_SLOT_SS, ESPN_SLOT_NA], true));
+        assert!(roster.add_player_with_slots("Second Prospect", "SS", 1, &[ESPN_SLOT_SS, ESPN_SLOT_NA], None, None));
+        assert_eq!(roster.filled_count(), 4);
+    }
+
     // -- Combo roster slot tests (roster config with OF/MI/CI/P keys) --
 
     fn combo_roster_config() -> HashMap<String, usize> {
@@ -1211,4 +1426,71 @@ mod tests {
         let slots = vec![14]; // just SP
         assert!(roster.has_empty_slot_for_slots(&slots, false));
     }
+
+    #[test]
+    fn combo_fill_prefers_mi_over_if() {
+        let mut config = HashMap::new();
+        config.insert("SS".to_string(), 1);
+        config.insert("MI".to_string(), 1);
+        config.insert("IF".to_string(), 1);
+        let mut roster = Roster::new(&config);
+
+        assert!(roster.add_player("SS One", "SS", 10, None));
+        assert!(roster.add_player("SS Two", "SS", 10, None));
+        assert!(roster.add_player("SS Three", "SS", 10, None));
+
+        let holder = |pos: Position| {
+            roster
+                .slots
+                .iter()
+                .find(|s| s.position == pos)
+                .and_then(|s| s.player.as_ref())
+                .map(|p| p.name.clone())
+        };
+        assert_eq!(holder(Position::ShortStop).as_deref(), Some("SS One"));
+        assert_eq!(holder(Position::MiddleInfield).as_deref(), Some("SS Two"));
+        assert_eq!(holder(Position::Infield).as_deref(), Some("SS Three"));
+    }
+
+    #[test]
+    fn add_player_with_slots_fills_if_slot() {
+        let mut config = HashMap::new();
+        config.insert("1B".to_string(), 1);
+        config.insert("IF".to_string(), 1);
+        let mut roster = Roster::new(&config);
+
+        assert!(roster.add_player_with_slots("First", "1B", 10, &[1], None, None));
+        assert!(roster.add_player_with_slots("Second", "1B", 10, &[1], None, None));
+        assert!(!roster.has_empty_slot_for_slots(&[3], true));
+        assert!(roster
+            .slots
+            .iter()
+            .any(|s| s.position == Position::Infield && s.player.is_some()));
+    }
+
+    #[test]
+    fn players_move_only_to_open_slots_they_can_play() {
+        let mut config = HashMap::new();
+        config.insert("1B".to_string(), 1);
+        config.insert("SS".to_string(), 1);
+        config.insert("UTIL".to_string(), 1);
+        config.insert("BE".to_string(), 1);
+        let mut roster = Roster::new(&config);
+        assert!(roster.add_player("Slugger", "1B", 20, None));
+        assert!(roster.add_player("Glove", "SS", 10, None));
+        let (first, short, util, bench) = (0, 1, 2, 3);
+        assert_eq!(roster.slot_of("Slugger"), Some(first));
+
+        assert!(!roster.move_player(first, short), "a first baseman can't play short");
+        assert!(!roster.move_player(first, bench + 1), "no such slot");
+        assert_eq!(next_open_slot(&roster.slots, first), Some(util));
+
+        assert!(roster.move_player(first, util));
+        assert_eq!(roster.slot_of("Slugger"), Some(util));
+        assert!(roster.slots[first].player.is_none());
+        assert_eq!(next_open_slot(&roster.slots, util), Some(bench));
+        assert!(!roster.move_player(short, util), "UTIL is taken");
+        assert!(roster.move_player(util, first));
+        assert_eq!(roster.slot_of("Slugger"), Some(first));
+    }
 }
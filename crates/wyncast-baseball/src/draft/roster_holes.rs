@@ -0,0 +1,171 @@
+// Roster holes: positions where the players left can't go round.
+//
+// For each slot position I still have open, counts the teams (mine included)
+// with an open slot there and the available players above replacement who
+// could fill it, as the shopping list counts them. When there are fewer than
+// two players per team needing one the position is flagged, escalating to a
+// warning when there is no spare player and to a panic when some team will
+// be left without one. The bench and IL are skipped: anyone fills them.
+
+use super::pick::Position;
+use super::shopping_list::fits;
+use super::state::DraftState;
+use crate::valuation::zscore::PlayerValuation;
+
+/// How close a position is to running out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HoleAlert {
+    /// Fewer than two players left per team needing one.
+    Watch,
+    /// Exactly as many players left as teams needing one: no spare.
+    Warning,
+    /// Fewer players left than teams needing one.
+    Panic,
+}
+
+impl HoleAlert {
+    /// The alert for `players_left` players among `teams_needing` teams, or
+    /// `None` when there are plenty.
+    pub fn from_counts(players_left: usize, teams_needing: usize) -> Option<Self> {
+        if players_left < teams_needing {
+            Some(HoleAlert::Panic)
+        } else if players_left == teams_needing {
+            Some(HoleAlert::Warning)
+        } else if players_left < 2 * teams_needing {
+            Some(HoleAlert::Watch)
+        } else {
+            None
+        }
+    }
+
+    /// Return a human-readable label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            HoleAlert::Watch => "WATCH",
+            HoleAlert::Warning => "WARNING",
+            HoleAlert::Panic => "PANIC",
+        }
+    }
+}
+
+/// One of my open slot positions that is running short of players.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RosterHole {
+    pub position: Position,
+    /// Teams, mine included, with an open slot at this position.
+    pub teams_needing: usize,
+    /// Available players above replacement who could fill the slot.
+    pub players_left: usize,
+    pub alert: HoleAlert,
+}
+
+/// Teams with at least one open `position` slot.
+fn teams_needing(draft_state: &DraftState, position: Position) -> usize {
+    draft_state
+        .teams
+        .iter()
+        .filter(|t| {
+            t.roster
+                .slots
+                .iter()
+                .any(|s| s.position == position && s.player.is_none())
+        })
+        .count()
+}
+
+/// The roster holes for my team, most alarming first. Empty before my team
+/// is known.
+pub fn roster_holes(draft_state: &DraftState, available: &[PlayerValuation]) -> Vec<RosterHole> {
+    let Some(my_team) = draft_state.my_team() else {
+        return Vec::new();
+    };
+    let mut positions: Vec<Position> = Vec::new();
+    for slot in &my_team.roster.slots {
+        let skip = matches!(slot.position, Position::Bench) || slot.position.is_reserve_slot();
+        if slot.player.is_none() && !skip && !positions.contains(&slot.position) {
+            positions.push(slot.position);
+        }
+    }
+
+    let mut holes: Vec<RosterHole> = positions
+        .into_iter()
+        .filter_map(|position| {
+            let teams_needing = teams_needing(draft_state, position);
+            let players_left = available
+                .iter()
+                .filter(|p| p.vor > 0.0 && fits(position, p))
+                .count();
+            HoleAlert::from_counts(players_left, teams_needing).map(|alert| RosterHole {
+                position,
+                teams_needing,
+                players_left,
+                alert,
+            })
+        })
+        .collect();
+    holes.sort_by_key(|hole| std::cmp::Reverse(hole.alert));
+    holes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::roster::RosteredPlayer;
+    use crate::test_utils::{create_test_draft_state, TestPlayer};
+
+    fn fill(state: &mut DraftState, team: usize, position: Position) {
+        let slot = state.teams[team]
+            .roster
+            .slots
+            .iter_mut()
+            .find(|s| s.position == position && s.player.is_none())
+            .unwrap();
+        slot.player = Some(RosteredPlayer {
+            name: "X".into(),
+            price: 1,
+            position,
+            eligible_slots: vec![],
+            espn_player_id: None,
+        });
+    }
+
+    fn catchers(n: usize) -> Vec<PlayerValuation> {
+        (0..n)
+            .map(|i| {
+                TestPlayer::hitter(&format!("C{i}"))
+                    .positions(vec![Position::Catcher])
+                    .vor(1.0)
+                    .build()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn alerts_escalate_as_the_gap_closes() {
+        assert_eq!(HoleAlert::from_counts(8, 4), None);
+        assert_eq!(HoleAlert::from_counts(7, 4), Some(HoleAlert::Watch));
+        assert_eq!(HoleAlert::from_counts(4, 4), Some(HoleAlert::Warning));
+        assert_eq!(HoleAlert::from_counts(3, 4), Some(HoleAlert::Panic));
+    }
+
+    #[test]
+    fn holes_count_the_teams_still_needing_the_position() {
+        let mut state = create_test_draft_state(4);
+        fill(&mut state, 3, Position::Catcher);
+        let mut available = catchers(2);
+        available.push(TestPlayer::hitter("Backup C").positions(vec![Position::Catcher]).vor(-1.0).build());
+
+        // Three teams need a catcher and two are left above replacement.
+        let holes = roster_holes(&state, &available);
+        let catcher = holes.iter().find(|h| h.position == Position::Catcher).unwrap();
+        assert_eq!((catcher.teams_needing, catcher.players_left), (3, 2));
+        assert_eq!(catcher.alert, HoleAlert::Panic);
+        assert_eq!(holes[0].alert, HoleAlert::Panic, "most alarming first");
+        assert!(holes.iter().all(|h| h.position != Position::Bench));
+
+        // Once my catcher slot is filled it is no longer my hole.
+        fill(&mut state, 0, Position::Catcher);
+        let holes = roster_holes(&state, &catchers(20));
+        assert!(holes.iter().all(|h| h.position != Position::Catcher));
+    }
+}
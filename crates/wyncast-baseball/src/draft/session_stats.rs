@@ -0,0 +1,210 @@
+// Session statistics: how the draft is going so far.
+//
+// Everything here comes from the recorded picks, the value the model put on
+// each player when they were bought, and the pool still available: what
+// each position is going for, how much surplus (value over price) my picks
+// captured next to the best I could have had with as many picks, the
+// bargains other teams got that I had room for, and how much value is left
+// for every open roster slot in the league.
+
+use std::collections::HashMap;
+
+use super::pick::{espn_slot_from_position_str, pick_is_hitter, resolve_position, Position};
+use super::state::DraftState;
+use crate::valuation::zscore::PlayerValuation;
+
+/// Average price paid at one position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionPrice {
+    pub position: Position,
+    pub picks: usize,
+    pub avg_price: f64,
+}
+
+/// A player another team bought for less than my value, with a slot open
+/// for them on my roster.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissedBargain {
+    pub player: String,
+    pub price: u32,
+    pub value: f64,
+}
+
+impl MissedBargain {
+    pub fn surplus(&self) -> f64 {
+        self.value - f64::from(self.price)
+    }
+}
+
+/// Live analytics for the draft so far.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionStats {
+    /// Average price by position, in roster order.
+    pub position_prices: Vec<PositionPrice>,
+    /// Value minus price, summed over my picks.
+    pub my_surplus: f64,
+    /// The most surplus as many picks as mine could have captured: the
+    /// largest surpluses among every sale so far.
+    pub best_surplus: f64,
+    /// Bargains other teams got that would have fit my open slots, biggest
+    /// surplus first.
+    pub bargains_missed: Vec<MissedBargain>,
+    /// Open roster slots across the league.
+    pub open_slots: usize,
+    /// Value of the best available players that would fill those slots,
+    /// per slot.
+    pub value_per_open_slot: f64,
+}
+
+/// Compute the session statistics. `pick_values` holds the model's value of
+/// each drafted player when they were bought, by pick number; picks without
+/// one are left out of the surplus figures.
+pub fn session_stats(
+    draft_state: &DraftState,
+    pick_values: &HashMap<u32, f64>,
+    available: &[PlayerValuation],
+) -> SessionStats {
+    let my_team = draft_state.my_team();
+
+    let mut by_position: HashMap<Position, (usize, u32)> = HashMap::new();
+    for pick in &draft_state.picks {
+        if let Some(position) = resolve_position(&pick.position, &pick.eligible_slots) {
+            let entry = by_position.entry(position).or_default();
+            entry.0 += 1;
+            entry.1 += pick.price;
+        }
+    }
+    let mut position_prices: Vec<PositionPrice> = by_position
+        .into_iter()
+        .map(|(position, (picks, total))| PositionPrice {
+            position,
+            picks,
+            avg_price: f64::from(total) / picks as f64,
+        })
+        .collect();
+    position_prices.sort_by_key(|p| p.position.sort_order());
+
+    let mut my_surplus = 0.0;
+    let mut my_valued = 0;
+    let mut surpluses = Vec::new();
+    let mut bargains_missed = Vec::new();
+    for pick in &draft_state.picks {
+        let Some(&value) = pick_values.get(&pick.pick_number) else {
+            continue;
+        };
+        let surplus = value - f64::from(pick.price);
+        surpluses.push(surplus);
+        let Some(team) = my_team else {
+            continue;
+        };
+        if pick.team_id == team.team_id {
+            my_surplus += surplus;
+            my_valued += 1;
+        } else if surplus > 0.0 {
+            let mut slots = pick.eligible_slots.clone();
+            if slots.is_empty() {
+                slots.extend(espn_slot_from_position_str(&pick.position));
+            }
+            let is_hitter = pick_is_hitter(pick).unwrap_or(false);
+            if team.roster.has_empty_slot_for_slots(&slots, is_hitter) {
+                bargains_missed.push(MissedBargain {
+                    player: pick.player_name.clone(),
+                    price: pick.price,
+                    value,
+                });
+            }
+        }
+    }
+    surpluses.sort_by(|a, b| b.total_cmp(a));
+    let best_surplus = surpluses.iter().take(my_valued).sum();
+    bargains_missed.sort_by(|a, b| b.surplus().total_cmp(&a.surplus()));
+
+    let open_slots = draft_state
+        .teams
+        .iter()
+        .map(|t| t.roster.empty_slots())
+        .sum();
+    let mut values: Vec<f64> = available.iter().map(|p| p.dollar_value.max(0.0)).collect();
+    values.sort_by(|a, b| b.total_cmp(a));
+    let value_per_open_slot = if open_slots > 0 {
+        values.iter().take(open_slots).sum::<f64>() / open_slots as f64
+    } else {
+        0.0
+    };
+
+    SessionStats {
+        position_prices,
+        my_surplus,
+        best_surplus,
+        bargains_missed,
+        open_slots,
+        value_per_open_slot,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_draft_state, test_pick, TestPlayer};
+
+    #[test]
+    fn stats_summarize_prices_surplus_and_missed_bargains() {
+        let mut state = create_test_draft_state(2);
+        // Team 2's catcher and shortstop went under value; its first
+        // baseman did not, and the pitcher has no recorded value.
+        let sales = [
+            ("My C", 1, "C", 10, 14.0),
+            ("My SS", 1, "SS", 30, 25.0),
+            ("Their C", 2, "C", 2, 12.0),
+            ("Their SS", 2, "SS", 20, 28.0),
+            ("Their 1B", 2, "1B", 40, 30.0),
+            ("No value", 2, "SP", 5, f64::NAN),
+        ];
+        let mut values = HashMap::new();
+        for (player, team, position, price, value) in sales {
+            state.record_pick(test_pick(player).team(team).position(position).price(price).build());
+            if !value.is_nan() {
+                values.insert(state.picks.len() as u32, value);
+            }
+        }
+
+        let stats = session_stats(&state, &values, &[]);
+        let prices: Vec<(Position, usize, f64)> = stats
+            .position_prices
+            .iter()
+            .map(|p| (p.position, p.picks, p.avg_price))
+            .collect();
+        assert_eq!(
+            prices,
+            vec![
+                (Position::Catcher, 2, 6.0),
+                (Position::FirstBase, 1, 40.0),
+                (Position::ShortStop, 2, 25.0),
+                (Position::StartingPitcher, 1, 5.0),
+            ]
+        );
+        // +4 and -5; the best two sales were +10 and +8.
+        assert_eq!(stats.my_surplus, -1.0);
+        assert_eq!(stats.best_surplus, 18.0);
+        // Both would still have fit my bench.
+        let missed: Vec<&str> = stats.bargains_missed.iter().map(|b| b.player.as_str()).collect();
+        assert_eq!(missed, vec!["Their C", "Their SS"]);
+    }
+
+    #[test]
+    fn value_per_open_slot_counts_the_best_available_players() {
+        let state = create_test_draft_state(1);
+        let open = state.teams[0].roster.empty_slots();
+        let mut players: Vec<PlayerValuation> = (0..open + 5)
+            .map(|i| {
+                let mut p = TestPlayer::hitter(&format!("P{i}")).build();
+                p.dollar_value = if i < open { 10.0 } else { -3.0 };
+                p
+            })
+            .collect();
+        players.reverse();
+
+        let stats = session_stats(&state, &HashMap::new(), &players);
+        assert_eq!(stats, SessionStats { open_slots: open, value_per_open_slot: 10.0, ..Default::default() });
+    }
+}
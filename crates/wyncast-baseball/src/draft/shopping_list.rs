@@ -0,0 +1,193 @@
+// Shopping list: what my open roster slots still need.
+//
+// For each open slot position: how many slots are open, what to budget per
+// slot, and how many players above replacement are left who could fill it.
+// The budget comes from a greedy fill of my open slots with the best
+// available players (each into the narrowest open slot that takes them);
+// the money left after the minimum bids is shared out in proportion to what
+// each slot's player is worth over the minimum at today's inflation.
+
+use super::pick::Position;
+use super::roster::{slot_accepts, Roster};
+use crate::valuation::auction::InflationTracker;
+use crate::valuation::zscore::PlayerValuation;
+
+/// My open slots at one position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotNeed {
+    pub position: Position,
+    /// Open slots at this position.
+    pub open: usize,
+    /// Suggested spend per slot.
+    pub budget_per_slot: f64,
+    /// Available players above replacement who could fill the slot.
+    pub viable: usize,
+}
+
+/// Whether `player` can fill a `slot`.
+pub(crate) fn fits(slot: Position, player: &PlayerValuation) -> bool {
+    match slot {
+        Position::Bench => true,
+        Position::InjuredList | Position::Minors => false,
+        Position::Utility | Position::DesignatedHitter => !player.is_pitcher,
+        _ => player.positions.iter().any(|&p| slot_accepts(slot, p)),
+    }
+}
+
+/// Build the shopping list for `roster`, in roster order. Empty when the
+/// roster is full.
+pub fn shopping_list(
+    roster: &Roster,
+    budget_remaining: u32,
+    min_bid: u32,
+    available: &[PlayerValuation],
+    inflation: &InflationTracker,
+) -> Vec<SlotNeed> {
+    // Roster slots are already in fill order: dedicated positions, then
+    // combo slots, UTIL and the bench.
+    let open: Vec<Position> = roster
+        .slots
+        .iter()
+        .filter(|s| s.player.is_none() && !s.position.is_reserve_slot())
+        .map(|s| s.position)
+        .collect();
+    if open.is_empty() {
+        return Vec::new();
+    }
+
+    let mut ranked: Vec<&PlayerValuation> = available.iter().collect();
+    ranked.sort_by(|a, b| b.dollar_value.total_cmp(&a.dollar_value));
+    let floor = f64::from(min_bid);
+    let mut targets: Vec<Option<f64>> = vec![None; open.len()];
+    let mut unfilled = open.len();
+    for player in ranked {
+        if unfilled == 0 {
+            break;
+        }
+        let slot = (0..open.len()).find(|&i| targets[i].is_none() && fits(open[i], player));
+        if let Some(i) = slot {
+            targets[i] = Some(inflation.adjust(player.dollar_value));
+            unfilled -= 1;
+        }
+    }
+
+    let excess: Vec<f64> = targets
+        .iter()
+        .map(|t| (t.unwrap_or(floor) - floor).max(0.0))
+        .collect();
+    let total_excess: f64 = excess.iter().sum();
+    let budget = f64::from(budget_remaining);
+    let reserved = floor * open.len() as f64;
+    let budgets: Vec<f64> = if budget < reserved {
+        vec![budget / open.len() as f64; open.len()]
+    } else if total_excess > 0.0 {
+        excess
+            .iter()
+            .map(|e| floor + (budget - reserved) * e / total_excess)
+            .collect()
+    } else {
+        vec![budget / open.len() as f64; open.len()]
+    };
+
+    let mut needs: Vec<SlotNeed> = Vec::new();
+    for (position, spend) in open.into_iter().zip(budgets) {
+        match needs.iter_mut().find(|n| n.position == position) {
+            Some(need) => {
+                need.budget_per_slot += spend;
+                need.open += 1;
+            }
+            None => needs.push(SlotNeed {
+                position,
+                open: 1,
+                budget_per_slot: spend,
+                viable: available
+                    .iter()
+                    .filter(|p| p.vor > 0.0 && fits(position, p))
+                    .count(),
+            }),
+        }
+    }
+    for need in &mut needs {
+        need.budget_per_slot /= need.open as f64;
+    }
+    needs
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::test_utils::{assert_close, TestPlayer};
+    use crate::valuation::projections::PitcherType;
+
+    fn roster() -> Roster {
+        let config: HashMap<String, usize> = [("C", 1), ("SS", 1), ("UTIL", 1), ("SP", 2), ("BE", 1), ("IL", 1)]
+            .into_iter()
+            .map(|(pos, n)| (pos.to_string(), n))
+            .collect();
+        Roster::new(&config)
+    }
+
+    fn pool() -> Vec<PlayerValuation> {
+        vec![
+            TestPlayer::hitter("Star SS").positions(vec![Position::ShortStop]).dollar(41.0).vor(6.0).build(),
+            TestPlayer::hitter("Slugger").dollar(21.0).vor(3.0).build(),
+            TestPlayer::hitter("Backup C").positions(vec![Position::Catcher]).dollar(1.0).vor(-0.5).build(),
+            TestPlayer::pitcher("Ace", PitcherType::SP).dollar(31.0).vor(4.0).build(),
+            TestPlayer::pitcher("Arm", PitcherType::SP).dollar(11.0).vor(1.0).build(),
+            TestPlayer::pitcher("Closer", PitcherType::RP).dollar(6.0).vor(0.5).build(),
+        ]
+    }
+
+    #[test]
+    fn budget_follows_the_players_left_for_each_slot() {
+        let list = shopping_list(&roster(), 100, 1, &pool(), &InflationTracker::new());
+
+        let positions: Vec<Position> = list.iter().map(|n| n.position).collect();
+        assert_eq!(
+            positions,
+            vec![Position::Catcher, Position::ShortStop, Position::Utility, Position::StartingPitcher, Position::Bench]
+        );
+        // Filled best-first: Star SS at SS, Ace and Arm at SP, Slugger at
+        // UTIL, Closer on the bench and the backup catcher at C. The $94
+        // over the minimums follows each player's $ above $1.
+        let total_excess = 40.0 + 20.0 + 30.0 + 10.0 + 5.0;
+        let by_pos = |pos| list.iter().find(|n| n.position == pos).unwrap();
+        assert_close(by_pos(Position::ShortStop).budget_per_slot, 1.0 + 94.0 * 40.0 / total_excess, "SS");
+        assert_close(by_pos(Position::Catcher).budget_per_slot, 1.0, "C");
+        assert_close(
+            by_pos(Position::StartingPitcher).budget_per_slot,
+            1.0 + 94.0 * 20.0 / total_excess,
+            "SP average",
+        );
+        assert_eq!(by_pos(Position::StartingPitcher).open, 2);
+        let spend: f64 = list.iter().map(|n| n.budget_per_slot * n.open as f64).sum();
+        assert_close(spend, 100.0, "whole budget");
+
+        // Viable: above replacement and eligible.
+        assert_eq!(by_pos(Position::Catcher).viable, 0);
+        assert_eq!(by_pos(Position::ShortStop).viable, 1);
+        assert_eq!(by_pos(Position::Utility).viable, 2);
+        assert_eq!(by_pos(Position::StartingPitcher).viable, 2);
+        assert_eq!(by_pos(Position::Bench).viable, 5);
+    }
+
+    #[test]
+    fn short_budgets_split_evenly_and_full_rosters_need_nothing() {
+        let list = shopping_list(&roster(), 3, 1, &pool(), &InflationTracker::new());
+        assert!(list.iter().all(|n| (n.budget_per_slot - 0.5).abs() < 1e-9));
+
+        let mut full = roster();
+        for slot in &mut full.slots {
+            slot.player = Some(crate::draft::roster::RosteredPlayer {
+                name: "X".into(),
+                price: 1,
+                position: slot.position,
+                eligible_slots: vec![],
+                espn_player_id: None,
+            });
+        }
+        assert!(shopping_list(&full, 50, 1, &pool(), &InflationTracker::new()).is_empty());
+    }
+}
@@ -0,0 +1,138 @@
+// Snake order: who is on the clock in a snake draft.
+//
+// Teams pick one at a time in the draft order (ESPN's draft board columns,
+// or `league.nomination_order` in the config, as for auction nominations),
+// and the order reverses every round: 1..n, then n..1. With one pick per
+// turn, the number of picks made says whose turn it is. A draft has as many
+// rounds as a roster has draftable slots.
+
+use serde::{Deserialize, Serialize};
+
+use super::state::DraftState;
+use crate::valuation::draft_board::BoardEntry;
+
+/// Where a snake draft stands, seen from my team.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnakeTurn {
+    /// The round being picked, from 1.
+    pub round: usize,
+    pub rounds: usize,
+    /// The pick within the round, from 1.
+    pub pick_in_round: usize,
+    /// The pick overall, from 1.
+    pub overall: usize,
+    /// Name of the team on the clock.
+    pub team: String,
+    /// My next pick overall. `None` when my team is unknown, outside the
+    /// order, or done picking.
+    pub my_next_pick: Option<usize>,
+}
+
+impl SnakeTurn {
+    /// Picks before mine: 0 when I am on the clock.
+    pub fn my_turn_in(&self) -> Option<usize> {
+        self.my_next_pick.map(|pick| pick - self.overall)
+    }
+}
+
+/// A snake draft's clock and the top of its board (the round/pick displays).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnakeStatus {
+    /// `None` before the order is known or once the draft is over.
+    pub turn: Option<SnakeTurn>,
+    /// The best available players, ranked.
+    pub board: Vec<BoardEntry>,
+}
+
+/// Position in the draft order of the team making pick `overall` (from 1)
+/// among `teams` teams.
+pub fn order_position(overall: usize, teams: usize) -> usize {
+    let round = (overall - 1) / teams;
+    let idx = (overall - 1) % teams;
+    if round.is_multiple_of(2) {
+        idx
+    } else {
+        teams - 1 - idx
+    }
+}
+
+/// Who is on the clock, and when I pick next. `None` before the order is
+/// known or once every round is picked.
+pub fn snake_turn(draft_state: &DraftState) -> Option<SnakeTurn> {
+    let teams = &draft_state.teams;
+    let order: Vec<usize> = draft_state
+        .nomination_order
+        .iter()
+        .copied()
+        .filter(|&idx| idx < teams.len())
+        .collect();
+    let n = order.len();
+    if n == 0 {
+        return None;
+    }
+    let rounds = teams[order[0]].roster.draftable_count();
+    let overall = draft_state.picks.len() + 1;
+    if overall > rounds * n {
+        return None;
+    }
+
+    let my_next_pick = draft_state
+        .my_team_idx
+        .and_then(|me| order.iter().position(|&idx| idx == me))
+        .and_then(|mine| (overall..=rounds * n).find(|&pick| order_position(pick, n) == mine));
+
+    Some(SnakeTurn {
+        round: (overall - 1) / n + 1,
+        rounds,
+        pick_in_round: (overall - 1) % n + 1,
+        overall,
+        team: teams[order[order_position(overall, n)]].team_name.clone(),
+        my_next_pick,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_draft_state, test_pick};
+
+    #[test]
+    fn the_order_reverses_every_round() {
+        let picks: Vec<usize> = (1..=9).map(|overall| order_position(overall, 3)).collect();
+        assert_eq!(picks, [0, 1, 2, 2, 1, 0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn turn_follows_the_picks_made() {
+        let mut state = create_test_draft_state(3);
+        state.nomination_order = vec![1, 0, 2];
+        let turn = snake_turn(&state).unwrap();
+        assert_eq!((turn.round, turn.pick_in_round, turn.overall), (1, 1, 1));
+        assert_eq!(turn.team, "Team 2");
+        assert_eq!(turn.rounds, 26);
+        assert_eq!(turn.my_next_pick, Some(2));
+        assert_eq!(turn.my_turn_in(), Some(1));
+
+        // Team 2, me, Team 3, then Team 3 again to open round 2.
+        for team in [1, 0, 2] {
+            let player = format!("Player {}", state.picks.len() + 1);
+            state.record_pick(test_pick(&player).team(team + 1).position("UTIL").price(0).build());
+        }
+        let turn = snake_turn(&state).unwrap();
+        assert_eq!((turn.round, turn.pick_in_round, turn.overall), (2, 1, 4));
+        assert_eq!(turn.team, "Team 3");
+        assert_eq!(turn.my_next_pick, Some(5));
+    }
+
+    #[test]
+    fn no_turn_once_every_round_is_picked() {
+        let mut state = create_test_draft_state(2);
+        for i in 0..52 {
+            let team = order_position(i + 1, 2);
+            state.record_pick(test_pick(&format!("Player {i}")).team(team + 1).position("UTIL").price(0).build());
+        }
+        assert_eq!(snake_turn(&state), None);
+        state.nomination_order.clear();
+        assert_eq!(snake_turn(&state), None);
+    }
+}
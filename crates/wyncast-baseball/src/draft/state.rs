@@ -1,11 +1,11 @@
 // Draft state: current nomination, budgets, available players.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
-use super::pick::DraftPick;
+use super::pick::{enriched_position_str, DraftPick};
 use super::roster::Roster;
 
 /// The state of a single team during the draft.
@@ -45,6 +45,24 @@ pub struct ActiveNomination {
     pub eligible_slots: Vec<u16>,
 }
 
+/// A player a team kept from last season (`[[league.keepers.kept]]`): on
+/// its roster at his salary before the draft starts, without a pick.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeptPlayer {
+    pub player_name: String,
+    /// The team's name or ESPN team ID, as configured.
+    pub team: String,
+    /// Eligible positions, e.g. "2B/SS", for placing him on the roster.
+    pub position: String,
+    pub price: u32,
+}
+
+impl KeptPlayer {
+    pub(crate) fn kept_by(&self, team: &TeamState) -> bool {
+        team.team_id.eq_ignore_ascii_case(&self.team) || team.team_name.eq_ignore_ascii_case(&self.team)
+    }
+}
+
 /// The complete state of the draft.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DraftState {
@@ -62,6 +80,18 @@ pub struct DraftState {
     pub my_team_idx: Option<usize>,
     /// Order of team indices for nominations (round-robin, etc.).
     pub nomination_order: Vec<usize>,
+    /// Who nominated each player, by player name, as reported with the
+    /// nomination (a team name or ID).
+    #[serde(default)]
+    pub nominators: HashMap<String, String>,
+    /// Players kept from last season, put on their teams' rosters as the
+    /// teams are registered.
+    #[serde(default)]
+    pub keepers: Vec<KeptPlayer>,
+    /// Whether the keepers are entered on ESPN too, so the budgets ESPN
+    /// reports already have their salaries taken off.
+    #[serde(default)]
+    pub keepers_on_espn: bool,
     /// The salary cap per team (stored for restore).
     salary_cap: u32,
     /// The roster configuration (stored for restore).
@@ -87,11 +117,27 @@ impl DraftState {
             total_picks: 0,
             my_team_idx: None,
             nomination_order: Vec::new(),
+            nominators: HashMap::new(),
+            keepers: Vec::new(),
+            keepers_on_espn: false,
             salary_cap,
             roster_config: roster_config.clone(),
         }
     }
 
+    /// Remember who nominated the player on the block. Returns `true` when
+    /// this is news: a new nomination, or a nominator ESPN filled in late.
+    pub fn note_nominator(&mut self, nomination: &ActiveNomination) -> bool {
+        if nomination.nominated_by.is_empty()
+            || self.nominators.get(&nomination.player_name) == Some(&nomination.nominated_by)
+        {
+            return false;
+        }
+        self.nominators
+            .insert(nomination.player_name.clone(), nomination.nominated_by.clone());
+        true
+    }
+
     /// Identify the user's team by matching the ESPN team ID.
     ///
     /// After teams are registered via `reconcile_budgets()`, this method
@@ -117,7 +163,9 @@ impl DraftState {
     ///
     /// Deduplication: if the same player (by identity) has already been
     /// recorded, the call is a no-op. Player identity is determined by
-    /// (player_name, team_id) — this is stable even when ESPN's virtualized
+    /// (player_name, team_id), plus the position when both picks carry one
+    /// so two same-named players bought by one team both count — this is
+    /// stable even when ESPN's virtualized
     /// pick list causes pick_number renumbering. Using pick_number alone for
     /// dedup would cause new picks to be silently dropped when their number
     /// had been previously claimed by a renumbered existing pick.
@@ -149,8 +197,11 @@ impl DraftState {
                     return new_id == existing_id;
                 }
             }
-            // Fall back to (player_name, team_id) identity
-            p.player_name == pick.player_name && p.team_id == pick.team_id
+            // Fall back to (player_name, team_id) identity, told apart by
+            // position for players sharing a name
+            p.player_name == pick.player_name
+                && p.team_id == pick.team_id
+                && (p.position.is_empty() || pick.position.is_empty() || p.position == pick.position)
         });
         if dominated_by_identity {
             return;
@@ -207,8 +258,9 @@ impl DraftState {
     ///
     /// On the first call (when `self.teams` is empty), this auto-registers
     /// all teams from the ESPN data, building the full team registry.
-    /// On subsequent calls, it uses the ESPN-reported remaining budget as the
-    /// source of truth and adjusts `budget_remaining` and `budget_spent`.
+    /// On every call, it uses the ESPN-reported remaining budget as the
+    /// source of truth and adjusts `budget_remaining` and `budget_spent`,
+    /// taking off the keepers' salaries unless they are entered on ESPN.
     ///
     /// Returns a [`ReconcileResult`] indicating whether teams were registered
     /// for the first time and/or whether any budget values actually changed.
@@ -220,8 +272,8 @@ impl DraftState {
                     team_id: budget_data.team_id.clone(),
                     team_name: budget_data.team_name.clone(),
                     roster: Roster::new(&self.roster_config),
-                    budget_spent: self.salary_cap.saturating_sub(budget_data.budget),
-                    budget_remaining: budget_data.budget,
+                    budget_spent: 0,
+                    budget_remaining: self.salary_cap,
                 });
             }
 
@@ -234,15 +286,29 @@ impl DraftState {
             self.total_picks = draftable_per_team * self.teams.len();
             self.nomination_order = (0..self.teams.len()).collect();
 
+            self.apply_keepers();
+
             // Replay any picks stored during crash recovery before teams existed
             self.replay_pending_picks();
 
+            // ESPN's budgets already count those picks and keepers.
+            self.sync_budgets(espn_budgets);
+
             return ReconcileResult {
                 teams_registered: true,
                 budgets_changed: true,
             };
         }
 
+        ReconcileResult {
+            teams_registered: false,
+            budgets_changed: self.sync_budgets(espn_budgets),
+        }
+    }
+
+    /// Set each team's budget from the one ESPN reports. Returns whether
+    /// any budget changed.
+    fn sync_budgets(&mut self, espn_budgets: &[TeamBudgetPayload]) -> bool {
         let mut budgets_changed = false;
         for budget_data in espn_budgets {
             // Match by team_id — the authoritative ESPN team identifier
@@ -258,8 +324,14 @@ impl DraftState {
                 None
             };
             if let Some(team) = team {
-                let new_remaining = budget_data.budget;
-                let new_spent = self.salary_cap.saturating_sub(budget_data.budget);
+                // Keepers kept off the site aren't in ESPN's budgets.
+                let kept: u32 = if self.keepers_on_espn {
+                    0
+                } else {
+                    self.keepers.iter().filter(|k| k.kept_by(team)).map(|k| k.price).sum()
+                };
+                let new_remaining = budget_data.budget.saturating_sub(kept);
+                let new_spent = self.salary_cap.saturating_sub(new_remaining);
                 if team.budget_remaining != new_remaining || team.budget_spent != new_spent {
                     team.budget_remaining = new_remaining;
                     team.budget_spent = new_spent;
@@ -267,17 +339,41 @@ impl DraftState {
                 }
             }
         }
-        ReconcileResult {
-            teams_registered: false,
-            budgets_changed,
-        }
+        budgets_changed
     }
 
-    /// Total salary spent across all teams.
+    /// Total salary spent across all teams. Before the teams are
+    /// registered, the keepers' salaries.
     pub fn total_spent(&self) -> u32 {
+        if self.teams.is_empty() {
+            return self.keepers.iter().map(|k| k.price).sum();
+        }
         self.teams.iter().map(|t| t.budget_spent).sum()
     }
 
+    /// Put each kept player on his team's roster and charge his salary to
+    /// its budget. Players already on the roster are left alone, so this
+    /// is safe to repeat; keepers of unknown teams are skipped.
+    pub fn apply_keepers(&mut self) {
+        if self.teams.is_empty() {
+            return;
+        }
+        for keeper in &self.keepers {
+            let Some(team) = self.teams.iter_mut().find(|t| keeper.kept_by(t)) else {
+                warn!("{} is kept by '{}', which is not a team in this draft", keeper.player_name, keeper.team);
+                continue;
+            };
+            if team.roster.has_player(&keeper.player_name, None) {
+                continue;
+            }
+            if !team.roster.add_player(&keeper.player_name, &keeper.position, keeper.price, None) {
+                warn!("No roster slot for {}'s keeper {}", team.team_name, keeper.player_name);
+            }
+            team.budget_spent += keeper.price;
+            team.budget_remaining = team.budget_remaining.saturating_sub(keeper.price);
+        }
+    }
+
     /// Look up a team by ID.
     pub fn team(&self, team_id: &str) -> Option<&TeamState> {
         self.teams.iter().find(|t| t.team_id == team_id)
@@ -295,6 +391,12 @@ impl DraftState {
         self.my_team_idx.and_then(|idx| self.teams.get(idx))
     }
 
+    /// Mutable reference to the user's team state, if teams have been
+    /// registered.
+    pub fn my_team_mut(&mut self) -> Option<&mut TeamState> {
+        self.my_team_idx.and_then(|idx| self.teams.get_mut(idx))
+    }
+
     /// Restore the draft state by replaying a sequence of picks.
     ///
     /// This is used for crash recovery: given a saved list of picks,
@@ -330,6 +432,7 @@ impl DraftState {
             team.budget_remaining = self.salary_cap;
             team.roster = Roster::new(&self.roster_config);
         }
+        self.apply_keepers();
         self.picks.clear();
         self.pick_count = 0;
 
@@ -352,6 +455,18 @@ impl DraftState {
             self.record_pick(pick);
         }
     }
+
+    /// Serialize the complete draft state (teams, rosters, picks, the
+    /// current nomination, salary cap and roster config) as pretty JSON,
+    /// e.g. for a support bundle.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Restore a draft state written by [`DraftState::to_json`].
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
 }
 
 /// Result of reconciling team budgets with ESPN data.
@@ -458,19 +573,177 @@ pub struct StateDiff {
     pub nomination_cleared: bool,
     /// Whether only the bid amount/bidder changed on the same nomination.
     pub bid_updated: bool,
+    /// Each decision behind the fields above, with the inputs it was made
+    /// on, in the order it was made.
+    pub decisions: Vec<DiffDecision>,
+}
+
+/// One decision `compute_state_diff` made, with the inputs behind it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffDecision {
+    /// A pick was treated as new: its player's identity was not among the
+    /// previous snapshot's picks.
+    NewPick {
+        pick_number: u32,
+        player_name: String,
+        /// The ESPN player ID, or `name|team_id|$price` without one.
+        identity: String,
+        previous_picks: usize,
+    },
+    NominationAppeared { player_name: String, bid: u32 },
+    NominationCleared { player_name: String },
+    /// A different player is on the block. `by_id` is whether the players
+    /// were told apart by ESPN ID rather than by name and position.
+    NominationReplaced { from: String, to: String, by_id: bool },
+    /// Same player, new bid or bidder.
+    BidChanged {
+        player_name: String,
+        from_bid: u32,
+        to_bid: u32,
+        from_bidder: Option<String>,
+        to_bidder: Option<String>,
+    },
+    /// Same player and bid; the nominating team arrived late.
+    NominatedByBackfilled { player_name: String, nominated_by: String },
+}
+
+impl std::fmt::Display for DiffDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiffDecision::NewPick { pick_number, player_name, identity, previous_picks } => write!(
+                f,
+                "pick {pick_number} {player_name} is new: {identity} is not among the {previous_picks} previous picks"
+            ),
+            DiffDecision::NominationAppeared { player_name, bid } => {
+                write!(f, "nomination appeared: {player_name} at ${bid}")
+            }
+            DiffDecision::NominationCleared { player_name } => {
+                write!(f, "nomination cleared: {player_name}")
+            }
+            DiffDecision::NominationReplaced { from, to, by_id } => {
+                let how = if *by_id { "ESPN IDs differ" } else { "name or position differs" };
+                write!(f, "nomination changed from {from} to {to}: {how}")
+            }
+            DiffDecision::BidChanged { player_name, from_bid, to_bid, from_bidder, to_bidder } => {
+                write!(f, "bid on {player_name} went ${from_bid}\u{2192}${to_bid}")?;
+                if from_bidder != to_bidder {
+                    let name = |b: &Option<String>| b.clone().unwrap_or_else(|| "nobody".into());
+                    write!(f, ", high bidder {}\u{2192}{}", name(from_bidder), name(to_bidder))?;
+                }
+                Ok(())
+            }
+            DiffDecision::NominatedByBackfilled { player_name, nominated_by } => {
+                write!(f, "{player_name} was nominated by {nominated_by}, filled in late")
+            }
+        }
+    }
+}
+
+impl StateDiff {
+    /// One line on what changed and why, e.g. `1 pick new, nomination
+    /// changed because bid went $5→$7`. `None` when nothing changed.
+    pub fn summary(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        match self.new_picks.len() {
+            0 => {}
+            1 => parts.push("1 pick new".to_string()),
+            n => parts.push(format!("{n} picks new")),
+        }
+        for decision in &self.decisions {
+            let because = match decision {
+                DiffDecision::NewPick { .. } => continue,
+                DiffDecision::NominationAppeared { player_name, .. } => {
+                    format!("nomination appeared ({player_name})")
+                }
+                DiffDecision::NominationCleared { .. } => "nomination cleared".to_string(),
+                DiffDecision::NominationReplaced { to, .. } => {
+                    format!("nomination changed because {to} replaced the player on the block")
+                }
+                DiffDecision::BidChanged { from_bid, to_bid, .. } if from_bid != to_bid => {
+                    format!("nomination changed because bid went ${from_bid}\u{2192}${to_bid}")
+                }
+                DiffDecision::BidChanged { .. } => {
+                    "nomination changed because the high bidder changed".to_string()
+                }
+                DiffDecision::NominatedByBackfilled { .. } => {
+                    "nomination changed because its nominating team arrived".to_string()
+                }
+            };
+            parts.push(because);
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
+
+/// The picks seen in a snapshot, by identity: the ESPN player ID, and the
+/// sale itself (player, team and price). A pick is known when either
+/// matches, so a pick scraped with an ID in one snapshot and without one in
+/// the next is still the same pick.
+#[derive(Default)]
+struct KnownPicks<'a> {
+    ids: HashSet<&'a str>,
+    sales: HashSet<(&'a str, &'a str, u32)>,
+}
+
+impl<'a> KnownPicks<'a> {
+    fn contains(&self, pick: &PickPayload) -> bool {
+        (!pick.player_id.is_empty() && self.ids.contains(pick.player_id.as_str()))
+            || self
+                .sales
+                .contains(&(pick.player_name.as_str(), pick.team_id.as_str(), pick.price))
+    }
+
+    fn insert(&mut self, pick: &'a PickPayload) {
+        if !pick.player_id.is_empty() {
+            self.ids.insert(&pick.player_id);
+        }
+        self.sales
+            .insert((&pick.player_name, &pick.team_id, pick.price));
+    }
+
+    /// How the pick is identified, for the diff explanation.
+    fn identity(pick: &PickPayload) -> String {
+        if pick.player_id.is_empty() {
+            format!("{}|{}|${}", pick.player_name, pick.team_id, pick.price)
+        } else {
+            pick.player_id.clone()
+        }
+    }
+}
+
+impl StateUpdatePayload {
+    /// Carry over the picks of `previous` that this snapshot no longer
+    /// lists, so a snapshot stored for the next diff knows every pick seen.
+    /// ESPN's virtualized pick list drops picks scrolled out of view and
+    /// sends them again later; without this they would come back as new.
+    pub fn remember_picks(&mut self, previous: &StateUpdatePayload) {
+        let mut known = KnownPicks::default();
+        for pick in &self.picks {
+            known.insert(pick);
+        }
+        let forgotten: Vec<PickPayload> = previous
+            .picks
+            .iter()
+            .filter(|pick| !known.contains(pick))
+            .cloned()
+            .collect();
+        self.picks.extend(forgotten);
+    }
 }
 
 /// Compute the differences between two consecutive state snapshots.
 ///
 /// If `previous` is `None`, all picks and the current nomination are treated as new.
 ///
-/// Pick detection uses **player identity** (ESPN player ID when available,
-/// falling back to player_name + team_name) rather than pick_number. This
-/// guards against unstable pick numbering from ESPN's virtualized pick list,
-/// where the pick counter label can increment before the new DOM entry appears,
-/// causing existing picks to be temporarily renumbered. Relying on pick_number
-/// would cause the real new pick to be missed when its number was already
-/// "claimed" by a renumbered existing pick in a previous snapshot.
+/// Pick detection uses **pick identity** (ESPN player ID, or the player,
+/// team and price of the sale; see `KnownPicks`) rather than pick_number
+/// or position in the list. This guards against unstable pick numbering
+/// from ESPN's virtualized pick list, where the pick counter label can
+/// increment before the new DOM entry appears, causing existing picks to be
+/// temporarily renumbered, and against picks listed out of order or twice.
+/// Relying on pick_number would cause the real new pick to be missed when
+/// its number was already "claimed" by a renumbered existing pick in a
+/// previous snapshot.
 pub fn compute_state_diff(
     previous: &Option<StateUpdatePayload>,
     current: &StateUpdatePayload,
@@ -481,47 +754,38 @@ pub fn compute_state_diff(
         new_nomination: None,
         nomination_cleared: false,
         bid_updated: false,
+        decisions: Vec::new(),
     };
 
-    // Build a set of player identities from the previous snapshot.
-    // Identity uses player_id (ESPN player ID) when non-empty, falling back
-    // to (player_name, team_id). This matches record_pick's dedup criteria,
-    // so compute_state_diff never emits a pick that record_pick would reject.
-    let prev_player_identities: std::collections::HashSet<String> = previous
-        .as_ref()
-        .map(|p| {
-            p.picks
-                .iter()
-                .map(|pk| {
-                    if !pk.player_id.is_empty() {
-                        pk.player_id.clone()
-                    } else {
-                        format!("{}|{}", pk.player_name, pk.team_id)
-                    }
-                })
-                .collect()
-        })
-        .unwrap_or_default();
+    // The picks of the previous snapshot. record_pick still dedups by
+    // player and team as the last guard against double-counting a player.
+    let mut known = KnownPicks::default();
+    let previous_picks = previous.as_ref().map_or(&[][..], |p| &p.picks[..]);
+    for pick in previous_picks {
+        known.insert(pick);
+    }
 
     for pick_payload in &current.picks {
-        let identity_key = if !pick_payload.player_id.is_empty() {
-            pick_payload.player_id.clone()
-        } else {
-            format!("{}|{}", pick_payload.player_name, pick_payload.team_id)
-        };
-        let dominated_by_identity = prev_player_identities.contains(&identity_key);
-
-        // A pick is new if its player identity was NOT in the previous snapshot.
-        // This is the sole check — pick_number is intentionally ignored because
-        // ESPN's virtualized pick list can renumber existing picks, and emitting
-        // already-known players would cause spurious DB writes and recalculations.
-        if !dominated_by_identity {
+        // A pick is new if its identity was NOT in the previous snapshot.
+        // This is the sole check — pick_number and list position are
+        // intentionally ignored because ESPN's virtualized pick list can
+        // renumber and reorder existing picks, and emitting already-known
+        // players would cause spurious DB writes and recalculations. Each
+        // new pick is remembered at once, so one listed twice is new once.
+        if !known.contains(pick_payload) {
+            known.insert(pick_payload);
+            diff.decisions.push(DiffDecision::NewPick {
+                pick_number: pick_payload.pick_number,
+                player_name: pick_payload.player_name.clone(),
+                identity: KnownPicks::identity(pick_payload),
+                previous_picks: previous_picks.len(),
+            });
             diff.new_picks.push(DraftPick {
                 pick_number: pick_payload.pick_number,
                 team_id: pick_payload.team_id.clone(),
                 team_name: pick_payload.team_name.clone(),
                 player_name: pick_payload.player_name.clone(),
-                position: pick_payload.position.clone(),
+                position: enriched_position_str(&pick_payload.position, &pick_payload.eligible_slots),
                 price: pick_payload.price,
                 espn_player_id: Some(pick_payload.player_id.clone()),
                 eligible_slots: pick_payload.eligible_slots.clone(),
@@ -535,8 +799,9 @@ pub fn compute_state_diff(
     // windowed/virtualized, meaning the extension may receive only a subset
     // of picks with pick_number values computed relative to that window.
     // Processing in pick_number order ensures record_pick assigns canonical
-    // sequential numbers correctly.
-    diff.new_picks.sort_unstable_by_key(|p| p.pick_number);
+    // sequential numbers correctly. The sort is stable so picks sharing a
+    // number keep the order ESPN listed them in.
+    diff.new_picks.sort_by_key(|p| p.pick_number);
 
     // Compare nominations
     let prev_nom = previous
@@ -552,17 +817,25 @@ pub fn compute_state_diff(
             // New nomination appeared
             diff.nomination_changed = true;
             diff.new_nomination = Some(nomination_from_payload(nom));
+            diff.decisions.push(DiffDecision::NominationAppeared {
+                player_name: nom.player_name.clone(),
+                bid: nom.current_bid,
+            });
         }
-        (Some(_), None) => {
+        (Some(prev), None) => {
             // Nomination was cleared (pick completed)
             diff.nomination_changed = true;
             diff.nomination_cleared = true;
+            diff.decisions.push(DiffDecision::NominationCleared {
+                player_name: prev.player_name.clone(),
+            });
         }
         (Some(prev), Some(curr)) => {
             // Detect nomination change: compare by player_id when available,
             // fall back to player_name (+ position) when IDs are empty
             // (DOM scraping doesn't provide player IDs).
-            let is_different_player = if !prev.player_id.is_empty() && !curr.player_id.is_empty() {
+            let by_id = !prev.player_id.is_empty() && !curr.player_id.is_empty();
+            let is_different_player = if by_id {
                 prev.player_id != curr.player_id
             } else {
                 prev.player_name != curr.player_name || prev.position != curr.position
@@ -572,6 +845,11 @@ pub fn compute_state_diff(
                 // Different player nominated
                 diff.nomination_changed = true;
                 diff.new_nomination = Some(nomination_from_payload(curr));
+                diff.decisions.push(DiffDecision::NominationReplaced {
+                    from: prev.player_name.clone(),
+                    to: curr.player_name.clone(),
+                    by_id,
+                });
             } else if prev.current_bid != curr.current_bid
                 || prev.current_bidder != curr.current_bidder
                 || (prev.nominated_by.is_empty() && !curr.nominated_by.is_empty())
@@ -585,6 +863,22 @@ pub fn compute_state_diff(
                 // we detect this as a bid update so the TUI picks it up.
                 diff.bid_updated = true;
                 diff.new_nomination = Some(nomination_from_payload(curr));
+                diff.decisions.push(
+                    if prev.current_bid != curr.current_bid || prev.current_bidder != curr.current_bidder {
+                        DiffDecision::BidChanged {
+                            player_name: curr.player_name.clone(),
+                            from_bid: prev.current_bid,
+                            to_bid: curr.current_bid,
+                            from_bidder: prev.current_bidder.clone(),
+                            to_bidder: curr.current_bidder.clone(),
+                        }
+                    } else {
+                        DiffDecision::NominatedByBackfilled {
+                            player_name: curr.player_name.clone(),
+                            nominated_by: curr.nominated_by.clone(),
+                        }
+                    },
+                );
             }
         }
     }
@@ -592,12 +886,13 @@ pub fn compute_state_diff(
     diff
 }
 
-/// Convert a NominationPayload into an ActiveNomination.
-fn nomination_from_payload(payload: &NominationPayload) -> ActiveNomination {
+/// Convert a NominationPayload into an ActiveNomination, resolving the
+/// position from ESPN eligible slots when the scraped string disagrees.
+pub fn nomination_from_payload(payload: &NominationPayload) -> ActiveNomination {
     ActiveNomination {
         player_name: payload.player_name.clone(),
         player_id: payload.player_id.clone(),
-        position: payload.position.clone(),
+        position: enriched_position_str(&payload.position, &payload.eligible_slots),
         nominated_by: payload.nominated_by.clone(),
         current_bid: payload.current_bid,
         current_bidder: payload.current_bidder.clone(),
@@ -823,6 +1118,58 @@ mod tests {
         assert!(state.team("nonexistent").is_none());
     }
 
+    #[test]
+    fn keepers_join_their_teams_at_their_salaries() {
+        let mut state = DraftState::new(260, &test_roster_config());
+        state.keepers = vec![
+            KeptPlayer { player_name: "Juan Soto".into(), team: "team 3".into(), position: "RF".into(), price: 38 },
+            KeptPlayer { player_name: "Nobody".into(), team: "Team 99".into(), position: "C".into(), price: 5 },
+        ];
+        assert_eq!(state.total_spent(), 43, "counted before the teams are known");
+
+        state.reconcile_budgets(&test_espn_budgets());
+        let team = state.team("3").unwrap();
+        assert!(team.roster.has_player("Juan Soto", None));
+        assert_eq!((team.budget_spent, team.budget_remaining), (38, 222));
+        assert_eq!(state.total_spent(), 38);
+        assert!(state.picks.is_empty(), "a keeper is not a pick");
+
+        // ESPN keeps reporting the full budget; the keeper stays charged.
+        let result = state.reconcile_budgets(&test_espn_budgets());
+        assert!(!result.budgets_changed);
+        state.apply_keepers();
+        assert_eq!(state.team("3").unwrap().budget_remaining, 222);
+
+        state.restore_from_picks(vec![]);
+        let team = state.team("3").unwrap();
+        assert!(team.roster.has_player("Juan Soto", None));
+        assert_eq!(team.budget_remaining, 222);
+    }
+
+    #[test]
+    fn keepers_entered_on_espn_are_charged_once() {
+        let mut state = DraftState::new(260, &test_roster_config());
+        state.keepers = vec![KeptPlayer {
+            player_name: "Juan Soto".into(),
+            team: "Team 3".into(),
+            position: "RF".into(),
+            price: 38,
+        }];
+        state.keepers_on_espn = true;
+
+        // ESPN's budget for team 3 already has Soto's salary off.
+        let mut budgets = test_espn_budgets();
+        budgets.iter_mut().find(|b| b.team_id == "3").unwrap().budget = 222;
+        state.reconcile_budgets(&budgets);
+        let team = state.team("3").unwrap();
+        assert!(team.roster.has_player("Juan Soto", None));
+        assert_eq!((team.budget_spent, team.budget_remaining), (38, 222));
+
+        let result = state.reconcile_budgets(&budgets);
+        assert!(!result.budgets_changed);
+        assert_eq!(state.team("3").unwrap().budget_remaining, 222);
+    }
+
     #[test]
     fn restore_from_picks() {
         let roster_config = test_roster_config();
@@ -1136,6 +1483,24 @@ mod tests {
         assert_eq!(diff.new_picks[3].player_name, "Player D");
     }
 
+    #[test]
+    fn diff_enriches_positions_from_eligible_slots() {
+        let mut pick = make_pick_payload(1, "team_1", "Player A", "UTIL", 20);
+        pick.eligible_slots = vec![1, 7, 12, 16, 17]; // 1B, CI, UTIL, BE, IL
+        let mut nom = make_nomination("p2", "Player B", 5, None);
+        nom.position = "DH".to_string();
+        nom.eligible_slots = vec![13, 15, 16, 17]; // P, RP, BE, IL
+        let current = StateUpdatePayload {
+            picks: vec![pick],
+            current_nomination: Some(nom),
+            ..Default::default()
+        };
+
+        let diff = compute_state_diff(&None, &current);
+        assert_eq!(diff.new_picks[0].position, "1B");
+        assert_eq!(diff.new_nomination.unwrap().position, "RP");
+    }
+
     #[test]
     fn diff_no_changes() {
         let state = StateUpdatePayload {
@@ -1265,6 +1630,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn diff_explains_each_decision() {
+        let previous = StateUpdatePayload {
+            picks: vec![make_pick_payload(1, "team_1", "Player A", "SP", 20)],
+            current_nomination: Some(make_nomination("p1", "Player B", 5, Some("team_2"))),
+            ..Default::default()
+        };
+        let current = StateUpdatePayload {
+            picks: vec![
+                make_pick_payload(1, "team_1", "Player A", "SP", 20),
+                make_pick_payload(2, "team_2", "Player C", "C", 8),
+            ],
+            current_nomination: Some(make_nomination("p1", "Player B", 7, Some("team_2"))),
+            ..Default::default()
+        };
+
+        let diff = compute_state_diff(&Some(previous.clone()), &current);
+        assert_eq!(
+            diff.decisions.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "pick 2 Player C is new: player_2 is not among the 1 previous picks",
+                "bid on Player B went $5\u{2192}$7",
+            ]
+        );
+        assert_eq!(
+            diff.summary().as_deref(),
+            Some("1 pick new, nomination changed because bid went $5\u{2192}$7")
+        );
+
+        let diff = compute_state_diff(&Some(previous.clone()), &previous);
+        assert!(diff.decisions.is_empty());
+        assert_eq!(diff.summary(), None);
+
+        let replaced = StateUpdatePayload {
+            current_nomination: Some(make_nomination("", "Player D", 1, None)),
+            ..previous.clone()
+        };
+        let diff = compute_state_diff(&Some(previous), &replaced);
+        assert_eq!(
+            diff.decisions[0].to_string(),
+            "nomination changed from Player B to Player D: name or position differs"
+        );
+    }
+
     #[test]
     fn diff_bid_updated_bidder_only() {
         let previous = StateUpdatePayload {
@@ -1507,6 +1916,112 @@ mod tests {
         assert_eq!(diff.new_picks[0].player_name, "Player C");
     }
 
+    #[test]
+    fn diff_pick_listed_twice_is_new_once() {
+        let current = StateUpdatePayload {
+            picks: vec![
+                make_pick_payload(1, "1", "Player A", "SP", 20),
+                make_pick_payload(2, "2", "Player B", "CF", 30),
+                make_pick_payload(1, "1", "Player A", "SP", 20),
+            ],
+            ..Default::default()
+        };
+        let diff = compute_state_diff(&None, &current);
+        let names: Vec<&str> = diff.new_picks.iter().map(|p| p.player_name.as_str()).collect();
+        assert_eq!(names, ["Player A", "Player B"]);
+    }
+
+    #[test]
+    fn diff_knows_a_pick_scraped_with_and_without_an_id() {
+        let mut without_id = make_pick_payload(1, "1", "Player A", "SP", 20);
+        without_id.player_id.clear();
+        let with_id = make_pick_payload(1, "1", "Player A", "SP", 20);
+        let snapshot = |pick: &PickPayload| StateUpdatePayload {
+            picks: vec![pick.clone()],
+            ..Default::default()
+        };
+
+        let diff = compute_state_diff(&Some(snapshot(&without_id)), &snapshot(&with_id));
+        assert!(diff.new_picks.is_empty());
+        let diff = compute_state_diff(&Some(snapshot(&with_id)), &snapshot(&without_id));
+        assert!(diff.new_picks.is_empty());
+
+        // Without IDs, the same player bought by the same team at another
+        // price is a different sale.
+        let mut repriced = without_id.clone();
+        repriced.price = 25;
+        let diff = compute_state_diff(&Some(snapshot(&without_id)), &snapshot(&repriced));
+        assert_eq!(diff.decisions[0].to_string(), "pick 1 Player A is new: Player A|1|$25 is not among the 1 previous picks");
+    }
+
+    #[test]
+    fn remembered_picks_are_not_new_when_scrolled_back_into_view() {
+        let picks: Vec<PickPayload> = (1..=4)
+            .map(|n| make_pick_payload(n, "1", &format!("Player {n}"), "SP", n))
+            .collect();
+        let window = |range: std::ops::Range<usize>| StateUpdatePayload {
+            picks: picks[range].to_vec(),
+            ..Default::default()
+        };
+
+        let mut previous = window(0..3);
+        // Scrolled: the first pick drops out as the fourth arrives.
+        let mut current = window(1..4);
+        assert_eq!(compute_state_diff(&Some(previous.clone()), &current).new_picks.len(), 1);
+        current.remember_picks(&previous);
+        assert_eq!(current.picks.len(), 4);
+        previous = current;
+
+        // Scrolled back: the first pick returns and is not new.
+        let diff = compute_state_diff(&Some(previous), &window(0..2));
+        assert!(diff.new_picks.is_empty());
+    }
+
+    #[test]
+    fn scrambled_pick_lists_never_double_count_a_player() {
+        let picks: Vec<PickPayload> = (1..=8)
+            .map(|n| {
+                let team = if n % 2 == 0 { "2" } else { "1" };
+                make_pick_payload(n, team, &format!("Player {n}"), "SP", n)
+            })
+            .collect();
+        // Each snapshot shows the picks so far in a different order, some
+        // windowed, some repeating a pick.
+        let orders: [&[usize]; 6] = [
+            &[1, 0],
+            &[2, 0, 1, 0],
+            &[3, 1, 2],
+            &[5, 4, 3, 0, 2, 1],
+            &[4, 6, 5],
+            &[7, 0, 6, 1, 5, 2, 4, 3, 7],
+        ];
+
+        let mut state = create_test_state();
+        let mut previous: Option<StateUpdatePayload> = None;
+        for order in orders {
+            let mut current = StateUpdatePayload {
+                picks: order.iter().map(|&i| picks[i].clone()).collect(),
+                ..Default::default()
+            };
+            for pick in compute_state_diff(&previous, &current).new_picks {
+                state.record_pick(pick);
+            }
+            if let Some(ref previous) = previous {
+                current.remember_picks(previous);
+            }
+            previous = Some(current);
+        }
+
+        assert_eq!(state.picks.len(), 8);
+        let prices: Vec<u32> = state.picks.iter().map(|p| p.price).collect();
+        assert_eq!(prices, [1, 2, 3, 4, 5, 6, 7, 8], "recorded in pick order");
+        for team_id in ["1", "2"] {
+            let team = state.team(team_id).unwrap();
+            assert_eq!(team.roster.filled_count(), 4);
+            assert_eq!(team.budget_spent, team.roster.slots.iter().filter_map(|s| s.player.as_ref()).map(|p| p.price).sum::<u32>());
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Tests: record_pick deduplication
     // -----------------------------------------------------------------------
@@ -1609,6 +2124,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn record_pick_same_name_at_another_position_not_deduped() {
+        let mut state = create_test_state();
+        let will_smith = |n: u32, position: &str| DraftPick {
+            pick_number: n,
+            team_id: "1".to_string(),
+            team_name: "Team 1".to_string(),
+            player_name: "Will Smith".to_string(),
+            position: position.to_string(),
+            price: 10,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+        state.record_pick(will_smith(1, "C"));
+        state.record_pick(will_smith(2, "RP"));
+        state.record_pick(will_smith(3, "C"));
+
+        assert_eq!(state.picks.len(), 2, "the catcher again is a duplicate");
+        assert_eq!(state.team("1").unwrap().budget_spent, 20);
+    }
+
     #[test]
     fn record_pick_dedup_by_espn_player_id() {
         let mut state = create_test_state();
@@ -1948,4 +2485,65 @@ mod tests {
         assert_eq!(team_beta.roster.filled_count(), 1);
         assert!(team_beta.roster.has_player("Aaron Judge", None));
     }
+
+    #[test]
+    fn json_round_trip_restores_a_working_state() {
+        let mut state = create_test_state();
+        state.record_pick(DraftPick {
+            pick_number: 1,
+            team_id: "1".to_string(),
+            team_name: "Team 1".to_string(),
+            player_name: "Mike Trout".to_string(),
+            position: "CF".to_string(),
+            price: 45,
+            espn_player_id: Some("espn_1".to_string()),
+            eligible_slots: vec![],
+            assigned_slot: None,
+        });
+        state.current_nomination = Some(ActiveNomination {
+            player_name: "Aaron Judge".to_string(),
+            player_id: "espn_2".to_string(),
+            position: "RF".to_string(),
+            nominated_by: "Team 2".to_string(),
+            current_bid: 12,
+            current_bidder: Some("Team 3".to_string()),
+            time_remaining: Some(20),
+            eligible_slots: vec![],
+        });
+
+        let json = state.to_json().unwrap();
+        let mut restored = DraftState::from_json(&json).unwrap();
+        // Compare as values: the roster config is a HashMap, so key order
+        // can differ between two serializations.
+        let as_value = |s: &str| serde_json::from_str::<serde_json::Value>(s).unwrap();
+        assert_eq!(as_value(&restored.to_json().unwrap()), as_value(&json));
+        assert_eq!(restored.my_team_idx, state.my_team_idx);
+        assert_eq!(
+            restored.current_nomination.as_ref().map(|n| n.current_bid),
+            Some(12)
+        );
+
+        // The salary cap and roster config come back too, so picks keep
+        // applying normally.
+        restored.record_pick(DraftPick {
+            pick_number: 2,
+            team_id: "2".to_string(),
+            team_name: "Team 2".to_string(),
+            player_name: "Aaron Judge".to_string(),
+            position: "RF".to_string(),
+            price: 30,
+            espn_player_id: Some("espn_2".to_string()),
+            eligible_slots: vec![],
+            assigned_slot: None,
+        });
+        let team2 = restored.team("2").unwrap();
+        assert_eq!(team2.budget_remaining, 230);
+        assert!(team2.roster.has_player("Aaron Judge", None));
+    }
+
+    #[test]
+    fn from_json_rejects_malformed_input() {
+        assert!(DraftState::from_json("{\"teams\": []}").is_err());
+        assert!(DraftState::from_json("not json").is_err());
+    }
 }
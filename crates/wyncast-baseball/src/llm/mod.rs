@@ -1,3 +1,4 @@
 // LLM prompt construction for baseball domain.
 
 pub mod prompt;
+pub mod style;
@@ -3,18 +3,31 @@
 // Constructs compact, structured prompts for the Claude API to analyze
 // draft nominations and plan future nominations. Each prompt includes
 // pre-computed numbers so the LLM focuses on trade-offs and context
-// rather than arithmetic.
+// rather than arithmetic. A snake draft has no nominations or prices: its
+// system prompt asks for picks, and planning asks whom to take from the
+// ranked board by the time my pick comes.
 
-use wyncast_core::config::LeagueConfig;
+use wyncast_core::config::{LeagueConfig, LlmConfig};
 use wyncast_core::nomination::NominationInfo;
 use wyncast_core::stats::{CategoryValues, StatDefinition, StatRegistry};
+use super::style;
+use crate::draft::nomination_order::nomination_turn;
+use crate::draft::nominations::{nomination_patterns, NominationStyle};
 use crate::draft::pick::Position;
 use crate::draft::roster::Roster;
+use crate::draft::snake::SnakeTurn;
 use crate::draft::state::DraftState;
 use crate::valuation::auction::InflationTracker;
+use crate::valuation::cliff;
+use crate::valuation::compare::PlayerComparison;
 use crate::valuation::scarcity::ScarcityEntry;
 use crate::valuation::zscore::{CategoryZScores, PlayerValuation};
 
+/// Characters (about four per token) the opponents' roster holes may take
+/// in the planning prompt. Past it, the teams with the least money left are
+/// listed with their budgets only.
+const OPPONENT_HOLES_BUDGET: usize = 1600;
+
 // ---------------------------------------------------------------------------
 // Supporting types
 // ---------------------------------------------------------------------------
@@ -82,15 +95,6 @@ fn format_scoring_type(scoring_type: &str) -> String {
     }
 }
 
-/// Count draftable roster slots (total minus IL).
-fn draftable_roster_size(roster: &std::collections::HashMap<String, usize>) -> usize {
-    roster
-        .iter()
-        .filter(|(k, _)| k.as_str() != "IL")
-        .map(|(_, v)| v)
-        .sum()
-}
-
 /// Format league context line from config for use in LLM prompts.
 pub fn format_league_context(league: &LeagueConfig, roster_config: Option<&std::collections::HashMap<String, usize>>) -> String {
     let scoring = format_scoring_type(&league.scoring_type);
@@ -98,15 +102,20 @@ pub fn format_league_context(league: &LeagueConfig, roster_config: Option<&std::
     let pitching = league.pitching_categories.categories.join(", ");
     let roster_info = match roster_config {
         Some(rc) => {
-            let roster_size = draftable_roster_size(rc);
+            let roster_size = crate::valuation::auction::roster_size(rc);
             format!("{}-player rosters", roster_size)
         }
         None => "roster pending (waiting for ESPN connection)".to_string(),
     };
+    let format = if league.is_snake() {
+        "snake draft".to_string()
+    } else {
+        format!("salary cap ${}", league.salary_cap)
+    };
     format!(
-        "{}-team {}, salary cap ${}, {}.\n\
+        "{}-team {}, {}, {}.\n\
          Categories: {} (hitting) | {} (pitching)",
-        league.num_teams, scoring, league.salary_cap, roster_info, batting, pitching
+        league.num_teams, scoring, format, roster_info, batting, pitching
     )
 }
 
@@ -134,6 +143,25 @@ pub fn system_prompt(league: &LeagueConfig, roster_config: Option<&std::collecti
     };
 
     let league_ctx = format_league_context(league, roster_config);
+    if league.is_snake() {
+        return format!(
+            "You are a fantasy baseball snake draft advisor.\n\
+             \n\
+             {}\n\
+             \n\
+             {}\n\
+             \n\
+             Teams pick in turn and the order reverses every round; there are no bids or prices. \
+             Players are ranked by value over replacement. When I ask about my pick, you will provide:\n\
+             1. PICK: The player to take\n\
+             2. BACKUP: Who to take if the pick is gone\n\
+             3. FIT: How the pick fits my specific roster and category needs\n\
+             4. WAIT OR TAKE: Which positions or players are likely to last until my following pick, and which will not\n\
+             \n\
+             Be concise and direct. Use the pre-computed numbers I provide \u{2014} do NOT do arithmetic. Focus on trade-offs and context the numbers don't capture.",
+            league_ctx, strategy_section
+        );
+    }
     format!(
         "You are a fantasy baseball auction draft advisor.\n\
          \n\
@@ -164,7 +192,8 @@ pub fn system_prompt(league: &LeagueConfig, roster_config: Option<&std::collecti
 ///
 /// The prompt includes all relevant context: the nominated player's profile,
 /// the user's roster state, category needs, positional scarcity, similar
-/// available players, and recent market comparisons.
+/// available players, and recent market comparisons. The closing question
+/// asks for the verbosity, language and TL;DR set in `llm`.
 #[allow(clippy::too_many_arguments)]
 pub fn build_nomination_analysis_prompt(
     player: &PlayerValuation,
@@ -177,6 +206,7 @@ pub fn build_nomination_analysis_prompt(
     inflation: &InflationTracker,
     budget: &BudgetContext,
     registry: &StatRegistry,
+    llm: &LlmConfig,
 ) -> String {
     let adjusted_value = inflation.adjust(player.dollar_value);
     let positions_str = player
@@ -249,6 +279,10 @@ pub fn build_nomination_analysis_prompt(
         "  Engine verdict: {} | Bid floor: ${} | Bid ceiling: ${}\n",
         budget.engine_verdict, budget.engine_bid_floor, budget.engine_bid_ceiling,
     ));
+    prompt.push_str(&format!(
+        "  Bids are whole dollars, raised ${} at a time; suggest only such bids.\n",
+        inflation.bid_increment,
+    ));
     if budget.engine_bid_ceiling > budget.max_safe_bid {
         prompt.push_str(&format!(
             "  WARNING: Bid ceiling (${}) exceeds max safe bid (${}). Budget-constrained.\n",
@@ -292,6 +326,16 @@ pub fn build_nomination_analysis_prompt(
         "## WHAT SHOULD I DO?\n\
          Give me your verdict, bid range, fit assessment, and strategy notes.",
     );
+    let style = style::instructions(llm);
+    if !style.is_empty() {
+        prompt.push('\n');
+        prompt.push_str(&style);
+    }
+    let clock = style::clock_instructions(nomination.time_remaining);
+    if !clock.is_empty() {
+        prompt.push('\n');
+        prompt.push_str(&clock);
+    }
 
     prompt
 }
@@ -303,7 +347,11 @@ pub fn build_nomination_analysis_prompt(
 /// Build a prompt for planning what player to nominate next.
 ///
 /// Includes the user's current roster, category strengths, positional scarcity,
-/// opponent budget snapshots, top available targets, and sell candidates.
+/// each opponent's budget and roster holes, who else needs each position,
+/// top available targets, and sell candidates.
+/// When the nomination order is known, the question changes with my turn:
+/// whom to nominate now when I am next, or what will still make sense by
+/// the time my turn comes when it is further off.
 #[allow(clippy::too_many_arguments)]
 pub fn build_nomination_planning_prompt(
     my_roster: &Roster,
@@ -334,6 +382,23 @@ pub fn build_nomination_planning_prompt(
         budget.max_safe_bid,
     ));
 
+    // Section 1b: NOMINATION TURN
+    let turns_away = nomination_turn(draft_state).and_then(|turn| {
+        let n = turn.my_turn_in?;
+        prompt.push_str("## NOMINATION TURN\n");
+        if n == 0 {
+            prompt.push_str("I nominate next.\n\n");
+        } else {
+            prompt.push_str(&format!(
+                "{} nominates next; my turn is {} nomination{} away.\n\n",
+                turn.next_team,
+                n,
+                if n == 1 { "" } else { "s" },
+            ));
+        }
+        Some(n)
+    });
+
     // Section 2: MY ROSTER state
     prompt.push_str("## MY ROSTER\n");
     prompt.push_str(&format_roster_for_prompt(my_roster));
@@ -356,26 +421,78 @@ pub fn build_nomination_planning_prompt(
     }
     prompt.push('\n');
 
-    // Section 5: OPPONENT BUDGET SNAPSHOT
-    prompt.push_str("## OPPONENT BUDGETS\n");
-    for team in &draft_state.teams {
-        if team.team_id == my_team_id {
-            continue;
-        }
+    // Section 5: OPPONENT BUDGETS AND ROSTER HOLES, richest first, within
+    // the holes' share of the prompt
+    prompt.push_str("## OPPONENT BUDGETS AND ROSTER HOLES\n");
+    let mut opponents: Vec<_> = draft_state.teams.iter().filter(|t| t.team_id != my_team_id).collect();
+    opponents.sort_by_key(|t| std::cmp::Reverse(t.budget_remaining));
+    let mut holes_budget = OPPONENT_HOLES_BUDGET;
+    for team in &opponents {
         prompt.push_str(&format!(
-            "  {} : ${} spent, ${} remaining, {} slots open\n",
+            "  {} : ${} spent, ${} remaining, {} slots open",
             team.team_name,
             team.budget_spent,
             team.budget_remaining,
             team.roster.empty_slots(),
         ));
+        let holes = format_open_positions(&open_positions(&team.roster));
+        if !holes.is_empty() && holes.len() <= holes_budget {
+            holes_budget -= holes.len();
+            prompt.push_str(&format!(" -- needs {holes}"));
+        }
+        prompt.push('\n');
     }
     prompt.push('\n');
 
-    // Section 6: TOP 10 AVAILABLE PLAYERS I WANT
+    // Section 5b: WHO ELSE NEEDS EACH POSITION
+    let mut demand: Vec<(Position, usize)> = Vec::new();
+    for team in &opponents {
+        for (position, _) in open_positions(&team.roster) {
+            match demand.iter_mut().find(|(p, _)| *p == position) {
+                Some((_, teams)) => *teams += 1,
+                None => demand.push((position, 1)),
+            }
+        }
+    }
+    if !demand.is_empty() {
+        demand.sort_by_key(|(p, _)| p.sort_order());
+        let line: Vec<String> = demand
+            .iter()
+            .map(|(p, teams)| format!("{} {}", p.display_str(), teams))
+            .collect();
+        prompt.push_str("## OPPONENTS WITH AN OPEN SLOT, BY POSITION\n");
+        prompt.push_str(&format!("  {}\n\n", line.join(", ")));
+    }
+
+    // Section 6: OPPONENT NOMINATION HABITS
+    let patterns = nomination_patterns(draft_state);
+    if patterns.iter().any(|p| p.nominated > 0) {
+        prompt.push_str("## OPPONENT NOMINATION HABITS (players sold so far)\n");
+        for (team, pattern) in draft_state.teams.iter().zip(&patterns) {
+            if team.team_id == my_team_id || pattern.nominated == 0 {
+                continue;
+            }
+            prompt.push_str(&format!(
+                "  {} : nominated {}, bought {} themselves, avg ${:.0}",
+                team.team_name,
+                pattern.nominated,
+                pattern.kept,
+                pattern.avg_price().unwrap_or(0.0),
+            ));
+            match pattern.style() {
+                NominationStyle::Targets => prompt.push_str(" -- nominates players they want"),
+                NominationStyle::PriceEnforcer => prompt.push_str(" -- price enforcer"),
+                NominationStyle::Mixed | NominationStyle::Unknown => {}
+            }
+            prompt.push('\n');
+        }
+        prompt.push('\n');
+    }
+
+    // Section 7: TOP 10 AVAILABLE PLAYERS I WANT
     let top_targets = find_top_targets(available_players, my_roster, inflation, 10);
-    prompt.push_str("## TOP 10 AVAILABLE TARGETS (sorted by adjusted value x roster fit)\n");
-    for (i, p) in top_targets.iter().enumerate() {
+    prompt.push_str("## TOP 10 AVAILABLE TARGETS (sorted by adjusted value x roster fit x cliff to the next best)\n");
+    for (i, (p, cliff)) in top_targets.iter().enumerate() {
         let adj = inflation.adjust(p.dollar_value);
         let positions_str = p
             .positions
@@ -389,18 +506,19 @@ pub fn build_nomination_planning_prompt(
             ""
         };
         prompt.push_str(&format!(
-            "  {}. {} ({}) - ${:.0} adj, VOR {:.1}{}\n",
+            "  {}. {} ({}) - ${:.0} adj, VOR {:.1}, cliff ${:.0}{}\n",
             i + 1,
             p.name,
             positions_str,
             adj,
             p.vor,
+            cliff,
             fills,
         ));
     }
     prompt.push('\n');
 
-    // Section 7: TOP 5 "NOMINATE TO SELL" CANDIDATES
+    // Section 8: TOP 5 "NOMINATE TO SELL" CANDIDATES
     let sell_candidates =
         find_nominate_to_sell_candidates(available_players, my_roster, draft_state, 5);
     if !sell_candidates.is_empty() {
@@ -418,12 +536,201 @@ pub fn build_nomination_planning_prompt(
         prompt.push('\n');
     }
 
-    // Section 8: Closing question
+    // Section 9: Closing question, by how far off my turn is
+    match turns_away {
+        Some(0) => prompt.push_str(
+            "## WHO SHOULD I NOMINATE NOW AND WHY?\n\
+             I am on the clock next. Give me your top pick to nominate, backup option, and reasoning.",
+        ),
+        Some(n) => prompt.push_str(&format!(
+            "## WHO SHOULD I NOMINATE WHEN MY TURN COMES?\n\
+             My turn is {n} nominations away, so some of these players will be sold first. \
+             Give me a top pick that is likely to still be there, a backup, and which of the \
+             targets above I should be ready to bid on before my turn.",
+        )),
+        None => prompt.push_str(
+            "## WHO SHOULD I NOMINATE AND WHY?\n\
+             Give me your top pick to nominate, backup option, and reasoning.",
+        ),
+    }
+
+    prompt
+}
+
+/// Players from the top of the board the snake planning prompt lists.
+const SNAKE_BOARD_SIZE: usize = 15;
+
+/// Build a prompt for planning my next pick in a snake draft.
+///
+/// Includes where the draft stands and when I pick next, my roster,
+/// category strengths, positional scarcity, the opponents' roster holes,
+/// and the top of the board (`available_players`, which a snake draft's
+/// valuation keeps in rank order). The question changes with my turn: whom
+/// to take now when I am on the clock, or whom to expect to still be there
+/// when my pick is further off.
+pub fn build_snake_pick_prompt(
+    my_roster: &Roster,
+    category_needs: &CategoryValues,
+    scarcity: &[ScarcityEntry],
+    available_players: &[PlayerValuation],
+    draft_state: &DraftState,
+    turn: Option<&SnakeTurn>,
+    registry: &StatRegistry,
+) -> String {
+    let my_team_id = draft_state.my_team().map(|t| t.team_id.as_str()).unwrap_or("");
+    let mut prompt = String::with_capacity(2048);
+
+    // Section 1: Header
+    prompt.push_str("## SNAKE PICK PLANNING\n");
+    match turn {
+        Some(turn) => prompt.push_str(&format!(
+            "Round {} of {}, pick {} (#{}) | {} on the clock | {} open slots\n\n",
+            turn.round,
+            turn.rounds,
+            turn.pick_in_round,
+            turn.overall,
+            turn.team,
+            my_roster.empty_slots(),
+        )),
+        None => prompt.push_str(&format!("{} open slots\n\n", my_roster.empty_slots())),
+    }
+    let turns_away = turn.and_then(SnakeTurn::my_turn_in);
+
+    // Section 2: MY ROSTER state
+    prompt.push_str("## MY ROSTER\n");
+    prompt.push_str(&format_roster_for_prompt(my_roster));
+    prompt.push('\n');
+
+    // Section 3: CATEGORY STRENGTHS
+    prompt.push_str("## CATEGORY STRENGTHS (need level, higher = more need)\n");
+    prompt.push_str(&format_category_needs(category_needs, registry));
+    prompt.push('\n');
+
+    // Section 4: POSITIONAL SCARCITY
+    prompt.push_str("## POSITIONAL SCARCITY\n");
+    for entry in scarcity {
+        prompt.push_str(&format!(
+            "  {} : {} ({} above replacement)\n",
+            entry.position.display_str(),
+            entry.urgency.label(),
+            entry.players_above_replacement,
+        ));
+    }
+    prompt.push('\n');
+
+    // Section 5: OPPONENT ROSTER HOLES, within their share of the prompt
+    prompt.push_str("## OPPONENT ROSTER HOLES\n");
+    let mut holes_budget = OPPONENT_HOLES_BUDGET;
+    for team in draft_state.teams.iter().filter(|t| t.team_id != my_team_id) {
+        prompt.push_str(&format!("  {} : {} slots open", team.team_name, team.roster.empty_slots()));
+        let holes = format_open_positions(&open_positions(&team.roster));
+        if !holes.is_empty() && holes.len() <= holes_budget {
+            holes_budget -= holes.len();
+            prompt.push_str(&format!(" -- needs {holes}"));
+        }
+        prompt.push('\n');
+    }
+    prompt.push('\n');
+
+    // Section 6: TOP OF THE BOARD
+    prompt.push_str(&format!("## TOP {SNAKE_BOARD_SIZE} AVAILABLE (ranked by VOR)\n"));
+    for (i, p) in available_players.iter().take(SNAKE_BOARD_SIZE).enumerate() {
+        let positions_str = p.positions.iter().map(|pos| pos.display_str()).collect::<Vec<_>>().join("/");
+        let fills = if p.positions.iter().any(|pos| my_roster.has_empty_slot(*pos)) {
+            " [FILLS SLOT]"
+        } else {
+            ""
+        };
+        prompt.push_str(&format!("  {}. {} ({}) - VOR {:.1}{}\n", i + 1, p.name, positions_str, p.vor, fills));
+    }
+    prompt.push('\n');
+
+    // Section 7: Closing question, by how far off my pick is
+    match turns_away {
+        Some(0) => prompt.push_str(
+            "## WHO SHOULD I PICK NOW AND WHY?\n\
+             I am on the clock. Give me your pick, a backup, and what is likely to last until my following pick.",
+        ),
+        Some(n) => prompt.push_str(&format!(
+            "## WHO SHOULD I PICK WHEN MY TURN COMES?\n\
+             My pick is {n} pick{} away, so about {n} of these players will be taken first. \
+             Give me a pick that is likely to still be there, a backup, and which players I should \
+             not expect to last.",
+            if n == 1 { "" } else { "s" },
+        )),
+        None => prompt.push_str(
+            "## WHO SHOULD I PICK AND WHY?\n\
+             Give me your pick, a backup, and reasoning.",
+        ),
+    }
+
+    prompt
+}
+
+/// Build the one-shot prompt asking which of two compared players to
+/// prefer, from the same numbers the compare overlay shows.
+pub fn build_comparison_prompt(
+    comparison: &PlayerComparison,
+    my_roster: Option<&Roster>,
+    category_needs: &CategoryValues,
+    registry: &StatRegistry,
+) -> String {
+    let [a, b] = &comparison.players;
+    let mut prompt = String::with_capacity(1536);
+
+    prompt.push_str(&format!("## PLAYER COMPARISON: {} vs {}\n", a.name, b.name));
+    for p in [a, b] {
+        let positions: Vec<&str> = p.positions.iter().map(|pos| pos.display_str()).collect();
+        prompt.push_str(&format!(
+            "  {} ({}, {}) : value ${:.0}, consensus ${:.0}, inflated ${:.0}, VOR {:.1}, \
+             total z {:+.2}, playing time {:.0}, {}open slot, need fit {:+.2}\n",
+            p.name,
+            p.team,
+            positions.join("/"),
+            p.dollar_value,
+            p.consensus_value,
+            p.adjusted_value,
+            p.vor,
+            p.total_zscore,
+            p.playing_time,
+            if p.open_slot { "" } else { "no " },
+            p.need_fit,
+        ));
+        for adjustment in &p.adjustments {
+            prompt.push_str(&format!("    adjusted {adjustment}\n"));
+        }
+    }
+    prompt.push('\n');
+
+    prompt.push_str(&format!("## CATEGORIES (projection / z-score)\n  Cat   {:<16}{}\n", a.name, b.name));
+    for line in &comparison.categories {
+        let precision = if line.rate { 3 } else { 0 };
+        prompt.push_str(&format!(
+            "  {:<4}  {:>6.*} / {:>+5.2}   {:>6.*} / {:>+5.2}\n",
+            line.category,
+            precision,
+            line.projections[0],
+            line.zscores[0],
+            precision,
+            line.projections[1],
+            line.zscores[1],
+        ));
+    }
+    prompt.push('\n');
+
+    if let Some(roster) = my_roster {
+        prompt.push_str("## MY ROSTER\n");
+        prompt.push_str(&format_roster_for_prompt(roster));
+        prompt.push('\n');
+    }
+    prompt.push_str("## CATEGORY STRENGTHS (need level, higher = more need)\n");
+    prompt.push_str(&format_category_needs(category_needs, registry));
+    prompt.push('\n');
+
     prompt.push_str(
-        "## WHO SHOULD I NOMINATE AND WHY?\n\
-         Give me your top pick to nominate, backup option, and reasoning.",
+        "## WHICH ONE SHOULD I TARGET?\n\
+         Pick one for my team in a few sentences: value, risk, and fit with my roster and needs.",
     );
-
     prompt
 }
 
@@ -561,10 +868,9 @@ pub fn find_market_comps(
 
     for pick in recent {
         // Check if position matches.
-        let pick_pos = Position::from_str_pos(&pick.position);
-        let position_matches = pick_pos
-            .map(|pp| player.positions.contains(&pp))
-            .unwrap_or(false);
+        let position_matches = Position::parse_list(&pick.position)
+            .iter()
+            .any(|pp| player.positions.contains(pp));
 
         if !position_matches {
             continue;
@@ -743,12 +1049,39 @@ pub fn find_nominate_to_sell_candidates(
     candidates
 }
 
+/// A roster's open starting slots by position, in roster order, with how
+/// many of each. Bench and reserve slots are left out: anyone fills them.
+fn open_positions(roster: &Roster) -> Vec<(Position, usize)> {
+    let mut open: Vec<(Position, usize)> = Vec::new();
+    for slot in roster.slots.iter().filter(|s| s.player.is_none()) {
+        if slot.position == Position::Bench || slot.position.is_reserve_slot() {
+            continue;
+        }
+        match open.iter_mut().find(|(p, _)| *p == slot.position) {
+            Some((_, n)) => *n += 1,
+            None => open.push((slot.position, 1)),
+        }
+    }
+    open
+}
+
+/// Compact open positions, e.g. "C, OF x3, RP x2".
+fn format_open_positions(open: &[(Position, usize)]) -> String {
+    open.iter()
+        .map(|(p, n)| match n {
+            1 => p.display_str().to_string(),
+            n => format!("{} x{}", p.display_str(), n),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Format the user's roster for prompt inclusion.
 pub fn format_roster_for_prompt(roster: &Roster) -> String {
     let mut s = String::new();
 
     for slot in &roster.slots {
-        if slot.position == Position::InjuredList {
+        if slot.position.is_reserve_slot() {
             continue;
         }
         let status = match &slot.player {
@@ -786,27 +1119,32 @@ pub fn format_category_needs(needs: &CategoryValues, registry: &StatRegistry) ->
 }
 
 /// Find top available players ranked by adjusted value, with a boost for
-/// players who fill empty roster slots.
+/// players who fill empty roster slots that grows with their cliff value.
+/// Returns each with their cliff value.
 fn find_top_targets<'a>(
     available_players: &'a [PlayerValuation],
     my_roster: &Roster,
     inflation: &InflationTracker,
     count: usize,
-) -> Vec<&'a PlayerValuation> {
-    let mut scored: Vec<(&PlayerValuation, f64)> = available_players
+) -> Vec<(&'a PlayerValuation, f64)> {
+    let cliffs = cliff::cliff_values(available_players);
+    let mut scored: Vec<(&PlayerValuation, f64, f64)> = available_players
         .iter()
-        .filter(|p| p.dollar_value > 1.0)
-        .map(|p| {
+        .zip(cliffs)
+        .filter(|(p, _)| p.dollar_value > 1.0)
+        .map(|(p, cliff)| {
             let adj = inflation.adjust(p.dollar_value);
             let fills_slot = p.positions.iter().any(|pos| my_roster.has_empty_slot(*pos));
-            let fit_bonus = if fills_slot { adj * 0.20 } else { 0.0 };
-            (p, adj + fit_bonus)
+            // Filling a slot matters more when the position falls off a
+            // cliff behind this player.
+            let fit_bonus = if fills_slot { adj * 0.20 + cliff * 0.5 } else { 0.0 };
+            (p, adj + fit_bonus, cliff)
         })
         .collect();
 
     scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
 
-    scored.into_iter().take(count).map(|(p, _)| p).collect()
+    scored.into_iter().take(count).map(|(p, _, cliff)| (p, cliff)).collect()
 }
 
 // ---------------------------------------------------------------------------
@@ -961,7 +1299,7 @@ mod tests {
             player.clone(),
             make_hitter("Similar CF", 8.0, vec![Position::CenterField], 38.0),
         ];
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let draft_state = create_test_draft_state_10();
         let inflation = InflationTracker::new();
 
@@ -976,6 +1314,7 @@ mod tests {
             &inflation,
             &test_budget_context(),
             &registry,
+            &LlmConfig::default(),
         );
 
         assert!(
@@ -1023,7 +1362,7 @@ mod tests {
         let roster = Roster::new(&test_roster_config());
         let needs = CategoryValues::uniform(registry.len(), 0.5);
         let available = vec![player.clone()];
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let draft_state = create_test_draft_state_10();
         let inflation = InflationTracker::new();
 
@@ -1038,6 +1377,7 @@ mod tests {
             &inflation,
             &test_budget_context(),
             &registry,
+            &LlmConfig::default(),
         );
 
         assert!(prompt.contains("$30"), "should contain dollar value");
@@ -1047,6 +1387,48 @@ mod tests {
 
     // ---- Nomination planning prompt tests ----
 
+    #[test]
+    fn comparison_prompt_lines_up_both_players() {
+        let registry = test_registry();
+        let roster = Roster::new(&test_roster_config());
+        let needs = CategoryValues::uniform(registry.len(), 0.5);
+        let a = make_hitter("H1", 10.0, vec![Position::FirstBase], 40.0);
+        let b = make_pitcher("P1", 7.0, PitcherType::SP, 30.0);
+        let comparison = crate::valuation::compare::compare(
+            &a,
+            &b,
+            Some(&roster),
+            &needs,
+            &InflationTracker::new(),
+            &registry,
+        );
+
+        let prompt = build_comparison_prompt(&comparison, Some(&roster), &needs, &registry);
+        assert!(prompt.contains("## PLAYER COMPARISON: H1 vs P1"));
+        assert!(prompt.contains("value $40"));
+        assert!(prompt.contains("## CATEGORIES"));
+        assert!(prompt.contains("HR"));
+        assert!(prompt.contains("ERA"));
+        assert!(prompt.contains("## MY ROSTER"));
+        assert!(prompt.contains("WHICH ONE SHOULD I TARGET?"));
+    }
+
+    #[test]
+    fn top_targets_favor_a_slot_with_a_cliff_behind_it() {
+        let roster = Roster::new(&test_roster_config());
+        let available = vec![
+            make_hitter("OF1", 6.0, vec![Position::LeftField], 22.0),
+            make_hitter("OF2", 5.0, vec![Position::LeftField], 21.0),
+            make_hitter("C1", 5.0, vec![Position::Catcher], 20.0),
+            make_hitter("C2", 1.0, vec![Position::Catcher], 6.0),
+            make_hitter("OF scrub", 0.0, vec![Position::LeftField], 1.0),
+        ];
+        let targets = find_top_targets(&available, &roster, &InflationTracker::new(), 10);
+        let rank = |name: &str| targets.iter().position(|(p, _)| p.name == name).unwrap();
+        assert!(rank("C1") < rank("OF1"), "$14 cliff behind C1, $1 behind OF1");
+        assert_eq!(targets[rank("C1")].1, 14.0);
+    }
+
     #[test]
     fn nomination_planning_prompt_contains_sections() {
         let registry = test_registry();
@@ -1057,7 +1439,7 @@ mod tests {
             make_hitter("H2", 8.0, vec![Position::SecondBase], 35.0),
             make_pitcher("P1", 7.0, PitcherType::SP, 30.0),
         ];
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let draft_state = create_test_draft_state_10();
         let inflation = InflationTracker::new();
 
@@ -1108,7 +1490,7 @@ mod tests {
         let roster = Roster::new(&test_roster_config());
         let needs = CategoryValues::uniform(registry.len(), 0.5);
         let available = vec![make_hitter("H1", 10.0, vec![Position::FirstBase], 40.0)];
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let mut draft_state = create_test_draft_state_10();
 
         // Record a pick so Team 2 has spent money
@@ -1146,6 +1528,186 @@ mod tests {
             prompt.contains("$210 remaining"),
             "should show opponent remaining budget"
         );
+        assert!(
+            !prompt.contains("NOMINATION HABITS"),
+            "no habits section before any nominator is known"
+        );
+    }
+
+    #[test]
+    fn planning_prompt_shows_opponent_roster_holes() {
+        let registry = test_registry();
+        let roster = Roster::new(&test_roster_config());
+        let needs = CategoryValues::uniform(registry.len(), 0.5);
+        let available = vec![make_hitter("H1", 10.0, vec![Position::FirstBase], 40.0)];
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
+        let mut draft_state = create_test_draft_state_10();
+        draft_state.record_pick(DraftPick {
+            pick_number: 1,
+            team_id: "2".into(),
+            team_name: "Team 2".into(),
+            player_name: "Catcher".into(),
+            position: "C".into(),
+            price: 50,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        });
+        let planning_prompt = |draft_state: &DraftState| {
+            build_nomination_planning_prompt(
+                &roster,
+                &needs,
+                &scarcity,
+                &available,
+                draft_state,
+                &InflationTracker::new(),
+                &test_budget_context(),
+                &registry,
+            )
+        };
+
+        let prompt = planning_prompt(&draft_state);
+        let lines: Vec<&str> = prompt.lines().collect();
+        let section = lines.iter().position(|l| *l == "## OPPONENT BUDGETS AND ROSTER HOLES").unwrap();
+        assert!(lines[section + 1].starts_with("  Team 3 : $0 spent, $260 remaining"));
+        assert!(lines[section + 1].ends_with("-- needs C, 1B, 2B, 3B, SS, LF, CF, RF, UTIL, SP x5, RP x6"));
+        // The team with the least money left comes last; BE and IL are not holes.
+        assert!(lines[section + 9].starts_with("  Team 2 : $50 spent, $210 remaining"));
+        assert!(lines[section + 9].ends_with("-- needs 1B, 2B, 3B, SS, LF, CF, RF, UTIL, SP x5, RP x6"));
+        assert!(prompt.contains("## OPPONENTS WITH AN OPEN SLOT, BY POSITION\n  C 8, 1B 9,"));
+
+        // Past the budget, the rest are listed with their money only.
+        let crowded = planning_prompt(&create_test_draft_state(40));
+        let opponents: Vec<&str> = crowded.lines().filter(|l| l.contains(" remaining, ")).collect();
+        assert_eq!(opponents.len(), 39);
+        assert!(opponents[0].contains("-- needs"));
+        assert!(!opponents[38].contains("-- needs"));
+    }
+
+    #[test]
+    fn planning_prompt_shows_opponent_nomination_habits() {
+        let registry = test_registry();
+        let roster = Roster::new(&test_roster_config());
+        let needs = CategoryValues::uniform(registry.len(), 0.5);
+        let available = vec![make_hitter("H1", 10.0, vec![Position::FirstBase], 40.0)];
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
+        let mut draft_state = create_test_draft_state_10();
+
+        // Team 3 nominates three players and sells every one of them.
+        for (i, name) in ["P1", "P2", "P3"].into_iter().enumerate() {
+            draft_state.nominators.insert(name.into(), "Team 3".into());
+            draft_state.record_pick(DraftPick {
+                pick_number: i as u32 + 1,
+                team_id: "2".into(),
+                team_name: "Team 2".into(),
+                player_name: name.into(),
+                position: "SP".into(),
+                price: 20,
+                espn_player_id: None,
+                eligible_slots: vec![],
+                assigned_slot: None,
+            });
+        }
+
+        let prompt = build_nomination_planning_prompt(
+            &roster,
+            &needs,
+            &scarcity,
+            &available,
+            &draft_state,
+            &InflationTracker::new(),
+            &test_budget_context(),
+            &registry,
+        );
+
+        assert!(prompt.contains("## OPPONENT NOMINATION HABITS"));
+        assert!(
+            prompt.contains("Team 3 : nominated 3, bought 0 themselves, avg $20 -- price enforcer"),
+            "got: {prompt}"
+        );
+    }
+
+    #[test]
+    fn planning_prompt_follows_my_nomination_turn() {
+        let registry = test_registry();
+        let roster = Roster::new(&test_roster_config());
+        let needs = CategoryValues::uniform(registry.len(), 0.5);
+        let available = vec![make_hitter("H1", 10.0, vec![Position::FirstBase], 40.0)];
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
+        let mut draft_state = create_test_draft_state_10();
+        let build = |draft_state: &DraftState| {
+            build_nomination_planning_prompt(
+                &roster,
+                &needs,
+                &scarcity,
+                &available,
+                draft_state,
+                &InflationTracker::new(),
+                &test_budget_context(),
+                &registry,
+            )
+        };
+
+        // My team tops the order, so I open the nominating.
+        let prompt = build(&draft_state);
+        assert!(prompt.contains("## NOMINATION TURN\nI nominate next."), "got: {prompt}");
+        assert!(prompt.contains("WHO SHOULD I NOMINATE NOW"));
+
+        // Once I have nominated, the other nine go first.
+        draft_state.nominators.insert("P1".into(), "Team 1".into());
+        draft_state.record_pick(DraftPick {
+            pick_number: 1,
+            team_id: "2".into(),
+            team_name: "Team 2".into(),
+            player_name: "P1".into(),
+            position: "SP".into(),
+            price: 20,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        });
+        let prompt = build(&draft_state);
+        assert!(prompt.contains("Team 2 nominates next; my turn is 9 nominations away."));
+        assert!(prompt.contains("WHO SHOULD I NOMINATE WHEN MY TURN COMES"));
+    }
+
+    #[test]
+    fn snake_prompts_ask_for_picks_from_the_board() {
+        let mut league = test_league_config();
+        league.draft_type = wyncast_core::config::DraftType::Snake;
+        let sp = system_prompt(&league, None, None);
+        assert!(sp.contains("snake draft advisor") && sp.contains("10-team H2H Most Categories, snake draft"));
+        assert!(!sp.contains("BID RANGE") && !sp.contains("salary cap"), "got: {sp}");
+
+        let registry = test_registry();
+        let roster = Roster::new(&test_roster_config());
+        let needs = CategoryValues::uniform(registry.len(), 0.5);
+        let available = vec![
+            make_hitter("Star", 10.0, vec![Position::FirstBase], 0.0),
+            make_pitcher("Ace", 8.0, PitcherType::SP, 0.0),
+        ];
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
+        let draft_state = create_test_draft_state_10();
+        let turn = SnakeTurn {
+            round: 2,
+            rounds: 26,
+            pick_in_round: 3,
+            overall: 13,
+            team: "Team 8".into(),
+            my_next_pick: Some(20),
+        };
+        let prompt =
+            build_snake_pick_prompt(&roster, &needs, &scarcity, &available, &draft_state, Some(&turn), &registry);
+        assert!(prompt.contains("Round 2 of 26, pick 3 (#13) | Team 8 on the clock"), "got: {prompt}");
+        assert!(prompt.contains("  1. Star (1B) - VOR 10.0 [FILLS SLOT]"));
+        assert!(prompt.contains("  2. Ace (SP) - VOR 8.0"));
+        assert!(prompt.contains("My pick is 7 picks away"));
+        assert!(!prompt.contains('$'), "no prices in a snake draft: {prompt}");
+
+        let now = SnakeTurn { my_next_pick: Some(13), ..turn };
+        let prompt =
+            build_snake_pick_prompt(&roster, &needs, &scarcity, &available, &draft_state, Some(&now), &registry);
+        assert!(prompt.contains("WHO SHOULD I PICK NOW"));
     }
 
     // ---- Market comp tests ----
@@ -1459,7 +2021,7 @@ mod tests {
         let roster = Roster::new(&test_roster_config());
         let needs = CategoryValues::uniform(registry.len(), 0.5);
         let available = vec![player.clone()];
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let draft_state = create_test_draft_state_10();
         let inflation = InflationTracker::new();
         let budget = test_budget_context();
@@ -1475,6 +2037,7 @@ mod tests {
             &inflation,
             &budget,
             &registry,
+            &LlmConfig::default(),
         );
 
         assert!(prompt.contains("## BUDGET CONSTRAINTS"), "should have budget constraints section");
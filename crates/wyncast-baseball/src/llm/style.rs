@@ -0,0 +1,224 @@
+// Analysis style: the `[llm]` verbosity, language and TL;DR settings.
+//
+// The nomination analysis prompt asks for the style, and the finished reply
+// is trimmed to it, since the model does not always keep to a length: a
+// terse reply is cut to its first few bullets, and a TL;DR line is moved to
+// the top wherever the model put it. Streamed tokens show as they arrive;
+// the trimmed text replaces them once the reply is complete.
+//
+// The analysis also keeps to the auction clock: with under
+// `SHORT_ANSWER_UNDER_SECS` left it asks for a short answer, and a reply
+// still streaming when the clock runs out is finalized where it stands,
+// cut back to its last whole sentence and closed with the engine's verdict.
+
+use wyncast_core::config::{AnalysisVerbosity, LlmConfig};
+
+/// Lines a terse analysis keeps, TL;DR aside.
+pub const TERSE_LINES: usize = 5;
+
+/// With less than this many seconds on the auction clock, the analysis
+/// asks for a short answer.
+pub const SHORT_ANSWER_UNDER_SECS: u32 = 20;
+
+/// Token budget of a short answer.
+pub const SHORT_ANSWER_MAX_TOKENS: u32 = 300;
+
+/// Whether `time_remaining` seconds on the clock calls for a short answer.
+pub fn wants_short_answer(time_remaining: Option<u32>) -> bool {
+    time_remaining.is_some_and(|secs| secs < SHORT_ANSWER_UNDER_SECS)
+}
+
+/// Prompt lines asking for a short answer when the clock is short; empty
+/// otherwise.
+pub fn clock_instructions(time_remaining: Option<u32>) -> String {
+    match time_remaining {
+        Some(secs) if wants_short_answer(time_remaining) => format!(
+            "Only {secs} seconds remain on the auction clock. Start with one line \
+             \"VERDICT: <verdict>, max $<bid>\", then at most two short bullets."
+        ),
+        _ => String::new(),
+    }
+}
+
+/// Prompt lines asking for the configured style; empty for English prose
+/// without a TL;DR.
+pub fn instructions(llm: &LlmConfig) -> String {
+    let mut lines = Vec::new();
+    if llm.analysis_tldr {
+        lines.push(
+            "Start with one line beginning \"TL;DR:\" that gives the verdict and walk-away price."
+                .to_string(),
+        );
+    }
+    if llm.analysis_verbosity == AnalysisVerbosity::Terse {
+        lines.push(format!(
+            "Answer in at most {TERSE_LINES} bullets of one short line each, starting with \"- \". \
+             No headings and no paragraphs: I have seconds to read this while bidding."
+        ));
+    }
+    let language = llm.analysis_language.trim();
+    if !language.is_empty() && !language.eq_ignore_ascii_case("english") {
+        lines.push(format!("Write the analysis in {language}."));
+    }
+    lines.join("\n")
+}
+
+/// `text` cut to the configured style: the TL;DR line first when asked
+/// for, and in terse mode only the first `TERSE_LINES` bullets (or lines,
+/// if the reply has no bullets).
+pub fn trim_analysis(text: &str, llm: &LlmConfig) -> String {
+    let terse = llm.analysis_verbosity == AnalysisVerbosity::Terse;
+    if !terse && !llm.analysis_tldr {
+        return text.to_string();
+    }
+
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let tldr = if llm.analysis_tldr {
+        lines.iter().position(|line| is_tldr(line))
+    } else {
+        None
+    };
+    let rest = lines
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| Some(i) != tldr)
+        .map(|(_, line)| *line);
+
+    let mut out: Vec<&str> = tldr.map(|i| lines[i].trim()).into_iter().collect();
+    if terse {
+        let body: Vec<&str> = rest.filter(|line| !line.trim().is_empty()).collect();
+        let bullets: Vec<&str> = body.iter().copied().filter(|line| is_bullet(line)).collect();
+        let kept = if bullets.is_empty() { body } else { bullets };
+        out.extend(kept.into_iter().take(TERSE_LINES));
+    } else {
+        out.extend(rest);
+    }
+    out.join("\n").trim().to_string()
+}
+
+/// A reply cut off by the auction clock, made actionable: a partial last
+/// line is cut back to its last whole sentence (or dropped), and unless the
+/// reply already gave a verdict, the engine's `verdict` and `max_bid` close
+/// it.
+pub fn finalize_at_deadline(text: &str, verdict: &str, max_bid: u32) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if !text.ends_with('\n') {
+        if let Some(last) = lines.pop() {
+            let end = last.rfind(['.', '!', '?']).map(|i| i + 1);
+            if let Some(end) = end {
+                lines.push(&last[..end]);
+            }
+        }
+    }
+    let mut out = lines.join("\n").trim_end().to_string();
+    if !lines.iter().any(|line| is_verdict(line)) {
+        if !out.is_empty() {
+            out.push_str("\n\n");
+        }
+        out.push_str(&format!("VERDICT (clock expiring): {verdict}, max ${max_bid}"));
+    }
+    out
+}
+
+/// A line giving the verdict, like "VERDICT: PASS" or a TL;DR.
+fn is_verdict(line: &str) -> bool {
+    let line = line.trim_start_matches(['*', '#', '-', ' ', '_']).to_ascii_lowercase();
+    line.starts_with("verdict") || line.starts_with("tl;dr")
+}
+
+/// A `TL;DR` line, markdown emphasis or bullet aside.
+fn is_tldr(line: &str) -> bool {
+    line.trim_start_matches(['*', '#', '-', ' ', '_'])
+        .to_ascii_lowercase()
+        .starts_with("tl;dr")
+}
+
+/// A markdown bullet or numbered item.
+fn is_bullet(line: &str) -> bool {
+    let line = line.trim_start();
+    if ["- ", "* ", "• "].iter().any(|mark| line.starts_with(mark)) {
+        return true;
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    digits > 0 && (line[digits..].starts_with(". ") || line[digits..].starts_with(") "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn llm(verbosity: AnalysisVerbosity, language: &str, tldr: bool) -> LlmConfig {
+        LlmConfig {
+            analysis_verbosity: verbosity,
+            analysis_language: language.into(),
+            analysis_tldr: tldr,
+            ..LlmConfig::default()
+        }
+    }
+
+    #[test]
+    fn a_short_clock_asks_for_a_short_answer() {
+        assert!(clock_instructions(None).is_empty());
+        assert!(clock_instructions(Some(20)).is_empty());
+        let prompt = clock_instructions(Some(12));
+        assert!(prompt.starts_with("Only 12 seconds remain"), "{prompt}");
+        assert!(prompt.contains("VERDICT:"));
+    }
+
+    #[test]
+    fn a_reply_cut_off_by_the_clock_ends_with_a_verdict() {
+        let cut = "Strong bat for the price.\n- Fills 1B. Power is real and the park hel";
+        assert_eq!(
+            finalize_at_deadline(cut, "STRONG TARGET", 24),
+            "Strong bat for the price.\n- Fills 1B.\n\nVERDICT (clock expiring): STRONG TARGET, max $24"
+        );
+        // A verdict already given is kept as the last word.
+        let cut = "**VERDICT:** PASS, max $8\n- Too rich for the bud";
+        assert_eq!(finalize_at_deadline(cut, "CONDITIONAL", 12), "**VERDICT:** PASS, max $8");
+        // Nothing streamed yet: the engine's verdict alone.
+        assert_eq!(finalize_at_deadline("", "PASS", 3), "VERDICT (clock expiring): PASS, max $3");
+    }
+
+    #[test]
+    fn default_style_adds_nothing_and_keeps_the_reply() {
+        let config = LlmConfig::default();
+        assert!(instructions(&config).is_empty());
+        let text = "VERDICT: PASS\n\nToo rich for the budget.";
+        assert_eq!(trim_analysis(text, &config), text);
+    }
+
+    #[test]
+    fn instructions_ask_for_each_setting() {
+        let prompt = instructions(&llm(AnalysisVerbosity::Terse, "Spanish", true));
+        assert!(prompt.contains("TL;DR:"));
+        assert!(prompt.contains("at most 5 bullets"));
+        assert!(prompt.contains("Write the analysis in Spanish."));
+    }
+
+    #[test]
+    fn terse_keeps_the_first_bullets_after_the_tldr() {
+        let text = "Here is my take.\n\n- Verdict: BID TO WIN\n- Range: $20-$26\n\nSome prose.\n\
+                    - Fit: fills SS\n1. Strategy: push Team 3\n2) Watch the budget\n- Extra\n\
+                    **TL;DR:** Bid to $26.";
+        let trimmed = trim_analysis(text, &llm(AnalysisVerbosity::Terse, "English", true));
+        assert_eq!(
+            trimmed,
+            "**TL;DR:** Bid to $26.\n- Verdict: BID TO WIN\n- Range: $20-$26\n- Fit: fills SS\n\
+             1. Strategy: push Team 3\n2) Watch the budget"
+        );
+    }
+
+    #[test]
+    fn terse_without_bullets_keeps_the_first_lines() {
+        let text = "One.\n\nTwo.\nThree.\nFour.\nFive.\nSix.";
+        let trimmed = trim_analysis(text, &llm(AnalysisVerbosity::Terse, "English", false));
+        assert_eq!(trimmed, "One.\nTwo.\nThree.\nFour.\nFive.");
+    }
+
+    #[test]
+    fn prose_with_tldr_only_moves_the_tldr_up() {
+        let text = "VERDICT: PASS\n\nToo rich.\nTL;DR: pass.";
+        let trimmed = trim_analysis(text, &llm(AnalysisVerbosity::Prose, "English", true));
+        assert_eq!(trimmed, "TL;DR: pass.\nVERDICT: PASS\n\nToo rich.");
+    }
+}
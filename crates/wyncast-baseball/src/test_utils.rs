@@ -8,7 +8,7 @@ use std::collections::HashMap;
 use wyncast_core::config::*;
 use wyncast_core::stats::{CategoryValues, StatRegistry};
 
-use crate::draft::pick::Position;
+use crate::draft::pick::{DraftPick, Position};
 use crate::draft::state::{DraftState, TeamBudgetPayload};
 use crate::valuation::projections::PitcherType;
 use crate::valuation::zscore::{CategoryZScores, PlayerValuation, ProjectionData};
@@ -51,6 +51,14 @@ pub fn test_league_config() -> LeagueConfig {
             gs_per_week: 7,
         },
         teams: HashMap::new(),
+        player_pool: Default::default(),
+        min_bid: 1,
+        keepers: Default::default(),
+        nomination_order: vec![],
+        roster: HashMap::new(),
+        bid_increment: 1,
+        draft_type: Default::default(),
+        points: Default::default(),
     }
 }
 
@@ -77,6 +85,7 @@ pub fn test_roster_config() -> HashMap<String, usize> {
 pub fn test_strategy_config() -> StrategyConfig {
     StrategyConfig {
         hitting_budget_fraction: 0.65,
+        adaptive_budget_split: false,
         weights: CategoryWeights::from_pairs([
             ("R", 1.0),
             ("HR", 1.0),
@@ -92,6 +101,8 @@ pub fn test_strategy_config() -> StrategyConfig {
             ("WHIP", 1.0),
         ]),
         strategy_overview: None,
+        flexibility: FlexibilityConfig::default(),
+        valuation: ValuationRulesConfig::default(),
         pool: PoolConfig {
             min_pa: 300,
             min_ip_sp: 80.0,
@@ -107,6 +118,10 @@ pub fn test_strategy_config() -> StrategyConfig {
             planning_max_tokens: 2048,
             analysis_trigger: "nomination".into(),
             prefire_planning: true,
+            analysis_verbosity: Default::default(),
+            analysis_language: "English".into(),
+            analysis_tldr: false,
+            prefire: Default::default(),
         },
     }
 }
@@ -119,6 +134,12 @@ pub fn test_config() -> Config {
         credentials: CredentialsConfig::default(),
         ws_port: 9001,
         data_paths: DataPaths::default(),
+        logging: LoggingConfig::default(),
+        notify: Default::default(),
+        discord_bot: Default::default(),
+        accessibility: Default::default(),
+        display: Default::default(),
+        backup: Default::default(),
     }
 }
 
@@ -297,6 +318,8 @@ impl TestPlayer {
                     ("g".into(), 30.0),
                     ("gs".into(), 30.0),
                 ]),
+                provenance: HashMap::new(),
+                adjustments: Vec::new(),
             };
             (proj, CategoryZScores::pitcher(zv, total))
         } else {
@@ -312,6 +335,8 @@ impl TestPlayer {
                     ("sb".into(), 10.0),
                     ("avg".into(), 0.273),
                 ]),
+                provenance: HashMap::new(),
+                adjustments: Vec::new(),
             };
             (proj, CategoryZScores::hitter(zv, total))
         };
@@ -330,10 +355,72 @@ impl TestPlayer {
             initial_vor: self.vor,
             best_position: self.positions.first().copied(),
             dollar_value: self.dollar_value,
+            flex_bonus: 0.0,
+            consensus_value: self.dollar_value,
+            custom_stats: Vec::new(),
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// DraftPick builder
+// ---------------------------------------------------------------------------
+
+/// Start building a `DraftPick` of `player`: pick number 0, bought for $1
+/// at 1B by team "1" ("Team 1") until told otherwise.
+pub fn test_pick(player: &str) -> TestPick {
+    TestPick {
+        pick: DraftPick {
+            pick_number: 0,
+            team_id: "1".into(),
+            team_name: "Team 1".into(),
+            player_name: player.into(),
+            position: "1B".into(),
+            price: 1,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        },
+    }
+}
+
+/// Builder for `DraftPick` test fixtures; see [`test_pick`].
+pub struct TestPick {
+    pick: DraftPick,
+}
+
+impl TestPick {
+    /// Set the pick number.
+    pub fn number(mut self, n: u32) -> Self {
+        self.pick.pick_number = n;
+        self
+    }
+
+    /// Set the buying team by ID; its name is "Team {id}".
+    pub fn team(mut self, team_id: impl std::fmt::Display) -> Self {
+        self.pick.team_id = team_id.to_string();
+        self.pick.team_name = format!("Team {team_id}");
+        self
+    }
+
+    /// Set the position string.
+    pub fn position(mut self, position: &str) -> Self {
+        self.pick.position = position.into();
+        self
+    }
+
+    /// Set the price.
+    pub fn price(mut self, price: u32) -> Self {
+        self.pick.price = price;
+        self
+    }
+
+    /// Build the `DraftPick`.
+    pub fn build(self) -> DraftPick {
+        self.pick
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Stat-based PlayerValuation constructors
 // ---------------------------------------------------------------------------
@@ -370,6 +457,8 @@ pub fn make_hitter(
                 ("sb".into(), sb as f64),
                 ("avg".into(), avg),
             ]),
+            provenance: HashMap::new(),
+            adjustments: Vec::new(),
         },
         total_zscore: 0.0,
         category_zscores: CategoryZScores::zeros_hitter(test_registry().len()),
@@ -377,6 +466,9 @@ pub fn make_hitter(
         initial_vor: 0.0,
         best_position: None,
         dollar_value: 0.0,
+        flex_bonus: 0.0,
+        consensus_value: 0.0,
+        custom_stats: Vec::new(),
     }
 }
 
@@ -423,6 +515,8 @@ pub fn make_pitcher(
                     },
                 ),
             ]),
+            provenance: HashMap::new(),
+            adjustments: Vec::new(),
         },
         total_zscore: 0.0,
         category_zscores: CategoryZScores::zeros_pitcher(test_registry().len()),
@@ -430,5 +524,8 @@ pub fn make_pitcher(
         initial_vor: 0.0,
         best_position: None,
         dollar_value: 0.0,
+        flex_bonus: 0.0,
+        consensus_value: 0.0,
+        custom_stats: Vec::new(),
     }
 }
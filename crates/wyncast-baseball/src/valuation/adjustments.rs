@@ -0,0 +1,295 @@
+// Projection adjustments: park factors, league factors and manual haircuts.
+//
+// Simple multipliers read from a CSV and applied to the projections before
+// valuation, like the player pool restriction. A row targets one player (by
+// name) or every player on an MLB team (by abbreviation), and one stat or,
+// with the stat left blank, every counting stat (a playing-time haircut that
+// leaves the rates alone). Rows that reach the same player and stat multiply.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use wyncast_core::config::DataPaths;
+
+use super::player_pool::canonical_team;
+use super::projections::{resolve_data_path, AllProjections, ProjectionError};
+
+/// Hitter stats a row can name; every one but `avg` is a counting stat.
+const HITTER_STATS: [&str; 9] = ["pa", "ab", "h", "hr", "r", "rbi", "bb", "sb", "avg"];
+
+/// Pitcher stats a row can name; `era` and `whip` are the rates.
+const PITCHER_STATS: [&str; 9] = ["ip", "k", "w", "sv", "hd", "era", "whip", "g", "gs"];
+
+/// Stats left alone by a row with no stat.
+const RATE_STATS: [&str; 3] = ["avg", "era", "whip"];
+
+/// One row of the adjustments CSV.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Adjustment {
+    /// A player's name or an MLB team abbreviation.
+    pub target: String,
+    /// The stat to scale (lowercase), or `None` for every counting stat.
+    pub stat: Option<String>,
+    pub factor: f64,
+    /// Why, e.g. "Coors" or "injury haircut". May be empty.
+    pub note: String,
+}
+
+/// A factor that was applied to one of a player's projected stats, as shown
+/// in the player detail.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppliedAdjustment {
+    pub stat: String,
+    pub factor: f64,
+    /// The row's target and note, e.g. "COL: Coors".
+    pub reason: String,
+}
+
+impl Adjustment {
+    /// The reason shown next to the factor.
+    fn reason(&self) -> String {
+        if self.note.is_empty() {
+            self.target.clone()
+        } else {
+            format!("{}: {}", self.target, self.note)
+        }
+    }
+
+    /// Whether the row reaches a player with this name and team.
+    fn targets(&self, name: &str, team: &str) -> bool {
+        self.target.eq_ignore_ascii_case(name)
+            || (!team.is_empty() && canonical_team(&self.target) == canonical_team(team))
+    }
+
+    /// The stats among `stats` this row scales.
+    fn stats<'a>(&'a self, stats: &'a [&'static str]) -> impl Iterator<Item = &'static str> + 'a {
+        stats.iter().copied().filter(move |s| match &self.stat {
+            Some(stat) => stat == s,
+            None => !RATE_STATS.contains(s),
+        })
+    }
+}
+
+/// Adjustments CSV row.
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+struct RawAdjustment {
+    Target: String,
+    #[serde(default)]
+    Stat: String,
+    Factor: f64,
+    #[serde(default)]
+    Note: String,
+}
+
+fn load_from_reader<R: Read>(rdr: R) -> Result<Vec<Adjustment>, csv::Error> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(rdr);
+    let mut adjustments = Vec::new();
+    for result in reader.deserialize::<RawAdjustment>() {
+        let raw = match result {
+            Ok(raw) => raw,
+            Err(e) => {
+                warn!("skipping malformed adjustment row: {}", e);
+                continue;
+            }
+        };
+        let target = raw.Target.trim();
+        let stat = raw.Stat.trim().to_lowercase();
+        if target.is_empty() {
+            warn!("skipping adjustment row with no target");
+            continue;
+        }
+        if !raw.Factor.is_finite() || raw.Factor <= 0.0 {
+            warn!("skipping adjustment for '{}': factor must be positive", target);
+            continue;
+        }
+        if !stat.is_empty() && !HITTER_STATS.contains(&stat.as_str()) && !PITCHER_STATS.contains(&stat.as_str()) {
+            warn!("skipping adjustment for '{}': unknown stat '{}'", target, raw.Stat.trim());
+            continue;
+        }
+        adjustments.push(Adjustment {
+            target: target.to_string(),
+            stat: (!stat.is_empty()).then_some(stat),
+            factor: raw.Factor,
+            note: raw.Note.trim().to_string(),
+        });
+    }
+    Ok(adjustments)
+}
+
+/// Load the adjustments CSV configured under `[data_paths]`, or nothing when
+/// none is configured.
+pub fn load(paths: &DataPaths) -> Result<Vec<Adjustment>, ProjectionError> {
+    let Some(raw) = &paths.adjustments else {
+        return Ok(Vec::new());
+    };
+    let path = resolve_data_path(raw);
+    let file = std::fs::File::open(&path).map_err(|e| ProjectionError::Io {
+        path: path.display().to_string(),
+        source: e,
+    })?;
+    load_from_reader(file).map_err(|e| ProjectionError::Csv {
+        path: path.display().to_string(),
+        source: e,
+    })
+}
+
+/// Scale a whole-number stat, which projections keep rounded.
+fn scale_count(value: &mut u32, factor: f64) {
+    *value = (f64::from(*value) * factor).round() as u32;
+}
+
+/// Apply every adjustment to `projections` in place and return what was
+/// applied to each player, by name.
+pub fn apply(
+    projections: &mut AllProjections,
+    adjustments: &[Adjustment],
+) -> HashMap<String, Vec<AppliedAdjustment>> {
+    let mut applied: HashMap<String, Vec<AppliedAdjustment>> = HashMap::new();
+    if adjustments.is_empty() {
+        return applied;
+    }
+    let mut record = |name: &str, stat: &str, adjustment: &Adjustment| {
+        applied.entry(name.to_string()).or_default().push(AppliedAdjustment {
+            stat: stat.to_string(),
+            factor: adjustment.factor,
+            reason: adjustment.reason(),
+        });
+    };
+
+    for h in &mut projections.hitters {
+        for adjustment in adjustments.iter().filter(|a| a.targets(&h.name, &h.team)) {
+            let factor = adjustment.factor;
+            for stat in adjustment.stats(&HITTER_STATS) {
+                match stat {
+                    "pa" => scale_count(&mut h.pa, factor),
+                    "ab" => scale_count(&mut h.ab, factor),
+                    "h" => scale_count(&mut h.h, factor),
+                    "hr" => scale_count(&mut h.hr, factor),
+                    "r" => scale_count(&mut h.r, factor),
+                    "rbi" => scale_count(&mut h.rbi, factor),
+                    "bb" => scale_count(&mut h.bb, factor),
+                    "sb" => scale_count(&mut h.sb, factor),
+                    "avg" => h.avg *= factor,
+                    _ => continue,
+                }
+                record(&h.name, stat, adjustment);
+            }
+        }
+    }
+    for p in &mut projections.pitchers {
+        for adjustment in adjustments.iter().filter(|a| a.targets(&p.name, &p.team)) {
+            let factor = adjustment.factor;
+            for stat in adjustment.stats(&PITCHER_STATS) {
+                match stat {
+                    "ip" => p.ip *= factor,
+                    "k" => scale_count(&mut p.k, factor),
+                    "w" => scale_count(&mut p.w, factor),
+                    "sv" => scale_count(&mut p.sv, factor),
+                    "hd" => scale_count(&mut p.hd, factor),
+                    "era" => p.era *= factor,
+                    "whip" => p.whip *= factor,
+                    "g" => scale_count(&mut p.g, factor),
+                    "gs" => scale_count(&mut p.gs, factor),
+                    _ => continue,
+                }
+                record(&p.name, stat, adjustment);
+            }
+        }
+    }
+    applied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::valuation::projections::{HitterProjection, PitcherProjection, PitcherType, ProjectionSource};
+
+    fn hitter(name: &str, team: &str) -> HitterProjection {
+        HitterProjection {
+            name: name.into(),
+            team: team.into(),
+            pa: 600,
+            ab: 540,
+            h: 150,
+            hr: 25,
+            r: 90,
+            rbi: 85,
+            bb: 55,
+            sb: 10,
+            avg: 0.278,
+            espn_position: String::new(),
+        }
+    }
+
+    fn pitcher(name: &str, team: &str) -> PitcherProjection {
+        PitcherProjection {
+            name: name.into(),
+            team: team.into(),
+            pitcher_type: PitcherType::SP,
+            ip: 180.0,
+            k: 200,
+            w: 12,
+            sv: 0,
+            hd: 0,
+            era: 3.50,
+            whip: 1.15,
+            g: 30,
+            gs: 30,
+        }
+    }
+
+    #[test]
+    fn rows_are_validated_on_load() {
+        let csv = "Target,Stat,Factor,Note\n\
+                   COL,HR,1.2,Coors\n\
+                   Mike Trout,,0.8,\n\
+                   SEA,xyz,0.9,\n\
+                   SF,hr,0,\n";
+        let adjustments = load_from_reader(csv.as_bytes()).unwrap();
+        assert_eq!(
+            adjustments,
+            vec![
+                Adjustment { target: "COL".into(), stat: Some("hr".into()), factor: 1.2, note: "Coors".into() },
+                Adjustment { target: "Mike Trout".into(), stat: None, factor: 0.8, note: String::new() },
+            ]
+        );
+    }
+
+    #[test]
+    fn factors_scale_matching_players_and_stack() {
+        let mut projections = AllProjections {
+            hitters: vec![hitter("Rockie", "COL"), hitter("Mike Trout", "LAA"), hitter("Other", "NYY")],
+            pitchers: vec![pitcher("Rockie Arm", "COL")],
+            source: ProjectionSource::Espn,
+        };
+        let adjustments = [
+            Adjustment { target: "col".into(), stat: Some("hr".into()), factor: 1.2, note: "Coors".into() },
+            Adjustment { target: "COL".into(), stat: Some("era".into()), factor: 1.1, note: String::new() },
+            Adjustment { target: "Rockie".into(), stat: Some("hr".into()), factor: 0.5, note: String::new() },
+            Adjustment { target: "mike trout".into(), stat: None, factor: 0.8, note: "injury".into() },
+        ];
+        let applied = apply(&mut projections, &adjustments);
+
+        let [rockie, trout, other] = &projections.hitters[..] else { unreachable!() };
+        assert_eq!(rockie.hr, 15, "25 x 1.2 = 30, then x 0.5");
+        assert_eq!(rockie.pa, 600);
+        assert_eq!(trout.pa, 480);
+        assert_eq!(trout.sb, 8);
+        assert_eq!(trout.avg, 0.278, "rates are left alone by a playing-time haircut");
+        assert_eq!(other.hr, 25);
+        assert!((projections.pitchers[0].era - 3.85).abs() < 1e-9);
+
+        assert_eq!(
+            applied["Rockie"],
+            vec![
+                AppliedAdjustment { stat: "hr".into(), factor: 1.2, reason: "col: Coors".into() },
+                AppliedAdjustment { stat: "hr".into(), factor: 0.5, reason: "Rockie".into() },
+            ]
+        );
+        assert_eq!(applied["Mike Trout"].len(), 8, "every counting stat");
+        assert!(!applied.contains_key("Other"));
+    }
+}
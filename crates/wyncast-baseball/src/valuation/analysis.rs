@@ -4,11 +4,16 @@
 // needs, and category impact into a single actionable verdict for each
 // nominated player.
 
-use wyncast_core::stats::{CategoryValues, StatRegistry};
-use crate::draft::pick::Position;
+use wyncast_core::stats::{
+    CategoryValues, PlayerType, ProjectionData, SortDirection, StatComputation, StatDefinition,
+    StatRegistry,
+};
+use crate::draft::pick::{eligible_positions_from_slots, primary_position_from_slots, Position};
 use crate::draft::roster::Roster;
+use crate::draft::shopping_list::fits;
 use crate::valuation::auction::InflationTracker;
 use crate::valuation::scarcity::{ScarcityEntry, ScarcityUrgency, scarcity_for_position};
+use crate::valuation::standings::StandingsShift;
 use crate::valuation::zscore::PlayerValuation;
 
 // ---------------------------------------------------------------------------
@@ -37,6 +42,57 @@ impl InstantVerdict {
     }
 }
 
+/// Why a player can't be a target for my roster, whatever he is worth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterBlock {
+    /// No open slot takes him, the bench included.
+    NoOpenSlot,
+    /// Buying him at his bid floor would leave too little to fill my other
+    /// open slots at the minimum bid; `max_bid` is the most I can pay.
+    OverBudget { max_bid: u32 },
+}
+
+impl RosterBlock {
+    /// Short reason, e.g. "no open slot".
+    pub fn label(&self) -> String {
+        match self {
+            RosterBlock::NoOpenSlot => "no open slot".to_string(),
+            RosterBlock::OverBudget { max_bid } => format!("can't afford, max ${max_bid}"),
+        }
+    }
+}
+
+/// Whether I can roster `player` at all: an open slot must take him, and
+/// his `bid_floor` must leave `min_bid` for each of my other open slots.
+/// A player only the bench takes fills no active slot, so he leaves every
+/// active slot to fill. Minors slots are kept for prospects, which a
+/// valuation cannot tell apart, so they never count as open here.
+pub fn roster_block(
+    player: &PlayerValuation,
+    my_roster: &Roster,
+    budget_remaining: u32,
+    min_bid: u32,
+    bid_floor: u32,
+) -> Option<RosterBlock> {
+    // Slots are in fill order, so the first that takes him is where he goes.
+    let slot = my_roster
+        .slots
+        .iter()
+        .filter(|s| s.player.is_none())
+        .map(|s| s.position)
+        .find(|&slot| fits(slot, player));
+    let Some(slot) = slot else {
+        return Some(RosterBlock::NoOpenSlot);
+    };
+
+    let max_bid = if slot.is_reserve_slot() {
+        budget_remaining.saturating_sub(my_roster.empty_slots() as u32 * min_bid)
+    } else {
+        my_roster.max_bid(budget_remaining, min_bid)
+    };
+    (max_bid < bid_floor.max(min_bid)).then_some(RosterBlock::OverBudget { max_bid })
+}
+
 // ---------------------------------------------------------------------------
 // Similar player
 // ---------------------------------------------------------------------------
@@ -74,12 +130,21 @@ pub struct InstantAnalysis {
     pub scarcity_at_position: ScarcityUrgency,
     /// Top 3 category impacts: (category_name, need_weighted_zscore).
     pub category_impact: Vec<(String, f64)>,
+    /// Per-category change to my team's projected totals if I buy the player,
+    /// in registry order.
+    pub category_gains: Vec<CategoryGain>,
+    /// How buying the player would move me in the projected category
+    /// standings. Left empty here; the caller fills it in from every team's
+    /// roster (see `standings::standings_impact`).
+    pub standings: Vec<StandingsShift>,
     /// Minimum recommended bid (70% of adjusted value).
     pub bid_floor: u32,
     /// Maximum recommended bid (adjusted value + scarcity premium).
     pub bid_ceiling: u32,
-    /// Overall verdict.
+    /// Overall verdict. Never a target when `roster_block` is set.
     pub verdict: InstantVerdict,
+    /// Why I can't roster the player, if I can't.
+    pub roster_block: Option<RosterBlock>,
     /// 2-3 similar available players for comparison.
     pub similar_players: Vec<SimilarPlayer>,
 }
@@ -93,14 +158,19 @@ pub struct InstantAnalysis {
 /// # Arguments
 /// - `player` - The nominated player's valuation data.
 /// - `my_roster` - The user's current roster state.
+/// - `budget_remaining` - The user's remaining budget.
+/// - `my_projections` - Projections for the players already on my roster.
 /// - `available_players` - All undrafted players.
 /// - `scarcity` - Pre-computed scarcity entries.
 /// - `inflation` - Current inflation tracker state.
 /// - `category_needs` - The user's per-category need levels.
 /// - `registry` - Stat registry for category metadata.
+#[allow(clippy::too_many_arguments)]
 pub fn compute_instant_analysis(
     player: &PlayerValuation,
     my_roster: &Roster,
+    budget_remaining: u32,
+    my_projections: &[ProjectionData],
     available_players: &[PlayerValuation],
     scarcity: &[ScarcityEntry],
     inflation: &InflationTracker,
@@ -136,20 +206,27 @@ pub fn compute_instant_analysis(
 
     // Compute category impact: z-score * category need for each category.
     let category_impact = compute_category_impact(player, category_needs, registry);
+    let category_gains =
+        compute_marginal_gains(player, my_projections, available_players, registry);
 
     // Bid range calculation.
-    let bid_floor = (adjusted_value * 0.70).round().max(1.0) as u32;
+    let bid_floor = inflation.legal_bid(adjusted_value * 0.70);
     let premium = scarcity_at_position.premium();
-    let bid_ceiling = (adjusted_value * (1.0 + premium)).round().max(1.0) as u32;
+    let bid_ceiling = inflation.legal_bid(adjusted_value * (1.0 + premium));
 
-    // Determine verdict.
-    let verdict = compute_verdict(
-        fills_empty_slot,
-        scarcity_at_position,
-        player,
-        available_players,
-        best_pos,
-    );
+    // Determine verdict; a player I can't roster is a pass.
+    let roster_block = roster_block(player, my_roster, budget_remaining, inflation.min_bid as u32, bid_floor);
+    let verdict = if roster_block.is_some() {
+        InstantVerdict::Pass
+    } else {
+        compute_verdict(
+            fills_empty_slot,
+            scarcity_at_position,
+            player,
+            available_players,
+            best_pos,
+        )
+    };
 
     // Find similar players.
     let similar_players = find_similar_players(player, available_players, best_pos);
@@ -163,13 +240,46 @@ pub fn compute_instant_analysis(
         fills_position,
         scarcity_at_position,
         category_impact,
+        category_gains,
+        standings: Vec::new(),
         bid_floor,
         bid_ceiling,
         verdict,
+        roster_block,
         similar_players,
     }
 }
 
+// ---------------------------------------------------------------------------
+// Live eligibility
+// ---------------------------------------------------------------------------
+
+/// Return a copy of `player` whose positions come from live ESPN eligible
+/// slots instead of the projection file.
+///
+/// See [`eligible_positions_from_slots`] for how slots map to positions.
+/// `best_position` is kept if still eligible, otherwise replaced with the
+/// primary slot position. With no usable slots, the player is unchanged.
+pub fn apply_eligible_slots(player: &PlayerValuation, eligible_slots: &[u16]) -> PlayerValuation {
+    let mut positions = eligible_positions_from_slots(eligible_slots);
+    if positions.is_empty() {
+        return player.clone();
+    }
+    positions.sort();
+    positions.dedup();
+
+    let best_position = match player.best_position {
+        Some(pos) if positions.contains(&pos) => Some(pos),
+        _ => primary_position_from_slots(eligible_slots),
+    };
+
+    PlayerValuation {
+        positions,
+        best_position,
+        ..player.clone()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Verdict logic
 // ---------------------------------------------------------------------------
@@ -276,6 +386,156 @@ fn compute_category_impact(
     impacts
 }
 
+// ---------------------------------------------------------------------------
+// Marginal category gains
+// ---------------------------------------------------------------------------
+
+/// How much buying a player would move one of my team's projected category
+/// totals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryGain {
+    /// Category abbreviation (e.g. "HR", "ERA").
+    pub category: String,
+    /// My projected total (rate for rate stats) without the player.
+    pub without: f64,
+    /// My projected total (rate for rate stats) with the player added.
+    pub with: f64,
+    /// `with - without` in the category's favorable direction, so a
+    /// negative gain always means the player hurts me there.
+    pub gain: f64,
+    /// `gain` relative to what a typical top available player of the same
+    /// type would add to my roster (1.0 = typical).
+    pub relative: f64,
+}
+
+/// How many of the top available players form the "typical" reference for
+/// `CategoryGain::relative`.
+const GAIN_REFERENCE_POOL: usize = 20;
+
+/// Compute the per-category change to my projected team totals from adding
+/// `player` to the players already on my roster.
+///
+/// Only categories the player can contribute to are returned (batting for
+/// hitters, pitching for pitchers, both for two-way players). Counting stats
+/// are summed; rate stats are volume-weighted. When my roster has no volume
+/// yet in a rate stat (e.g. no pitchers for ERA), the baseline is the
+/// reference pool's rate, so the gain reads as "better or worse than a
+/// typical pickup" instead of against an empty staff.
+pub fn compute_marginal_gains(
+    player: &PlayerValuation,
+    my_projections: &[ProjectionData],
+    available_players: &[PlayerValuation],
+    registry: &StatRegistry,
+) -> Vec<CategoryGain> {
+    let candidate = ProjectionData::from(&player.projection);
+    let reference: Vec<ProjectionData> = available_players
+        .iter()
+        .filter(|p| p.name != player.name && p.is_pitcher == player.is_pitcher)
+        .take(GAIN_REFERENCE_POOL)
+        .map(|p| ProjectionData::from(&p.projection))
+        .collect();
+
+    registry
+        .all_stats()
+        .iter()
+        .filter(|stat| contributes_to(player, stat))
+        .map(|stat| {
+            let mine = aggregate(stat, my_projections.iter());
+            let pool = aggregate(stat, reference.iter());
+            let (without, with) = with_and_without(stat, mine, pool, &candidate);
+            let gain = favorable(stat, without, with);
+
+            let reference_gains: Vec<f64> = reference
+                .iter()
+                .map(|p| {
+                    let (w0, w1) = with_and_without(stat, mine, pool, p);
+                    favorable(stat, w0, w1).abs()
+                })
+                .collect();
+            let typical = if reference_gains.is_empty() {
+                0.0
+            } else {
+                reference_gains.iter().sum::<f64>() / reference_gains.len() as f64
+            };
+            let relative = if typical > 1e-9 { gain / typical } else { 0.0 };
+
+            CategoryGain {
+                category: stat.abbrev.clone(),
+                without,
+                with,
+                gain,
+                relative,
+            }
+        })
+        .collect()
+}
+
+/// Whether `player` has projections feeding `stat`.
+pub(crate) fn contributes_to(player: &PlayerValuation, stat: &StatDefinition) -> bool {
+    match stat.player_type {
+        PlayerType::Hitter => !player.is_pitcher,
+        PlayerType::Pitcher => player.is_pitcher || player.is_two_way,
+    }
+}
+
+/// Team-level value of `stat` over a set of projections, as
+/// `(value, volume)`. Counting stats report a volume of 0.
+pub(crate) fn aggregate<'a>(
+    stat: &StatDefinition,
+    projections: impl Iterator<Item = &'a ProjectionData>,
+) -> (f64, f64) {
+    match &stat.computation {
+        StatComputation::Counting { projection_key } => {
+            (projections.map(|p| p.get_or_zero(projection_key)).sum(), 0.0)
+        }
+        StatComputation::RateStat {
+            volume_key,
+            rate_key,
+            ..
+        } => {
+            let (weighted, volume) = projections.fold((0.0, 0.0), |(w, v), p| {
+                let vol = p.get_or_zero(volume_key);
+                (w + vol * p.get_or_zero(rate_key), v + vol)
+            });
+            if volume > 0.0 {
+                (weighted / volume, volume)
+            } else {
+                (0.0, 0.0)
+            }
+        }
+    }
+}
+
+/// My `(without, with)` values for `stat` when adding `candidate`.
+fn with_and_without(
+    stat: &StatDefinition,
+    (mine, my_volume): (f64, f64),
+    (pool_rate, _): (f64, f64),
+    candidate: &ProjectionData,
+) -> (f64, f64) {
+    let (value, volume) = aggregate(stat, std::iter::once(candidate));
+    match stat.computation {
+        StatComputation::Counting { .. } => (mine, mine + value),
+        StatComputation::RateStat { .. } if my_volume > 0.0 => {
+            let total = my_volume + volume;
+            (mine, (mine * my_volume + value * volume) / total)
+        }
+        StatComputation::RateStat { .. } => {
+            // Nothing rostered yet: compare against the typical pickup.
+            let with = if volume > 0.0 { value } else { pool_rate };
+            (pool_rate, with)
+        }
+    }
+}
+
+/// Signed improvement from `without` to `with`, positive when favorable.
+fn favorable(stat: &StatDefinition, without: f64, with: f64) -> f64 {
+    match stat.sort_direction {
+        SortDirection::HigherIsBetter => with - without,
+        SortDirection::LowerIsBetter => without - with,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Similar players
 // ---------------------------------------------------------------------------
@@ -346,6 +606,8 @@ mod tests {
     use crate::test_utils::{approx_eq, test_registry, test_roster_config, TestPlayer};
     use crate::valuation::auction::InflationTracker;
     use crate::valuation::scarcity::compute_scarcity;
+    use crate::valuation::projections::PitcherType;
+    use std::collections::HashMap;
 
     fn make_hitter(name: &str, vor: f64, positions: Vec<Position>, dollar: f64) -> PlayerValuation {
         TestPlayer::hitter(name).vor(vor).positions(positions).dollar(dollar).build()
@@ -362,13 +624,15 @@ mod tests {
             make_hitter("Other C", 3.0, vec![Position::Catcher], 15.0),
         ];
 
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let inflation = InflationTracker::new();
         let needs = CategoryValues::uniform(registry.len(), 0.5);
 
         let analysis = compute_instant_analysis(
             &available[0],
             &roster,
+            260,
+            &[],
             &available,
             &scarcity,
             &inflation,
@@ -394,13 +658,15 @@ mod tests {
         let player = make_hitter("Bad C", -2.0, vec![Position::Catcher], 1.0);
         let available = vec![player.clone()];
 
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let inflation = InflationTracker::new();
         let needs = CategoryValues::uniform(registry.len(), 0.5);
 
         let analysis = compute_instant_analysis(
             &player,
             &roster,
+            260,
+            &[],
             &available,
             &scarcity,
             &inflation,
@@ -426,13 +692,15 @@ mod tests {
             make_hitter("Other C", 3.0, vec![Position::Catcher], 10.0),
         ];
 
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let inflation = InflationTracker::new(); // rate = 1.0
         let needs = CategoryValues::uniform(registry.len(), 0.5);
 
         let analysis = compute_instant_analysis(
             &available[0],
             &roster,
+            260,
+            &[],
             &available,
             &scarcity,
             &inflation,
@@ -463,7 +731,7 @@ mod tests {
             ));
         }
 
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let mut inflation = InflationTracker::new();
         inflation.inflation_rate = 1.1;
         let needs = CategoryValues::uniform(registry.len(), 0.5);
@@ -471,6 +739,8 @@ mod tests {
         let analysis = compute_instant_analysis(
             &available[0],
             &roster,
+            260,
+            &[],
             &available,
             &scarcity,
             &inflation,
@@ -549,7 +819,7 @@ mod tests {
             ));
         }
 
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let inflation = InflationTracker::new();
         let needs = CategoryValues::uniform(registry.len(), 0.5);
 
@@ -557,6 +827,8 @@ mod tests {
         let analysis = compute_instant_analysis(
             &available[4],
             &roster,
+            260,
+            &[],
             &available,
             &scarcity,
             &inflation,
@@ -584,7 +856,7 @@ mod tests {
             ));
         }
 
-        let scarcity = compute_scarcity(&available, &test_roster_config());
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
         let inflation = InflationTracker::new();
         let needs = CategoryValues::uniform(registry.len(), 0.5);
 
@@ -592,6 +864,8 @@ mod tests {
         let analysis = compute_instant_analysis(
             &available[1],
             &roster,
+            260,
+            &[],
             &available,
             &scarcity,
             &inflation,
@@ -601,4 +875,218 @@ mod tests {
 
         assert_eq!(analysis.verdict, InstantVerdict::StrongTarget);
     }
+
+    // -- Live eligibility --
+
+    #[test]
+    fn apply_eligible_slots_replaces_projection_positions() {
+        let player = TestPlayer::hitter("Shifted").positions(vec![Position::SecondBase]).build();
+        // ESPN now lists SS/3B/UTIL/BE/IL
+        let live = apply_eligible_slots(&player, &[4, 3, 12, 16, 17]);
+        assert_eq!(live.positions, vec![Position::ThirdBase, Position::ShortStop]);
+        assert_eq!(live.best_position, Some(Position::ShortStop));
+    }
+
+    #[test]
+    fn apply_eligible_slots_ignores_implied_combo_slots() {
+        let player = TestPlayer::hitter("Starter").positions(vec![Position::StartingPitcher]).build();
+        // P + SP: the generic P slot must not add RP eligibility.
+        let live = apply_eligible_slots(&player, &[13, 14, 16, 17]);
+        assert_eq!(live.positions, vec![Position::StartingPitcher]);
+    }
+
+    #[test]
+    fn apply_eligible_slots_without_slots_is_identity() {
+        let player = make_hitter("Plain", 3.0, vec![Position::Catcher], 10.0);
+        let live = apply_eligible_slots(&player, &[]);
+        assert_eq!(live.positions, player.positions);
+        assert_eq!(live.best_position, player.best_position);
+    }
+
+    #[test]
+    fn apply_eligible_slots_changes_fills_empty_slot() {
+        let registry = test_registry();
+        let mut roster = Roster::new(&test_roster_config());
+        roster.add_player("Incumbent 2B", "2B", 10, None);
+
+        // Projections say 2B only (filled); ESPN says 2B/SS (SS is open).
+        let player = make_hitter("Middle IF", 4.0, vec![Position::SecondBase], 20.0);
+        let available = vec![player.clone()];
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
+        let inflation = InflationTracker::new();
+        let needs = CategoryValues::uniform(registry.len(), 0.5);
+
+        let stale = compute_instant_analysis(
+            &player, &roster, 260, &[], &available, &scarcity, &inflation, &needs, &registry,
+        );
+        let live_player = apply_eligible_slots(&player, &[2, 4, 6, 12, 16, 17]);
+        let live = compute_instant_analysis(
+            &live_player, &roster, 260, &[], &available, &scarcity, &inflation, &needs, &registry,
+        );
+
+        assert!(!stale.fills_empty_slot);
+        assert!(live.fills_empty_slot);
+        assert_eq!(live.fills_position, Some(Position::ShortStop));
+    }
+
+    // -- Marginal category gains --
+
+    fn gain<'a>(gains: &'a [CategoryGain], category: &str) -> &'a CategoryGain {
+        gains.iter().find(|g| g.category == category).unwrap()
+    }
+
+    fn core_projection(p: &PlayerValuation) -> ProjectionData {
+        ProjectionData::from(&p.projection)
+    }
+
+    #[test]
+    fn marginal_gains_add_counting_stats_to_my_totals() {
+        let registry = test_registry();
+        let mine = vec![core_projection(&TestPlayer::hitter("Already Mine").build())];
+        let target = TestPlayer::hitter("Target").build();
+
+        let gains = compute_marginal_gains(&target, &mine, &[], &registry);
+
+        // Hitter only touches batting categories.
+        let cats: Vec<&str> = gains.iter().map(|g| g.category.as_str()).collect();
+        assert_eq!(cats, vec!["R", "HR", "RBI", "BB", "SB", "AVG"]);
+        let hr = gain(&gains, "HR");
+        assert!(approx_eq(hr.without, 25.0, 1e-9));
+        assert!(approx_eq(hr.with, 50.0, 1e-9));
+        assert!(approx_eq(hr.gain, 25.0, 1e-9));
+        // Same AVG as my roster: no change.
+        assert!(approx_eq(gain(&gains, "AVG").gain, 0.0, 1e-9));
+    }
+
+    #[test]
+    fn marginal_gains_weight_rate_stats_by_volume() {
+        let registry = test_registry();
+        let mut ace = TestPlayer::pitcher("Ace", PitcherType::SP).build();
+        ace.projection.values.insert("era".into(), 2.00);
+        let mut mine_p = TestPlayer::pitcher("My SP", PitcherType::SP).build();
+        mine_p.projection.values.insert("era".into(), 4.00);
+        let mine = vec![core_projection(&mine_p)];
+
+        let gains = compute_marginal_gains(&ace, &mine, &[], &registry);
+
+        // Equal IP: team ERA goes from 4.00 to 3.00, a favorable +1.00.
+        let era = gain(&gains, "ERA");
+        assert!(approx_eq(era.without, 4.00, 1e-9));
+        assert!(approx_eq(era.with, 3.00, 1e-9));
+        assert!(approx_eq(era.gain, 1.00, 1e-9));
+        assert!(gains.iter().all(|g| g.category != "HR"));
+    }
+
+    #[test]
+    fn marginal_gains_penalize_bad_rate_stats() {
+        let registry = test_registry();
+        let mut arsonist = TestPlayer::pitcher("Arsonist", PitcherType::SP).build();
+        arsonist.projection.values.insert("whip".into(), 1.50);
+        let mine = vec![core_projection(&TestPlayer::pitcher("My SP", PitcherType::SP).build())];
+
+        let gains = compute_marginal_gains(&arsonist, &mine, &[], &registry);
+
+        assert!(gain(&gains, "WHIP").gain < 0.0);
+        assert!(gain(&gains, "K").gain > 0.0);
+    }
+
+    #[test]
+    fn marginal_gains_with_empty_staff_compare_rates_to_typical_pickup() {
+        let registry = test_registry();
+        let mut ace = TestPlayer::pitcher("Ace", PitcherType::SP).build();
+        ace.projection.values.insert("era".into(), 2.70);
+        let pool = vec![
+            ace.clone(),
+            TestPlayer::pitcher("Typical A", PitcherType::SP).build(),
+            TestPlayer::pitcher("Typical B", PitcherType::SP).build(),
+        ];
+
+        let gains = compute_marginal_gains(&ace, &[], &pool, &registry);
+
+        // Baseline is the pool's 3.20 ERA rather than an empty staff's 0.00.
+        let era = gain(&gains, "ERA");
+        assert!(approx_eq(era.without, 3.20, 1e-9));
+        assert!(approx_eq(era.gain, 0.50, 1e-9));
+    }
+
+    #[test]
+    fn marginal_gains_relative_to_typical_available_player() {
+        let registry = test_registry();
+        let mut slugger = TestPlayer::hitter("Slugger").build();
+        slugger.projection.values.insert("hr".into(), 50.0);
+        let pool = vec![
+            slugger.clone(),
+            TestPlayer::hitter("Typical A").build(),
+            TestPlayer::hitter("Typical B").build(),
+            TestPlayer::pitcher("Not A Hitter", PitcherType::SP).build(),
+        ];
+
+        let gains = compute_marginal_gains(&slugger, &[], &pool, &registry);
+
+        // Typical hitter adds 25 HR; the slugger adds 50.
+        assert!(approx_eq(gain(&gains, "HR").relative, 2.0, 1e-9));
+        assert!(approx_eq(gain(&gains, "R").relative, 1.0, 1e-9));
+    }
+
+    #[test]
+    fn instant_analysis_includes_category_gains() {
+        let registry = test_registry();
+        let roster = Roster::new(&test_roster_config());
+        let available = vec![make_hitter("Target", 3.0, vec![Position::FirstBase], 20.0)];
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
+
+        let analysis = compute_instant_analysis(
+            &available[0],
+            &roster,
+            260,
+            &[],
+            &available,
+            &scarcity,
+            &InflationTracker::new(),
+            &CategoryValues::uniform(registry.len(), 0.5),
+            &registry,
+        );
+
+        assert_eq!(analysis.category_gains.len(), registry.batting_count());
+        assert!(approx_eq(gain(&analysis.category_gains, "HR").gain, 25.0, 1e-9));
+    }
+
+    #[test]
+    fn a_player_i_cannot_roster_is_never_a_target() {
+        let registry = test_registry();
+        let catcher = make_hitter("Only C", 8.0, vec![Position::Catcher], 30.0);
+        let ace = TestPlayer::pitcher("Ace", PitcherType::SP).vor(8.0).dollar(30.0).build();
+        let available = vec![catcher.clone(), ace.clone()];
+        let scarcity = compute_scarcity(&available, &test_roster_config(), 10);
+        let analyze = |player: &PlayerValuation, roster: &Roster, budget: u32| {
+            compute_instant_analysis(
+                player,
+                roster,
+                budget,
+                &[],
+                &available,
+                &scarcity,
+                &InflationTracker::new(),
+                &CategoryValues::uniform(registry.len(), 0.5),
+                &registry,
+            )
+        };
+
+        // Only a catcher slot is open: a catcher fits, a starter does not.
+        let roster = Roster::new(&HashMap::from([("C".to_string(), 1)]));
+        let analysis = analyze(&catcher, &roster, 260);
+        assert_eq!(analysis.roster_block, None);
+        assert_ne!(analysis.verdict, InstantVerdict::Pass);
+        let analysis = analyze(&ace, &roster, 260);
+        assert_eq!(analysis.roster_block, Some(RosterBlock::NoOpenSlot));
+        assert_eq!(analysis.verdict, InstantVerdict::Pass);
+
+        // A bench slot takes anyone, but $6 must cover five open slots.
+        let roster = Roster::new(&HashMap::from([("C".to_string(), 1), ("BE".to_string(), 4)]));
+        assert_eq!(analyze(&ace, &roster, 260).roster_block, None);
+        let analysis = analyze(&catcher, &roster, 6);
+        assert_eq!(analysis.roster_block, Some(RosterBlock::OverBudget { max_bid: 2 }));
+        assert_eq!(analysis.verdict, InstantVerdict::Pass);
+        assert_eq!(RosterBlock::OverBudget { max_bid: 2 }.label(), "can't afford, max $2");
+    }
 }
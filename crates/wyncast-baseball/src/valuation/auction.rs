@@ -2,12 +2,19 @@
 //
 // Converts VOR (Value Over Replacement) numbers into auction dollar amounts
 // for a salary-cap draft. The budget is split between hitting and pitching
-// according to a configurable fraction, and dollars are distributed
-// proportionally to positive VOR within each pool.
+// according to a configurable fraction (or, in adaptive mode, a split learned
+// from the room's spending), and dollars are distributed proportionally to
+// positive VOR within each pool. The `[valuation]` rules then set the floor,
+// optionally compress the top end, and cap any single player's value.
 
 use std::collections::HashMap;
 
-use wyncast_core::config::{LeagueConfig, StrategyConfig};
+use serde::{Deserialize, Serialize};
+use wyncast_core::config::{
+    CompressionCurve, FlexibilityConfig, LeagueConfig, StrategyConfig, ValuationRulesConfig,
+};
+use crate::draft::ladder;
+use crate::draft::pick::{pick_is_hitter, Position};
 use crate::draft::state::DraftState;
 use crate::valuation::zscore::PlayerValuation;
 
@@ -29,6 +36,8 @@ pub struct AuctionValues {
     pub dollars_per_vor_hitter: f64,
     /// Dollars per unit of VOR for pitchers with positive VOR.
     pub dollars_per_vor_pitcher: f64,
+    /// Value of a replacement-level player (`valuation.min_value`).
+    pub min_value: f64,
 }
 
 // ---------------------------------------------------------------------------
@@ -37,16 +46,13 @@ pub struct AuctionValues {
 
 /// Compute the active roster size from the league config.
 ///
-/// This is the sum of all roster slot counts, **excluding** IL slots.
-/// IL slots are not counted because injured-list players do not consume
-/// salary cap space in the auction.
+/// This is the sum of all roster slot counts, **excluding** IL and minors
+/// (NA/taxi) slots. Reserve slots are not counted because their players do
+/// not consume salary cap space in the auction.
 pub fn roster_size(roster_config: &HashMap<String, usize>) -> usize {
     roster_config
         .iter()
-        .filter(|(key, _)| {
-            let upper = key.to_uppercase();
-            upper != "IL" && upper != "DL"
-        })
+        .filter(|(key, _)| !Position::from_roster_slot_str(key).is_some_and(|pos| pos.is_reserve_slot()))
         .map(|(_, &count)| count)
         .sum()
 }
@@ -59,14 +65,15 @@ pub fn roster_size(roster_config: &HashMap<String, usize>) -> usize {
 ///
 /// Algorithm:
 /// 1. `total_dollars` = `num_teams * salary_cap`
-/// 2. `min_bids` = `num_teams * roster_size * $1` (every slot costs at least $1)
+/// 2. `min_bids` = `num_teams * roster_size * min_value` (every slot costs at
+///    least the minimum, $1 by default)
 /// 3. `distributable` = `total_dollars - min_bids`
 /// 4. Split distributable between hitting and pitching via `hitting_budget_fraction`
 /// 5. Sum positive VOR in each pool
 /// 6. `dollars_per_vor` = `pool_distributable / total_positive_vor`
 ///
 /// If a pool has zero total positive VOR (e.g. no pitchers), the conversion
-/// rate is set to 0.0 so that every player in that pool gets the $1 minimum.
+/// rate is set to 0.0 so that every player in that pool gets the minimum.
 pub fn compute_auction_values(
     hitters: &[&PlayerValuation],
     pitchers: &[&PlayerValuation],
@@ -77,7 +84,8 @@ pub fn compute_auction_values(
 ) -> AuctionValues {
     let total_dollars = num_teams as f64 * salary_cap as f64;
     let roster = roster_size(roster_config);
-    let min_bids = num_teams as f64 * roster as f64;
+    let min_value = strategy.valuation.min_value;
+    let min_bids = num_teams as f64 * roster as f64 * min_value;
     let distributable = (total_dollars - min_bids).max(0.0);
 
     let hitting_distributable = distributable * strategy.hitting_budget_fraction;
@@ -99,21 +107,22 @@ pub fn compute_auction_values(
         0.0
     };
 
-    // The full budget for each pool = distributable portion + the $1 minimums
+    // The full budget for each pool = distributable portion + the minimums
     // for that pool's players. But for reporting purposes we store the
-    // distributable portions — the $1 minimums are implicit.
+    // distributable portions — the minimums are implicit.
     AuctionValues {
         hitting_budget: hitting_distributable,
         pitching_budget: pitching_distributable,
         dollars_per_vor_hitter,
         dollars_per_vor_pitcher,
+        min_value,
     }
 }
 
 /// Compute the dollar value for a single player given the auction conversion factors.
 ///
-/// - Players with positive VOR: `value = (VOR * dollars_per_vor) + $1`
-/// - Players with zero or negative VOR: `value = $1` (the floor)
+/// - Players with positive VOR: `value = (VOR * dollars_per_vor) + min_value`
+/// - Players with zero or negative VOR: `value = min_value` (the floor)
 ///
 /// Two-way players use a blended dollars_per_vor: a weighted average of the
 /// hitter and pitcher rates based on the ratio of their positive hitting vs
@@ -143,8 +152,347 @@ pub fn player_dollar_value(player: &PlayerValuation, auction: &AuctionValues) ->
         auction.dollars_per_vor_hitter
     };
 
-    let raw = (player.vor * dollars_per_vor) + 1.0;
-    raw.max(1.0)
+    let raw = (player.vor * dollars_per_vor) + auction.min_value;
+    raw.max(auction.min_value)
+}
+
+// ---------------------------------------------------------------------------
+// Floor / cap / compression rules
+// ---------------------------------------------------------------------------
+
+/// Apply the `[valuation]` compression curve and max-share cap to a pool's
+/// base dollar values, in place.
+///
+/// Both rules act on surplus (dollars above `min_value`) and preserve the
+/// pool's total surplus: compression rescales every surplus so the sum is
+/// unchanged, and dollars trimmed by the cap are handed to the uncapped
+/// players in proportion to their surplus (repeating until nobody new hits
+/// the cap). Players at the floor stay at the floor.
+pub fn apply_valuation_rules(values: &mut [f64], rules: &ValuationRulesConfig, salary_cap: u32) {
+    let floor = rules.min_value;
+    let cap = salary_cap as f64;
+    let mut surplus: Vec<f64> = values.iter().map(|v| (v - floor).max(0.0)).collect();
+    let total: f64 = surplus.iter().sum();
+    if total <= 0.0 {
+        return;
+    }
+
+    if rules.compression == CompressionCurve::Power {
+        let knee = rules.compression_knee * cap;
+        if knee > 0.0 {
+            for s in surplus.iter_mut().filter(|s| **s > knee) {
+                *s = knee * (*s / knee).powf(rules.compression_exponent);
+            }
+            let compressed: f64 = surplus.iter().sum();
+            if compressed > 0.0 {
+                let scale = total / compressed;
+                surplus.iter_mut().for_each(|s| *s *= scale);
+            }
+        }
+    }
+
+    if rules.max_cap_share > 0.0 {
+        let max_surplus = (rules.max_cap_share * cap - floor).max(0.0);
+        let mut capped = vec![false; surplus.len()];
+        loop {
+            let mut excess = 0.0;
+            for (s, is_capped) in surplus.iter_mut().zip(capped.iter_mut()) {
+                if !*is_capped && *s >= max_surplus {
+                    excess += *s - max_surplus;
+                    *s = max_surplus;
+                    *is_capped = true;
+                }
+            }
+            let open: f64 = surplus
+                .iter()
+                .zip(&capped)
+                .filter(|(_, c)| !**c)
+                .map(|(s, _)| s)
+                .sum();
+            if excess <= 1e-9 || open <= 0.0 {
+                break;
+            }
+            let scale = 1.0 + excess / open;
+            for (s, _) in surplus.iter_mut().zip(&capped).filter(|(_, c)| !**c) {
+                *s *= scale;
+            }
+        }
+    }
+
+    for (v, s) in values.iter_mut().zip(surplus) {
+        *v = floor + s;
+    }
+}
+
+/// Price `players` with `auction`, then apply the valuation rules and the
+/// positional flexibility premium. The premium is paid out of the pool
+/// rather than on top of it: every surplus is scaled down so the pool still
+/// adds up to the dollars the conversion handed out. It never pushes a
+/// player past the max-share cap.
+fn price_players(
+    players: &mut [PlayerValuation],
+    auction: &AuctionValues,
+    roster_config: &HashMap<String, usize>,
+    salary_cap: u32,
+    strategy: &StrategyConfig,
+) {
+    let mut base: Vec<f64> = players
+        .iter()
+        .map(|p| player_dollar_value(p, auction))
+        .collect();
+    apply_valuation_rules(&mut base, &strategy.valuation, salary_cap);
+
+    let rules = &strategy.valuation;
+    let max_value = if rules.max_cap_share > 0.0 {
+        rules.max_cap_share * salary_cap as f64
+    } else {
+        f64::INFINITY
+    };
+    let bonuses: Vec<f64> = players
+        .iter()
+        .zip(&base)
+        .map(|(player, &base)| {
+            let bonus = flex_bonus(player, base, auction.min_value, roster_config, &strategy.flexibility);
+            bonus.min((max_value - base).max(0.0))
+        })
+        .collect();
+    let surplus: f64 = base.iter().map(|b| (b - auction.min_value).max(0.0)).sum();
+    let premium: f64 = bonuses.iter().sum();
+    let scale = if premium > 0.0 { surplus / (surplus + premium) } else { 1.0 };
+    for ((player, base), bonus) in players.iter_mut().zip(base).zip(bonuses) {
+        let trimmed = (base - auction.min_value).max(0.0) * (1.0 - scale);
+        player.flex_bonus = bonus * scale;
+        player.dollar_value = base - trimmed + player.flex_bonus;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Positional flexibility
+// ---------------------------------------------------------------------------
+
+/// Count the distinct starting hitter slots in the league's roster that a
+/// player can fill.
+///
+/// UTIL/DH (open to every hitter), bench/IL, pitcher slots, and the MI/CI/IF
+/// combo slots are ignored: none of them distinguishes a multi-position
+/// player from a single-position one. A generic OF slot counts once.
+pub fn eligible_starting_slots(
+    player: &PlayerValuation,
+    roster_config: &HashMap<String, usize>,
+) -> usize {
+    roster_config
+        .iter()
+        .filter(|(_, &count)| count > 0)
+        .filter_map(|(key, _)| Position::from_roster_slot_str(key))
+        .filter(|slot| {
+            slot.is_hitter()
+                && !slot.is_meta_slot()
+                && !matches!(
+                    slot,
+                    Position::DesignatedHitter
+                        | Position::MiddleInfield
+                        | Position::CornerInfield
+                        | Position::Infield
+                )
+        })
+        .filter(|slot| {
+            player.positions.contains(slot)
+                || slot
+                    .accepted_positions()
+                    .iter()
+                    .any(|pos| player.positions.contains(pos))
+        })
+        .count()
+}
+
+/// Dollar premium for multi-position eligibility on top of `base_value`.
+///
+/// Each eligible starting slot beyond the first adds `extra_slot_pct` of the
+/// player's surplus value (dollars above `floor`, the valuation minimum), or
+/// `catcher_extra_slot_pct` for catcher-eligible players, capped at
+/// `max_pct`. Pitchers and single-slot hitters get no premium.
+pub fn flex_bonus(
+    player: &PlayerValuation,
+    base_value: f64,
+    floor: f64,
+    roster_config: &HashMap<String, usize>,
+    config: &FlexibilityConfig,
+) -> f64 {
+    if !config.enabled || player.is_pitcher {
+        return 0.0;
+    }
+
+    let extra_slots = eligible_starting_slots(player, roster_config).saturating_sub(1);
+    if extra_slots == 0 {
+        return 0.0;
+    }
+
+    let per_slot = if player.positions.contains(&Position::Catcher) {
+        config.catcher_extra_slot_pct
+    } else {
+        config.extra_slot_pct
+    };
+    let pct = (extra_slots as f64 * per_slot).min(config.max_pct);
+    (base_value - floor).max(0.0) * pct
+}
+
+// ---------------------------------------------------------------------------
+// Adaptive hitter/pitcher split
+// ---------------------------------------------------------------------------
+
+/// Weight of the configured split, in dollars per team, when blending it
+/// with the room's realized split. Once the room has spent this much per
+/// team, the two carry equal weight.
+const SPLIT_PRIOR_DOLLARS_PER_TEAM: f64 = 50.0;
+
+/// The hitter/pitcher split used to price the remaining pool.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BudgetSplit {
+    /// Share of the remaining distributable dollars that goes to hitters.
+    pub hitting_fraction: f64,
+    /// Whether the split was learned from the room rather than taken from
+    /// `hitting_budget_fraction`.
+    pub learned: bool,
+}
+
+impl BudgetSplit {
+    /// The configured split, unchanged.
+    pub fn fixed(strategy: &StrategyConfig) -> Self {
+        BudgetSplit {
+            hitting_fraction: strategy.hitting_budget_fraction,
+            learned: false,
+        }
+    }
+}
+
+/// Compute the hitter/pitcher split for pricing the remaining pool.
+///
+/// With `adaptive_budget_split` off, or before any money has been spent,
+/// this is just `hitting_budget_fraction`. Otherwise:
+///
+/// 1. Estimate the room's overall hitting share by blending the configured
+///    fraction with the realized share of dollars spent on hitters, weighted
+///    by how much has been spent (see `SPLIT_PRIOR_DOLLARS_PER_TEAM`).
+/// 2. Project the dollars the room has left for each side: its estimated
+///    share of the league budget minus what it already spent there.
+/// 3. Reserve the minimum bid for every open starting slot on each side
+///    (remaining supply); a side with no open slots gets nothing more.
+/// 4. The hitting fraction is the hitters' share of what remains.
+pub fn effective_budget_split(
+    draft_state: &DraftState,
+    league: &LeagueConfig,
+    strategy: &StrategyConfig,
+) -> BudgetSplit {
+    let fixed = BudgetSplit::fixed(strategy);
+    if !strategy.adaptive_budget_split {
+        return fixed;
+    }
+
+    let (mut hitting_spent, mut pitching_spent) = (0.0, 0.0);
+    for pick in &draft_state.picks {
+        match pick_is_hitter(pick) {
+            Some(true) => hitting_spent += pick.price as f64,
+            Some(false) => pitching_spent += pick.price as f64,
+            None => {}
+        }
+    }
+    let spent = hitting_spent + pitching_spent;
+    if spent <= 0.0 {
+        return fixed;
+    }
+
+    let total_budget = league.num_teams as f64 * league.salary_cap as f64;
+    let prior = SPLIT_PRIOR_DOLLARS_PER_TEAM * league.num_teams as f64;
+    let weight = spent / (spent + prior);
+    let room_fraction =
+        strategy.hitting_budget_fraction * (1.0 - weight) + (hitting_spent / spent) * weight;
+
+    let (mut open_hitting, mut open_pitching) = (0.0, 0.0);
+    for slot in draft_state
+        .teams
+        .iter()
+        .flat_map(|t| &t.roster.slots)
+        .filter(|s| s.player.is_none())
+    {
+        match slot.position {
+            Position::Bench | Position::InjuredList | Position::Minors => {}
+            pos if pos.is_hitter() => open_hitting += 1.0,
+            _ => open_pitching += 1.0,
+        }
+    }
+
+    let distributable = |share: f64, spent: f64, open: f64| {
+        if open > 0.0 {
+            (share * total_budget - spent - open * league.min_bid as f64).max(0.0)
+        } else {
+            0.0
+        }
+    };
+    let hitting = distributable(room_fraction, hitting_spent, open_hitting);
+    let pitching = distributable(1.0 - room_fraction, pitching_spent, open_pitching);
+    if hitting + pitching <= 0.0 {
+        return fixed;
+    }
+
+    BudgetSplit {
+        hitting_fraction: hitting / (hitting + pitching),
+        learned: true,
+    }
+}
+
+/// Re-price the remaining pool at a new hitter/pitcher split.
+///
+/// The pool's total surplus (dollars above the floor, flexibility premium
+/// included) is kept as-is and only redistributed between hitters and
+/// pitchers, so the inflation rate stays comparable before and after. The
+/// valuation rules and flexibility bonuses are reapplied to the new base
+/// values and the list is re-sorted.
+pub fn resplit_remaining_pool(
+    players: &mut [PlayerValuation],
+    hitting_fraction: f64,
+    roster_config: &HashMap<String, usize>,
+    salary_cap: u32,
+    strategy: &StrategyConfig,
+) {
+    let min_value = strategy.valuation.min_value;
+    let surplus: f64 = players
+        .iter()
+        .map(|p| (p.dollar_value - min_value).max(0.0))
+        .sum();
+    let hitter_vor: f64 = players
+        .iter()
+        .filter(|p| !p.is_pitcher && p.vor > 0.0)
+        .map(|p| p.vor)
+        .sum();
+    let pitcher_vor: f64 = players
+        .iter()
+        .filter(|p| p.is_pitcher && p.vor > 0.0)
+        .map(|p| p.vor)
+        .sum();
+
+    // A side with no positive VOR left cannot absorb dollars; give them all
+    // to the other side.
+    let hitting_fraction = match (hitter_vor > 0.0, pitcher_vor > 0.0) {
+        (true, false) => 1.0,
+        (false, true) => 0.0,
+        _ => hitting_fraction.clamp(0.0, 1.0),
+    };
+    let per_vor = |share: f64, vor: f64| if vor > 0.0 { surplus * share / vor } else { 0.0 };
+    let auction = AuctionValues {
+        hitting_budget: surplus * hitting_fraction,
+        pitching_budget: surplus * (1.0 - hitting_fraction),
+        dollars_per_vor_hitter: per_vor(hitting_fraction, hitter_vor),
+        dollars_per_vor_pitcher: per_vor(1.0 - hitting_fraction, pitcher_vor),
+        min_value,
+    };
+
+    price_players(players, &auction, roster_config, salary_cap, strategy);
+
+    players.sort_by(|a, b| {
+        b.dollar_value
+            .partial_cmp(&a.dollar_value)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
 }
 
 // ---------------------------------------------------------------------------
@@ -156,7 +504,7 @@ pub fn player_dollar_value(player: &PlayerValuation, auction: &AuctionValues) ->
 /// By comparing how much money has been spent against how much pre-draft value
 /// has been consumed, we can tell whether the league is overpaying (inflation)
 /// or underpaying (deflation) relative to our valuations.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InflationTracker {
     /// Total dollars spent across the entire league so far.
     pub total_dollars_spent: f64,
@@ -164,11 +512,34 @@ pub struct InflationTracker {
     pub total_predraft_value_spent: f64,
     /// Total dollars remaining across all teams.
     pub remaining_dollars: f64,
-    /// Sum of dollar values for all undrafted players with value > $1.
+    /// Sum of dollar values for all undrafted players worth more than the
+    /// minimum bid.
     pub remaining_predraft_value: f64,
-    /// Inflation rate: remaining_dollars / remaining_predraft_value.
+    /// Inflation rate values are adjusted by: `raw_inflation_rate`, or its
+    /// smoothed, damped version after `smooth`.
     /// > 1.0 = deflation (bargains available), < 1.0 = inflation (prices rising).
     pub inflation_rate: f64,
+    /// remaining_dollars / remaining_predraft_value, as the room stands.
+    #[serde(default = "neutral_rate")]
+    pub raw_inflation_rate: f64,
+    /// The league's minimum bid, the floor adjusted values never drop below.
+    #[serde(default = "default_min_bid")]
+    pub min_bid: f64,
+    /// The league's bid increment; suggested bids are multiples of it.
+    #[serde(default = "default_bid_increment")]
+    pub bid_increment: u32,
+}
+
+fn default_min_bid() -> f64 {
+    1.0
+}
+
+fn neutral_rate() -> f64 {
+    1.0
+}
+
+fn default_bid_increment() -> u32 {
+    1
 }
 
 impl InflationTracker {
@@ -180,6 +551,9 @@ impl InflationTracker {
             remaining_dollars: 0.0,
             remaining_predraft_value: 0.0,
             inflation_rate: 1.0,
+            raw_inflation_rate: 1.0,
+            min_bid: default_min_bid(),
+            bid_increment: default_bid_increment(),
         }
     }
 
@@ -195,6 +569,8 @@ impl InflationTracker {
         league: &LeagueConfig,
     ) {
         let total_budget = league.num_teams as f64 * league.salary_cap as f64;
+        self.min_bid = league.min_bid as f64;
+        self.bid_increment = league.bid_increment;
         self.total_dollars_spent = draft_state.total_spent() as f64;
         self.remaining_dollars = total_budget - self.total_dollars_spent;
 
@@ -204,7 +580,7 @@ impl InflationTracker {
         // But we can also compute it directly from available_players.
         self.remaining_predraft_value = available_players
             .iter()
-            .filter(|p| p.dollar_value > 1.0)
+            .filter(|p| p.dollar_value > self.min_bid)
             .map(|p| p.dollar_value)
             .sum();
 
@@ -213,19 +589,89 @@ impl InflationTracker {
         // But since we only have the available pool, we track it as:
         self.total_predraft_value_spent = total_budget - self.remaining_dollars;
 
-        self.inflation_rate = if self.remaining_predraft_value > 0.0 {
-            self.remaining_dollars / self.remaining_predraft_value
-        } else {
-            1.0
-        };
+        self.raw_inflation_rate = rate(self.remaining_dollars, self.remaining_predraft_value);
+        self.inflation_rate = self.raw_inflation_rate;
+    }
+
+    /// Damp outlier picks and smooth the rate over the picks, after `update`
+    /// (`[valuation] inflation_outlier_cap` and `inflation_smoothing`).
+    ///
+    /// Each pick counts for at most `inflation_outlier_cap` dollars of
+    /// surplus either side of its player's value in `pick_values` (by pick
+    /// number), and the
+    /// rate after every pick is worked out again from those damped prices:
+    /// the dollars left after it over the value of the players still
+    /// undrafted then. `inflation_rate` becomes the exponentially weighted
+    /// average of those rates, each weighted `1 - inflation_smoothing`
+    /// against the average before it. Recomputed from the picks every time,
+    /// so a rebuild lands on the same rate as pick-by-pick updates. With
+    /// both settings off the rate stays the raw one.
+    pub fn smooth(&mut self, draft_state: &DraftState, pick_values: &HashMap<u32, f64>, rules: &ValuationRulesConfig) {
+        let cap = rules.inflation_outlier_cap;
+        let weight = 1.0 - rules.inflation_smoothing;
+        if cap <= 0.0 && weight >= 1.0 {
+            return;
+        }
+
+        let picks: Vec<(f64, f64)> = draft_state
+            .picks
+            .iter()
+            .map(|p| {
+                // (pre-draft value counted in the pool, price counted as spent)
+                let price = p.price as f64;
+                match pick_values.get(&p.pick_number) {
+                    Some(&value) => {
+                        let counted = if value > self.min_bid { value } else { 0.0 };
+                        let damped = if cap > 0.0 { value + (price - value).clamp(-cap, cap) } else { price };
+                        (counted, damped)
+                    }
+                    None => (0.0, price),
+                }
+            })
+            .collect();
+
+        // Dollars spent other than on picks (keepers, ESPN's corrections)
+        // count from the start.
+        let total_budget = self.remaining_dollars + self.total_dollars_spent;
+        let pick_prices: f64 = draft_state.picks.iter().map(|p| p.price as f64).sum();
+        let mut spent = self.total_dollars_spent - pick_prices;
+        let mut value_left = self.remaining_predraft_value + picks.iter().map(|&(value, _)| value).sum::<f64>();
+
+        let mut smoothed = rate(total_budget - spent, value_left);
+        for (value, damped) in picks {
+            spent += damped;
+            value_left -= value;
+            smoothed += weight * (rate(total_budget - spent, value_left) - smoothed);
+        }
+        self.inflation_rate = smoothed;
     }
 
     /// Adjust a base dollar value by the current inflation rate.
     ///
-    /// The $1 floor is preserved: we adjust only the surplus above $1,
-    /// then re-add the floor.
+    /// The minimum-bid floor is preserved: we adjust only the surplus above
+    /// `min_bid`, then re-add the floor.
     pub fn adjust(&self, base_value: f64) -> f64 {
-        ((base_value - 1.0) * self.inflation_rate + 1.0).max(1.0)
+        ((base_value - self.min_bid) * self.inflation_rate + self.min_bid).max(self.min_bid)
+    }
+
+    /// `adjust`, rounded to a legal bid.
+    pub fn adjusted_bid(&self, base_value: f64) -> u32 {
+        self.legal_bid(self.adjust(base_value))
+    }
+
+    /// A dollar amount rounded to the nearest legal bid: a multiple of the
+    /// bid increment, at least the minimum bid.
+    pub fn legal_bid(&self, amount: f64) -> u32 {
+        ladder::legal_bid(amount, self.min_bid as u32, self.bid_increment)
+    }
+}
+
+/// Dollars left over the value left, or neutral with no value left.
+fn rate(dollars: f64, value: f64) -> f64 {
+    if value > 0.0 {
+        dollars / value
+    } else {
+        1.0
     }
 }
 
@@ -243,7 +689,9 @@ impl Default for InflationTracker {
 ///
 /// 1. Separate into hitters and pitchers.
 /// 2. Compute auction conversion factors.
-/// 3. Set `dollar_value` on each player.
+/// 3. Set `dollar_value` on each player, with the `[valuation]` floor,
+///    compression, and cap rules applied, plus the positional flexibility
+///    premium (also recorded separately in `flex_bonus`).
 /// 4. Re-sort the full list descending by dollar value.
 pub fn apply_auction_values(
     players: &mut [PlayerValuation],
@@ -259,9 +707,7 @@ pub fn apply_auction_values(
     let auction = compute_auction_values(&hitters, &pitchers, roster_config, num_teams, salary_cap, strategy);
 
     // Apply dollar values to each player.
-    for player in players.iter_mut() {
-        player.dollar_value = player_dollar_value(player, &auction);
-    }
+    price_players(players, &auction, roster_config, salary_cap, strategy);
 
     // Sort descending by dollar value.
     players.sort_by(|a, b| {
@@ -280,7 +726,7 @@ mod tests {
     use super::*;
     use wyncast_core::config::*;
     use crate::draft::pick::Position;
-    use crate::test_utils::{approx_eq, test_registry, test_roster_config, test_strategy_config, TestPlayer};
+    use crate::test_utils::{approx_eq, test_registry, test_roster_config, test_pick, test_strategy_config, TestPlayer};
     use crate::valuation::projections::PitcherType;
 
     // ---- Test helpers ----
@@ -368,6 +814,7 @@ mod tests {
             pitching_budget: 819.0,
             dollars_per_vor_hitter: 10.0,
             dollars_per_vor_pitcher: 8.0,
+            min_value: 1.0,
         };
 
         let hitter = make_hitter("Good Hitter", 5.0);
@@ -396,6 +843,7 @@ mod tests {
             pitching_budget: 819.0,
             dollars_per_vor_hitter: 10.0,
             dollars_per_vor_pitcher: 8.0,
+            min_value: 1.0,
         };
 
         let hitter = make_hitter("Bad Hitter", -5.0);
@@ -415,6 +863,7 @@ mod tests {
             pitching_budget: 819.0,
             dollars_per_vor_hitter: 10.0,
             dollars_per_vor_pitcher: 8.0,
+            min_value: 1.0,
         };
 
         let hitter = make_hitter("Replacement Hitter", 0.0);
@@ -764,10 +1213,366 @@ mod tests {
 
         // Should still be 26 (same active slots, DL excluded)
         assert_eq!(roster_size(&roster), 26);
+
+        // Minors slots sit outside the cap too.
+        roster.insert("NA".into(), 2);
+        assert_eq!(roster_size(&roster), 26);
     }
 
     // ---- Inflation Tracker tests ----
 
+    // ---- Positional flexibility ----
+
+    fn flex_hitter(name: &str, vor: f64, positions: Vec<Position>) -> PlayerValuation {
+        TestPlayer::hitter(name).vor(vor).positions(positions).build()
+    }
+
+    #[test]
+    fn eligible_starting_slots_ignores_util_and_combo_slots() {
+        let mut roster = test_roster_config();
+        roster.insert("MI".into(), 1);
+        roster.insert("CI".into(), 1);
+        roster.insert("IF".into(), 1);
+
+        let single = flex_hitter("Single", 1.0, vec![Position::FirstBase]);
+        assert_eq!(eligible_starting_slots(&single, &roster), 1);
+
+        let multi = flex_hitter(
+            "Multi",
+            1.0,
+            vec![Position::SecondBase, Position::ShortStop, Position::CenterField],
+        );
+        assert_eq!(eligible_starting_slots(&multi, &roster), 3);
+    }
+
+    #[test]
+    fn eligible_starting_slots_counts_generic_of_once() {
+        let mut roster = HashMap::new();
+        roster.insert("1B".to_string(), 1);
+        roster.insert("OF".to_string(), 3);
+        roster.insert("UTIL".to_string(), 1);
+
+        let of = flex_hitter(
+            "Outfielder",
+            1.0,
+            vec![Position::LeftField, Position::CenterField, Position::RightField],
+        );
+        assert_eq!(eligible_starting_slots(&of, &roster), 1);
+
+        let of_1b = flex_hitter("Corner", 1.0, vec![Position::FirstBase, Position::LeftField]);
+        assert_eq!(eligible_starting_slots(&of_1b, &roster), 2);
+    }
+
+    #[test]
+    fn flex_bonus_scales_with_extra_slots() {
+        let roster = test_roster_config();
+        let config = FlexibilityConfig::default();
+
+        let single = flex_hitter("Single", 1.0, vec![Position::SecondBase]);
+        assert!(approx_eq(flex_bonus(&single, 31.0, 1.0, &roster, &config), 0.0, 1e-9));
+
+        // 2B/SS: one extra slot -> 3% of the $30 surplus.
+        let two = flex_hitter("Two", 1.0, vec![Position::SecondBase, Position::ShortStop]);
+        assert!(approx_eq(flex_bonus(&two, 31.0, 1.0, &roster, &config), 0.9, 1e-9));
+
+        // 2B/SS/3B: two extra slots -> 6%.
+        let three = flex_hitter(
+            "Three",
+            1.0,
+            vec![Position::SecondBase, Position::ShortStop, Position::ThirdBase],
+        );
+        assert!(approx_eq(flex_bonus(&three, 31.0, 1.0, &roster, &config), 1.8, 1e-9));
+    }
+
+    #[test]
+    fn flex_bonus_higher_for_catchers_and_capped() {
+        let roster = test_roster_config();
+        let config = FlexibilityConfig::default();
+
+        // C/1B: one extra slot at the catcher rate (5%).
+        let catcher = flex_hitter("Catcher", 1.0, vec![Position::Catcher, Position::FirstBase]);
+        assert!(approx_eq(flex_bonus(&catcher, 21.0, 1.0, &roster, &config), 1.0, 1e-9));
+
+        // Eligible at all seven starting slots: 6 extra * 3% = 18%, capped at 12%.
+        let utility_man = flex_hitter(
+            "Everywhere",
+            1.0,
+            vec![
+                Position::FirstBase,
+                Position::SecondBase,
+                Position::ThirdBase,
+                Position::ShortStop,
+                Position::LeftField,
+                Position::CenterField,
+                Position::RightField,
+                Position::Utility,
+            ],
+        );
+        assert!(approx_eq(flex_bonus(&utility_man, 11.0, 1.0, &roster, &config), 1.2, 1e-9));
+    }
+
+    #[test]
+    fn flex_bonus_zero_for_pitchers_floor_players_and_when_disabled() {
+        let roster = test_roster_config();
+        let config = FlexibilityConfig::default();
+
+        let mut pitcher = make_pitcher("Two Slot Arm", 5.0, PitcherType::SP);
+        pitcher.positions = vec![Position::StartingPitcher, Position::ReliefPitcher];
+        assert!(approx_eq(flex_bonus(&pitcher, 30.0, 1.0, &roster, &config), 0.0, 1e-9));
+
+        let multi = flex_hitter("Multi", 0.0, vec![Position::SecondBase, Position::ShortStop]);
+        assert!(approx_eq(flex_bonus(&multi, 1.0, 1.0, &roster, &config), 0.0, 1e-9));
+
+        let disabled = FlexibilityConfig {
+            enabled: false,
+            ..FlexibilityConfig::default()
+        };
+        assert!(approx_eq(flex_bonus(&multi, 30.0, 1.0, &roster, &disabled), 0.0, 1e-9));
+    }
+
+    #[test]
+    fn apply_auction_values_records_flex_bonus_separately() {
+        let roster = test_roster_config();
+        let strategy = test_strategy_config();
+
+        let mut players = vec![
+            flex_hitter("Single", 5.0, vec![Position::SecondBase]),
+            flex_hitter("Multi", 5.0, vec![Position::SecondBase, Position::ShortStop]),
+        ];
+        apply_auction_values(&mut players, &roster, TEST_NUM_TEAMS, TEST_SALARY_CAP, &strategy);
+
+        // Same VOR, so the multi-position player sorts first by the premium alone.
+        assert_eq!(players[0].name, "Multi");
+        let single = &players[1];
+        let multi = &players[0];
+        assert!(approx_eq(single.flex_bonus, 0.0, 1e-9));
+        assert!(multi.flex_bonus > 0.0);
+        assert!(approx_eq(
+            multi.dollar_value - multi.flex_bonus,
+            single.dollar_value,
+            1e-9
+        ));
+        assert!(approx_eq(
+            multi.flex_bonus,
+            (single.dollar_value - 1.0) * strategy.flexibility.extra_slot_pct,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn flex_premium_comes_out_of_the_budget() {
+        let roster = test_roster_config();
+        let strategy = test_strategy_config();
+        let mut without = strategy.clone();
+        without.flexibility.enabled = false;
+        let pool = || {
+            vec![
+                flex_hitter("Single", 5.0, vec![Position::SecondBase]),
+                flex_hitter("Multi", 5.0, vec![Position::SecondBase, Position::ShortStop]),
+                make_pitcher("P1", 5.0, PitcherType::SP),
+            ]
+        };
+        let total = |players: &[PlayerValuation]| -> f64 { players.iter().map(|p| p.dollar_value).sum() };
+
+        let mut flexed = pool();
+        apply_auction_values(&mut flexed, &roster, TEST_NUM_TEAMS, TEST_SALARY_CAP, &strategy);
+        let mut plain = pool();
+        apply_auction_values(&mut plain, &roster, TEST_NUM_TEAMS, TEST_SALARY_CAP, &without);
+
+        assert!(flexed.iter().any(|p| p.flex_bonus > 0.0));
+        assert!(approx_eq(total(&flexed), total(&plain), 1e-6));
+    }
+
+    // ---- Adaptive split ----
+
+    fn split_draft_state(roster_config: &HashMap<String, usize>) -> DraftState {
+        let budgets: Vec<crate::draft::state::TeamBudgetPayload> = (1..=TEST_NUM_TEAMS)
+            .map(|i| crate::draft::state::TeamBudgetPayload {
+                team_id: format!("{}", i),
+                team_name: format!("Team {}", i),
+                budget: TEST_SALARY_CAP,
+            })
+            .collect();
+        let mut state = DraftState::new(TEST_SALARY_CAP, roster_config);
+        state.reconcile_budgets(&budgets);
+        state
+    }
+
+    fn adaptive_strategy() -> StrategyConfig {
+        StrategyConfig {
+            adaptive_budget_split: true,
+            ..test_strategy_config()
+        }
+    }
+
+    #[test]
+    fn effective_split_is_configured_when_fixed_or_before_spending() {
+        let roster = test_roster_config();
+        let mut state = split_draft_state(&roster);
+        let league = LeagueConfig::default();
+
+        let split = effective_budget_split(&state, &league, &adaptive_strategy());
+        assert_eq!(split, BudgetSplit { hitting_fraction: 0.65, learned: false });
+
+        state.record_pick(test_pick("Slugger").team(1).position("1B").price(60).build());
+        let split = effective_budget_split(&state, &league, &test_strategy_config());
+        assert_eq!(split, BudgetSplit { hitting_fraction: 0.65, learned: false });
+    }
+
+    #[test]
+    fn effective_split_follows_room_spending() {
+        let roster = test_roster_config();
+        let league = LeagueConfig::default();
+
+        // A room paying up for hitters is expected to keep doing so.
+        let mut hitter_room = split_draft_state(&roster);
+        for team in 1..=TEST_NUM_TEAMS {
+            hitter_room.record_pick(test_pick(&format!("H{team}")).team(team).position("1B").price(60).build());
+        }
+        let split = effective_budget_split(&hitter_room, &league, &adaptive_strategy());
+        assert!(split.learned);
+        assert!(split.hitting_fraction > 0.65, "got {}", split.hitting_fraction);
+
+        // Likewise for a room paying up for pitchers.
+        let mut pitcher_room = split_draft_state(&roster);
+        for team in 1..=TEST_NUM_TEAMS {
+            pitcher_room.record_pick(test_pick(&format!("P{team}")).team(team).position("SP").price(40).build());
+        }
+        let split = effective_budget_split(&pitcher_room, &league, &adaptive_strategy());
+        assert!(split.hitting_fraction < 0.65, "got {}", split.hitting_fraction);
+    }
+
+    #[test]
+    fn effective_split_gives_nothing_to_a_filled_side() {
+        let mut roster = HashMap::new();
+        roster.insert("1B".to_string(), 1);
+        roster.insert("SP".to_string(), 1);
+        let league = LeagueConfig::default();
+        let mut state = split_draft_state(&roster);
+        for team in 1..=TEST_NUM_TEAMS {
+            state.record_pick(test_pick(&format!("P{team}")).team(team).position("SP").price(10).build());
+        }
+
+        let split = effective_budget_split(&state, &league, &adaptive_strategy());
+        assert!(approx_eq(split.hitting_fraction, 1.0, 1e-9), "got {}", split.hitting_fraction);
+    }
+
+    #[test]
+    fn resplit_keeps_total_surplus_and_moves_dollars() {
+        let roster = test_roster_config();
+        let strategy = test_strategy_config();
+        let mut players = vec![
+            make_hitter("H1", 10.0),
+            make_hitter("H2", 5.0),
+            make_pitcher("P1", 8.0, PitcherType::SP),
+        ];
+        apply_auction_values(&mut players, &roster, TEST_NUM_TEAMS, TEST_SALARY_CAP, &strategy);
+        let surplus = |ps: &[PlayerValuation]| -> f64 {
+            ps.iter().map(|p| (p.dollar_value - p.flex_bonus - 1.0).max(0.0)).sum()
+        };
+        let before = surplus(&players);
+        let p1_before = players.iter().find(|p| p.name == "P1").unwrap().dollar_value;
+
+        resplit_remaining_pool(&mut players, 0.5, &roster, TEST_SALARY_CAP, &strategy);
+
+        assert!(approx_eq(surplus(&players), before, 1e-6));
+        let p1 = players.iter().find(|p| p.name == "P1").unwrap();
+        assert!(p1.dollar_value > p1_before, "pitchers gain at a lower hitting share");
+        assert!(approx_eq(p1.dollar_value - 1.0, before * 0.5, 1e-6));
+        assert_eq!(players[0].name, "P1", "list is re-sorted by value");
+    }
+
+    fn rules(max_cap_share: f64, compression: CompressionCurve) -> ValuationRulesConfig {
+        ValuationRulesConfig {
+            max_cap_share,
+            compression,
+            ..ValuationRulesConfig::default()
+        }
+    }
+
+    #[test]
+    fn valuation_rules_default_leaves_values_alone() {
+        let mut values = vec![150.0, 40.0, 10.0, 1.0];
+        apply_valuation_rules(&mut values, &ValuationRulesConfig::default(), 260);
+        assert_eq!(values, vec![150.0, 40.0, 10.0, 1.0]);
+    }
+
+    #[test]
+    fn max_cap_share_caps_and_redistributes() {
+        // 40% of a $260 cap is $104.
+        let mut values = vec![150.0, 40.0, 10.0, 1.0];
+        let total: f64 = values.iter().sum();
+        apply_valuation_rules(&mut values, &rules(0.40, CompressionCurve::None), 260);
+
+        assert!(approx_eq(values[0], 104.0, 1e-9), "got {}", values[0]);
+        assert!(approx_eq(values.iter().sum::<f64>(), total, 1e-6));
+        // The trimmed $46 goes to the others by surplus: 39/(39+9) and 9/48.
+        assert!(approx_eq(values[1], 40.0 + 46.0 * 39.0 / 48.0, 1e-6));
+        assert!(approx_eq(values[2], 10.0 + 46.0 * 9.0 / 48.0, 1e-6));
+        assert!(approx_eq(values[3], 1.0, 1e-9), "floor players stay at the floor");
+    }
+
+    #[test]
+    fn max_cap_share_repeats_when_redistribution_hits_the_cap() {
+        let mut values = vec![200.0, 100.0, 11.0];
+        let total: f64 = values.iter().sum();
+        apply_valuation_rules(&mut values, &rules(0.40, CompressionCurve::None), 260);
+
+        assert!(approx_eq(values[0], 104.0, 1e-9));
+        assert!(approx_eq(values[1], 104.0, 1e-9));
+        assert!(approx_eq(values.iter().sum::<f64>(), total, 1e-6));
+    }
+
+    #[test]
+    fn power_compression_narrows_the_top_end() {
+        let mut values = vec![120.0, 60.0, 20.0, 5.0];
+        let total: f64 = values.iter().sum();
+        apply_valuation_rules(&mut values, &rules(0.0, CompressionCurve::Power), 260);
+
+        assert!(values[0] < 120.0, "top player loses value, got {}", values[0]);
+        assert!(values[3] > 5.0, "cheap players gain value, got {}", values[3]);
+        assert!(values[0] > values[1], "order is preserved");
+        assert!(approx_eq(values.iter().sum::<f64>(), total, 1e-6));
+    }
+
+    #[test]
+    fn min_value_sets_the_floor() {
+        let roster = test_roster_config();
+        let mut strategy = test_strategy_config();
+        strategy.valuation.min_value = 0.0;
+        let mut players = vec![make_hitter("Star", 10.0), make_hitter("Scrub", -2.0)];
+        apply_auction_values(&mut players, &roster, TEST_NUM_TEAMS, TEST_SALARY_CAP, &strategy);
+
+        let scrub = players.iter().find(|p| p.name == "Scrub").unwrap();
+        assert!(approx_eq(scrub.dollar_value, 0.0, 1e-9), "got {}", scrub.dollar_value);
+
+        // A $0 floor frees every roster slot's dollar for distribution.
+        let star = players.iter().find(|p| p.name == "Star").unwrap();
+        let distributable = TEST_NUM_TEAMS as f64 * TEST_SALARY_CAP as f64 * 0.65;
+        assert!(approx_eq(star.dollar_value, distributable, 1e-6), "got {}", star.dollar_value);
+    }
+
+    #[test]
+    fn flex_bonus_does_not_exceed_max_share() {
+        let roster = test_roster_config();
+        let mut strategy = test_strategy_config();
+        strategy.valuation.max_cap_share = 0.40;
+        let mut players = vec![
+            TestPlayer::hitter("Utility Star")
+                .positions(vec![Position::SecondBase, Position::ShortStop])
+                .vor(10.0)
+                .build(),
+            make_hitter("H2", 5.0),
+            make_pitcher("P1", 5.0, PitcherType::SP),
+        ];
+        apply_auction_values(&mut players, &roster, TEST_NUM_TEAMS, TEST_SALARY_CAP, &strategy);
+
+        let cap = 0.40 * TEST_SALARY_CAP as f64;
+        for p in &players {
+            assert!(p.dollar_value <= cap + 1e-9, "{} valued at {}", p.name, p.dollar_value);
+        }
+    }
+
     #[test]
     fn inflation_tracker_new_defaults() {
         let tracker = InflationTracker::new();
@@ -848,6 +1653,75 @@ mod tests {
         assert!(approx_eq(adjusted_min, 1.0, 0.01));
     }
 
+    #[test]
+    fn inflation_adjustment_floors_at_zero_in_zero_dollar_leagues() {
+        let mut tracker = InflationTracker::new();
+        tracker.min_bid = 0.0;
+        tracker.inflation_rate = 0.5;
+
+        // (0.6 - 0) * 0.5 + 0 = 0.3: rounds to a $0 bid
+        assert!(approx_eq(tracker.adjust(0.6), 0.3, 1e-9));
+        assert_eq!(tracker.adjusted_bid(0.6), 0);
+        assert!(approx_eq(tracker.adjust(30.0), 15.0, 1e-9));
+
+        // The floor comes from the league on update.
+        let league = LeagueConfig { min_bid: 0, ..LeagueConfig::default() };
+        let state = DraftState::new(260, &test_roster_config());
+        let mut tracker = InflationTracker::new();
+        tracker.update(&[], &state, &league);
+        assert!(approx_eq(tracker.min_bid, 0.0, 1e-9));
+    }
+
+    #[test]
+    fn smoothing_and_damping_hold_back_an_absurd_overpay() {
+        use crate::draft::pick::DraftPick;
+        use crate::draft::state::{DraftState, TeamBudgetPayload};
+
+        let league = LeagueConfig { num_teams: 2, salary_cap: 260, ..LeagueConfig::default() };
+        let mut draft_state = DraftState::new(260, &test_roster_config());
+        draft_state.reconcile_budgets(&[
+            TeamBudgetPayload { team_id: "1".into(), team_name: "Team 1".into(), budget: 260 },
+            TeamBudgetPayload { team_id: "2".into(), team_name: "Team 2".into(), budget: 260 },
+        ]);
+        draft_state.record_pick(DraftPick {
+            pick_number: 1,
+            team_id: "2".into(),
+            team_name: "Team 2".into(),
+            player_name: "Star".into(),
+            position: "1B".into(),
+            price: 150,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        });
+        let pick_values = HashMap::from([(1, 50.0)]);
+        let available: Vec<PlayerValuation> = ["A", "B", "C"]
+            .iter()
+            .map(|name| TestPlayer::hitter(name).dollar(100.0).build())
+            .collect();
+
+        let mut tracker = InflationTracker::new();
+        tracker.update(&available, &draft_state, &league);
+        let raw = (520.0 - 150.0) / 300.0;
+        assert!(approx_eq(tracker.raw_inflation_rate, raw, 1e-9));
+
+        // Off by default: the rate is the raw one.
+        tracker.smooth(&draft_state, &pick_values, &ValuationRulesConfig::default());
+        assert!(approx_eq(tracker.inflation_rate, raw, 1e-9));
+
+        // The $100 overpay counts as $10.
+        let damped = ValuationRulesConfig { inflation_outlier_cap: 10.0, ..ValuationRulesConfig::default() };
+        tracker.smooth(&draft_state, &pick_values, &damped);
+        assert!(approx_eq(tracker.inflation_rate, (520.0 - 60.0) / 300.0, 1e-9));
+
+        // Halfway from the pre-draft rate to the raw one.
+        let smoothed = ValuationRulesConfig { inflation_smoothing: 0.5, ..ValuationRulesConfig::default() };
+        tracker.smooth(&draft_state, &pick_values, &smoothed);
+        let before = 520.0 / 350.0;
+        assert!(approx_eq(tracker.inflation_rate, before + 0.5 * (raw - before), 1e-9));
+        assert!(approx_eq(tracker.raw_inflation_rate, raw, 1e-9));
+    }
+
     #[test]
     fn inflation_update_from_draft_state() {
         use crate::draft::pick::DraftPick;
@@ -915,4 +1789,211 @@ mod tests {
         assert!(tracker.inflation_rate.is_finite());
         assert!(tracker.inflation_rate > 0.0);
     }
+
+    // ---- Property tests: dollar-conversion invariants ----
+
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// Tolerance for sums over a few hundred players.
+        const EPS: f64 = 1e-6;
+
+        /// Hitter and pitcher VOR pools, each with at least one player.
+        fn vor_pools() -> impl Strategy<Value = (Vec<f64>, Vec<f64>)> {
+            (
+                prop::collection::vec(-10.0f64..40.0, 1..150),
+                prop::collection::vec(-10.0f64..30.0, 1..100),
+            )
+        }
+
+        /// Valuation rules with a $1 floor and an arbitrary cap and curve.
+        fn valuation_rules() -> impl Strategy<Value = ValuationRulesConfig> {
+            (
+                prop_oneof![Just(0.0), 0.2f64..0.6],
+                any::<bool>(),
+                0.05f64..0.3,
+                0.5f64..1.0,
+            )
+                .prop_map(|(max_cap_share, power, knee, exponent)| ValuationRulesConfig {
+                    min_value: 1.0,
+                    max_cap_share,
+                    compression: if power { CompressionCurve::Power } else { CompressionCurve::None },
+                    compression_knee: knee,
+                    compression_exponent: exponent,
+                    ..ValuationRulesConfig::default()
+                })
+        }
+
+        fn strategy_with(hitting_fraction: f64, rules: ValuationRulesConfig) -> StrategyConfig {
+            let mut strategy = test_strategy_config();
+            strategy.hitting_budget_fraction = hitting_fraction;
+            strategy.valuation = rules;
+            strategy
+        }
+
+        fn pool(hitter_vors: &[f64], pitcher_vors: &[f64]) -> Vec<PlayerValuation> {
+            // Every third hitter plays two positions and earns the
+            // flexibility premium.
+            let hitters = hitter_vors.iter().enumerate().map(|(i, &vor)| {
+                let positions = if i % 3 == 0 {
+                    vec![Position::SecondBase, Position::ShortStop]
+                } else {
+                    vec![Position::FirstBase]
+                };
+                TestPlayer::hitter(&format!("H{i}")).vor(vor).positions(positions).build()
+            });
+            let pitchers = pitcher_vors.iter().enumerate().map(|(i, &vor)| {
+                let pt = if i % 2 == 0 { PitcherType::SP } else { PitcherType::RP };
+                make_pitcher(&format!("P{i}"), vor, pt)
+            });
+            hitters.chain(pitchers).collect()
+        }
+
+        fn priced(mut players: Vec<PlayerValuation>, strategy: &StrategyConfig) -> Vec<PlayerValuation> {
+            apply_auction_values(&mut players, &test_roster_config(), TEST_NUM_TEAMS, TEST_SALARY_CAP, strategy);
+            players
+        }
+
+        /// League budget less the floor reserved for every roster slot: the
+        /// dollars the conversion hands out above replacement level.
+        fn distributable(strategy: &StrategyConfig) -> f64 {
+            let slots = TEST_NUM_TEAMS * roster_size(&test_roster_config());
+            TEST_NUM_TEAMS as f64 * TEST_SALARY_CAP as f64
+                - slots as f64 * strategy.valuation.min_value
+        }
+
+        /// Whether the players above replacement have room under the
+        /// max-share cap for every distributable dollar.
+        fn room_under_cap(hitters: &[f64], pitchers: &[f64], strategy: &StrategyConfig) -> bool {
+            let rules = &strategy.valuation;
+            if rules.max_cap_share <= 0.0 {
+                return true;
+            }
+            let room = rules.max_cap_share * TEST_SALARY_CAP as f64 - rules.min_value;
+            let positive = hitters.iter().chain(pitchers).filter(|&&v| v > 0.0).count();
+            positive as f64 * room > distributable(strategy)
+        }
+
+        /// `(vor, dollar_value)` for one side of the pool, sorted by VOR,
+        /// leaving out the flexibility premium (which is paid for positions,
+        /// not VOR).
+        fn by_vor(players: &[PlayerValuation], pitchers: bool) -> Vec<(f64, f64)> {
+            let mut side: Vec<(f64, f64)> = players
+                .iter()
+                .filter(|p| p.is_pitcher == pitchers)
+                .map(|p| (p.vor, p.dollar_value - p.flex_bonus))
+                .collect();
+            side.sort_by(|a, b| a.0.total_cmp(&b.0));
+            side
+        }
+
+        proptest! {
+            #[test]
+            fn surplus_dollars_sum_to_the_distributable_budget(
+                (hitters, pitchers) in vor_pools(),
+                hitting_fraction in 0.3f64..0.8,
+                rules in valuation_rules(),
+            ) {
+                prop_assume!(hitters.iter().any(|&v| v > 0.0));
+                prop_assume!(pitchers.iter().any(|&v| v > 0.0));
+                let strategy = strategy_with(hitting_fraction, rules);
+                let budget = distributable(&strategy);
+                // The cap can only keep the total when the pool has room
+                // under it for every distributable dollar.
+                prop_assume!(room_under_cap(&hitters, &pitchers, &strategy));
+
+                let players = priced(pool(&hitters, &pitchers), &strategy);
+                let surplus: f64 = players
+                    .iter()
+                    .map(|p| p.dollar_value - strategy.valuation.min_value)
+                    .sum();
+                prop_assert!(
+                    (surplus - budget).abs() < EPS * budget,
+                    "surplus {} vs distributable {}", surplus, budget
+                );
+            }
+
+            #[test]
+            fn every_player_is_worth_at_least_the_floor(
+                (hitters, pitchers) in vor_pools(),
+                hitting_fraction in 0.3f64..0.8,
+                rules in valuation_rules(),
+            ) {
+                let strategy = strategy_with(hitting_fraction, rules);
+                for player in priced(pool(&hitters, &pitchers), &strategy) {
+                    prop_assert!(
+                        player.dollar_value >= 1.0 - EPS,
+                        "{} is worth {}", player.name, player.dollar_value
+                    );
+                }
+            }
+
+            #[test]
+            fn higher_vor_never_yields_fewer_dollars(
+                (hitters, pitchers) in vor_pools(),
+                hitting_fraction in 0.3f64..0.8,
+                rules in valuation_rules(),
+            ) {
+                let strategy = strategy_with(hitting_fraction, rules);
+                let players = priced(pool(&hitters, &pitchers), &strategy);
+                // Hitters and pitchers convert at different rates, so the
+                // ordering holds within each side.
+                for side in [by_vor(&players, false), by_vor(&players, true)] {
+                    for pair in side.windows(2) {
+                        prop_assert!(
+                            pair[1].1 >= pair[0].1 - EPS,
+                            "VOR {} -> ${} but VOR {} -> ${}",
+                            pair[0].0, pair[0].1, pair[1].0, pair[1].1
+                        );
+                    }
+                }
+            }
+
+            #[test]
+            fn removing_the_lowest_value_player_is_stable(
+                (hitters, pitchers) in vor_pools(),
+                hitting_fraction in 0.3f64..0.8,
+                rules in valuation_rules(),
+            ) {
+                // The premium is a share of the pool, so removing a player
+                // can shift its cost onto the rest; stability is about the
+                // conversion itself.
+                let mut strategy = strategy_with(hitting_fraction, rules);
+                strategy.flexibility.enabled = false;
+                // Without room the cap holds back dollars, and removing a
+                // player can move them around.
+                prop_assume!(room_under_cap(&hitters, &pitchers, &strategy));
+
+                let mut players = priced(pool(&hitters, &pitchers), &strategy);
+                prop_assume!(players.len() > 1);
+                let before: HashMap<String, f64> = players
+                    .iter()
+                    .map(|p| (p.name.clone(), p.dollar_value))
+                    .collect();
+
+                // Sorted descending, so the cheapest player is last.
+                let removed = players.pop().unwrap();
+                let after = priced(players, &strategy);
+
+                for player in &after {
+                    let old = before[&player.name];
+                    if removed.vor <= 0.0 {
+                        // A replacement-level player claims no surplus, so
+                        // nobody else's price moves.
+                        prop_assert!(
+                            (player.dollar_value - old).abs() < EPS,
+                            "{} moved from {} to {}", player.name, old, player.dollar_value
+                        );
+                    } else {
+                        // Its surplus goes back to the pool: nobody loses value.
+                        prop_assert!(
+                            player.dollar_value >= old - EPS,
+                            "{} fell from {} to {}", player.name, old, player.dollar_value
+                        );
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,241 @@
+// Warm-start cache of the initial valuations.
+//
+// Z-scores, VOR and auction values for every projected player are the
+// slowest part of getting to a usable screen. They depend only on the
+// projections, the league and strategy settings and the roster, so the
+// result is written to a binary file keyed by a fingerprint of those inputs
+// and read back for as long as they stay the same.
+//
+// The cache is an optimization only: a missing, stale or unreadable file
+// falls back to computing the valuations.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use wyncast_core::config::Config;
+use wyncast_core::stamp::Fnv;
+use wyncast_core::stats::StatRegistry;
+
+use super::projections::AllProjections;
+use super::zscore::PlayerValuation;
+
+/// Bump when `PlayerValuation` or the valuation math changes, so caches
+/// written by an older build are recomputed.
+const FORMAT_VERSION: u32 = 4;
+
+/// Why the cache could not be read or written.
+#[derive(Debug, thiserror::Error)]
+pub enum CacheError {
+    #[error("failed to {action} {path}: {source}")]
+    Io {
+        action: &'static str,
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("{path} is not a valuations cache: {source}")]
+    Decode { path: String, source: bincode::Error },
+
+    #[error("failed to encode the valuations cache: {0}")]
+    Encode(bincode::Error),
+}
+
+impl CacheError {
+    fn io(action: &'static str, path: &Path) -> impl FnOnce(std::io::Error) -> Self {
+        let path = path.display().to_string();
+        move |source| CacheError::Io { action, path, source }
+    }
+}
+
+/// What the cache file holds.
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    key: u64,
+    players: Vec<PlayerValuation>,
+}
+
+/// Fingerprint of everything the initial valuations are computed from.
+pub fn fingerprint(
+    projections: &AllProjections,
+    config: &Config,
+    roster_config: &HashMap<String, usize>,
+) -> u64 {
+    let strategy = &config.strategy;
+    // JSON objects keep their keys sorted, so the HashMaps among the
+    // settings (weights, roster) come out the same on every run.
+    let roster: BTreeMap<&String, &usize> = roster_config.iter().collect();
+    let settings = serde_json::json!({
+        "league": config.league,
+        "hitting_budget_fraction": strategy.hitting_budget_fraction,
+        "adaptive_budget_split": strategy.adaptive_budget_split,
+        "weights": strategy.weights,
+        "pool": strategy.pool,
+        "flexibility": strategy.flexibility,
+        "valuation": strategy.valuation,
+        "roster": roster,
+    });
+
+    let mut hash = Fnv::new();
+    hash.write(&FORMAT_VERSION.to_le_bytes());
+    let _ = write!(hash, "{settings}\n{projections:?}");
+    hash.0
+}
+
+/// The players cached at `path` under `key`. `Ok(None)` when there is no
+/// cache yet or it was written for different inputs.
+pub fn load(path: &Path, key: u64) -> Result<Option<Vec<PlayerValuation>>, CacheError> {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(CacheError::io("read", path)(e)),
+    };
+    let cache: CacheFile = bincode::deserialize(&bytes).map_err(|source| CacheError::Decode {
+        path: path.display().to_string(),
+        source,
+    })?;
+    Ok((cache.key == key).then_some(cache.players))
+}
+
+/// Write `players` to `path` under `key`, replacing any earlier cache.
+pub fn save(path: &Path, key: u64, players: &[PlayerValuation]) -> Result<(), CacheError> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(CacheError::io("create", dir))?;
+    }
+    let bytes = bincode::serialize(&CacheFile { key, players: players.to_vec() }).map_err(CacheError::Encode)?;
+    // Write under a temporary name first so a crash never leaves a
+    // truncated cache behind.
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, bytes).map_err(CacheError::io("write", &partial))?;
+    std::fs::rename(&partial, path).map_err(CacheError::io("move the cache into place at", path))?;
+    Ok(())
+}
+
+/// `compute_initial`, served from the cache at `path` when the inputs are
+/// unchanged since it was written, and refreshing it when they are not. A
+/// cache that cannot be read or written is only logged.
+pub fn compute_initial_cached(
+    projections: &AllProjections,
+    config: &Config,
+    roster_config: &HashMap<String, usize>,
+    registry: &StatRegistry,
+    path: &Path,
+) -> Vec<PlayerValuation> {
+    let key = fingerprint(projections, config, roster_config);
+    match load(path, key) {
+        Ok(Some(players)) => {
+            debug!("Loaded {} valuations from {}", players.len(), path.display());
+            return players;
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Ignoring the valuations cache: {}", e),
+    }
+
+    let players = super::compute_initial(projections, config, roster_config, registry);
+    if let Err(e) = save(path, key, &players) {
+        warn!("Failed to write the valuations cache: {}", e);
+    }
+    players
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_config, test_registry, test_roster_config};
+    use crate::valuation::projections::{HitterProjection, ProjectionSource};
+    use std::path::PathBuf;
+
+    fn cache_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join("valuations.cache")
+    }
+
+    fn hitter(name: &str, hr: u32) -> HitterProjection {
+        HitterProjection {
+            name: name.into(),
+            team: "NYY".into(),
+            pa: 600,
+            ab: 540,
+            h: 150,
+            hr,
+            r: 90,
+            rbi: 85,
+            bb: 55,
+            sb: 10,
+            avg: 0.278,
+            espn_position: "OF".into(),
+        }
+    }
+
+    fn projections() -> AllProjections {
+        AllProjections {
+            hitters: vec![hitter("Aaron Judge", 45), hitter("Juan Soto", 35), hitter("Cody Bellinger", 20)],
+            pitchers: vec![],
+            source: ProjectionSource::Espn,
+        }
+    }
+
+    #[test]
+    fn fingerprint_follows_the_inputs() {
+        let config = test_config();
+        let roster = test_roster_config();
+        let key = fingerprint(&projections(), &config, &roster);
+        assert_eq!(key, fingerprint(&projections(), &config, &roster.clone()));
+
+        let mut changed = projections();
+        changed.hitters[0].hr += 1;
+        assert_ne!(key, fingerprint(&changed, &config, &roster));
+
+        let mut reweighted = config.clone();
+        reweighted.strategy.weights.0.insert("HR".into(), 2.0);
+        assert_ne!(key, fingerprint(&projections(), &reweighted, &roster));
+
+        let mut bigger = roster.clone();
+        *bigger.entry("BE".into()).or_default() += 1;
+        assert_ne!(key, fingerprint(&projections(), &config, &bigger));
+    }
+
+    #[test]
+    fn cached_valuations_match_and_go_stale_with_the_config() {
+        let path = cache_path("valuation_cache_test_round_trip");
+        let config = test_config();
+        let roster = test_roster_config();
+        let registry = test_registry();
+
+        let fresh = compute_initial_cached(&projections(), &config, &roster, &registry, &path);
+        let key = fingerprint(&projections(), &config, &roster);
+        let cached = load(&path, key).unwrap().expect("cache written");
+        assert_eq!(cached.len(), fresh.len());
+        for (a, b) in cached.iter().zip(&fresh) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.dollar_value, b.dollar_value);
+            assert_eq!(a.category_zscores.zscores(), b.category_zscores.zscores());
+        }
+
+        let mut changed = config.clone();
+        changed.league.salary_cap += 40;
+        let other = fingerprint(&projections(), &changed, &roster);
+        assert!(load(&path, other).unwrap().is_none());
+        compute_initial_cached(&projections(), &changed, &roster, &registry, &path);
+        assert!(load(&path, other).unwrap().is_some());
+        assert!(load(&path, key).unwrap().is_none());
+    }
+
+    #[test]
+    fn unreadable_cache_is_recomputed() {
+        let path = cache_path("valuation_cache_test_garbage");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "not a cache").unwrap();
+        let config = test_config();
+        let roster = test_roster_config();
+
+        assert!(load(&path, 0).is_err());
+        let players = compute_initial_cached(&projections(), &config, &roster, &test_registry(), &path);
+        assert_eq!(players.len(), 3);
+        let key = fingerprint(&projections(), &config, &roster);
+        assert!(load(&path, key).unwrap().is_some());
+    }
+}
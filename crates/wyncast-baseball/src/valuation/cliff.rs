@@ -0,0 +1,91 @@
+// Cliff values: how far value drops behind a player at their position.
+//
+// A player's cliff is the dollar gap to the next most valuable available
+// player at their position (their VOR-assigned best position, or their first
+// listed one), counting everyone eligible there. A $20 catcher with a $6
+// catcher behind them is a bigger loss to miss than a $25 outfielder with a
+// $23 one behind them, so the cliff says who to target, or nominate, first.
+// The last player at a position drops all the way to nothing.
+
+use std::collections::HashMap;
+
+use crate::draft::pick::Position;
+use crate::valuation::scarcity::player_eligible_at;
+use crate::valuation::zscore::PlayerValuation;
+
+/// The position a player's cliff is measured at.
+pub fn cliff_position(player: &PlayerValuation) -> Option<Position> {
+    player.best_position.or_else(|| player.positions.first().copied())
+}
+
+/// Each player's cliff value, aligned with `players`. Never negative.
+pub fn cliff_values(players: &[PlayerValuation]) -> Vec<f64> {
+    // Dollar values at each position any player is measured at, high to low.
+    let mut ladders: HashMap<Position, Vec<f64>> = HashMap::new();
+    for position in players.iter().filter_map(cliff_position) {
+        ladders.entry(position).or_insert_with(|| {
+            let mut values: Vec<f64> = players
+                .iter()
+                .filter(|p| player_eligible_at(p, position))
+                .map(|p| p.dollar_value)
+                .collect();
+            values.sort_by(|a, b| b.total_cmp(a));
+            values
+        });
+    }
+
+    players
+        .iter()
+        .map(|player| {
+            let Some(ladder) = cliff_position(player).and_then(|pos| ladders.get(&pos)) else {
+                return 0.0;
+            };
+            // The player holds the first spot at their value; the next best
+            // is the one after it, which may be a tie.
+            let rank = ladder.partition_point(|&v| v > player.dollar_value);
+            let next = ladder.get(rank + 1).copied().unwrap_or(0.0);
+            (player.dollar_value - next).max(0.0)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestPlayer;
+    use crate::valuation::projections::PitcherType;
+
+    #[test]
+    fn cliff_is_the_gap_to_the_next_best_at_the_position() {
+        let players = vec![
+            TestPlayer::hitter("OF1").positions(vec![Position::LeftField]).dollar(25.0).build(),
+            TestPlayer::hitter("C1").positions(vec![Position::Catcher]).dollar(20.0).build(),
+            TestPlayer::hitter("OF2").positions(vec![Position::LeftField]).dollar(23.0).build(),
+            TestPlayer::hitter("C2").positions(vec![Position::Catcher]).dollar(6.0).build(),
+            TestPlayer::pitcher("Ace", PitcherType::SP).dollar(30.0).build(),
+        ];
+        let cliffs = cliff_values(&players);
+        assert_eq!(cliffs[0], 2.0);
+        assert_eq!(cliffs[1], 14.0);
+        assert_eq!(cliffs[2], 23.0, "the last one at a position drops to nothing");
+        assert_eq!(cliffs[3], 6.0);
+        assert_eq!(cliffs[4], 30.0);
+    }
+
+    #[test]
+    fn eligible_players_count_as_next_best_and_ties_have_no_cliff() {
+        let players = vec![
+            TestPlayer::hitter("SS").positions(vec![Position::ShortStop]).dollar(18.0).build(),
+            TestPlayer::hitter("2B/SS")
+                .positions(vec![Position::SecondBase, Position::ShortStop])
+                .dollar(15.0)
+                .build(),
+            TestPlayer::hitter("SS twin").positions(vec![Position::ShortStop]).dollar(15.0).build(),
+        ];
+        let cliffs = cliff_values(&players);
+        assert_eq!(cliffs[0], 3.0);
+        assert_eq!(cliffs[1], 15.0, "measured at 2B, where nobody is behind them");
+        assert_eq!(cliffs[2], 0.0, "the 2B/SS is just as good at SS");
+        assert_eq!(cliff_values(&[]), Vec::<f64>::new());
+    }
+}
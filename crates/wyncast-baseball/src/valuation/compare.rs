@@ -0,0 +1,225 @@
+// Side-by-side comparison of two players, for choosing between similar
+// targets at the same price.
+//
+// Lines the two up on everything the choice turns on: value (mine, the
+// consensus, and today's inflated price), VOR, each category's projection
+// and z-score, playing time and any projection adjustments (the risk), and
+// fit: whether I have an open slot they can fill and how their z-scores
+// line up with my category needs. Schedules are not part of the
+// projections, so fit is judged on the roster and categories alone.
+
+use wyncast_core::stats::{self, CategoryValues, StatComputation, StatRegistry};
+
+use super::auction::InflationTracker;
+use super::scarcity::player_eligible_at;
+use super::zscore::PlayerValuation;
+use crate::draft::pick::Position;
+use crate::draft::roster::Roster;
+
+/// One side of the comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparedPlayer {
+    pub name: String,
+    pub team: String,
+    pub positions: Vec<Position>,
+    pub dollar_value: f64,
+    pub consensus_value: f64,
+    /// Value after inflation: what the player should go for now.
+    pub adjusted_value: f64,
+    pub vor: f64,
+    pub total_zscore: f64,
+    /// Projected plate appearances for hitters, innings for pitchers.
+    pub playing_time: f64,
+    /// Factors from the adjustments CSV behind the projection, e.g.
+    /// `x0.90 LAD: hamstring`, one per factor and reason.
+    pub adjustments: Vec<String>,
+    /// Whether my roster has an open starting slot they can fill.
+    pub open_slot: bool,
+    /// Category z-scores weighted by my category needs.
+    pub need_fit: f64,
+}
+
+/// One category for both players.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryLine {
+    pub category: String,
+    /// Whether the stat is a rate (AVG, ERA, ...).
+    pub rate: bool,
+    pub projections: [f64; 2],
+    pub zscores: [f64; 2],
+}
+
+/// Two players side by side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerComparison {
+    pub players: [ComparedPlayer; 2],
+    /// Categories scored for either player, batting before pitching.
+    pub categories: Vec<CategoryLine>,
+}
+
+/// Compare `a` and `b` for my roster (if my team is known) and category
+/// needs.
+pub fn compare(
+    a: &PlayerValuation,
+    b: &PlayerValuation,
+    roster: Option<&Roster>,
+    category_needs: &CategoryValues,
+    inflation: &InflationTracker,
+    registry: &StatRegistry,
+) -> PlayerComparison {
+    let hits = |p: &PlayerValuation| !p.is_pitcher;
+    let pitches = |p: &PlayerValuation| p.is_pitcher || p.is_two_way;
+    let mut indices: Vec<usize> = Vec::new();
+    if hits(a) || hits(b) {
+        indices.extend(registry.batting_indices());
+    }
+    if pitches(a) || pitches(b) {
+        indices.extend(registry.pitching_indices());
+    }
+
+    let all_stats = registry.all_stats();
+    let projections = [a, b].map(|p| stats::ProjectionData::from(&p.projection));
+    let categories = indices
+        .into_iter()
+        .map(|idx| {
+            let def = &all_stats[idx];
+            let (key, rate) = match &def.computation {
+                StatComputation::Counting { projection_key } => (projection_key, false),
+                StatComputation::RateStat { rate_key, .. } => (rate_key, true),
+            };
+            CategoryLine {
+                category: def.abbrev.clone(),
+                rate,
+                projections: [0, 1].map(|i| projections[i].get_or_zero(key)),
+                zscores: [a, b].map(|p| p.category_zscores.zscores().get(idx).unwrap_or(0.0)),
+            }
+        })
+        .collect();
+
+    PlayerComparison {
+        players: [a, b].map(|p| compared(p, roster, category_needs, inflation)),
+        categories,
+    }
+}
+
+fn compared(
+    player: &PlayerValuation,
+    roster: Option<&Roster>,
+    category_needs: &CategoryValues,
+    inflation: &InflationTracker,
+) -> ComparedPlayer {
+    let zscores = player.category_zscores.zscores();
+    let need_fit = (0..category_needs.len())
+        .map(|i| zscores.get(i).unwrap_or(0.0) * category_needs.get(i).unwrap_or(0.0))
+        .sum();
+    let playing_time = if player.is_pitcher {
+        player.projection.get("ip")
+    } else {
+        player.projection.get("pa")
+    };
+
+    let mut adjustments: Vec<String> = Vec::new();
+    for applied in &player.projection.adjustments {
+        let line = format!("x{:.2} {}", applied.factor, applied.reason);
+        if !adjustments.contains(&line) {
+            adjustments.push(line);
+        }
+    }
+
+    ComparedPlayer {
+        name: player.name.clone(),
+        team: player.team.clone(),
+        positions: player.positions.clone(),
+        dollar_value: player.dollar_value,
+        consensus_value: player.consensus_value,
+        adjusted_value: inflation.adjust(player.dollar_value),
+        vor: player.vor,
+        total_zscore: player.total_zscore,
+        playing_time,
+        adjustments,
+        open_slot: roster.is_some_and(|r| has_open_slot(r, player)),
+        need_fit,
+    }
+}
+
+/// An empty starting slot (not bench or IL) the player is eligible for.
+fn has_open_slot(roster: &Roster, player: &PlayerValuation) -> bool {
+    roster.slots.iter().any(|slot| {
+        slot.player.is_none()
+            && match slot.position {
+                Position::Bench | Position::InjuredList | Position::Minors => false,
+                Position::Utility => !player.is_pitcher,
+                position => player_eligible_at(player, position),
+            }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_registry, test_roster_config, TestPlayer};
+
+    #[test]
+    fn compares_values_categories_and_fit() {
+        let registry = test_registry();
+        let catcher = TestPlayer::hitter("Catcher")
+            .positions(vec![Position::Catcher])
+            .dollar(20.0)
+            .zscores(&[("HR", 1.5), ("SB", -0.5)])
+            .build();
+        let mut shortstop = TestPlayer::hitter("Shortstop")
+            .positions(vec![Position::ShortStop])
+            .dollar(21.0)
+            .zscores(&[("HR", 0.2), ("SB", 2.0)])
+            .build();
+        shortstop.consensus_value = 25.0;
+
+        let mut roster = Roster::new(&test_roster_config());
+        // Catcher and utility filled: nowhere left to start a catcher.
+        let filled = [Position::Catcher, Position::Utility];
+        for slot in roster.slots.iter_mut().filter(|s| filled.contains(&s.position)) {
+            slot.player = Some(crate::draft::roster::RosteredPlayer {
+                name: "Mine".into(),
+                price: 5,
+                position: Position::Catcher,
+                eligible_slots: vec![],
+                espn_player_id: None,
+            });
+        }
+        let mut needs = CategoryValues::zeros(registry.len());
+        needs.set(registry.index_of("SB").unwrap(), 1.0);
+
+        let inflation = InflationTracker::new();
+        let comparison = compare(&catcher, &shortstop, Some(&roster), &needs, &inflation, &registry);
+        let [c, s] = &comparison.players;
+        assert_eq!((c.dollar_value, s.consensus_value), (20.0, 25.0));
+        assert_eq!(c.playing_time, 600.0);
+        assert!(!c.open_slot && s.open_slot);
+        assert_eq!((c.need_fit, s.need_fit), (-0.5, 2.0));
+
+        // Two hitters: batting categories only.
+        assert_eq!(comparison.categories.len(), registry.batting_indices().len());
+        let hr = comparison.categories.iter().find(|l| l.category == "HR").unwrap();
+        assert_eq!((hr.projections, hr.zscores), ([25.0, 25.0], [1.5, 0.2]));
+        let avg = comparison.categories.iter().find(|l| l.category == "AVG").unwrap();
+        assert!(avg.rate);
+    }
+
+    #[test]
+    fn a_hitter_against_a_pitcher_shows_both_sides() {
+        let registry = test_registry();
+        let hitter = TestPlayer::hitter("Bat").build();
+        let pitcher = TestPlayer::pitcher("Arm", crate::valuation::projections::PitcherType::SP).build();
+        let comparison = compare(
+            &hitter,
+            &pitcher,
+            None,
+            &CategoryValues::zeros(registry.len()),
+            &InflationTracker::new(),
+            &registry,
+        );
+        assert_eq!(comparison.categories.len(), registry.len());
+        assert_eq!(comparison.players[1].playing_time, 180.0);
+        assert!(!comparison.players[0].open_slot);
+    }
+}
@@ -0,0 +1,89 @@
+// Custom stats: the computed columns defined under `[display] custom_stats`.
+//
+// Each definition is parsed once, when the app starts, and evaluated
+// against every valued player's projection by a valuation plugin, so the
+// values are there after every revaluation. Player values are left alone.
+
+use tracing::warn;
+use wyncast_core::expr::CustomStat;
+
+use super::plugins::ValuationPlugin;
+use super::zscore::PlayerValuation;
+
+/// The configured custom stats, computed into each player's `custom_stats`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomStats {
+    stats: Vec<CustomStat>,
+}
+
+impl CustomStats {
+    /// Parse the `name = expression` definitions. One that does not parse
+    /// (config validation reports it) is logged and left out.
+    pub fn parse(definitions: &[String]) -> Self {
+        let stats = definitions
+            .iter()
+            .filter_map(|definition| match CustomStat::parse(definition) {
+                Ok(stat) => Some(stat),
+                Err(e) => {
+                    warn!("Skipping custom stat \"{}\": {}", definition, e);
+                    None
+                }
+            })
+            .collect();
+        CustomStats { stats }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stats.is_empty()
+    }
+
+    /// Column names, in config order.
+    pub fn names(&self) -> Vec<String> {
+        self.stats.iter().map(|s| s.name.clone()).collect()
+    }
+}
+
+impl ValuationPlugin for CustomStats {
+    fn name(&self) -> &str {
+        "custom stats"
+    }
+
+    fn adjust(&self, players: &mut [PlayerValuation]) {
+        for player in players {
+            let values = &player.projection.values;
+            player.custom_stats = self
+                .stats
+                .iter()
+                .map(|stat| stat.expr.eval(&|key| values.get(key).copied()))
+                .collect();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestPlayer;
+    use crate::valuation::projections::PitcherType;
+
+    #[test]
+    fn stats_are_computed_from_each_projection() {
+        let stats = CustomStats::parse(&["kbb = (k - bb) / ip".into(), "broken = k +".into(), "hr_pa = hr / pa".into()]);
+        assert_eq!(stats.names(), ["kbb", "hr_pa"]);
+
+        let mut ace = TestPlayer::pitcher("Ace", PitcherType::SP).dollar(30.0).build();
+        ace.projection.values.extend([("k".into(), 220.0), ("bb".into(), 40.0), ("ip".into(), 200.0)]);
+        let mut slugger = TestPlayer::hitter("Slugger").dollar(35.0).build();
+        slugger.projection.values.extend([("hr".into(), 40.0), ("pa".into(), 640.0)]);
+        let mut players = vec![slugger, ace];
+
+        stats.adjust(&mut players);
+        assert_eq!(players[0].custom_stats, vec![None, Some(0.0625)]);
+        assert_eq!(players[1].custom_stats, vec![Some(0.9), None]);
+        assert_eq!(players[0].dollar_value, 35.0);
+
+        // Adjusting again recomputes rather than appends.
+        stats.adjust(&mut players);
+        assert_eq!(players[1].custom_stats.len(), 2);
+    }
+}
@@ -0,0 +1,91 @@
+// Draft board: the available players ranked, for snake drafts.
+//
+// A snake draft has no prices, so rather than dollar values the players are
+// ranked by value over replacement, the same position-adjusted measure the
+// auction values start from, best first. Ties (typically among players at
+// replacement level) fall back to the total z-score.
+//
+// The valuation pipeline ranks the pool in place of the auction conversion:
+// the available players are kept in board order and carry no dollar value,
+// so a player's rank is their place in the pool.
+
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use super::zscore::PlayerValuation;
+use crate::draft::pick::positions_str;
+
+/// One player's place on the board.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardEntry {
+    /// 1 for the best available player.
+    pub rank: usize,
+    pub name: String,
+    /// Eligible positions, e.g. "2B/SS".
+    pub positions: String,
+    pub vor: f64,
+}
+
+/// Board order: value over replacement, then total z-score, best first.
+fn board_order(a: &PlayerValuation, b: &PlayerValuation) -> Ordering {
+    b.vor.total_cmp(&a.vor).then(b.total_zscore.total_cmp(&a.total_zscore))
+}
+
+/// Put `players` in board order with no dollar values: the snake draft's
+/// stand-in for the auction conversion.
+pub fn apply_ranks(players: &mut [PlayerValuation]) {
+    for player in players.iter_mut() {
+        player.dollar_value = 0.0;
+    }
+    players.sort_by(board_order);
+}
+
+/// `players` ranked best first.
+pub fn draft_board(players: &[PlayerValuation]) -> Vec<BoardEntry> {
+    let mut ranked: Vec<&PlayerValuation> = players.iter().collect();
+    ranked.sort_by(|a, b| board_order(a, b));
+    ranked
+        .into_iter()
+        .enumerate()
+        .map(|(i, p)| BoardEntry {
+            rank: i + 1,
+            name: p.name.clone(),
+            positions: positions_str(&p.positions),
+            vor: p.vor,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestPlayer;
+
+    #[test]
+    fn players_are_ranked_by_value_over_replacement() {
+        let players = vec![
+            TestPlayer::hitter("Replacement").vor(0.0).total_zscore(-1.0).build(),
+            TestPlayer::hitter("Star").vor(6.0).total_zscore(5.0).build(),
+            TestPlayer::hitter("Starter").vor(2.5).total_zscore(3.0).build(),
+            TestPlayer::hitter("Bench").vor(0.0).total_zscore(0.5).build(),
+        ];
+
+        let board = draft_board(&players);
+        let names: Vec<&str> = board.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["Star", "Starter", "Bench", "Replacement"]);
+        assert_eq!(board[0].rank, 1);
+        assert_eq!(board[3].rank, 4);
+    }
+
+    #[test]
+    fn ranking_orders_the_pool_without_prices() {
+        let mut players = vec![
+            TestPlayer::hitter("Bench").vor(0.0).total_zscore(0.5).dollar(1.0).build(),
+            TestPlayer::hitter("Star").vor(6.0).total_zscore(5.0).dollar(40.0).build(),
+        ];
+        apply_ranks(&mut players);
+        assert_eq!((players[0].name.as_str(), players[1].name.as_str()), ("Star", "Bench"));
+        assert!(players.iter().all(|p| p.dollar_value == 0.0));
+    }
+}
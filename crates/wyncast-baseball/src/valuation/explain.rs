@@ -0,0 +1,220 @@
+// Valuation explain view: the calculation chain behind one player's value.
+//
+// Walks a single player from projections to price: the stat scored in each
+// category against the pool's mean and standard deviation, the category
+// weights, the replacement baseline the VOR is measured from, what each
+// unit of VOR is worth in dollars, the flexibility premium, and the
+// inflation multiplier. The pool statistics are recomputed from the
+// projections the same way the initial valuation computes them; everything
+// else comes from the player's own valuation, so the chain always ends at
+// the value shown everywhere else.
+
+use std::collections::HashMap;
+
+use wyncast_core::config::Config;
+use wyncast_core::stats::{self, StatComputation, StatRegistry};
+
+use super::auction::InflationTracker;
+use super::projections::AllProjections;
+use super::zscore::{pool_baseline, weights_to_category_values, PlayerValuation};
+use crate::draft::pick::Position;
+
+/// One category's step: the projected stat, how it compares to the pool,
+/// and what it adds to the total z-score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryStep {
+    pub category: String,
+    /// The projected stat: a count, or the rate for rate stats.
+    pub stat: f64,
+    /// What was scored: the stat itself, or its volume-weighted
+    /// contribution over the league average for rate stats.
+    pub scored: f64,
+    /// Whether the stat is a rate (AVG, ERA, ...) scored by contribution.
+    pub rate: bool,
+    pub pool_mean: f64,
+    pub pool_stdev: f64,
+    pub zscore: f64,
+    pub weight: f64,
+}
+
+impl CategoryStep {
+    /// The z-score times the category weight.
+    pub fn weighted(&self) -> f64 {
+        self.zscore * self.weight
+    }
+}
+
+/// Every step from a player's projections to their final value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValuationExplanation {
+    pub player: String,
+    /// Scored categories, batting before pitching.
+    pub categories: Vec<CategoryStep>,
+    /// Sum of the weighted z-scores.
+    pub total_zscore: f64,
+    /// The position the VOR is measured at.
+    pub position: Option<Position>,
+    /// Replacement-level z-score at that position.
+    pub replacement: f64,
+    pub vor: f64,
+    /// Dollars per unit of VOR, after the compression and cap rules.
+    pub dollars_per_vor: f64,
+    /// The value of a replacement-level player.
+    pub min_value: f64,
+    pub flex_bonus: f64,
+    /// The model's value: floor + VOR dollars + flexibility bonus.
+    pub base_value: f64,
+    pub inflation_rate: f64,
+    /// The base value adjusted for inflation: what to pay today.
+    pub adjusted_value: f64,
+}
+
+/// Explain `player`'s valuation. `projections`, `config` and `roster_config`
+/// must be the ones the valuations were computed from.
+pub fn explain(
+    player: &PlayerValuation,
+    projections: &AllProjections,
+    config: &Config,
+    roster_config: &HashMap<String, usize>,
+    registry: &StatRegistry,
+    inflation: &InflationTracker,
+) -> ValuationExplanation {
+    let pool = config.strategy.pool.covering(roster_config, config.league.num_teams);
+    let baseline = pool_baseline(projections, &pool, registry);
+    let weights = weights_to_category_values(&config.strategy.weights, registry);
+    let projection = stats::ProjectionData::from(&player.projection);
+
+    let mut sides = Vec::new();
+    if !player.is_pitcher {
+        sides.push((registry.batting_indices(), &baseline.hitter_stats, &baseline.hitter_league_avgs));
+    }
+    if player.is_pitcher || player.is_two_way {
+        sides.push((registry.pitching_indices(), &baseline.pitcher_stats, &baseline.pitcher_league_avgs));
+    }
+
+    let all_stats = registry.all_stats();
+    let mut categories = Vec::new();
+    for (indices, pool_stats, league_avgs) in sides {
+        for &idx in indices {
+            let def = &all_stats[idx];
+            let (stat, scored, rate) = match &def.computation {
+                StatComputation::Counting { projection_key } => {
+                    let value = projection.get_or_zero(projection_key);
+                    (value, value, false)
+                }
+                StatComputation::RateStat { volume_key, rate_key, divisor } => {
+                    let rate = projection.get_or_zero(rate_key);
+                    let contribution = stats::rate_stat_contribution(
+                        projection.get_or_zero(volume_key),
+                        rate,
+                        league_avgs.get(&idx).copied().unwrap_or(0.0),
+                        *divisor,
+                        def.sort_direction,
+                    );
+                    (rate, contribution, true)
+                }
+            };
+            categories.push(CategoryStep {
+                category: def.abbrev.clone(),
+                stat,
+                scored,
+                rate,
+                pool_mean: pool_stats[idx].mean,
+                pool_stdev: pool_stats[idx].stdev,
+                zscore: player.category_zscores.zscores().get(idx).unwrap_or(0.0),
+                weight: weights.get(idx).unwrap_or(0.0),
+            });
+        }
+    }
+
+    let min_value = config.strategy.valuation.min_value;
+    let vor_dollars = player.dollar_value - player.flex_bonus - min_value;
+    ValuationExplanation {
+        player: player.name.clone(),
+        categories,
+        total_zscore: player.total_zscore,
+        position: player.best_position,
+        replacement: player.total_zscore - player.vor,
+        vor: player.vor,
+        dollars_per_vor: if player.vor > 0.0 { vor_dollars / player.vor } else { 0.0 },
+        min_value,
+        flex_bonus: player.flex_bonus,
+        base_value: player.dollar_value,
+        inflation_rate: inflation.inflation_rate,
+        adjusted_value: inflation.adjust(player.dollar_value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_close, test_config, test_registry, test_roster_config};
+    use crate::valuation::compute_initial;
+    use crate::valuation::projections::{HitterProjection, ProjectionSource};
+
+    fn hitter(name: &str, hr: u32, avg: f64) -> HitterProjection {
+        HitterProjection {
+            name: name.into(),
+            team: "NYY".into(),
+            pa: 600,
+            ab: 540,
+            h: (540.0 * avg) as u32,
+            hr,
+            r: 90,
+            rbi: 85,
+            bb: 55,
+            sb: 10,
+            avg,
+            espn_position: "1B".into(),
+        }
+    }
+
+    #[test]
+    fn explanation_rebuilds_the_value_chain() {
+        let projections = AllProjections {
+            hitters: vec![
+                hitter("Slugger", 45, 0.290),
+                hitter("Regular", 25, 0.265),
+                hitter("Scrub", 8, 0.230),
+            ],
+            pitchers: vec![],
+            source: ProjectionSource::Espn,
+        };
+        let config = test_config();
+        let roster = test_roster_config();
+        let registry = test_registry();
+        let players = compute_initial(&projections, &config, &roster, &registry);
+        let slugger = players.iter().find(|p| p.name == "Slugger").unwrap();
+        let mut inflation = InflationTracker::new();
+        inflation.inflation_rate = 1.2;
+
+        let explanation = explain(slugger, &projections, &config, &roster, &registry, &inflation);
+
+        // Batting categories only, each re-deriving the stored z-score.
+        let names: Vec<&str> = explanation.categories.iter().map(|c| c.category.as_str()).collect();
+        assert_eq!(names, vec!["R", "HR", "RBI", "BB", "SB", "AVG"]);
+        for step in &explanation.categories {
+            let z = if step.pool_stdev > 0.0 { (step.scored - step.pool_mean) / step.pool_stdev } else { 0.0 };
+            assert_close(z, step.zscore, &step.category);
+        }
+        let hr = &explanation.categories[1];
+        assert_eq!((hr.stat, hr.rate), (45.0, false));
+        assert_close(hr.pool_mean, 26.0, "HR mean");
+        let avg = &explanation.categories[5];
+        assert_eq!(avg.stat, 0.290);
+        assert!(avg.rate && avg.scored > 0.0);
+
+        // The weighted z-scores add up to the total, and the chain ends at
+        // the player's value.
+        let weighted: f64 = explanation.categories.iter().map(CategoryStep::weighted).sum();
+        assert_close(weighted, slugger.total_zscore, "total z");
+        assert_close(explanation.replacement + explanation.vor, slugger.total_zscore, "baseline");
+        assert_eq!(explanation.position, Some(Position::FirstBase));
+        assert_close(
+            explanation.min_value + explanation.vor * explanation.dollars_per_vor + explanation.flex_bonus,
+            slugger.dollar_value,
+            "base value",
+        );
+        assert_close(explanation.adjusted_value, inflation.adjust(slugger.dollar_value), "adjusted");
+    }
+}
@@ -0,0 +1,367 @@
+// Telling apart players who share a name.
+//
+// Two players can share a name (Will Smith the catcher and Will Smith the
+// reliever), and the extension identifies a pick or a nomination by name
+// and ESPN position only. Lookups of pool players by name go through
+// `find_player`, which narrows same-named players by position and, when the
+// caller knows it, MLB team. A pick that still matches more than one player
+// is left in the pool for me to resolve rather than removing the wrong one
+// (or both).
+
+use crate::draft::pick::{position_tokens, positions_str, DraftPick, Position};
+use crate::valuation::projections::{AllProjections, PitcherType};
+use crate::valuation::zscore::PlayerValuation;
+
+/// How a name matched the player pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerMatch {
+    /// Nobody in the pool has the name.
+    Missing,
+    /// Exactly one player fits; their index in the pool.
+    Unique(usize),
+    /// Several players fit even after narrowing; their indices.
+    Ambiguous(Vec<usize>),
+}
+
+impl PlayerMatch {
+    /// The matched player, if exactly one fits.
+    pub fn unique(&self) -> Option<usize> {
+        match self {
+            PlayerMatch::Unique(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// The matched player, or the first candidate when several fit: for
+    /// lookups where a guess beats nothing, like the nominated player's
+    /// analysis.
+    pub fn best(&self) -> Option<usize> {
+        match self {
+            PlayerMatch::Missing => None,
+            PlayerMatch::Unique(i) => Some(*i),
+            PlayerMatch::Ambiguous(candidates) => candidates.first().copied(),
+        }
+    }
+}
+
+/// Find `name` in `players`, narrowing same-named players by the ESPN
+/// `position` (e.g. `C`, `SP`, `OF`; empty when unknown) and the MLB `team`
+/// when given. A filter that would rule out every candidate is skipped.
+pub fn find_player(players: &[PlayerValuation], name: &str, position: &str, team: Option<&str>) -> PlayerMatch {
+    let mut candidates: Vec<usize> = players
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.name == name)
+        .map(|(i, _)| i)
+        .collect();
+
+    if candidates.len() > 1 {
+        narrow(&mut candidates, |i| fits_position(&players[i], position));
+    }
+    if let Some(team) = team.filter(|_| candidates.len() > 1) {
+        narrow(&mut candidates, |i| players[i].team.eq_ignore_ascii_case(team));
+    }
+
+    match candidates.len() {
+        0 => PlayerMatch::Missing,
+        1 => PlayerMatch::Unique(candidates[0]),
+        _ => PlayerMatch::Ambiguous(candidates),
+    }
+}
+
+/// A pool player as a view shows them: enough to tell namesakes apart
+/// when a command names one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerRef {
+    pub name: String,
+    /// MLB team.
+    pub team: String,
+    /// Positions as a compact string, e.g. `1B/OF`.
+    pub position: String,
+}
+
+impl PlayerRef {
+    pub fn of(player: &PlayerValuation) -> Self {
+        Self {
+            name: player.name.clone(),
+            team: player.team.clone(),
+            position: positions_str(&player.positions),
+        }
+    }
+
+    /// A reference by name alone, which any namesake fits.
+    pub fn named(name: &str) -> Self {
+        Self { name: name.to_string(), team: String::new(), position: String::new() }
+    }
+
+    /// Find the player in `players`, by name, then position and team.
+    pub fn find(&self, players: &[PlayerValuation]) -> PlayerMatch {
+        let team = Some(self.team.as_str()).filter(|t| !t.is_empty());
+        find_player(players, &self.name, &self.position, team)
+    }
+}
+
+/// Every position played by someone named `name`, for a pick that names
+/// the player but not which namesake: it fits each of them.
+pub fn name_positions(players: &[PlayerValuation], name: &str) -> Vec<Position> {
+    let mut positions = Vec::new();
+    for position in players.iter().filter(|p| p.name == name).flat_map(|p| &p.positions) {
+        if !positions.contains(position) {
+            positions.push(*position);
+        }
+    }
+    positions
+}
+
+/// Keep the candidates passing `keep`, unless none would.
+fn narrow(candidates: &mut Vec<usize>, keep: impl Fn(usize) -> bool) {
+    if candidates.iter().any(|&i| keep(i)) {
+        candidates.retain(|&i| keep(i));
+    }
+}
+
+/// Whether the player can be listed at an ESPN position string such as
+/// `SS`, `OF` or `SP/RP`. Pitchers fit any pitcher position, since ESPN
+/// and the projections don't always agree on starter or reliever. An
+/// empty or unknown position fits everyone.
+pub fn fits_position(player: &PlayerValuation, position: &str) -> bool {
+    let pitches = player.is_pitcher || player.is_two_way;
+    let mut known = false;
+    for token in position_tokens(position) {
+        let fits = match token.to_ascii_uppercase().as_str() {
+            "P" | "SP" | "RP" => pitches,
+            "OF" | "LF" | "CF" | "RF" => player.positions.iter().any(|p| {
+                matches!(p, Position::LeftField | Position::CenterField | Position::RightField)
+            }),
+            "DH" | "UTIL" => !player.is_pitcher,
+            _ => match Position::from_str_pos(token) {
+                Some(pos) => player.positions.contains(&pos),
+                None => continue,
+            },
+        };
+        if fits {
+            return true;
+        }
+        known = true;
+    }
+    !known
+}
+
+/// Whether a hitter and a pitcher projection row are the same person (a
+/// two-way player): the same name, on the same team when both are known.
+pub fn same_player(name: &str, team: &str, other_name: &str, other_team: &str) -> bool {
+    name == other_name && (team.is_empty() || other_team.is_empty() || team.eq_ignore_ascii_case(other_team))
+}
+
+/// A name more than one player in the projections goes by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedName {
+    pub name: String,
+    /// Each player's team and position, e.g. `LAD C`.
+    pub players: Vec<String>,
+}
+
+impl std::fmt::Display for SharedName {
+    /// `Will Smith (LAD C, ATL RP)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.players.join(", "))
+    }
+}
+
+/// Names shared by more than one player in the projections. Rows merged as
+/// a two-way player count once.
+pub fn duplicate_names(projections: &AllProjections) -> Vec<SharedName> {
+    // (name, team, position) per person.
+    let mut people: Vec<(&str, &str, &str)> = projections
+        .hitters
+        .iter()
+        .map(|h| (h.name.as_str(), h.team.as_str(), h.espn_position.as_str()))
+        .collect();
+    for pitcher in &projections.pitchers {
+        let two_way = projections
+            .hitters
+            .iter()
+            .any(|h| same_player(&h.name, &h.team, &pitcher.name, &pitcher.team));
+        if !two_way {
+            let position = match pitcher.pitcher_type {
+                PitcherType::SP => "SP",
+                PitcherType::RP => "RP",
+            };
+            people.push((&pitcher.name, &pitcher.team, position));
+        }
+    }
+
+    let mut names: Vec<&str> = people.iter().map(|(name, _, _)| *name).collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let players: Vec<String> = people
+                .iter()
+                .filter(|(n, _, _)| *n == name)
+                .map(|(_, team, position)| format!("{team} {position}").trim().to_string())
+                .collect();
+            (players.len() > 1).then(|| SharedName { name: name.to_string(), players })
+        })
+        .collect()
+}
+
+/// A pick whose name matches more than one player in the pool, waiting for
+/// me to say which one went.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmbiguousPick {
+    pub pick_number: u32,
+    pub player_name: String,
+    /// The ESPN position on the pick.
+    pub position: String,
+    /// The fantasy team that bought the player.
+    pub team_name: String,
+    pub price: u32,
+    pub candidates: Vec<PickCandidate>,
+}
+
+/// One of the players an ambiguous pick could be.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PickCandidate {
+    /// MLB team, which tells same-named players apart.
+    pub team: String,
+    pub positions: Vec<Position>,
+    pub dollar_value: f64,
+}
+
+impl AmbiguousPick {
+    /// The ambiguous pick for `pick` among the `candidates` indices of
+    /// `players`.
+    pub fn new(pick: &DraftPick, players: &[PlayerValuation], candidates: &[usize]) -> Self {
+        Self {
+            pick_number: pick.pick_number,
+            player_name: pick.player_name.clone(),
+            position: pick.position.clone(),
+            team_name: pick.team_name.clone(),
+            price: pick.price,
+            candidates: candidates
+                .iter()
+                .map(|&i| PickCandidate {
+                    team: players[i].team.clone(),
+                    positions: players[i].positions.clone(),
+                    dollar_value: players[i].dollar_value,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Remove each pick's player from `pool`, matching by name and position.
+/// Returns the picks that matched several players; those players stay.
+pub fn remove_drafted(pool: &mut Vec<PlayerValuation>, picks: &[DraftPick]) -> Vec<AmbiguousPick> {
+    let mut ambiguous = Vec::new();
+    for pick in picks {
+        match find_player(pool, &pick.player_name, &pick.position, None) {
+            PlayerMatch::Missing => {}
+            PlayerMatch::Unique(i) => {
+                pool.remove(i);
+            }
+            PlayerMatch::Ambiguous(candidates) => ambiguous.push(AmbiguousPick::new(pick, pool, &candidates)),
+        }
+    }
+    ambiguous
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestPlayer;
+    use crate::valuation::projections::{HitterProjection, PitcherProjection, ProjectionSource};
+
+    fn will_smiths() -> Vec<PlayerValuation> {
+        let mut catcher = TestPlayer::hitter("Will Smith").positions(vec![Position::Catcher]).build();
+        catcher.team = "LAD".into();
+        let mut reliever = TestPlayer::pitcher("Will Smith", PitcherType::RP).build();
+        reliever.team = "ATL".into();
+        let mut other_catcher = TestPlayer::hitter("Will Smith").positions(vec![Position::Catcher]).build();
+        other_catcher.team = "KC".into();
+        vec![TestPlayer::hitter("Juan Soto").build(), catcher, reliever, other_catcher]
+    }
+
+    #[test]
+    fn position_then_team_tell_same_names_apart() {
+        let players = will_smiths();
+        assert_eq!(find_player(&players, "Juan Soto", "OF", None), PlayerMatch::Unique(0));
+        assert_eq!(find_player(&players, "Nobody", "", None), PlayerMatch::Missing);
+        assert_eq!(find_player(&players, "Will Smith", "RP", None), PlayerMatch::Unique(2));
+        assert_eq!(find_player(&players, "Will Smith", "C", None), PlayerMatch::Ambiguous(vec![1, 3]));
+        assert_eq!(find_player(&players, "Will Smith", "C", Some("kc")), PlayerMatch::Unique(3));
+        // A team nobody plays for rules nobody out.
+        assert_eq!(find_player(&players, "Will Smith", "C", Some("NYY")).best(), Some(1));
+    }
+
+    #[test]
+    fn a_player_ref_finds_that_namesake() {
+        let players = will_smiths();
+        for (i, player) in players.iter().enumerate() {
+            assert_eq!(PlayerRef::of(player).find(&players), PlayerMatch::Unique(i));
+        }
+    }
+
+    #[test]
+    fn removing_picks_leaves_ambiguous_ones_for_me() {
+        let mut pool = will_smiths();
+        let pick = |name: &str, position: &str| DraftPick {
+            pick_number: 1,
+            team_id: "1".into(),
+            team_name: "Team 1".into(),
+            player_name: name.into(),
+            position: position.into(),
+            price: 10,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+        let ambiguous = remove_drafted(&mut pool, &[pick("Will Smith", "RP"), pick("Will Smith", "C")]);
+        assert_eq!(pool.len(), 3);
+        assert!(pool.iter().all(|p| !p.is_pitcher));
+        assert_eq!(ambiguous.len(), 1);
+        let teams: Vec<&str> = ambiguous[0].candidates.iter().map(|c| c.team.as_str()).collect();
+        assert_eq!(teams, ["LAD", "KC"]);
+    }
+
+    #[test]
+    fn two_way_rows_are_not_duplicates() {
+        let hitter = |name: &str, team: &str, position: &str| HitterProjection {
+            name: name.into(),
+            team: team.into(),
+            pa: 600,
+            ab: 540,
+            h: 150,
+            hr: 25,
+            r: 90,
+            rbi: 85,
+            bb: 55,
+            sb: 10,
+            avg: 0.278,
+            espn_position: position.into(),
+        };
+        let pitcher = |name: &str, team: &str| PitcherProjection {
+            name: name.into(),
+            team: team.into(),
+            pitcher_type: PitcherType::RP,
+            ip: 65.0,
+            k: 80,
+            w: 4,
+            sv: 20,
+            hd: 5,
+            era: 3.20,
+            whip: 1.10,
+            g: 65,
+            gs: 0,
+        };
+        let projections = AllProjections {
+            hitters: vec![hitter("Will Smith", "LAD", "C"), hitter("Ohtani", "LAD", "DH")],
+            pitchers: vec![pitcher("Will Smith", "ATL"), pitcher("Ohtani", "LAD")],
+            source: ProjectionSource::Espn,
+        };
+        let shared: Vec<String> = duplicate_names(&projections).iter().map(ToString::to_string).collect();
+        assert_eq!(shared, ["Will Smith (LAD C, ATL RP)"]);
+    }
+}
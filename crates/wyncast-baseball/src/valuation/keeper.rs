@@ -0,0 +1,129 @@
+// Keeper contract values for auction keeper leagues.
+//
+// A contract fixes a player's salary for the rest of its term, rising each
+// season by the league's raise rules (`[league.keepers]`). Its worth is the
+// gap between the model's auction value and what it costs: this season's
+// surplus decides whether to keep the player now, and the surplus over the
+// remaining seasons shows how long the contract stays a bargain. Future
+// seasons assume the player keeps this season's value.
+
+use wyncast_core::config::{KeeperContract, KeeperRules};
+
+use super::zscore::PlayerValuation;
+
+/// A contract priced against the model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeeperValue {
+    pub player: String,
+    /// Salary for each season left on the contract, this one first.
+    pub salaries: Vec<u32>,
+    /// The model's auction value this season; `None` when the player is not
+    /// in the available pool (already drafted, or not projected).
+    pub value: Option<f64>,
+}
+
+impl KeeperValue {
+    /// This season's salary.
+    pub fn salary(&self) -> u32 {
+        self.salaries.first().copied().unwrap_or(0)
+    }
+
+    /// Seasons left, counting this one.
+    pub fn years(&self) -> usize {
+        self.salaries.len()
+    }
+
+    /// Value minus this season's salary.
+    pub fn surplus(&self) -> Option<f64> {
+        self.value.map(|v| v - f64::from(self.salary()))
+    }
+
+    /// Value minus salary, summed over the seasons after this one.
+    pub fn future_surplus(&self) -> Option<f64> {
+        self.value
+            .map(|v| self.salaries.iter().skip(1).map(|&s| v - f64::from(s)).sum())
+    }
+}
+
+/// The salary of `contract` for each season left on it, this one first.
+/// Each raise applies the percentage (rounded up to whole dollars) and then
+/// the flat amount; a contract's own raise replaces the league's.
+pub fn salary_schedule(contract: &KeeperContract, rules: &KeeperRules) -> Vec<u32> {
+    let raise = contract.raise.unwrap_or(rules.raise);
+    let raise_pct = contract.raise_pct.unwrap_or(rules.raise_pct);
+    let mut salary = contract.salary;
+    let mut schedule = Vec::with_capacity(contract.years as usize);
+    for _ in 0..contract.years {
+        schedule.push(salary);
+        // The epsilon keeps 30 * 0.1 from rounding up to $4.
+        let bump = (f64::from(salary) * raise_pct - 1e-9).ceil().max(0.0) as u32;
+        salary = salary.saturating_add(bump).saturating_add(raise);
+    }
+    schedule
+}
+
+/// Price every contract against the available players, in contract order.
+pub fn keeper_values(
+    contracts: &[KeeperContract],
+    rules: &KeeperRules,
+    players: &[PlayerValuation],
+) -> Vec<KeeperValue> {
+    contracts
+        .iter()
+        .map(|contract| KeeperValue {
+            player: contract.player.clone(),
+            salaries: salary_schedule(contract, rules),
+            value: players
+                .iter()
+                .find(|p| p.name == contract.player)
+                .map(|p| p.dollar_value),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestPlayer;
+
+    fn contract(salary: u32, years: u32) -> KeeperContract {
+        KeeperContract {
+            player: "Juan Soto".into(),
+            salary,
+            years,
+            raise: None,
+            raise_pct: None,
+        }
+    }
+
+    #[test]
+    fn salary_schedule_applies_league_and_contract_raises() {
+        let rules = KeeperRules { raise: 5, raise_pct: 0.1, ..Default::default() };
+        // 30 -> 30 + 3 + 5 = 38 -> 38 + 4 (3.8 rounded up) + 5 = 47
+        assert_eq!(salary_schedule(&contract(30, 3), &rules), vec![30, 38, 47]);
+
+        let own = KeeperContract { raise: Some(2), raise_pct: Some(0.0), ..contract(10, 3) };
+        assert_eq!(salary_schedule(&own, &rules), vec![10, 12, 14]);
+        assert!(salary_schedule(&contract(10, 0), &rules).is_empty());
+    }
+
+    #[test]
+    fn keeper_values_compare_salaries_with_model_value() {
+        let rules = KeeperRules { raise: 5, ..Default::default() };
+        let mut soto = TestPlayer::hitter("Juan Soto").build();
+        soto.dollar_value = 40.0;
+        let contracts = vec![
+            contract(30, 3),
+            KeeperContract { player: "Nobody".into(), ..contract(5, 1) },
+        ];
+
+        let values = keeper_values(&contracts, &rules, &[soto]);
+        assert_eq!(values[0].salary(), 30);
+        assert_eq!(values[0].years(), 3);
+        assert_eq!(values[0].surplus(), Some(10.0));
+        // Next two seasons at $35 and $40.
+        assert_eq!(values[0].future_surplus(), Some(5.0));
+        assert_eq!(values[1].value, None);
+        assert_eq!(values[1].surplus(), None);
+    }
+}
@@ -1,15 +1,32 @@
-// Valuation engine: z-scores, VOR, auction dollar conversion.
+// Valuation engine: z-scores (or projected points, in a points league), VOR,
+// auction dollar conversion.
 
+pub mod adjustments;
 pub mod analysis;
 pub mod auction;
+pub mod cache;
+pub mod cliff;
+pub mod draft_board;
+pub mod compare;
+pub mod custom_stats;
+pub mod explain;
+pub mod identity;
+pub mod keeper;
+pub mod player_pool;
+pub mod plugins;
+pub mod points;
+pub mod prep;
 pub mod projections;
 pub mod scarcity;
+pub mod standings;
+pub mod tiers;
 pub mod vor;
 pub mod zscore;
 
 use std::collections::HashMap;
 
-use wyncast_core::config::{Config, LeagueConfig, StrategyConfig};
+use tracing::debug;
+use wyncast_core::config::{CategoryWeights, Config, LeagueConfig, StrategyConfig};
 use wyncast_core::stats::{self, CategoryValues, StatRegistry};
 use crate::draft::state::DraftState;
 use projections::AllProjections;
@@ -27,9 +44,17 @@ use zscore::{
 ///
 /// 1. **Z-scores** — compute per-category z-scores for every player, producing
 ///    a `Vec<PlayerValuation>` sorted by total z-score.
+///    In a points league (`league.is_points()`), each player's total is
+///    their projected fantasy points instead.
 /// 2. **VOR** — adjust z-scores by positional replacement level, sort by VOR.
 /// 3. **Auction dollars** — convert VOR into dollar values using the league's
-///    salary cap, sort by dollar value descending.
+///    salary cap, sort by dollar value descending. In a snake draft
+///    (`league.is_snake()`), rank the players by VOR instead, with no dollar
+///    values.
+/// 4. **Consensus** — run steps 1–3 again with every category weighted
+///    equally and the default budget split, recording each player's result
+///    as their `consensus_value`: what a room without my punts and weights
+///    would pay.
 ///
 /// The returned list is sorted by descending dollar value, ready for display
 /// or further processing (inflation tracking, scarcity adjustments, etc.).
@@ -38,13 +63,71 @@ pub fn compute_initial(
     config: &Config,
     roster_config: &HashMap<String, usize>,
     registry: &StatRegistry,
-) -> anyhow::Result<Vec<PlayerValuation>> {
+) -> Vec<PlayerValuation> {
+    let mut players = value_pool(projections, config, roster_config, registry);
+
+    let neutral = Config {
+        strategy: neutral_strategy(&config.strategy, registry),
+        ..config.clone()
+    };
+    let consensus: HashMap<String, f64> = value_pool(projections, &neutral, roster_config, registry)
+        .into_iter()
+        .map(|p| (p.name, p.dollar_value))
+        .collect();
+    for player in players.iter_mut() {
+        player.consensus_value = consensus.get(&player.name).copied().unwrap_or(player.dollar_value);
+    }
+
+    players
+}
+
+/// My strategy with its category weights and budget split reset: every
+/// league category weighted 1.0 (so nothing is punted) and the default
+/// hitter/pitcher split.
+fn neutral_strategy(strategy: &StrategyConfig, registry: &StatRegistry) -> StrategyConfig {
+    StrategyConfig {
+        hitting_budget_fraction: StrategyConfig::default().hitting_budget_fraction,
+        weights: CategoryWeights::from_pairs(
+            registry.all_stats().iter().map(|def| (def.abbrev.clone(), 1.0)),
+        ),
+        ..strategy.clone()
+    }
+}
+
+/// Steps 1–3 of `compute_initial` under `config`'s strategy.
+fn value_pool(
+    projections: &AllProjections,
+    config: &Config,
+    roster_config: &HashMap<String, usize>,
+    registry: &StatRegistry,
+) -> Vec<PlayerValuation> {
     let weight_values = weights_to_category_values(&config.strategy.weights, registry);
 
+    // Deep leagues can start more players than the configured pools hold;
+    // grow them so the z-score baseline reaches past every starter.
+    let pool = config.strategy.pool.covering(roster_config, config.league.num_teams);
+    let sized;
+    let config = if pool == config.strategy.pool {
+        config
+    } else {
+        debug!(
+            "Growing the player pools to {}/{}/{} for {} teams",
+            pool.hitter_pool_size, pool.sp_pool_size, pool.rp_pool_size, config.league.num_teams
+        );
+        sized = Config {
+            strategy: StrategyConfig { pool, ..config.strategy.clone() },
+            ..config.clone()
+        };
+        &sized
+    };
+
     // Step 1: Z-scores
     let mut players = zscore::compute_initial_zscores(
         projections, config, registry, &weight_values,
     );
+    if config.league.is_points() {
+        points::apply_points(&mut players, &config.league.points);
+    }
 
     // Step 2: VOR adjustment
     vor::apply_vor(&mut players, roster_config, config.league.num_teams);
@@ -54,10 +137,14 @@ pub fn compute_initial(
         player.initial_vor = player.vor;
     }
 
-    // Step 3: Auction dollar conversion
-    auction::apply_auction_values(&mut players, roster_config, config.league.num_teams, config.league.salary_cap, &config.strategy);
+    // Step 3: Auction dollar conversion (or the snake draft's ranking)
+    if config.league.is_snake() {
+        draft_board::apply_ranks(&mut players);
+    } else {
+        auction::apply_auction_values(&mut players, roster_config, config.league.num_teams, config.league.salary_cap, &config.strategy);
+    }
 
-    Ok(players)
+    players
 }
 
 // ---------------------------------------------------------------------------
@@ -73,9 +160,11 @@ pub fn compute_initial(
 ///
 /// # Algorithm
 /// 1. Separate players into hitter and pitcher sub-pools.
-/// 2. Recompute pool statistics and z-scores from embedded projection data.
+/// 2. Recompute pool statistics and z-scores from embedded projection data
+///    (projected points in a points league).
 /// 3. Recompute replacement levels and VOR.
-/// 4. Recompute auction values (incorporating current draft budget state).
+/// 4. Recompute auction values (incorporating current draft budget state),
+///    or in a snake draft rank the players by VOR without dollar values.
 /// 5. Sort by dollar value descending.
 ///
 /// The `available_players` vector is mutated in place.
@@ -187,11 +276,19 @@ pub fn recalculate_all(
         available_players[i].total_zscore = combined;
     }
 
+    if league.is_points() {
+        points::apply_points(available_players, &league.points);
+    }
+
     // ---- 6. Recompute VOR ----
     vor::apply_vor(available_players, roster_config, league.num_teams);
 
-    // ---- 7. Recompute auction values ----
-    auction::apply_auction_values(available_players, roster_config, league.num_teams, league.salary_cap, strategy);
+    // ---- 7. Recompute auction values (or the snake draft's ranking) ----
+    if league.is_snake() {
+        draft_board::apply_ranks(available_players);
+    } else {
+        auction::apply_auction_values(available_players, roster_config, league.num_teams, league.salary_cap, strategy);
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -219,6 +316,61 @@ mod tests {
         test_utils::create_test_draft_state(2)
     }
 
+    #[test]
+    fn points_leagues_value_players_by_projected_points() {
+        let mut league = test_league_config();
+        league.scoring_type = "h2h_points".into();
+        league.points = HashMap::from([("SB".to_string(), 10.0), ("HR".to_string(), 1.0)]);
+        let mut players = vec![
+            make_hitter("Slugger", 100, 45, 110, 80, 2, 560, 0.290, vec![Position::FirstBase]),
+            make_hitter("Speedster", 70, 5, 40, 40, 60, 520, 0.260, vec![Position::FirstBase]),
+            make_hitter("Filler", 50, 10, 45, 30, 5, 480, 0.240, vec![Position::FirstBase]),
+            make_pitcher("P_Ace", 250, 18, 0, 0, 200.0, 2.80, 1.00, PitcherType::SP),
+        ];
+
+        recalculate_all(
+            &mut players,
+            &test_roster_config(),
+            &league,
+            &test_strategy_config(),
+            &create_test_draft_state(),
+            &test_registry(),
+        );
+        let speedster = find_player(&players, "Speedster");
+        let slugger = find_player(&players, "Slugger");
+        assert_eq!(speedster.total_zscore, 605.0);
+        assert_eq!(slugger.total_zscore, 65.0);
+        assert!(speedster.dollar_value > slugger.dollar_value);
+        // No pitching stat scores, so pitchers are worth nothing.
+        assert_eq!(find_player(&players, "P_Ace").total_zscore, 0.0);
+    }
+
+    #[test]
+    fn snake_drafts_rank_players_instead_of_pricing_them() {
+        let mut league = test_league_config();
+        league.draft_type = wyncast_core::config::DraftType::Snake;
+        let mut players = vec![
+            make_hitter("Filler", 50, 10, 45, 30, 5, 480, 0.240, vec![Position::FirstBase]),
+            make_hitter("Slugger", 100, 45, 110, 80, 2, 560, 0.290, vec![Position::FirstBase]),
+            make_pitcher("P_Ace", 250, 18, 0, 0, 200.0, 2.80, 1.00, PitcherType::SP),
+            make_pitcher("P_Back", 120, 8, 0, 0, 150.0, 4.60, 1.40, PitcherType::SP),
+        ];
+
+        recalculate_all(
+            &mut players,
+            &test_roster_config(),
+            &league,
+            &test_strategy_config(),
+            &create_test_draft_state(),
+            &test_registry(),
+        );
+        assert!(players.iter().all(|p| p.dollar_value == 0.0));
+        assert!(players.windows(2).all(|w| w[0].vor >= w[1].vor), "pool is in board order");
+        let rank = |name: &str| players.iter().position(|p| p.name == name).unwrap();
+        assert!(rank("Slugger") < rank("Filler"));
+        assert!(rank("P_Ace") < rank("P_Back"));
+    }
+
     #[test]
     fn values_stable_after_player_removal() {
         let league = test_league_config();
@@ -362,6 +514,8 @@ mod tests {
                     ("g".into(), 30.0),
                     ("gs".into(), if pitcher_type == crate::valuation::projections::PitcherType::SP { 30.0 } else { 0.0 }),
                 ]),
+                provenance: HashMap::new(),
+                adjustments: Vec::new(),
             },
             total_zscore: 0.0,
             category_zscores: CategoryZScores::two_way(CategoryValues::zeros(test_registry().len()), 0.0, 0.0),
@@ -369,6 +523,9 @@ mod tests {
             initial_vor: 0.0,
             best_position: None,
             dollar_value: 0.0,
+            flex_bonus: 0.0,
+            consensus_value: 0.0,
+            custom_stats: Vec::new(),
         }
     }
 
@@ -634,4 +791,125 @@ mod tests {
         assert_close(find_player(&players, "H_Good").total_zscore, 3.083448550621077, "H_Good zscore");
         assert_close(find_player(&players, "P_Mid").total_zscore, -5.857803730629427, "P_Mid zscore");
     }
+
+    // Deep leagues: the pipeline has to hold up past the 10-12 teams the
+    // defaults were tuned for.
+
+    /// `hitters` + `sp` + `rp` projected players with steadily declining
+    /// stats, hitters spread over the positions as a roster starts them.
+    fn deep_projections(hitters: usize, sp: usize, rp: usize) -> AllProjections {
+        use crate::valuation::projections::{HitterProjection, PitcherProjection, ProjectionSource};
+        const POSITIONS: [&str; 8] = ["C", "1B", "2B", "3B", "SS", "LF", "CF", "RF"];
+
+        let hitters = (0..hitters)
+            .map(|i| {
+                let d = i as u32 / 8;
+                HitterProjection {
+                    name: format!("H{i}"),
+                    team: "NYY".into(),
+                    pa: 620,
+                    ab: 550,
+                    h: 170 - d / 2,
+                    hr: 40u32.saturating_sub(d / 2),
+                    r: 105 - d,
+                    rbi: 105 - d,
+                    bb: 70u32.saturating_sub(d / 2),
+                    sb: 25u32.saturating_sub(d / 3),
+                    avg: f64::from(170 - d / 2) / 550.0,
+                    espn_position: POSITIONS[i % POSITIONS.len()].into(),
+                }
+            })
+            .collect();
+        let pitcher = |name: String, pitcher_type, i: usize| {
+            let d = i as f64;
+            let starter = pitcher_type == PitcherType::SP;
+            PitcherProjection {
+                name,
+                team: "NYY".into(),
+                pitcher_type,
+                ip: if starter { 190.0 - d * 0.5 } else { 70.0 - d * 0.1 },
+                k: if starter { 230 - i as u32 } else { 90 - i as u32 / 3 },
+                w: if starter { 16 - i as u32 / 12 } else { 4 },
+                sv: if starter { 0 } else { 35u32.saturating_sub(i as u32 / 2) },
+                hd: if starter { 0 } else { 5 + i as u32 / 8 },
+                era: 2.80 + d * 0.012,
+                whip: 1.00 + d * 0.003,
+                g: if starter { 32 } else { 65 },
+                gs: if starter { 32 } else { 0 },
+            }
+        };
+        let pitchers = (0..sp)
+            .map(|i| pitcher(format!("SP{i}"), PitcherType::SP, i))
+            .chain((0..rp).map(|i| pitcher(format!("RP{i}"), PitcherType::RP, i)))
+            .collect();
+
+        AllProjections { hitters, pitchers, source: ProjectionSource::Espn }
+    }
+
+    #[test]
+    fn consensus_values_ignore_my_weights_and_punts() {
+        let roster = test_roster_config();
+        let registry = test_registry();
+        let projections = deep_projections(120, 70, 80);
+        let mut config = test_utils::test_config();
+        config.strategy.weights = CategoryWeights::from_pairs(
+            registry.all_stats().iter().map(|def| (def.abbrev.clone(), 1.0)),
+        );
+        config.strategy.hitting_budget_fraction = StrategyConfig::default().hitting_budget_fraction;
+
+        // Without weights or a budget split of my own, I am the consensus.
+        let neutral = compute_initial(&projections, &config, &roster, &registry);
+        assert!(neutral.iter().all(|p| (p.dollar_value - p.consensus_value).abs() < 1e-9));
+
+        // Punting saves leaves the room paying more for closers than I would.
+        config.strategy.weights.0.insert("SV".into(), 0.0);
+        let punted = compute_initial(&projections, &config, &roster, &registry);
+        let closer = punted.iter().find(|p| p.name == "RP0").unwrap();
+        let before = neutral.iter().find(|p| p.name == "RP0").unwrap();
+        assert!(closer.dollar_value < closer.consensus_value, "{closer:?}");
+        assert!((closer.consensus_value - before.dollar_value).abs() < 1e-9);
+    }
+
+    #[test]
+    fn deep_leagues_value_every_starter() {
+        let roster = test_roster_config();
+        for num_teams in [16, 20] {
+            let mut config = test_utils::test_config();
+            config.league.num_teams = num_teams;
+            let projections = deep_projections(12 * num_teams, 7 * num_teams, 8 * num_teams);
+
+            let players = compute_initial(&projections, &config, &roster, &test_registry());
+            assert_eq!(players.len(), 27 * num_teams);
+
+            // Every positional and pitching slot in the league has a player
+            // worth more than the minimum to fill it.
+            let worth = |pitchers: bool| {
+                players
+                    .iter()
+                    .filter(|p| p.is_pitcher == pitchers && p.vor > 0.0 && p.dollar_value > 1.0)
+                    .count()
+            };
+            assert!(worth(false) >= 8 * num_teams, "{num_teams} teams: {} hitters", worth(false));
+            assert!(worth(true) >= 11 * num_teams, "{num_teams} teams: {} pitchers", worth(true));
+
+            // The league's whole budget is spent on the players it rosters.
+            let rostered: f64 = players
+                .iter()
+                .take(auction::roster_size(&roster) * num_teams)
+                .map(|p| p.dollar_value)
+                .sum();
+            let budget = (num_teams * config.league.salary_cap as usize) as f64;
+            assert!(
+                (rostered - budget).abs() < budget * 0.05,
+                "{num_teams} teams: ${rostered:.0} of ${budget:.0}"
+            );
+
+            // Nothing is scarce before the first pick.
+            let entries = scarcity::compute_scarcity(&players, &roster, num_teams);
+            assert!(
+                entries.iter().all(|e| e.urgency != scarcity::ScarcityUrgency::Critical),
+                "{num_teams} teams: {entries:?}"
+            );
+        }
+    }
 }
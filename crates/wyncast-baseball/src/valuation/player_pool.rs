@@ -0,0 +1,197 @@
+// Player pool restriction: AL-only / NL-only leagues and team whitelists.
+//
+// Applied to the projections before valuation, so players outside the pool
+// are never valued or displayed. Projection sources disagree on team
+// abbreviations (FanGraphs writes "SFG", ESPN "SF"), so both sides of every
+// comparison go through `canonical_team`.
+
+use std::collections::HashSet;
+
+use wyncast_core::config::PlayerPool;
+
+use super::projections::AllProjections;
+
+/// One of the two MLB leagues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MlbLeague {
+    American,
+    National,
+}
+
+impl MlbLeague {
+    /// Parse "AL" / "NL" (case-insensitive).
+    pub fn from_abbrev(s: &str) -> Option<Self> {
+        match s.to_uppercase().as_str() {
+            "AL" => Some(MlbLeague::American),
+            "NL" => Some(MlbLeague::National),
+            _ => None,
+        }
+    }
+}
+
+/// Canonical abbreviation, league, and the aliases used by projection
+/// sources for every MLB team.
+const TEAMS: &[(&str, MlbLeague, &[&str])] = &[
+    ("BAL", MlbLeague::American, &[]),
+    ("BOS", MlbLeague::American, &[]),
+    ("NYY", MlbLeague::American, &["NYA"]),
+    ("TB", MlbLeague::American, &["TBR", "TBA"]),
+    ("TOR", MlbLeague::American, &[]),
+    ("CWS", MlbLeague::American, &["CHW", "CHA"]),
+    ("CLE", MlbLeague::American, &[]),
+    ("DET", MlbLeague::American, &[]),
+    ("KC", MlbLeague::American, &["KCR", "KCA"]),
+    ("MIN", MlbLeague::American, &[]),
+    ("HOU", MlbLeague::American, &[]),
+    ("LAA", MlbLeague::American, &["ANA"]),
+    ("ATH", MlbLeague::American, &["OAK"]),
+    ("SEA", MlbLeague::American, &[]),
+    ("TEX", MlbLeague::American, &[]),
+    ("ATL", MlbLeague::National, &[]),
+    ("MIA", MlbLeague::National, &["FLA"]),
+    ("NYM", MlbLeague::National, &["NYN"]),
+    ("PHI", MlbLeague::National, &[]),
+    ("WSH", MlbLeague::National, &["WSN", "WAS"]),
+    ("CHC", MlbLeague::National, &["CHN"]),
+    ("CIN", MlbLeague::National, &[]),
+    ("MIL", MlbLeague::National, &[]),
+    ("PIT", MlbLeague::National, &[]),
+    ("STL", MlbLeague::National, &["SLN"]),
+    ("ARI", MlbLeague::National, &["AZ"]),
+    ("COL", MlbLeague::National, &[]),
+    ("LAD", MlbLeague::National, &["LAN"]),
+    ("SD", MlbLeague::National, &["SDP", "SDN"]),
+    ("SF", MlbLeague::National, &["SFG", "SFN"]),
+];
+
+fn lookup(team: &str) -> Option<(&'static str, MlbLeague)> {
+    let team = team.trim().to_uppercase();
+    TEAMS
+        .iter()
+        .find(|(abbrev, _, aliases)| *abbrev == team || aliases.contains(&team.as_str()))
+        .map(|&(abbrev, league, _)| (abbrev, league))
+}
+
+/// The canonical abbreviation for a team (e.g. "SFG" → "SF"). Unknown
+/// abbreviations (free agents, typos) are returned upper-cased.
+pub fn canonical_team(team: &str) -> String {
+    lookup(team).map_or_else(|| team.trim().to_uppercase(), |(abbrev, _)| abbrev.to_string())
+}
+
+/// The MLB league a team plays in, or `None` for free agents and unknown
+/// abbreviations.
+pub fn mlb_league(team: &str) -> Option<MlbLeague> {
+    lookup(team).map(|(_, league)| league)
+}
+
+/// Whether a player on `team` is draftable under `pool`.
+///
+/// An unrestricted pool admits everyone. Otherwise the team must belong to
+/// one of the listed leagues or be one of the listed teams, so free agents
+/// are outside any restricted pool.
+pub fn in_pool(team: &str, pool: &PlayerPool) -> bool {
+    if pool.is_unrestricted() {
+        return true;
+    }
+    let canonical = canonical_team(team);
+    if pool.teams.iter().any(|t| canonical_team(t) == canonical) {
+        return true;
+    }
+    mlb_league(team).is_some_and(|league| {
+        pool.leagues
+            .iter()
+            .any(|l| MlbLeague::from_abbrev(l) == Some(league))
+    })
+}
+
+/// Drop every projection outside `pool` and return the removed names.
+pub fn restrict(projections: &mut AllProjections, pool: &PlayerPool) -> HashSet<String> {
+    let mut excluded = HashSet::new();
+    if pool.is_unrestricted() {
+        return excluded;
+    }
+    projections.hitters.retain(|h| {
+        let keep = in_pool(&h.team, pool);
+        if !keep {
+            excluded.insert(h.name.clone());
+        }
+        keep
+    });
+    projections.pitchers.retain(|p| {
+        let keep = in_pool(&p.team, pool);
+        if !keep {
+            excluded.insert(p.name.clone());
+        }
+        keep
+    });
+    excluded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::valuation::projections::{HitterProjection, ProjectionSource};
+
+    fn pool(leagues: &[&str], teams: &[&str]) -> PlayerPool {
+        PlayerPool {
+            leagues: leagues.iter().map(|s| s.to_string()).collect(),
+            teams: teams.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn hitter(name: &str, team: &str) -> HitterProjection {
+        HitterProjection {
+            name: name.into(),
+            team: team.into(),
+            pa: 600,
+            ab: 540,
+            h: 150,
+            hr: 25,
+            r: 90,
+            rbi: 85,
+            bb: 55,
+            sb: 10,
+            avg: 0.278,
+            espn_position: String::new(),
+        }
+    }
+
+    #[test]
+    fn aliases_resolve_to_the_same_team() {
+        assert_eq!(canonical_team("SFG"), "SF");
+        assert_eq!(canonical_team("sf"), "SF");
+        assert_eq!(canonical_team("CHW"), "CWS");
+        assert_eq!(canonical_team("FA"), "FA");
+        assert_eq!(mlb_league("WSN"), Some(MlbLeague::National));
+        assert_eq!(mlb_league("OAK"), Some(MlbLeague::American));
+        assert_eq!(mlb_league("FA"), None);
+    }
+
+    #[test]
+    fn unrestricted_pool_admits_everyone() {
+        assert!(in_pool("NYY", &PlayerPool::default()));
+        assert!(in_pool("FA", &PlayerPool::default()));
+    }
+
+    #[test]
+    fn league_and_team_lists_are_combined() {
+        let al_plus_dodgers = pool(&["AL"], &["LAN"]);
+        assert!(in_pool("NYY", &al_plus_dodgers));
+        assert!(in_pool("LAD", &al_plus_dodgers));
+        assert!(!in_pool("SD", &al_plus_dodgers));
+        assert!(!in_pool("FA", &al_plus_dodgers));
+    }
+
+    #[test]
+    fn restrict_drops_and_reports_players_outside_the_pool() {
+        let mut projections = AllProjections {
+            hitters: vec![hitter("Judge", "NYY"), hitter("Ohtani", "LAD"), hitter("Nobody", "FA")],
+            pitchers: vec![],
+            source: ProjectionSource::Espn,
+        };
+        let excluded = restrict(&mut projections, &pool(&["NL"], &[]));
+        let kept: Vec<&str> = projections.hitters.iter().map(|h| h.name.as_str()).collect();
+        assert_eq!(kept, vec!["Ohtani"]);
+        assert_eq!(excluded, HashSet::from(["Judge".to_string(), "Nobody".to_string()]));
+    }
+}
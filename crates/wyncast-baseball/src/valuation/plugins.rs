@@ -0,0 +1,253 @@
+// Valuation plugins: custom adjustments to player values.
+//
+// A plugin post-processes the valued pool after each valuation (the initial
+// one, a budget re-split, a strategy change), e.g. "my park's hitters +3%".
+// Plugins are trait objects registered at startup; with the `scripting`
+// feature, the Rhai scripts listed under `[data_paths] scripts` are loaded as
+// plugins too. A script defines `fn adjust(player)` and returns the player's
+// new dollar value, or nothing to leave it alone.
+
+use tracing::{info, warn};
+use wyncast_core::config::DataPaths;
+
+use super::zscore::PlayerValuation;
+
+/// A custom adjustment to player values.
+pub trait ValuationPlugin: Send + Sync {
+    /// Name for the logs.
+    fn name(&self) -> &str;
+
+    /// Adjust the freshly valued `players` in place.
+    fn adjust(&self, players: &mut [PlayerValuation]);
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginError {
+    #[error("failed to load script {path}: {message}")]
+    Script { path: String, message: String },
+
+    #[error("script {path} needs a build with the `scripting` feature")]
+    ScriptingDisabled { path: String },
+}
+
+/// The registered plugins, run in registration order.
+#[derive(Default)]
+pub struct Plugins {
+    plugins: Vec<Box<dyn ValuationPlugin>>,
+}
+
+impl std::fmt::Debug for Plugins {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl Plugins {
+    /// Load the scripts listed in `paths`. A script that cannot be loaded
+    /// is logged and left out.
+    pub fn load(paths: &DataPaths) -> Self {
+        let mut plugins = Plugins::default();
+        for raw in &paths.scripts {
+            match load_script(raw) {
+                Ok(script) => {
+                    info!("Loaded valuation script {}", raw);
+                    plugins.plugins.push(script);
+                }
+                Err(e) => warn!("{}", e),
+            }
+        }
+        plugins
+    }
+
+    /// Register `plugin` to run after the ones already registered.
+    pub fn register(&mut self, plugin: impl ValuationPlugin + 'static) {
+        self.plugins.push(Box::new(plugin));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.plugins.iter().map(|p| p.name())
+    }
+
+    /// Run every plugin over `players`, then restore the pool's order by
+    /// dollar value.
+    pub fn apply(&self, players: &mut [PlayerValuation]) {
+        if self.plugins.is_empty() {
+            return;
+        }
+        for plugin in &self.plugins {
+            plugin.adjust(players);
+        }
+        players.sort_by(|a, b| b.dollar_value.total_cmp(&a.dollar_value));
+    }
+}
+
+#[cfg(feature = "scripting")]
+fn load_script(raw: &str) -> Result<Box<dyn ValuationPlugin>, PluginError> {
+    Ok(Box::new(script::ScriptPlugin::load(raw)?))
+}
+
+#[cfg(not(feature = "scripting"))]
+fn load_script(raw: &str) -> Result<Box<dyn ValuationPlugin>, PluginError> {
+    Err(PluginError::ScriptingDisabled { path: raw.to_string() })
+}
+
+#[cfg(feature = "scripting")]
+mod script {
+    use rhai::{Dynamic, Engine, Map, Scope, AST};
+    use tracing::warn;
+
+    use super::{PluginError, ValuationPlugin};
+    use crate::valuation::projections::resolve_data_path;
+    use crate::valuation::zscore::PlayerValuation;
+
+    /// A Rhai script's `adjust(player)`, called once per player. The player
+    /// is a map of `name`, `team`, `positions`, `is_pitcher`, `vor`,
+    /// `dollar_value` and the projected `stats` by key.
+    pub(super) struct ScriptPlugin {
+        path: String,
+        engine: Engine,
+        ast: AST,
+    }
+
+    impl ScriptPlugin {
+        pub(super) fn load(raw: &str) -> Result<Self, PluginError> {
+            let engine = Engine::new();
+            let ast = engine
+                .compile_file(resolve_data_path(raw))
+                .map_err(|e| PluginError::Script { path: raw.to_string(), message: e.to_string() })?;
+            Ok(ScriptPlugin { path: raw.to_string(), engine, ast })
+        }
+    }
+
+    impl ValuationPlugin for ScriptPlugin {
+        fn name(&self) -> &str {
+            &self.path
+        }
+
+        fn adjust(&self, players: &mut [PlayerValuation]) {
+            for player in players {
+                let result = self
+                    .engine
+                    .call_fn::<Dynamic>(&mut Scope::new(), &self.ast, "adjust", (player_map(player),));
+                match result {
+                    Ok(value) if value.is_unit() => {}
+                    Ok(value) => match value.as_float().or_else(|_| value.as_int().map(|i| i as f64)) {
+                        Ok(dollars) => player.dollar_value = dollars,
+                        Err(kind) => warn!("{}: adjust({}) returned a {}, not a number", self.path, player.name, kind),
+                    },
+                    Err(e) => warn!("{}: adjust({}) failed: {}", self.path, player.name, e),
+                }
+            }
+        }
+    }
+
+    fn player_map(player: &PlayerValuation) -> Map {
+        let positions: Vec<Dynamic> = player.positions.iter().map(|p| p.display_str().into()).collect();
+        let stats: Map = player
+            .projection
+            .values
+            .iter()
+            .map(|(key, &value)| (key.as_str().into(), value.into()))
+            .collect();
+        let mut map = Map::new();
+        map.insert("name".into(), player.name.clone().into());
+        map.insert("team".into(), player.team.clone().into());
+        map.insert("positions".into(), positions.into());
+        map.insert("is_pitcher".into(), player.is_pitcher.into());
+        map.insert("vor".into(), player.vor.into());
+        map.insert("dollar_value".into(), player.dollar_value.into());
+        map.insert("stats".into(), stats.into());
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestPlayer;
+    #[cfg(feature = "scripting")]
+    use crate::valuation::projections::PitcherType;
+
+    struct ParkBoost;
+
+    impl ValuationPlugin for ParkBoost {
+        fn name(&self) -> &str {
+            "park boost"
+        }
+
+        fn adjust(&self, players: &mut [PlayerValuation]) {
+            for player in players.iter_mut().filter(|p| p.team == "COL" && !p.is_pitcher) {
+                player.dollar_value *= 1.5;
+            }
+        }
+    }
+
+    fn pool() -> Vec<PlayerValuation> {
+        let mut rockie = TestPlayer::hitter("Rockie").dollar(10.0).build();
+        rockie.team = "COL".into();
+        vec![TestPlayer::hitter("Other").dollar(12.0).build(), rockie]
+    }
+
+    #[test]
+    fn registered_plugins_adjust_and_reorder_the_pool() {
+        let mut plugins = Plugins::default();
+        let mut players = pool();
+        plugins.apply(&mut players);
+        assert_eq!(players[0].name, "Other", "no plugins, no change");
+
+        plugins.register(ParkBoost);
+        assert_eq!(plugins.names().collect::<Vec<_>>(), ["park boost"]);
+        plugins.apply(&mut players);
+        assert_eq!((players[0].name.as_str(), players[0].dollar_value), ("Rockie", 15.0));
+        assert_eq!(players[1].dollar_value, 12.0);
+    }
+
+    #[cfg(not(feature = "scripting"))]
+    #[test]
+    fn scripts_need_the_scripting_feature() {
+        let paths = DataPaths { scripts: vec!["boost.rhai".into()], ..Default::default() };
+        assert!(Plugins::load(&paths).is_empty());
+        assert!(matches!(load_script("boost.rhai"), Err(PluginError::ScriptingDisabled { .. })));
+    }
+
+    #[cfg(feature = "scripting")]
+    #[test]
+    fn scripts_return_new_dollar_values() {
+        let dir = std::env::temp_dir().join(format!("wyncast-plugins-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("boost.rhai");
+        std::fs::write(
+            &path,
+            r#"fn adjust(p) { if p.team == "COL" && !p.is_pitcher { p.dollar_value * 1.5 } }"#,
+        )
+        .unwrap();
+        let paths = DataPaths { scripts: vec![path.display().to_string()], ..Default::default() };
+
+        let plugins = Plugins::load(&paths);
+        let mut players = pool();
+        plugins.apply(&mut players);
+        assert_eq!((players[0].name.as_str(), players[0].dollar_value), ("Rockie", 15.0));
+        assert_eq!(players[1].dollar_value, 12.0);
+
+        std::fs::write(
+            &path,
+            r#"fn adjust(p) { if p.positions.contains("RP") && p.stats.sv < 10.0 { p.dollar_value * 0.5 } }"#,
+        )
+        .unwrap();
+        let mut setup = TestPlayer::pitcher("Setup", PitcherType::RP).dollar(6.0).build();
+        setup.projection.values.insert("sv".into(), 3.0);
+        let mut closer = TestPlayer::pitcher("Closer", PitcherType::RP).dollar(8.0).build();
+        closer.projection.values.insert("sv".into(), 30.0);
+        let mut relievers = vec![closer, setup];
+        Plugins::load(&paths).apply(&mut relievers);
+        assert_eq!((relievers[0].dollar_value, relievers[1].dollar_value), (8.0, 3.0));
+
+        let broken = DataPaths { scripts: vec!["/nonexistent/boost.rhai".into()], ..Default::default() };
+        assert!(Plugins::load(&broken).is_empty());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
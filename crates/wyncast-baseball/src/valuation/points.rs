@@ -0,0 +1,103 @@
+// Points leagues: players valued by projected fantasy points.
+//
+// In a league whose `scoring_type` ends in "points", each projected stat is
+// worth the points `[league.points]` gives it, and a player's total takes
+// the place of the total z-score: VOR and the auction conversion run on
+// points unchanged. The per-category z-scores are kept for the category
+// displays, but no longer add up to the total.
+//
+// Point keys are projection stats, matched case insensitively: batting
+// stats by name ("HR", "BB", ...) and pitching stats with a "P_" prefix
+// ("P_IP", "P_K", ...), so a stat both sides have scores each side on its
+// own. "P_ER" is derived from ERA and innings, "P_BBH" (walks plus hits
+// allowed) from WHIP and innings. Stats a projection does not have count
+// for nothing.
+
+use std::collections::HashMap;
+
+use super::zscore::{CategoryZScores, PlayerValuation, ProjectionData};
+
+/// The projected value of pitching stat `stat` (without its prefix),
+/// deriving earned runs from ERA and walks plus hits from WHIP.
+fn pitching_value(projection: &ProjectionData, stat: &str) -> f64 {
+    match stat {
+        "er" if !projection.values.contains_key("er") => projection.get("era") * projection.get("ip") / 9.0,
+        "bbh" => projection.get("whip") * projection.get("ip"),
+        _ => projection.get(stat),
+    }
+}
+
+/// Projected (batting, pitching) points for `projection`.
+pub fn projected_points(projection: &ProjectionData, points: &HashMap<String, f64>) -> (f64, f64) {
+    let mut batting = 0.0;
+    let mut pitching = 0.0;
+    for (stat, value) in points {
+        let stat = stat.to_lowercase();
+        match stat.strip_prefix("p_") {
+            Some(stat) => pitching += value * pitching_value(projection, stat),
+            None => batting += value * projection.get(&stat),
+        }
+    }
+    (batting, pitching)
+}
+
+/// Set each player's total to their projected points.
+pub fn apply_points(players: &mut [PlayerValuation], points: &HashMap<String, f64>) {
+    for player in players.iter_mut() {
+        let (batting, pitching) = projected_points(&player.projection, points);
+        let zscores = player.category_zscores.zscores().clone();
+        player.category_zscores = if player.is_two_way {
+            CategoryZScores::two_way(zscores, batting, pitching)
+        } else if player.is_pitcher {
+            CategoryZScores::pitcher(zscores, pitching)
+        } else {
+            CategoryZScores::hitter(zscores, batting)
+        };
+        player.total_zscore = player.category_zscores.total();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::Position;
+    use crate::test_utils::{make_hitter, make_pitcher};
+    use crate::valuation::projections::PitcherType;
+
+    #[test]
+    fn totals_become_projected_points() {
+        let points: HashMap<String, f64> =
+            [("HR", 4.0), ("R", 1.0), ("RBI", 1.0), ("P_IP", 3.0), ("P_K", 1.0), ("P_ER", -2.0)]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect();
+        let mut players = vec![
+            make_hitter("Slugger", 90, 40, 100, 70, 5, 550, 0.280, vec![Position::FirstBase]),
+            make_pitcher("Ace", 220, 15, 0, 0, 180.0, 3.00, 1.05, PitcherType::SP),
+        ];
+
+        apply_points(&mut players, &points);
+        // 40 HR * 4 + 90 R + 100 RBI.
+        assert_eq!(players[0].total_zscore, 350.0);
+        // 180 IP * 3 + 220 K - 60 ER * 2.
+        assert!((players[1].total_zscore - 640.0).abs() < 1e-9);
+        assert_eq!(players[1].category_zscores.total(), players[1].total_zscore);
+
+        let (batting, pitching) = projected_points(&players[0].projection, &points);
+        assert_eq!((batting, pitching), (350.0, 0.0));
+    }
+
+    #[test]
+    fn walks_score_batters_and_pitchers_apart() {
+        let points: HashMap<String, f64> =
+            [("BB", 1.0), ("P_BBH", -1.0)].into_iter().map(|(k, v)| (k.to_string(), v)).collect();
+        let slugger = make_hitter("Slugger", 90, 40, 100, 70, 5, 550, 0.280, vec![Position::FirstBase]);
+        let ace = make_pitcher("Ace", 220, 15, 0, 0, 180.0, 3.00, 1.10, PitcherType::SP);
+
+        assert_eq!(projected_points(&slugger.projection, &points), (70.0, 0.0));
+        let (batting, pitching) = projected_points(&ace.projection, &points);
+        assert_eq!(batting, 0.0);
+        // 1.10 WHIP over 180 innings: 198 walks and hits allowed.
+        assert!((pitching + 198.0).abs() < 1e-9);
+    }
+}
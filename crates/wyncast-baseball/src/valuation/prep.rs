@@ -0,0 +1,247 @@
+// My draft prep sheet: values, tiers, targets, avoids and notes.
+//
+// A CSV exported from the spreadsheet I prep in, read once at startup. Each
+// row names a player and may set my own dollar value (which overrides the
+// engine's for the quick-bid cap), a tier, whether I'm targeting or avoiding
+// the player, and a note. Headers are matched loosely ("My Value",
+// "my_value" and "myvalue" all work) and any other columns are ignored.
+//
+// Names come from the spreadsheet, not the projections, so they are
+// reconciled against the player pool once valuations exist: a name that
+// matches only when case, accents, periods or a Jr./Sr. suffix are ignored
+// takes the projections' spelling, and names that match nobody are
+// reported.
+
+use std::io::Read;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use wyncast_core::config::DataPaths;
+
+use super::projections::{resolve_data_path, ProjectionError};
+use super::zscore::PlayerValuation;
+
+/// One player's row of the prep sheet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrepEntry {
+    pub player: String,
+    /// My dollar value, overriding the engine's where I bid.
+    pub my_value: Option<f64>,
+    pub tier: Option<u32>,
+    /// On my watchlist.
+    pub target: bool,
+    /// On my avoid list.
+    pub avoid: bool,
+    /// May be empty.
+    pub note: String,
+}
+
+/// Column indices of the prep sheet's fields, from its header row.
+#[derive(Debug, Default)]
+struct Columns {
+    player: Option<usize>,
+    my_value: Option<usize>,
+    tier: Option<usize>,
+    target: Option<usize>,
+    avoid: Option<usize>,
+    note: Option<usize>,
+}
+
+impl Columns {
+    fn from_headers(headers: &csv::StringRecord) -> Self {
+        let mut columns = Columns::default();
+        for (i, header) in headers.iter().enumerate() {
+            let key: String = header
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .map(|c| c.to_ascii_lowercase())
+                .collect();
+            let column = match key.as_str() {
+                "player" | "name" => &mut columns.player,
+                "myvalue" | "value" | "myprice" => &mut columns.my_value,
+                "tier" => &mut columns.tier,
+                "target" | "watch" | "watchlist" => &mut columns.target,
+                "avoid" => &mut columns.avoid,
+                "note" | "notes" => &mut columns.note,
+                _ => continue,
+            };
+            column.get_or_insert(i);
+        }
+        columns
+    }
+}
+
+/// Whether a target/avoid cell is ticked.
+pub(crate) fn ticked(cell: &str) -> bool {
+    matches!(
+        cell.trim().to_ascii_lowercase().as_str(),
+        "y" | "yes" | "x" | "true" | "1" | "✓"
+    )
+}
+
+fn load_from_reader<R: Read>(rdr: R) -> Result<Vec<PrepEntry>, csv::Error> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(rdr);
+    let columns = Columns::from_headers(reader.headers()?);
+    let Some(player_column) = columns.player else {
+        warn!("prep sheet has no player column; ignoring it");
+        return Ok(Vec::new());
+    };
+
+    let mut entries = Vec::new();
+    for result in reader.records() {
+        let record = match result {
+            Ok(record) => record,
+            Err(e) => {
+                warn!("skipping malformed prep sheet row: {}", e);
+                continue;
+            }
+        };
+        let cell = |column: Option<usize>| column.and_then(|i| record.get(i)).map_or("", str::trim);
+        let player = cell(Some(player_column));
+        if player.is_empty() {
+            continue;
+        }
+        let my_value = match cell(columns.my_value).trim_start_matches('$') {
+            "" => None,
+            value => match value.parse::<f64>() {
+                Ok(value) if value.is_finite() => Some(value),
+                _ => {
+                    warn!("prep sheet: ignoring value '{}' for {}", value, player);
+                    None
+                }
+            },
+        };
+        let tier = match cell(columns.tier) {
+            "" => None,
+            tier => tier.parse().map_err(|_| warn!("prep sheet: ignoring tier '{}' for {}", tier, player)).ok(),
+        };
+        entries.push(PrepEntry {
+            player: player.to_string(),
+            my_value,
+            tier,
+            target: ticked(cell(columns.target)),
+            avoid: ticked(cell(columns.avoid)),
+            note: cell(columns.note).to_string(),
+        });
+    }
+    Ok(entries)
+}
+
+/// Load the prep sheet configured under `[data_paths]`, or nothing when
+/// none is configured.
+pub fn load(paths: &DataPaths) -> Result<Vec<PrepEntry>, ProjectionError> {
+    let Some(raw) = &paths.prep else {
+        return Ok(Vec::new());
+    };
+    let path = resolve_data_path(raw);
+    let file = std::fs::File::open(&path).map_err(|e| ProjectionError::Io {
+        path: path.display().to_string(),
+        source: e,
+    })?;
+    load_from_reader(file).map_err(|e| ProjectionError::Csv {
+        path: path.display().to_string(),
+        source: e,
+    })
+}
+
+/// A name with case, accents, periods and a generational suffix dropped,
+/// so "Bobby Witt Jr." and "bobby witt" compare equal.
+pub(crate) fn loose_name(name: &str) -> String {
+    let folded: String = name
+        .chars()
+        .filter(|c| *c != '.' && *c != '\'')
+        .map(|c| match c {
+            'á' | 'à' | 'ä' | 'â' | 'Á' => 'a',
+            'é' | 'è' | 'ë' | 'ê' | 'É' => 'e',
+            'í' | 'ì' | 'ï' | 'î' | 'Í' => 'i',
+            'ó' | 'ò' | 'ö' | 'ô' | 'Ó' => 'o',
+            'ú' | 'ù' | 'ü' | 'û' | 'Ú' => 'u',
+            'ñ' | 'Ñ' => 'n',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect();
+    let words: Vec<&str> = folded.split_whitespace().collect();
+    match words.split_last() {
+        Some((last, rest)) if !rest.is_empty() && matches!(*last, "jr" | "sr" | "ii" | "iii" | "iv") => rest.join(" "),
+        _ => words.join(" "),
+    }
+}
+
+/// Match each entry's name to `players`, taking the players' spelling when
+/// only a loose match fits, and return the names that match nobody, in
+/// sheet order.
+pub fn reconcile(entries: &mut [PrepEntry], players: &[PlayerValuation]) -> Vec<String> {
+    let mut unmatched = Vec::new();
+    for entry in entries {
+        if players.iter().any(|p| p.name == entry.player) {
+            continue;
+        }
+        let loose = loose_name(&entry.player);
+        match players.iter().find(|p| loose_name(&p.name) == loose) {
+            Some(player) => entry.player = player.name.clone(),
+            None => unmatched.push(entry.player.clone()),
+        }
+    }
+    unmatched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestPlayer;
+
+    #[test]
+    fn reads_loosely_named_columns() {
+        let csv = "\
+Player,My Value,Tier,Target?,Avoid?,Note,Rank
+Juan Soto,$48,1,x,,anchor,3
+Bobby Witt Jr.,abc,2,,yes,
+,12,,,,no name
+Luis Arraez,,,,,\"\"\"contact\"\" guy\"
+";
+        let entries = load_from_reader(csv.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[0],
+            PrepEntry {
+                player: "Juan Soto".into(),
+                my_value: Some(48.0),
+                tier: Some(1),
+                target: true,
+                avoid: false,
+                note: "anchor".into(),
+            }
+        );
+        assert_eq!((entries[1].my_value, entries[1].tier, entries[1].avoid), (None, Some(2), true));
+        assert_eq!(entries[2].note, "\"contact\" guy");
+    }
+
+    #[test]
+    fn a_sheet_without_a_player_column_is_ignored() {
+        let entries = load_from_reader("Who,Value\nJuan Soto,40\n".as_bytes()).unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn reconciling_takes_the_projections_spelling() {
+        let players = vec![
+            TestPlayer::hitter("Bobby Witt Jr.").build(),
+            TestPlayer::hitter("José Ramírez").build(),
+            TestPlayer::hitter("Juan Soto").build(),
+        ];
+        let entry = |name: &str| PrepEntry {
+            player: name.into(),
+            my_value: None,
+            tier: None,
+            target: false,
+            avoid: false,
+            note: String::new(),
+        };
+        let mut entries = vec![entry("bobby witt"), entry("Jose Ramirez"), entry("Juan Soto"), entry("Jon Doe")];
+
+        let unmatched = reconcile(&mut entries, &players);
+        assert_eq!(unmatched, ["Jon Doe"]);
+        let names: Vec<&str> = entries.iter().map(|e| e.player.as_str()).collect();
+        assert_eq!(names, ["Bobby Witt Jr.", "José Ramírez", "Juan Soto", "Jon Doe"]);
+    }
+}
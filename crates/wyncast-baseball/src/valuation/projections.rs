@@ -102,6 +102,51 @@ impl From<&PitcherProjection> for ProjectionData {
 pub struct AllProjections {
     pub hitters: Vec<HitterProjection>,
     pub pitchers: Vec<PitcherProjection>,
+    /// Where the projections came from; every value valued from this set is
+    /// attributed to it.
+    pub source: ProjectionSource,
+}
+
+/// Where a set of projections came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectionSource {
+    /// Converted from ESPN's projections, relayed by the extension.
+    Espn,
+    /// Loaded from the configured CSV files, which override ESPN's.
+    Csv { hitters: String, pitchers: String },
+}
+
+impl ProjectionSource {
+    /// Source name for hitter values, or pitcher values when `pitcher` is
+    /// set: "ESPN", or the CSV's file name.
+    pub fn name(&self, pitcher: bool) -> String {
+        match self {
+            ProjectionSource::Espn => "ESPN".to_string(),
+            ProjectionSource::Csv { hitters, pitchers } => {
+                let path = Path::new(if pitcher { pitchers } else { hitters });
+                path.file_name()
+                    .map(|f| f.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| path.display().to_string())
+            }
+        }
+    }
+
+    /// Whether values from this source replace ESPN's defaults.
+    pub fn is_override(&self) -> bool {
+        matches!(self, ProjectionSource::Csv { .. })
+    }
+}
+
+impl AllProjections {
+    /// Projection data for a player by name, from the pitcher list when
+    /// `pitcher` is set and the hitter list otherwise.
+    pub fn projection_for(&self, name: &str, pitcher: bool) -> Option<ProjectionData> {
+        if pitcher {
+            self.pitchers.iter().find(|p| p.name == name).map(ProjectionData::from)
+        } else {
+            self.hitters.iter().find(|h| h.name == name).map(ProjectionData::from)
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -320,7 +365,7 @@ pub fn load_all(config: &Config) -> Result<Option<AllProjections>, ProjectionErr
 ///   (dev workflow, files live in the repo checkout).
 /// - **Release builds** (`cargo build --release`): resolve relative to the
 ///   OS app data directory (`~/.local/share/wyncast` on Linux).
-fn resolve_data_path(raw: &str) -> std::path::PathBuf {
+pub fn resolve_data_path(raw: &str) -> std::path::PathBuf {
     let p = Path::new(raw);
     if p.is_absolute() {
         return p.to_path_buf();
@@ -373,7 +418,14 @@ pub fn load_all_from_paths(paths: &DataPaths) -> Result<Option<AllProjections>,
                 ));
             }
 
-            Ok(Some(AllProjections { hitters, pitchers }))
+            Ok(Some(AllProjections {
+                hitters,
+                pitchers,
+                source: ProjectionSource::Csv {
+                    hitters: hitters_path.display().to_string(),
+                    pitchers: pitchers_path.display().to_string(),
+                },
+            }))
         }
     }
 }
@@ -544,7 +596,11 @@ pub fn from_espn_projections(espn: &[EspnPlayerProjection]) -> AllProjections {
         }
     }
 
-    AllProjections { hitters, pitchers }
+    AllProjections {
+        hitters,
+        pitchers,
+        source: ProjectionSource::Espn,
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -555,6 +611,36 @@ pub fn from_espn_projections(espn: &[EspnPlayerProjection]) -> AllProjections {
 mod tests {
     use super::*;
 
+    // -- Lookup by name --
+
+    #[test]
+    fn projection_for_looks_up_the_requested_list() {
+        let hitters = load_hitters_from_reader(
+            "Name,Team,PA,AB,H,HR,R,RBI,BB,SB,AVG\nShohei Ohtani,LAD,650,560,170,45,110,100,80,20,0.304".as_bytes(),
+        )
+        .unwrap();
+        let all = AllProjections { hitters, pitchers: vec![], source: ProjectionSource::Espn };
+
+        let data = all.projection_for("Shohei Ohtani", false).unwrap();
+        assert_eq!(data.get_or_zero("hr"), 45.0);
+        assert!(all.projection_for("Shohei Ohtani", true).is_none());
+        assert!(all.projection_for("Nobody", false).is_none());
+    }
+
+    #[test]
+    fn projection_source_names_and_override_flag() {
+        assert_eq!(ProjectionSource::Espn.name(false), "ESPN");
+        assert!(!ProjectionSource::Espn.is_override());
+
+        let csv = ProjectionSource::Csv {
+            hitters: "/data/steamer-hitters.csv".into(),
+            pitchers: "/data/steamer-pitchers.csv".into(),
+        };
+        assert_eq!(csv.name(false), "steamer-hitters.csv");
+        assert_eq!(csv.name(true), "steamer-pitchers.csv");
+        assert!(csv.is_override());
+    }
+
     // -- Hitter CSV round-trip --
 
     #[test]
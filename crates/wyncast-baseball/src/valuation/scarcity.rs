@@ -14,7 +14,12 @@ use crate::valuation::zscore::PlayerValuation;
 // Scarcity urgency levels
 // ---------------------------------------------------------------------------
 
-/// How urgently a position needs to be addressed.
+/// League size the urgency thresholds are set for; other leagues scale the
+/// count of players left to it.
+const REFERENCE_TEAMS: usize = 10;
+
+/// How urgently a position needs to be addressed. The counts are for a
+/// 10-team league.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScarcityUrgency {
     /// 0-2 players above replacement: act now or miss out.
@@ -38,6 +43,13 @@ impl ScarcityUrgency {
         }
     }
 
+    /// Urgency in a `num_teams` league: the count is scaled to the
+    /// 10-team thresholds, so 5 players left among 20 teams is as urgent as
+    /// 2 among 10.
+    pub fn for_league(players_above_replacement: usize, num_teams: usize) -> Self {
+        Self::from_count(players_above_replacement * REFERENCE_TEAMS / num_teams.max(1))
+    }
+
     /// Return a human-readable label.
     pub fn label(&self) -> &'static str {
         match self {
@@ -89,10 +101,10 @@ pub struct ScarcityEntry {
 
 /// Derive tracked positions from roster config keys.
 ///
-/// Skips meta slots (BE, IL). For combo slots (OF, MI, CI, P), expands to
+/// Skips meta slots (BE, IL). For combo slots (OF, MI, CI, IF, P), expands to
 /// their constituent concrete positions so scarcity is tracked at the
 /// concrete level (e.g. LF, CF, RF instead of just OF).
-fn derive_tracked_positions(roster_config: &HashMap<String, usize>) -> Vec<Position> {
+pub(crate) fn derive_tracked_positions(roster_config: &HashMap<String, usize>) -> Vec<Position> {
     let mut positions = Vec::new();
     let mut seen = std::collections::HashSet::new();
 
@@ -122,7 +134,7 @@ fn derive_tracked_positions(roster_config: &HashMap<String, usize>) -> Vec<Posit
 /// Primary check: the `positions` list (populated from ESPN eligible_slots
 /// overlay or backfilled by the VOR pipeline). Fallback: `best_position`
 /// (set by VOR computation) and `pitcher_type` (always known for pitchers).
-fn player_eligible_at(p: &PlayerValuation, pos: Position) -> bool {
+pub(crate) fn player_eligible_at(p: &PlayerValuation, pos: Position) -> bool {
     // Primary: explicit positions list
     if p.positions.contains(&pos) {
         return true;
@@ -156,10 +168,11 @@ fn player_eligible_at(p: &PlayerValuation, pos: Position) -> bool {
 /// 3. Count how many are above replacement (VOR > 0).
 /// 4. Find the top VOR and the 3rd-best VOR.
 /// 5. Compute dropoff = top - 3rd-best.
-/// 6. Assign urgency based on count thresholds.
+/// 6. Assign urgency based on count thresholds, scaled to `num_teams`.
 pub fn compute_scarcity(
     available_players: &[PlayerValuation],
     roster_config: &HashMap<String, usize>,
+    num_teams: usize,
 ) -> Vec<ScarcityEntry> {
     let tracked = derive_tracked_positions(roster_config);
     let mut entries = Vec::new();
@@ -191,7 +204,7 @@ pub fn compute_scarcity(
 
         let dropoff = top_available_vor - replacement_vor;
 
-        let urgency = ScarcityUrgency::from_count(players_above_replacement);
+        let urgency = ScarcityUrgency::for_league(players_above_replacement, num_teams);
 
         entries.push(ScarcityEntry {
             position: pos,
@@ -265,6 +278,20 @@ mod tests {
         assert_eq!(ScarcityUrgency::from_count(15), ScarcityUrgency::Low);
     }
 
+    #[test]
+    fn urgency_scales_with_league_size() {
+        for count in 0..20 {
+            assert_eq!(ScarcityUrgency::for_league(count, 10), ScarcityUrgency::from_count(count));
+        }
+        // Five left is Medium among 10 teams but Critical among 20.
+        assert_eq!(ScarcityUrgency::for_league(5, 20), ScarcityUrgency::Critical);
+        assert_eq!(ScarcityUrgency::for_league(8, 16), ScarcityUrgency::Medium);
+        assert_eq!(ScarcityUrgency::for_league(16, 20), ScarcityUrgency::Low);
+        // A small league runs short sooner.
+        assert_eq!(ScarcityUrgency::for_league(4, 8), ScarcityUrgency::Medium);
+        assert_eq!(ScarcityUrgency::for_league(3, 0), ScarcityUrgency::Low);
+    }
+
     #[test]
     fn scarcity_dropoff_calculation() {
         let roster = test_roster_config();
@@ -277,7 +304,7 @@ mod tests {
             make_hitter("C4", 1.0, vec![Position::Catcher]),
         ];
 
-        let scarcity = compute_scarcity(&players, &roster);
+        let scarcity = compute_scarcity(&players, &roster, 10);
         let c_entry = scarcity_for_position(&scarcity, Position::Catcher).unwrap();
 
         assert_eq!(c_entry.players_above_replacement, 4);
@@ -297,7 +324,7 @@ mod tests {
             make_hitter("SS2", 2.0, vec![Position::ShortStop]),
         ];
 
-        let scarcity = compute_scarcity(&players, &roster);
+        let scarcity = compute_scarcity(&players, &roster, 10);
         let ss_entry = scarcity_for_position(&scarcity, Position::ShortStop).unwrap();
 
         assert_eq!(ss_entry.players_above_replacement, 2);
@@ -312,7 +339,7 @@ mod tests {
         let roster = test_roster_config();
         let players: Vec<PlayerValuation> = Vec::new();
 
-        let scarcity = compute_scarcity(&players, &roster);
+        let scarcity = compute_scarcity(&players, &roster, 10);
         let c_entry = scarcity_for_position(&scarcity, Position::Catcher).unwrap();
 
         assert_eq!(c_entry.players_above_replacement, 0);
@@ -336,7 +363,7 @@ mod tests {
             })
             .collect();
 
-        let scarcity = compute_scarcity(&players, &roster);
+        let scarcity = compute_scarcity(&players, &roster, 10);
         let fb_entry = scarcity_for_position(&scarcity, Position::FirstBase).unwrap();
 
         assert_eq!(fb_entry.players_above_replacement, 10);
@@ -356,7 +383,7 @@ mod tests {
             make_hitter("2B_bad2", -3.0, vec![Position::SecondBase]),
         ];
 
-        let scarcity = compute_scarcity(&players, &roster);
+        let scarcity = compute_scarcity(&players, &roster, 10);
         let sb_entry = scarcity_for_position(&scarcity, Position::SecondBase).unwrap();
 
         // Only 1 player with positive VOR
@@ -384,7 +411,7 @@ mod tests {
             ));
         }
 
-        let scarcity = compute_scarcity(&players, &roster);
+        let scarcity = compute_scarcity(&players, &roster, 10);
 
         let sp_entry = scarcity_for_position(&scarcity, Position::StartingPitcher).unwrap();
         assert_eq!(sp_entry.players_above_replacement, 6);
@@ -411,7 +438,7 @@ mod tests {
             players.push(make_hitter(&format!("1B_{}", i), 10.0 - i as f64, vec![Position::FirstBase]));
         }
 
-        let scarcity = compute_scarcity(&players, &roster);
+        let scarcity = compute_scarcity(&players, &roster, 10);
 
         // Critical positions should come first
         let first_urgency = scarcity[0].urgency;
@@ -512,7 +539,7 @@ mod tests {
             players.push(p);
         }
 
-        let scarcity = compute_scarcity(&players, &roster);
+        let scarcity = compute_scarcity(&players, &roster, 10);
 
         // No position should be Critical with 15 players per position
         let critical_count = scarcity
@@ -564,7 +591,7 @@ mod tests {
             players.push(p);
         }
 
-        let scarcity = compute_scarcity(&players, &roster);
+        let scarcity = compute_scarcity(&players, &roster, 10);
         let ss_entry = scarcity_for_position(&scarcity, Position::ShortStop).unwrap();
 
         assert_eq!(ss_entry.players_above_replacement, 10);
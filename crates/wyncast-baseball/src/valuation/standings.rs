@@ -0,0 +1,223 @@
+// Projected standings: where each team would finish in each category, and
+// how buying the nominated player would move me.
+//
+// A team's projected totals come from the players it has bought plus, for
+// each open hitter and pitcher slot, the expected fill: the average of the
+// best available players, as many as the league has slots of that kind
+// open, since those are the players the slots will go to. Teams are then
+// ranked per category in its favorable direction (1 = first).
+
+use std::iter;
+
+use wyncast_core::stats::{ProjectionData, SortDirection, StatComputation, StatDefinition, StatRegistry};
+
+use crate::draft::pick::Position;
+use crate::draft::roster::Roster;
+use crate::valuation::analysis::{aggregate, contributes_to};
+use crate::valuation::projections::AllProjections;
+use crate::valuation::zscore::PlayerValuation;
+
+/// A team's projected roster: the players it has and its open slots.
+#[derive(Debug, Clone, Default)]
+pub struct TeamProjection {
+    pub players: Vec<ProjectionData>,
+    pub open_hitters: usize,
+    pub open_pitchers: usize,
+}
+
+impl TeamProjection {
+    /// Project `roster`, looking its players up in `projections`. Bench and
+    /// IL slots are not counted as open.
+    pub fn from_roster(roster: &Roster, projections: &AllProjections) -> Self {
+        let mut team = TeamProjection::default();
+        for slot in &roster.slots {
+            match &slot.player {
+                Some(rp) => team.players.extend(projections.projection_for(&rp.name, !rp.position.is_hitter())),
+                None if matches!(slot.position, Position::Bench | Position::InjuredList | Position::Minors) => {}
+                None if slot.position.is_hitter() => team.open_hitters += 1,
+                None => team.open_pitchers += 1,
+            }
+        }
+        team
+    }
+
+    /// The team's value in `stat`, open slots filled.
+    fn value(&self, stat: &StatDefinition, fill: &Fill) -> f64 {
+        let projections = self
+            .players
+            .iter()
+            .chain(iter::repeat_n(&fill.hitter, self.open_hitters))
+            .chain(iter::repeat_n(&fill.pitcher, self.open_pitchers));
+        aggregate(stat, projections).0
+    }
+}
+
+/// My rank in one category before and after buying the player.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandingsShift {
+    pub category: String,
+    pub before: usize,
+    pub after: usize,
+}
+
+/// The expected player for an open hitter and pitcher slot.
+struct Fill {
+    hitter: ProjectionData,
+    pitcher: ProjectionData,
+}
+
+/// Each team's rank per category, in registry order.
+fn category_ranks(teams: &[TeamProjection], fill: &Fill, registry: &StatRegistry) -> Vec<Vec<usize>> {
+    let values: Vec<Vec<f64>> = teams
+        .iter()
+        .map(|team| registry.all_stats().iter().map(|stat| team.value(stat, fill)).collect())
+        .collect();
+    values
+        .iter()
+        .map(|mine| {
+            registry
+                .all_stats()
+                .iter()
+                .enumerate()
+                .map(|(i, stat)| {
+                    let better = values
+                        .iter()
+                        .filter(|other| match stat.sort_direction {
+                            SortDirection::HigherIsBetter => other[i] > mine[i] + 1e-9,
+                            SortDirection::LowerIsBetter => other[i] < mine[i] - 1e-9,
+                        })
+                        .count();
+                    better + 1
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// How buying `player` would move team `me` in the projected standings,
+/// for each category the player contributes to. The player is taken out
+/// of `available` for the expected fill, and takes one of my open slots of
+/// their kind.
+pub fn standings_impact(
+    player: &PlayerValuation,
+    me: usize,
+    teams: &[TeamProjection],
+    available: &[PlayerValuation],
+    registry: &StatRegistry,
+) -> Vec<StandingsShift> {
+    if me >= teams.len() {
+        return Vec::new();
+    }
+    let others: Vec<&PlayerValuation> = available.iter().filter(|p| p.name != player.name).collect();
+    let fill = Fill {
+        hitter: expected_fill(&others, false, teams.iter().map(|t| t.open_hitters).sum(), registry),
+        pitcher: expected_fill(&others, true, teams.iter().map(|t| t.open_pitchers).sum(), registry),
+    };
+    let before = category_ranks(teams, &fill, registry);
+
+    let mut with = teams.to_vec();
+    let mine = &mut with[me];
+    mine.players.push(ProjectionData::from(&player.projection));
+    if player.is_pitcher {
+        mine.open_pitchers = mine.open_pitchers.saturating_sub(1);
+    } else {
+        mine.open_hitters = mine.open_hitters.saturating_sub(1);
+    }
+    let after = category_ranks(&with, &fill, registry);
+
+    registry
+        .all_stats()
+        .iter()
+        .enumerate()
+        .filter(|(_, stat)| contributes_to(player, stat))
+        .map(|(i, stat)| StandingsShift {
+            category: stat.abbrev.clone(),
+            before: before[me][i],
+            after: after[me][i],
+        })
+        .collect()
+}
+
+/// The average of the best `count` available hitters or pitchers.
+fn expected_fill(available: &[&PlayerValuation], pitchers: bool, count: usize, registry: &StatRegistry) -> ProjectionData {
+    let mut pool: Vec<&PlayerValuation> = available.iter().copied().filter(|p| p.is_pitcher == pitchers).collect();
+    pool.sort_by(|a, b| b.dollar_value.total_cmp(&a.dollar_value));
+    let projections: Vec<ProjectionData> = pool
+        .into_iter()
+        .take(count)
+        .map(|p| ProjectionData::from(&p.projection))
+        .collect();
+
+    let mut average = ProjectionData::new();
+    if projections.is_empty() {
+        return average;
+    }
+    let n = projections.len() as f64;
+    for stat in registry.all_stats() {
+        match &stat.computation {
+            StatComputation::Counting { projection_key } => {
+                let total: f64 = projections.iter().map(|p| p.get_or_zero(projection_key)).sum();
+                average.insert(projection_key.clone(), total / n);
+            }
+            StatComputation::RateStat { volume_key, rate_key, .. } => {
+                let (rate, volume) = aggregate(stat, projections.iter());
+                average.insert(volume_key.clone(), volume / n);
+                average.insert(rate_key.clone(), rate);
+            }
+        }
+    }
+    average
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_registry, TestPlayer};
+
+    fn hitter(name: &str, dollars: f64, hr: f64, sb: f64) -> PlayerValuation {
+        let mut player = TestPlayer::hitter(name).dollar(dollars).build();
+        player.projection.values.extend([
+            ("pa".to_string(), 600.0),
+            ("ab".to_string(), 500.0),
+            ("h".to_string(), 130.0),
+            ("hr".to_string(), hr),
+            ("sb".to_string(), sb),
+        ]);
+        player
+    }
+
+    fn team(players: &[&PlayerValuation], open_hitters: usize) -> TeamProjection {
+        TeamProjection {
+            players: players.iter().map(|p| ProjectionData::from(&p.projection)).collect(),
+            open_hitters,
+            open_pitchers: 0,
+        }
+    }
+
+    fn shift<'a>(shifts: &'a [StandingsShift], category: &str) -> &'a StandingsShift {
+        shifts.iter().find(|s| s.category == category).unwrap()
+    }
+
+    #[test]
+    fn a_speedster_moves_me_up_in_steals_only() {
+        let registry = test_registry();
+        let (a, b, c) = (hitter("A", 20.0, 30.0, 5.0), hitter("B", 18.0, 20.0, 20.0), hitter("C", 15.0, 25.0, 10.0));
+        let teams = vec![team(&[&a], 1), team(&[&b], 1), team(&[&c], 1)];
+        let speedster = hitter("Speedster", 12.0, 2.0, 40.0);
+        let available = vec![hitter("Fill", 5.0, 10.0, 10.0), speedster.clone()];
+
+        let shifts = standings_impact(&speedster, 0, &teams, &available, &registry);
+        // Hitting categories only, and I am third in steals before.
+        assert!(shifts.iter().all(|s| registry.get(&s.category).unwrap().player_type == wyncast_core::stats::PlayerType::Hitter));
+        assert_eq!(shift(&shifts, "SB"), &StandingsShift { category: "SB".into(), before: 3, after: 1 });
+        // 30 + 10 (fill) HR before, 30 + 2 after: from first to second.
+        assert_eq!((shift(&shifts, "HR").before, shift(&shifts, "HR").after), (1, 2));
+    }
+
+    #[test]
+    fn unknown_team_has_no_impact() {
+        let registry = test_registry();
+        let player = hitter("X", 10.0, 10.0, 10.0);
+        assert!(standings_impact(&player, 3, &[TeamProjection::default()], &[], &registry).is_empty());
+    }
+}
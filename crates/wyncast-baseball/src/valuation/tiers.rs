@@ -0,0 +1,142 @@
+// Position tiers: the most valuable players at each position, in tiers.
+//
+// Positions are the concrete ones the roster fills (combo slots expanded, as
+// for scarcity), and a player is listed at every position they are eligible
+// for. Within a position a new tier starts wherever the dollar value falls
+// by at least `TIER_BREAK` from the player above, so a tier is a run of
+// players worth about the same.
+
+use std::collections::HashMap;
+
+use crate::draft::pick::Position;
+use crate::valuation::scarcity::{derive_tracked_positions, player_eligible_at};
+use crate::valuation::zscore::PlayerValuation;
+
+/// Drop in dollar value between neighbours that starts a new tier.
+pub const TIER_BREAK: f64 = 3.0;
+
+/// One player in a position's ranking.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TieredPlayer {
+    pub name: String,
+    pub team: String,
+    pub dollar_value: f64,
+    pub vor: f64,
+    /// 1 for the top tier.
+    pub tier: usize,
+}
+
+/// The top players at one position, most valuable first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionTiers {
+    pub position: Position,
+    pub players: Vec<TieredPlayer>,
+}
+
+/// Tier numbers for dollar values sorted high to low.
+pub fn tiers(values: &[f64]) -> Vec<usize> {
+    let mut tier = 1;
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            if i > 0 && values[i - 1] - value >= TIER_BREAK {
+                tier += 1;
+            }
+            tier
+        })
+        .collect()
+}
+
+/// The top `top_n` players at each position in `roster_config`, in position
+/// order.
+pub fn position_tiers(
+    players: &[PlayerValuation],
+    roster_config: &HashMap<String, usize>,
+    top_n: usize,
+) -> Vec<PositionTiers> {
+    let mut positions = derive_tracked_positions(roster_config);
+    positions.sort();
+    positions
+        .into_iter()
+        .map(|position| {
+            let mut eligible: Vec<&PlayerValuation> = players
+                .iter()
+                .filter(|p| player_eligible_at(p, position))
+                .collect();
+            eligible.sort_by(|a, b| b.dollar_value.total_cmp(&a.dollar_value));
+            eligible.truncate(top_n);
+            let values: Vec<f64> = eligible.iter().map(|p| p.dollar_value).collect();
+            let players = eligible
+                .into_iter()
+                .zip(tiers(&values))
+                .map(|(p, tier)| TieredPlayer {
+                    name: p.name.clone(),
+                    team: p.team.clone(),
+                    dollar_value: p.dollar_value,
+                    vor: p.vor,
+                    tier,
+                })
+                .collect();
+            PositionTiers { position, players }
+        })
+        .collect()
+}
+
+/// The tier of the player named `name` among the players eligible at
+/// `position`, or `None` if they are not one of them.
+pub fn tier_at(players: &[PlayerValuation], position: Position, name: &str) -> Option<usize> {
+    let mut eligible: Vec<&PlayerValuation> = players
+        .iter()
+        .filter(|p| player_eligible_at(p, position))
+        .collect();
+    eligible.sort_by(|a, b| b.dollar_value.total_cmp(&a.dollar_value));
+    let values: Vec<f64> = eligible.iter().map(|p| p.dollar_value).collect();
+    let index = eligible.iter().position(|p| p.name == name)?;
+    tiers(&values).get(index).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_roster_config, TestPlayer};
+    use crate::valuation::projections::PitcherType;
+
+    #[test]
+    fn a_big_drop_starts_a_new_tier() {
+        assert_eq!(tiers(&[40.0, 38.5, 35.5, 35.0, 20.0]), vec![1, 1, 2, 2, 3]);
+        assert!(tiers(&[]).is_empty());
+    }
+
+    #[test]
+    fn each_position_lists_its_top_players() {
+        let players = vec![
+            TestPlayer::hitter("SS1").positions(vec![Position::ShortStop]).dollar(30.0).build(),
+            TestPlayer::hitter("SS2B").positions(vec![Position::SecondBase, Position::ShortStop]).dollar(25.0).build(),
+            TestPlayer::hitter("SS3").positions(vec![Position::ShortStop]).dollar(24.0).build(),
+            TestPlayer::pitcher("Ace", PitcherType::SP).dollar(28.0).build(),
+        ];
+        let ranked = position_tiers(&players, &test_roster_config(), 2);
+
+        let at = |pos| &ranked.iter().find(|r| r.position == pos).unwrap().players;
+        let names = |pos| at(pos).iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names(Position::ShortStop), vec!["SS1", "SS2B"]);
+        assert_eq!(at(Position::ShortStop)[1].tier, 2);
+        assert_eq!(names(Position::SecondBase), vec!["SS2B"]);
+        assert_eq!(names(Position::StartingPitcher), vec!["Ace"]);
+        assert!(names(Position::Catcher).is_empty());
+        assert!(ranked.iter().all(|r| !r.position.is_meta_slot()));
+    }
+
+    #[test]
+    fn tier_at_ranks_a_player_within_a_position() {
+        let players = vec![
+            TestPlayer::hitter("SS1").positions(vec![Position::ShortStop]).dollar(30.0).build(),
+            TestPlayer::hitter("SS2B").positions(vec![Position::SecondBase, Position::ShortStop]).dollar(25.0).build(),
+            TestPlayer::pitcher("Ace", PitcherType::SP).dollar(28.0).build(),
+        ];
+        assert_eq!(tier_at(&players, Position::ShortStop, "SS2B"), Some(2));
+        assert_eq!(tier_at(&players, Position::SecondBase, "SS2B"), Some(1));
+        assert_eq!(tier_at(&players, Position::ShortStop, "Ace"), None);
+    }
+}
@@ -41,7 +41,7 @@ pub fn determine_replacement_levels(
     // ---- Derive hitter positions from roster config ----
 
     // Build position_slots: concrete hitter positions -> total slots per team.
-    // Combo slots (OF, MI, CI) are expanded: their slot counts are added to
+    // Combo slots (OF, MI, CI, IF) are expanded: their slot counts are added to
     // each constituent position for player eligibility matching purposes,
     // and also counted in the total dedicated slots for UTIL calculation.
     let mut position_slots: HashMap<Position, usize> = HashMap::new();
@@ -54,7 +54,7 @@ pub fn determine_replacement_levels(
                 // DH doesn't have dedicated replacement-level tracking;
                 // DH-eligible hitters compete via UTIL.
             } else if pos.is_combo_slot() && pos.is_hitter() {
-                // Combo hitter slots (OF, MI, CI): expand to constituent positions.
+                // Combo hitter slots (OF, MI, CI, IF): expand to constituent positions.
                 // These slots accept any of their constituent positions, so they
                 // contribute to the total hitter pool but we track replacement
                 // levels at the concrete position level.
@@ -388,6 +388,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn replacement_levels_expand_infield_slot() {
+        // One SS slot plus one IF slot: the IF slot adds a starter to each of
+        // 1B, 2B, 3B and SS, so the 3rd best shortstop is replacement level.
+        let mut roster = HashMap::new();
+        roster.insert("SS".to_string(), 1);
+        roster.insert("IF".to_string(), 1);
+
+        let players: Vec<PlayerValuation> = [10.0, 8.0, 6.0, 4.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &z)| make_hitter_valuation(&format!("SS{i}"), z, vec![Position::ShortStop]))
+            .collect();
+
+        let levels = determine_replacement_levels(&players, &roster, 1);
+        assert!(approx_eq(levels[&Position::ShortStop], 6.0, 0.01));
+        for pos in [Position::FirstBase, Position::SecondBase, Position::ThirdBase] {
+            assert!(levels.contains_key(&pos), "IF should track {pos}");
+        }
+        assert!(!levels.contains_key(&Position::Infield));
+    }
+
     #[test]
     fn replacement_levels_position_specific_dominates() {
         // When a position is scarce, its replacement level should be LOWER than
@@ -2,9 +2,13 @@
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+
 use wyncast_core::config::{CategoryWeights, Config, PoolConfig};
 use wyncast_core::stats::{self, CategoryValues, StatComputation, StatRegistry};
-use crate::draft::pick::Position;
+use crate::draft::pick::{position_tokens, Position};
+use crate::valuation::adjustments::AppliedAdjustment;
+use crate::valuation::identity;
 use crate::valuation::projections::{AllProjections, HitterProjection, PitcherProjection, PitcherType};
 
 // ---------------------------------------------------------------------------
@@ -77,7 +81,7 @@ pub fn weights_to_category_values(
 /// Per-category z-scores for a player, stored as a full-length CategoryValues
 /// vector indexed by StatRegistry position. Hitter variants have 0.0 at
 /// pitching indices; Pitcher variants have 0.0 at batting indices.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CategoryZScores {
     Hitter {
         zscores: CategoryValues,
@@ -194,9 +198,27 @@ impl CategoryZScores {
 /// The hitter/pitcher distinction is carried by `PlayerValuation.is_pitcher`
 /// and `PlayerValuation.is_two_way` flags. A two-way player's ProjectionData
 /// contains both hitting and pitching keys merged together.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectionData {
     pub values: HashMap<String, f64>,
+    /// Where each value came from, keyed like `values`. A blended value has
+    /// one entry per source. Empty when the data was built without a source.
+    pub provenance: HashMap<String, Vec<FieldProvenance>>,
+    /// Factors from the adjustments CSV applied to these values, in the
+    /// order they were applied.
+    #[serde(default)]
+    pub adjustments: Vec<AppliedAdjustment>,
+}
+
+/// One source's contribution to a projection value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldProvenance {
+    /// Source name: "ESPN" or a CSV file name.
+    pub source: String,
+    /// Share of the value taken from this source; 1.0 when it is the only one.
+    pub weight: f64,
+    /// Whether the value replaces ESPN's default projection.
+    pub overridden: bool,
 }
 
 impl ProjectionData {
@@ -210,9 +232,60 @@ impl ProjectionData {
         for (k, v) in &other.values {
             self.values.insert(k.clone(), *v);
         }
+        for (k, sources) in &other.provenance {
+            self.provenance.insert(k.clone(), sources.clone());
+        }
+    }
+
+    /// Attribute every value to a single source.
+    pub fn with_source(mut self, source: &str, overridden: bool) -> Self {
+        self.provenance = self
+            .values
+            .keys()
+            .map(|k| {
+                let entry = FieldProvenance {
+                    source: source.to_string(),
+                    weight: 1.0,
+                    overridden,
+                };
+                (k.clone(), vec![entry])
+            })
+            .collect();
+        self
+    }
+
+    /// Whether any value overrides ESPN's or blends several sources.
+    pub fn is_overridden_or_blended(&self) -> bool {
+        self.provenance
+            .values()
+            .any(|sources| sources.len() > 1 || sources.iter().any(|s| s.overridden))
     }
 }
 
+/// Describe a value's sources, e.g. "ESPN", "steamer.csv (override)" or
+/// "60% ESPN + 40% steamer.csv".
+pub fn describe_provenance(sources: &[FieldProvenance]) -> String {
+    let mut text = match sources {
+        [] => "unknown".to_string(),
+        [only] => only.source.clone(),
+        _ => sources
+            .iter()
+            .map(|s| format!("{:.0}% {}", s.weight * 100.0, s.source))
+            .collect::<Vec<_>>()
+            .join(" + "),
+    };
+    if sources.iter().any(|s| s.overridden) {
+        text.push_str(" (override)");
+    }
+    text
+}
+
+/// Attribute a projection built from `projections` to the set's source.
+fn attributed(data: ProjectionData, projections: &AllProjections, pitcher: bool) -> ProjectionData {
+    let source = &projections.source;
+    data.with_source(&source.name(pitcher), source.is_override())
+}
+
 impl From<&HitterProjection> for ProjectionData {
     fn from(h: &HitterProjection) -> Self {
         ProjectionData {
@@ -227,6 +300,8 @@ impl From<&HitterProjection> for ProjectionData {
                 ("sb".into(), h.sb as f64),
                 ("avg".into(), h.avg),
             ]),
+            provenance: HashMap::new(),
+            adjustments: Vec::new(),
         }
     }
 }
@@ -245,6 +320,8 @@ impl From<&PitcherProjection> for ProjectionData {
                 ("g".into(), p.g as f64),
                 ("gs".into(), p.gs as f64),
             ]),
+            provenance: HashMap::new(),
+            adjustments: Vec::new(),
         }
     }
 }
@@ -267,7 +344,7 @@ impl From<&ProjectionData> for stats::ProjectionData {
 ///
 /// Fields `vor`, `best_position`, and `dollar_value` are initialized
 /// to defaults here and filled by subsequent pipeline stages (Tasks 06/07).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerValuation {
     pub name: String,
     pub team: String,
@@ -288,6 +365,17 @@ pub struct PlayerValuation {
     pub initial_vor: f64,
     pub best_position: Option<Position>,
     pub dollar_value: f64,
+    /// Portion of `dollar_value` that comes from the multi-position
+    /// flexibility premium (see `auction::flex_bonus`).
+    pub flex_bonus: f64,
+    /// Dollar value with every category weighted equally and the default
+    /// budget split: the league consensus, against which `dollar_value`
+    /// carries my weights and punts. Unchanged by strategy recalculation.
+    pub consensus_value: f64,
+    /// The config's custom stats, in config order: `None` where the
+    /// player's projection cannot compute one.
+    #[serde(default)]
+    pub custom_stats: Vec<Option<f64>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -443,33 +531,27 @@ pub(crate) fn compute_player_category_zscores(
     total
 }
 
-// ---------------------------------------------------------------------------
-// Top-level entry point
-// ---------------------------------------------------------------------------
+/// The pool statistics every player is scored against.
+#[derive(Debug, Clone)]
+pub struct PoolBaseline {
+    /// Per-category hitter means and standard deviations, indexed by
+    /// registry position.
+    pub hitter_stats: Vec<PoolStats>,
+    /// League averages of the batting rate stats, by registry index.
+    pub hitter_league_avgs: HashMap<usize, f64>,
+    /// Per-category pitcher means and standard deviations.
+    pub pitcher_stats: Vec<PoolStats>,
+    /// League averages of the pitching rate stats.
+    pub pitcher_league_avgs: HashMap<usize, f64>,
+}
 
-/// Compute initial z-scores for all players, returning a `Vec<PlayerValuation>`
-/// sorted descending by total z-score.
-///
-/// Steps:
-/// 1. Filter hitter/SP/RP pools according to config thresholds.
-/// 2. Compute league averages for rate stats from the filtered pools.
-/// 3. Compute per-category pool stats (using volume-weighted contributions
-///    for AVG, ERA, WHIP).
-/// 4. Score every player (including those below the pool threshold) against
-///    the pool stats.
-/// 5. Apply category weights and sum to a total z-score.
-///
-/// Fields `vor`, `best_position`, and `dollar_value` are left at defaults
-/// for downstream pipeline stages.
-pub fn compute_initial_zscores(
+/// Filter the hitter, SP and RP pools from `projections` and compute their
+/// per-category statistics. SP and RP share one pitcher pool.
+pub fn pool_baseline(
     projections: &AllProjections,
-    config: &Config,
+    pool_cfg: &PoolConfig,
     registry: &StatRegistry,
-    weight_values: &CategoryValues,
-) -> Vec<PlayerValuation> {
-    let pool_cfg = &config.strategy.pool;
-
-    // ---- 1. Filter pools ----
+) -> PoolBaseline {
     let hitter_pool = filter_hitter_pool(&projections.hitters, pool_cfg);
     let sp_pool = filter_sp_pool(&projections.pitchers, pool_cfg);
     let rp_pool = filter_rp_pool(&projections.pitchers, pool_cfg);
@@ -481,7 +563,6 @@ pub fn compute_initial_zscores(
         .copied()
         .collect();
 
-    // ---- 2+3. Pool stats via generic loop ----
     let hitter_pool_data: Vec<stats::ProjectionData> = hitter_pool
         .iter()
         .map(|h| stats::ProjectionData::from(*h))
@@ -498,6 +579,46 @@ pub fn compute_initial_zscores(
         &pitcher_pool_data, registry.pitching_indices(), registry,
     );
 
+    PoolBaseline {
+        hitter_stats,
+        hitter_league_avgs,
+        pitcher_stats,
+        pitcher_league_avgs,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Top-level entry point
+// ---------------------------------------------------------------------------
+
+/// Compute initial z-scores for all players, returning a `Vec<PlayerValuation>`
+/// sorted descending by total z-score.
+///
+/// Steps:
+/// 1. Filter hitter/SP/RP pools according to config thresholds.
+/// 2. Compute league averages for rate stats from the filtered pools.
+/// 3. Compute per-category pool stats (using volume-weighted contributions
+///    for AVG, ERA, WHIP).
+/// 4. Score every player (including those below the pool threshold) against
+///    the pool stats.
+/// 5. Apply category weights and sum to a total z-score.
+///
+/// Fields `vor`, `best_position`, and `dollar_value` are left at defaults
+/// for downstream pipeline stages.
+pub fn compute_initial_zscores(
+    projections: &AllProjections,
+    config: &Config,
+    registry: &StatRegistry,
+    weight_values: &CategoryValues,
+) -> Vec<PlayerValuation> {
+    // ---- 1-3. Filter pools and compute their stats ----
+    let PoolBaseline {
+        hitter_stats,
+        hitter_league_avgs,
+        pitcher_stats,
+        pitcher_league_avgs,
+    } = pool_baseline(projections, &config.strategy.pool, registry);
+
     // ---- 4+5. Score all players ----
     let mut valuations: Vec<PlayerValuation> = Vec::with_capacity(
         projections.hitters.len() + projections.pitchers.len(),
@@ -510,9 +631,9 @@ pub fn compute_initial_zscores(
         .map(|p| p.name.as_str())
         .collect();
 
-    // Track which pitcher names were matched as two-way players so we can
-    // skip their standalone pitcher entry later.
-    let mut two_way_pitcher_names: std::collections::HashSet<String> =
+    // Track which pitchers (name and team) were matched as two-way players
+    // so we can skip their standalone pitcher entry later.
+    let mut two_way_pitcher_names: std::collections::HashSet<(String, String)> =
         std::collections::HashSet::new();
 
     for hitter in &projections.hitters {
@@ -522,7 +643,7 @@ pub fn compute_initial_zscores(
                 projections
                     .pitchers
                     .iter()
-                    .find(|p| p.name == hitter.name)
+                    .find(|p| identity::same_player(&p.name, &p.team, &hitter.name, &hitter.team))
             })
             .flatten()
         {
@@ -554,8 +675,7 @@ pub fn compute_initial_zscores(
             // Live ESPN eligible_slots will override these at runtime.
             let mut two_way_positions = vec![pitcher_pos];
             if !hitter.espn_position.is_empty() {
-                for token in hitter.espn_position.split('/') {
-                    let t = token.trim();
+                for t in position_tokens(&hitter.espn_position) {
                     if t.eq_ignore_ascii_case("OF") {
                         for of_pos in [Position::LeftField, Position::CenterField, Position::RightField] {
                             if !of_pos.is_meta_slot() && !two_way_positions.contains(&of_pos) {
@@ -570,7 +690,7 @@ pub fn compute_initial_zscores(
                 }
             }
 
-            two_way_pitcher_names.insert(hitter.name.clone());
+            two_way_pitcher_names.insert((matching_pitcher.name.clone(), matching_pitcher.team.clone()));
 
             valuations.push(PlayerValuation {
                 name: hitter.name.clone(),
@@ -580,8 +700,8 @@ pub fn compute_initial_zscores(
                 is_two_way: true,
                 pitcher_type: Some(matching_pitcher.pitcher_type),
                 projection: {
-                    let mut proj = ProjectionData::from(hitter);
-                    proj.merge(&ProjectionData::from(matching_pitcher));
+                    let mut proj = attributed(ProjectionData::from(hitter), projections, false);
+                    proj.merge(&attributed(ProjectionData::from(matching_pitcher), projections, true));
                     proj
                 },
                 total_zscore: combined_total,
@@ -590,6 +710,9 @@ pub fn compute_initial_zscores(
                 initial_vor: 0.0,
                 best_position: None,
                 dollar_value: 0.0,
+                flex_bonus: 0.0,
+                consensus_value: 0.0,
+                custom_stats: Vec::new(),
             });
         } else {
             // Normal hitter (not a two-way player).
@@ -605,8 +728,7 @@ pub fn compute_initial_zscores(
             // may be overridden by live ESPN eligible_slots during draft.
             let positions: Vec<Position> = if !hitter.espn_position.is_empty() {
                 let mut pos: Vec<Position> = Vec::new();
-                for token in hitter.espn_position.split('/') {
-                    let t = token.trim();
+                for t in position_tokens(&hitter.espn_position) {
                     if t.eq_ignore_ascii_case("OF") {
                         pos.push(Position::LeftField);
                         pos.push(Position::CenterField);
@@ -631,20 +753,23 @@ pub fn compute_initial_zscores(
                 is_pitcher: false,
                 is_two_way: false,
                 pitcher_type: None,
-                projection: ProjectionData::from(hitter),
+                projection: attributed(ProjectionData::from(hitter), projections, false),
                 total_zscore: total,
                 category_zscores: CategoryZScores::hitter(zscores, total),
                 vor: 0.0,
                 initial_vor: 0.0,
                 best_position: None,
                 dollar_value: 0.0,
+                flex_bonus: 0.0,
+                consensus_value: 0.0,
+                custom_stats: Vec::new(),
             });
         }
     }
 
     for pitcher in &projections.pitchers {
         // Skip pitchers that were already merged into a two-way player entry.
-        if two_way_pitcher_names.contains(&pitcher.name) {
+        if two_way_pitcher_names.contains(&(pitcher.name.clone(), pitcher.team.clone())) {
             continue;
         }
 
@@ -667,13 +792,16 @@ pub fn compute_initial_zscores(
             is_pitcher: true,
             is_two_way: false,
             pitcher_type: Some(pitcher.pitcher_type),
-            projection: ProjectionData::from(pitcher),
+            projection: attributed(ProjectionData::from(pitcher), projections, true),
             total_zscore: total,
             category_zscores: CategoryZScores::pitcher(zscores, total),
             vor: 0.0,
             initial_vor: 0.0,
             best_position: None,
             dollar_value: 0.0,
+            flex_bonus: 0.0,
+            consensus_value: 0.0,
+            custom_stats: Vec::new(),
         });
     }
 
@@ -744,15 +872,26 @@ mod tests {
                     gs_per_week: 7,
                 },
                 teams: std::collections::HashMap::new(),
+                player_pool: Default::default(),
+                min_bid: 1,
+                keepers: Default::default(),
+                nomination_order: vec![],
+                roster: HashMap::new(),
+                bid_increment: 1,
+                draft_type: Default::default(),
+                points: Default::default(),
             },
             strategy: StrategyConfig {
                 hitting_budget_fraction: 0.65,
+                adaptive_budget_split: false,
                 weights: CategoryWeights::from_pairs([
                     ("R", 1.0), ("HR", 1.0), ("RBI", 1.0), ("BB", 1.0),
                     ("SB", 1.0), ("AVG", 1.0), ("K", 1.0), ("W", 1.0),
                     ("SV", 0.7), ("HD", 1.0), ("ERA", 1.0), ("WHIP", 1.0),
                 ]),
                 strategy_overview: None,
+                flexibility: FlexibilityConfig::default(),
+                valuation: ValuationRulesConfig::default(),
                 pool: PoolConfig {
                     min_pa: 200,
                     min_ip_sp: 50.0,
@@ -768,11 +907,21 @@ mod tests {
                     planning_max_tokens: 2048,
                     analysis_trigger: "nomination".into(),
                     prefire_planning: true,
+                    analysis_verbosity: Default::default(),
+                    analysis_language: "English".into(),
+                    analysis_tldr: false,
+                    prefire: Default::default(),
                 },
             },
             credentials: CredentialsConfig::default(),
             ws_port: 9001,
             data_paths: DataPaths::default(),
+            logging: LoggingConfig::default(),
+            notify: Default::default(),
+            discord_bot: Default::default(),
+            accessibility: Default::default(),
+        display: Default::default(),
+            backup: Default::default(),
         }
     }
 
@@ -1052,6 +1201,63 @@ mod tests {
         assert_eq!(pool[1].g, 35);
     }
 
+    // ---- Provenance ----
+
+    #[test]
+    fn valuations_attribute_every_value_to_the_projection_source() {
+        let projections = AllProjections {
+            hitters: vec![make_hitter("Csv Hitter", 600, 540, 155, 30, 90, 85, 55, 12)],
+            pitchers: vec![make_sp("Csv Ace", 200.0, 250, 18, 2.50, 0.95)],
+            source: ProjectionSource::Csv {
+                hitters: "data/hitters.csv".into(),
+                pitchers: "data/pitchers.csv".into(),
+            },
+        };
+        let config = test_config();
+        let (registry, weight_values) = test_registry_and_weights(&config);
+        let valuations = compute_initial_zscores(&projections, &config, &registry, &weight_values);
+
+        let hitter = valuations.iter().find(|v| v.name == "Csv Hitter").unwrap();
+        assert_eq!(
+            hitter.projection.provenance.len(),
+            hitter.projection.values.len()
+        );
+        assert_eq!(
+            hitter.projection.provenance["hr"],
+            vec![FieldProvenance { source: "hitters.csv".into(), weight: 1.0, overridden: true }]
+        );
+        assert!(hitter.projection.is_overridden_or_blended());
+
+        let ace = valuations.iter().find(|v| v.name == "Csv Ace").unwrap();
+        assert_eq!(ace.projection.provenance["era"][0].source, "pitchers.csv");
+    }
+
+    #[test]
+    fn espn_values_are_neither_overridden_nor_blended() {
+        let data = ProjectionData::from(&make_hitter("H", 600, 540, 155, 30, 90, 85, 55, 12))
+            .with_source("ESPN", false);
+        assert!(!data.is_overridden_or_blended());
+        assert_eq!(describe_provenance(&data.provenance["hr"]), "ESPN");
+    }
+
+    #[test]
+    fn describe_provenance_formats_blends_and_overrides() {
+        let source = |name: &str, weight, overridden| FieldProvenance {
+            source: name.into(),
+            weight,
+            overridden,
+        };
+        assert_eq!(
+            describe_provenance(&[source("steamer.csv", 1.0, true)]),
+            "steamer.csv (override)"
+        );
+        assert_eq!(
+            describe_provenance(&[source("ESPN", 0.6, false), source("steamer.csv", 0.4, false)]),
+            "60% ESPN + 40% steamer.csv"
+        );
+        assert_eq!(describe_provenance(&[]), "unknown");
+    }
+
     // ---- Synthetic dataset integration test ----
 
     #[test]
@@ -1075,6 +1281,7 @@ mod tests {
         let projections = AllProjections {
             hitters,
             pitchers,
+            source: ProjectionSource::Espn,
         };
 
         // Config with pools small enough to include all players
@@ -1182,6 +1389,7 @@ mod tests {
         let projections = AllProjections {
             hitters,
             pitchers,
+            source: ProjectionSource::Espn,
         };
 
         let mut config = test_config();
@@ -1224,6 +1432,7 @@ mod tests {
         let projections = AllProjections {
             hitters,
             pitchers,
+            source: ProjectionSource::Espn,
         };
 
         let mut config = test_config();
@@ -1264,6 +1473,7 @@ mod tests {
         let projections = AllProjections {
             hitters,
             pitchers,
+            source: ProjectionSource::Espn,
         };
 
         let mut config = test_config();
@@ -1298,6 +1508,7 @@ mod tests {
         let projections = AllProjections {
             hitters,
             pitchers,
+            source: ProjectionSource::Espn,
         };
 
         let mut config = test_config();
@@ -1333,10 +1544,13 @@ mod tests {
 
     #[test]
     fn two_way_player_detected_when_name_matches() {
-        // A player appearing in both hitters and pitchers CSVs should be
-        // detected as a two-way player.
+        // A player appearing in both hitters and pitchers CSVs, on the same
+        // team, should be detected as a two-way player.
         let hitters = vec![
-            make_hitter("Shohei Ohtani", 600, 540, 162, 40, 100, 95, 55, 15),
+            HitterProjection {
+                team: "LAD".into(),
+                ..make_hitter("Shohei Ohtani", 600, 540, 162, 40, 100, 95, 55, 15)
+            },
             make_hitter("Regular Hitter", 550, 500, 140, 25, 80, 75, 45, 10),
         ];
 
@@ -1358,7 +1572,7 @@ mod tests {
             make_sp("Regular SP", 180.0, 190, 14, 3.30, 1.10),
         ];
 
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1433,7 +1647,7 @@ mod tests {
             make_sp("Filler SP2", 160.0, 150, 10, 3.80, 1.20),
         ];
 
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1505,7 +1719,7 @@ mod tests {
             make_sp("Filler SP2", 160.0, 150, 10, 3.80, 1.20),
         ];
 
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1571,7 +1785,7 @@ mod tests {
             make_rp("Pure RP", 60.0, 70, 30, 0, 2.50, 0.95, 55),
         ];
 
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1603,7 +1817,7 @@ mod tests {
             make_hitter("Some Other", 600, 540, 150, 25, 80, 75, 50, 10),
         ];
         let pitchers = vec![make_sp("SP1", 180.0, 190, 14, 3.30, 1.10)];
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1628,7 +1842,7 @@ mod tests {
 
         let hitters = vec![hitter];
         let pitchers = vec![make_sp("SP1", 180.0, 190, 14, 3.30, 1.10)];
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1654,7 +1868,7 @@ mod tests {
 
         let hitters = vec![hitter];
         let pitchers = vec![make_sp("SP1", 180.0, 190, 14, 3.30, 1.10)];
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1677,7 +1891,7 @@ mod tests {
 
         let hitters = vec![hitter];
         let pitchers = vec![make_sp("SP1", 180.0, 190, 14, 3.30, 1.10)];
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1700,7 +1914,7 @@ mod tests {
 
         let hitters = vec![hitter];
         let pitchers = vec![make_sp("SP1", 180.0, 190, 14, 3.30, 1.10)];
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1724,7 +1938,7 @@ mod tests {
 
         let hitters = vec![hitter];
         let pitchers = vec![make_sp("SP1", 180.0, 190, 14, 3.30, 1.10)];
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1748,7 +1962,7 @@ mod tests {
 
         let hitters = vec![hitter];
         let pitchers = vec![make_sp("SP1", 180.0, 190, 14, 3.30, 1.10)];
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 100;
@@ -1788,7 +2002,7 @@ mod tests {
             make_rp("Setup Man", 70.0, 75, 5, 25, 3.00, 1.10, 65),
         ];
 
-        let projections = AllProjections { hitters, pitchers };
+        let projections = AllProjections { hitters, pitchers, source: ProjectionSource::Espn };
 
         let mut config = test_config();
         config.strategy.pool.min_pa = 200;
@@ -1,17 +1,32 @@
-// OS-appropriate application data directory resolution.
+// OS-appropriate application data and cache directory resolution.
 //
-// On Linux:   ~/.local/share/wyncast
-// On macOS:   ~/Library/Application Support/wyncast
-// On Windows: %APPDATA%\wyncast
+// On Linux:   ~/.local/share/wyncast   (cache: ~/.cache/wyncast)
+// On macOS:   ~/Library/Application Support/wyncast   (cache: ~/Library/Caches/wyncast)
+// On Windows: %APPDATA%\wyncast   (cache: %LOCALAPPDATA%\wyncast\cache)
 //
 // All config, database, and log files are stored here so the application
 // does not litter files in whatever directory it happens to be launched from.
+// `WYNCAST_DATA_DIR` and `WYNCAST_CACHE_DIR` move either directory; the
+// database and cache can also be moved in strategy.toml's `[data_paths]`.
 
 use directories::ProjectDirs;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
 
 const APP_NAME: &str = "wyncast";
 
+/// Environment variable that replaces the app data directory.
+pub const DATA_DIR_ENV: &str = "WYNCAST_DATA_DIR";
+
+/// Environment variable that replaces the app cache directory.
+pub const CACHE_DIR_ENV: &str = "WYNCAST_CACHE_DIR";
+
+/// File name of the database.
+pub const DB_FILE: &str = "draft-assistant.db";
+
+/// File name of the warm-start valuations cache.
+pub const VALUATION_CACHE_FILE: &str = "valuations.cache";
+
 fn project_dirs() -> ProjectDirs {
     ProjectDirs::from("", "", APP_NAME)
         .expect("could not determine app data directory")
@@ -31,7 +46,7 @@ fn project_dirs() -> ProjectDirs {
 /// indicate a misconfigured home directory) or if the directory cannot be
 /// created.
 pub fn app_data_dir() -> PathBuf {
-    let dir = project_dirs().data_dir().to_path_buf();
+    let dir = env_dir(DATA_DIR_ENV).unwrap_or_else(|| project_dirs().data_dir().to_path_buf());
 
     std::fs::create_dir_all(&dir)
         .unwrap_or_else(|e| panic!("failed to create app data directory {}: {e}", dir.display()));
@@ -39,6 +54,32 @@ pub fn app_data_dir() -> PathBuf {
     dir
 }
 
+/// Returns the OS-standard cache directory for wyncast, or
+/// `WYNCAST_CACHE_DIR` when set. Creates the directory if it does not
+/// already exist.
+///
+/// - Linux:   `~/.cache/wyncast`
+/// - macOS:   `~/Library/Caches/wyncast`
+/// - Windows: `%LOCALAPPDATA%\wyncast\cache`
+///
+/// # Panics
+///
+/// Panics if the directory cannot be created.
+pub fn app_cache_dir() -> PathBuf {
+    let dir = env_dir(CACHE_DIR_ENV).unwrap_or_else(|| project_dirs().cache_dir().to_path_buf());
+
+    std::fs::create_dir_all(&dir)
+        .unwrap_or_else(|e| panic!("failed to create app cache directory {}: {e}", dir.display()));
+
+    dir
+}
+
+/// The directory named by the environment variable `var`, if set and not
+/// empty.
+fn env_dir(var: &str) -> Option<PathBuf> {
+    std::env::var_os(var).filter(|v| !v.is_empty()).map(PathBuf::from)
+}
+
 /// Returns the path to the configuration directory inside the app data directory.
 ///
 /// Example: `~/.local/share/wyncast/config`
@@ -52,7 +93,87 @@ pub fn config_dir() -> PathBuf {
 ///
 /// Example: `~/.local/share/wyncast/draft-assistant.db`
 pub fn db_path() -> PathBuf {
-    app_data_dir().join("draft-assistant.db")
+    app_data_dir().join(DB_FILE)
+}
+
+/// Returns the path to the warm-start valuations cache inside the app cache
+/// directory.
+///
+/// Example: `~/.cache/wyncast/valuations.cache`
+pub fn valuation_cache_path() -> PathBuf {
+    app_cache_dir().join(VALUATION_CACHE_FILE)
+}
+
+/// A file moved from where an older version (or a launch from another
+/// directory) left it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
+/// A file to move, with the files that belong to it (a database's SQLite
+/// journal) and only ever move with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileMove {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    pub companions: Vec<(PathBuf, PathBuf)>,
+}
+
+impl FileMove {
+    pub fn new(from: PathBuf, to: PathBuf) -> Self {
+        FileMove { from, to, companions: Vec::new() }
+    }
+}
+
+/// Move each file that exists at `from` but not yet at `to`, creating `to`'s
+/// directory, and with it its companions. A file already at `to` is never
+/// overwritten: the copy in the new place wins, and the old one (companions
+/// included, so a stale journal never lands next to another database) is
+/// left alone. Files that cannot be renamed (another filesystem) are
+/// copied, then removed.
+pub fn migrate_files(moves: &[FileMove]) -> io::Result<Vec<Migration>> {
+    let mut migrated = Vec::new();
+    for m in moves {
+        if m.from == m.to || !m.from.is_file() || m.to.exists() {
+            continue;
+        }
+        if let Some(parent) = m.to.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let companions = m
+            .companions
+            .iter()
+            .filter(|(from, to)| from.is_file() && !to.exists())
+            .map(|(from, to)| (from, to));
+        for (from, to) in std::iter::once((&m.from, &m.to)).chain(companions) {
+            if std::fs::rename(from, to).is_err() {
+                std::fs::copy(from, to)?;
+                std::fs::remove_file(from)?;
+            }
+            migrated.push(Migration { from: from.clone(), to: to.clone() });
+        }
+    }
+    Ok(migrated)
+}
+
+/// The files to move on first run into `db` and `cache`: a database and
+/// cache created in the launch directory `cwd` (with the database's SQLite
+/// journal files), and a cache kept in the data directory before the cache
+/// had its own.
+pub fn legacy_moves(cwd: &Path, db: &Path, cache: &Path) -> Vec<FileMove> {
+    let mut database = FileMove::new(cwd.join(DB_FILE), db.to_path_buf());
+    for suffix in ["-wal", "-shm"] {
+        let mut to = db.as_os_str().to_owned();
+        to.push(suffix);
+        database.companions.push((cwd.join(format!("{DB_FILE}{suffix}")), to.into()));
+    }
+    vec![
+        database,
+        FileMove::new(cwd.join(VALUATION_CACHE_FILE), cache.to_path_buf()),
+        FileMove::new(app_data_dir().join(VALUATION_CACHE_FILE), cache.to_path_buf()),
+    ]
 }
 
 /// Returns the path to the log directory inside the app data directory,
@@ -96,6 +217,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn valuation_cache_lives_in_the_cache_dir() {
+        let path = valuation_cache_path();
+        assert_eq!(path.parent(), Some(app_cache_dir().as_path()));
+        assert!(app_cache_dir().exists());
+    }
+
+    #[test]
+    fn migrate_files_moves_only_what_is_missing() {
+        let root = std::env::temp_dir().join(format!("wyncast-migrate-{}", std::process::id()));
+        let (cwd, data) = (root.join("cwd"), root.join("data"));
+        std::fs::create_dir_all(&cwd).unwrap();
+        std::fs::create_dir_all(&data).unwrap();
+        std::fs::write(cwd.join(DB_FILE), "old db").unwrap();
+        std::fs::write(cwd.join(VALUATION_CACHE_FILE), "old cache").unwrap();
+        std::fs::write(data.join(VALUATION_CACHE_FILE), "new cache").unwrap();
+
+        let moves = [
+            FileMove::new(cwd.join(DB_FILE), data.join(DB_FILE)),
+            FileMove::new(cwd.join(VALUATION_CACHE_FILE), data.join(VALUATION_CACHE_FILE)),
+            FileMove::new(cwd.join("missing"), data.join("missing")),
+        ];
+        let migrated = migrate_files(&moves).unwrap();
+        assert_eq!(migrated, [Migration { from: cwd.join(DB_FILE), to: data.join(DB_FILE) }]);
+        assert_eq!(std::fs::read_to_string(data.join(DB_FILE)).unwrap(), "old db");
+        assert!(!cwd.join(DB_FILE).exists());
+        // The cache already in place is kept; the old one stays where it was.
+        assert_eq!(std::fs::read_to_string(data.join(VALUATION_CACHE_FILE)).unwrap(), "new cache");
+        assert!(cwd.join(VALUATION_CACHE_FILE).exists());
+        assert!(migrate_files(&moves).unwrap().is_empty(), "nothing left to move");
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn legacy_moves_cover_the_database_journal() {
+        let moves = legacy_moves(Path::new("/launch"), Path::new("/data/my.db"), Path::new("/cache/v.cache"));
+        assert_eq!(moves[0].from, PathBuf::from("/launch/draft-assistant.db"));
+        assert_eq!(moves[0].to, PathBuf::from("/data/my.db"));
+        assert!(moves[0]
+            .companions
+            .contains(&(PathBuf::from("/launch/draft-assistant.db-wal"), PathBuf::from("/data/my.db-wal"))));
+        assert!(moves.contains(&FileMove::new(PathBuf::from("/launch/valuations.cache"), PathBuf::from("/cache/v.cache"))));
+    }
+
+    #[test]
+    fn a_stale_journal_stays_behind_when_its_database_does() {
+        let root = std::env::temp_dir().join(format!("wyncast-journal-{}", std::process::id()));
+        let (cwd, data) = (root.join("cwd"), root.join("data"));
+        std::fs::create_dir_all(&cwd).unwrap();
+        std::fs::create_dir_all(&data).unwrap();
+        std::fs::write(cwd.join(DB_FILE), "old db").unwrap();
+        std::fs::write(cwd.join(format!("{DB_FILE}-wal")), "old wal").unwrap();
+        let db = data.join(DB_FILE);
+        std::fs::write(&db, "new db").unwrap();
+
+        let moves = legacy_moves(&cwd, &db, &root.join("cache").join(VALUATION_CACHE_FILE));
+        assert!(migrate_files(&moves[..1]).unwrap().is_empty());
+        assert!(!data.join(format!("{DB_FILE}-wal")).exists(), "the old WAL must not meet the new database");
+        assert!(cwd.join(format!("{DB_FILE}-wal")).exists());
+
+        // Without a database in place, the journal moves with its own.
+        std::fs::remove_file(&db).unwrap();
+        let migrated = migrate_files(&moves[..1]).unwrap();
+        assert_eq!(migrated.len(), 2);
+        assert_eq!(std::fs::read_to_string(data.join(format!("{DB_FILE}-wal"))).unwrap(), "old wal");
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
     #[test]
     fn log_dir_exists_after_call() {
         let dir = log_dir();
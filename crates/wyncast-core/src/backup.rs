@@ -0,0 +1,186 @@
+// Draft database backups: timestamped copies written to a second location
+// (e.g. a Dropbox folder) as configured in the `[backup]` section of
+// strategy.toml, retention pruning, and the restore used by
+// `--restore-backup` at startup.
+//
+// Backups are written under a temporary name and renamed into place, so a
+// sync client never picks up a half-written file.
+
+use std::path::{Path, PathBuf};
+
+use rusqlite::{Connection, OpenFlags};
+
+use crate::db::{Database, DbContext, DbError, Result};
+
+/// File name prefix shared by every backup.
+const PREFIX: &str = "draft-assistant-";
+
+/// File name suffix of a finished backup.
+const SUFFIX: &str = ".db";
+
+/// Path of a backup taken now: `draft-assistant-YYYYmmdd-HHMMSS.db`, so
+/// sorting by name sorts by age.
+pub fn backup_path(dir: &Path) -> PathBuf {
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    dir.join(format!("{PREFIX}{stamp}{SUFFIX}"))
+}
+
+/// Every backup in `dir`, newest first. A missing directory has none.
+pub fn list_backups(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .with_context(|| format!("failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.starts_with(PREFIX) && name.ends_with(SUFFIX))
+        .collect();
+    names.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(names.into_iter().map(|name| dir.join(name)).collect())
+}
+
+/// The newest backup in `dir`, if any.
+pub fn latest_backup(dir: &Path) -> Result<Option<PathBuf>> {
+    Ok(list_backups(dir)?.into_iter().next())
+}
+
+/// Delete all but the newest `keep` backups in `dir`. Returns the number of
+/// files removed.
+pub fn prune_backups(dir: &Path, keep: usize) -> Result<usize> {
+    let stale = list_backups(dir)?.into_iter().skip(keep);
+    let mut removed = 0;
+    for path in stale {
+        std::fs::remove_file(&path)
+            .with_context(|| format!("failed to delete {}", path.display()))?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+/// Back up `db` into `dir`, keeping the newest `keep` backups. Returns the
+/// path of the new backup.
+pub fn write_backup(db: &Database, dir: &Path, keep: usize) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let path = backup_path(dir);
+    let partial = path.with_extension("db.partial");
+    // `VACUUM INTO` refuses to overwrite, so clear any leftover from an
+    // interrupted backup first.
+    let _ = std::fs::remove_file(&partial);
+    let partial_str = partial
+        .to_str()
+        .ok_or_else(|| DbError::InvalidPath("backup path contains non-UTF-8 characters".into()))?;
+    db.backup_to(partial_str)?;
+    std::fs::rename(&partial, &path)
+        .with_context(|| format!("failed to move backup into place at {}", path.display()))?;
+    prune_backups(dir, keep)?;
+    Ok(path)
+}
+
+/// Replace the database at `db_path` with `backup`. Must run before the
+/// database is opened.
+///
+/// The backup is checked first, and the current database is kept next to
+/// it as `<name>.before-restore`, so a bad restore can be undone by hand.
+pub fn restore(backup: &Path, db_path: &Path) -> Result<()> {
+    let conn = Connection::open_with_flags(backup, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .with_context(|| format!("failed to open backup {}", backup.display()))?;
+    let check: String = conn
+        .query_row("PRAGMA quick_check", [], |row| row.get(0))
+        .with_context(|| format!("{} is not a readable database", backup.display()))?;
+    if check != "ok" {
+        return Err(DbError::DamagedBackup {
+            path: backup.display().to_string(),
+            check,
+        });
+    }
+    drop(conn);
+
+    if db_path.exists() {
+        let previous = sidecar(db_path, ".before-restore");
+        std::fs::copy(db_path, &previous)
+            .with_context(|| format!("failed to keep the current database as {}", previous.display()))?;
+    }
+    // Stale WAL files belong to the database being replaced.
+    for suffix in ["-wal", "-shm"] {
+        let _ = std::fs::remove_file(sidecar(db_path, suffix));
+    }
+    std::fs::copy(backup, db_path)
+        .with_context(|| format!("failed to copy {} to {}", backup.display(), db_path.display()))?;
+    Ok(())
+}
+
+/// `path` with `suffix` appended to its file name.
+fn sidecar(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn fresh_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn list_and_prune_keep_the_newest_backups() {
+        let dir = fresh_dir("backup_test_prune");
+        for name in [
+            "draft-assistant-20260301-100000.db",
+            "draft-assistant-20260301-110000.db",
+            "draft-assistant-20260301-120000.db",
+            "draft-assistant-20260301-130000.db.partial",
+            "notes.txt",
+        ] {
+            fs::write(dir.join(name), "x").unwrap();
+        }
+
+        assert_eq!(
+            latest_backup(&dir).unwrap(),
+            Some(dir.join("draft-assistant-20260301-120000.db"))
+        );
+        assert_eq!(prune_backups(&dir, 2).unwrap(), 1);
+        assert!(!dir.join("draft-assistant-20260301-100000.db").exists());
+        assert!(dir.join("draft-assistant-20260301-130000.db.partial").exists());
+        assert!(dir.join("notes.txt").exists());
+        assert!(list_backups(&dir.join("missing")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn backup_round_trips_through_restore() {
+        let dir = fresh_dir("backup_test_round_trip");
+        let db_path = dir.join("live.db");
+        let db = Database::open(db_path.to_str().unwrap()).unwrap();
+        db.set_draft_id("draft_before").unwrap();
+
+        let backup = write_backup(&db, &dir.join("backups"), 3).unwrap();
+        assert!(backup.exists());
+        db.set_draft_id("draft_after").unwrap();
+        drop(db);
+
+        restore(&backup, &db_path).unwrap();
+        let db = Database::open(db_path.to_str().unwrap()).unwrap();
+        assert_eq!(db.get_draft_id().unwrap().as_deref(), Some("draft_before"));
+        assert!(dir.join("live.db.before-restore").exists());
+    }
+
+    #[test]
+    fn restore_rejects_a_file_that_is_not_a_database() {
+        let dir = fresh_dir("backup_test_reject");
+        let bogus = dir.join("draft-assistant-20260301-100000.db");
+        fs::write(&bogus, "definitely not sqlite, but long enough to have a header").unwrap();
+        let db_path = dir.join("live.db");
+        fs::write(&db_path, "current").unwrap();
+
+        let err = restore(&bogus, &db_path).unwrap_err();
+        assert!(matches!(err, DbError::Sqlite { .. }), "{err}");
+        assert_eq!(fs::read_to_string(&db_path).unwrap(), "current");
+    }
+}
@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+use crate::expr::CustomStat;
 use crate::llm::provider::LlmProvider;
 
 // ---------------------------------------------------------------------------
@@ -22,13 +23,35 @@ pub enum ConfigError {
         source: toml::de::Error,
     },
 
-    #[error("validation error for field `{field}`: {message}")]
-    ValidationError { field: String, message: String },
+    #[error("invalid settings:\n{}", list_problems(.0))]
+    Invalid(Vec<ConfigProblem>),
 
     #[error("failed to initialize default config files: {message}")]
     DefaultsWriteError { message: String },
 }
 
+/// One setting that failed validation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigProblem {
+    /// Dotted path of the setting, e.g. `pool.sp_pool_size`.
+    pub field: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}`: {}", self.field, self.message)
+    }
+}
+
+fn list_problems(problems: &[ConfigProblem]) -> String {
+    problems
+        .iter()
+        .map(|p| format!("  - {p}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 // ---------------------------------------------------------------------------
 // Top-level assembled Config
 // ---------------------------------------------------------------------------
@@ -40,6 +63,12 @@ pub struct Config {
     pub credentials: CredentialsConfig,
     pub ws_port: u16,
     pub data_paths: DataPaths,
+    pub logging: LoggingConfig,
+    pub notify: NotifyConfig,
+    pub discord_bot: DiscordBotConfig,
+    pub accessibility: AccessibilityConfig,
+    pub display: DisplayConfig,
+    pub backup: BackupConfig,
 }
 
 impl Default for Config {
@@ -50,6 +79,12 @@ impl Default for Config {
             credentials: CredentialsConfig::default(),
             ws_port: 9001,
             data_paths: DataPaths::default(),
+            logging: LoggingConfig::default(),
+            notify: NotifyConfig::default(),
+            discord_bot: DiscordBotConfig::default(),
+            accessibility: AccessibilityConfig::default(),
+            display: DisplayConfig::default(),
+            backup: BackupConfig::default(),
         }
     }
 }
@@ -78,6 +113,69 @@ pub struct LeagueConfig {
     /// from ESPN's live draft data via the extension.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub teams: HashMap<String, String>,
+    /// Which MLB players the league drafts from (AL-only, NL-only, or a
+    /// team whitelist). Unrestricted by default.
+    #[serde(default, skip_serializing_if = "PlayerPool::is_unrestricted")]
+    pub player_pool: PlayerPool,
+    /// Smallest legal bid: $1 in most leagues, $0 where empty-dollar bids
+    /// are allowed. Every open roster slot reserves this much budget.
+    #[serde(default = "default_min_bid")]
+    pub min_bid: u32,
+    /// The platform's bid increment in whole dollars: each raise adds a
+    /// multiple of it. ESPN takes $1 raises.
+    #[serde(default = "default_bid_increment")]
+    pub bid_increment: u32,
+    /// Keeper contracts and their raise rules (`[league.keepers]`), for
+    /// auction keeper leagues with multi-year contracts.
+    #[serde(default, skip_serializing_if = "KeeperRules::is_empty")]
+    pub keepers: KeeperRules,
+    /// Team names (or ESPN team IDs) in the order they nominate. Empty to
+    /// take the order from ESPN's draft board; unlisted teams go last.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nomination_order: Vec<String>,
+    /// Roster slots and their counts (`[league.roster]`, e.g. `SP = 5`),
+    /// used until ESPN's draft board reports the league's own. Empty for
+    /// the default roster.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub roster: HashMap<String, usize>,
+    /// `"auction"` (the default) or `"snake"`. In a snake draft teams pick
+    /// in turn, in `nomination_order`, reversing every round, and players
+    /// are ranked rather than priced.
+    #[serde(default)]
+    pub draft_type: DraftType,
+    /// Points per unit of each projected stat (`[league.points]`, e.g.
+    /// `HR = 4.0`, `P_ER = -2.0`), for a points league: one whose
+    /// `scoring_type` ends in "points". Keys are `BATTING_POINTS` and
+    /// `PITCHING_POINTS`, case insensitive.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub points: HashMap<String, f64>,
+}
+
+/// Batting stats a points league can score.
+pub const BATTING_POINTS: &[&str] = &["PA", "AB", "H", "HR", "R", "RBI", "BB", "SB"];
+
+/// Pitching stats a points league can score. The `P_` prefix keeps them
+/// apart from batting stats of the same name: `BB` is a batter's walks,
+/// `P_BBH` the walks plus hits a pitcher allows.
+pub const PITCHING_POINTS: &[&str] = &["P_IP", "P_K", "P_W", "P_SV", "P_HD", "P_ER", "P_BBH", "P_G", "P_GS"];
+
+/// How the league drafts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DraftType {
+    /// Teams bid on nominated players against a salary cap.
+    #[default]
+    Auction,
+    /// Teams pick in turn, the order reversing every round.
+    Snake,
+}
+
+fn default_min_bid() -> u32 {
+    1
+}
+
+fn default_bid_increment() -> u32 {
+    1
 }
 
 impl Default for LeagueConfig {
@@ -110,10 +208,115 @@ impl Default for LeagueConfig {
             },
             roster_limits: RosterLimits::default(),
             teams: HashMap::new(),
+            player_pool: PlayerPool::default(),
+            min_bid: default_min_bid(),
+            keepers: KeeperRules::default(),
+            nomination_order: Vec::new(),
+            roster: HashMap::new(),
+            bid_increment: default_bid_increment(),
+            draft_type: DraftType::Auction,
+            points: HashMap::new(),
         }
     }
 }
 
+impl LeagueConfig {
+    /// Whether players are valued by projected fantasy points rather than
+    /// category z-scores: `scoring_type` "points", "h2h_points" and the like.
+    pub fn is_points(&self) -> bool {
+        self.scoring_type.to_lowercase().ends_with("points")
+    }
+
+    /// Whether teams pick in turn rather than bid: players are ranked, not
+    /// priced.
+    pub fn is_snake(&self) -> bool {
+        self.draft_type == DraftType::Snake
+    }
+
+    /// The configured roster, or the default one when none is set.
+    pub fn roster_slots(&self) -> HashMap<String, usize> {
+        if self.roster.is_empty() {
+            default_roster()
+        } else {
+            self.roster.clone()
+        }
+    }
+}
+
+/// The `[league.player_pool]` table: restricts the draftable players to
+/// MLB leagues (`leagues = ["AL"]`) and/or specific MLB teams
+/// (`teams = ["NYY", "BOS"]`). A player is in the pool when his team
+/// matches either list; with both lists empty every player is.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PlayerPool {
+    /// MLB leagues: "AL" and/or "NL".
+    pub leagues: Vec<String>,
+    /// MLB team abbreviations.
+    pub teams: Vec<String>,
+}
+
+impl PlayerPool {
+    pub fn is_unrestricted(&self) -> bool {
+        self.leagues.is_empty() && self.teams.is_empty()
+    }
+}
+
+/// Keeper contracts in an auction keeper league. Each year a contract is
+/// kept its salary goes up by `raise_pct` (rounded up) and then by `raise`
+/// dollars, unless the contract sets its own raise.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct KeeperRules {
+    /// Dollars added to a salary for each year it is kept.
+    pub raise: u32,
+    /// Fraction added to a salary for each year it is kept (0.1 = 10%).
+    pub raise_pct: f64,
+    /// Length in years of the contract signed with each player I draft.
+    /// 0 leaves my picks untracked.
+    pub contract_years: u32,
+    /// Players already under contract.
+    pub contracts: Vec<KeeperContract>,
+    /// Players kept from last season, on their teams' rosters at their
+    /// salaries before the draft starts.
+    pub kept: Vec<Keeper>,
+    /// Whether the `kept` players are entered on ESPN too, so its budgets
+    /// already have their salaries taken off.
+    pub kept_on_espn: bool,
+}
+
+impl KeeperRules {
+    pub fn is_empty(&self) -> bool {
+        *self == KeeperRules::default()
+    }
+}
+
+/// A player under a keeper contract.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct KeeperContract {
+    pub player: String,
+    /// This season's salary.
+    pub salary: u32,
+    /// Seasons left on the contract, counting this one.
+    pub years: u32,
+    /// Overrides the league's dollar raise for this contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raise: Option<u32>,
+    /// Overrides the league's percentage raise for this contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raise_pct: Option<f64>,
+}
+
+/// A player a team kept (`[[league.keepers.kept]]`).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Keeper {
+    pub player: String,
+    /// The keeping team's name or ESPN team ID.
+    pub team: String,
+    /// The salary he carries into the draft.
+    pub price: u32,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CategoriesSection {
     pub categories: Vec<String>,
@@ -136,6 +339,21 @@ impl Default for RosterLimits {
     }
 }
 
+/// The roster used until ESPN's draft board reports the league's own.
+pub fn default_roster() -> HashMap<String, usize> {
+    [
+        ("C", 1), ("1B", 1), ("2B", 1), ("3B", 1), ("SS", 1),
+        ("LF", 1), ("CF", 1), ("RF", 1), ("UTIL", 1),
+        ("SP", 5), ("RP", 6), ("BE", 6), ("IL", 5),
+    ]
+    .into_iter()
+    .map(|(slot, n)| (slot.to_string(), n))
+    .collect()
+}
+
+/// Roster slots that are not filled from the hitter pool.
+const NON_HITTER_SLOTS: &[&str] = &["SP", "RP", "P", "BE", "IL", "NA", "MINORS", "TAXI"];
+
 // ---------------------------------------------------------------------------
 // strategy.toml structs
 // ---------------------------------------------------------------------------
@@ -152,6 +370,22 @@ struct StrategyFile {
     data_paths: DataPaths,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     strategy_overview: Option<String>,
+    #[serde(default)]
+    logging: LoggingConfig,
+    #[serde(default)]
+    notify: NotifyConfig,
+    #[serde(default, skip_serializing_if = "DiscordBotConfig::is_empty")]
+    discord_bot: DiscordBotConfig,
+    #[serde(default)]
+    accessibility: AccessibilityConfig,
+    #[serde(default)]
+    display: DisplayConfig,
+    #[serde(default, skip_serializing_if = "BackupConfig::is_disabled")]
+    backup: BackupConfig,
+    #[serde(default)]
+    flexibility: FlexibilityConfig,
+    #[serde(default)]
+    valuation: ValuationRulesConfig,
 }
 
 impl Default for StrategyFile {
@@ -160,6 +394,7 @@ impl Default for StrategyFile {
         Self {
             budget: BudgetSection {
                 hitting_budget_fraction: strategy.hitting_budget_fraction,
+                adaptive_split: strategy.adaptive_budget_split,
             },
             category_weights: strategy.weights,
             pool: strategy.pool,
@@ -167,6 +402,14 @@ impl Default for StrategyFile {
             websocket: WebsocketSection { port: 9001 },
             data_paths: DataPaths::default(),
             strategy_overview: None,
+            logging: LoggingConfig::default(),
+            notify: NotifyConfig::default(),
+            discord_bot: DiscordBotConfig::default(),
+            accessibility: AccessibilityConfig::default(),
+            display: DisplayConfig::default(),
+            backup: BackupConfig::default(),
+            flexibility: strategy.flexibility,
+            valuation: strategy.valuation,
         }
     }
 }
@@ -174,6 +417,8 @@ impl Default for StrategyFile {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 struct BudgetSection {
     hitting_budget_fraction: f64,
+    #[serde(default)]
+    adaptive_split: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -185,22 +430,30 @@ struct WebsocketSection {
 #[derive(Debug, Clone)]
 pub struct StrategyConfig {
     pub hitting_budget_fraction: f64,
+    /// Learn the hitter/pitcher split from the room's spending during the
+    /// draft instead of holding `hitting_budget_fraction` fixed.
+    pub adaptive_budget_split: bool,
     pub weights: CategoryWeights,
     pub pool: PoolConfig,
     pub llm: LlmConfig,
     /// Prose overview of the user's draft strategy, generated by the LLM
     /// during onboarding. Included in draft-time LLM prompts for context.
     pub strategy_overview: Option<String>,
+    pub flexibility: FlexibilityConfig,
+    pub valuation: ValuationRulesConfig,
 }
 
 impl Default for StrategyConfig {
     fn default() -> Self {
         Self {
             hitting_budget_fraction: 0.65,
+            adaptive_budget_split: false,
             weights: CategoryWeights::default(),
             pool: PoolConfig::default(),
             llm: LlmConfig::default(),
             strategy_overview: None,
+            flexibility: FlexibilityConfig::default(),
+            valuation: ValuationRulesConfig::default(),
         }
     }
 }
@@ -244,7 +497,7 @@ impl Default for CategoryWeights {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct PoolConfig {
     pub min_pa: usize,
     pub min_ip_sp: f64,
@@ -254,19 +507,149 @@ pub struct PoolConfig {
     pub rp_pool_size: usize,
 }
 
+/// Default pool depth per team; a 10-team league gets pools of 150
+/// hitters, 70 SP and 80 RP.
+const HITTER_POOL_PER_TEAM: usize = 15;
+const SP_POOL_PER_TEAM: usize = 7;
+const RP_POOL_PER_TEAM: usize = 8;
+
 impl Default for PoolConfig {
     fn default() -> Self {
+        Self::for_league(10)
+    }
+}
+
+impl PoolConfig {
+    /// Default pools sized for a `num_teams` league.
+    pub fn for_league(num_teams: usize) -> Self {
         Self {
             min_pa: 200,
             min_ip_sp: 50.0,
             min_g_rp: 20,
-            hitter_pool_size: 150,
-            sp_pool_size: 70,
-            rp_pool_size: 80,
+            hitter_pool_size: HITTER_POOL_PER_TEAM * num_teams,
+            sp_pool_size: SP_POOL_PER_TEAM * num_teams,
+            rp_pool_size: RP_POOL_PER_TEAM * num_teams,
+        }
+    }
+
+    /// These pools, with any that cannot hold the players a `num_teams`
+    /// league starts with `roster` grown to those starters plus one
+    /// replacement-level player per team.
+    pub fn covering(&self, roster: &HashMap<String, usize>, num_teams: usize) -> Self {
+        let demand = PoolDemand::new(roster, num_teams);
+        let cover = |size: usize, starters: usize| size.max(starters + num_teams);
+        Self {
+            hitter_pool_size: cover(self.hitter_pool_size, demand.hitters),
+            sp_pool_size: cover(self.sp_pool_size, demand.sp),
+            rp_pool_size: cover(self.rp_pool_size, demand.rp),
+            ..self.clone()
+        }
+    }
+}
+
+/// How many hitters, SP and RP a league starts: every team's slots from
+/// each pool.
+struct PoolDemand {
+    hitters: usize,
+    sp: usize,
+    rp: usize,
+}
+
+impl PoolDemand {
+    fn new(roster: &HashMap<String, usize>, num_teams: usize) -> Self {
+        let starters = |slot: &str| roster.get(slot).copied().unwrap_or(0) * num_teams;
+        let hitters = roster
+            .iter()
+            .filter(|(slot, _)| !NON_HITTER_SLOTS.contains(&slot.as_str()))
+            .map(|(_, n)| n * num_teams)
+            .sum();
+        Self { hitters, sp: starters("SP"), rp: starters("RP") }
+    }
+}
+
+/// The `[flexibility]` section of strategy.toml: the premium paid for
+/// multi-position eligibility during auction conversion.
+///
+/// Percentages are fractions of a player's surplus value (dollars above the
+/// $1 minimum), applied per eligible starting slot beyond the first.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FlexibilityConfig {
+    pub enabled: bool,
+    /// Premium per additional infield/outfield slot.
+    pub extra_slot_pct: f64,
+    /// Premium per additional slot for catcher-eligible players, whose
+    /// flexibility is rarer and frees up the thinnest position.
+    pub catcher_extra_slot_pct: f64,
+    /// Upper bound on the total premium.
+    pub max_pct: f64,
+}
+
+impl Default for FlexibilityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            extra_slot_pct: 0.03,
+            catcher_extra_slot_pct: 0.05,
+            max_pct: 0.12,
+        }
+    }
+}
+
+/// The `[valuation]` section of strategy.toml: floor, cap, and top-end
+/// shaping rules applied when VOR is converted to auction dollars.
+///
+/// Linear VOR-to-dollars overprices the very top of the pool in shallow
+/// leagues; the compression curve and cap pull those values back and hand
+/// the removed dollars to the rest of the pool, so the total is unchanged.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ValuationRulesConfig {
+    /// Value of a replacement-level player (the minimum bid).
+    pub min_value: f64,
+    /// No player is valued above this fraction of the salary cap.
+    /// `0.0` disables the cap.
+    pub max_cap_share: f64,
+    pub compression: CompressionCurve,
+    /// Surplus above this fraction of the salary cap is compressed.
+    pub compression_knee: f64,
+    /// Exponent applied to surplus above the knee (below 1.0 compresses).
+    pub compression_exponent: f64,
+    /// Weight of the rate before each pick in the inflation rate values
+    /// are adjusted by, an exponentially weighted average over the picks.
+    /// `0.0` follows the raw rate.
+    pub inflation_smoothing: f64,
+    /// Most surplus (price over value, either way) a pick counts for in
+    /// the inflation rate, so a few absurd buys can't swing it. `0.0`
+    /// counts every price in full.
+    pub inflation_outlier_cap: f64,
+}
+
+impl Default for ValuationRulesConfig {
+    fn default() -> Self {
+        Self {
+            min_value: 1.0,
+            max_cap_share: 0.0,
+            compression: CompressionCurve::None,
+            compression_knee: 0.15,
+            compression_exponent: 0.75,
+            inflation_smoothing: 0.0,
+            inflation_outlier_cap: 0.0,
         }
     }
 }
 
+/// How top-end surplus values are shaped during auction conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionCurve {
+    /// Dollars stay linear in VOR.
+    None,
+    /// "Superstar compression": surplus above the knee `k` becomes
+    /// `k * (surplus / k) ^ exponent`.
+    Power,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LlmConfig {
     /// Which LLM backend to use.  Defaults to `anthropic` for backwards
@@ -278,6 +661,69 @@ pub struct LlmConfig {
     pub planning_max_tokens: u32,
     pub analysis_trigger: String,
     pub prefire_planning: bool,
+    /// `terse` asks the nomination analysis for a few one-line bullets and
+    /// trims the reply to them; `prose` leaves it in full.
+    #[serde(default)]
+    pub analysis_verbosity: AnalysisVerbosity,
+    /// Language the nomination analysis is written in.
+    #[serde(default = "default_analysis_language")]
+    pub analysis_language: String,
+    /// Open the nomination analysis with a one-line `TL;DR:` verdict.
+    #[serde(default)]
+    pub analysis_tldr: bool,
+    /// When `prefire_planning` starts a plan between picks.
+    #[serde(default)]
+    pub prefire: PrefireConfig,
+}
+
+/// How long the nomination analysis runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnalysisVerbosity {
+    /// Verdict, bid range, fit and strategy in full.
+    #[default]
+    Prose,
+    /// A handful of short bullets, readable in the seconds a bid allows.
+    Terse,
+}
+
+/// How often plans are prefired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrefireMode {
+    /// Only when the draft has changed enough for the last plan to be stale,
+    /// or my turn to nominate is near.
+    #[default]
+    Adaptive,
+    /// After every pick.
+    Always,
+}
+
+/// The `[llm.prefire]` section: when a nomination plan is prefired after a
+/// pick. Early in the draft most picks change little for my plan, so by
+/// default a new plan waits for my roster or budget to change, for enough of
+/// the top of the available pool to go, or for my turn to come close.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PrefireConfig {
+    pub mode: PrefireMode,
+    /// Always prefire when I nominate within this many nominations.
+    pub turn_window: usize,
+    /// How many of the most valuable available players are watched.
+    pub top_n: usize,
+    /// How many of them must be drafted since the last plan to prefire.
+    pub top_changes: usize,
+}
+
+impl Default for PrefireConfig {
+    fn default() -> Self {
+        Self {
+            mode: PrefireMode::Adaptive,
+            turn_window: 2,
+            top_n: 10,
+            top_changes: 3,
+        }
+    }
 }
 
 impl Default for LlmConfig {
@@ -289,6 +735,10 @@ impl Default for LlmConfig {
             planning_max_tokens: 2048,
             analysis_trigger: "nomination".to_string(),
             prefire_planning: true,
+            analysis_verbosity: AnalysisVerbosity::Prose,
+            analysis_language: default_analysis_language(),
+            analysis_tldr: false,
+            prefire: PrefireConfig::default(),
         }
     }
 }
@@ -297,11 +747,34 @@ fn default_llm_provider() -> LlmProvider {
     LlmProvider::Anthropic
 }
 
+fn default_analysis_language() -> String {
+    "English".to_string()
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[derive(Default)]
 pub struct DataPaths {
     pub hitters: Option<String>,
     pub pitchers: Option<String>,
+    /// CSV of multipliers (park factors, manual haircuts) applied to the
+    /// projections before valuation.
+    pub adjustments: Option<String>,
+    /// CSV of my draft prep: my values, tiers, targets, avoids and notes.
+    pub prep: Option<String>,
+    /// CSV of the players I mean to nominate first, in order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nomination_queue: Option<String>,
+    /// Rhai scripts that adjust player values after each valuation, run in
+    /// order. Needs a build with the `scripting` feature.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scripts: Vec<String>,
+    /// Database file. `None` uses `<app_data_dir>/draft-assistant.db`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+    /// Directory for the valuations cache. `None` uses the platform cache
+    /// directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_dir: Option<String>,
 }
 
 
@@ -310,6 +783,228 @@ impl DataPaths {
     pub fn is_empty(&self) -> bool {
         self.hitters.is_none() && self.pitchers.is_none()
     }
+
+    /// Where the database lives: `database`, or the app data directory.
+    pub fn database_path(&self) -> PathBuf {
+        match &self.database {
+            Some(path) => PathBuf::from(path),
+            None => crate::app_dirs::db_path(),
+        }
+    }
+
+    /// Where the valuations cache lives: in `cache_dir`, or the app cache
+    /// directory.
+    pub fn valuation_cache_path(&self) -> PathBuf {
+        match &self.cache_dir {
+            Some(dir) => PathBuf::from(dir).join(crate::app_dirs::VALUATION_CACHE_FILE),
+            None => crate::app_dirs::valuation_cache_path(),
+        }
+    }
+}
+
+/// Output format for log lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable single-line records.
+    #[default]
+    Text,
+    /// One JSON object per line (for `jq` and log shippers).
+    Json,
+}
+
+/// The `[logging]` section of strategy.toml.
+///
+/// Every field has a default so existing strategy.toml files without the
+/// section keep working.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+    /// Directory for log files. `None` uses `<app_data_dir>/logs`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+    /// Write a new timestamped file per session instead of truncating a
+    /// single fixed-name file on every start.
+    pub per_session: bool,
+    /// Rotate the active file once it exceeds this many megabytes.
+    /// `0` disables size-based rotation.
+    pub max_file_size_mb: u64,
+    /// How many files to retain: session files when `per_session` is set,
+    /// and rotated backups of each file.
+    pub max_files: usize,
+    pub format: LogFormat,
+    /// Log every decision the state diff makes (which picks were new, why
+    /// the nomination changed) with its inputs, and show the latest in the
+    /// TUI, for debugging the extension's updates.
+    pub explain_diffs: bool,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            dir: None,
+            per_session: true,
+            max_file_size_mb: 10,
+            max_files: 10,
+            format: LogFormat::Text,
+            explain_diffs: false,
+        }
+    }
+}
+
+/// Payload shape of the draft recap webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookKind {
+    Discord,
+    Slack,
+}
+
+/// The `[notify]` section of strategy.toml: where the draft recap goes once
+/// every roster slot is filled. Nothing is sent unless configured; the
+/// webhook URL itself lives in credentials.toml as `recap_webhook_url`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct NotifyConfig {
+    /// Post the recap to `recap_webhook_url` in this shape.
+    pub webhook: Option<WebhookKind>,
+    /// Write the recap as an email-ready HTML file to the log directory.
+    pub html: bool,
+}
+
+impl NotifyConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.webhook.is_some() || self.html
+    }
+}
+
+/// The `[discord_bot]` section of strategy.toml. The bot (built with the
+/// `discord-bot` feature) runs when credentials.toml has a
+/// `discord_bot_token`, and only answers direct messages from these users.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DiscordBotConfig {
+    /// Discord user IDs allowed to query the draft.
+    pub allowed_users: Vec<String>,
+}
+
+impl DiscordBotConfig {
+    pub fn is_empty(&self) -> bool {
+        self.allowed_users.is_empty()
+    }
+}
+
+/// The `[accessibility]` section of strategy.toml.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AccessibilityConfig {
+    /// Also write key draft updates (nominations, verdicts, max bid, picks)
+    /// as plain text lines, one per update, for screen readers and
+    /// `tail -f`.
+    pub plain_output: bool,
+    /// File for the plain output. `None` writes `draft-feed.txt` to the log
+    /// directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plain_output_path: Option<String>,
+}
+
+/// The `[display]` section of strategy.toml.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    /// Show budgets and player values as a percentage of the salary cap
+    /// instead of dollars, so they read the same whatever the league's cap.
+    pub percent_of_cap: bool,
+    /// URL template for a player's FanGraphs page, opened from the player
+    /// detail. `{name}` and `{team}` are replaced by the player's name and
+    /// MLB team, URL-encoded.
+    pub fangraphs_url: String,
+    /// URL template for a player's Baseball Savant page, like
+    /// `fangraphs_url`.
+    pub savant_url: String,
+    /// Toast each completed pick with its price against the model.
+    pub pick_toasts: bool,
+    /// Seconds without a new pick after which snoozed recalculation (`z`)
+    /// resumes by itself. 0 waits for `z` again.
+    pub recalc_snooze_secs: u64,
+    /// Extra stat columns computed from projection fields, each defined as
+    /// `name = expression`, e.g. `"K/9 = k * 9 / ip"`. Shown in the
+    /// Available table and the player detail.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub custom_stats: Vec<String>,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        // DuckDuckGo's `\` jumps to the first result, which lands on the
+        // player's page without an ID mapping.
+        Self {
+            percent_of_cap: false,
+            fangraphs_url: "https://duckduckgo.com/?q=%5C{name}+site%3Afangraphs.com%2Fplayers".into(),
+            savant_url: "https://duckduckgo.com/?q=%5C{name}+site%3Abaseballsavant.mlb.com%2Fsavant-player".into(),
+            pick_toasts: true,
+            recalc_snooze_secs: 10,
+            custom_stats: Vec::new(),
+        }
+    }
+}
+
+/// An external stats site with a page per player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsSite {
+    FanGraphs,
+    Savant,
+}
+
+impl StatsSite {
+    pub fn label(self) -> &'static str {
+        match self {
+            StatsSite::FanGraphs => "FanGraphs",
+            StatsSite::Savant => "Baseball Savant",
+        }
+    }
+}
+
+impl DisplayConfig {
+    /// The URL template for `site`.
+    pub fn stats_url(&self, site: StatsSite) -> &str {
+        match site {
+            StatsSite::FanGraphs => &self.fangraphs_url,
+            StatsSite::Savant => &self.savant_url,
+        }
+    }
+}
+
+/// The `[backup]` section of strategy.toml: periodic copies of the draft
+/// database to a second location (e.g. a Dropbox folder). Backups are off
+/// until `dir` is set.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BackupConfig {
+    /// Directory for the backup files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+    /// Minutes between backups. A backup is skipped when no pick has been
+    /// made since the last one.
+    pub interval_minutes: u64,
+    /// How many backup files to retain; older ones are deleted.
+    pub keep: usize,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            dir: None,
+            interval_minutes: 5,
+            keep: 12,
+        }
+    }
+}
+
+impl BackupConfig {
+    pub fn is_disabled(&self) -> bool {
+        self.dir.is_none()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -321,6 +1016,12 @@ pub struct CredentialsConfig {
     pub anthropic_api_key: Option<String>,
     pub google_api_key: Option<String>,
     pub openai_api_key: Option<String>,
+    /// Discord or Slack incoming-webhook URL for the draft recap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recap_webhook_url: Option<String>,
+    /// Token of the Discord bot that answers draft queries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discord_bot_token: Option<String>,
 }
 
 impl std::fmt::Debug for CredentialsConfig {
@@ -329,6 +1030,8 @@ impl std::fmt::Debug for CredentialsConfig {
             .field("anthropic_api_key", &self.anthropic_api_key.as_ref().map(|_| "[REDACTED]"))
             .field("google_api_key", &self.google_api_key.as_ref().map(|_| "[REDACTED]"))
             .field("openai_api_key", &self.openai_api_key.as_ref().map(|_| "[REDACTED]"))
+            .field("recap_webhook_url", &self.recap_webhook_url.as_ref().map(|_| "[REDACTED]"))
+            .field("discord_bot_token", &self.discord_bot_token.as_ref().map(|_| "[REDACTED]"))
             .finish()
     }
 }
@@ -367,14 +1070,23 @@ pub(crate) fn load_config_from(base_dir: &Path) -> Result<Config, ConfigError> {
 
     let strategy = StrategyConfig {
         hitting_budget_fraction: strategy_file.budget.hitting_budget_fraction,
+        adaptive_budget_split: strategy_file.budget.adaptive_split,
         weights: strategy_file.category_weights,
         pool: strategy_file.pool,
         llm: strategy_file.llm,
         strategy_overview: strategy_file.strategy_overview,
+        flexibility: strategy_file.flexibility,
+        valuation: strategy_file.valuation,
     };
 
     let ws_port = strategy_file.websocket.port;
     let data_paths = strategy_file.data_paths;
+    let logging = strategy_file.logging;
+    let notify = strategy_file.notify;
+    let discord_bot = strategy_file.discord_bot;
+    let accessibility = strategy_file.accessibility;
+    let display = strategy_file.display;
+    let backup = strategy_file.backup;
 
     // --- credentials.toml (optional) ---
     let credentials_path = config_dir.join("credentials.toml");
@@ -394,6 +1106,12 @@ pub(crate) fn load_config_from(base_dir: &Path) -> Result<Config, ConfigError> {
         credentials,
         ws_port,
         data_paths,
+        logging,
+        notify,
+        discord_bot,
+        accessibility,
+        display,
+        backup,
     };
 
     validate(&config)?;
@@ -515,35 +1233,130 @@ fn read_file(path: &Path) -> Result<String, ConfigError> {
 // Validation
 // ---------------------------------------------------------------------------
 
-fn validate(config: &Config) -> Result<(), ConfigError> {
+/// Check every setting and the ones that must agree with each other,
+/// collecting every problem rather than stopping at the first, so a broken
+/// config can be fixed in one pass.
+pub fn validate(config: &Config) -> Result<(), ConfigError> {
+    let mut problems = Vec::new();
+
     // League validations
     if config.league.num_teams == 0 {
-        return Err(ConfigError::ValidationError {
+        problems.push(ConfigProblem {
             field: "league.num_teams".into(),
             message: "must be greater than 0".into(),
         });
     }
 
     if config.league.salary_cap == 0 {
-        return Err(ConfigError::ValidationError {
+        problems.push(ConfigProblem {
             field: "league.salary_cap".into(),
             message: "must be greater than 0".into(),
         });
     }
 
+    if config.league.min_bid > 1 {
+        problems.push(ConfigProblem {
+            field: "league.min_bid".into(),
+            message: format!("must be 0 or 1, got {}", config.league.min_bid),
+        });
+    }
+
+    if config.league.bid_increment == 0 {
+        problems.push(ConfigProblem {
+            field: "league.bid_increment".into(),
+            message: "must be at least 1".into(),
+        });
+    }
+
+    for league in &config.league.player_pool.leagues {
+        if !matches!(league.to_uppercase().as_str(), "AL" | "NL") {
+            problems.push(ConfigProblem {
+                field: "league.player_pool.leagues".into(),
+                message: format!("must be \"AL\" or \"NL\", got \"{league}\""),
+            });
+        }
+    }
+
+    if config.league.is_points() && config.league.points.is_empty() {
+        problems.push(ConfigProblem {
+            field: "league.points".into(),
+            message: format!("must give the points per stat for scoring_type \"{}\"", config.league.scoring_type),
+        });
+    }
+    let scored: Vec<&str> = BATTING_POINTS.iter().chain(PITCHING_POINTS).copied().collect();
+    let mut unknown: Vec<&String> =
+        config.league.points.keys().filter(|stat| !scored.iter().any(|s| s.eq_ignore_ascii_case(stat))).collect();
+    unknown.sort();
+    for stat in unknown {
+        problems.push(ConfigProblem {
+            field: "league.points".into(),
+            message: format!("unknown stat \"{stat}\", expected one of {}", scored.join(", ")),
+        });
+    }
+    if let Some((stat, value)) = config.league.points.iter().find(|(_, v)| !v.is_finite()) {
+        problems.push(ConfigProblem {
+            field: "league.points".into(),
+            message: format!("{stat} must be a number, got {value}"),
+        });
+    }
+
+    let keepers = &config.league.keepers;
+    let pcts = std::iter::once(keepers.raise_pct).chain(keepers.contracts.iter().filter_map(|c| c.raise_pct));
+    for pct in pcts {
+        if !(pct >= 0.0 && pct.is_finite()) {
+            problems.push(ConfigProblem {
+                field: "league.keepers.raise_pct".into(),
+                message: format!("must be 0 or more, got {pct}"),
+            });
+        }
+    }
+    if let Some(c) = keepers.contracts.iter().find(|c| c.years == 0) {
+        problems.push(ConfigProblem {
+            field: "league.keepers.contracts".into(),
+            message: format!("contract for {} must have at least 1 year left", c.player),
+        });
+    }
+    if let Some(k) = keepers.kept.iter().find(|k| k.price > config.league.salary_cap) {
+        problems.push(ConfigProblem {
+            field: "league.keepers.kept".into(),
+            message: format!("{} is kept for ${}, over the ${} salary cap", k.player, k.price, config.league.salary_cap),
+        });
+    }
+
     // Strategy validations
     let frac = config.strategy.hitting_budget_fraction;
-    if !(0.0..=1.0).contains(&frac) {
-        return Err(ConfigError::ValidationError {
+    if !(frac > 0.0 && frac < 1.0) {
+        problems.push(ConfigProblem {
             field: "strategy.hitting_budget_fraction".into(),
-            message: format!("must be between 0.0 and 1.0 inclusive, got {frac}"),
+            message: format!(
+                "must be between 0.0 and 1.0 exclusive (both hitters and pitchers need a budget), got {frac}"
+            ),
         });
     }
 
+    // Every scored category needs a weight; a missing one silently counts
+    // for nothing.
+    let categories = config
+        .league
+        .batting_categories
+        .categories
+        .iter()
+        .chain(&config.league.pitching_categories.categories);
+    for category in categories {
+        if config.strategy.weights.get(category).is_none() {
+            problems.push(ConfigProblem {
+                field: format!("weights.{category}"),
+                message: format!(
+                    "{category} is a league category but has no weight; add `{category} = 1.0` under [weights] in strategy.toml"
+                ),
+            });
+        }
+    }
+
     // Category weights must all be positive
     for (name, val) in config.strategy.weights.iter() {
         if val <= 0.0 {
-            return Err(ConfigError::ValidationError {
+            problems.push(ConfigProblem {
                 field: format!("weights.{name}"),
                 message: format!("must be > 0, got {val}"),
             });
@@ -561,21 +1374,189 @@ fn validate(config: &Config) -> Result<(), ConfigError> {
     ];
     for (name, val) in pool_fields {
         if *val == 0 {
-            return Err(ConfigError::ValidationError {
+            problems.push(ConfigProblem {
                 field: name.to_string(),
                 message: "must be > 0".into(),
             });
         }
     }
 
+    if !config.league.roster.is_empty() && config.league.roster.values().all(|&n| n == 0) {
+        problems.push(ConfigProblem {
+            field: "league.roster".into(),
+            message: "needs at least one slot".into(),
+        });
+    }
+    problems.extend(pool_shortfalls(&config.league.roster_slots(), config.league.num_teams, pool));
+
     if pool.min_ip_sp <= 0.0 {
-        return Err(ConfigError::ValidationError {
+        problems.push(ConfigProblem {
             field: "pool.min_ip_sp".into(),
             message: format!("must be > 0, got {}", pool.min_ip_sp),
         });
     }
 
-    Ok(())
+    let prefire = &config.strategy.llm.prefire;
+    if prefire.mode == PrefireMode::Adaptive && !(1..=prefire.top_n).contains(&prefire.top_changes) {
+        problems.push(ConfigProblem {
+            field: "llm.prefire.top_changes".into(),
+            message: format!(
+                "must be between 1 and top_n ({}), got {}",
+                prefire.top_n, prefire.top_changes
+            ),
+        });
+    }
+
+    let flex = &config.strategy.flexibility;
+    for (name, val) in [
+        ("flexibility.extra_slot_pct", flex.extra_slot_pct),
+        ("flexibility.catcher_extra_slot_pct", flex.catcher_extra_slot_pct),
+        ("flexibility.max_pct", flex.max_pct),
+    ] {
+        if !(0.0..=1.0).contains(&val) {
+            problems.push(ConfigProblem {
+                field: name.into(),
+                message: format!("must be between 0.0 and 1.0 inclusive, got {val}"),
+            });
+        }
+    }
+
+    let rules = &config.strategy.valuation;
+    if rules.min_value < 0.0 {
+        problems.push(ConfigProblem {
+            field: "valuation.min_value".into(),
+            message: format!("must be >= 0, got {}", rules.min_value),
+        });
+    }
+    for (name, val) in [
+        ("valuation.max_cap_share", rules.max_cap_share),
+        ("valuation.compression_knee", rules.compression_knee),
+        ("valuation.compression_exponent", rules.compression_exponent),
+    ] {
+        if !(0.0..=1.0).contains(&val) {
+            problems.push(ConfigProblem {
+                field: name.into(),
+                message: format!("must be between 0.0 and 1.0 inclusive, got {val}"),
+            });
+        }
+    }
+    if rules.compression == CompressionCurve::Power
+        && (rules.compression_knee <= 0.0 || rules.compression_exponent <= 0.0)
+    {
+        problems.push(ConfigProblem {
+            field: "valuation.compression".into(),
+            message: "power compression needs compression_knee and compression_exponent > 0"
+                .into(),
+        });
+    }
+    if rules.max_cap_share > 0.0
+        && rules.max_cap_share * (config.league.salary_cap as f64) < rules.min_value
+    {
+        problems.push(ConfigProblem {
+            field: "valuation.max_cap_share".into(),
+            message: "cap is below valuation.min_value".into(),
+        });
+    }
+    if !(0.0..1.0).contains(&rules.inflation_smoothing) {
+        problems.push(ConfigProblem {
+            field: "valuation.inflation_smoothing".into(),
+            message: format!("must be at least 0.0 and below 1.0, got {}", rules.inflation_smoothing),
+        });
+    }
+    if !(rules.inflation_outlier_cap >= 0.0 && rules.inflation_outlier_cap.is_finite()) {
+        problems.push(ConfigProblem {
+            field: "valuation.inflation_outlier_cap".into(),
+            message: format!("must be 0 or more, got {}", rules.inflation_outlier_cap),
+        });
+    }
+
+    if config.logging.max_files == 0 {
+        problems.push(ConfigProblem {
+            field: "logging.max_files".into(),
+            message: "must be > 0".into(),
+        });
+    }
+
+    if config.notify.webhook.is_some() && config.credentials.recap_webhook_url.is_none() {
+        problems.push(ConfigProblem {
+            field: "notify.webhook".into(),
+            message: "needs recap_webhook_url in credentials.toml".into(),
+        });
+    }
+
+    if config.credentials.discord_bot_token.is_some() && config.discord_bot.is_empty() {
+        problems.push(ConfigProblem {
+            field: "discord_bot.allowed_users".into(),
+            message: "must list at least one user when discord_bot_token is set".into(),
+        });
+    }
+
+    for (name, url) in [
+        ("display.fangraphs_url", &config.display.fangraphs_url),
+        ("display.savant_url", &config.display.savant_url),
+    ] {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            problems.push(ConfigProblem {
+                field: name.into(),
+                message: format!("must be an http(s) URL, got \"{url}\""),
+            });
+        }
+    }
+
+    for definition in &config.display.custom_stats {
+        if let Err(e) = CustomStat::parse(definition) {
+            problems.push(ConfigProblem {
+                field: "display.custom_stats".into(),
+                message: format!("\"{definition}\": {e}"),
+            });
+        }
+    }
+
+    if !config.backup.is_disabled() {
+        for (name, val) in [
+            ("backup.interval_minutes", config.backup.interval_minutes),
+            ("backup.keep", config.backup.keep as u64),
+        ] {
+            if val == 0 {
+                problems.push(ConfigProblem {
+                    field: name.into(),
+                    message: "must be > 0".into(),
+                });
+            }
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(ConfigError::Invalid(problems))
+    }
+}
+
+/// Pool sizes too small for `roster`: each of the `num_teams` teams starts
+/// its slots from the hitter, SP and RP pools, and replacement level sits
+/// just past the players they start, so every pool must hold at least that
+/// many players.
+pub fn pool_shortfalls(
+    roster: &HashMap<String, usize>,
+    num_teams: usize,
+    pool: &PoolConfig,
+) -> Vec<ConfigProblem> {
+    let demand = PoolDemand::new(roster, num_teams);
+    [
+        ("pool.hitter_pool_size", pool.hitter_pool_size, demand.hitters, "hitters"),
+        ("pool.sp_pool_size", pool.sp_pool_size, demand.sp, "SP"),
+        ("pool.rp_pool_size", pool.rp_pool_size, demand.rp, "RP"),
+    ]
+    .into_iter()
+    .filter(|&(_, size, demand, _)| size > 0 && size < demand)
+    .map(|(field, size, demand, label)| ConfigProblem {
+        field: field.into(),
+        message: format!(
+            "{size} is smaller than the {demand} {label} the league starts ({num_teams} teams); raise it to at least {demand}"
+        ),
+    })
+    .collect()
 }
 
 // ---------------------------------------------------------------------------
@@ -636,6 +1617,7 @@ mod tests {
 
         // Strategy assertions
         assert!((config.strategy.hitting_budget_fraction - 0.65).abs() < f64::EPSILON);
+        assert!(!config.strategy.adaptive_budget_split);
         assert!((config.strategy.weights.get("SV").unwrap() - 0.7).abs() < f64::EPSILON);
         assert!((config.strategy.weights.get("R").unwrap() - 1.0).abs() < f64::EPSILON);
         assert_eq!(config.strategy.pool.hitter_pool_size, 150);
@@ -647,6 +1629,9 @@ mod tests {
         assert_eq!(config.strategy.llm.planning_max_tokens, 2048);
         assert_eq!(config.strategy.llm.analysis_trigger, "nomination");
         assert!(config.strategy.llm.prefire_planning);
+        assert_eq!(config.strategy.llm.analysis_verbosity, AnalysisVerbosity::Prose);
+        assert_eq!(config.strategy.llm.analysis_language, "English");
+        assert!(!config.strategy.llm.analysis_tldr);
 
         // Infrastructure assertions
         assert_eq!(config.ws_port, 9001);
@@ -698,16 +1683,140 @@ mod tests {
     }
 
     #[test]
-    fn rejects_num_teams_zero() {
-        let tmp = std::env::temp_dir().join("config_test_num_teams_zero");
+    fn keeper_contracts_parse_and_validate() {
+        let tmp = std::env::temp_dir().join("config_test_keepers");
         let config_dir = tmp.join("config");
         let _ = fs::remove_dir_all(&tmp);
         fs::create_dir_all(&config_dir).unwrap();
+        write_default_strategy_toml(&config_dir);
+        let league_toml = toml::to_string_pretty(&LeagueFile {
+            league: LeagueConfig::default(),
+        })
+        .unwrap();
+        assert!(!league_toml.contains("keepers"));
+
+        let keepers = "\n[league.keepers]\nraise = 5\n\n\
+                       [[league.keepers.contracts]]\nplayer = \"Juan Soto\"\nsalary = 30\nyears = 3\nraise_pct = 0.1\n\n\
+                       [[league.keepers.kept]]\nplayer = \"Juan Soto\"\nteam = \"Team 3\"\nprice = 30\n";
+        fs::write(config_dir.join("league.toml"), format!("{league_toml}{keepers}")).unwrap();
+        let rules = load_config_from(&tmp).unwrap().league.keepers;
+        assert_eq!(rules.raise, 5);
+        assert_eq!(rules.contract_years, 0);
+        assert_eq!(rules.contracts[0].player, "Juan Soto");
+        assert_eq!(rules.contracts[0].raise, None);
+        assert_eq!(rules.contracts[0].raise_pct, Some(0.1));
+        assert_eq!(rules.kept, [Keeper { player: "Juan Soto".into(), team: "Team 3".into(), price: 30 }]);
+        assert!(!rules.kept_on_espn);
+
+        let expired = keepers.replace("years = 3", "years = 0");
+        fs::write(config_dir.join("league.toml"), format!("{league_toml}{expired}")).unwrap();
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => assert_eq!(problems[0].field, "league.keepers.contracts"),
+            other => panic!("expected Invalid, got: {other}"),
+        }
 
-        // Write a league.toml with num_teams = 0
-        let league_toml = r#"
-[league]
-name = "Test"
+        let over_cap = keepers.replace("price = 30", "price = 300");
+        fs::write(config_dir.join("league.toml"), format!("{league_toml}{over_cap}")).unwrap();
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => assert_eq!(problems[0].field, "league.keepers.kept"),
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn min_bid_may_be_zero_or_one() {
+        let tmp = std::env::temp_dir().join("config_test_min_bid");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+        write_default_strategy_toml(&config_dir);
+        let league_toml = toml::to_string_pretty(&LeagueFile {
+            league: LeagueConfig::default(),
+        })
+        .unwrap();
+        assert!(league_toml.contains("min_bid = 1"));
+
+        fs::write(config_dir.join("league.toml"), league_toml.replace("min_bid = 1", "min_bid = 0")).unwrap();
+        assert_eq!(load_config_from(&tmp).unwrap().league.min_bid, 0);
+
+        fs::write(config_dir.join("league.toml"), league_toml.replace("min_bid = 1", "min_bid = 2")).unwrap();
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => assert_eq!(problems[0].field, "league.min_bid"),
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        assert!(league_toml.contains("bid_increment = 1"));
+        fs::write(config_dir.join("league.toml"), league_toml.replace("bid_increment = 1", "bid_increment = 0")).unwrap();
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => assert_eq!(problems[0].field, "league.bid_increment"),
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn points_leagues_need_point_values() {
+        let tmp = std::env::temp_dir().join("config_test_points");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+        write_default_strategy_toml(&config_dir);
+        let league_toml = toml::to_string_pretty(&LeagueFile {
+            league: LeagueConfig::default(),
+        })
+        .unwrap();
+        let points_toml = league_toml.replace("h2h_most_categories", "h2h_points");
+
+        fs::write(config_dir.join("league.toml"), &points_toml).unwrap();
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => assert_eq!(problems[0].field, "league.points"),
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        fs::write(
+            config_dir.join("league.toml"),
+            format!("{points_toml}\n[league.points]\nHR = 4.0\nER = -2.0\nXBH = 1.0\n"),
+        )
+        .unwrap();
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => {
+                let messages: Vec<&str> = problems.iter().map(|p| p.message.as_str()).collect();
+                assert_eq!(messages.len(), 2);
+                assert!(messages[0].starts_with("unknown stat \"ER\", expected one of PA, AB, H, HR,"));
+                assert!(messages[0].ends_with("P_ER, P_BBH, P_G, P_GS"));
+                assert!(messages[1].starts_with("unknown stat \"XBH\""));
+            }
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        fs::write(
+            config_dir.join("league.toml"),
+            format!("{points_toml}\n[league.points]\nHR = 4.0\nbb = 1.0\nP_ER = -2.0\n"),
+        )
+        .unwrap();
+        let league = load_config_from(&tmp).unwrap().league;
+        assert!(league.is_points());
+        assert_eq!(league.points["HR"], 4.0);
+        assert_eq!(league.points["P_ER"], -2.0);
+        assert!(!LeagueConfig::default().is_points());
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn rejects_num_teams_zero() {
+        let tmp = std::env::temp_dir().join("config_test_num_teams_zero");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        // Write a league.toml with num_teams = 0
+        let league_toml = r#"
+[league]
+name = "Test"
 platform = "espn"
 num_teams = 0
 scoring_type = "h2h"
@@ -732,10 +1841,10 @@ gs_per_week = 7
 
         let err = load_config_from(&tmp).unwrap_err();
         match &err {
-            ConfigError::ValidationError { field, .. } => {
-                assert_eq!(field, "league.num_teams");
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "league.num_teams");
             }
-            other => panic!("expected ValidationError, got: {other}"),
+            other => panic!("expected Invalid, got: {other}"),
         }
 
         let _ = fs::remove_dir_all(&tmp);
@@ -775,15 +1884,98 @@ gs_per_week = 7
 
         let err = load_config_from(&tmp).unwrap_err();
         match &err {
-            ConfigError::ValidationError { field, .. } => {
-                assert_eq!(field, "league.salary_cap");
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "league.salary_cap");
             }
-            other => panic!("expected ValidationError, got: {other}"),
+            other => panic!("expected Invalid, got: {other}"),
         }
 
         let _ = fs::remove_dir_all(&tmp);
     }
 
+    const POOL_LEAGUE_TOML: &str = r#"
+[league]
+name = "Test"
+platform = "espn"
+num_teams = 10
+scoring_type = "h2h"
+salary_cap = 260
+
+[league.batting_categories]
+categories = ["R"]
+
+[league.pitching_categories]
+categories = ["K"]
+
+[league.roster_limits]
+max_sp = 7
+max_rp = 7
+gs_per_week = 7
+
+[league.player_pool]
+"#;
+
+    #[test]
+    fn loads_player_pool_restriction() {
+        let tmp = std::env::temp_dir().join("config_test_player_pool");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        let league_toml = format!("{POOL_LEAGUE_TOML}leagues = [\"AL\"]\nteams = [\"LAD\"]\n");
+        fs::write(config_dir.join("league.toml"), league_toml).unwrap();
+        write_default_strategy_toml(&config_dir);
+
+        let config = load_config_from(&tmp).unwrap();
+        assert_eq!(config.league.player_pool.leagues, vec!["AL"]);
+        assert_eq!(config.league.player_pool.teams, vec!["LAD"]);
+        assert!(!config.league.player_pool.is_unrestricted());
+        assert!(LeagueConfig::default().player_pool.is_unrestricted());
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn rejects_unknown_pool_league() {
+        let tmp = std::env::temp_dir().join("config_test_bad_pool_league");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        let league_toml = format!("{POOL_LEAGUE_TOML}leagues = [\"PCL\"]\n");
+        fs::write(config_dir.join("league.toml"), league_toml).unwrap();
+        write_default_strategy_toml(&config_dir);
+
+        let err = load_config_from(&tmp).unwrap_err();
+        match &err {
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "league.player_pool.leagues");
+            }
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn loads_adaptive_budget_split() {
+        let tmp = std::env::temp_dir().join("config_test_adaptive_split");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+
+        let strategy_text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        let modified = strategy_text.replace("adaptive_split = false", "adaptive_split = true");
+        fs::write(config_dir.join("strategy.toml"), modified).unwrap();
+
+        let config = load_config_from(&tmp).unwrap();
+        assert!(config.strategy.adaptive_budget_split);
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn rejects_hitting_budget_fraction_too_high() {
         let tmp = std::env::temp_dir().join("config_test_budget_high");
@@ -803,15 +1995,79 @@ gs_per_week = 7
 
         let err = load_config_from(&tmp).unwrap_err();
         match &err {
-            ConfigError::ValidationError { field, .. } => {
-                assert_eq!(field, "strategy.hitting_budget_fraction");
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "strategy.hitting_budget_fraction");
             }
-            other => panic!("expected ValidationError, got: {other}"),
+            other => panic!("expected Invalid, got: {other}"),
         }
 
         let _ = fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn reports_every_problem_at_once() {
+        let tmp = std::env::temp_dir().join("config_test_every_problem");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        let mut league = LeagueConfig::default();
+        league.batting_categories.categories.push("OBP".into());
+        let league_text = toml::to_string_pretty(&LeagueFile { league }).unwrap();
+        fs::write(config_dir.join("league.toml"), league_text).unwrap();
+        let strategy_text = toml::to_string_pretty(&StrategyFile::default())
+            .unwrap()
+            .replace("hitting_budget_fraction = 0.65", "hitting_budget_fraction = 1.0")
+            .replace("sp_pool_size = 70", "sp_pool_size = 30");
+        fs::write(config_dir.join("strategy.toml"), strategy_text).unwrap();
+
+        let err = load_config_from(&tmp).unwrap_err();
+        let ConfigError::Invalid(problems) = &err else {
+            panic!("expected Invalid, got: {err}");
+        };
+        let fields: Vec<&str> = problems.iter().map(|p| p.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["strategy.hitting_budget_fraction", "weights.OBP", "pool.sp_pool_size"]
+        );
+        let text = err.to_string();
+        assert!(text.contains("add `OBP = 1.0` under [weights]"), "got: {text}");
+        assert!(text.contains("30 is smaller than the 50 SP the league starts (10 teams)"), "got: {text}");
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn pool_shortfalls_grow_with_the_league() {
+        let pool = PoolConfig::default();
+        assert!(pool_shortfalls(&default_roster(), 12, &pool).is_empty());
+
+        let fields: Vec<String> = pool_shortfalls(&default_roster(), 15, &pool)
+            .into_iter()
+            .map(|p| p.field)
+            .collect();
+        assert_eq!(fields, vec!["pool.sp_pool_size", "pool.rp_pool_size"]);
+    }
+
+    #[test]
+    fn pools_scale_with_the_league() {
+        for teams in [16, 20] {
+            assert!(pool_shortfalls(&default_roster(), teams, &PoolConfig::for_league(teams)).is_empty());
+        }
+
+        // A 10-team league's pools already cover its starters.
+        let pool = PoolConfig::default();
+        assert_eq!(pool.covering(&default_roster(), 10), pool);
+
+        // At 20 teams the league starts 180 hitters, 100 SP and 120 RP.
+        let grown = pool.covering(&default_roster(), 20);
+        assert_eq!(
+            (grown.hitter_pool_size, grown.sp_pool_size, grown.rp_pool_size),
+            (200, 120, 140)
+        );
+        assert!(pool_shortfalls(&default_roster(), 20, &grown).is_empty());
+    }
+
     #[test]
     fn rejects_hitting_budget_fraction_negative() {
         let tmp = std::env::temp_dir().join("config_test_budget_neg");
@@ -830,10 +2086,10 @@ gs_per_week = 7
 
         let err = load_config_from(&tmp).unwrap_err();
         match &err {
-            ConfigError::ValidationError { field, .. } => {
-                assert_eq!(field, "strategy.hitting_budget_fraction");
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "strategy.hitting_budget_fraction");
             }
-            other => panic!("expected ValidationError, got: {other}"),
+            other => panic!("expected Invalid, got: {other}"),
         }
 
         let _ = fs::remove_dir_all(&tmp);
@@ -855,10 +2111,10 @@ gs_per_week = 7
 
         let err = load_config_from(&tmp).unwrap_err();
         match &err {
-            ConfigError::ValidationError { field, .. } => {
-                assert_eq!(field, "weights.SV");
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "weights.SV");
             }
-            other => panic!("expected ValidationError, got: {other}"),
+            other => panic!("expected Invalid, got: {other}"),
         }
 
         let _ = fs::remove_dir_all(&tmp);
@@ -879,15 +2135,186 @@ gs_per_week = 7
 
         let err = load_config_from(&tmp).unwrap_err();
         match &err {
-            ConfigError::ValidationError { field, .. } => {
-                assert_eq!(field, "pool.hitter_pool_size");
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "pool.hitter_pool_size");
             }
-            other => panic!("expected ValidationError, got: {other}"),
+            other => panic!("expected Invalid, got: {other}"),
         }
 
         let _ = fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn notify_webhook_needs_a_url() {
+        let tmp = std::env::temp_dir().join("config_test_notify_webhook");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+        let strategy_file = StrategyFile {
+            notify: NotifyConfig {
+                webhook: Some(WebhookKind::Discord),
+                html: true,
+            },
+            ..StrategyFile::default()
+        };
+        let text = toml::to_string_pretty(&strategy_file).unwrap();
+        assert!(text.contains("webhook = \"discord\""));
+        fs::write(config_dir.join("strategy.toml"), text).unwrap();
+
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => assert_eq!(problems[0].field, "notify.webhook"),
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        fs::write(
+            config_dir.join("credentials.toml"),
+            "recap_webhook_url = \"https://discord.com/api/webhooks/1/abc\"\n",
+        )
+        .unwrap();
+        let config = load_config_from(&tmp).expect("webhook with a url should load");
+        assert_eq!(config.notify.webhook, Some(WebhookKind::Discord));
+        assert!(config.notify.html);
+        assert!(config.credentials.recap_webhook_url.is_some());
+        assert!(!format!("{:?}", config.credentials).contains("discord.com"));
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn discord_bot_token_needs_allowed_users() {
+        let tmp = std::env::temp_dir().join("config_test_discord_bot");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+        let text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        assert!(!text.contains("discord_bot"));
+        fs::write(config_dir.join("strategy.toml"), &text).unwrap();
+        fs::write(config_dir.join("credentials.toml"), "discord_bot_token = \"secret\"\n").unwrap();
+
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "discord_bot.allowed_users")
+            }
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        let text = format!("{text}\n[discord_bot]\nallowed_users = [\"1234\"]\n");
+        fs::write(config_dir.join("strategy.toml"), text).unwrap();
+        let config = load_config_from(&tmp).expect("bot with allowed users should load");
+        assert_eq!(config.discord_bot.allowed_users, vec!["1234"]);
+        assert!(!format!("{:?}", config.credentials).contains("secret"));
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn backup_section_is_optional_and_validated() {
+        let tmp = std::env::temp_dir().join("config_test_backup");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+        let text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        assert!(!text.contains("[backup]"));
+        fs::write(config_dir.join("strategy.toml"), &text).unwrap();
+        assert!(load_config_from(&tmp).unwrap().backup.is_disabled());
+
+        let with_backup = format!("{text}\n[backup]\ndir = \"/tmp/backups\"\nkeep = 0\n");
+        fs::write(config_dir.join("strategy.toml"), with_backup).unwrap();
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => assert_eq!(problems[0].field, "backup.keep"),
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        let with_backup = format!("{text}\n[backup]\ndir = \"/tmp/backups\"\n");
+        fs::write(config_dir.join("strategy.toml"), with_backup).unwrap();
+        let config = load_config_from(&tmp).expect("backup section should load");
+        assert_eq!(config.backup.dir.as_deref(), Some("/tmp/backups"));
+        assert_eq!(config.backup.interval_minutes, 5);
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn stats_links_default_and_must_be_web_urls() {
+        let tmp = std::env::temp_dir().join("config_test_stats_links");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+        let text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        let old_display = text.replace(
+            "[display]",
+            "[display]\nfangraphs_url = \"https://www.fangraphs.com/search?q={name}\"",
+        );
+        let old_display: String = old_display
+            .lines()
+            .filter(|l| !l.starts_with("savant_url") && !l.contains("site%3Afangraphs"))
+            .map(|l| format!("{l}\n"))
+            .collect();
+        fs::write(config_dir.join("strategy.toml"), &old_display).unwrap();
+        let config = load_config_from(&tmp).expect("display without savant_url should load");
+        assert_eq!(config.display.stats_url(StatsSite::FanGraphs), "https://www.fangraphs.com/search?q={name}");
+        assert_eq!(config.display.savant_url, DisplayConfig::default().savant_url);
+
+        let bad = old_display.replace("https://www.fangraphs.com", "file:///etc");
+        fs::write(config_dir.join("strategy.toml"), bad).unwrap();
+        match load_config_from(&tmp).unwrap_err() {
+            ConfigError::Invalid(problems) => assert_eq!(problems[0].field, "display.fangraphs_url"),
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn custom_stats_must_parse() {
+        let mut config = Config {
+            display: DisplayConfig {
+                custom_stats: vec!["K-BB% = (k - bb) / ip".into(), "iso = slg -".into()],
+                ..DisplayConfig::default()
+            },
+            ..Config::default()
+        };
+        match validate(&config).unwrap_err() {
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems.len(), 1);
+                assert_eq!(problems[0].field, "display.custom_stats");
+                assert_eq!(problems[0].message, "\"iso = slg -\": expression ends too soon");
+            }
+            other => panic!("expected Invalid, got: {other}"),
+        }
+        config.display.custom_stats.pop();
+        assert!(validate(&config).is_ok());
+    }
+
+    #[test]
+    fn prefire_section_parses_and_is_checked() {
+        let llm: LlmConfig = toml::from_str(
+            "model = \"m\"\nanalysis_max_tokens = 1\nplanning_max_tokens = 1\n\
+             analysis_trigger = \"nomination\"\nprefire_planning = true\n\
+             [prefire]\nturn_window = 1\ntop_changes = 5\n",
+        )
+        .unwrap();
+        assert_eq!(llm.prefire.mode, PrefireMode::Adaptive);
+        assert_eq!((llm.prefire.turn_window, llm.prefire.top_n, llm.prefire.top_changes), (1, 10, 5));
+
+        let mut config = Config::default();
+        config.strategy.llm.prefire.top_changes = 11;
+        match validate(&config).unwrap_err() {
+            ConfigError::Invalid(problems) => assert_eq!(problems[0].field, "llm.prefire.top_changes"),
+            other => panic!("expected Invalid, got: {other}"),
+        }
+        config.strategy.llm.prefire.mode = PrefireMode::Always;
+        assert!(validate(&config).is_ok());
+    }
+
     #[test]
     fn file_not_found_for_missing_league_toml() {
         let tmp = std::env::temp_dir().join("config_test_missing_league");
@@ -1008,6 +2435,192 @@ gs_per_week = 7
         let _ = fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn strategy_toml_without_logging_section_uses_defaults() {
+        let tmp = std::env::temp_dir().join("config_test_no_logging_section");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+
+        // Strip the [logging] table to mimic a strategy.toml that predates it.
+        let strategy_text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        let without_logging = strategy_text
+            .split("\n[logging]")
+            .next()
+            .unwrap()
+            .to_string();
+        fs::write(config_dir.join("strategy.toml"), without_logging).unwrap();
+
+        let config = load_config_from(&tmp).expect("should load without [logging]");
+        assert!(config.logging.per_session);
+        assert_eq!(config.logging.format, LogFormat::Text);
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn strategy_toml_with_logging_overrides() {
+        let tmp = std::env::temp_dir().join("config_test_logging_override");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+
+        let strategy_file = StrategyFile {
+            logging: LoggingConfig {
+                dir: Some("/var/log/wyncast".into()),
+                per_session: false,
+                max_file_size_mb: 0,
+                max_files: 3,
+                format: LogFormat::Json,
+                explain_diffs: true,
+            },
+            ..StrategyFile::default()
+        };
+        let text = toml::to_string_pretty(&strategy_file).unwrap();
+        assert!(text.contains("format = \"json\""));
+        fs::write(config_dir.join("strategy.toml"), text).unwrap();
+
+        let config = load_config_from(&tmp).expect("should load config with logging");
+        assert_eq!(config.logging.dir.as_deref(), Some("/var/log/wyncast"));
+        assert!(!config.logging.per_session);
+        assert_eq!(config.logging.max_file_size_mb, 0);
+        assert_eq!(config.logging.max_files, 3);
+        assert_eq!(config.logging.format, LogFormat::Json);
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn rejects_zero_log_max_files() {
+        let tmp = std::env::temp_dir().join("config_test_zero_log_files");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+
+        let strategy_text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        let modified = strategy_text.replace("max_files = 10", "max_files = 0");
+        fs::write(config_dir.join("strategy.toml"), modified).unwrap();
+
+        let err = load_config_from(&tmp).unwrap_err();
+        match &err {
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "logging.max_files");
+            }
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn strategy_toml_without_flexibility_section_uses_defaults() {
+        let tmp = std::env::temp_dir().join("config_test_no_flexibility_section");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+
+        // Strip the [flexibility] table to mimic a strategy.toml that predates it.
+        let strategy_text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        let without_flex = strategy_text
+            .split("\n[flexibility]")
+            .next()
+            .unwrap()
+            .to_string();
+        assert!(!without_flex.contains("extra_slot_pct"));
+        fs::write(config_dir.join("strategy.toml"), without_flex).unwrap();
+
+        let config = load_config_from(&tmp).expect("should load without [flexibility]");
+        assert!(config.strategy.flexibility.enabled);
+        assert!((config.strategy.flexibility.extra_slot_pct - 0.03).abs() < f64::EPSILON);
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn rejects_out_of_range_flexibility_pct() {
+        let tmp = std::env::temp_dir().join("config_test_bad_flexibility");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+
+        let strategy_text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        let modified = strategy_text.replace("max_pct = 0.12", "max_pct = 1.5");
+        fs::write(config_dir.join("strategy.toml"), modified).unwrap();
+
+        let err = load_config_from(&tmp).unwrap_err();
+        match &err {
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "flexibility.max_pct");
+            }
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn loads_valuation_rules_section() {
+        let tmp = std::env::temp_dir().join("config_test_valuation_rules");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+
+        let strategy_text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        assert!(strategy_text.contains("[valuation]"));
+        let modified = strategy_text
+            .replace("max_cap_share = 0.0", "max_cap_share = 0.4")
+            .replace("compression = \"none\"", "compression = \"power\"")
+            .replace("inflation_smoothing = 0.0", "inflation_smoothing = 0.7")
+            .replace("inflation_outlier_cap = 0.0", "inflation_outlier_cap = 15.0");
+        fs::write(config_dir.join("strategy.toml"), modified).unwrap();
+
+        let config = load_config_from(&tmp).expect("should load [valuation]");
+        let rules = &config.strategy.valuation;
+        assert!((rules.min_value - 1.0).abs() < f64::EPSILON);
+        assert!((rules.max_cap_share - 0.4).abs() < f64::EPSILON);
+        assert_eq!(rules.compression, CompressionCurve::Power);
+        assert!((rules.inflation_smoothing - 0.7).abs() < f64::EPSILON);
+        assert!((rules.inflation_outlier_cap - 15.0).abs() < f64::EPSILON);
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn rejects_negative_min_value() {
+        let tmp = std::env::temp_dir().join("config_test_bad_min_value");
+        let config_dir = tmp.join("config");
+        let _ = fs::remove_dir_all(&tmp);
+        fs::create_dir_all(&config_dir).unwrap();
+
+        write_default_league_toml(&config_dir);
+
+        let strategy_text = toml::to_string_pretty(&StrategyFile::default()).unwrap();
+        let modified = strategy_text.replace("min_value = 1.0", "min_value = -1.0");
+        fs::write(config_dir.join("strategy.toml"), modified).unwrap();
+
+        let err = load_config_from(&tmp).unwrap_err();
+        match &err {
+            ConfigError::Invalid(problems) => {
+                assert_eq!(problems[0].field, "valuation.min_value");
+            }
+            other => panic!("expected Invalid, got: {other}"),
+        }
+
+        let _ = fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn ensure_default_config_files_skips_existing() {
         let tmp = std::env::temp_dir().join("config_test_ensure_skips");
@@ -1069,11 +2682,21 @@ gs_per_week = 7
         assert_eq!(config.strategy.llm.planning_max_tokens, 2048);
         assert_eq!(config.strategy.llm.analysis_trigger, "nomination");
         assert!(config.strategy.llm.prefire_planning);
+        assert!(config.strategy.flexibility.enabled);
+        assert!((config.strategy.flexibility.extra_slot_pct - 0.03).abs() < f64::EPSILON);
+        assert!((config.strategy.flexibility.catcher_extra_slot_pct - 0.05).abs() < f64::EPSILON);
+        assert!((config.strategy.flexibility.max_pct - 0.12).abs() < f64::EPSILON);
 
         assert_eq!(config.ws_port, 9001);
         assert!(config.data_paths.hitters.is_none());
         assert!(config.data_paths.pitchers.is_none());
 
+        assert!(config.logging.dir.is_none());
+        assert!(config.logging.per_session);
+        assert_eq!(config.logging.max_file_size_mb, 10);
+        assert_eq!(config.logging.max_files, 10);
+        assert_eq!(config.logging.format, LogFormat::Text);
+
         assert!(config.credentials.anthropic_api_key.is_none());
         assert!(config.credentials.google_api_key.is_none());
         assert!(config.credentials.openai_api_key.is_none());
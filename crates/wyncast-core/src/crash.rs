@@ -0,0 +1,232 @@
+// Crash capture: a panic hook that persists the panic message and backtrace
+// to the database (and a plain-text crash file) so the next launch can tell
+// the user where the previous session died.
+//
+// The hook runs on the panicking thread with the process in an unknown
+// state, so it opens its own database connection rather than touching the
+// app's `Database`, and every step is best-effort.
+
+use std::backtrace::Backtrace;
+use std::fmt::Write as _;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::Database;
+
+/// A panic captured by [`install_panic_hook`], as stored in `crash_reports`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrashReport {
+    /// Row ID (0 for a report that has not been persisted yet).
+    pub id: i64,
+    /// UTC timestamp of the panic (RFC 3339).
+    pub occurred_at: String,
+    /// Draft session that was active when the panic happened.
+    pub draft_id: Option<String>,
+    /// Number of picks recorded in that session at the time of the panic.
+    pub pick_count: Option<u32>,
+    /// The panic payload, e.g. `index out of bounds: ...`.
+    pub message: String,
+    /// Source location (`file:line:col`) of the panic, if known.
+    pub location: Option<String>,
+    /// Fully captured backtrace.
+    pub backtrace: String,
+    /// Path of the crash file written alongside the DB row, if any.
+    pub crash_file: Option<String>,
+}
+
+impl CrashReport {
+    /// One-line summary for notices, e.g.
+    /// `Previous session crashed at pick 87: index out of bounds (src/app.rs:10:5)`.
+    pub fn summary(&self) -> String {
+        let mut out = match self.pick_count {
+            Some(n) => format!("Previous session crashed at pick {n}"),
+            None => "Previous session crashed".to_string(),
+        };
+        let _ = write!(out, ": {}", self.message);
+        if let Some(ref loc) = self.location {
+            let _ = write!(out, " ({loc})");
+        }
+        out
+    }
+
+    /// Full human-readable report, as written to the crash file.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "wyncast crash report");
+        let _ = writeln!(out, "time:     {}", self.occurred_at);
+        let _ = writeln!(out, "draft:    {}", self.draft_id.as_deref().unwrap_or("-"));
+        let _ = writeln!(
+            out,
+            "picks:    {}",
+            self.pick_count.map_or_else(|| "-".to_string(), |n| n.to_string())
+        );
+        let _ = writeln!(out, "location: {}", self.location.as_deref().unwrap_or("-"));
+        let _ = writeln!(out, "message:  {}", self.message);
+        let _ = writeln!(out);
+        let _ = writeln!(out, "backtrace:");
+        let _ = writeln!(out, "{}", self.backtrace);
+        out
+    }
+}
+
+/// Draft context recorded alongside a panic. Kept up to date by the app as
+/// picks arrive so a crash report can say *where* in the draft it happened.
+#[derive(Debug, Default)]
+struct CrashContext {
+    draft_id: Option<String>,
+    pick_count: Option<u32>,
+}
+
+static CONTEXT: Mutex<CrashContext> = Mutex::new(CrashContext {
+    draft_id: None,
+    pick_count: None,
+});
+
+/// Update the draft context attached to any subsequent crash report.
+pub fn set_draft_context(draft_id: &str, pick_count: usize) {
+    let mut ctx = CONTEXT.lock().unwrap_or_else(|e| e.into_inner());
+    ctx.draft_id = Some(draft_id.to_string());
+    ctx.pick_count = Some(pick_count as u32);
+}
+
+/// Install a panic hook that records every panic to `db_path` and writes a
+/// `crash-YYYYmmdd-HHMMSS.txt` file into `crash_dir`, then defers to the
+/// previously installed hook (so the default message still reaches stderr).
+///
+/// Install this *before* any hook that restores the terminal; that hook
+/// should wrap this one so the screen is usable again before we do I/O.
+pub fn install_panic_hook(db_path: PathBuf, crash_dir: PathBuf) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = capture(info);
+        persist(report, &db_path, &crash_dir);
+        previous(info);
+    }));
+}
+
+/// Build a (not yet persisted) report from panic info and the current context.
+fn capture(info: &PanicHookInfo<'_>) -> CrashReport {
+    let payload = info.payload();
+    let message = if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    };
+
+    // `try_lock`: the panic may have happened while the context was held.
+    let (draft_id, pick_count) = match CONTEXT.try_lock() {
+        Ok(ctx) => (ctx.draft_id.clone(), ctx.pick_count),
+        Err(std::sync::TryLockError::Poisoned(e)) => {
+            let ctx = e.into_inner();
+            (ctx.draft_id.clone(), ctx.pick_count)
+        }
+        Err(std::sync::TryLockError::WouldBlock) => (None, None),
+    };
+
+    CrashReport {
+        id: 0,
+        occurred_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        draft_id,
+        pick_count,
+        message,
+        location: info.location().map(|l| l.to_string()),
+        backtrace: Backtrace::force_capture().to_string(),
+        crash_file: None,
+    }
+}
+
+/// Write the crash file, then the DB row. Failures are reported on stderr
+/// only; there is nothing else useful to do from inside a panic hook.
+fn persist(mut report: CrashReport, db_path: &Path, crash_dir: &Path) {
+    match write_crash_file(&report, crash_dir) {
+        Ok(path) => report.crash_file = Some(path.to_string_lossy().into_owned()),
+        Err(e) => eprintln!("failed to write crash file: {e}"),
+    }
+
+    let result = Database::open(&db_path.to_string_lossy()).and_then(|db| db.record_crash(&report));
+    if let Err(e) = result {
+        eprintln!("failed to record crash in database: {e:#}");
+    }
+}
+
+/// Write `report` as text to a timestamped file in `dir`.
+pub fn write_crash_file(report: &CrashReport, dir: &Path) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let path = dir.join(format!("crash-{stamp}.txt"));
+    std::fs::write(&path, report.to_text())?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_report() -> CrashReport {
+        CrashReport {
+            id: 0,
+            occurred_at: "2026-03-01T20:15:00Z".to_string(),
+            draft_id: Some("draft_20260301_200000_000".to_string()),
+            pick_count: Some(87),
+            message: "index out of bounds".to_string(),
+            location: Some("src/app.rs:10:5".to_string()),
+            backtrace: "0: main".to_string(),
+            crash_file: None,
+        }
+    }
+
+    #[test]
+    fn summary_includes_pick_and_location() {
+        assert_eq!(
+            sample_report().summary(),
+            "Previous session crashed at pick 87: index out of bounds (src/app.rs:10:5)"
+        );
+    }
+
+    #[test]
+    fn summary_without_context() {
+        let report = CrashReport {
+            pick_count: None,
+            location: None,
+            ..sample_report()
+        };
+        assert_eq!(report.summary(), "Previous session crashed: index out of bounds");
+    }
+
+    #[test]
+    fn crash_file_contains_message_and_backtrace() {
+        let dir = std::env::temp_dir().join("crash_test_write_file");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let path = write_crash_file(&sample_report(), &dir).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.contains("message:  index out of bounds"));
+        assert!(text.contains("picks:    87"));
+        assert!(text.contains("0: main"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn persist_records_to_database_and_file() {
+        let dir = std::env::temp_dir().join("crash_test_persist");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("test.db");
+
+        persist(sample_report(), &db_path, &dir);
+
+        let db = Database::open(&db_path.to_string_lossy()).unwrap();
+        let stored = db.latest_unacknowledged_crash().unwrap().expect("crash recorded");
+        assert_eq!(stored.pick_count, Some(87));
+        assert_eq!(stored.message, "index out of bounds");
+        let crash_file = stored.crash_file.expect("crash file path recorded");
+        assert!(Path::new(&crash_file).exists());
+        drop(db);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
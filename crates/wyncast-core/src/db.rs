@@ -1,11 +1,99 @@
 // SQLite persistence layer for draft state.
 
+use std::collections::HashMap;
 use std::sync::{Mutex, MutexGuard};
 
-use anyhow::{Context, Result};
 use rusqlite::{params, Connection};
 
+use crate::config::KeeperContract;
+use crate::crash::CrashReport;
+use crate::journal::{JournalAction, JournalEntry};
+use crate::nomination::NominationVerdict;
 use crate::picks::DraftPick;
+use crate::stamp::SessionStamp;
+
+// ---------------------------------------------------------------------------
+// Error type
+// ---------------------------------------------------------------------------
+
+/// Errors from the database, its migrations and its backups. Each failure
+/// carries what was being done when it happened.
+#[derive(Debug, thiserror::Error)]
+pub enum DbError {
+    #[error("{context}: {source}")]
+    Sqlite {
+        context: String,
+        source: rusqlite::Error,
+    },
+
+    #[error("{context}: {source}")]
+    Json {
+        context: String,
+        source: serde_json::Error,
+    },
+
+    #[error("{context}: {source}")]
+    Io {
+        context: String,
+        source: std::io::Error,
+    },
+
+    #[error("migration v{version} '{name}' is irreversible (no down SQL)")]
+    Irreversible { version: i64, name: &'static str },
+
+    #[error("backup {path} is damaged: {check}")]
+    DamagedBackup { path: String, check: String },
+
+    #[error("{0}")]
+    InvalidPath(String),
+}
+
+/// Result of a database operation.
+pub type Result<T, E = DbError> = std::result::Result<T, E>;
+
+/// An error a database operation can fail with, before it is described.
+pub(crate) trait DbCause {
+    fn with(self, context: String) -> DbError;
+}
+
+impl DbCause for rusqlite::Error {
+    fn with(self, context: String) -> DbError {
+        DbError::Sqlite { context, source: self }
+    }
+}
+
+impl DbCause for serde_json::Error {
+    fn with(self, context: String) -> DbError {
+        DbError::Json { context, source: self }
+    }
+}
+
+impl DbCause for std::io::Error {
+    fn with(self, context: String) -> DbError {
+        DbError::Io { context, source: self }
+    }
+}
+
+/// Describes what a failed call was doing, turning its error into a
+/// [`DbError`].
+pub(crate) trait DbContext<T> {
+    fn context(self, context: &str) -> Result<T>;
+    fn with_context(self, context: impl FnOnce() -> String) -> Result<T>;
+}
+
+impl<T, E: DbCause> DbContext<T> for std::result::Result<T, E> {
+    fn context(self, context: &str) -> Result<T> {
+        self.map_err(|e| e.with(context.to_string()))
+    }
+
+    fn with_context(self, context: impl FnOnce() -> String) -> Result<T> {
+        self.map_err(|e| e.with(context()))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Database
+// ---------------------------------------------------------------------------
 
 /// SQLite-backed persistence for players, projections, draft picks, and
 /// key-value draft state.
@@ -51,27 +139,40 @@ impl Database {
     /// from different sessions don't intermingle.
     pub fn record_pick(&self, pick: &DraftPick, draft_id: &str) -> Result<()> {
         let conn = self.conn();
-        let eligible_slots_json = serde_json::to_string(&pick.eligible_slots)
-            .context("failed to serialize eligible_slots")?;
-        let assigned_slot_val: Option<i64> = pick.assigned_slot.map(|v| v as i64);
-        conn.execute(
-            "INSERT OR IGNORE INTO draft_picks
-                (pick_number, team_id, team_name, espn_player_id, player_name, position, price, eligible_slots, assigned_slot, draft_id)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
-            params![
-                pick.pick_number,
-                pick.team_id,
-                pick.team_name,
-                pick.espn_player_id,
-                pick.player_name,
-                pick.position,
-                pick.price,
-                eligible_slots_json,
-                assigned_slot_val,
-                draft_id,
-            ],
-        )
-        .context("failed to record draft pick")?;
+        insert_pick(&conn, pick, draft_id)?;
+        Ok(())
+    }
+
+    /// Record several draft picks in a single transaction. Either every pick
+    /// is written or, if any insert fails, none are. Duplicates are skipped
+    /// exactly as in [`record_pick`](Self::record_pick). Returns the number
+    /// of rows actually inserted.
+    pub fn record_picks(&self, picks: &[DraftPick], draft_id: &str) -> Result<usize> {
+        let mut conn = self.conn();
+        let tx = conn
+            .transaction()
+            .context("failed to begin pick batch transaction")?;
+        let mut inserted = 0;
+        for pick in picks {
+            inserted += insert_pick(&tx, pick, draft_id)?;
+        }
+        tx.commit().context("failed to commit pick batch")?;
+        Ok(inserted)
+    }
+
+    /// Replace the pick log of `draft_id` with `picks` in one transaction,
+    /// for when a pick is taken back and the later ones are renumbered.
+    pub fn replace_picks(&self, picks: &[DraftPick], draft_id: &str) -> Result<()> {
+        let mut conn = self.conn();
+        let tx = conn
+            .transaction()
+            .context("failed to begin pick replacement transaction")?;
+        tx.execute("DELETE FROM draft_picks WHERE draft_id = ?1", params![draft_id])
+            .context("failed to delete draft picks")?;
+        for pick in picks {
+            insert_pick(&tx, pick, draft_id)?;
+        }
+        tx.commit().context("failed to commit pick replacement")?;
         Ok(())
     }
 
@@ -242,6 +343,16 @@ impl Database {
             params![draft_id],
         )
         .context("failed to delete draft picks")?;
+        conn.execute(
+            "DELETE FROM nominations WHERE draft_id = ?1",
+            params![draft_id],
+        )
+        .context("failed to delete nominations")?;
+        conn.execute(
+            "DELETE FROM pick_verdicts WHERE draft_id = ?1",
+            params![draft_id],
+        )
+        .context("failed to delete pick verdicts")?;
         Ok(())
     }
 
@@ -253,12 +364,405 @@ impl Database {
         let tx = conn.transaction().context("failed to begin transaction")?;
         tx.execute("DELETE FROM draft_picks", [])
             .context("failed to delete draft picks")?;
+        tx.execute("DELETE FROM nominations", [])
+            .context("failed to delete nominations")?;
+        tx.execute("DELETE FROM pick_verdicts", [])
+            .context("failed to delete pick verdicts")?;
         tx.execute("DELETE FROM draft_state", [])
             .context("failed to delete draft state")?;
         tx.commit().context("failed to commit clear_all_drafts")?;
         Ok(())
     }
 
+    /// Begin a fresh draft session: delete all draft picks and draft state
+    /// (like [`clear_all_drafts`](Self::clear_all_drafts)) and store
+    /// `draft_id` as the current draft, all in one transaction. If any step
+    /// fails the previous session is left untouched.
+    pub fn start_new_draft(&self, draft_id: &str) -> Result<()> {
+        let mut conn = self.conn();
+        let tx = conn
+            .transaction()
+            .context("failed to begin new draft transaction")?;
+        tx.execute("DELETE FROM draft_picks", [])
+            .context("failed to delete draft picks")?;
+        tx.execute("DELETE FROM nominations", [])
+            .context("failed to delete nominations")?;
+        tx.execute("DELETE FROM pick_verdicts", [])
+            .context("failed to delete pick verdicts")?;
+        tx.execute("DELETE FROM draft_state", [])
+            .context("failed to delete draft state")?;
+        let draft_id_json = serde_json::to_string(&serde_json::Value::String(draft_id.to_string()))
+            .context("failed to serialize draft_id")?;
+        tx.execute(
+            "INSERT INTO draft_state (key, value) VALUES (?1, ?2)",
+            params![Self::DRAFT_ID_KEY, draft_id_json],
+        )
+        .context("failed to save draft_id")?;
+        tx.commit().context("failed to commit new draft")?;
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------
+    // Crash reports
+    // ------------------------------------------------------------------
+
+    /// Persist a crash report captured by the panic hook. Crash reports live
+    /// in their own table so `clear_all_drafts` does not discard them.
+    pub fn record_crash(&self, report: &CrashReport) -> Result<()> {
+        let conn = self.conn();
+        conn.execute(
+            "INSERT INTO crash_reports
+                (occurred_at, draft_id, pick_count, message, location, backtrace, crash_file)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                report.occurred_at,
+                report.draft_id,
+                report.pick_count,
+                report.message,
+                report.location,
+                report.backtrace,
+                report.crash_file,
+            ],
+        )
+        .context("failed to record crash report")?;
+        Ok(())
+    }
+
+    /// Return the most recent crash report the user has not yet been told
+    /// about, if any.
+    pub fn latest_unacknowledged_crash(&self) -> Result<Option<CrashReport>> {
+        let conn = self.conn();
+        let result = conn.query_row(
+            "SELECT id, occurred_at, draft_id, pick_count, message, location, backtrace, crash_file
+             FROM crash_reports
+             WHERE acknowledged = 0
+             ORDER BY id DESC
+             LIMIT 1",
+            [],
+            |row| {
+                Ok(CrashReport {
+                    id: row.get(0)?,
+                    occurred_at: row.get(1)?,
+                    draft_id: row.get(2)?,
+                    pick_count: row.get(3)?,
+                    message: row.get(4)?,
+                    location: row.get(5)?,
+                    backtrace: row.get(6)?,
+                    crash_file: row.get(7)?,
+                })
+            },
+        );
+        match result {
+            Ok(report) => Ok(Some(report)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e).context("failed to load crash report"),
+        }
+    }
+
+    /// Mark every stored crash report as shown to the user.
+    pub fn acknowledge_crashes(&self) -> Result<()> {
+        let conn = self.conn();
+        conn.execute("UPDATE crash_reports SET acknowledged = 1 WHERE acknowledged = 0", [])
+            .context("failed to acknowledge crash reports")?;
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------
+    // Decision journal
+    // ------------------------------------------------------------------
+
+    /// Append a quick-bid decision to the journal. Like crash reports,
+    /// journal entries are kept by `clear_all_drafts` and `start_new_draft`
+    /// so past drafts can still be reviewed.
+    pub fn record_decision(&self, entry: &JournalEntry) -> Result<()> {
+        let conn = self.conn();
+        conn.execute(
+            "INSERT INTO decision_journal
+                (recorded_at, draft_id, player_name, action, amount, current_bid)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                entry.recorded_at,
+                entry.draft_id,
+                entry.player_name,
+                entry.action.as_str(),
+                entry.amount,
+                entry.current_bid,
+            ],
+        )
+        .context("failed to record decision")?;
+        Ok(())
+    }
+
+    /// Load every journal entry for `draft_id`, oldest first.
+    pub fn load_decisions(&self, draft_id: &str) -> Result<Vec<JournalEntry>> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, recorded_at, draft_id, player_name, action, amount, current_bid
+                 FROM decision_journal
+                 WHERE draft_id = ?1
+                 ORDER BY id ASC",
+            )
+            .context("failed to prepare load_decisions query")?;
+        let rows = stmt
+            .query_map(params![draft_id], |row| {
+                let action: String = row.get(4)?;
+                let action = JournalAction::parse(&action).ok_or_else(|| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        4,
+                        rusqlite::types::Type::Text,
+                        format!("unknown journal action: {action}").into(),
+                    )
+                })?;
+                Ok(JournalEntry {
+                    id: row.get(0)?,
+                    recorded_at: row.get(1)?,
+                    draft_id: row.get(2)?,
+                    player_name: row.get(3)?,
+                    action,
+                    amount: row.get(5)?,
+                    current_bid: row.get(6)?,
+                })
+            })
+            .context("failed to query decision journal")?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .context("failed to read decision journal row")
+    }
+
+    // ------------------------------------------------------------------
+    // Nominations
+    // ------------------------------------------------------------------
+
+    /// Record that `nominated_by` nominated `player_name` in `draft_id`,
+    /// replacing an earlier nominator (ESPN can fill it in late).
+    pub fn record_nomination(&self, draft_id: &str, player_name: &str, nominated_by: &str) -> Result<()> {
+        let conn = self.conn();
+        conn.execute(
+            "INSERT OR REPLACE INTO nominations (draft_id, player_name, nominated_by)
+             VALUES (?1, ?2, ?3)",
+            params![draft_id, player_name, nominated_by],
+        )
+        .context("failed to record nomination")?;
+        Ok(())
+    }
+
+    /// Who nominated each player in `draft_id`, by player name.
+    pub fn load_nominations(&self, draft_id: &str) -> Result<HashMap<String, String>> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare("SELECT player_name, nominated_by FROM nominations WHERE draft_id = ?1")
+            .context("failed to prepare load_nominations query")?;
+        let rows = stmt
+            .query_map(params![draft_id], |row| Ok((row.get(0)?, row.get(1)?)))
+            .context("failed to query nominations")?;
+        rows.collect::<rusqlite::Result<HashMap<_, _>>>()
+            .context("failed to read nomination row")
+    }
+
+    // ------------------------------------------------------------------
+    // Pick verdicts
+    // ------------------------------------------------------------------
+
+    /// Record the model's final verdict on a player sold in `draft_id`.
+    pub fn record_pick_verdict(&self, draft_id: &str, verdict: &NominationVerdict) -> Result<()> {
+        let conn = self.conn();
+        conn.execute(
+            "INSERT OR REPLACE INTO pick_verdicts (draft_id, player_name, verdict, max_bid)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![draft_id, verdict.player_name, verdict.verdict, verdict.max_bid],
+        )
+        .context("failed to record pick verdict")?;
+        Ok(())
+    }
+
+    /// The model's verdicts on the players sold in `draft_id`, by player name.
+    pub fn load_pick_verdicts(&self, draft_id: &str) -> Result<HashMap<String, NominationVerdict>> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare("SELECT player_name, verdict, max_bid FROM pick_verdicts WHERE draft_id = ?1")
+            .context("failed to prepare load_pick_verdicts query")?;
+        let rows = stmt
+            .query_map(params![draft_id], |row| {
+                let verdict = NominationVerdict {
+                    player_name: row.get(0)?,
+                    verdict: row.get(1)?,
+                    max_bid: row.get(2)?,
+                };
+                Ok((verdict.player_name.clone(), verdict))
+            })
+            .context("failed to query pick verdicts")?;
+        rows.collect::<rusqlite::Result<HashMap<_, _>>>()
+            .context("failed to read pick verdict row")
+    }
+
+    // ------------------------------------------------------------------
+    // Session stamps
+    // ------------------------------------------------------------------
+
+    /// Record the model version and inputs a session started with. Stamps
+    /// are kept by `clear_all_drafts` and `start_new_draft` so a past draft
+    /// can be traced to what produced its advice.
+    pub fn record_session_stamp(&self, stamp: &SessionStamp) -> Result<()> {
+        let inputs = serde_json::to_string(&stamp.inputs).context("failed to serialize stamp inputs")?;
+        let weights = serde_json::to_string(&stamp.weights).context("failed to serialize stamp weights")?;
+        let settings = serde_json::to_string(&stamp.settings).context("failed to serialize stamp settings")?;
+        let conn = self.conn();
+        conn.execute(
+            "INSERT INTO session_stamps
+                (recorded_at, draft_id, app_version, config_hash, inputs, weights, settings)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                stamp.recorded_at,
+                stamp.draft_id,
+                stamp.app_version,
+                stamp.config_hash,
+                inputs,
+                weights,
+                settings,
+            ],
+        )
+        .context("failed to record session stamp")?;
+        Ok(())
+    }
+
+    /// Every session stamp of `draft_id`, oldest first: one per start of the
+    /// app during the draft.
+    pub fn load_session_stamps(&self, draft_id: &str) -> Result<Vec<SessionStamp>> {
+        self.query_session_stamps(
+            "SELECT recorded_at, draft_id, app_version, config_hash, inputs, weights, settings
+             FROM session_stamps
+             WHERE draft_id = ?1
+             ORDER BY id ASC",
+            draft_id,
+        )
+    }
+
+    /// The last stamp of the draft before `draft_id`: the most recent one
+    /// of another draft recorded before `draft_id`'s first. `None` when
+    /// `draft_id` is the first draft stamped.
+    pub fn previous_session_stamp(&self, draft_id: &str) -> Result<Option<SessionStamp>> {
+        let stamps = self.query_session_stamps(
+            "SELECT recorded_at, draft_id, app_version, config_hash, inputs, weights, settings
+             FROM session_stamps
+             WHERE draft_id != ?1
+               AND id < COALESCE((SELECT MIN(id) FROM session_stamps WHERE draft_id = ?1), 9223372036854775807)
+             ORDER BY id DESC
+             LIMIT 1",
+            draft_id,
+        )?;
+        Ok(stamps.into_iter().next())
+    }
+
+    fn query_session_stamps(&self, sql: &str, draft_id: &str) -> Result<Vec<SessionStamp>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(sql).context("failed to prepare session stamps query")?;
+        let rows = stmt
+            .query_map(params![draft_id], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, String>(6)?,
+                ))
+            })
+            .context("failed to query session stamps")?;
+        let mut stamps = Vec::new();
+        for row in rows {
+            let (recorded_at, draft_id, app_version, config_hash, inputs, weights, settings) =
+                row.context("failed to read session stamp row")?;
+            stamps.push(SessionStamp {
+                recorded_at,
+                draft_id,
+                app_version,
+                config_hash,
+                inputs: serde_json::from_str(&inputs).context("failed to parse stamp inputs")?,
+                weights: serde_json::from_str(&weights).context("failed to parse stamp weights")?,
+                settings: serde_json::from_str(&settings).context("failed to parse stamp settings")?,
+            });
+        }
+        Ok(stamps)
+    }
+
+    // ------------------------------------------------------------------
+    // Keeper contracts
+    // ------------------------------------------------------------------
+
+    /// Insert or replace the contract for `contract.player`. Contracts
+    /// carry over from season to season, so `clear_all_drafts` and
+    /// `start_new_draft` leave them alone.
+    pub fn save_keeper_contract(&self, contract: &KeeperContract) -> Result<()> {
+        let conn = self.conn();
+        conn.execute(
+            "INSERT INTO keeper_contracts (player_name, salary, years, raise_amount, raise_pct, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+             ON CONFLICT(player_name) DO UPDATE SET
+                salary = excluded.salary,
+                years = excluded.years,
+                raise_amount = excluded.raise_amount,
+                raise_pct = excluded.raise_pct,
+                updated_at = excluded.updated_at",
+            params![
+                contract.player,
+                contract.salary,
+                contract.years,
+                contract.raise,
+                contract.raise_pct,
+            ],
+        )
+        .context("failed to save keeper contract")?;
+        Ok(())
+    }
+
+    /// Delete the contract for `player`, if there is one.
+    pub fn delete_keeper_contract(&self, player: &str) -> Result<()> {
+        let conn = self.conn();
+        conn.execute("DELETE FROM keeper_contracts WHERE player_name = ?1", params![player])
+            .context("failed to delete keeper contract")?;
+        Ok(())
+    }
+
+    /// Load every keeper contract, by player name.
+    pub fn load_keeper_contracts(&self) -> Result<Vec<KeeperContract>> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(
+                "SELECT player_name, salary, years, raise_amount, raise_pct
+                 FROM keeper_contracts
+                 ORDER BY player_name ASC",
+            )
+            .context("failed to prepare load_keeper_contracts query")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(KeeperContract {
+                    player: row.get(0)?,
+                    salary: row.get(1)?,
+                    years: row.get(2)?,
+                    raise: row.get(3)?,
+                    raise_pct: row.get(4)?,
+                })
+            })
+            .context("failed to query keeper contracts")?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .context("failed to read keeper contract row")
+    }
+
+    // ------------------------------------------------------------------
+    // Backups
+    // ------------------------------------------------------------------
+
+    /// Write a consistent copy of the whole database to `path`, which must
+    /// not exist yet. Uses `VACUUM INTO`, so the copy is compact and safe to
+    /// take while the app keeps writing.
+    pub fn backup_to(&self, path: &str) -> Result<()> {
+        let conn = self.conn();
+        conn.execute("VACUUM INTO ?1", params![path])
+            .with_context(|| format!("failed to back up database to {path}"))?;
+        Ok(())
+    }
+
     // ------------------------------------------------------------------
     // Draft ID management
     // ------------------------------------------------------------------
@@ -386,6 +890,33 @@ impl Database {
     }
 }
 
+/// Insert one pick with `INSERT OR IGNORE`. Shared by the single-pick and
+/// batch paths; `conn` may be a plain connection or an open transaction.
+/// Returns the number of rows inserted (0 for a duplicate).
+fn insert_pick(conn: &Connection, pick: &DraftPick, draft_id: &str) -> Result<usize> {
+    let eligible_slots_json = serde_json::to_string(&pick.eligible_slots)
+        .context("failed to serialize eligible_slots")?;
+    let assigned_slot_val: Option<i64> = pick.assigned_slot.map(|v| v as i64);
+    conn.execute(
+        "INSERT OR IGNORE INTO draft_picks
+            (pick_number, team_id, team_name, espn_player_id, player_name, position, price, eligible_slots, assigned_slot, draft_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            pick.pick_number,
+            pick.team_id,
+            pick.team_name,
+            pick.espn_player_id,
+            pick.player_name,
+            pick.position,
+            pick.price,
+            eligible_slots_json,
+            assigned_slot_val,
+            draft_id,
+        ],
+    )
+    .context("failed to record draft pick")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -776,6 +1307,241 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // ------------------------------------------------------------------
+    // Crash reports
+    // ------------------------------------------------------------------
+
+    fn sample_crash(pick_count: u32) -> CrashReport {
+        CrashReport {
+            id: 0,
+            occurred_at: "2026-03-01T20:15:00Z".to_string(),
+            draft_id: Some(TEST_DRAFT_ID.to_string()),
+            pick_count: Some(pick_count),
+            message: "boom".to_string(),
+            location: Some("src/app.rs:1:1".to_string()),
+            backtrace: "0: main".to_string(),
+            crash_file: None,
+        }
+    }
+
+    #[test]
+    fn latest_unacknowledged_crash_none_when_empty() {
+        let db = test_db();
+        assert!(db.latest_unacknowledged_crash().unwrap().is_none());
+    }
+
+    #[test]
+    fn latest_unacknowledged_crash_returns_newest() {
+        let db = test_db();
+        db.record_crash(&sample_crash(10)).unwrap();
+        db.record_crash(&sample_crash(87)).unwrap();
+
+        let report = db.latest_unacknowledged_crash().unwrap().unwrap();
+        assert_eq!(report.pick_count, Some(87));
+        assert_eq!(report.draft_id.as_deref(), Some(TEST_DRAFT_ID));
+        assert_eq!(report.backtrace, "0: main");
+        assert!(report.id > 0);
+    }
+
+    #[test]
+    fn acknowledge_crashes_hides_reports() {
+        let db = test_db();
+        db.record_crash(&sample_crash(87)).unwrap();
+        db.acknowledge_crashes().unwrap();
+        assert!(db.latest_unacknowledged_crash().unwrap().is_none());
+    }
+
+    #[test]
+    fn clear_all_drafts_keeps_crash_reports() {
+        let db = test_db();
+        db.record_crash(&sample_crash(87)).unwrap();
+        db.clear_all_drafts().unwrap();
+        assert!(db.latest_unacknowledged_crash().unwrap().is_some());
+    }
+
+    // ------------------------------------------------------------------
+    // Decision journal
+    // ------------------------------------------------------------------
+
+    fn sample_decision(action: JournalAction, amount: Option<u32>) -> JournalEntry {
+        JournalEntry {
+            id: 0,
+            recorded_at: "2026-03-01T20:15:00Z".to_string(),
+            draft_id: TEST_DRAFT_ID.to_string(),
+            player_name: "Juan Soto".to_string(),
+            action,
+            amount,
+            current_bid: 17,
+        }
+    }
+
+    #[test]
+    fn record_and_load_decisions_in_order() {
+        let db = test_db();
+        db.record_decision(&sample_decision(JournalAction::In, Some(18)))
+            .unwrap();
+        db.record_decision(&sample_decision(JournalAction::Cap, Some(24)))
+            .unwrap();
+        db.record_decision(&sample_decision(JournalAction::Out, None))
+            .unwrap();
+
+        let entries = db.load_decisions(TEST_DRAFT_ID).unwrap();
+        let actions: Vec<_> = entries.iter().map(|e| (e.action, e.amount)).collect();
+        assert_eq!(
+            actions,
+            vec![
+                (JournalAction::In, Some(18)),
+                (JournalAction::Cap, Some(24)),
+                (JournalAction::Out, None),
+            ]
+        );
+        assert_eq!(entries[0].player_name, "Juan Soto");
+        assert_eq!(entries[0].current_bid, 17);
+        assert!(entries[0].id > 0);
+        assert!(db.load_decisions("other-draft").unwrap().is_empty());
+    }
+
+    #[test]
+    fn start_new_draft_keeps_decision_journal() {
+        let db = test_db();
+        db.record_decision(&sample_decision(JournalAction::In, Some(18)))
+            .unwrap();
+        db.start_new_draft("next-draft").unwrap();
+        assert_eq!(db.load_decisions(TEST_DRAFT_ID).unwrap().len(), 1);
+    }
+
+    // ------------------------------------------------------------------
+    // Nominations
+    // ------------------------------------------------------------------
+
+    #[test]
+    fn nominations_are_scoped_to_the_draft_and_cleared_with_it() {
+        let db = test_db();
+        db.record_nomination(TEST_DRAFT_ID, "Juan Soto", "").unwrap();
+        db.record_nomination(TEST_DRAFT_ID, "Juan Soto", "Team 3").unwrap();
+        db.record_nomination(TEST_DRAFT_ID, "Aaron Judge", "Team 1").unwrap();
+        db.record_nomination("other-draft", "Mookie Betts", "Team 2").unwrap();
+
+        let nominators = db.load_nominations(TEST_DRAFT_ID).unwrap();
+        assert_eq!(nominators.len(), 2);
+        assert_eq!(nominators["Juan Soto"], "Team 3");
+
+        db.clear_draft(TEST_DRAFT_ID).unwrap();
+        assert!(db.load_nominations(TEST_DRAFT_ID).unwrap().is_empty());
+        db.start_new_draft("next-draft").unwrap();
+        assert!(db.load_nominations("other-draft").unwrap().is_empty());
+    }
+
+    // ------------------------------------------------------------------
+    // Pick verdicts
+    // ------------------------------------------------------------------
+
+    #[test]
+    fn pick_verdicts_are_scoped_to_the_draft_and_cleared_with_it() {
+        let db = test_db();
+        let verdict = |player: &str, max_bid| NominationVerdict {
+            player_name: player.to_string(),
+            verdict: "CONDITIONAL".to_string(),
+            max_bid,
+        };
+        db.record_pick_verdict(TEST_DRAFT_ID, &verdict("Juan Soto", 40)).unwrap();
+        db.record_pick_verdict(TEST_DRAFT_ID, &verdict("Juan Soto", 38)).unwrap();
+        db.record_pick_verdict("other-draft", &verdict("Mookie Betts", 25)).unwrap();
+
+        let verdicts = db.load_pick_verdicts(TEST_DRAFT_ID).unwrap();
+        assert_eq!(verdicts.len(), 1);
+        assert_eq!(verdicts["Juan Soto"], verdict("Juan Soto", 38));
+
+        db.clear_draft(TEST_DRAFT_ID).unwrap();
+        assert!(db.load_pick_verdicts(TEST_DRAFT_ID).unwrap().is_empty());
+        db.start_new_draft("next-draft").unwrap();
+        assert!(db.load_pick_verdicts("other-draft").unwrap().is_empty());
+    }
+
+    // ------------------------------------------------------------------
+    // Session stamps
+    // ------------------------------------------------------------------
+
+    #[test]
+    fn session_stamps_round_trip_and_outlive_the_draft() {
+        let db = test_db();
+        let stamp = SessionStamp {
+            recorded_at: "2026-03-01T19:00:00.000Z".to_string(),
+            draft_id: TEST_DRAFT_ID.to_string(),
+            app_version: "0.1.0".to_string(),
+            config_hash: "00ff00ff00ff00ff".to_string(),
+            inputs: vec![crate::stamp::InputFile {
+                label: "hitters".to_string(),
+                path: "data/hitters.csv".to_string(),
+                hash: Some("0123456789abcdef".to_string()),
+            }],
+            weights: [("HR".to_string(), 1.5)].into_iter().collect(),
+            settings: [("pool.min_pa".to_string(), "200".to_string())].into_iter().collect(),
+        };
+        db.record_session_stamp(&stamp).unwrap();
+        let restarted = SessionStamp { config_hash: "ff00ff00ff00ff00".to_string(), ..stamp.clone() };
+        db.record_session_stamp(&restarted).unwrap();
+
+        assert_eq!(db.load_session_stamps(TEST_DRAFT_ID).unwrap(), vec![stamp, restarted]);
+        assert!(db.load_session_stamps("other-draft").unwrap().is_empty());
+        db.clear_all_drafts().unwrap();
+        assert_eq!(db.load_session_stamps(TEST_DRAFT_ID).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn previous_session_stamp_is_the_last_of_the_draft_before() {
+        let db = test_db();
+        let stamp = |draft_id: &str, config_hash: &str| SessionStamp {
+            recorded_at: "2026-03-01T19:00:00.000Z".to_string(),
+            draft_id: draft_id.to_string(),
+            app_version: "0.1.0".to_string(),
+            config_hash: config_hash.to_string(),
+            inputs: vec![],
+            weights: Default::default(),
+            settings: Default::default(),
+        };
+        db.record_session_stamp(&stamp("draft_a", "a1")).unwrap();
+        db.record_session_stamp(&stamp("draft_a", "a2")).unwrap();
+        db.record_session_stamp(&stamp("draft_b", "b1")).unwrap();
+        db.record_session_stamp(&stamp("draft_c", "c1")).unwrap();
+        // A restart of draft_b after draft_c started does not make draft_c
+        // its predecessor.
+        db.record_session_stamp(&stamp("draft_b", "b2")).unwrap();
+
+        assert_eq!(db.previous_session_stamp("draft_a").unwrap(), None);
+        assert_eq!(db.previous_session_stamp("draft_b").unwrap().unwrap().config_hash, "a2");
+        assert_eq!(db.previous_session_stamp("draft_c").unwrap().unwrap().config_hash, "b1");
+        assert_eq!(db.previous_session_stamp("draft_d").unwrap().unwrap().config_hash, "b2");
+    }
+
+    // ------------------------------------------------------------------
+    // Keeper contracts
+    // ------------------------------------------------------------------
+
+    #[test]
+    fn keeper_contracts_upsert_and_survive_new_drafts() {
+        let db = test_db();
+        let mut contract = KeeperContract {
+            player: "Juan Soto".to_string(),
+            salary: 30,
+            years: 3,
+            raise: None,
+            raise_pct: Some(0.1),
+        };
+        db.save_keeper_contract(&contract).unwrap();
+        contract.salary = 35;
+        contract.raise = Some(5);
+        db.save_keeper_contract(&contract).unwrap();
+        db.start_new_draft("next-draft").unwrap();
+        db.clear_all_drafts().unwrap();
+
+        assert_eq!(db.load_keeper_contracts().unwrap(), vec![contract]);
+
+        db.delete_keeper_contract("Juan Soto").unwrap();
+        db.delete_keeper_contract("Nobody").unwrap();
+        assert!(db.load_keeper_contracts().unwrap().is_empty());
+    }
+
     // ------------------------------------------------------------------
     // Batch import
     // ------------------------------------------------------------------
@@ -1001,4 +1767,105 @@ mod tests {
         // Draft state (including stored draft_id) should be cleared
         assert!(db.get_draft_id().unwrap().is_none());
     }
+
+    // ------------------------------------------------------------------
+    // Atomic multi-row writes
+    // ------------------------------------------------------------------
+
+    /// Install a trigger that aborts any write to `table` matching `when`,
+    /// simulating a failure partway through a multi-step operation.
+    fn fail_writes(db: &Database, table: &str, when: &str) {
+        db.conn()
+            .execute_batch(&format!(
+                "CREATE TRIGGER simulated_failure BEFORE INSERT ON {table}
+                 WHEN {when}
+                 BEGIN SELECT RAISE(ABORT, 'simulated failure'); END;"
+            ))
+            .unwrap();
+    }
+
+    #[test]
+    fn record_picks_inserts_all_and_skips_duplicates() {
+        let db = test_db();
+        db.record_pick(&sample_pick(1), TEST_DRAFT_ID).unwrap();
+
+        let inserted = db
+            .record_picks(&[sample_pick(1), sample_pick(2), sample_pick(3)], TEST_DRAFT_ID)
+            .unwrap();
+        assert_eq!(inserted, 2);
+        assert_eq!(db.pick_count(TEST_DRAFT_ID).unwrap(), 3);
+    }
+
+    #[test]
+    fn replace_picks_rewrites_only_this_draft() {
+        let db = test_db();
+        db.record_picks(&[sample_pick(1), sample_pick(2), sample_pick(3)], TEST_DRAFT_ID)
+            .unwrap();
+        db.record_pick(&sample_pick(1), "other-draft").unwrap();
+
+        // Pick 2 is taken back and pick 3 moves up.
+        let mut moved = sample_pick(3);
+        moved.pick_number = 2;
+        db.replace_picks(&[sample_pick(1), moved], TEST_DRAFT_ID).unwrap();
+
+        let picks = db.load_picks(TEST_DRAFT_ID).unwrap();
+        let names: Vec<_> = picks.iter().map(|p| (p.pick_number, p.player_name.as_str())).collect();
+        assert_eq!(names, vec![(1, "Player 1"), (2, "Player 3")]);
+        assert_eq!(db.pick_count("other-draft").unwrap(), 1);
+    }
+
+    #[test]
+    fn record_picks_rolls_back_on_mid_batch_failure() {
+        let db = test_db();
+        fail_writes(&db, "draft_picks", "NEW.pick_number = 3");
+
+        let result = db.record_picks(
+            &[sample_pick(1), sample_pick(2), sample_pick(3), sample_pick(4)],
+            TEST_DRAFT_ID,
+        );
+        assert!(result.is_err());
+        // Picks 1 and 2 were inserted before the failure but must not survive.
+        assert_eq!(db.pick_count(TEST_DRAFT_ID).unwrap(), 0);
+    }
+
+    #[test]
+    fn start_new_draft_replaces_previous_session() {
+        let db = test_db();
+        db.record_pick(&sample_pick(1), "draft_a").unwrap();
+        db.set_both_draft_ids("draft_a", "espn_12345_2026").unwrap();
+
+        db.start_new_draft("draft_b").unwrap();
+
+        assert!(!db.has_draft_in_progress("draft_a").unwrap());
+        assert_eq!(db.get_draft_id().unwrap(), Some("draft_b".to_string()));
+        assert!(db.get_espn_draft_id().unwrap().is_none());
+    }
+
+    #[test]
+    fn start_new_draft_rolls_back_on_failure() {
+        let db = test_db();
+        db.record_pick(&sample_pick(1), "draft_a").unwrap();
+        db.set_draft_id("draft_a").unwrap();
+        // Deleting succeeds; storing the new draft ID fails afterwards.
+        fail_writes(&db, "draft_state", "NEW.key = 'current_draft_id'");
+
+        assert!(db.start_new_draft("draft_b").is_err());
+
+        // The old session must be fully intact.
+        assert!(db.has_draft_in_progress("draft_a").unwrap());
+        assert_eq!(db.get_draft_id().unwrap(), Some("draft_a".to_string()));
+    }
+
+    #[test]
+    fn set_both_draft_ids_rolls_back_on_failure() {
+        let db = test_db();
+        db.set_both_draft_ids("draft_a", "espn_a").unwrap();
+        // The draft ID write succeeds; the ESPN ID write fails after it.
+        fail_writes(&db, "draft_state", "NEW.key = 'espn_draft_id'");
+
+        assert!(db.set_both_draft_ids("draft_b", "espn_b").is_err());
+
+        assert_eq!(db.get_draft_id().unwrap(), Some("draft_a".to_string()));
+        assert_eq!(db.get_espn_draft_id().unwrap(), Some("espn_a".to_string()));
+    }
 }
@@ -0,0 +1,285 @@
+// Expressions: the arithmetic behind custom computed stats.
+//
+// A custom stat is defined in config as `name = expression`, e.g.
+// `kbb = (k - bb) / ip`. Expressions are numbers and projection fields
+// combined with `+ - * /`, unary minus and parentheses, with the usual
+// precedence. They are parsed once, when the config loads, and evaluated
+// against each player's projection; a field the player does not have, or a
+// division by zero, leaves the stat without a value for that player.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ExprError {
+    #[error("expected `name = expression`, e.g. `kbb = (k - bb) / ip`")]
+    MissingName,
+
+    #[error("unexpected {found} at column {column}")]
+    Unexpected { found: String, column: usize },
+
+    #[error("expression ends too soon")]
+    UnexpectedEnd,
+}
+
+/// A binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// A parsed expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    /// A projection field, by key.
+    Field(String),
+    Neg(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn parse(text: &str) -> Result<Self, ExprError> {
+        let tokens = tokenize(text)?;
+        let mut parser = Parser { tokens: &tokens, pos: 0 };
+        let expr = parser.sum()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(expr),
+            Some((token, column)) => Err(ExprError::Unexpected { found: token.to_string(), column: *column }),
+        }
+    }
+
+    /// The value with each field looked up by `field`, or `None` if a field
+    /// is missing or the result is not a finite number.
+    pub fn eval(&self, field: &impl Fn(&str) -> Option<f64>) -> Option<f64> {
+        let value = match self {
+            Expr::Number(n) => *n,
+            Expr::Field(key) => field(key)?,
+            Expr::Neg(inner) => -inner.eval(field)?,
+            Expr::Binary(op, lhs, rhs) => {
+                let (a, b) = (lhs.eval(field)?, rhs.eval(field)?);
+                match op {
+                    Op::Add => a + b,
+                    Op::Sub => a - b,
+                    Op::Mul => a * b,
+                    Op::Div => a / b,
+                }
+            }
+        };
+        value.is_finite().then_some(value)
+    }
+}
+
+/// A named computed stat from config.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomStat {
+    pub name: String,
+    pub expr: Expr,
+}
+
+impl CustomStat {
+    /// Parse a `name = expression` definition.
+    pub fn parse(definition: &str) -> Result<Self, ExprError> {
+        let (name, expr) = definition.split_once('=').ok_or(ExprError::MissingName)?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ExprError::MissingName);
+        }
+        Ok(CustomStat { name: name.to_string(), expr: Expr::parse(expr)? })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(Op),
+    Open,
+    Close,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "`{n}`"),
+            Token::Ident(name) => write!(f, "`{name}`"),
+            Token::Op(op) => {
+                let symbol = match op {
+                    Op::Add => '+',
+                    Op::Sub => '-',
+                    Op::Mul => '*',
+                    Op::Div => '/',
+                };
+                write!(f, "`{symbol}`")
+            }
+            Token::Open => write!(f, "`(`"),
+            Token::Close => write!(f, "`)`"),
+        }
+    }
+}
+
+/// Split `text` into tokens, each with its column (1-based).
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, ExprError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let token = match c {
+            '+' => Token::Op(Op::Add),
+            '-' => Token::Op(Op::Sub),
+            '*' => Token::Op(Op::Mul),
+            '/' => Token::Op(Op::Div),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            c if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let literal: String = chars[start..i].iter().collect();
+                let n = literal
+                    .parse()
+                    .map_err(|_| ExprError::Unexpected { found: format!("`{literal}`"), column })?;
+                tokens.push((Token::Number(n), column));
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push((Token::Ident(chars[start..i].iter().collect()), column));
+                continue;
+            }
+            other => return Err(ExprError::Unexpected { found: format!("`{other}`"), column }),
+        };
+        tokens.push((token, column));
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent over the tokens: sums of products of unary terms.
+struct Parser<'a> {
+    tokens: &'a [(Token, usize)],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<&(Token, usize)> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn peek_op(&self, ops: [Op; 2]) -> Option<Op> {
+        match self.tokens.get(self.pos) {
+            Some((Token::Op(op), _)) if ops.contains(op) => Some(*op),
+            _ => None,
+        }
+    }
+
+    fn sum(&mut self) -> Result<Expr, ExprError> {
+        let mut expr = self.product()?;
+        while let Some(op) = self.peek_op([Op::Add, Op::Sub]) {
+            self.pos += 1;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.product()?));
+        }
+        Ok(expr)
+    }
+
+    fn product(&mut self) -> Result<Expr, ExprError> {
+        let mut expr = self.unary()?;
+        while let Some(op) = self.peek_op([Op::Mul, Op::Div]) {
+            self.pos += 1;
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ExprError> {
+        let (token, column) = self.next().cloned().ok_or(ExprError::UnexpectedEnd)?;
+        match token {
+            Token::Number(n) => Ok(Expr::Number(n)),
+            Token::Ident(name) => Ok(Expr::Field(name)),
+            Token::Op(Op::Sub) => Ok(Expr::Neg(Box::new(self.unary()?))),
+            Token::Open => {
+                let inner = self.sum()?;
+                match self.next() {
+                    Some((Token::Close, _)) => Ok(inner),
+                    Some((token, column)) => Err(ExprError::Unexpected { found: token.to_string(), column: *column }),
+                    None => Err(ExprError::UnexpectedEnd),
+                }
+            }
+            token => Err(ExprError::Unexpected { found: token.to_string(), column }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn eval(text: &str, fields: &[(&str, f64)]) -> Option<f64> {
+        let fields: HashMap<&str, f64> = fields.iter().copied().collect();
+        Expr::parse(text).unwrap().eval(&|key| fields.get(key).copied())
+    }
+
+    #[test]
+    fn arithmetic_follows_precedence() {
+        assert_eq!(eval("1 + 2 * 3", &[]), Some(7.0));
+        assert_eq!(eval("(1 + 2) * 3", &[]), Some(9.0));
+        assert_eq!(eval("10 - 4 - 3", &[]), Some(3.0));
+        assert_eq!(eval("-2 * -3", &[]), Some(6.0));
+        assert_eq!(eval("1.5 / .5", &[]), Some(3.0));
+    }
+
+    #[test]
+    fn fields_come_from_the_projection() {
+        let pitcher = [("k", 200.0), ("bb", 50.0), ("ip", 180.0)];
+        let kbb = eval("(k - bb) / ip", &pitcher).unwrap();
+        assert!((kbb - 150.0 / 180.0).abs() < 1e-12);
+        assert_eq!(eval("(k - bb) / ip", &[("hr", 30.0)]), None, "missing fields");
+        assert_eq!(eval("k / ip", &[("k", 5.0), ("ip", 0.0)]), None, "division by zero");
+    }
+
+    #[test]
+    fn definitions_need_a_name_and_a_valid_expression() {
+        let stat = CustomStat::parse(" K-BB% = (k - bb) / ip ").unwrap();
+        assert_eq!(stat.name, "K-BB%");
+        assert_eq!(
+            stat.expr,
+            Expr::Binary(
+                Op::Div,
+                Box::new(Expr::Binary(Op::Sub, Box::new(Expr::Field("k".into())), Box::new(Expr::Field("bb".into())))),
+                Box::new(Expr::Field("ip".into())),
+            )
+        );
+
+        assert_eq!(CustomStat::parse("(k - bb) / ip"), Err(ExprError::MissingName));
+        assert_eq!(CustomStat::parse(" = k"), Err(ExprError::MissingName));
+        assert_eq!(
+            CustomStat::parse("kbb = (k - bb) /"),
+            Err(ExprError::UnexpectedEnd)
+        );
+        assert_eq!(
+            CustomStat::parse("kbb = k bb").unwrap_err().to_string(),
+            "unexpected `bb` at column 4"
+        );
+        assert_eq!(
+            CustomStat::parse("kbb = k % bb").unwrap_err().to_string(),
+            "unexpected `%` at column 4"
+        );
+        assert_eq!(CustomStat::parse("kbb = (k - bb").unwrap_err(), ExprError::UnexpectedEnd);
+    }
+}
@@ -0,0 +1,250 @@
+// Decision journal: the user's bid intent for the player on the block,
+// logged one keypress at a time while the nomination clock runs.
+//
+// Each quick-bid key resolves to a `JournalEntry` with an absolute dollar
+// amount, so replaying a nomination's entries in order reproduces the
+// `BidIntent` the banner showed at the time. Entries are stored in the
+// `decision_journal` table and, like crash reports, survive
+// `clear_all_drafts` so plan-vs-actual can be reviewed after the draft.
+
+use serde::{Deserialize, Serialize};
+
+/// A single-key quick bid pressed during an active nomination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuickBid {
+    /// "I'm in": willing to bid at least the next dollar.
+    In,
+    /// "I'm out": not bidding on this player.
+    Out,
+    /// Raise my intended bid by this many dollars.
+    Raise(u32),
+    /// Set my cap (the most I will pay).
+    Cap,
+}
+
+/// What a journal entry recorded. Stored as text in `decision_journal.action`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JournalAction {
+    In,
+    Out,
+    Raise,
+    Cap,
+}
+
+impl JournalAction {
+    /// The string stored in the database.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JournalAction::In => "in",
+            JournalAction::Out => "out",
+            JournalAction::Raise => "raise",
+            JournalAction::Cap => "cap",
+        }
+    }
+
+    /// Parse the string stored in the database.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "in" => Some(JournalAction::In),
+            "out" => Some(JournalAction::Out),
+            "raise" => Some(JournalAction::Raise),
+            "cap" => Some(JournalAction::Cap),
+            _ => None,
+        }
+    }
+}
+
+/// One row of the decision journal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Row ID (0 for an entry that has not been persisted yet).
+    pub id: i64,
+    /// UTC timestamp of the keypress (RFC 3339).
+    pub recorded_at: String,
+    /// Draft session the entry belongs to.
+    pub draft_id: String,
+    /// The nominated player the intent applies to.
+    pub player_name: String,
+    pub action: JournalAction,
+    /// Resolved dollar amount: my bid for `In`/`Raise`, my cap for `Cap`,
+    /// `None` for `Out`.
+    pub amount: Option<u32>,
+    /// Highest bid on the player when the key was pressed.
+    pub current_bid: u32,
+}
+
+/// My plan for the player currently on the block, as shown in the banner.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BidIntent {
+    pub player_name: String,
+    /// `Some(true)` once I'm in, `Some(false)` once I'm out.
+    pub interested: Option<bool>,
+    /// The bid I'm in at.
+    pub bid: Option<u32>,
+    /// The most I will pay.
+    pub cap: Option<u32>,
+}
+
+impl BidIntent {
+    /// A fresh, undecided intent for `player_name`.
+    pub fn new(player_name: &str) -> Self {
+        Self {
+            player_name: player_name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Resolve a quick-bid key against the current auction state into the
+    /// action and absolute dollar amount to journal.
+    ///
+    /// `suggested_cap` is used by [`QuickBid::Cap`] (typically the player's
+    /// inflation-adjusted value); without one the cap is my current bid.
+    pub fn resolve(
+        &self,
+        key: QuickBid,
+        current_bid: u32,
+        suggested_cap: Option<u32>,
+    ) -> (JournalAction, Option<u32>) {
+        let next = current_bid + 1;
+        let held = self.bid.filter(|_| self.interested == Some(true));
+        match key {
+            QuickBid::In => (JournalAction::In, Some(held.unwrap_or(0).max(next))),
+            QuickBid::Out => (JournalAction::Out, None),
+            QuickBid::Raise(by) => (
+                JournalAction::Raise,
+                Some(held.unwrap_or(current_bid).max(current_bid) + by),
+            ),
+            QuickBid::Cap => (
+                JournalAction::Cap,
+                Some(suggested_cap.or(held).unwrap_or(next).max(1)),
+            ),
+        }
+    }
+
+    /// Apply a resolved journal action.
+    pub fn apply(&mut self, action: JournalAction, amount: Option<u32>) {
+        match action {
+            JournalAction::In | JournalAction::Raise => {
+                self.interested = Some(true);
+                self.bid = amount;
+            }
+            JournalAction::Out => {
+                self.interested = Some(false);
+                self.bid = None;
+            }
+            JournalAction::Cap => self.cap = amount,
+        }
+    }
+
+    /// Banner text, e.g. `You: in at $18, cap $24`. `None` while nothing
+    /// has been logged.
+    pub fn summary(&self) -> Option<String> {
+        let cap = self.cap.map(|c| format!("cap ${c}"));
+        let stance = match (self.interested, self.bid) {
+            (Some(false), _) => return Some("You: out".to_string()),
+            (Some(true), Some(bid)) => Some(format!("in at ${bid}")),
+            (Some(true), None) => Some("in".to_string()),
+            (None, _) => None,
+        };
+        let parts: Vec<String> = stance.into_iter().chain(cap).collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(format!("You: {}", parts.join(", ")))
+        }
+    }
+
+    /// Whether the current bid has passed my cap.
+    pub fn over_cap(&self, current_bid: u32) -> bool {
+        self.cap.is_some_and(|cap| current_bid > cap)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(intent: &mut BidIntent, key: QuickBid, current_bid: u32, cap: Option<u32>) {
+        let (action, amount) = intent.resolve(key, current_bid, cap);
+        intent.apply(action, amount);
+    }
+
+    #[test]
+    fn in_then_cap_reads_like_the_banner() {
+        let mut intent = BidIntent::new("Juan Soto");
+        assert_eq!(intent.summary(), None);
+
+        press(&mut intent, QuickBid::In, 17, None);
+        press(&mut intent, QuickBid::Cap, 17, Some(24));
+        assert_eq!(intent.summary().as_deref(), Some("You: in at $18, cap $24"));
+    }
+
+    #[test]
+    fn raise_builds_on_held_bid_or_current_bid() {
+        let mut intent = BidIntent::new("Juan Soto");
+        press(&mut intent, QuickBid::Raise(1), 10, None);
+        assert_eq!(intent.bid, Some(11));
+        press(&mut intent, QuickBid::Raise(5), 10, None);
+        assert_eq!(intent.bid, Some(16));
+        // The auction passed my bid: raising starts from the current bid.
+        press(&mut intent, QuickBid::Raise(1), 20, None);
+        assert_eq!(intent.bid, Some(21));
+    }
+
+    #[test]
+    fn in_keeps_a_higher_held_bid() {
+        let mut intent = BidIntent::new("Juan Soto");
+        press(&mut intent, QuickBid::Raise(5), 10, None);
+        press(&mut intent, QuickBid::In, 12, None);
+        assert_eq!(intent.bid, Some(15));
+    }
+
+    #[test]
+    fn out_clears_bid_but_keeps_cap() {
+        let mut intent = BidIntent::new("Juan Soto");
+        press(&mut intent, QuickBid::In, 5, None);
+        press(&mut intent, QuickBid::Cap, 5, Some(12));
+        press(&mut intent, QuickBid::Out, 9, None);
+        assert_eq!(intent.bid, None);
+        assert_eq!(intent.cap, Some(12));
+        assert_eq!(intent.summary().as_deref(), Some("You: out"));
+    }
+
+    #[test]
+    fn cap_without_suggestion_uses_held_bid() {
+        let mut intent = BidIntent::new("Juan Soto");
+        press(&mut intent, QuickBid::Cap, 7, None);
+        assert_eq!(intent.cap, Some(8));
+        assert_eq!(intent.summary().as_deref(), Some("You: cap $8"));
+
+        press(&mut intent, QuickBid::Raise(5), 7, None);
+        press(&mut intent, QuickBid::Cap, 7, None);
+        assert_eq!(intent.cap, Some(12));
+    }
+
+    #[test]
+    fn over_cap_only_when_bid_exceeds_cap() {
+        let mut intent = BidIntent::new("Juan Soto");
+        assert!(!intent.over_cap(50));
+        intent.cap = Some(24);
+        assert!(!intent.over_cap(24));
+        assert!(intent.over_cap(25));
+    }
+
+    #[test]
+    fn action_round_trips_through_str() {
+        for action in [
+            JournalAction::In,
+            JournalAction::Out,
+            JournalAction::Raise,
+            JournalAction::Cap,
+        ] {
+            assert_eq!(JournalAction::parse(action.as_str()), Some(action));
+        }
+        assert_eq!(JournalAction::parse("bogus"), None);
+    }
+}
@@ -1,12 +1,18 @@
 // wyncast-core: shared infrastructure (no application logic).
 
 pub mod app_dirs;
+pub mod backup;
 pub mod config;
+pub mod crash;
 pub mod db;
 pub mod espn;
+pub mod expr;
+pub mod journal;
 pub mod llm;
+pub mod logging;
 pub mod migrations;
 pub mod nomination;
 pub mod picks;
+pub mod stamp;
 pub mod stats;
 pub mod ws_server;
@@ -0,0 +1,261 @@
+// Log file destinations: directory resolution, per-session file naming,
+// size-based rotation, and retention pruning.
+//
+// The tracing subscriber itself is built by each binary (TUI / GUI); this
+// module only decides *where* bytes go so both front-ends share the same
+// on-disk layout.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::config::LoggingConfig;
+
+/// Resolve the log directory from config, creating it if necessary.
+///
+/// Falls back to `<app_data_dir>/logs` when `logging.dir` is unset.
+pub fn resolve_log_dir(config: &LoggingConfig) -> io::Result<PathBuf> {
+    match &config.dir {
+        Some(dir) => {
+            let dir = PathBuf::from(dir);
+            std::fs::create_dir_all(&dir)?;
+            Ok(dir)
+        }
+        None => Ok(crate::app_dirs::log_dir()),
+    }
+}
+
+/// Path of the log file for this run.
+///
+/// With `per_session` the file is `<base_name>-YYYYmmdd-HHMMSS.log`, so
+/// every launch gets its own file and sorting by name sorts by start time.
+/// Otherwise the fixed `<base_name>.log` is reused (appended to).
+pub fn session_log_path(dir: &Path, base_name: &str, per_session: bool) -> PathBuf {
+    if per_session {
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        dir.join(format!("{base_name}-{stamp}.log"))
+    } else {
+        dir.join(format!("{base_name}.log"))
+    }
+}
+
+/// Delete all but the newest `keep` per-session log files for `base_name`
+/// (including their rotated backups). Returns the number of files removed.
+pub fn prune_session_logs(dir: &Path, base_name: &str, keep: usize) -> io::Result<usize> {
+    let prefix = format!("{base_name}-");
+    let mut sessions: Vec<String> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.starts_with(&prefix) && name.ends_with(".log"))
+        .collect();
+
+    // Timestamped names sort chronologically; newest first.
+    sessions.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut removed = 0;
+    for stale in sessions.iter().skip(keep) {
+        for entry in std::fs::read_dir(dir)?.filter_map(|e| e.ok()) {
+            let name = entry.file_name();
+            let Some(name) = name.to_str() else { continue };
+            if name == stale || name.starts_with(&format!("{stale}.")) {
+                std::fs::remove_file(entry.path())?;
+                removed += 1;
+            }
+        }
+    }
+    Ok(removed)
+}
+
+/// Open the log file described by `config` for `base_name`, pruning old
+/// session files first.
+pub fn open_log_writer(config: &LoggingConfig, base_name: &str) -> io::Result<RotatingFileWriter> {
+    let dir = resolve_log_dir(config)?;
+    if config.per_session {
+        // Keep room for the file we're about to create.
+        prune_session_logs(&dir, base_name, config.max_files.saturating_sub(1))?;
+    }
+    let path = session_log_path(&dir, base_name, config.per_session);
+    let max_bytes = (config.max_file_size_mb > 0).then(|| config.max_file_size_mb * 1024 * 1024);
+    RotatingFileWriter::open(path, max_bytes, config.max_files)
+}
+
+/// An append-only file writer that rolls over to numbered backups
+/// (`file.log.1`, `file.log.2`, ...) once the active file exceeds
+/// `max_bytes`. The oldest backup beyond `max_backups` is deleted.
+#[derive(Debug)]
+pub struct RotatingFileWriter {
+    path: PathBuf,
+    file: File,
+    written: u64,
+    max_bytes: Option<u64>,
+    max_backups: usize,
+}
+
+impl RotatingFileWriter {
+    /// Open (or create) `path` in append mode. Existing content is kept.
+    pub fn open(path: PathBuf, max_bytes: Option<u64>, max_backups: usize) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file,
+            written,
+            max_bytes,
+            max_backups,
+        })
+    }
+
+    /// Path of the active log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn backup_path(&self, n: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_os_string();
+        name.push(format!(".{n}"));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_backups == 0 {
+            // No backups retained: just start the file over.
+            self.file = File::create(&self.path)?;
+        } else {
+            let oldest = self.backup_path(self.max_backups);
+            if oldest.exists() {
+                std::fs::remove_file(&oldest)?;
+            }
+            for n in (1..self.max_backups).rev() {
+                let from = self.backup_path(n);
+                if from.exists() {
+                    std::fs::rename(&from, self.backup_path(n + 1))?;
+                }
+            }
+            std::fs::rename(&self.path, self.backup_path(1))?;
+            self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        }
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(max) = self.max_bytes {
+            if self.written > 0 && self.written + buf.len() as u64 > max {
+                self.rotate()?;
+            }
+        }
+        let n = self.file.write(buf)?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn fresh_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn fixed_name_when_not_per_session() {
+        let dir = PathBuf::from("/logs");
+        assert_eq!(
+            session_log_path(&dir, "draft-assistant", false),
+            dir.join("draft-assistant.log")
+        );
+    }
+
+    #[test]
+    fn per_session_name_is_timestamped() {
+        let path = session_log_path(Path::new("/logs"), "draft-assistant", true);
+        let name = path.file_name().unwrap().to_str().unwrap();
+        assert!(name.starts_with("draft-assistant-"));
+        assert!(name.ends_with(".log"));
+        // "draft-assistant-" + "YYYYmmdd-HHMMSS" + ".log"
+        assert_eq!(name.len(), "draft-assistant-".len() + 15 + ".log".len());
+    }
+
+    #[test]
+    fn reopening_appends_instead_of_truncating() {
+        let dir = fresh_dir("logging_test_append");
+        let path = dir.join("app.log");
+
+        let mut w = RotatingFileWriter::open(path.clone(), None, 3).unwrap();
+        w.write_all(b"first\n").unwrap();
+        drop(w);
+        let mut w = RotatingFileWriter::open(path.clone(), None, 3).unwrap();
+        w.write_all(b"second\n").unwrap();
+        drop(w);
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rotates_when_size_exceeded_and_caps_backups() {
+        let dir = fresh_dir("logging_test_rotate");
+        let path = dir.join("app.log");
+
+        let mut w = RotatingFileWriter::open(path.clone(), Some(10), 2).unwrap();
+        for line in ["aaaaaaaa\n", "bbbbbbbb\n", "cccccccc\n", "dddddddd\n"] {
+            w.write_all(line.as_bytes()).unwrap();
+        }
+        w.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "dddddddd\n");
+        assert_eq!(fs::read_to_string(dir.join("app.log.1")).unwrap(), "cccccccc\n");
+        assert_eq!(fs::read_to_string(dir.join("app.log.2")).unwrap(), "bbbbbbbb\n");
+        assert!(!dir.join("app.log.3").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn prune_keeps_newest_sessions_and_their_backups() {
+        let dir = fresh_dir("logging_test_prune");
+        for name in [
+            "app-20260101-000000.log",
+            "app-20260101-000000.log.1",
+            "app-20260102-000000.log",
+            "app-20260103-000000.log",
+            "other.log",
+        ] {
+            fs::write(dir.join(name), "x").unwrap();
+        }
+
+        let removed = prune_session_logs(&dir, "app", 2).unwrap();
+        assert_eq!(removed, 2);
+        assert!(!dir.join("app-20260101-000000.log").exists());
+        assert!(!dir.join("app-20260101-000000.log.1").exists());
+        assert!(dir.join("app-20260102-000000.log").exists());
+        assert!(dir.join("app-20260103-000000.log").exists());
+        assert!(dir.join("other.log").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn open_log_writer_uses_configured_dir() {
+        let dir = fresh_dir("logging_test_open").join("nested");
+        let config = LoggingConfig {
+            dir: Some(dir.to_string_lossy().into_owned()),
+            per_session: false,
+            ..LoggingConfig::default()
+        };
+        let w = open_log_writer(&config, "app").unwrap();
+        assert_eq!(w.path(), dir.join("app.log"));
+        assert!(dir.join("app.log").exists());
+        let _ = fs::remove_dir_all(dir.parent().unwrap());
+    }
+}
@@ -5,7 +5,7 @@
 // runs in its own transaction; a failure leaves the database at the last
 // successfully applied version.
 
-use anyhow::{Context, Result};
+use crate::db::{DbContext, DbError, Result};
 use rusqlite::Connection;
 
 /// A single versioned migration step.
@@ -19,12 +19,56 @@ pub struct Migration {
 }
 
 /// All known migrations, in ascending version order.
-static MIGRATIONS: &[Migration] = &[Migration {
-    version: 1,
-    name: "initial_schema",
-    up: include_str!("../../../migrations/up/V001__initial_schema.up.sql"),
-    down: Some(include_str!("../../../migrations/down/V001__initial_schema.down.sql")),
-}];
+static MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial_schema",
+        up: include_str!("../../../migrations/up/V001__initial_schema.up.sql"),
+        down: Some(include_str!("../../../migrations/down/V001__initial_schema.down.sql")),
+    },
+    Migration {
+        version: 2,
+        name: "crash_reports",
+        up: include_str!("../../../migrations/up/V002__crash_reports.up.sql"),
+        down: Some(include_str!("../../../migrations/down/V002__crash_reports.down.sql")),
+    },
+    Migration {
+        version: 3,
+        name: "decision_journal",
+        up: include_str!("../../../migrations/up/V003__decision_journal.up.sql"),
+        down: Some(include_str!("../../../migrations/down/V003__decision_journal.down.sql")),
+    },
+    Migration {
+        version: 4,
+        name: "keeper_contracts",
+        up: include_str!("../../../migrations/up/V004__keeper_contracts.up.sql"),
+        down: Some(include_str!("../../../migrations/down/V004__keeper_contracts.down.sql")),
+    },
+    Migration {
+        version: 5,
+        name: "nominations",
+        up: include_str!("../../../migrations/up/V005__nominations.up.sql"),
+        down: Some(include_str!("../../../migrations/down/V005__nominations.down.sql")),
+    },
+    Migration {
+        version: 6,
+        name: "pick_verdicts",
+        up: include_str!("../../../migrations/up/V006__pick_verdicts.up.sql"),
+        down: Some(include_str!("../../../migrations/down/V006__pick_verdicts.down.sql")),
+    },
+    Migration {
+        version: 7,
+        name: "session_stamps",
+        up: include_str!("../../../migrations/up/V007__session_stamps.up.sql"),
+        down: Some(include_str!("../../../migrations/down/V007__session_stamps.down.sql")),
+    },
+    Migration {
+        version: 8,
+        name: "session_settings",
+        up: include_str!("../../../migrations/up/V008__session_settings.up.sql"),
+        down: Some(include_str!("../../../migrations/down/V008__session_settings.down.sql")),
+    },
+];
 
 /// Drives schema migrations for the SQLite database.
 pub struct MigrationRunner;
@@ -120,12 +164,9 @@ impl MigrationRunner {
                 continue;
             }
 
-            let down_sql = migration.down.ok_or_else(|| {
-                anyhow::anyhow!(
-                    "migration v{} '{}' is irreversible (no down SQL)",
-                    migration.version,
-                    migration.name
-                )
+            let down_sql = migration.down.ok_or(DbError::Irreversible {
+                version: migration.version,
+                name: migration.name,
             })?;
 
             // `unchecked_transaction` is used because `rollback_to` takes `&Connection`
@@ -187,7 +228,7 @@ mod tests {
     fn fresh_db_runs_all_migrations() {
         let conn = in_memory();
         MigrationRunner::run_pending(&conn).expect("run_pending");
-        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 1);
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 8);
     }
 
     #[test]
@@ -195,7 +236,7 @@ mod tests {
         let conn = in_memory();
         MigrationRunner::run_pending(&conn).expect("first run");
         MigrationRunner::run_pending(&conn).expect("second run");
-        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 1);
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 8);
     }
 
     #[test]
@@ -216,7 +257,42 @@ mod tests {
     fn rollback_removes_migration() {
         let conn = in_memory();
         MigrationRunner::run_pending(&conn).expect("run_pending");
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 8);
+
+        MigrationRunner::rollback_to(&conn, 7).expect("rollback_to 7");
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 7);
+        assert!(conn.prepare("SELECT settings FROM session_stamps LIMIT 0").is_err());
+        assert!(conn.prepare("SELECT weights FROM session_stamps LIMIT 0").is_ok());
+
+        MigrationRunner::rollback_to(&conn, 6).expect("rollback_to 6");
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 6);
+        assert!(conn.prepare("SELECT id FROM session_stamps LIMIT 0").is_err());
+        assert!(conn.prepare("SELECT player_name FROM pick_verdicts LIMIT 0").is_ok());
+
+        MigrationRunner::rollback_to(&conn, 5).expect("rollback_to 5");
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 5);
+        assert!(conn.prepare("SELECT player_name FROM pick_verdicts LIMIT 0").is_err());
+        assert!(conn.prepare("SELECT player_name FROM nominations LIMIT 0").is_ok());
+
+        MigrationRunner::rollback_to(&conn, 4).expect("rollback_to 4");
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 4);
+        assert!(conn.prepare("SELECT player_name FROM nominations LIMIT 0").is_err());
+        assert!(conn.prepare("SELECT player_name FROM keeper_contracts LIMIT 0").is_ok());
+
+        MigrationRunner::rollback_to(&conn, 3).expect("rollback_to 3");
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 3);
+        assert!(conn.prepare("SELECT player_name FROM keeper_contracts LIMIT 0").is_err());
+        assert!(conn.prepare("SELECT id FROM decision_journal LIMIT 0").is_ok());
+
+        MigrationRunner::rollback_to(&conn, 2).expect("rollback_to 2");
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 2);
+        assert!(conn.prepare("SELECT id FROM decision_journal LIMIT 0").is_err());
+        assert!(conn.prepare("SELECT id FROM crash_reports LIMIT 0").is_ok());
+
+        MigrationRunner::rollback_to(&conn, 1).expect("rollback_to 1");
         assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 1);
+        assert!(conn.prepare("SELECT id FROM crash_reports LIMIT 0").is_err());
+        assert!(conn.prepare("SELECT id FROM players LIMIT 0").is_ok());
 
         MigrationRunner::rollback_to(&conn, 0).expect("rollback_to 0");
         assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 0);
@@ -234,11 +310,11 @@ mod tests {
         let conn = in_memory();
         MigrationRunner::run_pending(&conn).expect("run_pending");
 
-        // Roll back from v1 to v0, then back to v0 again — second call is a no-op.
+        // Roll back to v0, then back to v0 again — second call is a no-op.
         MigrationRunner::rollback_to(&conn, 0).expect("rollback_to 0 first time");
         assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 0);
 
-        // v1 is no longer applied; rollback_to should silently skip it.
+        // Nothing is applied any more; rollback_to should silently skip it.
         MigrationRunner::rollback_to(&conn, 0).expect("rollback_to 0 second time (no-op)");
         assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 0);
     }
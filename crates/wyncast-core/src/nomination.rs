@@ -15,3 +15,14 @@ pub struct NominationInfo {
     pub time_remaining: Option<u32>,
     pub eligible_slots: Vec<u16>,
 }
+
+/// The engine's last verdict on a nominated player, stored with the pick
+/// once the player is sold so the draft recap can grade the model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NominationVerdict {
+    pub player_name: String,
+    /// The verdict label, e.g. "STRONG TARGET".
+    pub verdict: String,
+    /// The most the model said to bid.
+    pub max_bid: u32,
+}
@@ -0,0 +1,298 @@
+// Session stamp: which model and inputs produced a draft session's advice.
+//
+// At session start the app version, a hash of the config, a hash of every
+// configured input file and the category weights are recorded in the
+// `session_stamps` table, and the same stamp heads every export and report,
+// so a draft reviewed months later can be traced to the exact build and
+// files behind it. Like crash reports and the decision journal, stamps
+// survive `clear_all_drafts`.
+//
+// Hashes are 64-bit FNV-1a, which (unlike `DefaultHasher`) hash the same in
+// every build. The config hash covers the settings that shape valuations and
+// advice; credentials are never part of it.
+//
+// The strategy settings themselves are kept too, flattened to one value per
+// setting, so the recap can list what changed since the previous draft.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher`, it hashes the same in every build,
+/// which a hash stored on disk needs.
+pub struct Fnv(pub u64);
+
+impl Fnv {
+    pub fn new() -> Self {
+        Fnv(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Write for Fnv {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// The FNV-1a hash of `bytes`, as 16 hex digits.
+pub fn hash_hex(bytes: &[u8]) -> String {
+    let mut hash = Fnv::new();
+    hash.write(bytes);
+    format!("{:016x}", hash.0)
+}
+
+/// A configured input file and the hash of its contents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputFile {
+    /// The `[data_paths]` entry it was configured as, e.g. "hitters".
+    pub label: String,
+    pub path: String,
+    /// `None` if the file could not be read.
+    pub hash: Option<String>,
+}
+
+/// The model version and inputs behind a draft session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionStamp {
+    /// UTC timestamp of the session start (RFC 3339).
+    pub recorded_at: String,
+    pub draft_id: String,
+    pub app_version: String,
+    pub config_hash: String,
+    /// Projection CSVs, adjustments, prep sheet, nomination script and
+    /// valuation scripts, as configured. Empty projections mean they came
+    /// from ESPN.
+    pub inputs: Vec<InputFile>,
+    /// Category weights, by category.
+    pub weights: BTreeMap<String, f64>,
+    /// The strategy settings, flattened: "valuation.inflation_smoothing" to
+    /// "0.3". Empty for stamps recorded before settings were kept.
+    #[serde(default)]
+    pub settings: BTreeMap<String, String>,
+}
+
+/// A strategy setting that differs between two sessions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingChange {
+    pub key: String,
+    /// `None` when the setting was not there.
+    pub previous: Option<String>,
+    pub current: Option<String>,
+}
+
+impl SessionStamp {
+    /// Stamp a session of `draft_id` started now with `config`, reading each
+    /// configured input file to hash it.
+    pub fn new(draft_id: &str, config: &Config) -> Self {
+        let paths = &config.data_paths;
+        let files = [
+            ("hitters", &paths.hitters),
+            ("pitchers", &paths.pitchers),
+            ("adjustments", &paths.adjustments),
+            ("prep", &paths.prep),
+            ("nomination_queue", &paths.nomination_queue),
+        ];
+        let inputs = files
+            .into_iter()
+            .filter_map(|(label, path)| Some((label, path.as_ref()?)))
+            .chain(paths.scripts.iter().map(|path| ("script", path)))
+            .map(|(label, path)| InputFile {
+                label: label.to_string(),
+                path: path.clone(),
+                hash: std::fs::read(path).ok().map(|bytes| hash_hex(&bytes)),
+            })
+            .collect();
+
+        SessionStamp {
+            recorded_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            draft_id: draft_id.to_string(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            config_hash: config_hash(config),
+            inputs,
+            weights: config.strategy.weights.0.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            settings: strategy_settings(config),
+        }
+    }
+
+    /// The strategy settings that differ from `previous`'s, by key.
+    pub fn settings_changes(&self, previous: &SessionStamp) -> Vec<SettingChange> {
+        let keys: std::collections::BTreeSet<&String> = self.settings.keys().chain(previous.settings.keys()).collect();
+        keys.into_iter()
+            .filter_map(|key| {
+                let before = previous.settings.get(key);
+                let after = self.settings.get(key);
+                (before != after).then(|| SettingChange {
+                    key: key.clone(),
+                    previous: before.cloned(),
+                    current: after.cloned(),
+                })
+            })
+            .collect()
+    }
+
+    /// One line naming the version and inputs, for the head of a report.
+    pub fn summary(&self) -> String {
+        let mut parts = vec![
+            format!("wyncast {}", self.app_version),
+            format!("config {}", self.config_hash),
+        ];
+        if !self.inputs.iter().any(|f| f.label == "hitters" || f.label == "pitchers") {
+            parts.push("projections from ESPN".to_string());
+        }
+        for input in &self.inputs {
+            let file = std::path::Path::new(&input.path)
+                .file_name()
+                .map_or(input.path.clone(), |name| name.to_string_lossy().into_owned());
+            parts.push(format!("{} {}", file, input.hash.as_deref().unwrap_or("unreadable")));
+        }
+        if !self.weights.is_empty() {
+            let weights: Vec<String> = self.weights.iter().map(|(k, v)| format!("{k}={v}")).collect();
+            parts.push(format!("weights {}", weights.join(" ")));
+        }
+        parts.join(", ")
+    }
+}
+
+/// The strategy settings, flattened to dotted keys.
+fn strategy_settings(config: &Config) -> BTreeMap<String, String> {
+    let strategy = &config.strategy;
+    let settings = serde_json::json!({
+        "hitting_budget_fraction": strategy.hitting_budget_fraction,
+        "adaptive_budget_split": strategy.adaptive_budget_split,
+        "weights": strategy.weights,
+        "pool": strategy.pool,
+        "flexibility": strategy.flexibility,
+        "valuation": strategy.valuation,
+        "llm_provider": strategy.llm.provider,
+        "llm_model": strategy.llm.model,
+    });
+    let mut flat = BTreeMap::new();
+    flatten("", &settings, &mut flat);
+    flat
+}
+
+fn flatten(prefix: &str, value: &serde_json::Value, out: &mut BTreeMap<String, String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+                flatten(&key, value, out);
+            }
+        }
+        // An unset option is left out, like a setting that is not there.
+        serde_json::Value::Null => {}
+        serde_json::Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
+/// Hash of the settings that shape valuations and advice.
+fn config_hash(config: &Config) -> String {
+    let strategy = &config.strategy;
+    // JSON objects keep their keys sorted, so the HashMaps among the
+    // settings (weights, roster) come out the same on every run.
+    let settings = serde_json::json!({
+        "league": config.league,
+        "hitting_budget_fraction": strategy.hitting_budget_fraction,
+        "adaptive_budget_split": strategy.adaptive_budget_split,
+        "weights": strategy.weights,
+        "pool": strategy.pool,
+        "flexibility": strategy.flexibility,
+        "valuation": strategy.valuation,
+        "llm_provider": strategy.llm.provider,
+        "llm_model": strategy.llm.model,
+        "strategy_overview": strategy.strategy_overview,
+        "data_paths": config.data_paths,
+        "display": config.display,
+    });
+    hash_hex(settings.to_string().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_stamp_follows_the_config_and_input_files() {
+        let dir = std::env::temp_dir().join(format!("wyncast-stamp-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let hitters = dir.join("hitters.csv");
+        std::fs::write(&hitters, "Name,HR\nSlugger,40\n").unwrap();
+
+        let mut config = Config::default();
+        config.data_paths.hitters = Some(hitters.to_string_lossy().into_owned());
+        config.data_paths.prep = Some(dir.join("missing.csv").to_string_lossy().into_owned());
+        config.strategy.weights.0.insert("HR".into(), 1.5);
+        let stamp = SessionStamp::new("draft_1", &config);
+        assert_eq!(stamp.app_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(stamp.inputs.len(), 2);
+        assert_eq!(stamp.inputs[0].hash.as_deref(), Some(hash_hex(b"Name,HR\nSlugger,40\n").as_str()));
+        assert_eq!(stamp.inputs[1].hash, None);
+        assert_eq!(stamp.weights["HR"], 1.5);
+        let summary = stamp.summary();
+        assert!(summary.starts_with(&format!("wyncast {}, config {}", stamp.app_version, stamp.config_hash)));
+        assert!(summary.contains("missing.csv unreadable"), "{summary}");
+        assert!(summary.contains("HR=1.5"), "{summary}");
+
+        // Same inputs, same stamp; an edited file or setting changes it.
+        assert_eq!(SessionStamp::new("draft_1", &config).config_hash, stamp.config_hash);
+        std::fs::write(&hitters, "Name,HR\nSlugger,41\n").unwrap();
+        assert_ne!(SessionStamp::new("draft_1", &config).inputs[0].hash, stamp.inputs[0].hash);
+        config.strategy.weights.0.insert("HR".into(), 2.0);
+        assert_ne!(SessionStamp::new("draft_1", &config).config_hash, stamp.config_hash);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn settings_changes_list_what_differs_from_the_previous_session() {
+        let mut config = Config::default();
+        let previous = SessionStamp::new("draft_1", &config);
+        assert!(previous.settings.contains_key("hitting_budget_fraction"));
+        assert!(previous.settings_changes(&previous).is_empty());
+
+        config.strategy.hitting_budget_fraction = 0.7;
+        config.strategy.weights.0.insert("SB".into(), 0.5);
+        let current = SessionStamp::new("draft_2", &config);
+        let changes = current.settings_changes(&previous);
+        assert_eq!(
+            changes,
+            [
+                SettingChange {
+                    key: "hitting_budget_fraction".into(),
+                    previous: previous.settings.get("hitting_budget_fraction").cloned(),
+                    current: Some("0.7".into()),
+                },
+                SettingChange { key: "weights.SB".into(), previous: Some("1.0".into()), current: Some("0.5".into()) },
+            ]
+        );
+    }
+
+    #[test]
+    fn espn_projections_are_noted() {
+        let stamp = SessionStamp::new("draft_1", &Config::default());
+        assert!(stamp.inputs.is_empty());
+        assert!(stamp.summary().contains("projections from ESPN"));
+    }
+}
@@ -5,6 +5,8 @@
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+
 use crate::config::LeagueConfig;
 
 // ---------------------------------------------------------------------------
@@ -184,7 +186,7 @@ impl StatRegistry {
 // CategoryValues
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CategoryValues {
     values: Vec<f64>,
 }
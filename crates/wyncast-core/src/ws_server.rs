@@ -1,11 +1,98 @@
 // WebSocket server for communication with the Firefox extension.
 
+use std::future::Future;
+use std::time::{Duration, Instant};
+
 use async_trait::async_trait;
 use futures_util::{SinkExt, StreamExt};
-use tokio::net::TcpListener;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::{CloseFrame, WebSocketConfig};
 use tokio_tungstenite::tungstenite::Message;
-use tracing::{info, warn};
+use tracing::{error, info, warn};
+
+/// How many consecutive ports (starting at the configured one) `serve`
+/// tries before giving up.
+pub const PORT_FALLBACK_ATTEMPTS: u16 = 10;
+
+/// Path of the HTTP discovery endpoint served on the WebSocket port. The
+/// extension probes it across the fallback range to find the active port.
+pub const DISCOVERY_PATH: &str = "/wyncast";
+
+/// How long to wait for a new connection's first bytes when deciding
+/// whether it is a discovery request.
+const DISCOVERY_PEEK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Largest message (and frame) the server reads. The extension's biggest
+/// payload, a full state sync, is well under this; the socket stops reading
+/// a bigger one as soon as it passes the limit, without buffering the rest,
+/// and closes the connection with code 1009 (Message Too Big).
+pub const MAX_MESSAGE_BYTES: usize = 512 * 1024;
+
+/// How long to keep discarding the rest of an oversized message after
+/// closing: until the client goes quiet for `OVERSIZED_DRAIN_IDLE`, or at
+/// most `OVERSIZED_DRAIN_TIMEOUT`.
+const OVERSIZED_DRAIN_IDLE: Duration = Duration::from_millis(100);
+const OVERSIZED_DRAIN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Most text messages forwarded per second. The extension debounces its
+/// updates, so only a misbehaving build comes near this; the excess is
+/// dropped and logged.
+pub const MAX_MESSAGES_PER_SECOND: u32 = 50;
+
+/// Counts a connection's messages in one-second windows and turns away the
+/// ones over [`MAX_MESSAGES_PER_SECOND`].
+struct RateLimiter {
+    window_start: Instant,
+    admitted: u32,
+    dropped: u32,
+}
+
+impl RateLimiter {
+    fn new(now: Instant) -> Self {
+        Self {
+            window_start: now,
+            admitted: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Whether a message arriving at `now` may be forwarded. Starting a new
+    /// window logs how many the last one dropped.
+    fn admit(&mut self, now: Instant, addr_str: &str) -> bool {
+        if now.duration_since(self.window_start) >= Duration::from_secs(1) {
+            if self.dropped > 0 {
+                warn!("Dropped {} messages from {addr_str} over the rate limit", self.dropped);
+            }
+            *self = Self::new(now);
+        }
+        if self.admitted < MAX_MESSAGES_PER_SECOND {
+            self.admitted += 1;
+            true
+        } else {
+            if self.dropped == 0 {
+                warn!("Rate limiting {addr_str}: over {MAX_MESSAGES_PER_SECOND} messages per second");
+            }
+            self.dropped += 1;
+            false
+        }
+    }
+}
+
+/// Errors binding the server or accepting connections.
+#[derive(Debug, thiserror::Error)]
+pub enum WsError {
+    #[error("port {port} unavailable: {source}")]
+    Bind { port: u16, source: std::io::Error },
+
+    #[error("no free port in 127.0.0.1:{first}-{last}")]
+    NoFreePort { first: u16, last: u16 },
+
+    #[error("failed to accept a connection: {0}")]
+    Accept(std::io::Error),
+}
 
 /// Events emitted by the WebSocket server to the application layer.
 #[derive(Debug, PartialEq)]
@@ -16,6 +103,12 @@ pub enum WsEvent {
     Disconnected,
     /// A text message was received from the client (raw JSON string).
     Message(String),
+    /// The server is listening. `port` differs from `requested_port` when
+    /// the configured port was taken and a fallback port was used.
+    Listening { port: u16, requested_port: u16 },
+    /// No port in the fallback range could be bound; the server is not
+    /// running.
+    BindFailed { requested_port: u16, error: String },
 }
 
 /// A connection that can be read for WebSocket messages.
@@ -25,6 +118,8 @@ pub trait WsConnection: Send {
     async fn next_message(&mut self) -> Option<Result<Message, String>>;
     /// Send a text message to the connected client.
     async fn send_message(&mut self, text: String) -> Result<(), String>;
+    /// Send a close frame telling the client the server is going away.
+    async fn close(&mut self) -> Result<(), String>;
 }
 
 /// A listener that accepts incoming WebSocket connections.
@@ -35,7 +130,42 @@ pub trait WsListener: Send {
 
     /// Wait for and accept the next client connection. Returns the connection
     /// and a human-readable address string.
-    async fn accept(&mut self) -> anyhow::Result<(Self::Connection, String)>;
+    async fn accept(&mut self) -> Result<(Self::Connection, String), WsError>;
+}
+
+/// Bind the server on the configured port (falling back to the next
+/// [`PORT_FALLBACK_ATTEMPTS`] - 1 ports if it is taken), report the outcome
+/// through `tx` as [`WsEvent::Listening`] or [`WsEvent::BindFailed`], and
+/// run until `shutdown` resolves.
+pub async fn serve(
+    port: u16,
+    tx: mpsc::Sender<WsEvent>,
+    outbound_rx: mpsc::Receiver<String>,
+    shutdown: impl Future<Output = ()> + Send,
+) {
+    match TungsteniteListener::bind_with_fallback(port, PORT_FALLBACK_ATTEMPTS).await {
+        Ok(listener) => {
+            let event = WsEvent::Listening {
+                port: listener.port(),
+                requested_port: port,
+            };
+            if tx.send(event).await.is_err() {
+                return;
+            }
+            if let Err(e) = run_until_shutdown(listener, tx, outbound_rx, shutdown).await {
+                error!("WebSocket server error: {e}");
+            }
+        }
+        Err(e) => {
+            error!("Failed to bind WebSocket server: {e}");
+            let _ = tx
+                .send(WsEvent::BindFailed {
+                    requested_port: port,
+                    error: e.to_string(),
+                })
+                .await;
+        }
+    }
 }
 
 /// Run the WebSocket server using the provided listener, forwarding events
@@ -45,12 +175,31 @@ pub trait WsListener: Send {
 /// and forwards them as [`WsEvent::Message`]. The server runs forever (until
 /// the task is cancelled, the channel is closed, or an accept error occurs).
 pub async fn run<L: WsListener>(
+    listener: L,
+    tx: mpsc::Sender<WsEvent>,
+    outbound_rx: mpsc::Receiver<String>,
+) -> Result<(), WsError> {
+    run_until_shutdown(listener, tx, outbound_rx, std::future::pending()).await
+}
+
+/// Like [`run`], but returns once `shutdown` resolves. A connected client is
+/// sent a close frame first, as it is when the outbound channel closes, so
+/// the extension sees a clean "going away" rather than a dropped socket.
+pub async fn run_until_shutdown<L: WsListener>(
     mut listener: L,
     tx: mpsc::Sender<WsEvent>,
     mut outbound_rx: mpsc::Receiver<String>,
-) -> anyhow::Result<()> {
+    shutdown: impl Future<Output = ()> + Send,
+) -> Result<(), WsError> {
+    tokio::pin!(shutdown);
     loop {
-        let (mut conn, addr_str) = listener.accept().await?;
+        let (mut conn, addr_str) = tokio::select! {
+            accepted = listener.accept() => accepted?,
+            _ = &mut shutdown => {
+                info!("WebSocket server shutting down");
+                return Ok(());
+            }
+        };
         info!("Accepted connection from {addr_str}");
 
         if tx
@@ -65,10 +214,12 @@ pub async fn run<L: WsListener>(
 
         // Read messages and process outbound messages concurrently.
         // The loop ends when the connection closes or errors.
+        let mut limiter = RateLimiter::new(Instant::now());
         loop {
             tokio::select! {
                 msg_result = conn.next_message() => {
                     match msg_result {
+                        Some(Ok(Message::Text(_))) if !limiter.admit(Instant::now(), &addr_str) => {}
                         Some(Ok(Message::Text(text))) => {
                             if tx.send(WsEvent::Message(text.to_string())).await.is_err() {
                                 return Ok(());
@@ -102,10 +253,16 @@ pub async fn run<L: WsListener>(
                         None => {
                             // Outbound channel closed, server shutting down
                             info!("Outbound channel closed");
+                            close_connection(&mut conn, &addr_str).await;
                             return Ok(());
                         }
                     }
                 }
+                _ = &mut shutdown => {
+                    info!("WebSocket server shutting down");
+                    close_connection(&mut conn, &addr_str).await;
+                    return Ok(());
+                }
             }
         }
 
@@ -117,51 +274,197 @@ pub async fn run<L: WsListener>(
     Ok(())
 }
 
+/// Send a close frame, logging (but otherwise ignoring) failure: the client
+/// may already be gone.
+async fn close_connection<C: WsConnection>(conn: &mut C, addr_str: &str) {
+    if let Err(e) = conn.close().await {
+        warn!("Failed to send close frame to {addr_str}: {e}");
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Production implementation: real TCP + tungstenite
 // ---------------------------------------------------------------------------
 
 /// A real WebSocket connection backed by a TCP stream and tungstenite.
 pub struct TungsteniteConnection {
-    read: futures_util::stream::SplitStream<
-        tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
-    >,
-    write: futures_util::stream::SplitSink<
-        tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
-        Message,
-    >,
+    ws: tokio_tungstenite::WebSocketStream<TcpStream>,
+}
+
+impl TungsteniteConnection {
+    /// Refuse a message over [`MAX_MESSAGE_BYTES`]: send a close frame with
+    /// code 1009 (Message Too Big), so the extension backs off rather than
+    /// reconnecting and resending it at once, then discard what is left of
+    /// the message. Closing with it unread would reset the connection
+    /// before the client reads the close frame.
+    async fn refuse_oversized(&mut self) {
+        let frame = CloseFrame {
+            code: CloseCode::Size,
+            reason: "message too big".into(),
+        };
+        if let Err(e) = self.ws.send(Message::Close(Some(frame))).await {
+            warn!("Failed to send close frame for an oversized message: {e}");
+            return;
+        }
+        let stream = self.ws.get_mut();
+        let mut discard = [0u8; 8192];
+        let drain = async {
+            while let Ok(Ok(n)) = tokio::time::timeout(OVERSIZED_DRAIN_IDLE, stream.read(&mut discard)).await {
+                if n == 0 {
+                    break;
+                }
+            }
+        };
+        let _ = tokio::time::timeout(OVERSIZED_DRAIN_TIMEOUT, drain).await;
+    }
 }
 
 #[async_trait]
 impl WsConnection for TungsteniteConnection {
     async fn next_message(&mut self) -> Option<Result<Message, String>> {
-        self.read
-            .next()
-            .await
-            .map(|r| r.map_err(|e| e.to_string()))
+        let result = self.ws.next().await?;
+        if let Err(tokio_tungstenite::tungstenite::Error::Capacity(_)) = &result {
+            self.refuse_oversized().await;
+        }
+        Some(result.map_err(|e| e.to_string()))
     }
 
     async fn send_message(&mut self, text: String) -> Result<(), String> {
-        self.write
+        self.ws
             .send(Message::Text(text.into()))
             .await
             .map_err(|e| e.to_string())
     }
+
+    async fn close(&mut self) -> Result<(), String> {
+        let frame = CloseFrame {
+            code: CloseCode::Away,
+            reason: "server shutting down".into(),
+        };
+        self.ws
+            .send(Message::Close(Some(frame)))
+            .await
+            .map_err(|e| e.to_string())
+    }
 }
 
 /// A real TCP listener that performs WebSocket handshakes via tungstenite.
+///
+/// Plain `GET /wyncast` requests are answered with [`discovery_body`]
+/// instead of a WebSocket handshake. Like WebSocket clients, they are only
+/// accepted while no client is connected.
+///
+/// Each accepted stream is classified and handshaken in its own task, so a
+/// connection that is slow to send its request doesn't hold up the others.
 pub struct TungsteniteListener {
     listener: TcpListener,
+    port: u16,
+    /// Connections whose WebSocket handshake has completed.
+    handshaken: mpsc::Receiver<(TungsteniteConnection, String)>,
+    handshaken_tx: mpsc::Sender<(TungsteniteConnection, String)>,
 }
 
 impl TungsteniteListener {
     /// Bind a TCP listener on `127.0.0.1:{port}` and return a new
     /// `TungsteniteListener`.
-    pub async fn bind(port: u16) -> anyhow::Result<Self> {
-        let listener = TcpListener::bind(format!("127.0.0.1:{port}")).await?;
-        let local_addr = listener.local_addr()?;
+    pub async fn bind(port: u16) -> Result<Self, WsError> {
+        let bind_error = |source| WsError::Bind { port, source };
+        let listener = TcpListener::bind(format!("127.0.0.1:{port}")).await.map_err(bind_error)?;
+        let local_addr = listener.local_addr().map_err(bind_error)?;
         info!("WebSocket server listening on {local_addr}");
-        Ok(Self { listener })
+        let (handshaken_tx, handshaken) = mpsc::channel(8);
+        Ok(Self {
+            listener,
+            port: local_addr.port(),
+            handshaken,
+            handshaken_tx,
+        })
+    }
+
+    /// Bind the first free port among `port`, `port + 1`, ... (`attempts`
+    /// ports in total).
+    pub async fn bind_with_fallback(port: u16, attempts: u16) -> Result<Self, WsError> {
+        let last = port.saturating_add(attempts.saturating_sub(1));
+        for candidate in port..=last {
+            match Self::bind(candidate).await {
+                Ok(listener) => return Ok(listener),
+                Err(e) => warn!("WebSocket {e}"),
+            }
+        }
+        Err(WsError::NoFreePort { first: port, last })
+    }
+
+    /// The port actually bound.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+/// JSON served at [`DISCOVERY_PATH`], identifying this server and its port.
+pub fn discovery_body(port: u16) -> String {
+    serde_json::json!({
+        "service": "wyncast",
+        "version": env!("CARGO_PKG_VERSION"),
+        "ws_port": port,
+    })
+    .to_string()
+}
+
+/// Whether the first bytes of a connection are a discovery request
+/// (`GET /wyncast ...`) rather than a WebSocket upgrade.
+fn is_discovery_request(head: &[u8]) -> bool {
+    let line = head.split(|&b| b == b'\r' || b == b'\n').next().unwrap_or_default();
+    let mut parts = line.split(|&b| b == b' ');
+    parts.next() == Some(b"GET".as_slice()) && parts.next() == Some(DISCOVERY_PATH.as_bytes())
+}
+
+/// Answer a discovery request and close the connection.
+async fn respond_to_discovery(mut stream: TcpStream, port: u16) -> std::io::Result<()> {
+    // Consume the request so the client sees a clean close, not a reset.
+    let mut request = [0u8; 1024];
+    let _ = stream.read(&mut request).await?;
+
+    let body = discovery_body(port);
+    let response = format!(
+        "HTTP/1.1 200 OK\r\n\
+         Content-Type: application/json\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Answer `stream` if it is a discovery request, otherwise complete its
+/// WebSocket handshake and pass the connection to `handshaken`.
+async fn classify_connection(
+    stream: TcpStream,
+    addr: String,
+    port: u16,
+    handshaken: mpsc::Sender<(TungsteniteConnection, String)>,
+) {
+    let mut head = [0u8; 64];
+    let peeked = tokio::time::timeout(DISCOVERY_PEEK_TIMEOUT, stream.peek(&mut head)).await;
+    if let Ok(Ok(n)) = peeked {
+        if is_discovery_request(&head[..n]) {
+            if let Err(e) = respond_to_discovery(stream, port).await {
+                warn!("Failed to answer discovery request from {addr}: {e}");
+            }
+            return;
+        }
+    }
+
+    let config = WebSocketConfig::default()
+        .max_message_size(Some(MAX_MESSAGE_BYTES))
+        .max_frame_size(Some(MAX_MESSAGE_BYTES));
+    match tokio_tungstenite::accept_async_with_config(stream, Some(config)).await {
+        Ok(ws) => {
+            // The listener is gone if this fails; the connection just closes.
+            let _ = handshaken.send((TungsteniteConnection { ws }, addr)).await;
+        }
+        Err(e) => warn!("WebSocket handshake failed for {addr}: {e}"),
     }
 }
 
@@ -169,19 +472,14 @@ impl TungsteniteListener {
 impl WsListener for TungsteniteListener {
     type Connection = TungsteniteConnection;
 
-    async fn accept(&mut self) -> anyhow::Result<(TungsteniteConnection, String)> {
+    async fn accept(&mut self) -> Result<(TungsteniteConnection, String), WsError> {
         loop {
-            let (stream, addr) = self.listener.accept().await?;
-            let addr_str = addr.to_string();
-
-            match tokio_tungstenite::accept_async(stream).await {
-                Ok(ws) => {
-                    let (write, read) = ws.split();
-                    return Ok((TungsteniteConnection { read, write }, addr_str));
-                }
-                Err(e) => {
-                    warn!("WebSocket handshake failed for {addr_str}: {e}");
-                    continue;
+            tokio::select! {
+                Some(connection) = self.handshaken.recv() => return Ok(connection),
+                accepted = self.listener.accept() => {
+                    let (stream, addr) = accepted.map_err(WsError::Accept)?;
+                    let handshaken = self.handshaken_tx.clone();
+                    tokio::spawn(classify_connection(stream, addr.to_string(), self.port, handshaken));
                 }
             }
         }
@@ -192,7 +490,9 @@ impl WsListener for TungsteniteListener {
 mod tests {
     use super::*;
     use std::collections::VecDeque;
-    use tokio_tungstenite::tungstenite::Error as WsError;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use tokio_tungstenite::tungstenite::Error as TungsteniteError;
 
     // -----------------------------------------------------------------------
     // Mock implementations
@@ -219,6 +519,47 @@ mod tests {
         async fn send_message(&mut self, _text: String) -> Result<(), String> {
             Ok(())
         }
+        async fn close(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    /// A mock connection that never sends anything and records whether the
+    /// server closed it.
+    struct IdleConnection {
+        closed: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl WsConnection for IdleConnection {
+        async fn next_message(&mut self) -> Option<Result<Message, String>> {
+            std::future::pending().await
+        }
+        async fn send_message(&mut self, _text: String) -> Result<(), String> {
+            Ok(())
+        }
+        async fn close(&mut self) -> Result<(), String> {
+            self.closed.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    /// A mock listener that hands out one `IdleConnection` (if any), then
+    /// waits forever for the next client.
+    struct IdleListener {
+        connection: Option<IdleConnection>,
+    }
+
+    #[async_trait]
+    impl WsListener for IdleListener {
+        type Connection = IdleConnection;
+
+        async fn accept(&mut self) -> Result<(IdleConnection, String), WsError> {
+            match self.connection.take() {
+                Some(conn) => Ok((conn, "mock:idle".into())),
+                None => std::future::pending().await,
+            }
+        }
     }
 
     /// A mock listener that yields pre-configured connections, then errors.
@@ -238,10 +579,10 @@ mod tests {
     impl WsListener for MockListener {
         type Connection = MockConnection;
 
-        async fn accept(&mut self) -> anyhow::Result<(MockConnection, String)> {
+        async fn accept(&mut self) -> Result<(MockConnection, String), WsError> {
             self.connections
                 .pop_front()
-                .ok_or_else(|| anyhow::anyhow!("no more mock connections"))
+                .ok_or_else(|| WsError::Accept(std::io::Error::other("no more mock connections")))
         }
     }
 
@@ -334,7 +675,7 @@ mod tests {
         let (_outbound_tx, outbound_rx) = dummy_outbound();
         let conn = MockConnection::new(vec![
             Ok(Message::Text("before_error".into())),
-            Err(WsError::ConnectionClosed.to_string()),
+            Err(TungsteniteError::ConnectionClosed.to_string()),
             Ok(Message::Text("should_not_appear".into())),
         ]);
         let listener = MockListener::new(vec![(conn, "mock:2".into())]);
@@ -371,6 +712,72 @@ mod tests {
         assert_eq!(msg_events[0], &WsEvent::Message("after_ignored".into()));
     }
 
+    #[tokio::test]
+    async fn oversized_messages_are_refused_by_the_socket() {
+        let listener = TungsteniteListener::bind(0).await.unwrap();
+        let port = listener.port();
+        let (tx, mut rx) = mpsc::channel(64);
+        let (_outbound_tx, outbound_rx) = dummy_outbound();
+        let server = tokio::spawn(run(listener, tx, outbound_rx));
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://127.0.0.1:{port}")).await.unwrap();
+        client.send(Message::Text("small".into())).await.unwrap();
+        // The server may hang up before these are all written.
+        let _ = client.send(Message::Text("x".repeat(MAX_MESSAGE_BYTES + 1).into())).await;
+        let _ = client.send(Message::Text("after_oversized".into())).await;
+
+        // The client is told why, so it can back off.
+        let close = loop {
+            match client.next().await {
+                Some(Ok(Message::Close(frame))) => break frame,
+                Some(Ok(_)) => {}
+                other => panic!("Expected a close frame, got {other:?}"),
+            }
+        };
+        assert_eq!(close.map(|f| f.code), Some(CloseCode::Size));
+
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            let done = event == WsEvent::Disconnected;
+            events.push(event);
+            if done {
+                break;
+            }
+        }
+        server.abort();
+        assert_eq!(events[1..], [WsEvent::Message("small".into()), WsEvent::Disconnected]);
+    }
+
+    #[tokio::test]
+    async fn messages_over_the_rate_limit_are_dropped() {
+        let (tx, mut rx) = mpsc::channel(256);
+        let (_outbound_tx, outbound_rx) = dummy_outbound();
+        let flood = (0..MAX_MESSAGES_PER_SECOND + 20)
+            .map(|i| Ok(Message::Text(i.to_string().into())))
+            .collect();
+        let listener = MockListener::new(vec![(MockConnection::new(flood), "mock:8".into())]);
+
+        let _ = run(listener, tx, outbound_rx).await;
+
+        let forwarded = drain_events(&mut rx)
+            .into_iter()
+            .filter(|e| matches!(e, WsEvent::Message(_)))
+            .count();
+        assert_eq!(forwarded, MAX_MESSAGES_PER_SECOND as usize);
+    }
+
+    #[test]
+    fn rate_limit_resets_each_second() {
+        let start = Instant::now();
+        let mut limiter = RateLimiter::new(start);
+        for _ in 0..MAX_MESSAGES_PER_SECOND {
+            assert!(limiter.admit(start, "mock"));
+        }
+        assert!(!limiter.admit(start + Duration::from_millis(999), "mock"));
+        assert!(limiter.admit(start + Duration::from_secs(1), "mock"));
+        assert_eq!(limiter.dropped, 0, "a new window starts clean");
+    }
+
     #[tokio::test]
     async fn server_stops_when_channel_closed() {
         let (tx, rx) = mpsc::channel(64);
@@ -449,4 +856,143 @@ mod tests {
         let events = drain_events(&mut rx);
         assert_eq!(events[1], WsEvent::Message(payload.to_string()));
     }
+
+    #[tokio::test]
+    async fn shutdown_sends_close_frame_to_connected_client() {
+        let (tx, mut rx) = mpsc::channel(64);
+        let (_outbound_tx, outbound_rx) = dummy_outbound();
+        let closed = Arc::new(AtomicBool::new(false));
+        let listener = IdleListener {
+            connection: Some(IdleConnection {
+                closed: closed.clone(),
+            }),
+        };
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+
+        let server = tokio::spawn(run_until_shutdown(listener, tx, outbound_rx, async {
+            let _ = shutdown_rx.await;
+        }));
+        // Wait for the client to connect before shutting down.
+        assert!(matches!(rx.recv().await, Some(WsEvent::Connected { .. })));
+        shutdown_tx.send(()).unwrap();
+
+        assert!(server.await.unwrap().is_ok());
+        assert!(closed.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn shutdown_while_waiting_for_a_client_returns() {
+        let (tx, _rx) = mpsc::channel(64);
+        let (_outbound_tx, outbound_rx) = dummy_outbound();
+        let listener = IdleListener { connection: None };
+
+        let result = run_until_shutdown(listener, tx, outbound_rx, async {}).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn outbound_channel_closed_sends_close_frame() {
+        let (tx, mut rx) = mpsc::channel(64);
+        let (outbound_tx, outbound_rx) = dummy_outbound();
+        let closed = Arc::new(AtomicBool::new(false));
+        let listener = IdleListener {
+            connection: Some(IdleConnection {
+                closed: closed.clone(),
+            }),
+        };
+
+        let server = tokio::spawn(run(listener, tx, outbound_rx));
+        assert!(matches!(rx.recv().await, Some(WsEvent::Connected { .. })));
+        drop(outbound_tx);
+
+        assert!(server.await.unwrap().is_ok());
+        assert!(closed.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn bind_with_fallback_skips_a_taken_port() {
+        let taken = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = taken.local_addr().unwrap().port();
+
+        let listener = TungsteniteListener::bind_with_fallback(port, 5).await.unwrap();
+        assert_ne!(listener.port(), port);
+        assert!(listener.port() > port && listener.port() < port.saturating_add(5));
+
+        assert!(TungsteniteListener::bind_with_fallback(port, 1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn serve_reports_bind_failure() {
+        let taken = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = taken.local_addr().unwrap().port();
+        // Occupy the whole fallback range so every attempt fails.
+        let mut held = Vec::new();
+        for offset in 1..PORT_FALLBACK_ATTEMPTS {
+            if let Ok(l) = TcpListener::bind(format!("127.0.0.1:{}", port.saturating_add(offset))).await {
+                held.push(l);
+            }
+        }
+        if held.len() + 1 < PORT_FALLBACK_ATTEMPTS as usize {
+            // Another process owns part of the range; nothing to assert.
+            return;
+        }
+
+        let (tx, mut rx) = mpsc::channel(64);
+        let (_outbound_tx, outbound_rx) = dummy_outbound();
+        serve(port, tx, outbound_rx, async {}).await;
+
+        match rx.recv().await {
+            Some(WsEvent::BindFailed { requested_port, .. }) => assert_eq!(requested_port, port),
+            other => panic!("expected BindFailed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn discovery_request_is_recognized_by_its_request_line() {
+        assert!(is_discovery_request(b"GET /wyncast HTTP/1.1\r\nHost: localhost\r\n"));
+        assert!(!is_discovery_request(b"GET / HTTP/1.1\r\nUpgrade: websocket\r\n"));
+        assert!(!is_discovery_request(b"GET /wyncastle HTTP/1.1\r\n"));
+        assert!(!is_discovery_request(b"POST /wyncast HTTP/1.1\r\n"));
+        assert!(!is_discovery_request(b""));
+    }
+
+    #[tokio::test]
+    async fn a_silent_connection_does_not_hold_up_the_next() {
+        let mut listener = TungsteniteListener::bind(0).await.unwrap();
+        let port = listener.port();
+        let server = tokio::spawn(async move { listener.accept().await.map(|(_, addr)| addr) });
+
+        // Connects but never sends a request line.
+        let _silent = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let started = Instant::now();
+        let (_client, _) = tokio_tungstenite::connect_async(format!("ws://127.0.0.1:{port}")).await.unwrap();
+
+        assert!(server.await.unwrap().is_ok());
+        assert!(started.elapsed() < DISCOVERY_PEEK_TIMEOUT, "took {:?}", started.elapsed());
+    }
+
+    #[tokio::test]
+    async fn listener_answers_discovery_with_its_port() {
+        let mut listener = TungsteniteListener::bind(0).await.unwrap();
+        let port = listener.port();
+        let server = tokio::spawn(async move {
+            let _ = listener.accept().await;
+        });
+
+        let mut client = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        client
+            .write_all(b"GET /wyncast HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).await.unwrap();
+        server.abort();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "got: {response}");
+        assert!(response.contains("Access-Control-Allow-Origin: *"));
+        let body = response.split("\r\n\r\n").nth(1).unwrap();
+        let json: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(json["service"], "wyncast");
+        assert_eq!(json["ws_port"], port);
+    }
 }
@@ -6,7 +6,7 @@ use iced::{Element, Subscription, Task};
 use tokio::sync::mpsc;
 use twui::{Toaster, ToastType};
 use wyncast_app::protocol::{
-    AppMode, ConnectionStatus, LlmStreamUpdate, TabId, UiUpdate, UserCommand,
+    AppMode, ConnectionStatus, LlmStreamUpdate, TabId, UiUpdate, UserCommand, WsServerStatus,
 };
 
 use crate::bridge;
@@ -163,6 +163,30 @@ pub fn update(app: &mut App, msg: Message) -> Task<Message> {
                         err.clone(),
                     );
                 }
+                UiUpdate::WsServer(status) => match status {
+                    WsServerStatus::Listening { port, requested_port } if status.is_fallback() => {
+                        app.toaster.show(
+                            ToastType::Warning,
+                            "WebSocket port changed",
+                            format!("Port {requested_port} is in use; listening on {port}"),
+                        );
+                    }
+                    WsServerStatus::BindFailed { error, .. } => {
+                        app.toaster.show(
+                            ToastType::Error,
+                            "Extension cannot connect",
+                            error.clone(),
+                        );
+                    }
+                    WsServerStatus::Listening { .. } => {}
+                },
+                UiUpdate::CrashNotice(report) => {
+                    let mut body = report.summary();
+                    if let Some(ref path) = report.crash_file {
+                        body.push_str(&format!(" — see {path}"));
+                    }
+                    app.toaster.show(ToastType::Error, "Previous session crashed", body);
+                }
                 _ => {}
             }
             match update {
@@ -175,7 +199,7 @@ pub fn update(app: &mut App, msg: Message) -> Task<Message> {
                 UiUpdate::PlanStarted { request_id } => {
                     dispatch_draft(app, DraftMessage::PlanStarted { request_id })
                 }
-                UiUpdate::NominationUpdate { info, analysis_request_id } => {
+                UiUpdate::NominationUpdate { info, analysis_request_id, .. } => {
                     dispatch_draft(
                         app,
                         DraftMessage::Nominated { analysis_request_id, info },
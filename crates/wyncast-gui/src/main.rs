@@ -1,8 +1,8 @@
 // Wyncast GUI entry point.
 //
 // Startup sequence:
-// 1. Initialize tracing (log to file)
-// 2. Load config + check onboarding status
+// 1. Load config, initialize tracing (log to file)
+// 2. Check onboarding status
 // 3. Open database, clear stale draft state
 // 4. Load projections, initialize DraftState
 // 5. Create mpsc channels
@@ -28,17 +28,16 @@ use anyhow::Context;
 use tracing::info;
 
 fn main() -> anyhow::Result<()> {
-    // 1. Tracing
-    init_tracing()?;
-    info!("Wyncast GUI starting up");
-
-    // 2. Config + onboarding check
+    // 1. Config, then tracing (which reads its [logging] section)
     let config = wyncast_core::config::load_config().context("failed to load configuration")?;
+    init_tracing(&config.logging)?;
+    info!("Wyncast GUI starting up");
     info!(
         "Config loaded: league={}, {} teams, ${} salary cap",
         config.league.name, config.league.num_teams, config.league.salary_cap
     );
 
+    // 2. Onboarding check
     let onboarding_manager = wyncast_app::onboarding::OnboardingManager::new(
         wyncast_core::app_dirs::config_dir(),
         wyncast_app::onboarding::RealFileSystem,
@@ -58,13 +57,14 @@ fn main() -> anyhow::Result<()> {
     let db = wyncast_core::db::Database::open(db_path_str).context("failed to open database")?;
     info!("Database opened at {db_path_str}");
 
-    db.clear_all_drafts().context("failed to clear persisted draft state")?;
-    let draft_id = {
-        let id = wyncast_core::db::Database::generate_draft_id();
-        db.set_draft_id(&id)?;
-        info!("Starting new draft session: {id}");
-        id
-    };
+    // Record panics to the database and a crash file next to the logs.
+    let crash_dir = wyncast_core::logging::resolve_log_dir(&config.logging)
+        .context("failed to resolve log directory")?;
+    wyncast_core::crash::install_panic_hook(db_path.clone(), crash_dir);
+
+    let draft_id = wyncast_core::db::Database::generate_draft_id();
+    db.start_new_draft(&draft_id).context("failed to reset persisted draft state")?;
+    info!("Starting new draft session: {draft_id}");
 
     // 4. Projections + DraftState
     info!("Loading projections…");
@@ -105,17 +105,17 @@ fn main() -> anyhow::Result<()> {
         .build()
         .context("failed to build tokio runtime")?;
 
-    let listener =
-        rt.block_on(wyncast_core::ws_server::TungsteniteListener::bind(ws_port))
-            .with_context(|| format!("failed to bind WebSocket server on port {ws_port}"))?;
-
-    info!("WebSocket server listening on 127.0.0.1:{ws_port}");
-
-    let ws_handle = rt.spawn(async move {
-        if let Err(e) = wyncast_core::ws_server::run(listener, ws_tx, ws_outbound_rx).await {
-            tracing::error!("WebSocket server error: {e}");
-        }
-    });
+    // The server falls back to the next free port if the configured one is
+    // taken and reports the outcome to the app.
+    let (ws_shutdown_tx, ws_shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let mut ws_handle = rt.spawn(wyncast_core::ws_server::serve(
+        ws_port,
+        ws_tx,
+        ws_outbound_rx,
+        async {
+            let _ = ws_shutdown_rx.await;
+        },
+    ));
     rt.spawn(async move {
         if let Err(e) = wyncast_app::app::run(ws_rx, llm_rx, cmd_rx, ui_tx, app_state).await {
             tracing::error!("Application loop error: {e}");
@@ -168,32 +168,44 @@ fn main() -> anyhow::Result<()> {
         .run()
         .context("Iced error")?;
 
-    ws_handle.abort();
+    // Stop the WebSocket server, letting it send a close frame to the
+    // extension. Only abort if it does not stop in time.
+    let _ = ws_shutdown_tx.send(());
+    let stopped = rt.block_on(tokio::time::timeout(
+        std::time::Duration::from_secs(2),
+        &mut ws_handle,
+    ));
+    if stopped.is_err() {
+        ws_handle.abort();
+    }
     info!("Wyncast GUI shut down");
     Ok(())
 }
 
-fn init_tracing() -> anyhow::Result<()> {
+fn init_tracing(logging: &wyncast_core::config::LoggingConfig) -> anyhow::Result<()> {
     use tracing_subscriber::fmt;
     use tracing_subscriber::EnvFilter;
+    use wyncast_core::config::LogFormat;
 
-    let log_dir = wyncast_core::app_dirs::log_dir();
-    let log_file = std::fs::File::create(log_dir.join("wyncast-gui.log"))?;
+    let writer = wyncast_core::logging::open_log_writer(logging, "wyncast-gui")
+        .context("failed to open log file")?;
 
-    let subscriber = fmt::Subscriber::builder()
+    let builder = fmt::Subscriber::builder()
         .with_env_filter(
             EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| EnvFilter::new("wyncast_gui=debug,warn")),
         )
-        .with_writer(log_file)
+        .with_writer(Mutex::new(writer))
         .with_ansi(false)
         .with_target(true)
         .with_thread_ids(true)
-        .with_line_number(true)
-        .finish();
+        .with_line_number(true);
 
-    tracing::subscriber::set_global_default(subscriber)
-        .context("failed to set tracing subscriber")?;
+    let result = match logging.format {
+        LogFormat::Text => tracing::subscriber::set_global_default(builder.finish()),
+        LogFormat::Json => tracing::subscriber::set_global_default(builder.json().finish()),
+    };
+    result.context("failed to set tracing subscriber")?;
 
     Ok(())
 }
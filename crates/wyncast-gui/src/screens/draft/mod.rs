@@ -300,6 +300,7 @@ impl DraftScreen {
                 self.draft_log.available_players = snapshot.available_players;
                 self.teams.team_snapshots = snapshot.team_snapshots;
                 self.teams.salary_cap = snapshot.salary_cap;
+                self.teams.min_bid = snapshot.min_bid;
                 self.my_roster = snapshot.my_roster;
                 self.positional_scarcity = snapshot.positional_scarcity;
                 self.budget_spent = snapshot.budget_spent;
@@ -454,10 +455,13 @@ mod tests {
             available_players: vec![],
             positional_scarcity: vec![],
             draft_log: vec![],
+            out_of_pool_picks: vec![],
+            keeper_values: vec![],
             my_roster: vec![],
             budget_spent: 0,
             budget_remaining: 260,
             salary_cap: 260,
+            min_bid: 1,
             inflation_rate: 1.0,
             max_bid: 260,
             avg_per_slot: 0.0,
@@ -465,6 +469,8 @@ mod tests {
             hitting_target: 182,
             pitching_spent: 0,
             pitching_target: 78,
+            hitting_split: 0.0,
+            split_learned: false,
             team_snapshots: vec![],
             llm_configured: false,
         })
@@ -375,7 +375,7 @@ mod tests {
             is_pitcher: false,
             is_two_way: false,
             pitcher_type: None,
-            projection: ProjectionData { values: HashMap::new() },
+            projection: ProjectionData { values: HashMap::new(), provenance: HashMap::new() },
             total_zscore: 2.5,
             category_zscores: CategoryZScores::Hitter {
                 zscores: CategoryValues::zeros(0),
@@ -385,6 +385,7 @@ mod tests {
             initial_vor: 10.0,
             best_position: Some(Position::CenterField),
             dollar_value,
+            flex_bonus: 0.0,
         }
     }
 
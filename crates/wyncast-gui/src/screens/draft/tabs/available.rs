@@ -346,6 +346,7 @@ mod tests {
                     ("sb".into(), 10.0),
                     ("avg".into(), 0.270),
                 ]),
+                provenance: HashMap::new(),
             },
             total_zscore: 2.5,
             category_zscores: CategoryZScores::hitter(
@@ -356,6 +357,7 @@ mod tests {
             initial_vor: 4.0,
             best_position: None,
             dollar_value: dollar,
+            flex_bonus: 0.0,
         }
     }
 
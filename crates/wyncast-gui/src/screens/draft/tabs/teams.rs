@@ -6,6 +6,7 @@ use twui::{
     empty_state, frame, text,
 };
 use wyncast_app::protocol::{ScrollDirection, TeamSnapshot};
+use wyncast_baseball::draft::roster::reserve;
 
 use crate::widgets::data_table::{Column, DataTableStyle, ROW_HEIGHT, data_table};
 
@@ -25,6 +26,7 @@ pub enum TeamsMessage {
 pub struct TeamsPanel {
     pub team_snapshots: Vec<TeamSnapshot>,
     pub salary_cap: u32,
+    pub min_bid: u32,
     scroll_id: WidgetId,
 }
 
@@ -33,6 +35,7 @@ impl TeamsPanel {
         Self {
             team_snapshots: Vec::new(),
             salary_cap: 0,
+            min_bid: 1,
             scroll_id: WidgetId::unique(),
         }
     }
@@ -66,7 +69,7 @@ impl TeamsPanel {
             .into();
         }
 
-        let rows = build_rows(&self.team_snapshots, self.salary_cap);
+        let rows = build_rows(&self.team_snapshots, self.salary_cap, self.min_bid);
 
         let table = data_table(
             columns(),
@@ -113,12 +116,13 @@ fn columns() -> Vec<Column> {
 fn build_rows(
     snapshots: &[TeamSnapshot],
     salary_cap: u32,
+    min_bid: u32,
 ) -> Vec<Vec<Element<'static, TeamsMessage>>> {
     snapshots
         .iter()
         .map(|team| {
             let spent = salary_cap.saturating_sub(team.budget_remaining);
-            let max_bid = compute_max_bid(team.budget_remaining, team.slots_filled, team.total_slots);
+            let max_bid = compute_max_bid(team.budget_remaining, team.slots_filled, team.total_slots, min_bid);
 
             vec![
                 cell_text(team.name.clone()),
@@ -157,12 +161,12 @@ fn scroll_delta(dir: ScrollDirection) -> (f32, f32) {
 
 /// Compute the maximum bid a team can make.
 ///
-/// The team must keep at least $1 for each remaining empty slot (minus the
-/// current bid slot itself), so:
-///   max_bid = budget_remaining - max(empty_slots - 1, 0)
-pub fn compute_max_bid(remaining: u32, slots_filled: usize, total_slots: usize) -> u32 {
+/// The team must keep at least `min_bid` for each remaining empty slot
+/// (minus the current bid slot itself), so:
+///   max_bid = budget_remaining - max(empty_slots - 1, 0) * min_bid
+pub fn compute_max_bid(remaining: u32, slots_filled: usize, total_slots: usize, min_bid: u32) -> u32 {
     let empty_slots = total_slots.saturating_sub(slots_filled);
-    remaining.saturating_sub(empty_slots.saturating_sub(1) as u32)
+    remaining.saturating_sub(reserve(empty_slots, min_bid))
 }
 
 // ---------------------------------------------------------------------------
@@ -176,25 +180,31 @@ mod tests {
     #[test]
     fn compute_max_bid_standard_case() {
         // remaining=200, filled=10, total=26 => empty=16, max_bid=200-(16-1)=185
-        assert_eq!(compute_max_bid(200, 10, 26), 185);
+        assert_eq!(compute_max_bid(200, 10, 26, 1), 185);
     }
 
     #[test]
     fn compute_max_bid_one_empty_slot() {
         // remaining=10, filled=25, total=26 => empty=1, max_bid=10-(1-1)=10-0=10
-        assert_eq!(compute_max_bid(10, 25, 26), 10);
+        assert_eq!(compute_max_bid(10, 25, 26, 1), 10);
     }
 
     #[test]
     fn compute_max_bid_saturating_underflow() {
         // remaining=1, filled=0, total=26 => empty=26, max_bid=1.saturating_sub(25)=0
-        assert_eq!(compute_max_bid(1, 0, 26), 0);
+        assert_eq!(compute_max_bid(1, 0, 26, 1), 0);
     }
 
     #[test]
     fn compute_max_bid_fully_filled() {
         // remaining=5, filled=26, total=26 => empty=0, max_bid=5.saturating_sub(0)=5
-        assert_eq!(compute_max_bid(5, 26, 26), 5);
+        assert_eq!(compute_max_bid(5, 26, 26, 1), 5);
+    }
+
+    #[test]
+    fn compute_max_bid_zero_dollar_league() {
+        // With $0 bids nothing is reserved: max_bid = remaining
+        assert_eq!(compute_max_bid(200, 10, 26, 0), 200);
     }
 
     #[test]
@@ -0,0 +1,56 @@
+// LLM errors: why a streamed request failed.
+//
+// Failures reach the app as an `LlmEvent::Error` carrying the error's
+// message, so the streaming functions report them on the event channel
+// rather than returning them; the typed error lets callers tell the
+// failures apart.
+
+use wyncast_core::llm::events::LlmEvent;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum LlmError {
+    #[error("LLM not configured")]
+    NotConfigured,
+
+    #[error("API key not configured")]
+    MissingApiKey,
+
+    #[error("Failed to create event source: {0}")]
+    EventSource(String),
+
+    #[error("API returned status {0}")]
+    Status(String),
+
+    #[error("Network error: {0}")]
+    Network(String),
+
+    #[error("Stream error: {0}")]
+    Stream(String),
+
+    /// The stream ended before the provider finished the message.
+    #[error("{0}")]
+    Incomplete(&'static str),
+}
+
+impl LlmError {
+    /// The error event for request `generation`.
+    pub fn event(&self, generation: u64) -> LlmEvent {
+        LlmEvent::Error {
+            message: self.to_string(),
+            generation,
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<&reqwest_eventsource::Error> for LlmError {
+    fn from(err: &reqwest_eventsource::Error) -> Self {
+        match err {
+            reqwest_eventsource::Error::InvalidStatusCode(status, _response) => {
+                LlmError::Status(status.to_string())
+            }
+            reqwest_eventsource::Error::Transport(e) => LlmError::Network(e.to_string()),
+            other => LlmError::Stream(other.to_string()),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 // wyncast-llm: LLM client infrastructure (HTTP/SSE streaming).
 
 pub mod client;
+pub mod error;
+#[cfg(feature = "http")]
+mod providers;
 
 // Re-export commonly used types from wyncast-core for convenience
 pub use wyncast_core::llm::events::LlmEvent;
@@ -3,12 +3,18 @@
 
 // Modules remaining in wyncast-tui
 pub mod llm;
+pub mod plain_feed;
+#[cfg(feature = "tui")]
 pub mod tui;
 
 // Re-exports from wyncast-core for backward-compat within this crate's tests
 pub use wyncast_core::app_dirs;
+pub use wyncast_core::backup;
 pub use wyncast_core::config;
+pub use wyncast_core::crash;
 pub use wyncast_core::db;
+pub use wyncast_core::journal;
+pub use wyncast_core::logging;
 pub use wyncast_core::migrations;
 pub use wyncast_core::picks;
 pub use wyncast_core::stats;
@@ -1,15 +1,22 @@
 // Draft assistant entry point.
 //
-// Startup sequence:
-// 1. Initialize tracing (log to file, not terminal)
-// 2. Load config
-// 3. Open database, check for crash recovery
+// Startup sequence (`--export-schema` prints the extension protocol's JSON
+// Schema and exits before any of it):
+// 1. Load config
+// 2. Initialize tracing (log to file, not terminal) and log the resolved
+//    paths; with `--values [N]`, print the top N players at each position
+//    and exit; with `--doctor`, print the resolved paths and exit; with
+//    `--make-fixture LOG DIR`, turn a recorded session into a test fixture
+//    and exit
+// 3. Move files an older version left in the launch directory, restore from
+//    a backup if asked (`--restore-backup [FILE]`), open database, install
+//    crash-capture panic hook
 // 4. Load projections, compute initial valuations
 // 5. Initialize DraftState
 // 6. Create mpsc channels
 // 7. Spawn WebSocket server task
 // 8. Spawn app logic task
-// 9. TUI placeholder (wait for Ctrl+C)
+// 9. Run the TUI (or, in headless builds, wait for Ctrl+C)
 // 10. Cleanup on exit
 
 use wyncast_tui::app;
@@ -18,26 +25,58 @@ use wyncast_tui::db;
 use wyncast_tui::draft;
 use wyncast_tui::llm;
 use wyncast_tui::onboarding;
+#[cfg(feature = "tui")]
 use wyncast_tui::tui;
 use wyncast_tui::valuation;
 use wyncast_tui::ws_server;
 
 use anyhow::Context;
 use tokio::sync::mpsc;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    // 1. Initialize tracing (log to file, not terminal)
-    init_tracing()?;
-    info!("Draft assistant starting up");
+    // Needs no config: the extension's tests run it without one.
+    if std::env::args().skip(1).any(|arg| arg == "--export-schema") {
+        print!("{}", app::protocol_schema::render());
+        return Ok(());
+    }
 
-    // 2. Load config
+    // 1. Load config (before tracing, which reads its [logging] section)
     let config = config::load_config().context("failed to load configuration")?;
+
+    // 2. Initialize tracing (log to file, not terminal)
+    init_tracing(&config.logging)?;
+    info!("Draft assistant starting up");
     info!(
         "Config loaded: league={}, {} teams, ${} salary cap",
         config.league.name, config.league.num_teams, config.league.salary_cap
     );
+    let paths = app::doctor::resolved_paths(&config);
+    for p in &paths {
+        info!("{}: {}", p.label, p.path.display());
+    }
+
+    if std::env::args().skip(1).any(|arg| arg == "--doctor") {
+        print!("{}", app::doctor::render(&paths));
+        return Ok(());
+    }
+
+    // Quick mode: value the projections, print them and exit before the
+    // database, WebSocket server or TUI start.
+    if let Some(top_n) = values_request(std::env::args().skip(1)) {
+        let rankings = app::values_report::compute(&config, top_n)?;
+        print!("{}", app::values_report::render(&config, &rankings));
+        return Ok(());
+    }
+
+    // Quick mode: turn a recorded session into a regression fixture.
+    if let Some((log, dir)) = fixture_request(std::env::args().skip(1))? {
+        let league = app::session_fixture::FixtureLeague::from_config(&config);
+        let (trimmed, expected) = app::session_fixture::make_fixture(&log, &dir, league).await?;
+        print!("{}", app::session_fixture::summary(&dir, &trimmed, &expected));
+        return Ok(());
+    }
 
     // Check onboarding status and determine initial app mode
     let onboarding_manager = onboarding::OnboardingManager::new(
@@ -55,29 +94,85 @@ async fn main() -> anyhow::Result<()> {
         );
         wyncast_tui::protocol::AppMode::Onboarding(progress.current_step)
     };
+    #[cfg(not(feature = "tui"))]
+    if matches!(initial_app_mode, wyncast_tui::protocol::AppMode::Onboarding(_)) {
+        anyhow::bail!("setup is not finished; run a build with the `tui` feature to complete onboarding");
+    }
 
-    // 3. Open database (always stored in the OS app data directory)
-    let db_path = wyncast_tui::app_dirs::db_path();
+    // 3. Open database (in the OS app data directory unless
+    // `[data_paths] database` moves it), first moving a database or cache
+    // that an older version created in the launch directory
+    let db_path = config.data_paths.database_path();
+    let valuation_cache = config.data_paths.valuation_cache_path();
+    match std::env::current_dir() {
+        Ok(cwd) => {
+            let moves = wyncast_tui::app_dirs::legacy_moves(&cwd, &db_path, &valuation_cache);
+            match wyncast_tui::app_dirs::migrate_files(&moves) {
+                Ok(migrated) => {
+                    for m in migrated {
+                        info!("Moved {} to {}", m.from.display(), m.to.display());
+                    }
+                }
+                Err(e) => warn!("Failed to move files from an older version: {}", e),
+            }
+        }
+        Err(e) => warn!("Cannot read the launch directory to look for old files: {}", e),
+    }
+    if let Some(dir) = db_path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create database directory {}", dir.display()))?;
+    }
     let db_path_str = db_path
         .to_str()
         .context("database path contains non-UTF-8 characters")?;
+    let restored = match restore_request(std::env::args().skip(1)) {
+        Some(file) => {
+            let backup = match file {
+                Some(file) => file,
+                None => latest_backup(&config.backup)?,
+            };
+            wyncast_tui::backup::restore(&backup, &db_path)
+                .with_context(|| format!("failed to restore {}", backup.display()))?;
+            info!("Database restored from {}", backup.display());
+            true
+        }
+        None => false,
+    };
     let db = db::Database::open(db_path_str).context("failed to open database")?;
     info!("Database opened at {}", db_path_str);
 
-    // Clear all persisted draft state on launch. The live draft (via the
-    // extension's keyframe snapshots) is the only source of truth. Stale
-    // DB data from previous sessions is the main source of phantom picks
-    // and roster corruption.
-    db.clear_all_drafts().context("failed to clear persisted draft state on startup")?;
-    info!("Cleared persisted draft state — starting fresh from extension keyframes");
-
-    // Generate a fresh draft ID for this session. Since we just cleared the
-    // DB, there is no stored draft_id to resume from.
-    let draft_id = {
-        let id = db::Database::generate_draft_id();
-        db.set_draft_id(&id)?;
-        info!("Starting new draft session: {}", id);
-        id
+    // Record panics to the database and a crash file next to the logs. The
+    // TUI wraps this hook with one that restores the terminal first.
+    let crash_dir = wyncast_tui::logging::resolve_log_dir(&config.logging)
+        .context("failed to resolve log directory")?;
+    wyncast_tui::crash::install_panic_hook(db_path.clone(), crash_dir);
+
+    // Clear all persisted draft state on launch and start a fresh draft
+    // session in one transaction. The live draft (via the extension's
+    // keyframe snapshots) is the only source of truth. Stale DB data from
+    // previous sessions is the main source of phantom picks and roster
+    // corruption.
+    //
+    // A restored database keeps its draft session instead, so the picks and
+    // decision journal it holds stay attached to the draft.
+    let restored_draft_id = if restored {
+        db.get_draft_id().context("failed to read the restored draft ID")?
+    } else {
+        None
+    };
+    let draft_id = match restored_draft_id {
+        Some(draft_id) => {
+            info!("Resuming restored draft session: {}", draft_id);
+            draft_id
+        }
+        None => {
+            let draft_id = db::Database::generate_draft_id();
+            db.start_new_draft(&draft_id)
+                .context("failed to reset persisted draft state on startup")?;
+            info!("Cleared persisted draft state — starting fresh from extension keyframes");
+            info!("Starting new draft session: {}", draft_id);
+            draft_id
+        }
     };
 
     // 4. Load projections if CSV paths are configured (optional override)
@@ -114,14 +209,15 @@ async fn main() -> anyhow::Result<()> {
 
     // Build the LLM client from config
     let llm_client = llm::client::LlmClient::from_config(&config);
-    match &llm_client {
-        llm::client::LlmClient::Active(_) => info!("LLM client initialized (API key configured)"),
-        llm::client::LlmClient::Disabled => info!("LLM client disabled (no API key)"),
+    if llm_client.is_active() {
+        info!("LLM client initialized (API key configured)");
+    } else {
+        info!("LLM client disabled (no API key)");
     }
 
     // Create the application state. No crash recovery — we start fresh and
     // wait for the first keyframe from the extension.
-    let app_state = app::AppState::new(
+    let mut app_state = app::AppState::new(
         config.clone(),
         draft_state,
         available_players,
@@ -135,22 +231,17 @@ async fn main() -> anyhow::Result<()> {
         onboarding_manager,
         None, // roster_config deferred until ESPN connection
     );
+    app_state.valuation_cache = Some(valuation_cache);
     info!("Starting fresh — waiting for first keyframe from extension");
+    let app_state = start_discord_bot(&config, app_state);
 
-    // 7. Spawn WebSocket server task
+    // 7. Spawn WebSocket server task. It falls back to the next free port if
+    // the configured one is taken and reports the outcome to the app.
     let ws_port = config.ws_port;
-    let ws_handle = tokio::spawn(async move {
-        match ws_server::TungsteniteListener::bind(ws_port).await {
-            Ok(listener) => {
-                if let Err(e) = ws_server::run(listener, ws_tx, ws_outbound_rx).await {
-                    error!("WebSocket server error: {}", e);
-                }
-            }
-            Err(e) => {
-                error!("Failed to bind WebSocket server on port {}: {}", ws_port, e);
-            }
-        }
-    });
+    let (ws_shutdown_tx, ws_shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let mut ws_handle = tokio::spawn(ws_server::serve(ws_port, ws_tx, ws_outbound_rx, async {
+        let _ = ws_shutdown_rx.await;
+    }));
 
     // 8. Spawn app logic task
     let app_handle = tokio::spawn(async move {
@@ -159,17 +250,39 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
+    // Tee the UI updates into the plain-text feed for screen readers. A
+    // headless build has nothing else to show the draft, so it always does.
+    let ui_rx = if config.accessibility.plain_output || cfg!(not(feature = "tui")) {
+        match wyncast_tui::plain_feed::open(&config.accessibility, &config.logging) {
+            Ok((feed, path)) => {
+                info!("Writing plain output to {}", path.display());
+                wyncast_tui::plain_feed::tee(ui_rx, feed)
+            }
+            Err(e) => {
+                error!("Plain output disabled: {:#}", e);
+                ui_rx
+            }
+        }
+    } else {
+        ui_rx
+    };
+
     // 9. Run the TUI event loop (blocking until user quits)
-    info!("Application ready. WebSocket server listening on 127.0.0.1:{}", ws_port);
+    info!("Application ready (WebSocket port {} requested)", ws_port);
 
     // Drop the LLM sender clone; AppState holds its own clone for spawning tasks.
     drop(llm_tx);
 
     // The TUI consumes ui_rx and sends commands through cmd_tx.
     // It blocks until the user presses 'q' or Ctrl+C.
+    #[cfg(feature = "tui")]
     if let Err(e) = tui::run(ui_rx, cmd_tx, initial_app_mode).await {
         error!("TUI error: {}", e);
     }
+    #[cfg(not(feature = "tui"))]
+    if let Err(e) = run_headless(ui_rx, cmd_tx).await {
+        error!("Headless run error: {:#}", e);
+    }
 
     // 10. Cleanup: wait for app task to finish (with timeout)
     let _ = tokio::time::timeout(std::time::Duration::from_secs(5), async {
@@ -177,35 +290,125 @@ async fn main() -> anyhow::Result<()> {
     })
     .await;
 
-    // Abort WebSocket server (it loops forever)
-    ws_handle.abort();
+    // Stop the WebSocket server, letting it send a close frame to the
+    // extension. Only abort if it does not stop in time.
+    let _ = ws_shutdown_tx.send(());
+    if tokio::time::timeout(std::time::Duration::from_secs(2), &mut ws_handle)
+        .await
+        .is_err()
+    {
+        ws_handle.abort();
+    }
 
     info!("Draft assistant shut down cleanly");
     Ok(())
 }
 
+/// Headless builds (without the `tui` feature) have no terminal UI: drain
+/// the UI updates until Ctrl+C, then ask the app to quit.
+#[cfg(not(feature = "tui"))]
+async fn run_headless(
+    mut ui_rx: mpsc::Receiver<wyncast_tui::protocol::UiUpdate>,
+    cmd_tx: mpsc::Sender<wyncast_tui::protocol::UserCommand>,
+) -> anyhow::Result<()> {
+    eprintln!("wyncast is running headless; press Ctrl+C to stop");
+    tokio::select! {
+        _ = async { while ui_rx.recv().await.is_some() {} } => {}
+        result = tokio::signal::ctrl_c() => result.context("failed to listen for Ctrl+C")?,
+    }
+    let _ = cmd_tx.send(wyncast_tui::protocol::UserCommand::Quit).await;
+    Ok(())
+}
+
+/// Parse `--restore-backup [FILE]` from the command line. `Some(None)` means
+/// the flag was given without a file (restore the newest backup).
+fn restore_request(mut args: impl Iterator<Item = String>) -> Option<Option<std::path::PathBuf>> {
+    args.position(|arg| arg == "--restore-backup")?;
+    Some(args.next().filter(|arg| !arg.starts_with("--")).map(Into::into))
+}
+
+/// Parse `--values [N]` from the command line: the players to list per
+/// position, or the default count when none is given.
+fn values_request(mut args: impl Iterator<Item = String>) -> Option<usize> {
+    args.position(|arg| arg == "--values")?;
+    let top_n = args.next().and_then(|arg| arg.parse().ok());
+    Some(top_n.unwrap_or(app::values_report::DEFAULT_TOP_N))
+}
+
+/// Parse `--make-fixture LOG DIR` from the command line: the recorded
+/// session and the fixture directory to write.
+fn fixture_request(
+    mut args: impl Iterator<Item = String>,
+) -> anyhow::Result<Option<(std::path::PathBuf, std::path::PathBuf)>> {
+    if args.position(|arg| arg == "--make-fixture").is_none() {
+        return Ok(None);
+    }
+    match (args.next(), args.next()) {
+        (Some(log), Some(dir)) => Ok(Some((log.into(), dir.into()))),
+        _ => anyhow::bail!("usage: --make-fixture LOG DIR"),
+    }
+}
+
+/// The newest backup in `[backup] dir`.
+fn latest_backup(config: &config::BackupConfig) -> anyhow::Result<std::path::PathBuf> {
+    let dir = config
+        .dir
+        .as_deref()
+        .context("--restore-backup without a file needs [backup] dir in strategy.toml")?;
+    wyncast_tui::backup::latest_backup(std::path::Path::new(dir))?
+        .with_context(|| format!("no backups found in {dir}"))
+}
+
+/// Spawn the Discord query bot when a token is configured and hand its
+/// query channel to the app.
+#[cfg(feature = "discord-bot")]
+fn start_discord_bot(config: &config::Config, mut app_state: app::AppState) -> app::AppState {
+    if let Some(token) = config.credentials.discord_bot_token.clone() {
+        let (query_tx, query_rx) = mpsc::channel(16);
+        app_state.query_rx = Some(query_rx);
+        let allowed_users = config.discord_bot.allowed_users.clone();
+        tokio::spawn(app::discord_bot::run(token, allowed_users, query_tx));
+        info!("Discord bot enabled for {} user(s)", config.discord_bot.allowed_users.len());
+    }
+    app_state
+}
+
+#[cfg(not(feature = "discord-bot"))]
+fn start_discord_bot(config: &config::Config, app_state: app::AppState) -> app::AppState {
+    if config.credentials.discord_bot_token.is_some() {
+        tracing::warn!("discord_bot_token is set, but this build has no Discord bot (build with --features discord-bot)");
+    }
+    app_state
+}
+
 /// Initialize tracing to log to a file (not the terminal, which is used by the TUI).
-fn init_tracing() -> anyhow::Result<()> {
+///
+/// Destination, per-session naming, rotation, and format come from the
+/// `[logging]` section of strategy.toml.
+fn init_tracing(logging: &config::LoggingConfig) -> anyhow::Result<()> {
+    use std::sync::Mutex;
     use tracing_subscriber::fmt;
     use tracing_subscriber::EnvFilter;
 
-    let log_dir = wyncast_tui::app_dirs::log_dir();
-    let log_file = std::fs::File::create(log_dir.join("draft-assistant.log"))?;
+    let writer = wyncast_tui::logging::open_log_writer(logging, "draft-assistant")
+        .context("failed to open log file")?;
 
-    let subscriber = fmt::Subscriber::builder()
+    let builder = fmt::Subscriber::builder()
         .with_env_filter(
             EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| EnvFilter::new("wyncast_tui=info,warn")),
         )
-        .with_writer(log_file)
+        .with_writer(Mutex::new(writer))
         .with_ansi(false)
         .with_target(true)
         .with_thread_ids(true)
-        .with_line_number(true)
-        .finish();
+        .with_line_number(true);
 
-    tracing::subscriber::set_global_default(subscriber)
-        .context("failed to set tracing subscriber")?;
+    let result = match logging.format {
+        config::LogFormat::Text => tracing::subscriber::set_global_default(builder.finish()),
+        config::LogFormat::Json => tracing::subscriber::set_global_default(builder.json().finish()),
+    };
+    result.context("failed to set tracing subscriber")?;
 
     Ok(())
 }
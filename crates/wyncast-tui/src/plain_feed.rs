@@ -0,0 +1,247 @@
+// Plain-text draft feed for screen readers and log followers.
+//
+// The TUI redraws a grid of panels, which screen readers cannot follow. When
+// `[accessibility] plain_output` is on, the UI updates are teed through
+// `PlainFeed` on their way to the TUI, and the key moments of the draft
+// (nominations, verdicts, my max bid, picks) are appended to a file as one
+// sentence per line. The TUI owns the terminal, so the feed goes to a file
+// that can be followed with `tail -f` or a screen reader's file watcher.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use anyhow::Context;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::config::{AccessibilityConfig, LoggingConfig};
+use crate::logging;
+use crate::protocol::{AppSnapshot, ConnectionStatus, InstantVerdict, UiUpdate};
+
+/// Snapshots bringing more new picks than this are summarized in one line
+/// instead of reading out every pick (e.g. catching up after a restart).
+const CATCH_UP_THRESHOLD: usize = 5;
+
+/// The verdict as words a screen reader speaks naturally (the TUI badge is
+/// upper case).
+fn verdict_words(verdict: InstantVerdict) -> &'static str {
+    match verdict {
+        InstantVerdict::StrongTarget => "strong target",
+        InstantVerdict::ConditionalTarget => "conditional target",
+        InstantVerdict::Pass => "pass",
+    }
+}
+
+/// Turns UI updates into plain text lines written to `out`.
+pub struct PlainFeed<W: Write> {
+    out: W,
+    /// Picks already announced.
+    picks_seen: usize,
+    /// My max bid as last announced.
+    max_bid: Option<u32>,
+}
+
+impl<W: Write> PlainFeed<W> {
+    pub fn new(out: W) -> Self {
+        PlainFeed { out, picks_seen: 0, max_bid: None }
+    }
+
+    /// Write the lines for `update`, if it has any.
+    pub fn observe(&mut self, update: &UiUpdate) -> io::Result<()> {
+        let lines = self.lines(update);
+        for line in &lines {
+            writeln!(self.out, "{line}")?;
+        }
+        if !lines.is_empty() {
+            self.out.flush()?;
+        }
+        Ok(())
+    }
+
+    fn lines(&mut self, update: &UiUpdate) -> Vec<String> {
+        match update {
+            UiUpdate::StateSnapshot(snapshot) => self.snapshot_lines(snapshot),
+            UiUpdate::NominationUpdate { info, analysis, .. } => {
+                let mut lines = vec![format!(
+                    "Nominated: {} ({}) by {}, bid ${}.",
+                    info.player_name, info.position, info.nominated_by, info.current_bid
+                )];
+                if let Some(a) = analysis {
+                    let mut verdict = match &a.roster_block {
+                        Some(block) => format!("Verdict: {}, {}.", verdict_words(a.verdict), block),
+                        None => format!("Verdict: {}.", verdict_words(a.verdict)),
+                    };
+                    verdict.push_str(&format!(" Worth ${:.0} after inflation.", a.adjusted_value));
+                    if let Some(max) = self.max_bid {
+                        verdict.push_str(&format!(" My max bid ${max}."));
+                    }
+                    lines.push(verdict);
+                }
+                lines
+            }
+            UiUpdate::ConnectionStatus(ConnectionStatus::Connected) => {
+                vec!["Extension connected.".to_string()]
+            }
+            UiUpdate::ConnectionStatus(ConnectionStatus::Disconnected) => {
+                vec!["Extension disconnected.".to_string()]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn snapshot_lines(&mut self, snapshot: &AppSnapshot) -> Vec<String> {
+        let mut lines = Vec::new();
+        let picks = &snapshot.draft_log;
+        if picks.len() < self.picks_seen {
+            // The draft was reset or replaced; start counting again.
+            self.picks_seen = 0;
+        }
+        let new_picks = &picks[self.picks_seen..];
+        if new_picks.len() > CATCH_UP_THRESHOLD {
+            lines.push(format!("Caught up: {} picks so far.", picks.len()));
+        } else {
+            for pick in new_picks {
+                lines.push(format!(
+                    "Pick {}: {} ({}) to {} for ${}.",
+                    pick.pick_number, pick.player_name, pick.position, pick.team_name, pick.price
+                ));
+            }
+        }
+        self.picks_seen = picks.len();
+
+        if self.max_bid != Some(snapshot.max_bid) {
+            self.max_bid = Some(snapshot.max_bid);
+            lines.push(format!(
+                "My max bid ${}, ${} left.",
+                snapshot.max_bid, snapshot.budget_remaining
+            ));
+        }
+        lines
+    }
+}
+
+/// Open the feed file configured in `[accessibility]`, appending so a
+/// restart mid-draft keeps the earlier lines.
+pub fn open(
+    config: &AccessibilityConfig,
+    logging_config: &LoggingConfig,
+) -> anyhow::Result<(PlainFeed<File>, PathBuf)> {
+    let path = match &config.plain_output_path {
+        Some(path) => PathBuf::from(path),
+        None => logging::resolve_log_dir(logging_config)
+            .context("failed to resolve the log directory")?
+            .join("draft-feed.txt"),
+    };
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    Ok((PlainFeed::new(file), path))
+}
+
+/// Pass every update from `ui_rx` through `feed` and on to the returned
+/// receiver, which the TUI reads instead.
+pub fn tee<W: Write + Send + 'static>(
+    mut ui_rx: mpsc::Receiver<UiUpdate>,
+    mut feed: PlainFeed<W>,
+) -> mpsc::Receiver<UiUpdate> {
+    let (tx, rx) = mpsc::channel(256);
+    tokio::spawn(async move {
+        while let Some(update) = ui_rx.recv().await {
+            if let Err(e) = feed.observe(&update) {
+                warn!("Failed to write the plain output feed: {}", e);
+            }
+            if tx.send(update).await.is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::DraftPick;
+    use crate::protocol::{InstantAnalysis, NominationInfo};
+    use crate::test_utils::{test_pick, test_snapshot};
+
+    fn snapshot(picks: Vec<DraftPick>, max_bid: u32) -> UiUpdate {
+        let mut snapshot = test_snapshot(0, 260, None);
+        snapshot.draft_log = picks;
+        snapshot.max_bid = max_bid;
+        snapshot.budget_remaining = 200;
+        UiUpdate::StateSnapshot(Box::new(snapshot))
+    }
+
+    fn output(feed: &PlainFeed<Vec<u8>>) -> String {
+        String::from_utf8(feed.out.clone()).unwrap()
+    }
+
+    #[test]
+    fn announces_new_picks_and_max_bid_changes_once() {
+        let mut feed = PlainFeed::new(Vec::new());
+        let witt = test_pick("Bobby Witt Jr.").number(1).team(2).position("SS").price(21).build();
+        let henderson = test_pick("Gunnar Henderson").number(2).team(2).position("SS").price(22).build();
+        feed.observe(&snapshot(vec![], 180)).unwrap();
+        feed.observe(&snapshot(vec![witt.clone()], 180)).unwrap();
+        feed.observe(&snapshot(vec![witt.clone()], 180)).unwrap();
+        feed.observe(&snapshot(vec![witt, henderson], 170)).unwrap();
+        assert_eq!(
+            output(&feed),
+            "My max bid $180, $200 left.\n\
+             Pick 1: Bobby Witt Jr. (SS) to Team 2 for $21.\n\
+             Pick 2: Gunnar Henderson (SS) to Team 2 for $22.\n\
+             My max bid $170, $200 left.\n"
+        );
+    }
+
+    #[test]
+    fn summarizes_a_large_catch_up() {
+        let mut feed = PlainFeed::new(Vec::new());
+        let picks: Vec<DraftPick> = (1..=40).map(|n| test_pick("Someone").number(n).build()).collect();
+        feed.observe(&snapshot(picks, 100)).unwrap();
+        assert_eq!(output(&feed), "Caught up: 40 picks so far.\nMy max bid $100, $200 left.\n");
+    }
+
+    #[test]
+    fn nomination_reads_out_the_verdict_and_max_bid() {
+        let mut feed = PlainFeed::new(Vec::new());
+        feed.observe(&snapshot(vec![], 150)).unwrap();
+        feed.out.clear();
+
+        let info = NominationInfo {
+            player_name: "Juan Soto".into(),
+            position: "OF".into(),
+            nominated_by: "Team 3".into(),
+            current_bid: 1,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        };
+        let analysis = InstantAnalysis {
+            player_name: "Juan Soto".into(),
+            dollar_value: 44.0,
+            adjusted_value: 47.4,
+            verdict: InstantVerdict::StrongTarget,
+            roster_block: None,
+            category_gains: vec![],
+            standings: vec![],
+        };
+        feed.observe(&UiUpdate::NominationUpdate {
+            info: Box::new(info.clone()),
+            analysis_request_id: None,
+            analysis: Some(Box::new(analysis)),
+        })
+        .unwrap();
+        // Bids are not read out.
+        feed.observe(&UiUpdate::BidUpdate(Box::new(info))).unwrap();
+        assert_eq!(
+            output(&feed),
+            "Nominated: Juan Soto (OF) by Team 3, bid $1.\n\
+             Verdict: strong target. Worth $47 after inflation. My max bid $150.\n"
+        );
+    }
+}
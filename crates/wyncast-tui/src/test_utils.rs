@@ -8,10 +8,13 @@ use std::collections::HashMap;
 use crate::config::*;
 use crate::draft::pick::Position;
 use crate::draft::state::{DraftState, TeamBudgetPayload};
+use crate::protocol::{AppMode, AppSnapshot, TabId};
 use crate::stats::{CategoryValues, StatRegistry};
 use crate::valuation::projections::PitcherType;
 use crate::valuation::zscore::{CategoryZScores, PlayerValuation, ProjectionData};
 
+pub use wyncast_baseball::test_utils::test_pick;
+
 // ---------------------------------------------------------------------------
 // Configuration fixtures
 // ---------------------------------------------------------------------------
@@ -50,6 +53,14 @@ pub fn test_league_config() -> LeagueConfig {
             gs_per_week: 7,
         },
         teams: HashMap::new(),
+        player_pool: Default::default(),
+        min_bid: 1,
+        keepers: Default::default(),
+        nomination_order: vec![],
+        roster: HashMap::new(),
+        bid_increment: 1,
+        draft_type: Default::default(),
+        points: Default::default(),
     }
 }
 
@@ -76,6 +87,7 @@ pub fn test_roster_config() -> HashMap<String, usize> {
 pub fn test_strategy_config() -> StrategyConfig {
     StrategyConfig {
         hitting_budget_fraction: 0.65,
+        adaptive_budget_split: false,
         weights: CategoryWeights::from_pairs([
             ("R", 1.0),
             ("HR", 1.0),
@@ -91,6 +103,8 @@ pub fn test_strategy_config() -> StrategyConfig {
             ("WHIP", 1.0),
         ]),
         strategy_overview: None,
+        flexibility: FlexibilityConfig::default(),
+        valuation: ValuationRulesConfig::default(),
         pool: PoolConfig {
             min_pa: 300,
             min_ip_sp: 80.0,
@@ -106,6 +120,10 @@ pub fn test_strategy_config() -> StrategyConfig {
             planning_max_tokens: 2048,
             analysis_trigger: "nomination".into(),
             prefire_planning: true,
+            analysis_verbosity: Default::default(),
+            analysis_language: "English".into(),
+            analysis_tldr: false,
+            prefire: Default::default(),
         },
     }
 }
@@ -118,6 +136,12 @@ pub fn test_config() -> Config {
         credentials: CredentialsConfig::default(),
         ws_port: 9001,
         data_paths: DataPaths::default(),
+        logging: LoggingConfig::default(),
+        notify: Default::default(),
+        discord_bot: Default::default(),
+        accessibility: Default::default(),
+        display: Default::default(),
+        backup: Default::default(),
     }
 }
 
@@ -149,6 +173,57 @@ pub fn create_test_draft_state(num_teams: usize) -> DraftState {
     state
 }
 
+/// Helper to build a test AppSnapshot with sensible defaults.
+pub fn test_snapshot(pick_count: usize, total_picks: usize, active_tab: Option<TabId>) -> AppSnapshot {
+    AppSnapshot {
+        app_mode: AppMode::Draft,
+        pick_count,
+        total_picks,
+        active_tab,
+        available_players: vec![],
+        positional_scarcity: vec![],
+        draft_log: vec![],
+        out_of_pool_picks: vec![],
+        ambiguous_picks: vec![],
+        invariant_violations: vec![],
+        prep: vec![],
+        prep_unmatched: vec![],
+        keeper_values: vec![],
+        session_stats: Default::default(),
+        shopping_list: vec![],
+        dart_board: vec![],
+        roster_holes: vec![],
+        nomination_turn: None,
+        snake: None,
+        nomination_advice: None,
+        nomination_script: vec![],
+        plan_b: None,
+        my_roster: vec![],
+        budget_spent: 0,
+        budget_remaining: 260,
+        salary_cap: 260,
+        min_bid: 1,
+        bid_increment: 1,
+        percent_of_cap: false,
+        pick_toasts: true,
+        custom_stats: vec![],
+        recalc_snoozed: None,
+        pick_values: HashMap::new(),
+        inflation_rate: 1.0,
+        raw_inflation_rate: 1.0,
+        max_bid: 0,
+        avg_per_slot: 0.0,
+        hitting_spent: 0,
+        hitting_target: 0,
+        pitching_spent: 0,
+        pitching_target: 0,
+        hitting_split: 0.0,
+        split_learned: false,
+        team_snapshots: vec![],
+        llm_configured: true,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // CategoryValues helpers
 // ---------------------------------------------------------------------------
@@ -318,6 +393,8 @@ impl TestPlayer {
                     ("g".into(), 30.0),
                     ("gs".into(), 30.0),
                 ]),
+                provenance: HashMap::new(),
+                adjustments: Vec::new(),
             };
             (proj, CategoryZScores::pitcher(zv, total))
         } else {
@@ -333,6 +410,8 @@ impl TestPlayer {
                     ("sb".into(), 10.0),
                     ("avg".into(), 0.273),
                 ]),
+                provenance: HashMap::new(),
+                adjustments: Vec::new(),
             };
             (proj, CategoryZScores::hitter(zv, total))
         };
@@ -351,6 +430,9 @@ impl TestPlayer {
             initial_vor: self.vor,
             best_position: self.positions.first().copied(),
             dollar_value: self.dollar_value,
+            flex_bonus: 0.0,
+            consensus_value: self.dollar_value,
+            custom_stats: Vec::new(),
         }
     }
 }
@@ -393,6 +475,8 @@ pub fn make_hitter(
                 ("sb".into(), sb as f64),
                 ("avg".into(), avg),
             ]),
+            provenance: HashMap::new(),
+            adjustments: Vec::new(),
         },
         total_zscore: 0.0,
         category_zscores: CategoryZScores::zeros_hitter(test_registry().len()),
@@ -400,6 +484,9 @@ pub fn make_hitter(
         initial_vor: 0.0,
         best_position: None,
         dollar_value: 0.0,
+        flex_bonus: 0.0,
+        consensus_value: 0.0,
+        custom_stats: Vec::new(),
     }
 }
 
@@ -448,6 +535,8 @@ pub fn make_pitcher(
                     },
                 ),
             ]),
+            provenance: HashMap::new(),
+            adjustments: Vec::new(),
         },
         total_zscore: 0.0,
         category_zscores: CategoryZScores::zeros_pitcher(test_registry().len()),
@@ -455,5 +544,8 @@ pub fn make_pitcher(
         initial_vor: 0.0,
         best_position: None,
         dollar_value: 0.0,
+        flex_bonus: 0.0,
+        consensus_value: 0.0,
+        custom_stats: Vec::new(),
     }
 }
@@ -12,7 +12,8 @@ use crossterm::event::KeyCode;
 use ratatui::Frame;
 use tracing::info;
 
-use crate::protocol::{AppMode, AppSnapshot, ConnectionStatus, SettingsSection, TabId, UiUpdate, UserCommand};
+use crate::draft::roster_holes::HoleAlert;
+use crate::protocol::{AppMode, AppSnapshot, ConnectionStatus, RecalcProgress, SettingsSection, UiUpdate, UserCommand};
 use crate::tui::subscription::{Subscription, SubscriptionId};
 use crate::tui::subscription::keybinding::{
     ctrl, KeyBindingRecipe, KeybindManager, PRIORITY_MODAL,
@@ -23,14 +24,23 @@ use super::confirm_dialog::ConfirmDialog;
 use super::draft::main_panel::analysis::AnalysisPanelMessage;
 use super::draft::main_panel::available::AvailablePanelMessage;
 use super::draft::main_panel::MainPanelMessage;
+use super::draft::modal::compare::CompareModalMessage;
+use super::draft::optimistic::EditKind;
+use super::draft::modal::player_detail::PlayerDetailModalMessage;
+use super::draft::modal::resolve_pick::ResolvePickModalMessage;
+use super::draft::modal::time_machine::TimeMachineModalMessage;
 use super::draft::sidebar::plan::PlanPanelMessage;
 use super::draft::{DraftScreen, DraftScreenMessage};
+use super::frame_budget::DEGRADED_ROW_LIMIT;
 use super::home::HomeMessage;
 use super::llm_stream::LlmStreamMessage;
 use super::matchup::{MatchupScreen, MatchupScreenMessage};
 use super::onboarding::{self, OnboardingMessage};
 use super::settings::{self, SettingsMessage};
+use super::ws_warning::{WsWarning, WsWarningMessage};
+use super::onboarding::league_setup::LeagueSetupState;
 use super::{BudgetStatus, LlmSetupState, StrategySetupState, TeamSummary};
+use crate::valuation::prep::PrepEntry;
 use crate::tui::subscription::keybinding::KeybindHint;
 
 // ---------------------------------------------------------------------------
@@ -43,6 +53,7 @@ pub struct App {
     pub matchup_screen: MatchupScreen,
     pub active_keybinds: Vec<KeybindHint>,
     pub llm_setup: LlmSetupState,
+    pub league_setup: LeagueSetupState,
     pub strategy_setup: StrategySetupState,
     pub settings_tab: SettingsSection,
     pub confirm_exit_settings: ConfirmDialog,
@@ -51,6 +62,10 @@ pub struct App {
     /// True once content-bearing data arrives from the ESPN extension while
     /// connected. Reset on disconnect.
     pub espn_page_detected: bool,
+    /// Overlay shown when the WebSocket server could not bind a port.
+    pub ws_warning: WsWarning,
+    /// Progress of a bulk recalculation in the app, shown as an overlay.
+    pub recalc_progress: Option<RecalcProgress>,
     /// Stable ID for the global Ctrl+C subscription (never changes).
     sub_id_global: SubscriptionId,
     /// Stable ID for the 500ms timer subscription (never changes).
@@ -68,17 +83,26 @@ impl App {
             matchup_screen: MatchupScreen::new(),
             active_keybinds: Vec::new(),
             llm_setup: LlmSetupState::default(),
+            league_setup: LeagueSetupState::default(),
             strategy_setup: StrategySetupState::default(),
             settings_tab: SettingsSection::LlmConfig,
             confirm_exit_settings: ConfirmDialog::unsaved_changes(),
             matchup_snapshot: None,
             espn_page_detected: false,
+            ws_warning: WsWarning::default(),
+            recalc_progress: None,
             sub_id_global: SubscriptionId::unique(),
             sub_id_tick: SubscriptionId::unique(),
             tick_count: 0,
         }
     }
 
+    /// Show a command's effect on the board before the app answers it.
+    /// Called as each command is sent.
+    pub fn predict(&mut self, cmd: &UserCommand) {
+        self.draft_screen.predict(cmd);
+    }
+
     // -----------------------------------------------------------------------
     // UiUpdate processing (absorbed from apply_ui_update)
     // -----------------------------------------------------------------------
@@ -91,23 +115,36 @@ impl App {
                     self.espn_page_detected = true;
                 }
             }
-            UiUpdate::NominationUpdate { info, analysis_request_id } => {
+            UiUpdate::NominationUpdate { info, analysis_request_id, analysis } => {
+                // The crash notice shares the banner; the first nomination replaces it.
+                self.draft_screen.crash_notice = None;
                 self.draft_screen.current_nomination = Some(*info);
                 self.draft_screen.analysis_request_id = analysis_request_id;
                 self.draft_screen.main_panel.analysis.update(AnalysisPanelMessage::Stream(LlmStreamMessage::Clear));
-                self.draft_screen.instant_analysis = None;
-                if self.draft_screen.main_panel.active_tab() == TabId::Available {
-                    self.draft_screen.main_panel.available.update(AvailablePanelMessage::Scroll(
-                        crate::tui::scroll::ScrollDirection::Top,
-                    ));
+                self.draft_screen.instant_analysis = analysis.map(|a| *a);
+                // Keep my logged intent only if the same player is re-sent.
+                let nominated = self.draft_screen.current_nomination.as_ref().map(|n| &n.player_name);
+                if self.draft_screen.bid_intent.as_ref().map(|i| &i.player_name) != nominated {
+                    self.draft_screen.bid_intent = None;
                 }
+                self.draft_screen.refresh_bid_ladder();
+                self.draft_screen.refresh_nominated();
+                self.draft_screen.scroll_to_nominated();
             }
             UiUpdate::BidUpdate(nomination) => {
                 self.draft_screen.current_nomination = Some(*nomination);
+                self.draft_screen.refresh_bid_ladder();
+                self.draft_screen.refresh_nominated();
+            }
+            UiUpdate::BidIntent(intent) => {
+                self.draft_screen.bid_intent = Some(intent);
             }
             UiUpdate::NominationCleared => {
                 self.draft_screen.current_nomination = None;
+                self.draft_screen.nominated = None;
                 self.draft_screen.instant_analysis = None;
+                self.draft_screen.bid_intent = None;
+                self.draft_screen.bid_ladder = None;
                 self.draft_screen.analysis_request_id = None;
                 self.draft_screen.main_panel.analysis.update(AnalysisPanelMessage::Stream(LlmStreamMessage::Clear));
             }
@@ -118,6 +155,12 @@ impl App {
                     LlmStreamMessage::TokenReceived(String::new()),
                 ));
             }
+            UiUpdate::ComparisonStarted { request_id } => {
+                self.draft_screen.compare_request_id = Some(request_id);
+            }
+            UiUpdate::LlmPaused(paused) => {
+                self.draft_screen.sidebar.plan.update(PlanPanelMessage::Paused(paused));
+            }
             UiUpdate::LlmUpdate { request_id, update } => {
                 let stream_msg = match update {
                     crate::protocol::LlmStreamUpdate::Token(text) => LlmStreamMessage::TokenReceived(text),
@@ -128,6 +171,11 @@ impl App {
                     self.draft_screen.main_panel.analysis.update(AnalysisPanelMessage::Stream(stream_msg));
                 } else if self.draft_screen.plan_request_id == Some(request_id) {
                     self.draft_screen.sidebar.plan.update(PlanPanelMessage::Stream(stream_msg));
+                } else if self.draft_screen.compare_request_id == Some(request_id) {
+                    self.draft_screen
+                        .modal_layer
+                        .compare
+                        .update(CompareModalMessage::Stream(stream_msg));
                 }
                 // else: stale request ID, discard
             }
@@ -188,6 +236,8 @@ impl App {
                             self.llm_setup.saved_api_key_mask.clear();
                         }
                     }
+                    OnboardingUpdate::LeagueSync(setup) => self.league_setup.load(&setup),
+                    OnboardingUpdate::LeagueRejected(problems) => self.league_setup.problems = problems,
                     OnboardingUpdate::StrategyLlmToken(token) => {
                         self.strategy_setup.generation_output.push_str(&token);
                     }
@@ -229,6 +279,117 @@ impl App {
                     self.espn_page_detected = true;
                 }
             }
+            UiUpdate::WsServer(status) => {
+                self.ws_warning.show(&status);
+                self.draft_screen.ws_server = Some(status);
+            }
+            UiUpdate::ExtensionLatency { rtt_ms } => {
+                self.draft_screen.extension_latency = Some((rtt_ms, std::time::Instant::now()));
+            }
+            UiUpdate::ExtensionHealth(health) => {
+                self.draft_screen.extension_health = Some((health, std::time::Instant::now()));
+            }
+            UiUpdate::SupportBundle(outcome) => {
+                self.draft_screen.file_notice = Some(
+                    outcome
+                        .map(|path| format!("State dumped to {path}"))
+                        .map_err(|e| format!("State dump failed: {e}")),
+                );
+            }
+            UiUpdate::ProjectionAudit(outcome) => {
+                self.draft_screen.file_notice = Some(
+                    outcome
+                        .map(|path| format!("Projection audit written to {path}"))
+                        .map_err(|e| format!("Projection audit failed: {e}")),
+                );
+            }
+            UiUpdate::KeeperReport(outcome) => {
+                self.draft_screen.file_notice = Some(
+                    outcome
+                        .map(|path| format!("Keeper report written to {path}"))
+                        .map_err(|e| format!("Keeper report failed: {e}")),
+                );
+            }
+            UiUpdate::AvailableExport(outcome) => {
+                self.draft_screen.file_notice = Some(
+                    outcome
+                        .map(|path| format!("Available view written to {path}"))
+                        .map_err(|e| format!("Available view export failed: {e}")),
+                );
+            }
+            UiUpdate::DiffExplanation(explanation) => {
+                self.draft_screen.modal_layer.diff_explain.push(explanation);
+            }
+            UiUpdate::UiDesynced { lost } => {
+                self.draft_screen.ui_desynced = Some(lost);
+            }
+            UiUpdate::RecalcProgress(progress) => {
+                self.recalc_progress = progress;
+            }
+            UiUpdate::UndoRedo(outcome) => {
+                self.draft_screen.file_notice = Some(outcome);
+            }
+            UiUpdate::PinSet { name, pinned } => {
+                self.draft_screen
+                    .main_panel
+                    .available
+                    .update(AvailablePanelMessage::SetPin { name, pinned });
+            }
+            UiUpdate::ManualPicked(outcome) => {
+                if outcome.is_err() {
+                    self.draft_screen.reject_edit(EditKind::Pick);
+                }
+                self.draft_screen.file_notice = Some(
+                    outcome
+                        .map(|player| format!("Recorded the manual pick of {player}"))
+                        .map_err(|e| format!("Manual pick rejected: {e}")),
+                );
+            }
+            UiUpdate::StatsPageOpened(outcome) => {
+                self.draft_screen.file_notice = Some(
+                    outcome
+                        .map(|page| format!("Opened the {page}"))
+                        .map_err(|e| format!("Couldn't open the browser: {e}")),
+                );
+            }
+            UiUpdate::PickResolved(outcome) => {
+                if outcome.is_err() {
+                    self.draft_screen.reject_edit(EditKind::Resolve);
+                }
+                self.draft_screen.file_notice = Some(
+                    outcome
+                        .map(|resolved| format!("Resolved {resolved}"))
+                        .map_err(|e| format!("Couldn't resolve the pick: {e}")),
+                );
+            }
+            UiUpdate::DraftRecap(outcome) => {
+                self.draft_screen.file_notice = Some(
+                    outcome
+                        .map(|done| format!("Draft recap {done}"))
+                        .map_err(|e| format!("Draft recap failed: {e}")),
+                );
+            }
+            UiUpdate::CrashNotice(report) => {
+                self.draft_screen.crash_notice = Some(*report);
+            }
+            UiUpdate::PlayerExplanation(explanation) => {
+                self.draft_screen
+                    .modal_layer
+                    .player_detail
+                    .update(PlayerDetailModalMessage::Explained(explanation));
+            }
+            UiUpdate::PlayerComparison(comparison) => {
+                self.draft_screen
+                    .modal_layer
+                    .compare
+                    .update(CompareModalMessage::Loaded(comparison));
+            }
+            UiUpdate::TimeMachine { pick_number, snapshot } => {
+                self.draft_screen
+                    .modal_layer
+                    .time_machine
+                    .update(TimeMachineModalMessage::Loaded { pick_number, snapshot });
+            }
             UiUpdate::ModeChanged(mode) => {
                 self.confirm_exit_settings.open = false;
                 if let AppMode::Settings(section) = &mode {
@@ -274,8 +435,72 @@ impl App {
         }
 
         ds.available_players = snapshot.available_players;
+        ds.available_generation += 1;
         ds.positional_scarcity = snapshot.positional_scarcity;
         ds.draft_log = snapshot.draft_log;
+        // Keep showing my edits the app hasn't got to yet.
+        ds.pending_edits
+            .reapply(&mut ds.available_players, &mut ds.draft_log, &snapshot.ambiguous_picks);
+        ds.refresh_nominated();
+        ds.out_of_pool_picks = snapshot.out_of_pool_picks;
+        // A broken invariant means the advice can't be trusted: say so once.
+        let broken = snapshot
+            .invariant_violations
+            .iter()
+            .find(|v| !ds.invariant_violations.contains(v));
+        if let Some(violation) = broken {
+            ds.file_notice = Some(Err(format!("State check failed: {violation}")));
+        }
+        ds.invariant_violations = snapshot.invariant_violations;
+        ds.keeper_values = snapshot.keeper_values;
+        // Import the prep sheet once its names are reconciled against the
+        // valued pool: pin the targets, mark the avoids, and report the
+        // names that matched nobody.
+        if snapshot.prep != ds.prep && !ds.available_players.is_empty() {
+            let names = |keep: fn(&PrepEntry) -> bool| {
+                let matched = |e: &&PrepEntry| keep(e) && !snapshot.prep_unmatched.contains(&e.player);
+                snapshot.prep.iter().filter(matched).map(|e| e.player.clone()).collect()
+            };
+            ds.main_panel.available.update(AvailablePanelMessage::ImportPrep {
+                targets: names(|e| e.target),
+                avoid: names(|e| e.avoid),
+            });
+            ds.file_notice = Some(prep_report(snapshot.prep.len(), &snapshot.prep_unmatched));
+            ds.prep = snapshot.prep;
+        }
+        // Ask which player an ambiguous pick was, unless I'm busy elsewhere.
+        ds.modal_layer
+            .resolve_pick
+            .update(ResolvePickModalMessage::SetPending(snapshot.ambiguous_picks));
+        if !ds.modal_layer.has_active_modal() {
+            ds.modal_layer.resolve_pick.update(ResolvePickModalMessage::Show);
+        }
+        ds.modal_layer.session_stats.stats = snapshot.session_stats;
+        ds.modal_layer.dart_board.groups = snapshot.dart_board;
+        ds.shopping_list = snapshot.shopping_list;
+        // Speak up once when a hole turns into a warning or worse.
+        let escalated = snapshot.roster_holes.iter().find(|hole| {
+            hole.alert >= HoleAlert::Warning
+                && !ds
+                    .roster_holes
+                    .iter()
+                    .any(|old| old.position == hole.position && old.alert >= hole.alert)
+        });
+        if let Some(hole) = escalated {
+            ds.file_notice = Some(Err(format!(
+                "{}: {} {} left for {} teams",
+                hole.alert.label(),
+                hole.players_left,
+                hole.position.display_str(),
+                hole.teams_needing,
+            )));
+        }
+        ds.roster_holes = snapshot.roster_holes;
+        ds.plan_b = snapshot.plan_b;
+        ds.nomination_turn = snapshot.nomination_turn;
+        ds.snake = snapshot.snake;
+        ds.sidebar.plan.set_advice(snapshot.nomination_advice);
+        ds.sidebar.plan.set_script(snapshot.nomination_script);
         ds.my_roster = snapshot.my_roster;
 
         ds.budget = BudgetStatus {
@@ -283,15 +508,35 @@ impl App {
             remaining: snapshot.budget_remaining,
             cap: snapshot.salary_cap,
             inflation_rate: snapshot.inflation_rate,
+            raw_inflation_rate: snapshot.raw_inflation_rate,
             max_bid: snapshot.max_bid,
             avg_per_slot: snapshot.avg_per_slot,
             hitting_spent: snapshot.hitting_spent,
             hitting_target: snapshot.hitting_target,
             pitching_spent: snapshot.pitching_spent,
             pitching_target: snapshot.pitching_target,
+            hitting_split: snapshot.hitting_split,
+            split_learned: snapshot.split_learned,
+            percent_of_cap: snapshot.percent_of_cap,
         };
+        ds.main_panel.set_money(ds.budget.money());
+        ds.main_panel.available.set_ranked(ds.snake.is_some());
+        ds.main_panel.available.set_custom_stats(snapshot.custom_stats.clone());
+        ds.modal_layer.player_detail.custom_stats = snapshot.custom_stats;
+        // Toast the picks since the last snapshot; those there at the
+        // first one are history.
+        let toasted = ds.toasted_picks.unwrap_or(ds.draft_log.len()).min(ds.draft_log.len());
+        if snapshot.pick_toasts {
+            let now = std::time::Instant::now();
+            for pick in &ds.draft_log[toasted..] {
+                let value = snapshot.pick_values.get(&pick.pick_number).copied();
+                ds.toasts.push_pick(pick, value, ds.budget.money(), now);
+            }
+        }
+        ds.toasted_picks = Some(ds.draft_log.len());
 
         ds.inflation = snapshot.inflation_rate;
+        ds.bid_increment = snapshot.bid_increment;
 
         ds.team_summaries = snapshot
             .team_snapshots
@@ -301,10 +546,26 @@ impl App {
                 budget_remaining: ts.budget_remaining,
                 slots_filled: ts.slots_filled,
                 total_slots: ts.total_slots,
+                max_bid: ts.max_bid,
+                buys_10: ts.buys_10,
+                buys_20: ts.buys_20,
+                nominations: ts.nominations,
             })
             .collect();
 
+        ds.refresh_bid_ladder();
         ds.llm_configured = snapshot.llm_configured;
+        ds.recalc_snoozed = snapshot.recalc_snoozed;
+    }
+
+    /// Report render-loop load: the average frame time while degraded, or
+    /// `None` once rendering keeps up again. Degraded rendering is shown in
+    /// the status bar and caps the long tables at `DEGRADED_ROW_LIMIT` rows.
+    pub fn set_render_load(&mut self, degraded_average: Option<Duration>) {
+        let ds = &mut self.draft_screen;
+        ds.render_load = degraded_average;
+        ds.main_panel
+            .set_row_limit(degraded_average.map(|_| DEGRADED_ROW_LIMIT));
     }
 
     pub fn settings_is_editing(&self) -> bool {
@@ -343,6 +604,11 @@ impl App {
                 }
             }
         }
+        // Drawn last so they sit on top of every screen.
+        if let Some(progress) = &self.recalc_progress {
+            super::recalc_progress::view(frame, frame.area(), progress);
+        }
+        self.ws_warning.view(frame, frame.area());
     }
 }
 
@@ -371,6 +637,8 @@ pub enum AppMessage {
     Settings(SettingsMessage),
     /// Delegate a message to the onboarding screen.
     Onboarding(OnboardingMessage),
+    /// Delegate a message to the WebSocket bind-failure overlay.
+    WsWarning(WsWarningMessage),
     /// Fired by the 500ms `TimerRecipe`. Used for blinking indicators and
     /// other periodic UI refreshes. Increments `App::tick_count`.
     Tick,
@@ -410,13 +678,19 @@ impl App {
                 onboarding::update(
                     &step,
                     &mut self.llm_setup,
+                    &mut self.league_setup,
                     &mut self.strategy_setup,
                     m,
                 )
                 .map(Action::Command)
             }
+            AppMessage::WsWarning(m) => {
+                self.ws_warning.update(m);
+                None
+            }
             AppMessage::Tick => {
                 self.tick_count = self.tick_count.wrapping_add(1);
+                self.draft_screen.toasts.expire(std::time::Instant::now());
                 None
             }
         }
@@ -479,12 +753,33 @@ impl App {
             AppMode::Onboarding(step) => onboarding::subscription(
                 step,
                 &self.llm_setup,
+                &self.league_setup,
                 &self.strategy_setup,
                 kb,
             )
             .map(AppMessage::Onboarding),
         };
 
-        Subscription::batch([global, timer_sub, mode_sub])
+        // The bind-failure overlay captures input above every screen while open.
+        let ws_warning_sub = self.ws_warning.subscription(kb).map(AppMessage::WsWarning);
+
+        Subscription::batch([global, timer_sub, ws_warning_sub, mode_sub])
+    }
+}
+
+/// The notice after importing the prep sheet: how many players came in, or
+/// the names that matched nobody.
+fn prep_report(imported: usize, unmatched: &[String]) -> Result<String, String> {
+    const SHOWN: usize = 3;
+    if unmatched.is_empty() {
+        return Ok(format!("Prep sheet: imported {imported} players"));
+    }
+    let mut names = unmatched[..unmatched.len().min(SHOWN)].join(", ");
+    if unmatched.len() > SHOWN {
+        names.push_str(&format!(" and {} more", unmatched.len() - SHOWN));
     }
+    Err(format!(
+        "Prep sheet: {} of {imported} names matched no player: {names}",
+        unmatched.len()
+    ))
 }
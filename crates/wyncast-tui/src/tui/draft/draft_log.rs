@@ -1,8 +1,9 @@
+use std::cell::Cell;
 use std::collections::HashMap;
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Margin, Rect};
-use ratatui::style::{Color, Style};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{
     Block, Borders, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
@@ -23,18 +24,42 @@ pub enum DraftLogMessage {
 
 const PAGE_SIZE: usize = 20;
 
+/// Appended to picks of players outside the league's player pool.
+const OUT_OF_POOL_TAG: &str = "  [outside pool]";
+
 /// Stateful draft log panel component.
+///
+/// Scrolling moves a cursor over the picks (newest first); the viewport
+/// follows the cursor. The highlighted pick is the one the time machine
+/// opens at.
 pub struct DraftLogPanel {
+    /// Cursor position, as an index into the newest-first list.
     scroll: ScrollState,
+    /// First visible row, adjusted at render time to keep the cursor visible.
+    viewport: Cell<usize>,
+    /// Most rows to draw regardless of height; set while rendering is slow.
+    row_limit: Option<usize>,
 }
 
 impl DraftLogPanel {
     pub fn new() -> Self {
         Self {
             scroll: ScrollState::new(),
+            viewport: Cell::new(0),
+            row_limit: None,
         }
     }
 
+    pub fn set_row_limit(&mut self, limit: Option<usize>) {
+        self.row_limit = limit;
+    }
+
+    /// The pick under the cursor, if any.
+    pub fn selected_pick<'a>(&self, picks: &'a [DraftPick]) -> Option<&'a DraftPick> {
+        let last = picks.len().checked_sub(1)?;
+        picks.iter().rev().nth(self.scroll.offset().min(last))
+    }
+
     pub fn update(&mut self, msg: DraftLogMessage) -> Option<Action> {
         match msg {
             DraftLogMessage::Scroll(dir) => {
@@ -61,7 +86,17 @@ impl DraftLogPanel {
         }
     }
 
-    pub fn view(&self, frame: &mut Frame, area: Rect, picks: &[DraftPick], available_players: &[PlayerValuation], focused: bool) {
+    /// Render the log. Picks named in `out_of_pool` (drafted players outside
+    /// the league's player pool) are flagged.
+    pub fn view(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        picks: &[DraftPick],
+        available_players: &[PlayerValuation],
+        out_of_pool: &[String],
+        focused: bool,
+    ) {
         let focus_border = focused_border_style(focused, Style::default());
 
         if picks.is_empty() {
@@ -80,20 +115,44 @@ impl DraftLogPanel {
         let value_map = build_value_map(available_players);
 
         let visible_rows = (area.height as usize).saturating_sub(2);
+        let visible_rows = self.row_limit.map_or(visible_rows, |limit| visible_rows.min(limit));
         let all_picks: Vec<_> = picks.iter().rev().collect();
         let total = all_picks.len();
 
-        let scroll_offset = self.scroll.clamped_offset(total, visible_rows);
+        // Clamp the cursor to the last pick, then scroll the viewport just
+        // enough to keep it on screen.
+        let cursor = self.scroll.clamped_offset(total, 1);
+        let rows = visible_rows.max(1);
+        let mut scroll_offset = self.viewport.get().min(total.saturating_sub(rows));
+        if cursor < scroll_offset {
+            scroll_offset = cursor;
+        } else if cursor >= scroll_offset + rows {
+            scroll_offset = cursor + 1 - rows;
+        }
+        self.viewport.set(scroll_offset);
 
         let items: Vec<ListItem> = all_picks
             .into_iter()
+            .enumerate()
             .skip(scroll_offset)
-            .take(visible_rows.max(1))
-            .map(|pick| {
+            .take(rows)
+            .map(|(i, pick)| {
                 let value = value_map.get(pick.player_name.as_str()).copied();
-                let color = pick_color(pick.price, value);
-                let text = format_pick(pick);
-                ListItem::new(Line::from(Span::styled(text, Style::default().fg(color))))
+                let outside_pool = out_of_pool.contains(&pick.player_name);
+                let color = if outside_pool {
+                    Color::Magenta
+                } else {
+                    pick_color(pick.price, value)
+                };
+                let mut style = Style::default().fg(color);
+                if i == cursor {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                let mut text = format_pick(pick);
+                if outside_pool {
+                    text.push_str(OUT_OF_POOL_TAG);
+                }
+                ListItem::new(Line::from(Span::styled(text, style)))
             })
             .collect();
 
@@ -321,6 +380,48 @@ mod tests {
         assert_eq!(panel.key_to_message(key(KeyCode::Esc)), None);
     }
 
+    // -- Cursor --
+
+    #[test]
+    fn selected_pick_is_newest_first_and_clamped() {
+        let mut panel = DraftLogPanel::new();
+        let picks = vec![
+            make_pick(1, "Player 1", "SP", 30),
+            make_pick(2, "Player 2", "C", 15),
+            make_pick(3, "Player 3", "1B", 20),
+        ];
+        assert!(panel.selected_pick(&[]).is_none());
+        assert_eq!(panel.selected_pick(&picks).unwrap().pick_number, 3);
+
+        panel.update(DraftLogMessage::Scroll(ScrollDirection::Down));
+        assert_eq!(panel.selected_pick(&picks).unwrap().pick_number, 2);
+
+        panel.update(DraftLogMessage::Scroll(ScrollDirection::Bottom));
+        assert_eq!(panel.selected_pick(&picks).unwrap().pick_number, 1);
+    }
+
+    #[test]
+    fn view_keeps_cursor_visible() {
+        let backend = ratatui::backend::TestBackend::new(60, 5);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut panel = DraftLogPanel::new();
+        let picks: Vec<DraftPick> = (1..=10)
+            .map(|n| make_pick(n, &format!("Player {n}"), "SP", 10))
+            .collect();
+        for _ in 0..6 {
+            panel.update(DraftLogMessage::Scroll(ScrollDirection::Down));
+        }
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &picks, &[], &[], true))
+            .unwrap();
+
+        // 3 visible rows; cursor on the 7th newest pick (#4) sits on the last row.
+        assert_eq!(panel.viewport.get(), 4);
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..60).map(|x| buffer[(x, 3)].symbol()).collect();
+        assert!(row.contains("Player 4"), "got: {row}");
+    }
+
     // -- format_pick --
 
     #[test]
@@ -384,7 +485,7 @@ mod tests {
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
         let panel = DraftLogPanel::new();
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], &[], false))
+            .draw(|frame| panel.view(frame, frame.area(), &[], &[], &[], false))
             .unwrap();
     }
 
@@ -398,7 +499,7 @@ mod tests {
             make_pick(2, "Player 2", "C", 15),
         ];
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &picks, &[], false))
+            .draw(|frame| panel.view(frame, frame.area(), &picks, &[], &[], false))
             .unwrap();
     }
 
@@ -408,7 +509,30 @@ mod tests {
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
         let panel = DraftLogPanel::new();
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], &[], true))
+            .draw(|frame| panel.view(frame, frame.area(), &[], &[], &[], true))
             .unwrap();
     }
+
+    #[test]
+    fn view_flags_picks_outside_the_pool() {
+        let backend = ratatui::backend::TestBackend::new(80, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let panel = DraftLogPanel::new();
+        let picks = vec![
+            make_pick(1, "Aaron Judge", "RF", 50),
+            make_pick(2, "Mookie Betts", "SS", 40),
+        ];
+        let out_of_pool = vec!["Aaron Judge".to_string()];
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &picks, &[], &out_of_pool, false))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        let judge = rows.iter().find(|r| r.contains("Aaron Judge")).unwrap();
+        let betts = rows.iter().find(|r| r.contains("Mookie Betts")).unwrap();
+        assert!(judge.contains("[outside pool]"));
+        assert!(!betts.contains("[outside pool]"));
+    }
 }
@@ -0,0 +1,316 @@
+// Help registry for the draft screen.
+//
+// Declares, for every tab and focusable panel, what its columns mean, which
+// keys work there, and what its colors say. The `?` overlay renders whichever
+// entry matches the current focus. Topics are resolved with exhaustive
+// matches over `FocusPanel` and `TabId`, so a new panel or tab does not
+// compile until it has an entry, and a tab's feature keys are read from
+// `TabId::supports` so they follow the capability table automatically.
+
+use ratatui::style::Color;
+
+use crate::protocol::{TabFeature, TabId};
+use crate::tui::FocusPanel;
+
+/// What the help overlay can describe: one of the tabs, or a sidebar panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpTopic {
+    Analysis,
+    Available,
+    DraftLog,
+    Teams,
+    Roster,
+    ShoppingList,
+    Scarcity,
+    Budget,
+    NominationPlan,
+}
+
+/// One widget's help: a one-line summary, then its columns, keys and colors
+/// as `(label, meaning)` pairs.
+#[derive(Debug, Clone, Copy)]
+pub struct HelpEntry {
+    pub title: &'static str,
+    pub about: &'static str,
+    pub columns: &'static [(&'static str, &'static str)],
+    pub keys: &'static [(&'static str, &'static str)],
+    pub colors: &'static [(Color, &'static str)],
+}
+
+/// Keys that work on every tab and panel.
+pub const GLOBAL_KEYS: &[(&str, &str)] = &[
+    ("1-4", "Switch tab"),
+    ("Tab/S-Tab", "Focus the next/previous panel"),
+    ("j/k, PgUp/PgDn", "Scroll the focused panel"),
+    ("y/n/+/5/c", "Bid intent while a player is on the block"),
+    ("s", "Session stats"),
+    ("d", "$1 dart board for the endgame"),
+    ("w", "Why the last update changed what it did"),
+    ("a", "Say which player an ambiguous pick was"),
+    ("z", "Snooze recalculation in a pick burst / resume"),
+    ("u / ^R", "Undo / redo a manual edit"),
+    ("^D / ^E / ^K", "Dump state / audit projections / keeper report"),
+    (",", "Settings"),
+    ("r", "Resync with the extension and the app"),
+    ("?", "This help"),
+    ("q", "Quit"),
+];
+
+impl HelpTopic {
+    /// Every topic, tabs first.
+    pub const ALL: [HelpTopic; 9] = [
+        HelpTopic::Analysis,
+        HelpTopic::Available,
+        HelpTopic::DraftLog,
+        HelpTopic::Teams,
+        HelpTopic::Roster,
+        HelpTopic::ShoppingList,
+        HelpTopic::Scarcity,
+        HelpTopic::Budget,
+        HelpTopic::NominationPlan,
+    ];
+
+    /// The topic for the focused panel, or for the active tab when the main
+    /// panel (or nothing) has focus.
+    pub fn resolve(focus: Option<FocusPanel>, tab: TabId) -> Self {
+        match focus {
+            None | Some(FocusPanel::MainPanel) => match tab {
+                TabId::Analysis => HelpTopic::Analysis,
+                TabId::Available => HelpTopic::Available,
+                TabId::DraftLog => HelpTopic::DraftLog,
+                TabId::Teams => HelpTopic::Teams,
+            },
+            Some(FocusPanel::Roster) => HelpTopic::Roster,
+            Some(FocusPanel::ShoppingList) => HelpTopic::ShoppingList,
+            Some(FocusPanel::Scarcity) => HelpTopic::Scarcity,
+            Some(FocusPanel::Budget) => HelpTopic::Budget,
+            Some(FocusPanel::NominationPlan) => HelpTopic::NominationPlan,
+        }
+    }
+
+    /// The tab this topic describes, if it is one.
+    pub fn tab(self) -> Option<TabId> {
+        match self {
+            HelpTopic::Analysis => Some(TabId::Analysis),
+            HelpTopic::Available => Some(TabId::Available),
+            HelpTopic::DraftLog => Some(TabId::DraftLog),
+            HelpTopic::Teams => Some(TabId::Teams),
+            HelpTopic::Roster
+            | HelpTopic::ShoppingList
+            | HelpTopic::Scarcity
+            | HelpTopic::Budget
+            | HelpTopic::NominationPlan => None,
+        }
+    }
+
+    /// The keys specific to this topic: the entry's own, then one per tab
+    /// feature the tab supports.
+    pub fn keys(self) -> Vec<(&'static str, &'static str)> {
+        let mut keys = self.entry().keys.to_vec();
+        if let Some(tab) = self.tab() {
+            keys.extend(
+                TabFeature::ALL
+                    .into_iter()
+                    .filter(|&feature| tab.supports(feature))
+                    .map(feature_key),
+            );
+        }
+        keys
+    }
+
+    /// The registry entry for this topic.
+    pub fn entry(self) -> HelpEntry {
+        match self {
+            HelpTopic::Analysis => HelpEntry {
+                title: "Analysis",
+                about: "The verdict on the player on the block, then the LLM's take.",
+                columns: &[],
+                keys: &[],
+                colors: &[
+                    (Color::DarkGray, "Algorithmic only, no LLM analysis"),
+                    (Color::Yellow, "Streaming"),
+                    (Color::Green, "Complete"),
+                    (Color::Red, "The analysis failed"),
+                    (Color::Cyan, "Border when focused"),
+                ],
+            },
+            HelpTopic::Available => HelpEntry {
+                title: "Available Players",
+                about: "Undrafted players, most valuable first.",
+                columns: &[
+                    ("#", "Rank in the current view"),
+                    ("Pos", "Eligible positions"),
+                    ("$Val", "The model's dollar value"),
+                    ("VOR", "Value over replacement at the best position"),
+                    ("Cliff", "$ gap to the next best at the position"),
+                    ("zTotal", "Sum of the weighted category z-scores"),
+                ],
+                keys: &[],
+                colors: &[
+                    (Color::Yellow, "The player on the block"),
+                    (Color::Cyan, "Pinned player (marked *)"),
+                ],
+            },
+            HelpTopic::DraftLog => HelpEntry {
+                title: "Draft Log",
+                about: "Every pick so far: #pick Team: Player (Pos) -- $price.",
+                columns: &[],
+                keys: &[],
+                colors: &[
+                    (Color::Green, "Bargain: under 90% of value"),
+                    (Color::White, "Fair price"),
+                    (Color::Red, "Overpay: over 110% of value"),
+                    (Color::Magenta, "Player from outside the valued pool"),
+                ],
+            },
+            HelpTopic::Teams => HelpEntry {
+                title: "Teams",
+                about: "Every team's budget, roster and nomination habits.",
+                columns: &[
+                    ("Budget", "Dollars left"),
+                    ("Filled", "Roster slots filled / total"),
+                    ("Remaining", "Open roster slots"),
+                    ("Max Bid", "Most the team can bid on one player"),
+                    ("$10+/$20+", "$10 and $20 players the team can still afford"),
+                    ("Noms", "Own nominations bought / made"),
+                    ("Nominates", "What the team's nominations suggest"),
+                ],
+                keys: &[],
+                colors: &[],
+            },
+            HelpTopic::Roster => HelpEntry {
+                title: "My Roster",
+                about: "Your roster slots and what you paid for each player.",
+                columns: &[("POS: Name ($)", "A filled slot; [empty] when open")],
+                keys: &[("e", "Move the top player to their next open slot")],
+                colors: &[(Color::Yellow, "Slots the player on the block could fill")],
+            },
+            HelpTopic::ShoppingList => HelpEntry {
+                title: "Shopping List",
+                about: "Your open slots and what to spend on each.",
+                columns: &[
+                    ("xN", "Open slots at the position"),
+                    ("$/slot", "Suggested spend per slot"),
+                    ("left", "Players above replacement who fit"),
+                    ("!N need", "Teams needing it, when players run short"),
+                ],
+                keys: &[],
+                colors: &[
+                    (Color::Yellow, "Under two players per open slot or team"),
+                    (Color::Red, "Fewer players than slots; none to spare"),
+                ],
+            },
+            HelpTopic::Scarcity => HelpEntry {
+                title: "Positional Scarcity",
+                about: "How many players above replacement are left at each position.",
+                columns: &[
+                    (">", "The position of the player on the block"),
+                    ("(n)", "Players above replacement left"),
+                ],
+                keys: &[],
+                colors: &[
+                    (Color::Red, "Critical"),
+                    (Color::Yellow, "High"),
+                    (Color::Blue, "Medium"),
+                    (Color::Green, "Low"),
+                ],
+            },
+            HelpTopic::Budget => HelpEntry {
+                title: "Budget",
+                about: "Your spending against the cap and the room's inflation.",
+                columns: &[
+                    ("Spent", "Spent / cap; hitting and pitching vs targets"),
+                    ("Inflation", "Multiplier applied to every value"),
+                    ("Split", "Hitting/pitching share of the budget"),
+                    ("Max Bid", "Most you can bid and still fill the roster"),
+                    ("Avg/Slot", "Dollars left per open slot"),
+                ],
+                keys: &[],
+                colors: &[
+                    (Color::Green, "Inflation above 1.0; hit/pit under 80%"),
+                    (Color::Yellow, "Hit/pit at 80-100% of target"),
+                    (Color::Red, "Inflation below 1.0; hit/pit over target"),
+                    (Color::Magenta, "Split learned from the room"),
+                    (Color::DarkGray, "Fixed split from the config"),
+                ],
+            },
+            HelpTopic::NominationPlan => HelpEntry {
+                title: "Nomination Plan",
+                about: "Whom to nominate next: the LLM's plan, or picks for the stage.",
+                columns: &[],
+                keys: &[],
+                colors: &[
+                    (Color::DarkGray, "Not yet computed"),
+                    (Color::Yellow, "Streaming"),
+                    (Color::Green, "Ready"),
+                    (Color::Red, "The plan failed"),
+                    (Color::Magenta, "Paused until the extension reconnects"),
+                    (Color::Cyan, "Border when focused"),
+                ],
+            },
+        }
+    }
+}
+
+/// The key and description of a tab feature.
+fn feature_key(feature: TabFeature) -> (&'static str, &'static str) {
+    match feature {
+        TabFeature::Filter => ("/", "Filter by name"),
+        TabFeature::PositionFilter => ("p", "Filter by position"),
+        TabFeature::PlayerDetail => ("i", "Player detail (e inside explains the value)"),
+        TabFeature::Pin => ("f", "Pin or unpin the top player"),
+        TabFeature::Sort => ("o", "Sort by value or by cliff"),
+        TabFeature::Pool => ("h", "All players, hitters only or pitchers only"),
+        TabFeature::Export => ("x", "Export the rows shown to a CSV"),
+        TabFeature::Compare => ("m", "Mark two players to compare side by side"),
+        TabFeature::TimeMachine => ("t", "Time machine at the selected pick"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_topic_has_help() {
+        for topic in HelpTopic::ALL {
+            let entry = topic.entry();
+            assert!(!entry.title.is_empty() && !entry.about.is_empty(), "{topic:?}");
+            assert!(
+                !entry.columns.is_empty() || !topic.keys().is_empty() || !entry.colors.is_empty(),
+                "{topic:?} has nothing to explain"
+            );
+        }
+    }
+
+    #[test]
+    fn focus_picks_the_panel_and_the_main_panel_picks_the_tab() {
+        assert_eq!(HelpTopic::resolve(None, TabId::Teams), HelpTopic::Teams);
+        assert_eq!(
+            HelpTopic::resolve(Some(FocusPanel::MainPanel), TabId::DraftLog),
+            HelpTopic::DraftLog
+        );
+        assert_eq!(
+            HelpTopic::resolve(Some(FocusPanel::Scarcity), TabId::Available),
+            HelpTopic::Scarcity
+        );
+        for topic in HelpTopic::ALL {
+            if let Some(tab) = topic.tab() {
+                assert_eq!(HelpTopic::resolve(None, tab), topic);
+            }
+        }
+    }
+
+    #[test]
+    fn tab_keys_follow_the_feature_table() {
+        let keys: Vec<&str> = HelpTopic::Available.keys().iter().map(|k| k.0).collect();
+        assert_eq!(keys, vec!["/", "p", "i", "f", "o", "h", "x", "m"]);
+        let keys: Vec<&str> = HelpTopic::DraftLog.keys().iter().map(|k| k.0).collect();
+        assert_eq!(keys, vec!["t"]);
+        assert!(HelpTopic::Budget.keys().is_empty());
+    }
+}
@@ -1,8 +1,47 @@
 // AvailablePanel component: filterable, scrollable table of undrafted players.
 //
-// Owns filter state (text filter, filter mode, position filter) and scroll
-// state internally. The parent passes in the player data and nominated player
-// name; the component handles filtering, rendering, and input routing.
+// Owns filter state (text filter, filter mode, position filter), pinned
+// players and scroll state internally. The parent passes in the player data
+// and the nominated player; the component handles filtering, rendering, and
+// input routing. When a player is nominated the table scrolls to their row. Pinned players stay at the top of the table whatever the
+// filters, for the rest of the session. The rest are in value order, or in
+// cliff order: by the dollar gap to the next best available player at their
+// position. The prep sheet's targets arrive pinned, and its avoids are
+// dimmed and marked.
+//
+// Beside my value (with my category weights and punts) each row shows the
+// consensus value, with every category weighted equally. Where the two part
+// by `CONSENSUS_GAP` or more the consensus is colored: green when I value the
+// player above the room, red when the room will pay more than they're worth
+// to me.
+//
+// In a snake draft the players carry no prices: the value column becomes
+// each player's rank on the board (their place in the pool, which the
+// valuation keeps in board order), and the consensus and cliff columns and
+// the value totals are left out.
+//
+// The custom stats from `[display] custom_stats` follow as extra columns,
+// `-` where a player's projection cannot compute one.
+//
+// `m` marks the top row for a quick compare; the mark shows in the title
+// until a second player is marked against it.
+//
+// `h` switches between all players, hitters only and pitchers only, so the
+// two pools can be weighed without the dollar order interleaving them. Each
+// view keeps its own order and scroll position; pinned players only show in
+// the views their pool belongs to.
+//
+// Rendering is virtualized: the filter result and the formatted cells are
+// cached between frames, keyed by the data generation and the filters, and
+// only the rows around the viewport are ever formatted.
+//
+// The top row is tracked by player rather than by index: when new data
+// arrives under the same filters, the scroll offset moves so the same player
+// stays on top, or the nearest one below it if they were drafted. Scrolled
+// to the very top, the table stays there.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Margin, Rect};
@@ -14,7 +53,9 @@ use ratatui::widgets::{
 use ratatui::Frame;
 
 use crate::draft::pick::Position;
+use crate::protocol::AvailableViewRow;
 use crate::tui::action::Action;
+use crate::tui::draft::nominated::NominatedPlayer;
 use crate::tui::scroll::{ScrollDirection, ScrollState};
 use crate::tui::subscription::{
     Subscription, SubscriptionId,
@@ -23,12 +64,29 @@ use crate::tui::subscription::{
     },
 };
 use crate::tui::text_input::TextInput;
-use crate::tui::widgets::focused_border_style;
+use crate::tui::widgets::money::MoneyFormat;
+use crate::tui::widgets::{focused_border_style, table_summary};
+use crate::tui::BudgetStatus;
+use crate::valuation::identity::PlayerRef;
+use crate::valuation::cliff::cliff_values;
 use crate::valuation::zscore::PlayerValuation;
 
 /// Page size for PageUp/PageDown scrolling (matches TUI input convention).
 const PAGE_SIZE: usize = 20;
 
+/// Marks a pinned player's name.
+const PIN_MARK: &str = "* ";
+
+/// Marks the name of a player on my avoid list.
+const AVOID_MARK: &str = "x ";
+
+/// Rows formatted above and below the viewport, so scrolling a line or two
+/// reuses cached rows.
+const ROW_BUFFER: usize = 10;
+
+/// Dollars between my value and the consensus that color the consensus.
+const CONSENSUS_GAP: f64 = 3.0;
+
 /// Messages that can be sent to the AvailablePanel component.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AvailablePanelMessage {
@@ -38,6 +96,77 @@ pub enum AvailablePanelMessage {
     FilterKeyPress(KeyEvent),
     SetPositionFilter(Option<Position>),
     ClearFilters,
+    /// Pin the named player to the top of the table, or unpin them.
+    TogglePin(String),
+    /// Pin or unpin the named player.
+    SetPin { name: String, pinned: bool },
+    /// Pin my prep sheet's targets (those not pinned yet) and mark its
+    /// avoids.
+    ImportPrep { targets: Vec<String>, avoid: Vec<String> },
+    /// Switch between value and cliff order.
+    ToggleSort,
+    /// Switch to the next player pool: all, hitters, pitchers.
+    CyclePool,
+    /// Mark the named player as the first of a quick compare, or clear the
+    /// mark.
+    SetCompareMark(Option<PlayerRef>),
+}
+
+/// Order of the unpinned rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AvailableSort {
+    /// By dollar value, as the players arrive.
+    #[default]
+    Value,
+    /// By cliff value, largest first.
+    Cliff,
+}
+
+/// Which players the table lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AvailablePool {
+    #[default]
+    All,
+    Hitters,
+    Pitchers,
+}
+
+impl AvailablePool {
+    fn next(self) -> Self {
+        match self {
+            AvailablePool::All => AvailablePool::Hitters,
+            AvailablePool::Hitters => AvailablePool::Pitchers,
+            AvailablePool::Pitchers => AvailablePool::All,
+        }
+    }
+
+    fn includes(self, player: &PlayerValuation) -> bool {
+        match self {
+            AvailablePool::All => true,
+            AvailablePool::Hitters => !player.is_pitcher,
+            AvailablePool::Pitchers => player.is_pitcher,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            AvailablePool::All => "Available Players",
+            AvailablePool::Hitters => "Available Hitters",
+            AvailablePool::Pitchers => "Available Pitchers",
+        }
+    }
+}
+
+/// Order and scroll position of a pool's view, kept while another pool is
+/// shown.
+#[derive(Debug, Clone, Copy, Default)]
+struct PoolView {
+    offset: usize,
+    sort: AvailableSort,
 }
 
 /// AvailablePanel component: available players table with integrated filtering.
@@ -46,9 +175,201 @@ pub struct AvailablePanel {
     filter_text: TextInput,
     filter_mode: bool,
     position_filter: Option<Position>,
+    /// Names of the pinned players.
+    pinned: Vec<String>,
+    /// Names of the players on my avoid list.
+    avoided: Vec<String>,
+    sort: AvailableSort,
+    pool: AvailablePool,
+    /// The other pools' views, by `AvailablePool::index`.
+    pool_views: [PoolView; 3],
+    /// The player marked to compare against the next one marked.
+    compare_mark: Option<PlayerRef>,
+    /// How dollar values are shown.
+    money: MoneyFormat,
+    /// Snake draft: ranks instead of prices.
+    ranked: bool,
+    /// Names of the custom stat columns.
+    custom_stats: Vec<String>,
+    /// Most rows to draw regardless of height; set while rendering is slow.
+    row_limit: Option<usize>,
+    /// Filter result and formatted rows from previous frames.
+    row_cache: RefCell<RowCache>,
     sub_id: SubscriptionId,
 }
 
+/// Identifies the data and filters a `RowCache` was built for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RowCacheKey {
+    generation: u64,
+    player_count: usize,
+    filter_text: String,
+    position_filter: Option<Position>,
+    pinned: Vec<String>,
+    avoided: Vec<String>,
+    sort: AvailableSort,
+    pool: AvailablePool,
+    money: MoneyFormat,
+    ranked: bool,
+}
+
+/// Pre-formatted cells of one player row.
+#[derive(Debug)]
+struct FormattedRow {
+    name: String,
+    pinned: bool,
+    avoided: bool,
+    positions: String,
+    dollar_value: String,
+    consensus: String,
+    /// My value less the consensus value.
+    gap: f64,
+    vor: String,
+    cliff: String,
+    total_zscore: String,
+    custom_stats: Vec<String>,
+}
+
+impl FormattedRow {
+    /// `rank` is the player's place on a snake draft's board, shown in
+    /// place of their value.
+    fn new(
+        player: &PlayerValuation,
+        cliff: f64,
+        pinned: bool,
+        avoided: bool,
+        money: MoneyFormat,
+        rank: Option<usize>,
+    ) -> Self {
+        Self {
+            name: player.name.clone(),
+            pinned,
+            avoided,
+            positions: format_positions(&player.positions),
+            dollar_value: match rank {
+                Some(rank) => format!("#{rank}"),
+                None => money.amount(player.dollar_value),
+            },
+            consensus: money.amount(player.consensus_value),
+            gap: player.dollar_value - player.consensus_value,
+            vor: format!("{:.1}", player.vor),
+            cliff: money.amount(cliff),
+            total_zscore: format!("{:.2}", player.total_zscore),
+            custom_stats: player.custom_stats.iter().map(|&v| format_custom_stat(v)).collect(),
+        }
+    }
+}
+
+/// Players passing the filters, with rows formatted lazily as they scroll
+/// into view.
+#[derive(Debug, Default)]
+struct RowCache {
+    key: Option<RowCacheKey>,
+    /// Indices into the player slice, in display order.
+    filtered: Vec<usize>,
+    /// Names of the players in `filtered`, to find them again in new data.
+    names: Vec<String>,
+    /// How many of the first `filtered` entries are pinned players.
+    pinned_count: usize,
+    /// Formatted rows aligned with `filtered`; `None` until first needed.
+    rows: Vec<Option<FormattedRow>>,
+    /// Every player's cliff value, aligned with the player slice.
+    cliffs: Vec<f64>,
+    /// Sum of the filtered players' dollar values.
+    total_value: f64,
+}
+
+impl RowCache {
+    /// Re-run the filters if the data or the filters changed since the last
+    /// frame, dropping all formatted rows.
+    ///
+    /// When only the data changed, returns the new offset for the row that
+    /// was at offset `top`: the same player if still listed, otherwise the
+    /// nearest player below them who is. `None` keeps the offset as it is.
+    fn refresh(&mut self, key: RowCacheKey, players: &[PlayerValuation], top: usize) -> Option<usize> {
+        if self.key.as_ref() == Some(&key) {
+            return None;
+        }
+        let same_filters = self.key.as_ref().is_some_and(|old| {
+            old.filter_text == key.filter_text
+                && old.position_filter == key.position_filter
+                && old.pinned == key.pinned
+                && old.sort == key.sort
+                && old.pool == key.pool
+        });
+        let same_data = self.key.as_ref().is_some_and(|old| {
+            old.generation == key.generation && old.player_count == key.player_count
+        });
+        if !same_data {
+            self.cliffs = cliff_values(players);
+        }
+        (self.filtered, self.pinned_count) = display_order(
+            players,
+            &self.cliffs,
+            key.position_filter.as_ref(),
+            &key.filter_text,
+            &key.pinned,
+            key.sort,
+            key.pool,
+        );
+        self.rows = std::iter::repeat_with(|| None)
+            .take(self.filtered.len())
+            .collect();
+        self.total_value = self.filtered.iter().map(|&i| players[i].dollar_value).sum();
+        self.key = Some(key);
+        let previous = std::mem::replace(
+            &mut self.names,
+            self.filtered.iter().map(|&i| players[i].name.clone()).collect(),
+        );
+
+        if !same_filters || top == 0 {
+            return None;
+        }
+        let position: HashMap<&str, usize> = self
+            .names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+        previous
+            .get(top..)?
+            .iter()
+            .find_map(|name| position.get(name.as_str()).copied())
+    }
+
+    /// Aggregates for the pinned summary row: count, total value and average
+    /// value of the players passing the filters.
+    fn summary(&self, money: MoneyFormat) -> Line<'static> {
+        let count = self.filtered.len();
+        let mut items = vec![format!("{count} players")];
+        if self.pinned_count > 0 {
+            items.push(format!("{} pinned", self.pinned_count));
+        }
+        let ranked = self.key.as_ref().is_some_and(|key| key.ranked);
+        if count > 0 && !ranked {
+            items.push(format!("{} total value", money.amount(self.total_value)));
+            items.push(format!("{} avg", money.average(self.total_value / count as f64)));
+        }
+        table_summary::line(items)
+    }
+
+    /// Format any rows in `range` (indices into `filtered`) not yet formatted.
+    fn materialize(&mut self, players: &[PlayerValuation], range: std::ops::Range<usize>, money: MoneyFormat) {
+        let Some(key) = &self.key else {
+            return;
+        };
+        for i in range {
+            if self.rows[i].is_none() {
+                let player = &players[self.filtered[i]];
+                let avoided = key.avoided.contains(&player.name);
+                let cliff = self.cliffs[self.filtered[i]];
+                let rank = key.ranked.then_some(self.filtered[i] + 1);
+                self.rows[i] = Some(FormattedRow::new(player, cliff, i < self.pinned_count, avoided, money, rank));
+            }
+        }
+    }
+}
+
 impl AvailablePanel {
     pub fn new() -> Self {
         Self {
@@ -56,10 +377,39 @@ impl AvailablePanel {
             filter_text: TextInput::new(),
             filter_mode: false,
             position_filter: None,
+            pinned: Vec::new(),
+            avoided: Vec::new(),
+            sort: AvailableSort::default(),
+            pool: AvailablePool::default(),
+            pool_views: [PoolView::default(); 3],
+            compare_mark: None,
+            money: BudgetStatus::default().money(),
+            ranked: false,
+            custom_stats: Vec::new(),
+            row_limit: None,
+            row_cache: RefCell::new(RowCache::default()),
             sub_id: SubscriptionId::unique(),
         }
     }
 
+    pub fn set_row_limit(&mut self, limit: Option<usize>) {
+        self.row_limit = limit;
+    }
+
+    pub fn set_money(&mut self, money: MoneyFormat) {
+        self.money = money;
+    }
+
+    /// Show board ranks instead of prices, for a snake draft.
+    pub fn set_ranked(&mut self, ranked: bool) {
+        self.ranked = ranked;
+    }
+
+    /// Show a column for each of these custom stats, in order.
+    pub fn set_custom_stats(&mut self, names: Vec<String>) {
+        self.custom_stats = names;
+    }
+
     /// Declare keybindings for the subscription system.
     ///
     /// When filter mode is active, returns a capturing
@@ -126,6 +476,52 @@ impl AvailablePanel {
                 self.scroll.reset();
                 None
             }
+            AvailablePanelMessage::TogglePin(name) => {
+                if let Some(i) = self.pinned.iter().position(|n| *n == name) {
+                    self.pinned.remove(i);
+                } else {
+                    self.pinned.push(name);
+                }
+                None
+            }
+            AvailablePanelMessage::SetPin { name, pinned } => {
+                if pinned != self.pinned.contains(&name) {
+                    return self.update(AvailablePanelMessage::TogglePin(name));
+                }
+                None
+            }
+            AvailablePanelMessage::ImportPrep { targets, avoid } => {
+                for name in targets {
+                    if !self.pinned.contains(&name) {
+                        self.pinned.push(name);
+                    }
+                }
+                self.avoided = avoid;
+                None
+            }
+            AvailablePanelMessage::ToggleSort => {
+                self.sort = match self.sort {
+                    AvailableSort::Value => AvailableSort::Cliff,
+                    AvailableSort::Cliff => AvailableSort::Value,
+                };
+                self.scroll.reset();
+                None
+            }
+            AvailablePanelMessage::CyclePool => {
+                self.pool_views[self.pool.index()] = PoolView { offset: self.scroll.offset(), sort: self.sort };
+                self.pool = self.pool.next();
+                let view = self.pool_views[self.pool.index()];
+                self.sort = view.sort;
+                self.scroll.jump_to(view.offset);
+                // Drop the cached rows, so the next frame does not move the
+                // restored offset to keep the other view's top player.
+                *self.row_cache.get_mut() = RowCache::default();
+                None
+            }
+            AvailablePanelMessage::SetCompareMark(player) => {
+                self.compare_mark = player;
+                None
+            }
         }
     }
 
@@ -144,40 +540,190 @@ impl AvailablePanel {
         &self.filter_text
     }
 
+    /// Names of the pinned players, in the order they were pinned.
+    pub fn pinned(&self) -> &[String] {
+        &self.pinned
+    }
+
+    /// Names of the players on my avoid list.
+    pub fn avoided(&self) -> &[String] {
+        &self.avoided
+    }
+
+    /// The player marked for a quick compare, if any.
+    pub fn compare_mark(&self) -> Option<&PlayerRef> {
+        self.compare_mark.as_ref()
+    }
+
+    /// Current row order.
+    pub fn sort(&self) -> AvailableSort {
+        self.sort
+    }
+
+    /// The players listed: all, hitters or pitchers.
+    pub fn pool(&self) -> AvailablePool {
+        self.pool
+    }
+
     /// Raw scroll offset (for testing/inspection).
     pub fn scroll_offset(&self) -> usize {
         self.scroll.offset()
     }
 
+    /// The player in the first row at the current scroll position, after
+    /// applying the active filters and pins. Used as the target of the
+    /// player detail popup and the pin toggle.
+    pub fn top_player<'a>(&self, players: &'a [PlayerValuation]) -> Option<&'a PlayerValuation> {
+        let cliffs = match self.sort {
+            AvailableSort::Value => Vec::new(),
+            AvailableSort::Cliff => cliff_values(players),
+        };
+        let (order, _) = display_order(
+            players,
+            &cliffs,
+            self.position_filter.as_ref(),
+            self.filter_text.value(),
+            &self.pinned,
+            self.sort,
+            self.pool,
+        );
+        let last = order.len().checked_sub(1)?;
+        Some(&players[order[self.scroll.offset().min(last)]])
+    }
+
+    /// Scroll so the player named `name` is the top row, as the table
+    /// orders them now, or to the top if the filters hide them. Returns
+    /// whether they are listed.
+    pub fn scroll_to_player(&mut self, name: &str, players: &[PlayerValuation]) -> bool {
+        let cliffs = match self.sort {
+            AvailableSort::Value => Vec::new(),
+            AvailableSort::Cliff => cliff_values(players),
+        };
+        let (order, _) = display_order(
+            players,
+            &cliffs,
+            self.position_filter.as_ref(),
+            self.filter_text.value(),
+            &self.pinned,
+            self.sort,
+            self.pool,
+        );
+        // Drop the cached rows, so the next frame does not move the offset
+        // to keep an earlier top player in place.
+        *self.row_cache.get_mut() = RowCache::default();
+        match order.iter().position(|&i| players[i].name == name) {
+            Some(row) => {
+                self.scroll.jump_to(row);
+                true
+            }
+            None => {
+                self.scroll.reset();
+                false
+            }
+        }
+    }
+
+    /// Every row passing the filters, pinned players first and in the
+    /// current order, as the table shows them.
+    pub fn view_rows(&self, players: &[PlayerValuation]) -> Vec<AvailableViewRow> {
+        let cliffs = cliff_values(players);
+        let (order, pinned_count) = display_order(
+            players,
+            &cliffs,
+            self.position_filter.as_ref(),
+            self.filter_text.value(),
+            &self.pinned,
+            self.sort,
+            self.pool,
+        );
+        order
+            .into_iter()
+            .enumerate()
+            .map(|(i, idx)| {
+                let player = &players[idx];
+                AvailableViewRow {
+                    name: player.name.clone(),
+                    team: player.team.clone(),
+                    positions: format_positions(&player.positions),
+                    dollar_value: player.dollar_value,
+                    consensus_value: player.consensus_value,
+                    vor: player.vor,
+                    cliff: cliffs[idx],
+                    total_zscore: player.total_zscore,
+                    pinned: i < pinned_count,
+                }
+            })
+            .collect()
+    }
+
     /// Render the available players table into the given area.
+    ///
+    /// `generation` must change whenever `players` is replaced; while it stays
+    /// the same, the filter result and formatted rows of earlier frames are
+    /// reused.
     pub fn view(
         &self,
         frame: &mut Frame,
         area: Rect,
         players: &[PlayerValuation],
-        nominated_name: Option<&str>,
+        generation: u64,
+        nominated: Option<&NominatedPlayer>,
         focused: bool,
     ) {
-        let filtered = filter_players(
+        let mut cache = self.row_cache.borrow_mut();
+        let anchored = cache.refresh(
+            RowCacheKey {
+                generation,
+                player_count: players.len(),
+                filter_text: self.filter_text.value().to_string(),
+                position_filter: self.position_filter,
+                pinned: self.pinned.clone(),
+                avoided: self.avoided.clone(),
+                sort: self.sort,
+                pool: self.pool,
+                money: self.money,
+                ranked: self.ranked,
+            },
             players,
-            self.position_filter.as_ref(),
-            self.filter_text.value(),
+            self.scroll.offset(),
         );
+        if let Some(offset) = anchored {
+            self.scroll.jump_to(offset);
+        }
+        let filtered_count = cache.filtered.len();
 
-        // Visible row count: subtract 2 (borders) + 1 (header) = 3
-        let visible_rows = (area.height as usize).saturating_sub(3);
+        // Visible row count: subtract 2 (borders) + 1 (summary) + 1 (header) = 4
+        let visible_rows = (area.height as usize).saturating_sub(4);
+        let visible_rows = self.row_limit.map_or(visible_rows, |limit| visible_rows.min(limit));
 
         // Use ScrollState's clamped offset for safe rendering
-        let scroll_offset = self.scroll.clamped_offset(filtered.len(), visible_rows);
+        let scroll_offset = self.scroll.clamped_offset(filtered_count, visible_rows);
+        let visible_end = (scroll_offset + visible_rows.max(1)).min(filtered_count);
+        cache.materialize(
+            players,
+            scroll_offset.saturating_sub(ROW_BUFFER)..(visible_end + ROW_BUFFER).min(filtered_count),
+            self.money,
+        );
+        let cache = &*cache;
 
-        let header = Row::new(vec![
+        // A snake draft ranks rather than prices: no consensus or cliff.
+        let priced = !self.ranked;
+        let mut header_cells = vec![
             Cell::from("#"),
             Cell::from("Name"),
             Cell::from("Pos"),
-            Cell::from("$Val"),
-            Cell::from("VOR"),
-            Cell::from("zTotal"),
-        ])
+            Cell::from(if priced { "$Val" } else { "Rank" }),
+        ];
+        if priced {
+            header_cells.push(Cell::from("Cons"));
+        }
+        header_cells.push(Cell::from("VOR"));
+        if priced {
+            header_cells.push(Cell::from("Cliff"));
+        }
+        header_cells.push(Cell::from("zTotal"));
+        header_cells.extend(self.custom_stats.iter().map(|name| Cell::from(name.as_str())));
+        let header = Row::new(header_cells)
         .style(
             Style::default()
                 .fg(Color::White)
@@ -185,49 +731,79 @@ impl AvailablePanel {
         )
         .bottom_margin(0);
 
-        // Only render the visible slice of rows
-        let visible_filtered: Vec<_> = filtered
-            .iter()
-            .enumerate()
-            .skip(scroll_offset)
-            .take(visible_rows.max(1))
-            .collect();
-
-        let rows: Vec<Row> = visible_filtered
-            .iter()
+        // Only render the visible slice of rows, from the cached cells
+        let rows: Vec<Row> = (scroll_offset..visible_end)
+            .filter_map(|i| Some((i, cache.rows[i].as_ref()?)))
             .map(|(i, p)| {
-                let is_nominated = nominated_name.is_some_and(|name| name == p.name);
+                let is_nominated = nominated.is_some_and(|n| n.is(&p.name));
                 let style = if is_nominated {
                     Style::default()
                         .fg(Color::Black)
                         .bg(Color::Yellow)
                         .add_modifier(Modifier::BOLD)
+                } else if p.pinned {
+                    Style::default().fg(Color::Cyan)
+                } else if p.avoided {
+                    Style::default().fg(Color::DarkGray)
                 } else {
                     Style::default()
                 };
+                let name = if p.pinned {
+                    Cell::from(format!("{PIN_MARK}{}", p.name))
+                } else if p.avoided {
+                    Cell::from(format!("{AVOID_MARK}{}", p.name))
+                } else {
+                    Cell::from(p.name.as_str())
+                };
 
-                Row::new(vec![
+                let mut cells = vec![
                     Cell::from(format!("{}", i + 1)),
-                    Cell::from(p.name.clone()),
-                    Cell::from(format_positions(&p.positions)),
-                    Cell::from(format!("${:.0}", p.dollar_value)),
-                    Cell::from(format!("{:.1}", p.vor)),
-                    Cell::from(format!("{:.2}", p.total_zscore)),
-                ])
-                .style(style)
+                    name,
+                    Cell::from(p.positions.as_str()),
+                    Cell::from(p.dollar_value.as_str()),
+                ];
+                if priced {
+                    cells.push(if is_nominated {
+                        Cell::from(p.consensus.as_str())
+                    } else {
+                        Cell::from(p.consensus.as_str()).style(gap_style(p.gap))
+                    });
+                }
+                cells.push(Cell::from(p.vor.as_str()));
+                if priced {
+                    cells.push(Cell::from(p.cliff.as_str()));
+                }
+                cells.push(Cell::from(p.total_zscore.as_str()));
+                cells.extend(
+                    (0..self.custom_stats.len())
+                        .map(|c| Cell::from(p.custom_stats.get(c).map_or("-", String::as_str))),
+                );
+                Row::new(cells).style(style)
             })
             .collect();
 
-        let title = self.build_title(filtered.len());
+        let title = self.build_title(filtered_count);
 
-        let widths = [
+        let money_width = ratatui::layout::Constraint::Length(self.money.width().max(6));
+        let mut widths = vec![
             ratatui::layout::Constraint::Length(4),
             ratatui::layout::Constraint::Min(16),
             ratatui::layout::Constraint::Length(8),
-            ratatui::layout::Constraint::Length(6),
-            ratatui::layout::Constraint::Length(6),
-            ratatui::layout::Constraint::Length(7),
+            money_width,
         ];
+        if priced {
+            widths.push(money_width);
+        }
+        widths.push(ratatui::layout::Constraint::Length(6));
+        if priced {
+            widths.push(money_width);
+        }
+        widths.push(ratatui::layout::Constraint::Length(7));
+        widths.extend(
+            self.custom_stats
+                .iter()
+                .map(|name| ratatui::layout::Constraint::Length(name.chars().count().max(6) as u16)),
+        );
 
         // Border style priority: filter mode > focus > default.
         let block = if self.filter_mode {
@@ -251,16 +827,16 @@ impl AvailablePanel {
 
         let table = Table::new(rows, widths)
             .header(header)
-            .block(block)
             .row_highlight_style(Style::default().bg(Color::DarkGray))
             .highlight_symbol(">> ");
 
-        frame.render_widget(table, area);
+        let table_area = table_summary::render(frame, area, block, cache.summary(self.money));
+        frame.render_widget(table, table_area);
 
         // Render vertical scrollbar whenever content overflows
-        if filtered.len() > visible_rows {
+        if filtered_count > visible_rows {
             let mut scrollbar_state =
-                ScrollbarState::new(filtered.len().saturating_sub(visible_rows))
+                ScrollbarState::new(filtered_count.saturating_sub(visible_rows))
                     .position(scroll_offset);
             frame.render_stateful_widget(
                 Scrollbar::new(ScrollbarOrientation::VerticalRight),
@@ -275,13 +851,19 @@ impl AvailablePanel {
 
     /// Build the title with filter info and pre-computed count.
     fn build_title(&self, filtered_count: usize) -> Line<'static> {
-        let mut title = String::from("Available Players");
+        let mut title = String::from(self.pool.title());
         if let Some(ref pos) = self.position_filter {
             title.push_str(&format!(" [{}]", pos.display_str()));
         }
         if !self.filter_text.is_empty() {
             title.push_str(&format!(" \"{}\"", self.filter_text.value()));
         }
+        if self.sort == AvailableSort::Cliff {
+            title.push_str(" by cliff");
+        }
+        if let Some(mark) = &self.compare_mark {
+            title.push_str(&format!(" compare: {}", mark.name));
+        }
         title.push_str(&format!(" ({})", filtered_count));
         Line::from(title)
     }
@@ -294,6 +876,64 @@ impl Default for AvailablePanel {
 }
 
 
+/// Indices of the rows to show, in display order, and how many of them lead
+/// the table as pinned players. Only players in `pool` are shown. Pinned
+/// players come first and ignore the filters; the rest are the players
+/// passing them, in `sort` order. `cliffs` is aligned with `players` and only
+/// read when sorting by cliff.
+fn display_order(
+    players: &[PlayerValuation],
+    cliffs: &[f64],
+    position_filter: Option<&Position>,
+    filter_text: &str,
+    pinned: &[String],
+    sort: AvailableSort,
+    pool: AvailablePool,
+) -> (Vec<usize>, usize) {
+    let text_lower = filter_text.to_lowercase();
+    let is_pinned = |p: &PlayerValuation| pinned.contains(&p.name);
+    let mut order: Vec<usize> = players
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| is_pinned(p) && pool.includes(p))
+        .map(|(i, _)| i)
+        .collect();
+    let pinned_count = order.len();
+    order.extend(
+        players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| !is_pinned(p) && pool.includes(p) && matches_filters(p, position_filter, &text_lower))
+            .map(|(i, _)| i),
+    );
+    if sort == AvailableSort::Cliff {
+        order[pinned_count..].sort_by(|&a, &b| cliffs[b].total_cmp(&cliffs[a]));
+    }
+    (order, pinned_count)
+}
+
+/// A custom stat value for display: three decimals for rates, one for
+/// counts, `-` where the player has none.
+pub fn format_custom_stat(value: Option<f64>) -> String {
+    match value {
+        None => "-".into(),
+        Some(v) if v.abs() < 10.0 => format!("{v:.3}"),
+        Some(v) => format!("{v:.1}"),
+    }
+}
+
+/// Green when I value a player `CONSENSUS_GAP` above the room, red when the
+/// room values them that far above me.
+fn gap_style(gap: f64) -> Style {
+    if gap >= CONSENSUS_GAP {
+        Style::default().fg(Color::Green)
+    } else if gap <= -CONSENSUS_GAP {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default()
+    }
+}
+
 /// Filter players by position and text search.
 pub fn filter_players<'a>(
     players: &'a [PlayerValuation],
@@ -304,22 +944,27 @@ pub fn filter_players<'a>(
 
     players
         .iter()
-        .filter(|p| {
-            // Position filter
-            if let Some(pos) = position_filter {
-                if !p.positions.contains(pos) {
-                    return false;
-                }
-            }
-            // Text filter (match on name)
-            if !text_lower.is_empty() && !p.name.to_lowercase().contains(&text_lower) {
-                return false;
-            }
-            true
-        })
+        .filter(|p| matches_filters(p, position_filter, &text_lower))
         .collect()
 }
 
+/// Whether a player passes the position filter and the (lowercased) text
+/// filter.
+fn matches_filters(
+    player: &PlayerValuation,
+    position_filter: Option<&Position>,
+    text_lower: &str,
+) -> bool {
+    // Position filter
+    if let Some(pos) = position_filter {
+        if !player.positions.contains(pos) {
+            return false;
+        }
+    }
+    // Text filter (match on name)
+    text_lower.is_empty() || player.name.to_lowercase().contains(text_lower)
+}
+
 /// Format position list as a compact string (e.g., "1B/OF").
 pub fn format_positions(positions: &[Position]) -> String {
     if positions.is_empty() {
@@ -368,6 +1013,8 @@ mod tests {
                     ("hr".into(), 25.0), ("r".into(), 80.0), ("rbi".into(), 85.0),
                     ("bb".into(), 50.0), ("sb".into(), 10.0), ("avg".into(), 0.273),
                 ]),
+                provenance: HashMap::new(),
+                adjustments: Vec::new(),
             },
             total_zscore: 3.5,
             category_zscores: CategoryZScores::hitter(CategoryValues::zeros(test_registry().len()), 3.5),
@@ -375,6 +1022,9 @@ mod tests {
             initial_vor: 0.0,
             best_position: None,
             dollar_value: dollar,
+            flex_bonus: 0.0,
+            consensus_value: 0.0,
+            custom_stats: Vec::new(),
         }
     }
 
@@ -397,6 +1047,64 @@ mod tests {
         assert!(panel.position_filter().is_none());
     }
 
+    // -- top_player --
+
+    #[test]
+    fn top_player_follows_scroll_and_filters() {
+        let players = vec![
+            make_test_player("Catcher A", vec![Position::Catcher], 30.0),
+            make_test_player("Short B", vec![Position::ShortStop], 20.0),
+            make_test_player("Short C", vec![Position::ShortStop], 10.0),
+        ];
+        let mut panel = AvailablePanel::new();
+        assert_eq!(panel.top_player(&players).unwrap().name, "Catcher A");
+
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        assert_eq!(panel.top_player(&players).unwrap().name, "Short B");
+
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::ShortStop)));
+        assert_eq!(panel.top_player(&players).unwrap().name, "Short C");
+
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::FirstBase)));
+        assert!(panel.top_player(&players).is_none());
+    }
+
+    // -- Pins --
+
+    #[test]
+    fn toggle_pin_adds_and_removes() {
+        let mut panel = AvailablePanel::new();
+        assert_eq!(panel.update(AvailablePanelMessage::TogglePin("Short C".into())), None);
+        panel.update(AvailablePanelMessage::TogglePin("Catcher A".into()));
+        assert_eq!(panel.pinned(), ["Short C", "Catcher A"]);
+
+        panel.update(AvailablePanelMessage::TogglePin("Short C".into()));
+        assert_eq!(panel.pinned(), ["Catcher A"]);
+    }
+
+    #[test]
+    fn pinned_players_lead_and_ignore_filters() {
+        let players = vec![
+            make_test_player("Catcher A", vec![Position::Catcher], 30.0),
+            make_test_player("Short B", vec![Position::ShortStop], 20.0),
+            make_test_player("Short C", vec![Position::ShortStop], 10.0),
+        ];
+        let mut panel = AvailablePanel::new();
+        panel.update(AvailablePanelMessage::TogglePin("Short C".into()));
+        assert_eq!(panel.top_player(&players).unwrap().name, "Short C");
+
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        assert_eq!(panel.top_player(&players).unwrap().name, "Catcher A");
+
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::Catcher)));
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Top));
+        assert_eq!(panel.top_player(&players).unwrap().name, "Short C");
+
+        // A pin for a player who has been drafted just drops out.
+        let rest = vec![players[0].clone()];
+        assert_eq!(panel.top_player(&rest).unwrap().name, "Catcher A");
+    }
+
     // -- Update: ToggleFilterMode --
 
     #[test]
@@ -591,8 +1299,392 @@ mod tests {
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
         let panel = AvailablePanel::new();
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], None, false))
+            .draw(|frame| panel.view(frame, frame.area(), &[], 0, None, false))
+            .unwrap();
+    }
+
+    #[test]
+    fn view_respects_row_limit() {
+        let backend = ratatui::backend::TestBackend::new(100, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut panel = AvailablePanel::new();
+        panel.set_row_limit(Some(1));
+        let players = vec![
+            make_test_player("Player A", vec![Position::Catcher], 20.0),
+            make_test_player("Player B", vec![Position::FirstBase], 15.0),
+        ];
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 0, None, false))
+            .unwrap();
+        let text: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("Player A"));
+        assert!(!text.contains("Player B"));
+    }
+
+    fn formatted_rows(panel: &AvailablePanel) -> usize {
+        panel.row_cache.borrow().rows.iter().filter(|r| r.is_some()).count()
+    }
+
+    fn buffer_text(terminal: &ratatui::Terminal<ratatui::backend::TestBackend>) -> String {
+        terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect()
+    }
+
+    #[test]
+    fn view_formats_only_rows_near_viewport() {
+        let backend = ratatui::backend::TestBackend::new(100, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut panel = AvailablePanel::new();
+        let players: Vec<_> = (0..200)
+            .map(|i| make_test_player(&format!("Player {i}"), vec![Position::Catcher], 1.0))
+            .collect();
+
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        // 26 visible rows plus the buffer below (none above at the top).
+        assert_eq!(formatted_rows(&panel), 26 + ROW_BUFFER);
+
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::PageDown));
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        // Rows from the first frame are kept; only the new window is added.
+        assert_eq!(formatted_rows(&panel), PAGE_SIZE + 26 + ROW_BUFFER);
+        assert!(buffer_text(&terminal).contains("Player 20 "));
+    }
+
+    #[test]
+    fn view_reuses_rows_until_generation_or_filter_changes() {
+        let backend = ratatui::backend::TestBackend::new(100, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut panel = AvailablePanel::new();
+        let before = vec![make_test_player("Player A", vec![Position::Catcher], 20.0)];
+        let after = vec![make_test_player("Player B", vec![Position::Catcher], 20.0)];
+
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &before, 1, None, false))
+            .unwrap();
+        // Same generation: the cached row is drawn.
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &after, 1, None, false))
+            .unwrap();
+        assert!(buffer_text(&terminal).contains("Player A"));
+
+        // New generation: rows are rebuilt from the new data.
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &after, 2, None, false))
             .unwrap();
+        assert!(buffer_text(&terminal).contains("Player B"));
+
+        // A filter change re-runs the filters on the same generation.
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::FirstBase)));
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &after, 2, None, false))
+            .unwrap();
+        assert!(!buffer_text(&terminal).contains("Player B"));
+    }
+
+    /// Draw `players` as data generation `generation`, one row high, and
+    /// return the name of the player in the top row.
+    fn draw_top(panel: &AvailablePanel, players: &[PlayerValuation], generation: u64) -> String {
+        let backend = ratatui::backend::TestBackend::new(100, 5);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), players, generation, None, false))
+            .unwrap();
+        panel.top_player(players).unwrap().name.clone()
+    }
+
+    fn catchers(names: &[&str]) -> Vec<PlayerValuation> {
+        names
+            .iter()
+            .map(|name| make_test_player(name, vec![Position::Catcher], 1.0))
+            .collect()
+    }
+
+    #[test]
+    fn top_row_keeps_its_player_when_others_are_removed() {
+        let mut panel = AvailablePanel::new();
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        assert_eq!(draw_top(&panel, &catchers(&["A", "B", "C", "D", "E"]), 1), "C");
+
+        // A and B drafted: C moves up two rows and stays on top.
+        assert_eq!(draw_top(&panel, &catchers(&["C", "D", "E"]), 2), "C");
+        assert_eq!(panel.scroll_offset(), 0);
+
+        // New data that reorders the players above C follows C too.
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        assert_eq!(draw_top(&panel, &catchers(&["C", "D", "E"]), 3), "D");
+        assert_eq!(draw_top(&panel, &catchers(&["X", "Y", "C", "D", "E"]), 4), "D");
+        assert_eq!(panel.scroll_offset(), 3);
+    }
+
+    #[test]
+    fn removing_the_top_player_moves_to_the_next_one_below() {
+        let mut panel = AvailablePanel::new();
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::Catcher)));
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        let mut players = catchers(&["A", "B", "C", "D"]);
+        players.insert(2, make_test_player("Short", vec![Position::ShortStop], 1.0));
+        assert_eq!(draw_top(&panel, &players, 1), "B");
+
+        // B drafted, and C with them: D was next below under the filter.
+        let players = catchers(&["A", "D"]);
+        assert_eq!(draw_top(&panel, &players, 2), "D");
+        assert_eq!(panel.scroll_offset(), 1);
+
+        // Everyone from the top row down drafted: the offset is clamped.
+        assert_eq!(draw_top(&panel, &catchers(&["A"]), 3), "A");
+    }
+
+    #[test]
+    fn top_of_the_table_and_filter_changes_are_not_anchored() {
+        let panel = AvailablePanel::new();
+        assert_eq!(draw_top(&panel, &catchers(&["A", "B"]), 1), "A");
+        // At the top, a new leader shows on top.
+        assert_eq!(draw_top(&panel, &catchers(&["Z", "A", "B"]), 2), "Z");
+
+        let mut panel = AvailablePanel::new();
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        assert_eq!(draw_top(&panel, &catchers(&["A", "B", "C"]), 1), "B");
+        // A filter change keeps the offset as it is.
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::Catcher)));
+        assert_eq!(draw_top(&panel, &catchers(&["Z", "A", "B", "C"]), 2), "A");
+    }
+
+    #[test]
+    fn cliff_sort_puts_the_biggest_drop_first() {
+        let mut panel = AvailablePanel::new();
+        let players = vec![
+            make_test_player("Outfielder", vec![Position::LeftField], 25.0),
+            make_test_player("Next OF", vec![Position::LeftField], 23.0),
+            make_test_player("Catcher", vec![Position::Catcher], 20.0),
+            make_test_player("Next C", vec![Position::Catcher], 6.0),
+            make_test_player("Last OF", vec![Position::LeftField], 22.0),
+        ];
+        assert_eq!(panel.top_player(&players).unwrap().name, "Outfielder");
+
+        panel.update(AvailablePanelMessage::ToggleSort);
+        assert_eq!(panel.sort(), AvailableSort::Cliff);
+        assert_eq!(panel.top_player(&players).unwrap().name, "Last OF");
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        assert_eq!(panel.top_player(&players).unwrap().name, "Catcher");
+
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 12)).unwrap();
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Available Players by cliff (5)"), "{text}");
+
+        panel.update(AvailablePanelMessage::SetCompareMark(Some(PlayerRef::named("Catcher"))));
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        assert!(buffer_text(&terminal).contains("by cliff compare: Catcher (5)"));
+        panel.update(AvailablePanelMessage::SetCompareMark(None));
+        assert!(text.contains("Cliff"));
+        assert!(text.contains("$14"), "the catcher's cliff");
+
+        panel.update(AvailablePanelMessage::ToggleSort);
+        assert_eq!(panel.sort(), AvailableSort::Value);
+    }
+
+    #[test]
+    fn each_pool_keeps_its_own_order_and_scroll() {
+        let mut panel = AvailablePanel::new();
+        let pitcher = |name: &str, dollar| PlayerValuation {
+            is_pitcher: true,
+            ..make_test_player(name, vec![Position::StartingPitcher], dollar)
+        };
+        let players = vec![
+            make_test_player("Slugger", vec![Position::LeftField], 30.0),
+            pitcher("Ace", 28.0),
+            make_test_player("Catcher", vec![Position::Catcher], 20.0),
+            pitcher("Starter", 12.0),
+            make_test_player("Next C", vec![Position::Catcher], 6.0),
+        ];
+        panel.update(AvailablePanelMessage::TogglePin("Ace".into()));
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        assert_eq!(panel.top_player(&players).unwrap().name, "Slugger");
+
+        panel.update(AvailablePanelMessage::CyclePool);
+        assert_eq!(panel.pool(), AvailablePool::Hitters);
+        let names: Vec<String> = panel.view_rows(&players).into_iter().map(|r| r.name).collect();
+        assert_eq!(names, ["Slugger", "Catcher", "Next C"], "the pinned pitcher stays out");
+        assert_eq!(panel.scroll_offset(), 0);
+        panel.update(AvailablePanelMessage::ToggleSort);
+        panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+
+        panel.update(AvailablePanelMessage::CyclePool);
+        assert_eq!(panel.pool(), AvailablePool::Pitchers);
+        assert_eq!(panel.sort(), AvailableSort::Value);
+        assert_eq!(panel.top_player(&players).unwrap().name, "Ace");
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 12)).unwrap();
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        assert!(buffer_text(&terminal).contains("Available Pitchers (2)"));
+
+        // Back round to all players, then to hitters, each where it was left.
+        panel.update(AvailablePanelMessage::CyclePool);
+        assert_eq!((panel.pool(), panel.sort(), panel.scroll_offset()), (AvailablePool::All, AvailableSort::Value, 1));
+        panel.update(AvailablePanelMessage::CyclePool);
+        assert_eq!((panel.sort(), panel.scroll_offset()), (AvailableSort::Cliff, 1));
+    }
+
+    #[test]
+    fn view_rows_are_the_rows_shown() {
+        let mut panel = AvailablePanel::new();
+        let players = vec![
+            make_test_player("Outfielder", vec![Position::LeftField], 25.0),
+            make_test_player("Catcher", vec![Position::Catcher], 20.0),
+            make_test_player("Next C", vec![Position::Catcher], 6.0),
+            make_test_player("Shortstop", vec![Position::ShortStop], 12.0),
+        ];
+        panel.update(AvailablePanelMessage::TogglePin("Shortstop".into()));
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::Catcher)));
+        panel.update(AvailablePanelMessage::ToggleSort);
+
+        let rows = panel.view_rows(&players);
+        let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["Shortstop", "Catcher", "Next C"]);
+        assert!(rows[0].pinned && !rows[1].pinned);
+        assert_eq!((rows[1].dollar_value, rows[1].cliff), (20.0, 14.0));
+        assert_eq!(rows[1].positions, "C");
+    }
+
+    #[test]
+    fn values_follow_the_money_format() {
+        let mut panel = AvailablePanel::new();
+        let players = vec![make_test_player("Catcher", vec![Position::Catcher], 20.0)];
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 8)).unwrap();
+        panel.set_money(MoneyFormat::new(200, true));
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("10.0%"), "{text}");
+        assert!(text.contains("10.00% avg"), "{text}");
+
+        // Switching back reformats the cached rows.
+        panel.set_money(MoneyFormat::new(200, false));
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        assert!(buffer_text(&terminal).contains("$20"));
+    }
+
+    #[test]
+    fn a_snake_draft_shows_board_ranks_instead_of_prices() {
+        let mut panel = AvailablePanel::new();
+        let players = vec![
+            make_test_player("Catcher", vec![Position::Catcher], 0.0),
+            make_test_player("Shortstop", vec![Position::ShortStop], 0.0),
+        ];
+        panel.set_ranked(true);
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::ShortStop)));
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 8)).unwrap();
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        let text = buffer_text(&terminal);
+        // The board rank, not the place in the filtered list.
+        assert!(text.contains("Rank") && text.contains("#2"), "{text}");
+        assert!(!text.contains("Cons") && !text.contains("Cliff") && !text.contains("total value"), "{text}");
+    }
+
+    #[test]
+    fn consensus_column_colors_the_gap_to_my_value() {
+        let panel = AvailablePanel::new();
+        let mut players = vec![
+            make_test_player("Mine", vec![Position::Catcher], 20.0),
+            make_test_player("Theirs", vec![Position::Catcher], 10.0),
+        ];
+        players[0].consensus_value = 12.0;
+        players[1].consensus_value = 11.0;
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 8)).unwrap();
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Cons"), "{text}");
+        assert!(text.contains("$12"), "{text}");
+
+        assert_eq!(panel.view_rows(&players)[0].consensus_value, 12.0);
+        assert_eq!(gap_style(8.0).fg, Some(Color::Green));
+        assert_eq!(gap_style(-3.0).fg, Some(Color::Red));
+        assert_eq!(gap_style(-1.0).fg, None);
+    }
+
+    #[test]
+    fn view_pins_summary_of_filtered_players() {
+        let backend = ratatui::backend::TestBackend::new(100, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut panel = AvailablePanel::new();
+        let players = vec![
+            make_test_player("Player A", vec![Position::Catcher], 20.0),
+            make_test_player("Player B", vec![Position::FirstBase], 15.0),
+            make_test_player("Player C", vec![Position::Catcher], 4.0),
+        ];
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        assert!(buffer_text(&terminal).contains("3 players \u{b7} $39 total value \u{b7} $13.0 avg"));
+
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::Catcher)));
+        for _ in 0..5 {
+            panel.update(AvailablePanelMessage::Scroll(ScrollDirection::Down));
+        }
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        assert!(buffer_text(&terminal).contains("2 players \u{b7} $24 total value \u{b7} $12.0 avg"));
+    }
+
+    #[test]
+    fn view_marks_pinned_rows() {
+        let backend = ratatui::backend::TestBackend::new(100, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut panel = AvailablePanel::new();
+        let players = vec![
+            make_test_player("Player A", vec![Position::Catcher], 20.0),
+            make_test_player("Player B", vec![Position::FirstBase], 15.0),
+        ];
+        panel.update(AvailablePanelMessage::TogglePin("Player B".into()));
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::Catcher)));
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("* Player B"));
+        assert!(text.find("Player B").unwrap() < text.find("Player A").unwrap());
+        assert!(text.contains("1 pinned"));
+    }
+
+    #[test]
+    fn prep_pins_targets_and_marks_avoids() {
+        let backend = ratatui::backend::TestBackend::new(100, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut panel = AvailablePanel::new();
+        let players = vec![
+            make_test_player("Player A", vec![Position::Catcher], 20.0),
+            make_test_player("Player B", vec![Position::FirstBase], 15.0),
+            make_test_player("Player C", vec![Position::FirstBase], 10.0),
+        ];
+        panel.update(AvailablePanelMessage::TogglePin("Player C".into()));
+        panel.update(AvailablePanelMessage::ImportPrep {
+            targets: vec!["Player C".into(), "Player B".into()],
+            avoid: vec!["Player A".into()],
+        });
+        assert_eq!(panel.pinned(), ["Player C", "Player B"]);
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 1, None, false))
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("x Player A"));
+        assert!(text.find("Player B").unwrap() < text.find("Player A").unwrap());
     }
 
     #[test]
@@ -605,7 +1697,7 @@ mod tests {
             make_test_player("Player B", vec![Position::FirstBase], 15.0),
         ];
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &players, None, false))
+            .draw(|frame| panel.view(frame, frame.area(), &players, 0, None, false))
             .unwrap();
     }
 
@@ -615,7 +1707,7 @@ mod tests {
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
         let panel = AvailablePanel::new();
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], None, true))
+            .draw(|frame| panel.view(frame, frame.area(), &[], 0, None, true))
             .unwrap();
     }
 
@@ -629,7 +1721,7 @@ mod tests {
             't',
         ))));
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], None, false))
+            .draw(|frame| panel.view(frame, frame.area(), &[], 0, None, false))
             .unwrap();
     }
 
@@ -642,10 +1734,50 @@ mod tests {
             make_test_player("Player A", vec![Position::Catcher], 20.0),
             make_test_player("Player B", vec![Position::FirstBase], 15.0),
         ];
+        let nominated = NominatedPlayer { name: "Player A".into(), position: Some(Position::Catcher), tier: Some(1) };
         terminal
             .draw(|frame| {
-                panel.view(frame, frame.area(), &players, Some("Player A"), false)
+                panel.view(frame, frame.area(), &players, 0, Some(&nominated), false)
             })
             .unwrap();
     }
+
+    #[test]
+    fn custom_stats_get_a_column_each() {
+        let backend = ratatui::backend::TestBackend::new(120, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut panel = AvailablePanel::new();
+        panel.set_custom_stats(vec!["K-BB%".into()]);
+        let mut ace = make_test_player("Ace", vec![Position::StartingPitcher], 30.0);
+        ace.custom_stats = vec![Some(0.21)];
+        let players = vec![ace, make_test_player("Bat", vec![Position::Catcher], 20.0)];
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), &players, 0, None, false))
+            .unwrap();
+        let line = |y: u16| -> String { (0..120).map(|x| terminal.backend().buffer()[(x, y)].symbol()).collect() };
+        assert!(line(2).trim_end().trim_end_matches('│').trim_end().ends_with("K-BB%"), "{}", line(2));
+        assert!(line(3).contains("0.210"), "{}", line(3));
+        assert!(line(4).trim_end().trim_end_matches('│').trim_end().ends_with('-'), "{}", line(4));
+    }
+
+    #[test]
+    fn scroll_to_player_puts_them_on_top() {
+        let mut panel = AvailablePanel::new();
+        let players: Vec<PlayerValuation> = (0..30)
+            .map(|i| make_test_player(&format!("Player {i}"), vec![Position::Catcher], 40.0 - i as f64))
+            .collect();
+        assert!(panel.scroll_to_player("Player 12", &players));
+        assert_eq!(panel.scroll_offset(), 12);
+        assert_eq!(panel.top_player(&players).unwrap().name, "Player 12");
+
+        // Pinned players lead the table.
+        panel.update(AvailablePanelMessage::TogglePin("Player 20".into()));
+        assert!(panel.scroll_to_player("Player 12", &players));
+        assert_eq!(panel.scroll_offset(), 13);
+
+        // Filtered out: back to the top.
+        panel.update(AvailablePanelMessage::SetPositionFilter(Some(Position::FirstBase)));
+        assert!(!panel.scroll_to_player("Player 12", &players));
+        assert_eq!(panel.scroll_offset(), 0);
+    }
 }
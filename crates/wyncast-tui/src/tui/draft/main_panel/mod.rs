@@ -1,13 +1,16 @@
 pub mod analysis;
 pub mod available;
 
+use std::time::Duration;
+
 use ratatui::Frame;
 use ratatui::layout::Rect;
 
 use crate::draft::pick::DraftPick;
-use crate::protocol::TabId;
+use crate::protocol::{ExtensionHealth, TabId};
 use crate::tui::TeamSummary;
 use crate::tui::action::Action;
+use crate::tui::widgets::money::MoneyFormat;
 use crate::tui::subscription::Subscription;
 use crate::tui::subscription::keybinding::KeybindManager;
 use crate::valuation::zscore::PlayerValuation;
@@ -15,6 +18,7 @@ use crate::valuation::zscore::PlayerValuation;
 use analysis::{AnalysisPanel, AnalysisPanelMessage};
 use available::{AvailablePanel, AvailablePanelMessage};
 use super::draft_log::{DraftLogPanel, DraftLogMessage};
+use super::nominated::NominatedPlayer;
 use super::teams::{TeamsPanel, TeamsMessage};
 
 /// Messages handled by the MainPanel component.
@@ -52,6 +56,20 @@ impl MainPanel {
         self.active_tab
     }
 
+    /// Cap the rows drawn by the long tables (players, draft log), or lift
+    /// the cap with `None`.
+    pub fn set_row_limit(&mut self, limit: Option<usize>) {
+        self.available.set_row_limit(limit);
+        self.draft_log.set_row_limit(limit);
+    }
+
+    /// Show money in the tables (player values, team budgets) in `money`'s
+    /// format.
+    pub fn set_money(&mut self, money: MoneyFormat) {
+        self.available.set_money(money);
+        self.teams.set_money(money);
+    }
+
     /// Declare keybindings for the subscription system.
     ///
     /// Only the active tab's subscription is returned — inactive panels are
@@ -87,21 +105,38 @@ impl MainPanel {
         frame: &mut Frame,
         area: Rect,
         available_players: &[PlayerValuation],
-        nominated_name: Option<&str>,
+        available_generation: u64,
+        nominated: Option<&NominatedPlayer>,
         draft_log: &[DraftPick],
+        out_of_pool_picks: &[String],
         team_summaries: &[TeamSummary],
+        extension_health: Option<(&ExtensionHealth, Duration)>,
         focused: bool,
     ) {
         match self.active_tab {
             TabId::Analysis => self.analysis.view(frame, area, focused),
             TabId::Available => {
-                self.available.view(frame, area, available_players, nominated_name, focused);
+                self.available.view(
+                    frame,
+                    area,
+                    available_players,
+                    available_generation,
+                    nominated,
+                    focused,
+                );
             }
             TabId::DraftLog => {
-                self.draft_log.view(frame, area, draft_log, available_players, focused);
+                self.draft_log.view(
+                    frame,
+                    area,
+                    draft_log,
+                    available_players,
+                    out_of_pool_picks,
+                    focused,
+                );
             }
             TabId::Teams => {
-                self.teams.view(frame, area, team_summaries, focused);
+                self.teams.view(frame, area, team_summaries, extension_health, focused);
             }
         }
     }
@@ -192,7 +227,7 @@ mod tests {
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
         let panel = MainPanel::new();
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], None, &[], &[], false))
+            .draw(|frame| panel.view(frame, frame.area(), &[], 0, None, &[], &[], &[], None, false))
             .unwrap();
     }
 
@@ -203,7 +238,7 @@ mod tests {
         let mut panel = MainPanel::new();
         panel.update(MainPanelMessage::SwitchTab(TabId::Available));
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], None, &[], &[], false))
+            .draw(|frame| panel.view(frame, frame.area(), &[], 0, None, &[], &[], &[], None, false))
             .unwrap();
     }
 
@@ -214,7 +249,7 @@ mod tests {
         let mut panel = MainPanel::new();
         panel.update(MainPanelMessage::SwitchTab(TabId::DraftLog));
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], None, &[], &[], false))
+            .draw(|frame| panel.view(frame, frame.area(), &[], 0, None, &[], &[], &[], None, false))
             .unwrap();
     }
 
@@ -225,7 +260,7 @@ mod tests {
         let mut panel = MainPanel::new();
         panel.update(MainPanelMessage::SwitchTab(TabId::Teams));
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], None, &[], &[], false))
+            .draw(|frame| panel.view(frame, frame.area(), &[], 0, None, &[], &[], &[], None, false))
             .unwrap();
     }
 }
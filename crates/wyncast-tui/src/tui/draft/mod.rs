@@ -1,40 +1,70 @@
 pub mod draft_log;
+pub mod help;
 pub mod main_panel;
 pub mod modal;
+pub mod nominated;
+pub mod optimistic;
 pub mod sidebar;
 pub mod teams;
 
 use std::collections::HashMap;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
 
 use crossterm::event::KeyCode;
+use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::Frame;
 
-use crate::draft::pick::{DraftPick, Position};
-use crate::draft::roster::RosterSlot;
+use crate::crash::CrashReport;
+use crate::journal::{BidIntent, QuickBid};
+use crate::draft::invariants::Violation;
+use crate::draft::ladder::{self, BidLadder};
+use crate::draft::nomination_order::NominationTurn;
+use crate::draft::snake::SnakeStatus;
+use crate::draft::pick::{positions_str, DraftPick};
+use crate::draft::plan_b::PlanB;
+use crate::draft::roster::{next_open_slot, RosterSlot};
+use crate::draft::roster_holes::RosterHole;
+use crate::draft::shopping_list::SlotNeed;
 use crate::protocol::{
-    ConnectionStatus, InstantAnalysis, NominationInfo, TabFeature, TabId, UserCommand,
+    ConnectionStatus, ExtensionHealth, InstantAnalysis, NominationInfo, TabFeature, TabId, UserCommand,
+    WsServerStatus,
 };
 use crate::tui::layout::build_layout;
 use crate::tui::scroll::ScrollDirection;
 use crate::tui::subscription::{Subscription, SubscriptionId};
 use crate::tui::subscription::keybinding::{
-    exact, shift, KeyBindingRecipe, KeybindHint as KbHint, KeybindManager, PRIORITY_NORMAL,
+    ctrl, exact, shift, KeyBindingRecipe, KeybindHint as KbHint, KeybindManager, PRIORITY_NORMAL,
 };
 use crate::tui::widgets;
+use crate::tui::widgets::toast::{ToastQueue, MAX_TOASTS};
 use crate::tui::{BudgetStatus, FocusPanel, TeamSummary};
+use crate::valuation::identity::{self, PlayerRef};
+use crate::valuation::keeper::KeeperValue;
+use crate::valuation::prep::PrepEntry;
 use crate::valuation::scarcity::ScarcityEntry;
 use crate::valuation::zscore::PlayerValuation;
 
 use crate::tui::action::Action;
 
 use draft_log::DraftLogMessage;
+use help::HelpTopic;
 use main_panel::analysis::AnalysisPanelMessage;
 use main_panel::available::AvailablePanelMessage;
 use main_panel::{MainPanel, MainPanelMessage};
 use modal::ModalLayer;
+use nominated::NominatedPlayer;
+use optimistic::{EditKind, PendingEdit, PendingEdits};
+use modal::compare::{CompareModalAction, CompareModalMessage};
+use modal::help::HelpModalMessage;
+use modal::player_detail::{PlayerDetailModalAction, PlayerDetailModalMessage};
 use modal::position_filter::{PositionFilterModalAction, PositionFilterModalMessage};
+use modal::diff_explain::DiffExplainModalMessage;
+use modal::resolve_pick::{ResolvePickModalAction, ResolvePickModalMessage};
+use modal::session_stats::SessionStatsModalMessage;
+use modal::dart_board::DartBoardModalMessage;
+use modal::time_machine::{TimeMachineModalAction, TimeMachineModalMessage};
 use modal::{ModalLayerAction, ModalLayerMessage};
 use sidebar::plan::PlanPanelMessage;
 use sidebar::roster::RosterMessage;
@@ -46,6 +76,10 @@ use teams::TeamsMessage;
 // DraftScreen
 // ---------------------------------------------------------------------------
 
+/// Team counts priced on the bid ladder: a two-team bidding war, and the
+/// price only one team can still pay.
+const LADDER_SURVIVORS: [usize; 2] = [2, 1];
+
 /// Top-level component for the draft mode dashboard.
 ///
 /// Composes MainPanel, Sidebar, ModalLayer, and the stateless status bar,
@@ -56,29 +90,88 @@ pub struct DraftScreen {
     pub main_panel: MainPanel,
     /// Sidebar component: roster, scarcity, plan panels (budget is stateless).
     pub sidebar: Sidebar,
-    /// Draft-mode modal overlays (position filter + quit confirmation).
+    /// Draft-mode modal overlays (position filter, player detail, time
+    /// machine, quit confirmation).
     pub modal_layer: ModalLayer,
     /// Which panel currently has keyboard focus for scroll routing.
     /// `None` means no panel is focused (scroll goes to active tab by default).
     pub focused_panel: Option<FocusPanel>,
     /// WebSocket connection status.
     pub connection_status: ConnectionStatus,
+    /// Port the WebSocket server bound (or its bind failure), once known.
+    pub ws_server: Option<WsServerStatus>,
+    /// The last file written on request (support bundle, projection audit),
+    /// or why it failed, as shown in the status bar.
+    pub file_notice: Option<Result<String, String>>,
+    /// Last measured round trip to the extension (ms) and when it arrived.
+    pub extension_latency: Option<(u64, Instant)>,
+    /// The extension's latest scraping health and when it arrived, shown on
+    /// the Teams tab.
+    pub extension_health: Option<(ExtensionHealth, Instant)>,
+    /// Average frame time while the render loop is degraded.
+    pub render_load: Option<Duration>,
+    /// Picks waiting while recalculation is snoozed (`z`).
+    pub recalc_snoozed: Option<usize>,
+    /// Updates from the app lost since the last resync (`r`).
+    pub ui_desynced: Option<usize>,
     /// Number of picks completed.
     pub pick_number: usize,
     /// Total picks in the draft.
     pub total_picks: usize,
     /// Current active nomination, if any.
     pub current_nomination: Option<NominationInfo>,
+    /// The nominated player as every panel highlights them; follows
+    /// `current_nomination` via `refresh_nominated`.
+    pub nominated: Option<NominatedPlayer>,
     /// Instant analysis for the current nomination.
     pub instant_analysis: Option<InstantAnalysis>,
+    /// My logged bid intent for the current nomination (quick-bid keys).
+    pub bid_intent: Option<BidIntent>,
+    /// Strategic bid amounts for the current nomination.
+    pub bid_ladder: Option<BidLadder>,
     /// User's team budget status.
     pub budget: BudgetStatus,
     /// Current inflation rate.
     pub inflation: f64,
+    /// The league's bid increment, the step of the bid ladder.
+    pub bid_increment: u32,
     /// All available (undrafted) players sorted by value.
     pub available_players: Vec<PlayerValuation>,
+    /// Bumped whenever `available_players` is replaced, so the players table
+    /// knows when its cached rows are stale.
+    pub available_generation: u64,
     /// Chronological list of completed draft picks.
     pub draft_log: Vec<DraftPick>,
+    /// When the pick ticker started rotating.
+    ticker_epoch: Instant,
+    /// Manual edits shown before the app confirmed them.
+    pub pending_edits: PendingEdits,
+    /// Toasts for the latest picks.
+    pub toasts: ToastQueue,
+    /// Picks already toasted (or there before the first snapshot); `None`
+    /// until the first snapshot.
+    pub toasted_picks: Option<usize>,
+    /// Names of drafted players outside the league's player pool.
+    pub out_of_pool_picks: Vec<String>,
+    /// Draft-state invariants the app last found broken.
+    pub invariant_violations: Vec<Violation>,
+    /// Keeper contracts priced against the model.
+    pub keeper_values: Vec<KeeperValue>,
+    /// My prep sheet, names reconciled against the projections.
+    pub prep: Vec<PrepEntry>,
+    /// My open slots by position, with a suggested budget for each.
+    pub shopping_list: Vec<SlotNeed>,
+    /// My open positions running short of players, flagged in the shopping
+    /// list.
+    pub roster_holes: Vec<RosterHole>,
+    /// Substitutes for the player I was just outbid on, in a strip under
+    /// the nomination plan.
+    pub plan_b: Option<PlanB>,
+    /// Whose turn it is to nominate, shown in the status bar.
+    pub nomination_turn: Option<NominationTurn>,
+    /// A snake draft's round, pick and board, shown in place of the
+    /// nomination banner and the budget. `None` in an auction.
+    pub snake: Option<SnakeStatus>,
     /// Summary of each team's draft state.
     pub team_summaries: Vec<TeamSummary>,
     /// User's roster slots (position + optional player).
@@ -92,8 +185,13 @@ pub struct DraftScreen {
     pub analysis_request_id: Option<u64>,
     /// Active plan LLM request ID (for routing LlmUpdate events).
     pub plan_request_id: Option<u64>,
+    /// Active LLM comparison request ID (for routing LlmUpdate events).
+    pub compare_request_id: Option<u64>,
     /// Per-widget scroll offsets (keyed by widget name).
     pub scroll_offset: HashMap<String, usize>,
+    /// Crash report from the previous session, shown in the nomination
+    /// banner until the first nomination arrives.
+    pub crash_notice: Option<CrashReport>,
     /// Stable base ID used to derive state-dependent subscription IDs for
     /// DraftScreen's own keybindings. The actual ID is hashed from this plus
     /// `focused_panel` and `active_tab` so the listener is rebuilt when those
@@ -109,25 +207,163 @@ impl DraftScreen {
             modal_layer: ModalLayer::new(),
             focused_panel: None,
             connection_status: ConnectionStatus::Disconnected,
+            ws_server: None,
+            file_notice: None,
+            extension_latency: None,
+            extension_health: None,
+            render_load: None,
+            recalc_snoozed: None,
+            ui_desynced: None,
             pick_number: 0,
             total_picks: 0,
             current_nomination: None,
+            nominated: None,
             instant_analysis: None,
+            bid_intent: None,
+            bid_ladder: None,
             budget: BudgetStatus::default(),
             inflation: 1.0,
+            bid_increment: 1,
             available_players: Vec::new(),
+            available_generation: 0,
             draft_log: Vec::new(),
+            ticker_epoch: Instant::now(),
+            pending_edits: PendingEdits::default(),
+            toasts: ToastQueue::default(),
+            toasted_picks: None,
+            out_of_pool_picks: Vec::new(),
+            invariant_violations: Vec::new(),
+            keeper_values: Vec::new(),
+            prep: Vec::new(),
+            shopping_list: Vec::new(),
+            roster_holes: Vec::new(),
+            plan_b: None,
+            nomination_turn: None,
+            snake: None,
             team_summaries: Vec::new(),
             my_roster: Vec::new(),
             positional_scarcity: Vec::new(),
             llm_configured: true,
             analysis_request_id: None,
             plan_request_id: None,
+            compare_request_id: None,
             scroll_offset: HashMap::new(),
+            crash_notice: None,
             sub_id_base: SubscriptionId::unique(),
         }
     }
 
+    /// Recompute the bid ladder for the current nomination from every team's
+    /// max bid, jumping past the teams that cannot pay the adjusted value.
+    pub fn refresh_bid_ladder(&mut self) {
+        self.bid_ladder = self.current_nomination.as_ref().map(|nom| {
+            let max_bids: Vec<u32> = self.team_summaries.iter().map(|t| t.max_bid).collect();
+            let threshold = self
+                .instant_analysis
+                .as_ref()
+                .filter(|a| a.player_name == nom.player_name)
+                .map_or(0, |a| a.adjusted_value.round().max(0.0) as u32);
+            ladder::bid_ladder(nom.current_bid, self.bid_increment, &max_bids, threshold, &LADDER_SURVIVORS)
+        });
+    }
+
+    /// Re-derive the nominated player from the current nomination and the
+    /// available players.
+    pub fn refresh_nominated(&mut self) {
+        self.nominated = self
+            .current_nomination
+            .as_ref()
+            .map(|nom| NominatedPlayer::new(nom, &self.available_players));
+    }
+
+    /// Scroll the Available table to the nominated player.
+    pub fn scroll_to_nominated(&mut self) {
+        if let Some(nominated) = &self.nominated {
+            self.main_panel.available.scroll_to_player(&nominated.name, &self.available_players);
+        }
+    }
+
+    /// Show the effect of a command on its way to the app before the app
+    /// answers: manual picks and resolved picks are kept pending until a
+    /// snapshot includes them, and a quick bid updates my intent the way
+    /// the app will.
+    pub fn predict(&mut self, cmd: &UserCommand) {
+        match cmd {
+            UserCommand::ManualPick { player_name, team_idx, price } => {
+                // An unknown team is left to the app to reject.
+                let Some(team) = self.team_summaries.get(*team_idx) else {
+                    return;
+                };
+                let pick = DraftPick {
+                    pick_number: 0,
+                    team_id: String::new(),
+                    team_name: team.name.clone(),
+                    player_name: player_name.clone(),
+                    // As the app records it: the positions of the players
+                    // of that name.
+                    position: match identity::name_positions(&self.available_players, player_name) {
+                        positions if positions.is_empty() => "UTIL".to_string(),
+                        positions => positions_str(&positions),
+                    },
+                    price: *price,
+                    espn_player_id: None,
+                    eligible_slots: vec![],
+                    assigned_slot: None,
+                };
+                let edit = PendingEdit::Pick { pick, removed: None };
+                self.pending_edits.push(edit, &mut self.available_players, &mut self.draft_log);
+                self.available_generation += 1;
+            }
+            UserCommand::ResolvePick { pick_number, team } => {
+                let Some(pick) = self.modal_layer.resolve_pick.pending(*pick_number) else {
+                    return;
+                };
+                let edit = PendingEdit::Resolve {
+                    pick_number: *pick_number,
+                    player_name: pick.player_name.clone(),
+                    position: pick.position.clone(),
+                    team: team.clone(),
+                    removed: None,
+                };
+                self.pending_edits.push(edit, &mut self.available_players, &mut self.draft_log);
+                self.available_generation += 1;
+            }
+            UserCommand::QuickBid(key) => {
+                let Some(nom) = &self.current_nomination else {
+                    return;
+                };
+                // The app's cap: my prep value, else the adjusted value.
+                let cap = self
+                    .prep
+                    .iter()
+                    .find(|e| e.player == nom.player_name)
+                    .and_then(|e| e.my_value)
+                    .or_else(|| {
+                        self.instant_analysis
+                            .as_ref()
+                            .filter(|a| a.player_name == nom.player_name)
+                            .map(|a| a.adjusted_value)
+                    })
+                    .map(|value| value.max(0.0).round() as u32);
+                let intent = match self.bid_intent.take() {
+                    Some(intent) if intent.player_name == nom.player_name => intent,
+                    _ => BidIntent::new(&nom.player_name),
+                };
+                let (action, amount) = intent.resolve(*key, nom.current_bid, cap);
+                self.bid_intent.insert(intent).apply(action, amount);
+            }
+            _ => {}
+        }
+    }
+
+    /// The app rejected my oldest pending edit of `kind`: take it back off
+    /// the board.
+    pub fn reject_edit(&mut self, kind: EditKind) {
+        if self.pending_edits.reject(kind, &mut self.available_players, &mut self.draft_log) {
+            self.available_generation += 1;
+        }
+    }
+
     /// Render the full draft dashboard.
     pub fn view(&self, frame: &mut Frame, keybinds: &[crate::tui::KeybindHint]) {
         let layout = build_layout(frame.area());
@@ -136,70 +372,114 @@ impl DraftScreen {
             frame,
             layout.status_bar,
             self.connection_status,
+            self.ws_server.as_ref(),
+            self.extension_latency.map(|(rtt_ms, at)| (rtt_ms, at.elapsed())),
+            self.render_load,
+            self.recalc_snoozed,
+            self.ui_desynced,
+            self.file_notice.as_ref(),
             self.pick_number,
             self.total_picks,
+            self.nomination_turn.as_ref(),
             self.main_panel.active_tab(),
             self.llm_configured,
         );
-        widgets::nomination_banner::render(
+        widgets::ticker::render(
             frame,
-            layout.nomination_banner,
-            self.current_nomination.as_ref(),
-            self.instant_analysis.as_ref(),
+            layout.ticker,
+            &self.draft_log,
+            self.budget.money(),
+            self.ticker_epoch.elapsed(),
         );
+        match &self.snake {
+            Some(snake) => widgets::snake::render_clock(frame, layout.nomination_banner, snake),
+            None => widgets::nomination_banner::render(
+                frame,
+                layout.nomination_banner,
+                self.current_nomination.as_ref(),
+                self.instant_analysis.as_ref(),
+                self.bid_intent.as_ref(),
+                self.bid_ladder.as_ref(),
+                self.crash_notice.as_ref(),
+            ),
+        }
 
         let main_focused = self.focused_panel == Some(FocusPanel::MainPanel);
         let roster_focused = self.focused_panel == Some(FocusPanel::Roster);
         let scarcity_focused = self.focused_panel == Some(FocusPanel::Scarcity);
         let budget_focused = self.focused_panel == Some(FocusPanel::Budget);
+        let shopping_focused = self.focused_panel == Some(FocusPanel::ShoppingList);
         let nom_plan_focused = self.focused_panel == Some(FocusPanel::NominationPlan);
 
         // Main panel: delegates to active tab
-        let nominated_name = self
-            .current_nomination
-            .as_ref()
-            .map(|n| n.player_name.as_str());
         self.main_panel.view(
             frame,
             layout.main_panel,
             &self.available_players,
-            nominated_name,
+            self.available_generation,
+            self.nominated.as_ref(),
             &self.draft_log,
+            &self.out_of_pool_picks,
             &self.team_summaries,
+            self.extension_health.as_ref().map(|(health, at)| (health, at.elapsed())),
             main_focused,
         );
 
         // Sidebar: roster, scarcity, nomination plan
-        let nominated_position = self
-            .current_nomination
-            .as_ref()
-            .and_then(|n| Position::from_str_pos(&n.position));
+        // Plan B: a strip under the nomination plan after I'm outbid
+        let mut plan_area = layout.nomination_plan;
+        if let Some(ref plan_b) = self.plan_b {
+            let [plan, strip] = Layout::vertical([
+                Constraint::Min(3),
+                Constraint::Length(widgets::plan_b::height(plan_b)),
+            ])
+            .areas(layout.nomination_plan);
+            plan_area = plan;
+            widgets::plan_b::render(frame, strip, plan_b, self.budget.money());
+        }
         self.sidebar.view(
             frame,
             layout.roster,
             layout.scarcity,
-            layout.nomination_plan,
+            plan_area,
             &self.my_roster,
             &self.positional_scarcity,
-            nominated_position.as_ref(),
+            self.nominated.as_ref(),
             roster_focused,
             scarcity_focused,
             nom_plan_focused,
         );
 
-        // Budget: bottom of left column
-        widgets::budget::render(
+        // Shopping list: sidebar, under the roster
+        widgets::shopping_list::render(
             frame,
-            layout.budget,
-            &self.budget,
-            self.scroll_offset.get("budget").copied().unwrap_or(0),
-            budget_focused,
+            layout.shopping_list,
+            &self.shopping_list,
+            &self.roster_holes,
+            self.scroll_offset.get("shopping_list").copied().unwrap_or(0),
+            shopping_focused,
         );
 
+        // Budget (or a snake draft's round and pick): bottom of left column
+        let budget_scroll = self.scroll_offset.get("budget").copied().unwrap_or(0);
+        match &self.snake {
+            Some(snake) => {
+                widgets::snake::render_position(frame, layout.budget, snake, budget_scroll, budget_focused)
+            }
+            None => widgets::budget::render(frame, layout.budget, &self.budget, budget_scroll, budget_focused),
+        }
+
         // Help bar: render keybind hints passed in from App (from kb_manager).
         crate::tui::render_help_bar_draft(frame, layout.help_bar, self.main_panel.available.filter_mode(), self.main_panel.available.filter_text(), keybinds);
 
-        // Modal overlay layer (position filter + quit confirm)
+        // Pick toasts, over the top right of the dashboard.
+        let toast_area = Rect {
+            height: MAX_TOASTS as u16,
+            ..layout.ticker
+        };
+        widgets::toast::render(frame, toast_area.intersection(frame.area()), &self.toasts, Instant::now());
+
+        // Modal overlay layer (position filter, player detail, time machine, quit confirm)
         self.modal_layer.view(frame, frame.area());
     }
 
@@ -221,6 +501,7 @@ impl DraftScreen {
             Some(FocusPanel::Roster) => "roster",
             Some(FocusPanel::Scarcity) => "scarcity",
             Some(FocusPanel::Budget) => "budget",
+            Some(FocusPanel::ShoppingList) => "shopping_list",
             Some(FocusPanel::NominationPlan) => "nom_plan",
             Some(FocusPanel::MainPanel) | None => self.active_widget_key(),
         }
@@ -436,6 +717,7 @@ impl DraftScreen {
                 Some(FocusPanel::Roster) => 3,
                 Some(FocusPanel::Scarcity) => 4,
                 Some(FocusPanel::NominationPlan) => 5,
+                Some(FocusPanel::ShoppingList) => 6,
             };
             fp_disc.hash(&mut hasher);
             // Hash active tab.
@@ -446,6 +728,8 @@ impl DraftScreen {
                 TabId::Teams => 3,
             };
             tab_disc.hash(&mut hasher);
+            let has_nomination = self.current_nomination.is_some();
+            has_nomination.hash(&mut hasher);
             let own_id = SubscriptionId::from_u64(hasher.finish());
 
             let supports_filter = self.main_panel.active_tab().supports(TabFeature::Filter);
@@ -453,6 +737,19 @@ impl DraftScreen {
                 .main_panel
                 .active_tab()
                 .supports(TabFeature::PositionFilter);
+            let supports_player_detail = self
+                .main_panel
+                .active_tab()
+                .supports(TabFeature::PlayerDetail);
+            let supports_time_machine = self
+                .main_panel
+                .active_tab()
+                .supports(TabFeature::TimeMachine);
+            let supports_pin = self.main_panel.active_tab().supports(TabFeature::Pin);
+            let supports_sort = self.main_panel.active_tab().supports(TabFeature::Sort);
+            let supports_pool = self.main_panel.active_tab().supports(TabFeature::Pool);
+            let supports_export = self.main_panel.active_tab().supports(TabFeature::Export);
+            let supports_compare = self.main_panel.active_tab().supports(TabFeature::Compare);
             let has_focus = self.focused_panel.is_some();
 
             let mut recipe = KeyBindingRecipe::<DraftScreenMessage>::new(own_id)
@@ -473,6 +770,56 @@ impl DraftScreen {
                     |_| DraftScreenMessage::OpenSettings,
                     KbHint::new(",", "Settings"),
                 )
+                .bind(
+                    ctrl(KeyCode::Char('d')),
+                    |_| DraftScreenMessage::DumpSupportBundle,
+                    KbHint::new("^D", "Dump state"),
+                )
+                .bind(
+                    ctrl(KeyCode::Char('e')),
+                    |_| DraftScreenMessage::ExportProjectionAudit,
+                    KbHint::new("^E", "Audit projections"),
+                )
+                .bind(
+                    ctrl(KeyCode::Char('k')),
+                    |_| DraftScreenMessage::ExportKeeperReport,
+                    KbHint::new("^K", "Keeper report"),
+                )
+                .bind(
+                    exact(KeyCode::Char('s')),
+                    |_| DraftScreenMessage::OpenSessionStats,
+                    KbHint::new("s", "Session stats"),
+                )
+                .bind(
+                    exact(KeyCode::Char('d')),
+                    |_| DraftScreenMessage::OpenDartBoard,
+                    KbHint::new("d", "Dart board"),
+                )
+                .bind(
+                    exact(KeyCode::Char('?')),
+                    |_| DraftScreenMessage::OpenHelp,
+                    KbHint::new("?", "Help"),
+                )
+                .bind(
+                    shift(KeyCode::Char('?')),
+                    |_| DraftScreenMessage::OpenHelp,
+                    None,
+                )
+                .bind(
+                    exact(KeyCode::Char('z')),
+                    |_| DraftScreenMessage::SnoozeRecalc,
+                    KbHint::new("z", "Snooze recalc"),
+                )
+                .bind(
+                    exact(KeyCode::Char('u')),
+                    |_| DraftScreenMessage::Undo,
+                    KbHint::new("u/^R", "Undo/redo"),
+                )
+                .bind(
+                    ctrl(KeyCode::Char('r')),
+                    |_| DraftScreenMessage::Redo,
+                    None,
+                )
                 .bind(
                     exact(KeyCode::Char('1')),
                     |_| DraftScreenMessage::SwitchTab(TabId::Analysis),
@@ -572,6 +919,15 @@ impl DraftScreen {
                     );
             }
 
+            // Slot moves: only while my roster has focus
+            if self.focused_panel == Some(FocusPanel::Roster) {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('e')),
+                    |_| DraftScreenMessage::MoveRosterSlot,
+                    KbHint::new("e", "Move slot"),
+                );
+            }
+
             // Filter bindings: only on tabs that support filtering
             if supports_filter {
                 recipe = recipe.bind(
@@ -587,6 +943,110 @@ impl DraftScreen {
                     KbHint::new("p", "Pos filter"),
                 );
             }
+            if supports_player_detail {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('i')),
+                    |_| DraftScreenMessage::OpenPlayerDetail,
+                    KbHint::new("i", "Player info"),
+                );
+            }
+            if supports_pin {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('f')),
+                    |_| DraftScreenMessage::TogglePin,
+                    KbHint::new("f", "Pin"),
+                );
+            }
+            if supports_sort {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('o')),
+                    |_| DraftScreenMessage::ToggleSort,
+                    KbHint::new("o", "Sort"),
+                );
+            }
+            if supports_pool {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('h')),
+                    |_| DraftScreenMessage::CyclePool,
+                    KbHint::new("h", "Hitters/pitchers"),
+                );
+            }
+            if supports_export {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('x')),
+                    |_| DraftScreenMessage::ExportAvailableView,
+                    KbHint::new("x", "Export"),
+                );
+            }
+            if supports_compare {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('m')),
+                    |_| DraftScreenMessage::MarkCompare,
+                    KbHint::new("m", "Compare"),
+                );
+            }
+            if supports_time_machine {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('t')),
+                    |_| DraftScreenMessage::OpenTimeMachine,
+                    KbHint::new("t", "Time machine"),
+                );
+            }
+            // Diff explainer: only once the app has explained a state update
+            if !self.modal_layer.diff_explain.history.is_empty() {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('w')),
+                    |_| DraftScreenMessage::OpenDiffExplain,
+                    KbHint::new("w", "Why (last diff)"),
+                );
+            }
+            // Ambiguous picks: only while one is waiting to be resolved
+            if self.modal_layer.resolve_pick.has_pending() {
+                recipe = recipe.bind(
+                    exact(KeyCode::Char('a')),
+                    |_| DraftScreenMessage::OpenResolvePick,
+                    KbHint::new("a", "Ambiguous picks"),
+                );
+            }
+            // Quick-bid keys: only while a player is on the block
+            if has_nomination {
+                recipe = recipe
+                    .bind(
+                        exact(KeyCode::Char('y')),
+                        |_| DraftScreenMessage::QuickBid(QuickBid::In),
+                        KbHint::new("y/n/+/5/c", "In/Out/+1/+5/Cap"),
+                    )
+                    .bind(
+                        exact(KeyCode::Char('n')),
+                        |_| DraftScreenMessage::QuickBid(QuickBid::Out),
+                        None,
+                    )
+                    .bind(
+                        exact(KeyCode::Char('+')),
+                        |_| DraftScreenMessage::QuickBid(QuickBid::Raise(1)),
+                        None,
+                    )
+                    .bind(
+                        shift(KeyCode::Char('+')),
+                        |_| DraftScreenMessage::QuickBid(QuickBid::Raise(1)),
+                        None,
+                    )
+                    .bind(
+                        exact(KeyCode::Char('=')),
+                        |_| DraftScreenMessage::QuickBid(QuickBid::Raise(1)),
+                        None,
+                    )
+                    .bind(
+                        exact(KeyCode::Char('5')),
+                        |_| DraftScreenMessage::QuickBid(QuickBid::Raise(5)),
+                        None,
+                    )
+                    .bind(
+                        exact(KeyCode::Char('c')),
+                        |_| DraftScreenMessage::QuickBid(QuickBid::Cap),
+                        None,
+                    );
+            }
 
             kb.subscribe(recipe)
         };
@@ -620,10 +1080,58 @@ pub enum DraftScreenMessage {
     ToggleFilter,
     /// Open the position filter modal on the Available tab (mirrors `p` key).
     OpenPositionFilter,
+    /// Open the player detail popup for the top visible player on the
+    /// Available tab (mirrors `i` key).
+    OpenPlayerDetail,
+    /// Pin the top visible player on the Available tab, or unpin them
+    /// (mirrors `f` key).
+    TogglePin,
+    /// Switch the Available tab between value and cliff order (mirrors `o`
+    /// key).
+    ToggleSort,
+    /// Switch the Available tab between all players, hitters and pitchers
+    /// (mirrors `h` key).
+    CyclePool,
+    /// Write the Available tab's rows, as shown, to a CSV (mirrors `x` key).
+    ExportAvailableView,
+    /// Mark the top row of the Available tab for a quick compare, or
+    /// compare it with the player already marked (mirrors `m` key).
+    MarkCompare,
+    /// Open the time machine at the selected pick on the Draft Log tab
+    /// (mirrors `t` key).
+    OpenTimeMachine,
+    /// Open the session stats overlay (mirrors `s` key).
+    OpenSessionStats,
+    /// Open the $1 dart board overlay (mirrors `d` key).
+    OpenDartBoard,
+    /// Open the state diff explainer overlay (mirrors `w` key).
+    OpenDiffExplain,
+    /// Show the ambiguous picks again, including those put aside (mirrors
+    /// `a` key).
+    OpenResolvePick,
+    /// Open help for the focused panel, or the active tab (mirrors `?` key).
+    OpenHelp,
+    /// Log a bid intent for the current nomination (mirrors `y`/`n`/`+`/`5`/`c`).
+    QuickBid(QuickBid),
     /// Enter the quit-confirmation dialog.
     RequestQuit,
     /// Request a full keyframe sync from the extension.
     RequestResync,
+    /// Dump the complete app state to a support bundle file.
+    DumpSupportBundle,
+    /// Export the overridden and blended projections for audit.
+    ExportProjectionAudit,
+    /// Export the keeper contracts priced against the model.
+    ExportKeeperReport,
+    /// Snooze automatic recalculation, or end the snooze (mirrors `z` key).
+    SnoozeRecalc,
+    /// Move the top visible player on my roster to their next open slot
+    /// (mirrors `e` key while the roster has focus).
+    MoveRosterSlot,
+    /// Undo the last edit made by hand.
+    Undo,
+    /// Redo the last undone edit.
+    Redo,
     /// Open the settings screen.
     OpenSettings,
 }
@@ -652,6 +1160,23 @@ impl DraftScreen {
                                 .available
                                 .update(AvailablePanelMessage::SetPositionFilter(pos));
                         }
+                        ModalLayerAction::PlayerDetail(PlayerDetailModalAction::Explain(player)) => {
+                            return Some(Action::Command(UserCommand::ExplainPlayer { player }));
+                        }
+                        ModalLayerAction::PlayerDetail(PlayerDetailModalAction::OpenStats(name, team, site)) => {
+                            return Some(Action::Command(UserCommand::OpenStatsPage { name, team, site }));
+                        }
+                        ModalLayerAction::Compare(CompareModalAction::AskLlm(a, b)) => {
+                            return Some(Action::Command(UserCommand::CompareWithLlm { a, b }));
+                        }
+                        ModalLayerAction::ResolvePick(ResolvePickModalAction::Resolve { pick_number, team }) => {
+                            return Some(Action::Command(UserCommand::ResolvePick { pick_number, team }));
+                        }
+                        ModalLayerAction::TimeMachine(TimeMachineModalAction::Request(pick_number)) => {
+                            return Some(Action::Command(UserCommand::RequestTimeMachine {
+                                pick_number,
+                            }));
+                        }
                         _ => {}
                     }
                 }
@@ -708,13 +1233,156 @@ impl DraftScreen {
                 }
                 None
             }
+            DraftScreenMessage::OpenPlayerDetail => {
+                if self
+                    .main_panel
+                    .active_tab()
+                    .supports(TabFeature::PlayerDetail)
+                {
+                    if let Some(player) =
+                        self.main_panel.available.top_player(&self.available_players)
+                    {
+                        let keeper = self
+                            .keeper_values
+                            .iter()
+                            .find(|k| k.player == player.name)
+                            .cloned();
+                        let prep = self.prep.iter().find(|e| e.player == player.name).cloned();
+                        self.modal_layer.player_detail.update(
+                            PlayerDetailModalMessage::Open(Box::new(player.clone()), keeper, prep),
+                        );
+                    }
+                }
+                None
+            }
+            DraftScreenMessage::TogglePin => {
+                if !self.main_panel.active_tab().supports(TabFeature::Pin) {
+                    return None;
+                }
+                let name = self
+                    .main_panel
+                    .available
+                    .top_player(&self.available_players)?
+                    .name
+                    .clone();
+                self.main_panel
+                    .available
+                    .update(AvailablePanelMessage::TogglePin(name.clone()));
+                // The app keeps the pin on its undo stack.
+                let pinned = self.main_panel.available.pinned().contains(&name);
+                Some(Action::Command(UserCommand::SetPin { name, pinned }))
+            }
+            DraftScreenMessage::ToggleSort => {
+                if !self.main_panel.active_tab().supports(TabFeature::Sort) {
+                    return None;
+                }
+                self.main_panel.available.update(AvailablePanelMessage::ToggleSort)
+            }
+            DraftScreenMessage::CyclePool => {
+                if !self.main_panel.active_tab().supports(TabFeature::Pool) {
+                    return None;
+                }
+                self.main_panel.available.update(AvailablePanelMessage::CyclePool)
+            }
+            DraftScreenMessage::ExportAvailableView => {
+                if !self.main_panel.active_tab().supports(TabFeature::Export) {
+                    return None;
+                }
+                let rows = self.main_panel.available.view_rows(&self.available_players);
+                Some(Action::Command(UserCommand::ExportAvailableView(rows)))
+            }
+            DraftScreenMessage::MarkCompare => {
+                if !self.main_panel.active_tab().supports(TabFeature::Compare) {
+                    return None;
+                }
+                let player = PlayerRef::of(self.main_panel.available.top_player(&self.available_players)?);
+                match self.main_panel.available.compare_mark().cloned() {
+                    // Marking the same player again clears the mark.
+                    Some(marked) if marked == player => {
+                        self.main_panel.available.update(AvailablePanelMessage::SetCompareMark(None))
+                    }
+                    Some(marked) => {
+                        self.main_panel.available.update(AvailablePanelMessage::SetCompareMark(None));
+                        self.modal_layer.compare.update(CompareModalMessage::Open(marked.clone(), player.clone()));
+                        Some(Action::Command(UserCommand::ComparePlayers { a: marked, b: player }))
+                    }
+                    None => self
+                        .main_panel
+                        .available
+                        .update(AvailablePanelMessage::SetCompareMark(Some(player))),
+                }
+            }
+            DraftScreenMessage::OpenTimeMachine => {
+                if !self.main_panel.active_tab().supports(TabFeature::TimeMachine) {
+                    return None;
+                }
+                let pick_number = self
+                    .main_panel
+                    .draft_log
+                    .selected_pick(&self.draft_log)?
+                    .pick_number as usize;
+                self.update(DraftScreenMessage::Modal(ModalLayerMessage::TimeMachine(
+                    TimeMachineModalMessage::Open {
+                        pick_number,
+                        total_picks: self.draft_log.len(),
+                    },
+                )))
+            }
+            DraftScreenMessage::OpenSessionStats => self.update(DraftScreenMessage::Modal(
+                ModalLayerMessage::SessionStats(SessionStatsModalMessage::Open),
+            )),
+            DraftScreenMessage::OpenDartBoard => self.update(DraftScreenMessage::Modal(
+                ModalLayerMessage::DartBoard(DartBoardModalMessage::Open),
+            )),
+            DraftScreenMessage::OpenDiffExplain => self.update(DraftScreenMessage::Modal(
+                ModalLayerMessage::DiffExplain(DiffExplainModalMessage::Open),
+            )),
+            DraftScreenMessage::OpenResolvePick => self.update(DraftScreenMessage::Modal(
+                ModalLayerMessage::ResolvePick(ResolvePickModalMessage::ShowAll),
+            )),
+            DraftScreenMessage::OpenHelp => {
+                let topic = HelpTopic::resolve(self.focused_panel, self.main_panel.active_tab());
+                self.update(DraftScreenMessage::Modal(ModalLayerMessage::Help(
+                    HelpModalMessage::Open(topic),
+                )))
+            }
+            DraftScreenMessage::QuickBid(key) => {
+                self.current_nomination.as_ref()?;
+                Some(Action::Command(UserCommand::QuickBid(key)))
+            }
             DraftScreenMessage::RequestQuit => {
                 self.modal_layer.quit_confirm.update(ConfirmMessage::Open);
                 None
             }
             DraftScreenMessage::RequestResync => {
+                // The app answers with a full snapshot, and reports again
+                // if that is lost too.
+                self.ui_desynced = None;
                 Some(Action::Command(UserCommand::RequestKeyframe))
             }
+            DraftScreenMessage::DumpSupportBundle => {
+                Some(Action::Command(UserCommand::DumpSupportBundle))
+            }
+            DraftScreenMessage::ExportProjectionAudit => {
+                Some(Action::Command(UserCommand::ExportProjectionAudit))
+            }
+            DraftScreenMessage::ExportKeeperReport => {
+                Some(Action::Command(UserCommand::ExportKeeperReport))
+            }
+            DraftScreenMessage::SnoozeRecalc => Some(Action::Command(UserCommand::SnoozeRecalc)),
+            DraftScreenMessage::MoveRosterSlot => {
+                let from = self.sidebar.roster.top_slot(&self.my_roster)?;
+                let player_name = self.my_roster[from].player.as_ref()?.name.clone();
+                match next_open_slot(&self.my_roster, from) {
+                    Some(slot) => Some(Action::Command(UserCommand::MoveToSlot { player_name, slot })),
+                    None => {
+                        self.file_notice = Some(Err(format!("No open slot {player_name} can play")));
+                        None
+                    }
+                }
+            }
+            DraftScreenMessage::Undo => Some(Action::Command(UserCommand::Undo)),
+            DraftScreenMessage::Redo => Some(Action::Command(UserCommand::Redo)),
             DraftScreenMessage::OpenSettings => {
                 Some(Action::Command(UserCommand::OpenSettings))
             }
@@ -0,0 +1,425 @@
+// Quick-compare modal component (Elm Architecture).
+//
+// A centered read-only overlay lining up two marked players side by side:
+// values (mine, the consensus, today's inflated price), VOR, each
+// category's projection and z-score, playing time and projection
+// adjustments for risk, and fit with my roster and category needs. The
+// app works the comparison out on request; the better side of each row is
+// highlighted.
+//
+// `l` asks the LLM, once, which of the two to take; the answer streams in
+// under the table.
+
+use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::protocol::LlmStatus;
+use crate::tui::draft::main_panel::available::format_positions;
+use crate::tui::llm_stream::{LlmStreamMessage, LlmStreamState};
+use crate::tui::subscription::{
+    Subscription, SubscriptionId,
+    keybinding::{exact, KeyBindingRecipe, KeybindHint, KeybindManager, PRIORITY_MODAL},
+};
+use crate::tui::widgets::centered_rect;
+use crate::valuation::compare::{ComparedPlayer, PlayerComparison};
+use crate::valuation::identity::PlayerRef;
+
+// ---------------------------------------------------------------------------
+// Message
+// ---------------------------------------------------------------------------
+
+/// Messages that drive the compare modal.
+#[derive(Debug, Clone)]
+pub enum CompareModalMessage {
+    /// Open the overlay for two players while the app compares them.
+    Open(PlayerRef, PlayerRef),
+    /// The comparison arrived from the app, or why it couldn't be made.
+    Loaded(Result<Box<PlayerComparison>, String>),
+    /// Ask the LLM which of the two to take (l).
+    AskLlm,
+    /// Streamed LLM comparison text.
+    Stream(LlmStreamMessage),
+    /// Close the overlay (Esc / Enter / m).
+    Close,
+}
+
+/// Actions returned by [`CompareModal::update`] for the parent to handle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompareModalAction {
+    /// Ask the app for an LLM comparison of these two players.
+    AskLlm(PlayerRef, PlayerRef),
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Width of the modal dialog.
+const MODAL_WIDTH: u16 = 60;
+
+/// State for the compare overlay.
+#[derive(Debug, Clone)]
+pub struct CompareModal {
+    /// Whether the modal is currently visible.
+    pub open: bool,
+    /// The two players being compared, in mark order.
+    players: Option<(PlayerRef, PlayerRef)>,
+    /// The comparison, once it has arrived.
+    comparison: Option<Result<PlayerComparison, String>>,
+    /// The LLM's answer, streamed after `l`.
+    llm: LlmStreamState,
+    /// Whether the LLM has been asked for these two.
+    asked: bool,
+    sub_id: SubscriptionId,
+}
+
+impl Default for CompareModal {
+    fn default() -> Self {
+        Self {
+            open: false,
+            players: None,
+            comparison: None,
+            llm: LlmStreamState::new(),
+            asked: false,
+            sub_id: SubscriptionId::unique(),
+        }
+    }
+}
+
+impl CompareModal {
+    /// The two players being compared, if open.
+    pub fn names(&self) -> Option<(&str, &str)> {
+        self.players.as_ref().map(|(a, b)| (a.name.as_str(), b.name.as_str()))
+    }
+
+    /// The LLM comparison text so far.
+    pub fn llm_text(&self) -> &str {
+        &self.llm.text
+    }
+
+    // -- Elm Architecture API ------------------------------------------------
+
+    /// Declare keybindings for the subscription system.
+    ///
+    /// Returns a capturing subscription at `PRIORITY_MODAL` while open, or
+    /// `Subscription::none()` when closed.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<CompareModalMessage> {
+        if !self.open {
+            return Subscription::none();
+        }
+
+        let recipe = KeyBindingRecipe::new(self.sub_id)
+            .priority(PRIORITY_MODAL)
+            .capture()
+            .bind(
+                exact(KeyCode::Esc),
+                |_| CompareModalMessage::Close,
+                KeybindHint::new("Esc", "Close"),
+            )
+            .bind(
+                exact(KeyCode::Enter),
+                |_| CompareModalMessage::Close,
+                None,
+            )
+            .bind(
+                exact(KeyCode::Char('m')),
+                |_| CompareModalMessage::Close,
+                None,
+            )
+            .bind(
+                exact(KeyCode::Char('l')),
+                |_| CompareModalMessage::AskLlm,
+                (!self.asked).then(|| KeybindHint::new("l", "Ask LLM")),
+            );
+
+        kb.subscribe(recipe)
+    }
+
+    /// Process a message and return an optional action for the parent.
+    pub fn update(&mut self, msg: CompareModalMessage) -> Option<CompareModalAction> {
+        match msg {
+            CompareModalMessage::Open(a, b) => {
+                self.players = Some((a, b));
+                self.comparison = None;
+                self.llm.update(LlmStreamMessage::Clear);
+                self.asked = false;
+                self.open = true;
+            }
+            CompareModalMessage::Loaded(result) => {
+                // Drop a late answer about a pair no longer shown.
+                let shown = match &result {
+                    Ok(c) => {
+                        let [a, b] = &c.players;
+                        self.names() == Some((a.name.as_str(), b.name.as_str()))
+                    }
+                    Err(_) => true,
+                };
+                if self.open && shown {
+                    self.comparison = Some(result.map(|c| *c));
+                }
+            }
+            CompareModalMessage::AskLlm => {
+                // One shot per pair.
+                if self.open && !self.asked {
+                    self.asked = true;
+                    self.llm.update(LlmStreamMessage::TokenReceived(String::new()));
+                    let (a, b) = self.players.clone()?;
+                    return Some(CompareModalAction::AskLlm(a, b));
+                }
+            }
+            CompareModalMessage::Stream(m) => {
+                if self.open {
+                    self.llm.update(m);
+                }
+            }
+            CompareModalMessage::Close => {
+                self.open = false;
+                self.players = None;
+                self.comparison = None;
+                self.llm.update(LlmStreamMessage::Clear);
+                self.asked = false;
+            }
+        }
+        None
+    }
+
+    /// Render the modal overlay. Only draws when open.
+    pub fn view(&self, frame: &mut Frame, area: Rect) {
+        let Some((a, b)) = self.names().filter(|_| self.open) else {
+            return;
+        };
+
+        let mut lines = build_compare_lines(self.comparison.as_ref());
+        if self.asked {
+            lines.push(Line::from(""));
+            lines.extend(llm_lines(&self.llm));
+        }
+        // Height: border(2) + content lines, plus the LLM text's wrapping.
+        let inner_width = MODAL_WIDTH.saturating_sub(2).max(1) as usize;
+        let wrapped: usize = lines.iter().map(|l| l.width().max(1).div_ceil(inner_width)).sum();
+        let modal_area = centered_rect(MODAL_WIDTH, 2 + wrapped as u16, area);
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                format!(" {a} vs {b} "),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        frame.render_widget(
+            Paragraph::new(lines).block(block).wrap(Wrap { trim: false }),
+            modal_area,
+        );
+    }
+}
+
+/// Build the comparison table: a row per value, then a row per category,
+/// then each player's projection adjustments.
+pub fn build_compare_lines(comparison: Option<&Result<PlayerComparison, String>>) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    let comparison = match comparison {
+        None => return vec![Line::from(Span::styled(" Comparing...", label))],
+        Some(Err(e)) => {
+            return vec![Line::from(Span::styled(format!(" {e}"), Style::default().fg(Color::Red)))];
+        }
+        Some(Ok(comparison)) => comparison,
+    };
+    let [a, b] = &comparison.players;
+
+    let row = |name: &str, values: [String; 2], better: Option<usize>| {
+        let style = |i: usize| {
+            if better == Some(i) {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            }
+        };
+        Line::from(vec![
+            Span::styled(format!(" {:<16}", name), label),
+            Span::styled(format!("{:>20}", values[0]), style(0)),
+            Span::styled(format!("{:>20}", values[1]), style(1)),
+        ])
+    };
+    let number_row = |name: &str, f: fn(&ComparedPlayer) -> f64, text: fn(f64) -> String| {
+        row(name, [text(f(a)), text(f(b))], higher(f(a), f(b)))
+    };
+
+    let mut lines = vec![
+        row("", [truncate(&a.name), truncate(&b.name)], None),
+        row(
+            "",
+            [a, b].map(|p| format!("{} {}", format_positions(&p.positions), p.team)),
+            None,
+        ),
+        Line::from(""),
+        number_row("Value", |p| p.dollar_value, |v| format!("${v:.1}")),
+        number_row("Consensus", |p| p.consensus_value, |v| format!("${v:.1}")),
+        number_row("Inflated", |p| p.adjusted_value, |v| format!("${v:.1}")),
+        number_row("VOR", |p| p.vor, |v| format!("{v:.1}")),
+        number_row("zTotal", |p| p.total_zscore, |v| format!("{v:+.2}")),
+        number_row("PA / IP", |p| p.playing_time, |v| format!("{v:.0}")),
+        row(
+            "Open slot",
+            [a, b].map(|p| if p.open_slot { "yes" } else { "no" }.to_string()),
+            match (a.open_slot, b.open_slot) {
+                (true, false) => Some(0),
+                (false, true) => Some(1),
+                _ => None,
+            },
+        ),
+        number_row("Need fit", |p| p.need_fit, |v| format!("{v:+.2}")),
+        Line::from(""),
+    ];
+
+    for line in &comparison.categories {
+        let cell = |i: usize| {
+            let projection = if line.rate {
+                format!("{:.3}", line.projections[i])
+            } else {
+                format!("{:.0}", line.projections[i])
+            };
+            format!("{projection} / {:+.2}", line.zscores[i])
+        };
+        lines.push(row(&line.category, [cell(0), cell(1)], higher(line.zscores[0], line.zscores[1])));
+    }
+
+    for p in [a, b].into_iter().filter(|p| !p.adjustments.is_empty()) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(format!(" {} adjusted:", p.name), label)));
+        for adjustment in &p.adjustments {
+            lines.push(Line::from(Span::styled(
+                format!("   {adjustment}"),
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+    }
+    lines
+}
+
+/// The LLM's answer so far, or where the request stands.
+fn llm_lines(llm: &LlmStreamState) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    match llm.status {
+        LlmStatus::Error => vec![Line::from(Span::styled(
+            format!(" {}", llm.text),
+            Style::default().fg(Color::Red),
+        ))],
+        _ if llm.text.is_empty() => vec![Line::from(Span::styled(" Asking the LLM...", label))],
+        _ => llm.text.lines().map(|l| Line::from(format!(" {l}"))).collect(),
+    }
+}
+
+/// Which side is higher, if they differ.
+fn higher(a: f64, b: f64) -> Option<usize> {
+    if a > b {
+        Some(0)
+    } else if b > a {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// A name cut to fit its column.
+fn truncate(name: &str) -> String {
+    if name.chars().count() <= 19 {
+        name.to_string()
+    } else {
+        let cut: String = name.chars().take(18).collect();
+        format!("{cut}…")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::valuation::compare::CategoryLine;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn player(name: &str, value: f64, open_slot: bool) -> ComparedPlayer {
+        ComparedPlayer {
+            name: name.into(),
+            team: "NYY".into(),
+            positions: vec![],
+            dollar_value: value,
+            consensus_value: value,
+            adjusted_value: value,
+            vor: 1.0,
+            total_zscore: 1.0,
+            playing_time: 600.0,
+            adjustments: vec![],
+            open_slot,
+            need_fit: 0.0,
+        }
+    }
+
+    fn comparison() -> PlayerComparison {
+        let mut b = player("Bravo", 18.0, false);
+        b.adjustments = vec!["x0.90 hamstring".into()];
+        PlayerComparison {
+            players: [player("Alpha", 20.0, true), b],
+            categories: vec![CategoryLine {
+                category: "AVG".into(),
+                rate: true,
+                projections: [0.281, 0.265],
+                zscores: [0.5, 1.25],
+            }],
+        }
+    }
+
+    #[test]
+    fn lines_put_the_players_side_by_side() {
+        let text: Vec<String> = build_compare_lines(Some(&Ok(comparison()))).iter().map(line_text).collect();
+        assert!(text[0].contains("Alpha") && text[0].contains("Bravo"));
+        let value = text.iter().find(|l| l.contains("Value")).unwrap();
+        assert!(value.contains("$20.0") && value.contains("$18.0"), "got: {value}");
+        let avg = text.iter().find(|l| l.contains("AVG")).unwrap();
+        assert!(avg.contains("0.281 / +0.50") && avg.contains("0.265 / +1.25"), "got: {avg}");
+        assert!(text.iter().any(|l| l.contains("x0.90 hamstring")));
+
+        assert_eq!(line_text(&build_compare_lines(None)[0]), " Comparing...");
+    }
+
+    #[test]
+    fn asks_the_llm_once_per_pair_and_drops_a_stale_comparison() {
+        let mut modal = CompareModal::default();
+        modal.update(CompareModalMessage::Open(PlayerRef::named("Alpha"), PlayerRef::named("Bravo")));
+        assert!(modal.open);
+
+        let mut other = comparison();
+        other.players[0].name = "Charlie".into();
+        modal.update(CompareModalMessage::Loaded(Ok(Box::new(other))));
+        assert!(modal.comparison.is_none());
+        modal.update(CompareModalMessage::Loaded(Ok(Box::new(comparison()))));
+        assert!(modal.comparison.is_some());
+
+        assert_eq!(
+            modal.update(CompareModalMessage::AskLlm),
+            Some(CompareModalAction::AskLlm(PlayerRef::named("Alpha"), PlayerRef::named("Bravo")))
+        );
+        assert_eq!(modal.update(CompareModalMessage::AskLlm), None);
+        modal.update(CompareModalMessage::Stream(LlmStreamMessage::TokenReceived("Take Alpha.".into())));
+        assert_eq!(modal.llm_text(), "Take Alpha.");
+
+        modal.update(CompareModalMessage::Close);
+        assert!(!modal.open);
+        assert!(modal.names().is_none());
+        assert!(modal.llm_text().is_empty());
+    }
+}
@@ -0,0 +1,244 @@
+// Dart board modal component (Elm Architecture).
+//
+// A centered read-only overlay for the endgame: for each of my open
+// positions, the cheap players left who are worth more than $1, best first,
+// with their upside flags. The app builds the board with every snapshot;
+// the overlay always shows the latest, so it stays current while open.
+
+use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::draft::dart_board::{DartGroup, ENDGAME_SLOTS};
+use crate::tui::subscription::{
+    Subscription, SubscriptionId,
+    keybinding::{exact, KeyBindingRecipe, KeybindHint, KeybindManager, PRIORITY_MODAL},
+};
+use crate::tui::widgets::centered_rect;
+
+// ---------------------------------------------------------------------------
+// Message
+// ---------------------------------------------------------------------------
+
+/// Messages that drive the dart board modal.
+#[derive(Debug, Clone)]
+pub enum DartBoardModalMessage {
+    /// Open the overlay.
+    Open,
+    /// Close the overlay (Esc / Enter / d).
+    Close,
+    ScrollUp,
+    ScrollDown,
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Width of the modal dialog.
+const MODAL_WIDTH: u16 = 52;
+
+/// State for the dart board overlay.
+#[derive(Debug, Clone)]
+pub struct DartBoardModal {
+    /// Whether the modal is currently visible.
+    pub open: bool,
+    /// The latest board from the app, kept current whether or not the
+    /// modal is open.
+    pub groups: Vec<DartGroup>,
+    scroll: u16,
+    sub_id: SubscriptionId,
+}
+
+impl Default for DartBoardModal {
+    fn default() -> Self {
+        Self {
+            open: false,
+            groups: Vec::new(),
+            scroll: 0,
+            sub_id: SubscriptionId::unique(),
+        }
+    }
+}
+
+impl DartBoardModal {
+    // -- Elm Architecture API ------------------------------------------------
+
+    /// Declare keybindings for the subscription system.
+    ///
+    /// Returns a capturing subscription at `PRIORITY_MODAL` while open, or
+    /// `Subscription::none()` when closed.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<DartBoardModalMessage> {
+        if !self.open {
+            return Subscription::none();
+        }
+
+        let recipe = KeyBindingRecipe::new(self.sub_id)
+            .priority(PRIORITY_MODAL)
+            .capture()
+            .bind(
+                exact(KeyCode::Up),
+                |_| DartBoardModalMessage::ScrollUp,
+                KeybindHint::new("↑↓/j/k", "Scroll"),
+            )
+            .bind(exact(KeyCode::Char('k')), |_| DartBoardModalMessage::ScrollUp, None)
+            .bind(exact(KeyCode::Down), |_| DartBoardModalMessage::ScrollDown, None)
+            .bind(exact(KeyCode::Char('j')), |_| DartBoardModalMessage::ScrollDown, None)
+            .bind(
+                exact(KeyCode::Esc),
+                |_| DartBoardModalMessage::Close,
+                KeybindHint::new("Esc", "Close"),
+            )
+            .bind(exact(KeyCode::Enter), |_| DartBoardModalMessage::Close, None)
+            .bind(exact(KeyCode::Char('d')), |_| DartBoardModalMessage::Close, None);
+
+        kb.subscribe(recipe)
+    }
+
+    /// Process a message.
+    pub fn update(&mut self, msg: DartBoardModalMessage) {
+        match msg {
+            DartBoardModalMessage::Open => {
+                self.open = true;
+                self.scroll = 0;
+            }
+            DartBoardModalMessage::Close => self.open = false,
+            DartBoardModalMessage::ScrollUp => self.scroll = self.scroll.saturating_sub(1),
+            DartBoardModalMessage::ScrollDown => self.scroll = self.scroll.saturating_add(1),
+        }
+    }
+
+    /// Render the modal overlay. Only draws when open.
+    pub fn view(&self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let lines = build_board_lines(&self.groups);
+        // Height: border(2) + content lines
+        let modal_height = 2 + lines.len() as u16;
+        let modal_area = centered_rect(MODAL_WIDTH, modal_height, area);
+        let max_scroll = (lines.len() as u16).saturating_sub(modal_area.height.saturating_sub(2));
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                " $1 Dart Board ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        frame.render_widget(
+            Paragraph::new(lines).block(block).scroll((self.scroll.min(max_scroll), 0)),
+            modal_area,
+        );
+    }
+}
+
+/// Build the overlay body: each open position, then its darts with their
+/// value and upside flags.
+pub fn build_board_lines(groups: &[DartGroup]) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    if groups.is_empty() {
+        return vec![
+            Line::from(Span::styled(
+                format!(" Opens when {ENDGAME_SLOTS} or fewer of your slots are open"),
+                label,
+            )),
+        ];
+    }
+
+    let mut lines = Vec::new();
+    for group in groups {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {}", group.position.display_str()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  {} open", group.open), label),
+        ]));
+        if group.darts.is_empty() {
+            lines.push(Line::from(Span::styled("   No darts left", label)));
+        }
+        for dart in &group.darts {
+            let flags: Vec<String> = dart.upside.iter().map(|u| u.label()).collect();
+            lines.push(Line::from(vec![
+                Span::raw(format!("   {:<20} {:<4}", dart.player_name, dart.team)),
+                Span::styled(format!("{:>6}", format!("${:.1}", dart.dollar_value)), Style::default().fg(Color::Green)),
+                Span::styled(format!("  {}", flags.join(" ")), Style::default().fg(Color::Yellow)),
+            ]));
+        }
+    }
+    lines
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::dart_board::{Dart, Upside};
+    use crate::draft::pick::Position;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn groups() -> Vec<DartGroup> {
+        vec![
+            DartGroup {
+                position: Position::Catcher,
+                open: 1,
+                darts: vec![Dart {
+                    player_name: "Platoon C".into(),
+                    team: "SEA".into(),
+                    dollar_value: 3.0,
+                    upside: vec![Upside::PlayingTime, Upside::Category("SB".into())],
+                }],
+            },
+            DartGroup {
+                position: Position::ReliefPitcher,
+                open: 2,
+                darts: vec![],
+            },
+        ]
+    }
+
+    #[test]
+    fn board_lines_list_darts_under_each_open_position() {
+        let text: Vec<String> = build_board_lines(&groups()).iter().map(line_text).collect();
+        assert_eq!(text[0], " C  1 open");
+        assert_eq!(text[1], format!("   {:<20} {:<4}  $3.0  PT SB+", "Platoon C", "SEA"));
+        assert_eq!(text[2], " RP  2 open");
+        assert_eq!(text[3], "   No darts left");
+
+        let empty: Vec<String> = build_board_lines(&[]).iter().map(line_text).collect();
+        assert!(empty[0].contains("Opens when 10 or fewer"), "got: {empty:?}");
+    }
+
+    #[test]
+    fn open_scroll_close_and_view() {
+        let mut modal = DartBoardModal { groups: groups(), ..Default::default() };
+        modal.update(DartBoardModalMessage::Open);
+        assert!(modal.open);
+        modal.update(DartBoardModalMessage::ScrollDown);
+        for (w, h) in [(80, 24), (10, 3)] {
+            let backend = ratatui::backend::TestBackend::new(w, h);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| modal.view(frame, frame.area()))
+                .unwrap();
+        }
+        modal.update(DartBoardModalMessage::Close);
+        assert!(!modal.open);
+    }
+}
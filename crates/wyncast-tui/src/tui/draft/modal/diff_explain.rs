@@ -0,0 +1,249 @@
+// State diff explainer modal component (Elm Architecture).
+//
+// A centered read-only overlay for debugging the extension's updates. With
+// `explain_diffs` on under `[logging]`, the app explains every state update
+// that changed something: a one-line summary and each decision behind it
+// (why a pick counted as new, why the nomination changed). The overlay shows
+// the latest in full and the summaries of the ones before it.
+
+use std::collections::VecDeque;
+
+use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::protocol::DiffExplanation;
+use crate::tui::subscription::{
+    Subscription, SubscriptionId,
+    keybinding::{exact, KeyBindingRecipe, KeybindHint, KeybindManager, PRIORITY_MODAL},
+};
+use crate::tui::widgets::centered_rect;
+
+// ---------------------------------------------------------------------------
+// Message
+// ---------------------------------------------------------------------------
+
+/// Messages that drive the diff explainer modal.
+#[derive(Debug, Clone)]
+pub enum DiffExplainModalMessage {
+    /// Open the overlay.
+    Open,
+    /// Close the overlay (Esc / Enter / w).
+    Close,
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Width of the modal dialog.
+const MODAL_WIDTH: u16 = 76;
+
+/// Explanations kept, the latest last.
+const HISTORY: usize = 20;
+
+/// Most decisions of the latest diff listed.
+const DECISIONS_SHOWN: usize = 12;
+
+/// State for the diff explainer overlay.
+#[derive(Debug, Clone)]
+pub struct DiffExplainModal {
+    /// Whether the modal is currently visible.
+    pub open: bool,
+    /// The latest explanations from the app, oldest first.
+    pub history: VecDeque<DiffExplanation>,
+    sub_id: SubscriptionId,
+}
+
+impl Default for DiffExplainModal {
+    fn default() -> Self {
+        Self {
+            open: false,
+            history: VecDeque::new(),
+            sub_id: SubscriptionId::unique(),
+        }
+    }
+}
+
+impl DiffExplainModal {
+    // -- Elm Architecture API ------------------------------------------------
+
+    /// Declare keybindings for the subscription system.
+    ///
+    /// Returns a capturing subscription at `PRIORITY_MODAL` while open, or
+    /// `Subscription::none()` when closed.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<DiffExplainModalMessage> {
+        if !self.open {
+            return Subscription::none();
+        }
+
+        let recipe = KeyBindingRecipe::new(self.sub_id)
+            .priority(PRIORITY_MODAL)
+            .capture()
+            .bind(
+                exact(KeyCode::Esc),
+                |_| DiffExplainModalMessage::Close,
+                KeybindHint::new("Esc", "Close"),
+            )
+            .bind(
+                exact(KeyCode::Enter),
+                |_| DiffExplainModalMessage::Close,
+                None,
+            )
+            .bind(
+                exact(KeyCode::Char('w')),
+                |_| DiffExplainModalMessage::Close,
+                None,
+            );
+
+        kb.subscribe(recipe)
+    }
+
+    /// Process a message.
+    pub fn update(&mut self, msg: DiffExplainModalMessage) {
+        match msg {
+            DiffExplainModalMessage::Open => self.open = true,
+            DiffExplainModalMessage::Close => self.open = false,
+        }
+    }
+
+    /// Record the app's latest explanation, dropping the oldest beyond
+    /// `HISTORY`.
+    pub fn push(&mut self, explanation: DiffExplanation) {
+        if self.history.len() == HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(explanation);
+    }
+
+    /// Render the modal overlay. Only draws when open.
+    pub fn view(&self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let lines = build_diff_lines(&self.history);
+        // Height: border(2) + content lines
+        let modal_height = 2 + lines.len() as u16;
+        let modal_area = centered_rect(MODAL_WIDTH, modal_height, area);
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                " State Diffs ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        frame.render_widget(
+            Paragraph::new(lines).block(block).wrap(Wrap { trim: false }),
+            modal_area,
+        );
+    }
+}
+
+/// Build the overlay body: the latest diff's summary and decisions, then the
+/// earlier summaries, newest first.
+pub fn build_diff_lines(history: &VecDeque<DiffExplanation>) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    let Some(latest) = history.back() else {
+        return vec![Line::from(Span::styled(" No state diffs yet", label))];
+    };
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled(format!(" {} ", latest.at), label),
+        Span::styled(latest.summary.clone(), Style::default().add_modifier(Modifier::BOLD)),
+    ])];
+    for decision in latest.decisions.iter().take(DECISIONS_SHOWN) {
+        lines.push(Line::from(format!("   {decision}")));
+    }
+    if latest.decisions.len() > DECISIONS_SHOWN {
+        lines.push(Line::from(Span::styled(
+            format!("   ... and {} more in the log", latest.decisions.len() - DECISIONS_SHOWN),
+            label,
+        )));
+    }
+
+    if history.len() > 1 {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(" Earlier", label)));
+        for earlier in history.iter().rev().skip(1) {
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {} ", earlier.at), label),
+                Span::raw(earlier.summary.clone()),
+            ]));
+        }
+    }
+    lines
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn explanation(at: &str, summary: &str, decisions: usize) -> DiffExplanation {
+        DiffExplanation {
+            at: at.into(),
+            summary: summary.into(),
+            decisions: (0..decisions).map(|i| format!("decision {i}")).collect(),
+        }
+    }
+
+    #[test]
+    fn lines_show_the_latest_in_full_then_earlier_summaries() {
+        let mut modal = DiffExplainModal::default();
+        modal.push(explanation("10:00:01", "1 pick new", 1));
+        modal.push(explanation("10:00:02", "nomination cleared", 15));
+        let text: Vec<String> = build_diff_lines(&modal.history).iter().map(line_text).collect();
+
+        assert_eq!(text[0], " 10:00:02 nomination cleared");
+        assert_eq!(text[1], "   decision 0");
+        assert_eq!(text[DECISIONS_SHOWN + 1], "   ... and 3 more in the log");
+        assert_eq!(text.last().unwrap(), " 10:00:01 1 pick new");
+
+        let empty: Vec<String> = build_diff_lines(&VecDeque::new()).iter().map(line_text).collect();
+        assert_eq!(empty, [" No state diffs yet"]);
+    }
+
+    #[test]
+    fn history_keeps_the_latest() {
+        let mut modal = DiffExplainModal::default();
+        for i in 0..HISTORY + 3 {
+            modal.push(explanation(&i.to_string(), "diff", 0));
+        }
+        assert_eq!(modal.history.len(), HISTORY);
+        assert_eq!(modal.history.front().unwrap().at, "3");
+    }
+
+    #[test]
+    fn open_close_and_view() {
+        let mut modal = DiffExplainModal::default();
+        modal.push(explanation("10:00:01", "1 pick new", 1));
+        modal.update(DiffExplainModalMessage::Open);
+        assert!(modal.open);
+        for (w, h) in [(80, 24), (10, 5)] {
+            let backend = ratatui::backend::TestBackend::new(w, h);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| modal.view(frame, frame.area()))
+                .unwrap();
+        }
+        modal.update(DiffExplainModalMessage::Close);
+        assert!(!modal.open);
+    }
+}
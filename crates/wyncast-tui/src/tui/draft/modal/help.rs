@@ -0,0 +1,242 @@
+// Help modal component (Elm Architecture).
+//
+// A centered read-only overlay opened with `?` that explains the focused
+// panel, or the active tab when nothing else has focus: its columns, the
+// keys that work there, and its color codes, followed by the keys that work
+// everywhere. The content comes from the help registry in `draft::help`.
+
+use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::tui::draft::help::{HelpTopic, GLOBAL_KEYS};
+use crate::tui::subscription::{
+    Subscription, SubscriptionId,
+    keybinding::{exact, shift, KeyBindingRecipe, KeybindHint, KeybindManager, PRIORITY_MODAL},
+};
+use crate::tui::widgets::centered_rect;
+
+// ---------------------------------------------------------------------------
+// Message
+// ---------------------------------------------------------------------------
+
+/// Messages that drive the help modal.
+#[derive(Debug, Clone)]
+pub enum HelpModalMessage {
+    /// Open the overlay for a topic.
+    Open(HelpTopic),
+    /// Close the overlay (Esc / Enter / ?).
+    Close,
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Width of the modal dialog.
+const MODAL_WIDTH: u16 = 66;
+
+/// Width of the label column.
+const LABEL_WIDTH: usize = 16;
+
+/// State for the help overlay.
+#[derive(Debug, Clone)]
+pub struct HelpModal {
+    /// Whether the modal is currently visible.
+    pub open: bool,
+    /// The topic being explained.
+    pub topic: HelpTopic,
+    sub_id: SubscriptionId,
+}
+
+impl Default for HelpModal {
+    fn default() -> Self {
+        Self {
+            open: false,
+            topic: HelpTopic::Analysis,
+            sub_id: SubscriptionId::unique(),
+        }
+    }
+}
+
+impl HelpModal {
+    // -- Elm Architecture API ------------------------------------------------
+
+    /// Declare keybindings for the subscription system.
+    ///
+    /// Returns a capturing subscription at `PRIORITY_MODAL` while open, or
+    /// `Subscription::none()` when closed.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<HelpModalMessage> {
+        if !self.open {
+            return Subscription::none();
+        }
+
+        let recipe = KeyBindingRecipe::new(self.sub_id)
+            .priority(PRIORITY_MODAL)
+            .capture()
+            .bind(
+                exact(KeyCode::Esc),
+                |_| HelpModalMessage::Close,
+                KeybindHint::new("Esc", "Close"),
+            )
+            .bind(exact(KeyCode::Enter), |_| HelpModalMessage::Close, None)
+            .bind(exact(KeyCode::Char('?')), |_| HelpModalMessage::Close, None)
+            .bind(shift(KeyCode::Char('?')), |_| HelpModalMessage::Close, None);
+
+        kb.subscribe(recipe)
+    }
+
+    /// Process a message.
+    pub fn update(&mut self, msg: HelpModalMessage) {
+        match msg {
+            HelpModalMessage::Open(topic) => {
+                self.topic = topic;
+                self.open = true;
+            }
+            HelpModalMessage::Close => self.open = false,
+        }
+    }
+
+    /// Render the modal overlay. Only draws when open.
+    pub fn view(&self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let lines = build_help_lines(self.topic);
+        // Height: border(2) + content lines
+        let modal_height = 2 + lines.len() as u16;
+        let modal_area = centered_rect(MODAL_WIDTH, modal_height, area);
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                format!(" Help: {} ", self.topic.entry().title),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+    }
+}
+
+/// Build the overlay body: the summary, then the topic's columns, keys and
+/// colors, then the keys that work everywhere. Empty sections are left out.
+pub fn build_help_lines(topic: HelpTopic) -> Vec<Line<'static>> {
+    let entry = topic.entry();
+    let heading = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD);
+    let label = Style::default().fg(Color::Yellow);
+    let text = Style::default().fg(Color::Gray);
+    let row = |name: &str, meaning: &str| {
+        Line::from(vec![
+            Span::styled(format!("  {:<LABEL_WIDTH$}", name), label),
+            Span::styled(meaning.to_string(), text),
+        ])
+    };
+
+    let mut lines = vec![Line::from(Span::styled(format!(" {}", entry.about), text))];
+    let mut section = |title: &str, rows: Vec<Line<'static>>| {
+        if rows.is_empty() {
+            return;
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(format!(" {title}"), heading)));
+        lines.extend(rows);
+    };
+
+    section(
+        "Columns",
+        entry.columns.iter().map(|(name, meaning)| row(name, meaning)).collect(),
+    );
+    section(
+        "Keys",
+        topic.keys().iter().map(|(key, meaning)| row(key, meaning)).collect(),
+    );
+    section(
+        "Colors",
+        entry
+            .colors
+            .iter()
+            .map(|(color, meaning)| {
+                Line::from(vec![
+                    Span::styled(format!("  {:<LABEL_WIDTH$}", "\u{25a0}"), Style::default().fg(*color)),
+                    Span::styled(meaning.to_string(), text),
+                ])
+            })
+            .collect(),
+    );
+    section(
+        "Everywhere",
+        GLOBAL_KEYS.iter().map(|(key, meaning)| row(key, meaning)).collect(),
+    );
+    lines
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn help_lines_cover_the_topic_then_global_keys() {
+        let text: Vec<String> = build_help_lines(HelpTopic::Available).iter().map(line_text).collect();
+        let position = |needle: &str| {
+            text.iter()
+                .position(|l| l.contains(needle))
+                .unwrap_or_else(|| panic!("{needle} missing from {text:?}"))
+        };
+
+        assert!(position(" Columns") < position("$Val"));
+        assert!(position(" Keys") < position("Filter by position"));
+        assert!(position(" Colors") < position("Pinned player"));
+        assert!(position(" Everywhere") < position("This help"));
+
+        // Sections with nothing to say are left out.
+        let text: Vec<String> = build_help_lines(HelpTopic::Budget).iter().map(line_text).collect();
+        assert!(!text.iter().any(|l| l == " Keys"));
+        assert!(text.iter().any(|l| l == " Colors"));
+    }
+
+    #[test]
+    fn every_topic_fits_the_modal() {
+        for topic in HelpTopic::ALL {
+            for line in build_help_lines(topic) {
+                let width = line_text(&line).chars().count();
+                assert!(width <= MODAL_WIDTH as usize - 2, "{topic:?}: {:?}", line_text(&line));
+            }
+        }
+    }
+
+    #[test]
+    fn open_close_and_view() {
+        let mut modal = HelpModal::default();
+        modal.update(HelpModalMessage::Open(HelpTopic::Scarcity));
+        assert!(modal.open);
+        assert_eq!(modal.topic, HelpTopic::Scarcity);
+        for (w, h) in [(80, 40), (10, 5)] {
+            let backend = ratatui::backend::TestBackend::new(w, h);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| modal.view(frame, frame.area()))
+                .unwrap();
+        }
+        modal.update(HelpModalMessage::Close);
+        assert!(!modal.open);
+    }
+}
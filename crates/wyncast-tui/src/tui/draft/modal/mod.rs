@@ -1,10 +1,18 @@
 // Modal overlay layer for draft mode (Elm Architecture).
 //
-// Composes the draft-mode modal overlays: PositionFilterModal and quit
-// confirmation dialog. The parent renders this layer last so modals
+// Composes the draft-mode modal overlays: PositionFilterModal,
+// PlayerDetailModal, CompareModal, ResolvePickModal, TimeMachineModal, SessionStatsModal, DartBoardModal, DiffExplainModal, HelpModal, and quit confirmation dialog. The parent renders this layer last so modals
 // appear on top of all other content.
 
+pub mod compare;
+pub mod dart_board;
+pub mod diff_explain;
+pub mod help;
+pub mod player_detail;
 pub mod position_filter;
+pub mod resolve_pick;
+pub mod session_stats;
+pub mod time_machine;
 
 use ratatui::layout::Rect;
 use ratatui::Frame;
@@ -12,7 +20,15 @@ use ratatui::Frame;
 use crate::tui::confirm_dialog::{ConfirmDialog, ConfirmMessage, ConfirmResult};
 use crate::tui::subscription::Subscription;
 use crate::tui::subscription::keybinding::KeybindManager;
+use compare::{CompareModal, CompareModalAction, CompareModalMessage};
+use dart_board::{DartBoardModal, DartBoardModalMessage};
+use diff_explain::{DiffExplainModal, DiffExplainModalMessage};
+use help::{HelpModal, HelpModalMessage};
+use player_detail::{PlayerDetailModal, PlayerDetailModalAction, PlayerDetailModalMessage};
 use position_filter::{PositionFilterModal, PositionFilterModalAction, PositionFilterModalMessage};
+use resolve_pick::{ResolvePickModal, ResolvePickModalAction, ResolvePickModalMessage};
+use session_stats::{SessionStatsModal, SessionStatsModalMessage};
+use time_machine::{TimeMachineModal, TimeMachineModalAction, TimeMachineModalMessage};
 
 // ---------------------------------------------------------------------------
 // Action
@@ -22,6 +38,10 @@ use position_filter::{PositionFilterModal, PositionFilterModalAction, PositionFi
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModalLayerAction {
     PositionFilter(PositionFilterModalAction),
+    PlayerDetail(PlayerDetailModalAction),
+    Compare(CompareModalAction),
+    ResolvePick(ResolvePickModalAction),
+    TimeMachine(TimeMachineModalAction),
     QuitConfirm(ConfirmResult),
 }
 
@@ -33,6 +53,14 @@ pub enum ModalLayerAction {
 #[derive(Debug, Clone)]
 pub enum ModalLayerMessage {
     PositionFilter(PositionFilterModalMessage),
+    PlayerDetail(PlayerDetailModalMessage),
+    Compare(CompareModalMessage),
+    ResolvePick(ResolvePickModalMessage),
+    TimeMachine(TimeMachineModalMessage),
+    SessionStats(SessionStatsModalMessage),
+    DartBoard(DartBoardModalMessage),
+    DiffExplain(DiffExplainModalMessage),
+    Help(HelpModalMessage),
     QuitConfirm(ConfirmMessage),
 }
 
@@ -47,6 +75,14 @@ pub enum ModalLayerMessage {
 #[derive(Debug, Clone)]
 pub struct ModalLayer {
     pub position_filter: PositionFilterModal,
+    pub player_detail: PlayerDetailModal,
+    pub compare: CompareModal,
+    pub resolve_pick: ResolvePickModal,
+    pub time_machine: TimeMachineModal,
+    pub session_stats: SessionStatsModal,
+    pub dart_board: DartBoardModal,
+    pub diff_explain: DiffExplainModal,
+    pub help: HelpModal,
     pub quit_confirm: ConfirmDialog,
 }
 
@@ -60,20 +96,38 @@ impl ModalLayer {
     pub fn new() -> Self {
         Self {
             position_filter: PositionFilterModal::default(),
+            player_detail: PlayerDetailModal::default(),
+            compare: CompareModal::default(),
+            resolve_pick: ResolvePickModal::default(),
+            time_machine: TimeMachineModal::default(),
+            session_stats: SessionStatsModal::default(),
+            dart_board: DartBoardModal::default(),
+            diff_explain: DiffExplainModal::default(),
+            help: HelpModal::default(),
             quit_confirm: ConfirmDialog::quit(),
         }
     }
 
     /// Returns `true` if any modal is currently intercepting input.
     pub fn has_active_modal(&self) -> bool {
-        self.position_filter.open || self.quit_confirm.open
+        self.position_filter.open
+            || self.player_detail.open
+            || self.compare.open
+            || self.resolve_pick.open
+            || self.time_machine.open
+            || self.session_stats.open
+            || self.dart_board.open
+            || self.diff_explain.open
+            || self.help.open
+            || self.quit_confirm.open
     }
 
     /// Declare keybindings for the subscription system.
     ///
     /// Only the open modal (if any) subscribes — quit confirm is checked first
-    /// (it has higher visual precedence), then position filter. Both are
-    /// mutually exclusive in normal flow, but the batch order encodes priority.
+    /// (it has higher visual precedence), then position filter, player
+    /// detail, compare, ambiguous pick, time machine, session stats, dart board, diff explainer, and help. They are mutually exclusive in normal flow,
+    /// but the batch order encodes priority.
     pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<ModalLayerMessage> {
         let quit_sub = self
             .quit_confirm
@@ -85,7 +139,52 @@ impl ModalLayer {
             .subscription(kb)
             .map(ModalLayerMessage::PositionFilter);
 
-        Subscription::batch([quit_sub, pos_sub])
+        let detail_sub = self
+            .player_detail
+            .subscription(kb)
+            .map(ModalLayerMessage::PlayerDetail);
+
+        let compare_sub = self.compare.subscription(kb).map(ModalLayerMessage::Compare);
+
+        let resolve_sub = self
+            .resolve_pick
+            .subscription(kb)
+            .map(ModalLayerMessage::ResolvePick);
+
+        let time_machine_sub = self
+            .time_machine
+            .subscription(kb)
+            .map(ModalLayerMessage::TimeMachine);
+
+        let stats_sub = self
+            .session_stats
+            .subscription(kb)
+            .map(ModalLayerMessage::SessionStats);
+
+        let dart_sub = self
+            .dart_board
+            .subscription(kb)
+            .map(ModalLayerMessage::DartBoard);
+
+        let diff_sub = self
+            .diff_explain
+            .subscription(kb)
+            .map(ModalLayerMessage::DiffExplain);
+
+        let help_sub = self.help.subscription(kb).map(ModalLayerMessage::Help);
+
+        Subscription::batch([
+            quit_sub,
+            pos_sub,
+            detail_sub,
+            compare_sub,
+            resolve_sub,
+            time_machine_sub,
+            stats_sub,
+            dart_sub,
+            diff_sub,
+            help_sub,
+        ])
     }
 
     /// Process a message and return an optional action for the parent.
@@ -94,18 +193,69 @@ impl ModalLayer {
             ModalLayerMessage::PositionFilter(m) => {
                 self.position_filter.update(m).map(ModalLayerAction::PositionFilter)
             }
+            ModalLayerMessage::PlayerDetail(m) => {
+                self.player_detail.update(m).map(ModalLayerAction::PlayerDetail)
+            }
+            ModalLayerMessage::Compare(m) => self.compare.update(m).map(ModalLayerAction::Compare),
+            ModalLayerMessage::ResolvePick(m) => {
+                self.resolve_pick.update(m).map(ModalLayerAction::ResolvePick)
+            }
+            ModalLayerMessage::TimeMachine(m) => {
+                self.time_machine.update(m).map(ModalLayerAction::TimeMachine)
+            }
+            ModalLayerMessage::SessionStats(m) => {
+                self.session_stats.update(m);
+                None
+            }
+            ModalLayerMessage::DartBoard(m) => {
+                self.dart_board.update(m);
+                None
+            }
+            ModalLayerMessage::DiffExplain(m) => {
+                self.diff_explain.update(m);
+                None
+            }
+            ModalLayerMessage::Help(m) => {
+                self.help.update(m);
+                None
+            }
             ModalLayerMessage::QuitConfirm(m) => {
                 self.quit_confirm.update(m).map(ModalLayerAction::QuitConfirm)
             }
         }
     }
 
-    /// Render all open modals. Position filter renders first; quit confirm
-    /// renders last (on top).
+    /// Render all open modals. Position filter, player detail, compare,
+    /// ambiguous pick, time machine, session stats, dart board, diff explainer, and help render first; quit confirm renders last
+    /// (on top).
     pub fn view(&self, frame: &mut Frame, area: Rect) {
         if self.position_filter.open {
             self.position_filter.view(frame, area);
         }
+        if self.player_detail.open {
+            self.player_detail.view(frame, area);
+        }
+        if self.compare.open {
+            self.compare.view(frame, area);
+        }
+        if self.resolve_pick.open {
+            self.resolve_pick.view(frame, area);
+        }
+        if self.time_machine.open {
+            self.time_machine.view(frame, area);
+        }
+        if self.session_stats.open {
+            self.session_stats.view(frame, area);
+        }
+        if self.dart_board.open {
+            self.dart_board.view(frame, area);
+        }
+        if self.diff_explain.open {
+            self.diff_explain.view(frame, area);
+        }
+        if self.help.open {
+            self.help.view(frame, area);
+        }
         if self.quit_confirm.open {
             self.quit_confirm.view(frame, area);
         }
@@ -126,9 +276,48 @@ mod tests {
         let layer = ModalLayer::new();
         assert!(!layer.has_active_modal());
         assert!(!layer.position_filter.open);
+        assert!(!layer.player_detail.open);
+        assert!(!layer.compare.open);
+        assert!(!layer.resolve_pick.open);
+        assert!(!layer.time_machine.open);
+        assert!(!layer.session_stats.open);
+        assert!(!layer.dart_board.open);
+        assert!(!layer.diff_explain.open);
+        assert!(!layer.help.open);
         assert!(!layer.quit_confirm.open);
     }
 
+    #[test]
+    fn update_time_machine_forwards_request() {
+        let mut layer = ModalLayer::new();
+        let action = layer.update(ModalLayerMessage::TimeMachine(
+            TimeMachineModalMessage::Open {
+                pick_number: 4,
+                total_picks: 10,
+            },
+        ));
+        assert_eq!(
+            action,
+            Some(ModalLayerAction::TimeMachine(TimeMachineModalAction::Request(4)))
+        );
+        assert!(layer.has_active_modal());
+    }
+
+    #[test]
+    fn update_player_detail_forwards() {
+        let mut layer = ModalLayer::new();
+        let player = crate::test_utils::TestPlayer::hitter("Detail").build();
+        let action = layer.update(ModalLayerMessage::PlayerDetail(
+            PlayerDetailModalMessage::Open(Box::new(player), None, None),
+        ));
+        assert!(action.is_none());
+        assert!(layer.player_detail.open);
+        assert!(layer.has_active_modal());
+
+        layer.update(ModalLayerMessage::PlayerDetail(PlayerDetailModalMessage::Close));
+        assert!(!layer.has_active_modal());
+    }
+
     #[test]
     fn has_active_modal_position_filter() {
         let mut layer = ModalLayer::new();
@@ -136,6 +325,20 @@ mod tests {
         assert!(layer.has_active_modal());
     }
 
+    #[test]
+    fn update_help_opens_the_topic() {
+        let mut layer = ModalLayer::new();
+        let action = layer.update(ModalLayerMessage::Help(HelpModalMessage::Open(
+            crate::tui::draft::help::HelpTopic::Budget,
+        )));
+        assert!(action.is_none());
+        assert!(layer.has_active_modal());
+        assert_eq!(layer.help.topic, crate::tui::draft::help::HelpTopic::Budget);
+
+        layer.update(ModalLayerMessage::Help(HelpModalMessage::Close));
+        assert!(!layer.has_active_modal());
+    }
+
     #[test]
     fn has_active_modal_quit_confirm() {
         let mut layer = ModalLayer::new();
@@ -0,0 +1,847 @@
+// Player detail modal component (Elm Architecture).
+//
+// A centered read-only popup for a single available player: identity,
+// rate stats, the dollar value broken down into its components (base
+// VOR conversion and positional flexibility bonus), the keeper contract
+// when the player is under one, the custom stats from config, my prep
+// sheet's value, tier and note for the player, and where the projections
+// came from. Holds a snapshot of the
+// player taken when it was opened.
+//
+// `e` switches to the explain view: the full calculation chain from
+// projections to price, which the app works out on request. `f` and `s`
+// open the player's FanGraphs and Baseball Savant pages in the browser.
+
+use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::tui::draft::main_panel::available::{format_custom_stat, format_positions};
+use crate::tui::subscription::{
+    Subscription, SubscriptionId,
+    keybinding::{exact, KeyBindingRecipe, KeybindHint, KeybindManager, PRIORITY_MODAL},
+};
+use crate::tui::widgets::centered_rect;
+use crate::config::StatsSite;
+use crate::valuation::explain::{CategoryStep, ValuationExplanation};
+use crate::valuation::identity::PlayerRef;
+use crate::valuation::keeper::KeeperValue;
+use crate::valuation::prep::PrepEntry;
+use crate::valuation::zscore::{describe_provenance, PlayerValuation};
+
+// ---------------------------------------------------------------------------
+// Message
+// ---------------------------------------------------------------------------
+
+/// Messages that drive the player detail modal.
+#[derive(Debug, Clone)]
+pub enum PlayerDetailModalMessage {
+    /// Open the modal showing a snapshot of `player`, its keeper contract
+    /// and its prep sheet row, if any.
+    Open(Box<PlayerValuation>, Option<KeeperValue>, Option<PrepEntry>),
+    /// Switch between the summary and the explain view (e).
+    ToggleExplain,
+    /// The calculation chain arrived from the app, or why it couldn't be
+    /// worked out.
+    Explained(Result<Box<ValuationExplanation>, String>),
+    /// Open the player's page on a stats site (f / s).
+    OpenStats(StatsSite),
+    /// Close the modal (Esc / Enter / i).
+    Close,
+}
+
+/// Actions returned by [`PlayerDetailModal::update`] for the parent to handle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerDetailModalAction {
+    /// Ask the app to explain this player's value.
+    Explain(PlayerRef),
+    /// Ask the app to open this player's page (name, MLB team) on a stats
+    /// site.
+    OpenStats(String, String, StatsSite),
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Width of the modal dialog.
+const MODAL_WIDTH: u16 = 44;
+
+/// Width of the modal in the explain view.
+const EXPLAIN_WIDTH: u16 = 64;
+
+/// State for the player detail modal overlay.
+#[derive(Debug, Clone)]
+pub struct PlayerDetailModal {
+    /// Whether the modal is currently visible.
+    pub open: bool,
+    /// The player being shown (snapshot at open time).
+    player: Option<PlayerValuation>,
+    /// The player's keeper contract, priced at open time.
+    keeper: Option<KeeperValue>,
+    /// My prep sheet's row for the player.
+    prep: Option<PrepEntry>,
+    /// Names of the custom stats, aligned with each player's values.
+    pub custom_stats: Vec<String>,
+    /// Whether the explain view is showing.
+    explaining: bool,
+    /// The calculation chain for the player, once it has arrived.
+    explanation: Option<Result<ValuationExplanation, String>>,
+    sub_id: SubscriptionId,
+}
+
+impl Default for PlayerDetailModal {
+    fn default() -> Self {
+        Self {
+            open: false,
+            player: None,
+            keeper: None,
+            prep: None,
+            custom_stats: Vec::new(),
+            explaining: false,
+            explanation: None,
+            sub_id: SubscriptionId::unique(),
+        }
+    }
+}
+
+impl PlayerDetailModal {
+    /// The player currently shown, if any.
+    pub fn player(&self) -> Option<&PlayerValuation> {
+        self.player.as_ref()
+    }
+
+    /// Whether the explain view is showing.
+    pub fn is_explaining(&self) -> bool {
+        self.explaining
+    }
+
+    // -- Elm Architecture API ------------------------------------------------
+
+    /// Declare keybindings for the subscription system.
+    ///
+    /// Returns a capturing subscription at `PRIORITY_MODAL` while open, or
+    /// `Subscription::none()` when closed.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<PlayerDetailModalMessage> {
+        if !self.open {
+            return Subscription::none();
+        }
+
+        let recipe = KeyBindingRecipe::new(self.sub_id)
+            .priority(PRIORITY_MODAL)
+            .capture()
+            .bind(
+                exact(KeyCode::Esc),
+                |_| PlayerDetailModalMessage::Close,
+                KeybindHint::new("Esc", "Close"),
+            )
+            .bind(
+                exact(KeyCode::Enter),
+                |_| PlayerDetailModalMessage::Close,
+                None,
+            )
+            .bind(
+                exact(KeyCode::Char('i')),
+                |_| PlayerDetailModalMessage::Close,
+                None,
+            )
+            .bind(
+                exact(KeyCode::Char('e')),
+                |_| PlayerDetailModalMessage::ToggleExplain,
+                KeybindHint::new("e", if self.explaining { "Summary" } else { "Explain" }),
+            )
+            .bind(
+                exact(KeyCode::Char('f')),
+                |_| PlayerDetailModalMessage::OpenStats(StatsSite::FanGraphs),
+                KeybindHint::new("f/s", "FanGraphs/Savant"),
+            )
+            .bind(
+                exact(KeyCode::Char('s')),
+                |_| PlayerDetailModalMessage::OpenStats(StatsSite::Savant),
+                None,
+            );
+
+        kb.subscribe(recipe)
+    }
+
+    /// Process a message and return an optional action for the parent.
+    pub fn update(&mut self, msg: PlayerDetailModalMessage) -> Option<PlayerDetailModalAction> {
+        match msg {
+            PlayerDetailModalMessage::Open(player, keeper, prep) => {
+                self.player = Some(*player);
+                self.keeper = keeper;
+                self.prep = prep;
+                self.explaining = false;
+                self.explanation = None;
+                self.open = true;
+            }
+            PlayerDetailModalMessage::ToggleExplain => {
+                self.explaining = !self.explaining;
+                // Ask once per player; the chain doesn't change while the
+                // popup is open.
+                if self.explaining && self.explanation.is_none() {
+                    return self
+                        .player
+                        .as_ref()
+                        .map(|p| PlayerDetailModalAction::Explain(PlayerRef::of(p)));
+                }
+            }
+            PlayerDetailModalMessage::Explained(result) => {
+                let current = self.player.as_ref().map(|p| p.name.as_str());
+                // Drop a late answer about a player no longer shown.
+                if result.as_ref().is_ok_and(|e| Some(e.player.as_str()) != current) {
+                    return None;
+                }
+                if self.open {
+                    self.explanation = Some(result.map(|e| *e));
+                }
+            }
+            PlayerDetailModalMessage::OpenStats(site) => {
+                let player = self.player.as_ref().filter(|_| self.open)?;
+                return Some(PlayerDetailModalAction::OpenStats(player.name.clone(), player.team.clone(), site));
+            }
+            PlayerDetailModalMessage::Close => {
+                self.open = false;
+                self.player = None;
+                self.keeper = None;
+                self.prep = None;
+                self.explaining = false;
+                self.explanation = None;
+            }
+        }
+        None
+    }
+
+    /// Render the modal overlay. Only draws when open with a player.
+    pub fn view(&self, frame: &mut Frame, area: Rect) {
+        let Some(player) = self.player.as_ref().filter(|_| self.open) else {
+            return;
+        };
+
+        let (lines, width) = if self.explaining {
+            (build_explain_lines(self.explanation.as_ref()), EXPLAIN_WIDTH)
+        } else {
+            let mut lines = build_detail_lines(player, self.keeper.as_ref());
+            lines.extend(custom_stat_lines(player, &self.custom_stats));
+            if let Some(prep) = &self.prep {
+                lines.extend(prep_lines(prep));
+            }
+            (lines, MODAL_WIDTH)
+        };
+        // Height: border(2) + content lines
+        let modal_height = 2 + lines.len() as u16;
+        let modal_area = centered_rect(width, modal_height, area);
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                format!(" {} ", player.name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+    }
+}
+
+/// Build the popup body: identity line, the value breakdown, the keeper
+/// contract, then the projection sources and adjustments.
+///
+/// The flexibility bonus always gets its own row (even at $0) so the
+/// breakdown reads the same for every player.
+pub fn build_detail_lines(player: &PlayerValuation, keeper: Option<&KeeperValue>) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    let base_value = player.dollar_value - player.flex_bonus;
+
+    let row = |name: &str, value: String, style: Style| {
+        Line::from(vec![
+            Span::styled(format!(" {:<22}", name), label),
+            Span::styled(format!("{:>12}", value), style),
+        ])
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                format!(" {} ", format_positions(&player.positions)),
+                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("- {}", player.team), label),
+        ]),
+        Line::from(""),
+        row("VOR", format!("{:.1}", player.vor), Style::default()),
+        row("zTotal", format!("{:.2}", player.total_zscore), Style::default()),
+        Line::from(""),
+        row("Base value", format!("${:.1}", base_value), Style::default()),
+        row(
+            "Flexibility bonus",
+            format!("+${:.1}", player.flex_bonus),
+            if player.flex_bonus > 0.0 {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            },
+        ),
+        row(
+            "Total value",
+            format!("${:.1}", player.dollar_value),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if let Some(keeper) = keeper {
+        lines.push(Line::from(""));
+        lines.push(row(
+            "Keeper salary",
+            format!("${} x{}y", keeper.salary(), keeper.years()),
+            Style::default(),
+        ));
+        if let Some(surplus) = keeper.surplus() {
+            lines.push(row("Surplus this year", signed_dollars(surplus), surplus_style(surplus)));
+        }
+        if let Some(future) = keeper.future_surplus().filter(|_| keeper.years() > 1) {
+            lines.push(row("Future surplus", signed_dollars(future), surplus_style(future)));
+        }
+    }
+    lines.extend(source_lines(player));
+    lines.extend(adjustment_lines(player));
+    lines
+}
+
+/// Build the explain view: one row per category from projected stat to
+/// weighted z-score, then the steps from total z-score to today's price.
+pub fn build_explain_lines(explanation: Option<&Result<ValuationExplanation, String>>) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    let explanation = match explanation {
+        None => return vec![Line::from(Span::styled(" Working out the calculation...", label))],
+        Some(Err(e)) => {
+            return vec![Line::from(Span::styled(format!(" {e}"), Style::default().fg(Color::Red)))];
+        }
+        Some(Ok(explanation)) => explanation,
+    };
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            " {:<5}{:>8}{:>8}{:>16}{:>8}{:>6}{:>8}",
+            "Cat", "Proj", "Scored", "Pool mean ± sd", "z", "Wt", "z×Wt"
+        ),
+        label,
+    ))];
+    for step in &explanation.categories {
+        lines.push(Line::from(category_row(step)));
+    }
+
+    let row = |name: String, value: String, style: Style| {
+        Line::from(vec![
+            Span::styled(format!(" {:<40}", name), label),
+            Span::styled(format!("{:>20}", value), style),
+        ])
+    };
+    let position = explanation.position.map(|p| p.display_str()).unwrap_or("-");
+    let vor_dollars = explanation.base_value - explanation.flex_bonus - explanation.min_value;
+    lines.extend([
+        Line::from(""),
+        row("Total z-score".into(), format!("{:+.2}", explanation.total_zscore), Style::default()),
+        row(
+            format!("Replacement level at {position}"),
+            format!("{:+.2}", explanation.replacement),
+            Style::default(),
+        ),
+        row("VOR".into(), format!("{:+.2}", explanation.vor), Style::default()),
+        row(
+            "Dollars per VOR".into(),
+            format!("${:.2}", explanation.dollars_per_vor),
+            Style::default(),
+        ),
+        row(
+            "Floor + VOR dollars".into(),
+            format!("${:.1} + ${:.1}", explanation.min_value, vor_dollars),
+            Style::default(),
+        ),
+        row("Flexibility bonus".into(), format!("+${:.1}", explanation.flex_bonus), Style::default()),
+        row("Base value".into(), format!("${:.1}", explanation.base_value), Style::default()),
+        row(
+            "Inflation multiplier".into(),
+            format!("x{:.2}", explanation.inflation_rate),
+            Style::default(),
+        ),
+        row(
+            "Adjusted value".into(),
+            format!("${:.1}", explanation.adjusted_value),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    lines
+}
+
+/// One category: the projected stat, the value scored (the contribution for
+/// rate stats), the pool it was scored against, and the weighted z-score.
+fn category_row(step: &CategoryStep) -> Vec<Span<'static>> {
+    let stat = if step.rate { format!("{:.3}", step.stat) } else { format!("{:.0}", step.stat) };
+    let scored = if step.rate { format!("{:+.1}", step.scored) } else { String::new() };
+    let weighted = step.weighted();
+    let style = if weighted > 0.0 {
+        Style::default().fg(Color::Green)
+    } else if weighted < 0.0 {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default()
+    };
+    vec![
+        Span::raw(format!(
+            " {:<5}{:>8}{:>8}{:>16}{:>+8.2}{:>6.2}",
+            step.category,
+            stat,
+            scored,
+            format!("{:.1} ± {:.1}", step.pool_mean, step.pool_stdev),
+            step.zscore,
+            step.weight,
+        )),
+        Span::styled(format!("{:>+8.2}", weighted), style),
+    ]
+}
+
+/// `+$4.0` / `-$4.0`.
+fn signed_dollars(amount: f64) -> String {
+    let sign = if amount < 0.0 { '-' } else { '+' };
+    format!("{sign}${:.1}", amount.abs())
+}
+
+fn surplus_style(amount: f64) -> Style {
+    if amount < 0.0 {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default().fg(Color::Green)
+    }
+}
+
+/// Where the projection values came from, one line per distinct source
+/// (override or blend) with the fields it supplied. A single source covering
+/// every field is shown on one line. Empty when provenance is unknown.
+fn source_lines(player: &PlayerValuation) -> Vec<Line<'static>> {
+    let provenance = &player.projection.provenance;
+    if provenance.is_empty() {
+        return Vec::new();
+    }
+
+    // Group fields by their description, in a stable order.
+    let mut fields: Vec<&String> = provenance.keys().collect();
+    fields.sort();
+    let mut groups: Vec<(String, Vec<&str>)> = Vec::new();
+    for field in fields {
+        let description = describe_provenance(&provenance[field]);
+        match groups.iter_mut().find(|(d, _)| *d == description) {
+            Some((_, members)) => members.push(field),
+            None => groups.push((description, vec![field])),
+        }
+    }
+
+    let label = Style::default().fg(Color::DarkGray);
+    let source_style = |description: &str| {
+        if description.contains("(override)") || description.contains('+') {
+            Style::default().fg(Color::Magenta)
+        } else {
+            Style::default()
+        }
+    };
+
+    let mut lines = vec![Line::from("")];
+    if let [(description, _)] = groups.as_slice() {
+        lines.push(Line::from(vec![
+            Span::styled(" Source ", label),
+            Span::styled(description.clone(), source_style(description)),
+        ]));
+        return lines;
+    }
+    lines.push(Line::from(Span::styled(" Sources", label)));
+    for (description, members) in &groups {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {description}: "), source_style(description)),
+            Span::styled(members.join(" "), label),
+        ]));
+    }
+    lines
+}
+
+/// The factors from the adjustments CSV behind the projection, one entry per
+/// row with the stats it scaled. Empty when nothing was adjusted.
+fn adjustment_lines(player: &PlayerValuation) -> Vec<Line<'static>> {
+    let mut groups: Vec<(f64, &str, Vec<&str>)> = Vec::new();
+    for applied in &player.projection.adjustments {
+        match groups
+            .iter_mut()
+            .find(|(factor, reason, _)| *factor == applied.factor && *reason == applied.reason)
+        {
+            Some((_, _, stats)) => stats.push(&applied.stat),
+            None => groups.push((applied.factor, &applied.reason, vec![&applied.stat])),
+        }
+    }
+    if groups.is_empty() {
+        return Vec::new();
+    }
+
+    let label = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![Line::from(""), Line::from(Span::styled(" Adjusted", label))];
+    for (factor, reason, stats) in groups {
+        lines.push(Line::from(Span::styled(
+            format!("  x{factor:.2} {reason}"),
+            Style::default().fg(Color::Magenta),
+        )));
+        lines.push(Line::from(Span::styled(format!("    {}", stats.join(" ")), label)));
+    }
+    lines
+}
+
+/// The custom stats, one row each, `-` where the player's projection
+/// cannot compute one.
+pub fn custom_stat_lines(player: &PlayerValuation, names: &[String]) -> Vec<Line<'static>> {
+    if names.is_empty() {
+        return Vec::new();
+    }
+    let label = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![Line::from("")];
+    for (i, name) in names.iter().enumerate() {
+        let value = format_custom_stat(player.custom_stats.get(i).copied().flatten());
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {:<22}", name), label),
+            Span::raw(format!("{:>12}", value)),
+        ]));
+    }
+    lines
+}
+
+/// My prep sheet's row for the player: my value, tier, whether I'm
+/// targeting or avoiding them, and the note.
+pub fn prep_lines(prep: &PrepEntry) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    let row = |name: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!(" {:<22}", name), label),
+            Span::raw(format!("{:>12}", value)),
+        ])
+    };
+
+    let mut lines = vec![Line::from("")];
+    if let Some(value) = prep.my_value {
+        lines.push(row("My value", format!("${value:.1}")));
+    }
+    if let Some(tier) = prep.tier {
+        lines.push(row("My tier", tier.to_string()));
+    }
+    if prep.target {
+        lines.push(Line::from(Span::styled(" On my watchlist", Style::default().fg(Color::Cyan))));
+    }
+    if prep.avoid {
+        lines.push(Line::from(Span::styled(" On my avoid list", Style::default().fg(Color::Red))));
+    }
+    if !prep.note.is_empty() {
+        lines.push(Line::from(vec![Span::styled(" Note ", label), Span::raw(prep.note.clone())]));
+    }
+    if lines.len() == 1 {
+        return Vec::new();
+    }
+    lines
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::Position;
+    use crate::test_utils::TestPlayer;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn flexible_player() -> PlayerValuation {
+        let mut p = TestPlayer::hitter("Utility Guy")
+            .positions(vec![Position::SecondBase, Position::ShortStop])
+            .vor(3.0)
+            .build();
+        p.dollar_value = 21.6;
+        p.flex_bonus = 0.6;
+        p
+    }
+
+    #[test]
+    fn open_and_close() {
+        let mut modal = PlayerDetailModal::default();
+        assert!(!modal.open);
+
+        modal.update(PlayerDetailModalMessage::Open(Box::new(flexible_player()), None, None));
+        assert!(modal.open);
+        assert_eq!(modal.player().unwrap().name, "Utility Guy");
+
+        modal.update(PlayerDetailModalMessage::Close);
+        assert!(!modal.open);
+        assert!(modal.player().is_none());
+    }
+
+    #[test]
+    fn detail_lines_show_flex_bonus_as_separate_component() {
+        let lines = build_detail_lines(&flexible_player(), None);
+        let text: Vec<String> = lines.iter().map(line_text).collect();
+
+        assert!(text[0].contains("2B/SS"));
+        let base = text.iter().find(|l| l.contains("Base value")).unwrap();
+        assert!(base.contains("$21.0"), "got: {base}");
+        let bonus = text.iter().find(|l| l.contains("Flexibility bonus")).unwrap();
+        assert!(bonus.contains("+$0.6"), "got: {bonus}");
+        let total = text.iter().find(|l| l.contains("Total value")).unwrap();
+        assert!(total.contains("$21.6"), "got: {total}");
+    }
+
+    #[test]
+    fn detail_lines_show_keeper_surplus_now_and_later() {
+        let keeper = KeeperValue {
+            player: "Utility Guy".into(),
+            salaries: vec![18, 23, 28],
+            value: Some(21.6),
+        };
+        let text: Vec<String> = build_detail_lines(&flexible_player(), Some(&keeper))
+            .iter()
+            .map(line_text)
+            .collect();
+        let salary = text.iter().find(|l| l.contains("Keeper salary")).unwrap();
+        assert!(salary.contains("$18 x3y"), "got: {salary}");
+        let now = text.iter().find(|l| l.contains("Surplus this year")).unwrap();
+        assert!(now.contains("+$3.6"), "got: {now}");
+        let future = text.iter().find(|l| l.contains("Future surplus")).unwrap();
+        assert!(future.contains("-$7.8"), "got: {future}");
+
+
+        // Not under contract, no keeper rows.
+        let text: Vec<String> = build_detail_lines(&flexible_player(), None).iter().map(line_text).collect();
+        assert!(!text.iter().any(|l| l.contains("Keeper") || l.contains("surplus")));
+    }
+
+    #[test]
+    fn custom_stat_lines_follow_the_config_names() {
+        let mut player = flexible_player();
+        player.custom_stats = vec![Some(0.1826), None];
+        let names = ["K-BB%".to_string(), "xwOBA".to_string()];
+        let text: Vec<String> = custom_stat_lines(&player, &names).iter().map(line_text).collect();
+        assert_eq!(text.len(), 3);
+        assert!(text[1].starts_with(" K-BB%") && text[1].ends_with("0.183"), "{text:?}");
+        assert!(text[2].starts_with(" xwOBA") && text[2].ends_with('-'), "{text:?}");
+        assert!(custom_stat_lines(&player, &[]).is_empty());
+    }
+
+    #[test]
+    fn prep_lines_show_what_my_sheet_says() {
+        let mut prep = PrepEntry {
+            player: "Utility Guy".into(),
+            my_value: Some(24.0),
+            tier: Some(2),
+            target: true,
+            avoid: false,
+            note: "SB upside".into(),
+        };
+        let text: Vec<String> = prep_lines(&prep).iter().map(line_text).collect();
+        assert!(text[1].starts_with(" My value") && text[1].ends_with("$24.0"));
+        assert!(text[2].starts_with(" My tier") && text[2].ends_with('2'));
+        assert_eq!(text[3..], [" On my watchlist", " Note SB upside"]);
+
+        // A row with nothing but the name adds nothing.
+        prep = PrepEntry { my_value: None, tier: None, target: false, note: String::new(), ..prep };
+        assert!(prep_lines(&prep).is_empty());
+    }
+
+    #[test]
+    fn detail_lines_show_a_single_source_on_one_line() {
+        let mut player = flexible_player();
+        player.projection = player.projection.with_source("hitters.csv", true);
+        let text: Vec<String> = build_detail_lines(&player, None).iter().map(line_text).collect();
+        assert_eq!(text.last().unwrap(), " Source hitters.csv (override)");
+
+        // No provenance, no section.
+        let text: Vec<String> = build_detail_lines(&flexible_player(), None).iter().map(line_text).collect();
+        assert!(!text.iter().any(|l| l.contains("Source")));
+    }
+
+    #[test]
+    fn detail_lines_group_fields_by_source() {
+        use crate::valuation::zscore::FieldProvenance;
+
+        let mut player = flexible_player();
+        player.projection = player.projection.with_source("ESPN", false);
+        let source = |name: &str, weight| FieldProvenance {
+            source: name.into(),
+            weight,
+            overridden: false,
+        };
+        player
+            .projection
+            .provenance
+            .insert("hr".into(), vec![source("ESPN", 0.5), source("steamer.csv", 0.5)]);
+        player
+            .projection
+            .provenance
+            .insert("sb".into(), vec![source("ESPN", 0.5), source("steamer.csv", 0.5)]);
+
+        let text: Vec<String> = build_detail_lines(&player, None).iter().map(line_text).collect();
+        assert!(text.contains(&" Sources".to_string()));
+        assert!(text.contains(&"  50% ESPN + 50% steamer.csv: hr sb".to_string()), "got: {text:?}");
+        assert!(text.iter().any(|l| l.starts_with("  ESPN: ") && !l.contains("hr")));
+    }
+
+    #[test]
+    fn detail_lines_list_the_adjustments_by_row() {
+        use crate::valuation::adjustments::AppliedAdjustment;
+
+        let mut player = flexible_player();
+        let applied = |stat: &str, factor, reason: &str| AppliedAdjustment {
+            stat: stat.into(),
+            factor,
+            reason: reason.into(),
+        };
+        player.projection.adjustments = vec![
+            applied("hr", 1.12, "COL: Coors"),
+            applied("pa", 0.9, "Utility Guy"),
+            applied("sb", 0.9, "Utility Guy"),
+        ];
+        let text: Vec<String> = build_detail_lines(&player, None).iter().map(line_text).collect();
+        let at = text.iter().position(|l| l == " Adjusted").expect("adjusted section");
+        assert_eq!(
+            text[at + 1..],
+            ["  x1.12 COL: Coors", "    hr", "  x0.90 Utility Guy", "    pa sb"]
+        );
+
+        let text: Vec<String> = build_detail_lines(&flexible_player(), None).iter().map(line_text).collect();
+        assert!(!text.iter().any(|l| l.contains("Adjusted")));
+    }
+
+    fn explanation() -> ValuationExplanation {
+        ValuationExplanation {
+            player: "Utility Guy".into(),
+            categories: vec![
+                CategoryStep {
+                    category: "HR".into(),
+                    stat: 30.0,
+                    scored: 30.0,
+                    rate: false,
+                    pool_mean: 20.0,
+                    pool_stdev: 8.0,
+                    zscore: 1.25,
+                    weight: 1.0,
+                },
+                CategoryStep {
+                    category: "AVG".into(),
+                    stat: 0.262,
+                    scored: -1.5,
+                    rate: true,
+                    pool_mean: 0.0,
+                    pool_stdev: 5.0,
+                    zscore: -0.3,
+                    weight: 1.0,
+                },
+            ],
+            total_zscore: 0.95,
+            position: Some(Position::ShortStop),
+            replacement: -2.05,
+            vor: 3.0,
+            dollars_per_vor: 6.8,
+            min_value: 1.0,
+            flex_bonus: 0.6,
+            base_value: 21.6,
+            inflation_rate: 1.1,
+            adjusted_value: 23.7,
+        }
+    }
+
+    #[test]
+    fn stats_keys_ask_for_the_shown_players_page() {
+        let mut modal = PlayerDetailModal::default();
+        assert!(modal.update(PlayerDetailModalMessage::OpenStats(StatsSite::Savant)).is_none());
+
+        let player = flexible_player();
+        let team = player.team.clone();
+        modal.update(PlayerDetailModalMessage::Open(Box::new(player), None, None));
+        let action = modal.update(PlayerDetailModalMessage::OpenStats(StatsSite::FanGraphs));
+        assert_eq!(
+            action,
+            Some(PlayerDetailModalAction::OpenStats("Utility Guy".into(), team, StatsSite::FanGraphs))
+        );
+        assert!(modal.open, "the popup stays up");
+    }
+
+    #[test]
+    fn explain_requests_the_chain_once_and_keeps_it() {
+        let mut modal = PlayerDetailModal::default();
+        modal.update(PlayerDetailModalMessage::Open(Box::new(flexible_player()), None, None));
+
+        let action = modal.update(PlayerDetailModalMessage::ToggleExplain);
+        assert_eq!(action, Some(PlayerDetailModalAction::Explain(PlayerRef::of(&flexible_player()))));
+        assert!(modal.is_explaining());
+
+        // A late answer about someone else is dropped.
+        let mut other = explanation();
+        other.player = "Someone Else".into();
+        modal.update(PlayerDetailModalMessage::Explained(Ok(Box::new(other))));
+        assert!(modal.explanation.is_none());
+
+        modal.update(PlayerDetailModalMessage::Explained(Ok(Box::new(explanation()))));
+        assert_eq!(modal.explanation, Some(Ok(explanation())));
+
+        // Back to the summary and again: nothing new to ask for.
+        assert!(modal.update(PlayerDetailModalMessage::ToggleExplain).is_none());
+        assert!(!modal.is_explaining());
+        assert!(modal.update(PlayerDetailModalMessage::ToggleExplain).is_none());
+
+        // Reopening starts from the summary.
+        modal.update(PlayerDetailModalMessage::Open(Box::new(flexible_player()), None, None));
+        assert!(!modal.is_explaining());
+        assert!(modal.explanation.is_none());
+    }
+
+    #[test]
+    fn explain_lines_walk_from_stats_to_price() {
+        let text: Vec<String> = build_explain_lines(Some(&Ok(explanation())))
+            .iter()
+            .map(line_text)
+            .collect();
+        let find = |label: &str| text.iter().find(|l| l.starts_with(label)).unwrap().clone();
+
+        assert_eq!(find(" HR"), " HR         30              20.0 ± 8.0   +1.25  1.00   +1.25");
+        assert_eq!(find(" AVG"), " AVG     0.262    -1.5       0.0 ± 5.0   -0.30  1.00   -0.30");
+        assert!(find(" Replacement level at SS").ends_with("-2.05"));
+        assert!(find(" Dollars per VOR").ends_with("$6.80"));
+        assert!(find(" Floor + VOR dollars").ends_with("$1.0 + $20.0"));
+        assert!(find(" Inflation multiplier").ends_with("x1.10"));
+        assert!(find(" Adjusted value").ends_with("$23.7"));
+
+        let loading: Vec<String> = build_explain_lines(None).iter().map(line_text).collect();
+        assert_eq!(loading, vec![" Working out the calculation..."]);
+        let failed = build_explain_lines(Some(&Err("No projections".into())));
+        assert_eq!(line_text(&failed[0]), " No projections");
+    }
+
+    #[test]
+    fn view_does_not_panic_when_open_or_on_small_terminal() {
+        let mut modal = PlayerDetailModal::default();
+        modal.update(PlayerDetailModalMessage::Open(Box::new(flexible_player()), None, None));
+        for explaining in [false, true] {
+            if explaining {
+                modal.update(PlayerDetailModalMessage::ToggleExplain);
+                modal.update(PlayerDetailModalMessage::Explained(Ok(Box::new(explanation()))));
+            }
+            for (w, h) in [(80, 24), (10, 5)] {
+                let backend = ratatui::backend::TestBackend::new(w, h);
+                let mut terminal = ratatui::Terminal::new(backend).unwrap();
+                terminal
+                    .draw(|frame| modal.view(frame, frame.area()))
+                    .unwrap();
+            }
+        }
+    }
+}
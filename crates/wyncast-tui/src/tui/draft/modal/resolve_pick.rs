@@ -0,0 +1,382 @@
+// Ambiguous pick modal component (Elm Architecture).
+//
+// Pops up when a pick's name matches more than one player in the pool (two
+// Will Smiths at the same position), listing the candidates by MLB team,
+// positions and value so I can say which one went. The app leaves both in
+// the pool until then.
+//
+// `1`-`9` or ↑/↓ + Enter choose; Esc puts the pick aside until `a` brings
+// the pending picks back.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::tui::draft::main_panel::available::format_positions;
+use crate::tui::subscription::{
+    Subscription, SubscriptionId,
+    keybinding::{exact, KeyBindingRecipe, KeybindHint, KeybindManager, PRIORITY_MODAL},
+};
+use crate::tui::widgets::centered_rect;
+use crate::valuation::identity::AmbiguousPick;
+
+// ---------------------------------------------------------------------------
+// Message
+// ---------------------------------------------------------------------------
+
+/// Messages that drive the ambiguous pick modal.
+#[derive(Debug, Clone)]
+pub enum ResolvePickModalMessage {
+    /// The app's pending ambiguous picks, from each snapshot.
+    SetPending(Vec<AmbiguousPick>),
+    /// Show the first pending pick not put aside, if any.
+    Show,
+    /// Show every pending pick again, including those put aside (a).
+    ShowAll,
+    MoveUp,
+    MoveDown,
+    /// Choose a candidate by its 1-based number (1-9).
+    Choose(usize),
+    /// Choose the highlighted candidate (Enter).
+    Confirm,
+    /// Put the pick aside for now (Esc).
+    Later,
+}
+
+/// Actions returned by [`ResolvePickModal::update`] for the parent to handle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvePickModalAction {
+    /// The pick was the candidate playing for this MLB team.
+    Resolve { pick_number: u32, team: String },
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Width of the modal dialog.
+const MODAL_WIDTH: u16 = 50;
+
+/// State for the ambiguous pick overlay.
+#[derive(Debug, Clone)]
+pub struct ResolvePickModal {
+    /// Whether the modal is currently visible.
+    pub open: bool,
+    /// Picks waiting to be resolved, oldest first.
+    pending: Vec<AmbiguousPick>,
+    /// Pick numbers put aside with Esc, or already answered.
+    set_aside: Vec<u32>,
+    /// Highlighted candidate of the shown pick.
+    selected: usize,
+    sub_id: SubscriptionId,
+}
+
+impl Default for ResolvePickModal {
+    fn default() -> Self {
+        Self {
+            open: false,
+            pending: Vec::new(),
+            set_aside: Vec::new(),
+            selected: 0,
+            sub_id: SubscriptionId::unique(),
+        }
+    }
+}
+
+impl ResolvePickModal {
+    /// Whether any pick is waiting to be resolved.
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// The pending pick with this number, shown or not.
+    pub fn pending(&self, pick_number: u32) -> Option<&AmbiguousPick> {
+        self.pending.iter().find(|p| p.pick_number == pick_number)
+    }
+
+    /// The pick shown: the first pending one not put aside.
+    pub fn current(&self) -> Option<&AmbiguousPick> {
+        self.pending
+            .iter()
+            .find(|p| !self.set_aside.contains(&p.pick_number))
+    }
+
+    // -- Elm Architecture API ------------------------------------------------
+
+    /// Declare keybindings for the subscription system.
+    ///
+    /// Returns a capturing subscription at `PRIORITY_MODAL` while open, or
+    /// `Subscription::none()` when closed.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<ResolvePickModalMessage> {
+        if !self.open {
+            return Subscription::none();
+        }
+
+        let mut recipe = KeyBindingRecipe::new(self.sub_id)
+            .priority(PRIORITY_MODAL)
+            .capture()
+            .bind(
+                exact(KeyCode::Esc),
+                |_| ResolvePickModalMessage::Later,
+                KeybindHint::new("Esc", "Later"),
+            )
+            .bind(
+                exact(KeyCode::Enter),
+                |_| ResolvePickModalMessage::Confirm,
+                KeybindHint::new("Enter", "Choose"),
+            )
+            .bind(
+                exact(KeyCode::Up),
+                |_| ResolvePickModalMessage::MoveUp,
+                KeybindHint::new("↑", "Up"),
+            )
+            .bind(
+                exact(KeyCode::Down),
+                |_| ResolvePickModalMessage::MoveDown,
+                KeybindHint::new("↓", "Down"),
+            );
+        for digit in '1'..='9' {
+            recipe = recipe.bind(exact(KeyCode::Char(digit)), choose_digit, None);
+        }
+
+        kb.subscribe(recipe)
+    }
+
+    /// Process a message and return an optional action for the parent.
+    pub fn update(&mut self, msg: ResolvePickModalMessage) -> Option<ResolvePickModalAction> {
+        match msg {
+            ResolvePickModalMessage::SetPending(pending) => {
+                let shown = self.current().map(|p| p.pick_number);
+                self.set_aside
+                    .retain(|n| pending.iter().any(|p| p.pick_number == *n));
+                self.pending = pending;
+                if self.current().map(|p| p.pick_number) != shown {
+                    self.selected = 0;
+                }
+                if self.current().is_none() {
+                    self.open = false;
+                }
+            }
+            ResolvePickModalMessage::Show => {
+                self.open = self.current().is_some();
+            }
+            ResolvePickModalMessage::ShowAll => {
+                self.set_aside.clear();
+                self.selected = 0;
+                self.open = self.current().is_some();
+            }
+            ResolvePickModalMessage::MoveUp => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            ResolvePickModalMessage::MoveDown => {
+                let count = self.current().map_or(0, |p| p.candidates.len());
+                if self.selected + 1 < count {
+                    self.selected += 1;
+                }
+            }
+            ResolvePickModalMessage::Choose(n) => {
+                let count = self.current().map_or(0, |p| p.candidates.len());
+                if (1..=count).contains(&n) {
+                    self.selected = n - 1;
+                    return self.update(ResolvePickModalMessage::Confirm);
+                }
+            }
+            ResolvePickModalMessage::Confirm => {
+                let pick = self.current()?;
+                let pick_number = pick.pick_number;
+                let team = pick.candidates.get(self.selected)?.team.clone();
+                self.next(pick_number);
+                return Some(ResolvePickModalAction::Resolve { pick_number, team });
+            }
+            ResolvePickModalMessage::Later => {
+                if let Some(pick_number) = self.current().map(|p| p.pick_number) {
+                    self.next(pick_number);
+                }
+            }
+        }
+        None
+    }
+
+    /// Put `pick_number` aside and show the next pick, or close.
+    fn next(&mut self, pick_number: u32) {
+        self.set_aside.push(pick_number);
+        self.selected = 0;
+        self.open = self.current().is_some();
+    }
+
+    /// Render the modal overlay. Only draws when open.
+    pub fn view(&self, frame: &mut Frame, area: Rect) {
+        let Some(pick) = self.current().filter(|_| self.open) else {
+            return;
+        };
+
+        let waiting = self
+            .pending
+            .iter()
+            .filter(|p| !self.set_aside.contains(&p.pick_number))
+            .count();
+        let lines = build_pick_lines(pick, self.selected, waiting - 1);
+        // Height: border(2) + content lines.
+        let modal_area = centered_rect(MODAL_WIDTH, 2 + lines.len() as u16, area);
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(Span::styled(
+                " Which player was it? ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+    }
+}
+
+fn choose_digit(key: KeyEvent) -> ResolvePickModalMessage {
+    let n = match key.code {
+        KeyCode::Char(c) => c.to_digit(10).unwrap_or(0) as usize,
+        _ => 0,
+    };
+    ResolvePickModalMessage::Choose(n)
+}
+
+/// The pick, a numbered row per candidate with the highlighted one marked,
+/// and how many more picks are waiting.
+pub fn build_pick_lines(pick: &AmbiguousPick, selected: usize, more: usize) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!(" Pick #{}: ", pick.pick_number), label),
+            Span::styled(
+                pick.player_name.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(" ({})", pick.position)),
+        ]),
+        Line::from(Span::styled(
+            format!(" ${} to {}", pick.price, pick.team_name),
+            label,
+        )),
+        Line::from(""),
+    ];
+    for (i, candidate) in pick.candidates.iter().enumerate() {
+        let style = if i == selected {
+            Style::default().fg(Color::Black).bg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(
+            format!(
+                " {}. {:<5} {:<12} ${:.1}",
+                i + 1,
+                candidate.team,
+                format_positions(&candidate.positions),
+                candidate.dollar_value,
+            ),
+            style,
+        )));
+    }
+    if more > 0 {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(" {more} more ambiguous pick{}", if more == 1 { "" } else { "s" }),
+            label,
+        )));
+    }
+    lines
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::Position;
+    use crate::valuation::identity::PickCandidate;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn pick(pick_number: u32) -> AmbiguousPick {
+        let candidate = |team: &str, value: f64| PickCandidate {
+            team: team.into(),
+            positions: vec![Position::Catcher],
+            dollar_value: value,
+        };
+        AmbiguousPick {
+            pick_number,
+            player_name: "Will Smith".into(),
+            position: "C".into(),
+            team_name: "Team 3".into(),
+            price: 14,
+            candidates: vec![candidate("LAD", 18.2), candidate("KC", 4.0)],
+        }
+    }
+
+    #[test]
+    fn lines_number_the_candidates() {
+        let lines: Vec<String> = build_pick_lines(&pick(12), 1, 1).iter().map(line_text).collect();
+        assert_eq!(lines[0], " Pick #12: Will Smith (C)");
+        assert_eq!(lines[1], " $14 to Team 3");
+        assert!(lines[3].starts_with(" 1. LAD") && lines[3].ends_with("$18.2"));
+        assert!(lines[4].starts_with(" 2. KC"));
+        assert_eq!(lines[6], " 1 more ambiguous pick");
+    }
+
+    #[test]
+    fn choosing_resolves_and_moves_to_the_next_pick() {
+        let mut modal = ResolvePickModal::default();
+        modal.update(ResolvePickModalMessage::SetPending(vec![pick(3), pick(7)]));
+        assert!(!modal.open);
+        modal.update(ResolvePickModalMessage::Show);
+        assert!(modal.open);
+
+        let action = modal.update(ResolvePickModalMessage::Choose(2));
+        assert_eq!(
+            action,
+            Some(ResolvePickModalAction::Resolve { pick_number: 3, team: "KC".into() })
+        );
+        assert_eq!(modal.current().map(|p| p.pick_number), Some(7));
+
+        // Out-of-range digits do nothing.
+        assert_eq!(modal.update(ResolvePickModalMessage::Choose(3)), None);
+        modal.update(ResolvePickModalMessage::MoveDown);
+        modal.update(ResolvePickModalMessage::MoveDown);
+        let action = modal.update(ResolvePickModalMessage::Confirm);
+        assert_eq!(
+            action,
+            Some(ResolvePickModalAction::Resolve { pick_number: 7, team: "KC".into() })
+        );
+        assert!(!modal.open);
+    }
+
+    #[test]
+    fn picks_put_aside_come_back_on_request() {
+        let mut modal = ResolvePickModal::default();
+        modal.update(ResolvePickModalMessage::SetPending(vec![pick(3)]));
+        modal.update(ResolvePickModalMessage::Show);
+        modal.update(ResolvePickModalMessage::Later);
+        assert!(!modal.open);
+
+        // The next snapshot still has it pending: it stays aside.
+        modal.update(ResolvePickModalMessage::SetPending(vec![pick(3)]));
+        modal.update(ResolvePickModalMessage::Show);
+        assert!(!modal.open && modal.has_pending());
+
+        modal.update(ResolvePickModalMessage::ShowAll);
+        assert!(modal.open);
+
+        // Resolved elsewhere: the modal closes.
+        modal.update(ResolvePickModalMessage::SetPending(vec![]));
+        assert!(!modal.open && !modal.has_pending());
+    }
+}
@@ -0,0 +1,269 @@
+// Session stats modal component (Elm Architecture).
+//
+// A centered read-only overlay with live analytics for the draft so far:
+// my surplus next to the best possible, the bargains other teams got that
+// would have fit my roster, the value left per open slot, and the average
+// price at each position. The app computes the numbers with every snapshot;
+// the overlay always shows the latest, so it stays current while open.
+
+use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::draft::session_stats::SessionStats;
+use crate::tui::subscription::{
+    Subscription, SubscriptionId,
+    keybinding::{exact, KeyBindingRecipe, KeybindHint, KeybindManager, PRIORITY_MODAL},
+};
+use crate::tui::widgets::centered_rect;
+
+// ---------------------------------------------------------------------------
+// Message
+// ---------------------------------------------------------------------------
+
+/// Messages that drive the session stats modal.
+#[derive(Debug, Clone)]
+pub enum SessionStatsModalMessage {
+    /// Open the overlay.
+    Open,
+    /// Close the overlay (Esc / Enter / s).
+    Close,
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Width of the modal dialog.
+const MODAL_WIDTH: u16 = 46;
+
+/// Most missed bargains listed by name.
+const BARGAINS_SHOWN: usize = 3;
+
+/// State for the session stats overlay.
+#[derive(Debug, Clone)]
+pub struct SessionStatsModal {
+    /// Whether the modal is currently visible.
+    pub open: bool,
+    /// The latest stats from the app, kept current whether or not the
+    /// modal is open.
+    pub stats: SessionStats,
+    sub_id: SubscriptionId,
+}
+
+impl Default for SessionStatsModal {
+    fn default() -> Self {
+        Self {
+            open: false,
+            stats: SessionStats::default(),
+            sub_id: SubscriptionId::unique(),
+        }
+    }
+}
+
+impl SessionStatsModal {
+    // -- Elm Architecture API ------------------------------------------------
+
+    /// Declare keybindings for the subscription system.
+    ///
+    /// Returns a capturing subscription at `PRIORITY_MODAL` while open, or
+    /// `Subscription::none()` when closed.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<SessionStatsModalMessage> {
+        if !self.open {
+            return Subscription::none();
+        }
+
+        let recipe = KeyBindingRecipe::new(self.sub_id)
+            .priority(PRIORITY_MODAL)
+            .capture()
+            .bind(
+                exact(KeyCode::Esc),
+                |_| SessionStatsModalMessage::Close,
+                KeybindHint::new("Esc", "Close"),
+            )
+            .bind(
+                exact(KeyCode::Enter),
+                |_| SessionStatsModalMessage::Close,
+                None,
+            )
+            .bind(
+                exact(KeyCode::Char('s')),
+                |_| SessionStatsModalMessage::Close,
+                None,
+            );
+
+        kb.subscribe(recipe)
+    }
+
+    /// Process a message.
+    pub fn update(&mut self, msg: SessionStatsModalMessage) {
+        match msg {
+            SessionStatsModalMessage::Open => self.open = true,
+            SessionStatsModalMessage::Close => self.open = false,
+        }
+    }
+
+    /// Render the modal overlay. Only draws when open.
+    pub fn view(&self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let lines = build_stats_lines(&self.stats);
+        // Height: border(2) + content lines
+        let modal_height = 2 + lines.len() as u16;
+        let modal_area = centered_rect(MODAL_WIDTH, modal_height, area);
+
+        frame.render_widget(Clear, modal_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(Span::styled(
+                " Session Stats ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        frame.render_widget(Paragraph::new(lines).block(block), modal_area);
+    }
+}
+
+/// Build the overlay body: surplus, missed bargains, value per open slot,
+/// then the average price by position.
+pub fn build_stats_lines(stats: &SessionStats) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    let row = |name: &str, value: String, style: Style| {
+        Line::from(vec![
+            Span::styled(format!(" {:<24}", name), label),
+            Span::styled(format!("{:>18}", value), style),
+        ])
+    };
+
+    let mut lines = vec![
+        row("My surplus", signed_dollars(stats.my_surplus), surplus_style(stats.my_surplus)),
+        row("Best possible", signed_dollars(stats.best_surplus), Style::default()),
+        row(
+            "Bargains missed",
+            stats.bargains_missed.len().to_string(),
+            if stats.bargains_missed.is_empty() {
+                Style::default()
+            } else {
+                Style::default().fg(Color::Yellow)
+            },
+        ),
+    ];
+    for bargain in stats.bargains_missed.iter().take(BARGAINS_SHOWN) {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "   {} ${} (worth ${:.0})",
+                bargain.player, bargain.price, bargain.value
+            ),
+            label,
+        )));
+    }
+    lines.push(row(
+        "Value per open slot",
+        format!("${:.1} x{}", stats.value_per_open_slot, stats.open_slots),
+        Style::default(),
+    ));
+
+    lines.push(Line::from(""));
+    if stats.position_prices.is_empty() {
+        lines.push(Line::from(Span::styled(" No players sold yet", label)));
+        return lines;
+    }
+    lines.push(Line::from(Span::styled(" Average price by position", label)));
+    for price in &stats.position_prices {
+        lines.push(row(
+            &format!("  {}", price.position.display_str()),
+            format!("${:.1} ({})", price.avg_price, price.picks),
+            Style::default(),
+        ));
+    }
+    lines
+}
+
+/// `+$4.0` / `-$4.0`.
+fn signed_dollars(amount: f64) -> String {
+    let sign = if amount < 0.0 { '-' } else { '+' };
+    format!("{sign}${:.1}", amount.abs())
+}
+
+fn surplus_style(amount: f64) -> Style {
+    if amount < 0.0 {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default().fg(Color::Green)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::Position;
+    use crate::draft::session_stats::{MissedBargain, PositionPrice};
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn stats() -> SessionStats {
+        SessionStats {
+            position_prices: vec![PositionPrice {
+                position: Position::ShortStop,
+                picks: 2,
+                avg_price: 25.0,
+            }],
+            my_surplus: -1.0,
+            best_surplus: 18.0,
+            bargains_missed: vec![MissedBargain {
+                player: "Their C".into(),
+                price: 2,
+                value: 12.0,
+            }],
+            open_slots: 40,
+            value_per_open_slot: 9.3,
+        }
+    }
+
+    #[test]
+    fn stats_lines_show_surplus_bargains_and_prices() {
+        let text: Vec<String> = build_stats_lines(&stats()).iter().map(line_text).collect();
+        let find = |label: &str| text.iter().find(|l| l.contains(label)).unwrap().clone();
+
+        assert!(find("My surplus").ends_with("-$1.0"));
+        assert!(find("Best possible").ends_with("+$18.0"));
+        assert!(find("Bargains missed").ends_with('1'));
+        assert!(text.contains(&"   Their C $2 (worth $12)".to_string()), "got: {text:?}");
+        assert!(find("Value per open slot").ends_with("$9.3 x40"));
+        assert!(find("  SS").ends_with("$25.0 (2)"));
+
+        let empty: Vec<String> = build_stats_lines(&SessionStats::default()).iter().map(line_text).collect();
+        assert_eq!(empty.last().unwrap(), " No players sold yet");
+    }
+
+    #[test]
+    fn open_close_and_view() {
+        let mut modal = SessionStatsModal { stats: stats(), ..Default::default() };
+        modal.update(SessionStatsModalMessage::Open);
+        assert!(modal.open);
+        for (w, h) in [(80, 24), (10, 5)] {
+            let backend = ratatui::backend::TestBackend::new(w, h);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| modal.view(frame, frame.area()))
+                .unwrap();
+        }
+        modal.update(SessionStatsModalMessage::Close);
+        assert!(!modal.open);
+    }
+}
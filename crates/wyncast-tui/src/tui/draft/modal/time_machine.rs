@@ -0,0 +1,532 @@
+// Time machine modal component (Elm Architecture).
+//
+// A read-only overlay showing the draft as it stood right after a past pick:
+// the pick itself, every team's budget, the best players still available,
+// and positional scarcity. The state is reconstructed by the app from the
+// pick log; this component only requests it and renders what comes back.
+// Left/Right step through picks one at a time.
+
+use crossterm::event::KeyCode;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::protocol::{TeamSnapshot, TimeMachineSnapshot};
+use crate::tui::draft::draft_log::format_pick;
+use crate::tui::draft::main_panel::available::format_positions;
+use crate::tui::draft::sidebar::scarcity::urgency_color;
+use crate::tui::subscription::{
+    Subscription, SubscriptionId,
+    keybinding::{exact, KeyBindingRecipe, KeybindHint, KeybindManager, PRIORITY_MODAL},
+};
+use crate::tui::widgets::centered_rect;
+use crate::valuation::scarcity::ScarcityEntry;
+use crate::valuation::zscore::PlayerValuation;
+
+// ---------------------------------------------------------------------------
+// Message / Action
+// ---------------------------------------------------------------------------
+
+/// Messages that drive the time machine modal.
+#[derive(Debug, Clone)]
+pub enum TimeMachineModalMessage {
+    /// Open the overlay at `pick_number` (1-based) out of `total_picks`.
+    Open { pick_number: usize, total_picks: usize },
+    /// Reconstructed state arrived (`None` if it couldn't be rebuilt).
+    Loaded {
+        pick_number: usize,
+        snapshot: Option<Box<TimeMachineSnapshot>>,
+    },
+    /// Step back one pick (Left / h).
+    Prev,
+    /// Step forward one pick (Right / l).
+    Next,
+    /// Close the overlay (Esc / t).
+    Close,
+}
+
+/// Actions returned by [`TimeMachineModal::update`] for the parent to handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeMachineModalAction {
+    /// Ask the app to reconstruct the state after this pick.
+    Request(usize),
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Maximum width of the modal dialog.
+const MODAL_WIDTH: u16 = 96;
+
+/// State for the time machine modal overlay.
+#[derive(Debug, Clone)]
+pub struct TimeMachineModal {
+    /// Whether the modal is currently visible.
+    pub open: bool,
+    /// The pick currently being viewed (1-based).
+    pick_number: usize,
+    /// Upper bound for stepping forward.
+    total_picks: usize,
+    /// State for `pick_number`, once it has arrived.
+    snapshot: Option<TimeMachineSnapshot>,
+    /// Whether a request for `pick_number` is outstanding.
+    loading: bool,
+    sub_id: SubscriptionId,
+}
+
+impl Default for TimeMachineModal {
+    fn default() -> Self {
+        Self {
+            open: false,
+            pick_number: 0,
+            total_picks: 0,
+            snapshot: None,
+            loading: false,
+            sub_id: SubscriptionId::unique(),
+        }
+    }
+}
+
+impl TimeMachineModal {
+    /// The pick currently being viewed (1-based; 0 when closed).
+    pub fn pick_number(&self) -> usize {
+        self.pick_number
+    }
+
+    /// The state currently shown, if it has arrived.
+    pub fn snapshot(&self) -> Option<&TimeMachineSnapshot> {
+        self.snapshot.as_ref()
+    }
+
+    /// Whether the modal is waiting for the app to send state.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    // -- Elm Architecture API ------------------------------------------------
+
+    /// Declare keybindings for the subscription system.
+    ///
+    /// Returns a capturing subscription at `PRIORITY_MODAL` while open, or
+    /// `Subscription::none()` when closed.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<TimeMachineModalMessage> {
+        if !self.open {
+            return Subscription::none();
+        }
+
+        let recipe = KeyBindingRecipe::new(self.sub_id)
+            .priority(PRIORITY_MODAL)
+            .capture()
+            .bind(
+                exact(KeyCode::Left),
+                |_| TimeMachineModalMessage::Prev,
+                KeybindHint::new("←/→", "Step"),
+            )
+            .bind(
+                exact(KeyCode::Char('h')),
+                |_| TimeMachineModalMessage::Prev,
+                None,
+            )
+            .bind(
+                exact(KeyCode::Right),
+                |_| TimeMachineModalMessage::Next,
+                None,
+            )
+            .bind(
+                exact(KeyCode::Char('l')),
+                |_| TimeMachineModalMessage::Next,
+                None,
+            )
+            .bind(
+                exact(KeyCode::Esc),
+                |_| TimeMachineModalMessage::Close,
+                KeybindHint::new("Esc", "Close"),
+            )
+            .bind(
+                exact(KeyCode::Char('t')),
+                |_| TimeMachineModalMessage::Close,
+                None,
+            );
+
+        kb.subscribe(recipe)
+    }
+
+    /// Process a message and return an optional action for the parent.
+    pub fn update(&mut self, msg: TimeMachineModalMessage) -> Option<TimeMachineModalAction> {
+        match msg {
+            TimeMachineModalMessage::Open {
+                pick_number,
+                total_picks,
+            } => {
+                self.open = true;
+                self.total_picks = total_picks;
+                self.go_to(pick_number)
+            }
+            TimeMachineModalMessage::Loaded {
+                pick_number,
+                snapshot,
+            } => {
+                // Ignore replies for picks we've already stepped away from.
+                if self.open && pick_number == self.pick_number {
+                    if let Some(ref s) = snapshot {
+                        self.total_picks = s.total_recorded;
+                    }
+                    self.snapshot = snapshot.map(|s| *s);
+                    self.loading = false;
+                }
+                None
+            }
+            TimeMachineModalMessage::Prev if self.pick_number > 1 => {
+                self.go_to(self.pick_number - 1)
+            }
+            TimeMachineModalMessage::Next if self.pick_number < self.total_picks => {
+                self.go_to(self.pick_number + 1)
+            }
+            TimeMachineModalMessage::Prev | TimeMachineModalMessage::Next => None,
+            TimeMachineModalMessage::Close => {
+                *self = Self {
+                    sub_id: self.sub_id,
+                    ..Self::default()
+                };
+                None
+            }
+        }
+    }
+
+    /// Move to `pick_number` and request its state.
+    fn go_to(&mut self, pick_number: usize) -> Option<TimeMachineModalAction> {
+        self.pick_number = pick_number;
+        self.snapshot = None;
+        self.loading = true;
+        Some(TimeMachineModalAction::Request(pick_number))
+    }
+
+    /// Render the modal overlay. Only draws when open.
+    pub fn view(&self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let title = Span::styled(
+            format!(" Time machine: after pick #{} of {} ", self.pick_number, self.total_picks),
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta))
+            .title(title);
+
+        let Some(snapshot) = self.snapshot.as_ref() else {
+            let text = if self.loading {
+                format!(" Reconstructing state after pick #{}...", self.pick_number)
+            } else {
+                format!(
+                    " State after pick #{} is unavailable (projections not loaded?)",
+                    self.pick_number
+                )
+            };
+            let modal_area = centered_rect(MODAL_WIDTH.min(72), 3, area);
+            frame.render_widget(Clear, modal_area);
+            frame.render_widget(
+                Paragraph::new(Span::styled(text, Style::default().fg(Color::Gray))).block(block),
+                modal_area,
+            );
+            return;
+        };
+
+        let header = build_header_lines(snapshot);
+        let budgets = build_budget_lines(&snapshot.teams);
+        let available = build_available_lines(&snapshot.top_available);
+        let scarcity = build_scarcity_line(&snapshot.scarcity);
+
+        // Height: border(2) + header + blank + columns + blank + scarcity
+        let columns_height = budgets.len().max(available.len()) as u16;
+        let modal_height = 2 + header.len() as u16 + 1 + columns_height + 2;
+        let modal_area = centered_rect(MODAL_WIDTH, modal_height, area);
+        frame.render_widget(Clear, modal_area);
+        let inner = block.inner(modal_area);
+        frame.render_widget(block, modal_area);
+
+        let [header_area, columns_area, scarcity_area] = Layout::vertical([
+            Constraint::Length(header.len() as u16 + 1),
+            Constraint::Min(1),
+            Constraint::Length(2),
+        ])
+        .areas(inner);
+        let [budget_area, available_area] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(columns_area);
+
+        frame.render_widget(Paragraph::new(header), header_area);
+        frame.render_widget(Paragraph::new(budgets), budget_area);
+        frame.render_widget(Paragraph::new(available), available_area);
+        frame.render_widget(
+            Paragraph::new(vec![Line::from(""), scarcity]),
+            scarcity_area,
+        );
+    }
+}
+
+/// The pick itself, its value at the time, and the league's inflation.
+pub fn build_header_lines(snapshot: &TimeMachineSnapshot) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::DarkGray);
+    let value = match snapshot.pick_value {
+        Some(v) => format!("${:.1}", v),
+        None => "-".to_string(),
+    };
+    vec![
+        Line::from(Span::styled(
+            format!(" {}", format_pick(&snapshot.pick)),
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(vec![
+            Span::styled(" Value: ", label),
+            Span::styled(value, Style::default().fg(Color::Cyan)),
+            Span::styled("  Inflation: ", label),
+            Span::styled(
+                format!("{:.2}x", snapshot.inflation_rate),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+    ]
+}
+
+/// One row per team: budget left and roster fill.
+pub fn build_budget_lines(teams: &[TeamSnapshot]) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        " Budgets",
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(teams.iter().map(|t| {
+        Line::from(format!(
+            " {:<18} ${:>3}  {:>2}/{:<2}",
+            truncate(&t.name, 18),
+            t.budget_remaining,
+            t.slots_filled,
+            t.total_slots
+        ))
+    }));
+    lines
+}
+
+/// The best players still on the board, with their dollar values.
+pub fn build_available_lines(players: &[PlayerValuation]) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        " Top available",
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(players.iter().map(|p| {
+        Line::from(vec![
+            Span::raw(format!(
+                " {:<22} {:<8}",
+                truncate(&p.name, 22),
+                format_positions(&p.positions)
+            )),
+            Span::styled(
+                format!(" ${:>5.1}", p.dollar_value),
+                Style::default().fg(Color::Cyan),
+            ),
+        ])
+    }));
+    lines
+}
+
+/// Compact scarcity summary: each position with its count above
+/// replacement, colored by urgency.
+pub fn build_scarcity_line(entries: &[ScarcityEntry]) -> Line<'static> {
+    let mut spans = vec![Span::styled(" Scarcity:", Style::default().fg(Color::DarkGray))];
+    for entry in entries {
+        spans.push(Span::styled(
+            format!(" {} {}", entry.position.display_str(), entry.players_above_replacement),
+            Style::default().fg(urgency_color(entry.urgency)),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Truncate `s` to at most `max` characters.
+fn truncate(s: &str, max: usize) -> String {
+    s.chars().take(max).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::{DraftPick, Position};
+    use crate::test_utils::TestPlayer;
+    use crate::valuation::scarcity::ScarcityUrgency;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn snapshot(pick_number: u32) -> TimeMachineSnapshot {
+        TimeMachineSnapshot {
+            pick: DraftPick {
+                pick_number,
+                team_id: "1".to_string(),
+                team_name: "Vorticists".to_string(),
+                player_name: "Mike Trout".to_string(),
+                position: "CF".to_string(),
+                price: 45,
+                espn_player_id: None,
+                eligible_slots: vec![],
+                assigned_slot: None,
+            },
+            pick_value: Some(41.5),
+            total_recorded: 3,
+            inflation_rate: 1.08,
+            teams: vec![TeamSnapshot {
+                name: "Vorticists".to_string(),
+                budget_remaining: 215,
+                slots_filled: 1,
+                total_slots: 23,
+                max_bid: 194,
+                buys_10: 20,
+                buys_20: 9,
+                nominations: Default::default(),
+            }],
+            top_available: vec![TestPlayer::hitter("Aaron Judge").dollar(38.0).build()],
+            scarcity: vec![ScarcityEntry {
+                position: Position::Catcher,
+                players_above_replacement: 2,
+                top_available_vor: 3.0,
+                replacement_vor: 1.0,
+                dropoff: 2.0,
+                urgency: ScarcityUrgency::Critical,
+            }],
+        }
+    }
+
+    fn opened(pick_number: usize) -> TimeMachineModal {
+        let mut modal = TimeMachineModal::default();
+        modal.update(TimeMachineModalMessage::Open {
+            pick_number,
+            total_picks: 3,
+        });
+        modal
+    }
+
+    #[test]
+    fn open_requests_state_and_waits() {
+        let mut modal = TimeMachineModal::default();
+        let action = modal.update(TimeMachineModalMessage::Open {
+            pick_number: 2,
+            total_picks: 3,
+        });
+        assert_eq!(action, Some(TimeMachineModalAction::Request(2)));
+        assert!(modal.open);
+        assert!(modal.is_loading());
+        assert!(modal.snapshot().is_none());
+    }
+
+    #[test]
+    fn loaded_fills_snapshot() {
+        let mut modal = opened(2);
+        modal.update(TimeMachineModalMessage::Loaded {
+            pick_number: 2,
+            snapshot: Some(Box::new(snapshot(2))),
+        });
+        assert!(!modal.is_loading());
+        assert_eq!(modal.snapshot().unwrap().pick.pick_number, 2);
+    }
+
+    #[test]
+    fn stale_reply_is_ignored() {
+        let mut modal = opened(2);
+        modal.update(TimeMachineModalMessage::Next);
+        modal.update(TimeMachineModalMessage::Loaded {
+            pick_number: 2,
+            snapshot: Some(Box::new(snapshot(2))),
+        });
+        assert_eq!(modal.pick_number(), 3);
+        assert!(modal.is_loading());
+        assert!(modal.snapshot().is_none());
+    }
+
+    #[test]
+    fn stepping_stays_within_recorded_picks() {
+        let mut modal = opened(1);
+        assert_eq!(modal.update(TimeMachineModalMessage::Prev), None);
+        assert_eq!(modal.pick_number(), 1);
+
+        assert_eq!(
+            modal.update(TimeMachineModalMessage::Next),
+            Some(TimeMachineModalAction::Request(2))
+        );
+        modal.update(TimeMachineModalMessage::Next);
+        assert_eq!(modal.update(TimeMachineModalMessage::Next), None);
+        assert_eq!(modal.pick_number(), 3);
+    }
+
+    #[test]
+    fn unavailable_state_stops_loading() {
+        let mut modal = opened(2);
+        modal.update(TimeMachineModalMessage::Loaded {
+            pick_number: 2,
+            snapshot: None,
+        });
+        assert!(!modal.is_loading());
+        assert!(modal.snapshot().is_none());
+    }
+
+    #[test]
+    fn close_resets() {
+        let mut modal = opened(2);
+        modal.update(TimeMachineModalMessage::Close);
+        assert!(!modal.open);
+        assert_eq!(modal.pick_number(), 0);
+        assert!(!modal.is_loading());
+    }
+
+    #[test]
+    fn lines_show_pick_budgets_players_and_scarcity() {
+        let s = snapshot(2);
+
+        let header: Vec<String> = build_header_lines(&s).iter().map(line_text).collect();
+        assert!(header[0].contains("#2 Vorticists: Mike Trout (CF) -- $45"));
+        assert!(header[1].contains("$41.5"));
+        assert!(header[1].contains("1.08x"));
+
+        let budgets: Vec<String> = build_budget_lines(&s.teams).iter().map(line_text).collect();
+        assert!(budgets[1].contains("Vorticists"));
+        assert!(budgets[1].contains("$215"));
+        assert!(budgets[1].contains("1/23"));
+
+        let players: Vec<String> =
+            build_available_lines(&s.top_available).iter().map(line_text).collect();
+        assert!(players[1].contains("Aaron Judge"));
+        assert!(players[1].contains("$ 38.0"));
+
+        let scarcity = line_text(&build_scarcity_line(&s.scarcity));
+        assert!(scarcity.contains("C 2"));
+    }
+
+    #[test]
+    fn view_does_not_panic_loading_loaded_or_small() {
+        let mut modal = opened(2);
+        for (w, h) in [(120, 40), (10, 5)] {
+            let backend = ratatui::backend::TestBackend::new(w, h);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal.draw(|frame| modal.view(frame, frame.area())).unwrap();
+        }
+        modal.update(TimeMachineModalMessage::Loaded {
+            pick_number: 2,
+            snapshot: Some(Box::new(snapshot(2))),
+        });
+        for (w, h) in [(120, 40), (10, 5)] {
+            let backend = ratatui::backend::TestBackend::new(w, h);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal.draw(|frame| modal.view(frame, frame.area())).unwrap();
+        }
+    }
+}
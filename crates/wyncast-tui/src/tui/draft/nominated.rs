@@ -0,0 +1,106 @@
+// The nominated player: the one identity every draft panel highlights.
+//
+// Derived from the current nomination whenever it or the player pool
+// changes, and handed to each panel that marks the player on the block: the
+// Available table highlights their row (and scrolls to it when they are
+// nominated), the roster and scarcity panels mark their position, and the
+// scarcity panel shows their tier there. Panels match against it instead
+// of each reading the nomination their own way.
+
+use crate::draft::pick::Position;
+use crate::protocol::NominationInfo;
+use crate::valuation::tiers::tier_at;
+use crate::valuation::zscore::PlayerValuation;
+
+/// The player on the block, as the panels identify them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NominatedPlayer {
+    /// The player's name, which identifies them in every panel.
+    pub name: String,
+    /// The nominated position, if it is one the roster knows.
+    pub position: Option<Position>,
+    /// Their tier at that position among the available players, 1 for the
+    /// top tier.
+    pub tier: Option<usize>,
+}
+
+impl NominatedPlayer {
+    pub fn new(nomination: &NominationInfo, available: &[PlayerValuation]) -> Self {
+        let position = Position::parse_primary(&nomination.position);
+        Self {
+            name: nomination.player_name.clone(),
+            position,
+            tier: position.and_then(|pos| tier_at(available, pos, &nomination.player_name)),
+        }
+    }
+
+    /// Whether `name` is the nominated player.
+    pub fn is(&self, name: &str) -> bool {
+        self.name == name
+    }
+
+    /// Whether a slot or entry at `position` is marked for the nominated
+    /// player: the same position, a combo slot accepting it (OF for LF), or
+    /// for a combo nomination, a position it accepts (LF for OF).
+    pub fn marks(&self, position: Position) -> bool {
+        let Some(pos) = self.position else {
+            return false;
+        };
+        position == pos
+            || (position.is_combo_slot() && position.accepted_positions().contains(&pos))
+            || (pos.is_combo_slot() && pos.accepted_positions().contains(&position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestPlayer;
+
+    fn nomination(name: &str, position: &str) -> NominationInfo {
+        NominationInfo {
+            player_name: name.into(),
+            position: position.into(),
+            nominated_by: "Team 1".into(),
+            current_bid: 1,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        }
+    }
+
+    fn hitter(name: &str, position: Position, dollar_value: f64) -> PlayerValuation {
+        TestPlayer::hitter(name).positions(vec![position]).dollar(dollar_value).build()
+    }
+
+    #[test]
+    fn the_nominated_player_carries_their_tier() {
+        let available = vec![
+            hitter("Top SS", Position::ShortStop, 30.0),
+            hitter("Next SS", Position::ShortStop, 22.0),
+            hitter("Top C", Position::Catcher, 25.0),
+        ];
+        let nominated = NominatedPlayer::new(&nomination("Next SS", "SS"), &available);
+        assert!(nominated.is("Next SS"));
+        assert_eq!(nominated.position, Some(Position::ShortStop));
+        assert_eq!(nominated.tier, Some(2));
+
+        let unknown = NominatedPlayer::new(&nomination("Prospect", "SS"), &available);
+        assert_eq!(unknown.tier, None);
+    }
+
+    #[test]
+    fn positions_are_marked_combo_aware() {
+        let lf = NominatedPlayer::new(&nomination("A", "LF"), &[]);
+        assert!(lf.marks(Position::LeftField));
+        assert!(lf.marks(Position::Outfield));
+        assert!(!lf.marks(Position::CenterField));
+
+        let of = NominatedPlayer { position: Some(Position::Outfield), ..lf };
+        assert!(of.marks(Position::CenterField));
+        assert!(!of.marks(Position::Catcher));
+
+        let unknown = NominatedPlayer::new(&nomination("C", "??"), &[]);
+        assert!(!unknown.marks(Position::Catcher));
+    }
+}
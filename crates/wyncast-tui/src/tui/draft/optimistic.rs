@@ -0,0 +1,253 @@
+// Optimistic edits: manual commands shown before the app confirms them.
+//
+// A manual pick or a resolved ambiguous pick goes to the app loop, which
+// answers with a fresh snapshot. Rather than leave the board unchanged
+// until then, the draft screen applies the edit to its own copy at once and
+// keeps it pending. Each snapshot replaces that copy, so pending edits are
+// applied again on top of it until the snapshot shows them (the app took
+// them). An edit the app rejects is undone, and the screen says why.
+
+use crate::draft::pick::DraftPick;
+use crate::valuation::identity::{self, AmbiguousPick};
+use crate::valuation::zscore::PlayerValuation;
+
+/// An edit sent to the app and shown before it answers.
+#[derive(Debug, Clone)]
+pub enum PendingEdit {
+    /// A manual pick: the player leaves the pool and the pick joins the log.
+    Pick {
+        pick: DraftPick,
+        /// The pool entry taken out, put back if the app rejects the pick.
+        removed: Option<PlayerValuation>,
+    },
+    /// An ambiguous pick resolved: the player with the name and position on
+    /// the pick and this MLB team leaves the pool.
+    Resolve {
+        pick_number: u32,
+        player_name: String,
+        position: String,
+        team: String,
+        removed: Option<PlayerValuation>,
+    },
+}
+
+/// Which kind of edit an answer from the app is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditKind {
+    Pick,
+    Resolve,
+}
+
+impl PendingEdit {
+    fn kind(&self) -> EditKind {
+        match self {
+            PendingEdit::Pick { .. } => EditKind::Pick,
+            PendingEdit::Resolve { .. } => EditKind::Resolve,
+        }
+    }
+
+    /// Apply the edit to the screen's pool and log. The player leaves the
+    /// pool as the app would take them: by name and position, and a pick
+    /// more than one player fits leaves them all until it is resolved.
+    fn apply(&mut self, available: &mut Vec<PlayerValuation>, log: &mut Vec<DraftPick>) {
+        match self {
+            PendingEdit::Pick { pick, removed } => {
+                let found = identity::find_player(available, &pick.player_name, &pick.position, None).unique();
+                *removed = found.map(|i| available.remove(i));
+                pick.pick_number = log.len() as u32 + 1;
+                log.push(pick.clone());
+            }
+            PendingEdit::Resolve { player_name, position, team, removed, .. } => {
+                let found = identity::find_player(available, player_name, position, Some(team))
+                    .unique()
+                    .filter(|&i| available[i].team.eq_ignore_ascii_case(team));
+                *removed = found.map(|i| available.remove(i));
+            }
+        }
+    }
+
+    /// Take the edit back out of the screen's pool and log.
+    fn undo(self, available: &mut Vec<PlayerValuation>, log: &mut Vec<DraftPick>) {
+        let removed = match self {
+            PendingEdit::Pick { pick, removed } => {
+                log.retain(|p| p.pick_number != pick.pick_number || p.player_name != pick.player_name);
+                removed
+            }
+            PendingEdit::Resolve { removed, .. } => removed,
+        };
+        if let Some(player) = removed {
+            // Back in value order, as the pool is kept.
+            let at = available
+                .iter()
+                .position(|p| p.dollar_value < player.dollar_value)
+                .unwrap_or(available.len());
+            available.insert(at, player);
+        }
+    }
+
+    /// Whether freshly arrived app state already includes the edit.
+    fn taken(&self, log: &[DraftPick], ambiguous: &[AmbiguousPick]) -> bool {
+        match self {
+            PendingEdit::Pick { pick, .. } => log.iter().any(|p| {
+                p.player_name == pick.player_name && p.team_name == pick.team_name && p.price == pick.price
+            }),
+            PendingEdit::Resolve { pick_number, .. } => !ambiguous.iter().any(|a| a.pick_number == *pick_number),
+        }
+    }
+}
+
+/// Edits shown but not yet confirmed by the app, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct PendingEdits {
+    edits: Vec<PendingEdit>,
+}
+
+impl PendingEdits {
+    /// Whether any edit is waiting on the app.
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Show `edit` now and keep it until the app answers.
+    pub fn push(&mut self, mut edit: PendingEdit, available: &mut Vec<PlayerValuation>, log: &mut Vec<DraftPick>) {
+        edit.apply(available, log);
+        self.edits.push(edit);
+    }
+
+    /// After a snapshot replaced the pool and log: drop the edits it
+    /// includes and apply the rest again.
+    pub fn reapply(&mut self, available: &mut Vec<PlayerValuation>, log: &mut Vec<DraftPick>, ambiguous: &[AmbiguousPick]) {
+        self.edits.retain(|edit| !edit.taken(log, ambiguous));
+        for edit in &mut self.edits {
+            edit.apply(available, log);
+        }
+    }
+
+    /// The app rejected the oldest pending edit of `kind` (the app answers
+    /// in order, and the edits it took are gone with its snapshot): undo
+    /// it. Returns whether there was one.
+    pub fn reject(&mut self, kind: EditKind, available: &mut Vec<PlayerValuation>, log: &mut Vec<DraftPick>) -> bool {
+        let Some(i) = self.edits.iter().position(|e| e.kind() == kind) else {
+            return false;
+        };
+        self.edits.remove(i).undo(available, log);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::Position;
+    use crate::test_utils::TestPlayer;
+
+    fn pool() -> Vec<PlayerValuation> {
+        vec![
+            TestPlayer::hitter("Alpha").dollar(30.0).build(),
+            TestPlayer::hitter("Bravo").dollar(20.0).build(),
+            TestPlayer::hitter("Charlie").dollar(10.0).build(),
+        ]
+    }
+
+    fn manual_pick(player: &str) -> PendingEdit {
+        PendingEdit::Pick {
+            pick: DraftPick {
+                pick_number: 0,
+                team_id: "3".into(),
+                team_name: "Team 3".into(),
+                player_name: player.into(),
+                position: "UTIL".into(),
+                price: 12,
+                espn_player_id: None,
+                eligible_slots: vec![],
+                assigned_slot: None,
+            },
+            removed: None,
+        }
+    }
+
+    fn names(available: &[PlayerValuation]) -> Vec<&str> {
+        available.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    #[test]
+    fn a_pick_shows_at_once_and_survives_stale_snapshots() {
+        let mut pending = PendingEdits::default();
+        let (mut available, mut log) = (pool(), Vec::new());
+        pending.push(manual_pick("Bravo"), &mut available, &mut log);
+        assert_eq!(names(&available), ["Alpha", "Charlie"]);
+        assert_eq!(log[0].pick_number, 1);
+
+        // A snapshot from before the app took the pick: shown again.
+        let (mut available, mut log) = (pool(), Vec::new());
+        pending.reapply(&mut available, &mut log, &[]);
+        assert_eq!(names(&available), ["Alpha", "Charlie"]);
+        assert_eq!(log.len(), 1);
+
+        // The app's snapshot has it: no longer pending.
+        let mut taken = log.clone();
+        let mut available = vec![TestPlayer::hitter("Alpha").build()];
+        pending.reapply(&mut available, &mut taken, &[]);
+        assert!(pending.is_empty());
+        assert_eq!((available.len(), taken.len()), (1, 1));
+    }
+
+    #[test]
+    fn namesakes_leave_only_once_told_apart() {
+        let mut pending = PendingEdits::default();
+        let mut catcher = TestPlayer::hitter("Bravo").positions(vec![Position::Catcher]).dollar(15.0).build();
+        catcher.team = "KC".into();
+        let (mut available, mut log) = (pool(), Vec::new());
+        available.push(catcher);
+
+        // UTIL fits either Bravo, so both stay, as in the app.
+        pending.push(manual_pick("Bravo"), &mut available, &mut log);
+        assert_eq!(available.len(), 4);
+
+        let mut at_catcher = manual_pick("Bravo");
+        if let PendingEdit::Pick { pick, .. } = &mut at_catcher {
+            pick.position = "C".into();
+        }
+        pending.push(at_catcher, &mut available, &mut log);
+        assert_eq!(names(&available), ["Alpha", "Bravo", "Charlie"]);
+        assert_eq!(available[1].team, "TST");
+
+        let resolve = PendingEdit::Resolve {
+            pick_number: 1,
+            player_name: "Bravo".into(),
+            position: "UTIL".into(),
+            team: "tst".into(),
+            removed: None,
+        };
+        pending.push(resolve, &mut available, &mut log);
+        assert_eq!(names(&available), ["Alpha", "Charlie"]);
+    }
+
+    #[test]
+    fn a_rejected_edit_is_undone() {
+        let mut pending = PendingEdits::default();
+        let (mut available, mut log) = (pool(), Vec::new());
+        pending.push(manual_pick("Bravo"), &mut available, &mut log);
+        pending.push(
+            PendingEdit::Resolve {
+                pick_number: 4,
+                player_name: "Alpha".into(),
+                position: "1B".into(),
+                team: "tst".into(),
+                removed: None,
+            },
+            &mut available,
+            &mut log,
+        );
+        assert_eq!(names(&available), ["Charlie"]);
+
+        assert!(pending.reject(EditKind::Pick, &mut available, &mut log));
+        assert_eq!(names(&available), ["Bravo", "Charlie"]);
+        assert!(log.is_empty());
+        assert!(!pending.reject(EditKind::Pick, &mut available, &mut log));
+
+        pending.reject(EditKind::Resolve, &mut available, &mut log);
+        assert_eq!(names(&available), ["Alpha", "Bravo", "Charlie"]);
+        assert!(pending.is_empty());
+    }
+}
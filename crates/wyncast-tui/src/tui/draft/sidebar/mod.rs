@@ -5,9 +5,9 @@ pub mod scarcity;
 use ratatui::layout::Rect;
 use ratatui::Frame;
 
-use crate::draft::pick::Position;
 use crate::draft::roster::RosterSlot;
 use crate::tui::action::Action;
+use crate::tui::draft::nominated::NominatedPlayer;
 use crate::tui::subscription::Subscription;
 use crate::tui::subscription::keybinding::KeybindManager;
 use crate::valuation::scarcity::ScarcityEntry;
@@ -78,13 +78,13 @@ impl Sidebar {
         plan_area: Rect,
         my_roster: &[RosterSlot],
         positional_scarcity: &[ScarcityEntry],
-        nominated_position: Option<&Position>,
+        nominated: Option<&NominatedPlayer>,
         roster_focused: bool,
         scarcity_focused: bool,
         plan_focused: bool,
     ) {
-        self.roster.view(frame, roster_area, my_roster, nominated_position, roster_focused);
-        self.scarcity.view(frame, scarcity_area, positional_scarcity, nominated_position, scarcity_focused);
+        self.roster.view(frame, roster_area, my_roster, nominated, roster_focused);
+        self.scarcity.view(frame, scarcity_area, positional_scarcity, nominated, scarcity_focused);
         self.plan.view(frame, plan_area, plan_focused);
     }
 }
@@ -1,12 +1,16 @@
 // PlanPanel component: wraps LlmStreamState with nomination plan chrome.
 //
 // Renders Claude's nomination plan with:
-// - Title with status indicator (Idle/Streaming/Complete/Error with colors)
+// - Title with status indicator (Idle/Streaming/Complete/Error with colors),
+//   or a paused marker while the extension is disconnected
 // - Auto-scroll to bottom while streaming
 // - User-controlled scroll when not streaming
 // - Word wrap, scrollbar when content overflows
 // - Status-dependent border color (yellow=streaming, red=error, cyan=focused)
-// - Placeholder text when empty
+// - Placeholder text when empty: the non-LLM nomination advice if there is
+//   any, with the draft stage shown in the title either way
+// - My nomination script, when configured, above the plan: each scripted
+//   player with their live status, the next one marked
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Margin, Rect};
@@ -17,6 +21,8 @@ use ratatui::widgets::{
 };
 use ratatui::Frame;
 
+use crate::draft::nomination_advice::NominationAdvice;
+use crate::draft::nomination_queue::{ScriptStatus, ScriptedNomination};
 use crate::protocol::LlmStatus;
 use crate::tui::action::Action;
 use crate::tui::llm_stream::{LlmStreamMessage, LlmStreamState};
@@ -28,11 +34,20 @@ use crate::tui::widgets::focused_border_style;
 pub enum PlanPanelMessage {
     Stream(LlmStreamMessage),
     Scroll(ScrollDirection),
+    /// Automatic planning was paused or resumed with the connection.
+    Paused(bool),
 }
 
 /// PlanPanel component: LLM nomination plan rendering with status chrome.
 pub struct PlanPanel {
     stream: LlmStreamState,
+    /// Automatic planning is paused until the extension resyncs; the last
+    /// plan stays on screen but may be stale.
+    paused: bool,
+    /// Stage-aware suggestions from the app, shown until the LLM has a plan.
+    advice: Option<NominationAdvice>,
+    /// My nomination script with live statuses, shown above the plan.
+    script: Vec<ScriptedNomination>,
 }
 
 /// Page size for PageUp/PageDown scrolling (matches TUI input convention).
@@ -42,6 +57,9 @@ impl PlanPanel {
     pub fn new() -> Self {
         Self {
             stream: LlmStreamState::new(),
+            paused: false,
+            advice: None,
+            script: Vec::new(),
         }
     }
 
@@ -52,6 +70,10 @@ impl PlanPanel {
                 self.stream.scroll(dir, PAGE_SIZE);
                 None
             }
+            PlanPanelMessage::Paused(paused) => {
+                self.paused = paused;
+                None
+            }
         }
     }
 
@@ -82,6 +104,21 @@ impl PlanPanel {
         self.stream.status
     }
 
+    /// Whether automatic planning is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Replace the non-LLM nomination advice.
+    pub fn set_advice(&mut self, advice: Option<NominationAdvice>) {
+        self.advice = advice;
+    }
+
+    /// Replace the nomination script.
+    pub fn set_script(&mut self, script: Vec<ScriptedNomination>) {
+        self.script = script;
+    }
+
     /// Raw scroll offset (for testing/inspection).
     pub fn scroll_offset(&self) -> usize {
         self.stream.scroll_offset()
@@ -89,13 +126,21 @@ impl PlanPanel {
 
     /// Render the plan panel into the given area.
     pub fn view(&self, frame: &mut Frame, area: Rect, focused: bool) {
-        let title_line = build_title(self.stream.status);
+        let title_line = build_title(self.stream.status, self.paused, self.advice.as_ref());
 
         let content = if self.stream.text.is_empty() {
-            placeholder_text(self.stream.status)
+            match &self.advice {
+                Some(advice) if self.stream.status == LlmStatus::Idle => advice_text(advice),
+                _ => placeholder_text(self.stream.status),
+            }
         } else {
             self.stream.text.clone()
         };
+        let content = if self.script.is_empty() {
+            content
+        } else {
+            format!("{}\n\n{}", script_text(&self.script), content)
+        };
 
         // Compute scroll: auto-scroll to bottom while streaming
         let inner_height = area.height.saturating_sub(2) as usize; // subtract border
@@ -141,19 +186,76 @@ impl Default for PlanPanel {
     }
 }
 
-/// Build the title line with status indicator.
-fn build_title(status: LlmStatus) -> Line<'static> {
-    let (status_text, status_color) = status_indicator(status);
-    Line::from(vec![
-        Span::styled(
-            "Nomination Plan",
-            Style::default().add_modifier(Modifier::BOLD),
-        ),
-        Span::styled(" -- ", Style::default().fg(Color::DarkGray)),
-        Span::styled(status_text, Style::default().fg(status_color)),
-    ])
+/// Build the title line with the draft stage and status indicator, or the
+/// paused marker.
+fn build_title(status: LlmStatus, paused: bool, advice: Option<&NominationAdvice>) -> Line<'static> {
+    let (status_text, status_color) = if paused {
+        PAUSED_INDICATOR
+    } else {
+        status_indicator(status)
+    };
+    let mut spans = vec![Span::styled(
+        "Nomination Plan",
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+    if let Some(advice) = advice {
+        spans.push(Span::styled(
+            format!(" [{}]", advice.stage.label()),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    spans.push(Span::styled(" -- ", Style::default().fg(Color::DarkGray)));
+    spans.push(Span::styled(status_text, Style::default().fg(status_color)));
+    Line::from(spans)
 }
 
+/// The non-LLM advice as panel text: the stage's goal, then one line per
+/// suggested player.
+fn advice_text(advice: &NominationAdvice) -> String {
+    let stage = advice.stage;
+    let mut text = format!("{} draft: {}", capitalize(stage.label()), stage.goal());
+    if advice.suggestions.is_empty() {
+        text.push_str("
+Nobody fits right now.");
+    }
+    for suggestion in &advice.suggestions {
+        text.push_str(&format!("
+- {}: {}", suggestion.player_name, suggestion.reason));
+    }
+    text
+}
+
+/// The nomination script as panel text: a count of the players left, then
+/// one line per player, the next one marked `>` and the ones done or
+/// dropped marked `x`.
+fn script_text(script: &[ScriptedNomination]) -> String {
+    let left = script.iter().filter(|s| s.status.is_pending()).count();
+    let mut text = format!("Script: {} of {} left", left, script.len());
+    for scripted in script {
+        let marker = match scripted.status {
+            ScriptStatus::Next => '>',
+            ScriptStatus::Waiting => ' ',
+            _ => 'x',
+        };
+        let label = scripted.status.label();
+        text.push_str(&format!("\n{marker} {}", scripted.player));
+        if !label.is_empty() {
+            text.push_str(&format!(" ({label})"));
+        }
+    }
+    text
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+/// Status shown while automatic planning is paused for a disconnect.
+pub const PAUSED_INDICATOR: (&str, Color) = ("paused: disconnected", Color::Magenta);
+
 /// Return status text and color for the plan status.
 pub fn status_indicator(status: LlmStatus) -> (&'static str, Color) {
     match status {
@@ -391,6 +493,83 @@ mod tests {
         assert_eq!(status_indicator(LlmStatus::Error).1, Color::Red);
     }
 
+    #[test]
+    fn paused_replaces_the_status_and_keeps_the_plan() {
+        let mut panel = PlanPanel::new();
+        panel.update(PlanPanelMessage::Stream(LlmStreamMessage::Complete("Nominate X.".into())));
+        assert!(panel.update(PlanPanelMessage::Paused(true)).is_none());
+        assert!(panel.is_paused());
+        assert_eq!(panel.text(), "Nominate X.");
+        assert_eq!(
+            build_title(panel.status(), true, None).to_string(),
+            "Nomination Plan -- paused: disconnected"
+        );
+
+        panel.update(PlanPanelMessage::Paused(false));
+        assert_eq!(build_title(panel.status(), false, None).to_string(), "Nomination Plan -- ready");
+    }
+
+    #[test]
+    fn advice_fills_the_panel_until_the_llm_plans() {
+        use crate::draft::nomination_advice::{DraftStage, NominationSuggestion};
+
+        let advice = NominationAdvice {
+            stage: DraftStage::Mid,
+            suggestions: vec![NominationSuggestion {
+                player_name: "Ace".into(),
+                dollar_value: 25.0,
+                reason: "SP run, 3 of the last 6 picks: make them pay".into(),
+            }],
+        };
+        assert_eq!(
+            advice_text(&advice),
+            "Mid draft: price-enforce runs\n- Ace: SP run, 3 of the last 6 picks: make them pay"
+        );
+        assert_eq!(
+            build_title(LlmStatus::Idle, false, Some(&advice)).to_string(),
+            "Nomination Plan [mid] -- not yet computed"
+        );
+        let none = NominationAdvice { stage: DraftStage::Late, suggestions: vec![] };
+        assert_eq!(advice_text(&none), "Late draft: grab $1 targets\nNobody fits right now.");
+
+        let mut panel = PlanPanel::new();
+        panel.set_advice(Some(advice));
+        let backend = ratatui::backend::TestBackend::new(60, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), false))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..60).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert!(row.contains("Mid draft: price-enforce runs"), "{row}");
+    }
+
+    #[test]
+    fn the_script_sits_above_the_plan() {
+        let scripted = |player: &str, status| ScriptedNomination { player: player.into(), status };
+        let script = vec![
+            scripted("Juan Soto", ScriptStatus::NominatedBy("Team 2".into())),
+            scripted("Bobby Witt Jr.", ScriptStatus::Next),
+            scripted("Luis Arraez", ScriptStatus::Waiting),
+        ];
+        assert_eq!(
+            script_text(&script),
+            "Script: 2 of 3 left\nx Juan Soto (nominated by Team 2)\n> Bobby Witt Jr. (next)\n  Luis Arraez"
+        );
+
+        let mut panel = PlanPanel::new();
+        panel.set_script(script);
+        let backend = ratatui::backend::TestBackend::new(60, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| panel.view(frame, frame.area(), false))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y| (0..60).map(|x| buffer[(x, y)].symbol()).collect::<String>();
+        assert!(row(1).contains("Script: 2 of 3 left"), "{}", row(1));
+        assert!(row(6).contains("No nomination plan yet."), "{}", row(6));
+    }
+
     // -- Placeholder text --
 
     #[test]
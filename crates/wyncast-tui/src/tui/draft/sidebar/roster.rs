@@ -3,7 +3,8 @@
 // Position slots with filled/empty status.
 // "C: [empty]" or "1B: Pete Alonso ($28)"
 // Highlight positions matching nominated player.
-// Scrollable via Tab-focus and arrow keys.
+// Scrollable via Tab-focus and arrow keys; the player in the top row can
+// be moved to another open slot they can play.
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Margin, Rect};
@@ -14,9 +15,9 @@ use ratatui::widgets::{
 };
 use ratatui::Frame;
 
-use crate::draft::pick::Position;
 use crate::draft::roster::RosterSlot;
 use crate::tui::action::Action;
+use crate::tui::draft::nominated::NominatedPlayer;
 use crate::tui::scroll::{ScrollDirection, ScrollState};
 use crate::tui::widgets::focused_border_style;
 
@@ -70,15 +71,22 @@ impl RosterPanel {
         }
     }
 
+    /// Index of the slot in the first row at the current scroll position.
+    /// Used as the target of a slot move.
+    pub fn top_slot(&self, roster: &[RosterSlot]) -> Option<usize> {
+        let last = roster.len().checked_sub(1)?;
+        Some(self.scroll.offset().min(last))
+    }
+
     /// Render the roster panel.
     ///
-    /// `nominated_position`: highlight slots matching this position (from current nomination).
+    /// `nominated`: highlight the slots the nominated player's position fills.
     pub fn view(
         &self,
         frame: &mut Frame,
         area: Rect,
         roster: &[RosterSlot],
-        nominated_position: Option<&Position>,
+        nominated: Option<&NominatedPlayer>,
         focused: bool,
     ) {
         let border = focused_border_style(focused, Style::default());
@@ -107,28 +115,7 @@ impl RosterPanel {
             .skip(scroll_offset)
             .take(visible_rows.max(1))
             .map(|slot| {
-                let is_highlight =
-                    nominated_position.is_some_and(|pos| {
-                        // Exact match
-                        if slot.position == *pos {
-                            return true;
-                        }
-                        // Combo-aware: if nominated position is concrete (e.g. LF),
-                        // also highlight combo slots that accept it (e.g. OF).
-                        if slot.position.is_combo_slot()
-                            && slot.position.accepted_positions().contains(pos)
-                        {
-                            return true;
-                        }
-                        // Reverse: if nominated position is combo (e.g. OF),
-                        // also highlight concrete slots it accepts (e.g. LF/CF/RF).
-                        if pos.is_combo_slot()
-                            && pos.accepted_positions().contains(&slot.position)
-                        {
-                            return true;
-                        }
-                        false
-                    });
+                let is_highlight = nominated.is_some_and(|n| n.marks(slot.position));
                 format_slot(slot, is_highlight)
             })
             .collect();
@@ -220,6 +207,7 @@ pub fn format_slot_text(slot: &RosterSlot) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::draft::pick::Position;
     use crate::draft::roster::RosteredPlayer;
     use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
 
@@ -466,9 +454,9 @@ mod tests {
                 player: None,
             },
         ];
-        let pos = Position::Catcher;
+        let nominated = NominatedPlayer { name: "A".into(), position: Some(Position::Catcher), tier: None };
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &roster, Some(&pos), false))
+            .draw(|frame| panel.view(frame, frame.area(), &roster, Some(&nominated), false))
             .unwrap();
     }
 }
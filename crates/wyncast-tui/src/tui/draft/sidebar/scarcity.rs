@@ -2,7 +2,7 @@
 //
 // One row per position with visual gauge/bar.
 // Color: Red=Critical, Yellow=High, Blue=Medium, Green=Low
-// Mark nominated player's position.
+// Mark nominated player's position, with their tier there.
 // Scrollable via Tab-focus and arrow keys.
 
 use crossterm::event::{KeyCode, KeyEvent};
@@ -14,8 +14,8 @@ use ratatui::widgets::{
 };
 use ratatui::Frame;
 
-use crate::draft::pick::Position;
 use crate::tui::action::Action;
+use crate::tui::draft::nominated::NominatedPlayer;
 use crate::tui::scroll::{ScrollDirection, ScrollState};
 use crate::tui::widgets::focused_border_style;
 use crate::valuation::scarcity::{ScarcityEntry, ScarcityUrgency};
@@ -69,13 +69,14 @@ impl ScarcityPanel {
 
     /// Render the scarcity panel.
     ///
-    /// `nominated_position`: highlight entries matching this position (from current nomination).
+    /// `nominated`: mark the entries for the nominated player's position,
+    /// with their tier.
     pub fn view(
         &self,
         frame: &mut Frame,
         area: Rect,
         scarcity: &[ScarcityEntry],
-        nominated_position: Option<&Position>,
+        nominated: Option<&NominatedPlayer>,
         focused: bool,
     ) {
         let border = focused_border_style(focused, Style::default());
@@ -104,26 +105,8 @@ impl ScarcityPanel {
             .skip(scroll_offset)
             .take(visible_rows.max(1))
             .map(|entry| {
-                let is_nominated =
-                    nominated_position.is_some_and(|pos| {
-                        if entry.position == *pos {
-                            return true;
-                        }
-                        // Combo-aware: concrete nominated pos highlights combo entries
-                        if entry.position.is_combo_slot()
-                            && entry.position.accepted_positions().contains(pos)
-                        {
-                            return true;
-                        }
-                        // Reverse: combo nominated pos highlights concrete entries
-                        if pos.is_combo_slot()
-                            && pos.accepted_positions().contains(&entry.position)
-                        {
-                            return true;
-                        }
-                        false
-                    });
-                format_scarcity_entry(entry, is_nominated)
+                let marked = nominated.filter(|n| n.marks(entry.position));
+                format_scarcity_entry(entry, marked)
             })
             .collect();
 
@@ -165,8 +148,10 @@ impl Default for ScarcityPanel {
     }
 }
 
-/// Format a scarcity entry as a ListItem with a visual gauge.
-fn format_scarcity_entry<'a>(entry: &ScarcityEntry, is_nominated: bool) -> ListItem<'a> {
+/// Format a scarcity entry as a ListItem with a visual gauge. `nominated`
+/// is the nominated player when the entry is marked for them.
+fn format_scarcity_entry<'a>(entry: &ScarcityEntry, nominated: Option<&NominatedPlayer>) -> ListItem<'a> {
+    let is_nominated = nominated.is_some();
     let pos_label = entry.position.display_str();
     let urgency_label = format_urgency(entry.urgency);
     let color = urgency_color(entry.urgency);
@@ -174,7 +159,7 @@ fn format_scarcity_entry<'a>(entry: &ScarcityEntry, is_nominated: bool) -> ListI
 
     let marker = if is_nominated { ">" } else { " " };
 
-    let spans = vec![
+    let mut spans = vec![
         Span::styled(
             format!("{}{:>3} ", marker, pos_label),
             if is_nominated {
@@ -196,6 +181,12 @@ fn format_scarcity_entry<'a>(entry: &ScarcityEntry, is_nominated: bool) -> ListI
             Style::default().fg(Color::DarkGray),
         ),
     ];
+    if let Some(tier) = nominated.and_then(|n| n.tier) {
+        spans.push(Span::styled(
+            format!(" T{tier}"),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ));
+    }
 
     ListItem::new(Line::from(spans))
 }
@@ -235,6 +226,7 @@ pub fn format_urgency(urgency: ScarcityUrgency) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::draft::pick::Position;
     use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
 
     fn key(code: KeyCode) -> KeyEvent {
@@ -476,9 +468,12 @@ mod tests {
                 urgency: ScarcityUrgency::Critical,
             },
         ];
-        let pos = Position::Catcher;
+        let nominated = NominatedPlayer { name: "A".into(), position: Some(Position::Catcher), tier: Some(2) };
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &data, Some(&pos), false))
+            .draw(|frame| panel.view(frame, frame.area(), &data, Some(&nominated), false))
             .unwrap();
+        let row: String = (0..40).map(|x| terminal.backend().buffer()[(x, 1)].symbol()).collect();
+        assert!(row.contains(">  C [##"), "{row}");
+        assert!(row.contains("(2) T2"), "{row}");
     }
 }
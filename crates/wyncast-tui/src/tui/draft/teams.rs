@@ -1,15 +1,20 @@
+use std::time::Duration;
+
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Constraint, Margin, Rect};
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{
     Block, Borders, Cell, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
 };
 use ratatui::Frame;
 
+use crate::protocol::ExtensionHealth;
 use crate::tui::action::Action;
 use crate::tui::scroll::{ScrollDirection, ScrollState};
-use crate::tui::widgets::focused_border_style;
-use crate::tui::TeamSummary;
+use crate::tui::widgets::money::MoneyFormat;
+use crate::tui::widgets::{focused_border_style, table_summary};
+use crate::tui::{BudgetStatus, TeamSummary};
 
 /// Messages handled by the TeamsPanel.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,15 +27,22 @@ const PAGE_SIZE: usize = 20;
 /// Stateful teams overview panel component.
 pub struct TeamsPanel {
     scroll: ScrollState,
+    /// How budgets are shown.
+    money: MoneyFormat,
 }
 
 impl TeamsPanel {
     pub fn new() -> Self {
         Self {
             scroll: ScrollState::new(),
+            money: BudgetStatus::default().money(),
         }
     }
 
+    pub fn set_money(&mut self, money: MoneyFormat) {
+        self.money = money;
+    }
+
     pub fn update(&mut self, msg: TeamsMessage) -> Option<Action> {
         match msg {
             TeamsMessage::Scroll(dir) => {
@@ -57,15 +69,30 @@ impl TeamsPanel {
         }
     }
 
-    pub fn view(&self, frame: &mut Frame, area: Rect, teams: &[TeamSummary], focused: bool) {
-        // Visible row count: subtract 2 (borders) + 1 (header)
-        let visible_rows = (area.height as usize).saturating_sub(3);
+    /// Render the teams table. `extension_health` is the extension's latest
+    /// scraping health and how long ago it arrived, shown on the bottom
+    /// border.
+    pub fn view(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        teams: &[TeamSummary],
+        extension_health: Option<(&ExtensionHealth, Duration)>,
+        focused: bool,
+    ) {
+        // Visible row count: subtract 2 (borders) + 1 (summary) + 1 (header)
+        let visible_rows = (area.height as usize).saturating_sub(4);
 
         let header = Row::new(vec![
             Cell::from("Team"),
             Cell::from("Budget"),
             Cell::from("Filled"),
             Cell::from("Remaining"),
+            Cell::from("Max Bid"),
+            Cell::from("$10+"),
+            Cell::from("$20+"),
+            Cell::from("Noms"),
+            Cell::from("Nominates"),
         ])
         .style(
             Style::default()
@@ -85,34 +112,35 @@ impl TeamsPanel {
                 .iter()
                 .skip(scroll_offset)
                 .take(visible_rows.max(1))
-                .map(|team| {
-                    let remaining_slots = team.total_slots.saturating_sub(team.slots_filled);
-                    Row::new(vec![
-                        Cell::from(team.name.clone()),
-                        Cell::from(format_budget(team.budget_remaining)),
-                        Cell::from(format!("{}/{}", team.slots_filled, team.total_slots)),
-                        Cell::from(format!("{}", remaining_slots)),
-                    ])
-                })
+                .map(|team| Row::new(row_cells(team, self.money).map(Cell::from)))
                 .collect()
         };
 
+        let money_width = self.money.width().max(8);
         let widths = [
             Constraint::Min(16),
-            Constraint::Length(8),
+            Constraint::Length(money_width),
             Constraint::Length(8),
             Constraint::Length(10),
+            Constraint::Length(money_width),
+            Constraint::Length(5),
+            Constraint::Length(5),
+            Constraint::Length(5),
+            Constraint::Length(9),
         ];
 
         let focus_border = focused_border_style(focused, Style::default());
 
-        let table = Table::new(rows, widths).header(header).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(focus_border)
-                .title("Teams"),
-        );
-        frame.render_widget(table, area);
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(focus_border)
+            .title("Teams");
+        if let Some((health, age)) = extension_health {
+            block = block.title_bottom(health_line(health, age));
+        }
+        let table = Table::new(rows, widths).header(header);
+        let table_area = table_summary::render(frame, area, block, summary(teams, self.money));
+        frame.render_widget(table, table_area);
 
         // Render vertical scrollbar whenever content overflows
         if total > visible_rows {
@@ -135,9 +163,77 @@ impl Default for TeamsPanel {
     }
 }
 
+/// Cells of one team's row: budget, slots, how much buying power is left
+/// -- the max bid and how many $10+ and $20+ players the team can still
+/// afford -- and its nomination habits: nominations bought back out of
+/// those sold, and what that suggests.
+fn row_cells(team: &TeamSummary, money: MoneyFormat) -> [String; 9] {
+    let remaining_slots = team.total_slots.saturating_sub(team.slots_filled);
+    let noms = &team.nominations;
+    [
+        team.name.clone(),
+        format_budget(team.budget_remaining, money),
+        format!("{}/{}", team.slots_filled, team.total_slots),
+        format!("{}", remaining_slots),
+        format_budget(team.max_bid, money),
+        team.buys_10.to_string(),
+        team.buys_20.to_string(),
+        format!("{}/{}", noms.kept, noms.nominated),
+        noms.style().label().to_string(),
+    ]
+}
+
+/// Aggregates for the pinned summary row: team count, budget left across the
+/// league and what that leaves per open roster slot. The league's total is
+/// always in dollars, being many caps.
+pub fn summary(teams: &[TeamSummary], money: MoneyFormat) -> Line<'static> {
+    let mut items = vec![format!("{} teams", teams.len())];
+    if !teams.is_empty() {
+        let left: u32 = teams.iter().map(|t| t.budget_remaining).sum();
+        let open_slots: usize = teams
+            .iter()
+            .map(|t| t.total_slots.saturating_sub(t.slots_filled))
+            .sum();
+        items.push(format!("${left} left"));
+        if open_slots > 0 {
+            let average = money.average(f64::from(left) / open_slots as f64);
+            items.push(format!("{average} avg per open slot"));
+        }
+    }
+    table_summary::line(items)
+}
+
+/// The extension's scraping health, e.g.
+/// ` ext: 24 upd/min · picks 3s ago · nom 12s ago · 2 JS errors `, with the
+/// ages counted on from when the heartbeat arrived. Yellow once the content
+/// script has hit errors.
+pub fn health_line(health: &ExtensionHealth, age: Duration) -> Line<'static> {
+    let ago = |age_ms: Option<u64>| match age_ms {
+        Some(ms) => {
+            let secs = (Duration::from_millis(ms) + age).as_secs();
+            if secs < 60 {
+                format!("{secs}s ago")
+            } else {
+                format!("{}m ago", secs / 60)
+            }
+        }
+        None => "never".to_string(),
+    };
+    let mut items = vec![
+        format!("ext: {} upd/min", health.updates_per_minute),
+        format!("picks {}", ago(health.picks_age_ms)),
+        format!("nom {}", ago(health.nomination_age_ms)),
+    ];
+    if health.js_errors > 0 {
+        items.push(format!("{} JS errors", health.js_errors));
+    }
+    let color = if health.js_errors > 0 { Color::Yellow } else { Color::DarkGray };
+    Line::from(Span::styled(format!(" {} ", items.join(" \u{b7} ")), Style::default().fg(color)))
+}
+
 /// Format a budget value for display.
-pub fn format_budget(remaining: u32) -> String {
-    format!("${}", remaining)
+pub fn format_budget(remaining: u32, money: MoneyFormat) -> String {
+    money.amount(remaining.into())
 }
 
 // ---------------------------------------------------------------------------
@@ -147,6 +243,7 @@ pub fn format_budget(remaining: u32) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::draft::nominations::NominationPattern;
     use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
 
     fn key(code: KeyCode) -> KeyEvent {
@@ -289,9 +386,73 @@ mod tests {
 
     #[test]
     fn format_budget_basic() {
-        assert_eq!(format_budget(260), "$260");
-        assert_eq!(format_budget(0), "$0");
-        assert_eq!(format_budget(135), "$135");
+        let money = MoneyFormat::new(260, false);
+        assert_eq!(format_budget(260, money), "$260");
+        assert_eq!(format_budget(0, money), "$0");
+        assert_eq!(format_budget(135, money), "$135");
+        assert_eq!(format_budget(50, MoneyFormat::new(200, true)), "25.0%");
+    }
+
+    #[test]
+    fn row_cells_show_buying_power() {
+        let team = TeamSummary {
+            name: "Team Alpha".into(),
+            budget_remaining: 200,
+            slots_filled: 5,
+            total_slots: 26,
+            max_bid: 180,
+            buys_10: 19,
+            buys_20: 9,
+            nominations: NominationPattern { nominated: 4, kept: 3, total_price: 90 },
+        };
+        assert_eq!(
+            row_cells(&team, MoneyFormat::new(260, false)),
+            ["Team Alpha", "$200", "5/26", "21", "$180", "19", "9", "3/4", "targets"]
+        );
+    }
+
+    #[test]
+    fn summary_aggregates_budget_and_open_slots() {
+        let team = |budget_remaining, slots_filled| TeamSummary {
+            name: "Team".into(),
+            budget_remaining,
+            slots_filled,
+            total_slots: 10,
+            max_bid: budget_remaining,
+            buys_10: 0,
+            buys_20: 0,
+            nominations: Default::default(),
+        };
+        let teams = [team(100, 6), team(50, 8)];
+        let line = summary(&teams, MoneyFormat::new(260, false));
+        assert_eq!(line.to_string(), " 2 teams \u{b7} $150 left \u{b7} $25.0 avg per open slot");
+        let line = summary(&teams, MoneyFormat::new(1000, false));
+        assert_eq!(line.to_string(), " 2 teams \u{b7} $150 left \u{b7} $25 avg per open slot");
+        let line = summary(&teams, MoneyFormat::new(100, true));
+        assert_eq!(line.to_string(), " 2 teams \u{b7} $150 left \u{b7} 25.00% avg per open slot");
+
+        assert_eq!(summary(&[], MoneyFormat::new(260, false)).to_string(), " 0 teams");
+    }
+
+    #[test]
+    fn health_line_ages_scrapes_from_the_heartbeat() {
+        let health = ExtensionHealth {
+            updates_per_minute: 24,
+            picks_age_ms: Some(3_000),
+            nomination_age_ms: None,
+            js_errors: 0,
+        };
+        let line = health_line(&health, Duration::from_secs(2));
+        assert_eq!(line.to_string(), " ext: 24 upd/min \u{b7} picks 5s ago \u{b7} nom never ");
+        assert_eq!(line.spans[0].style.fg, Some(Color::DarkGray));
+
+        let failing = ExtensionHealth { js_errors: 2, picks_age_ms: Some(90_000), ..health };
+        let line = health_line(&failing, Duration::ZERO);
+        assert_eq!(
+            line.to_string(),
+            " ext: 24 upd/min \u{b7} picks 1m ago \u{b7} nom never \u{b7} 2 JS errors "
+        );
+        assert_eq!(line.spans[0].style.fg, Some(Color::Yellow));
     }
 
     // -- view() rendering --
@@ -302,7 +463,7 @@ mod tests {
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
         let panel = TeamsPanel::new();
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], false))
+            .draw(|frame| panel.view(frame, frame.area(), &[], None, false))
             .unwrap();
     }
 
@@ -317,26 +478,62 @@ mod tests {
                 budget_remaining: 200,
                 slots_filled: 5,
                 total_slots: 26,
+                max_bid: 179,
+                buys_10: 0,
+                buys_20: 0,
+                nominations: Default::default(),
             },
             TeamSummary {
                 name: "Team Beta".to_string(),
                 budget_remaining: 180,
                 slots_filled: 8,
                 total_slots: 26,
+                max_bid: 162,
+                buys_10: 0,
+                buys_20: 0,
+                nominations: Default::default(),
             },
         ];
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &teams, false))
+            .draw(|frame| panel.view(frame, frame.area(), &teams, None, false))
             .unwrap();
     }
 
+    #[test]
+    fn view_reclamps_scroll_after_resize() {
+        let teams: Vec<TeamSummary> = (0..12)
+            .map(|i| TeamSummary {
+                name: format!("Team {i}"),
+                budget_remaining: 260,
+                slots_filled: 0,
+                total_slots: 26,
+                max_bid: 235,
+                buys_10: 0,
+                buys_20: 0,
+                nominations: Default::default(),
+            })
+            .collect();
+        let mut panel = TeamsPanel::new();
+        panel.update(TeamsMessage::Scroll(ScrollDirection::Bottom));
+
+        // 10 rows leave 6 for teams, so the last page starts at team 6.
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 10)).unwrap();
+        terminal.draw(|frame| panel.view(frame, frame.area(), &teams, None, false)).unwrap();
+        assert_eq!(panel.scroll.offset(), 6);
+
+        // Taller: every team fits, so the offset snaps back to the top.
+        terminal.backend_mut().resize(80, 30);
+        terminal.draw(|frame| panel.view(frame, frame.area(), &teams, None, false)).unwrap();
+        assert_eq!(panel.scroll.offset(), 0);
+    }
+
     #[test]
     fn view_does_not_panic_when_focused() {
         let backend = ratatui::backend::TestBackend::new(80, 20);
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
         let panel = TeamsPanel::new();
         terminal
-            .draw(|frame| panel.view(frame, frame.area(), &[], true))
+            .draw(|frame| panel.view(frame, frame.area(), &[], None, true))
             .unwrap();
     }
 }
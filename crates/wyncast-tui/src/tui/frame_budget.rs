@@ -0,0 +1,174 @@
+// Render-loop load monitoring.
+//
+// The TUI redraws on a 33ms tick. When drawing takes longer than that (huge
+// tables, tiny terminals over SSH) the loop falls behind without any visible
+// sign. `FrameMonitor` measures each draw, flags the loop as degraded when the
+// recent average exceeds the budget, and tells the loop how many ticks to skip
+// after a slow frame. UI updates are still drained on skipped ticks, so only
+// the drawing is deferred.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Time available for one frame at ~30 fps.
+pub const FRAME_BUDGET: Duration = Duration::from_millis(33);
+
+/// A degraded loop is considered recovered once the average drops below this.
+/// Lower than the budget so the indicator does not flap around it.
+const RECOVERY_THRESHOLD: Duration = Duration::from_millis(25);
+
+/// Number of recent frames averaged.
+const WINDOW: usize = 15;
+
+/// Most ticks skipped after a single slow frame.
+const MAX_SKIPPED_TICKS: u32 = 3;
+
+/// Table row cap while degraded; fewer rows means fewer cells to diff and
+/// write to the terminal.
+pub const DEGRADED_ROW_LIMIT: usize = 15;
+
+/// Tracks recent frame times and decides when the render loop is degraded.
+#[derive(Debug, Default)]
+pub struct FrameMonitor {
+    samples: VecDeque<Duration>,
+    degraded: bool,
+    skip_ticks: u32,
+}
+
+impl FrameMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record how long the last draw took. Returns `true` when this changed
+    /// whether the loop is degraded.
+    pub fn record(&mut self, frame_time: Duration) -> bool {
+        if self.samples.len() == WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(frame_time);
+
+        let was_degraded = self.degraded;
+        let average = self.average();
+        if average > FRAME_BUDGET {
+            self.degraded = true;
+        } else if average < RECOVERY_THRESHOLD {
+            self.degraded = false;
+        }
+
+        // Give the terminal as many ticks as the slow frame overran by.
+        self.skip_ticks = if self.degraded {
+            let overrun = frame_time.as_millis() / FRAME_BUDGET.as_millis();
+            (overrun as u32).min(MAX_SKIPPED_TICKS)
+        } else {
+            0
+        };
+
+        was_degraded != self.degraded
+    }
+
+    /// Whether the current tick should skip drawing. Consumes one pending skip.
+    pub fn should_skip(&mut self) -> bool {
+        if self.skip_ticks == 0 {
+            return false;
+        }
+        self.skip_ticks -= 1;
+        true
+    }
+
+    /// Drop any pending skips, e.g. when a resize needs an immediate redraw.
+    pub fn cancel_skips(&mut self) {
+        self.skip_ticks = 0;
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.degraded
+    }
+
+    /// Average of the recent frame times, or zero before the first frame.
+    pub fn average(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+
+    /// The average frame time while degraded, for the status bar.
+    pub fn degraded_average(&self) -> Option<Duration> {
+        self.degraded.then(|| self.average())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn fast_frames_never_degrade_or_skip() {
+        let mut monitor = FrameMonitor::new();
+        for _ in 0..50 {
+            assert!(!monitor.record(ms(5)));
+            assert!(!monitor.should_skip());
+        }
+        assert!(!monitor.is_degraded());
+        assert_eq!(monitor.degraded_average(), None);
+    }
+
+    #[test]
+    fn slow_frames_degrade_and_skip_ticks() {
+        let mut monitor = FrameMonitor::new();
+        assert!(monitor.record(ms(80)), "first slow frame flips to degraded");
+        assert!(monitor.is_degraded());
+        assert_eq!(monitor.degraded_average(), Some(ms(80)));
+
+        // 80ms overruns the 33ms budget twice: skip two ticks, then draw.
+        assert!(monitor.should_skip());
+        assert!(monitor.should_skip());
+        assert!(!monitor.should_skip());
+    }
+
+    #[test]
+    fn skipped_ticks_are_capped() {
+        let mut monitor = FrameMonitor::new();
+        monitor.record(ms(1000));
+        let skipped = std::iter::from_fn(|| monitor.should_skip().then_some(())).count();
+        assert_eq!(skipped, MAX_SKIPPED_TICKS as usize);
+    }
+
+    #[test]
+    fn cancel_skips_draws_on_the_next_tick() {
+        let mut monitor = FrameMonitor::new();
+        monitor.record(ms(1000));
+        monitor.cancel_skips();
+        assert!(!monitor.should_skip());
+        assert!(monitor.is_degraded(), "cancelling skips does not reset the average");
+    }
+
+    #[test]
+    fn recovery_needs_average_below_threshold() {
+        let mut monitor = FrameMonitor::new();
+        for _ in 0..WINDOW {
+            monitor.record(ms(40));
+        }
+        assert!(monitor.is_degraded());
+
+        // An average of ~30ms is under budget but above the recovery
+        // threshold, so the loop stays degraded.
+        for _ in 0..WINDOW {
+            monitor.record(ms(30));
+        }
+        assert!(monitor.is_degraded());
+
+        let mut changed = false;
+        for _ in 0..WINDOW {
+            changed |= monitor.record(ms(10));
+        }
+        assert!(changed);
+        assert!(!monitor.is_degraded());
+        assert!(!monitor.should_skip());
+    }
+}
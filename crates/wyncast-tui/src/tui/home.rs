@@ -13,7 +13,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
-use crate::protocol::ConnectionStatus;
+use crate::protocol::{ConnectionStatus, WsServerStatus};
 use crate::tui::app::App;
 use crate::tui::subscription::keybinding::{
     exact, KeyBindingRecipe, KeybindHint, KeybindManager,
@@ -128,11 +128,27 @@ pub fn render(frame: &mut Frame, app: &App) {
             Span::styled("● ", Style::default().fg(Color::Green)),
             Span::styled("Connected — waiting for ESPN page...", subtitle_style),
         ])
+    } else if let Some(WsServerStatus::BindFailed { requested_port, .. }) =
+        &app.draft_screen.ws_server
+    {
+        Line::from(vec![
+            Span::styled("● ", Style::default().fg(Color::Red)),
+            Span::styled(
+                format!("WebSocket server offline — port {requested_port} unavailable"),
+                Style::default().fg(Color::Red),
+            ),
+        ])
     } else {
         let dot = if app.tick_count.is_multiple_of(2) { "● " } else { "  " };
+        let waiting = match &app.draft_screen.ws_server {
+            Some(WsServerStatus::Listening { port, .. }) => {
+                format!("Waiting for connection on port {port}...")
+            }
+            _ => "Waiting for connection...".to_string(),
+        };
         Line::from(vec![
             Span::styled(dot, Style::default().fg(Color::Red)),
-            Span::styled("Waiting for connection...", subtitle_style),
+            Span::styled(waiting, subtitle_style),
         ])
     };
     lines.push(status_line);
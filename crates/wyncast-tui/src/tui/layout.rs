@@ -4,13 +4,15 @@
 //
 // +--------------------------------------------------+
 // | Status Bar (1 row)                                |
+// | Pick Ticker (1 row)                               |
 // +--------------------------------------------------+
-// | Nomination Banner (4 rows)                        |
+// | Nomination Banner (7 rows)                        |
 // +-------------------------+------------------------+
 // | Main Panel (65%)         | Sidebar (35%)          |
-// | (tabs: analysis/avail/   | +- Roster (33%) ------+|
-// |  log/teams)              | +- Scarcity (33%) ----+|
-// |                          | +- Nom Plan (34%) ----+|
+// | (tabs: analysis/avail/   | +- Roster (28%) ------+|
+// |  log/teams)              | +- Shopping (22%) ----+|
+// |                          | +- Scarcity (22%) ----+|
+// |                          | +- Nom Plan (28%) ----+|
 // +- Budget (7 rows) -------+                        |
 // +-------------------------+------------------------+
 // | Help Bar (1 row)                                  |
@@ -23,13 +25,17 @@ use ratatui::layout::{Constraint, Direction, Layout, Rect};
 pub struct AppLayout {
     /// Top row: connection status, draft progress, pick counter.
     pub status_bar: Rect,
-    /// Second row: current nomination details (player, bid, timer).
+    /// Under the status bar: the last few picks.
+    pub ticker: Rect,
+    /// Below the ticker: current nomination details (player, bid, timer).
     pub nomination_banner: Rect,
     /// Left side of the middle section: tab-switched content area.
     pub main_panel: Rect,
     /// Right sidebar top: user's roster.
     pub roster: Rect,
-    /// Right sidebar upper-middle: positional scarcity index.
+    /// Right sidebar under the roster: open slots and what to spend.
+    pub shopping_list: Rect,
+    /// Right sidebar lower-middle: positional scarcity index.
     pub scarcity: Rect,
     /// Left column bottom: budget/inflation summary.
     pub budget: Rect,
@@ -41,25 +47,27 @@ pub struct AppLayout {
 
 /// Build the dashboard layout from the available terminal area.
 ///
-/// The layout uses fixed heights for the status bar, nomination banner,
-/// and help bar, with the remaining space split between the main panel
+/// The layout uses fixed heights for the status bar, ticker, nomination
+/// banner and help bar, with the remaining space split between the main panel
 /// and a sidebar column.
 pub fn build_layout(area: Rect) -> AppLayout {
-    // Vertical: status(1) | nomination(4) | middle(fill) | help(1)
+    // Vertical: status(1) | ticker(1) | nomination(7) | middle(fill) | help(1)
     let vertical = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(1),  // status bar
-            Constraint::Length(4),  // nomination banner
+            Constraint::Length(1),  // pick ticker
+            Constraint::Length(7),  // nomination banner
             Constraint::Min(10),   // middle section (main + sidebar)
             Constraint::Length(1),  // help bar
         ])
         .split(area);
 
     let status_bar = vertical[0];
-    let nomination_banner = vertical[1];
-    let middle = vertical[2];
-    let help_bar = vertical[3];
+    let ticker = vertical[1];
+    let nomination_banner = vertical[2];
+    let middle = vertical[3];
+    let help_bar = vertical[4];
 
     // Horizontal: left column (65%) | sidebar (35%)
     let horizontal = Layout::default()
@@ -85,25 +93,30 @@ pub fn build_layout(area: Rect) -> AppLayout {
     let main_panel = left_sections[0];
     let budget = left_sections[1];
 
-    // Sidebar vertical: roster (33%) | scarcity (33%) | nomination_plan (34%)
+    // Sidebar vertical: roster (28%) | shopping_list (22%) | scarcity (22%) |
+    // nomination_plan (28%)
     let sidebar_sections = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(33),
-            Constraint::Percentage(33),
-            Constraint::Percentage(34),
+            Constraint::Percentage(28),
+            Constraint::Percentage(22),
+            Constraint::Percentage(22),
+            Constraint::Percentage(28),
         ])
         .split(sidebar);
 
     let roster = sidebar_sections[0];
-    let scarcity = sidebar_sections[1];
-    let nomination_plan = sidebar_sections[2];
+    let shopping_list = sidebar_sections[1];
+    let scarcity = sidebar_sections[2];
+    let nomination_plan = sidebar_sections[3];
 
     AppLayout {
         status_bar,
+        ticker,
         nomination_banner,
         main_panel,
         roster,
+        shopping_list,
         scarcity,
         budget,
         nomination_plan,
@@ -129,9 +142,11 @@ mod tests {
         let layout = build_layout(test_area());
         let rects = [
             ("status_bar", layout.status_bar),
+            ("ticker", layout.ticker),
             ("nomination_banner", layout.nomination_banner),
             ("main_panel", layout.main_panel),
             ("roster", layout.roster),
+            ("shopping_list", layout.shopping_list),
             ("scarcity", layout.scarcity),
             ("budget", layout.budget),
             ("nomination_plan", layout.nomination_plan),
@@ -156,6 +171,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn layout_ticker_is_one_row_under_the_status_bar() {
+        let layout = build_layout(test_area());
+        assert_eq!(layout.ticker.height, 1);
+        assert_eq!(layout.ticker.y, layout.status_bar.y + 1);
+        assert_eq!(layout.nomination_banner.y, layout.ticker.y + 1);
+    }
+
     #[test]
     fn layout_help_bar_height_is_one() {
         let layout = build_layout(test_area());
@@ -166,11 +189,11 @@ mod tests {
     }
 
     #[test]
-    fn layout_nomination_banner_height_is_four() {
+    fn layout_nomination_banner_height_is_seven() {
         let layout = build_layout(test_area());
         assert_eq!(
-            layout.nomination_banner.height, 4,
-            "Nomination banner should be exactly 4 rows"
+            layout.nomination_banner.height, 7,
+            "Nomination banner should be exactly 7 rows"
         );
     }
 
@@ -188,10 +211,14 @@ mod tests {
     #[test]
     fn layout_sidebar_sections_stack_vertically() {
         let layout = build_layout(test_area());
-        // Roster should be above scarcity
+        // Roster should be above the shopping list, and that above scarcity
+        assert!(
+            layout.roster.y < layout.shopping_list.y,
+            "Roster should be above the shopping list"
+        );
         assert!(
-            layout.roster.y < layout.scarcity.y,
-            "Roster should be above scarcity"
+            layout.shopping_list.y < layout.scarcity.y,
+            "Shopping list should be above scarcity"
         );
         // Scarcity should be above nomination_plan
         assert!(
@@ -219,9 +246,11 @@ mod tests {
         let layout = build_layout(area);
         let all_rects = [
             layout.status_bar,
+            layout.ticker,
             layout.nomination_banner,
             layout.main_panel,
             layout.roster,
+            layout.shopping_list,
             layout.scarcity,
             layout.budget,
             layout.nomination_plan,
@@ -251,9 +280,11 @@ mod tests {
         // All zones should still get some area
         let rects = [
             layout.status_bar,
+            layout.ticker,
             layout.nomination_banner,
             layout.main_panel,
             layout.roster,
+            layout.shopping_list,
             layout.scarcity,
             layout.budget,
             layout.nomination_plan,
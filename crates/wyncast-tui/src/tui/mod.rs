@@ -8,18 +8,19 @@ pub mod action;
 pub mod app;
 pub mod confirm_dialog;
 pub mod draft;
+pub mod frame_budget;
 pub mod home;
 pub mod layout;
 pub mod llm_stream;
 pub mod matchup;
 pub mod onboarding;
+pub mod recalc_progress;
 pub mod scroll;
 pub mod settings;
 pub mod subscription;
 pub mod text_input;
 pub mod widgets;
-
-use std::time::Duration;
+pub mod ws_warning;
 
 use crossterm::event::{Event, EventStream};
 use futures_util::StreamExt;
@@ -30,11 +31,14 @@ use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 use tokio::sync::mpsc;
 
+use crate::config::LeagueConfig;
+use crate::draft::nominations::NominationPattern;
 use crate::protocol::{AppMode, UiUpdate, UserCommand};
 use crate::tui::action::Action;
 use crate::tui::app::AppMessage;
 use crate::tui::subscription::{AppEvent, SubscriptionManager};
 use crate::tui::subscription::keybinding::KeybindManager;
+use crate::tui::widgets::money::MoneyFormat;
 
 pub use onboarding::llm_setup::LlmSetupState;
 pub use onboarding::strategy_setup::StrategySetupState;
@@ -52,13 +56,15 @@ pub use text_input::{TextInput, TextInputMessage};
 /// clears focus back to `None`.
 ///
 /// The cycle order follows left-to-right, then top-to-bottom within columns:
-/// `None -> MainPanel -> Budget -> Roster -> Scarcity -> NominationPlan -> None`
+/// `None -> MainPanel -> Budget -> Roster -> ShoppingList -> Scarcity -> NominationPlan -> None`
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FocusPanel {
     /// The active tab's content area (left side).
     MainPanel,
     /// Sidebar: My Roster panel.
     Roster,
+    /// Sidebar: Shopping List panel (open slots and what to spend).
+    ShoppingList,
     /// Sidebar: Positional Scarcity panel.
     Scarcity,
     /// Left column bottom: Budget panel.
@@ -73,12 +79,13 @@ impl FocusPanel {
         FocusPanel::MainPanel,
         FocusPanel::Budget,
         FocusPanel::Roster,
+        FocusPanel::ShoppingList,
         FocusPanel::Scarcity,
         FocusPanel::NominationPlan,
     ];
 
     /// Advance focus forward:
-    /// None -> MainPanel -> Budget -> Roster -> Scarcity -> NominationPlan -> None
+    /// None -> MainPanel -> Budget -> Roster -> ShoppingList -> Scarcity -> NominationPlan -> None
     pub fn next(current: Option<FocusPanel>) -> Option<FocusPanel> {
         match current {
             None => Some(Self::CYCLE[0]),
@@ -93,7 +100,7 @@ impl FocusPanel {
     }
 
     /// Advance focus backward:
-    /// None -> NominationPlan -> Scarcity -> Roster -> Budget -> MainPanel -> None
+    /// None -> NominationPlan -> Scarcity -> ShoppingList -> Roster -> Budget -> MainPanel -> None
     pub fn prev(current: Option<FocusPanel>) -> Option<FocusPanel> {
         match current {
             None => Some(*Self::CYCLE.last().unwrap()),
@@ -125,8 +132,10 @@ pub struct BudgetStatus {
     pub remaining: u32,
     /// Per-team salary cap.
     pub cap: u32,
-    /// Current league-wide inflation rate.
+    /// Current league-wide inflation rate, smoothed and damped.
     pub inflation_rate: f64,
+    /// The inflation rate before smoothing and damping.
+    pub raw_inflation_rate: f64,
     /// Maximum bid the user can make right now.
     pub max_bid: u32,
     /// Average dollars remaining per empty roster slot.
@@ -139,21 +148,41 @@ pub struct BudgetStatus {
     pub pitching_spent: u32,
     /// Pitching budget target (salary_cap * (1 - hitting_budget_fraction)).
     pub pitching_target: u32,
+    /// Hitters' share of the remaining pool's dollars (the effective split).
+    /// 0.0 until the first snapshot arrives.
+    pub hitting_split: f64,
+    /// Whether `hitting_split` was learned from the room's spending.
+    pub split_learned: bool,
+    /// Show amounts as a percentage of `cap` (the `[display]` setting).
+    pub percent_of_cap: bool,
+}
+
+impl BudgetStatus {
+    /// How to format money for this league's cap.
+    pub fn money(&self) -> MoneyFormat {
+        MoneyFormat::new(self.cap, self.percent_of_cap)
+    }
 }
 
 impl Default for BudgetStatus {
     fn default() -> Self {
+        // Until the first snapshot brings the league's cap.
+        let cap = LeagueConfig::default().salary_cap;
         BudgetStatus {
             spent: 0,
-            remaining: 260,
-            cap: 260,
+            remaining: cap,
+            cap,
             inflation_rate: 1.0,
+            raw_inflation_rate: 1.0,
             max_bid: 0,
             avg_per_slot: 0.0,
             hitting_spent: 0,
             hitting_target: 0,
             pitching_spent: 0,
             pitching_target: 0,
+            hitting_split: 0.0,
+            split_learned: false,
+            percent_of_cap: false,
         }
     }
 }
@@ -173,6 +202,14 @@ pub struct TeamSummary {
     pub slots_filled: usize,
     /// Total draftable roster slots.
     pub total_slots: usize,
+    /// Most the team can bid right now.
+    pub max_bid: u32,
+    /// How many $10+ players the team can still buy.
+    pub buys_10: usize,
+    /// How many $20+ players the team can still buy.
+    pub buys_20: usize,
+    /// The team's nominations among the players sold so far.
+    pub nominations: NominationPattern,
 }
 
 // Re-exports from draft modal layer.
@@ -325,9 +362,11 @@ pub async fn run(
     // 4. Create crossterm EventStream for async keyboard input
     let mut event_stream = EventStream::new();
 
-    // 5. Create render interval (~30fps)
-    let mut render_tick = tokio::time::interval(Duration::from_millis(33));
+    // 5. Create render interval (~30fps) and the monitor that notices when
+    //    drawing can't keep up with it.
+    let mut render_tick = tokio::time::interval(frame_budget::FRAME_BUDGET);
     render_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut frame_monitor = frame_budget::FrameMonitor::new();
 
     // 6. Create subscription manager and keybind manager for the new input system.
     let mut sub_manager = SubscriptionManager::<AppMessage>::new();
@@ -354,14 +393,26 @@ pub async fn run(
                                         break;
                                     }
                                     Action::Command(cmd) => {
+                                        app.predict(&cmd);
                                         let _ = cmd_tx.send(cmd).await;
                                     }
                                 }
                             }
                         }
                     }
+                    Some(Ok(Event::Resize(width, height))) => {
+                        // Resize the buffers, which also clears the screen, and
+                        // redraw now instead of on the next tick so no panel
+                        // shows stale cells. The draw recomputes the layout
+                        // for the new size, and each panel's view() clamps its
+                        // scroll offset to its new viewport.
+                        terminal.resize(Rect::new(0, 0, width, height))?;
+                        frame_monitor.cancel_skips();
+                        app.active_keybinds = kb_manager.hints();
+                        terminal.draw(|frame| app.view(frame))?;
+                    }
                     Some(Ok(_)) => {
-                        // Mouse events, resize events, etc. -- ignore for now
+                        // Mouse events, focus changes, etc. -- ignore for now
                     }
                     Some(Err(_)) => {
                         // Input error -- break out
@@ -401,6 +452,7 @@ pub async fn run(
                                 break;
                             }
                             Action::Command(cmd) => {
+                                app.predict(&cmd);
                                 let _ = cmd_tx.send(cmd).await;
                             }
                         }
@@ -412,9 +464,25 @@ pub async fn run(
                 let sub = app.subscription(&mut kb_manager);
                 sub_manager.sync(sub);
 
+                // Under load, give the terminal time to catch up. Updates
+                // above were still applied, so nothing backs up.
+                if frame_monitor.should_skip() {
+                    continue;
+                }
+
                 // Draw using hints from kb_manager.
                 app.active_keybinds = kb_manager.hints();
+                let draw_started = std::time::Instant::now();
                 terminal.draw(|frame| app.view(frame))?;
+                let frame_time = draw_started.elapsed();
+                if frame_monitor.record(frame_time) {
+                    tracing::warn!(
+                        "render loop {}: average frame time {:?}",
+                        if frame_monitor.is_degraded() { "degraded" } else { "recovered" },
+                        frame_monitor.average(),
+                    );
+                }
+                app.set_render_load(frame_monitor.degraded_average());
             }
         }
     }
@@ -434,11 +502,13 @@ mod tests {
     use super::*;
     use crate::protocol::{
         AppMode, ConnectionStatus, LlmStatus, LlmStreamUpdate, NominationInfo, TabId, TeamSnapshot,
+        WsServerStatus,
     };
     use draft::main_panel::analysis::AnalysisPanelMessage;
     use draft::main_panel::MainPanelMessage;
     use draft::sidebar::plan::PlanPanelMessage;
     use llm_stream::LlmStreamMessage;
+    use crate::test_utils::test_snapshot;
 
     // -- FocusPanel cycling --
 
@@ -447,7 +517,8 @@ mod tests {
         assert_eq!(FocusPanel::next(None), Some(FocusPanel::MainPanel));
         assert_eq!(FocusPanel::next(Some(FocusPanel::MainPanel)), Some(FocusPanel::Budget));
         assert_eq!(FocusPanel::next(Some(FocusPanel::Budget)), Some(FocusPanel::Roster));
-        assert_eq!(FocusPanel::next(Some(FocusPanel::Roster)), Some(FocusPanel::Scarcity));
+        assert_eq!(FocusPanel::next(Some(FocusPanel::Roster)), Some(FocusPanel::ShoppingList));
+        assert_eq!(FocusPanel::next(Some(FocusPanel::ShoppingList)), Some(FocusPanel::Scarcity));
         assert_eq!(FocusPanel::next(Some(FocusPanel::Scarcity)), Some(FocusPanel::NominationPlan));
         assert_eq!(FocusPanel::next(Some(FocusPanel::NominationPlan)), None);
     }
@@ -456,7 +527,8 @@ mod tests {
     fn focus_prev_cycles_backward() {
         assert_eq!(FocusPanel::prev(None), Some(FocusPanel::NominationPlan));
         assert_eq!(FocusPanel::prev(Some(FocusPanel::NominationPlan)), Some(FocusPanel::Scarcity));
-        assert_eq!(FocusPanel::prev(Some(FocusPanel::Scarcity)), Some(FocusPanel::Roster));
+        assert_eq!(FocusPanel::prev(Some(FocusPanel::Scarcity)), Some(FocusPanel::ShoppingList));
+        assert_eq!(FocusPanel::prev(Some(FocusPanel::ShoppingList)), Some(FocusPanel::Roster));
         assert_eq!(FocusPanel::prev(Some(FocusPanel::Roster)), Some(FocusPanel::Budget));
         assert_eq!(FocusPanel::prev(Some(FocusPanel::Budget)), Some(FocusPanel::MainPanel));
         assert_eq!(FocusPanel::prev(Some(FocusPanel::MainPanel)), None);
@@ -513,32 +585,6 @@ mod tests {
         assert_eq!(budget.pitching_target, 0);
     }
 
-    /// Helper to build a test AppSnapshot with sensible defaults.
-    fn test_snapshot(pick_count: usize, total_picks: usize, active_tab: Option<TabId>) -> crate::protocol::AppSnapshot {
-        crate::protocol::AppSnapshot {
-            app_mode: AppMode::Draft,
-            pick_count,
-            total_picks,
-            active_tab,
-            available_players: vec![],
-            positional_scarcity: vec![],
-            draft_log: vec![],
-            my_roster: vec![],
-            budget_spent: 0,
-            budget_remaining: 260,
-            salary_cap: 260,
-            inflation_rate: 1.0,
-            max_bid: 0,
-            avg_per_slot: 0.0,
-            hitting_spent: 0,
-            hitting_target: 0,
-            pitching_spent: 0,
-            pitching_target: 0,
-            team_snapshots: vec![],
-            llm_configured: true,
-        }
-    }
-
     #[test]
     fn apply_snapshot_updates_fields() {
         let mut app = app::App::default();
@@ -588,12 +634,20 @@ mod tests {
                 budget_remaining: 160,
                 slots_filled: 5,
                 total_slots: 26,
+                max_bid: 139,
+                buys_10: 14,
+                buys_20: 6,
+                nominations: Default::default(),
             },
             TeamSnapshot {
                 name: "Team 2".into(),
                 budget_remaining: 200,
                 slots_filled: 3,
                 total_slots: 26,
+                max_bid: 177,
+                buys_10: 19,
+                buys_20: 9,
+                nominations: Default::default(),
             },
         ];
 
@@ -629,6 +683,9 @@ mod tests {
             dollar_value: 30.0,
             adjusted_value: 28.0,
             verdict: InstantVerdict::Pass,
+            roster_block: None,
+            category_gains: vec![],
+            standings: vec![],
         });
 
         let nom = NominationInfo {
@@ -640,7 +697,11 @@ mod tests {
             time_remaining: Some(30),
             eligible_slots: vec![],
         };
-        app.apply_update(UiUpdate::NominationUpdate { info: Box::new(nom), analysis_request_id: None });
+        app.apply_update(UiUpdate::NominationUpdate {
+            info: Box::new(nom),
+            analysis_request_id: None,
+            analysis: None,
+        });
 
         assert!(app.draft_screen.current_nomination.is_some());
         assert_eq!(
@@ -652,6 +713,565 @@ mod tests {
         assert!(app.draft_screen.instant_analysis.is_none());
     }
 
+    #[test]
+    fn dump_support_bundle_requests_dump_and_shows_outcome() {
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        let action = app.draft_screen.update(DraftScreenMessage::DumpSupportBundle);
+        assert!(matches!(action, Some(Action::Command(UserCommand::DumpSupportBundle))));
+
+        app.apply_update(UiUpdate::SupportBundle(Ok("/tmp/support.json".into())));
+        assert_eq!(
+            app.draft_screen.file_notice,
+            Some(Ok("State dumped to /tmp/support.json".to_string()))
+        );
+    }
+
+    #[test]
+    fn export_projection_audit_requests_export_and_shows_outcome() {
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        let action = app.draft_screen.update(DraftScreenMessage::ExportProjectionAudit);
+        assert!(matches!(action, Some(Action::Command(UserCommand::ExportProjectionAudit))));
+
+        app.apply_update(UiUpdate::ProjectionAudit(Err("disk full".into())));
+        assert_eq!(
+            app.draft_screen.file_notice,
+            Some(Err("Projection audit failed: disk full".to_string()))
+        );
+    }
+
+    #[test]
+    fn export_keeper_report_requests_export_and_shows_outcome() {
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        let action = app.draft_screen.update(DraftScreenMessage::ExportKeeperReport);
+        assert!(matches!(action, Some(Action::Command(UserCommand::ExportKeeperReport))));
+
+        app.apply_update(UiUpdate::KeeperReport(Ok("/tmp/keeper-report.csv".into())));
+        assert_eq!(
+            app.draft_screen.file_notice,
+            Some(Ok("Keeper report written to /tmp/keeper-report.csv".to_string()))
+        );
+    }
+
+    #[test]
+    fn export_available_view_sends_the_rows_shown() {
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        let action = app.draft_screen.update(DraftScreenMessage::ExportAvailableView);
+        assert!(action.is_none(), "only from the Available tab");
+
+        app.draft_screen.update(DraftScreenMessage::SwitchTab(TabId::Available));
+        let action = app.draft_screen.update(DraftScreenMessage::ExportAvailableView);
+        assert!(matches!(action, Some(Action::Command(UserCommand::ExportAvailableView(_)))));
+
+        app.apply_update(UiUpdate::AvailableExport(Ok("/tmp/available.csv".into())));
+        assert_eq!(
+            app.draft_screen.file_notice,
+            Some(Ok("Available view written to /tmp/available.csv".to_string()))
+        );
+    }
+
+    #[test]
+    fn prep_sheet_is_imported_once_players_are_valued() {
+        use crate::test_utils::TestPlayer;
+        use crate::valuation::prep::PrepEntry;
+
+        let entry = |player: &str, target: bool, avoid: bool| PrepEntry {
+            player: player.into(),
+            my_value: None,
+            tier: None,
+            target,
+            avoid,
+            note: String::new(),
+        };
+        let mut app = app::App::default();
+        let mut snapshot = test_snapshot(0, 260, None);
+        snapshot.prep = vec![entry("Alpha", true, false), entry("Bravo", false, true), entry("Nobody", true, false)];
+        snapshot.prep_unmatched = vec!["Nobody".into()];
+
+        // No valuations yet: names aren't reconciled, wait.
+        app.apply_snapshot(snapshot.clone());
+        assert!(app.draft_screen.main_panel.available.pinned().is_empty());
+
+        snapshot.available_players =
+            vec![TestPlayer::hitter("Alpha").dollar(20.0).build(), TestPlayer::hitter("Bravo").dollar(10.0).build()];
+        app.apply_snapshot(snapshot.clone());
+        let available = &app.draft_screen.main_panel.available;
+        assert_eq!(available.pinned(), ["Alpha"]);
+        assert_eq!(available.avoided(), ["Bravo"]);
+        assert_eq!(
+            app.draft_screen.file_notice,
+            Some(Err("Prep sheet: 1 of 3 names matched no player: Nobody".to_string()))
+        );
+
+        // Later snapshots don't re-pin what I unpinned.
+        app.draft_screen.main_panel.available.update(
+            draft::main_panel::available::AvailablePanelMessage::TogglePin("Alpha".into()),
+        );
+        app.apply_snapshot(snapshot);
+        assert!(app.draft_screen.main_panel.available.pinned().is_empty());
+    }
+
+    #[test]
+    fn compare_marks_two_players_and_streams_the_llm_answer() {
+        use crate::protocol::LlmStreamUpdate;
+        use crate::test_utils::TestPlayer;
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        app.draft_screen.available_players = vec![
+            TestPlayer::hitter("Alpha").dollar(30.0).build(),
+            TestPlayer::hitter("Bravo").dollar(20.0).build(),
+        ];
+        assert!(app.draft_screen.update(DraftScreenMessage::MarkCompare).is_none());
+        app.draft_screen.update(DraftScreenMessage::SwitchTab(TabId::Available));
+
+        assert!(app.draft_screen.update(DraftScreenMessage::MarkCompare).is_none());
+        assert_eq!(app.draft_screen.main_panel.available.compare_mark().map(|p| p.name.as_str()), Some("Alpha"));
+        app.draft_screen.main_panel.available.update(
+            draft::main_panel::available::AvailablePanelMessage::Scroll(scroll::ScrollDirection::Down),
+        );
+        let action = app.draft_screen.update(DraftScreenMessage::MarkCompare);
+        assert!(matches!(
+            action,
+            Some(Action::Command(UserCommand::ComparePlayers { ref a, ref b })) if a.name == "Alpha" && b.name == "Bravo"
+        ));
+        assert_eq!(app.draft_screen.main_panel.available.compare_mark(), None);
+        assert_eq!(app.draft_screen.modal_layer.compare.names(), Some(("Alpha", "Bravo")));
+
+        app.apply_update(UiUpdate::ComparisonStarted { request_id: 7 });
+        app.apply_update(UiUpdate::LlmUpdate {
+            request_id: 7,
+            update: LlmStreamUpdate::Token("Take Alpha.".into()),
+        });
+        assert_eq!(app.draft_screen.modal_layer.compare.llm_text(), "Take Alpha.");
+    }
+
+    #[test]
+    fn session_stats_overlay_opens_with_the_latest_stats() {
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        let mut snapshot = test_snapshot(3, 260, None);
+        snapshot.session_stats.my_surplus = 7.5;
+        app.apply_snapshot(snapshot);
+
+        let action = app.draft_screen.update(DraftScreenMessage::OpenSessionStats);
+        assert!(action.is_none());
+        let stats = &app.draft_screen.modal_layer.session_stats;
+        assert!(stats.open);
+        assert_eq!(stats.stats.my_surplus, 7.5);
+    }
+
+    #[test]
+    fn dart_board_overlay_opens_with_the_latest_board() {
+        use draft::DraftScreenMessage;
+        use crate::draft::dart_board::DartGroup;
+        use crate::draft::pick::Position;
+
+        let mut app = app::App::default();
+        let mut snapshot = test_snapshot(3, 260, None);
+        snapshot.dart_board = vec![DartGroup { position: Position::Catcher, open: 1, darts: vec![] }];
+        app.apply_snapshot(snapshot);
+
+        let action = app.draft_screen.update(DraftScreenMessage::OpenDartBoard);
+        assert!(action.is_none());
+        let board = &app.draft_screen.modal_layer.dart_board;
+        assert!(board.open);
+        assert_eq!(board.groups[0].position, Position::Catcher);
+    }
+
+    #[test]
+    fn diff_explanations_collect_for_the_overlay() {
+        use draft::DraftScreenMessage;
+        use crate::protocol::DiffExplanation;
+
+        let mut app = app::App::default();
+        app.apply_update(UiUpdate::DiffExplanation(DiffExplanation {
+            at: "19:02:11".into(),
+            summary: "nomination changed because bid on Player B went $5→$7".into(),
+            decisions: vec!["bid on Player B went $5→$7".into()],
+        }));
+
+        let action = app.draft_screen.update(DraftScreenMessage::OpenDiffExplain);
+        assert!(action.is_none());
+        let modal = &app.draft_screen.modal_layer.diff_explain;
+        assert!(modal.open);
+        assert_eq!(modal.history.len(), 1);
+    }
+
+    #[test]
+    fn plan_b_strip_follows_the_snapshot() {
+        use crate::draft::pick::Position;
+        use crate::draft::plan_b::{OutbidPlayer, PlanB};
+
+        let mut app = app::App::default();
+        let mut snapshot = test_snapshot(3, 260, None);
+        snapshot.plan_b = Some(PlanB {
+            lost: OutbidPlayer {
+                player_name: "Star".into(),
+                position: Some(Position::ShortStop),
+                dollar_value: 30.0,
+                winner: "Team 2".into(),
+                price: 34,
+                my_bid: Some(31),
+            },
+            substitutes: vec![],
+        });
+        app.apply_snapshot(snapshot);
+        assert_eq!(app.draft_screen.plan_b.as_ref().unwrap().lost.player_name, "Star");
+
+        app.apply_snapshot(test_snapshot(3, 260, None));
+        assert!(app.draft_screen.plan_b.is_none());
+    }
+
+    #[test]
+    fn roster_hole_is_called_out_once_when_it_turns_red() {
+        use crate::draft::pick::Position;
+        use crate::draft::roster_holes::{HoleAlert, RosterHole};
+
+        let hole = |players_left, alert| RosterHole {
+            position: Position::Catcher,
+            teams_needing: 3,
+            players_left,
+            alert,
+        };
+        let mut app = app::App::default();
+        let mut snapshot = test_snapshot(3, 260, None);
+        snapshot.roster_holes = vec![hole(5, HoleAlert::Watch)];
+        app.apply_snapshot(snapshot.clone());
+        assert_eq!(app.draft_screen.file_notice, None, "watching is not worth a notice");
+
+        snapshot.roster_holes = vec![hole(2, HoleAlert::Panic)];
+        app.apply_snapshot(snapshot.clone());
+        assert_eq!(
+            app.draft_screen.file_notice,
+            Some(Err("PANIC: 2 C left for 3 teams".to_string()))
+        );
+
+        app.draft_screen.file_notice = None;
+        app.apply_snapshot(snapshot);
+        assert_eq!(app.draft_screen.file_notice, None, "no repeat at the same level");
+    }
+
+    #[test]
+    fn help_explains_the_focused_panel_or_the_active_tab() {
+        use draft::help::HelpTopic;
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        app.draft_screen.update(DraftScreenMessage::SwitchTab(TabId::DraftLog));
+        app.draft_screen.update(DraftScreenMessage::OpenHelp);
+        let help = &app.draft_screen.modal_layer.help;
+        assert!(help.open);
+        assert_eq!(help.topic, HelpTopic::DraftLog);
+
+        app.draft_screen.modal_layer.help.open = false;
+        app.draft_screen.focused_panel = Some(FocusPanel::Budget);
+        app.draft_screen.update(DraftScreenMessage::OpenHelp);
+        assert_eq!(app.draft_screen.modal_layer.help.topic, HelpTopic::Budget);
+    }
+
+    #[test]
+    fn undo_and_redo_request_commands_and_show_outcome() {
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        let action = app.draft_screen.update(DraftScreenMessage::Undo);
+        assert!(matches!(action, Some(Action::Command(UserCommand::Undo))));
+        let action = app.draft_screen.update(DraftScreenMessage::Redo);
+        assert!(matches!(action, Some(Action::Command(UserCommand::Redo))));
+
+        app.apply_update(UiUpdate::UndoRedo(Err("Nothing to undo".into())));
+        assert_eq!(app.draft_screen.file_notice, Some(Err("Nothing to undo".to_string())));
+    }
+
+    #[test]
+    fn pins_and_slot_moves_are_sent_to_the_app_to_undo() {
+        use crate::draft::pick::Position;
+        use crate::draft::roster::{RosterSlot, RosteredPlayer};
+        use crate::test_utils::TestPlayer;
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        app.draft_screen.update(DraftScreenMessage::SwitchTab(TabId::Available));
+        app.draft_screen.available_players = vec![TestPlayer::hitter("Alpha").dollar(20.0).build()];
+        let action = app.draft_screen.update(DraftScreenMessage::TogglePin);
+        assert_eq!(
+            action,
+            Some(Action::Command(UserCommand::SetPin { name: "Alpha".into(), pinned: true }))
+        );
+        // Undoing the pin comes back from the app.
+        app.apply_update(UiUpdate::PinSet { name: "Alpha".into(), pinned: false });
+        assert!(app.draft_screen.main_panel.available.pinned().is_empty());
+
+        let slot = |position, player: Option<&str>| RosterSlot {
+            position,
+            player: player.map(|name| RosteredPlayer {
+                name: name.into(),
+                price: 20,
+                position,
+                eligible_slots: vec![],
+                espn_player_id: None,
+            }),
+        };
+        app.draft_screen.my_roster = vec![
+            slot(Position::FirstBase, Some("Slugger")),
+            slot(Position::ShortStop, None),
+            slot(Position::Utility, None),
+        ];
+        let action = app.draft_screen.update(DraftScreenMessage::MoveRosterSlot);
+        assert_eq!(
+            action,
+            Some(Action::Command(UserCommand::MoveToSlot { player_name: "Slugger".into(), slot: 2 }))
+        );
+        app.draft_screen.my_roster[2] = slot(Position::Utility, Some("Designated"));
+        assert_eq!(app.draft_screen.update(DraftScreenMessage::MoveRosterSlot), None);
+        assert_eq!(app.draft_screen.file_notice, Some(Err("No open slot Slugger can play".to_string())));
+    }
+
+    #[test]
+    fn a_desynced_ui_warns_until_resynced() {
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        app.apply_update(UiUpdate::UiDesynced { lost: 3 });
+        assert_eq!(app.draft_screen.ui_desynced, Some(3));
+        app.apply_update(UiUpdate::NominationCleared);
+        assert_eq!(app.draft_screen.ui_desynced, Some(3), "the warning stays");
+
+        let action = app.draft_screen.update(DraftScreenMessage::RequestResync);
+        assert!(matches!(action, Some(Action::Command(UserCommand::RequestKeyframe))));
+        assert_eq!(app.draft_screen.ui_desynced, None);
+    }
+
+    #[test]
+    fn render_load_flags_status_bar_until_recovered() {
+        let mut app = app::App::default();
+        app.set_render_load(Some(std::time::Duration::from_millis(48)));
+        assert_eq!(
+            app.draft_screen.render_load,
+            Some(std::time::Duration::from_millis(48))
+        );
+
+        app.set_render_load(None);
+        assert_eq!(app.draft_screen.render_load, None);
+    }
+
+    #[test]
+    fn manual_pick_shows_before_the_app_answers() {
+        use crate::protocol::TeamSnapshot;
+        use crate::test_utils::TestPlayer;
+
+        let mut app = app::App::default();
+        let mut snapshot = test_snapshot(0, 260, None);
+        snapshot.available_players =
+            vec![TestPlayer::hitter("Alpha").dollar(20.0).build(), TestPlayer::hitter("Bravo").dollar(10.0).build()];
+        snapshot.team_snapshots = vec![TeamSnapshot {
+            name: "Team 1".into(),
+            budget_remaining: 260,
+            slots_filled: 0,
+            total_slots: 26,
+            max_bid: 235,
+            buys_10: 23,
+            buys_20: 11,
+            nominations: Default::default(),
+        }];
+        app.apply_snapshot(snapshot.clone());
+        let pick = |team_idx| UserCommand::ManualPick { player_name: "Alpha".into(), team_idx, price: 25 };
+
+        app.predict(&pick(0));
+        let names = |app: &app::App| -> Vec<String> {
+            app.draft_screen.available_players.iter().map(|p| p.name.clone()).collect()
+        };
+        assert_eq!(names(&app), ["Bravo"]);
+        assert_eq!(app.draft_screen.draft_log[0].team_name, "Team 1");
+
+        // A snapshot sent before the app got to it doesn't undo it.
+        app.apply_snapshot(snapshot.clone());
+        assert_eq!(names(&app), ["Bravo"]);
+
+        app.apply_update(UiUpdate::ManualPicked(Err("no team #0".into())));
+        assert_eq!(names(&app), ["Alpha", "Bravo"]);
+        assert!(app.draft_screen.draft_log.is_empty());
+        assert_eq!(
+            app.draft_screen.file_notice,
+            Some(Err("Manual pick rejected: no team #0".to_string()))
+        );
+
+        // An unknown team is left for the app to reject.
+        app.predict(&pick(5));
+        assert_eq!(names(&app).len(), 2);
+    }
+
+    #[test]
+    fn new_picks_are_toasted_once() {
+        use crate::draft::pick::DraftPick;
+        use std::time::Instant;
+
+        let pick = |n: u32, price: u32| DraftPick {
+            pick_number: n,
+            team_id: "7".to_string(),
+            team_name: "Team 7".to_string(),
+            player_name: format!("Player {n}"),
+            position: "SS".to_string(),
+            price,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+        let toasts = |app: &app::App| -> Vec<String> {
+            app.draft_screen.toasts.visible(Instant::now()).map(|t| t.text.clone()).collect()
+        };
+        let mut app = app::App::default();
+        let mut snapshot = test_snapshot(1, 260, None);
+        snapshot.draft_log = vec![pick(1, 20)];
+        app.apply_snapshot(snapshot.clone());
+        assert!(toasts(&app).is_empty(), "picks before the first snapshot are history");
+
+        snapshot.draft_log.push(pick(2, 38));
+        snapshot.pick_values.insert(2, 32.0);
+        app.apply_snapshot(snapshot.clone());
+        app.apply_snapshot(snapshot.clone());
+        assert_eq!(toasts(&app), ["Team 7 paid $38 for Player 2 — $6 over model"]);
+
+        // Turned off in `[display]`.
+        snapshot.draft_log.push(pick(3, 5));
+        snapshot.pick_toasts = false;
+        app.apply_snapshot(snapshot);
+        assert_eq!(toasts(&app).len(), 1);
+    }
+
+    #[test]
+    fn time_machine_opens_at_selected_pick_and_loads_reply() {
+        use crate::draft::pick::DraftPick;
+        use crate::protocol::TimeMachineSnapshot;
+        use draft::DraftScreenMessage;
+        use scroll::ScrollDirection;
+
+        let pick = |n: u32| DraftPick {
+            pick_number: n,
+            team_id: "1".to_string(),
+            team_name: "Team 1".to_string(),
+            player_name: format!("Player {n}"),
+            position: "1B".to_string(),
+            price: 10,
+            espn_player_id: None,
+            eligible_slots: vec![],
+            assigned_slot: None,
+        };
+        let mut app = app::App::default();
+        app.draft_screen.draft_log = vec![pick(1), pick(2), pick(3)];
+
+        // Not available outside the Draft Log tab.
+        assert!(app.draft_screen.update(DraftScreenMessage::OpenTimeMachine).is_none());
+
+        app.draft_screen.update(DraftScreenMessage::SwitchTab(TabId::DraftLog));
+        app.draft_screen.update(DraftScreenMessage::ScrollFocused(ScrollDirection::Down));
+        let action = app.draft_screen.update(DraftScreenMessage::OpenTimeMachine);
+        assert!(matches!(
+            action,
+            Some(Action::Command(UserCommand::RequestTimeMachine { pick_number: 2 }))
+        ));
+        assert!(app.draft_screen.modal_layer.time_machine.is_loading());
+
+        app.apply_update(UiUpdate::TimeMachine {
+            pick_number: 2,
+            snapshot: Some(Box::new(TimeMachineSnapshot {
+                pick: pick(2),
+                pick_value: None,
+                total_recorded: 3,
+                inflation_rate: 1.0,
+                teams: vec![],
+                top_available: vec![],
+                scarcity: vec![],
+            })),
+        });
+        let modal = &app.draft_screen.modal_layer.time_machine;
+        assert!(!modal.is_loading());
+        assert_eq!(modal.snapshot().unwrap().pick.player_name, "Player 2");
+    }
+
+    #[test]
+    fn quick_bid_sends_command_and_intent_clears_with_nomination() {
+        use crate::journal::{BidIntent, QuickBid};
+        use draft::DraftScreenMessage;
+
+        let mut app = app::App::default();
+        // Ignored while nothing is on the block.
+        assert!(app
+            .draft_screen
+            .update(DraftScreenMessage::QuickBid(QuickBid::In))
+            .is_none());
+
+        app.draft_screen.current_nomination = Some(NominationInfo {
+            player_name: "Mike Trout".to_string(),
+            position: "CF".to_string(),
+            nominated_by: "Team Alpha".to_string(),
+            current_bid: 17,
+            current_bidder: None,
+            time_remaining: Some(30),
+            eligible_slots: vec![],
+        });
+        let action = app
+            .draft_screen
+            .update(DraftScreenMessage::QuickBid(QuickBid::Raise(5)));
+        assert!(matches!(
+            action,
+            Some(Action::Command(UserCommand::QuickBid(QuickBid::Raise(5))))
+        ));
+
+        let intent = BidIntent {
+            player_name: "Mike Trout".to_string(),
+            interested: Some(true),
+            bid: Some(22),
+            cap: None,
+        };
+        app.apply_update(UiUpdate::BidIntent(intent.clone()));
+        assert_eq!(app.draft_screen.bid_intent, Some(intent));
+
+        app.apply_update(UiUpdate::NominationCleared);
+        assert!(app.draft_screen.bid_intent.is_none());
+    }
+
+    #[test]
+    fn apply_update_nomination_update_stores_instant_analysis() {
+        use crate::protocol::{InstantAnalysis, InstantVerdict};
+
+        let mut app = app::App::default();
+        let nom = NominationInfo {
+            player_name: "Mike Trout".to_string(),
+            position: "CF".to_string(),
+            nominated_by: "Team Alpha".to_string(),
+            current_bid: 45,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        };
+        let analysis = InstantAnalysis {
+            player_name: "Mike Trout".to_string(),
+            dollar_value: 42.0,
+            adjusted_value: 45.0,
+            verdict: InstantVerdict::StrongTarget,
+            roster_block: None,
+            category_gains: vec![],
+            standings: vec![],
+        };
+        app.apply_update(UiUpdate::NominationUpdate {
+            info: Box::new(nom),
+            analysis_request_id: None,
+            analysis: Some(Box::new(analysis.clone())),
+        });
+
+        assert_eq!(app.draft_screen.instant_analysis, Some(analysis));
+    }
+
     #[test]
     fn apply_update_bid_update_preserves_analysis_text() {
         let mut app = app::App::default();
@@ -686,6 +1306,63 @@ mod tests {
         assert_eq!(app.draft_screen.main_panel.analysis.status(), LlmStatus::Streaming);
     }
 
+    #[test]
+    fn bid_ladder_follows_the_bidding() {
+        use crate::protocol::{InstantAnalysis, InstantVerdict};
+
+        let mut app = app::App::default();
+        app.draft_screen.team_summaries = [60, 45, 30, 25, 12, 5]
+            .into_iter()
+            .enumerate()
+            .map(|(i, max_bid)| TeamSummary {
+                name: format!("Team {i}"),
+                budget_remaining: max_bid + 20,
+                slots_filled: 5,
+                total_slots: 26,
+                max_bid,
+                buys_10: 0,
+                buys_20: 0,
+                nominations: Default::default(),
+            })
+            .collect();
+        let nom = NominationInfo {
+            player_name: "Mike Trout".to_string(),
+            position: "CF".to_string(),
+            nominated_by: "Team 0".to_string(),
+            current_bid: 12,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        };
+        let analysis = InstantAnalysis {
+            player_name: "Mike Trout".to_string(),
+            dollar_value: 38.0,
+            adjusted_value: 40.4,
+            verdict: InstantVerdict::StrongTarget,
+            roster_block: None,
+            category_gains: vec![],
+            standings: vec![],
+        };
+        app.apply_update(UiUpdate::NominationUpdate {
+            info: Box::new(nom.clone()),
+            analysis_request_id: None,
+            analysis: Some(Box::new(analysis)),
+        });
+        let ladder = app.draft_screen.bid_ladder.clone().expect("ladder for the nomination");
+        assert_eq!(ladder.next_bid, 13);
+        assert_eq!(ladder.jump.map(|j| (j.price, j.priced_out)), Some((31, 2)));
+
+        // Past $30 only the teams that can pay $40 are left: no jump.
+        app.apply_update(UiUpdate::BidUpdate(Box::new(NominationInfo { current_bid: 35, ..nom })));
+        let ladder = app.draft_screen.bid_ladder.clone().unwrap();
+        assert_eq!(ladder.next_bid, 36);
+        assert_eq!(ladder.jump, None);
+        assert_eq!(ladder.survivors, vec![(1, 46)]);
+
+        app.apply_update(UiUpdate::NominationCleared);
+        assert!(app.draft_screen.bid_ladder.is_none());
+    }
+
     #[test]
     fn apply_update_nomination_cleared() {
         let mut app = app::App::default();
@@ -705,11 +1382,45 @@ mod tests {
         app.apply_update(UiUpdate::NominationCleared);
 
         assert!(app.draft_screen.current_nomination.is_none());
+        assert!(app.draft_screen.nominated.is_none());
         assert!(app.draft_screen.instant_analysis.is_none());
         assert!(app.draft_screen.main_panel.analysis.text().is_empty());
         assert_eq!(app.draft_screen.main_panel.analysis.status(), LlmStatus::Idle);
     }
 
+    #[test]
+    fn a_nomination_is_highlighted_and_scrolled_to() {
+        use crate::draft::pick::Position;
+        use crate::test_utils::TestPlayer;
+
+        let mut app = app::App::default();
+        app.draft_screen.available_players = [("Top SS", 30.0), ("Mid SS", 24.0), ("Late SS", 12.0)]
+            .into_iter()
+            .map(|(name, dollars)| TestPlayer::hitter(name).positions(vec![Position::ShortStop]).dollar(dollars).build())
+            .collect();
+        app.apply_update(UiUpdate::NominationUpdate {
+            info: Box::new(NominationInfo {
+                player_name: "Late SS".to_string(),
+                position: "SS".to_string(),
+                nominated_by: "Team Alpha".to_string(),
+                current_bid: 1,
+                current_bidder: None,
+                time_remaining: None,
+                eligible_slots: vec![],
+            }),
+            analysis_request_id: None,
+            analysis: None,
+        });
+
+        let nominated = app.draft_screen.nominated.clone().expect("nominated player");
+        assert_eq!((nominated.name.as_str(), nominated.position, nominated.tier), ("Late SS", Some(Position::ShortStop), Some(3)));
+        let available = &app.draft_screen.main_panel.available;
+        assert_eq!(available.top_player(&app.draft_screen.available_players).unwrap().name, "Late SS");
+
+        app.apply_update(UiUpdate::NominationCleared);
+        assert!(app.draft_screen.nominated.is_none());
+    }
+
     #[test]
     fn apply_update_analysis_token() {
         let mut app = app::App::default();
@@ -792,6 +1503,25 @@ mod tests {
         assert!(!app.espn_page_detected);
     }
 
+    #[test]
+    fn apply_update_ws_server_records_port_and_warns_on_bind_failure() {
+        let mut app = app::App::default();
+        let listening = WsServerStatus::Listening { port: 9002, requested_port: 9001 };
+        app.apply_update(UiUpdate::WsServer(listening.clone()));
+        assert_eq!(app.draft_screen.ws_server, Some(listening));
+        assert!(!app.ws_warning.open);
+
+        app.apply_update(UiUpdate::WsServer(WsServerStatus::BindFailed {
+            requested_port: 9001,
+            error: "no free port".into(),
+        }));
+        assert!(app.ws_warning.open);
+        app.update(app::AppMessage::WsWarning(
+            crate::tui::ws_warning::WsWarningMessage::Dismiss,
+        ));
+        assert!(!app.ws_warning.open);
+    }
+
     // -- espn_page_detected --
 
     #[test]
@@ -0,0 +1,395 @@
+// League setup screen: league size, cap, roster, categories and projections.
+//
+// This is Step 2 of the onboarding wizard, between the LLM setup and the
+// strategy. Each answer is a line of text: the user moves between them,
+// edits one with Enter, and saves with `s`. The app checks the answers
+// against the full config validation before writing league.toml and
+// strategy.toml, and sends back what is wrong, shown under the form, or
+// moves on to the strategy step.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::onboarding::league_setup::{format_roster, parse_categories, parse_roster, LeagueSetup};
+use crate::protocol::{OnboardingAction, UserCommand};
+use crate::tui::subscription::keybinding::{
+    exact, KeyBindingRecipe, KeybindHint, KeybindManager, KeyTrigger, PRIORITY_CAPTURE, PRIORITY_NORMAL,
+};
+use crate::tui::subscription::{Subscription, SubscriptionId};
+use crate::tui::TextInput;
+
+// ---------------------------------------------------------------------------
+// LeagueField
+// ---------------------------------------------------------------------------
+
+/// One answer on the league setup form, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeagueField {
+    Teams,
+    SalaryCap,
+    Roster,
+    BattingCategories,
+    PitchingCategories,
+    HitterProjections,
+    PitcherProjections,
+}
+
+impl LeagueField {
+    pub const ALL: [LeagueField; 7] = [
+        LeagueField::Teams,
+        LeagueField::SalaryCap,
+        LeagueField::Roster,
+        LeagueField::BattingCategories,
+        LeagueField::PitchingCategories,
+        LeagueField::HitterProjections,
+        LeagueField::PitcherProjections,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&f| f == self).unwrap_or(0)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LeagueField::Teams => "Teams",
+            LeagueField::SalaryCap => "Salary cap ($)",
+            LeagueField::Roster => "Roster slots",
+            LeagueField::BattingCategories => "Batting categories",
+            LeagueField::PitchingCategories => "Pitching categories",
+            LeagueField::HitterProjections => "Hitter projections CSV",
+            LeagueField::PitcherProjections => "Pitcher projections CSV",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            LeagueField::Roster => "e.g. C:1 1B:1 2B:1 SS:1 OF:3 UTIL:1 SP:5 RP:3 BE:4",
+            LeagueField::BattingCategories => "e.g. R, HR, RBI, SB, AVG",
+            LeagueField::PitchingCategories => "e.g. K, W, SV, ERA, WHIP",
+            LeagueField::HitterProjections | LeagueField::PitcherProjections => {
+                "blank to use ESPN's projections from the extension"
+            }
+            LeagueField::Teams | LeagueField::SalaryCap => "",
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// LeagueSetupState
+// ---------------------------------------------------------------------------
+
+/// UI state for the league setup screen.
+#[derive(Debug, Clone)]
+pub struct LeagueSetupState {
+    /// One input per `LeagueField::ALL` entry.
+    pub inputs: Vec<TextInput>,
+    pub focus: LeagueField,
+    /// Whether the focused answer is being typed into.
+    pub editing: bool,
+    /// The focused answer before editing, restored on Esc.
+    backup: String,
+    /// What the app (or parsing) found wrong with the last save.
+    pub problems: Vec<String>,
+    sub_id: SubscriptionId,
+}
+
+impl Default for LeagueSetupState {
+    fn default() -> Self {
+        let mut state = LeagueSetupState {
+            inputs: LeagueField::ALL.iter().map(|_| TextInput::new()).collect(),
+            focus: LeagueField::Teams,
+            editing: false,
+            backup: String::new(),
+            problems: Vec::new(),
+            sub_id: SubscriptionId::unique(),
+        };
+        state.load(&LeagueSetup::default());
+        state
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LeagueSetupMessage {
+    FieldUp,
+    FieldDown,
+    StartEdit,
+    Input(KeyEvent),
+    FinishEdit,
+    CancelEdit,
+    Save,
+    Back,
+    Quit,
+}
+
+impl LeagueSetupState {
+    /// Fill the form from `setup`, clearing any problems shown.
+    pub fn load(&mut self, setup: &LeagueSetup) {
+        let text = |field: LeagueField| match field {
+            LeagueField::Teams => setup.num_teams.to_string(),
+            LeagueField::SalaryCap => setup.salary_cap.to_string(),
+            LeagueField::Roster => format_roster(&setup.roster),
+            LeagueField::BattingCategories => setup.batting_categories.join(", "),
+            LeagueField::PitchingCategories => setup.pitching_categories.join(", "),
+            LeagueField::HitterProjections => setup.hitters.clone().unwrap_or_default(),
+            LeagueField::PitcherProjections => setup.pitchers.clone().unwrap_or_default(),
+        };
+        for field in LeagueField::ALL {
+            self.inputs[field.index()].set_value(&text(field));
+        }
+        self.editing = false;
+        self.problems.clear();
+    }
+
+    fn value(&self, field: LeagueField) -> &str {
+        self.inputs[field.index()].value().trim()
+    }
+
+    /// The answers on the form, or what keeps them from being read.
+    pub fn answers(&self) -> Result<LeagueSetup, Vec<String>> {
+        let mut problems = Vec::new();
+        let num_teams = self.value(LeagueField::Teams).parse().unwrap_or_else(|_| {
+            problems.push(format!("Teams: '{}' is not a number", self.value(LeagueField::Teams)));
+            0
+        });
+        let salary_cap = self.value(LeagueField::SalaryCap).trim_start_matches('$').parse().unwrap_or_else(|_| {
+            problems.push(format!("Salary cap: '{}' is not a dollar amount", self.value(LeagueField::SalaryCap)));
+            0
+        });
+        let roster = parse_roster(self.value(LeagueField::Roster)).unwrap_or_else(|e| {
+            problems.push(format!("Roster slots: {e}"));
+            Default::default()
+        });
+        let path = |field| Some(self.value(field).to_string()).filter(|p| !p.is_empty());
+        let setup = LeagueSetup {
+            num_teams,
+            salary_cap,
+            roster,
+            batting_categories: parse_categories(self.value(LeagueField::BattingCategories)),
+            pitching_categories: parse_categories(self.value(LeagueField::PitchingCategories)),
+            hitters: path(LeagueField::HitterProjections),
+            pitchers: path(LeagueField::PitcherProjections),
+        };
+        if problems.is_empty() {
+            Ok(setup)
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Declare keybindings: while editing, every key goes to the focused
+    /// input; otherwise move, edit, save or go back.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<LeagueSetupMessage> {
+        // Rebuilt when editing starts or stops, so the right set is active.
+        let mut hasher = DefaultHasher::new();
+        self.sub_id.hash(&mut hasher);
+        self.editing.hash(&mut hasher);
+        let sub_id = SubscriptionId::from_u64(hasher.finish());
+
+        if self.editing {
+            let recipe = KeyBindingRecipe::new(sub_id)
+                .priority(PRIORITY_CAPTURE)
+                .capture()
+                .bind(exact(KeyCode::Enter), |_| LeagueSetupMessage::FinishEdit, KeybindHint::new("Enter", "Done"))
+                .bind(exact(KeyCode::Esc), |_| LeagueSetupMessage::CancelEdit, KeybindHint::new("Esc", "Cancel"))
+                .bind(KeyTrigger::Any, LeagueSetupMessage::Input, None);
+            return kb.subscribe(recipe);
+        }
+
+        let recipe = KeyBindingRecipe::new(sub_id)
+            .priority(PRIORITY_NORMAL)
+            .bind(exact(KeyCode::Up), |_| LeagueSetupMessage::FieldUp, KeybindHint::new("↑/k", "Up"))
+            .bind(exact(KeyCode::Char('k')), |_| LeagueSetupMessage::FieldUp, None)
+            .bind(exact(KeyCode::Down), |_| LeagueSetupMessage::FieldDown, KeybindHint::new("↓/j", "Down"))
+            .bind(exact(KeyCode::Char('j')), |_| LeagueSetupMessage::FieldDown, None)
+            .bind(exact(KeyCode::Enter), |_| LeagueSetupMessage::StartEdit, KeybindHint::new("Enter", "Edit"))
+            .bind(exact(KeyCode::Char('s')), |_| LeagueSetupMessage::Save, KeybindHint::new("s", "Save & continue"))
+            .bind(exact(KeyCode::Esc), |_| LeagueSetupMessage::Back, KeybindHint::new("Esc", "Back"))
+            .bind(exact(KeyCode::Char('q')), |_| LeagueSetupMessage::Quit, KeybindHint::new("q", "Quit"));
+        kb.subscribe(recipe)
+    }
+
+    pub fn update(&mut self, msg: LeagueSetupMessage) -> Option<UserCommand> {
+        let step = |focus: LeagueField, by: usize| {
+            LeagueField::ALL[(focus.index() + by) % LeagueField::ALL.len()]
+        };
+        match msg {
+            LeagueSetupMessage::FieldUp => self.focus = step(self.focus, LeagueField::ALL.len() - 1),
+            LeagueSetupMessage::FieldDown => self.focus = step(self.focus, 1),
+            LeagueSetupMessage::StartEdit => {
+                let input = &mut self.inputs[self.focus.index()];
+                self.backup = input.value().to_string();
+                input.move_end();
+                self.editing = true;
+            }
+            LeagueSetupMessage::Input(key) => {
+                if let Some(ti_msg) = TextInput::key_to_message(&key) {
+                    self.inputs[self.focus.index()].update(ti_msg);
+                }
+            }
+            LeagueSetupMessage::FinishEdit => {
+                self.editing = false;
+                if self.focus != LeagueField::PitcherProjections {
+                    self.focus = step(self.focus, 1);
+                }
+            }
+            LeagueSetupMessage::CancelEdit => {
+                let backup = std::mem::take(&mut self.backup);
+                self.inputs[self.focus.index()].set_value(&backup);
+                self.editing = false;
+            }
+            LeagueSetupMessage::Save => match self.answers() {
+                Ok(setup) => {
+                    self.problems.clear();
+                    return Some(UserCommand::OnboardingAction(OnboardingAction::SaveLeagueConfig(setup)));
+                }
+                Err(problems) => self.problems = problems,
+            },
+            LeagueSetupMessage::Back => return Some(UserCommand::OnboardingAction(OnboardingAction::GoBack)),
+            LeagueSetupMessage::Quit => return Some(UserCommand::Quit),
+        }
+        None
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Rendering
+// ---------------------------------------------------------------------------
+
+/// Render the league setup form: one row per answer with its hint, then
+/// any problems with the last save.
+pub fn render(frame: &mut Frame, area: Rect, state: &LeagueSetupState) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Line::from(vec![Span::styled(
+            " Set Up Your League ",
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )]))
+        .title_alignment(Alignment::Center);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [form_area, problems_area] =
+        Layout::vertical([Constraint::Length(LeagueField::ALL.len() as u16 * 2 + 2), Constraint::Min(0)])
+            .areas(inner);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "How your league drafts. Change anything later in league.toml and strategy.toml.",
+            Style::default().fg(Color::Gray),
+        )),
+        Line::default(),
+    ];
+    for field in LeagueField::ALL {
+        let focused = field == state.focus;
+        let marker = if focused { "> " } else { "  " };
+        let label_style = if focused {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let mut spans = vec![Span::styled(format!("{marker}{:<24}", field.label()), label_style)];
+        let input = &state.inputs[field.index()];
+        if focused && state.editing {
+            spans.extend(input.styled_spans(
+                Style::default().fg(Color::White),
+                Style::default().fg(Color::Yellow),
+                Style::default().bg(Color::DarkGray),
+            ));
+        } else {
+            spans.push(Span::styled(input.value().to_string(), Style::default().fg(Color::Cyan)));
+        }
+        lines.push(Line::from(spans));
+        lines.push(Line::from(Span::styled(
+            format!("  {:<24}{}", "", field.hint()),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    frame.render_widget(Paragraph::new(lines), form_area);
+
+    if !state.problems.is_empty() {
+        let lines: Vec<Line> = state
+            .problems
+            .iter()
+            .map(|p| Line::from(Span::styled(format!("  ✗ {p}"), Style::default().fg(Color::Red))))
+            .collect();
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), problems_area);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn type_into(state: &mut LeagueSetupState, field: LeagueField, text: &str) {
+        state.focus = field;
+        state.update(LeagueSetupMessage::StartEdit);
+        state.inputs[field.index()].clear();
+        for ch in text.chars() {
+            state.update(LeagueSetupMessage::Input(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE)));
+        }
+        state.update(LeagueSetupMessage::FinishEdit);
+    }
+
+    #[test]
+    fn saving_sends_the_answers_on_the_form() {
+        let mut state = LeagueSetupState::default();
+        type_into(&mut state, LeagueField::Teams, "12");
+        type_into(&mut state, LeagueField::Roster, "C:2 OF:5 SP:6 RP:4");
+        type_into(&mut state, LeagueField::BattingCategories, "r, hr, obp");
+        type_into(&mut state, LeagueField::HitterProjections, "data/h.csv");
+        assert_eq!(state.focus, LeagueField::PitcherProjections);
+
+        let Some(UserCommand::OnboardingAction(OnboardingAction::SaveLeagueConfig(setup))) =
+            state.update(LeagueSetupMessage::Save)
+        else {
+            panic!("expected the answers to be sent");
+        };
+        assert_eq!(setup.num_teams, 12);
+        assert_eq!(setup.salary_cap, 260);
+        assert_eq!(setup.roster["OF"], 5);
+        assert_eq!(setup.batting_categories, ["R", "HR", "OBP"]);
+        assert_eq!((setup.hitters.as_deref(), setup.pitchers), (Some("data/h.csv"), None));
+    }
+
+    #[test]
+    fn unreadable_answers_stay_on_the_form() {
+        let mut state = LeagueSetupState::default();
+        type_into(&mut state, LeagueField::SalaryCap, "lots");
+        type_into(&mut state, LeagueField::Roster, "C");
+        assert_eq!(state.update(LeagueSetupMessage::Save), None);
+        assert_eq!(state.problems.len(), 2);
+
+        // Esc while editing puts the answer back.
+        state.focus = LeagueField::Teams;
+        state.update(LeagueSetupMessage::StartEdit);
+        state.update(LeagueSetupMessage::Input(KeyEvent::new(KeyCode::Char('9'), KeyModifiers::NONE)));
+        state.update(LeagueSetupMessage::CancelEdit);
+        assert_eq!(state.value(LeagueField::Teams), "10");
+    }
+
+    #[test]
+    fn render_does_not_panic() {
+        let backend = ratatui::backend::TestBackend::new(80, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let state = LeagueSetupState {
+            problems: vec!["`league.num_teams`: must be greater than 0".into()],
+            editing: true,
+            ..Default::default()
+        };
+        terminal.draw(|frame| render(frame, frame.area(), &state)).unwrap();
+    }
+}
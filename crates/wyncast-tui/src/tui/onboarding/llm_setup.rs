@@ -2,7 +2,7 @@
 //
 // This is Step 1 of the onboarding wizard. The user selects an LLM provider,
 // chooses a model, enters an API key, and optionally tests the connection
-// before proceeding to strategy configuration.
+// before proceeding to league setup.
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
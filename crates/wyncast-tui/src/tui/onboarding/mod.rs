@@ -1,5 +1,6 @@
 // Onboarding screen dispatcher: routes rendering and input to the correct step.
 
+pub mod league_setup;
 pub mod llm_setup;
 pub mod strategy_setup;
 
@@ -12,12 +13,14 @@ use crate::tui::app::App;
 use crate::tui::subscription::Subscription;
 use crate::tui::subscription::keybinding::KeybindManager;
 
+use self::league_setup::{LeagueSetupMessage, LeagueSetupState};
 use self::llm_setup::{LlmSetupMessage, LlmSetupState};
 use self::strategy_setup::{StrategySetupMessage, StrategySetupState};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum OnboardingMessage {
     LlmSetup(LlmSetupMessage),
+    League(LeagueSetupMessage),
     Strategy(StrategySetupMessage),
 }
 
@@ -25,10 +28,12 @@ pub enum OnboardingMessage {
 ///
 /// Delegates entirely to the active step's leaf subscription:
 /// - `LlmSetup` → `LlmSetupState::subscription(kb, false)`.
+/// - `LeagueSetup` → `LeagueSetupState::subscription(kb)`.
 /// - `StrategySetup` / `Complete` → `StrategySetupState::subscription(kb)`.
 pub fn subscription(
     step: &OnboardingStep,
     llm_setup: &LlmSetupState,
+    league_setup: &LeagueSetupState,
     strategy_setup: &StrategySetupState,
     kb: &mut KeybindManager,
 ) -> Subscription<OnboardingMessage> {
@@ -36,6 +41,9 @@ pub fn subscription(
         OnboardingStep::LlmSetup => llm_setup
             .subscription(kb, false)
             .map(OnboardingMessage::LlmSetup),
+        OnboardingStep::LeagueSetup => league_setup
+            .subscription(kb)
+            .map(OnboardingMessage::League),
         OnboardingStep::StrategySetup | OnboardingStep::Complete => strategy_setup
             .subscription(kb)
             .map(OnboardingMessage::Strategy),
@@ -45,11 +53,13 @@ pub fn subscription(
 pub fn update(
     _step: &OnboardingStep,
     llm_setup: &mut LlmSetupState,
+    league_setup: &mut LeagueSetupState,
     strategy_setup: &mut StrategySetupState,
     msg: OnboardingMessage,
 ) -> Option<UserCommand> {
     match msg {
         OnboardingMessage::LlmSetup(m) => llm_setup.update(m),
+        OnboardingMessage::League(m) => league_setup.update(m),
         OnboardingMessage::Strategy(m) => strategy_setup.update(m),
     }
 }
@@ -74,6 +84,9 @@ pub fn render(frame: &mut Frame, step: &OnboardingStep, state: &App) {
         OnboardingStep::LlmSetup => {
             llm_setup::render(frame, content_area, &state.llm_setup);
         }
+        OnboardingStep::LeagueSetup => {
+            league_setup::render(frame, content_area, &state.league_setup);
+        }
         OnboardingStep::StrategySetup => {
             strategy_setup::render(frame, content_area, &state.strategy_setup);
         }
@@ -108,6 +121,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn render_league_setup_does_not_panic() {
+        let backend = ratatui::backend::TestBackend::new(80, 40);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let state = App::default();
+        terminal
+            .draw(|frame| render(frame, &OnboardingStep::LeagueSetup, &state))
+            .unwrap();
+    }
+
     #[test]
     fn render_strategy_placeholder_does_not_panic() {
         let backend = ratatui::backend::TestBackend::new(80, 40);
@@ -1,6 +1,6 @@
 // Strategy setup screen: linear wizard flow for draft strategy configuration.
 //
-// This is Step 2 of the onboarding wizard. The wizard proceeds through four
+// This is Step 3 of the onboarding wizard. The wizard proceeds through four
 // steps:
 //   1. Input: large text area for natural language strategy description
 //   2. Generating: LLM streams output while processing the description
@@ -118,7 +118,7 @@ pub struct StrategySetupState {
     pub generation_output: String,
     /// Error message from LLM generation, if any.
     pub generation_error: Option<String>,
-    /// Hitting budget percentage (0-100).
+    /// Hitting budget percentage (1-99).
     pub hitting_budget_pct: u8,
     /// Category weight values.
     pub category_weights: CategoryWeights,
@@ -229,7 +229,8 @@ impl StrategySetupState {
 
         if field == "budget" {
             if let Ok(val) = self.field_input.value().parse::<u8>() {
-                if val <= 100 {
+                // Both hitters and pitchers need some budget.
+                if (1..=99).contains(&val) {
                     self.hitting_budget_pct = val;
                     self.editing_field = None;
                     self.field_input.clear();
@@ -1696,6 +1697,12 @@ mod tests {
         // Editing state should be preserved so user can retry
         assert_eq!(s.editing_field.as_deref(), Some("budget"));
         assert_eq!(s.field_input.value(), "101");
+
+        for all_or_nothing in ["100", "0"] {
+            s.field_input.set_value(all_or_nothing);
+            assert!(!s.confirm_edit());
+        }
+        assert_eq!(s.hitting_budget_pct, 65);
     }
 
     #[test]
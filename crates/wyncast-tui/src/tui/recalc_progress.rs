@@ -0,0 +1,97 @@
+// Bulk recalculation progress overlay.
+//
+// Shown on top of whatever screen is active while the app rebuilds its
+// valuations after a full state sync, so a long rebuild reads as work in
+// progress rather than a hung app. It has no keys: the app closes it with
+// `UiUpdate::RecalcProgress(None)` when the rebuild is done.
+//
+// ┌ Recalculating ─────────────────────────────┐
+// │ Applying picks                             │
+// │ ██████████████████████████████████████ 99% │
+// │ Picks 150/150 · recalcs 1/3                │
+// └────────────────────────────────────────────┘
+
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Gauge, Paragraph};
+use ratatui::Frame;
+
+use crate::protocol::RecalcProgress;
+use crate::tui::widgets::centered_rect;
+
+/// Width of the overlay.
+const OVERLAY_WIDTH: u16 = 48;
+
+/// Render the overlay centered on `area`.
+pub fn view(frame: &mut Frame, area: Rect, progress: &RecalcProgress) {
+    let dialog_area = centered_rect(OVERLAY_WIDTH, 5, area);
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(Span::styled(
+            " Recalculating ",
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        ))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let [stage, bar, counts] = Layout::vertical([Constraint::Length(1); 3]).areas(inner);
+    frame.render_widget(
+        Paragraph::new(Line::from(format!(" {}", progress.stage))).style(Style::default().fg(Color::White)),
+        stage,
+    );
+    frame.render_widget(
+        Gauge::default()
+            .gauge_style(Style::default().fg(Color::Cyan).bg(Color::DarkGray))
+            .ratio(progress.ratio()),
+        bar,
+    );
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(counts_text(progress), Style::default().fg(Color::DarkGray)))),
+        counts,
+    );
+}
+
+/// " Picks 150/150 · recalcs 1/3".
+fn counts_text(progress: &RecalcProgress) -> String {
+    format!(
+        " Picks {}/{} · recalcs {}/{}",
+        progress.picks_applied, progress.picks_total, progress.recalcs_done, progress.recalcs_total
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn progress() -> RecalcProgress {
+        RecalcProgress {
+            stage: "Applying picks".into(),
+            picks_applied: 150,
+            picks_total: 150,
+            recalcs_done: 1,
+            recalcs_total: 3,
+        }
+    }
+
+    #[test]
+    fn overlay_shows_the_stage_and_counts() {
+        let progress = progress();
+        assert_eq!(counts_text(&progress), " Picks 150/150 · recalcs 1/3");
+        assert!((progress.ratio() - 151.0 / 153.0).abs() < 1e-9);
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal.draw(|frame| view(frame, frame.area(), &progress)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("Recalculating"));
+        assert!(text.contains("Applying picks"));
+        assert!(text.contains("99%"));
+    }
+}
@@ -60,6 +60,12 @@ impl ScrollState {
         });
     }
 
+    /// Jump to `offset`. Takes `&self` like `clamped_offset()`, so a view can
+    /// keep the same row on top when its content changes under it.
+    pub fn jump_to(&self, offset: usize) {
+        self.offset.set(offset);
+    }
+
     /// Jump to the end. The actual max offset will be resolved by
     /// `clamped_offset()` at render time.
     pub fn scroll_to_end(&mut self) {
@@ -110,6 +116,13 @@ mod tests {
         assert_eq!(s.offset(), 0);
     }
 
+    #[test]
+    fn jump_to_sets_offset() {
+        let s = ScrollState::new();
+        s.jump_to(7);
+        assert_eq!(s.offset(), 7);
+    }
+
     #[test]
     fn scroll_up_at_zero_stays_at_zero() {
         let mut s = ScrollState::new();
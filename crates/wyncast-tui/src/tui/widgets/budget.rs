@@ -1,7 +1,7 @@
 // Budget widget: remaining budget, inflation factor, and spending pace.
 //
 // Key-value display:
-// Spent, Remaining, Inflation, Max bid, Avg/slot
+// Spent, Remaining, Inflation (+ effective hitter/pitcher split), Max bid, Avg/slot
 // Inflation > 1.0 = green (others overspending), < 1.0 = red
 
 use ratatui::layout::Rect;
@@ -12,6 +12,7 @@ use ratatui::Frame;
 
 use crate::tui::BudgetStatus;
 use super::focused_border_style;
+use super::money::MoneyFormat;
 
 /// Render the budget display into the given area.
 ///
@@ -40,16 +41,17 @@ pub fn render(frame: &mut Frame, area: Rect, budget: &BudgetStatus, scroll_offse
 /// Build the budget display lines.
 fn build_budget_lines(budget: &BudgetStatus) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
+    let money = budget.money();
 
     // Spent
     let mut spent_spans = vec![
         Span::styled(" Spent:     ", Style::default().fg(Color::Gray)),
         Span::styled(
-            format!("${}", budget.spent),
+            money.amount(budget.spent.into()),
             Style::default().fg(Color::White),
         ),
         Span::styled(
-            format!(" / ${}", budget.cap),
+            format!(" / {}", MoneyFormat::new(budget.cap, false).amount(budget.cap.into())),
             Style::default().fg(Color::DarkGray),
         ),
     ];
@@ -81,7 +83,7 @@ fn build_budget_lines(budget: &BudgetStatus) -> Vec<Line<'static>> {
     lines.push(Line::from(vec![
         Span::styled(" Remaining: ", Style::default().fg(Color::Gray)),
         Span::styled(
-            format!("${}", budget.remaining),
+            money.amount(budget.remaining.into()),
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
@@ -90,7 +92,7 @@ fn build_budget_lines(budget: &BudgetStatus) -> Vec<Line<'static>> {
 
     // Inflation
     let inflation_color = inflation_color(budget.inflation_rate);
-    lines.push(Line::from(vec![
+    let mut inflation_spans = vec![
         Span::styled(" Inflation: ", Style::default().fg(Color::Gray)),
         Span::styled(
             format_inflation(budget.inflation_rate),
@@ -98,13 +100,28 @@ fn build_budget_lines(budget: &BudgetStatus) -> Vec<Line<'static>> {
                 .fg(inflation_color)
                 .add_modifier(Modifier::BOLD),
         ),
-    ]));
+    ];
+    if let Some(raw) = format_raw_inflation(budget.inflation_rate, budget.raw_inflation_rate) {
+        inflation_spans.push(Span::styled(raw, Style::default().fg(Color::DarkGray)));
+    }
+    if budget.hitting_split > 0.0 {
+        inflation_spans.push(Span::styled("    ", Style::default()));
+        inflation_spans.push(Span::styled(
+            format_split(budget.hitting_split, budget.split_learned),
+            Style::default().fg(if budget.split_learned {
+                Color::Magenta
+            } else {
+                Color::DarkGray
+            }),
+        ));
+    }
+    lines.push(Line::from(inflation_spans));
 
     // Max bid
     lines.push(Line::from(vec![
         Span::styled(" Max Bid:   ", Style::default().fg(Color::Gray)),
         Span::styled(
-            format!("${}", budget.max_bid),
+            money.amount(budget.max_bid.into()),
             Style::default().fg(Color::White),
         ),
     ]));
@@ -113,7 +130,7 @@ fn build_budget_lines(budget: &BudgetStatus) -> Vec<Line<'static>> {
     lines.push(Line::from(vec![
         Span::styled(" Avg/Slot:  ", Style::default().fg(Color::Gray)),
         Span::styled(
-            format!("${:.1}", budget.avg_per_slot),
+            money.average(budget.avg_per_slot),
             Style::default().fg(Color::White),
         ),
     ]));
@@ -136,6 +153,15 @@ pub fn inflation_color(rate: f64) -> Color {
     }
 }
 
+/// Format the effective hitter/pitcher split, e.g. `Split 68/32 (room)`.
+/// `(room)` marks a split learned from the room's spending; a fixed split
+/// is tagged `(fixed)`.
+pub fn format_split(hitting_fraction: f64, learned: bool) -> String {
+    let hit = (hitting_fraction * 100.0).round() as u32;
+    let tag = if learned { "room" } else { "fixed" };
+    format!("Split {}/{} ({})", hit, 100u32.saturating_sub(hit), tag)
+}
+
 /// Return color for hitter/pitcher budget percentage.
 /// Green < 80%, Yellow 80-100%, Red > 100%.
 fn split_color(pct: u32) -> Color {
@@ -153,6 +179,13 @@ pub fn format_inflation(rate: f64) -> String {
     format!("{:.3}x", rate)
 }
 
+/// The raw rate beside a smoothed or damped one, e.g. ` (raw 1.320x)`;
+/// `None` when they show the same.
+pub fn format_raw_inflation(rate: f64, raw: f64) -> Option<String> {
+    let raw = format_inflation(raw);
+    (raw != format_inflation(rate)).then(|| format!(" (raw {raw})"))
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -185,6 +218,57 @@ mod tests {
         assert_eq!(format_inflation(0.85), "0.850x");
     }
 
+    #[test]
+    fn raw_inflation_shows_only_when_it_differs() {
+        assert_eq!(format_raw_inflation(1.15, 1.15), None);
+        assert_eq!(format_raw_inflation(1.15, 1.3204), Some(" (raw 1.320x)".into()));
+
+        let budget = BudgetStatus { inflation_rate: 1.05, raw_inflation_rate: 0.8, ..BudgetStatus::default() };
+        assert_eq!(build_budget_lines(&budget)[2].to_string(), " Inflation: 1.050x (raw 0.800x)");
+    }
+
+    #[test]
+    fn format_split_tags_source() {
+        assert_eq!(format_split(0.65, false), "Split 65/35 (fixed)");
+        assert_eq!(format_split(0.684, true), "Split 68/32 (room)");
+    }
+
+    #[test]
+    fn build_budget_lines_show_split_once_known() {
+        let mut budget = BudgetStatus::default();
+        let has_split = |b: &BudgetStatus| {
+            build_budget_lines(b)[2].to_string().contains("Split")
+        };
+        assert!(!has_split(&budget));
+
+        budget.hitting_split = 0.7;
+        budget.split_learned = true;
+        assert!(build_budget_lines(&budget)[2].to_string().contains("Split 70/30 (room)"));
+        assert!(has_split(&budget));
+    }
+
+    #[test]
+    fn build_budget_lines_scale_with_the_cap() {
+        let mut budget = BudgetStatus {
+            spent: 250,
+            remaining: 750,
+            cap: 1000,
+            max_bid: 728,
+            avg_per_slot: 31.3,
+            ..BudgetStatus::default()
+        };
+        let text = |b: &BudgetStatus| build_budget_lines(b).iter().map(|l| l.to_string()).collect::<Vec<_>>();
+        assert_eq!(text(&budget)[0], " Spent:     $250 / $1000");
+        assert_eq!(text(&budget)[4], " Avg/Slot:  $31");
+
+        budget.percent_of_cap = true;
+        let lines = text(&budget);
+        assert_eq!(lines[0], " Spent:     25.0% / $1000");
+        assert_eq!(lines[1], " Remaining: 75.0%");
+        assert_eq!(lines[3], " Max Bid:   72.8%");
+        assert_eq!(lines[4], " Avg/Slot:  3.13%");
+    }
+
     #[test]
     fn build_budget_lines_default() {
         let budget = BudgetStatus::default();
@@ -211,12 +295,16 @@ mod tests {
             remaining: 140,
             cap: 260,
             inflation_rate: 1.15,
+            raw_inflation_rate: 1.15,
             max_bid: 115,
             avg_per_slot: 10.8,
             hitting_spent: 0,
             hitting_target: 0,
             pitching_spent: 0,
             pitching_target: 0,
+            hitting_split: 0.0,
+            split_learned: false,
+            percent_of_cap: false,
         };
         terminal
             .draw(|frame| render(frame, frame.area(), &budget, 0, false))
@@ -260,12 +348,16 @@ mod tests {
             remaining: 140,
             cap: 260,
             inflation_rate: 1.0,
+            raw_inflation_rate: 1.0,
             max_bid: 115,
             avg_per_slot: 10.0,
             hitting_spent: 85,
             hitting_target: 169,
             pitching_spent: 35,
             pitching_target: 91,
+            hitting_split: 0.0,
+            split_learned: false,
+            percent_of_cap: false,
         };
         let lines = build_budget_lines(&budget);
         assert_eq!(lines.len(), 5);
@@ -280,12 +372,16 @@ mod tests {
             remaining: 140,
             cap: 260,
             inflation_rate: 1.15,
+            raw_inflation_rate: 1.15,
             max_bid: 115,
             avg_per_slot: 10.8,
             hitting_spent: 85,
             hitting_target: 169,
             pitching_spent: 35,
             pitching_target: 91,
+            hitting_split: 0.0,
+            split_learned: false,
+            percent_of_cap: false,
         };
         terminal
             .draw(|frame| render(frame, frame.area(), &budget, 0, false))
@@ -1,10 +1,18 @@
 // TUI widget modules for each dashboard panel.
 
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::style::{Color, Style};
 
 pub mod budget;
+pub mod money;
 pub mod nomination_banner;
+pub mod plan_b;
+pub mod shopping_list;
+pub mod snake;
 pub mod status_bar;
+pub mod table_summary;
+pub mod ticker;
+pub mod toast;
 
 /// Return a cyan border style when focused, otherwise the provided base style.
 ///
@@ -17,3 +25,14 @@ pub fn focused_border_style(focused: bool, base_style: Style) -> Style {
         base_style
     }
 }
+
+/// Compute a centered rectangle of the given size within `area`, for a
+/// modal overlay. If the area is too small, the rectangle is clamped to it.
+pub(crate) fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let vertical = Layout::vertical([Constraint::Length(height.min(area.height))])
+        .flex(Flex::Center)
+        .split(area);
+    Layout::horizontal([Constraint::Length(width.min(area.width))])
+        .flex(Flex::Center)
+        .split(vertical[0])[0]
+}
@@ -0,0 +1,100 @@
+// Money formatting that scales with the league's salary cap.
+//
+// Amounts are whole dollars at any cap, but averages (dollars per slot, per
+// player) need more precision in a $100 league than in a $1000 one: the
+// decimals shown drop as the cap gains digits. In percent-of-cap mode every
+// amount is shown as a share of the cap instead, which reads the same in any
+// league. Column widths come from the widest amount, the cap itself.
+
+/// How to format money for one league.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoneyFormat {
+    /// The league's salary cap in dollars.
+    pub cap: u32,
+    /// Show amounts as a percentage of `cap` instead of dollars.
+    pub percent_of_cap: bool,
+}
+
+impl MoneyFormat {
+    pub fn new(cap: u32, percent_of_cap: bool) -> Self {
+        Self { cap, percent_of_cap }
+    }
+
+    /// A whole amount, e.g. `$42`, or `16.2%` of the cap.
+    pub fn amount(&self, dollars: f64) -> String {
+        if self.percent_of_cap {
+            format!("{:.1}%", self.percent(dollars))
+        } else {
+            format!("${dollars:.0}")
+        }
+    }
+
+    /// An average, with a decimal place more than `amount` below a $1000
+    /// cap and two more below $100.
+    pub fn average(&self, dollars: f64) -> String {
+        if self.percent_of_cap {
+            format!("{:.2}%", self.percent(dollars))
+        } else {
+            format!("${dollars:.prec$}", prec = self.average_decimals())
+        }
+    }
+
+    /// Characters needed for any amount up to the cap, for column widths.
+    pub fn width(&self) -> u16 {
+        if self.percent_of_cap {
+            "100.0%".len() as u16
+        } else {
+            self.amount(f64::from(self.cap)).len() as u16
+        }
+    }
+
+    fn average_decimals(&self) -> usize {
+        match self.cap {
+            1000.. => 0,
+            100.. => 1,
+            _ => 2,
+        }
+    }
+
+    fn percent(&self, dollars: f64) -> f64 {
+        if self.cap == 0 {
+            0.0
+        } else {
+            dollars / f64::from(self.cap) * 100.0
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn average_precision_follows_the_cap() {
+        assert_eq!(MoneyFormat::new(50, false).average(1.926), "$1.93");
+        assert_eq!(MoneyFormat::new(260, false).average(10.04), "$10.0");
+        assert_eq!(MoneyFormat::new(1000, false).average(38.46), "$38");
+        assert_eq!(MoneyFormat::new(1000, false).amount(412.0), "$412");
+    }
+
+    #[test]
+    fn percent_of_cap_reads_the_same_in_any_league() {
+        let small = MoneyFormat::new(100, true);
+        let big = MoneyFormat::new(1000, true);
+        assert_eq!(small.amount(25.0), "25.0%");
+        assert_eq!(big.amount(250.0), "25.0%");
+        assert_eq!(big.average(38.46), "3.85%");
+        assert_eq!(MoneyFormat::new(0, true).amount(5.0), "0.0%");
+    }
+
+    #[test]
+    fn width_fits_the_cap() {
+        assert_eq!(MoneyFormat::new(100, false).width(), 4);
+        assert_eq!(MoneyFormat::new(1000, false).width(), 5);
+        assert_eq!(MoneyFormat::new(1000, true).width(), 6);
+    }
+}
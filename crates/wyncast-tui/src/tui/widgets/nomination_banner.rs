@@ -1,9 +1,13 @@
 // Nomination banner widget: displays current player on the block.
 //
-// 4-row layout when nomination active:
+// 7-row layout when nomination active:
 // Line 1: "NOW UP: {player} ({pos}) -- nom. by {team}"
-// Line 2: "Bid: ${bid} | Value: ${value} | Adj: ${adjusted}"
-// When no nomination: "Waiting for next nomination..." in dim
+// Line 2: "Bid: ${bid} | Value: ${value} | Adj: ${adjusted} | You: in at $18, cap $24"
+// Line 3: "Ladder: next $13 | jump $31 prices out 2 | 2 left at $31 | 1 left at $46"
+// Line 4: "My team: HR ██░░ +25  SB █░░░ +4 ..." (marginal category gains)
+// Line 5: "Standings: SB 7th→4th  HR 5th→4th" (projected category ranks)
+// When no nomination: "Waiting for next nomination..." in dim, or a notice
+// about the previous session's crash if one was recorded.
 
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
@@ -11,7 +15,12 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 use ratatui::Frame;
 
+use crate::crash::CrashReport;
+use crate::draft::ladder::BidLadder;
+use crate::journal::BidIntent;
 use crate::protocol::{InstantAnalysis, InstantVerdict, NominationInfo};
+use crate::valuation::analysis::CategoryGain;
+use crate::valuation::standings::StandingsShift;
 
 /// Render the nomination banner into the given area.
 pub fn render(
@@ -19,9 +28,12 @@ pub fn render(
     area: Rect,
     nomination: Option<&NominationInfo>,
     analysis: Option<&InstantAnalysis>,
+    intent: Option<&BidIntent>,
+    ladder: Option<&BidLadder>,
+    crash_notice: Option<&CrashReport>,
 ) {
     if let Some(nom) = nomination {
-        let lines = build_nomination_lines(nom, analysis);
+        let lines = build_nomination_lines(nom, analysis, intent, ladder);
         let paragraph = Paragraph::new(lines).block(
             Block::default()
                 .borders(Borders::ALL)
@@ -29,6 +41,14 @@ pub fn render(
                 .border_style(Style::default().fg(Color::Yellow)),
         );
         frame.render_widget(paragraph, area);
+    } else if let Some(report) = crash_notice {
+        let paragraph = Paragraph::new(build_crash_lines(report)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Previous session crashed")
+                .border_style(Style::default().fg(Color::Red)),
+        );
+        frame.render_widget(paragraph, area);
     } else {
         let paragraph = Paragraph::new(Line::from(Span::styled(
             "  Waiting for next nomination...",
@@ -45,10 +65,29 @@ pub fn render(
     }
 }
 
+/// Build the content lines of the crash notice shown in place of the banner.
+fn build_crash_lines<'a>(report: &CrashReport) -> Vec<Line<'a>> {
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            format!(" {}", report.summary()),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+    ])];
+
+    let details = match report.crash_file {
+        Some(ref path) => format!(" {} -- backtrace in {}", report.occurred_at, path),
+        None => format!(" {} -- backtrace in the log", report.occurred_at),
+    };
+    lines.push(Line::from(Span::styled(details, Style::default().fg(Color::Gray))));
+    lines
+}
+
 /// Build the content lines of the nomination banner.
 fn build_nomination_lines<'a>(
     nom: &NominationInfo,
     analysis: Option<&InstantAnalysis>,
+    intent: Option<&BidIntent>,
+    ladder: Option<&BidLadder>,
 ) -> Vec<Line<'a>> {
     let mut lines = Vec::new();
 
@@ -72,9 +111,9 @@ fn build_nomination_lines<'a>(
         ),
     ]));
 
-    // Line 2: Bid / Value / Adjusted
+    // Line 2: Bid / Value / Adjusted, then my logged intent
     if let Some(analysis) = analysis {
-        let spans = vec![
+        let mut spans = vec![
             Span::styled(" Bid: ", Style::default().fg(Color::Gray)),
             Span::styled(
                 format_dollar(nom.current_bid),
@@ -98,20 +137,167 @@ fn build_nomination_lines<'a>(
                     .add_modifier(Modifier::BOLD),
             ),
         ];
+        if let Some(block) = &analysis.roster_block {
+            spans.push(Span::styled(format!(" ({block})"), Style::default().fg(Color::Yellow)));
+        }
+        spans.extend(intent_spans(nom, intent));
         lines.push(Line::from(spans));
+        lines.extend(ladder.map(build_ladder_line));
+
+        // Line 4: what the player adds to my roster, per category
+        if !analysis.category_gains.is_empty() {
+            lines.push(build_gains_line(&analysis.category_gains));
+        }
+
+        // Line 5: where buying the player moves me in the standings
+        if !analysis.standings.is_empty() {
+            lines.push(build_standings_line(&analysis.standings));
+        }
     } else {
-        lines.push(Line::from(vec![
+        let mut spans = vec![
             Span::styled(" Bid: ", Style::default().fg(Color::Gray)),
             Span::styled(
                 format_dollar(nom.current_bid),
                 Style::default().fg(Color::White),
             ),
-        ]));
+        ];
+        spans.extend(intent_spans(nom, intent));
+        lines.push(Line::from(spans));
+        lines.extend(ladder.map(build_ladder_line));
     }
 
     lines
 }
 
+/// Build the bid ladder line: the next legal bid, the jump bid and the
+/// prices at which only a few teams can still bid.
+fn build_ladder_line<'a>(ladder: &BidLadder) -> Line<'a> {
+    let label = Style::default().fg(Color::Gray);
+    let price = Style::default().fg(Color::White);
+    let mut spans = vec![
+        Span::styled(" Ladder: next ", label),
+        Span::styled(format_dollar(ladder.next_bid), price),
+    ];
+    if let Some(jump) = ladder.jump {
+        spans.push(Span::styled(" | jump ", label));
+        spans.push(Span::styled(
+            format_dollar(jump.price),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::styled(format!(" prices out {}", jump.priced_out), label));
+    }
+    for &(teams, at) in &ladder.survivors {
+        spans.push(Span::styled(format!(" | {teams} left at "), label));
+        spans.push(Span::styled(format_dollar(at), price));
+    }
+    Line::from(spans)
+}
+
+/// Spans for my logged bid intent, e.g. `| You: in at $18, cap $24`.
+/// Red once the bidding has passed my cap, dim once I'm out.
+fn intent_spans<'a>(nom: &NominationInfo, intent: Option<&BidIntent>) -> Vec<Span<'a>> {
+    let Some(intent) = intent.filter(|i| i.player_name == nom.player_name) else {
+        return Vec::new();
+    };
+    let Some(summary) = intent.summary() else {
+        return Vec::new();
+    };
+    let style = if intent.interested == Some(false) {
+        Style::default().fg(Color::DarkGray)
+    } else if intent.over_cap(nom.current_bid) {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)
+    };
+    vec![
+        Span::styled(" | ", Style::default().fg(Color::Gray)),
+        Span::styled(summary, style),
+    ]
+}
+
+/// Number of cells in each category's mini bar.
+const GAIN_BAR_WIDTH: usize = 4;
+
+/// Build the marginal-gains line: one mini bar per category, scaled so a
+/// typical top available player fills half the bar.
+fn build_gains_line<'a>(gains: &[CategoryGain]) -> Line<'a> {
+    let mut spans = vec![Span::styled(" My team:", Style::default().fg(Color::Gray))];
+    for gain in gains {
+        let color = if gain.gain > 0.0 {
+            Color::Green
+        } else if gain.gain < 0.0 {
+            Color::Red
+        } else {
+            Color::DarkGray
+        };
+        spans.push(Span::styled(
+            format!(" {} ", gain.category),
+            Style::default().fg(Color::White),
+        ));
+        spans.push(Span::styled(gain_bar(gain.relative), Style::default().fg(color)));
+        spans.push(Span::styled(
+            format!(" {}", format_gain(gain.gain)),
+            Style::default().fg(color),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Build the standings line: my projected rank before and after buying
+/// the player, for the categories it changes.
+fn build_standings_line<'a>(shifts: &[StandingsShift]) -> Line<'a> {
+    let mut spans = vec![Span::styled(" Standings:", Style::default().fg(Color::Gray))];
+    let moved: Vec<&StandingsShift> = shifts.iter().filter(|s| s.after != s.before).collect();
+    if moved.is_empty() {
+        spans.push(Span::styled(" no change", Style::default().fg(Color::DarkGray)));
+    }
+    for shift in moved {
+        let color = if shift.after < shift.before { Color::Green } else { Color::Red };
+        spans.push(Span::styled(
+            format!(" {} ", shift.category),
+            Style::default().fg(Color::White),
+        ));
+        spans.push(Span::styled(
+            format!("{}\u{2192}{}", ordinal(shift.before), ordinal(shift.after)),
+            Style::default().fg(color),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// `1st`, `2nd`, `3rd`, `4th`, ... `11th`, `12th`, `13th`, `21st`.
+pub fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+/// Render a mini bar for a relative gain. Twice the typical gain (or more)
+/// fills the bar; the sign is carried by color, not by the bar.
+pub fn gain_bar(relative: f64) -> String {
+    let filled = (relative.abs().min(2.0) / 2.0 * GAIN_BAR_WIDTH as f64).round() as usize;
+    format!(
+        "{}{}",
+        "\u{2588}".repeat(filled),
+        "\u{2591}".repeat(GAIN_BAR_WIDTH - filled)
+    )
+}
+
+/// Format a category gain with a sign: whole numbers for counting stats,
+/// three decimals (without the leading zero) for small rate-stat changes.
+pub fn format_gain(gain: f64) -> String {
+    if gain.abs() >= 1.0 {
+        format!("{:+.0}", gain)
+    } else {
+        format!("{:+.3}", gain).replacen("0.", ".", 1)
+    }
+}
+
 /// Format a u32 dollar value as "$X".
 pub fn format_dollar(value: u32) -> String {
     format!("${}", value)
@@ -193,7 +379,7 @@ mod tests {
             time_remaining: Some(30),
             eligible_slots: vec![],
         };
-        let lines = build_nomination_lines(&nom, None);
+        let lines = build_nomination_lines(&nom, None, None, None);
         assert_eq!(lines.len(), 2);
     }
 
@@ -213,9 +399,170 @@ mod tests {
             dollar_value: 42.0,
             adjusted_value: 45.5,
             verdict: InstantVerdict::StrongTarget,
+            roster_block: None,
+            category_gains: vec![],
+            standings: vec![],
         };
-        let lines = build_nomination_lines(&nom, Some(&analysis));
+        let lines = build_nomination_lines(&nom, Some(&analysis), None, None);
         assert_eq!(lines.len(), 2);
+
+        // A player I can't roster says why he is a pass.
+        let analysis = InstantAnalysis {
+            verdict: InstantVerdict::Pass,
+            roster_block: Some("no open slot".to_string()),
+            ..analysis
+        };
+        let lines = build_nomination_lines(&nom, Some(&analysis), None, None);
+        assert!(lines[1].to_string().contains("PASS (no open slot)"), "got: {}", lines[1]);
+    }
+
+    #[test]
+    fn build_nomination_lines_with_category_gains() {
+        let nom = NominationInfo {
+            player_name: "Mike Trout".to_string(),
+            position: "CF".to_string(),
+            nominated_by: "Team Alpha".to_string(),
+            current_bid: 45,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        };
+        let gain = |category: &str, gain: f64, relative: f64| CategoryGain {
+            category: category.to_string(),
+            without: 0.0,
+            with: gain,
+            gain,
+            relative,
+        };
+        let analysis = InstantAnalysis {
+            player_name: "Mike Trout".to_string(),
+            dollar_value: 42.0,
+            adjusted_value: 45.5,
+            verdict: InstantVerdict::StrongTarget,
+            roster_block: None,
+            category_gains: vec![gain("HR", 38.0, 1.5), gain("AVG", -0.004, -0.5)],
+            standings: vec![],
+        };
+        let lines = build_nomination_lines(&nom, Some(&analysis), None, None);
+        assert_eq!(lines.len(), 3);
+
+        let text = lines[2].to_string();
+        assert!(text.contains("HR \u{2588}\u{2588}\u{2588}\u{2591} +38"), "got: {text}");
+        assert!(text.contains("AVG \u{2588}\u{2591}\u{2591}\u{2591} -.004"), "got: {text}");
+        let avg_bar = lines[2].spans.iter().find(|s| s.content.contains('\u{2588}') && s.style.fg == Some(Color::Red));
+        assert!(avg_bar.is_some(), "negative gains are drawn in red");
+    }
+
+    #[test]
+    fn build_nomination_lines_show_standings_moves() {
+        let nom = NominationInfo {
+            player_name: "Speedster".to_string(),
+            position: "CF".to_string(),
+            nominated_by: "Team Alpha".to_string(),
+            current_bid: 8,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        };
+        let shift = |category: &str, before: usize, after: usize| StandingsShift {
+            category: category.to_string(),
+            before,
+            after,
+        };
+        let mut analysis = InstantAnalysis {
+            player_name: "Speedster".to_string(),
+            dollar_value: 10.0,
+            adjusted_value: 11.0,
+            verdict: InstantVerdict::ConditionalTarget,
+            roster_block: None,
+            category_gains: vec![],
+            standings: vec![shift("SB", 7, 4), shift("HR", 5, 5), shift("AVG", 2, 3)],
+        };
+        let lines = build_nomination_lines(&nom, Some(&analysis), None, None);
+        let text = lines[2].to_string();
+        assert_eq!(text, " Standings: SB 7th\u{2192}4th AVG 2nd\u{2192}3rd");
+
+        analysis.standings = vec![shift("HR", 5, 5)];
+        let lines = build_nomination_lines(&nom, Some(&analysis), None, None);
+        assert_eq!(lines[2].to_string(), " Standings: no change");
+
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(12), "12th");
+        assert_eq!(ordinal(22), "22nd");
+    }
+
+    #[test]
+    fn build_nomination_lines_show_bid_intent() {
+        let nom = NominationInfo {
+            player_name: "Mike Trout".to_string(),
+            position: "CF".to_string(),
+            nominated_by: "Team Alpha".to_string(),
+            current_bid: 17,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        };
+        let intent = BidIntent {
+            player_name: "Mike Trout".to_string(),
+            interested: Some(true),
+            bid: Some(18),
+            cap: Some(24),
+        };
+        let lines = build_nomination_lines(&nom, None, Some(&intent), None);
+        let text = lines[1].to_string();
+        assert!(text.ends_with("| You: in at $18, cap $24"), "got: {text}");
+
+        // Past my cap: drawn in red.
+        let over = NominationInfo { current_bid: 25, ..nom.clone() };
+        let lines = build_nomination_lines(&over, None, Some(&intent), None);
+        let span = lines[1].spans.last().unwrap();
+        assert_eq!(span.style.fg, Some(Color::Red));
+
+        // An intent left over from another player is ignored.
+        let other = BidIntent { player_name: "Someone Else".to_string(), ..intent };
+        let lines = build_nomination_lines(&nom, None, Some(&other), None);
+        assert!(!lines[1].to_string().contains("You:"));
+    }
+
+    #[test]
+    fn build_nomination_lines_show_ladder_under_the_bid() {
+        let nom = NominationInfo {
+            player_name: "Mike Trout".to_string(),
+            position: "CF".to_string(),
+            nominated_by: "Team Alpha".to_string(),
+            current_bid: 12,
+            current_bidder: None,
+            time_remaining: None,
+            eligible_slots: vec![],
+        };
+        let ladder = crate::draft::ladder::bid_ladder(12, 1, &[60, 45, 30, 25, 12, 5], 40, &[2, 1]);
+        let lines = build_nomination_lines(&nom, None, None, Some(&ladder));
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[2].to_string(),
+            " Ladder: next $13 | jump $31 prices out 2 | 2 left at $31 | 1 left at $46"
+        );
+
+        // Nobody to price out: just the next bid.
+        let ladder = crate::draft::ladder::bid_ladder(12, 1, &[60], 40, &[2, 1]);
+        let lines = build_nomination_lines(&nom, None, None, Some(&ladder));
+        assert_eq!(lines[2].to_string(), " Ladder: next $13");
+    }
+
+    #[test]
+    fn gain_bar_scales_and_caps() {
+        assert_eq!(gain_bar(0.0), "\u{2591}\u{2591}\u{2591}\u{2591}");
+        assert_eq!(gain_bar(1.0), "\u{2588}\u{2588}\u{2591}\u{2591}");
+        assert_eq!(gain_bar(-1.0), "\u{2588}\u{2588}\u{2591}\u{2591}");
+        assert_eq!(gain_bar(5.0), "\u{2588}\u{2588}\u{2588}\u{2588}");
+    }
+
+    #[test]
+    fn format_gain_counting_and_rate() {
+        assert_eq!(format_gain(25.0), "+25");
+        assert_eq!(format_gain(-3.4), "-3");
+        assert_eq!(format_gain(0.0123), "+.012");
+        assert_eq!(format_gain(-0.25), "-.250");
     }
 
     #[test]
@@ -223,7 +570,7 @@ mod tests {
         let backend = ratatui::backend::TestBackend::new(80, 6);
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
         terminal
-            .draw(|frame| render(frame, frame.area(), None, None))
+            .draw(|frame| render(frame, frame.area(), None, None, None, None, None))
             .unwrap();
     }
 
@@ -241,7 +588,25 @@ mod tests {
             eligible_slots: vec![],
         };
         terminal
-            .draw(|frame| render(frame, frame.area(), Some(&nom), None))
+            .draw(|frame| render(frame, frame.area(), Some(&nom), None, None, None, None))
             .unwrap();
     }
+
+    #[test]
+    fn build_crash_lines_mentions_pick_and_file() {
+        let report = CrashReport {
+            id: 1,
+            occurred_at: "2026-03-01T20:15:00Z".to_string(),
+            draft_id: None,
+            pick_count: Some(87),
+            message: "boom".to_string(),
+            location: None,
+            backtrace: String::new(),
+            crash_file: Some("/tmp/crash-20260301-201500.txt".to_string()),
+        };
+        let lines = build_crash_lines(&report);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].to_string().contains("crashed at pick 87"));
+        assert!(lines[1].to_string().contains("/tmp/crash-20260301-201500.txt"));
+    }
 }
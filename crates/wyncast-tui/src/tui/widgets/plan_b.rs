@@ -0,0 +1,126 @@
+// Plan B widget: substitutes for the player I was just outbid on.
+//
+// A strip under the nomination plan, shown only after a player I was in on
+// goes to another team and until I buy a player. The first line says who
+// was lost, to whom and for how much; then the best players left at their
+// position, with how far each drops from the lost player's tier.
+
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use crate::draft::plan_b::{PlanB, Substitute, SUBSTITUTES};
+use super::money::MoneyFormat;
+
+/// Rows the strip takes, borders included.
+pub fn height(plan_b: &PlanB) -> u16 {
+    2 + 1 + plan_b.substitutes.len().clamp(1, SUBSTITUTES) as u16
+}
+
+/// Render the strip into the given area.
+pub fn render(frame: &mut Frame, area: Rect, plan_b: &PlanB, money: MoneyFormat) {
+    let paragraph = Paragraph::new(build_lines(plan_b, money)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title("Plan B"),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// The lost player, then one line per substitute.
+fn build_lines(plan_b: &PlanB, money: MoneyFormat) -> Vec<Line<'static>> {
+    let lost = &plan_b.lost;
+    let mut headline = format!(
+        " Lost {} to {} at {}",
+        lost.player_name,
+        lost.winner,
+        money.amount(f64::from(lost.price))
+    );
+    if let Some(bid) = lost.my_bid {
+        headline.push_str(&format!(" (you {})", money.amount(f64::from(bid))));
+    }
+    let mut lines = vec![Line::from(Span::styled(headline, Style::default().fg(Color::Yellow)))];
+
+    if plan_b.substitutes.is_empty() {
+        let position = lost.position.map_or("the position", |p| p.display_str());
+        lines.push(Line::from(Span::styled(
+            format!("  Nobody left at {position}."),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for substitute in &plan_b.substitutes {
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {} ", substitute.name)),
+            Span::styled(money.amount(substitute.dollar_value), Style::default().fg(Color::Cyan)),
+            Span::styled(format!(" {}", tier_label(substitute)), tier_style(substitute)),
+        ]));
+    }
+    lines
+}
+
+fn tier_label(substitute: &Substitute) -> String {
+    match substitute.tiers_down {
+        0 => "same tier".to_string(),
+        1 => "1 tier down".to_string(),
+        n => format!("{n} tiers down"),
+    }
+}
+
+/// Green for a like-for-like swap, gray for a step down.
+fn tier_style(substitute: &Substitute) -> Style {
+    if substitute.tiers_down == 0 {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::Position;
+    use crate::draft::plan_b::OutbidPlayer;
+
+    fn plan(substitutes: Vec<Substitute>) -> PlanB {
+        PlanB {
+            lost: OutbidPlayer {
+                player_name: "Star".into(),
+                position: Some(Position::ShortStop),
+                dollar_value: 30.0,
+                winner: "Team 2".into(),
+                price: 34,
+                my_bid: Some(31),
+            },
+            substitutes,
+        }
+    }
+
+    fn substitute(name: &str, dollar_value: f64, tiers_down: usize) -> Substitute {
+        Substitute { name: name.into(), dollar_value, tiers_down }
+    }
+
+    #[test]
+    fn lines_name_the_loss_and_the_substitutes() {
+        let plan_b = plan(vec![substitute("Close", 28.5, 0), substitute("Drop", 24.0, 2)]);
+        let lines = build_lines(&plan_b, MoneyFormat::new(260, false));
+        assert_eq!(lines[0].to_string(), " Lost Star to Team 2 at $34 (you $31)");
+        assert_eq!(lines[1].to_string(), "  Close $28 same tier");
+        assert_eq!(lines[2].to_string(), "  Drop $24 2 tiers down");
+        assert_eq!(height(&plan_b), 5);
+    }
+
+    #[test]
+    fn empty_plan_b_says_nobody_is_left() {
+        let plan_b = plan(vec![]);
+        let lines = build_lines(&plan_b, MoneyFormat::new(260, false));
+        assert_eq!(lines[1].to_string(), "  Nobody left at SS.");
+        assert_eq!(height(&plan_b), 4);
+    }
+}
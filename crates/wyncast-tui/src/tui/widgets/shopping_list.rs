@@ -0,0 +1,159 @@
+// Shopping list widget: my open roster slots at a glance.
+//
+// One row per open slot position: how many are open, the suggested spend
+// per slot, and how many players above replacement are left to fill them.
+// The player count turns red when there are fewer players than open slots,
+// yellow when fewer than two per slot. A position running short across the
+// league gets a flag with the number of teams still needing one.
+
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use crate::draft::roster_holes::{HoleAlert, RosterHole};
+use crate::draft::shopping_list::SlotNeed;
+use super::focused_border_style;
+
+/// Render the shopping list into the given area.
+///
+/// When `focused` is true, the border is highlighted in cyan to indicate this
+/// panel has keyboard focus for scroll routing.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    needs: &[SlotNeed],
+    holes: &[RosterHole],
+    scroll_offset: usize,
+    focused: bool,
+) {
+    let lines = build_lines(needs, holes);
+    let visible_rows = (area.height as usize).saturating_sub(2);
+    let max_offset = lines.len().saturating_sub(visible_rows);
+    let scroll = scroll_offset.min(max_offset) as u16;
+
+    let border = focused_border_style(focused, Style::default());
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(border)
+                .title("Shopping List"),
+        )
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+/// Build one line per open slot position.
+fn build_lines(needs: &[SlotNeed], holes: &[RosterHole]) -> Vec<Line<'static>> {
+    if needs.is_empty() {
+        return vec![Line::from(Span::styled(
+            "  Roster full.",
+            Style::default().fg(Color::DarkGray),
+        ))];
+    }
+    needs
+        .iter()
+        .map(|need| {
+            let mut spans = vec![
+                Span::styled(
+                    format!(" {:>4} x{:<2}", need.position.display_str(), need.open),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    format!(" ${:>3.0}/slot", need.budget_per_slot),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    format!(" {:>3} left", need.viable),
+                    Style::default().fg(viable_color(need)),
+                ),
+            ];
+            if let Some(hole) = holes.iter().find(|h| h.position == need.position) {
+                spans.push(Span::styled(
+                    format!(" !{} need", hole.teams_needing),
+                    alert_style(hole.alert),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Red when the players left can't fill the open slots, yellow when
+/// there are fewer than two per slot.
+fn viable_color(need: &SlotNeed) -> Color {
+    if need.viable < need.open {
+        Color::Red
+    } else if need.viable < 2 * need.open {
+        Color::Yellow
+    } else {
+        Color::Gray
+    }
+}
+
+/// Yellow to watch, red once there is no spare, bold red once some team
+/// will go without.
+fn alert_style(alert: HoleAlert) -> Style {
+    match alert {
+        HoleAlert::Watch => Style::default().fg(Color::Yellow),
+        HoleAlert::Warning => Style::default().fg(Color::Red),
+        HoleAlert::Panic => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft::pick::Position;
+
+    fn need(position: Position, open: usize, budget_per_slot: f64, viable: usize) -> SlotNeed {
+        SlotNeed { position, open, budget_per_slot, viable }
+    }
+
+    #[test]
+    fn lines_show_open_slots_budget_and_players_left() {
+        let needs = [
+            need(Position::ShortStop, 1, 23.4, 1),
+            need(Position::StartingPitcher, 3, 9.0, 2),
+            need(Position::Bench, 2, 1.0, 40),
+        ];
+        let holes = [RosterHole {
+            position: Position::ShortStop,
+            teams_needing: 3,
+            players_left: 1,
+            alert: HoleAlert::Panic,
+        }];
+        let lines = build_lines(&needs, &holes);
+        assert_eq!(lines[0].to_string(), "   SS x1  $ 23/slot   1 left !3 need");
+        assert_eq!(lines[1].to_string(), "   SP x3  $  9/slot   2 left");
+
+        let count_color = |line: &Line| line.spans[2].style.fg;
+        assert_eq!(count_color(&lines[0]), Some(Color::Yellow));
+        assert_eq!(count_color(&lines[1]), Some(Color::Red));
+        assert_eq!(count_color(&lines[2]), Some(Color::Gray));
+
+        assert_eq!(lines[0].spans[3].style, alert_style(HoleAlert::Panic));
+        assert_eq!(lines[1].spans.len(), 3, "no flag without a hole");
+
+        assert_eq!(build_lines(&[], &[])[0].to_string(), "  Roster full.");
+    }
+
+    #[test]
+    fn render_does_not_panic() {
+        let needs = [need(Position::Catcher, 1, 5.0, 3)];
+        for (w, h) in [(40, 10), (10, 2)] {
+            let backend = ratatui::backend::TestBackend::new(w, h);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| render(frame, frame.area(), &needs, &[], 5, true))
+                .unwrap();
+        }
+    }
+}
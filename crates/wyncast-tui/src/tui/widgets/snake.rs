@@ -0,0 +1,177 @@
+// Snake draft widgets: the round/pick displays that stand in for the
+// nomination banner and the budget panel when `league.draft_type = "snake"`.
+//
+// Clock (in the banner's place):
+// Line 1: "ON THE CLOCK: Team 3 -- round 5 of 26, pick 3 (#43)"
+// Line 2: "Your next pick: #48, in 5 picks"
+// Line 3: "Best available: 1. Star (SS)  2. Ace (SP) ..."
+//
+// Draft position (in the budget panel's place):
+// Round, Pick, On the clock, My next pick
+
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use crate::draft::snake::{SnakeStatus, SnakeTurn};
+use super::focused_border_style;
+
+/// Render who is on the clock, in place of the nomination banner.
+pub fn render_clock(frame: &mut Frame, area: Rect, status: &SnakeStatus) {
+    let mine = status.turn.as_ref().and_then(SnakeTurn::my_turn_in) == Some(0);
+    let paragraph = Paragraph::new(build_clock_lines(status)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Snake Draft")
+            .border_style(Style::default().fg(if mine { Color::Green } else { Color::Yellow })),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Render my place in the draft, in place of the budget panel.
+pub fn render_position(frame: &mut Frame, area: Rect, status: &SnakeStatus, scroll_offset: usize, focused: bool) {
+    let lines = build_position_lines(status);
+    let visible_rows = (area.height as usize).saturating_sub(2);
+    let scroll = scroll_offset.min(lines.len().saturating_sub(visible_rows)) as u16;
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(focused_border_style(focused, Style::default()))
+                .title("Draft"),
+        )
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+/// "round 5 of 26, pick 3 (#43)".
+fn format_pick(turn: &SnakeTurn) -> String {
+    format!("round {} of {}, pick {} (#{})", turn.round, turn.rounds, turn.pick_in_round, turn.overall)
+}
+
+/// "#48, in 5 picks", "#44, next", or "done".
+fn format_next_pick(turn: &SnakeTurn) -> String {
+    match (turn.my_next_pick, turn.my_turn_in()) {
+        (Some(_), Some(0)) => "now".to_string(),
+        (Some(pick), Some(1)) => format!("#{pick}, next"),
+        (Some(pick), Some(turns)) => format!("#{pick}, in {turns} picks"),
+        _ => "done".to_string(),
+    }
+}
+
+/// Build the clock lines.
+fn build_clock_lines(status: &SnakeStatus) -> Vec<Line<'static>> {
+    let Some(turn) = &status.turn else {
+        return vec![Line::from(Span::styled(
+            "  No pick on the clock",
+            Style::default().fg(Color::DarkGray).add_modifier(Modifier::DIM),
+        ))];
+    };
+
+    let mut lines = Vec::new();
+    let (label, team, color) = if turn.my_turn_in() == Some(0) {
+        (" YOU'RE ON THE CLOCK", String::new(), Color::Green)
+    } else {
+        (" ON THE CLOCK: ", turn.team.clone(), Color::Yellow)
+    };
+    lines.push(Line::from(vec![
+        Span::styled(label, Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        Span::styled(team, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+        Span::styled(format!(" -- {}", format_pick(turn)), Style::default().fg(Color::Gray)),
+    ]));
+
+    if turn.my_turn_in() != Some(0) {
+        lines.push(Line::from(vec![
+            Span::styled(" Your next pick: ", Style::default().fg(Color::Gray)),
+            Span::styled(format_next_pick(turn), Style::default().fg(Color::Cyan)),
+        ]));
+    }
+
+    if !status.board.is_empty() {
+        let mut spans = vec![Span::styled(" Best available:", Style::default().fg(Color::Gray))];
+        for entry in &status.board {
+            spans.push(Span::styled(format!(" {}. ", entry.rank), Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled(
+                format!("{} ({})", entry.name, entry.positions),
+                Style::default().fg(Color::White),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Build the draft position lines.
+fn build_position_lines(status: &SnakeStatus) -> Vec<Line<'static>> {
+    let row = |label: &'static str, value: String, color: Color| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Gray)),
+            Span::styled(value, Style::default().fg(color)),
+        ])
+    };
+    let Some(turn) = &status.turn else {
+        return vec![row(" Round:     ", "-".to_string(), Color::DarkGray)];
+    };
+    vec![
+        row(" Round:     ", format!("{} / {}", turn.round, turn.rounds), Color::White),
+        row(" Pick:      ", format!("{} (#{} overall)", turn.pick_in_round, turn.overall), Color::White),
+        row(" On clock:  ", turn.team.clone(), Color::White),
+        Line::from(vec![
+            Span::styled(" Next pick: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format_next_pick(turn),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::valuation::draft_board::BoardEntry;
+
+    fn status(overall: usize, my_next_pick: Option<usize>) -> SnakeStatus {
+        SnakeStatus {
+            turn: Some(SnakeTurn {
+                round: 5,
+                rounds: 26,
+                pick_in_round: 3,
+                overall,
+                team: "Team 3".into(),
+                my_next_pick,
+            }),
+            board: vec![BoardEntry { rank: 1, name: "Star".into(), positions: "SS".into(), vor: 4.0 }],
+        }
+    }
+
+    fn text(lines: Vec<Line>) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn clock_shows_the_pick_and_my_next_one() {
+        let lines = text(build_clock_lines(&status(43, Some(48))));
+        assert_eq!(lines[0], " ON THE CLOCK: Team 3 -- round 5 of 26, pick 3 (#43)");
+        assert_eq!(lines[1], " Your next pick: #48, in 5 picks");
+        assert_eq!(lines[2], " Best available: 1. Star (SS)");
+
+        let lines = text(build_clock_lines(&status(43, Some(43))));
+        assert_eq!(lines[0], " YOU'RE ON THE CLOCK -- round 5 of 26, pick 3 (#43)");
+        assert_eq!(lines.len(), 2);
+
+        let done = SnakeStatus { turn: None, board: vec![] };
+        assert_eq!(text(build_clock_lines(&done)), ["  No pick on the clock"]);
+    }
+
+    #[test]
+    fn position_replaces_the_budget() {
+        let lines = text(build_position_lines(&status(43, Some(44))));
+        assert_eq!(lines[0], " Round:     5 / 26");
+        assert_eq!(lines[1], " Pick:      3 (#43 overall)");
+        assert_eq!(lines[3], " Next pick: #44, next");
+        assert_eq!(text(build_position_lines(&status(43, None)))[3], " Next pick: done");
+    }
+}
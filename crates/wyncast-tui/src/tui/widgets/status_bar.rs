@@ -1,22 +1,41 @@
 // Status bar widget: connection status, draft progress, tab indicator.
 
+use std::time::Duration;
+
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
-use crate::protocol::{ConnectionStatus, TabId};
+use crate::draft::nomination_order::NominationTurn;
+use crate::protocol::{ConnectionStatus, TabId, WsServerStatus};
 
 /// Render the status bar into the given area.
 ///
-/// Layout: [connection indicator] [pick counter] [tab bar]
+/// Layout: [connection indicator] [server port] [extension latency]
+/// [slow render] [recalc snooze] [UI desync] [pick counter] [nomination turn] [tab bar]
+/// [file notice]
+///
+/// `extension_latency` is the last round trip to the extension (ms) and how
+/// long ago it was measured. `render_load` is the average frame time while
+/// the render loop is over budget. `recalc_snoozed` counts the picks not
+/// yet priced in while recalculation is snoozed. `ui_desynced` counts the
+/// updates from the app that never arrived, until a resync.
+#[allow(clippy::too_many_arguments)]
 pub fn render(
     frame: &mut Frame,
     area: Rect,
     connection_status: ConnectionStatus,
+    ws_server: Option<&WsServerStatus>,
+    extension_latency: Option<(u64, Duration)>,
+    render_load: Option<Duration>,
+    recalc_snoozed: Option<usize>,
+    ui_desynced: Option<usize>,
+    file_notice: Option<&Result<String, String>>,
     pick_number: usize,
     total_picks: usize,
+    nomination_turn: Option<&NominationTurn>,
     active_tab: TabId,
     llm_configured: bool,
 ) {
@@ -29,12 +48,48 @@ pub fn render(
         Style::default().fg(dot_color),
     ));
 
+    // Port the extension should connect to (or that the server is down)
+    if let Some(span) = ws_server.map(server_span) {
+        spans.push(span);
+        spans.push(Span::raw(" "));
+    }
+
+    // Heartbeat round trip, to tell scraping lag from app lag
+    if let Some((rtt_ms, age)) = extension_latency {
+        spans.push(latency_span(rtt_ms, age));
+        spans.push(Span::raw(" "));
+    }
+
+    // Frames are taking longer than the render budget
+    if let Some(average) = render_load {
+        spans.push(render_load_span(average));
+        spans.push(Span::raw(" "));
+    }
+
+    // Recalculation snoozed for a pick burst
+    if let Some(picks) = recalc_snoozed {
+        spans.push(recalc_snooze_span(picks));
+        spans.push(Span::raw(" "));
+    }
+
+    // Updates from the app were lost: the screen may be stale
+    if let Some(lost) = ui_desynced {
+        spans.push(ui_desync_span(lost));
+        spans.push(Span::raw(" "));
+    }
+
     // Pick counter
     spans.push(Span::styled(
         format!("Pick {}/{}", pick_number, total_picks),
         Style::default().fg(Color::White),
     ));
 
+    // Who nominates next, and when my turn comes
+    if let Some(turn) = nomination_turn {
+        spans.push(Span::styled(" | ", Style::default().fg(Color::Gray)));
+        spans.push(nomination_turn_span(turn));
+    }
+
     // Separator
     spans.push(Span::styled(" | ", Style::default().fg(Color::Gray)));
 
@@ -55,6 +110,12 @@ pub fn render(
         ));
     }
 
+    // Where the last requested file went (Ctrl+D, Ctrl+E)
+    if let Some(notice) = file_notice {
+        spans.push(Span::styled(" | ", Style::default().fg(Color::Gray)));
+        spans.push(file_notice_span(notice));
+    }
+
     let paragraph = Paragraph::new(Line::from(spans))
         .style(Style::default().bg(Color::Black));
     frame.render_widget(paragraph, area);
@@ -68,6 +129,106 @@ pub fn connection_indicator(status: ConnectionStatus) -> (&'static str, Color) {
     }
 }
 
+/// The WebSocket server's port, highlighted when it is a fallback port
+/// (the extension must be pointed at it), or a warning when nothing bound.
+pub fn server_span(status: &WsServerStatus) -> Span<'static> {
+    match status {
+        WsServerStatus::Listening { port, .. } if status.is_fallback() => {
+            Span::styled(format!(":{port}"), Style::default().fg(Color::Yellow))
+        }
+        WsServerStatus::Listening { port, .. } => {
+            Span::styled(format!(":{port}"), Style::default().fg(Color::DarkGray))
+        }
+        WsServerStatus::BindFailed { .. } => Span::styled(
+            "WS offline",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+    }
+}
+
+/// Round trips above this are shown in yellow.
+const SLOW_RTT_MS: u64 = 250;
+
+/// A measurement older than this means heartbeats have stopped arriving
+/// (they are sent every 5s), so it is shown in red.
+const STALE_HEARTBEAT: Duration = Duration::from_secs(15);
+
+/// The extension's round-trip latency and age, e.g. `ext: 120ms / 3s ago`.
+pub fn latency_span(rtt_ms: u64, age: Duration) -> Span<'static> {
+    let secs = age.as_secs();
+    let ago = if secs < 60 {
+        format!("{secs}s ago")
+    } else {
+        format!("{}m ago", secs / 60)
+    };
+    let color = if age > STALE_HEARTBEAT {
+        Color::Red
+    } else if rtt_ms > SLOW_RTT_MS {
+        Color::Yellow
+    } else {
+        Color::DarkGray
+    };
+    Span::styled(format!("ext: {rtt_ms}ms / {ago}"), Style::default().fg(color))
+}
+
+/// Warning shown while frames take longer than the render budget.
+pub fn render_load_span(average: Duration) -> Span<'static> {
+    Span::styled(
+        format!("SLOW RENDER {}ms", average.as_millis()),
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+    )
+}
+
+/// Warning shown while recalculation is snoozed, with the picks waiting.
+pub fn recalc_snooze_span(picks: usize) -> Span<'static> {
+    let label = match picks {
+        0 => "RECALC SNOOZED".to_string(),
+        1 => "RECALC SNOOZED (1 pick)".to_string(),
+        n => format!("RECALC SNOOZED ({n} picks)"),
+    };
+    Span::styled(label, Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD))
+}
+
+/// The persistent warning that the screen has missed updates.
+pub fn ui_desync_span(lost: usize) -> Span<'static> {
+    let updates = if lost == 1 { "1 update".to_string() } else { format!("{lost} updates") };
+    Span::styled(
+        format!("UI DESYNCED ({updates} lost) — press r to force resync"),
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+    )
+}
+
+/// Where the last requested file was written (green), or why it could not
+/// be (red).
+pub fn file_notice_span(notice: &Result<String, String>) -> Span<'static> {
+    match notice {
+        Ok(message) => Span::styled(message.clone(), Style::default().fg(Color::Green)),
+        Err(message) => Span::styled(message.clone(), Style::default().fg(Color::Red)),
+    }
+}
+
+/// My turn is highlighted this many nominations ahead.
+const SOON_TURNS: usize = 2;
+
+/// Who nominates next and how far off my turn is, e.g.
+/// `Nom: Team 3 (you in 4)`; `Nom: YOU` when I am next.
+pub fn nomination_turn_span(turn: &NominationTurn) -> Span<'static> {
+    match turn.my_turn_in {
+        Some(0) => Span::styled(
+            "Nom: YOU",
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+        ),
+        Some(n) => Span::styled(
+            format!("Nom: {} (you in {n})", turn.next_team),
+            Style::default().fg(if n <= SOON_TURNS { Color::Yellow } else { Color::White }),
+        ),
+        None => Span::styled(
+            format!("Nom: {}", turn.next_team),
+            Style::default().fg(Color::DarkGray),
+        ),
+    }
+}
+
 /// Build tab indicator spans with descriptive labels and active tab highlighted.
 /// E.g. "[1:Analysis] [2:Players] [3:Log] [4:Teams]"
 pub fn tab_spans(active: TabId) -> Vec<Span<'static>> {
@@ -126,6 +287,81 @@ mod tests {
         assert_eq!(color, Color::Red);
     }
 
+    #[test]
+    fn server_span_shows_port_and_flags_fallback() {
+        let normal = WsServerStatus::Listening { port: 9001, requested_port: 9001 };
+        let span = server_span(&normal);
+        assert_eq!(span.content, ":9001");
+        assert_eq!(span.style.fg, Some(Color::DarkGray));
+
+        let fallback = WsServerStatus::Listening { port: 9003, requested_port: 9001 };
+        let span = server_span(&fallback);
+        assert_eq!(span.content, ":9003");
+        assert_eq!(span.style.fg, Some(Color::Yellow));
+
+        let failed = WsServerStatus::BindFailed { requested_port: 9001, error: "in use".into() };
+        assert_eq!(server_span(&failed).content, "WS offline");
+    }
+
+    #[test]
+    fn latency_span_shows_rtt_and_age() {
+        let span = latency_span(120, Duration::from_secs(3));
+        assert_eq!(span.content, "ext: 120ms / 3s ago");
+        assert_eq!(span.style.fg, Some(Color::DarkGray));
+
+        assert_eq!(latency_span(900, Duration::from_secs(1)).style.fg, Some(Color::Yellow));
+
+        let stale = latency_span(120, Duration::from_secs(125));
+        assert_eq!(stale.content, "ext: 120ms / 2m ago");
+        assert_eq!(stale.style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn render_load_span_shows_average_frame_time() {
+        let span = render_load_span(Duration::from_millis(48));
+        assert_eq!(span.content, "SLOW RENDER 48ms");
+        assert_eq!(span.style.fg, Some(Color::Yellow));
+    }
+
+    #[test]
+    fn recalc_snooze_span_counts_waiting_picks() {
+        assert_eq!(recalc_snooze_span(0).content, "RECALC SNOOZED");
+        assert_eq!(recalc_snooze_span(1).content, "RECALC SNOOZED (1 pick)");
+        assert_eq!(recalc_snooze_span(4).content, "RECALC SNOOZED (4 picks)");
+    }
+
+    #[test]
+    fn ui_desync_span_asks_for_a_resync() {
+        assert_eq!(ui_desync_span(1).content, "UI DESYNCED (1 update lost) — press r to force resync");
+        assert_eq!(ui_desync_span(7).style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn nomination_turn_span_shows_how_far_off_my_turn_is() {
+        let turn = |my_turn_in| NominationTurn { next_team: "Team 3".into(), my_turn_in };
+        let mine = nomination_turn_span(&turn(Some(0)));
+        assert_eq!(mine.content, "Nom: YOU");
+        assert_eq!(mine.style.fg, Some(Color::Green));
+
+        let soon = nomination_turn_span(&turn(Some(2)));
+        assert_eq!(soon.content, "Nom: Team 3 (you in 2)");
+        assert_eq!(soon.style.fg, Some(Color::Yellow));
+        assert_eq!(nomination_turn_span(&turn(Some(5))).style.fg, Some(Color::White));
+
+        assert_eq!(nomination_turn_span(&turn(None)).content, "Nom: Team 3");
+    }
+
+    #[test]
+    fn file_notice_span_colors_success_and_failure() {
+        let ok = file_notice_span(&Ok("State dumped to /tmp/logs/support.json".into()));
+        assert_eq!(ok.content, "State dumped to /tmp/logs/support.json");
+        assert_eq!(ok.style.fg, Some(Color::Green));
+
+        let err = file_notice_span(&Err("State dump failed: permission denied".into()));
+        assert_eq!(err.content, "State dump failed: permission denied");
+        assert_eq!(err.style.fg, Some(Color::Red));
+    }
+
     #[test]
     fn tab_spans_highlight_active() {
         let spans = tab_spans(TabId::Available);
@@ -169,8 +405,15 @@ mod tests {
                     frame,
                     frame.area(),
                     ConnectionStatus::Disconnected,
+                    None,
+                    None,
+                    None,
+                    Some(3),
+                    None,
+                    None,
                     0,
                     0,
+                    None,
                     TabId::Analysis,
                     false,
                 )
@@ -188,8 +431,15 @@ mod tests {
                     frame,
                     frame.area(),
                     ConnectionStatus::Disconnected,
+                    None,
+                    None,
+                    None,
+                    Some(3),
+                    None,
+                    None,
                     0,
                     0,
+                    None,
                     TabId::Analysis,
                     true,
                 )
@@ -207,8 +457,15 @@ mod tests {
                     frame,
                     frame.area(),
                     ConnectionStatus::Disconnected,
+                    None,
+                    None,
+                    None,
+                    Some(3),
+                    None,
+                    None,
                     0,
                     0,
+                    None,
                     TabId::Analysis,
                     false,
                 )
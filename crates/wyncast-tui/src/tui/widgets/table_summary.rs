@@ -0,0 +1,59 @@
+// Summary row pinned above a table's column header.
+//
+// Tables that scroll (Available, Teams) show aggregates for the rows they
+// currently hold on a fixed line under the top border, so the totals stay
+// visible however far the table is scrolled.
+
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+
+/// Separator between summary items.
+const SEPARATOR: &str = " \u{b7} ";
+
+/// Draw `block` over `area` with `summary` on its first inner line, and return
+/// the area below the summary for the table itself (header included).
+pub fn render(frame: &mut Frame, area: Rect, block: Block, summary: Line) -> Rect {
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [summary_area, table_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(inner);
+    frame.render_widget(Paragraph::new(summary), summary_area);
+    table_area
+}
+
+/// Join summary items into one dimmed line, e.g. `12 teams · $1830 left`.
+pub fn line(items: Vec<String>) -> Line<'static> {
+    let text = items.join(SEPARATOR);
+    Line::from(Span::styled(format!(" {text}"), Style::default().fg(Color::DarkGray)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::Borders;
+
+    #[test]
+    fn line_joins_items() {
+        let line = line(vec!["3 players".into(), "$42 total".into()]);
+        assert_eq!(line.to_string(), " 3 players \u{b7} $42 total");
+    }
+
+    #[test]
+    fn render_reserves_first_inner_line() {
+        let backend = ratatui::backend::TestBackend::new(40, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut table_area = Rect::default();
+        terminal
+            .draw(|frame| {
+                let block = Block::default().borders(Borders::ALL);
+                table_area = render(frame, frame.area(), block, line(vec!["summary".into()]));
+            })
+            .unwrap();
+        assert_eq!(table_area, Rect::new(1, 2, 38, 7));
+        let text: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("summary"));
+    }
+}
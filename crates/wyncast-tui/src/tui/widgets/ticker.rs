@@ -0,0 +1,110 @@
+// Pick ticker: the last few sales on one line under the status bar.
+//
+// Newest first, e.g. `Soto $48 → Team 5 • Cole $35 → Team 10`, so the
+// room's latest buys stay in view from any tab. When they don't all fit the
+// width, the line rotates by a pick every `ROTATE_EVERY`, bringing each one
+// round to the front in turn.
+
+use std::time::Duration;
+
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::draft::pick::DraftPick;
+use super::money::MoneyFormat;
+
+/// Picks shown on the ticker.
+pub const TICKER_PICKS: usize = 5;
+
+/// How long each rotation holds while the picks overflow the line.
+pub const ROTATE_EVERY: Duration = Duration::from_secs(3);
+
+const SEPARATOR: &str = " • ";
+
+/// Render the ticker into the given area. `elapsed` drives the rotation.
+pub fn render(frame: &mut Frame, area: Rect, picks: &[DraftPick], money: MoneyFormat, elapsed: Duration) {
+    let step = (elapsed.as_secs() / ROTATE_EVERY.as_secs()) as usize;
+    let paragraph = Paragraph::new(build_line(picks, area.width as usize, money, step))
+        .style(Style::default().bg(Color::Black));
+    frame.render_widget(paragraph, area);
+}
+
+/// The latest picks, newest first, rotated `step` picks when they are wider
+/// than `width`.
+fn build_line(picks: &[DraftPick], width: usize, money: MoneyFormat, step: usize) -> Line<'static> {
+    let label = Span::styled(" Last: ", Style::default().fg(Color::DarkGray));
+    if picks.is_empty() {
+        return Line::from(vec![label, Span::styled("no picks yet", Style::default().fg(Color::DarkGray))]);
+    }
+
+    let mut recent: Vec<&DraftPick> = picks.iter().rev().take(TICKER_PICKS).collect();
+    let full_width = label.width()
+        + recent.iter().map(|pick| item_width(pick, money)).sum::<usize>()
+        + SEPARATOR.chars().count() * (recent.len() - 1);
+    if full_width > width {
+        let len = recent.len();
+        recent.rotate_left(step % len);
+    }
+
+    let mut spans = vec![label];
+    for (i, pick) in recent.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(SEPARATOR, Style::default().fg(Color::DarkGray)));
+        }
+        spans.extend(item_spans(pick, money));
+    }
+    Line::from(spans)
+}
+
+/// `Soto $48 → Team 5`
+fn item_spans(pick: &DraftPick, money: MoneyFormat) -> [Span<'static>; 3] {
+    [
+        Span::styled(format!("{} ", pick.player_name), Style::default().fg(Color::White)),
+        Span::styled(money.amount(f64::from(pick.price)), Style::default().fg(Color::Cyan)),
+        Span::styled(format!(" → {}", pick.team_name), Style::default().fg(Color::Gray)),
+    ]
+}
+
+fn item_width(pick: &DraftPick, money: MoneyFormat) -> usize {
+    item_spans(pick, money).iter().map(Span::width).sum()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_pick;
+
+    fn money() -> MoneyFormat {
+        MoneyFormat::new(260, false)
+    }
+
+    #[test]
+    fn shows_the_last_picks_newest_first() {
+        let picks: Vec<DraftPick> = (1..=7).map(|n| test_pick(&format!("P{n}")).number(n).price(n).build()).collect();
+        let line = build_line(&picks, 200, money(), 4);
+        assert_eq!(
+            line.to_string(),
+            " Last: P7 $7 → Team 1 • P6 $6 → Team 1 • P5 $5 → Team 1 • P4 $4 → Team 1 • P3 $3 → Team 1"
+        );
+    }
+
+    #[test]
+    fn rotates_only_when_the_picks_overflow() {
+        let picks = vec![
+            test_pick("Cole").number(1).team(10).price(35).build(),
+            test_pick("Soto").number(2).team(5).price(48).build(),
+        ];
+        let line = build_line(&picks, 30, money(), 1);
+        assert_eq!(line.to_string(), " Last: Cole $35 → Team 10 • Soto $48 → Team 5");
+        let line = build_line(&picks, 30, money(), 2);
+        assert!(line.to_string().starts_with(" Last: Soto $48"));
+        assert!(build_line(&[], 30, money(), 0).to_string().ends_with("no picks yet"));
+    }
+}
@@ -0,0 +1,145 @@
+// Pick toasts: a short note per completed sale, e.g. `Team 7 paid $38 for
+// Turner — $6 over model`, stacked in the top-right corner over the
+// dashboard and dismissed after `TOAST_TTL`.
+//
+// The queue holds at most `MAX_TOASTS`; a burst of picks pushes the oldest
+// out early. Over-model prices are red, bargains green.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::draft::pick::DraftPick;
+use super::money::MoneyFormat;
+
+/// How long a toast stays up.
+pub const TOAST_TTL: Duration = Duration::from_secs(6);
+
+/// Most toasts shown at once.
+pub const MAX_TOASTS: usize = 3;
+
+/// Dollars from the model within which a price counts as at model.
+const AT_MODEL: f64 = 0.5;
+
+/// One pick's toast.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub text: String,
+    /// Price less the model's value; `None` when the player had no value.
+    pub over_model: Option<f64>,
+    shown_at: Instant,
+}
+
+/// The toasts currently up, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct ToastQueue {
+    toasts: VecDeque<Toast>,
+}
+
+impl ToastQueue {
+    /// Queue a toast for `pick`, against the model's `value` of the player
+    /// when bought.
+    pub fn push_pick(&mut self, pick: &DraftPick, value: Option<f64>, money: MoneyFormat, now: Instant) {
+        let price = f64::from(pick.price);
+        let over_model = value.map(|v| price - v);
+        let mut text = format!("{} paid {} for {}", pick.team_name, money.amount(price), pick.player_name);
+        match over_model {
+            Some(gap) if gap.abs() < AT_MODEL => text.push_str(" — at model"),
+            Some(gap) if gap > 0.0 => text.push_str(&format!(" — {} over model", money.amount(gap))),
+            Some(gap) => text.push_str(&format!(" — {} under model", money.amount(-gap))),
+            None => {}
+        }
+        if self.toasts.len() == MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+        self.toasts.push_back(Toast { text, over_model, shown_at: now });
+    }
+
+    /// Drop the toasts that have been up for `TOAST_TTL`.
+    pub fn expire(&mut self, now: Instant) {
+        self.toasts.retain(|t| now.duration_since(t.shown_at) < TOAST_TTL);
+    }
+
+    /// The toasts up at `now`, oldest first.
+    pub fn visible(&self, now: Instant) -> impl Iterator<Item = &Toast> {
+        self.toasts
+            .iter()
+            .filter(move |t| now.duration_since(t.shown_at) < TOAST_TTL)
+    }
+}
+
+/// Render the toasts stacked down the right edge of `area`, newest at the
+/// bottom.
+pub fn render(frame: &mut Frame, area: Rect, queue: &ToastQueue, now: Instant) {
+    for (i, toast) in queue.visible(now).enumerate() {
+        let width = (toast.text.chars().count() as u16 + 2).min(area.width);
+        let y = area.y + i as u16;
+        if y >= area.bottom() {
+            break;
+        }
+        let toast_area = Rect::new(area.right() - width, y, width, 1);
+        frame.render_widget(Clear, toast_area);
+        frame.render_widget(
+            Paragraph::new(Line::from(format!(" {} ", toast.text))).style(toast_style(toast.over_model)),
+            toast_area,
+        );
+    }
+}
+
+fn toast_style(over_model: Option<f64>) -> Style {
+    let style = Style::default().bg(Color::DarkGray);
+    match over_model {
+        Some(gap) if gap >= AT_MODEL => style.fg(Color::LightRed),
+        Some(gap) if gap <= -AT_MODEL => style.fg(Color::LightGreen),
+        _ => style.fg(Color::White),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_pick;
+
+    #[test]
+    fn toasts_give_the_verdict_against_the_model() {
+        let money = MoneyFormat::new(260, false);
+        let now = Instant::now();
+        let mut queue = ToastQueue::default();
+        queue.push_pick(&test_pick("Turner").team(7).price(38).build(), Some(32.2), money, now);
+        queue.push_pick(&test_pick("Soto").team(7).price(40).build(), Some(48.0), money, now);
+        queue.push_pick(&test_pick("Cole").team(7).price(30).build(), Some(30.3), money, now);
+        queue.push_pick(&test_pick("Nobody").team(7).price(1).build(), None, money, now);
+
+        let text: Vec<&str> = queue.visible(now).map(|t| t.text.as_str()).collect();
+        assert_eq!(
+            text,
+            [
+                "Team 7 paid $40 for Soto — $8 under model",
+                "Team 7 paid $30 for Cole — at model",
+                "Team 7 paid $1 for Nobody",
+            ]
+        );
+    }
+
+    #[test]
+    fn toasts_dismiss_themselves() {
+        let money = MoneyFormat::new(260, false);
+        let now = Instant::now();
+        let mut queue = ToastQueue::default();
+        queue.push_pick(&test_pick("Turner").team(7).price(38).build(), Some(32.0), money, now);
+        assert_eq!(queue.visible(now + TOAST_TTL / 2).count(), 1);
+        assert_eq!(queue.visible(now + TOAST_TTL).count(), 0);
+
+        queue.expire(now + TOAST_TTL);
+        assert!(queue.toasts.is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+// WebSocket bind-failure warning overlay (Elm Architecture).
+//
+// Shown on top of whatever screen is active when the WebSocket server could
+// not bind any port in its fallback range, so the user learns at startup
+// (rather than at nomination time) that the extension cannot connect.
+
+use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::protocol::WsServerStatus;
+use crate::tui::subscription::{
+    Subscription, SubscriptionId,
+    keybinding::{exact, KeyBindingRecipe, KeybindHint, KeybindManager, KeyTrigger, PRIORITY_MODAL},
+};
+use crate::tui::widgets::centered_rect;
+
+// ---------------------------------------------------------------------------
+// Message
+// ---------------------------------------------------------------------------
+
+/// Messages that drive the warning overlay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WsWarningMessage {
+    /// Dismiss the overlay (any key).
+    Dismiss,
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Width of the overlay.
+const WARNING_WIDTH: u16 = 60;
+
+/// State for the bind-failure overlay.
+#[derive(Debug, Clone)]
+pub struct WsWarning {
+    /// Whether the overlay is currently visible.
+    pub open: bool,
+    /// The port from strategy.toml.
+    requested_port: u16,
+    /// The bind error, as reported by the server.
+    error: String,
+    sub_id: SubscriptionId,
+}
+
+impl Default for WsWarning {
+    fn default() -> Self {
+        Self {
+            open: false,
+            requested_port: 0,
+            error: String::new(),
+            sub_id: SubscriptionId::unique(),
+        }
+    }
+}
+
+impl WsWarning {
+    /// Open the overlay for a failed bind; other statuses are ignored.
+    pub fn show(&mut self, status: &WsServerStatus) {
+        if let WsServerStatus::BindFailed { requested_port, error } = status {
+            self.requested_port = *requested_port;
+            self.error = error.clone();
+            self.open = true;
+        }
+    }
+
+    // -- Elm Architecture API ------------------------------------------------
+
+    /// Declare keybindings for the subscription system.
+    ///
+    /// Returns a capturing subscription at `PRIORITY_MODAL` while open, or
+    /// `Subscription::none()` when closed. Any key dismisses the overlay.
+    pub fn subscription(&self, kb: &mut KeybindManager) -> Subscription<WsWarningMessage> {
+        if !self.open {
+            return Subscription::none();
+        }
+
+        let recipe = KeyBindingRecipe::new(self.sub_id)
+            .priority(PRIORITY_MODAL)
+            .capture()
+            .bind(
+                exact(KeyCode::Esc),
+                |_| WsWarningMessage::Dismiss,
+                KeybindHint::new("Esc", "Dismiss"),
+            )
+            .bind(KeyTrigger::Any, |_| WsWarningMessage::Dismiss, None);
+
+        kb.subscribe(recipe)
+    }
+
+    /// Process a message.
+    pub fn update(&mut self, msg: WsWarningMessage) {
+        match msg {
+            WsWarningMessage::Dismiss => self.open = false,
+        }
+    }
+
+    /// Render the overlay centered on `area`. Only draws when open.
+    pub fn view(&self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let lines = self.lines();
+        // Height: border(2) + content lines + room for the error to wrap.
+        let height = 2 + lines.len() as u16 + 2;
+        let dialog_area = centered_rect(WARNING_WIDTH, height, area);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red))
+            .title(Span::styled(
+                " Extension cannot connect ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+
+        let paragraph = Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .style(Style::default().bg(Color::Black));
+        frame.render_widget(paragraph, dialog_area);
+    }
+
+    /// The overlay body.
+    fn lines(&self) -> Vec<Line<'static>> {
+        let muted = Style::default().fg(Color::DarkGray);
+        vec![
+            Line::from(format!(
+                " The WebSocket server could not bind port {} or any fallback port.",
+                self.requested_port
+            )),
+            Line::from(Span::styled(format!(" {}", self.error), muted)),
+            Line::from(""),
+            Line::from(" Free the port (or change [websocket] port in strategy.toml)"),
+            Line::from(" and restart. The draft assistant keeps running offline."),
+            Line::from(Span::styled(" Press any key to dismiss.", muted)),
+        ]
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bind_failed() -> WsServerStatus {
+        WsServerStatus::BindFailed {
+            requested_port: 9001,
+            error: "no free port in 127.0.0.1:9001-9010".into(),
+        }
+    }
+
+    #[test]
+    fn opens_only_for_bind_failure_and_dismisses() {
+        let mut warning = WsWarning::default();
+        warning.show(&WsServerStatus::Listening { port: 9002, requested_port: 9001 });
+        assert!(!warning.open);
+
+        warning.show(&bind_failed());
+        assert!(warning.open);
+        let text: String = warning
+            .lines()
+            .iter()
+            .flat_map(|l| l.spans.iter().map(|s| s.content.to_string()))
+            .collect();
+        assert!(text.contains("port 9001"));
+        assert!(text.contains("9001-9010"));
+
+        warning.update(WsWarningMessage::Dismiss);
+        assert!(!warning.open);
+    }
+
+    #[test]
+    fn view_does_not_panic_on_small_terminal() {
+        let mut warning = WsWarning::default();
+        warning.show(&bind_failed());
+        for (w, h) in [(100, 30), (12, 4)] {
+            let backend = ratatui::backend::TestBackend::new(w, h);
+            let mut terminal = ratatui::Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| warning.view(frame, frame.area()))
+                .unwrap();
+        }
+    }
+}
@@ -18,7 +18,7 @@ use wyncast_tui::protocol::*;
 use wyncast_tui::valuation::projections::{AllProjections, PitcherType};
 use wyncast_tui::valuation::zscore::PlayerValuation;
 use wyncast_tui::stats::StatRegistry;
-use wyncast_tui::ws_server::WsEvent;
+use wyncast_tui::ws_server::{self, TungsteniteListener, WsEvent};
 
 use tokio::sync::mpsc;
 
@@ -95,10 +95,19 @@ fn inline_config() -> Config {
             gs_per_week: 7,
         },
         teams: HashMap::new(),
+        player_pool: Default::default(),
+        min_bid: 1,
+        keepers: Default::default(),
+        nomination_order: vec![],
+        roster: HashMap::new(),
+        bid_increment: 1,
+        draft_type: Default::default(),
+        points: Default::default(),
     };
 
     let strategy = StrategyConfig {
         hitting_budget_fraction: 0.65,
+        adaptive_budget_split: false,
         weights: CategoryWeights::from_pairs([
             ("R", 1.0), ("HR", 1.0), ("RBI", 1.0), ("BB", 1.2),
             ("SB", 1.0), ("AVG", 1.0), ("K", 1.0), ("W", 1.0),
@@ -119,8 +128,14 @@ fn inline_config() -> Config {
             planning_max_tokens: 2048,
             analysis_trigger: "nomination".into(),
             prefire_planning: true,
+            analysis_verbosity: Default::default(),
+            analysis_language: "English".into(),
+            analysis_tldr: false,
+            prefire: Default::default(),
         },
         strategy_overview: None,
+        flexibility: FlexibilityConfig::default(),
+        valuation: ValuationRulesConfig::default(),
     };
 
     Config {
@@ -131,7 +146,19 @@ fn inline_config() -> Config {
         data_paths: DataPaths {
             hitters: Some(format!("{}/sample_hitters.csv", FIXTURES)),
             pitchers: Some(format!("{}/sample_pitchers.csv", FIXTURES)),
+            adjustments: None,
+            prep: None,
+            nomination_queue: None,
+            scripts: vec![],
+            database: None,
+            cache_dir: None,
         },
+        logging: LoggingConfig::default(),
+        notify: Default::default(),
+        discord_bot: Default::default(),
+        accessibility: Default::default(),
+        display: Default::default(),
+        backup: Default::default(),
     }
 }
 
@@ -146,7 +173,6 @@ fn load_fixture_players(config: &Config) -> Vec<PlayerValuation> {
     let registry = StatRegistry::from_league_config(&config.league)
         .expect("test registry");
     wyncast_tui::valuation::compute_initial(&projections, config, &roster_config(), &registry)
-        .expect("initial valuation should succeed")
 }
 
 /// Load fixture projections.
@@ -711,6 +737,7 @@ fn nomination_analysis_prompt_contains_required_sections() {
         &state.inflation,
         &budget,
         &state.stat_registry,
+        &state.config.strategy.llm,
     );
 
     // Verify required sections are present
@@ -1568,6 +1595,7 @@ fn end_to_end_pipeline() {
             &state.inflation,
             &budget,
             &state.stat_registry,
+            &state.config.strategy.llm,
         );
 
         assert!(!prompt.is_empty(), "Prompt should not be empty");
@@ -3743,8 +3771,7 @@ fn alt_league_config_full_pipeline() {
         &config,
         &roster_config(),
         &registry,
-    )
-    .expect("alt-league pipeline should succeed");
+    );
 
     assert!(!players.is_empty(), "pipeline should produce players");
 
@@ -3801,3 +3828,388 @@ fn unknown_category_gives_descriptive_error() {
         "error message should name the unknown category; got: {msg}"
     );
 }
+
+// ===========================================================================
+// Tests: Real WebSocket network path
+// ===========================================================================
+//
+// The event-loop tests above feed `WsEvent`s straight into the app's channel.
+// These bind the real `TungsteniteListener` on an ephemeral port and drive it
+// with a tokio-tungstenite client, so the handshake, framing, outbound
+// messages and close handling are all exercised as they are in production.
+
+type WsClient = tokio_tungstenite::WebSocketStream<
+    tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+>;
+
+/// How long to wait for any single event before failing the test.
+const NETWORK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// The app event loop and WebSocket server wired together over a real
+/// socket, as `main` wires them.
+struct NetworkHarness {
+    port: u16,
+    ui_rx: mpsc::Receiver<UiUpdate>,
+    cmd_tx: mpsc::Sender<UserCommand>,
+    _llm_tx: mpsc::Sender<wyncast_tui::protocol::LlmEvent>,
+    shutdown_tx: tokio::sync::oneshot::Sender<()>,
+    app: tokio::task::JoinHandle<anyhow::Result<()>>,
+    server: tokio::task::JoinHandle<Result<(), ws_server::WsError>>,
+}
+
+impl NetworkHarness {
+    /// Bind the server on an ephemeral port, start the event loop with
+    /// fixture state, and drain the initial snapshot.
+    async fn start() -> Self {
+        let listener = TungsteniteListener::bind(0)
+            .await
+            .expect("bind ephemeral port");
+        let port = listener.port();
+
+        let (ws_tx, ws_rx) = mpsc::channel(16);
+        let (outbound_tx, outbound_rx) = mpsc::channel(16);
+        let (llm_tx, llm_rx) = mpsc::channel(16);
+        let (cmd_tx, cmd_rx) = mpsc::channel(16);
+        let (ui_tx, mut ui_rx) = mpsc::channel(64);
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+
+        let mut state = create_test_app_state_from_fixtures();
+        state.ws_outbound_tx = Some(outbound_tx);
+
+        let server = tokio::spawn(ws_server::run_until_shutdown(
+            listener,
+            ws_tx,
+            outbound_rx,
+            async {
+                let _ = shutdown_rx.await;
+            },
+        ));
+        let app = tokio::spawn(app::run(ws_rx, llm_rx, cmd_rx, ui_tx, state));
+        drain_initial_snapshot(&mut ui_rx).await;
+
+        Self {
+            port,
+            ui_rx,
+            cmd_tx,
+            _llm_tx: llm_tx,
+            shutdown_tx,
+            app,
+            server,
+        }
+    }
+
+    /// Open a client connection, as the extension does.
+    async fn connect(&self) -> WsClient {
+        let url = format!("ws://127.0.0.1:{}", self.port);
+        let (client, _response) = tokio::time::timeout(
+            NETWORK_TIMEOUT,
+            tokio_tungstenite::connect_async(url),
+        )
+        .await
+        .expect("connect timed out")
+        .expect("client should connect");
+        client
+    }
+
+    /// Wait for the first UI update matching `pred`, skipping any others
+    /// (budget snapshots, mode changes) the event loop sends along the way.
+    async fn wait_for(&mut self, what: &str, pred: impl Fn(&UiUpdate) -> bool) -> UiUpdate {
+        let deadline = tokio::time::Instant::now() + NETWORK_TIMEOUT;
+        loop {
+            let update = tokio::time::timeout_at(deadline, self.ui_rx.recv())
+                .await
+                .unwrap_or_else(|_| panic!("timed out waiting for {what}"))
+                .unwrap_or_else(|| panic!("UI channel closed waiting for {what}"));
+            if pred(&update) {
+                return update;
+            }
+        }
+    }
+
+    /// Wait for a snapshot reporting `pick_count` picks.
+    async fn wait_for_pick_count(&mut self, pick_count: u32) -> AppSnapshot {
+        let what = format!("snapshot with {pick_count} picks");
+        match self
+            .wait_for(&what, |u| {
+                matches!(u, UiUpdate::StateSnapshot(s) if s.pick_count == pick_count as usize)
+            })
+            .await
+        {
+            UiUpdate::StateSnapshot(snapshot) => *snapshot,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Stop the server, quit the event loop, and check both exit cleanly.
+    async fn shut_down(self) {
+        let _ = self.shutdown_tx.send(());
+        self.cmd_tx.send(UserCommand::Quit).await.unwrap();
+        let server = tokio::time::timeout(NETWORK_TIMEOUT, self.server)
+            .await
+            .expect("server should stop on shutdown")
+            .unwrap();
+        assert!(server.is_ok(), "server exited with {server:?}");
+        let app = tokio::time::timeout(NETWORK_TIMEOUT, self.app)
+            .await
+            .expect("event loop should stop on quit")
+            .unwrap();
+        assert!(app.is_ok(), "event loop exited with {app:?}");
+    }
+}
+
+/// Send one text frame from the client.
+async fn client_send(client: &mut WsClient, json: String) {
+    use futures_util::SinkExt;
+    client
+        .send(tokio_tungstenite::tungstenite::Message::Text(json.into()))
+        .await
+        .expect("client send");
+}
+
+/// Read the next non-control frame the server sends the client.
+async fn client_recv(client: &mut WsClient) -> tokio_tungstenite::tungstenite::Message {
+    use futures_util::StreamExt;
+    use tokio_tungstenite::tungstenite::Message;
+    loop {
+        let msg = tokio::time::timeout(NETWORK_TIMEOUT, client.next())
+            .await
+            .expect("timed out waiting for a server frame")
+            .expect("server closed the stream without a close frame")
+            .expect("client read");
+        if !matches!(msg, Message::Ping(_) | Message::Pong(_)) {
+            return msg;
+        }
+    }
+}
+
+/// Assert the next server frame is a REQUEST_KEYFRAME.
+async fn expect_keyframe_request(client: &mut WsClient) {
+    match client_recv(client).await {
+        tokio_tungstenite::tungstenite::Message::Text(text) => {
+            assert!(text.contains("REQUEST_KEYFRAME"), "unexpected outbound message: {text}");
+        }
+        other => panic!("Expected a text frame, got {:?}", other),
+    }
+}
+
+/// Assert the next server frame is the RESUME token answering a handshake,
+/// with `pick_count` picks processed.
+async fn expect_resume(client: &mut WsClient, pick_count: u64) {
+    match client_recv(client).await {
+        tokio_tungstenite::tungstenite::Message::Text(text) => {
+            let msg: serde_json::Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(msg["type"], "RESUME", "unexpected outbound message: {text}");
+            assert_eq!(msg["payload"]["pickCount"], pick_count, "{text}");
+        }
+        other => panic!("Expected a text frame, got {:?}", other),
+    }
+}
+
+/// Like `build_state_update_json`, but as the FULL_STATE_SYNC keyframe the
+/// extension sends on (re)connect.
+fn build_full_state_sync_json(
+    events: &[MockDraftEvent],
+    up_to_pick: u32,
+    nomination: Option<(&str, &str, &str, &str)>,
+) -> String {
+    let mut value: serde_json::Value =
+        serde_json::from_str(&build_state_update_json(events, up_to_pick, nomination)).unwrap();
+    value["type"] = "FULL_STATE_SYNC".into();
+    value.to_string()
+}
+
+/// Drive a scripted draft through a real socket: connect, full sync, picks,
+/// a nomination and its bidding, a disconnect, a reconnect with a fresh
+/// keyframe, and a server shutdown.
+#[tokio::test]
+async fn real_websocket_client_drives_scripted_draft() {
+    let mut harness = NetworkHarness::start().await;
+    let events = generate_mock_draft_events();
+    let budgets: Vec<(String, u32)> = (1..=10).map(|i| (format!("Team {}", i), 260)).collect();
+
+    // --- Connect and sync ---
+    let mut client = harness.connect().await;
+    harness
+        .wait_for("Connected", |u| {
+            matches!(u, UiUpdate::ConnectionStatus(ConnectionStatus::Connected))
+        })
+        .await;
+
+    client_send(
+        &mut client,
+        serde_json::json!({
+            "type": "EXTENSION_CONNECTED",
+            "payload": { "platform": "firefox", "extensionVersion": "1.0.0" }
+        })
+        .to_string(),
+    )
+    .await;
+    expect_resume(&mut client, 0).await;
+    client_send(&mut client, build_full_state_sync_json(&events, 2, None)).await;
+    let snapshot = harness.wait_for_pick_count(2).await;
+    assert_eq!(snapshot.draft_log.len(), 2);
+
+    // The sync carries no draft board, so the backend asks for another
+    // keyframe over the outbound half of the same socket.
+    expect_keyframe_request(&mut client).await;
+
+    // --- Incremental picks and a nomination ---
+    client_send(
+        &mut client,
+        build_state_update_json(&events, 3, Some(("espn_103", "Bobby Witt Jr.", "SS", "Team 6"))),
+    )
+    .await;
+    harness.wait_for_pick_count(3).await;
+    match harness
+        .wait_for("NominationUpdate", |u| matches!(u, UiUpdate::NominationUpdate { .. }))
+        .await
+    {
+        UiUpdate::NominationUpdate { info, .. } => assert_eq!(info.player_name, "Bobby Witt Jr."),
+        _ => unreachable!(),
+    }
+
+    // Bidding on the same nomination arrives as a bid update.
+    let bid = serde_json::json!({
+        "playerId": "espn_103",
+        "playerName": "Bobby Witt Jr.",
+        "position": "SS",
+        "nominatedBy": "Team 6",
+        "currentBid": 17,
+        "currentBidder": "Team 2",
+        "timeRemaining": 12
+    });
+    client_send(
+        &mut client,
+        build_state_update_json_with_custom_nomination(&events, 3, &budgets, Some(bid)),
+    )
+    .await;
+    match harness
+        .wait_for("BidUpdate", |u| matches!(u, UiUpdate::BidUpdate(_)))
+        .await
+    {
+        UiUpdate::BidUpdate(info) => {
+            assert_eq!(info.player_name, "Bobby Witt Jr.");
+            assert_eq!(info.current_bid, 17);
+        }
+        _ => unreachable!(),
+    }
+
+    // --- Disconnect ---
+    client.close(None).await.expect("client close");
+    harness
+        .wait_for("Disconnected", |u| {
+            matches!(u, UiUpdate::ConnectionStatus(ConnectionStatus::Disconnected))
+        })
+        .await;
+
+    // --- Reconnect: the keyframe resumes from the picks we have ---
+    let mut client = harness.connect().await;
+    harness
+        .wait_for("Connected after reconnect", |u| {
+            matches!(u, UiUpdate::ConnectionStatus(ConnectionStatus::Connected))
+        })
+        .await;
+    client_send(
+        &mut client,
+        serde_json::json!({
+            "type": "EXTENSION_CONNECTED",
+            "payload": { "platform": "firefox", "extensionVersion": "1.0.0" }
+        })
+        .to_string(),
+    )
+    .await;
+    // Outbound messages reach the new connection, not the closed one.
+    expect_resume(&mut client, 3).await;
+    client_send(
+        &mut client,
+        build_full_state_sync_json(&events, 4, Some(("espn_104", "Mookie Betts", "SS", "Team 7"))),
+    )
+    .await;
+    let snapshot = harness.wait_for_pick_count(4).await;
+    assert_eq!(snapshot.draft_log.len(), 4, "picks must not be double-counted after reconnect");
+    assert!(
+        !snapshot.available_players.iter().any(|p| p.name == "Bobby Witt Jr."),
+        "a drafted player should leave the available pool"
+    );
+    match harness
+        .wait_for("NominationUpdate after reconnect", |u| {
+            matches!(u, UiUpdate::NominationUpdate { .. })
+        })
+        .await
+    {
+        UiUpdate::NominationUpdate { info, .. } => assert_eq!(info.player_name, "Mookie Betts"),
+        _ => unreachable!(),
+    }
+
+    // --- Shutdown: the connected client gets a close frame ---
+    let _ = harness.shutdown_tx.send(());
+    let NetworkHarness { cmd_tx, app, server, .. } = harness;
+    match client_recv(&mut client).await {
+        tokio_tungstenite::tungstenite::Message::Close(Some(frame)) => {
+            assert_eq!(frame.reason.as_str(), "server shutting down");
+        }
+        other => panic!("Expected a close frame, got {:?}", other),
+    }
+    tokio::time::timeout(NETWORK_TIMEOUT, server)
+        .await
+        .expect("server should stop on shutdown")
+        .unwrap()
+        .unwrap();
+    cmd_tx.send(UserCommand::Quit).await.unwrap();
+    tokio::time::timeout(NETWORK_TIMEOUT, app)
+        .await
+        .expect("event loop should stop on quit")
+        .unwrap()
+        .unwrap();
+}
+
+/// Two clients in a row each see the handshake complete and each session is
+/// reported as its own connect/disconnect pair.
+#[tokio::test]
+async fn real_websocket_reconnects_are_reported_in_order() {
+    let mut harness = NetworkHarness::start().await;
+
+    for _ in 0..2 {
+        let mut client = harness.connect().await;
+        harness
+            .wait_for("Connected", |u| {
+                matches!(u, UiUpdate::ConnectionStatus(ConnectionStatus::Connected))
+            })
+            .await;
+        client.close(None).await.expect("client close");
+        harness
+            .wait_for("Disconnected", |u| {
+                matches!(u, UiUpdate::ConnectionStatus(ConnectionStatus::Disconnected))
+            })
+            .await;
+    }
+
+    harness.shut_down().await;
+}
+
+// ===========================================================================
+// Tests: Recorded session fixtures
+// ===========================================================================
+
+// Each directory under `tests/fixtures/sessions` is a draft session recorded
+// with `WYNCAST_RECORD_SESSION` and turned into a fixture with
+// `--make-fixture`. Replaying its messages must still end in the state it
+// expects.
+
+#[tokio::test]
+async fn recorded_sessions_replay_to_their_expected_state() {
+    let mut dirs: Vec<_> = std::fs::read_dir(format!("{}/sessions", FIXTURES))
+        .expect("sessions fixture directory")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    assert!(!dirs.is_empty(), "no session fixtures");
+
+    for dir in dirs {
+        let (expected, actual) = app::session_fixture::check_fixture(&dir)
+            .await
+            .unwrap_or_else(|e| panic!("{}: {:#}", dir.display(), e));
+        assert_eq!(actual, expected, "{} replayed differently", dir.display());
+    }
+}
@@ -8,11 +8,23 @@
 // Configuration
 // ---------------------------------------------------------------------------
 
-// Must match the WebSocket port in ws_server.rs
-const WS_URL = 'ws://localhost:9001';
+// Must match the WebSocket port in strategy.toml and PORT_FALLBACK_ATTEMPTS /
+// DISCOVERY_PATH in ws_server.rs: the backend falls back to the next free
+// port when the configured one is taken, and we find it by probing its
+// discovery endpoint across the same range.
+const WS_BASE_PORT = 9001;
+const WS_PORT_FALLBACK_ATTEMPTS = 10;
+const DISCOVERY_PATH = '/wyncast';
+const DISCOVERY_TIMEOUT_MS = 500;
 const HEARTBEAT_INTERVAL_MS = 5000;
+const HEALTH_WINDOW_MS = 60000;
 const RECONNECT_BASE_MS = 1000;
 const RECONNECT_MAX_MS = 30000;
+// Close code the backend sends when a message is over its size limit.
+const CLOSE_MESSAGE_TOO_BIG = 1009;
+// How long to wait for the backend's RESUME before asking for a snapshot
+// anyway; a backend without the resume handshake never sends one.
+const RESUME_WAIT_MS = 1000;
 const ESPN_HOSTNAME = 'fantasy.espn.com';
 const ESPN_BASEBALL_PATH_PREFIX = '/baseball/';
 
@@ -40,11 +52,46 @@ function isEspnAppUrl(urlStr) {
 
 let ws = null;
 let heartbeatTimer = null;
+// The backend's last HEARTBEAT_ACK, echoed in the next heartbeat so it can
+// measure round-trip latency: { timestamp, receivedAt }.
+let pendingAck = null;
 let reconnectTimer = null;
+// Pending snapshot request while we wait for the backend's RESUME.
+let resumeTimer = null;
 let reconnectDelay = RECONNECT_BASE_MS;
+let discovering = false;
 let isConnected = false;
 let intentionalDisconnect = false;
 
+// Scraping health reported with each heartbeat: when each draft state
+// message was relayed (pruned to the last minute), when picks and a
+// nomination were last seen in one, and how many scrape errors the content
+// script reported.
+const scrapeHealth = {
+  relayedAt: [],
+  lastPicksAt: null,
+  lastNominationAt: null,
+  jsErrors: 0,
+};
+
+/**
+ * Record a relayed draft state message in the scraping health.
+ */
+function recordScrape(message) {
+  if (message.type !== 'STATE_UPDATE' && message.type !== 'FULL_STATE_SYNC') {
+    return;
+  }
+  const now = Date.now();
+  scrapeHealth.relayedAt.push(now);
+  const payload = message.payload || {};
+  if (payload.picks && payload.picks.length > 0) {
+    scrapeHealth.lastPicksAt = now;
+  }
+  if (payload.currentNomination) {
+    scrapeHealth.lastNominationAt = now;
+  }
+}
+
 // ---------------------------------------------------------------------------
 // Active tab tracking
 // ---------------------------------------------------------------------------
@@ -148,15 +195,32 @@ function sendHandshake(config) {
 
 /**
  * Send a heartbeat message to keep the connection alive.
+ *
+ * Echoes the backend's last ack along with how long we held it, so the
+ * backend can subtract the wait between heartbeats from the round trip, and
+ * carries the scraping health.
  */
 function sendHeartbeat() {
-  const heartbeat = {
-    type: 'EXTENSION_HEARTBEAT',
-    payload: {
-      timestamp: Date.now(),
-    },
-  };
-  wsSend(heartbeat);
+  const payload = { timestamp: Date.now() };
+  if (pendingAck) {
+    payload.ackTimestamp = pendingAck.timestamp;
+    payload.ackHeldMs = payload.timestamp - pendingAck.receivedAt;
+    pendingAck = null;
+  }
+
+  // Scraping health, with ages rather than times so the backend never
+  // compares our clock with its own.
+  const windowStart = payload.timestamp - HEALTH_WINDOW_MS;
+  scrapeHealth.relayedAt = scrapeHealth.relayedAt.filter((t) => t > windowStart);
+  payload.updatesPerMinute = scrapeHealth.relayedAt.length;
+  if (scrapeHealth.lastPicksAt !== null) {
+    payload.picksAgeMs = payload.timestamp - scrapeHealth.lastPicksAt;
+  }
+  if (scrapeHealth.lastNominationAt !== null) {
+    payload.nominationAgeMs = payload.timestamp - scrapeHealth.lastNominationAt;
+  }
+  payload.jsErrors = scrapeHealth.jsErrors;
+  wsSend({ type: 'EXTENSION_HEARTBEAT', payload });
 }
 
 /**
@@ -164,6 +228,7 @@ function sendHeartbeat() {
  */
 function startHeartbeat() {
   stopHeartbeat();
+  pendingAck = null;
   heartbeatTimer = setInterval(sendHeartbeat, HEARTBEAT_INTERVAL_MS);
 }
 
@@ -218,9 +283,38 @@ function requestFullStateSyncFromContentScript(config) {
 }
 
 /**
- * Establish a WebSocket connection to the backend.
+ * Find the backend by probing its discovery endpoint on every port in the
+ * fallback range. Resolves to the lowest port that answers as wyncast, or
+ * null when none does.
+ */
+async function discoverPort() {
+  const ports = [];
+  for (let i = 0; i < WS_PORT_FALLBACK_ATTEMPTS; i++) {
+    ports.push(WS_BASE_PORT + i);
+  }
+  const found = await Promise.all(ports.map(async (port) => {
+    try {
+      const resp = await fetch(`http://localhost:${port}${DISCOVERY_PATH}`, {
+        signal: AbortSignal.timeout(DISCOVERY_TIMEOUT_MS),
+      });
+      if (!resp.ok) return null;
+      const info = await resp.json();
+      return info.service === 'wyncast' ? info.ws_port : null;
+    } catch (e) {
+      return null;
+    }
+  }));
+  return found.find((port) => port !== null) ?? null;
+}
+
+/**
+ * Discover the backend's port, then establish a WebSocket connection to it.
  */
 function connect(config) {
+  if (discovering) {
+    return;
+  }
+
   // Clean up any existing connection
   if (ws) {
     try {
@@ -231,10 +325,31 @@ function connect(config) {
     ws = null;
   }
 
-  log(`Connecting to ${WS_URL}...`);
+  discovering = true;
+  discoverPort().then((port) => {
+    discovering = false;
+    if (knownTabs.size === 0) {
+      log('All content script tabs closed during discovery; not connecting');
+      return;
+    }
+    if (port === null) {
+      log(`Backend not found on ports ${WS_BASE_PORT}-${WS_BASE_PORT + WS_PORT_FALLBACK_ATTEMPTS - 1}`);
+      scheduleReconnect(config);
+      return;
+    }
+    openSocket(config, port);
+  });
+}
+
+/**
+ * Open the WebSocket to the backend on a discovered port.
+ */
+function openSocket(config, port) {
+  const url = `ws://localhost:${port}`;
+  log(`Connecting to ${url}...`);
 
   try {
-    ws = new WebSocket(WS_URL);
+    ws = new WebSocket(url);
   } catch (e) {
     error('Failed to create WebSocket:', e.message || e);
     scheduleReconnect(config);
@@ -254,10 +369,15 @@ function connect(config) {
     // Start heartbeat
     startHeartbeat();
 
-    // Request a full state snapshot from the content script so the backend
-    // can rebuild draft state from scratch rather than applying diffs against
-    // a blank slate.
-    requestFullStateSyncFromContentScript(config);
+    // Request a full state snapshot from the content script once the
+    // backend has said what it already has (RESUME), so it can apply just
+    // the picks it missed, or rebuild draft state from scratch rather than
+    // applying diffs against a blank slate.
+    resumeTimer = setTimeout(() => {
+      resumeTimer = null;
+      log('No RESUME from backend — requesting a full snapshot');
+      requestFullStateSyncFromContentScript(config);
+    }, RESUME_WAIT_MS);
   };
 
   ws.onclose = (event) => {
@@ -265,10 +385,18 @@ function connect(config) {
     isConnected = false;
     ws = null;
     stopHeartbeat();
+    clearTimeout(resumeTimer);
+    resumeTimer = null;
 
     if (intentionalDisconnect) {
       intentionalDisconnect = false;
     } else {
+      if (event.code === CLOSE_MESSAGE_TOO_BIG) {
+        // Reconnecting at once would resend the same snapshot and be
+        // refused again; wait the longest backoff before trying.
+        warn('Backend refused a message as too big; backing off');
+        reconnectDelay = RECONNECT_MAX_MS;
+      }
       scheduleReconnect(config);
     }
   };
@@ -285,6 +413,15 @@ function connect(config) {
       if (msg.type === 'REQUEST_KEYFRAME') {
         log('Backend requested keyframe — forwarding to content script');
         requestFullStateSyncFromContentScript(config);
+      } else if (msg.type === 'RESUME') {
+        if (resumeTimer !== null) {
+          clearTimeout(resumeTimer);
+          resumeTimer = null;
+          log('Backend resumes after pick', msg.payload.pickCount, '— requesting snapshot');
+          requestFullStateSyncFromContentScript(config);
+        }
+      } else if (msg.type === 'HEARTBEAT_ACK') {
+        pendingAck = { timestamp: msg.timestamp, receivedAt: Date.now() };
       }
     } catch (e) {
       warn('Failed to parse backend message:', e.message || e);
@@ -383,6 +520,12 @@ function initBackgroundCore(config) {
       return;
     }
 
+    // Scrape errors are counted for the heartbeat, not relayed.
+    if (message.type === 'SCRAPE_ERROR') {
+      scrapeHealth.jsErrors += 1;
+      return;
+    }
+
     log('Relaying', message.type, 'from tab', tabId);
 
     // Build a protocol-compliant message with ONLY the fields that
@@ -395,7 +538,9 @@ function initBackgroundCore(config) {
 
     // Forward to WebSocket
     if (isConnected) {
-      if (!wsSend(forwarded)) {
+      if (wsSend(forwarded)) {
+        recordScrape(forwarded);
+      } else {
         warn('WebSocket send failed; message dropped');
       }
     } else {
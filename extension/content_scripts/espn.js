@@ -27,6 +27,7 @@ const ESPN_SLOT_SP   = 14;
 const ESPN_SLOT_RP   = 15;
 const ESPN_SLOT_BE   = 16;
 const ESPN_SLOT_IL   = 17;
+const ESPN_SLOT_NA   = 18; // minor league
 
 /**
  * Map a position string from the ESPN draft page to the corresponding ESPN
@@ -65,6 +66,9 @@ function espnSlotIdFromPositionStr(posStr) {
     case 'BN':   return ESPN_SLOT_BE;
     case 'IL':
     case 'DL':   return ESPN_SLOT_IL;
+    case 'NA':
+    case 'MINORS':
+    case 'TAXI': return ESPN_SLOT_NA;
     default:     return null;
   }
 }
@@ -900,11 +904,29 @@ function scrapeDom() {
     state.teamIdMapping = scrapeTeamIdMapping();
   } catch (e) {
     error('DOM scraping error:', e);
+    reportScrapeError(e);
   }
 
   return state;
 }
 
+/**
+ * Tell the background script a scrape failed, so the backend can show the
+ * error count with the extension's health. Not relayed to the backend.
+ */
+function reportScrapeError(e) {
+  try {
+    browser.runtime.sendMessage({
+      source: 'wyndham-draft-sync',
+      type: 'SCRAPE_ERROR',
+      timestamp: Date.now(),
+      payload: { message: String((e && e.message) || e) },
+    }).catch(() => {});
+  } catch (err) {
+    // Background script unavailable; nothing to report to.
+  }
+}
+
 // ---------------------------------------------------------------------------
 // Draft identifier extraction
 // ---------------------------------------------------------------------------
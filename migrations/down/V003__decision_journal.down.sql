@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_decision_journal_draft;
+DROP TABLE IF EXISTS decision_journal;
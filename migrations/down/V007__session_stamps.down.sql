@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_session_stamps_draft;
+DROP TABLE IF EXISTS session_stamps;
@@ -0,0 +1 @@
+ALTER TABLE session_stamps DROP COLUMN settings;
@@ -0,0 +1,11 @@
+CREATE TABLE crash_reports (
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    occurred_at  TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    draft_id     TEXT,
+    pick_count   INTEGER,
+    message      TEXT NOT NULL,
+    location     TEXT,
+    backtrace    TEXT NOT NULL,
+    crash_file   TEXT,
+    acknowledged INTEGER NOT NULL DEFAULT 0
+);
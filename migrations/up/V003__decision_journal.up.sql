@@ -0,0 +1,11 @@
+CREATE TABLE decision_journal (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    recorded_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    draft_id    TEXT NOT NULL,
+    player_name TEXT NOT NULL,
+    action      TEXT NOT NULL,
+    amount      INTEGER,
+    current_bid INTEGER NOT NULL
+);
+
+CREATE INDEX idx_decision_journal_draft ON decision_journal(draft_id);
@@ -0,0 +1,8 @@
+CREATE TABLE keeper_contracts (
+    player_name  TEXT PRIMARY KEY,
+    salary       INTEGER NOT NULL,
+    years        INTEGER NOT NULL,
+    raise_amount INTEGER,
+    raise_pct    REAL,
+    updated_at   TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
@@ -0,0 +1,6 @@
+CREATE TABLE nominations (
+    draft_id     TEXT NOT NULL,
+    player_name  TEXT NOT NULL,
+    nominated_by TEXT NOT NULL,
+    PRIMARY KEY (draft_id, player_name)
+);
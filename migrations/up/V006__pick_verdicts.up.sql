@@ -0,0 +1,7 @@
+CREATE TABLE pick_verdicts (
+    draft_id     TEXT NOT NULL,
+    player_name  TEXT NOT NULL,
+    verdict      TEXT NOT NULL,
+    max_bid      INTEGER NOT NULL,
+    PRIMARY KEY (draft_id, player_name)
+);
@@ -0,0 +1,11 @@
+CREATE TABLE session_stamps (
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    recorded_at  TEXT NOT NULL,
+    draft_id     TEXT NOT NULL,
+    app_version  TEXT NOT NULL,
+    config_hash  TEXT NOT NULL,
+    inputs       TEXT NOT NULL,
+    weights      TEXT NOT NULL
+);
+
+CREATE INDEX idx_session_stamps_draft ON session_stamps(draft_id);
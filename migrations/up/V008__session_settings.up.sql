@@ -0,0 +1 @@
+ALTER TABLE session_stamps ADD COLUMN settings TEXT NOT NULL DEFAULT '{}';